@@ -0,0 +1,154 @@
+//! Character advancement rules
+//!
+//! What a level-up grants under each rule system. The Engine decides when a
+//! character levels up and has the final say; these rules let the level-up
+//! wizard guide the player and catch invalid choices before they are sent.
+
+use std::collections::HashMap;
+
+use super::websocket_messages::LevelUpChoices;
+use super::world_snapshot::{RuleSystemType, StatDefinition};
+
+/// What the player gets to choose when reaching a new level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelUpAllowance {
+    /// Points to spread across stats
+    pub stat_points: u32,
+    /// Most points any one stat can take this level
+    pub max_per_stat: u32,
+    /// New skills the character can learn
+    pub new_skills: u32,
+}
+
+impl LevelUpAllowance {
+    /// The allowance for reaching `new_level` under a rule system
+    pub fn for_level(system_type: RuleSystemType, new_level: u32) -> Self {
+        match system_type {
+            // Stat increases every fourth level, a new proficiency otherwise
+            RuleSystemType::D20 if new_level.is_multiple_of(4) => Self {
+                stat_points: 2,
+                max_per_stat: 2,
+                new_skills: 0,
+            },
+            RuleSystemType::D20 => Self {
+                stat_points: 0,
+                max_per_stat: 0,
+                new_skills: 1,
+            },
+            // Characteristics are fixed; advancement is all skills
+            RuleSystemType::D100 => Self {
+                stat_points: 0,
+                max_per_stat: 0,
+                new_skills: 2,
+            },
+            RuleSystemType::Narrative | RuleSystemType::Custom => Self {
+                stat_points: 1,
+                max_per_stat: 1,
+                new_skills: 1,
+            },
+        }
+    }
+
+    /// Whether there is nothing to choose
+    pub fn is_empty(&self) -> bool {
+        self.stat_points == 0 && self.new_skills == 0
+    }
+}
+
+/// Check level-up choices against the allowance and the rule system's stats
+///
+/// `current_stats` holds the character's stat values keyed by abbreviation;
+/// `known_skills` holds the IDs of skills they already have.
+pub fn validate_level_up(
+    choices: &LevelUpChoices,
+    allowance: &LevelUpAllowance,
+    stats: &[StatDefinition],
+    current_stats: &HashMap<String, i32>,
+    known_skills: &[String],
+) -> Result<(), String> {
+    let mut spent = 0;
+    for (abbreviation, &increase) in &choices.stat_increases {
+        if increase == 0 {
+            continue;
+        }
+        let Some(stat) = stats.iter().find(|s| &s.abbreviation == abbreviation) else {
+            return Err(format!("{} isn't a stat in this rule system", abbreviation));
+        };
+        if increase > allowance.max_per_stat {
+            return Err(format!(
+                "{} can go up by at most {} this level",
+                stat.name, allowance.max_per_stat
+            ));
+        }
+        let current = current_stats.get(abbreviation).copied().unwrap_or(stat.default_value);
+        if current + increase as i32 > stat.max_value {
+            return Err(format!("{} can't go above {}", stat.name, stat.max_value));
+        }
+        spent += increase;
+    }
+    if spent != allowance.stat_points {
+        return Err(format!(
+            "Spend exactly {} stat point(s) ({} spent)",
+            allowance.stat_points, spent
+        ));
+    }
+
+    if choices.new_skill_ids.len() > allowance.new_skills as usize {
+        return Err(format!("Choose at most {} new skill(s)", allowance.new_skills));
+    }
+    for (i, skill_id) in choices.new_skill_ids.iter().enumerate() {
+        if known_skills.contains(skill_id) || choices.new_skill_ids[..i].contains(skill_id) {
+            return Err("Each new skill must be one the character doesn't already have".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stat(abbreviation: &str, max_value: i32) -> StatDefinition {
+        StatDefinition {
+            name: abbreviation.to_string(),
+            abbreviation: abbreviation.to_string(),
+            min_value: 1,
+            max_value,
+            default_value: 10,
+        }
+    }
+
+    #[test]
+    fn validates_stat_points() {
+        let allowance = LevelUpAllowance::for_level(RuleSystemType::D20, 4);
+        let stats = vec![stat("STR", 20), stat("DEX", 20)];
+        let current = HashMap::from([("STR".to_string(), 19)]);
+        let choose = |increases: &[(&str, u32)]| LevelUpChoices {
+            stat_increases: increases.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+            new_skill_ids: vec![],
+        };
+
+        assert!(validate_level_up(&choose(&[("DEX", 2)]), &allowance, &stats, &current, &[]).is_ok());
+        assert!(validate_level_up(&choose(&[("STR", 1), ("DEX", 1)]), &allowance, &stats, &current, &[]).is_ok());
+        // Over the stat's maximum, unspent points, unknown stat
+        assert!(validate_level_up(&choose(&[("STR", 2)]), &allowance, &stats, &current, &[]).is_err());
+        assert!(validate_level_up(&choose(&[("DEX", 1)]), &allowance, &stats, &current, &[]).is_err());
+        assert!(validate_level_up(&choose(&[("LUK", 2)]), &allowance, &stats, &current, &[]).is_err());
+    }
+
+    #[test]
+    fn validates_new_skills() {
+        let allowance = LevelUpAllowance::for_level(RuleSystemType::D100, 2);
+        let known = vec!["stealth".to_string()];
+        let choose = |ids: &[&str]| LevelUpChoices {
+            stat_increases: HashMap::new(),
+            new_skill_ids: ids.iter().map(|s| s.to_string()).collect(),
+        };
+
+        assert!(validate_level_up(&choose(&["climb", "swim"]), &allowance, &[], &HashMap::new(), &known).is_ok());
+        assert!(validate_level_up(&choose(&["climb", "swim", "ride"]), &allowance, &[], &HashMap::new(), &known).is_err());
+        assert!(validate_level_up(&choose(&["stealth"]), &allowance, &[], &HashMap::new(), &known).is_err());
+        assert!(validate_level_up(&choose(&["climb", "climb"]), &allowance, &[], &HashMap::new(), &known).is_err());
+    }
+}
@@ -0,0 +1,289 @@
+//! Annotations - what the DM draws over backdrops
+//!
+//! Annotations live on a surface and belong to a layer. Points are stored
+//! as fractions of the surface's width and height, so drawings line up at
+//! any screen size. Layers can be hidden from players, letting the DM
+//! prepare notes on the same surface.
+//!
+//! The only surface is a region's backdrop, which is often a battle map.
+//! Location maps and handouts aren't surfaces: the Engine sends no location
+//! map image (the mini-map's `map_image` is never filled in) and there are
+//! no handouts to draw on.
+
+use serde::{Deserialize, Serialize};
+
+/// Side of the square coordinate space annotations are drawn in
+pub const VIEWBOX_SIZE: f32 = 1000.0;
+
+/// What an annotation is drawn on
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AnnotationSurface {
+    /// A region's backdrop, as shown behind the scene
+    Backdrop { region_id: String },
+}
+
+impl AnnotationSurface {
+    /// Stable key for storing a surface's annotations
+    pub fn key(&self) -> String {
+        match self {
+            Self::Backdrop { region_id } => format!("backdrop:{}", region_id),
+        }
+    }
+}
+
+/// A point on a surface, as fractions of its width and height
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AnnotationPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl AnnotationPoint {
+    /// A point clamped onto the surface
+    pub fn new(x: f32, y: f32) -> Self {
+        Self {
+            x: x.clamp(0.0, 1.0),
+            y: y.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Position in the annotation viewbox
+    pub fn scaled(&self) -> (f32, f32) {
+        (self.x * VIEWBOX_SIZE, self.y * VIEWBOX_SIZE)
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2)).sqrt()
+    }
+}
+
+/// What was drawn
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AnnotationShape {
+    Freehand { points: Vec<AnnotationPoint> },
+    Arrow { from: AnnotationPoint, to: AnnotationPoint },
+    Pin {
+        at: AnnotationPoint,
+        #[serde(default)]
+        label: Option<String>,
+    },
+    Text { at: AnnotationPoint, text: String },
+}
+
+/// One drawn mark
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    pub id: String,
+    pub layer_id: String,
+    /// CSS color
+    pub color: String,
+    pub shape: AnnotationShape,
+}
+
+/// A named group of annotations that can be shown or hidden together
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnnotationLayer {
+    pub id: String,
+    pub name: String,
+    /// Players only see layers the DM has made visible
+    pub visible_to_players: bool,
+}
+
+/// Everything drawn on one surface
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SurfaceAnnotations {
+    #[serde(default)]
+    pub layers: Vec<AnnotationLayer>,
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+}
+
+/// A surface with what's drawn on it, as sent when joining a session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnnotatedSurface {
+    pub surface: AnnotationSurface,
+    pub annotations: SurfaceAnnotations,
+}
+
+impl SurfaceAnnotations {
+    /// Annotations to draw, in drawing order
+    ///
+    /// The DM sees every layer; players only layers visible to them.
+    pub fn visible(&self, is_dm: bool) -> Vec<&Annotation> {
+        self.annotations
+            .iter()
+            .filter(|a| {
+                is_dm
+                    || self
+                        .layers
+                        .iter()
+                        .any(|l| l.id == a.layer_id && l.visible_to_players)
+            })
+            .collect()
+    }
+}
+
+impl AnnotationShape {
+    /// Distance from a point to the nearest part of the shape
+    pub fn distance_to(&self, point: &AnnotationPoint) -> f32 {
+        match self {
+            Self::Freehand { points } => points
+                .windows(2)
+                .map(|w| segment_distance(point, &w[0], &w[1]))
+                .chain(points.first().map(|p| p.distance(point)))
+                .fold(f32::MAX, f32::min),
+            Self::Arrow { from, to } => segment_distance(point, from, to),
+            Self::Pin { at, .. } | Self::Text { at, .. } => at.distance(point),
+        }
+    }
+}
+
+fn segment_distance(point: &AnnotationPoint, a: &AnnotationPoint, b: &AnnotationPoint) -> f32 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let length_sq = dx * dx + dy * dy;
+    if length_sq == 0.0 {
+        return a.distance(point);
+    }
+    let t = (((point.x - a.x) * dx + (point.y - a.y) * dy) / length_sq).clamp(0.0, 1.0);
+    AnnotationPoint { x: a.x + t * dx, y: a.y + t * dy }.distance(point)
+}
+
+/// The annotation closest to a point, if any is within `max_distance`
+///
+/// Later annotations win ties, since they are drawn on top.
+pub fn annotation_at<'a>(
+    annotations: &[&'a Annotation],
+    point: &AnnotationPoint,
+    max_distance: f32,
+) -> Option<&'a Annotation> {
+    annotations
+        .iter()
+        .rev()
+        .map(|a| (*a, a.shape.distance_to(point)))
+        .filter(|(_, d)| *d <= max_distance)
+        .fold(None, |best: Option<(&Annotation, f32)>, (a, d)| match best {
+            Some((_, best_d)) if best_d <= d => best,
+            _ => Some((a, d)),
+        })
+        .map(|(a, _)| a)
+}
+
+/// Drop points closer than `min_distance` to the last kept point
+///
+/// Keeps freehand strokes small enough to broadcast; the last point is
+/// always kept so the stroke ends where the pointer lifted.
+pub fn simplify_stroke(points: &[AnnotationPoint], min_distance: f32) -> Vec<AnnotationPoint> {
+    let mut kept: Vec<AnnotationPoint> = Vec::new();
+    for point in points {
+        match kept.last() {
+            Some(last) if last.distance(point) < min_distance => {}
+            _ => kept.push(*point),
+        }
+    }
+    if let (Some(last), Some(end)) = (kept.last(), points.last()) {
+        if last != end {
+            kept.push(*end);
+        }
+    }
+    kept
+}
+
+/// SVG path data for a stroke in the annotation viewbox
+pub fn stroke_path(points: &[AnnotationPoint]) -> String {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let (x, y) = p.scaled();
+            format!("{}{:.1} {:.1}", if i == 0 { "M" } else { " L" }, x, y)
+        })
+        .collect()
+}
+
+/// SVG polygon points for an arrowhead at the `to` end of an arrow
+pub fn arrow_head(from: AnnotationPoint, to: AnnotationPoint) -> String {
+    const LENGTH: f32 = 24.0;
+    const SPREAD: f32 = 0.45;
+    let (fx, fy) = from.scaled();
+    let (tx, ty) = to.scaled();
+    let angle = (ty - fy).atan2(tx - fx);
+    let left = (tx - LENGTH * (angle - SPREAD).cos(), ty - LENGTH * (angle - SPREAD).sin());
+    let right = (tx - LENGTH * (angle + SPREAD).cos(), ty - LENGTH * (angle + SPREAD).sin());
+    format!("{:.1},{:.1} {:.1},{:.1} {:.1},{:.1}", tx, ty, left.0, left.1, right.0, right.1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(id: &str, visible_to_players: bool) -> AnnotationLayer {
+        AnnotationLayer { id: id.to_string(), name: id.to_string(), visible_to_players }
+    }
+
+    fn pin(id: &str, layer_id: &str) -> Annotation {
+        Annotation {
+            id: id.to_string(),
+            layer_id: layer_id.to_string(),
+            color: "#f00".to_string(),
+            shape: AnnotationShape::Pin { at: AnnotationPoint::new(0.5, 0.5), label: None },
+        }
+    }
+
+    #[test]
+    fn players_only_see_visible_layers() {
+        let surface = SurfaceAnnotations {
+            layers: vec![layer("shared", true), layer("dm-notes", false)],
+            annotations: vec![pin("a", "shared"), pin("b", "dm-notes"), pin("c", "missing")],
+        };
+        let ids = |v: Vec<&Annotation>| v.iter().map(|a| a.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(surface.visible(false)), vec!["a"]);
+        assert_eq!(ids(surface.visible(true)), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn simplify_drops_close_points_but_keeps_the_end() {
+        let points = vec![
+            AnnotationPoint::new(0.0, 0.0),
+            AnnotationPoint::new(0.001, 0.0),
+            AnnotationPoint::new(0.1, 0.0),
+            AnnotationPoint::new(0.101, 0.0),
+        ];
+        let simplified = simplify_stroke(&points, 0.01);
+        assert_eq!(simplified.len(), 3);
+        assert_eq!(simplified.last(), points.last());
+    }
+
+    #[test]
+    fn points_are_clamped_and_scaled() {
+        let p = AnnotationPoint::new(1.5, -0.2);
+        assert_eq!(p.scaled(), (1000.0, 0.0));
+        assert_eq!(stroke_path(&[AnnotationPoint::new(0.1, 0.2), p]), "M100.0 200.0 L1000.0 0.0");
+    }
+
+    #[test]
+    fn annotation_at_finds_the_closest_mark() {
+        let mut arrow = pin("arrow", "shared");
+        arrow.shape = AnnotationShape::Arrow {
+            from: AnnotationPoint::new(0.0, 0.0),
+            to: AnnotationPoint::new(1.0, 0.0),
+        };
+        let marks = [pin("pin", "shared"), arrow];
+        let refs: Vec<&Annotation> = marks.iter().collect();
+
+        let near_arrow = annotation_at(&refs, &AnnotationPoint::new(0.3, 0.02), 0.05);
+        assert_eq!(near_arrow.map(|a| a.id.as_str()), Some("arrow"));
+        let near_pin = annotation_at(&refs, &AnnotationPoint::new(0.52, 0.5), 0.05);
+        assert_eq!(near_pin.map(|a| a.id.as_str()), Some("pin"));
+        assert!(annotation_at(&refs, &AnnotationPoint::new(0.9, 0.9), 0.05).is_none());
+    }
+
+    #[test]
+    fn surfaces_are_tagged_by_kind() {
+        let backdrop = AnnotationSurface::Backdrop { region_id: "r1".to_string() };
+        let json = serde_json::to_value(&backdrop).unwrap();
+        assert_eq!(json, serde_json::json!({ "kind": "backdrop", "region_id": "r1" }));
+        assert_eq!(backdrop.key(), "backdrop:r1");
+    }
+}
@@ -0,0 +1,179 @@
+//! Camera effects - Short moves of the visual-novel stage
+//!
+//! The DM plays one from the Director's quick actions, or attaches one to a
+//! challenge outcome or narrative event so it plays when that lands. Every
+//! client moves the stage (backdrop and characters, not the dialogue box)
+//! once and comes back to rest. The keyframes live in `styles/input.css`;
+//! the styles below set their timing and distances.
+
+use serde::{Deserialize, Serialize};
+
+use super::websocket_messages::CharacterPosition;
+
+/// How hard a shake is
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShakeStrength {
+    #[default]
+    Light,
+    Heavy,
+}
+
+impl ShakeStrength {
+    pub fn all() -> [Self; 2] {
+        [Self::Light, Self::Heavy]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Light => "Light",
+            Self::Heavy => "Heavy",
+        }
+    }
+}
+
+/// Which way a pan travels across the backdrop
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PanDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+impl PanDirection {
+    pub fn all() -> [Self; 2] {
+        [Self::LeftToRight, Self::RightToLeft]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::LeftToRight => "Left to right",
+            Self::RightToLeft => "Right to left",
+        }
+    }
+}
+
+/// Default length of a zoom, in milliseconds
+pub const DEFAULT_ZOOM_MS: u32 = 3_000;
+
+/// Default length of a pan, in milliseconds
+pub const DEFAULT_PAN_MS: u32 = 5_000;
+
+/// A camera move on the stage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CameraEffect {
+    /// Screen shake for impacts
+    Shake { strength: ShakeStrength },
+    /// Slow zoom in and back out, centred on a character if one is given
+    Zoom {
+        #[serde(default)]
+        character_id: Option<String>,
+        duration_ms: u32,
+    },
+    /// Pan across the backdrop
+    Pan { direction: PanDirection, duration_ms: u32 },
+}
+
+impl CameraEffect {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Shake { .. } => "Shake",
+            Self::Zoom { .. } => "Zoom",
+            Self::Pan { .. } => "Pan",
+        }
+    }
+
+    /// One effect of each kind with default settings, for pickers
+    pub fn templates() -> Vec<Self> {
+        vec![
+            Self::Shake { strength: ShakeStrength::Light },
+            Self::Zoom { character_id: None, duration_ms: DEFAULT_ZOOM_MS },
+            Self::Pan { direction: PanDirection::LeftToRight, duration_ms: DEFAULT_PAN_MS },
+        ]
+    }
+
+    /// How long the effect runs
+    pub fn duration_ms(&self) -> u32 {
+        match self {
+            Self::Shake { strength: ShakeStrength::Light } => 400,
+            Self::Shake { strength: ShakeStrength::Heavy } => 800,
+            Self::Zoom { duration_ms, .. } | Self::Pan { duration_ms, .. } => *duration_ms,
+        }
+    }
+
+    /// Whether an effect that started at `started_at_ms` is still running
+    pub fn is_playing(&self, started_at_ms: u64, now_ms: u64) -> bool {
+        now_ms.saturating_sub(started_at_ms) < u64::from(self.duration_ms())
+    }
+
+    /// CSS for the stage while the effect runs
+    ///
+    /// `focus` is where the zoomed character stands; a zoom without one
+    /// goes to the centre.
+    pub fn stage_style(&self, focus: Option<CharacterPosition>) -> String {
+        let duration = self.duration_ms();
+        match self {
+            Self::Shake { strength } => {
+                let distance = match strength {
+                    ShakeStrength::Light => 4,
+                    ShakeStrength::Heavy => 12,
+                };
+                format!("animation: camera-shake {}ms linear; --camera-shake: {}px;", duration, distance)
+            }
+            Self::Zoom { .. } => {
+                let origin = match focus {
+                    Some(CharacterPosition::Left) => 25,
+                    Some(CharacterPosition::Right) => 75,
+                    _ => 50,
+                };
+                format!(
+                    "animation: camera-zoom {}ms ease-in-out; transform-origin: {}% 40%;",
+                    duration, origin
+                )
+            }
+            Self::Pan { direction, .. } => {
+                let (from, to) = match direction {
+                    PanDirection::LeftToRight => (7, -7),
+                    PanDirection::RightToLeft => (-7, 7),
+                };
+                format!(
+                    "animation: camera-pan {}ms ease-in-out; --camera-pan-from: {}%; --camera-pan-to: {}%;",
+                    duration, from, to
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn effects_serialize_with_a_type_tag() {
+        let zoom = CameraEffect::Zoom { character_id: Some("npc-1".to_string()), duration_ms: 2_000 };
+        let json = serde_json::to_value(&zoom).unwrap();
+        assert_eq!(json["type"], "zoom");
+        assert_eq!(serde_json::from_value::<CameraEffect>(json).unwrap(), zoom);
+
+        let shake: CameraEffect = serde_json::from_str(r#"{"type":"shake","strength":"heavy"}"#).unwrap();
+        assert_eq!(shake, CameraEffect::Shake { strength: ShakeStrength::Heavy });
+    }
+
+    #[test]
+    fn styles_follow_the_effect_settings() {
+        let zoom = CameraEffect::Zoom { character_id: None, duration_ms: 2_000 };
+        assert_eq!(
+            zoom.stage_style(Some(CharacterPosition::Right)),
+            "animation: camera-zoom 2000ms ease-in-out; transform-origin: 75% 40%;"
+        );
+        let pan = CameraEffect::Pan { direction: PanDirection::RightToLeft, duration_ms: 4_000 };
+        assert!(pan.stage_style(None).contains("--camera-pan-from: -7%"));
+
+        let shake = CameraEffect::Shake { strength: ShakeStrength::Light };
+        assert!(shake.is_playing(1_000, 1_399));
+        assert!(!shake.is_playing(1_000, 1_400));
+    }
+}
@@ -0,0 +1,208 @@
+//! Crafting - Recipes that turn inventory items into new ones
+//!
+//! The DM defines recipes: ingredient items, an optional skill check, and
+//! the item they make. A player attempts one from the inventory panel; the
+//! Engine checks the ingredients, runs the check as an ordinary challenge
+//! (prompt, roll, DM approval), then updates the inventory and reports a
+//! [`CraftOutcome`]. Items are matched by name, as challenge outcomes do
+//! when they give items.
+
+use serde::{Deserialize, Serialize};
+
+use super::world_snapshot::{ChallengeDifficulty, InventoryItemData};
+
+/// Some number of one item, by name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ItemStack {
+    pub item_name: String,
+    pub quantity: u32,
+}
+
+impl ItemStack {
+    pub fn new(item_name: impl Into<String>, quantity: u32) -> Self {
+        Self {
+            item_name: item_name.into(),
+            quantity,
+        }
+    }
+
+    /// "Iron Ingot ×2", or just the name for one
+    pub fn label(&self) -> String {
+        if self.quantity == 1 {
+            self.item_name.clone()
+        } else {
+            format!("{} ×{}", self.item_name, self.quantity)
+        }
+    }
+}
+
+impl Default for ItemStack {
+    fn default() -> Self {
+        Self::new(String::new(), 1)
+    }
+}
+
+/// A crafting recipe
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CraftingRecipe {
+    pub id: String,
+    pub world_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub ingredients: Vec<ItemStack>,
+    /// Skill rolled to craft; the recipe always works without one
+    #[serde(default)]
+    pub skill_id: Option<String>,
+    #[serde(default)]
+    pub difficulty: ChallengeDifficulty,
+    pub result: ItemStack,
+    #[serde(default)]
+    pub result_description: Option<String>,
+    /// Whether a failed check still uses up the ingredients
+    #[serde(default)]
+    pub consume_on_failure: bool,
+}
+
+impl CraftingRecipe {
+    /// Ingredients the inventory is short of, with how many are needed in all
+    pub fn missing_ingredients(&self, inventory: &[InventoryItemData]) -> Vec<ItemStack> {
+        self.ingredients
+            .iter()
+            .filter_map(|ingredient| {
+                let have = held_quantity(inventory, &ingredient.item_name);
+                (have < ingredient.quantity).then(|| ingredient.clone())
+            })
+            .collect()
+    }
+
+    pub fn can_craft(&self, inventory: &[InventoryItemData]) -> bool {
+        self.missing_ingredients(inventory).is_empty()
+    }
+
+    /// "Iron Ingot ×2 + Leather → Sword", for lists
+    pub fn formula(&self) -> String {
+        let ingredients: Vec<String> = self.ingredients.iter().map(ItemStack::label).collect();
+        format!("{} → {}", ingredients.join(" + "), self.result.label())
+    }
+}
+
+/// How many of an item (matched by name, ignoring case) the inventory holds
+pub fn held_quantity(inventory: &[InventoryItemData], item_name: &str) -> u32 {
+    inventory
+        .iter()
+        .filter(|i| i.item.name.eq_ignore_ascii_case(item_name.trim()))
+        .map(|i| i.quantity)
+        .sum()
+}
+
+/// The end of a craft attempt, sent once the Engine has updated the inventory
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CraftOutcome {
+    pub pc_id: String,
+    pub pc_name: String,
+    pub recipe_id: String,
+    pub recipe_name: String,
+    pub success: bool,
+    /// Ingredients taken from the inventory
+    #[serde(default)]
+    pub consumed: Vec<ItemStack>,
+    /// Item added to the inventory, on success
+    #[serde(default)]
+    pub produced: Option<ItemStack>,
+}
+
+impl CraftOutcome {
+    /// One line for the session log
+    pub fn summary(&self) -> String {
+        match (&self.produced, self.success) {
+            (Some(item), true) => format!("{} crafted {}", self.pc_name, item.label()),
+            _ if self.consumed.is_empty() => format!("{} failed to craft {}", self.pc_name, self.recipe_name),
+            _ => format!(
+                "{} failed to craft {}; the ingredients were lost",
+                self.pc_name, self.recipe_name
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::{EntityStyle, ItemData};
+
+    fn held(name: &str, quantity: u32) -> InventoryItemData {
+        InventoryItemData {
+            item: ItemData {
+                id: name.to_lowercase(),
+                world_id: "w1".to_string(),
+                name: name.to_string(),
+                description: None,
+                item_type: None,
+                is_unique: false,
+                properties: None,
+                tags: Vec::new(),
+                modifiers: Vec::new(),
+                style: EntityStyle::default(),
+                image_url: None,
+                weight: None,
+                value: None,
+                hidden_notes: None,
+                has_hidden_notes: false,
+                deleted_at: None,
+            },
+            quantity,
+            equipped: false,
+            slot: None,
+            acquired_at: String::new(),
+            acquisition_method: None,
+            acquired_from: None,
+            acquired_location: None,
+            identified: false,
+        }
+    }
+
+    fn sword() -> CraftingRecipe {
+        CraftingRecipe {
+            name: "Forge a Sword".to_string(),
+            ingredients: vec![ItemStack::new("Iron Ingot", 2), ItemStack::new("Leather", 1)],
+            result: ItemStack::new("Sword", 1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn ingredients_are_counted_by_name() {
+        let recipe = sword();
+        let inventory = vec![held("iron ingot", 1), held("Iron Ingot", 1), held("Leather", 3)];
+        assert!(recipe.can_craft(&inventory));
+
+        let short = vec![held("Iron Ingot", 1)];
+        assert_eq!(
+            recipe.missing_ingredients(&short),
+            vec![ItemStack::new("Iron Ingot", 2), ItemStack::new("Leather", 1)]
+        );
+        assert_eq!(recipe.formula(), "Iron Ingot ×2 + Leather → Sword");
+    }
+
+    #[test]
+    fn outcome_summary_says_what_happened() {
+        let mut outcome = CraftOutcome {
+            pc_id: "pc1".to_string(),
+            pc_name: "Aria".to_string(),
+            recipe_id: "r1".to_string(),
+            recipe_name: "Forge a Sword".to_string(),
+            success: true,
+            consumed: vec![ItemStack::new("Iron Ingot", 2)],
+            produced: Some(ItemStack::new("Sword", 1)),
+        };
+        assert_eq!(outcome.summary(), "Aria crafted Sword");
+
+        outcome.success = false;
+        outcome.produced = None;
+        assert_eq!(outcome.summary(), "Aria failed to craft Forge a Sword; the ingredients were lost");
+        outcome.consumed.clear();
+        assert_eq!(outcome.summary(), "Aria failed to craft Forge a Sword");
+    }
+}
@@ -0,0 +1,196 @@
+//! Inline markup in dialogue text
+//!
+//! Approved NPC dialogue and narration may embed interactive references:
+//!
+//! - `[roll:2d6+3]` asks the player to roll dice
+//! - `{skill:Stealth}` points at one of the player's skills
+//!
+//! Markup that doesn't parse is shown as ordinary text, so older dialogue and
+//! stray brackets are unaffected.
+
+use std::fmt;
+
+/// Most dice allowed in one roll
+const MAX_DICE: u8 = 20;
+/// Largest die allowed
+const MAX_SIDES: u8 = 100;
+
+/// Dice notation such as `2d6+3`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiceNotation {
+    pub count: u8,
+    pub sides: u8,
+    pub modifier: i32,
+}
+
+impl DiceNotation {
+    /// Parse `XdY`, `XdY+Z`, `XdY-Z` or `dY` (one die)
+    pub fn parse(s: &str) -> Option<Self> {
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_lowercase();
+        let (count, rest) = s.split_once('d')?;
+        let count = if count.is_empty() { 1 } else { count.parse().ok()? };
+
+        let split_at = rest.find(['+', '-']).unwrap_or(rest.len());
+        let (sides, modifier) = rest.split_at(split_at);
+        let sides: u8 = sides.parse().ok()?;
+        let modifier = if modifier.is_empty() {
+            0
+        } else {
+            modifier.parse().ok()?
+        };
+
+        if count == 0 || count > MAX_DICE || sides == 0 || sides > MAX_SIDES {
+            return None;
+        }
+        Some(Self { count, sides, modifier })
+    }
+
+    /// Roll using `die(min, max)` for each die
+    pub fn roll(&self, mut die: impl FnMut(i32, i32) -> i32) -> DiceRoll {
+        let rolls: Vec<i32> = (0..self.count).map(|_| die(1, i32::from(self.sides))).collect();
+        let total = rolls.iter().sum::<i32>() + self.modifier;
+        DiceRoll {
+            notation: *self,
+            rolls,
+            total,
+        }
+    }
+}
+
+impl fmt::Display for DiceNotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}d{}", self.count, self.sides)?;
+        match self.modifier {
+            0 => Ok(()),
+            m if m > 0 => write!(f, "+{}", m),
+            m => write!(f, "{}", m),
+        }
+    }
+}
+
+/// The result of rolling a [`DiceNotation`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiceRoll {
+    pub notation: DiceNotation,
+    pub rolls: Vec<i32>,
+    pub total: i32,
+}
+
+impl DiceRoll {
+    /// e.g. "2d6+3: [4, 5] + 3 = 12"
+    pub fn breakdown(&self) -> String {
+        let rolls = self
+            .rolls
+            .iter()
+            .map(|r| r.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        match self.notation.modifier {
+            0 => format!("{}: [{}] = {}", self.notation, rolls, self.total),
+            m if m > 0 => format!("{}: [{}] + {} = {}", self.notation, rolls, m, self.total),
+            m => format!("{}: [{}] - {} = {}", self.notation, rolls, -m, self.total),
+        }
+    }
+}
+
+/// A piece of dialogue text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DialogueSegment {
+    Text(String),
+    Roll(DiceNotation),
+    Skill(String),
+}
+
+/// Split dialogue text into plain text, dice rolls and skill references
+pub fn parse_dialogue(text: &str) -> Vec<DialogueSegment> {
+    let mut segments = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find(['[', '{']) {
+        plain.push_str(&rest[..start]);
+        let candidate = &rest[start..];
+        match parse_token(candidate) {
+            Some((segment, len)) => {
+                if !plain.is_empty() {
+                    segments.push(DialogueSegment::Text(std::mem::take(&mut plain)));
+                }
+                segments.push(segment);
+                rest = &candidate[len..];
+            }
+            None => {
+                plain.push_str(&candidate[..1]);
+                rest = &candidate[1..];
+            }
+        }
+    }
+    plain.push_str(rest);
+    if !plain.is_empty() {
+        segments.push(DialogueSegment::Text(plain));
+    }
+    segments
+}
+
+/// Parse a token at the start of `s`, returning it and its byte length
+fn parse_token(s: &str) -> Option<(DialogueSegment, usize)> {
+    if let Some(body) = s.strip_prefix("[roll:") {
+        let end = body.find(']')?;
+        let notation = DiceNotation::parse(&body[..end])?;
+        return Some((DialogueSegment::Roll(notation), "[roll:".len() + end + 1));
+    }
+    if let Some(body) = s.strip_prefix("{skill:") {
+        let end = body.find('}')?;
+        let name = body[..end].trim();
+        if name.is_empty() {
+            return None;
+        }
+        return Some((DialogueSegment::Skill(name.to_string()), "{skill:".len() + end + 1));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_dice_notation() {
+        assert_eq!(
+            DiceNotation::parse("2d6+3"),
+            Some(DiceNotation { count: 2, sides: 6, modifier: 3 })
+        );
+        assert_eq!(
+            DiceNotation::parse(" D20 - 1 "),
+            Some(DiceNotation { count: 1, sides: 20, modifier: -1 })
+        );
+        assert_eq!(DiceNotation::parse("0d6"), None);
+        assert_eq!(DiceNotation::parse("2d"), None);
+        assert_eq!(DiceNotation::parse("banana"), None);
+        assert_eq!(DiceNotation::parse("1d20-2").unwrap().to_string(), "1d20-2");
+    }
+
+    #[test]
+    fn splits_dialogue_into_segments() {
+        let segments = parse_dialogue("Roll [roll:2d6+3] with {skill:Stealth}, or [not] {skill:}");
+        assert_eq!(
+            segments,
+            vec![
+                DialogueSegment::Text("Roll ".to_string()),
+                DialogueSegment::Roll(DiceNotation { count: 2, sides: 6, modifier: 3 }),
+                DialogueSegment::Text(" with ".to_string()),
+                DialogueSegment::Skill("Stealth".to_string()),
+                DialogueSegment::Text(", or [not] {skill:}".to_string()),
+            ]
+        );
+        assert!(parse_dialogue("").is_empty());
+    }
+
+    #[test]
+    fn rolls_and_describes_result() {
+        let notation = DiceNotation::parse("2d6+3").unwrap();
+        let mut faces = [4, 5].into_iter();
+        let roll = notation.roll(|_, _| faces.next().unwrap());
+        assert_eq!(roll.total, 12);
+        assert_eq!(roll.breakdown(), "2d6+3: [4, 5] + 3 = 12");
+    }
+}
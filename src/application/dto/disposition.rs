@@ -0,0 +1,89 @@
+//! Disposition - How an NPC feels about the party, as players see it
+//!
+//! The Engine sends the sentiment of the NPC's relationship with the party
+//! (-1.0 to 1.0) on scene characters, and again when it changes. Players see
+//! it bucketed into five steps, never the raw number. The DM can hide it for
+//! mystery NPCs, in which case players get no sentiment at all.
+
+use serde::{Deserialize, Serialize};
+
+/// Highest number of hearts on the meter
+pub const MAX_HEARTS: u8 = 4;
+
+/// Sentiment bucketed for display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Disposition {
+    Hostile,
+    Unfriendly,
+    Neutral,
+    Friendly,
+    Allied,
+}
+
+impl Disposition {
+    /// Bucket a relationship sentiment (-1.0 to 1.0)
+    pub fn from_sentiment(sentiment: f32) -> Self {
+        match sentiment {
+            s if s < -0.5 => Self::Hostile,
+            s if s < -0.15 => Self::Unfriendly,
+            s if s <= 0.15 => Self::Neutral,
+            s if s <= 0.5 => Self::Friendly,
+            _ => Self::Allied,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Hostile => "Hostile",
+            Self::Unfriendly => "Unfriendly",
+            Self::Neutral => "Neutral",
+            Self::Friendly => "Friendly",
+            Self::Allied => "Allied",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Self::Hostile => "😠",
+            Self::Unfriendly => "😒",
+            Self::Neutral => "😐",
+            Self::Friendly => "🙂",
+            Self::Allied => "💖",
+        }
+    }
+
+    /// Filled hearts out of [`MAX_HEARTS`]
+    pub fn hearts(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Tailwind text color for the meter
+    pub fn color_class(&self) -> &'static str {
+        match self {
+            Self::Hostile => "text-red-400",
+            Self::Unfriendly => "text-orange-300",
+            Self::Neutral => "text-gray-300",
+            Self::Friendly => "text-green-300",
+            Self::Allied => "text-pink-400",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sentiment_buckets_into_hearts() {
+        assert_eq!(Disposition::from_sentiment(-0.9), Disposition::Hostile);
+        assert_eq!(Disposition::from_sentiment(-0.3), Disposition::Unfriendly);
+        assert_eq!(Disposition::from_sentiment(0.0), Disposition::Neutral);
+        assert_eq!(Disposition::from_sentiment(0.15), Disposition::Neutral);
+        assert_eq!(Disposition::from_sentiment(0.4), Disposition::Friendly);
+        assert_eq!(Disposition::from_sentiment(1.0), Disposition::Allied);
+
+        assert_eq!(Disposition::Hostile.hearts(), 0);
+        assert_eq!(Disposition::Allied.hearts(), MAX_HEARTS);
+    }
+}
@@ -0,0 +1,109 @@
+//! Entity Style - The icon and accent colour a DM gives an entity
+//!
+//! Characters, locations and items can carry an emoji or short symbol and a
+//! hex accent colour, so they stand out in lists, the scene, the timeline
+//! and graphs. Both are optional; entities without them render as before.
+//! Event chains already had a colour of their own and add only the icon.
+
+use serde::{Deserialize, Serialize};
+
+/// Longest icon accepted, in characters; enough for joined emoji such as 🧙‍♀️
+pub const MAX_ICON_CHARS: usize = 8;
+
+/// Icons offered in the picker; any other short text can be typed in
+pub const ICON_PRESETS: &[&str] = &[
+    "🗡️", "🛡️", "🏹", "🧙", "👑", "💀", "🐉", "🕯️", "🏰", "🌲", "⚓", "🍺", "💎", "📜", "🔮", "⭐",
+];
+
+/// Accent colours offered in the picker
+pub const ACCENT_PRESETS: &[&str] = &[
+    "#ef4444", "#f97316", "#eab308", "#22c55e", "#14b8a6", "#3b82f6", "#8b5cf6", "#ec4899",
+];
+
+/// Icon and accent colour of an entity
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityStyle {
+    /// Emoji or short symbol shown before the name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    /// Hex colour such as "#3b82f6"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent_color: Option<String>,
+}
+
+impl EntityStyle {
+    /// Style from form input, keeping only a usable icon and colour
+    pub fn from_input(icon: &str, accent_color: &str) -> Self {
+        Self {
+            icon: clean_icon(icon),
+            accent_color: parse_accent_color(accent_color),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.icon.is_none() && self.accent_color.is_none()
+    }
+}
+
+/// Trimmed icon, or None if blank or too long
+pub fn clean_icon(icon: &str) -> Option<String> {
+    let icon = icon.trim();
+    if icon.is_empty() || icon.chars().count() > MAX_ICON_CHARS {
+        return None;
+    }
+    Some(icon.to_string())
+}
+
+/// Lowercased "#rrggbb" from "#rgb" or "#rrggbb", or None if not a hex colour
+pub fn parse_accent_color(color: &str) -> Option<String> {
+    let hex = color.trim().strip_prefix('#')?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let hex = hex.to_ascii_lowercase();
+    match hex.len() {
+        6 => Some(format!("#{}", hex)),
+        3 => Some(hex.chars().fold(String::from("#"), |mut out, c| {
+            out.push(c);
+            out.push(c);
+            out
+        })),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_is_cleaned_or_dropped() {
+        assert_eq!(parse_accent_color(" #3B82F6 "), Some("#3b82f6".to_string()));
+        assert_eq!(parse_accent_color("#f0a"), Some("#ff00aa".to_string()));
+        assert_eq!(parse_accent_color("3b82f6"), None);
+        assert_eq!(parse_accent_color("#12345"), None);
+        assert_eq!(parse_accent_color("#gggggg"), None);
+
+        assert_eq!(clean_icon(" 🧙‍♀️ "), Some("🧙‍♀️".to_string()));
+        assert_eq!(clean_icon("   "), None);
+        assert_eq!(clean_icon("a very long label"), None);
+
+        assert!(EntityStyle::from_input("", "red").is_empty());
+    }
+
+    #[test]
+    fn style_sits_alongside_the_entity_fields() {
+        #[derive(Deserialize, Serialize)]
+        struct Entity {
+            name: String,
+            #[serde(flatten)]
+            style: EntityStyle,
+        }
+
+        let entity: Entity = serde_json::from_str(r##"{"name":"Mira","icon":"🕯️","accent_color":"#eab308"}"##).unwrap();
+        assert_eq!(entity.style.icon.as_deref(), Some("🕯️"));
+        let plain: Entity = serde_json::from_str(r#"{"name":"Tobin"}"#).unwrap();
+        assert!(plain.style.is_empty());
+        assert_eq!(serde_json::to_string(&plain).unwrap(), r#"{"name":"Tobin"}"#);
+    }
+}
@@ -0,0 +1,159 @@
+//! Factions - Groups characters belong to, and where they stand
+//!
+//! A faction has goals, member characters and a standing toward each other
+//! faction it has an opinion of. The party's standing with each faction is
+//! tracked the same way and is what the DM adjusts during a session.
+//! Standings run from -100 (sworn enemies) to 100 (close allies).
+
+use serde::{Deserialize, Serialize};
+
+/// Lowest standing a faction can have
+pub const MIN_STANDING: i32 = -100;
+
+/// Highest standing a faction can have
+pub const MAX_STANDING: i32 = 100;
+
+/// A faction's standing toward another faction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FactionStanding {
+    pub faction_id: String,
+    pub standing: i32,
+}
+
+/// A faction in a world
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Faction {
+    pub id: String,
+    pub world_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// What the faction is working toward, most important first
+    #[serde(default)]
+    pub goals: Vec<String>,
+    /// IDs of member characters
+    #[serde(default)]
+    pub member_ids: Vec<String>,
+    /// Standings toward other factions; unlisted factions are neutral
+    #[serde(default)]
+    pub standings: Vec<FactionStanding>,
+    /// How the faction regards the party
+    #[serde(default)]
+    pub party_standing: i32,
+}
+
+impl Faction {
+    pub fn has_member(&self, character_id: &str) -> bool {
+        self.member_ids.iter().any(|id| id == character_id)
+    }
+
+    /// Standing toward another faction, neutral if none is set
+    pub fn standing_toward(&self, faction_id: &str) -> i32 {
+        self.standings
+            .iter()
+            .find(|s| s.faction_id == faction_id)
+            .map_or(0, |s| s.standing)
+    }
+
+    /// Set the standing toward another faction; neutral removes the entry
+    pub fn set_standing_toward(&mut self, faction_id: &str, standing: i32) {
+        let standing = clamp_standing(standing);
+        self.standings.retain(|s| s.faction_id != faction_id);
+        if standing != 0 {
+            self.standings.push(FactionStanding {
+                faction_id: faction_id.to_string(),
+                standing,
+            });
+        }
+    }
+}
+
+/// Keep a standing within [`MIN_STANDING`]..=[`MAX_STANDING`]
+pub fn clamp_standing(standing: i32) -> i32 {
+    standing.clamp(MIN_STANDING, MAX_STANDING)
+}
+
+/// Factions a character belongs to
+pub fn factions_of<'a>(character_id: &str, factions: &'a [Faction]) -> Vec<&'a Faction> {
+    factions.iter().filter(|f| f.has_member(character_id)).collect()
+}
+
+/// Named bands of standing, for labels and colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StandingLevel {
+    Hostile,
+    Unfriendly,
+    Neutral,
+    Friendly,
+    Allied,
+}
+
+impl StandingLevel {
+    pub fn from_standing(standing: i32) -> Self {
+        match standing {
+            i32::MIN..=-60 => Self::Hostile,
+            -59..=-20 => Self::Unfriendly,
+            -19..=19 => Self::Neutral,
+            20..=59 => Self::Friendly,
+            _ => Self::Allied,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Hostile => "Hostile",
+            Self::Unfriendly => "Unfriendly",
+            Self::Neutral => "Neutral",
+            Self::Friendly => "Friendly",
+            Self::Allied => "Allied",
+        }
+    }
+
+    /// Tailwind text color for the band
+    pub fn text_class(&self) -> &'static str {
+        match self {
+            Self::Hostile => "text-red-500",
+            Self::Unfriendly => "text-orange-400",
+            Self::Neutral => "text-gray-400",
+            Self::Friendly => "text-green-400",
+            Self::Allied => "text-emerald-300",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn faction(id: &str, members: &[&str]) -> Faction {
+        Faction {
+            id: id.to_string(),
+            name: id.to_string(),
+            member_ids: members.iter().map(|m| m.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn standings_default_to_neutral_and_stay_in_range() {
+        let mut guild = faction("guild", &[]);
+        assert_eq!(guild.standing_toward("watch"), 0);
+
+        guild.set_standing_toward("watch", -250);
+        assert_eq!(guild.standing_toward("watch"), MIN_STANDING);
+        assert_eq!(StandingLevel::from_standing(guild.standing_toward("watch")), StandingLevel::Hostile);
+
+        guild.set_standing_toward("watch", 0);
+        assert!(guild.standings.is_empty());
+        assert_eq!(StandingLevel::from_standing(19), StandingLevel::Neutral);
+        assert_eq!(StandingLevel::from_standing(20), StandingLevel::Friendly);
+    }
+
+    #[test]
+    fn characters_can_belong_to_several_factions() {
+        let factions = vec![faction("guild", &["mira"]), faction("watch", &["mira", "tobin"]), faction("cult", &[])];
+        let names: Vec<&str> = factions_of("mira", &factions).iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["guild", "watch"]);
+        assert!(factions_of("nobody", &factions).is_empty());
+    }
+}
@@ -0,0 +1,94 @@
+//! Fog of war - which regions each player has uncovered on the map
+//!
+//! Regions start hidden. A region is revealed for a PC when that PC visits
+//! it, and the DM can reveal or re-hide regions for one PC or the whole
+//! party. The Engine saves the reveals with the session and sends the whole
+//! state on join and after every change.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Who a reveal applies to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RevealScope {
+    /// Everyone at the table
+    Party,
+    /// One player character
+    Player { pc_id: String },
+}
+
+/// Revealed regions for the session
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MapReveals {
+    /// Regions every PC can see
+    #[serde(default)]
+    pub party: Vec<String>,
+    /// Regions only one PC can see, keyed by PC id; visits land here
+    #[serde(default)]
+    pub per_pc: HashMap<String, Vec<String>>,
+}
+
+impl MapReveals {
+    /// Whether `pc_id` (or, without one, the whole party) can see a region
+    pub fn is_revealed(&self, region_id: &str, pc_id: Option<&str>) -> bool {
+        self.party.iter().any(|id| id == region_id)
+            || pc_id
+                .and_then(|pc| self.per_pc.get(pc))
+                .is_some_and(|regions| regions.iter().any(|id| id == region_id))
+    }
+
+    /// Every region `pc_id` can see, party-wide reveals included
+    pub fn revealed_for(&self, pc_id: Option<&str>) -> Vec<String> {
+        let mut regions = self.party.clone();
+        if let Some(own) = pc_id.and_then(|pc| self.per_pc.get(pc)) {
+            for id in own {
+                if !regions.contains(id) {
+                    regions.push(id.clone());
+                }
+            }
+        }
+        regions
+    }
+
+    /// Reveal regions for `scope`
+    pub fn reveal(&mut self, region_ids: &[String], scope: &RevealScope) {
+        let regions = self.regions_mut(scope);
+        for id in region_ids {
+            if !regions.contains(id) {
+                regions.push(id.clone());
+            }
+        }
+    }
+
+    fn regions_mut(&mut self, scope: &RevealScope) -> &mut Vec<String> {
+        match scope {
+            RevealScope::Party => &mut self.party,
+            RevealScope::Player { pc_id } => self.per_pc.entry(pc_id.clone()).or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn visits_reveal_for_one_pc_and_party_reveals_for_all() {
+        let mut reveals = MapReveals::default();
+        let alice = RevealScope::Player { pc_id: "alice".to_string() };
+        reveals.reveal(&ids(&["tavern"]), &alice);
+        reveals.reveal(&ids(&["square"]), &RevealScope::Party);
+
+        assert!(reveals.is_revealed("tavern", Some("alice")));
+        assert!(!reveals.is_revealed("tavern", Some("bob")));
+        assert!(reveals.is_revealed("square", Some("bob")));
+        assert!(reveals.is_revealed("square", None));
+        assert_eq!(reveals.revealed_for(Some("alice")), ids(&["square", "tavern"]));
+    }
+}
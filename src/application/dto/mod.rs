@@ -6,7 +6,24 @@
 //!
 //! TODO (Phase 16.3): replace infra re-exports with real application DTOs + conversions.
 
+pub mod advancement;
+pub mod annotation;
+pub mod camera_effect;
+pub mod crafting;
+pub mod dialogue_markup;
+pub mod entity_style;
+pub mod faction;
+pub mod disposition;
+pub mod fog_of_war;
+pub mod npc_memory;
+pub mod protocol;
+pub mod recap;
+pub mod region_population;
+pub mod rich_text;
+pub mod routine;
+pub mod scene_mood;
 pub mod session_dto;
+pub mod shared_note;
 pub mod websocket_messages;
 pub mod world_snapshot;
 pub mod settings;
@@ -21,7 +38,7 @@ pub use websocket_messages::*;
 pub use world_snapshot::{
     // Rule system types
     RuleSystemConfig, RuleSystemPresetDetails, RuleSystemType, RuleSystemVariant,
-    StatDefinition, DiceSystem, SuccessComparison,
+    StatDefinition, DiceSystem, SuccessComparison, EquipmentSlotDefinition,
     // Skill types
     SkillData, SkillCategory,
     // Character sheet types
@@ -29,17 +46,83 @@ pub use world_snapshot::{
     FieldType, FieldValue,
     // Challenge types
     ChallengeData, ChallengeType, ChallengeDifficulty,
-    ChallengeOutcomes, Outcome,
+    AudioCue, AudioCueKind, ChallengeOutcomes, Outcome, OutcomeTrigger, TriggerCondition, TriggerType,
+    trigger_condition_errors,
     // Story arc types
-    StoryEventData, StoryEventTypeData,
+    StoryEventData, StoryEventSourceData, StoryEventTypeData,
     NarrativeEventData, CreateNarrativeEventRequest,
     // Session snapshot types (simplified format from Engine)
     SessionWorldSnapshot,
     // Inventory types (Phase 23B)
     ItemData, InventoryItemData,
+    // Relationship types
+    RelationshipData, RelationshipKind,
 };
 
+// Re-export rich text description types
+pub use rich_text::{
+    active_mention_query, complete_mention, description_plain_text, matching_mentions, EntityMention,
+    MentionKind, RichBlock, RichSpan, RichText,
+};
+
+// Re-export advancement rules
+pub use advancement::{validate_level_up, LevelUpAllowance};
+
+// Re-export annotation types
+pub use annotation::{
+    annotation_at, arrow_head, simplify_stroke, stroke_path, Annotation, AnnotationLayer, AnnotationPoint,
+    AnnotationShape, AnnotationSurface, SurfaceAnnotations, VIEWBOX_SIZE,
+};
+
+// Re-export camera effect types
+pub use camera_effect::{CameraEffect, PanDirection, ShakeStrength, DEFAULT_PAN_MS, DEFAULT_ZOOM_MS};
+
+// Re-export crafting types
+pub use crafting::{held_quantity, CraftOutcome, CraftingRecipe, ItemStack};
+
+// Re-export session recap types
+pub use recap::{RecapCard, SessionRecap, DEFAULT_RECAP_TITLE};
+
+// Re-export entity icon/colour types
+pub use entity_style::{EntityStyle, ACCENT_PRESETS, ICON_PRESETS, MAX_ICON_CHARS};
+
+// Re-export faction types
+pub use faction::{factions_of, Faction, FactionStanding, StandingLevel, MAX_STANDING, MIN_STANDING};
+
+// Re-export disposition types
+pub use disposition::{Disposition, MAX_HEARTS};
+
+// Re-export scene mood types
+pub use scene_mood::{SceneMood, Weather, MAX_MOOD_LEVEL};
+
+// Re-export region population types
+pub use region_population::{CrowdDensity, CrowdFigure, RegionPopulation};
+
+// Re-export protocol versioning and schema checks
+pub use protocol::{
+    decode_server_message, schema_issues, ProtocolCompatibility, SchemaIssue,
+    PROTOCOL_VERSION,
+};
+
+// Re-export fog of war types
+pub use fog_of_war::{MapReveals, RevealScope};
+
+// Re-export NPC memory types
+pub use npc_memory::{filter_memories, MemoryDraft, MemoryKind, NpcMemory, MAX_MEMORY_IMPORTANCE};
+
+// Re-export shared note types
+pub use shared_note::{NoteCursor, NoteRevision, SharedNoteDoc};
+
+// Re-export NPC routine types
+pub use routine::{routine_entry_for, set_routine_entry, DayPeriod, RoutineEntry};
+
+// Re-export dialogue markup types
+pub use dialogue_markup::{parse_dialogue, DiceNotation, DiceRoll, DialogueSegment};
+
 // Re-export settings DTOs
-pub use settings::{AppSettings, ContextBudgetConfig, SettingsFieldMetadata, SettingsMetadataResponse};
+pub use settings::{
+    AppSettings, ChallengeAutoResolveRules, ContextBudgetConfig, DirectorialPreset, SettingsFieldMetadata,
+    SettingsMetadataResponse, StoryCaptureRules,
+};
 
 // NOTE: Infrastructure asset loader now depends inward on these DTOs.
@@ -0,0 +1,196 @@
+//! NPC memory - what an NPC remembers about PCs and events
+//!
+//! The Engine keeps a memory list per NPC and feeds the relevant entries to
+//! the LLM as context when the NPC speaks. The DM can read the list, edit or
+//! delete entries, and inject new ones; every change comes back as the NPC's
+//! full list.
+
+use serde::{Deserialize, Serialize};
+
+/// Highest memory importance
+pub const MAX_MEMORY_IMPORTANCE: u8 = 5;
+
+/// Longest memory the Engine accepts, in characters
+pub const MAX_MEMORY_LENGTH: usize = 500;
+
+/// What a memory is about
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemoryKind {
+    /// An impression of a character, usually a PC
+    Impression,
+    /// Something the NPC saw or took part in
+    #[default]
+    Event,
+    /// Something the NPC was told or knows
+    Fact,
+}
+
+impl MemoryKind {
+    pub fn all() -> [Self; 3] {
+        [Self::Impression, Self::Event, Self::Fact]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Impression => "Impression",
+            Self::Event => "Event",
+            Self::Fact => "Fact",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Self::Impression => "💭",
+            Self::Event => "📜",
+            Self::Fact => "📌",
+        }
+    }
+
+    /// Value used in form selects
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Impression => "impression",
+            Self::Event => "event",
+            Self::Fact => "fact",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::all().into_iter().find(|k| k.as_str() == value)
+    }
+}
+
+/// A memory as the Engine stores it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NpcMemory {
+    pub id: String,
+    pub content: String,
+    #[serde(default)]
+    pub kind: MemoryKind,
+    /// Character the memory is about, if any
+    #[serde(default)]
+    pub subject_id: Option<String>,
+    #[serde(default)]
+    pub subject_name: Option<String>,
+    /// 1 (trivia) to [`MAX_MEMORY_IMPORTANCE`] (never forgotten); the Engine
+    /// prefers important memories when the context budget is tight
+    pub importance: u8,
+    /// Written by the DM rather than recorded from play
+    #[serde(default)]
+    pub injected: bool,
+    /// In-game time the memory was formed, as shown by the world clock
+    #[serde(default)]
+    pub game_time: Option<String>,
+}
+
+/// A memory the DM is adding or rewriting
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryDraft {
+    pub content: String,
+    pub kind: MemoryKind,
+    pub subject_id: Option<String>,
+    pub importance: u8,
+}
+
+impl MemoryDraft {
+    /// Start a draft from an existing memory
+    pub fn from_memory(memory: &NpcMemory) -> Self {
+        Self {
+            content: memory.content.clone(),
+            kind: memory.kind,
+            subject_id: memory.subject_id.clone(),
+            importance: memory.importance,
+        }
+    }
+
+    /// Check the draft before it is sent
+    pub fn validate(&self) -> Result<(), String> {
+        let length = self.content.trim().chars().count();
+        if length == 0 {
+            return Err("Write what the NPC remembers".to_string());
+        }
+        if length > MAX_MEMORY_LENGTH {
+            return Err(format!("Keep memories under {} characters", MAX_MEMORY_LENGTH));
+        }
+        if !(1..=MAX_MEMORY_IMPORTANCE).contains(&self.importance) {
+            return Err(format!("Importance must be 1 to {}", MAX_MEMORY_IMPORTANCE));
+        }
+        Ok(())
+    }
+}
+
+/// Memories matching `query` and `subject_id`, most important first
+///
+/// The query matches content and subject names, case-insensitively. The
+/// Engine's order is kept among memories of equal importance.
+pub fn filter_memories<'a>(memories: &'a [NpcMemory], query: &str, subject_id: Option<&str>) -> Vec<&'a NpcMemory> {
+    let query = query.trim().to_lowercase();
+    let mut matching: Vec<&NpcMemory> = memories
+        .iter()
+        .filter(|m| subject_id.is_none_or(|s| m.subject_id.as_deref() == Some(s)))
+        .filter(|m| {
+            query.is_empty()
+                || m.content.to_lowercase().contains(&query)
+                || m.subject_name.as_ref().is_some_and(|n| n.to_lowercase().contains(&query))
+        })
+        .collect();
+    matching.sort_by_key(|m| std::cmp::Reverse(m.importance));
+    matching
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory(id: &str, content: &str, subject: Option<&str>, importance: u8) -> NpcMemory {
+        NpcMemory {
+            id: id.to_string(),
+            content: content.to_string(),
+            kind: MemoryKind::Event,
+            subject_id: subject.map(str::to_string),
+            subject_name: subject.map(|s| format!("{} name", s)),
+            importance,
+            injected: false,
+            game_time: None,
+        }
+    }
+
+    #[test]
+    fn filter_keeps_matching_memories_most_important_first() {
+        let memories = vec![
+            memory("m1", "Paid for the ale", Some("pc1"), 2),
+            memory("m2", "Broke the door", Some("pc2"), 4),
+            memory("m3", "Asked about the ale", Some("pc1"), 4),
+        ];
+        let ids: Vec<&str> = filter_memories(&memories, "", None).iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["m2", "m3", "m1"]);
+
+        let ids: Vec<&str> = filter_memories(&memories, "ALE", Some("pc1")).iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["m3", "m1"]);
+
+        assert_eq!(filter_memories(&memories, "pc2 name", None).len(), 1);
+    }
+
+    #[test]
+    fn drafts_need_content_and_an_importance_in_range() {
+        let draft = MemoryDraft {
+            content: "Owes the party a favour".to_string(),
+            kind: MemoryKind::Fact,
+            subject_id: None,
+            importance: 3,
+        };
+        assert!(draft.validate().is_ok());
+        assert!(MemoryDraft { content: "  ".to_string(), ..draft.clone() }.validate().is_err());
+        assert!(MemoryDraft { importance: 0, ..draft.clone() }.validate().is_err());
+        assert!(MemoryDraft { content: "x".repeat(MAX_MEMORY_LENGTH + 1), ..draft }.validate().is_err());
+    }
+
+    #[test]
+    fn memories_decode_with_defaults() {
+        let memory: NpcMemory =
+            serde_json::from_str(r#"{"id":"m1","content":"Saw the fire","importance":3}"#).expect("memory");
+        assert_eq!(memory.kind, MemoryKind::Event);
+        assert!(!memory.injected);
+    }
+}
@@ -0,0 +1,255 @@
+//! Protocol versioning and schema checks for Engine messages
+//!
+//! Incoming messages are decoded with [`decode_server_message`], which keeps
+//! what serde alone would lose: a message type this client doesn't know, or
+//! fields it would ignore, come back as [`SchemaIssue`]s. The client logs
+//! them into the process-wide [`SchemaIssueLog`], which the diagnostics
+//! overlay reads. The protocol version is exchanged on join; see
+//! [`ProtocolCompatibility`].
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+
+use serde_json::Value;
+
+use super::websocket_messages::ServerMessage;
+
+/// Protocol version this client speaks, sent with `JoinSession`
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Oldest Engine protocol version this client still understands
+pub const MIN_ENGINE_PROTOCOL_VERSION: u32 = 1;
+
+/// How many schema issues the global log keeps
+pub const SCHEMA_ISSUE_CAPACITY: usize = 100;
+
+/// How the Engine's protocol version compares with this client's
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProtocolCompatibility {
+    /// Not known yet, or the Engine predates version reporting
+    #[default]
+    Unknown,
+    Match,
+    /// The Engine may send messages this client can't show
+    EngineNewer(u32),
+    /// The Engine may not understand some of this client's messages
+    EngineOlder(u32),
+}
+
+impl ProtocolCompatibility {
+    /// Compare the version the Engine reported on join
+    pub fn check(engine_version: Option<u32>) -> Self {
+        match engine_version {
+            None => Self::Unknown,
+            Some(v) if v > PROTOCOL_VERSION => Self::EngineNewer(v),
+            Some(v) if v < MIN_ENGINE_PROTOCOL_VERSION => Self::EngineOlder(v),
+            Some(_) => Self::Match,
+        }
+    }
+
+    /// Warning to show the user, if the versions don't line up
+    pub fn warning(&self) -> Option<String> {
+        match self {
+            Self::EngineNewer(v) => Some(format!(
+                "The Engine speaks a newer protocol (v{}) than this client (v{}). Update the Player; some updates may not show.",
+                v, PROTOCOL_VERSION
+            )),
+            Self::EngineOlder(v) => Some(format!(
+                "The Engine speaks an older protocol (v{}) than this client supports (v{}+). Update the Engine; some actions may fail.",
+                v, MIN_ENGINE_PROTOCOL_VERSION
+            )),
+            Self::Unknown | Self::Match => None,
+        }
+    }
+}
+
+/// Something in an Engine message this client couldn't fully use
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaIssue {
+    /// A message type this client doesn't know; the message was skipped
+    UnknownType { message_type: String },
+    /// Top-level fields the client ignored; the rest of the message was used
+    UnknownFields { message_type: String, fields: Vec<String> },
+    /// The message didn't match its type's schema and was skipped
+    Invalid { message_type: String, error: String },
+}
+
+impl SchemaIssue {
+    /// One-line description for logs and the diagnostics overlay
+    pub fn summary(&self) -> String {
+        match self {
+            Self::UnknownType { message_type } => format!("Unknown message type {}", message_type),
+            Self::UnknownFields { message_type, fields } => {
+                format!("{}: unknown field(s) {}", message_type, fields.join(", "))
+            }
+            Self::Invalid { message_type, error } => format!("{}: {}", message_type, error),
+        }
+    }
+}
+
+/// A decoded message, or `None` when it had to be skipped, with its issues
+#[derive(Debug, Clone)]
+pub struct DecodedMessage {
+    pub message: Option<ServerMessage>,
+    pub issues: Vec<SchemaIssue>,
+}
+
+/// Decode an Engine message, reporting anything the client would ignore
+pub fn decode_server_message(text: &str) -> DecodedMessage {
+    let raw: Value = match serde_json::from_str(text) {
+        Ok(raw) => raw,
+        Err(e) => return skipped(SchemaIssue::Invalid { message_type: "?".to_string(), error: e.to_string() }),
+    };
+    let message_type = raw.get("type").and_then(Value::as_str).unwrap_or("?").to_string();
+
+    let message = match serde_json::from_value::<ServerMessage>(raw.clone()) {
+        Ok(ServerMessage::Unknown) => return skipped(SchemaIssue::UnknownType { message_type }),
+        Ok(message) => message,
+        Err(e) => return skipped(SchemaIssue::Invalid { message_type, error: e.to_string() }),
+    };
+
+    let mut issues = Vec::new();
+    let fields = unknown_fields(&raw, &message);
+    if !fields.is_empty() {
+        issues.push(SchemaIssue::UnknownFields { message_type, fields });
+    }
+    DecodedMessage { message: Some(message), issues }
+}
+
+fn skipped(issue: SchemaIssue) -> DecodedMessage {
+    DecodedMessage { message: None, issues: vec![issue] }
+}
+
+/// Top-level fields of `raw` that didn't survive decoding
+///
+/// Fields that serialize away when empty would look unknown when the Engine
+/// sends them empty, so null, false, and empty values are not reported.
+fn unknown_fields(raw: &Value, message: &ServerMessage) -> Vec<String> {
+    let (Some(raw), Ok(Value::Object(known))) = (raw.as_object(), serde_json::to_value(message)) else {
+        return Vec::new();
+    };
+    raw.iter()
+        .filter(|(key, value)| !known.contains_key(*key) && !is_empty_value(value))
+        .map(|(key, _)| key.clone())
+        .collect()
+}
+
+fn is_empty_value(value: &Value) -> bool {
+    match value {
+        Value::Null | Value::Bool(false) => true,
+        Value::String(s) => s.is_empty(),
+        Value::Array(a) => a.is_empty(),
+        Value::Object(o) => o.is_empty(),
+        _ => false,
+    }
+}
+
+/// Recent schema issues, with how often each was seen
+pub struct SchemaIssueLog {
+    entries: Mutex<VecDeque<(SchemaIssue, u32)>>,
+}
+
+impl SchemaIssueLog {
+    pub fn new() -> Self {
+        Self { entries: Mutex::new(VecDeque::new()) }
+    }
+
+    /// Record an issue; a repeat only bumps its count
+    pub fn record(&self, issue: SchemaIssue) {
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+        if let Some(entry) = entries.iter_mut().find(|(known, _)| *known == issue) {
+            entry.1 += 1;
+            return;
+        }
+        if entries.len() == SCHEMA_ISSUE_CAPACITY {
+            entries.pop_front();
+        }
+        entries.push_back((issue, 1));
+    }
+
+    /// Copy of the issues, oldest first
+    pub fn snapshot(&self) -> Vec<(SchemaIssue, u32)> {
+        self.entries
+            .lock()
+            .map(|entries| entries.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
+
+impl Default for SchemaIssueLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The process-wide log the WebSocket client records into
+pub fn schema_issues() -> &'static SchemaIssueLog {
+    static LOG: OnceLock<SchemaIssueLog> = OnceLock::new();
+    LOG.get_or_init(SchemaIssueLog::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_message_decodes_without_issues() {
+        let decoded = decode_server_message(r#"{"type":"PlayerLeft","user_id":"u1"}"#);
+        assert!(matches!(decoded.message, Some(ServerMessage::PlayerLeft { .. })));
+        assert!(decoded.issues.is_empty());
+    }
+
+    #[test]
+    fn unknown_fields_are_reported_but_the_message_is_kept() {
+        let decoded = decode_server_message(r#"{"type":"PlayerLeft","user_id":"u1","reason":"kicked","extra":null}"#);
+        assert!(decoded.message.is_some());
+        assert_eq!(
+            decoded.issues,
+            vec![SchemaIssue::UnknownFields {
+                message_type: "PlayerLeft".to_string(),
+                fields: vec!["reason".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn unknown_and_invalid_messages_are_skipped() {
+        let unknown = decode_server_message(r#"{"type":"HologramSummoned","id":"h1"}"#);
+        assert!(unknown.message.is_none());
+        assert_eq!(
+            unknown.issues,
+            vec![SchemaIssue::UnknownType { message_type: "HologramSummoned".to_string() }]
+        );
+
+        let invalid = decode_server_message(r#"{"type":"PlayerLeft"}"#);
+        assert!(invalid.message.is_none());
+        assert!(matches!(&invalid.issues[..], [SchemaIssue::Invalid { message_type, .. }] if message_type == "PlayerLeft"));
+    }
+
+    #[test]
+    fn compatibility_compares_against_the_supported_range() {
+        assert_eq!(ProtocolCompatibility::check(None), ProtocolCompatibility::Unknown);
+        assert_eq!(ProtocolCompatibility::check(Some(PROTOCOL_VERSION)), ProtocolCompatibility::Match);
+        let newer = ProtocolCompatibility::check(Some(PROTOCOL_VERSION + 1));
+        assert_eq!(newer, ProtocolCompatibility::EngineNewer(PROTOCOL_VERSION + 1));
+        assert!(newer.warning().is_some());
+        assert!(ProtocolCompatibility::Match.warning().is_none());
+    }
+
+    #[test]
+    fn repeated_issues_are_counted_once() {
+        let log = SchemaIssueLog::new();
+        let issue = SchemaIssue::UnknownType { message_type: "X".to_string() };
+        log.record(issue.clone());
+        log.record(issue.clone());
+        assert_eq!(log.snapshot(), vec![(issue, 2)]);
+    }
+}
@@ -0,0 +1,136 @@
+//! Session recaps - "Previously on…" played back on the players' stage
+//!
+//! The DM picks timeline events and words a line of narration for each;
+//! players see them one after another over the backdrop of where each
+//! happened.
+
+use serde::{Deserialize, Serialize};
+
+use super::world_snapshot::{SessionWorldSnapshot, StoryEventData};
+
+/// Title a new recap starts with
+pub const DEFAULT_RECAP_TITLE: &str = "Previously on…";
+
+/// A recap as the DM composed it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionRecap {
+    /// Distinguishes one playback from the next
+    pub id: String,
+    pub title: String,
+    pub cards: Vec<RecapCard>,
+}
+
+/// One narrated moment of a recap
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecapCard {
+    pub event_id: String,
+    pub text: String,
+    /// Backdrop of the event's scene or location
+    #[serde(default)]
+    pub backdrop_url: Option<String>,
+    /// Where (and when, in game time) it happened
+    #[serde(default)]
+    pub caption: Option<String>,
+}
+
+impl RecapCard {
+    /// Card for a timeline event, staged where the event happened
+    pub fn from_event(event: &StoryEventData, text: String, world: Option<&SessionWorldSnapshot>) -> Self {
+        let scene = world.and_then(|w| {
+            event.scene_id.as_ref().and_then(|id| w.scenes.iter().find(|s| &s.id == id))
+        });
+        let location_id = event.location_id.as_ref().or(scene.map(|s| &s.location_id));
+        let location = world.zip(location_id).and_then(|(w, id)| w.get_location(id));
+
+        let backdrop_url = scene
+            .and_then(|s| s.backdrop_override.clone())
+            .or_else(|| location.and_then(|l| l.backdrop_asset.clone()));
+        let caption = match (location.map(|l| l.name.as_str()), event.game_time.as_deref()) {
+            (Some(place), Some(time)) => Some(format!("{} · {}", place, time)),
+            (Some(place), None) => Some(place.to_string()),
+            (None, Some(time)) => Some(time.to_string()),
+            (None, None) => None,
+        };
+
+        Self {
+            event_id: event.id.clone(),
+            text,
+            backdrop_url,
+            caption,
+        }
+    }
+
+    /// How long the card stays up: long enough to read it aloud
+    pub fn duration_ms(&self) -> u64 {
+        let words = self.text.split_whitespace().count() as u64;
+        (2_000 + words * 350).clamp(4_000, 15_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::world_snapshot::{SessionLocationData, SessionWorldData};
+    use crate::application::dto::EntityStyle;
+
+    fn event(location_id: Option<&str>, game_time: Option<&str>) -> StoryEventData {
+        serde_json::from_value(serde_json::json!({
+            "id": "e-1", "world_id": "w-1", "session_id": "s-1", "scene_id": null,
+            "location_id": location_id, "event_type": { "type": "custom", "event_subtype": "note", "title": "Note", "description": "" },
+            "timestamp": "2026-01-01T00:00:00Z", "game_time": game_time, "summary": "The bridge fell",
+            "involved_characters": [], "is_hidden": false, "tags": [], "triggered_by": null
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn card_is_staged_at_the_event_location() {
+        let world = SessionWorldSnapshot {
+            world: SessionWorldData {
+                id: "w-1".to_string(),
+                name: "Thornwood".to_string(),
+                description: String::new(),
+                rule_system: Default::default(),
+                created_at: String::new(),
+                updated_at: String::new(),
+            },
+            locations: vec![SessionLocationData {
+                id: "l-1".to_string(),
+                name: "Old Bridge".to_string(),
+                description: String::new(),
+                location_type: "Exterior".to_string(),
+                backdrop_asset: Some("/assets/bridge.png".to_string()),
+                animated_backdrop_asset: None,
+                parent_id: None,
+                style: EntityStyle::default(),
+                deleted_at: None,
+            }],
+            characters: Vec::new(),
+            scenes: Vec::new(),
+            current_scene: None,
+            items: Vec::new(),
+            challenges: Vec::new(),
+            factions: Vec::new(),
+        };
+
+        let card = RecapCard::from_event(&event(Some("l-1"), Some("Day 3")), "It fell.".to_string(), Some(&world));
+        assert_eq!(card.backdrop_url.as_deref(), Some("/assets/bridge.png"));
+        assert_eq!(card.caption.as_deref(), Some("Old Bridge · Day 3"));
+
+        let unstaged = RecapCard::from_event(&event(None, None), "It fell.".to_string(), None);
+        assert!(unstaged.backdrop_url.is_none() && unstaged.caption.is_none());
+    }
+
+    #[test]
+    fn duration_follows_the_text() {
+        let card = |text: &str| RecapCard {
+            event_id: "e".to_string(),
+            text: text.to_string(),
+            backdrop_url: None,
+            caption: None,
+        };
+        assert_eq!(card("Short.").duration_ms(), 4_000);
+        assert_eq!(card(&"word ".repeat(20)).duration_ms(), 9_000);
+        assert_eq!(card(&"word ".repeat(200)).duration_ms(), 15_000);
+    }
+}
@@ -0,0 +1,137 @@
+//! Region population - the background crowd in a region
+//!
+//! Regions carry how busy they are, so a tavern can feel full and a ruin
+//! empty without the DM placing every patron. The crowd is drawn as
+//! non-interactive silhouettes behind the characters on stage. The Engine
+//! saves the population with the region; the DM can change it live.
+
+use serde::{Deserialize, Serialize};
+
+/// How crowded a region is
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrowdDensity {
+    #[default]
+    Empty,
+    Sparse,
+    Moderate,
+    Busy,
+    Packed,
+}
+
+impl CrowdDensity {
+    pub fn all() -> [Self; 5] {
+        [Self::Empty, Self::Sparse, Self::Moderate, Self::Busy, Self::Packed]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Empty => "Empty",
+            Self::Sparse => "Sparse",
+            Self::Moderate => "Moderate",
+            Self::Busy => "Busy",
+            Self::Packed => "Packed",
+        }
+    }
+
+    /// Silhouettes drawn on the stage
+    pub fn silhouette_count(&self) -> usize {
+        match self {
+            Self::Empty => 0,
+            Self::Sparse => 2,
+            Self::Moderate => 4,
+            Self::Busy => 7,
+            Self::Packed => 11,
+        }
+    }
+}
+
+/// Background crowd of a region
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RegionPopulation {
+    pub density: CrowdDensity,
+    /// Who the crowd is, e.g. "dock workers"; shown in the crowd indicator
+    pub description: Option<String>,
+}
+
+/// Where one background figure stands, as percentages of the stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrowdFigure {
+    /// Horizontal center, 0-100
+    pub left: u8,
+    /// Height relative to a full-size figure; smaller ones read as further back
+    pub scale: u8,
+    /// Whether the figure faces left
+    pub mirrored: bool,
+}
+
+impl RegionPopulation {
+    pub fn is_empty(&self) -> bool {
+        self.density == CrowdDensity::Empty
+    }
+
+    /// Indicator text, e.g. "Busy · dock workers"
+    pub fn label(&self) -> String {
+        match self.description.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+            Some(description) => format!("{} · {}", self.density.display_name(), description),
+            None => self.density.display_name().to_string(),
+        }
+    }
+
+    /// The crowd's figures, spread across the stage
+    ///
+    /// Placement is derived from `seed` (the region ID), so a region's crowd
+    /// stands in the same spots on every client and every render.
+    pub fn figures(&self, seed: &str) -> Vec<CrowdFigure> {
+        let count = self.density.silhouette_count();
+        let mut state = seed.bytes().fold(0x811c_9dc5_u32, |h, b| (h ^ u32::from(b)).wrapping_mul(0x0100_0193));
+        let mut next = move || {
+            // xorshift32
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+        (0..count)
+            .map(|i| {
+                // One figure per slot across the width, jittered within it
+                let slot = 90 / count as u32;
+                let left = 5 + slot * i as u32 + next() % slot.max(1);
+                CrowdFigure {
+                    left: left.min(95) as u8,
+                    scale: (55 + next() % 30) as u8,
+                    mirrored: next() % 2 == 0,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn figures_follow_density_and_are_stable_per_region() {
+        let busy = RegionPopulation { density: CrowdDensity::Busy, description: None };
+        let figures = busy.figures("region-tavern");
+        assert_eq!(figures.len(), 7);
+        assert_eq!(figures, busy.figures("region-tavern"));
+        assert_ne!(figures, busy.figures("region-docks"));
+        assert!(figures.windows(2).all(|w| w[0].left < w[1].left));
+        assert!(figures.iter().all(|f| (5..=95).contains(&f.left) && (55..85).contains(&f.scale)));
+
+        assert!(RegionPopulation::default().figures("region-ruins").is_empty());
+    }
+
+    #[test]
+    fn population_from_an_older_engine_is_empty() {
+        let population: RegionPopulation = serde_json::from_str("{}").unwrap();
+        assert!(population.is_empty());
+
+        let population: RegionPopulation =
+            serde_json::from_str(r#"{"density": "packed", "description": " dock workers "}"#).unwrap();
+        assert_eq!(population.label(), "Packed · dock workers");
+    }
+}
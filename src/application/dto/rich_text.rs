@@ -0,0 +1,364 @@
+//! Rich text for entity descriptions
+//!
+//! Descriptions travel to and from the Engine as plain strings using a small
+//! markdown-style markup, so existing plain-text descriptions stay valid:
+//!
+//! - `**bold**` and `*italic*`
+//! - lines starting with `- ` form a bullet list
+//! - `@[Label](kind:id)` mentions an entity (`character`, `location`, `item`,
+//!   `event`) or a DM notes page (`note`)
+//! - a backslash escapes `*`, `@` and `\`
+//!
+//! `RichText` is the parsed form used by the editor and renderer.
+
+use serde::{Deserialize, Serialize};
+
+/// Kinds of entity that can be mentioned in a description
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MentionKind {
+    Character,
+    Location,
+    Item,
+    NarrativeEvent,
+    /// A page of the DM notes wiki
+    Note,
+}
+
+impl MentionKind {
+    /// Tag used in the markup, e.g. `character` in `@[Mira](character:abc)`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Character => "character",
+            Self::Location => "location",
+            Self::Item => "item",
+            Self::NarrativeEvent => "event",
+            Self::Note => "note",
+        }
+    }
+
+    pub fn from_tag(s: &str) -> Option<Self> {
+        match s {
+            "character" => Some(Self::Character),
+            "location" => Some(Self::Location),
+            "item" => Some(Self::Item),
+            "event" => Some(Self::NarrativeEvent),
+            "note" => Some(Self::Note),
+            _ => None,
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Self::Character => "👤",
+            Self::Location => "📍",
+            Self::Item => "🎒",
+            Self::NarrativeEvent => "📜",
+            Self::Note => "📝",
+        }
+    }
+}
+
+/// A reference to an entity inside a description
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityMention {
+    pub kind: MentionKind,
+    pub id: String,
+    /// Display label captured when the mention was written
+    pub label: String,
+}
+
+impl EntityMention {
+    /// Markup for this mention
+    pub fn to_markup(&self) -> String {
+        format!(
+            "@[{}]({}:{})",
+            self.label.replace(']', ""),
+            self.kind.as_str(),
+            self.id
+        )
+    }
+}
+
+/// Longest text after `@` still treated as a mention query
+const MAX_MENTION_QUERY: usize = 40;
+
+/// A `@query` being typed at the end of the text: `(byte offset of @, query)`
+pub fn active_mention_query(value: &str) -> Option<(usize, String)> {
+    let at = value.rfind('@')?;
+    let query = &value[at + 1..];
+    let starts_word = !matches!(value[..at].chars().last(), Some(c) if !c.is_whitespace());
+    if !starts_word
+        || query.starts_with('[')
+        || query.contains('\n')
+        || query.chars().count() > MAX_MENTION_QUERY
+    {
+        return None;
+    }
+    Some((at, query.to_string()))
+}
+
+/// Up to `limit` candidates whose label contains `query`, prefix matches first
+pub fn matching_mentions(candidates: &[EntityMention], query: &str, limit: usize) -> Vec<EntityMention> {
+    let query = query.to_lowercase();
+    let mut matches: Vec<&EntityMention> = candidates
+        .iter()
+        .filter(|c| c.label.to_lowercase().contains(&query))
+        .collect();
+    matches.sort_by_key(|c| !c.label.to_lowercase().starts_with(&query));
+    matches.into_iter().take(limit).cloned().collect()
+}
+
+/// Replace the `@query` starting at byte `at` with the mention's markup
+pub fn complete_mention(value: &str, at: usize, mention: &EntityMention) -> String {
+    format!("{}{} ", &value[..at], mention.to_markup())
+}
+
+/// An inline run of a rich text line
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RichSpan {
+    Text {
+        text: String,
+        #[serde(default)]
+        bold: bool,
+        #[serde(default)]
+        italic: bool,
+    },
+    Mention(EntityMention),
+    LineBreak,
+}
+
+/// A block-level element of rich text
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "content", rename_all = "snake_case")]
+pub enum RichBlock {
+    Paragraph(Vec<RichSpan>),
+    BulletList(Vec<Vec<RichSpan>>),
+}
+
+/// Parsed rich text document
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RichText {
+    pub blocks: Vec<RichBlock>,
+}
+
+impl RichText {
+    /// Parse description markup
+    pub fn parse(markup: &str) -> Self {
+        let mut blocks = Vec::new();
+        let mut paragraph: Vec<RichSpan> = Vec::new();
+        let mut list: Vec<Vec<RichSpan>> = Vec::new();
+
+        for line in markup.lines() {
+            let trimmed = line.trim_end();
+            if let Some(item) = trimmed.strip_prefix("- ") {
+                flush_paragraph(&mut blocks, &mut paragraph);
+                list.push(parse_inline(item));
+            } else if trimmed.trim().is_empty() {
+                flush_paragraph(&mut blocks, &mut paragraph);
+                flush_list(&mut blocks, &mut list);
+            } else {
+                flush_list(&mut blocks, &mut list);
+                if !paragraph.is_empty() {
+                    paragraph.push(RichSpan::LineBreak);
+                }
+                paragraph.extend(parse_inline(trimmed));
+            }
+        }
+        flush_paragraph(&mut blocks, &mut paragraph);
+        flush_list(&mut blocks, &mut list);
+
+        Self { blocks }
+    }
+
+    /// Text without formatting; mentions become their labels
+    pub fn plain_text(&self) -> String {
+        self.blocks
+            .iter()
+            .map(|block| match block {
+                RichBlock::Paragraph(spans) => spans_to_plain(spans),
+                RichBlock::BulletList(items) => items
+                    .iter()
+                    .map(|item| format!("• {}", spans_to_plain(item)))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// All entity mentions, in document order
+    pub fn mentions(&self) -> Vec<&EntityMention> {
+        let spans = self.blocks.iter().flat_map(|block| match block {
+            RichBlock::Paragraph(spans) => spans.iter().collect::<Vec<_>>(),
+            RichBlock::BulletList(items) => items.iter().flatten().collect(),
+        });
+        spans
+            .filter_map(|span| match span {
+                RichSpan::Mention(m) => Some(m),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Strip markup from a description, for previews and prompts
+pub fn description_plain_text(markup: &str) -> String {
+    RichText::parse(markup).plain_text()
+}
+
+fn flush_paragraph(blocks: &mut Vec<RichBlock>, paragraph: &mut Vec<RichSpan>) {
+    if !paragraph.is_empty() {
+        blocks.push(RichBlock::Paragraph(std::mem::take(paragraph)));
+    }
+}
+
+fn flush_list(blocks: &mut Vec<RichBlock>, list: &mut Vec<Vec<RichSpan>>) {
+    if !list.is_empty() {
+        blocks.push(RichBlock::BulletList(std::mem::take(list)));
+    }
+}
+
+/// Parse a single line of inline markup
+fn parse_inline(line: &str) -> Vec<RichSpan> {
+    let mut spans = Vec::new();
+    let mut text = String::new();
+    let (mut bold, mut italic) = (false, false);
+    let mut rest = line;
+
+    while let Some(c) = rest.chars().next() {
+        if c == '\\' {
+            let mut chars = rest[1..].chars();
+            match chars.next() {
+                Some(escaped @ ('*' | '@' | '\\')) => {
+                    text.push(escaped);
+                    rest = chars.as_str();
+                }
+                _ => {
+                    text.push('\\');
+                    rest = &rest[1..];
+                }
+            }
+        } else if let Some(after) = rest.strip_prefix("**") {
+            // Only toggle on when a closing marker exists on this line
+            if bold || after.contains("**") {
+                push_text(&mut spans, &mut text, bold, italic);
+                bold = !bold;
+            } else {
+                text.push_str("**");
+            }
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix('*') {
+            if italic || after.contains('*') {
+                push_text(&mut spans, &mut text, bold, italic);
+                italic = !italic;
+            } else {
+                text.push('*');
+            }
+            rest = after;
+        } else if let Some((mention, after)) = parse_mention(rest) {
+            push_text(&mut spans, &mut text, bold, italic);
+            spans.push(RichSpan::Mention(mention));
+            rest = after;
+        } else {
+            text.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    push_text(&mut spans, &mut text, bold, italic);
+    spans
+}
+
+/// Parse `@[Label](kind:id)` at the start of `s`
+fn parse_mention(s: &str) -> Option<(EntityMention, &str)> {
+    let after_open = s.strip_prefix("@[")?;
+    let label_end = after_open.find("](")?;
+    let label = &after_open[..label_end];
+    let after_label = &after_open[label_end + 2..];
+    let target_end = after_label.find(')')?;
+    let (kind, id) = after_label[..target_end].split_once(':')?;
+    let kind = MentionKind::from_tag(kind)?;
+    if id.is_empty() || label.is_empty() {
+        return None;
+    }
+    let mention = EntityMention {
+        kind,
+        id: id.to_string(),
+        label: label.to_string(),
+    };
+    Some((mention, &after_label[target_end + 1..]))
+}
+
+fn push_text(spans: &mut Vec<RichSpan>, text: &mut String, bold: bool, italic: bool) {
+    if !text.is_empty() {
+        spans.push(RichSpan::Text {
+            text: std::mem::take(text),
+            bold,
+            italic,
+        });
+    }
+}
+
+fn spans_to_plain(spans: &[RichSpan]) -> String {
+    spans
+        .iter()
+        .map(|span| match span {
+            RichSpan::Text { text, .. } => text.clone(),
+            RichSpan::Mention(m) => m.label.clone(),
+            RichSpan::LineBreak => "\n".to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_descriptions_parse_as_paragraphs() {
+        let text = RichText::parse("A quiet harbor town.\nFog most mornings.");
+        assert_eq!(text.blocks.len(), 1);
+        assert_eq!(text.plain_text(), "A quiet harbor town.\nFog most mornings.");
+    }
+
+    #[test]
+    fn parses_formatting_lists_and_mentions() {
+        let text = RichText::parse(
+            "Run by **Mira** at @[the Docks](location:loc-1)\n\n- *first*\n- 5 * 3",
+        );
+        assert_eq!(text.blocks.len(), 2);
+        assert_eq!(text.mentions()[0].id, "loc-1");
+        assert_eq!(text.mentions()[0].kind, MentionKind::Location);
+        assert!(matches!(
+            &text.blocks[0],
+            RichBlock::Paragraph(spans) if matches!(&spans[1], RichSpan::Text { bold: true, .. })
+        ));
+        // An unmatched asterisk stays literal
+        assert_eq!(text.plain_text(), "Run by Mira at the Docks\n\n• first\n• 5 * 3");
+    }
+
+    #[test]
+    fn completes_trailing_mentions() {
+        let candidates = vec![
+            EntityMention { kind: MentionKind::Location, id: "loc-1".into(), label: "Old Mill".into() },
+            EntityMention { kind: MentionKind::Item, id: "it-1".into(), label: "Millstone".into() },
+            EntityMention { kind: MentionKind::Character, id: "c-1".into(), label: "Mira".into() },
+        ];
+
+        assert_eq!(active_mention_query("mail@host"), None);
+        assert_eq!(active_mention_query("see @[Mira](character:c-1)"), None);
+        let (at, query) = active_mention_query("Bring the @mil").expect("query");
+        assert_eq!(query, "mil");
+
+        let matches = matching_mentions(&candidates, &query, 5);
+        let labels: Vec<&str> = matches.iter().map(|m| m.label.as_str()).collect();
+        assert_eq!(labels, vec!["Millstone", "Old Mill"]);
+
+        assert_eq!(
+            complete_mention("Bring the @mil", at, &matches[0]),
+            "Bring the @[Millstone](item:it-1) "
+        );
+        assert_eq!(RichText::parse("@[Millstone](item:it-1)").mentions()[0].kind, MentionKind::Item);
+    }
+}
@@ -0,0 +1,112 @@
+//! NPC routines - where a character is at each time of day
+//!
+//! A routine is a short list of day periods, each with a location and an
+//! activity ("morning: market, haggling over spices"). The Director uses it
+//! to answer "where would X be right now" from the world clock.
+
+use serde::{Deserialize, Serialize};
+
+/// Part of the day a routine entry covers
+///
+/// Matches the Engine's `time_of_day` values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DayPeriod {
+    Morning,
+    Afternoon,
+    Evening,
+    Night,
+}
+
+impl DayPeriod {
+    pub fn all() -> [Self; 4] {
+        [Self::Morning, Self::Afternoon, Self::Evening, Self::Night]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Morning => "Morning",
+            Self::Afternoon => "Afternoon",
+            Self::Evening => "Evening",
+            Self::Night => "Night",
+        }
+    }
+
+    /// The period for a game clock `time_of_day` string
+    pub fn from_time_of_day(time_of_day: &str) -> Option<Self> {
+        match time_of_day.trim().to_lowercase().as_str() {
+            "morning" | "dawn" => Some(Self::Morning),
+            "afternoon" | "noon" | "midday" => Some(Self::Afternoon),
+            "evening" | "dusk" => Some(Self::Evening),
+            "night" | "midnight" => Some(Self::Night),
+            _ => None,
+        }
+    }
+}
+
+/// Where a character is and what they're doing during one part of the day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoutineEntry {
+    pub period: DayPeriod,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub activity: String,
+}
+
+impl RoutineEntry {
+    /// Whether the entry says anything
+    pub fn is_empty(&self) -> bool {
+        self.location_id.is_none() && self.activity.trim().is_empty()
+    }
+}
+
+/// The routine entry for a part of the day
+pub fn routine_entry_for(routine: &[RoutineEntry], period: DayPeriod) -> Option<&RoutineEntry> {
+    routine.iter().find(|e| e.period == period && !e.is_empty())
+}
+
+/// Replace (or remove, when empty) the entry for `entry.period`, keeping
+/// entries in day order
+pub fn set_routine_entry(routine: &mut Vec<RoutineEntry>, entry: RoutineEntry) {
+    routine.retain(|e| e.period != entry.period);
+    if !entry.is_empty() {
+        routine.push(entry);
+    }
+    routine.sort_by_key(|e| DayPeriod::all().iter().position(|p| *p == e.period));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(period: DayPeriod, location: &str, activity: &str) -> RoutineEntry {
+        RoutineEntry {
+            period,
+            location_id: Some(location.to_string()),
+            activity: activity.to_string(),
+        }
+    }
+
+    #[test]
+    fn clock_strings_map_to_periods() {
+        assert_eq!(DayPeriod::from_time_of_day("Evening"), Some(DayPeriod::Evening));
+        assert_eq!(DayPeriod::from_time_of_day(" dawn "), Some(DayPeriod::Morning));
+        assert_eq!(DayPeriod::from_time_of_day("teatime"), None);
+    }
+
+    #[test]
+    fn set_entry_replaces_removes_and_orders() {
+        let mut routine = Vec::new();
+        set_routine_entry(&mut routine, entry(DayPeriod::Evening, "tavern", "drinking"));
+        set_routine_entry(&mut routine, entry(DayPeriod::Morning, "market", "haggling"));
+        assert_eq!(routine[0].period, DayPeriod::Morning);
+        assert_eq!(routine_entry_for(&routine, DayPeriod::Evening).unwrap().activity, "drinking");
+
+        set_routine_entry(
+            &mut routine,
+            RoutineEntry { period: DayPeriod::Evening, location_id: None, activity: String::new() },
+        );
+        assert_eq!(routine.len(), 1);
+        assert!(routine_entry_for(&routine, DayPeriod::Evening).is_none());
+    }
+}
@@ -0,0 +1,231 @@
+//! Scene mood - the DM's lighting and weather over a region's backdrop
+//!
+//! A mood is a tint color, a vignette, a darkness level and a weather
+//! layer, drawn over the backdrop on every client. The Engine saves it
+//! with the region, so a region looks the same whenever the party returns.
+//! Levels are percentages; the styles below turn them into the overlay
+//! layers' CSS.
+
+use serde::{Deserialize, Serialize};
+
+/// Highest tint, vignette or darkness level
+pub const MAX_MOOD_LEVEL: u8 = 100;
+
+/// Weather drawn over the stage
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Weather {
+    #[default]
+    Clear,
+    Rain,
+    Snow,
+    Fog,
+}
+
+impl Weather {
+    pub fn all() -> [Self; 4] {
+        [Self::Clear, Self::Rain, Self::Snow, Self::Fog]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Clear => "Clear",
+            Self::Rain => "Rain",
+            Self::Snow => "Snow",
+            Self::Fog => "Fog",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Self::Clear => "☀",
+            Self::Rain => "🌧",
+            Self::Snow => "❄",
+            Self::Fog => "🌫",
+        }
+    }
+
+    /// Class of the animated weather layer (see `styles/input.css`)
+    pub fn layer_class(&self) -> Option<&'static str> {
+        match self {
+            Self::Clear => None,
+            Self::Rain => Some("weather-rain"),
+            Self::Snow => Some("weather-snow"),
+            Self::Fog => Some("weather-fog"),
+        }
+    }
+}
+
+/// Lighting and weather for a region
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SceneMood {
+    /// Tint color as `#rrggbb`; None for no tint
+    pub tint: Option<String>,
+    /// How strongly the tint shows, 0-100
+    pub tint_strength: u8,
+    /// How dark the edges are, 0-100
+    pub vignette: u8,
+    /// How dark the whole stage is, 0-100
+    pub darkness: u8,
+    pub weather: Weather,
+}
+
+impl SceneMood {
+    /// Starting points for the DM's mood controls
+    pub fn presets() -> Vec<(&'static str, SceneMood)> {
+        let mood = |tint: &str, tint_strength, vignette, darkness, weather| SceneMood {
+            tint: Some(tint.to_string()),
+            tint_strength,
+            vignette,
+            darkness,
+            weather,
+        };
+        vec![
+            ("Clear", SceneMood::default()),
+            ("Dusk", mood("#f97316", 25, 30, 20, Weather::Clear)),
+            ("Night", mood("#1e3a8a", 35, 50, 55, Weather::Clear)),
+            ("Storm", mood("#475569", 30, 45, 40, Weather::Rain)),
+            ("Blizzard", mood("#e0f2fe", 25, 35, 10, Weather::Snow)),
+            ("Eerie", mood("#16a34a", 20, 60, 35, Weather::Fog)),
+        ]
+    }
+
+    /// Levels held to 0-100 and a tint that isn't `#rrggbb` dropped
+    pub fn normalized(mut self) -> Self {
+        self.tint = self.tint.filter(|t| is_hex_color(t)).map(|t| t.to_lowercase());
+        self.tint_strength = self.tint_strength.min(MAX_MOOD_LEVEL);
+        self.vignette = self.vignette.min(MAX_MOOD_LEVEL);
+        self.darkness = self.darkness.min(MAX_MOOD_LEVEL);
+        self
+    }
+
+    /// Whether the mood draws nothing over the backdrop
+    pub fn is_clear(&self) -> bool {
+        self.tint_style().is_none()
+            && self.vignette == 0
+            && self.darkness == 0
+            && self.weather == Weather::Clear
+    }
+
+    /// Style of the tint layer, if there is a visible tint
+    pub fn tint_style(&self) -> Option<String> {
+        let tint = self.tint.as_deref().filter(|t| is_hex_color(t))?;
+        (self.tint_strength > 0).then(|| {
+            format!(
+                "background-color: {}; opacity: {:.2}; mix-blend-mode: soft-light;",
+                tint,
+                level(self.tint_strength)
+            )
+        })
+    }
+
+    /// Style of the vignette layer, if there is a vignette
+    pub fn vignette_style(&self) -> Option<String> {
+        (self.vignette > 0).then(|| {
+            // Stronger vignettes reach further in from the edges
+            let clear_to = 100.0 - level(self.vignette) * 60.0;
+            format!(
+                "background: radial-gradient(ellipse at center, transparent {:.0}%, rgba(0, 0, 0, {:.2}) 100%);",
+                clear_to,
+                level(self.vignette)
+            )
+        })
+    }
+
+    /// Style of the darkness layer, if the stage is darkened
+    ///
+    /// Full darkness still leaves the backdrop faintly visible.
+    pub fn darkness_style(&self) -> Option<String> {
+        (self.darkness > 0).then(|| {
+            format!("background-color: rgba(0, 0, 0, {:.2});", level(self.darkness) * 0.85)
+        })
+    }
+
+    /// Short description for the DM, e.g. "Rain · dark 40% · vignette 30%"
+    pub fn summary(&self) -> String {
+        if self.is_clear() {
+            return "No mood effects".to_string();
+        }
+        let mut parts = Vec::new();
+        if self.weather != Weather::Clear {
+            parts.push(self.weather.display_name().to_string());
+        }
+        if let (Some(tint), Some(_)) = (self.tint.as_ref(), self.tint_style()) {
+            parts.push(format!("tint {} {}%", tint, self.tint_strength));
+        }
+        if self.darkness > 0 {
+            parts.push(format!("dark {}%", self.darkness));
+        }
+        if self.vignette > 0 {
+            parts.push(format!("vignette {}%", self.vignette));
+        }
+        parts.join(" · ")
+    }
+}
+
+/// A level as a fraction
+fn level(value: u8) -> f32 {
+    f32::from(value.min(MAX_MOOD_LEVEL)) / f32::from(MAX_MOOD_LEVEL)
+}
+
+/// Whether `value` is a `#rrggbb` color
+pub fn is_hex_color(value: &str) -> bool {
+    value.len() == 7
+        && value.starts_with('#')
+        && value[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_mood_is_clear_and_draws_nothing() {
+        let mood = SceneMood::default();
+        assert!(mood.is_clear());
+        assert!(mood.tint_style().is_none());
+        assert!(mood.vignette_style().is_none());
+        assert!(mood.darkness_style().is_none());
+        assert_eq!(mood.summary(), "No mood effects");
+    }
+
+    #[test]
+    fn normalized_clamps_levels_and_drops_bad_tints() {
+        let mood = SceneMood {
+            tint: Some("red".to_string()),
+            tint_strength: 40,
+            vignette: 250,
+            darkness: 101,
+            weather: Weather::Fog,
+        }
+        .normalized();
+        assert_eq!(mood.tint, None);
+        assert_eq!((mood.vignette, mood.darkness), (100, 100));
+
+        let tinted = SceneMood { tint: Some("#1E3A8A".to_string()), ..mood }.normalized();
+        assert_eq!(tinted.tint.as_deref(), Some("#1e3a8a"));
+    }
+
+    #[test]
+    fn styles_scale_with_levels() {
+        let mood = SceneMood {
+            tint: Some("#1e3a8a".to_string()),
+            tint_strength: 50,
+            vignette: 50,
+            darkness: 100,
+            weather: Weather::Rain,
+        };
+        assert!(mood.tint_style().unwrap().contains("opacity: 0.50"));
+        assert!(mood.vignette_style().unwrap().contains("transparent 70%"));
+        assert_eq!(mood.darkness_style().unwrap(), "background-color: rgba(0, 0, 0, 0.85);");
+        assert_eq!(mood.summary(), "Rain · tint #1e3a8a 50% · dark 100% · vignette 50%");
+    }
+
+    #[test]
+    fn mood_from_an_older_engine_defaults_missing_fields() {
+        let mood: SceneMood = serde_json::from_str(r#"{"darkness": 30}"#).unwrap();
+        assert_eq!(mood.darkness, 30);
+        assert_eq!(mood.weather, Weather::Clear);
+    }
+}
@@ -51,6 +51,109 @@ impl Default for ContextBudgetConfig {
     }
 }
 
+/// Which session moments become timeline story events automatically
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct StoryCaptureRules {
+    /// Record each resolved challenge with its roll and outcome
+    #[serde(default = "default_true")]
+    pub challenge_results: bool,
+    /// Record moves from one scene to another
+    #[serde(default = "default_true")]
+    pub scene_transitions: bool,
+    /// Record a player character entering a new region
+    #[serde(default)]
+    pub region_transitions: bool,
+    /// Record the first time the party meets each NPC in a session
+    #[serde(default = "default_true")]
+    pub npc_first_meetings: bool,
+    /// Record quick markers dropped from the Director panel
+    #[serde(default = "default_true")]
+    pub dm_markers: bool,
+}
+
+fn default_true() -> bool { true }
+
+impl Default for StoryCaptureRules {
+    fn default() -> Self {
+        Self {
+            challenge_results: true,
+            scene_transitions: true,
+            region_transitions: false,
+            npc_first_meetings: true,
+            dm_markers: true,
+        }
+    }
+}
+
+/// When challenge outcomes skip the DM's approval
+///
+/// Clear successes are applied as soon as the roll is in; borderline and
+/// failed rolls still wait in the decision queue.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ChallengeAutoResolveRules {
+    /// Auto-apply clear successes at all
+    #[serde(default)]
+    pub enabled: bool,
+    /// How far a roll's total has to beat the DC to count as clear
+    #[serde(default = "default_auto_resolve_margin")]
+    pub min_margin: u32,
+}
+
+fn default_auto_resolve_margin() -> u32 { 5 }
+
+impl Default for ChallengeAutoResolveRules {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_margin: default_auto_resolve_margin(),
+        }
+    }
+}
+
+/// A named tone for the whole table, switched from the Director panel
+///
+/// Everything in it goes to the Engine with the directorial context, so
+/// NPC responses follow it until another preset is picked.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct DirectorialPreset {
+    pub name: String,
+    /// Scene tone, e.g. "Tense"
+    pub tone: String,
+    /// How NPCs should speak and describe things
+    #[serde(default)]
+    pub style_notes: String,
+    /// Content rules, one per entry, e.g. "No gore"
+    #[serde(default)]
+    pub content_guidelines: Vec<String>,
+}
+
+/// Presets every world starts with
+pub fn builtin_directorial_presets() -> Vec<DirectorialPreset> {
+    vec![
+        DirectorialPreset {
+            name: "Gritty".to_string(),
+            tone: "Serious".to_string(),
+            style_notes: "Terse, world-weary speech. Violence has weight and consequences; nothing comes free.".to_string(),
+            content_guidelines: vec!["Keep injuries grounded rather than graphic".to_string()],
+        },
+        DirectorialPreset {
+            name: "Whimsical".to_string(),
+            tone: "Lighthearted".to_string(),
+            style_notes: "Playful, colourful language. NPCs are eccentric and quick to joke.".to_string(),
+            content_guidelines: vec!["Keep peril cartoonish".to_string()],
+        },
+        DirectorialPreset {
+            name: "Horror".to_string(),
+            tone: "Suspenseful".to_string(),
+            style_notes: "Slow dread. Describe sounds and half-seen things; NPCs are nervous and withhold what they know.".to_string(),
+            content_guidelines: vec![
+                "Imply rather than show gore".to_string(),
+                "No harm to children".to_string(),
+            ],
+        },
+    ]
+}
+
 /// Application settings from the Engine
 ///
 /// These settings control various aspects of the Engine's behavior,
@@ -132,6 +235,10 @@ pub struct AppSettings {
     #[serde(default = "default_outcome_branch_max")]
     pub outcome_branch_max: usize,
 
+    /// Challenge outcomes applied without waiting for the DM
+    #[serde(default)]
+    pub challenge_auto_resolve: ChallengeAutoResolveRules,
+
     // ============================================================================
     // LLM Settings
     // ============================================================================
@@ -143,6 +250,14 @@ pub struct AppSettings {
     /// Token budget configuration for LLM context building
     #[serde(default)]
     pub context_budget: ContextBudgetConfig,
+
+    /// Session moments captured to the timeline automatically
+    #[serde(default)]
+    pub story_capture: StoryCaptureRules,
+
+    /// Tone presets offered in the Director panel
+    #[serde(default = "builtin_directorial_presets")]
+    pub directorial_presets: Vec<DirectorialPreset>,
 }
 
 fn default_outcome_branch_count() -> usize { 2 }
@@ -169,8 +284,11 @@ impl Default for AppSettings {
             outcome_branch_count: 2,
             outcome_branch_min: 1,
             outcome_branch_max: 4,
+            challenge_auto_resolve: ChallengeAutoResolveRules::default(),
             suggestion_tokens_per_branch: 200,
             context_budget: ContextBudgetConfig::default(),
+            story_capture: StoryCaptureRules::default(),
+            directorial_presets: builtin_directorial_presets(),
         }
     }
 }
@@ -0,0 +1,53 @@
+//! Shared notes - directorial text the DM and co-DMs edit together
+//!
+//! The Engine holds one copy of each shared note and numbers its revisions.
+//! An edit names the revision it was based on; the Engine keeps the last
+//! write and broadcasts the new revision, with who made it, to every DM.
+//! Editors also report where they are typing, so the others can see them.
+
+use serde::{Deserialize, Serialize};
+
+/// Which note is being edited
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SharedNoteDoc {
+    /// The scene's directorial notes
+    SceneNotes,
+    /// What an NPC wants right now
+    NpcMotivation { npc_id: String },
+}
+
+impl SharedNoteDoc {
+    /// Stable key for maps of notes
+    pub fn key(&self) -> String {
+        match self {
+            Self::SceneNotes => "scene".to_string(),
+            Self::NpcMotivation { npc_id } => format!("npc:{}", npc_id),
+        }
+    }
+}
+
+/// One revision of a shared note
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NoteRevision {
+    pub text: String,
+    /// Counts up from 0 with every accepted edit
+    pub revision: u64,
+    /// Who wrote this revision; `None` before anyone has edited
+    #[serde(default)]
+    pub editor_id: Option<String>,
+    #[serde(default)]
+    pub editor_name: Option<String>,
+    /// RFC 3339 time of the edit
+    #[serde(default)]
+    pub edited_at: Option<String>,
+}
+
+/// Where another editor is typing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NoteCursor {
+    pub user_id: String,
+    pub user_name: String,
+    /// Character offset into the note
+    pub position: usize,
+}
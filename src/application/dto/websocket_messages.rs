@@ -5,6 +5,19 @@
 //! depend inward on them, and higher layers don't need to import from infrastructure.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use super::annotation::{AnnotatedSurface, Annotation, AnnotationLayer, AnnotationSurface, SurfaceAnnotations};
+use super::camera_effect::CameraEffect;
+use super::crafting::CraftOutcome;
+use super::disposition::Disposition;
+use super::entity_style::EntityStyle;
+use super::fog_of_war::{MapReveals, RevealScope};
+use super::npc_memory::{MemoryDraft, NpcMemory};
+use super::recap::SessionRecap;
+use super::region_population::RegionPopulation;
+use super::scene_mood::SceneMood;
+use super::shared_note::{NoteCursor, NoteRevision, SharedNoteDoc};
+use super::world_snapshot::{AudioCue, FieldValue};
 
 /// Messages sent from Player to Engine
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +30,9 @@ pub enum ClientMessage {
         /// Optional world ID to join (creates demo session if not provided)
         #[serde(default)]
         world_id: Option<String>,
+        /// Protocol version the client speaks
+        #[serde(default)]
+        protocol_version: Option<u32>,
     },
     /// Player performs an action
     PlayerAction {
@@ -24,6 +40,14 @@ pub enum ClientMessage {
         target: Option<String>,
         dialogue: Option<String>,
     },
+    /// DM acts as a character (possession); the Engine attributes the
+    /// action to that character and marks it DM-controlled
+    PossessedAction {
+        character_id: String,
+        action_type: String,
+        target: Option<String>,
+        dialogue: Option<String>,
+    },
     /// Request to change scene
     RequestSceneChange { scene_id: String },
     /// DM updates directorial context
@@ -207,6 +231,300 @@ pub enum ClientMessage {
         /// Optional specific arrival region (uses location default if not provided)
         arrival_region_id: Option<String>,
     },
+
+    // =========================================================================
+    // Presence (webcam / avatar tiles)
+    // =========================================================================
+
+    /// Change how this participant appears in the presence strip
+    SetPresenceMode {
+        mode: PresenceMode,
+    },
+
+    /// Relay a WebRTC signaling message to another participant
+    PresenceSignal {
+        /// Recipient user ID
+        to_user_id: String,
+        signal: PresenceSignalData,
+    },
+
+    // =========================================================================
+    // Session Pause
+    // =========================================================================
+
+    /// DM pauses the session and shows the intermission screen
+    PauseSession {
+        /// Optional message shown on the intermission screen
+        message: Option<String>,
+        /// Optional artwork asset path for the intermission screen
+        art_asset: Option<String>,
+    },
+
+    /// DM resumes a paused session
+    ResumeSession,
+
+    // =========================================================================
+    // Session Roles
+    // =========================================================================
+
+    /// DM changes another participant's role, e.g. to promote a co-DM
+    AssignRole {
+        user_id: String,
+        role: ParticipantRole,
+    },
+
+    // =========================================================================
+    // Whispers
+    // =========================================================================
+
+    /// Player sends a private message to the DM
+    WhisperToDm { text: String },
+
+    /// DM replies privately to a player's whisper
+    WhisperReply { to_user_id: String, text: String },
+
+    // =========================================================================
+    // Spectator Policy
+    // =========================================================================
+
+    /// DM changes what spectators are allowed to see
+    SetSpectatorPolicy { policy: SpectatorPolicy },
+
+    // =========================================================================
+    // Parallel Scenes
+    // =========================================================================
+
+    /// DM replaces the set of scenes running side by side, and who is in each
+    SetStagedScenes { scenes: Vec<StagedScene> },
+
+    /// DM points the Director panel at one staged scene; directorial
+    /// updates and approvals apply to it from then on
+    FocusStagedScene { scene_id: String },
+
+    // =========================================================================
+    // Asynchronous Play
+    // =========================================================================
+
+    /// DM turns play-by-post on or off; while on, actions sent with no DM
+    /// connected wait in the action inbox instead of being answered
+    SetAsyncPlay { enabled: bool },
+
+    /// DM has the Engine answer posted actions, in the order given; the
+    /// responses come back for approval as usual
+    ProcessInboxActions { action_ids: Vec<String> },
+
+    /// DM drops a posted action without answering it
+    DiscardInboxAction { action_id: String },
+    // =========================================================================
+    // Advancement
+    // =========================================================================
+
+    /// DM awards XP or a milestone to a player character
+    GrantAdvancement {
+        pc_id: String,
+        grant: AdvancementGrant,
+    },
+
+    /// Player confirms their choices for a pending level-up
+    SubmitLevelUp {
+        pc_id: String,
+        choices: LevelUpChoices,
+    },
+
+    // =========================================================================
+    // Conditions
+    // =========================================================================
+
+    /// DM applies a condition to a PC or NPC
+    ApplyCondition {
+        character_id: String,
+        condition: NewCondition,
+    },
+
+    /// DM removes a condition before it runs out
+    RemoveCondition {
+        character_id: String,
+        condition_id: String,
+    },
+
+    // =========================================================================
+    // Annotations
+    // =========================================================================
+
+    /// DM draws on a backdrop
+    AddAnnotation {
+        surface: AnnotationSurface,
+        annotation: Annotation,
+    },
+
+    /// DM erases one annotation
+    RemoveAnnotation {
+        surface: AnnotationSurface,
+        annotation_id: String,
+    },
+
+    /// DM creates a layer, or renames it or changes who can see it
+    SetAnnotationLayer {
+        surface: AnnotationSurface,
+        layer: AnnotationLayer,
+    },
+
+    /// DM erases everything on one layer
+    ClearAnnotationLayer {
+        surface: AnnotationSurface,
+        layer_id: String,
+    },
+
+    // =========================================================================
+    // Scene Mood
+    // =========================================================================
+
+    /// DM sets a region's lighting and weather; the Engine saves it with the
+    /// region and broadcasts it
+    SetSceneMood { region_id: String, mood: SceneMood },
+
+    /// DM sets how crowded a region is; the Engine saves it with the region
+    /// and broadcasts it
+    SetRegionPopulation {
+        region_id: String,
+        population: RegionPopulation,
+    },
+
+    // =========================================================================
+    // Camera Effects
+    // =========================================================================
+
+    /// DM plays a camera move on everyone's stage
+    PlayCameraEffect { effect: CameraEffect },
+
+    // =========================================================================
+    // Backdrops
+    // =========================================================================
+
+    /// DM makes one of a region's generated backdrops its active backdrop;
+    /// the Engine saves it and broadcasts the change
+    SetRegionBackdrop { region_id: String, asset_id: String },
+
+    // =========================================================================
+    // Fog of War
+    // =========================================================================
+
+    /// DM reveals map regions to one PC or the whole party
+    RevealRegions {
+        region_ids: Vec<String>,
+        scope: RevealScope,
+    },
+    /// DM hides map regions again
+    HideRegions {
+        region_ids: Vec<String>,
+        scope: RevealScope,
+    },
+
+    // =========================================================================
+    // NPC Memory
+    // =========================================================================
+
+    /// DM asks for what an NPC remembers; answered with `NpcMemories`
+    RequestNpcMemories { npc_id: String },
+    /// DM injects a memory into an NPC
+    AddNpcMemory { npc_id: String, memory: MemoryDraft },
+    /// DM rewrites one of an NPC's memories
+    UpdateNpcMemory {
+        npc_id: String,
+        memory_id: String,
+        memory: MemoryDraft,
+    },
+    /// DM makes an NPC forget a memory
+    DeleteNpcMemory { npc_id: String, memory_id: String },
+    /// DM hides (or shows again) an NPC's disposition meter for players;
+    /// answered with `DispositionChanged`
+    SetDispositionHidden { npc_id: String, hidden: bool },
+
+    // =========================================================================
+    // Encounters
+    // =========================================================================
+
+    /// DM runs a prepared encounter: the Engine moves the scene to its
+    /// region, puts its NPCs on stage and activates its challenges
+    RunEncounter { encounter_id: String },
+
+    // =========================================================================
+    // Crafting
+    // =========================================================================
+
+    /// Player tries a recipe: the Engine checks the ingredients, prompts
+    /// the recipe's skill check like any challenge, then updates the
+    /// inventory
+    AttemptCraft { pc_id: String, recipe_id: String },
+
+    // =========================================================================
+    // Items
+    // =========================================================================
+
+    /// DM reveals a held item's hidden notes to its holder; answered with
+    /// `ItemIdentified`
+    IdentifyItem { pc_id: String, item_id: String },
+
+    // =========================================================================
+    // Recaps
+    // =========================================================================
+
+    /// DM plays a "previously on…" recap for the session's players;
+    /// relayed as `RecapPlaying`
+    PlayRecap { recap: SessionRecap },
+
+    // =========================================================================
+    // Scripted Dialogue
+    // =========================================================================
+
+    /// DM makes a scene NPC say a line directly, without the LLM
+    SpeakAs {
+        npc_id: String,
+        text: String,
+        /// Record the line in the approval history as if it had been approved
+        record_as_approved: bool,
+    },
+
+    // =========================================================================
+    // Shared Notes
+    // =========================================================================
+
+    /// DM or co-DM replaces a shared note's text; the last write wins
+    EditSharedNote {
+        doc: SharedNoteDoc,
+        text: String,
+        /// Revision the edit was made on
+        base_revision: u64,
+    },
+    /// DM or co-DM moved their cursor in a shared note, or left it (`None`)
+    SharedNoteCursor {
+        doc: SharedNoteDoc,
+        position: Option<usize>,
+    },
+
+    // =========================================================================
+    // Dialogue Pacing
+    // =========================================================================
+
+    /// DM changes how dialogue advances in group scenes
+    SetDialoguePacing { pacing: DialoguePacing },
+    /// Player has finished reading the current line
+    DialogueRead,
+    /// DM advances paced dialogue for everyone
+    AdvanceDialogue,
+
+    // =========================================================================
+    // Decision Timers
+    // =========================================================================
+
+    /// DM changes the time limit on dialogue choices and challenge rolls
+    SetDecisionTimer { timer: DecisionTimerSettings },
+    /// Player's decision timer ran out
+    DecisionTimedOut {
+        decision: TimedDecision,
+        /// Whether the client took the default action for the player
+        defaulted: bool,
+    },
 }
 
 /// Messages received from Engine
@@ -219,6 +537,37 @@ pub enum ServerMessage {
         role: ParticipantRole,
         participants: Vec<ParticipantInfo>,
         world_snapshot: serde_json::Value, // WorldSnapshot as JSON
+        /// What spectators may currently see
+        #[serde(default)]
+        spectator_policy: SpectatorPolicy,
+        /// Protocol version the Engine speaks; older Engines don't send it
+        #[serde(default)]
+        protocol_version: Option<u32>,
+        /// Level-up the joining player has earned but not yet taken
+        #[serde(default)]
+        pending_level_up: Option<PendingLevelUpInfo>,
+        /// Time limit the DM has set on player decisions
+        #[serde(default)]
+        decision_timer: DecisionTimerSettings,
+        /// Active conditions, keyed by character ID
+        #[serde(default)]
+        conditions: HashMap<String, Vec<ActiveCondition>>,
+        /// What the DM has drawn, with players only getting layers visible to them
+        #[serde(default)]
+        annotations: Vec<AnnotatedSurface>,
+        /// IDs of the world's narrative events that have triggered, in this
+        /// session or an earlier one
+        #[serde(default)]
+        triggered_events: Vec<String>,
+        /// Parallel scenes running while the party is split
+        #[serde(default)]
+        staged_scenes: Vec<StagedScene>,
+        /// Challenge rolls resolved so far this session, oldest first
+        #[serde(default)]
+        session_rolls: Vec<ResolvedRoll>,
+        /// Tone and preset in effect (DM and co-DMs only)
+        #[serde(default)]
+        directorial_context: Option<DirectorialContext>,
     },
     /// A player joined the session (broadcast to others)
     PlayerJoined {
@@ -228,6 +577,23 @@ pub enum ServerMessage {
     },
     /// A player left the session (broadcast to others)
     PlayerLeft { user_id: String },
+    /// A player's connection dropped (broadcast to others)
+    ///
+    /// Their PC stays locked to them for `grace_secs`, and messages addressed
+    /// to them are queued. `PlayerLeft` follows if they don't come back.
+    PlayerDisconnected {
+        user_id: String,
+        #[serde(default)]
+        pc_id: Option<String>,
+        grace_secs: u32,
+    },
+    /// A disconnected player came back within the grace period
+    PlayerReconnected {
+        user_id: String,
+        /// Queued messages delivered to them on reconnect
+        #[serde(default)]
+        delivered_messages: u32,
+    },
     /// Player action was received and is being processed
     ActionReceived {
         action_id: String,
@@ -246,6 +612,9 @@ pub enum ServerMessage {
         speaker_name: String,
         text: String,
         choices: Vec<DialogueChoice>,
+        /// Scene the line belongs to, while the party is split
+        #[serde(default)]
+        scene_id: Option<String>,
     },
     /// LLM is processing (shown to DM)
     LLMProcessing { action_id: String },
@@ -258,6 +627,9 @@ pub enum ServerMessage {
         proposed_tools: Vec<ProposedTool>,
         challenge_suggestion: Option<ChallengeSuggestionInfo>,
         narrative_event_suggestion: Option<NarrativeEventSuggestionInfo>,
+        /// Player character whose action the NPC is answering
+        #[serde(default)]
+        pc_id: Option<String>,
     },
     /// Response was approved and executed
     ResponseApproved {
@@ -271,6 +643,8 @@ pub enum ServerMessage {
         skill_name: String,
         difficulty_display: String,
         description: String,
+        /// The character's total modifier, with equipment and conditions
+        /// already folded in by the Engine
         character_modifier: i32,
         /// Suggested dice formula based on rule system (e.g., "1d20", "1d100", "2d6")
         #[serde(default)]
@@ -295,6 +669,15 @@ pub enum ServerMessage {
         /// Individual dice results if rolled with formula
         #[serde(default)]
         individual_rolls: Option<Vec<i32>>,
+        /// The dice as entered, when the player rolled physical dice
+        #[serde(default)]
+        manual_entry: Option<ManualDiceEntry>,
+        /// Sound attached to the outcome
+        #[serde(default)]
+        audio_cue: Option<AudioCue>,
+        /// Camera move attached to the outcome
+        #[serde(default)]
+        camera_effect: Option<CameraEffect>,
     },
     /// Narrative event has been triggered
     NarrativeEventTriggered {
@@ -302,6 +685,12 @@ pub enum ServerMessage {
         event_name: String,
         outcome_description: String,
         scene_direction: String,
+        /// Sound attached to the event
+        #[serde(default)]
+        audio_cue: Option<AudioCue>,
+        /// Camera move attached to the event
+        #[serde(default)]
+        camera_effect: Option<CameraEffect>,
     },
     /// Party is split across multiple locations (sent to DM)
     SplitPartyNotification {
@@ -321,6 +710,9 @@ pub enum ServerMessage {
         entity_id: String,
         asset_type: String,
         position: u32,
+        /// Parameter overrides the batch was queued with (None = slot defaults)
+        #[serde(default)]
+        params: Option<GenerationParams>,
     },
     /// Generation progress update
     GenerationProgress { batch_id: String, progress: u8 },
@@ -339,6 +731,14 @@ pub enum ServerMessage {
         request_id: String,
         status: String,
     },
+    /// Streamed text for a suggestion request; `delta` extends the
+    /// suggestion at `index`, and `SuggestionComplete` follows with the
+    /// full results
+    SuggestionChunk {
+        request_id: String,
+        index: usize,
+        delta: String,
+    },
     /// A suggestion request has completed
     SuggestionComplete {
         request_id: String,
@@ -403,6 +803,12 @@ pub enum ServerMessage {
         outcome_description: String,
         outcome_triggers: Vec<ProposedTool>,
         roll_breakdown: Option<String>,
+        /// The challenge's DC, when it has a numeric one
+        #[serde(default)]
+        difficulty_class: Option<i32>,
+        /// The dice as entered, when the player rolled physical dice
+        #[serde(default)]
+        manual_entry: Option<ManualDiceEntry>,
     },
 
     /// LLM suggestions ready for challenge outcome (sent to DM) (P3.3/P3.4)
@@ -506,12 +912,291 @@ pub enum ServerMessage {
         /// Whether time is paused
         is_paused: bool,
     },
+
+    // =========================================================================
+    // Presence (webcam / avatar tiles)
+    // =========================================================================
+
+    /// A participant changed their presence mode (broadcast to all)
+    PresenceUpdated {
+        user_id: String,
+        mode: PresenceMode,
+    },
+
+    /// WebRTC signaling message relayed from another participant
+    PresenceSignal {
+        /// Sender user ID
+        from_user_id: String,
+        signal: PresenceSignalData,
+    },
+
+    // =========================================================================
+    // Session Pause
+    // =========================================================================
+
+    /// The DM paused the session (broadcast to all)
+    SessionPaused {
+        message: Option<String>,
+        art_asset: Option<String>,
+    },
+
+    /// The DM resumed the session (broadcast to all)
+    SessionResumed,
+
+    // =========================================================================
+    // Session Roles
+    // =========================================================================
+
+    /// A participant's role was changed by the DM (broadcast to all)
+    ParticipantRoleChanged {
+        user_id: String,
+        role: ParticipantRole,
+    },
+
+    // =========================================================================
+    // Whispers
+    // =========================================================================
+
+    /// A private message between a player and the DM
+    ///
+    /// Only delivered to the player in `player_user_id` and the session's DMs.
+    Whisper {
+        whisper_id: String,
+        /// The player whose thread this belongs to
+        player_user_id: String,
+        /// Character name of the player, if they have one
+        #[serde(default)]
+        player_name: Option<String>,
+        text: String,
+        /// Whether the DM wrote this (a reply) rather than the player
+        from_dm: bool,
+    },
+
+    // =========================================================================
+    // Spectator Policy
+    // =========================================================================
+
+    /// The DM changed the spectator policy (broadcast to all)
+    SpectatorPolicyChanged { policy: SpectatorPolicy },
+
+    // =========================================================================
+    // Parallel Scenes
+    // =========================================================================
+
+    /// The staged scenes or their members changed (broadcast to all)
+    StagedScenesChanged { scenes: Vec<StagedScene> },
+
+    // =========================================================================
+    // Asynchronous Play
+    // =========================================================================
+
+    /// Play-by-post was turned on or off (broadcast to all, and sent on join)
+    AsyncPlayChanged { enabled: bool },
+
+    /// The player's last action was stored in the DM's inbox rather than
+    /// answered now (sent to the acting player)
+    ActionPosted { action_id: String },
+
+    /// The whole action inbox, oldest first (sent to DMs on join and
+    /// whenever it changes)
+    ActionInbox { actions: Vec<InboxAction> },
+
+    /// The DM approved the response to a posted action (sent to the player
+    /// who posted it, or held for them until they reconnect)
+    PostedActionAnswered { action_id: String },
+    // =========================================================================
+    // Advancement
+    // =========================================================================
+
+    /// A player character was awarded XP or a milestone
+    ///
+    /// Delivered to the character's player and the session's DMs.
+    AdvancementGranted {
+        pc_id: String,
+        pc_name: String,
+        grant: AdvancementGrant,
+        /// Total XP after the grant
+        experience: u32,
+        /// Current level (before any pending level-ups are taken)
+        level: u32,
+        /// Levels earned but not yet taken through the level-up flow
+        pending_levels: u32,
+    },
+
+    /// A level-up was applied to the character's sheet
+    LevelUpApplied {
+        pc_id: String,
+        pc_name: String,
+        level: u32,
+        /// Levels still waiting to be taken
+        #[serde(default)]
+        pending_levels: u32,
+    },
+
+    /// Annotations on a surface changed
+    ///
+    /// Carries everything on the surface. The DM gets every layer; players
+    /// only get layers visible to them.
+    AnnotationsUpdated {
+        surface: AnnotationSurface,
+        annotations: SurfaceAnnotations,
+    },
+
+    /// A region's lighting and weather changed (broadcast to all)
+    SceneMoodChanged { region_id: String, mood: SceneMood },
+
+    /// A DM changed the tone or preset (sent to the DM and co-DMs)
+    DirectorialContextChanged { context: DirectorialContext },
+
+    /// A region's background crowd changed (broadcast to all)
+    RegionPopulationChanged {
+        region_id: String,
+        population: RegionPopulation,
+    },
+
+    /// A camera move to play on the stage (broadcast to all)
+    CameraEffectPlayed { effect: CameraEffect },
+
+    /// A region's backdrop was swapped (broadcast to all)
+    RegionBackdropChanged {
+        region_id: String,
+        backdrop_asset: Option<String>,
+    },
+
+    /// Which map regions are revealed, sent on join and after every visit
+    /// or reveal
+    ///
+    /// DMs get every PC's reveals; players get the party's and their own.
+    MapRevealsChanged { reveals: MapReveals },
+
+    /// An NPC's memories (to DMs), after a request or any change
+    ///
+    /// Carries the NPC's full list.
+    NpcMemories {
+        npc_id: String,
+        memories: Vec<NpcMemory>,
+    },
+
+    /// An NPC's feelings toward the party changed, or the DM hid or showed
+    /// its disposition
+    ///
+    /// Players get no sentiment while it is hidden; DMs always do.
+    DispositionChanged {
+        npc_id: String,
+        #[serde(default)]
+        sentiment: Option<f32>,
+        #[serde(default)]
+        hidden: bool,
+    },
+
+    /// An encounter was staged (confirmation to DMs)
+    ///
+    /// The scene, NPC and challenge changes arrive as their usual messages.
+    EncounterStarted {
+        encounter_id: String,
+        encounter_name: String,
+    },
+
+    /// A craft attempt finished and the inventory was updated
+    ///
+    /// Delivered to the crafting character's player and the session's DMs.
+    /// The roll, if the recipe has one, arrives first as the usual
+    /// challenge messages.
+    CraftResolved { outcome: CraftOutcome },
+
+    /// A craft attempt was refused before any roll, e.g. for missing
+    /// ingredients (to the player who tried)
+    CraftRejected { recipe_id: String, reason: String },
+
+    /// A held item was identified; its hidden notes now come with the
+    /// holder's inventory (to the holder's player and the session's DMs)
+    ItemIdentified {
+        pc_id: String,
+        item_id: String,
+        item_name: String,
+    },
+
+    /// The DM started a recap; players play it over their stage
+    RecapPlaying { recap: SessionRecap },
+
+    /// A shared note has a new revision (to DMs), sent on join and after
+    /// every accepted edit
+    SharedNoteUpdated {
+        doc: SharedNoteDoc,
+        note: NoteRevision,
+    },
+
+    /// Who else is editing a shared note, and where (to DMs)
+    SharedNotePresence {
+        doc: SharedNoteDoc,
+        cursors: Vec<NoteCursor>,
+    },
+
+    /// A character's active conditions changed (broadcast to all)
+    ///
+    /// Carries the character's full list, so an empty list means the last
+    /// condition was removed or ran out.
+    ConditionsChanged {
+        character_id: String,
+        character_name: String,
+        conditions: Vec<ActiveCondition>,
+    },
+
+    /// Values on a player character's sheet changed (damage, conditions, ...)
+    ///
+    /// Delivered to the character's player and the session's DMs. Only the
+    /// changed fields are included.
+    PcSheetUpdated {
+        pc_id: String,
+        values: HashMap<String, FieldValue>,
+    },
+
+    // =========================================================================
+    // World Updates
+    // =========================================================================
+
+    /// The world was edited mid-session and a new snapshot is available
+    WorldSnapshotUpdated {
+        world_snapshot: serde_json::Value, // WorldSnapshot as JSON
+    },
+
+    // =========================================================================
+    // Dialogue Pacing
+    // =========================================================================
+
+    /// The DM changed how dialogue advances (broadcast to all)
+    DialoguePacingChanged { pacing: DialoguePacing },
+    /// Players who have finished reading the current line
+    DialogueReadUpdated { finished_user_ids: Vec<String> },
+    /// Paced dialogue was released; players may continue
+    DialogueAdvanced,
+
+    // =========================================================================
+    // Decision Timers
+    // =========================================================================
+
+    /// The DM changed the decision timer (broadcast to all)
+    DecisionTimerChanged { timer: DecisionTimerSettings },
+    /// A player's decision timer ran out (sent to DMs)
+    DecisionTimedOut {
+        user_id: String,
+        #[serde(default)]
+        character_name: Option<String>,
+        decision: TimedDecision,
+        defaulted: bool,
+    },
+
+    /// A message type this client doesn't know (from a newer Engine)
+    #[serde(other)]
+    Unknown,
 }
 
 /// Participant role in the session
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ParticipantRole {
     DungeonMaster,
+    /// Secondary DM with a restricted Director panel
+    CoDungeonMaster,
     Player,
     Spectator,
 }
@@ -539,6 +1224,26 @@ pub struct SceneCharacterState {
     pub is_speaking: bool,
     #[serde(default)]
     pub emotion: String,
+    /// Sentiment of the NPC's relationship with the party (-1.0 to 1.0);
+    /// `None` when there is none, or for players when the DM hid it
+    #[serde(default)]
+    pub sentiment: Option<f32>,
+    /// The DM hid this NPC's disposition from players
+    #[serde(default)]
+    pub disposition_hidden: bool,
+    /// Icon and accent colour picked by the DM
+    #[serde(flatten)]
+    pub style: EntityStyle,
+}
+
+impl SceneCharacterState {
+    /// Disposition meter players may see, if any
+    pub fn disposition(&self) -> Option<Disposition> {
+        if self.disposition_hidden {
+            return None;
+        }
+        self.sentiment.map(Disposition::from_sentiment)
+    }
 }
 
 /// Character position on screen
@@ -566,6 +1271,42 @@ pub struct DialogueChoice {
     pub id: String,
     pub text: String,
     pub is_custom_input: bool,
+    /// What the player's character needs for the choice to be available
+    #[serde(default)]
+    pub requirements: Vec<ChoiceRequirement>,
+    /// How the choice is shown while its requirements aren't met
+    #[serde(default)]
+    pub when_locked: LockedChoiceDisplay,
+}
+
+/// Something a character must have for a dialogue choice to be available
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChoiceRequirement {
+    /// A skill modifier of at least `min`; the skill is named or given by ID
+    Skill { skill: String, min: i32 },
+    /// An item in the character's inventory, by name or ID
+    Item { item: String },
+    /// A narrative event that has already happened
+    Event {
+        event_id: String,
+        /// Shown in the explanation instead of the ID
+        #[serde(default)]
+        event_name: String,
+    },
+}
+
+/// How a dialogue choice with unmet requirements is shown
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockedChoiceDisplay {
+    /// Not shown at all
+    Hidden,
+    /// Shown greyed out, without saying why
+    #[default]
+    Greyed,
+    /// Shown greyed out with the requirements in a tooltip
+    Explained,
 }
 
 /// Directorial context from DM
@@ -575,6 +1316,15 @@ pub struct DirectorialContext {
     pub tone: String,
     pub npc_motivations: Vec<NpcMotivationData>,
     pub forbidden_topics: Vec<String>,
+    /// Preset the tone and notes below came from, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset_name: Option<String>,
+    /// How NPCs should speak and describe things
+    #[serde(default)]
+    pub style_notes: String,
+    /// Content rules for the LLM to respect
+    #[serde(default)]
+    pub content_guidelines: Vec<String>,
 }
 
 /// NPC motivation data
@@ -646,6 +1396,19 @@ pub struct ParticipantInfo {
     pub user_id: String,
     pub role: ParticipantRole,
     pub character_name: Option<String>,
+    #[serde(default)]
+    pub presence_mode: PresenceMode,
+}
+
+/// A level-up a player has earned but not yet taken
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingLevelUpInfo {
+    pub pc_id: String,
+    pub pc_name: String,
+    /// Level before this level-up
+    pub level: u32,
+    /// Levels earned and waiting, including this one
+    pub pending_levels: u32,
 }
 
 /// Narrative event suggestion from LLM
@@ -667,6 +1430,268 @@ pub struct SplitPartyLocation {
     pub pc_names: Vec<String>,
 }
 
+/// How a participant appears in the presence strip
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PresenceMode {
+    /// No tile shown
+    #[default]
+    Off,
+    /// Animated avatar tile (no camera)
+    Avatar,
+    /// Live webcam tile
+    Webcam,
+}
+
+/// What spectators are allowed to see
+///
+/// The Engine filters spectator traffic where it can; the client applies the
+/// same rules to anything that still reaches a spectator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpectatorPolicy {
+    /// Drop player↔DM whispers
+    pub hide_whispers: bool,
+    /// Hide roll values, modifiers and totals (outcomes stay visible)
+    pub hide_challenge_numbers: bool,
+    /// Strip directorial notes and scene directions
+    pub hide_dm_notes: bool,
+    /// Delay the spectator feed by this many seconds
+    pub feed_delay_secs: u32,
+}
+
+/// A play-by-post action waiting in the DM's inbox
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InboxAction {
+    pub action_id: String,
+    pub user_id: String,
+    /// Name of the character who acted
+    pub pc_name: String,
+    pub action_type: String,
+    #[serde(default)]
+    pub target: Option<String>,
+    #[serde(default)]
+    pub dialogue: Option<String>,
+    /// When it was posted, in Unix seconds
+    pub posted_at: u64,
+    /// Sent for answering; the response is on its way or awaiting approval
+    #[serde(default)]
+    pub processing: bool,
+}
+
+impl InboxAction {
+    /// e.g. `talk Mira: "Any work going?"`
+    pub fn summary(&self) -> String {
+        let mut summary = self.action_type.clone();
+        if let Some(target) = &self.target {
+            summary.push(' ');
+            summary.push_str(target);
+        }
+        if let Some(dialogue) = &self.dialogue {
+            summary.push_str(&format!(": \"{}\"", dialogue));
+        }
+        summary
+    }
+}
+
+/// A scene running alongside others while the party is split
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StagedScene {
+    pub scene_id: String,
+    pub scene_name: String,
+    /// Players in this scene; each player is in at most one
+    #[serde(default)]
+    pub user_ids: Vec<String>,
+}
+
+/// How dialogue advances in group scenes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DialoguePacing {
+    /// Each player reads at their own pace
+    #[default]
+    Free,
+    /// Dialogue advances when the DM releases it
+    DmPaced,
+    /// Dialogue advances once every player has finished reading
+    AllPlayers,
+}
+
+impl DialoguePacing {
+    pub fn all() -> [Self; 3] {
+        [Self::Free, Self::DmPaced, Self::AllPlayers]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Free => "Free",
+            Self::DmPaced => "DM paced",
+            Self::AllPlayers => "Wait for all players",
+        }
+    }
+
+    /// Whether players wait for a release before continuing
+    pub fn is_paced(&self) -> bool {
+        !matches!(self, Self::Free)
+    }
+}
+
+/// Time limit on player decisions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DecisionTimerSettings {
+    /// Seconds players get to decide; `None` turns timers off
+    pub seconds: Option<u32>,
+    /// What happens when time runs out
+    pub on_expiry: TimerExpiry,
+}
+
+impl DecisionTimerSettings {
+    /// The time limit, if timers are on
+    pub fn limit(&self) -> Option<u32> {
+        self.seconds.filter(|s| *s > 0)
+    }
+}
+
+/// What happens when a decision timer runs out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimerExpiry {
+    /// Tell the DM and keep waiting for the player
+    #[default]
+    NotifyDm,
+    /// Take the first choice, or roll the suggested dice
+    DefaultAction,
+}
+
+impl TimerExpiry {
+    pub fn all() -> [Self; 2] {
+        [Self::NotifyDm, Self::DefaultAction]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::NotifyDm => "Notify DM",
+            Self::DefaultAction => "Take default action",
+        }
+    }
+}
+
+/// A decision that can time out
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum TimedDecision {
+    /// Picking a dialogue choice
+    Dialogue,
+    /// Rolling for a challenge
+    Challenge { challenge_id: String, challenge_name: String },
+}
+
+impl TimedDecision {
+    /// Short description for logs and notifications
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Dialogue => "dialogue choice".to_string(),
+            Self::Challenge { challenge_name, .. } => format!("roll for \"{}\"", challenge_name),
+        }
+    }
+}
+
+/// How long a condition lasts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum ConditionDuration {
+    /// A number of combat rounds
+    Rounds { rounds: u32 },
+    /// A number of in-game minutes
+    Minutes { minutes: u32 },
+    /// Until the DM removes it
+    UntilRemoved,
+}
+
+impl ConditionDuration {
+    /// Short description for badges and tooltips
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Rounds { rounds: 1 } => "1 round".to_string(),
+            Self::Rounds { rounds } => format!("{} rounds", rounds),
+            Self::Minutes { minutes } => format!("{} min", minutes),
+            Self::UntilRemoved => "Until removed".to_string(),
+        }
+    }
+}
+
+/// A bonus or penalty a condition gives to challenge rolls
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConditionModifier {
+    /// Skill name the modifier applies to (matched case-insensitively), or
+    /// `None` for every roll
+    #[serde(default)]
+    pub skill: Option<String>,
+    pub value: i32,
+}
+
+/// A condition the DM is applying, before the Engine assigns it an ID
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NewCondition {
+    pub name: String,
+    pub duration: ConditionDuration,
+    #[serde(default)]
+    pub modifiers: Vec<ConditionModifier>,
+}
+
+/// A condition currently affecting a character
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActiveCondition {
+    pub id: String,
+    pub name: String,
+    /// Time left, counted down by the Engine
+    pub duration: ConditionDuration,
+    #[serde(default)]
+    pub modifiers: Vec<ConditionModifier>,
+}
+
+/// An award from the DM that moves a character toward their next level
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum AdvancementGrant {
+    /// Experience points, for XP-based games
+    Experience { amount: u32 },
+    /// A story milestone, which grants a level outright
+    Milestone { name: String },
+}
+
+impl AdvancementGrant {
+    /// Short description for logs and notifications
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Experience { amount } => format!("{} XP", amount),
+            Self::Milestone { name } => format!("milestone \"{}\"", name),
+        }
+    }
+}
+
+/// What a player picked during a level-up
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelUpChoices {
+    /// Points added to each stat, keyed by stat abbreviation
+    #[serde(default)]
+    pub stat_increases: HashMap<String, u32>,
+    /// Skills learned this level
+    #[serde(default)]
+    pub new_skill_ids: Vec<String>,
+}
+
+/// WebRTC signaling payload relayed through the Engine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum PresenceSignalData {
+    Offer { sdp: String },
+    Answer { sdp: String },
+    IceCandidate {
+        candidate: String,
+        sdp_mid: Option<String>,
+        sdp_mline_index: Option<u16>,
+    },
+}
+
 /// Dice input type for challenge rolls
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", content = "value")]
@@ -675,6 +1700,54 @@ pub enum DiceInputType {
     Formula(String),
     /// Use a manual result (physical dice roll)
     Manual(i32),
+    /// Use physical dice entered one die at a time
+    ManualDice(ManualDiceEntry),
+}
+
+/// Physical dice results a player entered by hand
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManualDiceEntry {
+    /// Dice the challenge called for, e.g. "2d6"; any modifier in it is
+    /// added by the Engine as for a formula roll
+    pub formula: String,
+    /// Each die's face, in the order entered
+    pub dice: Vec<i32>,
+    /// Why the entry looks unlikely, for the DM's attention. Shown to the
+    /// player as they enter it; the DM's client works them out again
+    #[serde(default)]
+    pub flags: Vec<String>,
+}
+
+impl ManualDiceEntry {
+    pub fn dice_total(&self) -> i32 {
+        self.dice.iter().sum()
+    }
+
+    /// e.g. "2d6: 4, 5"
+    pub fn summary(&self) -> String {
+        let dice: Vec<String> = self.dice.iter().map(|d| d.to_string()).collect();
+        format!("{}: {}", self.formula, dice.join(", "))
+    }
+}
+
+/// A challenge roll the Engine resolved earlier in the session; the roll
+/// fields of ChallengeResolved
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResolvedRoll {
+    pub challenge_name: String,
+    pub character_name: String,
+    pub roll: i32,
+    pub modifier: i32,
+    pub total: i32,
+    pub outcome: String,
+    #[serde(default)]
+    pub roll_breakdown: Option<String>,
+    #[serde(default)]
+    pub individual_rolls: Option<Vec<i32>>,
+    #[serde(default)]
+    pub manual_entry: Option<ManualDiceEntry>,
+    /// Unix seconds when it was resolved
+    pub timestamp: u64,
 }
 
 /// Ad-hoc challenge outcomes for DM-created challenges
@@ -753,6 +1826,12 @@ pub struct SceneRegionInfo {
     pub backdrop_asset: Option<String>,
     /// Atmosphere description
     pub atmosphere: Option<String>,
+    /// Lighting and weather the DM set for the region
+    #[serde(default)]
+    pub mood: SceneMood,
+    /// Background crowd drawn behind the characters
+    #[serde(default)]
+    pub population: RegionPopulation,
 }
 
 /// NPC presence data for scene display
@@ -802,3 +1881,53 @@ pub struct NavigationExit {
     /// Description of the exit
     pub description: Option<String>,
 }
+
+/// Per-request image generation parameter overrides
+///
+/// Each field overrides the corresponding workflow slot default when set;
+/// unset fields fall back to the slot's configuration.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GenerationParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub steps: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cfg_scale: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+}
+
+impl GenerationParams {
+    /// Whether no overrides are set
+    pub fn is_empty(&self) -> bool {
+        self.seed.is_none()
+            && self.steps.is_none()
+            && self.cfg_scale.is_none()
+            && self.width.is_none()
+            && self.height.is_none()
+    }
+
+    /// Short human-readable summary (e.g. "seed 42 · 30 steps · CFG 7.5 · 1024×768")
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(seed) = self.seed {
+            parts.push(format!("seed {}", seed));
+        }
+        if let Some(steps) = self.steps {
+            parts.push(format!("{} steps", steps));
+        }
+        if let Some(cfg) = self.cfg_scale {
+            parts.push(format!("CFG {:.1}", cfg));
+        }
+        match (self.width, self.height) {
+            (Some(w), Some(h)) => parts.push(format!("{}×{}", w, h)),
+            (Some(w), None) => parts.push(format!("width {}", w)),
+            (None, Some(h)) => parts.push(format!("height {}", h)),
+            (None, None) => {}
+        }
+        parts.join(" · ")
+    }
+}
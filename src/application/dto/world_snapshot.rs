@@ -7,6 +7,12 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::camera_effect::CameraEffect;
+use super::entity_style::EntityStyle;
+use super::faction::Faction;
+use super::rich_text::{EntityMention, MentionKind};
+use super::routine::RoutineEntry;
+
 /// Complete snapshot of a world from the Engine
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorldSnapshot {
@@ -99,9 +105,38 @@ pub struct SessionWorldSnapshot {
     pub scenes: Vec<SessionSceneData>,
     /// The current active scene (if any)
     pub current_scene: Option<SessionSceneData>,
+    /// All items in the world, when the Engine includes them
+    #[serde(default)]
+    pub items: Vec<ItemData>,
+    /// All challenges in the world, when the Engine includes them
+    #[serde(default)]
+    pub challenges: Vec<ChallengeData>,
+    /// All factions in the world, when the Engine includes them
+    #[serde(default)]
+    pub factions: Vec<Faction>,
 }
 
 impl SessionWorldSnapshot {
+    /// Characters, locations, and items that can be `@`-mentioned
+    pub fn mention_candidates(&self) -> Vec<EntityMention> {
+        let characters = self.characters.iter().map(|c| EntityMention {
+            kind: MentionKind::Character,
+            id: c.id.clone(),
+            label: c.name.clone(),
+        });
+        let locations = self.locations.iter().map(|l| EntityMention {
+            kind: MentionKind::Location,
+            id: l.id.clone(),
+            label: l.name.clone(),
+        });
+        let items = self.items.iter().map(|i| EntityMention {
+            kind: MentionKind::Item,
+            id: i.id.clone(),
+            label: i.name.clone(),
+        });
+        characters.chain(locations).chain(items).collect()
+    }
+
     /// Get a location by ID
     pub fn get_location(&self, id: &str) -> Option<&SessionLocationData> {
         self.locations.iter().find(|l| l.id == id)
@@ -130,18 +165,27 @@ pub struct SessionWorldData {
 }
 
 /// Location data for session snapshots (simplified)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SessionLocationData {
     pub id: String,
     pub name: String,
     pub description: String,
     pub location_type: String,
     pub backdrop_asset: Option<String>,
+    /// Looping video or animated image shown in place of the static backdrop
+    #[serde(default)]
+    pub animated_backdrop_asset: Option<String>,
     pub parent_id: Option<String>,
+    /// Icon and accent colour picked by the DM
+    #[serde(flatten)]
+    pub style: EntityStyle,
+    /// When the location was moved to the trash; the Engine may still send it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
 }
 
 /// Character data for session snapshots (simplified)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SessionCharacterData {
     pub id: String,
     pub name: String,
@@ -151,10 +195,19 @@ pub struct SessionCharacterData {
     pub portrait_asset: Option<String>,
     pub is_alive: bool,
     pub is_active: bool,
+    /// Where the character is at each time of day
+    #[serde(default)]
+    pub routine: Vec<RoutineEntry>,
+    /// Icon and accent colour picked by the DM
+    #[serde(flatten)]
+    pub style: EntityStyle,
+    /// When the character was moved to the trash; the Engine may still send it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
 }
 
 /// Scene data for session snapshots (simplified)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SessionSceneData {
     pub id: String,
     pub name: String,
@@ -198,6 +251,9 @@ pub struct RuleSystemConfig {
     pub dice_system: DiceSystem,
     pub success_comparison: SuccessComparison,
     pub skill_check_formula: String,
+    /// Slots items can be equipped into
+    #[serde(default = "default_equipment_slots")]
+    pub equipment_slots: Vec<EquipmentSlotDefinition>,
 }
 
 impl Default for RuleSystemConfig {
@@ -211,10 +267,45 @@ impl Default for RuleSystemConfig {
             dice_system: DiceSystem::D20,
             success_comparison: SuccessComparison::GreaterOrEqual,
             skill_check_formula: "1d20 + modifier vs DC".to_string(),
+            equipment_slots: default_equipment_slots(),
         }
     }
 }
 
+/// An equipment slot defined by a rule system (e.g. weapon, armor)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EquipmentSlotDefinition {
+    pub id: String,
+    pub name: String,
+    /// Item types that fit this slot (matched case-insensitively)
+    #[serde(default)]
+    pub item_types: Vec<String>,
+}
+
+impl EquipmentSlotDefinition {
+    fn new(id: &str, name: &str, item_types: &[&str]) -> Self {
+        Self {
+            id: id.to_string(),
+            name: name.to_string(),
+            item_types: item_types.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
+    /// Whether an item of this type can go in the slot
+    pub fn accepts(&self, item_type: &str) -> bool {
+        self.item_types.iter().any(|t| t.eq_ignore_ascii_case(item_type))
+    }
+}
+
+/// Weapon, armor, and trinket slots, used when a rule system defines none
+pub fn default_equipment_slots() -> Vec<EquipmentSlotDefinition> {
+    vec![
+        EquipmentSlotDefinition::new("weapon", "Weapon", &["Weapon"]),
+        EquipmentSlotDefinition::new("armor", "Armor", &["Armor", "Shield"]),
+        EquipmentSlotDefinition::new("trinket", "Trinket", &["Trinket", "Accessory", "Ring", "Amulet"]),
+    ]
+}
+
 /// Response wrapper for rule system preset details (matches Engine's RuleSystemPresetDetailsDto)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuleSystemPresetDetails {
@@ -447,6 +538,12 @@ pub struct ChallengeData {
     pub order: u32,
     pub is_favorite: bool,
     pub tags: Vec<String>,
+    /// Name of the shared catalog this challenge was copied from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_catalog: Option<String>,
+    /// When the challenge was moved to the trash, if it has been
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
 }
 
 /// Types of challenges
@@ -532,6 +629,60 @@ pub struct Outcome {
     pub description: String,
     #[serde(default)]
     pub triggers: Vec<OutcomeTrigger>,
+    /// Sound played on players' clients when this outcome lands
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_cue: Option<AudioCue>,
+    /// Camera move played on the stage when this outcome lands
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub camera_effect: Option<CameraEffect>,
+}
+
+/// How an audio cue plays
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioCueKind {
+    /// Short one-shot accent
+    #[default]
+    Sting,
+    /// Longer one-shot celebration
+    Fanfare,
+    /// Replaces the looping ambient track
+    Ambient,
+}
+
+impl AudioCueKind {
+    pub fn all() -> [Self; 3] {
+        [Self::Sting, Self::Fanfare, Self::Ambient]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Sting => "Sting",
+            Self::Fanfare => "Fanfare",
+            Self::Ambient => "Ambient switch",
+        }
+    }
+}
+
+/// A sound attached to a narrative event or challenge outcome
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioCue {
+    pub kind: AudioCueKind,
+    /// Audio file URL or asset path
+    pub asset: String,
+    /// Playback volume, 0.0 to 1.0
+    #[serde(default = "default_cue_volume")]
+    pub volume: f32,
+}
+
+fn default_cue_volume() -> f32 {
+    1.0
+}
+
+impl AudioCue {
+    pub fn new(kind: AudioCueKind, asset: impl Into<String>) -> Self {
+        Self { kind, asset: asset.into(), volume: default_cue_volume() }
+    }
 }
 
 /// Effects triggered by challenge outcomes
@@ -547,6 +698,33 @@ pub enum OutcomeTrigger {
     Custom { description: String },
 }
 
+impl OutcomeTrigger {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::RevealInformation { .. } => "Reveal Information",
+            Self::EnableChallenge { .. } => "Enable Challenge",
+            Self::DisableChallenge { .. } => "Disable Challenge",
+            Self::ModifyCharacterStat { .. } => "Modify Stat",
+            Self::TriggerScene { .. } => "Trigger Scene",
+            Self::GiveItem { .. } => "Give Item",
+            Self::Custom { .. } => "Custom",
+        }
+    }
+
+    /// One empty trigger of each kind, for pickers
+    pub fn templates() -> Vec<Self> {
+        vec![
+            Self::RevealInformation { info: String::new(), persist: true },
+            Self::EnableChallenge { challenge_id: String::new() },
+            Self::DisableChallenge { challenge_id: String::new() },
+            Self::ModifyCharacterStat { stat: String::new(), modifier: 0 },
+            Self::TriggerScene { scene_id: String::new() },
+            Self::GiveItem { item_name: String::new(), item_description: None },
+            Self::Custom { description: String::new() },
+        ]
+    }
+}
+
 /// Condition that triggers LLM to suggest a challenge
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TriggerCondition {
@@ -566,9 +744,106 @@ pub enum TriggerType {
     ChallengeComplete { challenge_id: String, requires_success: Option<bool> },
     TimeBased { turns: u32 },
     NpcPresent { npc_keywords: Vec<String> },
+    /// A player character enters a specific region
+    EnterRegion { region_id: String },
+    /// A player character starts a conversation with a specific NPC
+    TalkToNpc { npc_id: String },
+    /// A player character picks up a specific item
+    ItemAcquired { item_id: String },
+    /// A narrative event has fired
+    StoryEventOccurred { narrative_event_id: String },
     Custom { description: String },
 }
 
+impl TriggerType {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::ObjectInteraction { .. } => "Object Interaction",
+            Self::EnterArea { .. } => "Enter Area",
+            Self::DialogueTopic { .. } => "Dialogue Topic",
+            Self::ChallengeComplete { .. } => "Challenge Complete",
+            Self::TimeBased { .. } => "After Turns",
+            Self::NpcPresent { .. } => "NPC Present",
+            Self::EnterRegion { .. } => "Enter Region",
+            Self::TalkToNpc { .. } => "Talk to NPC",
+            Self::ItemAcquired { .. } => "Item Acquired",
+            Self::StoryEventOccurred { .. } => "Story Event",
+            Self::Custom { .. } => "Custom",
+        }
+    }
+
+    /// One empty condition of each kind, for pickers
+    ///
+    /// Conditions the Engine can check by ID come first.
+    pub fn templates() -> Vec<Self> {
+        vec![
+            Self::EnterRegion { region_id: String::new() },
+            Self::TalkToNpc { npc_id: String::new() },
+            Self::ItemAcquired { item_id: String::new() },
+            Self::StoryEventOccurred { narrative_event_id: String::new() },
+            Self::ChallengeComplete { challenge_id: String::new(), requires_success: None },
+            Self::TimeBased { turns: 1 },
+            Self::ObjectInteraction { keywords: Vec::new() },
+            Self::EnterArea { area_keywords: Vec::new() },
+            Self::DialogueTopic { topic_keywords: Vec::new() },
+            Self::NpcPresent { npc_keywords: Vec::new() },
+            Self::Custom { description: String::new() },
+        ]
+    }
+
+    /// Why the condition can't be checked as written, if it can't
+    pub fn problem(&self) -> Option<&'static str> {
+        match self {
+            Self::EnterRegion { region_id } if region_id.is_empty() => Some("needs a region"),
+            Self::TalkToNpc { npc_id } if npc_id.is_empty() => Some("needs an NPC"),
+            Self::ItemAcquired { item_id } if item_id.is_empty() => Some("needs an item"),
+            Self::StoryEventOccurred { narrative_event_id } if narrative_event_id.is_empty() => {
+                Some("needs a narrative event")
+            }
+            Self::ChallengeComplete { challenge_id, .. } if challenge_id.is_empty() => Some("needs a challenge"),
+            Self::TimeBased { turns: 0 } => Some("needs at least one turn"),
+            Self::ObjectInteraction { keywords: k }
+            | Self::EnterArea { area_keywords: k }
+            | Self::DialogueTopic { topic_keywords: k }
+            | Self::NpcPresent { npc_keywords: k }
+                if k.iter().all(|keyword| keyword.trim().is_empty()) =>
+            {
+                Some("needs at least one keyword")
+            }
+            Self::Custom { description } if description.trim().is_empty() => Some("needs a description"),
+            _ => None,
+        }
+    }
+}
+
+impl TriggerCondition {
+    pub fn new(condition_type: TriggerType) -> Self {
+        Self { condition_type, description: String::new(), required: false }
+    }
+
+    /// Short label for lists: the description, or the condition type
+    pub fn summary(&self) -> String {
+        if self.description.trim().is_empty() {
+            self.condition_type.display_name().to_string()
+        } else {
+            self.description.trim().to_string()
+        }
+    }
+}
+
+/// Validation errors for a challenge's trigger conditions, one per problem
+pub fn trigger_condition_errors(conditions: &[TriggerCondition]) -> Vec<String> {
+    conditions
+        .iter()
+        .enumerate()
+        .filter_map(|(index, condition)| {
+            condition.condition_type.problem().map(|problem| {
+                format!("Trigger {} ({}) {}", index + 1, condition.condition_type.display_name(), problem)
+            })
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActData {
     pub id: String,
@@ -607,6 +882,9 @@ pub struct CharacterData {
     pub is_active: bool,
     pub stats: serde_json::Value,
     pub wants: Vec<WantData>,
+    /// Where the character is at each time of day
+    #[serde(default)]
+    pub routine: Vec<RoutineEntry>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -626,6 +904,8 @@ pub struct LocationData {
     pub description: String,
     pub location_type: String,
     pub backdrop_asset: Option<String>,
+    #[serde(default)]
+    pub animated_backdrop_asset: Option<String>,
     pub grid_map_id: Option<String>,
     pub backdrop_regions: Vec<BackdropRegionData>,
 }
@@ -657,14 +937,107 @@ impl BackdropRegionData {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RelationshipData {
     pub id: String,
     pub from_character_id: String,
     pub to_character_id: String,
+    /// A [`RelationshipKind`] name; older worlds may hold free text
     pub relationship_type: String,
     pub sentiment: f32,
     pub known_to_player: bool,
+    /// How strong the tie is, 1 (passing) to 5 (defining)
+    #[serde(default = "default_relationship_strength")]
+    pub strength: u8,
+    #[serde(default)]
+    pub notes: String,
+}
+
+fn default_relationship_strength() -> u8 {
+    3
+}
+
+impl RelationshipData {
+    pub fn kind(&self) -> RelationshipKind {
+        RelationshipKind::from_type(&self.relationship_type)
+    }
+
+    /// The character on the other end from `character_id`
+    pub fn other_end(&self, character_id: &str) -> &str {
+        if self.from_character_id == character_id {
+            &self.to_character_id
+        } else {
+            &self.from_character_id
+        }
+    }
+}
+
+/// Typed relationship kinds the editor offers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelationshipKind {
+    Ally,
+    Rival,
+    Family,
+    /// One owes the other
+    Debt,
+    /// Anything else, including free-text types from older worlds
+    Other,
+}
+
+impl RelationshipKind {
+    pub fn all() -> [Self; 5] {
+        [Self::Ally, Self::Rival, Self::Family, Self::Debt, Self::Other]
+    }
+
+    pub fn from_type(relationship_type: &str) -> Self {
+        match relationship_type.trim().to_ascii_lowercase().as_str() {
+            "ally" => Self::Ally,
+            "rival" => Self::Rival,
+            "family" => Self::Family,
+            "debt" => Self::Debt,
+            _ => Self::Other,
+        }
+    }
+
+    /// Value stored in `relationship_type`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ally => "ally",
+            Self::Rival => "rival",
+            Self::Family => "family",
+            Self::Debt => "debt",
+            Self::Other => "other",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Ally => "Ally",
+            Self::Rival => "Rival",
+            Self::Family => "Family",
+            Self::Debt => "Debt",
+            Self::Other => "Other",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            Self::Ally => "🤝",
+            Self::Rival => "⚔️",
+            Self::Family => "🏠",
+            Self::Debt => "💰",
+            Self::Other => "🔗",
+        }
+    }
+
+    /// Sentiment a new relationship of this kind starts with
+    pub fn default_sentiment(&self) -> f32 {
+        match self {
+            Self::Ally | Self::Family => 0.5,
+            Self::Rival => -0.5,
+            Self::Debt | Self::Other => 0.0,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -693,6 +1066,22 @@ pub struct SheetTemplate {
     pub is_default: bool,
 }
 
+impl SheetTemplate {
+    /// Fields flagged as vital, in sheet order
+    pub fn vital_fields(&self) -> Vec<&SheetField> {
+        let mut sections: Vec<&SheetSection> = self.sections.iter().collect();
+        sections.sort_by_key(|s| s.order);
+        sections
+            .into_iter()
+            .flat_map(|section| {
+                let mut fields: Vec<&SheetField> = section.fields.iter().filter(|f| f.vital).collect();
+                fields.sort_by_key(|f| f.order);
+                fields
+            })
+            .collect()
+    }
+}
+
 /// A section in the character sheet
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SheetSection {
@@ -740,6 +1129,9 @@ pub struct SheetField {
     pub read_only: bool,
     #[serde(default)]
     pub order: u32,
+    /// Shown at a glance in the DM's party overview (HP, conditions, ...)
+    #[serde(default)]
+    pub vital: bool,
 }
 
 /// Field type with configuration
@@ -823,12 +1215,33 @@ pub enum FieldValue {
     },
 }
 
+impl FieldValue {
+    /// Compact text for the value, e.g. "12/20" for a resource
+    pub fn summary(&self) -> String {
+        match self {
+            Self::Number(n) => n.to_string(),
+            Self::Text(text) => text.clone(),
+            Self::Boolean(true) => "Yes".to_string(),
+            Self::Boolean(false) => "No".to_string(),
+            Self::Resource { current, max } => format!("{}/{}", current, max),
+            Self::List(items) if items.is_empty() => "None".to_string(),
+            Self::List(items) => items.join(", "),
+            Self::SkillEntry { bonus, .. } => format!("{:+}", bonus),
+        }
+    }
+
+    /// Whether a resource is at a quarter of its maximum or less
+    pub fn is_low(&self) -> bool {
+        matches!(self, Self::Resource { current, max } if *max > 0 && current * 4 <= *max)
+    }
+}
+
 // =============================================================================
 // Story Event Types (Phase 17)
 // =============================================================================
 
 /// A story event - an immutable record of something that happened during gameplay
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StoryEventData {
     pub id: String,
     pub world_id: String,
@@ -846,10 +1259,65 @@ pub struct StoryEventData {
     /// Human-readable event type name from Engine
     #[serde(default)]
     pub type_name: String,
+    /// Conversation or roll the event was recorded from
+    #[serde(default)]
+    pub source: Option<StoryEventSourceData>,
+}
+
+impl StoryEventData {
+    /// Where the event came from, if it can be traced back
+    ///
+    /// Events from older Engines carry no source; dialogue and challenge
+    /// events fall back to one built from their own details and timestamp.
+    /// Challenge events only record the character's ID, so `character_name`
+    /// looks up the name the roll was indexed under.
+    pub fn source_reference(&self, character_name: impl Fn(&str) -> Option<String>) -> Option<StoryEventSourceData> {
+        if let Some(source) = &self.source {
+            return Some(source.clone());
+        }
+        let at = chrono::DateTime::parse_from_rfc3339(&self.timestamp).ok()?.timestamp().max(0) as u64;
+        match &self.event_type {
+            StoryEventTypeData::DialogueExchange { npc_id, npc_name, .. } => Some(StoryEventSourceData::Conversation {
+                npc_id: npc_id.clone(),
+                npc_name: npc_name.clone(),
+                started_at: at,
+                ended_at: at,
+            }),
+            StoryEventTypeData::ChallengeAttempted { challenge_id: Some(challenge_id), character_id, .. } => {
+                Some(StoryEventSourceData::Challenge {
+                    challenge_id: challenge_id.clone(),
+                    character_name: character_name(character_id).unwrap_or_else(|| character_id.clone()),
+                    resolved_at: at,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// What a story event was recorded from, for linking back to it
+///
+/// Times are Unix seconds, matched against the session's conversation index.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StoryEventSourceData {
+    /// A stretch of conversation with an NPC
+    Conversation {
+        npc_id: String,
+        npc_name: String,
+        started_at: u64,
+        ended_at: u64,
+    },
+    /// A resolved challenge roll; the character is named, as in `ChallengeResolved`
+    Challenge {
+        challenge_id: String,
+        character_name: String,
+        resolved_at: u64,
+    },
 }
 
 /// Categories of story events
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum StoryEventTypeData {
     LocationChange {
@@ -935,6 +1403,12 @@ pub enum StoryEventTypeData {
         duration_minutes: u32,
         summary: String,
     },
+    SessionPaused {
+        message: Option<String>,
+    },
+    SessionResumed {
+        paused_minutes: u32,
+    },
     Custom {
         event_subtype: String,
         title: String,
@@ -995,8 +1469,55 @@ pub struct NarrativeEventData {
     pub chain_position: Option<u32>,
     pub outcome_count: usize,
     pub trigger_condition_count: usize,
+    /// Trigger conditions, when the Engine includes them
+    #[serde(default)]
+    pub trigger_conditions: Vec<NarrativeTriggerData>,
+    /// Possible outcomes, when the Engine includes them
+    #[serde(default)]
+    pub outcomes: Vec<NarrativeOutcomeData>,
+    /// Sound played on players' clients when the event fires
+    #[serde(default)]
+    pub audio_cue: Option<AudioCue>,
+    /// Camera move played on the stage when the event fires
+    #[serde(default)]
+    pub camera_effect: Option<CameraEffect>,
     pub created_at: String,
     pub updated_at: String,
+    /// When the event was moved to the trash, if it has been
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+    /// When the event was archived, if it is
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+}
+
+/// A trigger condition on a narrative event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NarrativeTriggerData {
+    /// Trigger kind, e.g. "event_completed" or "player_enters_location"
+    pub trigger_type: String,
+    /// Human-readable description of the condition
+    pub description: String,
+    /// Narrative event that must have fired, for "event_completed" triggers
+    #[serde(default)]
+    pub event_id: Option<String>,
+    /// Outcome that event must have resolved with, if any
+    #[serde(default)]
+    pub outcome_name: Option<String>,
+    #[serde(default)]
+    pub is_required: bool,
+}
+
+/// A possible outcome of a narrative event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NarrativeOutcomeData {
+    pub name: String,
+    pub label: String,
+    #[serde(default)]
+    pub description: String,
+    /// Narrative events this outcome queues up next
+    #[serde(default)]
+    pub chain_events: Vec<String>,
 }
 
 /// Request to create a new narrative event
@@ -1021,6 +1542,10 @@ pub struct CreateNarrativeEventRequest {
     pub is_active: bool,
     #[serde(default)]
     pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audio_cue: Option<AudioCue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub camera_effect: Option<CameraEffect>,
 }
 
 fn default_active() -> bool {
@@ -1040,6 +1565,8 @@ impl Default for CreateNarrativeEventRequest {
             priority: 0,
             is_active: true,
             tags: Vec::new(),
+            audio_cue: None,
+            camera_effect: None,
         }
     }
 }
@@ -1082,6 +1609,40 @@ pub struct ItemData {
     pub item_type: Option<String>,
     pub is_unique: bool,
     pub properties: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Bonuses the item grants while equipped
+    #[serde(default)]
+    pub modifiers: Vec<ItemModifier>,
+    /// Icon and accent colour picked by the DM
+    #[serde(flatten)]
+    pub style: EntityStyle,
+    /// Item art, shown in the detail view
+    #[serde(default)]
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub weight: Option<f32>,
+    /// Worth in the world's currency
+    #[serde(default)]
+    pub value: Option<u32>,
+    /// What the DM wrote about the item's true nature. The Engine leaves it
+    /// out of a player's copy until that copy is identified
+    #[serde(default)]
+    pub hidden_notes: Option<String>,
+    /// Whether the item has hidden notes, sent even while they are withheld
+    #[serde(default)]
+    pub has_hidden_notes: bool,
+    /// When the item was moved to the trash, if it has been
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+}
+
+/// A bonus (or penalty) an equipped item applies to a stat or skill
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemModifier {
+    /// Stat abbreviation, sheet field name, or skill name (matched case-insensitively)
+    pub target: String,
+    pub value: i32,
 }
 
 /// Inventory item - item with possession metadata
@@ -1090,8 +1651,20 @@ pub struct InventoryItemData {
     pub item: ItemData,
     pub quantity: u32,
     pub equipped: bool,
+    /// Equipment slot the item occupies while equipped
+    #[serde(default)]
+    pub slot: Option<String>,
     pub acquired_at: String,
     pub acquisition_method: Option<String>,
+    /// Who the item came from
+    #[serde(default)]
+    pub acquired_from: Option<String>,
+    /// Where the item was found or handed over
+    #[serde(default)]
+    pub acquired_location: Option<String>,
+    /// Whether the holder has learned the item's hidden notes
+    #[serde(default)]
+    pub identified: bool,
 }
 
 impl InventoryItemData {
@@ -1119,5 +1692,152 @@ impl InventoryItemData {
     pub fn is_quest(&self) -> bool {
         self.item.item_type.as_deref() == Some("Quest")
     }
+
+    /// Where the item came from, e.g. "Acquired from Mira at the Docks"
+    pub fn provenance(&self) -> Option<String> {
+        let from = self.acquired_from.as_deref().filter(|s| !s.is_empty());
+        let at = self.acquired_location.as_deref().filter(|s| !s.is_empty());
+        match (from, at) {
+            (Some(from), Some(at)) => Some(format!("Acquired from {} at {}", from, at)),
+            (Some(from), None) => Some(format!("Acquired from {}", from)),
+            (None, Some(at)) => Some(format!("Acquired at {}", at)),
+            (None, None) => None,
+        }
+    }
+
+    /// The item still has notes its holder hasn't learned
+    pub fn is_unidentified(&self) -> bool {
+        !self.identified
+            && (self.item.has_hidden_notes
+                || self.item.hidden_notes.as_deref().is_some_and(|n| !n.trim().is_empty()))
+    }
+
+    /// Hidden notes, once the holder has identified the item
+    pub fn revealed_notes(&self) -> Option<&str> {
+        self.item.hidden_notes.as_deref().filter(|_| self.identified)
+    }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trigger_condition_errors_name_incomplete_conditions() {
+        let mut region = TriggerCondition::new(TriggerType::EnterRegion { region_id: "r-1".to_string() });
+        region.description = "Enters the vault".to_string();
+        let conditions = vec![
+            region,
+            TriggerCondition::new(TriggerType::TalkToNpc { npc_id: String::new() }),
+            TriggerCondition::new(TriggerType::DialogueTopic { topic_keywords: vec![" ".to_string()] }),
+        ];
+
+        assert_eq!(conditions[0].summary(), "Enters the vault");
+        assert_eq!(conditions[1].summary(), "Talk to NPC");
+        assert_eq!(
+            trigger_condition_errors(&conditions),
+            vec![
+                "Trigger 2 (Talk to NPC) needs an NPC".to_string(),
+                "Trigger 3 (Dialogue Topic) needs at least one keyword".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn id_trigger_types_round_trip() {
+        let condition = TriggerCondition::new(TriggerType::ItemAcquired { item_id: "item-7".to_string() });
+        let json = serde_json::to_value(&condition).unwrap();
+        assert_eq!(json["condition_type"], serde_json::json!({ "type": "item_acquired", "item_id": "item-7" }));
+        assert_eq!(serde_json::from_value::<TriggerCondition>(json).unwrap(), condition);
+    }
+
+    fn field(id: &str, order: u32, vital: bool) -> SheetField {
+        SheetField {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: None,
+            field_type: FieldType::Checkbox { default: false },
+            required: false,
+            read_only: false,
+            order,
+            vital,
+        }
+    }
+
+    fn section(order: u32, fields: Vec<SheetField>) -> SheetSection {
+        SheetSection {
+            id: format!("s{}", order),
+            name: format!("Section {}", order),
+            description: None,
+            fields,
+            layout: SectionLayout::Vertical,
+            collapsible: false,
+            collapsed_by_default: false,
+            order,
+        }
+    }
+
+    #[test]
+    fn vital_fields_follow_sheet_order() {
+        let template = SheetTemplate {
+            id: "t".to_string(),
+            world_id: "w".to_string(),
+            name: "Sheet".to_string(),
+            description: String::new(),
+            variant: "custom".to_string(),
+            sections: vec![
+                section(2, vec![field("conditions", 0, true), field("notes", 1, false)]),
+                section(1, vec![field("ac", 2, true), field("hp", 1, true)]),
+            ],
+            is_default: true,
+        };
+        let ids: Vec<&str> = template.vital_fields().iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(ids, vec!["hp", "ac", "conditions"]);
+    }
+
+    #[test]
+    fn vital_flag_defaults_to_off() {
+        let field: SheetField = serde_json::from_value(serde_json::json!({
+            "id": "hp",
+            "name": "HP",
+            "field_type": { "type": "checkbox", "default": false }
+        }))
+        .unwrap();
+        assert!(!field.vital);
+    }
+
+    #[test]
+    fn field_value_summaries() {
+        assert_eq!(FieldValue::Resource { current: 4, max: 20 }.summary(), "4/20");
+        assert!(FieldValue::Resource { current: 4, max: 20 }.is_low());
+        assert!(!FieldValue::Resource { current: 6, max: 20 }.is_low());
+        assert_eq!(FieldValue::List(vec!["Poisoned".into(), "Prone".into()]).summary(), "Poisoned, Prone");
+        assert_eq!(FieldValue::List(Vec::new()).summary(), "None");
+    }
+
+    #[test]
+    fn item_provenance_and_identification() {
+        let mut held: InventoryItemData = serde_json::from_value(serde_json::json!({
+            "item": {
+                "id": "i-1", "world_id": "w-1", "name": "Black Dagger", "description": null,
+                "item_type": "Weapon", "is_unique": true, "properties": null,
+                "has_hidden_notes": true
+            },
+            "quantity": 1, "equipped": false, "acquired_at": "2026-01-01", "acquisition_method": "Gift",
+            "acquired_from": "Mira"
+        }))
+        .unwrap();
+
+        assert_eq!(held.provenance().as_deref(), Some("Acquired from Mira"));
+        held.acquired_location = Some("the Docks".to_string());
+        assert_eq!(held.provenance().as_deref(), Some("Acquired from Mira at the Docks"));
+
+        assert!(held.is_unidentified());
+        assert_eq!(held.revealed_notes(), None);
+        held.identified = true;
+        held.item.hidden_notes = Some("Cursed: it drinks the wielder's blood".to_string());
+        assert!(!held.is_unidentified());
+        assert_eq!(held.revealed_notes(), Some("Cursed: it drinks the wielder's blood"));
+    }
+}
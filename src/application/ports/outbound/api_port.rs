@@ -4,32 +4,101 @@
 //! allowing application services to interact with the API without
 //! depending on concrete HTTP client implementations.
 
-use serde::{de::DeserializeOwned, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
 use std::fmt;
 
 /// Error type for API operations
-#[derive(Debug, Clone)]
+///
+/// Each variant is a kind of failure the UI handles differently: network
+/// trouble and timeouts are retried, rejected requests are shown to the
+/// user as-is, and server errors count towards opening an endpoint's
+/// circuit. `Display` is the user-facing text; `Debug` keeps the detail
+/// for logs.
+#[derive(Debug, Clone, PartialEq)]
 pub enum ApiError {
-    /// Network request failed
-    RequestFailed(String),
-    /// Server returned non-success status
-    HttpError(u16, String),
-    /// Failed to parse response JSON
-    ParseError(String),
-    /// Failed to serialize request body
-    SerializeError(String),
+    /// The request never got a response (offline, DNS, connection refused)
+    Network(String),
+    /// No response within the request time limit
+    Timeout,
+    /// The Engine rejected the request (4xx other than 404)
+    Validation { status: u16, message: String },
     /// Resource not found (404)
     NotFound(String),
+    /// The Engine failed to handle the request (5xx)
+    Server { status: u16, message: String },
+    /// Failed to parse response JSON
+    Deserialize(String),
+    /// Failed to serialize request body
+    Serialize(String),
+    /// Not sent: the endpoint failed repeatedly and is paused
+    CircuitOpen { endpoint: String, retry_in_ms: u64 },
+}
+
+impl ApiError {
+    /// Error for a non-success HTTP status
+    pub fn from_status(status: u16, message: impl Into<String>) -> Self {
+        let message = message.into();
+        match status {
+            404 => Self::NotFound(message),
+            400..=499 => Self::Validation { status, message },
+            _ => Self::Server { status, message },
+        }
+    }
+
+    /// HTTP status, for errors that came with one
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Validation { status, .. } | Self::Server { status, .. } => Some(*status),
+            Self::NotFound(_) => Some(404),
+            _ => None,
+        }
+    }
+
+    /// Whether the same request may succeed if sent again
+    ///
+    /// Only safe to act on for idempotent requests.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Network(_) | Self::Timeout => true,
+            Self::Server { status, .. } => matches!(status, 500 | 502 | 503 | 504),
+            Self::Validation { status, .. } => *status == 429,
+            _ => false,
+        }
+    }
+
+    /// Whether this counts as the endpoint being unhealthy
+    ///
+    /// Rejected and missing requests are the caller's problem, not the
+    /// endpoint's, so they never open a circuit.
+    pub fn is_endpoint_failure(&self) -> bool {
+        matches!(self, Self::Network(_) | Self::Timeout | Self::Server { .. })
+    }
 }
 
 impl fmt::Display for ApiError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ApiError::RequestFailed(msg) => write!(f, "Request failed: {}", msg),
-            ApiError::HttpError(status, msg) => write!(f, "HTTP {}: {}", status, msg),
-            ApiError::ParseError(msg) => write!(f, "Parse error: {}", msg),
-            ApiError::SerializeError(msg) => write!(f, "Serialize error: {}", msg),
-            ApiError::NotFound(msg) => write!(f, "Not found: {}", msg),
+            ApiError::Network(_) => write!(f, "Couldn't reach the Engine. Check your connection and try again."),
+            ApiError::Timeout => write!(f, "The Engine took too long to respond. Try again in a moment."),
+            ApiError::Validation { status: 401, .. } => write!(f, "Your sign-in has expired. Sign in again to continue."),
+            ApiError::Validation { status: 403, .. } => write!(f, "You don't have permission to do that."),
+            ApiError::Validation { status: 409, message } if message.is_empty() => {
+                write!(f, "Someone else changed this first. Reload and try again.")
+            }
+            ApiError::Validation { status: 429, .. } => write!(f, "Too many requests. Wait a moment and try again."),
+            ApiError::Validation { message, .. } if !message.is_empty() => write!(f, "{}", message),
+            ApiError::Validation { .. } => write!(f, "The Engine rejected the request."),
+            ApiError::NotFound(_) => write!(f, "That no longer exists. It may have been deleted."),
+            ApiError::Server { status, .. } => write!(f, "The Engine ran into a problem (error {}). Try again later.", status),
+            ApiError::Deserialize(_) => {
+                write!(f, "The Engine sent a response this version of the Player can't read.")
+            }
+            ApiError::Serialize(_) => write!(f, "The request couldn't be prepared."),
+            ApiError::CircuitOpen { retry_in_ms, .. } => write!(
+                f,
+                "The Engine keeps failing on this request; trying again in {}s.",
+                retry_in_ms.div_ceil(1000)
+            ),
         }
     }
 }
@@ -42,6 +111,125 @@ impl From<ApiError> for String {
     }
 }
 
+/// The message of an Engine error body
+///
+/// The Engine answers rejected requests with `{"error": "..."}` or
+/// `{"message": "..."}`; plain-text bodies are used as they are.
+pub fn error_message_from_body(body: &str) -> Option<String> {
+    let body = body.trim();
+    if body.is_empty() {
+        return None;
+    }
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(json) => ["error", "message"]
+            .iter()
+            .find_map(|key| json.get(key).and_then(|v| v.as_str()))
+            .map(str::to_string),
+        Err(_) => Some(body.to_string()),
+    }
+}
+
+/// An endpoint whose circuit is open
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenCircuit {
+    /// Path pattern, with IDs replaced by `*`
+    pub endpoint: String,
+    pub retry_in_ms: u64,
+}
+
+/// Cursor-based page request for list endpoints
+///
+/// List endpoints accept `limit` and an opaque `cursor` from the previous
+/// page's `next_cursor`. Use `apply_to` to add them to a request path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRequest {
+    pub limit: u32,
+    pub cursor: Option<String>,
+}
+
+impl PageRequest {
+    /// Default number of items per page
+    pub const DEFAULT_LIMIT: u32 = 50;
+
+    /// Request the first page
+    pub fn first(limit: u32) -> Self {
+        Self { limit, cursor: None }
+    }
+
+    /// Request the page following `cursor`
+    pub fn after(cursor: impl Into<String>, limit: u32) -> Self {
+        Self {
+            limit,
+            cursor: Some(cursor.into()),
+        }
+    }
+
+    /// Append the page parameters to `path` as a query string
+    pub fn apply_to(&self, path: &str) -> String {
+        let separator = if path.contains('?') { '&' } else { '?' };
+        let mut out = format!("{}{}limit={}", path, separator, self.limit);
+        if let Some(cursor) = &self.cursor {
+            out.push_str("&cursor=");
+            for byte in cursor.bytes() {
+                match byte {
+                    b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                        out.push(byte as char)
+                    }
+                    _ => out.push_str(&format!("%{:02X}", byte)),
+                }
+            }
+        }
+        out
+    }
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self::first(Self::DEFAULT_LIMIT)
+    }
+}
+
+/// One page of a list endpoint
+///
+/// Engines without pagination return a bare JSON array; that deserializes
+/// as a single, final page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Cursor for the next page, `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Whether more pages follow this one
+    pub fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Page<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr<U> {
+            Paged {
+                items: Vec<U>,
+                #[serde(default)]
+                next_cursor: Option<String>,
+            },
+            Full(Vec<U>),
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Paged { items, next_cursor } => Page { items, next_cursor },
+            Repr::Full(items) => Page {
+                items,
+                next_cursor: None,
+            },
+        })
+    }
+}
+
 /// API Port trait for Engine HTTP operations
 ///
 /// This trait provides a platform-agnostic interface for making HTTP requests
@@ -113,4 +301,37 @@ pub trait ApiPort {
 
     /// DELETE request
     async fn delete(&self, path: &str) -> Result<(), ApiError>;
+
+    /// Endpoints currently paused after repeated failures
+    fn open_circuits(&self) -> Vec<OpenCircuit> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statuses_map_to_error_kinds() {
+        assert_eq!(ApiError::from_status(404, "x"), ApiError::NotFound("x".to_string()));
+        assert!(matches!(ApiError::from_status(422, ""), ApiError::Validation { status: 422, .. }));
+        assert!(matches!(ApiError::from_status(503, ""), ApiError::Server { status: 503, .. }));
+
+        assert!(ApiError::Timeout.is_retryable());
+        assert!(ApiError::from_status(503, "").is_retryable());
+        assert!(!ApiError::from_status(501, "").is_retryable());
+        assert!(!ApiError::from_status(422, "").is_retryable());
+        assert!(!ApiError::from_status(429, "").is_endpoint_failure());
+        assert!(!ApiError::NotFound(String::new()).is_endpoint_failure());
+    }
+
+    #[test]
+    fn rejected_requests_show_the_engine_message() {
+        let err = ApiError::from_status(422, error_message_from_body(r#"{"error":"Name is required"}"#).unwrap());
+        assert_eq!(err.to_string(), "Name is required");
+        assert_eq!(error_message_from_body("  "), None);
+        assert_eq!(error_message_from_body("Bad gateway").as_deref(), Some("Bad gateway"));
+        assert_eq!(error_message_from_body(r#"{"code":1}"#), None);
+    }
 }
@@ -4,7 +4,11 @@
 //! allowing application services to manage real-time game sessions without
 //! depending on concrete WebSocket client implementations.
 
-pub use crate::application::dto::websocket_messages::{ChallengeOutcomeDecisionData, DiceInputType};
+pub use crate::application::dto::websocket_messages::{
+    AdHocOutcomes, AdvancementGrant, ChallengeOutcomeDecisionData, DecisionTimerSettings, DialoguePacing, DiceInputType,
+    LevelUpChoices, NewCondition, PresenceMode, PresenceSignalData, SpectatorPolicy, TimedDecision, TimerExpiry,
+};
+use crate::application::dto::{Annotation, AnnotationLayer, AnnotationSurface, CameraEffect, MemoryDraft, RegionPopulation, RevealScope, SceneMood, SessionRecap, SharedNoteDoc, StagedScene};
 
 /// Connection state for the game session
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -26,12 +30,78 @@ pub enum ConnectionState {
 pub enum ParticipantRole {
     /// Game master / Dungeon master
     DungeonMaster,
+    /// Secondary DM who can run the table but not edit the world
+    CoDungeonMaster,
     /// Player character
     Player,
     /// Observer only
     Spectator,
 }
 
+impl ParticipantRole {
+    /// Whether this role may perform the given action
+    pub fn can(&self, capability: Capability) -> bool {
+        use Capability::*;
+        match self {
+            Self::DungeonMaster => true,
+            Self::CoDungeonMaster => matches!(
+                capability,
+                ViewDirector | ApproveDialogue | TriggerChallenges | DirectScene
+            ),
+            Self::Player | Self::Spectator => false,
+        }
+    }
+
+    /// Whether a possibly unknown role may perform the given action; with
+    /// no role yet (not joined) nothing is allowed
+    pub fn allows(role: Option<Self>, capability: Capability) -> bool {
+        role.is_some_and(|role| role.can(capability))
+    }
+
+    /// Whether this role uses the DM views
+    pub fn is_dm(&self) -> bool {
+        self.can(Capability::ViewDirector)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::DungeonMaster => "Dungeon Master",
+            Self::CoDungeonMaster => "Co-DM",
+            Self::Player => "Player",
+            Self::Spectator => "Spectator",
+        }
+    }
+}
+
+impl From<crate::application::dto::websocket_messages::ParticipantRole> for ParticipantRole {
+    fn from(role: crate::application::dto::websocket_messages::ParticipantRole) -> Self {
+        use crate::application::dto::websocket_messages::ParticipantRole as Dto;
+        match role {
+            Dto::DungeonMaster => Self::DungeonMaster,
+            Dto::CoDungeonMaster => Self::CoDungeonMaster,
+            Dto::Player => Self::Player,
+            Dto::Spectator => Self::Spectator,
+        }
+    }
+}
+
+/// Actions gated by session role
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    /// Open the Director panel
+    ViewDirector,
+    /// Approve, modify, or reject NPC dialogue
+    ApproveDialogue,
+    /// Trigger challenges and decide their outcomes
+    TriggerChallenges,
+    /// Change scenes and directorial notes
+    DirectScene,
+    /// Create, edit, and delete world entities (Creator, Story Arc, Settings)
+    EditWorld,
+    /// Pause the session and assign participant roles
+    ManageSession,
+}
+
 /// Approval decision from the DM
 #[derive(Debug, Clone)]
 pub enum ApprovalDecision {
@@ -56,6 +126,10 @@ pub struct DirectorialContext {
     pub tone: String,
     pub npc_motivations: Vec<NpcMotivation>,
     pub forbidden_topics: Vec<String>,
+    /// Preset the tone and notes came from, if any
+    pub preset_name: Option<String>,
+    pub style_notes: String,
+    pub content_guidelines: Vec<String>,
 }
 
 /// NPC motivation data
@@ -129,6 +203,16 @@ pub trait GameConnectionPort: Send + Sync {
     /// Trigger a challenge (DM only)
     fn trigger_challenge(&self, challenge_id: &str, target_character_id: &str) -> anyhow::Result<()>;
 
+    /// Create and trigger a challenge that isn't in the world's library (DM only)
+    fn create_adhoc_challenge(
+        &self,
+        challenge_name: &str,
+        skill_name: &str,
+        difficulty: &str,
+        target_pc_id: &str,
+        outcomes: AdHocOutcomes,
+    ) -> anyhow::Result<()>;
+
     /// Submit a challenge roll (Player only) - legacy method using raw i32
     fn submit_challenge_roll(&self, challenge_id: &str, roll: i32) -> anyhow::Result<()>;
 
@@ -144,6 +228,141 @@ pub trait GameConnectionPort: Send + Sync {
     /// Exit to a different location
     fn exit_to_location(&self, pc_id: &str, location_id: &str, arrival_region_id: Option<&str>) -> anyhow::Result<()>;
 
+    /// Change how this participant appears in the presence strip
+    fn set_presence_mode(&self, mode: PresenceMode) -> anyhow::Result<()>;
+
+    /// Relay a WebRTC signaling message to another participant
+    fn send_presence_signal(&self, to_user_id: &str, signal: PresenceSignalData) -> anyhow::Result<()>;
+
+    /// Pause the session and show the intermission screen (DM only)
+    fn pause_session(&self, message: Option<&str>, art_asset: Option<&str>) -> anyhow::Result<()>;
+
+    /// Resume a paused session (DM only)
+    fn resume_session(&self) -> anyhow::Result<()>;
+
+    /// Change another participant's role (DM only)
+    fn assign_role(&self, user_id: &str, role: ParticipantRole) -> anyhow::Result<()>;
+
+    /// Send a private message to the DM (players only)
+    fn send_whisper(&self, text: &str) -> anyhow::Result<()>;
+
+    /// Reply privately to a player's whisper (DM only)
+    fn reply_to_whisper(&self, to_user_id: &str, text: &str) -> anyhow::Result<()>;
+
+    /// Change what spectators are allowed to see (DM only)
+    fn set_spectator_policy(&self, policy: SpectatorPolicy) -> anyhow::Result<()>;
+
+    /// Replace the scenes running in parallel and who is in each (DM only)
+    fn set_staged_scenes(&self, scenes: Vec<StagedScene>) -> anyhow::Result<()>;
+
+    /// Direct one of the staged scenes (DM only)
+    fn focus_staged_scene(&self, scene_id: &str) -> anyhow::Result<()>;
+
+    /// Turn play-by-post on or off (DM only)
+    fn set_async_play(&self, enabled: bool) -> anyhow::Result<()>;
+
+    /// Answer posted actions from the inbox, in order (DM only)
+    fn process_inbox_actions(&self, action_ids: Vec<String>) -> anyhow::Result<()>;
+
+    /// Drop a posted action without answering it (DM only)
+    fn discard_inbox_action(&self, action_id: &str) -> anyhow::Result<()>;
+
+    /// Award XP or a milestone to a player character (DM only)
+    fn grant_advancement(&self, pc_id: &str, grant: AdvancementGrant) -> anyhow::Result<()>;
+
+    /// Take a pending level-up with the player's choices
+    fn submit_level_up(&self, pc_id: &str, choices: LevelUpChoices) -> anyhow::Result<()>;
+
+    /// Make a scene NPC say a line directly, bypassing the LLM (DM only)
+    fn speak_as(&self, npc_id: &str, text: &str, record_as_approved: bool) -> anyhow::Result<()>;
+
+    /// Change how dialogue advances in group scenes (DM only)
+    fn set_dialogue_pacing(&self, pacing: DialoguePacing) -> anyhow::Result<()>;
+
+    /// Tell the table this player has finished reading the current line
+    fn mark_dialogue_read(&self) -> anyhow::Result<()>;
+
+    /// Release paced dialogue for everyone (DM only)
+    fn advance_dialogue(&self) -> anyhow::Result<()>;
+
+    /// Change the time limit on player decisions (DM only)
+    fn set_decision_timer(&self, timer: DecisionTimerSettings) -> anyhow::Result<()>;
+
+    /// Tell the DM this player's decision timer ran out
+    fn report_decision_timeout(&self, decision: TimedDecision, defaulted: bool) -> anyhow::Result<()>;
+
+    /// Apply a condition to a PC or NPC (DM only)
+    fn apply_condition(&self, character_id: &str, condition: NewCondition) -> anyhow::Result<()>;
+
+    /// Remove a condition from a PC or NPC (DM only)
+    fn remove_condition(&self, character_id: &str, condition_id: &str) -> anyhow::Result<()>;
+
+    /// Draw an annotation on a backdrop (DM only)
+    fn add_annotation(&self, surface: AnnotationSurface, annotation: Annotation) -> anyhow::Result<()>;
+
+    /// Erase an annotation (DM only)
+    fn remove_annotation(&self, surface: AnnotationSurface, annotation_id: &str) -> anyhow::Result<()>;
+
+    /// Create or update an annotation layer (DM only)
+    fn set_annotation_layer(&self, surface: AnnotationSurface, layer: AnnotationLayer) -> anyhow::Result<()>;
+
+    /// Erase everything on an annotation layer (DM only)
+    fn clear_annotation_layer(&self, surface: AnnotationSurface, layer_id: &str) -> anyhow::Result<()>;
+
+    /// Run a prepared encounter (DM only)
+    fn run_encounter(&self, encounter_id: &str) -> anyhow::Result<()>;
+
+    /// Send an action as a character the DM has possessed
+    fn send_possessed_action(&self, character_id: &str, action_type: &str, target: Option<&str>, dialogue: Option<&str>) -> anyhow::Result<()>;
+
+    /// Set a region's lighting and weather (DM only)
+    fn set_scene_mood(&self, region_id: &str, mood: SceneMood) -> anyhow::Result<()>;
+
+    /// Make a generated asset a region's live backdrop (DM only)
+    fn set_region_backdrop(&self, region_id: &str, asset_id: &str) -> anyhow::Result<()>;
+
+    /// Ask for an NPC's memories; answered with `NpcMemories` (DM only)
+    fn request_npc_memories(&self, npc_id: &str) -> anyhow::Result<()>;
+
+    /// Inject a memory into an NPC (DM only)
+    fn add_npc_memory(&self, npc_id: &str, memory: MemoryDraft) -> anyhow::Result<()>;
+
+    /// Rewrite one of an NPC's memories (DM only)
+    fn update_npc_memory(&self, npc_id: &str, memory_id: &str, memory: MemoryDraft) -> anyhow::Result<()>;
+
+    /// Delete one of an NPC's memories (DM only)
+    fn delete_npc_memory(&self, npc_id: &str, memory_id: &str) -> anyhow::Result<()>;
+
+    /// Reveal map regions to one PC or the whole party (DM only)
+    fn reveal_regions(&self, region_ids: Vec<String>, scope: RevealScope) -> anyhow::Result<()>;
+
+    /// Hide map regions again (DM only)
+    fn hide_regions(&self, region_ids: Vec<String>, scope: RevealScope) -> anyhow::Result<()>;
+
+    /// Replace a shared note's text, based on `base_revision` (DM and co-DM)
+    fn edit_shared_note(&self, doc: SharedNoteDoc, text: &str, base_revision: u64) -> anyhow::Result<()>;
+
+    /// Report the cursor in a shared note, or `None` on leaving it (DM and co-DM)
+    fn send_shared_note_cursor(&self, doc: SharedNoteDoc, position: Option<usize>) -> anyhow::Result<()>;
+
+    /// Attempt a crafting recipe with a player character's inventory
+    fn attempt_craft(&self, pc_id: &str, recipe_id: &str) -> anyhow::Result<()>;
+
+    /// Reveal a held item's hidden notes to its holder (DM only)
+    fn identify_item(&self, pc_id: &str, item_id: &str) -> anyhow::Result<()>;
+
+    /// Play a recap on the players' stage (DM only)
+    fn play_recap(&self, recap: SessionRecap) -> anyhow::Result<()>;
+
+    /// Play a camera effect on everyone's stage
+    fn play_camera_effect(&self, effect: CameraEffect) -> anyhow::Result<()>;
+
+    /// Hide or show an NPC's disposition meter for players
+    fn set_disposition_hidden(&self, npc_id: &str, hidden: bool) -> anyhow::Result<()>;
+
+    /// Set how crowded a region is (DM only)
+    fn set_region_population(&self, region_id: &str, population: RegionPopulation) -> anyhow::Result<()>;
+
     /// Register a callback for state changes
     fn on_state_change(&self, callback: Box<dyn FnMut(ConnectionState) + Send + 'static>);
 
@@ -209,6 +428,16 @@ pub trait GameConnectionPort {
     /// Trigger a challenge for a character (DM only)
     fn trigger_challenge(&self, challenge_id: &str, target_character_id: &str) -> anyhow::Result<()>;
 
+    /// Create and trigger a challenge that isn't in the world's library (DM only)
+    fn create_adhoc_challenge(
+        &self,
+        challenge_name: &str,
+        skill_name: &str,
+        difficulty: &str,
+        target_pc_id: &str,
+        outcomes: AdHocOutcomes,
+    ) -> anyhow::Result<()>;
+
     /// Submit a challenge roll (Player only) - legacy method using raw i32
     fn submit_challenge_roll(&self, challenge_id: &str, roll: i32) -> anyhow::Result<()>;
 
@@ -224,6 +453,141 @@ pub trait GameConnectionPort {
     /// Exit to a different location
     fn exit_to_location(&self, pc_id: &str, location_id: &str, arrival_region_id: Option<&str>) -> anyhow::Result<()>;
 
+    /// Change how this participant appears in the presence strip
+    fn set_presence_mode(&self, mode: PresenceMode) -> anyhow::Result<()>;
+
+    /// Relay a WebRTC signaling message to another participant
+    fn send_presence_signal(&self, to_user_id: &str, signal: PresenceSignalData) -> anyhow::Result<()>;
+
+    /// Pause the session and show the intermission screen (DM only)
+    fn pause_session(&self, message: Option<&str>, art_asset: Option<&str>) -> anyhow::Result<()>;
+
+    /// Resume a paused session (DM only)
+    fn resume_session(&self) -> anyhow::Result<()>;
+
+    /// Change another participant's role (DM only)
+    fn assign_role(&self, user_id: &str, role: ParticipantRole) -> anyhow::Result<()>;
+
+    /// Send a private message to the DM (players only)
+    fn send_whisper(&self, text: &str) -> anyhow::Result<()>;
+
+    /// Reply privately to a player's whisper (DM only)
+    fn reply_to_whisper(&self, to_user_id: &str, text: &str) -> anyhow::Result<()>;
+
+    /// Change what spectators are allowed to see (DM only)
+    fn set_spectator_policy(&self, policy: SpectatorPolicy) -> anyhow::Result<()>;
+
+    /// Replace the scenes running in parallel and who is in each (DM only)
+    fn set_staged_scenes(&self, scenes: Vec<StagedScene>) -> anyhow::Result<()>;
+
+    /// Direct one of the staged scenes (DM only)
+    fn focus_staged_scene(&self, scene_id: &str) -> anyhow::Result<()>;
+
+    /// Turn play-by-post on or off (DM only)
+    fn set_async_play(&self, enabled: bool) -> anyhow::Result<()>;
+
+    /// Answer posted actions from the inbox, in order (DM only)
+    fn process_inbox_actions(&self, action_ids: Vec<String>) -> anyhow::Result<()>;
+
+    /// Drop a posted action without answering it (DM only)
+    fn discard_inbox_action(&self, action_id: &str) -> anyhow::Result<()>;
+
+    /// Award XP or a milestone to a player character (DM only)
+    fn grant_advancement(&self, pc_id: &str, grant: AdvancementGrant) -> anyhow::Result<()>;
+
+    /// Take a pending level-up with the player's choices
+    fn submit_level_up(&self, pc_id: &str, choices: LevelUpChoices) -> anyhow::Result<()>;
+
+    /// Make a scene NPC say a line directly, bypassing the LLM (DM only)
+    fn speak_as(&self, npc_id: &str, text: &str, record_as_approved: bool) -> anyhow::Result<()>;
+
+    /// Change how dialogue advances in group scenes (DM only)
+    fn set_dialogue_pacing(&self, pacing: DialoguePacing) -> anyhow::Result<()>;
+
+    /// Tell the table this player has finished reading the current line
+    fn mark_dialogue_read(&self) -> anyhow::Result<()>;
+
+    /// Release paced dialogue for everyone (DM only)
+    fn advance_dialogue(&self) -> anyhow::Result<()>;
+
+    /// Change the time limit on player decisions (DM only)
+    fn set_decision_timer(&self, timer: DecisionTimerSettings) -> anyhow::Result<()>;
+
+    /// Tell the DM this player's decision timer ran out
+    fn report_decision_timeout(&self, decision: TimedDecision, defaulted: bool) -> anyhow::Result<()>;
+
+    /// Apply a condition to a PC or NPC (DM only)
+    fn apply_condition(&self, character_id: &str, condition: NewCondition) -> anyhow::Result<()>;
+
+    /// Remove a condition from a PC or NPC (DM only)
+    fn remove_condition(&self, character_id: &str, condition_id: &str) -> anyhow::Result<()>;
+
+    /// Draw an annotation on a backdrop (DM only)
+    fn add_annotation(&self, surface: AnnotationSurface, annotation: Annotation) -> anyhow::Result<()>;
+
+    /// Erase an annotation (DM only)
+    fn remove_annotation(&self, surface: AnnotationSurface, annotation_id: &str) -> anyhow::Result<()>;
+
+    /// Create or update an annotation layer (DM only)
+    fn set_annotation_layer(&self, surface: AnnotationSurface, layer: AnnotationLayer) -> anyhow::Result<()>;
+
+    /// Erase everything on an annotation layer (DM only)
+    fn clear_annotation_layer(&self, surface: AnnotationSurface, layer_id: &str) -> anyhow::Result<()>;
+
+    /// Run a prepared encounter (DM only)
+    fn run_encounter(&self, encounter_id: &str) -> anyhow::Result<()>;
+
+    /// Send an action as a character the DM has possessed
+    fn send_possessed_action(&self, character_id: &str, action_type: &str, target: Option<&str>, dialogue: Option<&str>) -> anyhow::Result<()>;
+
+    /// Set a region's lighting and weather (DM only)
+    fn set_scene_mood(&self, region_id: &str, mood: SceneMood) -> anyhow::Result<()>;
+
+    /// Make a generated asset a region's live backdrop (DM only)
+    fn set_region_backdrop(&self, region_id: &str, asset_id: &str) -> anyhow::Result<()>;
+
+    /// Ask for an NPC's memories; answered with `NpcMemories` (DM only)
+    fn request_npc_memories(&self, npc_id: &str) -> anyhow::Result<()>;
+
+    /// Inject a memory into an NPC (DM only)
+    fn add_npc_memory(&self, npc_id: &str, memory: MemoryDraft) -> anyhow::Result<()>;
+
+    /// Rewrite one of an NPC's memories (DM only)
+    fn update_npc_memory(&self, npc_id: &str, memory_id: &str, memory: MemoryDraft) -> anyhow::Result<()>;
+
+    /// Delete one of an NPC's memories (DM only)
+    fn delete_npc_memory(&self, npc_id: &str, memory_id: &str) -> anyhow::Result<()>;
+
+    /// Reveal map regions to one PC or the whole party (DM only)
+    fn reveal_regions(&self, region_ids: Vec<String>, scope: RevealScope) -> anyhow::Result<()>;
+
+    /// Hide map regions again (DM only)
+    fn hide_regions(&self, region_ids: Vec<String>, scope: RevealScope) -> anyhow::Result<()>;
+
+    /// Replace a shared note's text, based on `base_revision` (DM and co-DM)
+    fn edit_shared_note(&self, doc: SharedNoteDoc, text: &str, base_revision: u64) -> anyhow::Result<()>;
+
+    /// Report the cursor in a shared note, or `None` on leaving it (DM and co-DM)
+    fn send_shared_note_cursor(&self, doc: SharedNoteDoc, position: Option<usize>) -> anyhow::Result<()>;
+
+    /// Attempt a crafting recipe with a player character's inventory
+    fn attempt_craft(&self, pc_id: &str, recipe_id: &str) -> anyhow::Result<()>;
+
+    /// Reveal a held item's hidden notes to its holder (DM only)
+    fn identify_item(&self, pc_id: &str, item_id: &str) -> anyhow::Result<()>;
+
+    /// Play a recap on the players' stage (DM only)
+    fn play_recap(&self, recap: SessionRecap) -> anyhow::Result<()>;
+
+    /// Play a camera effect on everyone's stage
+    fn play_camera_effect(&self, effect: CameraEffect) -> anyhow::Result<()>;
+
+    /// Hide or show an NPC's disposition meter for players
+    fn set_disposition_hidden(&self, npc_id: &str, hidden: bool) -> anyhow::Result<()>;
+
+    /// Set how crowded a region is (DM only)
+    fn set_region_population(&self, region_id: &str, population: RegionPopulation) -> anyhow::Result<()>;
+
     /// Register a callback for state changes
     ///
     /// The callback will be invoked whenever the connection state changes.
@@ -236,3 +600,45 @@ pub trait GameConnectionPort {
     /// message types as needed.
     fn on_message(&self, callback: Box<dyn FnMut(serde_json::Value) + 'static>);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [Capability; 6] = [
+        Capability::ViewDirector,
+        Capability::ApproveDialogue,
+        Capability::TriggerChallenges,
+        Capability::DirectScene,
+        Capability::EditWorld,
+        Capability::ManageSession,
+    ];
+
+    #[test]
+    fn dm_can_do_everything_and_others_nothing() {
+        for capability in ALL {
+            assert!(ParticipantRole::DungeonMaster.can(capability));
+            assert!(!ParticipantRole::Player.can(capability));
+            assert!(!ParticipantRole::Spectator.can(capability));
+        }
+    }
+
+    #[test]
+    fn co_dm_runs_the_table_but_not_the_world() {
+        let co_dm = ParticipantRole::CoDungeonMaster;
+        assert!(co_dm.can(Capability::ViewDirector));
+        assert!(co_dm.can(Capability::ApproveDialogue));
+        assert!(co_dm.can(Capability::TriggerChallenges));
+        assert!(co_dm.can(Capability::DirectScene));
+        assert!(!co_dm.can(Capability::EditWorld));
+        assert!(!co_dm.can(Capability::ManageSession));
+    }
+
+    #[test]
+    fn unknown_role_is_least_privileged() {
+        for capability in ALL {
+            assert!(!ParticipantRole::allows(None, capability));
+        }
+        assert!(ParticipantRole::allows(Some(ParticipantRole::DungeonMaster), Capability::EditWorld));
+    }
+}
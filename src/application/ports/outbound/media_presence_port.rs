@@ -0,0 +1,60 @@
+//! Media Presence Port - Outbound port for peer-to-peer webcam tiles
+//!
+//! This port abstracts local camera capture and the WebRTC peer connections
+//! behind the presence strip. Signaling is not part of the port: outgoing
+//! signals are handed to a callback so the caller can relay them over the
+//! game connection, and incoming signals are fed back in via `handle_signal`.
+
+pub use crate::application::dto::websocket_messages::PresenceSignalData;
+
+/// Media Presence Port trait for webcam capture and peer video
+///
+/// Implementations are single-threaded (browser media APIs are not `Send`),
+/// so callbacks are not required to be `Send` either. Platforms without
+/// WebRTC support report `supports_video() == false` and treat every other
+/// call as a no-op.
+pub trait MediaPresencePort {
+    /// Whether live webcam tiles are available on this platform
+    fn supports_video(&self) -> bool;
+
+    /// Start capturing the local webcam
+    ///
+    /// Capture is asynchronous; the local stream becomes available to
+    /// `attach_local_video` once the user grants camera access.
+    fn start_local_video(&self) -> anyhow::Result<()>;
+
+    /// Stop capturing the local webcam and release the camera
+    fn stop_local_video(&self);
+
+    /// Open a peer connection and send an offer to `peer_id`
+    fn connect_peer(&self, peer_id: &str) -> anyhow::Result<()>;
+
+    /// Apply a signaling message received from `peer_id`
+    fn handle_signal(&self, peer_id: &str, signal: PresenceSignalData) -> anyhow::Result<()>;
+
+    /// Close the connection to `peer_id`
+    fn disconnect_peer(&self, peer_id: &str);
+
+    /// Close every peer connection and stop local capture
+    fn disconnect_all(&self);
+
+    /// Register a callback for outgoing signals that must be relayed to a peer
+    fn on_signal(&self, callback: Box<dyn FnMut(String, PresenceSignalData) + 'static>);
+
+    /// Register a callback for local capture changes
+    ///
+    /// Called with `true` once the camera is live and `false` when capture
+    /// stops or the user denies access.
+    fn on_local_video(&self, callback: Box<dyn FnMut(bool) + 'static>);
+
+    /// Register a callback for remote video availability changes
+    ///
+    /// Called with `(peer_id, has_video)` when a peer's stream arrives or ends.
+    fn on_remote_video(&self, callback: Box<dyn FnMut(String, bool) + 'static>);
+
+    /// Render the local stream into the `<video>` element with the given DOM id
+    fn attach_local_video(&self, element_id: &str);
+
+    /// Render a peer's stream into the `<video>` element with the given DOM id
+    fn attach_remote_video(&self, peer_id: &str, element_id: &str);
+}
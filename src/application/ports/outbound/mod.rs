@@ -6,13 +6,16 @@
 
 pub mod api_port;
 pub mod game_connection_port;
+pub mod media_presence_port;
 pub mod platform;
 
-pub use api_port::{ApiError, ApiPort};
+pub use api_port::{ApiError, ApiPort, OpenCircuit, Page, PageRequest};
 pub use game_connection_port::{
-    ApprovalDecision, ChallengeOutcomeDecisionData, ConnectionState, DiceInputType, DirectorialContext, GameConnectionPort,
-    NpcMotivation, ParticipantRole,
+    AdHocOutcomes, AdvancementGrant, ApprovalDecision, Capability, ChallengeOutcomeDecisionData, ConnectionState, DecisionTimerSettings, DialoguePacing, DiceInputType,
+    DirectorialContext, GameConnectionPort, LevelUpChoices, NewCondition, NpcMotivation, ParticipantRole, PresenceMode, PresenceSignalData,
+    SpectatorPolicy, TimedDecision, TimerExpiry,
 };
+pub use media_presence_port::MediaPresencePort;
 pub use platform::{
-    Platform, storage_keys,
+    GamepadButton, LogRecord, Platform, ShareOutcome, storage_keys,
 };
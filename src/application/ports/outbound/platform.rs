@@ -5,6 +5,7 @@
 //! 2. Platform-specific code is isolated in infrastructure
 //! 3. Code becomes easily testable with mock implementations
 
+use serde::Serialize;
 use std::{future::Future, pin::Pin};
 use uuid::Uuid;
 
@@ -45,18 +46,110 @@ pub trait StorageProvider: Clone + 'static {
     fn remove(&self, key: &str);
 }
 
+/// A captured log line
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LogRecord {
+    /// Milliseconds since epoch
+    pub timestamp_ms: u64,
+    /// "ERROR", "WARN", "INFO", "DEBUG" or "TRACE"
+    pub level: String,
+    pub message: String,
+}
+
 /// Logging abstraction
 pub trait LogProvider: Clone + 'static {
     fn info(&self, msg: &str);
     fn error(&self, msg: &str);
     fn debug(&self, msg: &str);
     fn warn(&self, msg: &str);
+
+    /// Recently logged lines, oldest first (for bug reports)
+    fn recent(&self) -> Vec<LogRecord>;
 }
 
 /// Browser document operations (page title, etc.)
 pub trait DocumentProvider: Clone + 'static {
     /// Set the browser page title (no-op on desktop)
     fn set_page_title(&self, title: &str);
+
+    /// Make the page background transparent, so a browser source shows what
+    /// is behind it (no-op on desktop)
+    fn set_transparent_page(&self, transparent: bool);
+
+    /// Offer `contents` to the user as a downloadable file (browser) or write
+    /// it to disk (desktop); returns where it went, if known
+    fn save_file(&self, filename: &str, contents: &str) -> Result<String, String>;
+
+    /// Open an HTML page for printing: a new tab in the browser, the
+    /// system browser on desktop (`filename` names the temporary file)
+    fn print_document(&self, filename: &str, html: &str) -> Result<(), String>;
+
+    /// Short description of the runtime, e.g. the browser user agent
+    fn platform_description(&self) -> String;
+
+    /// Origin the app is served from, e.g. `https://play.example.com`
+    /// (None on desktop, where there is no page URL)
+    fn app_origin(&self) -> Option<String>;
+
+    /// Scale a base64-encoded image down so neither side exceeds
+    /// `max_dimension`, keeping its format; smaller images come back as-is
+    fn resize_image(
+        &self,
+        data_base64: String,
+        mime_type: &str,
+        max_dimension: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<ResizedImage, String>> + 'static>>;
+}
+
+/// An image after [`DocumentProvider::resize_image`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResizedImage {
+    pub data_base64: String,
+    pub width: u32,
+    pub height: u32,
+    /// Whether the image had to be scaled down
+    pub resized: bool,
+}
+
+/// A button on a standard-layout game controller
+///
+/// Stick deflection is reported as the matching D-pad direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    Up,
+    Down,
+    Left,
+    Right,
+    /// Bottom face button (A on Xbox layouts, Cross on PlayStation)
+    A,
+    /// Right face button (B on Xbox layouts, Circle on PlayStation)
+    B,
+    Start,
+}
+
+/// Game controller input (Gamepad API in the browser, gilrs on desktop)
+pub trait GamepadProvider: Clone + 'static {
+    /// Buttons currently held on any connected controller; empty when none is connected
+    fn pressed_buttons(&self) -> Vec<GamepadButton>;
+}
+
+/// How a share request was fulfilled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShareOutcome {
+    /// Handed to the system share sheet
+    Shared,
+    /// No share sheet here, so the text and link went to the clipboard
+    Copied,
+}
+
+/// System clipboard and share sheet
+pub trait ClipboardProvider: Clone + 'static {
+    /// Put `text` on the system clipboard
+    fn copy_text(&self, text: &str) -> Result<(), String>;
+
+    /// Offer `url` through the share sheet (Web Share API), falling back to
+    /// copying `text` and `url` where there isn't one
+    fn share(&self, title: &str, text: &str, url: &str) -> Result<ShareOutcome, String>;
 }
 
 /// Engine configuration provider for API URL management
@@ -66,6 +159,9 @@ pub trait EngineConfigProvider: Clone + 'static {
 
     /// Convert WebSocket URL to HTTP URL
     fn ws_to_http(&self, ws_url: &str) -> String;
+
+    /// Set (or clear) the auth token attached to Engine requests
+    fn set_auth_token(&self, token: Option<&str>);
 }
 
 /// Connection factory provider for creating game connections
@@ -89,6 +185,8 @@ pub struct Platform {
     storage: std::sync::Arc<dyn StorageProviderDyn>,
     log: std::sync::Arc<dyn LogProviderDyn>,
     document: std::sync::Arc<dyn DocumentProviderDyn>,
+    gamepad: std::sync::Arc<dyn GamepadProviderDyn>,
+    clipboard: std::sync::Arc<dyn ClipboardProviderDyn>,
     engine_config: std::sync::Arc<dyn EngineConfigProviderDyn>,
     connection_factory: std::sync::Arc<dyn ConnectionFactoryProviderDyn>,
 }
@@ -119,15 +217,37 @@ trait LogProviderDyn: Send + Sync {
     fn error(&self, msg: &str);
     fn debug(&self, msg: &str);
     fn warn(&self, msg: &str);
+    fn recent(&self) -> Vec<LogRecord>;
 }
 
 trait DocumentProviderDyn: Send + Sync {
     fn set_page_title(&self, title: &str);
+    fn set_transparent_page(&self, transparent: bool);
+    fn save_file(&self, filename: &str, contents: &str) -> Result<String, String>;
+    fn print_document(&self, filename: &str, html: &str) -> Result<(), String>;
+    fn platform_description(&self) -> String;
+    fn app_origin(&self) -> Option<String>;
+    fn resize_image(
+        &self,
+        data_base64: String,
+        mime_type: &str,
+        max_dimension: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<ResizedImage, String>> + 'static>>;
+}
+
+trait GamepadProviderDyn: Send + Sync {
+    fn pressed_buttons(&self) -> Vec<GamepadButton>;
+}
+
+trait ClipboardProviderDyn: Send + Sync {
+    fn copy_text(&self, text: &str) -> Result<(), String>;
+    fn share(&self, title: &str, text: &str, url: &str) -> Result<ShareOutcome, String>;
 }
 
 trait EngineConfigProviderDyn: Send + Sync {
     fn configure_engine_url(&self, ws_url: &str);
     fn ws_to_http(&self, ws_url: &str) -> String;
+    fn set_auth_token(&self, token: Option<&str>);
 }
 
 trait ConnectionFactoryProviderDyn: Send + Sync {
@@ -184,12 +304,53 @@ impl<T: LogProvider + Send + Sync> LogProviderDyn for T {
     fn warn(&self, msg: &str) {
         LogProvider::warn(self, msg)
     }
+    fn recent(&self) -> Vec<LogRecord> {
+        LogProvider::recent(self)
+    }
 }
 
 impl<T: DocumentProvider + Send + Sync> DocumentProviderDyn for T {
     fn set_page_title(&self, title: &str) {
         DocumentProvider::set_page_title(self, title)
     }
+    fn set_transparent_page(&self, transparent: bool) {
+        DocumentProvider::set_transparent_page(self, transparent)
+    }
+    fn save_file(&self, filename: &str, contents: &str) -> Result<String, String> {
+        DocumentProvider::save_file(self, filename, contents)
+    }
+    fn print_document(&self, filename: &str, html: &str) -> Result<(), String> {
+        DocumentProvider::print_document(self, filename, html)
+    }
+    fn platform_description(&self) -> String {
+        DocumentProvider::platform_description(self)
+    }
+    fn app_origin(&self) -> Option<String> {
+        DocumentProvider::app_origin(self)
+    }
+    fn resize_image(
+        &self,
+        data_base64: String,
+        mime_type: &str,
+        max_dimension: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<ResizedImage, String>> + 'static>> {
+        DocumentProvider::resize_image(self, data_base64, mime_type, max_dimension)
+    }
+}
+
+impl<T: GamepadProvider + Send + Sync> GamepadProviderDyn for T {
+    fn pressed_buttons(&self) -> Vec<GamepadButton> {
+        GamepadProvider::pressed_buttons(self)
+    }
+}
+
+impl<T: ClipboardProvider + Send + Sync> ClipboardProviderDyn for T {
+    fn copy_text(&self, text: &str) -> Result<(), String> {
+        ClipboardProvider::copy_text(self, text)
+    }
+    fn share(&self, title: &str, text: &str, url: &str) -> Result<ShareOutcome, String> {
+        ClipboardProvider::share(self, title, text, url)
+    }
 }
 
 impl<T: EngineConfigProvider + Send + Sync> EngineConfigProviderDyn for T {
@@ -200,6 +361,10 @@ impl<T: EngineConfigProvider + Send + Sync> EngineConfigProviderDyn for T {
     fn ws_to_http(&self, ws_url: &str) -> String {
         EngineConfigProvider::ws_to_http(self, ws_url)
     }
+
+    fn set_auth_token(&self, token: Option<&str>) {
+        EngineConfigProvider::set_auth_token(self, token)
+    }
 }
 
 impl<T: ConnectionFactoryProvider + Send + Sync> ConnectionFactoryProviderDyn for T {
@@ -210,13 +375,15 @@ impl<T: ConnectionFactoryProvider + Send + Sync> ConnectionFactoryProviderDyn fo
 
 impl Platform {
     /// Create a new Platform with the given providers
-    pub fn new<Tm, Sl, R, S, L, D, E, C>(
+    pub fn new<Tm, Sl, R, S, L, D, G, Cb, E, C>(
         time: Tm,
         sleep: Sl,
         random: R,
         storage: S,
         log: L,
         document: D,
+        gamepad: G,
+        clipboard: Cb,
         engine_config: E,
         connection_factory: C,
     ) -> Self
@@ -227,6 +394,8 @@ impl Platform {
         S: StorageProvider + Send + Sync,
         L: LogProvider + Send + Sync,
         D: DocumentProvider + Send + Sync,
+        G: GamepadProvider + Send + Sync,
+        Cb: ClipboardProvider + Send + Sync,
         E: EngineConfigProvider + Send + Sync,
         C: ConnectionFactoryProvider + Send + Sync,
     {
@@ -237,6 +406,8 @@ impl Platform {
             storage: std::sync::Arc::new(storage),
             log: std::sync::Arc::new(log),
             document: std::sync::Arc::new(document),
+            gamepad: std::sync::Arc::new(gamepad),
+            clipboard: std::sync::Arc::new(clipboard),
             engine_config: std::sync::Arc::new(engine_config),
             connection_factory: std::sync::Arc::new(connection_factory),
         }
@@ -316,11 +487,66 @@ impl Platform {
         self.log.warn(msg)
     }
 
+    /// Recently logged lines, oldest first
+    pub fn recent_logs(&self) -> Vec<LogRecord> {
+        self.log.recent()
+    }
+
     /// Set the browser page title (no-op on desktop)
     pub fn set_page_title(&self, title: &str) {
         self.document.set_page_title(title)
     }
 
+    /// Make the page background transparent (no-op on desktop)
+    pub fn set_transparent_page(&self, transparent: bool) {
+        self.document.set_transparent_page(transparent)
+    }
+
+    /// Save `contents` as a file: a download in the browser, a file on desktop
+    pub fn save_file(&self, filename: &str, contents: &str) -> Result<String, String> {
+        self.document.save_file(filename, contents)
+    }
+
+    /// Open an HTML page where the user can print it or save it as PDF
+    pub fn print_document(&self, filename: &str, html: &str) -> Result<(), String> {
+        self.document.print_document(filename, html)
+    }
+
+    /// Short description of the runtime (user agent or OS/arch)
+    pub fn platform_description(&self) -> String {
+        self.document.platform_description()
+    }
+
+    /// Origin the app is served from, when it runs in a browser
+    pub fn app_origin(&self) -> Option<String> {
+        self.document.app_origin()
+    }
+
+    /// Scale an image down to fit `max_dimension` before it is uploaded
+    pub async fn resize_image(
+        &self,
+        data_base64: String,
+        mime_type: &str,
+        max_dimension: u32,
+    ) -> Result<ResizedImage, String> {
+        self.document.resize_image(data_base64, mime_type, max_dimension).await
+    }
+
+    /// Buttons currently held on any connected game controller
+    pub fn gamepad_buttons(&self) -> Vec<GamepadButton> {
+        self.gamepad.pressed_buttons()
+    }
+
+    /// Copy `text` to the system clipboard
+    pub fn copy_to_clipboard(&self, text: &str) -> Result<(), String> {
+        self.clipboard.copy_text(text)
+    }
+
+    /// Share a link through the system share sheet, or copy it where there is none
+    pub fn share_link(&self, title: &str, text: &str, url: &str) -> Result<ShareOutcome, String> {
+        self.clipboard.share(title, text, url)
+    }
+
     /// Configure the base Engine URL for API calls (from WebSocket URL)
     pub fn configure_engine_url(&self, ws_url: &str) {
         self.engine_config.configure_engine_url(ws_url)
@@ -331,6 +557,11 @@ impl Platform {
         self.engine_config.ws_to_http(ws_url)
     }
 
+    /// Set (or clear) the auth token attached to Engine requests
+    pub fn set_auth_token(&self, token: Option<&str>) {
+        self.engine_config.set_auth_token(token)
+    }
+
     /// Create a game connection to the engine
     pub fn create_game_connection(&self, server_url: &str) -> std::sync::Arc<dyn super::GameConnectionPort> {
         self.connection_factory.create_game_connection(server_url)
@@ -343,4 +574,33 @@ pub mod storage_keys {
     pub const ROLE: &str = "wrldbldr_role";
     pub const LAST_WORLD: &str = "wrldbldr_last_world";
     pub const USER_ID: &str = "wrldbldr_user_id";
+    pub const AUTH_SESSION: &str = "wrldbldr_auth_session";
+    /// Prefix for form drafts; see `presentation::state::draft_persistence`
+    pub const DRAFT_PREFIX: &str = "wrldbldr_draft_";
+    /// Engine path (or absolute URL) bug reports are posted to
+    pub const BUG_REPORT_ENDPOINT: &str = "wrldbldr_bug_report_endpoint";
+    /// Per-device animated backdrop / data saver toggles
+    pub const BACKDROP_PREFERENCES: &str = "wrldbldr_backdrop_preferences";
+    /// Per-device reduced motion / dyslexia-friendly font toggles
+    pub const ACCESSIBILITY: &str = "wrldbldr_accessibility";
+    /// Onboarding tours finished or skipped on this device
+    pub const ONBOARDING_TOURS: &str = "wrldbldr_onboarding_tours";
+    /// Per-device developer performance overlay toggle
+    pub const PERF_OVERLAY: &str = "wrldbldr_perf_overlay";
+    /// Prefix for a world's "My Rolls" history, followed by the world ID
+    pub const ROLL_HISTORY_PREFIX: &str = "wrldbldr_rolls_";
+    /// Prefix for a world's play-by-post actions awaiting the DM, followed by the world ID
+    pub const POSTED_ACTIONS_PREFIX: &str = "wrldbldr_posted_actions_";
+    /// Prefix for a character's roll macros, followed by the player character ID
+    pub const ROLL_MACROS_PREFIX: &str = "wrldbldr_roll_macros_";
+    /// Prefix for a world's DM notes saved on this device, followed by the world ID
+    pub const NOTES_PREFIX: &str = "wrldbldr_notes_";
+    /// Prefix for saved filter presets, followed by the list and world ID
+    pub const FILTER_PRESETS_PREFIX: &str = "wrldbldr_filter_presets_";
+    /// Prefix for a session's DM approval audit log, followed by the session ID
+    pub const APPROVAL_AUDIT_PREFIX: &str = "wrldbldr_approval_audit_";
+    /// Named ComfyUI workflow setups saved on this device
+    pub const WORKFLOW_PRESETS: &str = "wrldbldr_workflow_presets";
+    /// Prefix for a world's DM house rules, followed by the world ID
+    pub const HOUSE_RULES_PREFIX: &str = "wrldbldr_house_rules_";
 }
@@ -0,0 +1,70 @@
+//! Accessibility - Per-device reduced motion and reading preferences
+//!
+//! Like backdrop preferences these live on the device, not the Engine, so a
+//! player who needs them gets them at every table. The root of the app
+//! carries [`AccessibilityPreferences::root_class`], and the stylesheet does
+//! the rest; components that animate in code (the typewriter, animated
+//! backdrops) check `reduced_motion` themselves.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::outbound::{storage_keys, Platform};
+
+/// Per-device accessibility preferences
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccessibilityPreferences {
+    /// Show dialogue in full at once and turn off transitions and animations
+    pub reduced_motion: bool,
+    /// Swap body text to a dyslexia-friendly font with wider spacing
+    pub dyslexia_font: bool,
+}
+
+impl AccessibilityPreferences {
+    /// Class list for the app root; the stylesheet keys off these
+    pub fn root_class(&self) -> String {
+        let mut classes = Vec::new();
+        if self.reduced_motion {
+            classes.push("reduce-motion");
+        }
+        if self.dyslexia_font {
+            classes.push("dyslexia-font");
+        }
+        classes.join(" ")
+    }
+}
+
+/// Load accessibility preferences saved on this device
+pub fn load_accessibility_preferences(platform: &Platform) -> AccessibilityPreferences {
+    platform
+        .storage_load(storage_keys::ACCESSIBILITY)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Save accessibility preferences on this device
+pub fn save_accessibility_preferences(platform: &Platform, preferences: &AccessibilityPreferences) {
+    match serde_json::to_string(preferences) {
+        Ok(json) => platform.storage_save(storage_keys::ACCESSIBILITY, &json),
+        Err(e) => platform.log_error(&format!("Failed to serialize accessibility preferences: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn root_class_lists_enabled_preferences() {
+        assert_eq!(AccessibilityPreferences::default().root_class(), "");
+        let both = AccessibilityPreferences { reduced_motion: true, dyslexia_font: true };
+        assert_eq!(both.root_class(), "reduce-motion dyslexia-font");
+    }
+
+    #[test]
+    fn missing_fields_default_to_off() {
+        let prefs: AccessibilityPreferences = serde_json::from_str(r#"{"dyslexia_font":true}"#).unwrap();
+        assert!(!prefs.reduced_motion);
+        assert!(prefs.dyslexia_font);
+    }
+}
@@ -0,0 +1,162 @@
+//! Action Inbox - Play-by-post actions waiting for the DM
+//!
+//! With asynchronous play on, players keep acting while no DM is
+//! connected. The Engine stores each such action, tells the player it was
+//! posted, and adds it to the DM's inbox. The DM later works through the
+//! inbox oldest first, a batch at a time, and approves the responses as
+//! usual; each player hears when one of their actions is answered.
+//!
+//! Players keep their posted actions in local storage, so the ones still
+//! waiting show after a reload. The list is capped at [`MAX_POSTED_ACTIONS`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::InboxAction;
+
+/// Most posted actions a player keeps
+pub const MAX_POSTED_ACTIONS: usize = 50;
+
+/// An action the player posted for the DM to answer later
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PostedAction {
+    pub action_id: String,
+    /// Short description shown to the player
+    pub label: String,
+    /// When it was posted, in Unix seconds
+    pub posted_at: u64,
+    /// The DM has answered it
+    #[serde(default)]
+    pub answered: bool,
+}
+
+/// The player's posted actions, oldest first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PostedActions {
+    actions: Vec<PostedAction>,
+}
+
+impl PostedActions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember a posted action, dropping the oldest answered ones past
+    /// [`MAX_POSTED_ACTIONS`]
+    pub fn post(&mut self, action_id: &str, label: &str, posted_at: u64) {
+        if self.actions.iter().any(|a| a.action_id == action_id) {
+            return;
+        }
+        self.actions.push(PostedAction {
+            action_id: action_id.to_string(),
+            label: label.to_string(),
+            posted_at,
+            answered: false,
+        });
+        while self.actions.len() > MAX_POSTED_ACTIONS {
+            match self.actions.iter().position(|a| a.answered) {
+                Some(index) => self.actions.remove(index),
+                None => self.actions.remove(0),
+            };
+        }
+    }
+
+    /// Mark an action answered; false if it isn't one of ours or was
+    /// already answered
+    pub fn answer(&mut self, action_id: &str) -> bool {
+        match self.actions.iter_mut().find(|a| a.action_id == action_id && !a.answered) {
+            Some(action) => {
+                action.answered = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Actions still waiting for the DM
+    pub fn waiting(&self) -> impl Iterator<Item = &PostedAction> {
+        self.actions.iter().filter(|a| !a.answered)
+    }
+
+    /// Answers the player hasn't looked at yet
+    pub fn unseen_answers(&self) -> usize {
+        self.actions.iter().filter(|a| a.answered).count()
+    }
+
+    /// Forget answered actions once the player has seen them
+    pub fn acknowledge_answers(&mut self) {
+        self.actions.retain(|a| !a.answered);
+    }
+}
+
+/// IDs of the next `size` inbox actions to answer, oldest first
+///
+/// Actions already being answered are skipped, so a batch never sends one
+/// twice.
+pub fn next_inbox_batch(inbox: &[InboxAction], size: usize) -> Vec<String> {
+    let mut waiting: Vec<&InboxAction> = inbox.iter().filter(|a| !a.processing).collect();
+    waiting.sort_by_key(|a| a.posted_at);
+    waiting.into_iter().take(size).map(|a| a.action_id.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inbox_action(action_id: &str, posted_at: u64, processing: bool) -> InboxAction {
+        InboxAction {
+            action_id: action_id.to_string(),
+            user_id: "u-1".to_string(),
+            pc_name: "Ada".to_string(),
+            action_type: "talk".to_string(),
+            target: Some("Mira".to_string()),
+            dialogue: None,
+            posted_at,
+            processing,
+        }
+    }
+
+    #[test]
+    fn answers_are_tracked_until_acknowledged() {
+        let mut posted = PostedActions::new();
+        posted.post("a-1", "Talk to Mira", 10);
+        posted.post("a-2", "Examine chest", 20);
+        posted.post("a-1", "Talk to Mira", 10);
+        assert_eq!(posted.waiting().count(), 2);
+
+        assert!(posted.answer("a-1"));
+        assert!(!posted.answer("a-1"));
+        assert!(!posted.answer("someone-else"));
+        assert_eq!(posted.unseen_answers(), 1);
+
+        posted.acknowledge_answers();
+        let left: Vec<&str> = posted.actions.iter().map(|a| a.action_id.as_str()).collect();
+        assert_eq!(left, vec!["a-2"]);
+    }
+
+    #[test]
+    fn cap_drops_answered_actions_first() {
+        let mut posted = PostedActions::new();
+        for i in 0..MAX_POSTED_ACTIONS {
+            posted.post(&format!("a-{}", i), "Wait", i as u64);
+        }
+        posted.answer("a-5");
+        posted.post("new", "Wait", 999);
+
+        assert_eq!(posted.actions.len(), MAX_POSTED_ACTIONS);
+        assert!(!posted.actions.iter().any(|a| a.action_id == "a-5"));
+        assert_eq!(posted.actions[0].action_id, "a-0");
+    }
+
+    #[test]
+    fn batches_take_the_oldest_waiting_actions() {
+        let inbox = vec![
+            inbox_action("late", 30, false),
+            inbox_action("sent", 5, true),
+            inbox_action("early", 10, false),
+            inbox_action("middle", 20, false),
+        ];
+        assert_eq!(next_inbox_batch(&inbox, 2), vec!["early", "middle"]);
+        assert_eq!(next_inbox_batch(&inbox, 10).len(), 3);
+        assert_eq!(inbox[2].summary(), "talk Mira");
+    }
+}
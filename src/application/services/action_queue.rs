@@ -0,0 +1,168 @@
+//! Action Queue - Serializing outgoing player actions
+//!
+//! Players can pick several choices or interactions faster than the Engine
+//! answers them. Rather than dropping inputs or letting them race, actions
+//! are queued and sent one at a time: the next one goes out only once the
+//! Engine has acknowledged the one in flight. Actions still waiting in the
+//! queue can be cancelled.
+
+use std::collections::VecDeque;
+
+use crate::domain::entities::PlayerAction;
+
+/// An action waiting in, or sent from, the queue
+#[derive(Debug, Clone)]
+pub struct QueuedAction {
+    /// Client-side ID, unique within the queue's lifetime
+    pub id: u64,
+    /// Short description shown to the player
+    pub label: String,
+    pub action: PlayerAction,
+}
+
+/// Outgoing player actions, at most one in flight at a time
+#[derive(Debug, Clone, Default)]
+pub struct ActionQueue {
+    pending: VecDeque<QueuedAction>,
+    in_flight: Option<QueuedAction>,
+    /// The last action the Engine acknowledged, with the Engine's ID for it
+    acknowledged: Option<(String, QueuedAction)>,
+    next_id: u64,
+}
+
+impl ActionQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an action to the back of the queue, returning its ID
+    pub fn enqueue(&mut self, action: PlayerAction, label: impl Into<String>) -> u64 {
+        self.next_id += 1;
+        self.pending.push_back(QueuedAction {
+            id: self.next_id,
+            label: label.into(),
+            action,
+        });
+        self.next_id
+    }
+
+    /// Take the next action to send, if nothing is in flight
+    ///
+    /// The returned action becomes the in-flight action until `settle` is
+    /// called.
+    pub fn take_next(&mut self) -> Option<PlayerAction> {
+        if self.in_flight.is_some() {
+            return None;
+        }
+        let next = self.pending.pop_front()?;
+        let action = next.action.clone();
+        self.in_flight = Some(next);
+        Some(action)
+    }
+
+    /// Mark the in-flight action as answered
+    pub fn settle(&mut self) -> Option<QueuedAction> {
+        self.in_flight.take()
+    }
+
+    /// Settle the in-flight action once the Engine received it as `action_id`
+    ///
+    /// It stays available through `acknowledged` for follow-up messages that
+    /// refer to it by the Engine's ID.
+    pub fn acknowledge(&mut self, action_id: &str) -> Option<&QueuedAction> {
+        let sent = self.in_flight.take()?;
+        self.acknowledged = Some((action_id.to_string(), sent));
+        self.acknowledged.as_ref().map(|(_, sent)| sent)
+    }
+
+    /// The acknowledged action the Engine knows as `action_id`
+    pub fn acknowledged(&self, action_id: &str) -> Option<&QueuedAction> {
+        self.acknowledged
+            .as_ref()
+            .filter(|(id, _)| id == action_id)
+            .map(|(_, sent)| sent)
+    }
+
+    /// Remove an unsent action; in-flight actions can't be cancelled
+    pub fn cancel(&mut self, id: u64) -> bool {
+        let before = self.pending.len();
+        self.pending.retain(|queued| queued.id != id);
+        self.pending.len() != before
+    }
+
+    /// Drop everything, e.g. when the connection is lost
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.in_flight = None;
+        self.acknowledged = None;
+    }
+
+    /// The action sent and awaiting an answer
+    pub fn in_flight(&self) -> Option<&QueuedAction> {
+        self.in_flight.as_ref()
+    }
+
+    /// Actions waiting to be sent, in send order
+    pub fn pending(&self) -> impl Iterator<Item = &QueuedAction> {
+        self.pending.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(queue: &ActionQueue) -> Vec<&str> {
+        queue.pending().map(|q| q.label.as_str()).collect()
+    }
+
+    #[test]
+    fn sends_one_at_a_time_in_order() {
+        let mut queue = ActionQueue::new();
+        queue.enqueue(PlayerAction::examine("door"), "Examine door");
+        queue.enqueue(PlayerAction::custom("hello"), "hello");
+
+        let first = queue.take_next().unwrap();
+        assert_eq!(first.target.as_deref(), Some("door"));
+        assert!(queue.take_next().is_none());
+        assert_eq!(labels(&queue), vec!["hello"]);
+
+        assert_eq!(queue.settle().unwrap().label, "Examine door");
+        let second = queue.take_next().unwrap();
+        assert_eq!(second.dialogue.as_deref(), Some("hello"));
+        queue.settle();
+        assert!(queue.in_flight().is_none());
+        assert!(labels(&queue).is_empty());
+    }
+
+    #[test]
+    fn acknowledging_settles_and_remembers_the_engine_id() {
+        let mut queue = ActionQueue::new();
+        queue.enqueue(PlayerAction::roll("1d20", "14"), "Roll 1d20");
+        queue.enqueue(PlayerAction::use_item("rope", None), "Use rope");
+        queue.take_next();
+
+        assert_eq!(queue.acknowledge("a1").unwrap().label, "Roll 1d20");
+        assert!(queue.in_flight().is_none());
+        assert_eq!(queue.acknowledged("a1").unwrap().label, "Roll 1d20");
+        assert!(queue.acknowledged("a2").is_none());
+        // Nothing in flight, nothing to acknowledge
+        assert!(queue.acknowledge("a2").is_none());
+        assert!(queue.take_next().is_some());
+    }
+
+    #[test]
+    fn cancels_only_unsent_actions() {
+        let mut queue = ActionQueue::new();
+        let sent = queue.enqueue(PlayerAction::examine("door"), "Examine door");
+        let waiting = queue.enqueue(PlayerAction::examine("chest"), "Examine chest");
+        queue.enqueue(PlayerAction::travel("market"), "Go to market");
+        queue.take_next();
+
+        assert!(!queue.cancel(sent));
+        assert!(queue.cancel(waiting));
+        assert!(!queue.cancel(waiting));
+        assert_eq!(labels(&queue), vec!["Go to market"]);
+        assert_eq!(queue.in_flight().unwrap().id, sent);
+    }
+}
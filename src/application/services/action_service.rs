@@ -36,6 +36,18 @@ impl ActionService {
         )
     }
 
+    /// Send an action as a character the DM has possessed
+    ///
+    /// The Engine attributes it to `character_id` and marks it DM-controlled.
+    pub fn send_possessed_action(&self, character_id: &str, action: PlayerAction) -> Result<()> {
+        self.connection.send_possessed_action(
+            character_id,
+            action.action_type.as_str(),
+            action.target.as_deref(),
+            action.dialogue.as_deref(),
+        )
+    }
+
     /// Send a dialogue choice selection
     pub fn select_choice(&self, choice_id: &str) -> Result<()> {
         let action = PlayerAction::dialogue_choice(choice_id);
@@ -72,6 +84,12 @@ impl ActionService {
         self.send_action(action)
     }
 
+    /// Share a dice roll result
+    pub fn send_roll(&self, notation: &str, result: &str) -> Result<()> {
+        let action = PlayerAction::roll(notation, result);
+        self.send_action(action)
+    }
+
     /// Get a reference to the underlying connection
     pub fn connection(&self) -> &dyn GameConnectionPort {
         self.connection.as_ref()
@@ -97,4 +115,17 @@ mod tests {
         assert_eq!(sent[0].action_type, "custom");
         assert_eq!(sent[0].dialogue.as_deref(), Some("hello"));
     }
+
+    #[test]
+    fn possessed_actions_carry_the_character() {
+        let conn = Arc::new(MockGameConnectionPort::new("ws://test/ws"));
+        let conn_dyn: Arc<dyn GameConnectionPort> = conn.clone();
+        let svc = ActionService::new(conn_dyn);
+
+        svc.send_possessed_action("npc-7", action_custom("Halt!")).unwrap();
+
+        let sent = conn.sent_actions();
+        assert_eq!(sent[0].acting_as.as_deref(), Some("npc-7"));
+        assert_eq!(sent[0].dialogue.as_deref(), Some("Halt!"));
+    }
 }
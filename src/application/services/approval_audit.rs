@@ -0,0 +1,317 @@
+//! Approval Audit - Every DM decision on an LLM response, kept per session
+//!
+//! The decision queue only shows the last few outcomes. The audit log keeps
+//! the whole record of a session's approvals on this device: what the NPC
+//! would have said, what was said instead, the feedback given on rejects
+//! and which proposed tools went through, so the DM can review afterwards
+//! what they changed and why.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::ProposedTool;
+use crate::application::ports::outbound::{storage_keys, ApprovalDecision, Platform};
+
+/// Most decisions kept per session
+pub const MAX_AUDIT_ENTRIES: usize = 1000;
+
+/// How the DM decided
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    Accepted,
+    Modified,
+    Rejected,
+    TakeOver,
+    /// A challenge outcome applied by the world's auto-resolve rules
+    AutoApplied,
+}
+
+impl AuditOutcome {
+    pub const ALL: [AuditOutcome; 5] = [
+        AuditOutcome::Accepted,
+        AuditOutcome::Modified,
+        AuditOutcome::Rejected,
+        AuditOutcome::TakeOver,
+        AuditOutcome::AutoApplied,
+    ];
+
+    pub fn of(decision: &ApprovalDecision) -> Self {
+        match decision {
+            ApprovalDecision::Accept => Self::Accepted,
+            ApprovalDecision::AcceptWithModification { .. } => Self::Modified,
+            ApprovalDecision::Reject { .. } => Self::Rejected,
+            ApprovalDecision::TakeOver { .. } => Self::TakeOver,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Accepted => "accepted",
+            Self::Modified => "modified",
+            Self::Rejected => "rejected",
+            Self::TakeOver => "takeover",
+            Self::AutoApplied => "auto-applied",
+        }
+    }
+}
+
+/// One recorded approval decision
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApprovalAuditEntry {
+    pub request_id: String,
+    pub npc_name: String,
+    pub outcome: AuditOutcome,
+    /// Dialogue the LLM proposed
+    #[serde(default)]
+    pub proposed_dialogue: String,
+    /// What was said instead, for modified responses and take-overs
+    #[serde(default)]
+    pub final_dialogue: Option<String>,
+    /// The DM's feedback on a rejected response
+    #[serde(default)]
+    pub feedback: Option<String>,
+    /// Names of the proposed tools that were approved
+    #[serde(default)]
+    pub approved_tools: Vec<String>,
+    /// Names of the proposed tools that were rejected
+    #[serde(default)]
+    pub rejected_tools: Vec<String>,
+    /// Why an auto-applied outcome skipped approval, e.g. "Pick Lock: 21 vs DC 15"
+    #[serde(default)]
+    pub auto_rule: Option<String>,
+    /// Unix timestamp (seconds) of the decision
+    pub timestamp: u64,
+}
+
+impl ApprovalAuditEntry {
+    /// Record a decision on a response that proposed `dialogue` and `tools`
+    ///
+    /// Tool IDs in the decision are turned into tool names. Accepting a
+    /// response as-is approves every proposed tool.
+    pub fn from_decision(
+        request_id: &str,
+        npc_name: &str,
+        dialogue: &str,
+        tools: &[ProposedTool],
+        decision: &ApprovalDecision,
+        timestamp: u64,
+    ) -> Self {
+        let tool_name = |id: &String| {
+            tools
+                .iter()
+                .find(|t| &t.id == id)
+                .map(|t| t.name.clone())
+                .unwrap_or_else(|| id.clone())
+        };
+
+        let mut entry = Self {
+            request_id: request_id.to_string(),
+            npc_name: npc_name.to_string(),
+            outcome: AuditOutcome::of(decision),
+            proposed_dialogue: dialogue.to_string(),
+            final_dialogue: None,
+            feedback: None,
+            approved_tools: Vec::new(),
+            rejected_tools: Vec::new(),
+            auto_rule: None,
+            timestamp,
+        };
+        match decision {
+            ApprovalDecision::Accept => {
+                entry.approved_tools = tools.iter().map(|t| t.name.clone()).collect();
+            }
+            ApprovalDecision::AcceptWithModification {
+                modified_dialogue,
+                approved_tools,
+                rejected_tools,
+            } => {
+                if modified_dialogue != dialogue {
+                    entry.final_dialogue = Some(modified_dialogue.clone());
+                }
+                entry.approved_tools = approved_tools.iter().map(tool_name).collect();
+                entry.rejected_tools = rejected_tools.iter().map(tool_name).collect();
+            }
+            ApprovalDecision::Reject { feedback } => {
+                entry.feedback = Some(feedback.clone()).filter(|f| !f.trim().is_empty());
+                entry.rejected_tools = tools.iter().map(|t| t.name.clone()).collect();
+            }
+            ApprovalDecision::TakeOver { dm_response } => {
+                entry.final_dialogue = Some(dm_response.clone());
+                entry.rejected_tools = tools.iter().map(|t| t.name.clone()).collect();
+            }
+        }
+        entry
+    }
+
+    /// Record a challenge outcome applied without the DM
+    ///
+    /// The character stands in for the NPC and the outcome description for
+    /// the dialogue, so the entry reads like any other in the history.
+    pub fn auto_applied_challenge(
+        resolution_id: &str,
+        character_name: &str,
+        challenge_name: &str,
+        outcome_description: &str,
+        total: i32,
+        margin: i32,
+        timestamp: u64,
+    ) -> Self {
+        Self {
+            request_id: resolution_id.to_string(),
+            npc_name: character_name.to_string(),
+            outcome: AuditOutcome::AutoApplied,
+            proposed_dialogue: outcome_description.to_string(),
+            final_dialogue: None,
+            feedback: None,
+            approved_tools: Vec::new(),
+            rejected_tools: Vec::new(),
+            auto_rule: Some(format!("{}: {} vs DC {} (beat by {})", challenge_name, total, total - margin, margin)),
+            timestamp,
+        }
+    }
+
+    /// Whether the entry mentions `query` in its NPC, dialogue or feedback
+    fn mentions(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        [
+            Some(&self.npc_name),
+            Some(&self.proposed_dialogue),
+            self.final_dialogue.as_ref(),
+            self.feedback.as_ref(),
+            self.auto_rule.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|text| text.to_lowercase().contains(&query))
+    }
+}
+
+/// Filters for the Approval History view
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditFilter {
+    pub outcome: Option<AuditOutcome>,
+    pub search: String,
+}
+
+impl AuditFilter {
+    pub fn matches(&self, entry: &ApprovalAuditEntry) -> bool {
+        self.outcome.is_none_or(|o| o == entry.outcome)
+            && (self.search.trim().is_empty() || entry.mentions(self.search.trim()))
+    }
+
+    /// Matching entries, newest first
+    pub fn apply<'a>(&self, entries: &'a [ApprovalAuditEntry]) -> Vec<&'a ApprovalAuditEntry> {
+        entries.iter().rev().filter(|e| self.matches(e)).collect()
+    }
+}
+
+/// How many entries have each outcome, in [`AuditOutcome::ALL`] order
+pub fn outcome_counts(entries: &[ApprovalAuditEntry]) -> Vec<(AuditOutcome, usize)> {
+    AuditOutcome::ALL
+        .iter()
+        .map(|o| (*o, entries.iter().filter(|e| e.outcome == *o).count()))
+        .collect()
+}
+
+fn audit_key(session_id: &str) -> String {
+    format!("{}{}", storage_keys::APPROVAL_AUDIT_PREFIX, session_id)
+}
+
+/// A session's audit log saved on this device
+pub fn load_approval_audit(platform: &Platform, session_id: &str) -> Vec<ApprovalAuditEntry> {
+    platform
+        .storage_load(&audit_key(session_id))
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Add a decision to a session's audit log, dropping the oldest past [`MAX_AUDIT_ENTRIES`]
+pub fn append_approval_audit(platform: &Platform, session_id: &str, entry: ApprovalAuditEntry) {
+    let mut entries = load_approval_audit(platform, session_id);
+    entries.push(entry);
+    let overflow = entries.len().saturating_sub(MAX_AUDIT_ENTRIES);
+    entries.drain(..overflow);
+    match serde_json::to_string(&entries) {
+        Ok(json) => platform.storage_save(&audit_key(session_id), &json),
+        Err(e) => platform.log_error(&format!("Failed to serialize approval audit: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tool(id: &str, name: &str) -> ProposedTool {
+        ProposedTool {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            arguments: serde_json::Value::Null,
+        }
+    }
+
+    fn entry(decision: &ApprovalDecision) -> ApprovalAuditEntry {
+        ApprovalAuditEntry::from_decision(
+            "r1",
+            "Marta",
+            "Welcome, traveller.",
+            &[tool("t1", "give_item"), tool("t2", "change_mood")],
+            decision,
+            100,
+        )
+    }
+
+    #[test]
+    fn modified_decisions_record_new_dialogue_and_tool_names() {
+        let e = entry(&ApprovalDecision::AcceptWithModification {
+            modified_dialogue: "Off with you.".to_string(),
+            approved_tools: vec!["t2".to_string()],
+            rejected_tools: vec!["t1".to_string()],
+        });
+        assert_eq!(e.outcome, AuditOutcome::Modified);
+        assert_eq!(e.final_dialogue.as_deref(), Some("Off with you."));
+        assert_eq!(e.approved_tools, vec!["change_mood"]);
+        assert_eq!(e.rejected_tools, vec!["give_item"]);
+    }
+
+    #[test]
+    fn accept_approves_every_tool_and_reject_keeps_feedback() {
+        let accepted = entry(&ApprovalDecision::Accept);
+        assert_eq!(accepted.approved_tools.len(), 2);
+        assert!(accepted.final_dialogue.is_none());
+
+        let rejected = entry(&ApprovalDecision::Reject { feedback: "Too friendly".to_string() });
+        assert_eq!(rejected.feedback.as_deref(), Some("Too friendly"));
+        assert_eq!(rejected.rejected_tools.len(), 2);
+    }
+
+    #[test]
+    fn filter_matches_outcome_and_text_newest_first() {
+        let mut older = entry(&ApprovalDecision::Reject { feedback: "Less formal".to_string() });
+        older.timestamp = 1;
+        let newer = entry(&ApprovalDecision::Accept);
+        let entries = vec![older, newer];
+
+        let all = AuditFilter::default().apply(&entries);
+        assert_eq!(all[0].outcome, AuditOutcome::Accepted);
+
+        let by_feedback = AuditFilter { search: "formal".to_string(), ..Default::default() };
+        assert_eq!(by_feedback.apply(&entries).len(), 1);
+
+        let rejected = AuditFilter { outcome: Some(AuditOutcome::Rejected), ..Default::default() };
+        assert_eq!(rejected.apply(&entries)[0].timestamp, 1);
+        assert_eq!(outcome_counts(&entries)[0], (AuditOutcome::Accepted, 1));
+    }
+
+    #[test]
+    fn auto_applied_challenges_record_the_rule_that_fired() {
+        let e = ApprovalAuditEntry::auto_applied_challenge("res-1", "Ada", "Pick Lock", "The lock clicks open.", 21, 6, 100);
+        assert_eq!(e.outcome, AuditOutcome::AutoApplied);
+        assert_eq!(e.auto_rule.as_deref(), Some("Pick Lock: 21 vs DC 15 (beat by 6)"));
+
+        // Entries saved before auto-resolve existed still load
+        let json = r#"{"request_id":"r1","npc_name":"Marta","outcome":"accepted","timestamp":1}"#;
+        let old: ApprovalAuditEntry = serde_json::from_str(json).unwrap();
+        assert!(old.auto_rule.is_none());
+    }
+}
@@ -0,0 +1,109 @@
+//! Archive - Shelving entities without deleting them
+//!
+//! Characters, locations, and narrative events can be archived when they are
+//! done with but may come back. Unlike the trash, archiving never purges
+//! anything: the entity keeps its ID, and whatever references it (scenes,
+//! relationships, trigger conditions) still resolves.
+//!
+//! Archived entities carry an `archived_at` timestamp. Plain list calls drop
+//! them with [`without_archived`], so default lists and pickers never offer
+//! them; the entity browser and event library load everything and filter
+//! with [`ArchiveFilter`].
+
+use crate::application::dto::NarrativeEventData;
+use crate::application::services::{CharacterSummary, LocationSummary};
+
+/// Entities that can be archived
+pub trait Archivable {
+    fn is_archived(&self) -> bool;
+}
+
+impl Archivable for CharacterSummary {
+    fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+}
+
+impl Archivable for LocationSummary {
+    fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+}
+
+impl Archivable for NarrativeEventData {
+    fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+}
+
+/// Drop archived entities from a list
+pub fn without_archived<T: Archivable>(mut items: Vec<T>) -> Vec<T> {
+    items.retain(|item| !item.is_archived());
+    items
+}
+
+/// `archived_at` for an entity archived at `now_unix_secs`, for updating a
+/// loaded list once the Engine has accepted the change
+pub fn archived_stamp(now_unix_secs: u64) -> String {
+    chrono::DateTime::from_timestamp(now_unix_secs as i64, 0)
+        .unwrap_or_default()
+        .to_rfc3339()
+}
+
+/// Which side of the archive a list shows
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ArchiveFilter {
+    /// Everything not archived
+    #[default]
+    Current,
+    /// Only archived entities
+    Archived,
+}
+
+impl ArchiveFilter {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Current => "Current",
+            Self::Archived => "Archived",
+        }
+    }
+
+    /// Whether `item` belongs in the list
+    pub fn matches<T: Archivable>(&self, item: &T) -> bool {
+        item.is_archived() == (*self == Self::Archived)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::EntityStyle;
+
+    fn character(id: &str, archived: bool) -> CharacterSummary {
+        CharacterSummary {
+            id: id.to_string(),
+            name: id.to_string(),
+            archetype: None,
+            tags: Vec::new(),
+            deleted_at: None,
+            archived_at: archived.then(|| "2026-01-01T00:00:00Z".to_string()),
+            style: EntityStyle::default(),
+        }
+    }
+
+    #[test]
+    fn plain_lists_drop_archived_entities() {
+        let kept = without_archived(vec![character("a", false), character("b", true)]);
+        assert_eq!(kept.iter().map(|c| c.id.as_str()).collect::<Vec<_>>(), vec!["a"]);
+    }
+
+    #[test]
+    fn filter_shows_one_side_of_the_archive() {
+        let current = character("a", false);
+        let archived = character("b", true);
+        assert!(ArchiveFilter::Current.matches(&current));
+        assert!(!ArchiveFilter::Current.matches(&archived));
+        assert!(ArchiveFilter::Archived.matches(&archived));
+        assert!(!ArchiveFilter::Archived.matches(&current));
+    }
+}
@@ -6,7 +6,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::application::dto::GenerationParams;
 use crate::application::ports::outbound::{ApiError, ApiPort};
+use crate::application::services::asset_storage::{AssetStorageReport, BulkDeleteResult};
+use crate::application::services::asset_upload::UploadAssetRequest;
 
 /// Asset data from API
 #[derive(Clone, Debug, PartialEq, Deserialize)]
@@ -17,8 +20,14 @@ pub struct Asset {
     pub is_active: bool,
     #[serde(default)]
     pub style_reference_id: Option<String>, // ID of asset used as style reference (if any)
+    /// Path the image is served from
+    #[serde(default)]
+    pub file_path: Option<String>,
 }
 
+/// Default img2img denoise strength when regenerating from an existing asset
+pub const DEFAULT_DENOISE: f32 = 0.6;
+
 /// Gallery response containing assets
 #[derive(Clone, Debug, Deserialize)]
 pub struct GalleryResponse {
@@ -39,6 +48,15 @@ pub struct GenerateRequest {
     pub count: u8,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub style_reference_id: Option<String>,
+    /// Existing asset to use as the img2img base instead of starting from noise
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_asset_id: Option<String>,
+    /// How far to move away from the source asset (0 keeps it, 1 ignores it)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub denoise: Option<f32>,
+    /// Overrides for the workflow slot's seed/steps/CFG/resolution defaults
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<GenerationParams>,
 }
 
 /// Asset service for managing entity assets
@@ -92,6 +110,37 @@ impl<A: ApiPort> AssetService<A> {
         self.api.delete(&path).await
     }
 
+    /// Store an uploaded image as an asset, bypassing generation
+    pub async fn upload_asset(
+        &self,
+        entity_type: &str,
+        entity_id: &str,
+        request: &UploadAssetRequest,
+    ) -> Result<Asset, ApiError> {
+        let path = format!("/api/{}/{}/gallery/upload", entity_type, entity_id);
+        self.api.post(&path, request).await
+    }
+
+    /// Fetch storage usage and quota for a world, with every stored asset
+    pub async fn get_storage_report(&self, world_id: &str) -> Result<AssetStorageReport, ApiError> {
+        let path = format!("/api/worlds/{}/assets/storage", world_id);
+        self.api.get(&path).await
+    }
+
+    /// Delete many assets at once
+    pub async fn bulk_delete_assets(
+        &self,
+        world_id: &str,
+        asset_ids: &[String],
+    ) -> Result<BulkDeleteResult, ApiError> {
+        #[derive(Serialize)]
+        struct BulkDeleteRequest<'a> {
+            asset_ids: &'a [String],
+        }
+        let path = format!("/api/worlds/{}/assets/bulk-delete", world_id);
+        self.api.post(&path, &BulkDeleteRequest { asset_ids }).await
+    }
+
     /// Queue asset generation
     pub async fn generate_assets(&self, request: &GenerateRequest) -> Result<(), ApiError> {
         self.api
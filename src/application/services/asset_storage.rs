@@ -0,0 +1,213 @@
+//! Asset Storage - Summarising a world's asset storage and planning cleanups
+//!
+//! The Engine reports every stored asset with its size and the world's quota.
+//! These helpers group that report for display, pick out assets that are safe
+//! to delete, and classify how close the world is to its quota.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// Fraction of the quota at which the DM is warned
+const WARNING_RATIO: f64 = 0.8;
+/// Fraction of the quota at which new generations may start failing
+const CRITICAL_RATIO: f64 = 0.95;
+
+/// A stored asset with its size
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct StoredAsset {
+    pub id: String,
+    pub entity_type: String,
+    pub entity_id: String,
+    #[serde(default)]
+    pub entity_name: Option<String>,
+    pub asset_type: String,
+    #[serde(default)]
+    pub label: Option<String>,
+    pub size_bytes: u64,
+    pub is_active: bool,
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+/// Storage usage for a world
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct AssetStorageReport {
+    pub used_bytes: u64,
+    /// Engine-side quota, if one is configured
+    #[serde(default)]
+    pub quota_bytes: Option<u64>,
+    #[serde(default)]
+    pub assets: Vec<StoredAsset>,
+}
+
+/// Result of a bulk delete
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct BulkDeleteResult {
+    #[serde(default)]
+    pub deleted: Vec<String>,
+    #[serde(default)]
+    pub freed_bytes: u64,
+}
+
+/// Which assets a cleanup targets
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CleanupFilter {
+    /// Inactive assets whose entity has an active asset of the same type
+    #[default]
+    Superseded,
+    /// Every inactive asset
+    Unused,
+}
+
+impl CleanupFilter {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Superseded, Self::Unused]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Superseded => "Superseded",
+            Self::Unused => "All inactive",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Superseded => "Inactive variants replaced by an active asset of the same type",
+            Self::Unused => "Every asset not currently in use, including an entity's only variants",
+        }
+    }
+}
+
+/// How close a world is to its storage quota
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuotaLevel {
+    Ok,
+    Warning,
+    Critical,
+}
+
+impl QuotaLevel {
+    /// Classify usage against an optional quota
+    pub fn for_usage(used_bytes: u64, quota_bytes: Option<u64>) -> Self {
+        let Some(quota) = quota_bytes.filter(|q| *q > 0) else {
+            return Self::Ok;
+        };
+        let ratio = used_bytes as f64 / quota as f64;
+        if ratio >= CRITICAL_RATIO {
+            Self::Critical
+        } else if ratio >= WARNING_RATIO {
+            Self::Warning
+        } else {
+            Self::Ok
+        }
+    }
+}
+
+/// Total size per key, largest first
+pub fn usage_by<F>(assets: &[StoredAsset], key: F) -> Vec<(String, u64)>
+where
+    F: Fn(&StoredAsset) -> String,
+{
+    let mut totals: HashMap<String, u64> = HashMap::new();
+    for asset in assets {
+        *totals.entry(key(asset)).or_default() += asset.size_bytes;
+    }
+    let mut totals: Vec<(String, u64)> = totals.into_iter().collect();
+    totals.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    totals
+}
+
+/// Assets a cleanup would delete
+pub fn cleanup_candidates(assets: &[StoredAsset], filter: CleanupFilter) -> Vec<&StoredAsset> {
+    assets
+        .iter()
+        .filter(|asset| !asset.is_active)
+        .filter(|asset| match filter {
+            CleanupFilter::Unused => true,
+            CleanupFilter::Superseded => assets.iter().any(|other| {
+                other.is_active
+                    && other.entity_type == asset.entity_type
+                    && other.entity_id == asset.entity_id
+                    && other.asset_type == asset.asset_type
+            }),
+        })
+        .collect()
+}
+
+/// Human-readable size, e.g. "1.5 MB"
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(id: &str, entity_id: &str, asset_type: &str, size_bytes: u64, is_active: bool) -> StoredAsset {
+        StoredAsset {
+            id: id.to_string(),
+            entity_type: "character".to_string(),
+            entity_id: entity_id.to_string(),
+            entity_name: None,
+            asset_type: asset_type.to_string(),
+            label: None,
+            size_bytes,
+            is_active,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn picks_cleanup_candidates() {
+        let assets = vec![
+            asset("a1", "mira", "portrait", 100, true),
+            asset("a2", "mira", "portrait", 100, false),
+            asset("a3", "mira", "sprite", 100, false),
+            asset("a4", "kett", "portrait", 100, false),
+        ];
+        let ids = |filter| {
+            cleanup_candidates(&assets, filter)
+                .into_iter()
+                .map(|a| a.id.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(CleanupFilter::Superseded), vec!["a2"]);
+        assert_eq!(ids(CleanupFilter::Unused), vec!["a2", "a3", "a4"]);
+    }
+
+    #[test]
+    fn groups_usage_largest_first() {
+        let assets = vec![
+            asset("a1", "mira", "portrait", 100, true),
+            asset("a2", "mira", "sprite", 300, true),
+            asset("a3", "kett", "portrait", 50, true),
+        ];
+        assert_eq!(
+            usage_by(&assets, |a| a.asset_type.clone()),
+            vec![("sprite".to_string(), 300), ("portrait".to_string(), 150)]
+        );
+    }
+
+    #[test]
+    fn classifies_quota_and_formats_sizes() {
+        assert_eq!(QuotaLevel::for_usage(50, None), QuotaLevel::Ok);
+        assert_eq!(QuotaLevel::for_usage(79, Some(100)), QuotaLevel::Ok);
+        assert_eq!(QuotaLevel::for_usage(80, Some(100)), QuotaLevel::Warning);
+        assert_eq!(QuotaLevel::for_usage(99, Some(100)), QuotaLevel::Critical);
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MB");
+    }
+}
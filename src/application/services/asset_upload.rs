@@ -0,0 +1,127 @@
+//! Asset Upload - Checking image files before they become manual assets
+//!
+//! Creators can drop their own artwork onto an entity instead of generating
+//! it. Files are checked here before anything is sent: only still image
+//! formats the Engine can serve are accepted, each asset slot has a largest
+//! useful size, and oversized images are scaled down client-side so uploads
+//! stay small.
+
+use serde::Serialize;
+
+/// Largest file accepted before resizing
+pub const MAX_UPLOAD_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Image formats that can be uploaded
+const ACCEPTED_MIME_TYPES: &[&str] = &["image/png", "image/jpeg", "image/webp"];
+
+/// Request to store an uploaded image as an entity asset
+#[derive(Clone, Debug, Serialize)]
+pub struct UploadAssetRequest {
+    /// World this asset belongs to
+    pub world_id: String,
+    pub asset_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub filename: String,
+    pub mime_type: String,
+    pub data_base64: String,
+    /// Make the upload the entity's active asset for its slot
+    pub activate: bool,
+}
+
+/// Why a file can't be uploaded
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UploadRejection {
+    /// Not a PNG, JPEG or WebP image
+    UnsupportedFormat(String),
+    TooLarge { size_bytes: u64 },
+    /// The slot holds generated media only, e.g. animated backdrops
+    SlotNotUploadable(String),
+}
+
+impl std::fmt::Display for UploadRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedFormat(mime) => write!(f, "{} files can't be uploaded; use PNG, JPEG or WebP", mime),
+            Self::TooLarge { size_bytes } => write!(
+                f,
+                "File is too large ({} MB, max {} MB)",
+                size_bytes.div_ceil(1024 * 1024),
+                MAX_UPLOAD_BYTES / (1024 * 1024)
+            ),
+            Self::SlotNotUploadable(asset_type) => write!(f, "{} assets can't be uploaded", asset_type.replace('_', " ")),
+        }
+    }
+}
+
+/// Longest side an image in `asset_type` is stored at, None if the slot
+/// doesn't take uploads
+pub fn max_dimension_for(asset_type: &str) -> Option<u32> {
+    match asset_type {
+        "portrait" | "sprite" => Some(1024),
+        "backdrop" | "emotion_sheet" => Some(2048),
+        _ => None,
+    }
+}
+
+/// MIME type of a dropped file, guessed from its name when the platform
+/// doesn't report one
+pub fn upload_mime_type(filename: &str, reported: Option<&str>) -> String {
+    if let Some(mime) = reported.filter(|m| !m.is_empty()) {
+        return mime.to_ascii_lowercase();
+    }
+    let extension = filename.rsplit_once('.').map(|(_, ext)| ext.to_ascii_lowercase()).unwrap_or_default();
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "webp" => "image/webp",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        _ => "application/octet-stream",
+    }
+    .to_string()
+}
+
+/// Check a file for an asset slot, returning the size to scale it down to
+pub fn validate_upload(asset_type: &str, mime_type: &str, size_bytes: u64) -> Result<u32, UploadRejection> {
+    let max_dimension =
+        max_dimension_for(asset_type).ok_or_else(|| UploadRejection::SlotNotUploadable(asset_type.to_string()))?;
+    if !ACCEPTED_MIME_TYPES.contains(&mime_type) {
+        return Err(UploadRejection::UnsupportedFormat(mime_type.to_string()));
+    }
+    if size_bytes > MAX_UPLOAD_BYTES {
+        return Err(UploadRejection::TooLarge { size_bytes });
+    }
+    Ok(max_dimension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uploads_are_checked_against_their_slot() {
+        assert_eq!(validate_upload("portrait", "image/png", 1024), Ok(1024));
+        assert_eq!(validate_upload("backdrop", "image/webp", 1024), Ok(2048));
+        assert_eq!(
+            validate_upload("animated_backdrop", "image/png", 1024),
+            Err(UploadRejection::SlotNotUploadable("animated_backdrop".to_string()))
+        );
+        assert_eq!(
+            validate_upload("sprite", "image/gif", 1024),
+            Err(UploadRejection::UnsupportedFormat("image/gif".to_string()))
+        );
+        assert_eq!(
+            validate_upload("sprite", "image/png", MAX_UPLOAD_BYTES + 1),
+            Err(UploadRejection::TooLarge { size_bytes: MAX_UPLOAD_BYTES + 1 })
+        );
+    }
+
+    #[test]
+    fn mime_type_falls_back_to_the_file_extension() {
+        assert_eq!(upload_mime_type("tavern.JPG", None), "image/jpeg");
+        assert_eq!(upload_mime_type("tavern.png", Some("")), "image/png");
+        assert_eq!(upload_mime_type("tavern", Some("Image/WebP")), "image/webp");
+        assert_eq!(upload_mime_type("notes.txt", None), "application/octet-stream");
+    }
+}
@@ -0,0 +1,168 @@
+//! Auth Service - Application service for Engine authentication
+//!
+//! This service logs the Player in to the Engine (with either an access token
+//! or a username/password pair), and manages the resulting session token.
+//! The token is persisted via the Platform storage port and handed to the
+//! Engine configuration provider so that infrastructure adapters can attach
+//! it to HTTP requests and the WebSocket handshake.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::outbound::{storage_keys, ApiError, ApiPort, Platform};
+
+/// Credentials used to log in to the Engine
+///
+/// Passwords are only ever sent to the Engine; they are never persisted.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum LoginCredentials {
+    /// Pre-issued access token (e.g. generated by the Engine admin)
+    Token { token: String },
+    /// Username and password
+    Password { username: String, password: String },
+}
+
+/// Identity of the logged-in user
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuthIdentity {
+    pub user_id: String,
+    pub display_name: String,
+}
+
+/// Session returned by the Engine after a successful login
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuthSession {
+    /// Bearer token to attach to subsequent requests
+    pub token: String,
+    /// Who the token belongs to
+    pub identity: AuthIdentity,
+    /// Expiry as Unix timestamp in seconds (None = does not expire)
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+}
+
+impl AuthSession {
+    /// Whether this session has expired at the given time
+    pub fn is_expired(&self, now_unix_secs: u64) -> bool {
+        self.expires_at.is_some_and(|exp| exp <= now_unix_secs)
+    }
+}
+
+/// Auth service for logging in and out of the Engine
+///
+/// This service provides methods for authentication operations
+/// while depending only on the `ApiPort` trait, not concrete
+/// infrastructure implementations.
+pub struct AuthService<A: ApiPort> {
+    api: A,
+}
+
+impl<A: ApiPort> AuthService<A> {
+    /// Create a new AuthService with the given API port
+    pub fn new(api: A) -> Self {
+        Self { api }
+    }
+
+    /// Log in with the given credentials
+    ///
+    /// # Returns
+    /// The session issued by the Engine. Callers should persist it with
+    /// [`persist_auth_session`] so the token is attached to later requests.
+    pub async fn login(&self, credentials: &LoginCredentials) -> Result<AuthSession, ApiError> {
+        self.api.post("/api/auth/login", credentials).await
+    }
+
+    /// Invalidate the current session token on the Engine
+    pub async fn logout(&self) -> Result<(), ApiError> {
+        self.api.post_empty("/api/auth/logout").await
+    }
+
+    /// Fetch the identity associated with the current token
+    pub async fn current_identity(&self) -> Result<AuthIdentity, ApiError> {
+        self.api.get("/api/auth/me").await
+    }
+}
+
+impl<A: ApiPort + Clone> Clone for AuthService<A> {
+    fn clone(&self) -> Self {
+        Self {
+            api: self.api.clone(),
+        }
+    }
+}
+
+/// Persist an auth session and activate its token for outgoing requests
+pub fn persist_auth_session(platform: &Platform, session: &AuthSession) {
+    match serde_json::to_string(session) {
+        Ok(json) => platform.storage_save(storage_keys::AUTH_SESSION, &json),
+        Err(e) => platform.log_error(&format!("Failed to serialize auth session: {}", e)),
+    }
+    platform.set_auth_token(Some(&session.token));
+}
+
+/// Restore a previously persisted auth session, activating its token
+///
+/// Expired sessions are discarded and `None` is returned.
+pub fn restore_auth_session(platform: &Platform) -> Option<AuthSession> {
+    let json = platform.storage_load(storage_keys::AUTH_SESSION)?;
+    let session = match serde_json::from_str::<AuthSession>(&json) {
+        Ok(session) => session,
+        Err(e) => {
+            platform.log_warn(&format!("Discarding unreadable auth session: {}", e));
+            clear_auth_session(platform);
+            return None;
+        }
+    };
+
+    if session.is_expired(platform.now_unix_secs()) {
+        clear_auth_session(platform);
+        return None;
+    }
+
+    platform.set_auth_token(Some(&session.token));
+    Some(session)
+}
+
+/// Remove the persisted auth session and stop attaching its token
+pub fn clear_auth_session(platform: &Platform) {
+    platform.storage_remove(storage_keys::AUTH_SESSION);
+    platform.set_auth_token(None);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(expires_at: Option<u64>) -> AuthSession {
+        AuthSession {
+            token: "tok".to_string(),
+            identity: AuthIdentity {
+                user_id: "u1".to_string(),
+                display_name: "Alice".to_string(),
+            },
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn test_session_expiry() {
+        assert!(!session(None).is_expired(1_000));
+        assert!(!session(Some(2_000)).is_expired(1_000));
+        assert!(session(Some(1_000)).is_expired(1_000));
+    }
+
+    #[test]
+    fn test_credentials_wire_format() {
+        let creds = LoginCredentials::Password {
+            username: "alice".to_string(),
+            password: "secret".to_string(),
+        };
+        let json = serde_json::to_value(&creds).unwrap();
+        assert_eq!(json["method"], "password");
+        assert_eq!(json["username"], "alice");
+
+        let creds = LoginCredentials::Token { token: "abc".to_string() };
+        let json = serde_json::to_value(&creds).unwrap();
+        assert_eq!(json["method"], "token");
+    }
+}
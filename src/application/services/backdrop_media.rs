@@ -0,0 +1,123 @@
+//! Backdrop Media - Choosing between static and animated scene backdrops
+//!
+//! A location can reference a looping video or an animated image alongside
+//! its static backdrop. Whether the animated version plays is a per-device
+//! preference: it can be turned off entirely, or limited by data saver to
+//! animated images, which are far smaller than video loops.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::outbound::{storage_keys, Platform};
+
+/// File extensions played through a `<video>` element
+const VIDEO_EXTENSIONS: [&str; 3] = ["webm", "mp4", "mov"];
+
+/// How an animated backdrop is rendered
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BackdropMediaKind {
+    /// Looping video (webm, mp4)
+    Video,
+    /// Animated image (webp, gif, apng)
+    Image,
+}
+
+impl BackdropMediaKind {
+    /// Detect the media kind from an asset URL's extension
+    pub fn from_url(url: &str) -> Self {
+        let path = url.split(['?', '#']).next().unwrap_or(url);
+        let extension = path.rsplit_once('.').map(|(_, ext)| ext.to_ascii_lowercase());
+        match extension {
+            Some(ext) if VIDEO_EXTENSIONS.contains(&ext.as_str()) => Self::Video,
+            _ => Self::Image,
+        }
+    }
+}
+
+/// Per-device backdrop preferences
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackdropPreferences {
+    /// Play animated backdrops at all
+    pub animated: bool,
+    /// Skip video loops to save bandwidth
+    pub data_saver: bool,
+}
+
+impl Default for BackdropPreferences {
+    fn default() -> Self {
+        Self {
+            animated: true,
+            data_saver: false,
+        }
+    }
+}
+
+/// The animated backdrop to play, if the preferences allow it
+pub fn choose_animated_backdrop(
+    animated_url: Option<&str>,
+    preferences: &BackdropPreferences,
+) -> Option<(String, BackdropMediaKind)> {
+    if !preferences.animated {
+        return None;
+    }
+    let url = animated_url.filter(|u| !u.is_empty())?;
+    let kind = BackdropMediaKind::from_url(url);
+    if preferences.data_saver && kind == BackdropMediaKind::Video {
+        return None;
+    }
+    Some((url.to_string(), kind))
+}
+
+/// Load backdrop preferences saved on this device
+pub fn load_backdrop_preferences(platform: &Platform) -> BackdropPreferences {
+    platform
+        .storage_load(storage_keys::BACKDROP_PREFERENCES)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Save backdrop preferences on this device
+pub fn save_backdrop_preferences(platform: &Platform, preferences: &BackdropPreferences) {
+    match serde_json::to_string(preferences) {
+        Ok(json) => platform.storage_save(storage_keys::BACKDROP_PREFERENCES, &json),
+        Err(e) => platform.log_error(&format!("Failed to serialize backdrop preferences: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_media_kind() {
+        assert_eq!(BackdropMediaKind::from_url("/assets/tavern.webm"), BackdropMediaKind::Video);
+        assert_eq!(BackdropMediaKind::from_url("/assets/tavern.MP4?v=2"), BackdropMediaKind::Video);
+        assert_eq!(BackdropMediaKind::from_url("/assets/tavern.webp"), BackdropMediaKind::Image);
+        assert_eq!(BackdropMediaKind::from_url("/assets/tavern"), BackdropMediaKind::Image);
+    }
+
+    #[test]
+    fn respects_preferences() {
+        let video = Some("/assets/rain.webm");
+        let image = Some("/assets/rain.webp");
+        let defaults = BackdropPreferences::default();
+        let saver = BackdropPreferences {
+            data_saver: true,
+            ..defaults
+        };
+        let off = BackdropPreferences {
+            animated: false,
+            ..defaults
+        };
+
+        assert_eq!(
+            choose_animated_backdrop(video, &defaults),
+            Some(("/assets/rain.webm".to_string(), BackdropMediaKind::Video))
+        );
+        assert_eq!(choose_animated_backdrop(video, &saver), None);
+        assert!(choose_animated_backdrop(image, &saver).is_some());
+        assert_eq!(choose_animated_backdrop(image, &off), None);
+        assert_eq!(choose_animated_backdrop(Some(""), &defaults), None);
+        assert_eq!(choose_animated_backdrop(None, &defaults), None);
+    }
+}
@@ -0,0 +1,200 @@
+//! Bug Report Service - Collect diagnostics and submit issue reports
+//!
+//! Bundles the user's description with recent logs, the app version, platform
+//! info, a redacted view of the connection, and an optional screenshot. The
+//! bundle can be saved locally or posted to the configured endpoint.
+
+use serde::Serialize;
+
+use crate::application::ports::outbound::{storage_keys, ApiError, ApiPort, LogRecord, Platform};
+
+/// Version of this build
+pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Where reports go unless another endpoint has been configured
+pub const DEFAULT_BUG_REPORT_ENDPOINT: &str = "/api/bug-reports";
+
+/// Most recent log lines included in a report
+const MAX_REPORT_LOGS: usize = 200;
+
+/// Connection details with identifying parts removed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConnectionSnapshot {
+    pub status: String,
+    /// Scheme, host and port only
+    pub server: Option<String>,
+    pub session_id: Option<String>,
+    /// Truncated user ID
+    pub user_id: Option<String>,
+    pub role: Option<String>,
+}
+
+impl ConnectionSnapshot {
+    /// Build a snapshot, dropping URL paths/credentials and shortening the user ID
+    pub fn redacted(
+        status: &str,
+        server_url: Option<&str>,
+        session_id: Option<&str>,
+        user_id: Option<&str>,
+        role: Option<&str>,
+    ) -> Self {
+        Self {
+            status: status.to_string(),
+            server: server_url.map(redact_url),
+            session_id: session_id.map(str::to_string),
+            user_id: user_id.map(redact_id),
+            role: role.map(str::to_string),
+        }
+    }
+}
+
+/// An image attached to a report
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScreenshotAttachment {
+    pub filename: String,
+    pub mime_type: String,
+    pub data_base64: String,
+}
+
+/// Everything sent with a bug report
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiagnosticBundle {
+    pub app_version: String,
+    pub platform: String,
+    /// Unix seconds
+    pub created_at: u64,
+    pub description: String,
+    pub connection: ConnectionSnapshot,
+    pub logs: Vec<LogRecord>,
+    pub screenshot: Option<ScreenshotAttachment>,
+}
+
+impl DiagnosticBundle {
+    /// Collect a bundle from the platform's recent logs and runtime info
+    pub fn collect(
+        platform: &Platform,
+        description: String,
+        connection: ConnectionSnapshot,
+        screenshot: Option<ScreenshotAttachment>,
+    ) -> Self {
+        let mut logs = platform.recent_logs();
+        let skip = logs.len().saturating_sub(MAX_REPORT_LOGS);
+        logs.drain(..skip);
+        for log in &mut logs {
+            log.message = redact_secrets(&log.message);
+        }
+
+        Self {
+            app_version: APP_VERSION.to_string(),
+            platform: platform.platform_description(),
+            created_at: platform.now_unix_secs(),
+            description,
+            connection,
+            logs,
+            screenshot,
+        }
+    }
+
+    /// Suggested filename when saving the bundle locally
+    pub fn filename(&self) -> String {
+        format!("wrldbldr-report-{}.json", self.created_at)
+    }
+}
+
+/// Configured report endpoint (Engine path or absolute URL)
+pub fn bug_report_endpoint(platform: &Platform) -> String {
+    platform
+        .storage_load(storage_keys::BUG_REPORT_ENDPOINT)
+        .filter(|endpoint| !endpoint.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_BUG_REPORT_ENDPOINT.to_string())
+}
+
+/// Keep only scheme, host and port of a URL
+fn redact_url(url: &str) -> String {
+    match url::Url::parse(url) {
+        Ok(parsed) => {
+            let host = parsed.host_str().unwrap_or_default();
+            match parsed.port() {
+                Some(port) => format!("{}://{}:{}", parsed.scheme(), host, port),
+                None => format!("{}://{}", parsed.scheme(), host),
+            }
+        }
+        Err(_) => "<invalid url>".to_string(),
+    }
+}
+
+/// Keep enough of an ID to correlate reports without exposing it
+fn redact_id(id: &str) -> String {
+    let prefix: String = id.chars().take(8).collect();
+    if prefix.len() < id.len() {
+        format!("{}…", prefix)
+    } else {
+        prefix
+    }
+}
+
+/// Mask auth tokens that may appear in logged URLs or headers
+pub fn redact_secrets(line: &str) -> String {
+    let mut out = line.to_string();
+    for marker in ["token=", "Bearer "] {
+        let mut search_from = 0;
+        while let Some(found) = out[search_from..].find(marker) {
+            let start = search_from + found + marker.len();
+            let end = out[start..]
+                .find(|c: char| c == '&' || c == '"' || c.is_whitespace())
+                .map(|i| start + i)
+                .unwrap_or(out.len());
+            out.replace_range(start..end, "<redacted>");
+            search_from = start + "<redacted>".len();
+        }
+    }
+    out
+}
+
+/// Bug report service for submitting diagnostic bundles
+///
+/// This service depends only on the `ApiPort` trait, not concrete
+/// infrastructure implementations.
+pub struct BugReportService<A: ApiPort> {
+    api: A,
+}
+
+impl<A: ApiPort> BugReportService<A> {
+    /// Create a new BugReportService with the given API port
+    pub fn new(api: A) -> Self {
+        Self { api }
+    }
+
+    /// Post a bundle to `endpoint`
+    pub async fn submit(&self, endpoint: &str, bundle: &DiagnosticBundle) -> Result<(), ApiError> {
+        self.api.post_no_response(endpoint, bundle).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_snapshot_is_redacted() {
+        let snapshot = ConnectionSnapshot::redacted(
+            "Connected",
+            Some("wss://user:pw@engine.example.com:8443/ws?token=abc"),
+            Some("session-1"),
+            Some("user-1234567890abcdef"),
+            Some("Player"),
+        );
+        assert_eq!(snapshot.server.as_deref(), Some("wss://engine.example.com:8443"));
+        assert_eq!(snapshot.user_id.as_deref(), Some("user-123…"));
+        assert_eq!(redact_id("short"), "short");
+    }
+
+    #[test]
+    fn test_secrets_are_masked_in_log_lines() {
+        assert_eq!(
+            redact_secrets("connecting to ws://h/ws?token=abc123&x=1 with Bearer xyz"),
+            "connecting to ws://h/ws?token=<redacted>&x=1 with Bearer <redacted>"
+        );
+        assert_eq!(redact_secrets("nothing to hide"), "nothing to hide");
+    }
+}
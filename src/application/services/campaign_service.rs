@@ -0,0 +1,298 @@
+//! Campaign Service - Campaigns that span several worlds
+//!
+//! A campaign groups the worlds one table plays through, in play order.
+//! Player characters are shared across a campaign's worlds and migrate
+//! from one to the next with their sheets, and the campaign timeline
+//! merges every world's story events into one history.
+
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::StoryEventData;
+use crate::application::ports::outbound::{ApiError, ApiPort};
+use crate::application::services::player_character_service::PlayerCharacterData;
+use crate::application::services::story_event_service::PaginatedStoryEventsResponse;
+
+/// A campaign and the worlds in it
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Campaign {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Worlds in play order
+    #[serde(default)]
+    pub world_ids: Vec<String>,
+}
+
+impl Campaign {
+    /// Whether `world_id` is part of the campaign
+    pub fn contains(&self, world_id: &str) -> bool {
+        self.world_ids.iter().any(|id| id == world_id)
+    }
+
+    /// 1-based place of `world_id` in play order
+    pub fn chapter_of(&self, world_id: &str) -> Option<usize> {
+        self.world_ids.iter().position(|id| id == world_id).map(|i| i + 1)
+    }
+}
+
+/// Request to create or update a campaign
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SaveCampaignRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub world_ids: Vec<String>,
+}
+
+impl SaveCampaignRequest {
+    /// Check the request before it is sent
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Campaign name is required".to_string());
+        }
+        if self.world_ids.is_empty() {
+            return Err("Pick at least one world".to_string());
+        }
+        for (i, id) in self.world_ids.iter().enumerate() {
+            if self.world_ids[..i].contains(id) {
+                return Err("A world can only be in a campaign once".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Request to move a shared PC into another of the campaign's worlds
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MigratePcRequest {
+    pub target_world_id: String,
+    /// Where the PC arrives; the Engine picks the world's default otherwise
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_location_id: Option<String>,
+}
+
+/// A story event on the campaign timeline, with the world it happened in
+#[derive(Clone, Debug)]
+pub struct CampaignEvent {
+    pub world_id: String,
+    pub world_name: String,
+    pub event: StoryEventData,
+}
+
+/// Merge each world's story events into one campaign timeline, newest first
+///
+/// `worlds` pairs a world's name with its events. Timestamps are compared
+/// as instants, so worlds recorded with different UTC offsets interleave
+/// correctly; unparseable timestamps sort last.
+pub fn merge_campaign_timeline(worlds: Vec<(String, Vec<StoryEventData>)>) -> Vec<CampaignEvent> {
+    let mut merged: Vec<CampaignEvent> = worlds
+        .into_iter()
+        .flat_map(|(world_name, events)| {
+            events.into_iter().map(move |event| CampaignEvent {
+                world_id: event.world_id.clone(),
+                world_name: world_name.clone(),
+                event,
+            })
+        })
+        .collect();
+    merged.sort_by_key(|e| {
+        std::cmp::Reverse(
+            DateTime::parse_from_rfc3339(&e.event.timestamp)
+                .map(|t| t.timestamp_millis())
+                .unwrap_or(i64::MIN),
+        )
+    });
+    merged
+}
+
+/// Campaign service for grouping worlds and sharing PCs between them
+///
+/// This service provides methods for campaign-related operations
+/// while depending only on the `ApiPort` trait, not concrete
+/// infrastructure implementations.
+pub struct CampaignService<A: ApiPort> {
+    api: A,
+}
+
+impl<A: ApiPort> CampaignService<A> {
+    /// Create a new CampaignService with the given API port
+    pub fn new(api: A) -> Self {
+        Self { api }
+    }
+
+    /// List all campaigns
+    pub async fn list_campaigns(&self) -> Result<Vec<Campaign>, ApiError> {
+        self.api.get("/api/campaigns").await
+    }
+
+    /// Create a campaign
+    pub async fn create_campaign(&self, request: &SaveCampaignRequest) -> Result<Campaign, ApiError> {
+        self.api.post("/api/campaigns", request).await
+    }
+
+    /// Update a campaign's name, description or worlds
+    pub async fn update_campaign(
+        &self,
+        campaign_id: &str,
+        request: &SaveCampaignRequest,
+    ) -> Result<Campaign, ApiError> {
+        let path = format!("/api/campaigns/{}", campaign_id);
+        self.api.put(&path, request).await
+    }
+
+    /// Delete a campaign; its worlds are kept
+    pub async fn delete_campaign(&self, campaign_id: &str) -> Result<(), ApiError> {
+        let path = format!("/api/campaigns/{}", campaign_id);
+        self.api.delete(&path).await
+    }
+
+    /// List the PCs shared across a campaign's worlds
+    pub async fn list_shared_pcs(&self, campaign_id: &str) -> Result<Vec<PlayerCharacterData>, ApiError> {
+        let path = format!("/api/campaigns/{}/player-characters", campaign_id);
+        self.api.get(&path).await
+    }
+
+    /// Move a shared PC into another world of the campaign
+    pub async fn migrate_pc(
+        &self,
+        campaign_id: &str,
+        pc_id: &str,
+        request: &MigratePcRequest,
+    ) -> Result<PlayerCharacterData, ApiError> {
+        let path = format!("/api/campaigns/{}/player-characters/{}/migrate", campaign_id, pc_id);
+        self.api.post(&path, request).await
+    }
+
+    /// Story events from every world in the campaign, newest first
+    ///
+    /// `world_name` names each world for the timeline; worlds it doesn't
+    /// know are shown by ID.
+    pub async fn campaign_timeline(
+        &self,
+        campaign: &Campaign,
+        world_name: impl Fn(&str) -> Option<String>,
+    ) -> Result<Vec<CampaignEvent>, ApiError> {
+        let mut worlds = Vec::with_capacity(campaign.world_ids.len());
+        for world_id in &campaign.world_ids {
+            let path = format!("/api/worlds/{}/story-events", world_id);
+            let page: PaginatedStoryEventsResponse = self.api.get(&path).await?;
+            let name = world_name(world_id).unwrap_or_else(|| world_id.clone());
+            worlds.push((name, page.events));
+        }
+        Ok(merge_campaign_timeline(worlds))
+    }
+}
+
+impl<A: ApiPort + Clone> Clone for CampaignService<A> {
+    fn clone(&self) -> Self {
+        Self {
+            api: self.api.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::testing::MockApiPort;
+
+    fn event(id: &str, world_id: &str, timestamp: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "world_id": world_id,
+            "session_id": "s1",
+            "scene_id": null,
+            "location_id": null,
+            "event_type": { "type": "dm_marker", "title": id, "note": "", "importance": "minor", "marker_type": "note" },
+            "timestamp": timestamp,
+            "game_time": null,
+            "summary": id,
+            "involved_characters": [],
+            "is_hidden": false,
+            "tags": [],
+            "triggered_by": null
+        })
+    }
+
+    fn page(events: Vec<serde_json::Value>) -> serde_json::Value {
+        serde_json::json!({ "events": events, "total": 0, "limit": 50, "offset": 0 })
+    }
+
+    #[test]
+    fn validate_needs_a_name_and_distinct_worlds() {
+        let request = SaveCampaignRequest {
+            name: "The Long Road".to_string(),
+            description: None,
+            world_ids: vec!["w1".to_string(), "w2".to_string()],
+        };
+        assert!(request.validate().is_ok());
+        assert!(SaveCampaignRequest { name: " ".to_string(), ..request.clone() }.validate().is_err());
+        assert!(SaveCampaignRequest { world_ids: vec![], ..request.clone() }.validate().is_err());
+        let repeated = vec!["w1".to_string(), "w1".to_string()];
+        assert!(SaveCampaignRequest { world_ids: repeated, ..request }.validate().is_err());
+    }
+
+    #[test]
+    fn chapters_follow_play_order() {
+        let campaign = Campaign {
+            world_ids: vec!["w1".to_string(), "w2".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(campaign.chapter_of("w2"), Some(2));
+        assert_eq!(campaign.chapter_of("w3"), None);
+        assert!(campaign.contains("w1"));
+    }
+
+    #[tokio::test]
+    async fn campaign_timeline_interleaves_worlds_newest_first() {
+        let api = MockApiPort::new();
+        api.when_get_json(
+            "/api/worlds/w1/story-events",
+            page(vec![event("a", "w1", "2024-01-01T10:00:00+00:00"), event("c", "w1", "2024-01-03T10:00:00+00:00")]),
+        );
+        api.when_get_json(
+            "/api/worlds/w2/story-events",
+            // 2024-01-02T10:00 UTC, recorded with an offset
+            page(vec![event("b", "w2", "2024-01-02T12:00:00+02:00"), event("x", "w2", "not a time")]),
+        );
+        let campaign = Campaign {
+            id: "c1".to_string(),
+            world_ids: vec!["w1".to_string(), "w2".to_string()],
+            ..Default::default()
+        };
+
+        let svc = CampaignService::new(api);
+        let timeline = svc
+            .campaign_timeline(&campaign, |id| (id == "w1").then(|| "Eldmoor".to_string()))
+            .await
+            .expect("timeline");
+
+        let ids: Vec<&str> = timeline.iter().map(|e| e.event.id.as_str()).collect();
+        assert_eq!(ids, ["c", "b", "a", "x"]);
+        assert_eq!(timeline[0].world_name, "Eldmoor");
+        assert_eq!(timeline[1].world_name, "w2");
+    }
+
+    #[tokio::test]
+    async fn migrate_pc_posts_the_target_world() {
+        let api = MockApiPort::new();
+        api.when_post_json(
+            "/api/campaigns/c1/player-characters/pc-1/migrate",
+            serde_json::json!({
+                "id": "pc-1", "session_id": "s2", "user_id": "u1", "world_id": "w2", "name": "Ash",
+                "current_location_id": "l9", "starting_location_id": "l9",
+                "created_at": "2024-01-01T00:00:00Z", "last_active_at": "2024-01-01T00:00:00Z"
+            }),
+        );
+
+        let svc = CampaignService::new(api.clone());
+        let request = MigratePcRequest { target_world_id: "w2".to_string(), starting_location_id: None };
+        let moved = svc.migrate_pc("c1", "pc-1", &request).await.expect("migrated");
+
+        assert_eq!(moved.world_id, "w2");
+        let body = api.requests()[0].body.clone().expect("body");
+        assert_eq!(body, serde_json::json!({ "target_world_id": "w2" }));
+    }
+}
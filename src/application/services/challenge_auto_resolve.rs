@@ -0,0 +1,53 @@
+//! Challenge Auto-Resolve - Which challenge outcomes skip the DM's approval
+//!
+//! Every resolved roll normally waits in the decision queue. With the
+//! world's auto-resolve rules on, a success whose total beats the DC by at
+//! least the configured margin is accepted straight away. Anything closer,
+//! any failure and any challenge without a numeric DC still goes to the DM.
+
+use crate::application::dto::ChallengeAutoResolveRules;
+
+/// Outcome types that can be applied without the DM
+const AUTO_APPLICABLE_OUTCOMES: &[&str] = &["success", "critical_success"];
+
+/// How far a pending outcome beat its DC, if the rules apply it without the DM
+pub fn auto_apply_margin(
+    rules: &ChallengeAutoResolveRules,
+    outcome_type: &str,
+    total: i32,
+    difficulty_class: Option<i32>,
+) -> Option<i32> {
+    if !rules.enabled || !AUTO_APPLICABLE_OUTCOMES.contains(&outcome_type) {
+        return None;
+    }
+    let margin = total - difficulty_class?;
+    (margin >= rules.min_margin as i32).then_some(margin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(min_margin: u32) -> ChallengeAutoResolveRules {
+        ChallengeAutoResolveRules { enabled: true, min_margin }
+    }
+
+    #[test]
+    fn only_clear_successes_skip_approval() {
+        assert_eq!(auto_apply_margin(&rules(5), "success", 20, Some(15)), Some(5));
+        assert_eq!(auto_apply_margin(&rules(5), "critical_success", 25, Some(15)), Some(10));
+        // Borderline
+        assert_eq!(auto_apply_margin(&rules(5), "success", 19, Some(15)), None);
+        // Not a success, even if the total is high
+        assert_eq!(auto_apply_margin(&rules(0), "failure", 30, Some(15)), None);
+        // No numeric DC to compare against
+        assert_eq!(auto_apply_margin(&rules(0), "success", 30, None), None);
+    }
+
+    #[test]
+    fn disabled_rules_queue_everything() {
+        let disabled = ChallengeAutoResolveRules::default();
+        assert!(!disabled.enabled);
+        assert_eq!(auto_apply_margin(&disabled, "critical_success", 30, Some(10)), None);
+    }
+}
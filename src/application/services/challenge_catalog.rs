@@ -0,0 +1,184 @@
+//! Challenge Catalog - Copying challenges from Engine-wide catalogs
+//!
+//! The Engine hosts shared challenge catalogs that any world can draw from.
+//! A catalog challenge references a skill from whichever world it was
+//! published in, so copying it into another world means finding the
+//! matching skill there: the same skill ID when both worlds use the rule
+//! system's default skills, otherwise a skill with the same name.
+
+use serde::Deserialize;
+
+use crate::application::dto::{ChallengeData, Outcome, OutcomeTrigger, SkillData, TriggerType};
+
+/// A shared catalog of challenges
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct ChallengeCatalog {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub challenge_count: u32,
+}
+
+/// A challenge in a shared catalog
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct CatalogChallenge {
+    pub catalog_id: String,
+    pub catalog_name: String,
+    /// Name of the challenge's skill in the world it was published from
+    #[serde(default)]
+    pub skill_name: String,
+    pub challenge: ChallengeData,
+}
+
+/// The skill in this world that a catalog challenge's skill maps to
+pub fn match_catalog_skill<'a>(entry: &CatalogChallenge, skills: &'a [SkillData]) -> Option<&'a SkillData> {
+    skills
+        .iter()
+        .find(|s| s.id == entry.challenge.skill_id)
+        .or_else(|| {
+            let name = entry.skill_name.trim();
+            (!name.is_empty())
+                .then(|| skills.iter().find(|s| s.name.trim().eq_ignore_ascii_case(name)))
+                .flatten()
+        })
+}
+
+/// A copy of a catalog challenge for this world, using the given skill
+///
+/// The copy gets a fresh ID and keeps its catalog as its source. Links to
+/// other challenges, scenes, and entities point into the catalog's world, so
+/// they are dropped: prerequisites, trigger conditions naming a challenge,
+/// region, NPC, item, or narrative event, and outcome triggers that enable,
+/// disable, or jump to them.
+pub fn copy_catalog_challenge(entry: &CatalogChallenge, world_id: &str, skill_id: &str) -> ChallengeData {
+    let mut copy = ChallengeData {
+        id: uuid::Uuid::new_v4().to_string(),
+        world_id: world_id.to_string(),
+        scene_id: None,
+        skill_id: skill_id.to_string(),
+        prerequisite_challenges: Vec::new(),
+        active: true,
+        order: 0,
+        is_favorite: false,
+        source_catalog: Some(entry.catalog_name.clone()),
+        deleted_at: None,
+        ..entry.challenge.clone()
+    };
+
+    copy.trigger_conditions.retain(|c| {
+        !matches!(
+            c.condition_type,
+            TriggerType::ChallengeComplete { .. }
+                | TriggerType::EnterRegion { .. }
+                | TriggerType::TalkToNpc { .. }
+                | TriggerType::ItemAcquired { .. }
+                | TriggerType::StoryEventOccurred { .. }
+        )
+    });
+    let outcomes = &mut copy.outcomes;
+    for outcome in [&mut outcomes.success, &mut outcomes.failure]
+        .into_iter()
+        .chain(outcomes.partial.as_mut())
+        .chain(outcomes.critical_success.as_mut())
+        .chain(outcomes.critical_failure.as_mut())
+    {
+        drop_foreign_triggers(outcome);
+    }
+    copy
+}
+
+fn drop_foreign_triggers(outcome: &mut Outcome) {
+    outcome.triggers.retain(|t| {
+        !matches!(
+            t,
+            OutcomeTrigger::EnableChallenge { .. }
+                | OutcomeTrigger::DisableChallenge { .. }
+                | OutcomeTrigger::TriggerScene { .. }
+        )
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::SkillCategory;
+
+    fn skill(id: &str, name: &str) -> SkillData {
+        SkillData {
+            id: id.to_string(),
+            world_id: "here".to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            category: SkillCategory::Other,
+            base_attribute: None,
+            is_custom: false,
+            is_hidden: false,
+            order: 0,
+        }
+    }
+
+    fn entry(skill_id: &str, skill_name: &str) -> CatalogChallenge {
+        let challenge: ChallengeData = serde_json::from_value(serde_json::json!({
+            "id": "cat-1",
+            "world_id": "there",
+            "scene_id": "scene-9",
+            "name": "Pick the Lock",
+            "description": "",
+            "challenge_type": "skill_check",
+            "skill_id": skill_id,
+            "difficulty": { "type": "dc", "value": 15 },
+            "outcomes": {
+                "success": { "description": "", "triggers": [
+                    { "type": "enable_challenge", "challenge_id": "cat-2" },
+                    { "type": "reveal_information", "info": "The vault is empty", "persist": true }
+                ] },
+                "failure": { "description": "", "triggers": [{ "type": "trigger_scene", "scene_id": "scene-9" }] }
+            },
+            "trigger_conditions": [
+                { "condition_type": { "type": "object_interaction", "keywords": ["lock"] }, "description": "" },
+                { "condition_type": { "type": "challenge_complete", "challenge_id": "cat-0", "requires_success": null }, "description": "" },
+                { "condition_type": { "type": "enter_region", "region_id": "vault-door" }, "description": "" }
+            ],
+            "prerequisite_challenges": ["cat-0"],
+            "active": false,
+            "order": 3,
+            "is_favorite": true,
+            "tags": ["heist"]
+        }))
+        .expect("challenge");
+        CatalogChallenge {
+            catalog_id: "thieves".to_string(),
+            catalog_name: "Thieves' Guild".to_string(),
+            skill_name: skill_name.to_string(),
+            challenge,
+        }
+    }
+
+    #[test]
+    fn matches_skills_by_id_then_name() {
+        let skills = vec![skill("sleight", "Sleight of Hand"), skill("local-stealth", "Stealth")];
+        assert_eq!(match_catalog_skill(&entry("sleight", "Legerdemain"), &skills).unwrap().id, "sleight");
+        assert_eq!(match_catalog_skill(&entry("other-stealth", "stealth "), &skills).unwrap().id, "local-stealth");
+        assert!(match_catalog_skill(&entry("other-climb", "Climb"), &skills).is_none());
+        assert!(match_catalog_skill(&entry("other-climb", ""), &skills).is_none());
+    }
+
+    #[test]
+    fn copies_into_this_world() {
+        let copy = copy_catalog_challenge(&entry("other-stealth", "Stealth"), "here", "local-stealth");
+        assert_ne!(copy.id, "cat-1");
+        assert_eq!(copy.world_id, "here");
+        assert_eq!(copy.skill_id, "local-stealth");
+        assert_eq!(copy.scene_id, None);
+        assert!(copy.prerequisite_challenges.is_empty());
+        assert_eq!(copy.trigger_conditions.len(), 1);
+        assert_eq!(copy.outcomes.success.triggers.len(), 1);
+        assert!(copy.outcomes.failure.triggers.is_empty());
+        assert!(copy.active && !copy.is_favorite);
+        assert_eq!(copy.name, "Pick the Lock");
+        assert_eq!(copy.tags, vec!["heist".to_string()]);
+        assert_eq!(copy.source_catalog.as_deref(), Some("Thieves' Guild"));
+    }
+}
@@ -0,0 +1,220 @@
+//! Challenge context - Drafting a challenge from something said at the table
+//!
+//! "Create challenge from this" on a conversation log entry or approval
+//! card hands its text here. The skill and difficulty are guessed from the
+//! wording, so the ad-hoc challenge form opens filled in and the DM only
+//! corrects what the guess got wrong.
+
+use crate::application::dto::websocket_messages::SceneCharacterState;
+use crate::application::dto::SkillData;
+
+/// Difficulty used when the text gives no hint
+pub const DEFAULT_DRAFT_DIFFICULTY: &str = "DC 15";
+
+/// Longest challenge name taken from the text, in words
+const NAME_WORDS: usize = 6;
+
+/// Words that suggest a skill; checked in order, first match wins
+const SKILL_HINTS: &[(&str, &[&str])] = &[
+    ("Persuasion", &["persuade", "convince", "negotiate", "bargain", "haggle", "plead"]),
+    ("Deception", &["lie", "bluff", "deceive", "trick", "pretend", "disguise"]),
+    ("Intimidation", &["threaten", "intimidate", "scare", "menace"]),
+    ("Insight", &["lying", "motive", "suspicious", "trust"]),
+    ("Stealth", &["sneak", "hide", "quietly", "unseen", "unnoticed"]),
+    ("Sleight of Hand", &["pickpocket", "lockpick", "steal", "palm", "pick the lock"]),
+    ("Athletics", &["climb", "jump", "swim", "lift", "shove", "break down"]),
+    ("Acrobatics", &["balance", "dodge", "tumble", "leap"]),
+    ("Investigation", &["investigate", "examine", "inspect", "clue", "search"]),
+    ("Perception", &["notice", "spot", "listen", "hear", "look around"]),
+    ("Medicine", &["heal", "wound", "bandage", "poison"]),
+    ("Arcana", &["magic", "spell", "rune", "arcane", "enchant"]),
+    ("History", &["remember", "recall", "history", "legend"]),
+    ("Survival", &["track", "forage", "trail", "wilderness"]),
+];
+
+/// Words that suggest a difficulty; checked hardest first
+const DIFFICULTY_HINTS: &[(&str, &[&str])] = &[
+    ("DC 25", &["impossible", "legendary", "extremely", "desperate"]),
+    ("DC 20", &["hard", "difficult", "tough", "dangerous", "risky"]),
+    ("DC 10", &["easy", "simple", "trivial", "quick"]),
+];
+
+/// Ad-hoc challenge fields guessed from conversation text
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChallengeDraft {
+    pub challenge_name: String,
+    /// Empty when nothing in the text pointed at a skill
+    pub skill_name: String,
+    pub difficulty: String,
+    /// The speaker, when they are a character in the scene
+    pub target_pc_id: Option<String>,
+    /// The text the draft came from, shown on the form
+    pub context: String,
+}
+
+/// Draft a challenge from what `speaker` said
+///
+/// The world's own skill names take precedence over the built-in hints,
+/// which only count when the world has that skill, under its spelling.
+pub fn draft_challenge(
+    text: &str,
+    speaker: Option<&str>,
+    skills: &[SkillData],
+    scene_characters: &[SceneCharacterState],
+) -> ChallengeDraft {
+    let words = words_of(text);
+    let skill_name = skills
+        .iter()
+        .find(|s| contains_phrase(&words, &s.name))
+        .map(|s| s.name.clone())
+        .or_else(|| {
+            SKILL_HINTS
+                .iter()
+                .filter(|(_, hints)| hints.iter().any(|h| contains_phrase(&words, h)))
+                .find_map(|(name, _)| skills.iter().find(|s| s.name.eq_ignore_ascii_case(name)))
+                .map(|s| s.name.clone())
+        })
+        .unwrap_or_default();
+    let difficulty = DIFFICULTY_HINTS
+        .iter()
+        .find(|(_, hints)| hints.iter().any(|h| contains_phrase(&words, h)))
+        .map_or(DEFAULT_DRAFT_DIFFICULTY, |(dc, _)| *dc)
+        .to_string();
+    let target_pc_id = speaker.and_then(|name| {
+        scene_characters
+            .iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name.trim()))
+            .map(|c| c.id.clone())
+    });
+
+    ChallengeDraft {
+        challenge_name: challenge_name(text, &skill_name),
+        skill_name,
+        difficulty,
+        target_pc_id,
+        context: text.trim().to_string(),
+    }
+}
+
+/// The first sentence, shortened; "<Skill> check" when there is none
+fn challenge_name(text: &str, skill_name: &str) -> String {
+    let sentence = text
+        .split(['.', '!', '?', '\n'])
+        .map(|s| s.trim().trim_matches(|c: char| !c.is_alphanumeric()))
+        .find(|s| !s.is_empty())
+        .unwrap_or_default();
+    let words: Vec<&str> = sentence.split_whitespace().collect();
+    if words.is_empty() {
+        return if skill_name.is_empty() { "Challenge".to_string() } else { format!("{} check", skill_name) };
+    }
+
+    let mut name = words[..words.len().min(NAME_WORDS)].join(" ");
+    if words.len() > NAME_WORDS {
+        name.push('…');
+    }
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => name,
+    }
+}
+
+/// Lowercase words of the text, punctuation dropped
+fn words_of(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Whether the phrase's words appear in order; the last may be inflected
+/// ("persuade" matches "persuaded", "climb" matches "climbing")
+fn contains_phrase(words: &[String], phrase: &str) -> bool {
+    let phrase = words_of(phrase);
+    let Some((last, leading)) = phrase.split_last() else {
+        return false;
+    };
+    words.windows(phrase.len()).any(|window| {
+        window[..leading.len()] == *leading && inflection_of(&window[leading.len()], last)
+    })
+}
+
+fn inflection_of(word: &str, stem: &str) -> bool {
+    let stem = stem.strip_suffix('e').filter(|_| !word.starts_with(stem)).unwrap_or(stem);
+    word.strip_prefix(stem)
+        .is_some_and(|rest| matches!(rest, "" | "s" | "es" | "d" | "ed" | "ing" | "ly"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::websocket_messages::CharacterPosition;
+    use crate::application::dto::{EntityStyle, SkillCategory};
+
+    fn skill(name: &str) -> SkillData {
+        SkillData {
+            id: name.to_lowercase(),
+            world_id: "w-1".to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            category: SkillCategory::Other,
+            base_attribute: None,
+            is_custom: false,
+            is_hidden: false,
+            order: 0,
+        }
+    }
+
+    fn character(id: &str, name: &str) -> SceneCharacterState {
+        SceneCharacterState {
+            id: id.to_string(),
+            name: name.to_string(),
+            sprite_asset: None,
+            portrait_asset: None,
+            position: CharacterPosition::Center,
+            is_speaking: false,
+            emotion: String::new(),
+            sentiment: None,
+            disposition_hidden: false,
+            style: EntityStyle::default(),
+        }
+    }
+
+    #[test]
+    fn hints_pick_skill_difficulty_and_target() {
+        let draft = draft_challenge(
+            "I try to convince the guard to let us through. It looks difficult.",
+            Some("Mira"),
+            &[skill("persuasion")],
+            &[character("pc-1", "Mira")],
+        );
+        assert_eq!(draft.skill_name, "persuasion");
+        assert_eq!(draft.difficulty, "DC 20");
+        assert_eq!(draft.target_pc_id.as_deref(), Some("pc-1"));
+        assert_eq!(draft.challenge_name, "I try to convince the guard…");
+    }
+
+    #[test]
+    fn world_skills_win_and_defaults_apply() {
+        let draft = draft_challenge("Can I use Lore to recall the sigil?", None, &[skill("Lore")], &[]);
+        assert_eq!(draft.skill_name, "Lore");
+        assert_eq!(draft.difficulty, DEFAULT_DRAFT_DIFFICULTY);
+        assert!(draft.target_pc_id.is_none());
+
+        let unknown_skill = draft_challenge("I try to convince the guard", None, &[skill("Lore")], &[]);
+        assert!(unknown_skill.skill_name.is_empty());
+
+        let unhinted = draft_challenge("...", Some("Narrator"), &[], &[]);
+        assert!(unhinted.skill_name.is_empty());
+        assert_eq!(unhinted.challenge_name, "Challenge");
+    }
+
+    #[test]
+    fn phrases_match_whole_words_with_inflections() {
+        let words = words_of("She climbed the wall and persuaded the believer");
+        assert!(contains_phrase(&words, "climb"));
+        assert!(contains_phrase(&words, "persuade"));
+        assert!(!contains_phrase(&words, "lie"));
+        assert!(contains_phrase(&words_of("We pick the lock"), "pick the lock"));
+    }
+}
@@ -5,7 +5,9 @@
 //! details from the presentation layer.
 
 use crate::application::dto::ChallengeData;
-use crate::application::ports::outbound::{ApiError, ApiPort};
+use crate::application::ports::outbound::{ApiError, ApiPort, Page, PageRequest};
+use crate::application::services::challenge_catalog::{CatalogChallenge, ChallengeCatalog};
+use crate::application::services::trash_service::without_trashed;
 
 /// Challenge service for managing challenges
 ///
@@ -25,7 +27,20 @@ impl<A: ApiPort> ChallengeService<A> {
     /// List all challenges in a world
     pub async fn list_challenges(&self, world_id: &str) -> Result<Vec<ChallengeData>, ApiError> {
         let path = format!("/api/worlds/{}/challenges", world_id);
-        self.api.get(&path).await
+        let challenges: Vec<ChallengeData> = self.api.get(&path).await?;
+        Ok(without_trashed(challenges))
+    }
+
+    /// List one page of challenges in a world
+    pub async fn list_challenges_page(
+        &self,
+        world_id: &str,
+        page: &PageRequest,
+    ) -> Result<Page<ChallengeData>, ApiError> {
+        let path = format!("/api/worlds/{}/challenges", world_id);
+        let mut page: Page<ChallengeData> = self.api.get(&page.apply_to(&path)).await?;
+        page.items = without_trashed(page.items);
+        Ok(page)
     }
 
     /// Get a single challenge by ID
@@ -53,7 +68,7 @@ impl<A: ApiPort> ChallengeService<A> {
         self.api.put(&path, challenge).await
     }
 
-    /// Delete a challenge
+    /// Move a challenge to the world's trash
     pub async fn delete_challenge(&self, challenge_id: &str) -> Result<(), ApiError> {
         let path = format!("/api/challenges/{}", challenge_id);
         self.api.delete(&path).await
@@ -70,6 +85,20 @@ impl<A: ApiPort> ChallengeService<A> {
         let path = format!("/api/challenges/{}/active", challenge_id);
         self.api.put_no_response(&path, &active).await
     }
+
+    /// List the Engine-wide challenge catalogs
+    pub async fn list_catalogs(&self) -> Result<Vec<ChallengeCatalog>, ApiError> {
+        self.api.get("/api/challenge-catalogs").await
+    }
+
+    /// List the challenges in a shared catalog
+    pub async fn list_catalog_challenges(
+        &self,
+        catalog_id: &str,
+    ) -> Result<Vec<CatalogChallenge>, ApiError> {
+        let path = format!("/api/challenge-catalogs/{}/challenges", catalog_id);
+        self.api.get(&path).await
+    }
 }
 
 impl<A: ApiPort + Clone> Clone for ChallengeService<A> {
@@ -102,4 +131,51 @@ mod tests {
         assert_eq!(reqs[0].method, "GET");
         assert_eq!(reqs[0].path, "/api/worlds/world-1/challenges");
     }
+
+    #[tokio::test]
+    async fn list_challenges_page_sends_cursor_and_reads_next_cursor() {
+        let api = MockApiPort::new();
+        api.when_get_json(
+            "/api/worlds/world-1/challenges?limit=2&cursor=abc%3D%3D",
+            serde_json::json!({ "items": [], "next_cursor": "def" }),
+        );
+
+        let svc = ChallengeService::new(api.clone());
+        let page = svc
+            .list_challenges_page("world-1", &PageRequest::after("abc==", 2))
+            .await
+            .expect("page");
+
+        assert!(page.items.is_empty());
+        assert_eq!(page.next_cursor.as_deref(), Some("def"));
+    }
+
+    #[tokio::test]
+    async fn list_challenges_page_accepts_unpaginated_response() {
+        let api = MockApiPort::new();
+        api.when_get_json("/api/worlds/world-1/challenges?limit=50", serde_json::json!([]));
+
+        let svc = ChallengeService::new(api.clone());
+        let page = svc
+            .list_challenges_page("world-1", &PageRequest::default())
+            .await
+            .expect("page");
+
+        assert!(!page.has_more());
+    }
+
+    #[tokio::test]
+    async fn list_catalog_challenges_reads_catalog_entries() {
+        let api = MockApiPort::new();
+        api.when_get_json(
+            "/api/challenge-catalogs/thieves/challenges",
+            serde_json::json!([]),
+        );
+
+        let svc = ChallengeService::new(api.clone());
+        let entries = svc.list_catalog_challenges("thieves").await.expect("entries");
+
+        assert!(entries.is_empty());
+        assert_eq!(api.requests()[0].path, "/api/challenge-catalogs/thieves/challenges");
+    }
 }
@@ -7,8 +7,10 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use crate::application::dto::{FieldValue, InventoryItemData};
-use crate::application::ports::outbound::{ApiError, ApiPort};
+use crate::application::dto::{EntityStyle, FieldValue, InventoryItemData, RoutineEntry};
+use crate::application::ports::outbound::{ApiError, ApiPort, Page, PageRequest};
+use crate::application::services::archive::without_archived;
+use crate::application::services::trash_service::without_trashed;
 
 /// Character summary for list views
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -16,6 +18,17 @@ pub struct CharacterSummary {
     pub id: String,
     pub name: String,
     pub archetype: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Icon and accent colour picked by the DM
+    #[serde(flatten)]
+    pub style: EntityStyle,
+    /// When the character was moved to the trash, if it has been
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+    /// When the entity was archived, if it is
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
 }
 
 /// Character sheet data from API
@@ -47,6 +60,14 @@ pub struct CharacterFormData {
     pub portrait_asset: Option<String>,
     #[serde(default)]
     pub sheet_data: Option<CharacterSheetDataApi>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Where the character is at each time of day
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routine: Vec<RoutineEntry>,
+    /// Icon and accent colour picked by the DM
+    #[serde(flatten)]
+    pub style: EntityStyle,
 }
 
 /// Character service for managing characters
@@ -64,10 +85,23 @@ impl<A: ApiPort> CharacterService<A> {
         Self { api }
     }
 
-    /// List all characters in a world
+    /// List the characters in a world, leaving out archived ones
     pub async fn list_characters(&self, world_id: &str) -> Result<Vec<CharacterSummary>, ApiError> {
         let path = format!("/api/worlds/{}/characters", world_id);
-        self.api.get(&path).await
+        let characters: Vec<CharacterSummary> = self.api.get(&path).await?;
+        Ok(without_archived(without_trashed(characters)))
+    }
+
+    /// List one page of characters in a world, archived ones included
+    pub async fn list_characters_page(
+        &self,
+        world_id: &str,
+        page: &PageRequest,
+    ) -> Result<Page<CharacterSummary>, ApiError> {
+        let path = format!("/api/worlds/{}/characters", world_id);
+        let mut page: Page<CharacterSummary> = self.api.get(&page.apply_to(&path)).await?;
+        page.items = without_trashed(page.items);
+        Ok(page)
     }
 
     /// Get a single character by ID
@@ -100,12 +134,18 @@ impl<A: ApiPort> CharacterService<A> {
         self.api.put(&path, character).await
     }
 
-    /// Delete a character
+    /// Move a character to the world's trash
     pub async fn delete_character(&self, character_id: &str) -> Result<(), ApiError> {
         let path = format!("/api/characters/{}", character_id);
         self.api.delete(&path).await
     }
 
+    /// Archive a character, or bring it back from the archive
+    pub async fn set_archived(&self, character_id: &str, archived: bool) -> Result<(), ApiError> {
+        let path = format!("/api/characters/{}/archived", character_id);
+        self.api.put_no_response(&path, &archived).await
+    }
+
     /// Change a character's archetype
     pub async fn change_archetype(
         &self,
@@ -135,6 +175,54 @@ impl<A: ApiPort> CharacterService<A> {
         let path = format!("/api/characters/{}/inventory", character_id);
         self.api.get(&path).await
     }
+
+    /// Equip an item into a slot, or unequip it when `slot` is `None`
+    pub async fn set_equipped(
+        &self,
+        character_id: &str,
+        item_id: &str,
+        slot: Option<&str>,
+    ) -> Result<(), ApiError> {
+        #[derive(Serialize)]
+        struct EquipRequest<'a> {
+            equipped: bool,
+            slot: Option<&'a str>,
+        }
+
+        let path = format!("/api/characters/{}/inventory/{}/equip", character_id, item_id);
+        let request = EquipRequest {
+            equipped: slot.is_some(),
+            slot,
+        };
+        self.api.put_no_response(&path, &request).await
+    }
+
+    /// Set what the DM knows about an item; holders see it once their copy
+    /// is identified. Blank notes clear them.
+    pub async fn set_item_hidden_notes(&self, item_id: &str, notes: &str) -> Result<(), ApiError> {
+        #[derive(Serialize)]
+        struct HiddenNotesRequest<'a> {
+            hidden_notes: Option<&'a str>,
+        }
+
+        let path = format!("/api/items/{}/hidden-notes", item_id);
+        let notes = notes.trim();
+        let request = HiddenNotesRequest {
+            hidden_notes: (!notes.is_empty()).then_some(notes),
+        };
+        self.api.put_no_response(&path, &request).await
+    }
+
+    /// Replace an item's icon and accent colour; an empty style clears them
+    pub async fn set_item_style(&self, item_id: &str, style: &EntityStyle) -> Result<(), ApiError> {
+        #[derive(Serialize)]
+        struct StyleRequest<'a> {
+            style: &'a EntityStyle,
+        }
+
+        let path = format!("/api/items/{}/style", item_id);
+        self.api.put_no_response(&path, &StyleRequest { style }).await
+    }
 }
 
 impl<A: ApiPort + Clone> Clone for CharacterService<A> {
@@ -0,0 +1,154 @@
+//! Choice Requirements - Which dialogue choices a character can take
+//!
+//! A dialogue choice may need a skill modifier, an item, or a narrative
+//! event that has already happened. The player's client checks these
+//! against what it knows of the character: skills and inventory by name or
+//! ID (ignoring case), and events it has seen triggered. Each choice says
+//! how it is shown when locked, so the DM decides whether players learn
+//! what they are missing.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::application::dto::{ChoiceRequirement, DialogueChoice, LockedChoiceDisplay};
+
+/// What the client knows of the player's character
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChoiceContext {
+    /// Skill modifiers, keyed by lowercased ID and name
+    skills: HashMap<String, i32>,
+    /// Lowercased IDs and names of carried items
+    items: HashSet<String>,
+    /// IDs of narrative events that have happened
+    events: HashSet<String>,
+}
+
+impl ChoiceContext {
+    pub fn with_skill(mut self, id: &str, name: &str, modifier: i32) -> Self {
+        self.skills.insert(id.to_lowercase(), modifier);
+        self.skills.insert(name.trim().to_lowercase(), modifier);
+        self
+    }
+
+    pub fn with_item(mut self, id: &str, name: &str) -> Self {
+        self.items.insert(id.to_lowercase());
+        self.items.insert(name.trim().to_lowercase());
+        self
+    }
+
+    pub fn with_event(mut self, event_id: &str) -> Self {
+        self.events.insert(event_id.to_string());
+        self
+    }
+}
+
+impl ChoiceRequirement {
+    pub fn is_met(&self, context: &ChoiceContext) -> bool {
+        match self {
+            Self::Skill { skill, min } => context
+                .skills
+                .get(&skill.trim().to_lowercase())
+                .is_some_and(|modifier| modifier >= min),
+            Self::Item { item } => context.items.contains(&item.trim().to_lowercase()),
+            Self::Event { event_id, .. } => context.events.contains(event_id),
+        }
+    }
+
+    /// Explanation shown to the player, e.g. "Stealth +3 or better"
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Skill { skill, min } => format!("{} {:+} or better", skill, min),
+            Self::Item { item } => format!("Carrying {}", item),
+            Self::Event { event_name, .. } if !event_name.is_empty() => format!("After \"{}\"", event_name),
+            Self::Event { .. } => "After an earlier event".to_string(),
+        }
+    }
+}
+
+/// Whether a choice can be taken, and how to show it if not
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ChoiceAvailability {
+    Available,
+    /// Shown but can't be picked; `reasons` is empty unless the choice
+    /// explains its requirements
+    Locked { reasons: Vec<String> },
+    Hidden,
+}
+
+impl ChoiceAvailability {
+    pub fn is_available(&self) -> bool {
+        matches!(self, Self::Available)
+    }
+}
+
+/// Check a choice's requirements against the character
+pub fn choice_availability(choice: &DialogueChoice, context: &ChoiceContext) -> ChoiceAvailability {
+    let unmet: Vec<&ChoiceRequirement> = choice.requirements.iter().filter(|r| !r.is_met(context)).collect();
+    if unmet.is_empty() {
+        return ChoiceAvailability::Available;
+    }
+    match choice.when_locked {
+        LockedChoiceDisplay::Hidden => ChoiceAvailability::Hidden,
+        LockedChoiceDisplay::Greyed => ChoiceAvailability::Locked { reasons: Vec::new() },
+        LockedChoiceDisplay::Explained => ChoiceAvailability::Locked {
+            reasons: unmet.iter().map(|r| r.describe()).collect(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn choice(requirements: Vec<ChoiceRequirement>, when_locked: LockedChoiceDisplay) -> DialogueChoice {
+        DialogueChoice {
+            id: "c1".to_string(),
+            text: "Pick the lock".to_string(),
+            is_custom_input: false,
+            requirements,
+            when_locked,
+        }
+    }
+
+    #[test]
+    fn requirements_match_by_name_or_id() {
+        let context = ChoiceContext::default()
+            .with_skill("sk-1", "Sleight of Hand", 4)
+            .with_item("it-9", "Lockpicks")
+            .with_event("ev-2");
+
+        let skill = |skill: &str, min| ChoiceRequirement::Skill { skill: skill.to_string(), min };
+        assert!(skill("sleight of hand", 4).is_met(&context));
+        assert!(skill("sk-1", 3).is_met(&context));
+        assert!(!skill("Sleight of Hand", 5).is_met(&context));
+        assert!(!skill("Stealth", -5).is_met(&context));
+
+        assert!(ChoiceRequirement::Item { item: "lockpicks".to_string() }.is_met(&context));
+        assert!(!ChoiceRequirement::Item { item: "Crowbar".to_string() }.is_met(&context));
+        let event = |id: &str| ChoiceRequirement::Event { event_id: id.to_string(), event_name: String::new() };
+        assert!(event("ev-2").is_met(&context));
+        assert!(!event("ev-3").is_met(&context));
+    }
+
+    #[test]
+    fn locked_choices_follow_their_display_setting() {
+        let requirements = vec![
+            ChoiceRequirement::Skill { skill: "Stealth".to_string(), min: 3 },
+            ChoiceRequirement::Item { item: "Lockpicks".to_string() },
+        ];
+        let context = ChoiceContext::default().with_item("it-9", "Lockpicks");
+
+        assert_eq!(
+            choice_availability(&choice(requirements.clone(), LockedChoiceDisplay::Explained), &context),
+            ChoiceAvailability::Locked { reasons: vec!["Stealth +3 or better".to_string()] }
+        );
+        assert_eq!(
+            choice_availability(&choice(requirements.clone(), LockedChoiceDisplay::Greyed), &context),
+            ChoiceAvailability::Locked { reasons: Vec::new() }
+        );
+        assert_eq!(
+            choice_availability(&choice(requirements, LockedChoiceDisplay::Hidden), &context),
+            ChoiceAvailability::Hidden
+        );
+        assert!(choice_availability(&choice(Vec::new(), LockedChoiceDisplay::Hidden), &context).is_available());
+    }
+}
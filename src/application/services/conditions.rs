@@ -0,0 +1,74 @@
+//! Conditions - Named status effects on PCs and NPCs
+//!
+//! The DM applies conditions such as poisoned or inspired, and the Engine
+//! counts their durations down and folds their modifiers into challenge
+//! rolls for matching skills. The Player shows them as icons.
+
+use crate::application::dto::ActiveCondition;
+
+/// A condition the DM can apply in one click
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConditionPreset {
+    pub name: &'static str,
+    pub icon: &'static str,
+    /// Modifier to every challenge roll while the condition lasts
+    pub roll_modifier: i32,
+}
+
+/// Common conditions offered by the DM's condition control
+pub const CONDITION_PRESETS: &[ConditionPreset] = &[
+    ConditionPreset { name: "Poisoned", icon: "🤢", roll_modifier: -2 },
+    ConditionPreset { name: "Frightened", icon: "😱", roll_modifier: -2 },
+    ConditionPreset { name: "Exhausted", icon: "😩", roll_modifier: -1 },
+    ConditionPreset { name: "Stunned", icon: "💫", roll_modifier: -5 },
+    ConditionPreset { name: "Prone", icon: "🛌", roll_modifier: 0 },
+    ConditionPreset { name: "Invisible", icon: "👻", roll_modifier: 0 },
+    ConditionPreset { name: "Inspired", icon: "✨", roll_modifier: 2 },
+    ConditionPreset { name: "Blessed", icon: "🙏", roll_modifier: 1 },
+];
+
+/// Icon shown for a condition; custom conditions get a generic marker
+pub fn condition_icon(name: &str) -> &'static str {
+    CONDITION_PRESETS
+        .iter()
+        .find(|p| p.name.eq_ignore_ascii_case(name.trim()))
+        .map(|p| p.icon)
+        .unwrap_or("◆")
+}
+
+/// Tooltip text for a condition: name, time left and modifiers
+pub fn condition_summary(condition: &ActiveCondition) -> String {
+    let mut parts = vec![condition.name.clone(), condition.duration.describe()];
+    for modifier in &condition.modifiers {
+        let target = modifier.skill.as_deref().unwrap_or("all rolls");
+        parts.push(format!("{:+} {}", modifier.value, target));
+    }
+    parts.join(" · ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::{ConditionDuration, ConditionModifier};
+
+    fn condition(name: &str, modifiers: Vec<ConditionModifier>) -> ActiveCondition {
+        ActiveCondition {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            duration: ConditionDuration::Rounds { rounds: 3 },
+            modifiers,
+        }
+    }
+
+    #[test]
+    fn icons_fall_back_for_custom_conditions() {
+        assert_eq!(condition_icon(" poisoned "), "🤢");
+        assert_eq!(condition_icon("Cursed"), "◆");
+    }
+
+    #[test]
+    fn summary_lists_duration_and_modifiers() {
+        let poisoned = condition("Poisoned", vec![ConditionModifier { skill: None, value: -2 }]);
+        assert_eq!(condition_summary(&poisoned), "Poisoned · 3 rounds · -2 all rolls");
+    }
+}
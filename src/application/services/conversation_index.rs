@@ -0,0 +1,216 @@
+//! Conversation Index - Dialogue and rolls kept for the session
+//!
+//! The dialogue box only ever shows the latest line, and the DM's
+//! conversation log is plain text. This index keeps every NPC line and
+//! challenge roll of the session with its speaker and time, so a timeline
+//! event's [`StoryEventSourceData`] can be resolved back to the excerpt or
+//! roll it came from. Both lists are capped, dropping the oldest first.
+
+use crate::application::dto::StoryEventSourceData;
+
+/// Most dialogue lines kept
+pub const MAX_INDEXED_LINES: usize = 1000;
+
+/// Most rolls kept
+pub const MAX_INDEXED_ROLLS: usize = 200;
+
+/// Seconds either side of a source's times that still count as a match,
+/// allowing for clock drift between the Engine and this client
+const MATCH_SLACK_SECS: u64 = 5;
+
+/// Lines shown before and after a single-moment conversation source
+const EXCERPT_CONTEXT_LINES: usize = 2;
+
+/// One line of dialogue heard this session
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedLine {
+    pub speaker_id: String,
+    pub speaker_name: String,
+    pub text: String,
+    pub timestamp: u64,
+}
+
+/// One challenge roll resolved this session
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexedRoll {
+    pub challenge_id: String,
+    pub challenge_name: String,
+    pub character_name: String,
+    pub roll: i32,
+    pub modifier: i32,
+    pub total: i32,
+    pub outcome: String,
+    pub outcome_description: String,
+    pub roll_breakdown: Option<String>,
+    pub timestamp: u64,
+}
+
+/// What a story event's source resolved to
+#[derive(Debug, Clone, PartialEq)]
+pub enum SourceDetail<'a> {
+    Conversation(Vec<&'a IndexedLine>),
+    Roll(&'a IndexedRoll),
+    /// Not heard this session, e.g. the event is from an earlier one
+    NotFound,
+}
+
+/// Dialogue lines and rolls of the current session, oldest first
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConversationIndex {
+    lines: Vec<IndexedLine>,
+    rolls: Vec<IndexedRoll>,
+}
+
+impl ConversationIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_line(&mut self, line: IndexedLine) {
+        self.lines.push(line);
+        if self.lines.len() > MAX_INDEXED_LINES {
+            self.lines.remove(0);
+        }
+    }
+
+    pub fn record_roll(&mut self, roll: IndexedRoll) {
+        self.rolls.push(roll);
+        if self.rolls.len() > MAX_INDEXED_ROLLS {
+            self.rolls.remove(0);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.rolls.clear();
+    }
+
+    /// Find the excerpt or roll a story event came from
+    pub fn resolve(&self, source: &StoryEventSourceData) -> SourceDetail<'_> {
+        match source {
+            StoryEventSourceData::Conversation { npc_id, started_at, ended_at, .. } => {
+                let excerpt = self.excerpt(npc_id, *started_at, *ended_at);
+                if excerpt.is_empty() {
+                    SourceDetail::NotFound
+                } else {
+                    SourceDetail::Conversation(excerpt)
+                }
+            }
+            StoryEventSourceData::Challenge { challenge_id, character_name, resolved_at } => self
+                .rolls
+                .iter()
+                .filter(|r| &r.challenge_id == challenge_id && &r.character_name == character_name)
+                .filter(|r| r.timestamp.abs_diff(*resolved_at) <= MATCH_SLACK_SECS)
+                .min_by_key(|r| r.timestamp.abs_diff(*resolved_at))
+                .map_or(SourceDetail::NotFound, SourceDetail::Roll),
+        }
+    }
+
+    /// Lines spoken between two times, if the NPC spoke in that window
+    ///
+    /// A window of a single moment, as inferred for older events, widens to
+    /// the NPC's line nearest that moment with a little context around it.
+    fn excerpt(&self, npc_id: &str, started_at: u64, ended_at: u64) -> Vec<&IndexedLine> {
+        let from = started_at.saturating_sub(MATCH_SLACK_SECS);
+        let to = ended_at.saturating_add(MATCH_SLACK_SECS);
+        let in_window: Vec<usize> = (0..self.lines.len())
+            .filter(|&i| (from..=to).contains(&self.lines[i].timestamp))
+            .collect();
+        if !in_window.iter().any(|&i| self.lines[i].speaker_id == npc_id) {
+            return Vec::new();
+        }
+        if started_at < ended_at {
+            return in_window.into_iter().map(|i| &self.lines[i]).collect();
+        }
+
+        let Some(nearest) = in_window
+            .into_iter()
+            .filter(|&i| self.lines[i].speaker_id == npc_id)
+            .min_by_key(|&i| self.lines[i].timestamp.abs_diff(started_at))
+        else {
+            return Vec::new();
+        };
+        let first = nearest.saturating_sub(EXCERPT_CONTEXT_LINES);
+        let last = (nearest + EXCERPT_CONTEXT_LINES).min(self.lines.len() - 1);
+        self.lines[first..=last].iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(speaker_id: &str, text: &str, timestamp: u64) -> IndexedLine {
+        IndexedLine {
+            speaker_id: speaker_id.to_string(),
+            speaker_name: speaker_id.to_string(),
+            text: text.to_string(),
+            timestamp,
+        }
+    }
+
+    fn conversation(npc_id: &str, started_at: u64, ended_at: u64) -> StoryEventSourceData {
+        StoryEventSourceData::Conversation {
+            npc_id: npc_id.to_string(),
+            npc_name: npc_id.to_string(),
+            started_at,
+            ended_at,
+        }
+    }
+
+    #[test]
+    fn conversations_resolve_to_their_window_or_nearest_line() {
+        let mut index = ConversationIndex::new();
+        for (i, speaker) in ["mira", "mira", "bran", "mira", "bran", "mira", "mira"].iter().enumerate() {
+            index.record_line(line(speaker, &format!("line {}", i), 100 + i as u64 * 60));
+        }
+
+        match index.resolve(&conversation("bran", 220, 340)) {
+            SourceDetail::Conversation(lines) => {
+                let texts: Vec<&str> = lines.iter().map(|l| l.text.as_str()).collect();
+                assert_eq!(texts, vec!["line 2", "line 3", "line 4"]);
+            }
+            other => panic!("expected an excerpt, got {:?}", other),
+        }
+
+        // A single moment gets the nearest line plus context either side
+        match index.resolve(&conversation("bran", 342, 342)) {
+            SourceDetail::Conversation(lines) => {
+                let texts: Vec<&str> = lines.iter().map(|l| l.text.as_str()).collect();
+                assert_eq!(texts, vec!["line 2", "line 3", "line 4", "line 5", "line 6"]);
+            }
+            other => panic!("expected an excerpt, got {:?}", other),
+        }
+
+        assert_eq!(index.resolve(&conversation("bran", 100, 100)), SourceDetail::NotFound);
+    }
+
+    #[test]
+    fn challenges_resolve_to_the_matching_roll() {
+        let mut index = ConversationIndex::new();
+        let roll = |character: &str, total: i32, timestamp: u64| IndexedRoll {
+            challenge_id: "c-1".to_string(),
+            challenge_name: "Pick Lock".to_string(),
+            character_name: character.to_string(),
+            roll: total,
+            modifier: 0,
+            total,
+            outcome: "success".to_string(),
+            outcome_description: String::new(),
+            roll_breakdown: None,
+            timestamp,
+        };
+        index.record_roll(roll("Ada", 12, 100));
+        index.record_roll(roll("Bo", 7, 101));
+        index.record_roll(roll("Ada", 18, 500));
+
+        let source = |character: &str, resolved_at: u64| StoryEventSourceData::Challenge {
+            challenge_id: "c-1".to_string(),
+            character_name: character.to_string(),
+            resolved_at,
+        };
+        assert!(matches!(index.resolve(&source("Ada", 502)), SourceDetail::Roll(r) if r.total == 18));
+        assert!(matches!(index.resolve(&source("Bo", 100)), SourceDetail::Roll(r) if r.total == 7));
+        assert_eq!(index.resolve(&source("Ada", 300)), SourceDetail::NotFound);
+    }
+}
@@ -0,0 +1,171 @@
+//! Crafting Service - Recipes scoped to a world
+//!
+//! Recipes are written in Creator Mode and attempted by players from the
+//! inventory panel. The attempt itself goes over the session connection
+//! (`SessionCommandService::attempt_craft`), since the Engine runs its skill
+//! check through the usual challenge flow.
+
+use serde::Serialize;
+
+use crate::application::dto::{ChallengeDifficulty, CraftingRecipe, ItemStack};
+use crate::application::ports::outbound::{ApiError, ApiPort};
+
+/// Request to create or update a recipe
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SaveRecipeRequest {
+    pub name: String,
+    pub description: String,
+    pub ingredients: Vec<ItemStack>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skill_id: Option<String>,
+    pub difficulty: ChallengeDifficulty,
+    pub result: ItemStack,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_description: Option<String>,
+    pub consume_on_failure: bool,
+}
+
+impl SaveRecipeRequest {
+    /// Check the request before it is sent
+    ///
+    /// A recipe needs a name, at least one ingredient, and a result, and
+    /// every amount has to be at least one.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Recipe name is required".to_string());
+        }
+        if self.ingredients.is_empty() {
+            return Err("Add at least one ingredient".to_string());
+        }
+        if self.ingredients.iter().any(|i| i.item_name.trim().is_empty()) {
+            return Err("Every ingredient needs an item".to_string());
+        }
+        if self.result.item_name.trim().is_empty() {
+            return Err("Pick the item the recipe makes".to_string());
+        }
+        if self.result.quantity == 0 || self.ingredients.iter().any(|i| i.quantity == 0) {
+            return Err("Quantities must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl From<&CraftingRecipe> for SaveRecipeRequest {
+    fn from(recipe: &CraftingRecipe) -> Self {
+        Self {
+            name: recipe.name.clone(),
+            description: recipe.description.clone(),
+            ingredients: recipe.ingredients.clone(),
+            skill_id: recipe.skill_id.clone(),
+            difficulty: recipe.difficulty.clone(),
+            result: recipe.result.clone(),
+            result_description: recipe.result_description.clone(),
+            consume_on_failure: recipe.consume_on_failure,
+        }
+    }
+}
+
+/// Crafting service for writing and listing recipes
+///
+/// This service provides CRUD for recipes while depending only on the
+/// `ApiPort` trait, not concrete infrastructure implementations.
+pub struct CraftingService<A: ApiPort> {
+    api: A,
+}
+
+impl<A: ApiPort> CraftingService<A> {
+    /// Create a new CraftingService with the given API port
+    pub fn new(api: A) -> Self {
+        Self { api }
+    }
+
+    /// List all recipes in a world
+    pub async fn list_recipes(&self, world_id: &str) -> Result<Vec<CraftingRecipe>, ApiError> {
+        let path = format!("/api/worlds/{}/recipes", world_id);
+        self.api.get(&path).await
+    }
+
+    /// Create a recipe
+    pub async fn create_recipe(
+        &self,
+        world_id: &str,
+        request: &SaveRecipeRequest,
+    ) -> Result<CraftingRecipe, ApiError> {
+        let path = format!("/api/worlds/{}/recipes", world_id);
+        self.api.post(&path, request).await
+    }
+
+    /// Update a recipe
+    pub async fn update_recipe(
+        &self,
+        recipe_id: &str,
+        request: &SaveRecipeRequest,
+    ) -> Result<CraftingRecipe, ApiError> {
+        let path = format!("/api/recipes/{}", recipe_id);
+        self.api.put(&path, request).await
+    }
+
+    /// Delete a recipe
+    pub async fn delete_recipe(&self, recipe_id: &str) -> Result<(), ApiError> {
+        let path = format!("/api/recipes/{}", recipe_id);
+        self.api.delete(&path).await
+    }
+}
+
+impl<A: ApiPort + Clone> Clone for CraftingService<A> {
+    fn clone(&self) -> Self {
+        Self {
+            api: self.api.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::testing::MockApiPort;
+
+    fn request(name: &str) -> SaveRecipeRequest {
+        SaveRecipeRequest {
+            name: name.to_string(),
+            ingredients: vec![ItemStack::new("Iron Ingot", 2)],
+            result: ItemStack::new("Sword", 1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn validate_needs_ingredients_and_a_result() {
+        assert!(request("Forge a Sword").validate().is_ok());
+        assert!(request(" ").validate().is_err());
+        assert!(SaveRecipeRequest { ingredients: Vec::new(), ..request("Nothing") }.validate().is_err());
+        assert!(SaveRecipeRequest { result: ItemStack::default(), ..request("No result") }.validate().is_err());
+        assert!(SaveRecipeRequest { result: ItemStack::new("Sword", 0), ..request("Zero") }.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn create_recipe_posts_to_world() {
+        let api = MockApiPort::new();
+        api.when_post_json(
+            "/api/worlds/world-1/recipes",
+            serde_json::json!({
+                "id": "r1",
+                "world_id": "world-1",
+                "name": "Forge a Sword",
+                "result": { "item_name": "Sword", "quantity": 1 }
+            }),
+        );
+
+        let svc = CraftingService::new(api.clone());
+        let created = svc.create_recipe("world-1", &request("Forge a Sword")).await.expect("created");
+
+        assert_eq!(created.id, "r1");
+        assert_eq!(created.difficulty, ChallengeDifficulty::default());
+        let reqs = api.requests();
+        assert_eq!(reqs[0].method, "POST");
+        assert_eq!(
+            reqs[0].body.as_ref().unwrap()["ingredients"],
+            serde_json::json!([{ "item_name": "Iron Ingot", "quantity": 2 }])
+        );
+    }
+}
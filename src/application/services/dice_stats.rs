@@ -0,0 +1,387 @@
+//! Dice Stats - Every roll of the session, and how fair the dice look
+//!
+//! Each client keeps the challenge rolls resolved during the session: who
+//! rolled, which dice, and each die's face. The DM's statistics panel
+//! builds face distributions from them per player and per die type, and
+//! flags groups whose average strays further from the expected one than
+//! luck easily explains. The log is kept for the session only, capped at
+//! [`MAX_SESSION_ROLLS`], and exports as CSV. The Engine keeps the
+//! session's rolls too and sends them on joining, so a reload or a DM who
+//! was away still counts every roll.
+
+use crate::application::dto::{DiceNotation, ManualDiceEntry, ResolvedRoll};
+
+/// Most rolls kept for a session
+pub const MAX_SESSION_ROLLS: usize = 2000;
+
+/// Dice needed in a group before its average is judged
+const MIN_DICE_FOR_OUTLIER: usize = 10;
+
+/// Standard errors the average may stray before the group is flagged
+const OUTLIER_Z: f64 = 2.5;
+
+/// One resolved challenge roll
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionRoll {
+    pub character_name: String,
+    pub challenge_name: String,
+    /// Dice rolled, e.g. "2d6"; None if the Engine didn't say
+    pub dice: Option<DiceNotation>,
+    /// Each die's face; empty when the faces aren't known
+    pub faces: Vec<i32>,
+    pub modifier: i32,
+    pub total: i32,
+    pub outcome: String,
+    /// Entered by hand from physical dice
+    pub manual: bool,
+    pub timestamp: u64,
+}
+
+impl SessionRoll {
+    /// A roll from the session's history sent on joining
+    pub fn from_resolved(resolved: &ResolvedRoll) -> Self {
+        Self {
+            character_name: resolved.character_name.clone(),
+            challenge_name: resolved.challenge_name.clone(),
+            dice: None,
+            faces: Vec::new(),
+            modifier: resolved.modifier,
+            total: resolved.total,
+            outcome: resolved.outcome.clone(),
+            manual: false,
+            timestamp: resolved.timestamp,
+        }
+        .with_dice(
+            resolved.roll,
+            resolved.roll_breakdown.as_deref(),
+            resolved.individual_rolls.as_deref(),
+            resolved.manual_entry.as_ref(),
+        )
+    }
+
+    /// Work out the dice and faces from a ChallengeResolved message
+    ///
+    /// Physical dice say what they were; digital rolls are read from the
+    /// breakdown, e.g. "1d20(14) + 3 = 17". A single die with no listed
+    /// faces shows the natural roll.
+    pub fn with_dice(
+        mut self,
+        roll: i32,
+        roll_breakdown: Option<&str>,
+        individual_rolls: Option<&[i32]>,
+        manual_entry: Option<&ManualDiceEntry>,
+    ) -> Self {
+        if let Some(entry) = manual_entry {
+            self.dice = DiceNotation::parse(&entry.formula);
+            self.faces = entry.dice.clone();
+            self.manual = true;
+            return self;
+        }
+        self.dice = roll_breakdown.and_then(|b| DiceNotation::parse(b.split('(').next().unwrap_or_default()));
+        self.faces = match (individual_rolls, self.dice) {
+            (Some(faces), _) => faces.to_vec(),
+            (None, Some(dice)) if dice.count == 1 => vec![roll],
+            _ => Vec::new(),
+        };
+        self
+    }
+
+    /// Faces that fit the dice, for the statistics
+    fn counted_faces(&self) -> Option<(u8, &[i32])> {
+        let sides = self.dice?.sides;
+        let fits = !self.faces.is_empty() && self.faces.iter().all(|&f| (1..=i32::from(sides)).contains(&f));
+        fits.then_some((sides, self.faces.as_slice()))
+    }
+}
+
+/// Which way a group's average leans, when it leans too far
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiceSkew {
+    High,
+    Low,
+}
+
+/// Face counts for one die type, for one player or everyone
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiceGroupStats {
+    /// Character name, or the die type (e.g. "d20") for everyone's rolls
+    pub label: String,
+    pub sides: u8,
+    /// How often each face came up; index 0 is face 1
+    pub face_counts: Vec<usize>,
+}
+
+impl DiceGroupStats {
+    fn new(label: &str, sides: u8) -> Self {
+        Self {
+            label: label.to_string(),
+            sides,
+            face_counts: vec![0; sides as usize],
+        }
+    }
+
+    /// Dice counted, not rolls: a 2d6 roll adds two
+    pub fn dice(&self) -> usize {
+        self.face_counts.iter().sum()
+    }
+
+    pub fn average(&self) -> f64 {
+        let sum: usize = self.face_counts.iter().enumerate().map(|(i, n)| (i + 1) * n).sum();
+        sum as f64 / self.dice().max(1) as f64
+    }
+
+    pub fn expected_average(&self) -> f64 {
+        (f64::from(self.sides) + 1.0) / 2.0
+    }
+
+    /// Set when the average is unlikely for fair dice
+    ///
+    /// Uses the standard error of a fair die's mean; small groups are
+    /// never flagged.
+    pub fn skew(&self) -> Option<DiceSkew> {
+        let n = self.dice();
+        if n < MIN_DICE_FOR_OUTLIER || self.sides < 2 {
+            return None;
+        }
+        let sides = f64::from(self.sides);
+        let std_error = ((sides * sides - 1.0) / 12.0 / n as f64).sqrt();
+        let z = (self.average() - self.expected_average()) / std_error;
+        if z >= OUTLIER_Z {
+            Some(DiceSkew::High)
+        } else if z <= -OUTLIER_Z {
+            Some(DiceSkew::Low)
+        } else {
+            None
+        }
+    }
+
+    fn count(&mut self, faces: &[i32]) {
+        for &face in faces {
+            self.face_counts[face as usize - 1] += 1;
+        }
+    }
+}
+
+/// The session's rolls, oldest first
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionDiceLog {
+    rolls: Vec<SessionRoll>,
+}
+
+impl SessionDiceLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn rolls(&self) -> &[SessionRoll] {
+        &self.rolls
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rolls.is_empty()
+    }
+
+    /// Add a roll, dropping the oldest past [`MAX_SESSION_ROLLS`]
+    pub fn record(&mut self, roll: SessionRoll) {
+        self.rolls.push(roll);
+        if self.rolls.len() > MAX_SESSION_ROLLS {
+            let excess = self.rolls.len() - MAX_SESSION_ROLLS;
+            self.rolls.drain(..excess);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.rolls.clear();
+    }
+
+    /// Replace the log with the session's history from SessionJoined
+    pub fn seed(&mut self, history: &[ResolvedRoll]) {
+        self.rolls.clear();
+        for resolved in history {
+            self.record(SessionRoll::from_resolved(resolved));
+        }
+    }
+
+    /// Distributions per character and die type, by name then die
+    pub fn per_player(&self) -> Vec<DiceGroupStats> {
+        let mut groups = self.group(|roll| roll.character_name.clone());
+        groups.sort_by(|a, b| a.label.cmp(&b.label).then(a.sides.cmp(&b.sides)));
+        groups
+    }
+
+    /// Everyone's distributions per die type, smallest die first
+    pub fn per_die(&self) -> Vec<DiceGroupStats> {
+        let mut groups = self.group(|roll| roll.dice.map(|d| format!("d{}", d.sides)).unwrap_or_default());
+        groups.sort_by_key(|g| g.sides);
+        groups
+    }
+
+    fn group(&self, label: impl Fn(&SessionRoll) -> String) -> Vec<DiceGroupStats> {
+        let mut groups: Vec<DiceGroupStats> = Vec::new();
+        for roll in &self.rolls {
+            let Some((sides, faces)) = roll.counted_faces() else {
+                continue;
+            };
+            let label = label(roll);
+            let index = match groups.iter().position(|g| g.label == label && g.sides == sides) {
+                Some(index) => index,
+                None => {
+                    groups.push(DiceGroupStats::new(&label, sides));
+                    groups.len() - 1
+                }
+            };
+            groups[index].count(faces);
+        }
+        groups
+    }
+
+    /// Every roll as CSV, one line each, oldest first
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("timestamp,character,challenge,dice,faces,modifier,total,outcome,manual\n");
+        for roll in &self.rolls {
+            let faces: Vec<String> = roll.faces.iter().map(|f| f.to_string()).collect();
+            let fields = [
+                roll.timestamp.to_string(),
+                csv_field(&roll.character_name),
+                csv_field(&roll.challenge_name),
+                roll.dice.map(|d| format!("{}d{}", d.count, d.sides)).unwrap_or_default(),
+                faces.join(" "),
+                roll.modifier.to_string(),
+                roll.total.to_string(),
+                csv_field(&roll.outcome),
+                roll.manual.to_string(),
+            ];
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// File name for the CSV export
+    pub fn csv_filename(session_id: Option<&str>) -> String {
+        match session_id {
+            Some(id) => format!("dice-rolls-{}.csv", id),
+            None => "dice-rolls.csv".to_string(),
+        }
+    }
+}
+
+/// Quote a field that holds a comma, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roll(character: &str, breakdown: &str, faces: &[i32]) -> SessionRoll {
+        SessionRoll {
+            character_name: character.to_string(),
+            challenge_name: "Pick Lock".to_string(),
+            dice: None,
+            faces: Vec::new(),
+            modifier: 2,
+            total: 0,
+            outcome: "success".to_string(),
+            manual: false,
+            timestamp: 1,
+        }
+        .with_dice(faces[0], Some(breakdown), Some(faces), None)
+    }
+
+    #[test]
+    fn dice_are_read_from_the_resolution() {
+        let digital = roll("Ada", "2d6(3,4) + 2 = 9", &[3, 4]);
+        assert_eq!(digital.dice.map(|d| (d.count, d.sides)), Some((2, 6)));
+
+        let base = roll("Ada", "Manual: 18", &[18]);
+        assert!(base.dice.is_none());
+        assert!(base.counted_faces().is_none());
+
+        let single = base.clone().with_dice(14, Some("1d20(14) + 3 = 17"), None, None);
+        assert_eq!(single.faces, vec![14]);
+
+        let entry = ManualDiceEntry { formula: "1d20".to_string(), dice: vec![20], flags: Vec::new() };
+        let manual = base.with_dice(20, Some("Manual: 20"), None, Some(&entry));
+        assert!(manual.manual);
+        assert_eq!(manual.counted_faces(), Some((20, &[20][..])));
+    }
+
+    #[test]
+    fn groups_per_player_and_die() {
+        let mut log = SessionDiceLog::new();
+        log.record(roll("Tobin", "1d20(5) = 5", &[5]));
+        log.record(roll("Ada", "2d6(1,6) = 7", &[1, 6]));
+        log.record(roll("Ada", "1d20(15) = 15", &[15]));
+        log.record(roll("Ada", "Manual: 12", &[12]));
+
+        let players: Vec<(String, u8, usize)> =
+            log.per_player().iter().map(|g| (g.label.clone(), g.sides, g.dice())).collect();
+        assert_eq!(
+            players,
+            vec![("Ada".to_string(), 6, 2), ("Ada".to_string(), 20, 1), ("Tobin".to_string(), 20, 1)]
+        );
+
+        let dice = log.per_die();
+        assert_eq!(dice[1].label, "d20");
+        assert_eq!(dice[1].face_counts[4], 1);
+        assert_eq!(dice[1].average(), 10.0);
+    }
+
+    #[test]
+    fn lopsided_dice_are_flagged() {
+        let mut hot = DiceGroupStats::new("Ada", 20);
+        hot.count(&[18, 19, 20, 17, 20, 16, 19, 18, 20, 15]);
+        assert_eq!(hot.skew(), Some(DiceSkew::High));
+
+        let mut fair = DiceGroupStats::new("Ada", 20);
+        fair.count(&[3, 17, 10, 12, 8, 14, 1, 20, 9, 11]);
+        assert_eq!(fair.skew(), None);
+
+        // Too few dice to say
+        let mut short = DiceGroupStats::new("Ada", 20);
+        short.count(&[20, 20, 20]);
+        assert_eq!(short.skew(), None);
+    }
+
+    #[test]
+    fn csv_quotes_awkward_fields() {
+        let mut log = SessionDiceLog::new();
+        let mut r = roll("Ada, the Bold", "2d6(3,4) + 2 = 9", &[3, 4]);
+        r.challenge_name = "Say \"hi\"".to_string();
+        r.total = 9;
+        log.record(r);
+
+        let csv = log.to_csv();
+        let line = csv.lines().nth(1).unwrap();
+        assert_eq!(line, "1,\"Ada, the Bold\",\"Say \"\"hi\"\"\",2d6,3 4,2,9,success,false");
+    }
+
+    #[test]
+    fn seeding_replaces_the_log_with_the_session_history() {
+        let mut log = SessionDiceLog::new();
+        log.record(roll("Stale", "1d20(3) = 3", &[3]));
+        let history = vec![ResolvedRoll {
+            challenge_name: "Pick Lock".to_string(),
+            character_name: "Ada".to_string(),
+            roll: 7,
+            modifier: 2,
+            total: 9,
+            outcome: "success".to_string(),
+            roll_breakdown: Some("2d6(3,4) + 2 = 9".to_string()),
+            individual_rolls: Some(vec![3, 4]),
+            manual_entry: None,
+            timestamp: 5,
+        }];
+
+        log.seed(&history);
+        assert_eq!(log.rolls().len(), 1);
+        assert_eq!(log.rolls()[0].character_name, "Ada");
+        assert_eq!(log.rolls()[0].faces, vec![3, 4]);
+    }
+}
@@ -0,0 +1,92 @@
+//! Directorial Presets - Switching the whole table's tone at once
+//!
+//! Each world keeps named presets in its settings (see `DirectorialPreset`).
+//! Picking one in the Director panel sends its tone, style notes and
+//! content guidelines to the Engine as the directorial context; picking a
+//! bare tone sends just that and drops the preset.
+
+use crate::application::dto::DirectorialPreset;
+use crate::application::ports::outbound::DirectorialContext;
+
+/// Directorial context for a tone, with the preset's notes if one is picked
+pub fn directorial_context(tone: &str, preset: Option<&DirectorialPreset>) -> DirectorialContext {
+    DirectorialContext {
+        scene_notes: String::new(),
+        tone: preset.map_or(tone, |p| p.tone.as_str()).to_string(),
+        npc_motivations: Vec::new(),
+        forbidden_topics: Vec::new(),
+        preset_name: preset.map(|p| p.name.clone()),
+        style_notes: preset.map(|p| p.style_notes.clone()).unwrap_or_default(),
+        content_guidelines: preset.map(|p| p.content_guidelines.clone()).unwrap_or_default(),
+    }
+}
+
+/// Presets are picked by name, so each needs one, and no two may share it
+pub fn validate_presets(presets: &[DirectorialPreset]) -> Result<(), String> {
+    let mut seen = std::collections::HashSet::new();
+    for preset in presets {
+        let name = preset.name.trim();
+        if name.is_empty() {
+            return Err("Every directorial preset needs a name".to_string());
+        }
+        if !seen.insert(name.to_lowercase()) {
+            return Err(format!("There is more than one directorial preset named \"{}\"", name));
+        }
+    }
+    Ok(())
+}
+
+/// Guidelines typed one per line; blank lines and list dashes are dropped
+pub fn parse_guidelines(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| line.trim().trim_start_matches(['-', '*', '•']).trim())
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Guidelines as editable text, one per line
+pub fn guidelines_text(guidelines: &[String]) -> String {
+    guidelines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::AppSettings;
+
+    #[test]
+    fn preset_fills_the_context() {
+        let presets = AppSettings::default().directorial_presets;
+        let horror = presets.iter().find(|p| p.name == "Horror").unwrap();
+
+        let context = directorial_context("Comedic", Some(horror));
+        assert_eq!(context.tone, horror.tone);
+        assert_eq!(context.preset_name.as_deref(), Some("Horror"));
+        assert_eq!(context.content_guidelines, horror.content_guidelines);
+
+        let bare = directorial_context("Comedic", None);
+        assert_eq!(bare.tone, "Comedic");
+        assert!(bare.preset_name.is_none());
+        assert!(bare.style_notes.is_empty());
+    }
+
+    #[test]
+    fn preset_names_are_required_and_unique() {
+        let mut presets = AppSettings::default().directorial_presets;
+        assert!(validate_presets(&presets).is_ok());
+
+        presets.push(DirectorialPreset { name: " horror ".to_string(), ..presets[0].clone() });
+        assert!(validate_presets(&presets).unwrap_err().contains("\"horror\""));
+
+        presets.last_mut().unwrap().name = "  ".to_string();
+        assert_eq!(validate_presets(&presets).unwrap_err(), "Every directorial preset needs a name");
+    }
+
+    #[test]
+    fn guidelines_round_trip_through_text() {
+        let guidelines = parse_guidelines("- No gore\n\n  * Keep it PG-13  \nNo spiders");
+        assert_eq!(guidelines, vec!["No gore", "Keep it PG-13", "No spiders"]);
+        assert_eq!(parse_guidelines(&guidelines_text(&guidelines)), guidelines);
+    }
+}
@@ -0,0 +1,206 @@
+//! Encounter Service - Prepared encounters scoped to a world
+//!
+//! An encounter bundles what the DM wants on stage at once: NPCs, the
+//! challenges they may call for, the region whose backdrop (often a battle
+//! map) sets the scene, and private notes. Encounters are built in Creator
+//! Mode and run from the Director panel, where the Engine stages everything
+//! in one step.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::outbound::{ApiError, ApiPort};
+
+/// A prepared encounter
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Encounter {
+    pub id: String,
+    pub world_id: String,
+    pub name: String,
+    /// NPCs put into the scene
+    #[serde(default)]
+    pub npc_ids: Vec<String>,
+    /// Challenges activated when the encounter runs
+    #[serde(default)]
+    pub challenge_ids: Vec<String>,
+    /// Location of the staging region
+    #[serde(default)]
+    pub location_id: Option<String>,
+    /// Region whose backdrop is shown
+    #[serde(default)]
+    pub region_id: Option<String>,
+    /// DM-only notes
+    #[serde(default)]
+    pub notes: String,
+}
+
+impl Encounter {
+    /// One-line summary, e.g. "3 NPCs · 2 challenges · backdrop"
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        match self.npc_ids.len() {
+            0 => {}
+            1 => parts.push("1 NPC".to_string()),
+            n => parts.push(format!("{} NPCs", n)),
+        }
+        match self.challenge_ids.len() {
+            0 => {}
+            1 => parts.push("1 challenge".to_string()),
+            n => parts.push(format!("{} challenges", n)),
+        }
+        if self.region_id.is_some() {
+            parts.push("backdrop".to_string());
+        }
+        if parts.is_empty() {
+            "Nothing staged yet".to_string()
+        } else {
+            parts.join(" · ")
+        }
+    }
+}
+
+/// Request to create or update an encounter
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SaveEncounterRequest {
+    pub name: String,
+    pub npc_ids: Vec<String>,
+    pub challenge_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region_id: Option<String>,
+    pub notes: String,
+}
+
+impl SaveEncounterRequest {
+    /// Check the request before it is sent
+    ///
+    /// An encounter needs a name and something to stage, and a region
+    /// only makes sense with its location.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Encounter name is required".to_string());
+        }
+        if self.npc_ids.is_empty() && self.challenge_ids.is_empty() && self.region_id.is_none() {
+            return Err("Add at least one NPC, challenge or backdrop region".to_string());
+        }
+        if self.region_id.is_some() && self.location_id.is_none() {
+            return Err("Pick the location the region belongs to".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Encounter service for building and listing encounters
+///
+/// This service provides CRUD for encounters while depending only on the
+/// `ApiPort` trait, not concrete infrastructure implementations. Running
+/// an encounter goes over the session connection instead
+/// (`SessionCommandService::run_encounter`).
+pub struct EncounterService<A: ApiPort> {
+    api: A,
+}
+
+impl<A: ApiPort> EncounterService<A> {
+    /// Create a new EncounterService with the given API port
+    pub fn new(api: A) -> Self {
+        Self { api }
+    }
+
+    /// List all encounters in a world
+    pub async fn list_encounters(&self, world_id: &str) -> Result<Vec<Encounter>, ApiError> {
+        let path = format!("/api/worlds/{}/encounters", world_id);
+        self.api.get(&path).await
+    }
+
+    /// Create an encounter
+    pub async fn create_encounter(
+        &self,
+        world_id: &str,
+        request: &SaveEncounterRequest,
+    ) -> Result<Encounter, ApiError> {
+        let path = format!("/api/worlds/{}/encounters", world_id);
+        self.api.post(&path, request).await
+    }
+
+    /// Update an encounter
+    pub async fn update_encounter(
+        &self,
+        encounter_id: &str,
+        request: &SaveEncounterRequest,
+    ) -> Result<Encounter, ApiError> {
+        let path = format!("/api/encounters/{}", encounter_id);
+        self.api.put(&path, request).await
+    }
+
+    /// Delete an encounter
+    pub async fn delete_encounter(&self, encounter_id: &str) -> Result<(), ApiError> {
+        let path = format!("/api/encounters/{}", encounter_id);
+        self.api.delete(&path).await
+    }
+}
+
+impl<A: ApiPort + Clone> Clone for EncounterService<A> {
+    fn clone(&self) -> Self {
+        Self {
+            api: self.api.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::testing::MockApiPort;
+
+    fn request(name: &str) -> SaveEncounterRequest {
+        SaveEncounterRequest {
+            name: name.to_string(),
+            npc_ids: vec!["npc-1".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn validate_needs_a_name_and_something_to_stage() {
+        assert!(request("Ambush").validate().is_ok());
+        assert!(request("  ").validate().is_err());
+        assert!(SaveEncounterRequest { name: "Empty".to_string(), ..Default::default() }.validate().is_err());
+
+        let region_only = SaveEncounterRequest {
+            name: "Bridge".to_string(),
+            region_id: Some("r1".to_string()),
+            ..Default::default()
+        };
+        assert!(region_only.validate().is_err());
+        assert!(SaveEncounterRequest { location_id: Some("l1".to_string()), ..region_only }.validate().is_ok());
+    }
+
+    #[test]
+    fn summary_counts_what_is_staged() {
+        let encounter = Encounter {
+            npc_ids: vec!["a".to_string(), "b".to_string()],
+            challenge_ids: vec!["c".to_string()],
+            region_id: Some("r".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(encounter.summary(), "2 NPCs · 1 challenge · backdrop");
+        assert_eq!(Encounter::default().summary(), "Nothing staged yet");
+    }
+
+    #[tokio::test]
+    async fn create_encounter_posts_to_world() {
+        let api = MockApiPort::new();
+        api.when_post_json(
+            "/api/worlds/world-1/encounters",
+            serde_json::json!({ "id": "e1", "world_id": "world-1", "name": "Ambush" }),
+        );
+
+        let svc = EncounterService::new(api.clone());
+        let created = svc.create_encounter("world-1", &request("Ambush")).await.expect("created");
+
+        assert_eq!(created.id, "e1");
+        let reqs = api.requests();
+        assert_eq!(reqs[0].method, "POST");
+        assert_eq!(reqs[0].body.as_ref().unwrap()["npc_ids"], serde_json::json!(["npc-1"]));
+    }
+}
@@ -0,0 +1,312 @@
+//! Engine Health - Deciding which Engine-backed features are usable
+//!
+//! The Engine reports ComfyUI's state directly. LLM availability and Engine
+//! latency are inferred: an LLM request that errors or goes unanswered for
+//! [`LLM_TIMEOUT_MS`] marks the LLM unavailable until it answers again, and
+//! heartbeat round trips give the latency. [`EngineHealth::modes`] turns
+//! that into the features the UI should switch off or adapt. The protocol
+//! version the Engine reports on join is checked here too, so a mismatch
+//! shows up with the other notices, as do HTTP endpoints whose circuit is
+//! open after repeated failures.
+
+use crate::application::dto::ProtocolCompatibility;
+use crate::application::ports::outbound::OpenCircuit;
+
+/// How long an LLM request may go unanswered before the LLM counts as down
+pub const LLM_TIMEOUT_MS: u64 = 90_000;
+
+/// Heartbeat round trip above which the Engine counts as slow
+pub const SLOW_LATENCY_MS: u64 = 1_500;
+
+/// How a single Engine dependency is doing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ServiceHealth {
+    #[default]
+    Available,
+    Degraded,
+    Unavailable,
+}
+
+/// Features to switch off or adapt, derived from [`EngineHealth`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DegradationModes {
+    /// ComfyUI can take generation requests
+    pub generation_available: bool,
+    /// The LLM is down, so NPC dialogue has to be written by the DM
+    pub manual_dialogue: bool,
+    /// The Engine is answering slowly
+    pub engine_slow: bool,
+}
+
+/// A status line explaining a degradation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthNotice {
+    pub severity: ServiceHealth,
+    pub message: String,
+}
+
+/// What the client knows about the Engine's dependencies
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EngineHealth {
+    comfyui: ServiceHealth,
+    comfyui_message: Option<String>,
+    llm: ServiceHealth,
+    /// When the oldest unanswered LLM request started, in ms
+    llm_waiting_since: Option<u64>,
+    /// When the outstanding heartbeat was sent, in ms
+    ping_sent_at: Option<u64>,
+    latency_ms: Option<u64>,
+    protocol: ProtocolCompatibility,
+    open_circuits: Vec<OpenCircuit>,
+}
+
+impl EngineHealth {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn comfyui(&self) -> ServiceHealth {
+        self.comfyui
+    }
+
+    pub fn llm(&self) -> ServiceHealth {
+        self.llm
+    }
+
+    /// Last measured heartbeat round trip
+    pub fn latency_ms(&self) -> Option<u64> {
+        self.latency_ms
+    }
+
+    pub fn protocol(&self) -> ProtocolCompatibility {
+        self.protocol
+    }
+
+    pub fn open_circuits(&self) -> &[OpenCircuit] {
+        &self.open_circuits
+    }
+
+    /// Replace the list of HTTP endpoints paused after repeated failures
+    pub fn set_open_circuits(&mut self, circuits: Vec<OpenCircuit>) {
+        self.open_circuits = circuits;
+    }
+
+    /// Record the protocol version the Engine reported on join
+    pub fn set_engine_protocol(&mut self, version: Option<u32>) {
+        self.protocol = ProtocolCompatibility::check(version);
+    }
+
+    /// Apply a ComfyUI state report ("connected", "degraded", "disconnected", "circuit_open")
+    pub fn set_comfyui_state(&mut self, state: &str, message: Option<String>) {
+        self.comfyui = match state {
+            "connected" => ServiceHealth::Available,
+            "degraded" => ServiceHealth::Degraded,
+            _ => ServiceHealth::Unavailable,
+        };
+        self.comfyui_message = message;
+    }
+
+    /// The Engine started an LLM request
+    pub fn llm_request_started(&mut self, now_ms: u64) {
+        self.llm_waiting_since.get_or_insert(now_ms);
+    }
+
+    /// The LLM produced something, so it's up
+    pub fn llm_responded(&mut self) {
+        self.llm = ServiceHealth::Available;
+        self.llm_waiting_since = None;
+    }
+
+    /// An LLM request failed
+    pub fn llm_failed(&mut self) {
+        self.llm = ServiceHealth::Unavailable;
+        self.llm_waiting_since = None;
+    }
+
+    /// A heartbeat went out; only the first unanswered one is timed
+    pub fn ping_sent(&mut self, now_ms: u64) {
+        self.ping_sent_at.get_or_insert(now_ms);
+    }
+
+    /// The Engine answered a heartbeat
+    pub fn pong_received(&mut self, now_ms: u64) {
+        if let Some(sent) = self.ping_sent_at.take() {
+            self.latency_ms = Some(now_ms.saturating_sub(sent));
+        }
+    }
+
+    /// Re-check timeouts
+    ///
+    /// A slow LLM request is first reported as degraded, then as
+    /// unavailable once it passes [`LLM_TIMEOUT_MS`]. An unanswered
+    /// heartbeat counts towards the latency while it's outstanding.
+    pub fn tick(&mut self, now_ms: u64) {
+        if let Some(since) = self.llm_waiting_since {
+            let waited = now_ms.saturating_sub(since);
+            if waited >= LLM_TIMEOUT_MS {
+                self.llm = ServiceHealth::Unavailable;
+            } else if waited >= LLM_TIMEOUT_MS / 2 && self.llm == ServiceHealth::Available {
+                self.llm = ServiceHealth::Degraded;
+            }
+        }
+        if let Some(sent) = self.ping_sent_at {
+            let waited = now_ms.saturating_sub(sent);
+            if waited > self.latency_ms.unwrap_or(0) {
+                self.latency_ms = Some(waited);
+            }
+        }
+    }
+
+    pub fn modes(&self) -> DegradationModes {
+        DegradationModes {
+            generation_available: self.comfyui != ServiceHealth::Unavailable,
+            manual_dialogue: self.llm == ServiceHealth::Unavailable,
+            engine_slow: self.latency_ms.is_some_and(|ms| ms >= SLOW_LATENCY_MS),
+        }
+    }
+
+    /// Status lines for everything that isn't fully available
+    pub fn notices(&self) -> Vec<HealthNotice> {
+        let mut notices = Vec::new();
+        match self.llm {
+            ServiceHealth::Unavailable => notices.push(HealthNotice {
+                severity: ServiceHealth::Unavailable,
+                message: "The LLM isn't responding. Manual dialogue mode: NPC replies are written by the DM until it recovers.".to_string(),
+            }),
+            ServiceHealth::Degraded => notices.push(HealthNotice {
+                severity: ServiceHealth::Degraded,
+                message: "The LLM is taking longer than usual to respond.".to_string(),
+            }),
+            ServiceHealth::Available => {}
+        }
+        match self.comfyui {
+            ServiceHealth::Unavailable => notices.push(HealthNotice {
+                severity: ServiceHealth::Unavailable,
+                message: self.comfyui_message.clone().unwrap_or_else(|| {
+                    "Image generation is unavailable while ComfyUI is down.".to_string()
+                }),
+            }),
+            ServiceHealth::Degraded => notices.push(HealthNotice {
+                severity: ServiceHealth::Degraded,
+                message: self
+                    .comfyui_message
+                    .clone()
+                    .unwrap_or_else(|| "Image generation may be slow or fail.".to_string()),
+            }),
+            ServiceHealth::Available => {}
+        }
+        if let Some(ms) = self.latency_ms.filter(|ms| *ms >= SLOW_LATENCY_MS) {
+            notices.push(HealthNotice {
+                severity: ServiceHealth::Degraded,
+                message: format!("The Engine is responding slowly ({:.1}s).", ms as f64 / 1000.0),
+            });
+        }
+        for circuit in &self.open_circuits {
+            notices.push(HealthNotice {
+                severity: ServiceHealth::Unavailable,
+                message: format!(
+                    "Engine requests for {} keep failing; paused for {}s before trying again.",
+                    endpoint_label(&circuit.endpoint),
+                    circuit.retry_in_ms.div_ceil(1000)
+                ),
+            });
+        }
+        if let Some(message) = self.protocol.warning() {
+            notices.push(HealthNotice { severity: ServiceHealth::Degraded, message });
+        }
+        notices
+    }
+}
+
+/// "player characters" for `/api/sessions/*/player-characters`
+fn endpoint_label(endpoint: &str) -> String {
+    endpoint
+        .rsplit('/')
+        .find(|segment| !segment.is_empty() && *segment != "*")
+        .unwrap_or(endpoint)
+        .replace('-', " ")
+}
+
+/// Whether an Engine error came from the LLM
+pub fn is_llm_error(code: &str, message: &str) -> bool {
+    code.to_ascii_lowercase().contains("llm") || message.to_ascii_lowercase().contains("llm")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unanswered_llm_request_switches_to_manual_dialogue() {
+        let mut health = EngineHealth::new();
+        health.llm_request_started(1_000);
+        health.llm_request_started(5_000);
+
+        health.tick(1_000 + LLM_TIMEOUT_MS / 2);
+        assert_eq!(health.llm(), ServiceHealth::Degraded);
+        assert!(!health.modes().manual_dialogue);
+
+        health.tick(1_000 + LLM_TIMEOUT_MS);
+        assert!(health.modes().manual_dialogue);
+        assert_eq!(health.notices()[0].severity, ServiceHealth::Unavailable);
+
+        health.llm_responded();
+        assert!(!health.modes().manual_dialogue);
+        assert!(health.notices().is_empty());
+    }
+
+    #[test]
+    fn comfyui_and_latency_drive_modes() {
+        let mut health = EngineHealth::new();
+        health.set_comfyui_state("circuit_open", None);
+        assert!(!health.modes().generation_available);
+        health.set_comfyui_state("degraded", Some("Queue backed up".to_string()));
+        assert!(health.modes().generation_available);
+        assert_eq!(health.notices()[0].message, "Queue backed up");
+
+        health.ping_sent(0);
+        health.ping_sent(500);
+        health.pong_received(200);
+        assert_eq!(health.latency_ms(), Some(200));
+        assert!(!health.modes().engine_slow);
+
+        // A heartbeat that never comes back counts while it's outstanding
+        health.ping_sent(1_000);
+        health.tick(1_000 + SLOW_LATENCY_MS);
+        assert!(health.modes().engine_slow);
+    }
+
+    #[test]
+    fn newer_engine_protocol_adds_a_notice() {
+        let mut health = EngineHealth::new();
+        health.set_engine_protocol(Some(crate::application::dto::PROTOCOL_VERSION));
+        assert!(health.notices().is_empty());
+        health.set_engine_protocol(Some(crate::application::dto::PROTOCOL_VERSION + 1));
+        assert_eq!(health.notices().len(), 1);
+        // Engines that predate version reporting aren't flagged
+        health.set_engine_protocol(None);
+        assert!(health.notices().is_empty());
+    }
+
+    #[test]
+    fn open_circuits_add_a_notice_per_endpoint() {
+        let mut health = EngineHealth::new();
+        health.set_open_circuits(vec![OpenCircuit {
+            endpoint: "/api/sessions/*/player-characters".to_string(),
+            retry_in_ms: 12_300,
+        }]);
+        assert_eq!(
+            health.notices()[0].message,
+            "Engine requests for player characters keep failing; paused for 13s before trying again."
+        );
+        health.set_open_circuits(Vec::new());
+        assert!(health.notices().is_empty());
+    }
+
+    #[test]
+    fn recognises_llm_errors() {
+        assert!(is_llm_error("LLM_TIMEOUT", ""));
+        assert!(is_llm_error("INTERNAL", "llm request failed"));
+        assert!(!is_llm_error("NOT_FOUND", "No such scene"));
+    }
+}
@@ -0,0 +1,205 @@
+//! Entity History Service - Saved versions of characters, locations and challenges
+//!
+//! The Engine stores a version each time one of these entities is saved,
+//! with who saved it and when. Versions carry the full saved document, so
+//! the Player works out field-level changes itself with [`diff_versions`].
+//! Rolling back saves an earlier version's contents as a new version, so
+//! history is never rewritten.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::application::ports::outbound::{ApiError, ApiPort};
+
+/// Kinds of entity with a version history
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryEntityKind {
+    Character,
+    Location,
+    Challenge,
+}
+
+impl HistoryEntityKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Character => "Character",
+            Self::Location => "Location",
+            Self::Challenge => "Challenge",
+        }
+    }
+
+    /// Path segment used by the version endpoints
+    pub fn path_segment(&self) -> &'static str {
+        match self {
+            Self::Character => "characters",
+            Self::Location => "locations",
+            Self::Challenge => "challenges",
+        }
+    }
+}
+
+/// One saved version of an entity
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct EntityVersion {
+    /// Increasing version number, starting at 1
+    pub version: u32,
+    /// Display name of whoever saved it, if known
+    #[serde(default)]
+    pub author: Option<String>,
+    /// RFC 3339 time the version was saved
+    pub saved_at: String,
+    /// The entity as it was saved
+    pub data: Value,
+}
+
+/// A top-level field that differs between two versions
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    /// Value in the older version (`None` if the field was absent)
+    pub before: Option<Value>,
+    /// Value in the newer version (`None` if the field was removed)
+    pub after: Option<Value>,
+}
+
+/// Fields that change on every save and say nothing about the edit
+const IGNORED_FIELDS: &[&str] = &["id", "world_id", "updated_at", "created_at"];
+
+/// Top-level fields that differ between an older and a newer version
+///
+/// Both documents are expected to be JSON objects; anything else is treated
+/// as a single `value` field. Changes are sorted by field name. Nulls count
+/// as absent, so an optional field that serializes as null doesn't show up.
+pub fn diff_versions(older: &Value, newer: &Value) -> Vec<FieldChange> {
+    let as_fields = |v: &Value| -> Vec<(String, Value)> {
+        match v {
+            Value::Object(map) => map
+                .iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(k, value)| (k.clone(), value.clone()))
+                .collect(),
+            other => vec![("value".to_string(), other.clone())],
+        }
+    };
+    let before = as_fields(older);
+    let after = as_fields(newer);
+
+    let mut fields: Vec<&String> = before.iter().chain(after.iter()).map(|(k, _)| k).collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        .filter(|f| !IGNORED_FIELDS.contains(&f.as_str()))
+        .filter_map(|field| {
+            let old = before.iter().find(|(k, _)| k == field).map(|(_, v)| v.clone());
+            let new = after.iter().find(|(k, _)| k == field).map(|(_, v)| v.clone());
+            (old != new).then(|| FieldChange { field: field.clone(), before: old, after: new })
+        })
+        .collect()
+}
+
+/// Short text for a field value in the history drawer
+pub fn display_value(value: Option<&Value>) -> String {
+    const MAX_CHARS: usize = 120;
+    let text = match value {
+        None => return "(empty)".to_string(),
+        Some(Value::String(s)) if s.is_empty() => return "(empty)".to_string(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    };
+    if text.chars().count() > MAX_CHARS {
+        let cut: String = text.chars().take(MAX_CHARS).collect();
+        format!("{}…", cut)
+    } else {
+        text
+    }
+}
+
+/// History service for listing and rolling back entity versions
+pub struct EntityHistoryService<A: ApiPort> {
+    api: A,
+}
+
+impl<A: ApiPort> EntityHistoryService<A> {
+    /// Create a new EntityHistoryService with the given API port
+    pub fn new(api: A) -> Self {
+        Self { api }
+    }
+
+    /// List an entity's versions, newest first
+    pub async fn list_versions(&self, kind: HistoryEntityKind, id: &str) -> Result<Vec<EntityVersion>, ApiError> {
+        let path = format!("/api/{}/{}/versions", kind.path_segment(), id);
+        let mut versions: Vec<EntityVersion> = self.api.get(&path).await?;
+        versions.sort_by_key(|v| std::cmp::Reverse(v.version));
+        Ok(versions)
+    }
+
+    /// Save an earlier version's contents as the entity's newest version
+    pub async fn rollback(&self, kind: HistoryEntityKind, id: &str, version: u32) -> Result<(), ApiError> {
+        let path = format!("/api/{}/{}/versions/{}/rollback", kind.path_segment(), id, version);
+        self.api.post_empty(&path).await
+    }
+}
+
+impl<A: ApiPort + Clone> Clone for EntityHistoryService<A> {
+    fn clone(&self) -> Self {
+        Self {
+            api: self.api.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::testing::MockApiPort;
+    use serde_json::json;
+
+    #[test]
+    fn diff_reports_changed_added_and_removed_fields() {
+        let older = json!({ "id": "c1", "name": "Ada", "archetype": "Mentor", "wants": "gold" });
+        let newer = json!({ "id": "c1", "name": "Ada", "archetype": "Trickster", "description": "Sly" });
+
+        let changes = diff_versions(&older, &newer);
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["archetype", "description", "wants"]);
+        assert_eq!(changes[1].before, None);
+        assert_eq!(changes[2].after, None);
+    }
+
+    #[test]
+    fn diff_ignores_bookkeeping_and_null_fields() {
+        let older = json!({ "name": "Ada", "updated_at": "2024-01-01", "sprite_asset": null });
+        let newer = json!({ "name": "Ada", "updated_at": "2024-01-02" });
+        assert!(diff_versions(&older, &newer).is_empty());
+    }
+
+    #[test]
+    fn display_value_shortens_long_text() {
+        assert_eq!(display_value(None), "(empty)");
+        assert_eq!(display_value(Some(&json!(["a", "b"]))), "[\"a\",\"b\"]");
+        let long = "x".repeat(200);
+        assert_eq!(display_value(Some(&json!(long))).chars().count(), 121);
+    }
+
+    #[tokio::test]
+    async fn lists_versions_newest_first() {
+        let api = MockApiPort::new();
+        api.when_get_json(
+            "/api/locations/loc-1/versions",
+            json!([
+                { "version": 1, "author": "DM", "saved_at": "2024-01-01T00:00:00Z", "data": { "name": "Inn" } },
+                { "version": 2, "saved_at": "2024-01-02T00:00:00Z", "data": { "name": "Old Inn" } }
+            ]),
+        );
+
+        let svc = EntityHistoryService::new(api.clone());
+        let versions = svc.list_versions(HistoryEntityKind::Location, "loc-1").await.expect("versions");
+
+        assert_eq!(versions[0].version, 2);
+        assert_eq!(versions[0].author, None);
+        assert_eq!(versions[1].author.as_deref(), Some("DM"));
+    }
+}
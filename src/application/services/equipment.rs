@@ -0,0 +1,177 @@
+//! Equipment - Equip rules and equipped-item modifiers
+//!
+//! Each rule system defines the slots items can be equipped into. A slot
+//! holds one item, so equipping into an occupied slot swaps the old item
+//! out. Modifiers on equipped items are added to matching sheet stats and
+//! to challenge rolls for matching skills.
+
+use std::collections::HashMap;
+
+use crate::application::dto::{
+    EquipmentSlotDefinition, FieldType, FieldValue, InventoryItemData, SheetTemplate,
+};
+
+/// The slot an item would be equipped into, if any accepts it
+pub fn slot_for_item<'a>(
+    slots: &'a [EquipmentSlotDefinition],
+    item: &InventoryItemData,
+) -> Option<&'a EquipmentSlotDefinition> {
+    let item_type = item.item.item_type.as_deref()?;
+    slots.iter().find(|slot| slot.accepts(item_type))
+}
+
+/// What toggling an item's equipped state changes
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquipChange {
+    pub item_id: String,
+    /// Slot the item goes into; `None` when unequipping
+    pub slot: Option<String>,
+    /// Items swapped out of the slot to make room
+    pub unequip: Vec<String>,
+}
+
+/// Plan equipping or unequipping an item
+///
+/// Returns `None` when the item isn't in the inventory or no slot accepts it.
+pub fn plan_equip_toggle(
+    items: &[InventoryItemData],
+    slots: &[EquipmentSlotDefinition],
+    item_id: &str,
+) -> Option<EquipChange> {
+    let item = items.iter().find(|i| i.item.id == item_id)?;
+    if item.equipped {
+        return Some(EquipChange { item_id: item_id.to_string(), slot: None, unequip: Vec::new() });
+    }
+    let slot = slot_for_item(slots, item)?;
+    let unequip = items
+        .iter()
+        .filter(|i| i.equipped && i.item.id != item_id)
+        .filter(|i| i.slot.as_deref() == Some(slot.id.as_str()))
+        .map(|i| i.item.id.clone())
+        .collect();
+    Some(EquipChange { item_id: item_id.to_string(), slot: Some(slot.id.clone()), unequip })
+}
+
+/// Apply a planned change to a local copy of the inventory
+pub fn apply_equip_change(items: &mut [InventoryItemData], change: &EquipChange) {
+    for item in items.iter_mut() {
+        if item.item.id == change.item_id {
+            item.equipped = change.slot.is_some();
+            item.slot = change.slot.clone();
+        } else if change.unequip.contains(&item.item.id) {
+            item.equipped = false;
+            item.slot = None;
+        }
+    }
+}
+
+/// Total bonus equipped items give to a stat or skill
+pub fn equipment_bonus(items: &[InventoryItemData], target: &str) -> i32 {
+    items
+        .iter()
+        .filter(|i| i.equipped)
+        .flat_map(|i| i.item.modifiers.iter())
+        .filter(|m| m.target.eq_ignore_ascii_case(target))
+        .map(|m| m.value)
+        .sum()
+}
+
+/// Sheet values with equipped-item modifiers folded into number fields
+///
+/// A modifier applies to a field when its target matches the field's name
+/// or ID. Fields without a stored value start from their default.
+pub fn sheet_values_with_equipment(
+    template: &SheetTemplate,
+    values: &HashMap<String, FieldValue>,
+    items: &[InventoryItemData],
+) -> HashMap<String, FieldValue> {
+    let mut adjusted = values.clone();
+    for field in template.sections.iter().flat_map(|s| s.fields.iter()) {
+        let FieldType::Number { default, .. } = &field.field_type else {
+            continue;
+        };
+        let bonus = equipment_bonus(items, &field.name) + equipment_bonus(items, &field.id);
+        if bonus == 0 {
+            continue;
+        }
+        let base = match values.get(&field.id) {
+            Some(FieldValue::Number(n)) => *n,
+            _ => default.unwrap_or(0),
+        };
+        adjusted.insert(field.id.clone(), FieldValue::Number(base + bonus));
+    }
+    adjusted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::world_snapshot::{default_equipment_slots, ItemModifier};
+    use crate::application::dto::{EntityStyle, ItemData};
+
+    fn item(id: &str, item_type: &str, equipped: bool, slot: Option<&str>) -> InventoryItemData {
+        InventoryItemData {
+            item: ItemData {
+                id: id.to_string(),
+                world_id: "w-1".to_string(),
+                name: id.to_string(),
+                description: None,
+                item_type: Some(item_type.to_string()),
+                is_unique: false,
+                properties: None,
+                tags: Vec::new(),
+                modifiers: vec![ItemModifier { target: "Stealth".to_string(), value: 2 }],
+                style: EntityStyle::default(),
+                image_url: None,
+                weight: None,
+                value: None,
+                hidden_notes: None,
+                has_hidden_notes: false,
+                deleted_at: None,
+            },
+            quantity: 1,
+            equipped,
+            slot: slot.map(str::to_string),
+            acquired_at: String::new(),
+            acquisition_method: None,
+            acquired_from: None,
+            acquired_location: None,
+            identified: false,
+        }
+    }
+
+    #[test]
+    fn equipping_into_an_occupied_slot_swaps_the_old_item_out() {
+        let slots = default_equipment_slots();
+        let mut items = vec![
+            item("dagger", "Weapon", true, Some("weapon")),
+            item("bow", "Weapon", false, None),
+            item("cloak", "Armor", true, Some("armor")),
+        ];
+
+        let change = plan_equip_toggle(&items, &slots, "bow").unwrap();
+        assert_eq!(change.slot.as_deref(), Some("weapon"));
+        assert_eq!(change.unequip, vec!["dagger".to_string()]);
+
+        apply_equip_change(&mut items, &change);
+        assert!(!items[0].equipped);
+        assert!(items[1].equipped);
+        assert!(items[2].equipped);
+    }
+
+    #[test]
+    fn items_without_a_slot_cannot_be_equipped() {
+        let items = vec![item("potion", "Consumable", false, None)];
+        assert_eq!(plan_equip_toggle(&items, &default_equipment_slots(), "potion"), None);
+    }
+
+    #[test]
+    fn only_equipped_items_grant_bonuses() {
+        let items = vec![
+            item("cloak", "Armor", true, Some("armor")),
+            item("boots", "Armor", false, None),
+        ];
+        assert_eq!(equipment_bonus(&items, "stealth"), 2);
+        assert_eq!(equipment_bonus(&items, "Athletics"), 0);
+    }
+}
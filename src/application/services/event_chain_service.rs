@@ -22,6 +22,9 @@ pub struct EventChainData {
     pub act_id: Option<String>,
     pub tags: Vec<String>,
     pub color: Option<String>,
+    /// Emoji or short symbol shown with the chain's colour
+    #[serde(default)]
+    pub icon: Option<String>,
     pub is_favorite: bool,
     pub progress_percent: u32,
     pub is_complete: bool,
@@ -44,6 +47,8 @@ pub struct CreateEventChainRequest {
     pub tags: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub is_active: bool,
 }
@@ -61,8 +66,12 @@ pub struct UpdateEventChainRequest {
     pub act_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
+    /// `Some(None)` is sent as null and clears the colour
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub color: Option<String>,
+    pub color: Option<Option<String>>,
+    /// `Some(None)` is sent as null and clears the icon
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<Option<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_active: Option<bool>,
 }
@@ -0,0 +1,179 @@
+//! Faction Service - Factions scoped to a world
+//!
+//! Factions are written in Creator Mode. During a session the DM moves the
+//! party's standing with one through `adjust_standing`, which the Engine
+//! applies to the stored value so co-DMs adjusting at once don't overwrite
+//! each other.
+
+use serde::Serialize;
+
+use crate::application::dto::{Faction, FactionStanding, MAX_STANDING, MIN_STANDING};
+use crate::application::ports::outbound::{ApiError, ApiPort};
+
+/// Request to create or update a faction
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SaveFactionRequest {
+    pub name: String,
+    pub description: String,
+    pub goals: Vec<String>,
+    pub member_ids: Vec<String>,
+    pub standings: Vec<FactionStanding>,
+}
+
+impl SaveFactionRequest {
+    /// Check the request before it is sent
+    ///
+    /// A faction needs a name, and its standings must be in range and
+    /// toward other factions.
+    pub fn validate(&self, faction_id: &str) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Faction name is required".to_string());
+        }
+        if self
+            .standings
+            .iter()
+            .any(|s| !(MIN_STANDING..=MAX_STANDING).contains(&s.standing))
+        {
+            return Err(format!("Standings must be between {} and {}", MIN_STANDING, MAX_STANDING));
+        }
+        if !faction_id.is_empty() && self.standings.iter().any(|s| s.faction_id == faction_id) {
+            return Err("A faction can't have a standing toward itself".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl From<&Faction> for SaveFactionRequest {
+    fn from(faction: &Faction) -> Self {
+        Self {
+            name: faction.name.clone(),
+            description: faction.description.clone(),
+            goals: faction.goals.clone(),
+            member_ids: faction.member_ids.clone(),
+            standings: faction.standings.clone(),
+        }
+    }
+}
+
+/// Request to move the party's standing with a faction
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AdjustStandingRequest {
+    /// Amount to add; negative lowers the standing
+    pub delta: i32,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub reason: String,
+}
+
+/// Faction service for writing factions and tracking standings
+///
+/// This service provides CRUD for factions while depending only on the
+/// `ApiPort` trait, not concrete infrastructure implementations.
+pub struct FactionService<A: ApiPort> {
+    api: A,
+}
+
+impl<A: ApiPort> FactionService<A> {
+    /// Create a new FactionService with the given API port
+    pub fn new(api: A) -> Self {
+        Self { api }
+    }
+
+    /// List all factions in a world
+    pub async fn list_factions(&self, world_id: &str) -> Result<Vec<Faction>, ApiError> {
+        let path = format!("/api/worlds/{}/factions", world_id);
+        self.api.get(&path).await
+    }
+
+    /// Create a faction
+    pub async fn create_faction(&self, world_id: &str, request: &SaveFactionRequest) -> Result<Faction, ApiError> {
+        let path = format!("/api/worlds/{}/factions", world_id);
+        self.api.post(&path, request).await
+    }
+
+    /// Update a faction
+    pub async fn update_faction(&self, faction_id: &str, request: &SaveFactionRequest) -> Result<Faction, ApiError> {
+        let path = format!("/api/factions/{}", faction_id);
+        self.api.put(&path, request).await
+    }
+
+    /// Delete a faction
+    pub async fn delete_faction(&self, faction_id: &str) -> Result<(), ApiError> {
+        let path = format!("/api/factions/{}", faction_id);
+        self.api.delete(&path).await
+    }
+
+    /// Move the party's standing with a faction, returning the updated faction
+    pub async fn adjust_standing(
+        &self,
+        faction_id: &str,
+        request: &AdjustStandingRequest,
+    ) -> Result<Faction, ApiError> {
+        let path = format!("/api/factions/{}/party-standing", faction_id);
+        self.api.post(&path, request).await
+    }
+}
+
+impl<A: ApiPort + Clone> Clone for FactionService<A> {
+    fn clone(&self) -> Self {
+        Self {
+            api: self.api.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::testing::MockApiPort;
+
+    fn standing(faction_id: &str, standing: i32) -> FactionStanding {
+        FactionStanding {
+            faction_id: faction_id.to_string(),
+            standing,
+        }
+    }
+
+    #[test]
+    fn validate_checks_name_and_standings() {
+        let request = SaveFactionRequest {
+            name: "Harbour Guild".to_string(),
+            standings: vec![standing("watch", -40)],
+            ..Default::default()
+        };
+        assert!(request.validate("guild").is_ok());
+        assert!(SaveFactionRequest { name: " ".to_string(), ..request.clone() }.validate("guild").is_err());
+        assert!(SaveFactionRequest { standings: vec![standing("watch", 101)], ..request.clone() }
+            .validate("guild")
+            .is_err());
+        assert!(SaveFactionRequest { standings: vec![standing("guild", 10)], ..request }
+            .validate("guild")
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn adjust_standing_posts_the_delta() {
+        let api = MockApiPort::new();
+        api.when_post_json(
+            "/api/factions/guild/party-standing",
+            serde_json::json!({
+                "id": "guild",
+                "world_id": "world-1",
+                "name": "Harbour Guild",
+                "party_standing": 25
+            }),
+        );
+
+        let svc = FactionService::new(api.clone());
+        let request = AdjustStandingRequest {
+            delta: 15,
+            reason: String::new(),
+        };
+        let updated = svc.adjust_standing("guild", &request).await.expect("adjusted");
+
+        assert_eq!(updated.party_standing, 25);
+        assert!(updated.member_ids.is_empty());
+        let reqs = api.requests();
+        assert_eq!(reqs[0].method, "POST");
+        assert_eq!(reqs[0].body.as_ref().unwrap(), &serde_json::json!({ "delta": 15 }));
+    }
+}
@@ -0,0 +1,143 @@
+//! Filter Presets - Named filter combinations saved per world
+//!
+//! The Challenge Library, Generation Queue and Story Arc timeline each let
+//! the DM save the current filters (type, tags, favorites, search) under a
+//! name and re-apply them from a chip. Presets are kept on this device,
+//! separately for each list and world.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::outbound::{storage_keys, Platform};
+use crate::domain::value_objects::tags::matches_all_tags;
+
+/// Most presets kept per list and world
+pub const MAX_PRESETS: usize = 20;
+
+/// Which list a preset belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterPresetScope {
+    ChallengeLibrary,
+    GenerationQueue,
+    Timeline,
+}
+
+impl FilterPresetScope {
+    fn key_part(&self) -> &'static str {
+        match self {
+            Self::ChallengeLibrary => "challenges",
+            Self::GenerationQueue => "queue",
+            Self::Timeline => "timeline",
+        }
+    }
+}
+
+/// A saved combination of filters
+///
+/// Lists ignore the fields they don't filter by.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FilterPreset {
+    pub name: String,
+    /// The list's own type filter value, e.g. "SkillCheck" or "Images"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_filter: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub favorites_only: bool,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub search: String,
+}
+
+impl FilterPreset {
+    /// Whether this preset's filters are the ones currently applied
+    pub fn matches(&self, current: &FilterPreset) -> bool {
+        self.type_filter == current.type_filter
+            && self.favorites_only == current.favorites_only
+            && self.search.trim().eq_ignore_ascii_case(current.search.trim())
+            && self.tags.len() == current.tags.len()
+            && matches_all_tags(&self.tags, &current.tags)
+    }
+
+    /// Whether the preset filters anything at all
+    pub fn is_empty(&self) -> bool {
+        self.type_filter.is_none() && self.tags.is_empty() && !self.favorites_only && self.search.trim().is_empty()
+    }
+}
+
+/// Add a preset, replacing any with the same name (case-insensitively)
+///
+/// New presets go to the end; past [`MAX_PRESETS`] the oldest is dropped.
+pub fn upsert_preset(presets: &mut Vec<FilterPreset>, preset: FilterPreset) {
+    if let Some(existing) = presets.iter_mut().find(|p| p.name.eq_ignore_ascii_case(&preset.name)) {
+        *existing = preset;
+        return;
+    }
+    presets.push(preset);
+    let overflow = presets.len().saturating_sub(MAX_PRESETS);
+    presets.drain(..overflow);
+}
+
+fn presets_key(scope: FilterPresetScope, world_id: &str) -> String {
+    format!("{}{}_{}", storage_keys::FILTER_PRESETS_PREFIX, scope.key_part(), world_id)
+}
+
+/// Presets saved on this device for a list in a world
+pub fn load_filter_presets(platform: &Platform, scope: FilterPresetScope, world_id: &str) -> Vec<FilterPreset> {
+    platform
+        .storage_load(&presets_key(scope, world_id))
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Save a list's presets for a world on this device
+pub fn save_filter_presets(platform: &Platform, scope: FilterPresetScope, world_id: &str, presets: &[FilterPreset]) {
+    match serde_json::to_string(presets) {
+        Ok(json) => platform.storage_save(&presets_key(scope, world_id), &json),
+        Err(e) => platform.log_error(&format!("Failed to serialize filter presets: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preset(name: &str, search: &str) -> FilterPreset {
+        FilterPreset {
+            name: name.to_string(),
+            search: search.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn upsert_replaces_by_name_and_caps_the_list() {
+        let mut presets = vec![preset("Combat", "goblin")];
+        upsert_preset(&mut presets, preset("combat", "orc"));
+        assert_eq!(presets.len(), 1);
+        assert_eq!(presets[0].search, "orc");
+
+        for i in 0..MAX_PRESETS {
+            upsert_preset(&mut presets, preset(&format!("P{}", i), ""));
+        }
+        assert_eq!(presets.len(), MAX_PRESETS);
+        assert_eq!(presets[0].name, "P0");
+    }
+
+    #[test]
+    fn matches_ignores_name_and_tag_order() {
+        let saved = FilterPreset {
+            name: "Social".to_string(),
+            tags: vec!["Noble".to_string(), "court".to_string()],
+            favorites_only: true,
+            ..Default::default()
+        };
+        let current = FilterPreset {
+            tags: vec!["court".to_string(), "noble".to_string()],
+            favorites_only: true,
+            ..Default::default()
+        };
+        assert!(saved.matches(&current));
+        assert!(!saved.matches(&FilterPreset { favorites_only: false, ..current }));
+        assert!(FilterPreset::default().is_empty());
+    }
+}
@@ -0,0 +1,321 @@
+//! Form Validation - Declarative rules for editor forms
+//!
+//! A form describes its rules once as [`FormRules`]: per-field rules
+//! (required, length, numeric range, pattern) plus cross-field checks. Each
+//! render it validates the current [`FormValues`] and gets back
+//! [`ValidationErrors`], keyed by field, which drive both the inline
+//! messages and the save gate. Checks that need more than the form's own
+//! values (prerequisite cycles, say) are added with
+//! [`ValidationErrors::push`]. Character sheet fields bring their own rules
+//! from the template; see [`FormRules::sheet_fields`].
+
+use std::collections::HashMap;
+
+use regex_lite::Regex;
+
+use crate::application::dto::{FieldType, FieldValue, SheetTemplate};
+
+/// A rule for one field's value
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldRule {
+    /// Not empty once trimmed
+    Required,
+    /// Character count, trimmed, within the bounds
+    Length { min: Option<usize>, max: Option<usize> },
+    /// A whole number within the bounds; empty values are left to `Required`
+    Range { min: Option<i64>, max: Option<i64> },
+    /// Matches `pattern`; `message` says what was expected
+    Pattern { pattern: &'static str, message: &'static str },
+}
+
+impl FieldRule {
+    /// Error for `value`, if the rule fails; `label` names the field
+    fn check(&self, label: &str, value: &str) -> Option<String> {
+        let value = value.trim();
+        match self {
+            Self::Required => value.is_empty().then(|| format!("{} is required", label)),
+            Self::Length { min, max } => {
+                let length = value.chars().count();
+                match (min, max) {
+                    (Some(min), _) if length < *min => Some(format!("{} must be at least {} characters", label, min)),
+                    (_, Some(max)) if length > *max => Some(format!("{} must be at most {} characters", label, max)),
+                    _ => None,
+                }
+            }
+            Self::Range { min, max } => {
+                if value.is_empty() {
+                    return None;
+                }
+                let Ok(number) = value.parse::<i64>() else {
+                    return Some(format!("{} must be a whole number", label));
+                };
+                match (min, max) {
+                    (Some(min), Some(max)) if number < *min || number > *max => {
+                        Some(format!("{} must be between {} and {}", label, min, max))
+                    }
+                    (Some(min), None) if number < *min => Some(format!("{} must be at least {}", label, min)),
+                    (None, Some(max)) if number > *max => Some(format!("{} must be at most {}", label, max)),
+                    _ => None,
+                }
+            }
+            Self::Pattern { pattern, message } => {
+                if value.is_empty() {
+                    return None;
+                }
+                // Patterns are written into the forms; a broken one fails open
+                let re = Regex::new(pattern).ok()?;
+                (!re.is_match(value)).then(|| format!("{} {}", label, message))
+            }
+        }
+    }
+}
+
+/// A form's current values, as text keyed by field name
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormValues(HashMap<String, String>);
+
+impl FormValues {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a field's value
+    pub fn with(mut self, field: impl Into<String>, value: impl ToString) -> Self {
+        self.0.insert(field.into(), value.to_string());
+        self
+    }
+
+    /// Add a character sheet's values, keyed by field ID
+    pub fn with_sheet_values(mut self, values: &HashMap<String, FieldValue>) -> Self {
+        for (id, value) in values {
+            self.0.insert(id.clone(), value.summary());
+        }
+        self
+    }
+
+    /// A field's value; missing fields read as empty
+    pub fn get(&self, field: &str) -> &str {
+        self.0.get(field).map(String::as_str).unwrap_or("")
+    }
+}
+
+/// A rule spanning several fields, reported against `field`
+#[derive(Debug, Clone)]
+struct CrossFieldRule {
+    field: String,
+    message: String,
+    /// Whether the values pass
+    check: fn(&FormValues) -> bool,
+}
+
+#[derive(Debug, Clone)]
+struct FieldRules {
+    field: String,
+    label: String,
+    rules: Vec<FieldRule>,
+}
+
+/// Every rule of one form
+#[derive(Debug, Clone, Default)]
+pub struct FormRules {
+    fields: Vec<FieldRules>,
+    cross: Vec<CrossFieldRule>,
+}
+
+impl FormRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rules for `field`, shown to the user as `label`
+    pub fn field(mut self, field: impl Into<String>, label: impl Into<String>, rules: Vec<FieldRule>) -> Self {
+        self.fields.push(FieldRules { field: field.into(), label: label.into(), rules });
+        self
+    }
+
+    /// Rules from a character sheet template, keyed by field ID
+    ///
+    /// Required fields must have a value, and number fields must stay within
+    /// their bounds.
+    pub fn sheet_fields(mut self, template: &SheetTemplate) -> Self {
+        for field in template.sections.iter().flat_map(|s| s.fields.iter()) {
+            let mut rules = Vec::new();
+            if field.required && !field.read_only {
+                rules.push(FieldRule::Required);
+            }
+            if let FieldType::Number { min, max, .. } = &field.field_type {
+                if min.is_some() || max.is_some() {
+                    rules.push(FieldRule::Range { min: min.map(i64::from), max: max.map(i64::from) });
+                }
+            }
+            if !rules.is_empty() {
+                self = self.field(field.id.clone(), field.name.clone(), rules);
+            }
+        }
+        self
+    }
+
+    /// A check across fields; `message` is reported against `field` when
+    /// `check` returns false
+    pub fn cross(mut self, field: impl Into<String>, message: impl Into<String>, check: fn(&FormValues) -> bool) -> Self {
+        self.cross.push(CrossFieldRule { field: field.into(), message: message.into(), check });
+        self
+    }
+
+    /// Check `values` against every rule
+    ///
+    /// Each field reports only its first failing rule. Cross-field checks
+    /// run after the field rules and are skipped for fields already in error.
+    pub fn validate(&self, values: &FormValues) -> ValidationErrors {
+        let mut errors = ValidationErrors::default();
+        for field in &self.fields {
+            let value = values.get(&field.field);
+            if let Some(message) = field.rules.iter().find_map(|rule| rule.check(&field.label, value)) {
+                errors.push(field.field.clone(), message);
+            }
+        }
+        for rule in &self.cross {
+            if errors.for_field(&rule.field).is_none() && !(rule.check)(values) {
+                errors.push(rule.field.clone(), rule.message.clone());
+            }
+        }
+        errors
+    }
+}
+
+/// Errors found in a form, in rule order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationErrors {
+    errors: Vec<(String, String)>,
+}
+
+impl ValidationErrors {
+    /// Add an error for `field`
+    pub fn push(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.errors.push((field.into(), message.into()));
+    }
+
+    /// Add an error for `field` when `failed` holds
+    pub fn push_if(&mut self, failed: bool, field: impl Into<String>, message: impl Into<String>) {
+        if failed {
+            self.push(field, message);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// First error for `field`
+    pub fn for_field(&self, field: &str) -> Option<&str> {
+        self.errors.iter().find(|(f, _)| *f == field).map(|(_, m)| m.as_str())
+    }
+
+    /// Each error as (field, message), in rule order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.errors.iter().map(|(f, m)| (f.as_str(), m.as_str()))
+    }
+
+    /// Every message, for a summary above the form
+    pub fn messages(&self) -> Vec<String> {
+        self.errors.iter().map(|(_, m)| m.clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules() -> FormRules {
+        FormRules::new()
+            .field("name", "Name", vec![FieldRule::Required, FieldRule::Length { min: None, max: Some(10) }])
+            .field("dc", "DC", vec![FieldRule::Range { min: Some(1), max: Some(30) }])
+            .field(
+                "code",
+                "Code",
+                vec![FieldRule::Pattern { pattern: r"^[A-Z]{3}$", message: "must be three capital letters" }],
+            )
+            .field("min", "Min", vec![FieldRule::Range { min: Some(0), max: None }])
+            .cross("max", "Max must not be below Min", |v| {
+                match (v.get("min").parse::<i64>(), v.get("max").parse::<i64>()) {
+                    (Ok(min), Ok(max)) => max >= min,
+                    _ => true,
+                }
+            })
+    }
+
+    #[test]
+    fn each_field_reports_its_first_failing_rule() {
+        let errors = rules().validate(&FormValues::new().with("name", "  ").with("dc", 40).with("code", "ab"));
+        assert_eq!(errors.for_field("name"), Some("Name is required"));
+        assert_eq!(errors.for_field("dc"), Some("DC must be between 1 and 30"));
+        assert_eq!(errors.for_field("code"), Some("Code must be three capital letters"));
+        assert_eq!(errors.messages().len(), 3);
+
+        let errors = rules().validate(&FormValues::new().with("name", "A much too long name").with("dc", "x"));
+        assert_eq!(errors.for_field("name"), Some("Name must be at most 10 characters"));
+        assert_eq!(errors.for_field("dc"), Some("DC must be a whole number"));
+    }
+
+    #[test]
+    fn valid_values_pass_and_empty_optional_fields_are_skipped() {
+        let errors = rules().validate(&FormValues::new().with("name", "Lockpick").with("code", "ABC"));
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn cross_field_rules_and_extra_errors_join_the_list() {
+        let values = FormValues::new().with("name", "Trap").with("min", 5).with("max", 2);
+        let mut errors = rules().validate(&values);
+        assert_eq!(errors.for_field("max"), Some("Max must not be below Min"));
+
+        errors.push_if(true, "prerequisites", "Prerequisites would create a cycle");
+        errors.push_if(false, "name", "never shown");
+        assert_eq!(
+            errors.messages(),
+            vec!["Max must not be below Min".to_string(), "Prerequisites would create a cycle".to_string()]
+        );
+    }
+
+    #[test]
+    fn sheet_templates_bring_required_and_range_rules() {
+        use crate::application::dto::{SectionLayout, SheetField, SheetSection};
+
+        let field = |id: &str, required: bool, field_type: FieldType| SheetField {
+            id: id.to_string(),
+            name: id.to_uppercase(),
+            description: None,
+            field_type,
+            required,
+            read_only: false,
+            order: 0,
+            vital: false,
+        };
+        let template = SheetTemplate {
+            id: "t1".to_string(),
+            world_id: "w1".to_string(),
+            name: "Classic".to_string(),
+            description: String::new(),
+            variant: String::new(),
+            sections: vec![SheetSection {
+                id: "s1".to_string(),
+                name: "Core".to_string(),
+                description: None,
+                fields: vec![
+                    field("str", false, FieldType::Number { min: Some(1), max: Some(20), default: None }),
+                    field("class", true, FieldType::Text { multiline: false, max_length: None }),
+                ],
+                layout: SectionLayout::Vertical,
+                collapsible: false,
+                collapsed_by_default: false,
+                order: 0,
+            }],
+            is_default: false,
+        };
+        let values = HashMap::from([("str".to_string(), FieldValue::Number(25))]);
+
+        let errors = FormRules::new().sheet_fields(&template).validate(&FormValues::new().with_sheet_values(&values));
+        assert_eq!(errors.for_field("str"), Some("STR must be between 1 and 20"));
+        assert_eq!(errors.for_field("class"), Some("CLASS is required"));
+    }
+}
@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::application::dto::GenerationParams;
 use crate::application::ports::outbound::{ApiError, ApiPort};
 
 /// DTO for batch status information from the Engine
@@ -21,6 +22,9 @@ pub struct BatchInfo {
     pub error: Option<String>,
     #[serde(default)]
     pub is_read: bool,
+    /// Parameter overrides the batch was generated with
+    #[serde(default)]
+    pub params: Option<GenerationParams>,
 }
 
 /// DTO for suggestion task information from the Engine
@@ -34,6 +38,9 @@ pub struct SuggestionInfo {
     pub error: Option<String>,
     #[serde(default)]
     pub is_read: bool,
+    /// Index of the suggestion the DM applied, as reported through feedback
+    #[serde(default)]
+    pub applied_index: Option<usize>,
 }
 
 /// Complete generation queue snapshot from the Engine
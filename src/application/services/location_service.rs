@@ -6,7 +6,10 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::application::ports::outbound::{ApiError, ApiPort};
+use crate::application::dto::{EntityStyle, RegionPopulation};
+use crate::application::ports::outbound::{ApiError, ApiPort, Page, PageRequest};
+use crate::application::services::archive::without_archived;
+use crate::application::services::trash_service::without_trashed;
 
 /// Location summary for list views
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -14,6 +17,20 @@ pub struct LocationSummary {
     pub id: String,
     pub name: String,
     pub location_type: Option<String>,
+    /// The location this one sits inside, if any
+    #[serde(default)]
+    pub parent_location_id: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Icon and accent colour picked by the DM
+    #[serde(flatten)]
+    pub style: EntityStyle,
+    /// When the location was moved to the trash, if it has been
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+    /// When the entity was archived, if it is
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
 }
 
 /// Full location data for create/edit forms via API
@@ -36,8 +53,15 @@ pub struct LocationFormData {
     pub parent_location_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub backdrop_asset: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animated_backdrop_asset: Option<String>,
     #[serde(default)]
     pub backdrop_regions: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Icon and accent colour picked by the DM
+    #[serde(flatten)]
+    pub style: EntityStyle,
 }
 
 /// Location connection data
@@ -74,6 +98,9 @@ pub struct RegionData {
     pub is_spawn_point: bool,
     #[serde(default)]
     pub order: u32,
+    /// Background crowd, as the DM last set it
+    #[serde(default)]
+    pub population: RegionPopulation,
 }
 
 /// Map bounds for positioning regions
@@ -85,6 +112,44 @@ pub struct MapBoundsData {
     pub height: u32,
 }
 
+/// An authored interaction available to players in a location or region
+///
+/// This is the DM-side definition; players receive the runtime view of it as
+/// `InteractionData` in scene updates.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InteractionTemplateData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub location_id: String,
+    /// Restrict the interaction to one region; `None` for the whole location
+    #[serde(default)]
+    pub region_id: Option<String>,
+    pub name: String,
+    /// e.g. "Examine", "Search", "Use Object"
+    pub interaction_type: String,
+    /// Object or feature acted on, shown to players as the target
+    #[serde(default)]
+    pub target_name: Option<String>,
+    /// Skill the player rolls, if the interaction needs a check
+    #[serde(default)]
+    pub required_skill_id: Option<String>,
+    /// Check difficulty, used when a skill is required
+    #[serde(default)]
+    pub difficulty: Option<u32>,
+    /// Narration on success (or unconditionally when no skill is required)
+    #[serde(default)]
+    pub success_outcome: String,
+    /// Narration on a failed check
+    #[serde(default)]
+    pub failure_outcome: String,
+    #[serde(default = "default_available")]
+    pub is_available: bool,
+}
+
+fn default_available() -> bool {
+    true
+}
+
 /// Location service for managing locations
 ///
 /// This service provides methods for location-related operations
@@ -100,10 +165,23 @@ impl<A: ApiPort> LocationService<A> {
         Self { api }
     }
 
-    /// List all locations in a world
+    /// List the locations in a world, leaving out archived ones
     pub async fn list_locations(&self, world_id: &str) -> Result<Vec<LocationSummary>, ApiError> {
         let path = format!("/api/worlds/{}/locations", world_id);
-        self.api.get(&path).await
+        let locations: Vec<LocationSummary> = self.api.get(&path).await?;
+        Ok(without_archived(without_trashed(locations)))
+    }
+
+    /// List one page of locations in a world, archived ones included
+    pub async fn list_locations_page(
+        &self,
+        world_id: &str,
+        page: &PageRequest,
+    ) -> Result<Page<LocationSummary>, ApiError> {
+        let path = format!("/api/worlds/{}/locations", world_id);
+        let mut page: Page<LocationSummary> = self.api.get(&page.apply_to(&path)).await?;
+        page.items = without_trashed(page.items);
+        Ok(page)
     }
 
     /// Get a single location by ID
@@ -136,7 +214,13 @@ impl<A: ApiPort> LocationService<A> {
         self.api.put(&path, location).await
     }
 
-    /// Delete a location
+    /// Archive a location, or bring it back from the archive
+    pub async fn set_archived(&self, location_id: &str, archived: bool) -> Result<(), ApiError> {
+        let path = format!("/api/locations/{}/archived", location_id);
+        self.api.put_no_response(&path, &archived).await
+    }
+
+    /// Move a location to the world's trash
     pub async fn delete_location(&self, location_id: &str) -> Result<(), ApiError> {
         let path = format!("/api/locations/{}", location_id);
         self.api.delete(&path).await
@@ -163,6 +247,41 @@ impl<A: ApiPort> LocationService<A> {
         let path = format!("/api/locations/{}/regions", location_id);
         self.api.get(&path).await
     }
+
+    /// List the interactions authored for a location
+    pub async fn list_interactions(
+        &self,
+        location_id: &str,
+    ) -> Result<Vec<InteractionTemplateData>, ApiError> {
+        let path = format!("/api/locations/{}/interactions", location_id);
+        self.api.get(&path).await
+    }
+
+    /// Create an interaction in a location
+    pub async fn create_interaction(
+        &self,
+        location_id: &str,
+        interaction: &InteractionTemplateData,
+    ) -> Result<InteractionTemplateData, ApiError> {
+        let path = format!("/api/locations/{}/interactions", location_id);
+        self.api.post(&path, interaction).await
+    }
+
+    /// Update an existing interaction
+    pub async fn update_interaction(
+        &self,
+        interaction_id: &str,
+        interaction: &InteractionTemplateData,
+    ) -> Result<InteractionTemplateData, ApiError> {
+        let path = format!("/api/interactions/{}", interaction_id);
+        self.api.put(&path, interaction).await
+    }
+
+    /// Delete an interaction
+    pub async fn delete_interaction(&self, interaction_id: &str) -> Result<(), ApiError> {
+        let path = format!("/api/interactions/{}", interaction_id);
+        self.api.delete(&path).await
+    }
 }
 
 impl<A: ApiPort + Clone> Clone for LocationService<A> {
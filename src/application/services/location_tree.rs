@@ -0,0 +1,178 @@
+//! Location Tree - Parent/child structure of a world's locations
+//!
+//! Locations nest (region > settlement > building > room) through an
+//! optional parent ID. These helpers walk that structure for the entity
+//! browser tree, the parent picker, and the PC View breadcrumb. Locations
+//! whose parent is missing are treated as roots, and cycles are cut
+//! rather than followed.
+
+use std::collections::HashSet;
+
+use crate::application::dto::world_snapshot::SessionLocationData;
+use crate::application::services::location_service::LocationSummary;
+
+/// A location that may sit inside another one
+pub trait LocationNode {
+    fn node_id(&self) -> &str;
+    fn parent_node_id(&self) -> Option<&str>;
+}
+
+impl LocationNode for LocationSummary {
+    fn node_id(&self) -> &str {
+        &self.id
+    }
+
+    fn parent_node_id(&self) -> Option<&str> {
+        self.parent_location_id.as_deref()
+    }
+}
+
+impl LocationNode for SessionLocationData {
+    fn node_id(&self) -> &str {
+        &self.id
+    }
+
+    fn parent_node_id(&self) -> Option<&str> {
+        self.parent_id.as_deref()
+    }
+}
+
+impl<T: LocationNode> LocationNode for &T {
+    fn node_id(&self) -> &str {
+        (*self).node_id()
+    }
+
+    fn parent_node_id(&self) -> Option<&str> {
+        (*self).parent_node_id()
+    }
+}
+
+/// Locations in depth-first tree order, each with its depth
+///
+/// Siblings keep their original order.
+pub fn tree_order<T: LocationNode>(locations: &[T]) -> Vec<(usize, &T)> {
+    let ids: HashSet<&str> = locations.iter().map(|l| l.node_id()).collect();
+    let mut ordered = Vec::with_capacity(locations.len());
+    let mut visited = HashSet::new();
+
+    fn visit<'a, T: LocationNode>(
+        locations: &'a [T],
+        node: &'a T,
+        depth: usize,
+        visited: &mut HashSet<&'a str>,
+        ordered: &mut Vec<(usize, &'a T)>,
+    ) {
+        if !visited.insert(node.node_id()) {
+            return;
+        }
+        ordered.push((depth, node));
+        for child in locations.iter().filter(|l| l.parent_node_id() == Some(node.node_id())) {
+            visit(locations, child, depth + 1, visited, ordered);
+        }
+    }
+
+    for root in locations
+        .iter()
+        .filter(|l| l.parent_node_id().is_none_or(|parent| !ids.contains(parent)))
+    {
+        visit(locations, root, 0, &mut visited, &mut ordered);
+    }
+    // Anything left is caught in a cycle
+    for node in locations {
+        visit(locations, node, 0, &mut visited, &mut ordered);
+    }
+    ordered
+}
+
+/// IDs of every location nested anywhere under `id`
+pub fn descendant_ids<T: LocationNode>(locations: &[T], id: &str) -> HashSet<String> {
+    let mut found: HashSet<String> = HashSet::new();
+    let mut frontier = vec![id.to_string()];
+    while let Some(parent) = frontier.pop() {
+        for child in locations.iter().filter(|l| l.parent_node_id() == Some(parent.as_str())) {
+            if child.node_id() != id && found.insert(child.node_id().to_string()) {
+                frontier.push(child.node_id().to_string());
+            }
+        }
+    }
+    found
+}
+
+/// The chain of locations from the outermost ancestor down to `id`
+///
+/// Empty when `id` isn't one of `locations`.
+pub fn ancestry<'a, T: LocationNode>(locations: &'a [T], id: &str) -> Vec<&'a T> {
+    let mut chain: Vec<&T> = Vec::new();
+    let mut current = locations.iter().find(|l| l.node_id() == id);
+    while let Some(node) = current {
+        if chain.iter().any(|seen| seen.node_id() == node.node_id()) {
+            break;
+        }
+        chain.push(node);
+        current = node
+            .parent_node_id()
+            .and_then(|parent| locations.iter().find(|l| l.node_id() == parent));
+    }
+    chain.reverse();
+    chain
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::EntityStyle;
+
+    fn location(id: &str, parent: Option<&str>) -> LocationSummary {
+        LocationSummary {
+            id: id.to_string(),
+            name: id.to_string(),
+            location_type: None,
+            parent_location_id: parent.map(str::to_string),
+            tags: Vec::new(),
+            deleted_at: None,
+            archived_at: None,
+            style: EntityStyle::default(),
+        }
+    }
+
+    fn world() -> Vec<LocationSummary> {
+        vec![
+            location("tavern", Some("town")),
+            location("region", None),
+            location("cellar", Some("tavern")),
+            location("town", Some("region")),
+            location("docks", Some("town")),
+        ]
+    }
+
+    #[test]
+    fn tree_order_nests_children_under_parents() {
+        let locations = world();
+        let order: Vec<(usize, &str)> = tree_order(&locations)
+            .into_iter()
+            .map(|(depth, l)| (depth, l.id.as_str()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![(0, "region"), (1, "town"), (2, "tavern"), (3, "cellar"), (2, "docks")]
+        );
+    }
+
+    #[test]
+    fn cycles_are_still_listed_once() {
+        let locations = vec![location("a", Some("b")), location("b", Some("a"))];
+        assert_eq!(tree_order(&locations).len(), 2);
+        assert_eq!(ancestry(&locations, "a").len(), 2);
+    }
+
+    #[test]
+    fn descendants_and_ancestry_follow_the_hierarchy() {
+        let locations = world();
+        let below_town = descendant_ids(&locations, "town");
+        assert_eq!(below_town.len(), 3);
+        assert!(below_town.contains("cellar"));
+
+        let trail: Vec<&str> = ancestry(&locations, "cellar").iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(trail, vec!["region", "town", "tavern", "cellar"]);
+    }
+}
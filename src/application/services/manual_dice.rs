@@ -0,0 +1,167 @@
+//! Manual Dice - Checking physical dice entered by hand
+//!
+//! A player rolling real dice enters each die's face rather than a total,
+//! so the entry can be checked against the dice the challenge calls for. A
+//! face the die doesn't have is rejected outright; an entry that is
+//! possible but very unlikely is flagged for the DM instead.
+
+use std::fmt;
+
+use crate::application::dto::{DiceNotation, ManualDiceEntry};
+
+/// Entries less likely than this (1 in 100) are flagged
+const IMPROBABLE_ODDS: f64 = 100.0;
+
+/// Why a single die's entry can't be used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DieEntryError {
+    Missing,
+    NotANumber,
+    /// Outside 1..=sides
+    Impossible { sides: u8 },
+}
+
+impl fmt::Display for DieEntryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing => write!(f, "enter a result"),
+            Self::NotANumber => write!(f, "enter a whole number"),
+            Self::Impossible { sides } => write!(f, "a d{} shows 1 to {}", sides, sides),
+        }
+    }
+}
+
+/// Check one die's entry against the number of sides
+pub fn check_die(entry: &str, sides: u8) -> Result<i32, DieEntryError> {
+    let entry = entry.trim();
+    if entry.is_empty() {
+        return Err(DieEntryError::Missing);
+    }
+    let face: i32 = entry.parse().map_err(|_| DieEntryError::NotANumber)?;
+    if !(1..=sides as i32).contains(&face) {
+        return Err(DieEntryError::Impossible { sides });
+    }
+    Ok(face)
+}
+
+/// Check every die of a roll, flagging unlikely results
+///
+/// `entries` holds one string per die, in the order the inputs show them.
+pub fn check_manual_dice(notation: &DiceNotation, entries: &[String]) -> Result<ManualDiceEntry, String> {
+    if entries.len() != notation.count as usize {
+        return Err(format!("Enter all {} dice", notation.count));
+    }
+    let dice = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| check_die(entry, notation.sides).map_err(|e| format!("Die {}: {}", i + 1, e)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let flags = improbability_flags(notation, &dice);
+    Ok(ManualDiceEntry {
+        formula: notation.to_string(),
+        dice,
+        flags,
+    })
+}
+
+/// Flags for an entry as it reached the DM, worked out again from its dice
+///
+/// The player's client computes `flags` for the player, but anything it
+/// sends can be edited, so the DM's side never takes them on trust. Dice
+/// the formula can't produce are flagged as well, should the Engine let
+/// them through.
+pub fn recheck_manual_dice(entry: &ManualDiceEntry) -> Vec<String> {
+    let Some(notation) = DiceNotation::parse(&entry.formula) else {
+        return vec![format!("Unrecognised dice \"{}\"", entry.formula)];
+    };
+    if entry.dice.len() != notation.count as usize {
+        return vec![format!("{} dice entered for {}", entry.dice.len(), notation)];
+    }
+    if entry.dice.iter().any(|&d| !(1..=notation.sides as i32).contains(&d)) {
+        return vec![format!("A die shows a face a d{} doesn't have", notation.sides)];
+    }
+    improbability_flags(&notation, &entry.dice)
+}
+
+/// Notes on results that are possible but rare enough to be worth a look
+fn improbability_flags(notation: &DiceNotation, dice: &[i32]) -> Vec<String> {
+    let sides = notation.sides as i32;
+    let odds_all_same = (sides as f64).powi(dice.len() as i32);
+    let mut flags = Vec::new();
+    if dice.iter().all(|&d| d == sides) && odds_all_same > IMPROBABLE_ODDS {
+        flags.push(format!("Every die shows its highest face ({})", odds_text(odds_all_same)));
+    } else if dice.len() >= 3 && dice.windows(2).all(|w| w[0] == w[1]) {
+        // Any matching set, not just this face
+        let odds = odds_all_same / sides as f64;
+        if odds > IMPROBABLE_ODDS {
+            flags.push(format!("Every die shows the same face ({})", odds_text(odds)));
+        }
+    }
+    flags
+}
+
+fn odds_text(odds: f64) -> String {
+    if odds > 1_000_000.0 {
+        "less than 1 in a million".to_string()
+    } else {
+        format!("1 in {:.0}", odds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn each_die_must_be_a_face_it_has() {
+        assert_eq!(check_die(" 6 ", 6), Ok(6));
+        assert_eq!(check_die("", 6), Err(DieEntryError::Missing));
+        assert_eq!(check_die("six", 6), Err(DieEntryError::NotANumber));
+        assert_eq!(check_die("7", 6), Err(DieEntryError::Impossible { sides: 6 }));
+        assert_eq!(check_die("0", 20), Err(DieEntryError::Impossible { sides: 20 }));
+
+        let two_d6 = DiceNotation::parse("2d6+1").unwrap();
+        assert!(check_manual_dice(&two_d6, &entries(&["4"])).is_err());
+        assert_eq!(check_manual_dice(&two_d6, &entries(&["4", "7"])).unwrap_err(), "Die 2: a d6 shows 1 to 6");
+
+        let entry = check_manual_dice(&two_d6, &entries(&["4", "5"])).unwrap();
+        assert_eq!(entry.dice, vec![4, 5]);
+        assert_eq!(entry.dice_total(), 9);
+        assert!(entry.flags.is_empty());
+    }
+
+    #[test]
+    fn rare_results_are_flagged_not_rejected() {
+        // A natural 20 is common enough to pass without comment
+        let d20 = DiceNotation::parse("1d20").unwrap();
+        assert!(check_manual_dice(&d20, &entries(&["20"])).unwrap().flags.is_empty());
+
+        let three_d6 = DiceNotation::parse("3d6").unwrap();
+        let maxed = check_manual_dice(&three_d6, &entries(&["6", "6", "6"])).unwrap();
+        assert_eq!(maxed.flags, vec!["Every die shows its highest face (1 in 216)".to_string()]);
+
+        // Three of a kind on 3d6 is 1 in 36; four on 4d6 is rarer
+        assert!(check_manual_dice(&three_d6, &entries(&["2", "2", "2"])).unwrap().flags.is_empty());
+        let four_d6 = DiceNotation::parse("4d6").unwrap();
+        let matching = check_manual_dice(&four_d6, &entries(&["2", "2", "2", "2"])).unwrap();
+        assert_eq!(matching.flags, vec!["Every die shows the same face (1 in 216)".to_string()]);
+    }
+
+    #[test]
+    fn flags_are_worked_out_again_whatever_was_sent() {
+        let stripped = ManualDiceEntry { formula: "3d6".to_string(), dice: vec![6, 6, 6], flags: Vec::new() };
+        assert_eq!(recheck_manual_dice(&stripped), vec!["Every die shows its highest face (1 in 216)".to_string()]);
+
+        let plain = ManualDiceEntry { formula: "2d6+1".to_string(), dice: vec![4, 5], flags: vec!["stale".to_string()] };
+        assert!(recheck_manual_dice(&plain).is_empty());
+
+        let impossible = ManualDiceEntry { formula: "1d20".to_string(), dice: vec![25], flags: Vec::new() };
+        assert_eq!(recheck_manual_dice(&impossible).len(), 1);
+        let short = ManualDiceEntry { formula: "2d6".to_string(), dice: vec![6], flags: Vec::new() };
+        assert_eq!(recheck_manual_dice(&short).len(), 1);
+    }
+}
@@ -4,38 +4,106 @@
 //! for the WrldBldr Player. Services depend on port traits, not concrete
 //! infrastructure implementations.
 
+pub mod accessibility;
+pub mod action_inbox;
+pub mod action_queue;
 pub mod action_service;
+pub mod approval_audit;
+pub mod archive;
 pub mod asset_service;
+pub mod asset_storage;
+pub mod asset_upload;
+pub mod auth_service;
+pub mod backdrop_media;
+pub mod bug_report_service;
+pub mod campaign_service;
+pub mod challenge_auto_resolve;
+pub mod challenge_catalog;
+pub mod challenge_context;
 pub mod challenge_service;
 pub mod character_service;
+pub mod choice_requirements;
+pub mod conditions;
+pub mod conversation_index;
+pub mod crafting_service;
+pub mod dice_stats;
+pub mod directorial_presets;
+pub mod encounter_service;
+pub mod engine_health;
+pub mod entity_history_service;
+pub mod equipment;
+pub mod faction_service;
+pub mod filter_presets;
+pub mod form_validation;
 pub mod generation_service;
 pub mod location_service;
+pub mod location_tree;
+pub mod manual_dice;
 pub mod narrative_event_service;
+pub mod note_sync;
+pub mod notes_service;
 pub mod observation_service;
+pub mod onboarding;
+pub mod outline_import;
 pub mod player_character_service;
+pub mod relationship_service;
+pub mod roll_history;
+pub mod roll_macros;
+pub mod rules_reference;
+pub mod scene_staging;
 pub mod session_service;
 pub mod session_command_service;
 pub mod settings_service;
+pub mod share_text;
+pub mod sheet_export;
+pub mod skill_import;
 pub mod skill_service;
+pub mod snapshot_diff;
+pub mod story_capture;
+pub mod stream_layout;
 pub mod story_event_service;
 pub mod suggestion_service;
+pub mod template_vars;
+pub mod trash_service;
+pub mod tutorial_world;
+pub mod workflow_presets;
 pub mod workflow_service;
 pub mod world_service;
 pub mod event_chain_service;
 
 // Re-export action service
+pub use action_inbox::{next_inbox_batch, PostedActions};
+pub use action_queue::ActionQueue;
 pub use action_service::ActionService;
 
 // Re-export session command service
 pub use session_command_service::SessionCommandService;
 
 // Re-export session service types
-pub use session_service::{port_connection_state_to_status, ParticipantRolePort, DEFAULT_ENGINE_URL};
+pub use session_service::{port_connection_state_to_status, ParticipantRolePort, DEFAULT_ENGINE_URL, DEMO_ENGINE_URL, DEMO_WORLD_ID};
 
 pub use session_service::{SessionEvent, SessionService};
 
+// Re-export auth service types
+pub use auth_service::{
+    clear_auth_session, persist_auth_session, restore_auth_session, AuthIdentity, AuthService,
+    LoginCredentials,
+};
+
+// Re-export bug report service types
+pub use bug_report_service::{
+    bug_report_endpoint, BugReportService, ConnectionSnapshot, DiagnosticBundle,
+    ScreenshotAttachment,
+};
+
 // Re-export world service types
 pub use world_service::WorldService;
+pub use tutorial_world::{TutorialProgress, TutorialWorldService};
+
+// Re-export campaign service types
+pub use campaign_service::{
+    Campaign, CampaignEvent, CampaignService, MigratePcRequest, SaveCampaignRequest,
+};
 
 // Re-export character service types
 pub use character_service::{CharacterFormData, CharacterService, CharacterSheetDataApi, CharacterSummary};
@@ -46,36 +114,169 @@ pub use player_character_service::{
 };
 
 // Re-export location service types
-pub use location_service::{LocationFormData, LocationService, LocationSummary, MapBoundsData, RegionData};
+pub use location_service::{InteractionTemplateData, LocationFormData, LocationService, LocationSummary, RegionData};
+pub use location_tree::{ancestry, descendant_ids, tree_order};
 
 // Re-export skill service types
-pub use skill_service::{CreateSkillRequest, SkillService, UpdateSkillRequest};
+pub use skill_service::{plan_skill_reorder, CreateSkillRequest, SkillService, UpdateSkillRequest};
+pub use skill_import::{
+    map_category, parse_skill_json, plan_import, unmapped_categories, ConflictResolution,
+    SkillImportAction, SkillPreset,
+};
+pub use outline_import::{outline_image_prompt, parse_outline};
 // Re-export SkillData and SkillCategory from dto (not skill_service)
 pub use crate::application::dto::{SkillCategory, SkillData};
 
 // Re-export challenge service types
 pub use challenge_service::ChallengeService;
+pub use challenge_catalog::{
+    copy_catalog_challenge, match_catalog_skill, CatalogChallenge, ChallengeCatalog,
+};
+pub use challenge_auto_resolve::auto_apply_margin;
+pub use challenge_context::{draft_challenge, ChallengeDraft};
+pub use choice_requirements::{choice_availability, ChoiceAvailability, ChoiceContext};
+pub use manual_dice::{check_die, check_manual_dice, recheck_manual_dice};
+pub use dice_stats::{DiceGroupStats, DiceSkew, SessionDiceLog, SessionRoll};
+pub use directorial_presets::{directorial_context, guidelines_text, parse_guidelines, validate_presets};
 
 // Re-export story event service types
 pub use story_event_service::{
-    CreateDmMarkerRequest, StoryEventService,
+    CreateDmMarkerRequest, CreateStoryEventRequest, StoryEventService,
+};
+pub use story_capture::{CapturedChallenge, StandingChange, StoryCapture};
+pub use conversation_index::{ConversationIndex, IndexedLine, IndexedRoll, SourceDetail};
+
+// Re-export engine health types
+pub use engine_health::{
+    is_llm_error, EngineHealth, ServiceHealth,
+};
+
+// Re-export condition helpers
+pub use conditions::{condition_icon, condition_summary, CONDITION_PRESETS};
+
+// Re-export equipment rules
+pub use equipment::{
+    apply_equip_change, plan_equip_toggle, sheet_values_with_equipment, slot_for_item,
 };
 
+// Re-export filter preset helpers
+pub use filter_presets::{
+    load_filter_presets, save_filter_presets, upsert_preset, FilterPreset, FilterPresetScope,
+};
+
+// Re-export form validation types
+pub use form_validation::{FieldRule, FormRules, FormValues, ValidationErrors};
+
+// Re-export approval audit log types
+pub use approval_audit::{
+    append_approval_audit, load_approval_audit, outcome_counts, ApprovalAuditEntry, AuditFilter,
+    AuditOutcome,
+};
+
+// Re-export template variable resolver
+pub use template_vars::TemplateContext;
+
+// Re-export roll history types
+pub use roll_history::{skill_stats, RollHistory, RollRecord};
+
+// Re-export roll macro types
+pub use roll_macros::{
+    load_roll_macros, parse_macro_formula, save_roll_macros, upsert_macro, MacroRoll, RollMacro,
+};
+
+// Re-export shared note sync types
+pub use note_sync::{
+    attribution, edit_position, line_at, merge_preview, MergeSide, SharedNote, NOTE_SEND_DELAY_MS,
+};
+
+// Re-export rules reference types
+pub use rules_reference::{
+    build_reference, load_house_rules, save_house_rules, search_reference, upsert_house_rule, HouseRule,
+    RuleCategory,
+};
+
+// Re-export scene staging types
+pub use scene_staging::{message_scene_id, SceneStaging};
+
+// Re-export spectator stream layout types
+pub use stream_layout::{StreamLayout, StreamSettings, CHROMA_PRESETS};
+
+// Re-export snapshot diff types
+pub use snapshot_diff::{
+    diff_snapshots, SnapshotChange, SnapshotChangeKind, SnapshotDiff, SnapshotEntityKind,
+};
+
+// Re-export entity history types
+pub use entity_history_service::{
+    diff_versions, display_value, EntityHistoryService, EntityVersion, HistoryEntityKind,
+};
+
+// Re-export crafting service types
+pub use crafting_service::{CraftingService, SaveRecipeRequest};
+
+// Re-export encounter service types
+pub use encounter_service::{Encounter, EncounterService, SaveEncounterRequest};
+
+// Re-export faction service types
+pub use faction_service::{AdjustStandingRequest, FactionService, SaveFactionRequest};
+
+// Re-export relationship service types
+pub use relationship_service::{RelationshipService, SaveRelationshipRequest};
+
+// Re-export clipboard/share text helpers
+pub use share_text::{challenge_stat_block, deep_link, entity_summary, story_event_text};
+
+// Re-export character sheet export
+pub use sheet_export::SheetExport;
+
 // Re-export narrative event service types
 pub use narrative_event_service::NarrativeEventService;
 
+// Re-export notes service types
+pub use notes_service::{
+    load_local_notes, merge_note, note_backlinks, save_local_notes, search_notes, NotePage,
+    NotesService, SaveNoteRequest, LOCAL_NOTE_PREFIX,
+};
+
 // Re-export workflow service types
 pub use workflow_service::{
     AnalyzeWorkflowResponse, InputDefault, PromptMapping, WorkflowAnalysis, WorkflowConfig,
     WorkflowInput, WorkflowService, WorkflowSlotCategory,
     WorkflowSlotStatus, TestWorkflowResponse,
 };
+pub use workflow_presets::{
+    load_workflow_presets, save_workflow_presets, upsert_workflow_preset, WorkflowPreset,
+};
 
 // Re-export asset service types
-pub use asset_service::{Asset, AssetService, GenerateRequest};
+pub use asset_service::{Asset, AssetService, GenerateRequest, DEFAULT_DENOISE};
+pub use asset_storage::{
+    cleanup_candidates, format_bytes, usage_by, AssetStorageReport, CleanupFilter,
+    QuotaLevel,
+};
+pub use asset_upload::{upload_mime_type, validate_upload, UploadAssetRequest};
+pub use backdrop_media::{
+    choose_animated_backdrop, load_backdrop_preferences, save_backdrop_preferences, BackdropMediaKind,
+    BackdropPreferences,
+};
+
+pub use accessibility::{
+    load_accessibility_preferences, save_accessibility_preferences, AccessibilityPreferences,
+};
+
+pub use onboarding::{load_tour_progress, save_tour_progress, tour_steps, TourProgress, TourView};
+
+// Re-export archive types
+pub use archive::{archived_stamp, Archivable, ArchiveFilter};
+
+// Re-export trash service types
+pub use trash_service::{
+    days_until_purge, strip_trashed, TrashEntityKind, TrashListing,
+    TrashService, TrashedEntity,
+};
 
 // Re-export suggestion service types
-pub use suggestion_service::{SuggestionContext, SuggestionService};
+pub use suggestion_service::{SuggestionContext, SuggestionFeedback, SuggestionRating, SuggestionService};
 
 // Re-export event chain service types
 pub use event_chain_service::{
@@ -6,6 +6,8 @@
 
 use crate::application::dto::{CreateNarrativeEventRequest, NarrativeEventData};
 use crate::application::ports::outbound::{ApiError, ApiPort};
+use crate::application::services::archive::without_archived;
+use crate::application::services::trash_service::without_trashed;
 
 /// Narrative event service for managing narrative events
 ///
@@ -22,13 +24,22 @@ impl<A: ApiPort> NarrativeEventService<A> {
         Self { api }
     }
 
-    /// List all narrative events for a world
+    /// List the narrative events for a world, leaving out archived ones
     pub async fn list_narrative_events(
         &self,
         world_id: &str,
+    ) -> Result<Vec<NarrativeEventData>, ApiError> {
+        Ok(without_archived(self.list_all_narrative_events(world_id).await?))
+    }
+
+    /// List every narrative event for a world, archived ones included
+    pub async fn list_all_narrative_events(
+        &self,
+        world_id: &str,
     ) -> Result<Vec<NarrativeEventData>, ApiError> {
         let path = format!("/api/worlds/{}/narrative-events", world_id);
-        self.api.get(&path).await
+        let events: Vec<NarrativeEventData> = self.api.get(&path).await?;
+        Ok(without_trashed(events))
     }
 
     /// List pending (active but not triggered) narrative events
@@ -40,7 +51,7 @@ impl<A: ApiPort> NarrativeEventService<A> {
 
         // Try pending endpoint first
         match self.api.get::<Vec<NarrativeEventData>>(&pending_path).await {
-            Ok(events) => Ok(events),
+            Ok(events) => Ok(without_archived(without_trashed(events))),
             Err(_) => {
                 // Fall back to fetching all and filtering client-side
                 let all: Vec<NarrativeEventData> = self.list_narrative_events(world_id).await?;
@@ -64,6 +75,12 @@ impl<A: ApiPort> NarrativeEventService<A> {
         self.api.put_no_response(&path, &active).await
     }
 
+    /// Archive a narrative event, or bring it back from the archive
+    pub async fn set_archived(&self, event_id: &str, archived: bool) -> Result<(), ApiError> {
+        let path = format!("/api/narrative-events/{}/archived", event_id);
+        self.api.put_no_response(&path, &archived).await
+    }
+
     /// Create a new narrative event
     pub async fn create_narrative_event(
         &self,
@@ -73,6 +90,12 @@ impl<A: ApiPort> NarrativeEventService<A> {
         let path = format!("/api/worlds/{}/narrative-events", world_id);
         self.api.post(&path, &request).await
     }
+
+    /// Move a narrative event to the world's trash
+    pub async fn delete_narrative_event(&self, event_id: &str) -> Result<(), ApiError> {
+        let path = format!("/api/narrative-events/{}", event_id);
+        self.api.delete(&path).await
+    }
 }
 
 impl<A: ApiPort + Clone> Clone for NarrativeEventService<A> {
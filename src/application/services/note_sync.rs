@@ -0,0 +1,235 @@
+//! Note Sync - The local side of shared directorial notes
+//!
+//! A [`SharedNote`] pairs the Engine's latest revision with the text in this
+//! editor. Local edits are sent after a short pause in typing; the Engine
+//! keeps the last write. When someone else's revision lands while this
+//! editor still has unsent changes, it is held as a conflict instead of
+//! overwriting them, and the DM picks mine, theirs, or both from a line
+//! [`merge_preview`].
+
+use crate::application::dto::NoteRevision;
+
+/// Pause in typing before an edit is sent, in milliseconds
+pub const NOTE_SEND_DELAY_MS: u64 = 600;
+
+/// One shared note as this editor sees it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SharedNote {
+    /// Latest revision from the Engine
+    pub synced: NoteRevision,
+    /// Text in the editor, possibly ahead of `synced`
+    pub local: String,
+    /// Someone else's revision that arrived over unsent local changes
+    pub conflict: Option<NoteRevision>,
+}
+
+impl SharedNote {
+    pub fn new(synced: NoteRevision) -> Self {
+        Self {
+            local: synced.text.clone(),
+            synced,
+            conflict: None,
+        }
+    }
+
+    /// Whether the editor has text the Engine hasn't got
+    pub fn has_local_changes(&self) -> bool {
+        self.local != self.synced.text
+    }
+
+    /// Whether local changes should be sent now (not while a conflict waits)
+    pub fn needs_send(&self) -> bool {
+        self.has_local_changes() && self.conflict.is_none()
+    }
+
+    /// A revision from the Engine
+    ///
+    /// Our own echoes and edits over an unchanged editor are taken as they
+    /// are. Someone else's edit over unsent local changes becomes a conflict.
+    /// Revisions older than the one we have are ignored.
+    pub fn apply_remote(&mut self, remote: NoteRevision, own_user_id: Option<&str>) {
+        if remote.revision < self.synced.revision {
+            return;
+        }
+        let own = own_user_id.is_some() && remote.editor_id.as_deref() == own_user_id;
+        if !self.has_local_changes() || remote.text == self.local {
+            self.local = remote.text.clone();
+            self.conflict = None;
+        } else if !own {
+            self.conflict = Some(remote.clone());
+        }
+        // Our own echo while we kept typing: the newer local text goes next
+        self.synced = remote;
+    }
+
+    /// Resolve a conflict with the local text, which overwrites theirs
+    pub fn keep_mine(&mut self) {
+        self.conflict = None;
+    }
+
+    /// Resolve a conflict by dropping local changes
+    pub fn take_theirs(&mut self) {
+        self.local = self.synced.text.clone();
+        self.conflict = None;
+    }
+
+    /// Resolve a conflict with every line from both sides
+    pub fn merge_both(&mut self) {
+        if let Some(theirs) = self.conflict.take() {
+            self.local = merged_text(&merge_preview(&self.local, &theirs.text));
+        }
+    }
+}
+
+/// Where a line of a merge preview comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeSide {
+    Both,
+    Mine,
+    Theirs,
+}
+
+/// One line of a merge preview
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeLine {
+    pub side: MergeSide,
+    pub text: String,
+}
+
+/// Line-by-line comparison of two versions of a note
+///
+/// Lines common to both keep their order; where the versions differ, their
+/// lines are listed mine first, then theirs.
+pub fn merge_preview(mine: &str, theirs: &str) -> Vec<MergeLine> {
+    let a: Vec<&str> = mine.lines().collect();
+    let b: Vec<&str> = theirs.lines().collect();
+
+    // Longest common subsequence table, filled from the end
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+        }
+    }
+
+    let line = |side, text: &str| MergeLine { side, text: text.to_string() };
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            lines.push(line(MergeSide::Both, a[i]));
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(line(MergeSide::Mine, a[i]));
+            i += 1;
+        } else {
+            lines.push(line(MergeSide::Theirs, b[j]));
+            j += 1;
+        }
+    }
+    lines
+}
+
+/// The text of a merge preview with every line kept
+pub fn merged_text(lines: &[MergeLine]) -> String {
+    lines.iter().map(|l| l.text.as_str()).collect::<Vec<_>>().join("\n")
+}
+
+/// Character offset of an edit: where `new` first differs from `old`
+pub fn edit_position(old: &str, new: &str) -> usize {
+    old.chars().zip(new.chars()).take_while(|(a, b)| a == b).count()
+}
+
+/// 1-based line number of a character offset
+pub fn line_at(text: &str, position: usize) -> usize {
+    text.chars().take(position).filter(|c| *c == '\n').count() + 1
+}
+
+/// "Edited by Sam at 14:32", for the revision's author line
+pub fn attribution(revision: &NoteRevision) -> Option<String> {
+    let name = revision.editor_name.as_deref()?;
+    let time = revision
+        .edited_at
+        .as_deref()
+        .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+        .map(|t| format!(" at {}", t.format("%H:%M")))
+        .unwrap_or_default();
+    Some(format!("Edited by {}{}", name, time))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn revision(text: &str, revision: u64, editor: &str) -> NoteRevision {
+        NoteRevision {
+            text: text.to_string(),
+            revision,
+            editor_id: Some(editor.to_string()),
+            editor_name: Some(editor.to_uppercase()),
+            edited_at: None,
+        }
+    }
+
+    #[test]
+    fn remote_edits_replace_an_unchanged_editor() {
+        let mut note = SharedNote::new(revision("one", 1, "dm"));
+        note.apply_remote(revision("one\ntwo", 2, "codm"), Some("dm"));
+        assert_eq!(note.local, "one\ntwo");
+        assert!(note.conflict.is_none());
+
+        // Stale revisions are ignored
+        note.apply_remote(revision("old", 1, "codm"), Some("dm"));
+        assert_eq!(note.local, "one\ntwo");
+    }
+
+    #[test]
+    fn own_echo_keeps_text_typed_since_the_send() {
+        let mut note = SharedNote::new(revision("a", 1, "dm"));
+        note.local = "ab".to_string();
+        note.apply_remote(revision("ab", 2, "dm"), Some("dm"));
+        assert!(!note.has_local_changes());
+
+        note.local = "abcd".to_string();
+        note.apply_remote(revision("abc", 3, "dm"), Some("dm"));
+        assert_eq!(note.local, "abcd");
+        assert_eq!(note.synced.revision, 3);
+        assert!(note.needs_send());
+    }
+
+    #[test]
+    fn others_edits_over_unsent_changes_wait_as_a_conflict() {
+        let mut note = SharedNote::new(revision("guard\ngate", 1, "dm"));
+        note.local = "guard\ngate\nmine".to_string();
+        note.apply_remote(revision("guard\ntheirs\ngate", 2, "codm"), Some("dm"));
+        assert!(note.conflict.is_some());
+        assert!(!note.needs_send());
+        assert_eq!(note.local, "guard\ngate\nmine");
+
+        let mut merged = note.clone();
+        merged.merge_both();
+        assert_eq!(merged.local, "guard\ntheirs\ngate\nmine");
+        assert!(merged.needs_send());
+
+        let mut theirs = note.clone();
+        theirs.take_theirs();
+        assert!(!theirs.has_local_changes());
+
+        note.keep_mine();
+        assert!(note.needs_send());
+    }
+
+    #[test]
+    fn merge_preview_marks_each_side() {
+        let sides: Vec<MergeSide> = merge_preview("a\nb\nc", "a\nx\nc").into_iter().map(|l| l.side).collect();
+        assert_eq!(sides, vec![MergeSide::Both, MergeSide::Mine, MergeSide::Theirs, MergeSide::Both]);
+    }
+
+    #[test]
+    fn cursor_helpers_find_the_edit_and_its_line() {
+        assert_eq!(edit_position("hello\nworld", "hello\nwXorld"), 7);
+        assert_eq!(line_at("hello\nworld", 7), 2);
+        assert_eq!(attribution(&revision("x", 1, "sam")).as_deref(), Some("Edited by SAM"));
+    }
+}
@@ -0,0 +1,254 @@
+//! Notes Service - DM notes wiki pages scoped to a world
+//!
+//! Pages are rich text (see `application::dto::rich_text`), so they can
+//! mention characters, locations and events, and link to each other with
+//! `@[Title](note:id)`. Pages are stored on the Engine; when it can't be
+//! reached the wiki keeps working from a copy saved on this device.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::{description_plain_text, EntityMention, MentionKind, RichText};
+use crate::application::ports::outbound::{storage_keys, ApiError, ApiPort, Platform};
+
+/// ID prefix for pages created while the Engine couldn't be reached
+pub const LOCAL_NOTE_PREFIX: &str = "local-";
+
+/// A wiki page
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NotePage {
+    pub id: String,
+    pub world_id: String,
+    pub title: String,
+    /// Rich text markup
+    #[serde(default)]
+    pub body: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+impl NotePage {
+    /// Mention markup linking to this page
+    pub fn mention(&self) -> EntityMention {
+        EntityMention {
+            kind: MentionKind::Note,
+            id: self.id.clone(),
+            label: self.title.clone(),
+        }
+    }
+
+    /// Whether this page was created on this device and the Engine hasn't seen it
+    pub fn is_local_only(&self) -> bool {
+        self.id.starts_with(LOCAL_NOTE_PREFIX)
+    }
+
+    /// Whether this page has changes saved only on this device
+    ///
+    /// Pages from the Engine carry `updated_at`; pages saved offline don't.
+    pub fn is_unsynced(&self) -> bool {
+        self.updated_at.is_empty()
+    }
+
+    /// Request that saves this page's content
+    pub fn save_request(&self) -> SaveNoteRequest {
+        SaveNoteRequest {
+            title: self.title.clone(),
+            body: self.body.clone(),
+            tags: self.tags.clone(),
+        }
+    }
+}
+
+/// Request to create or update a page
+#[derive(Clone, Debug, Serialize)]
+pub struct SaveNoteRequest {
+    pub title: String,
+    pub body: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+/// Notes service for the DM wiki
+///
+/// This service provides CRUD for wiki pages while depending only on the
+/// `ApiPort` trait, not concrete infrastructure implementations.
+pub struct NotesService<A: ApiPort> {
+    api: A,
+}
+
+impl<A: ApiPort> NotesService<A> {
+    /// Create a new NotesService with the given API port
+    pub fn new(api: A) -> Self {
+        Self { api }
+    }
+
+    /// List all pages in a world
+    pub async fn list_notes(&self, world_id: &str) -> Result<Vec<NotePage>, ApiError> {
+        let path = format!("/api/worlds/{}/notes", world_id);
+        self.api.get(&path).await
+    }
+
+    /// Create a page
+    pub async fn create_note(&self, world_id: &str, request: &SaveNoteRequest) -> Result<NotePage, ApiError> {
+        let path = format!("/api/worlds/{}/notes", world_id);
+        self.api.post(&path, request).await
+    }
+
+    /// Update a page
+    pub async fn update_note(&self, note_id: &str, request: &SaveNoteRequest) -> Result<NotePage, ApiError> {
+        let path = format!("/api/notes/{}", note_id);
+        self.api.put(&path, request).await
+    }
+
+    /// Delete a page
+    pub async fn delete_note(&self, note_id: &str) -> Result<(), ApiError> {
+        let path = format!("/api/notes/{}", note_id);
+        self.api.delete(&path).await
+    }
+
+    /// Push pages saved on this device while offline and merge them into
+    /// the Engine's `remote` pages
+    ///
+    /// Pages created offline are created on the Engine and take its ID.
+    /// A page that fails to push stays in the result unsynced, so it's kept
+    /// on this device and tried again next time.
+    pub async fn sync_local_notes(&self, world_id: &str, remote: Vec<NotePage>, local: &[NotePage]) -> Vec<NotePage> {
+        let mut merged = remote;
+        for page in local.iter().filter(|p| p.is_unsynced()) {
+            let request = page.save_request();
+            let result = if page.is_local_only() {
+                self.create_note(world_id, &request).await
+            } else {
+                self.update_note(&page.id, &request).await
+            };
+            match result {
+                Ok(saved) => {
+                    merged.retain(|p| p.id != page.id);
+                    merge_note(&mut merged, saved);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to sync note {}: {}", page.id, e);
+                    merge_note(&mut merged, page.clone());
+                }
+            }
+        }
+        merged
+    }
+}
+
+/// Replace the page with `page`'s ID, or add it
+pub fn merge_note(pages: &mut Vec<NotePage>, page: NotePage) {
+    match pages.iter_mut().find(|p| p.id == page.id) {
+        Some(slot) => *slot = page,
+        None => pages.push(page),
+    }
+}
+
+fn local_notes_key(world_id: &str) -> String {
+    format!("{}{}", storage_keys::NOTES_PREFIX, world_id)
+}
+
+/// Pages saved on this device for a world
+pub fn load_local_notes(platform: &Platform, world_id: &str) -> Vec<NotePage> {
+    platform
+        .storage_load(&local_notes_key(world_id))
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Save a world's pages on this device
+pub fn save_local_notes(platform: &Platform, world_id: &str, pages: &[NotePage]) {
+    match serde_json::to_string(pages) {
+        Ok(json) => platform.storage_save(&local_notes_key(world_id), &json),
+        Err(e) => platform.log_error(&format!("Failed to serialize notes: {}", e)),
+    }
+}
+
+/// Pages matching `query` in their title, tags or text
+///
+/// Title matches come first; an empty query matches every page. Within
+/// each group pages are sorted by title.
+pub fn search_notes<'a>(pages: &'a [NotePage], query: &str) -> Vec<&'a NotePage> {
+    let query = query.trim().to_lowercase();
+    let mut hits: Vec<(bool, &NotePage)> = pages
+        .iter()
+        .filter_map(|page| {
+            if query.is_empty() {
+                return Some((true, page));
+            }
+            let in_title = page.title.to_lowercase().contains(&query);
+            let in_rest = in_title
+                || page.tags.iter().any(|t| t.to_lowercase().contains(&query))
+                || description_plain_text(&page.body).to_lowercase().contains(&query);
+            in_rest.then_some((in_title, page))
+        })
+        .collect();
+    hits.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.title.to_lowercase().cmp(&b.1.title.to_lowercase())));
+    hits.into_iter().map(|(_, page)| page).collect()
+}
+
+/// Pages that link to the page `note_id`
+pub fn note_backlinks<'a>(pages: &'a [NotePage], note_id: &str) -> Vec<&'a NotePage> {
+    pages
+        .iter()
+        .filter(|page| page.id != note_id)
+        .filter(|page| {
+            RichText::parse(&page.body)
+                .mentions()
+                .iter()
+                .any(|m| m.kind == MentionKind::Note && m.id == note_id)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(id: &str, title: &str, body: &str) -> NotePage {
+        NotePage {
+            id: id.to_string(),
+            world_id: "w-1".to_string(),
+            title: title.to_string(),
+            body: body.to_string(),
+            tags: Vec::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn search_puts_title_matches_first() {
+        let pages = vec![
+            page("n-1", "Harbor Gossip", "The **smugglers** meet at dawn."),
+            page("n-2", "Smugglers", "A ring run out of the docks."),
+            page("n-3", "Weather", "Fog most mornings."),
+        ];
+        let titles: Vec<&str> = search_notes(&pages, "smuggler").iter().map(|p| p.title.as_str()).collect();
+        assert_eq!(titles, vec!["Smugglers", "Harbor Gossip"]);
+        assert_eq!(search_notes(&pages, "  ").len(), 3);
+    }
+
+    #[test]
+    fn backlinks_follow_note_mentions() {
+        let target = page("n-1", "Smugglers", "");
+        let pages = vec![
+            target.clone(),
+            page("n-2", "Docks", &format!("Run by {}.", target.mention().to_markup())),
+            page("n-3", "Mira", "Knows @[Mira](character:n-1)."),
+        ];
+        let linking: Vec<&str> = note_backlinks(&pages, "n-1").iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(linking, vec!["n-2"]);
+    }
+
+    #[test]
+    fn merging_replaces_by_id_or_appends() {
+        let mut pages = vec![page("n-1", "Smugglers", ""), page("n-2", "Docks", "")];
+        merge_note(&mut pages, page("n-2", "Harbor", ""));
+        merge_note(&mut pages, page("local-1", "Weather", ""));
+        let titles: Vec<&str> = pages.iter().map(|p| p.title.as_str()).collect();
+        assert_eq!(titles, vec!["Smugglers", "Harbor", "Weather"]);
+        assert!(pages[2].is_local_only());
+        assert!(pages[2].is_unsynced());
+    }
+}
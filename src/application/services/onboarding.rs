@@ -0,0 +1,188 @@
+//! Onboarding - Guided first-run tours of the DM View, Creator Mode and PC View
+//!
+//! Each view has a short list of steps, each pointing at a control marked
+//! with a `data-tour` attribute. Which tours a player has finished (or
+//! skipped) is kept on the device, like the other display preferences, and
+//! App Settings can start any of them again.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::outbound::{storage_keys, Platform};
+
+/// A view that has its own tour
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TourView {
+    Director,
+    Creator,
+    Pc,
+}
+
+impl TourView {
+    pub fn all() -> [Self; 3] {
+        [Self::Director, Self::Creator, Self::Pc]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Director => "DM View",
+            Self::Creator => "Creator Mode",
+            Self::Pc => "PC View",
+        }
+    }
+}
+
+/// One stop on a tour
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TourStep {
+    /// `data-tour` value of the control to spotlight; when it isn't on
+    /// screen (e.g. a collapsed panel on a phone) the step shows centred
+    pub anchor: &'static str,
+    pub title: &'static str,
+    pub body: &'static str,
+}
+
+const DIRECTOR_STEPS: &[TourStep] = &[
+    TourStep {
+        anchor: "director-scene",
+        title: "The stage",
+        body: "A smaller copy of what players see. Preview a backdrop here before you put it on their screens.",
+    },
+    TourStep {
+        anchor: "director-whispers",
+        title: "Whispers",
+        body: "Private messages from players land here, out of the shared conversation log.",
+    },
+    TourStep {
+        anchor: "director-session",
+        title: "Session info",
+        body: "Share the session with players and pause it for a break.",
+    },
+    TourStep {
+        anchor: "director-mood",
+        title: "Scene mood",
+        body: "Set lighting and weather for the region on stage. Every player sees the change.",
+    },
+    TourStep {
+        anchor: "director-quick-actions",
+        title: "Quick actions",
+        body: "Trigger challenges, open the rules reference, and play camera effects without leaving the scene.",
+    },
+];
+
+const CREATOR_STEPS: &[TourStep] = &[
+    TourStep {
+        anchor: "creator-browser",
+        title: "Entity browser",
+        body: "Characters, locations, encounters, recipes and factions live here. Pick one to edit it, or add a new one.",
+    },
+    TourStep {
+        anchor: "creator-editor",
+        title: "Editor",
+        body: "The selected entity's form. Suggest buttons ask the LLM for names, descriptions and more.",
+    },
+    TourStep {
+        anchor: "creator-queue",
+        title: "Generation queue",
+        body: "Image batches and text suggestions wait here. Open finished ones to pick a result.",
+    },
+];
+
+const PC_STEPS: &[TourStep] = &[
+    TourStep {
+        anchor: "pc-location",
+        title: "Where you are",
+        body: "Your current region and location. The DM moves the party between scenes.",
+    },
+    TourStep {
+        anchor: "pc-dialogue",
+        title: "Dialogue",
+        body: "NPCs speak here. Click to finish a line early, and pick a reply when choices appear.",
+    },
+    TourStep {
+        anchor: "pc-actions",
+        title: "Actions",
+        body: "Talk, look around, open your inventory and character sheet, or whisper to the DM.",
+    },
+];
+
+/// The steps of a view's tour, in order
+pub fn tour_steps(view: TourView) -> &'static [TourStep] {
+    match view {
+        TourView::Director => DIRECTOR_STEPS,
+        TourView::Creator => CREATOR_STEPS,
+        TourView::Pc => PC_STEPS,
+    }
+}
+
+/// Tours finished or skipped on this device
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TourProgress {
+    pub completed: Vec<TourView>,
+}
+
+impl TourProgress {
+    pub fn is_complete(&self, view: TourView) -> bool {
+        self.completed.contains(&view)
+    }
+
+    pub fn mark_complete(&mut self, view: TourView) {
+        if !self.is_complete(view) {
+            self.completed.push(view);
+        }
+    }
+
+    /// Show a view's tour again next time it opens
+    pub fn restart(&mut self, view: TourView) {
+        self.completed.retain(|v| *v != view);
+    }
+}
+
+/// Load tour progress saved on this device
+pub fn load_tour_progress(platform: &Platform) -> TourProgress {
+    platform
+        .storage_load(storage_keys::ONBOARDING_TOURS)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Save tour progress on this device
+pub fn save_tour_progress(platform: &Platform, progress: &TourProgress) {
+    match serde_json::to_string(progress) {
+        Ok(json) => platform.storage_save(storage_keys::ONBOARDING_TOURS, &json),
+        Err(e) => platform.log_error(&format!("Failed to serialize tour progress: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completing_and_restarting_a_tour() {
+        let mut progress = TourProgress::default();
+        progress.mark_complete(TourView::Creator);
+        progress.mark_complete(TourView::Creator);
+        assert_eq!(progress.completed, vec![TourView::Creator]);
+        assert!(!progress.is_complete(TourView::Pc));
+
+        let json = serde_json::to_string(&progress).unwrap();
+        assert_eq!(json, r#"{"completed":["creator"]}"#);
+
+        progress.restart(TourView::Creator);
+        assert!(!progress.is_complete(TourView::Creator));
+    }
+
+    #[test]
+    fn every_view_has_steps_with_distinct_anchors() {
+        for view in TourView::all() {
+            let steps = tour_steps(view);
+            assert!(!steps.is_empty());
+            let mut anchors: Vec<_> = steps.iter().map(|s| s.anchor).collect();
+            anchors.sort_unstable();
+            anchors.dedup();
+            assert_eq!(anchors.len(), steps.len());
+        }
+    }
+}
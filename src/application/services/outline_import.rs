@@ -0,0 +1,130 @@
+//! Outline Import - Planning bulk entity creation from a text outline
+//!
+//! A DM pastes an outline such as
+//!
+//! ```text
+//! NPCs:
+//! - Mira Vell - a nervous innkeeper hiding a debt
+//! - Brother Oswin: a monk who knows too much
+//! ```
+//!
+//! and each line becomes an entry with a name and a one-line summary. The
+//! summaries seed LLM suggestions for the full drafts, which the DM reviews
+//! before anything is created.
+
+/// Separators between a name and its summary, in order of preference
+const SEPARATORS: [&str; 5] = [" — ", " – ", " - ", ": ", " | "];
+
+/// Longest name accepted from an outline line
+const MAX_NAME_LEN: usize = 80;
+
+/// One entity described by an outline line
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutlineEntry {
+    pub name: String,
+    /// The rest of the line, if any
+    pub summary: String,
+}
+
+/// Parse an outline into entries, one per non-empty line
+///
+/// List markers (`-`, `*`, `•`, `1.`, `1)`) are stripped and section
+/// headings (lines ending in `:`) are skipped. Names repeated later in the
+/// outline are dropped.
+pub fn parse_outline(text: &str) -> Vec<OutlineEntry> {
+    let mut entries: Vec<OutlineEntry> = Vec::new();
+    for line in text.lines() {
+        let line = strip_list_marker(line.trim());
+        if line.is_empty() || line.ends_with(':') {
+            continue;
+        }
+
+        let (name, summary) = SEPARATORS
+            .iter()
+            .find_map(|sep| line.split_once(sep))
+            .unwrap_or((line, ""));
+        let name = name.trim().trim_matches(|c| c == '*' || c == '_').trim();
+        if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+            continue;
+        }
+        if entries.iter().any(|e| e.name.eq_ignore_ascii_case(name)) {
+            continue;
+        }
+        entries.push(OutlineEntry {
+            name: name.to_string(),
+            summary: summary.trim().to_string(),
+        });
+    }
+    entries
+}
+
+/// Prompt for an entity's image, built from its draft
+pub fn outline_image_prompt(kind: &str, name: &str, description: &str) -> String {
+    let description = description.trim();
+    if description.is_empty() {
+        format!("{} {}", kind, name)
+    } else {
+        format!("{} {}, {}", kind, name, description)
+    }
+}
+
+fn strip_list_marker(line: &str) -> &str {
+    if let Some(rest) = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("• "))
+    {
+        return rest.trim_start();
+    }
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 {
+        let rest = &line[digits..];
+        if let Some(rest) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return rest.trim_start();
+        }
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_names_and_summaries() {
+        let outline = "NPCs:\n- Mira Vell - a nervous innkeeper\n* Brother Oswin: a monk who knows too much\n\n2) **Kett**\n1. Mira Vell - duplicate";
+        assert_eq!(
+            parse_outline(outline),
+            vec![
+                OutlineEntry {
+                    name: "Mira Vell".to_string(),
+                    summary: "a nervous innkeeper".to_string(),
+                },
+                OutlineEntry {
+                    name: "Brother Oswin".to_string(),
+                    summary: "a monk who knows too much".to_string(),
+                },
+                OutlineEntry {
+                    name: "Kett".to_string(),
+                    summary: String::new(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_hyphenated_names_whole() {
+        let entries = parse_outline("Jean-Luc Moreau - a duelist");
+        assert_eq!(entries[0].name, "Jean-Luc Moreau");
+        assert_eq!(entries[0].summary, "a duelist");
+    }
+
+    #[test]
+    fn builds_image_prompts() {
+        assert_eq!(outline_image_prompt("portrait of", "Kett", ""), "portrait of Kett");
+        assert_eq!(
+            outline_image_prompt("portrait of", "Kett", " a gnome tinker "),
+            "portrait of Kett, a gnome tinker"
+        );
+    }
+}
@@ -35,10 +35,23 @@ pub struct PlayerCharacterData {
     pub sprite_asset: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub portrait_asset: Option<String>,
+    /// Total experience points earned
+    #[serde(default)]
+    pub experience: u32,
+    /// Current level
+    #[serde(default = "default_level")]
+    pub level: u32,
+    /// Levels earned but not yet taken through the level-up flow
+    #[serde(default)]
+    pub pending_levels: u32,
     pub created_at: String,
     pub last_active_at: String,
 }
 
+fn default_level() -> u32 {
+    1
+}
+
 /// Request to create a player character
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreatePlayerCharacterRequest {
@@ -0,0 +1,170 @@
+//! Relationship Service - Typed ties between characters
+//!
+//! Relationships are edited from the character form and read by the
+//! Director's social graph. Each one links two characters with a kind
+//! (ally, rival, family, debt), a strength and the DM's notes.
+
+use serde::Serialize;
+
+use crate::application::dto::{RelationshipData, RelationshipKind};
+use crate::application::ports::outbound::{ApiError, ApiPort};
+
+/// Request to create or update a relationship
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SaveRelationshipRequest {
+    pub from_character_id: String,
+    pub to_character_id: String,
+    pub relationship_type: String,
+    pub sentiment: f32,
+    pub strength: u8,
+    pub notes: String,
+    pub known_to_player: bool,
+}
+
+impl SaveRelationshipRequest {
+    /// A new relationship of `kind` from one character to another
+    pub fn new(from_character_id: &str, to_character_id: &str, kind: RelationshipKind) -> Self {
+        Self {
+            from_character_id: from_character_id.to_string(),
+            to_character_id: to_character_id.to_string(),
+            relationship_type: kind.as_str().to_string(),
+            sentiment: kind.default_sentiment(),
+            strength: 3,
+            notes: String::new(),
+            known_to_player: false,
+        }
+    }
+
+    /// Check the request before it is sent
+    pub fn validate(&self) -> Result<(), String> {
+        if self.to_character_id.is_empty() {
+            return Err("Pick the other character".to_string());
+        }
+        if self.from_character_id == self.to_character_id {
+            return Err("A character can't have a relationship with themselves".to_string());
+        }
+        if !(1..=5).contains(&self.strength) {
+            return Err("Strength must be between 1 and 5".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl From<&RelationshipData> for SaveRelationshipRequest {
+    fn from(relationship: &RelationshipData) -> Self {
+        Self {
+            from_character_id: relationship.from_character_id.clone(),
+            to_character_id: relationship.to_character_id.clone(),
+            relationship_type: relationship.relationship_type.clone(),
+            sentiment: relationship.sentiment,
+            strength: relationship.strength,
+            notes: relationship.notes.clone(),
+            known_to_player: relationship.known_to_player,
+        }
+    }
+}
+
+/// Relationship service for creating, listing and editing relationships
+///
+/// This service provides CRUD for relationships while depending only on
+/// the `ApiPort` trait, not concrete infrastructure implementations.
+pub struct RelationshipService<A: ApiPort> {
+    api: A,
+}
+
+impl<A: ApiPort> RelationshipService<A> {
+    /// Create a new RelationshipService with the given API port
+    pub fn new(api: A) -> Self {
+        Self { api }
+    }
+
+    /// List every relationship in a world
+    pub async fn list_relationships(&self, world_id: &str) -> Result<Vec<RelationshipData>, ApiError> {
+        let path = format!("/api/worlds/{}/relationships", world_id);
+        self.api.get(&path).await
+    }
+
+    /// List the relationships a character is part of, either end
+    pub async fn list_for_character(&self, character_id: &str) -> Result<Vec<RelationshipData>, ApiError> {
+        let path = format!("/api/characters/{}/relationships", character_id);
+        self.api.get(&path).await
+    }
+
+    /// Create a relationship
+    pub async fn create_relationship(
+        &self,
+        world_id: &str,
+        request: &SaveRelationshipRequest,
+    ) -> Result<RelationshipData, ApiError> {
+        let path = format!("/api/worlds/{}/relationships", world_id);
+        self.api.post(&path, request).await
+    }
+
+    /// Update a relationship
+    pub async fn update_relationship(
+        &self,
+        relationship_id: &str,
+        request: &SaveRelationshipRequest,
+    ) -> Result<RelationshipData, ApiError> {
+        let path = format!("/api/relationships/{}", relationship_id);
+        self.api.put(&path, request).await
+    }
+
+    /// Delete a relationship
+    pub async fn delete_relationship(&self, relationship_id: &str) -> Result<(), ApiError> {
+        let path = format!("/api/relationships/{}", relationship_id);
+        self.api.delete(&path).await
+    }
+}
+
+impl<A: ApiPort + Clone> Clone for RelationshipService<A> {
+    fn clone(&self) -> Self {
+        Self {
+            api: self.api.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::testing::MockApiPort;
+
+    #[test]
+    fn validate_needs_another_character_and_a_sane_strength() {
+        let request = SaveRelationshipRequest::new("mira", "bran", RelationshipKind::Rival);
+        assert!(request.validate().is_ok());
+        assert_eq!(request.sentiment, -0.5);
+        assert!(SaveRelationshipRequest::new("mira", "", RelationshipKind::Ally).validate().is_err());
+        assert!(SaveRelationshipRequest::new("mira", "mira", RelationshipKind::Ally).validate().is_err());
+        assert!(SaveRelationshipRequest { strength: 0, ..request }.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn create_relationship_posts_to_world() {
+        let api = MockApiPort::new();
+        api.when_post_json(
+            "/api/worlds/world-1/relationships",
+            serde_json::json!({
+                "id": "rel-1",
+                "from_character_id": "mira",
+                "to_character_id": "bran",
+                "relationship_type": "debt",
+                "sentiment": 0.0,
+                "known_to_player": false
+            }),
+        );
+
+        let svc = RelationshipService::new(api.clone());
+        let request = SaveRelationshipRequest::new("mira", "bran", RelationshipKind::Debt);
+        let created = svc.create_relationship("world-1", &request).await.expect("created");
+
+        assert_eq!(created.kind(), RelationshipKind::Debt);
+        // Relationships from older Engines have no strength or notes
+        assert_eq!(created.strength, 3);
+        assert_eq!(created.other_end("bran"), "mira");
+        let reqs = api.requests();
+        assert_eq!(reqs[0].method, "POST");
+        assert_eq!(reqs[0].body.as_ref().unwrap()["relationship_type"], "debt");
+    }
+}
@@ -0,0 +1,169 @@
+//! Roll History - A player's own challenge results, kept per world
+//!
+//! Each client remembers the challenges its player rolled for, so the
+//! "My Rolls" panel can show them across sessions along with per-skill
+//! success rates. The history lives in local storage and is capped at
+//! [`MAX_ROLLS`], dropping the oldest first.
+
+use serde::{Deserialize, Serialize};
+
+/// Most rolls kept per world
+pub const MAX_ROLLS: usize = 500;
+
+/// Stats label for rolls whose skill wasn't known
+const UNKNOWN_SKILL: &str = "Other";
+
+/// One resolved challenge the player rolled for
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RollRecord {
+    pub challenge_name: String,
+    pub character_name: String,
+    #[serde(default)]
+    pub skill_name: Option<String>,
+    pub roll: i32,
+    pub modifier: i32,
+    pub total: i32,
+    /// Outcome type ("success", "partial", "failure", "critical_success", ...)
+    pub outcome: String,
+    /// Narrative consequence from the DM
+    #[serde(default)]
+    pub outcome_description: String,
+    pub timestamp: u64,
+    /// Session the roll happened in
+    #[serde(default)]
+    pub session_id: Option<String>,
+}
+
+impl RollRecord {
+    /// Whether the roll succeeded, critically or not
+    ///
+    /// Partial successes don't count.
+    pub fn is_success(&self) -> bool {
+        matches!(self.outcome.as_str(), "success" | "critical_success")
+    }
+}
+
+/// Success counts for one skill
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkillRollStats {
+    pub skill_name: String,
+    pub attempts: usize,
+    pub successes: usize,
+}
+
+impl SkillRollStats {
+    /// Success rate as a whole percentage
+    pub fn success_percent(&self) -> u32 {
+        if self.attempts == 0 {
+            return 0;
+        }
+        ((self.successes * 100) as f64 / self.attempts as f64).round() as u32
+    }
+}
+
+/// A world's roll history, oldest first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RollHistory {
+    records: Vec<RollRecord>,
+}
+
+impl RollHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a roll, dropping the oldest past [`MAX_ROLLS`]
+    pub fn record(&mut self, record: RollRecord) {
+        self.records.push(record);
+        if self.records.len() > MAX_ROLLS {
+            let excess = self.records.len() - MAX_ROLLS;
+            self.records.drain(..excess);
+        }
+    }
+
+    /// Rolls newest first, limited to one session when given
+    pub fn newest_first(&self, session_id: Option<&str>) -> Vec<&RollRecord> {
+        self.records
+            .iter()
+            .rev()
+            .filter(|r| session_id.is_none() || r.session_id.as_deref() == session_id)
+            .collect()
+    }
+}
+
+/// Per-skill success counts, most-used skills first
+pub fn skill_stats(records: &[&RollRecord]) -> Vec<SkillRollStats> {
+    let mut stats: Vec<SkillRollStats> = Vec::new();
+    for record in records {
+        let skill_name = record.skill_name.as_deref().unwrap_or(UNKNOWN_SKILL);
+        let index = match stats.iter().position(|s| s.skill_name == skill_name) {
+            Some(index) => index,
+            None => {
+                stats.push(SkillRollStats { skill_name: skill_name.to_string(), attempts: 0, successes: 0 });
+                stats.len() - 1
+            }
+        };
+        stats[index].attempts += 1;
+        if record.is_success() {
+            stats[index].successes += 1;
+        }
+    }
+    stats.sort_by(|a, b| b.attempts.cmp(&a.attempts).then_with(|| a.skill_name.cmp(&b.skill_name)));
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roll(skill: Option<&str>, outcome: &str, session: &str, timestamp: u64) -> RollRecord {
+        RollRecord {
+            challenge_name: "Pick Lock".to_string(),
+            character_name: "Ada".to_string(),
+            skill_name: skill.map(str::to_string),
+            roll: 12,
+            modifier: 2,
+            total: 14,
+            outcome: outcome.to_string(),
+            outcome_description: String::new(),
+            timestamp,
+            session_id: Some(session.to_string()),
+        }
+    }
+
+    #[test]
+    fn filters_by_session_newest_first_and_caps() {
+        let mut history = RollHistory::new();
+        history.record(roll(Some("Stealth"), "success", "s-1", 1));
+        history.record(roll(Some("Stealth"), "failure", "s-2", 2));
+        history.record(roll(Some("Lore"), "success", "s-2", 3));
+
+        let this_session: Vec<u64> = history.newest_first(Some("s-2")).iter().map(|r| r.timestamp).collect();
+        assert_eq!(this_session, vec![3, 2]);
+        assert_eq!(history.newest_first(None).len(), 3);
+
+        for i in 0..MAX_ROLLS {
+            history.record(roll(None, "failure", "s-3", 10 + i as u64));
+        }
+        let kept = history.newest_first(None);
+        assert_eq!(kept.len(), MAX_ROLLS);
+        assert_eq!(kept[MAX_ROLLS - 1].timestamp, 10);
+    }
+
+    #[test]
+    fn skill_stats_count_successes_per_skill() {
+        let records = [
+            roll(Some("Stealth"), "critical_success", "s-1", 1),
+            roll(Some("Stealth"), "partial", "s-1", 2),
+            roll(Some("Stealth"), "failure", "s-1", 3),
+            roll(None, "success", "s-1", 4),
+        ];
+        let refs: Vec<&RollRecord> = records.iter().collect();
+
+        let stats = skill_stats(&refs);
+        assert_eq!(stats[0], SkillRollStats { skill_name: "Stealth".to_string(), attempts: 3, successes: 1 });
+        assert_eq!(stats[0].success_percent(), 33);
+        assert_eq!(stats[1].skill_name, UNKNOWN_SKILL);
+        assert_eq!(stats[1].success_percent(), 100);
+    }
+}
@@ -0,0 +1,193 @@
+//! Roll Macros - Named dice formulas a player rolls with one click
+//!
+//! A macro is a name plus one or more dice steps, written like
+//! `1d20+7, then 3d6`. Rolling it rolls every step and sends the whole
+//! result to the session as a single roll action. Macros are kept on this
+//! device, separately for each player character.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::{DiceNotation, DiceRoll};
+use crate::application::ports::outbound::{storage_keys, Platform};
+
+/// Most macros kept per character
+pub const MAX_MACROS: usize = 30;
+
+/// Most dice steps in one macro
+pub const MAX_STEPS: usize = 6;
+
+/// A saved roll, e.g. "Sneak Attack: 1d20+7, then 3d6"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RollMacro {
+    pub id: String,
+    pub name: String,
+    /// Dice notation for each step, in roll order
+    pub steps: Vec<String>,
+}
+
+impl RollMacro {
+    /// Build a macro from a name and a formula, checking every step parses
+    pub fn new(id: impl Into<String>, name: &str, formula: &str) -> Result<Self, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Give the macro a name".to_string());
+        }
+        let steps = parse_macro_formula(formula)?;
+        Ok(Self {
+            id: id.into(),
+            name: name.to_string(),
+            steps: steps.iter().map(|s| s.to_string()).collect(),
+        })
+    }
+
+    /// The steps joined back into a formula, e.g. "1d20+7, then 3d6"
+    pub fn formula(&self) -> String {
+        self.steps.join(", then ")
+    }
+
+    /// Roll every step using `die(min, max)` for each die
+    ///
+    /// Steps that no longer parse are skipped.
+    pub fn roll(&self, mut die: impl FnMut(i32, i32) -> i32) -> MacroRoll {
+        let rolls = self
+            .steps
+            .iter()
+            .filter_map(|s| DiceNotation::parse(s))
+            .map(|notation| notation.roll(&mut die))
+            .collect();
+        MacroRoll {
+            name: self.name.clone(),
+            rolls,
+        }
+    }
+}
+
+/// The result of rolling a [`RollMacro`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MacroRoll {
+    pub name: String,
+    pub rolls: Vec<DiceRoll>,
+}
+
+impl MacroRoll {
+    /// Notation of every step, e.g. "1d20+7, 3d6"
+    pub fn notation(&self) -> String {
+        self.rolls
+            .iter()
+            .map(|r| r.notation.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// e.g. "Sneak Attack — 1d20+7: [14] + 7 = 21; then 3d6: [2, 3, 5] = 10"
+    pub fn breakdown(&self) -> String {
+        let steps = self
+            .rolls
+            .iter()
+            .map(|r| r.breakdown())
+            .collect::<Vec<_>>()
+            .join("; then ");
+        format!("{} — {}", self.name, steps)
+    }
+}
+
+/// Split a formula like `1d20+7, then 3d6` into dice steps
+///
+/// Steps are separated by commas, semicolons or the word "then".
+pub fn parse_macro_formula(formula: &str) -> Result<Vec<DiceNotation>, String> {
+    let normalized = formula.to_lowercase().replace(';', ",").replace(" then ", ",");
+    let parts: Vec<&str> = normalized
+        .split(',')
+        .map(|p| p.trim().trim_start_matches("then").trim())
+        .filter(|p| !p.is_empty())
+        .collect();
+    if parts.is_empty() {
+        return Err("Enter at least one roll, e.g. 1d20+5".to_string());
+    }
+    if parts.len() > MAX_STEPS {
+        return Err(format!("A macro can have at most {} rolls", MAX_STEPS));
+    }
+    parts
+        .iter()
+        .map(|p| DiceNotation::parse(p).ok_or_else(|| format!("\"{}\" isn't dice notation like 2d6+3", p)))
+        .collect()
+}
+
+/// Add a macro, replacing any with the same ID
+///
+/// New macros go to the end; past [`MAX_MACROS`] the oldest is dropped.
+pub fn upsert_macro(macros: &mut Vec<RollMacro>, roll_macro: RollMacro) {
+    if let Some(existing) = macros.iter_mut().find(|m| m.id == roll_macro.id) {
+        *existing = roll_macro;
+        return;
+    }
+    macros.push(roll_macro);
+    let overflow = macros.len().saturating_sub(MAX_MACROS);
+    macros.drain(..overflow);
+}
+
+fn macros_key(pc_id: &str) -> String {
+    format!("{}{}", storage_keys::ROLL_MACROS_PREFIX, pc_id)
+}
+
+/// Macros saved on this device for a character
+pub fn load_roll_macros(platform: &Platform, pc_id: &str) -> Vec<RollMacro> {
+    platform
+        .storage_load(&macros_key(pc_id))
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Save a character's macros on this device
+pub fn save_roll_macros(platform: &Platform, pc_id: &str, macros: &[RollMacro]) {
+    match serde_json::to_string(macros) {
+        Ok(json) => platform.storage_save(&macros_key(pc_id), &json),
+        Err(e) => platform.log_error(&format!("Failed to serialize roll macros: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formulas_split_on_commas_and_then() {
+        let steps = parse_macro_formula("1d20+7, then 3d6").unwrap();
+        assert_eq!(steps.iter().map(|s| s.to_string()).collect::<Vec<_>>(), vec!["1d20+7", "3d6"]);
+        assert_eq!(parse_macro_formula("d20 then 2d8-1; d4").unwrap().len(), 3);
+        assert!(parse_macro_formula("  ").is_err());
+        assert!(parse_macro_formula("1d20, sword").is_err());
+    }
+
+    #[test]
+    fn rolling_covers_every_step() {
+        let sneak = RollMacro::new("m1", "Sneak Attack", "1d20+7, then 3d6").unwrap();
+        assert_eq!(sneak.formula(), "1d20+7, then 3d6");
+
+        let result = sneak.roll(|_, max| max);
+        assert_eq!(result.notation(), "1d20+7, 3d6");
+        assert_eq!(
+            result.breakdown(),
+            "Sneak Attack — 1d20+7: [20] + 7 = 27; then 3d6: [6, 6, 6] = 18"
+        );
+    }
+
+    #[test]
+    fn upsert_replaces_by_id_and_caps_the_list() {
+        let mut macros = vec![RollMacro::new("m1", "Attack", "1d20").unwrap()];
+        upsert_macro(&mut macros, RollMacro::new("m1", "Attack", "1d20+2").unwrap());
+        assert_eq!(macros.len(), 1);
+        assert_eq!(macros[0].steps, vec!["1d20+2"]);
+
+        for i in 0..MAX_MACROS {
+            upsert_macro(&mut macros, RollMacro::new(format!("n{}", i), "Roll", "1d6").unwrap());
+        }
+        assert_eq!(macros.len(), MAX_MACROS);
+        assert_eq!(macros[0].id, "n0");
+    }
+
+    #[test]
+    fn macros_need_a_name() {
+        assert!(RollMacro::new("m1", "  ", "1d20").is_err());
+    }
+}
@@ -0,0 +1,297 @@
+//! Rules Reference - A searchable digest of the world's rules for the DM
+//!
+//! Entries are built from the world's rule system (stats, dice, difficulty
+//! ladder, check formula) and skills, plus house rules the DM writes. House
+//! rules are kept on this device, separately for each world.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::{
+    DiceSystem, RuleSystemConfig, RuleSystemType, SkillData, SuccessComparison,
+};
+use crate::application::ports::outbound::{storage_keys, Platform};
+
+/// Most house rules kept per world
+pub const MAX_HOUSE_RULES: usize = 100;
+
+/// Where a reference entry comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleCategory {
+    System,
+    Stats,
+    Difficulty,
+    Skills,
+    HouseRules,
+}
+
+impl RuleCategory {
+    /// Every category, in the order the reference lists them
+    pub fn all() -> Vec<Self> {
+        vec![Self::HouseRules, Self::System, Self::Difficulty, Self::Stats, Self::Skills]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::System => "Core Rules",
+            Self::Stats => "Stats",
+            Self::Difficulty => "Difficulty Ladder",
+            Self::Skills => "Skills",
+            Self::HouseRules => "House Rules",
+        }
+    }
+}
+
+/// A rule the DM added on top of the rule system
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HouseRule {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+}
+
+impl HouseRule {
+    /// Build a house rule, checking it has a title
+    pub fn new(id: impl Into<String>, title: &str, body: &str) -> Result<Self, String> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err("Give the house rule a title".to_string());
+        }
+        Ok(Self {
+            id: id.into(),
+            title: title.to_string(),
+            body: body.trim().to_string(),
+        })
+    }
+}
+
+/// One entry in the reference
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleEntry {
+    /// Unique within the reference, unlike titles
+    pub id: String,
+    pub category: RuleCategory,
+    pub title: String,
+    pub body: String,
+    /// Set for house rules, which can be edited
+    pub house_rule_id: Option<String>,
+}
+
+impl RuleEntry {
+    fn new(id: impl Into<String>, category: RuleCategory, title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            category,
+            title: title.into(),
+            body: body.into(),
+            house_rule_id: None,
+        }
+    }
+
+    /// Whether the title or body contains `query` (lowercase)
+    fn matches(&self, query: &str) -> bool {
+        self.title.to_lowercase().contains(query) || self.body.to_lowercase().contains(query)
+    }
+}
+
+/// How the dice system reads aloud
+pub fn dice_system_summary(dice: &DiceSystem) -> String {
+    match dice {
+        DiceSystem::D20 => "Roll 1d20 and add modifiers".to_string(),
+        DiceSystem::D100 => "Roll percentile dice (1d100)".to_string(),
+        DiceSystem::DicePool { die_type, success_threshold } => format!(
+            "Roll a pool of d{}s; each die showing {} or more is a success",
+            die_type, success_threshold
+        ),
+        DiceSystem::Fate => "Roll four Fate dice (4dF), each -1, 0 or +1".to_string(),
+        DiceSystem::Custom(expression) => format!("Custom dice: {}", expression),
+    }
+}
+
+/// Difficulty steps for a rule system type, as (name, target)
+pub fn difficulty_ladder(system_type: RuleSystemType) -> Vec<(&'static str, &'static str)> {
+    match system_type {
+        RuleSystemType::D20 => vec![
+            ("Trivial", "DC 5"),
+            ("Easy", "DC 10"),
+            ("Moderate", "DC 15"),
+            ("Hard", "DC 20"),
+            ("Very Hard", "DC 25"),
+            ("Extreme", "DC 30"),
+        ],
+        RuleSystemType::D100 => vec![
+            ("Regular", "Roll under the full skill value"),
+            ("Hard", "Roll under half the skill value"),
+            ("Extreme", "Roll under a fifth of the skill value"),
+            ("Critical", "A roll of 01"),
+            ("Fumble", "A roll of 100 (96+ when the skill is under 50)"),
+        ],
+        RuleSystemType::Narrative => vec![
+            ("Strong hit", "10+: the character gets what they want"),
+            ("Weak hit", "7-9: success with a cost or complication"),
+            ("Miss", "6-: the DM makes a move"),
+        ],
+        RuleSystemType::Custom => Vec::new(),
+    }
+}
+
+/// Every reference entry for a world, house rules first
+pub fn build_reference(rules: &RuleSystemConfig, skills: &[SkillData], house_rules: &[HouseRule]) -> Vec<RuleEntry> {
+    let mut entries: Vec<RuleEntry> = house_rules
+        .iter()
+        .map(|rule| RuleEntry {
+            house_rule_id: Some(rule.id.clone()),
+            ..RuleEntry::new(format!("house-{}", rule.id), RuleCategory::HouseRules, rule.title.clone(), rule.body.clone())
+        })
+        .collect();
+
+    let system_name = if rules.name.is_empty() { rules.variant.display_name() } else { rules.name.as_str() };
+    entries.push(RuleEntry::new("system", RuleCategory::System, system_name, rules.description.clone()));
+    entries.push(RuleEntry::new("system-dice", RuleCategory::System, "Dice", dice_system_summary(&rules.dice_system)));
+    entries.push(RuleEntry::new("system-checks", RuleCategory::System, "Skill checks", rules.skill_check_formula.clone()));
+    let comparison = match rules.success_comparison {
+        SuccessComparison::GreaterOrEqual => "Meet or beat the target number",
+        SuccessComparison::LessOrEqual => "Roll at or under the target number",
+        SuccessComparison::Narrative => "The total picks an outcome tier",
+    };
+    entries.push(RuleEntry::new("system-success", RuleCategory::System, "Success", comparison));
+
+    for (name, target) in difficulty_ladder(rules.system_type) {
+        entries.push(RuleEntry::new(format!("difficulty-{}", name), RuleCategory::Difficulty, name, target));
+    }
+
+    for (index, stat) in rules.stat_definitions.iter().enumerate() {
+        entries.push(RuleEntry::new(
+            format!("stat-{}", index),
+            RuleCategory::Stats,
+            format!("{} ({})", stat.name, stat.abbreviation),
+            format!(
+                "Ranges from {} to {}; starts at {}",
+                stat.min_value, stat.max_value, stat.default_value
+            ),
+        ));
+    }
+
+    let mut visible: Vec<&SkillData> = skills.iter().filter(|s| !s.is_hidden).collect();
+    visible.sort_by_key(|s| s.order);
+    for skill in visible {
+        let mut body = skill.description.clone();
+        if let Some(attribute) = skill.base_attribute.as_ref().filter(|a| !a.is_empty()) {
+            if !body.is_empty() {
+                body.push(' ');
+            }
+            body.push_str(&format!("({}, {})", skill.category.display_name(), attribute));
+        }
+        entries.push(RuleEntry::new(format!("skill-{}", skill.id), RuleCategory::Skills, skill.name.clone(), body));
+    }
+
+    entries
+}
+
+/// Entries whose title or body contains `query`, case-insensitively
+pub fn search_reference<'a>(entries: &'a [RuleEntry], query: &str) -> Vec<&'a RuleEntry> {
+    let query = query.trim().to_lowercase();
+    entries.iter().filter(|e| query.is_empty() || e.matches(&query)).collect()
+}
+
+/// Add a house rule, replacing any with the same ID
+///
+/// New rules go to the end; past [`MAX_HOUSE_RULES`] the oldest is dropped.
+pub fn upsert_house_rule(rules: &mut Vec<HouseRule>, rule: HouseRule) {
+    if let Some(existing) = rules.iter_mut().find(|r| r.id == rule.id) {
+        *existing = rule;
+        return;
+    }
+    rules.push(rule);
+    let overflow = rules.len().saturating_sub(MAX_HOUSE_RULES);
+    rules.drain(..overflow);
+}
+
+fn house_rules_key(world_id: &str) -> String {
+    format!("{}{}", storage_keys::HOUSE_RULES_PREFIX, world_id)
+}
+
+/// House rules saved on this device for a world
+pub fn load_house_rules(platform: &Platform, world_id: &str) -> Vec<HouseRule> {
+    platform
+        .storage_load(&house_rules_key(world_id))
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Save a world's house rules on this device
+pub fn save_house_rules(platform: &Platform, world_id: &str, rules: &[HouseRule]) {
+    match serde_json::to_string(rules) {
+        Ok(json) => platform.storage_save(&house_rules_key(world_id), &json),
+        Err(e) => platform.log_error(&format!("Failed to serialize house rules: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::{SkillCategory, StatDefinition};
+
+    fn skill(name: &str, description: &str, hidden: bool) -> SkillData {
+        SkillData {
+            id: name.to_lowercase(),
+            world_id: "w1".to_string(),
+            name: name.to_string(),
+            description: description.to_string(),
+            category: SkillCategory::Physical,
+            base_attribute: Some("DEX".to_string()),
+            is_custom: false,
+            is_hidden: hidden,
+            order: 0,
+        }
+    }
+
+    #[test]
+    fn reference_covers_rules_stats_ladder_skills_and_house_rules() {
+        let mut rules = RuleSystemConfig::default();
+        rules.stat_definitions.push(StatDefinition {
+            name: "Dexterity".to_string(),
+            abbreviation: "DEX".to_string(),
+            min_value: 1,
+            max_value: 20,
+            default_value: 10,
+        });
+        let skills = vec![skill("Stealth", "Move unseen.", false), skill("Secret Art", "", true)];
+        let house = vec![HouseRule::new("h1", "Flanking", "+2 to hit when flanking").unwrap()];
+
+        let entries = build_reference(&rules, &skills, &house);
+        assert_eq!(entries[0].house_rule_id.as_deref(), Some("h1"));
+        assert!(entries.iter().any(|e| e.category == RuleCategory::Difficulty && e.body == "DC 15"));
+        assert!(entries.iter().any(|e| e.title == "Dexterity (DEX)"));
+        assert!(entries.iter().any(|e| e.title == "Stealth" && e.body == "Move unseen. (Physical, DEX)"));
+        assert!(!entries.iter().any(|e| e.title == "Secret Art"));
+    }
+
+    #[test]
+    fn entries_with_the_same_title_get_distinct_ids() {
+        let house = vec![
+            HouseRule::new("h1", "Stealth", "Advantage in darkness").unwrap(),
+            HouseRule::new("h2", "Stealth", "Disadvantage in plate").unwrap(),
+        ];
+        let entries = build_reference(&RuleSystemConfig::default(), &[skill("Stealth", "Move unseen.", false)], &house);
+        let ids: std::collections::HashSet<&str> = entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids.len(), entries.len());
+    }
+
+    #[test]
+    fn search_matches_titles_and_bodies() {
+        let house = vec![HouseRule::new("h1", "Flanking", "+2 to hit when flanking").unwrap()];
+        let entries = build_reference(&RuleSystemConfig::default(), &[skill("Stealth", "Move unseen.", false)], &house);
+        assert_eq!(search_reference(&entries, "UNSEEN").len(), 1);
+        assert_eq!(search_reference(&entries, "flank")[0].title, "Flanking");
+        assert_eq!(search_reference(&entries, "  ").len(), entries.len());
+    }
+
+    #[test]
+    fn house_rules_need_a_title_and_upsert_by_id() {
+        assert!(HouseRule::new("h1", " ", "body").is_err());
+        let mut rules = vec![HouseRule::new("h1", "Crits", "Max damage").unwrap()];
+        upsert_house_rule(&mut rules, HouseRule::new("h1", "Crits", "Double dice").unwrap());
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].body, "Double dice");
+    }
+}
@@ -0,0 +1,171 @@
+//! Scene Staging - Parallel scenes while the party is split
+//!
+//! When the party splits across locations the DM can run several scenes
+//! side by side. Each player is in at most one staged scene and only sees
+//! that one; the DM sees them all and directs one at a time. With nothing
+//! staged, everyone shares the single current scene as before.
+
+use crate::application::dto::{ServerMessage, StagedScene};
+
+/// The staged scenes, who is in each, and which one the DM is directing
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SceneStaging {
+    scenes: Vec<StagedScene>,
+    /// Scene the Director panel points at; the first scene if unset
+    focused: Option<String>,
+}
+
+impl SceneStaging {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn scenes(&self) -> &[StagedScene] {
+        &self.scenes
+    }
+
+    /// Whether more than one scene is running
+    pub fn is_split(&self) -> bool {
+        self.scenes.len() > 1
+    }
+
+    /// Scene the DM is directing
+    pub fn focused(&self) -> Option<&StagedScene> {
+        self.focused
+            .as_ref()
+            .and_then(|id| self.scenes.iter().find(|s| &s.scene_id == id))
+            .or_else(|| self.scenes.first())
+    }
+
+    /// Point the Director panel at a staged scene
+    ///
+    /// Returns false if the scene isn't staged.
+    pub fn focus(&mut self, scene_id: &str) -> bool {
+        if !self.scenes.iter().any(|s| s.scene_id == scene_id) {
+            return false;
+        }
+        self.focused = Some(scene_id.to_string());
+        true
+    }
+
+    /// Replace the scenes from ServerMessage::StagedScenesChanged
+    ///
+    /// Focus stays put while its scene is still staged.
+    pub fn set_scenes(&mut self, scenes: Vec<StagedScene>) {
+        self.scenes = scenes;
+        if self.focused().map(|s| s.scene_id.clone()) != self.focused {
+            self.focused = None;
+        }
+    }
+
+    /// Add a scene with no one in it yet
+    pub fn stage(&mut self, scene_id: &str, scene_name: &str) {
+        if self.scenes.iter().any(|s| s.scene_id == scene_id) {
+            return;
+        }
+        self.scenes.push(StagedScene {
+            scene_id: scene_id.to_string(),
+            scene_name: scene_name.to_string(),
+            user_ids: Vec::new(),
+        });
+    }
+
+    /// Drop a scene; its players are left unassigned
+    pub fn unstage(&mut self, scene_id: &str) {
+        self.scenes.retain(|s| s.scene_id != scene_id);
+        if self.focused.as_deref() == Some(scene_id) {
+            self.focused = None;
+        }
+    }
+
+    /// Move a player into a scene, out of whichever they were in
+    pub fn assign(&mut self, user_id: &str, scene_id: &str) {
+        self.unassign(user_id);
+        if let Some(scene) = self.scenes.iter_mut().find(|s| s.scene_id == scene_id) {
+            scene.user_ids.push(user_id.to_string());
+        }
+    }
+
+    pub fn unassign(&mut self, user_id: &str) {
+        for scene in &mut self.scenes {
+            scene.user_ids.retain(|id| id != user_id);
+        }
+    }
+
+    /// Staged scene a player is in, if any
+    pub fn scene_of(&self, user_id: &str) -> Option<&StagedScene> {
+        self.scenes.iter().find(|s| s.user_ids.iter().any(|id| id == user_id))
+    }
+
+    /// Whether a player should see traffic for the given scene
+    ///
+    /// Players not in any staged scene see everything, as do the DM and
+    /// spectators.
+    pub fn reaches(&self, user_id: &str, scene_id: &str) -> bool {
+        self.scene_of(user_id).is_none_or(|s| s.scene_id == scene_id)
+    }
+}
+
+/// Scene a scene-scoped server message belongs to
+///
+/// None for messages that aren't tied to one scene, or that the Engine
+/// sent without saying.
+pub fn message_scene_id(message: &ServerMessage) -> Option<&str> {
+    match message {
+        ServerMessage::SceneUpdate { scene, .. } => Some(&scene.id),
+        ServerMessage::DialogueResponse { scene_id, .. } => scene_id.as_deref(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split() -> SceneStaging {
+        let mut staging = SceneStaging::new();
+        staging.stage("docks", "The Docks");
+        staging.stage("manor", "Hale Manor");
+        staging.assign("alice", "docks");
+        staging.assign("bob", "manor");
+        staging
+    }
+
+    #[test]
+    fn players_are_in_one_scene_at_a_time() {
+        let mut staging = split();
+        assert!(staging.is_split());
+        assert_eq!(staging.scene_of("alice").unwrap().scene_id, "docks");
+
+        staging.assign("alice", "manor");
+        assert_eq!(staging.scene_of("alice").unwrap().scene_id, "manor");
+        assert!(staging.scenes()[0].user_ids.is_empty());
+
+        staging.unstage("manor");
+        assert!(staging.scene_of("alice").is_none());
+        assert!(!staging.is_split());
+    }
+
+    #[test]
+    fn players_only_see_their_scene() {
+        let staging = split();
+        assert!(staging.reaches("alice", "docks"));
+        assert!(!staging.reaches("alice", "manor"));
+        // Not in a staged scene: the DM, spectators, late joiners
+        assert!(staging.reaches("dm", "manor"));
+        assert!(SceneStaging::new().reaches("alice", "manor"));
+    }
+
+    #[test]
+    fn focus_falls_back_to_the_first_scene() {
+        let mut staging = split();
+        assert_eq!(staging.focused().unwrap().scene_id, "docks");
+        assert!(staging.focus("manor"));
+        assert!(!staging.focus("tower"));
+        assert_eq!(staging.focused().unwrap().scene_id, "manor");
+
+        let remaining = vec![staging.scenes()[0].clone()];
+        staging.set_scenes(remaining);
+        assert_eq!(staging.focused().unwrap().scene_id, "docks");
+    }
+}
@@ -7,7 +7,11 @@ use std::sync::Arc;
 
 use anyhow::Result;
 
-use crate::application::ports::outbound::{ApprovalDecision, DiceInputType, DirectorialContext, GameConnectionPort};
+use crate::application::dto::{Annotation, AnnotationLayer, AnnotationSurface, CameraEffect, MemoryDraft, RegionPopulation, RevealScope, SceneMood, SessionRecap, SharedNoteDoc, StagedScene};
+use crate::application::ports::outbound::{
+    AdHocOutcomes, AdvancementGrant, ApprovalDecision, DecisionTimerSettings, DialoguePacing, DiceInputType, DirectorialContext,
+    GameConnectionPort, LevelUpChoices, NewCondition, ParticipantRole, SpectatorPolicy, TimedDecision,
+};
 
 /// Application service for sending session commands via the game connection.
 #[derive(Clone)]
@@ -32,6 +36,18 @@ impl SessionCommandService {
         self.connection.trigger_challenge(challenge_id, target_character_id)
     }
 
+    pub fn create_adhoc_challenge(
+        &self,
+        challenge_name: &str,
+        skill_name: &str,
+        difficulty: &str,
+        target_pc_id: &str,
+        outcomes: AdHocOutcomes,
+    ) -> Result<()> {
+        self.connection
+            .create_adhoc_challenge(challenge_name, skill_name, difficulty, target_pc_id, outcomes)
+    }
+
     pub fn submit_challenge_roll(&self, challenge_id: &str, roll: i32) -> Result<()> {
         self.connection.submit_challenge_roll(challenge_id, roll)
     }
@@ -39,5 +55,172 @@ impl SessionCommandService {
     pub fn submit_challenge_roll_input(&self, challenge_id: &str, input: DiceInputType) -> Result<()> {
         self.connection.submit_challenge_roll_input(challenge_id, input)
     }
-}
 
+    pub fn pause_session(&self, message: Option<&str>, art_asset: Option<&str>) -> Result<()> {
+        self.connection.pause_session(message, art_asset)
+    }
+
+    pub fn resume_session(&self) -> Result<()> {
+        self.connection.resume_session()
+    }
+
+    pub fn assign_role(&self, user_id: &str, role: ParticipantRole) -> Result<()> {
+        self.connection.assign_role(user_id, role)
+    }
+
+    pub fn send_whisper(&self, text: &str) -> Result<()> {
+        self.connection.send_whisper(text)
+    }
+
+    pub fn reply_to_whisper(&self, to_user_id: &str, text: &str) -> Result<()> {
+        self.connection.reply_to_whisper(to_user_id, text)
+    }
+
+    pub fn set_spectator_policy(&self, policy: SpectatorPolicy) -> Result<()> {
+        self.connection.set_spectator_policy(policy)
+    }
+
+    pub fn set_staged_scenes(&self, scenes: Vec<StagedScene>) -> Result<()> {
+        self.connection.set_staged_scenes(scenes)
+    }
+
+    pub fn focus_staged_scene(&self, scene_id: &str) -> Result<()> {
+        self.connection.focus_staged_scene(scene_id)
+    }
+
+    pub fn set_async_play(&self, enabled: bool) -> Result<()> {
+        self.connection.set_async_play(enabled)
+    }
+
+    pub fn process_inbox_actions(&self, action_ids: Vec<String>) -> Result<()> {
+        self.connection.process_inbox_actions(action_ids)
+    }
+
+    pub fn discard_inbox_action(&self, action_id: &str) -> Result<()> {
+        self.connection.discard_inbox_action(action_id)
+    }
+
+    pub fn grant_advancement(&self, pc_id: &str, grant: AdvancementGrant) -> Result<()> {
+        self.connection.grant_advancement(pc_id, grant)
+    }
+
+    pub fn submit_level_up(&self, pc_id: &str, choices: LevelUpChoices) -> Result<()> {
+        self.connection.submit_level_up(pc_id, choices)
+    }
+
+    pub fn speak_as(&self, npc_id: &str, text: &str, record_as_approved: bool) -> Result<()> {
+        self.connection.speak_as(npc_id, text, record_as_approved)
+    }
+
+    pub fn set_dialogue_pacing(&self, pacing: DialoguePacing) -> Result<()> {
+        self.connection.set_dialogue_pacing(pacing)
+    }
+
+    pub fn mark_dialogue_read(&self) -> Result<()> {
+        self.connection.mark_dialogue_read()
+    }
+
+    pub fn advance_dialogue(&self) -> Result<()> {
+        self.connection.advance_dialogue()
+    }
+
+    pub fn set_decision_timer(&self, timer: DecisionTimerSettings) -> Result<()> {
+        self.connection.set_decision_timer(timer)
+    }
+
+    pub fn report_decision_timeout(&self, decision: TimedDecision, defaulted: bool) -> Result<()> {
+        self.connection.report_decision_timeout(decision, defaulted)
+    }
+
+    pub fn apply_condition(&self, character_id: &str, condition: NewCondition) -> Result<()> {
+        self.connection.apply_condition(character_id, condition)
+    }
+
+    pub fn remove_condition(&self, character_id: &str, condition_id: &str) -> Result<()> {
+        self.connection.remove_condition(character_id, condition_id)
+    }
+
+    pub fn add_annotation(&self, surface: AnnotationSurface, annotation: Annotation) -> Result<()> {
+        self.connection.add_annotation(surface, annotation)
+    }
+
+    pub fn remove_annotation(&self, surface: AnnotationSurface, annotation_id: &str) -> Result<()> {
+        self.connection.remove_annotation(surface, annotation_id)
+    }
+
+    pub fn set_annotation_layer(&self, surface: AnnotationSurface, layer: AnnotationLayer) -> Result<()> {
+        self.connection.set_annotation_layer(surface, layer)
+    }
+
+    pub fn clear_annotation_layer(&self, surface: AnnotationSurface, layer_id: &str) -> Result<()> {
+        self.connection.clear_annotation_layer(surface, layer_id)
+    }
+
+    pub fn run_encounter(&self, encounter_id: &str) -> Result<()> {
+        self.connection.run_encounter(encounter_id)
+    }
+
+    pub fn set_scene_mood(&self, region_id: &str, mood: SceneMood) -> Result<()> {
+        self.connection.set_scene_mood(region_id, mood)
+    }
+
+    pub fn set_region_backdrop(&self, region_id: &str, asset_id: &str) -> Result<()> {
+        self.connection.set_region_backdrop(region_id, asset_id)
+    }
+
+    pub fn request_npc_memories(&self, npc_id: &str) -> Result<()> {
+        self.connection.request_npc_memories(npc_id)
+    }
+
+    pub fn add_npc_memory(&self, npc_id: &str, memory: MemoryDraft) -> Result<()> {
+        self.connection.add_npc_memory(npc_id, memory)
+    }
+
+    pub fn update_npc_memory(&self, npc_id: &str, memory_id: &str, memory: MemoryDraft) -> Result<()> {
+        self.connection.update_npc_memory(npc_id, memory_id, memory)
+    }
+
+    pub fn delete_npc_memory(&self, npc_id: &str, memory_id: &str) -> Result<()> {
+        self.connection.delete_npc_memory(npc_id, memory_id)
+    }
+
+    pub fn reveal_regions(&self, region_ids: Vec<String>, scope: RevealScope) -> Result<()> {
+        self.connection.reveal_regions(region_ids, scope)
+    }
+
+    pub fn hide_regions(&self, region_ids: Vec<String>, scope: RevealScope) -> Result<()> {
+        self.connection.hide_regions(region_ids, scope)
+    }
+
+    pub fn edit_shared_note(&self, doc: SharedNoteDoc, text: &str, base_revision: u64) -> Result<()> {
+        self.connection.edit_shared_note(doc, text, base_revision)
+    }
+
+    pub fn send_shared_note_cursor(&self, doc: SharedNoteDoc, position: Option<usize>) -> Result<()> {
+        self.connection.send_shared_note_cursor(doc, position)
+    }
+
+    pub fn attempt_craft(&self, pc_id: &str, recipe_id: &str) -> Result<()> {
+        self.connection.attempt_craft(pc_id, recipe_id)
+    }
+
+    pub fn identify_item(&self, pc_id: &str, item_id: &str) -> Result<()> {
+        self.connection.identify_item(pc_id, item_id)
+    }
+
+    pub fn play_recap(&self, recap: SessionRecap) -> Result<()> {
+        self.connection.play_recap(recap)
+    }
+
+    pub fn play_camera_effect(&self, effect: CameraEffect) -> Result<()> {
+        self.connection.play_camera_effect(effect)
+    }
+
+    pub fn set_disposition_hidden(&self, npc_id: &str, hidden: bool) -> Result<()> {
+        self.connection.set_disposition_hidden(npc_id, hidden)
+    }
+
+    pub fn set_region_population(&self, region_id: &str, population: RegionPopulation) -> Result<()> {
+        self.connection.set_region_population(region_id, population)
+    }
+}
@@ -24,6 +24,13 @@ use futures_channel::mpsc;
 /// Default WebSocket URL for the Engine server
 pub const DEFAULT_ENGINE_URL: &str = "ws://localhost:3000/ws";
 
+/// Stand-in Engine URL for the demo session, which runs locally against a
+/// scripted world instead of a real Engine
+pub const DEMO_ENGINE_URL: &str = "demo://local";
+
+/// World ID of the demo session
+pub const DEMO_WORLD_ID: &str = "demo";
+
 // Re-export port types for external use
 pub use crate::application::ports::outbound::{
     ParticipantRole as ParticipantRolePort,
@@ -0,0 +1,182 @@
+//! Share Text - Plain-text versions of entities for the clipboard
+//!
+//! What the Copy and Share buttons put on the clipboard: challenge stat
+//! blocks, short character and location summaries, timeline events, and
+//! absolute deep links to routes in the app. Everything is plain text so it
+//! pastes cleanly into chat, documents or a VTT.
+
+use crate::application::dto::{ChallengeData, RichText, StoryEventData};
+
+/// A challenge as a stat block
+///
+/// ```text
+/// Pick the Lock
+/// Skill Check · Sleight of Hand · DC 15
+/// The lock is old but well oiled.
+///
+/// Success: The door swings open.
+/// Failure: The pick snaps.
+/// ```
+pub fn challenge_stat_block(challenge: &ChallengeData, skill_name: &str) -> String {
+    let mut lines = vec![challenge.name.clone()];
+    let rule = [
+        challenge.challenge_type.display_name().to_string(),
+        skill_name.to_string(),
+        challenge.difficulty.display(),
+    ]
+    .into_iter()
+    .filter(|part| !part.is_empty())
+    .collect::<Vec<_>>()
+    .join(" · ");
+    lines.push(rule);
+    if !challenge.description.trim().is_empty() {
+        lines.push(challenge.description.trim().to_string());
+    }
+
+    let outcomes = &challenge.outcomes;
+    let outcome_lines: Vec<String> = [
+        ("Critical success", outcomes.critical_success.as_ref()),
+        ("Success", Some(&outcomes.success)),
+        ("Partial", outcomes.partial.as_ref()),
+        ("Failure", Some(&outcomes.failure)),
+        ("Critical failure", outcomes.critical_failure.as_ref()),
+    ]
+    .into_iter()
+    .filter_map(|(label, outcome)| {
+        let text = outcome?.description.trim();
+        (!text.is_empty()).then(|| format!("{}: {}", label, text))
+    })
+    .collect();
+    if !outcome_lines.is_empty() {
+        lines.push(String::new());
+        lines.extend(outcome_lines);
+    }
+
+    if !challenge.tags.is_empty() {
+        lines.push(String::new());
+        lines.push(format!("Tags: {}", challenge.tags.join(", ")));
+    }
+    lines.join("\n")
+}
+
+/// A short summary of a character or location
+///
+/// `description` is description markup; mentions become their labels.
+/// Empty `details` are left out, so callers can pass every field they
+/// would share and only the filled ones show up.
+pub fn entity_summary(name: &str, kind: &str, description: &str, details: &[(&str, &str)]) -> String {
+    let mut text = if kind.is_empty() {
+        name.to_string()
+    } else {
+        format!("{} ({})", name, kind)
+    };
+
+    let description = RichText::parse(description).plain_text();
+    if !description.trim().is_empty() {
+        text.push('\n');
+        text.push_str(description.trim());
+    }
+
+    let details: Vec<String> = details
+        .iter()
+        .filter(|(_, value)| !value.trim().is_empty())
+        .map(|(label, value)| format!("{}: {}", label, value.trim()))
+        .collect();
+    if !details.is_empty() {
+        text.push_str("\n\n");
+        text.push_str(&details.join("\n"));
+    }
+    text
+}
+
+/// A timeline event, with its already-resolved `summary`
+pub fn story_event_text(event: &StoryEventData, summary: &str) -> String {
+    let when = event.game_time.as_deref().unwrap_or(&event.timestamp);
+    let mut text = if event.type_name.is_empty() {
+        format!("{}\n{}", summary, when)
+    } else {
+        format!("{}\n{} · {}", summary, event.type_name, when)
+    };
+    if !event.tags.is_empty() {
+        let tags: Vec<String> = event.tags.iter().map(|t| format!("#{}", t)).collect();
+        text.push('\n');
+        text.push_str(&tags.join(" "));
+    }
+    text
+}
+
+/// Absolute link to `path` (a route's URL) under the app's origin
+///
+/// Without an origin (desktop) the path is returned as-is.
+pub fn deep_link(origin: Option<&str>, path: &str) -> String {
+    match origin {
+        Some(origin) if !origin.is_empty() => format!("{}{}", origin.trim_end_matches('/'), path),
+        _ => path.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn challenge() -> ChallengeData {
+        serde_json::from_value(serde_json::json!({
+            "id": "c1",
+            "world_id": "w1",
+            "scene_id": null,
+            "name": "Pick the Lock",
+            "description": "The lock is old but well oiled.",
+            "challenge_type": "skill_check",
+            "skill_id": "s1",
+            "difficulty": { "type": "dc", "value": 15 },
+            "outcomes": {
+                "success": { "description": "The door swings open." },
+                "failure": { "description": "The pick snaps." },
+                "critical_failure": { "description": "" }
+            },
+            "trigger_conditions": [],
+            "prerequisite_challenges": [],
+            "active": true,
+            "order": 0,
+            "is_favorite": false,
+            "tags": ["vault", "heist"]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn stat_block_lists_rule_description_outcomes_and_tags() {
+        assert_eq!(
+            challenge_stat_block(&challenge(), "Sleight of Hand"),
+            "Pick the Lock\n\
+             Skill Check · Sleight of Hand · DC 15\n\
+             The lock is old but well oiled.\n\
+             \n\
+             Success: The door swings open.\n\
+             Failure: The pick snaps.\n\
+             \n\
+             Tags: vault, heist"
+        );
+    }
+
+    #[test]
+    fn entity_summary_skips_empty_details() {
+        let text = entity_summary(
+            "Marta",
+            "Mentor",
+            "Runs the inn.",
+            &[("Wants", "Her son home"), ("Fears", " ")],
+        );
+        assert_eq!(text, "Marta (Mentor)\nRuns the inn.\n\nWants: Her son home");
+        assert_eq!(entity_summary("The Crypt", "", "", &[]), "The Crypt");
+    }
+
+    #[test]
+    fn deep_links_join_origin_and_path() {
+        assert_eq!(
+            deep_link(Some("https://play.example.com/"), "/worlds/w1/watch"),
+            "https://play.example.com/worlds/w1/watch"
+        );
+        assert_eq!(deep_link(None, "/worlds/w1/watch"), "/worlds/w1/watch");
+    }
+}
@@ -0,0 +1,315 @@
+//! Sheet Export - Character sheets as printable HTML
+//!
+//! Renders a character's sheet (template sections, values, portrait,
+//! conditions and inventory) as a standalone HTML page with print styles.
+//! The page is saved as a file or handed to the platform to print, where
+//! the browser's "Save as PDF" covers PDF export.
+
+use std::collections::HashMap;
+
+use crate::application::dto::{
+    ActiveCondition, FieldType, FieldValue, InventoryItemData, SectionLayout, SheetField, SheetTemplate,
+};
+
+use super::conditions::condition_summary;
+use super::equipment::{equipment_bonus, sheet_values_with_equipment};
+
+/// Styles for the exported page, screen and print
+const SHEET_CSS: &str = "\
+body { font-family: Georgia, 'Times New Roman', serif; color: #111; max-width: 800px; margin: 24px auto; padding: 0 16px; }
+header { display: flex; gap: 16px; align-items: center; border-bottom: 2px solid #111; padding-bottom: 12px; }
+header img { width: 120px; height: 120px; object-fit: cover; border: 1px solid #999; border-radius: 4px; }
+h1 { margin: 0; font-size: 28px; }
+.template { margin: 4px 0 0; color: #555; font-size: 14px; }
+section { margin-top: 16px; break-inside: avoid; }
+h2 { font-size: 14px; text-transform: uppercase; letter-spacing: 0.05em; border-bottom: 1px solid #999; margin: 0 0 8px; padding-bottom: 2px; }
+.fields { display: grid; gap: 8px 16px; }
+.fields.vertical { grid-template-columns: 1fr; }
+.fields.two-column { grid-template-columns: 1fr 1fr; }
+.fields.flow { grid-template-columns: repeat(auto-fill, minmax(140px, 1fr)); }
+.field { display: flex; flex-direction: column; }
+.label { font-size: 11px; color: #555; }
+.value { font-size: 16px; font-weight: bold; }
+.note { font-size: 11px; color: #555; font-weight: normal; }
+ul { margin: 0; padding-left: 18px; }
+table { width: 100%; border-collapse: collapse; font-size: 13px; }
+th, td { text-align: left; padding: 3px 6px; border-bottom: 1px solid #ddd; }
+@media print { body { margin: 0; max-width: none; } @page { margin: 15mm; } }
+";
+
+/// A character's sheet, ready to export
+#[derive(Debug, Clone, PartialEq)]
+pub struct SheetExport {
+    pub character_name: String,
+    pub template: SheetTemplate,
+    /// Stored sheet values; equipment modifiers are folded in on render
+    pub values: HashMap<String, FieldValue>,
+    /// Portrait image URL, if the character has one
+    pub portrait_url: Option<String>,
+    pub inventory: Vec<InventoryItemData>,
+    pub conditions: Vec<ActiveCondition>,
+}
+
+impl SheetExport {
+    /// File name for the saved page, e.g. `mira-vale-sheet.html`
+    pub fn filename(&self) -> String {
+        let slug: String = self
+            .character_name
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '-' })
+            .collect::<String>()
+            .split('-')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        if slug.is_empty() {
+            "character-sheet.html".to_string()
+        } else {
+            format!("{}-sheet.html", slug)
+        }
+    }
+
+    /// The sheet as a standalone HTML page
+    ///
+    /// With `auto_print` the page opens the print dialog once it loads.
+    pub fn to_html(&self, auto_print: bool) -> String {
+        let values = sheet_values_with_equipment(&self.template, &self.values, &self.inventory);
+        let name = escape_html(&self.character_name);
+
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!("<title>{} - Character Sheet</title>\n", name));
+        html.push_str(&format!("<style>\n{}</style>\n", SHEET_CSS));
+        if auto_print {
+            html.push_str("<script>window.addEventListener('load', () => window.print());</script>\n");
+        }
+        html.push_str("</head>\n<body>\n<header>\n");
+        if let Some(url) = self.portrait_url.as_deref().filter(|u| !u.is_empty()) {
+            html.push_str(&format!("<img src=\"{}\" alt=\"{}\">\n", escape_html(url), name));
+        }
+        html.push_str(&format!(
+            "<div><h1>{}</h1><p class=\"template\">{}</p></div>\n</header>\n",
+            name,
+            escape_html(&self.template.name)
+        ));
+
+        if !self.conditions.is_empty() {
+            html.push_str("<section>\n<h2>Conditions</h2>\n<ul>\n");
+            for condition in &self.conditions {
+                html.push_str(&format!("<li>{}</li>\n", escape_html(&condition_summary(condition))));
+            }
+            html.push_str("</ul>\n</section>\n");
+        }
+
+        let mut sections: Vec<_> = self.template.sections.iter().collect();
+        sections.sort_by_key(|s| s.order);
+        for section in sections {
+            let fields_div = match section.layout {
+                SectionLayout::Vertical => "<div class=\"fields vertical\">".to_string(),
+                SectionLayout::Grid { columns } => format!(
+                    "<div class=\"fields\" style=\"grid-template-columns: repeat({}, 1fr)\">",
+                    columns.max(1)
+                ),
+                SectionLayout::Flow => "<div class=\"fields flow\">".to_string(),
+                SectionLayout::TwoColumn => "<div class=\"fields two-column\">".to_string(),
+            };
+            html.push_str(&format!("<section>\n<h2>{}</h2>\n{}\n", escape_html(&section.name), fields_div));
+            let mut fields: Vec<_> = section.fields.iter().collect();
+            fields.sort_by_key(|f| f.order);
+            for field in fields {
+                html.push_str(&self.field_html(field, values.get(&field.id)));
+            }
+            html.push_str("</div>\n</section>\n");
+        }
+
+        if !self.inventory.is_empty() {
+            html.push_str("<section>\n<h2>Inventory</h2>\n<table>\n");
+            html.push_str("<tr><th>Item</th><th>Type</th><th>Qty</th><th>Equipped</th></tr>\n");
+            for entry in &self.inventory {
+                let equipped = match (entry.equipped, entry.slot.as_deref()) {
+                    (true, Some(slot)) => escape_html(slot),
+                    (true, None) => "Yes".to_string(),
+                    (false, _) => String::new(),
+                };
+                html.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    escape_html(&entry.item.name),
+                    escape_html(entry.type_display()),
+                    entry.quantity,
+                    equipped
+                ));
+            }
+            html.push_str("</table>\n</section>\n");
+        }
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    fn field_html(&self, field: &SheetField, value: Option<&FieldValue>) -> String {
+        let mut note = String::new();
+        if matches!(field.field_type, FieldType::Number { .. }) {
+            let bonus = equipment_bonus(&self.inventory, &field.name) + equipment_bonus(&self.inventory, &field.id);
+            if bonus != 0 {
+                note = format!(" <span class=\"note\">({:+} gear)</span>", bonus);
+            }
+        }
+        if let FieldType::Derived { formula, .. } = &field.field_type {
+            if !formula.is_empty() {
+                note = format!(" <span class=\"note\">= {}</span>", escape_html(formula));
+            }
+        }
+        format!(
+            "<div class=\"field\"><span class=\"label\">{}</span><span class=\"value\">{}{}</span></div>\n",
+            escape_html(&field.name),
+            escape_html(&export_value(&field.field_type, value)),
+            note
+        )
+    }
+}
+
+/// A field's value as printed, falling back to the field's default
+fn export_value(field_type: &FieldType, value: Option<&FieldValue>) -> String {
+    match (field_type, value) {
+        (FieldType::Number { .. }, Some(FieldValue::Number(n))) => format!("{:+}", n),
+        (FieldType::Number { default, .. }, None) => format!("{:+}", default.unwrap_or(0)),
+        (FieldType::Checkbox { default }, None) => if *default { "Yes" } else { "No" }.to_string(),
+        (FieldType::Select { options }, Some(FieldValue::Text(s))) => options
+            .iter()
+            .find(|o| o.value == *s)
+            .map(|o| o.label.clone())
+            .unwrap_or_else(|| s.clone()),
+        (FieldType::Resource { .. }, Some(FieldValue::Resource { current, max })) => {
+            format!("{} / {}", current, max)
+        }
+        (_, Some(value)) => value.summary(),
+        (_, None) => "—".to_string(),
+    }
+}
+
+/// Escape text for HTML content and attribute values
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::world_snapshot::ItemModifier;
+    use crate::application::dto::{EntityStyle, ItemData, SheetSection};
+
+    fn field(id: &str, name: &str, field_type: FieldType) -> SheetField {
+        SheetField {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: None,
+            field_type,
+            required: false,
+            read_only: false,
+            order: 0,
+            vital: false,
+        }
+    }
+
+    fn export() -> SheetExport {
+        let template = SheetTemplate {
+            id: "t1".to_string(),
+            world_id: "w1".to_string(),
+            name: "Classic".to_string(),
+            description: String::new(),
+            variant: String::new(),
+            sections: vec![SheetSection {
+                id: "s1".to_string(),
+                name: "Skills".to_string(),
+                description: None,
+                fields: vec![
+                    field("stl", "Stealth", FieldType::Number { min: None, max: None, default: None }),
+                    field(
+                        "hp",
+                        "HP",
+                        FieldType::Resource { max_field: None, default_max: None },
+                    ),
+                ],
+                layout: SectionLayout::Vertical,
+                collapsible: false,
+                collapsed_by_default: false,
+                order: 0,
+            }],
+            is_default: false,
+        };
+        let cloak = InventoryItemData {
+            item: ItemData {
+                id: "cloak".to_string(),
+                world_id: "w1".to_string(),
+                name: "Cloak <of> Shadows".to_string(),
+                description: None,
+                item_type: Some("Armor".to_string()),
+                is_unique: false,
+                properties: None,
+                tags: Vec::new(),
+                modifiers: vec![ItemModifier { target: "Stealth".to_string(), value: 2 }],
+                style: EntityStyle::default(),
+                image_url: None,
+                weight: None,
+                value: None,
+                hidden_notes: None,
+                has_hidden_notes: false,
+                deleted_at: None,
+            },
+            quantity: 1,
+            equipped: true,
+            slot: Some("armor".to_string()),
+            acquired_at: String::new(),
+            acquisition_method: None,
+            acquired_from: None,
+            acquired_location: None,
+            identified: false,
+        };
+        SheetExport {
+            character_name: "Mira \"Fox\" Vale".to_string(),
+            template,
+            values: HashMap::from([
+                ("stl".to_string(), FieldValue::Number(3)),
+                ("hp".to_string(), FieldValue::Resource { current: 7, max: 12 }),
+            ]),
+            portrait_url: Some("https://example.com/mira.png".to_string()),
+            inventory: vec![cloak],
+            conditions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn sheet_includes_values_gear_bonuses_and_inventory() {
+        let html = export().to_html(false);
+        assert!(html.contains("<h1>Mira &quot;Fox&quot; Vale</h1>"));
+        assert!(html.contains("src=\"https://example.com/mira.png\""));
+        assert!(html.contains("+5 <span class=\"note\">(+2 gear)</span>"));
+        assert!(html.contains("7 / 12"));
+        assert!(html.contains("Cloak &lt;of&gt; Shadows"));
+        assert!(!html.contains("window.print"));
+    }
+
+    #[test]
+    fn print_pages_open_the_print_dialog() {
+        assert!(export().to_html(true).contains("window.print()"));
+    }
+
+    #[test]
+    fn filename_is_a_slug_of_the_character_name() {
+        assert_eq!(export().filename(), "mira-fox-vale-sheet.html");
+        let unnamed = SheetExport { character_name: "???".to_string(), ..export() };
+        assert_eq!(unnamed.filename(), "character-sheet.html");
+    }
+}
@@ -0,0 +1,354 @@
+//! Skill Import - Planning batch skill imports
+//!
+//! Skill sets come either from a bundled preset library or from a JSON file.
+//! Each imported entry is mapped onto a `SkillCategory` and checked against
+//! the world's existing skills; the result is a list of actions that
+//! `SkillService::import_skills` applies.
+
+use serde::Deserialize;
+
+use crate::application::dto::{SkillCategory, SkillData};
+
+/// A skill as described by a preset or an imported JSON file
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct SkillImportEntry {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Category as written in the source; mapped to a `SkillCategory` on import
+    #[serde(default)]
+    pub category: String,
+    #[serde(default)]
+    pub base_attribute: Option<String>,
+}
+
+impl SkillImportEntry {
+    fn new(name: &str, category: &str, base_attribute: Option<&str>, description: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            category: category.to_string(),
+            base_attribute: base_attribute.map(str::to_string),
+        }
+    }
+}
+
+/// Bundled skill libraries
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkillPreset {
+    Dnd5e,
+    FateCore,
+    CthulhuStyle,
+}
+
+impl SkillPreset {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Dnd5e, Self::FateCore, Self::CthulhuStyle]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Dnd5e => "D&D 5th Edition",
+            Self::FateCore => "FATE Core",
+            Self::CthulhuStyle => "Call of Cthulhu-style",
+        }
+    }
+
+    /// The skills in this preset
+    pub fn entries(&self) -> Vec<SkillImportEntry> {
+        let e = SkillImportEntry::new;
+        match self {
+            Self::Dnd5e => vec![
+                e("Acrobatics", "Physical", Some("DEX"), "Staying on your feet in tricky situations."),
+                e("Animal Handling", "Interpersonal", Some("WIS"), "Calming, controlling, or reading animals."),
+                e("Arcana", "Academic", Some("INT"), "Lore about spells, magic items, and the planes."),
+                e("Athletics", "Physical", Some("STR"), "Climbing, jumping, and swimming."),
+                e("Deception", "Social", Some("CHA"), "Convincingly hiding the truth."),
+                e("History", "Academic", Some("INT"), "Recalling lore about historical events and people."),
+                e("Insight", "Interpersonal", Some("WIS"), "Determining the true intentions of a creature."),
+                e("Intimidation", "Social", Some("CHA"), "Influencing others through threats."),
+                e("Investigation", "Investigation", Some("INT"), "Looking for clues and making deductions."),
+                e("Medicine", "Practical", Some("WIS"), "Stabilizing the dying and diagnosing illness."),
+                e("Nature", "Academic", Some("INT"), "Lore about terrain, plants, and animals."),
+                e("Perception", "Investigation", Some("WIS"), "Spotting, hearing, or otherwise noticing things."),
+                e("Performance", "Social", Some("CHA"), "Delighting an audience with music, dance, or story."),
+                e("Persuasion", "Social", Some("CHA"), "Influencing others with tact and good nature."),
+                e("Religion", "Academic", Some("INT"), "Lore about deities, rites, and holy symbols."),
+                e("Sleight of Hand", "Physical", Some("DEX"), "Manual trickery such as picking pockets."),
+                e("Stealth", "Physical", Some("DEX"), "Concealing yourself and moving quietly."),
+                e("Survival", "Practical", Some("WIS"), "Tracking, hunting, and navigating the wilds."),
+            ],
+            Self::FateCore => vec![
+                e("Athletics", "Physical", None, "Running, jumping, and dodging."),
+                e("Burglary", "Practical", None, "Getting past security and stealing things."),
+                e("Contacts", "Social", None, "Knowing people and finding the right ones."),
+                e("Crafts", "Practical", None, "Building, fixing, and breaking machinery."),
+                e("Deceive", "Social", None, "Lying to and misdirecting people."),
+                e("Drive", "Practical", None, "Operating vehicles under pressure."),
+                e("Empathy", "Interpersonal", None, "Reading people's moods and motives."),
+                e("Fight", "Combat", None, "Close-quarters combat."),
+                e("Investigate", "Investigation", None, "Deliberate, careful searching for clues."),
+                e("Lore", "Academic", None, "Knowledge and education."),
+                e("Notice", "Investigation", None, "Passive awareness of your surroundings."),
+                e("Physique", "Physical", None, "Raw strength and endurance."),
+                e("Provoke", "Social", None, "Getting a negative emotional reaction out of someone."),
+                e("Rapport", "Interpersonal", None, "Making connections and building trust."),
+                e("Resources", "Other", None, "Wealth and material goods."),
+                e("Shoot", "Combat", None, "Ranged combat."),
+                e("Stealth", "Physical", None, "Avoiding detection."),
+                e("Will", "Mental", None, "Mental fortitude and resistance to temptation."),
+            ],
+            Self::CthulhuStyle => vec![
+                e("Accounting", "Academic", None, "Understanding ledgers and financial records."),
+                e("Anthropology", "Academic", None, "Understanding other cultures by observation."),
+                e("Archaeology", "Academic", None, "Dating and identifying ancient artifacts."),
+                e("Charm", "Social", None, "Winning people over through flattery and warmth."),
+                e("Climb", "Physical", None, "Scaling walls, trees, and cliffs."),
+                e("Credit Rating", "Social", None, "Standing in society and access to money."),
+                e("Dodge", "Combat", None, "Getting out of the way of harm."),
+                e("Fast Talk", "Social", None, "Tricking someone into quick agreement."),
+                e("Fighting (Brawl)", "Combat", None, "Unarmed or improvised close combat."),
+                e("Firearms (Handgun)", "Combat", None, "Shooting pistols and revolvers."),
+                e("First Aid", "Practical", None, "Emergency treatment of injuries."),
+                e("History", "Academic", None, "Recalling the significance of places and events."),
+                e("Intimidate", "Social", None, "Frightening or coercing someone."),
+                e("Library Use", "Investigation", None, "Finding information in books and archives."),
+                e("Listen", "Investigation", None, "Hearing and interpreting sounds."),
+                e("Locksmith", "Practical", None, "Opening locks without the key."),
+                e("Occult", "Academic", None, "Knowledge of secret and magical traditions."),
+                e("Persuade", "Social", None, "Convincing someone through reasoned argument."),
+                e("Psychology", "Interpersonal", None, "Reading a person's motives and state of mind."),
+                e("Spot Hidden", "Investigation", None, "Noticing concealed things and clues."),
+                e("Stealth", "Physical", None, "Moving quietly and staying hidden."),
+                e("Swim", "Physical", None, "Staying afloat and moving through water."),
+            ],
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SkillImportFile {
+    List(Vec<SkillImportEntry>),
+    Wrapped { skills: Vec<SkillImportEntry> },
+}
+
+/// Parse a skills JSON file
+///
+/// Accepts either a bare array of skills or an object with a `skills` array.
+pub fn parse_skill_json(json: &str) -> Result<Vec<SkillImportEntry>, String> {
+    let file: SkillImportFile = serde_json::from_str(json)
+        .map_err(|e| format!("Invalid skills JSON: {}", e))?;
+    let entries = match file {
+        SkillImportFile::List(entries) | SkillImportFile::Wrapped { skills: entries } => entries,
+    };
+    let entries: Vec<SkillImportEntry> = entries
+        .into_iter()
+        .filter(|entry| !entry.name.trim().is_empty())
+        .collect();
+    if entries.is_empty() {
+        return Err("No skills found in the file".to_string());
+    }
+    Ok(entries)
+}
+
+/// Map a category name from an import source onto a `SkillCategory`
+///
+/// Matching ignores case and surrounding whitespace. Returns `None` for names
+/// that need an explicit mapping from the user.
+pub fn map_category(raw: &str) -> Option<SkillCategory> {
+    let raw = raw.trim();
+    SkillCategory::all()
+        .into_iter()
+        .find(|c| c.display_name().eq_ignore_ascii_case(raw))
+}
+
+/// What to do when an imported skill has the same name as an existing one
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// Keep the existing skill and drop the imported one
+    #[default]
+    Skip,
+    /// Replace the existing skill's details with the imported ones
+    Overwrite,
+    /// Import alongside the existing skill under a new name
+    Rename,
+}
+
+impl ConflictResolution {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Skip, Self::Overwrite, Self::Rename]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Skip => "Skip existing",
+            Self::Overwrite => "Overwrite existing",
+            Self::Rename => "Import as copy",
+        }
+    }
+}
+
+/// A single step of a planned import
+#[derive(Clone, Debug, PartialEq)]
+pub enum SkillImportAction {
+    Create {
+        name: String,
+        description: String,
+        category: SkillCategory,
+        base_attribute: Option<String>,
+    },
+    Update {
+        skill_id: String,
+        name: String,
+        description: String,
+        category: SkillCategory,
+        base_attribute: Option<String>,
+    },
+    Skip {
+        name: String,
+    },
+}
+
+/// Plan an import against the world's existing skills
+///
+/// Names are compared case-insensitively, both against existing skills and
+/// within the import itself (later duplicates are dropped). Categories are
+/// resolved with `category_override` first, then `map_category`, falling back
+/// to `SkillCategory::Custom`.
+pub fn plan_import(
+    existing: &[SkillData],
+    entries: &[SkillImportEntry],
+    resolution: ConflictResolution,
+    category_override: impl Fn(&str) -> Option<SkillCategory>,
+) -> Vec<SkillImportAction> {
+    let mut taken: Vec<String> = existing.iter().map(|s| s.name.to_lowercase()).collect();
+    let mut seen: Vec<String> = Vec::new();
+    let mut actions = Vec::new();
+
+    for entry in entries {
+        let name = entry.name.trim().to_string();
+        let key = name.to_lowercase();
+        if seen.contains(&key) {
+            continue;
+        }
+        seen.push(key.clone());
+
+        let category = category_override(&entry.category)
+            .or_else(|| map_category(&entry.category))
+            .unwrap_or(SkillCategory::Custom);
+        let description = entry.description.clone();
+        let base_attribute = entry.base_attribute.clone();
+
+        let conflict = existing.iter().find(|s| s.name.to_lowercase() == key);
+        let action = match (conflict, resolution) {
+            (None, _) => {
+                taken.push(key);
+                SkillImportAction::Create { name, description, category, base_attribute }
+            }
+            (Some(_), ConflictResolution::Skip) => SkillImportAction::Skip { name },
+            (Some(skill), ConflictResolution::Overwrite) => SkillImportAction::Update {
+                skill_id: skill.id.clone(),
+                name,
+                description,
+                category,
+                base_attribute,
+            },
+            (Some(_), ConflictResolution::Rename) => {
+                let renamed = unique_name(&name, &taken);
+                taken.push(renamed.to_lowercase());
+                SkillImportAction::Create { name: renamed, description, category, base_attribute }
+            }
+        };
+        actions.push(action);
+    }
+
+    actions
+}
+
+/// Category names in `entries` that `map_category` cannot resolve
+pub fn unmapped_categories(entries: &[SkillImportEntry]) -> Vec<String> {
+    let mut unmapped: Vec<String> = Vec::new();
+    for entry in entries {
+        let raw = entry.category.trim();
+        if map_category(raw).is_none() && !unmapped.iter().any(|c| c == raw) {
+            unmapped.push(raw.to_string());
+        }
+    }
+    unmapped
+}
+
+fn unique_name(name: &str, taken: &[String]) -> String {
+    (2..)
+        .map(|n| format!("{} ({})", name, n))
+        .find(|candidate| !taken.contains(&candidate.to_lowercase()))
+        .expect("unbounded range always yields a free name")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn existing(name: &str) -> SkillData {
+        SkillData {
+            id: format!("skill-{}", name.to_lowercase()),
+            world_id: "world-1".to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            category: SkillCategory::Physical,
+            base_attribute: None,
+            is_custom: false,
+            is_hidden: false,
+            order: 0,
+        }
+    }
+
+    #[test]
+    fn parse_accepts_array_and_wrapped_forms() {
+        let bare = r#"[{"name": "Climb", "category": "physical"}]"#;
+        let wrapped = r#"{"skills": [{"name": "Climb"}]}"#;
+        assert_eq!(parse_skill_json(bare).unwrap()[0].name, "Climb");
+        assert_eq!(parse_skill_json(wrapped).unwrap()[0].category, "");
+        assert!(parse_skill_json("[]").is_err());
+    }
+
+    #[test]
+    fn map_category_ignores_case() {
+        assert_eq!(map_category(" investigation "), Some(SkillCategory::Investigation));
+        assert_eq!(map_category("Cosmic"), None);
+    }
+
+    #[test]
+    fn plan_import_resolves_conflicts() {
+        let existing = vec![existing("Stealth")];
+        let entries = vec![
+            SkillImportEntry::new("stealth", "Physical", None, ""),
+            SkillImportEntry::new("Occult", "Cosmic", None, ""),
+        ];
+
+        let skipped = plan_import(&existing, &entries, ConflictResolution::Skip, |_| None);
+        assert!(matches!(skipped[0], SkillImportAction::Skip { .. }));
+        assert!(matches!(
+            skipped[1],
+            SkillImportAction::Create { category: SkillCategory::Custom, .. }
+        ));
+
+        let overwritten = plan_import(&existing, &entries, ConflictResolution::Overwrite, |_| None);
+        assert!(matches!(
+            &overwritten[0],
+            SkillImportAction::Update { skill_id, .. } if skill_id == "skill-stealth"
+        ));
+
+        let renamed = plan_import(&existing, &entries, ConflictResolution::Rename, |raw| {
+            (raw == "Cosmic").then_some(SkillCategory::Academic)
+        });
+        assert!(matches!(
+            &renamed[0],
+            SkillImportAction::Create { name, .. } if name == "stealth (2)"
+        ));
+        assert!(matches!(
+            renamed[1],
+            SkillImportAction::Create { category: SkillCategory::Academic, .. }
+        ));
+    }
+}
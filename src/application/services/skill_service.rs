@@ -7,6 +7,7 @@
 use serde::Serialize;
 
 use crate::application::dto::{SkillCategory, SkillData};
+use crate::application::services::skill_import::SkillImportAction;
 use crate::application::ports::outbound::{ApiError, ApiPort};
 
 /// Request to create a new skill
@@ -20,7 +21,7 @@ pub struct CreateSkillRequest {
 }
 
 /// Request to update a skill
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct UpdateSkillRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -32,6 +33,47 @@ pub struct UpdateSkillRequest {
     pub base_attribute: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<u32>,
+}
+
+/// Work out the order changes for dropping one skill onto another
+///
+/// The dragged skill takes the target's place within its category and the
+/// category's existing order values are handed out again in the new
+/// sequence, so other categories keep theirs. Returns only the skills whose
+/// `order` changed; dropping across categories changes nothing.
+pub fn plan_skill_reorder(skills: &[SkillData], dragged_id: &str, target_id: &str) -> Vec<(String, u32)> {
+    let (Some(dragged), Some(target)) = (
+        skills.iter().find(|s| s.id == dragged_id),
+        skills.iter().find(|s| s.id == target_id),
+    ) else {
+        return Vec::new();
+    };
+    if dragged.id == target.id || dragged.category != target.category {
+        return Vec::new();
+    }
+
+    let mut sequence: Vec<&SkillData> = skills.iter().filter(|s| s.category == dragged.category).collect();
+    sequence.sort_by_key(|s| s.order);
+    let mut slots: Vec<u32> = sequence.iter().map(|s| s.order).collect();
+    // Skills that share an order can't be told apart; number them instead
+    if slots.windows(2).any(|pair| pair[0] == pair[1]) {
+        let first = slots[0];
+        slots = (0..slots.len() as u32).map(|i| first + i).collect();
+    }
+
+    let from = sequence.iter().position(|s| s.id == dragged.id).unwrap_or_default();
+    let to = sequence.iter().position(|s| s.id == target.id).unwrap_or_default();
+    let moved = sequence.remove(from);
+    sequence.insert(to, moved);
+
+    sequence
+        .into_iter()
+        .zip(slots)
+        .filter(|(skill, order)| skill.order != *order)
+        .map(|(skill, order)| (skill.id.clone(), order))
+        .collect()
 }
 
 /// Skill service for managing skills
@@ -95,20 +137,73 @@ impl<A: ApiPort> SkillService<A> {
     ) -> Result<SkillData, ApiError> {
         let path = format!("/api/worlds/{}/skills/{}", world_id, skill_id);
         let request = UpdateSkillRequest {
-            name: None,
-            description: None,
-            category: None,
-            base_attribute: None,
             is_hidden: Some(is_hidden),
+            ..UpdateSkillRequest::default()
         };
         self.api.put(&path, &request).await
     }
 
+    /// Save new display orders, as planned by [`plan_skill_reorder`]
+    ///
+    /// Returns the updated skills; stops at the first failure.
+    pub async fn reorder_skills(
+        &self,
+        world_id: &str,
+        orders: &[(String, u32)],
+    ) -> Result<Vec<SkillData>, ApiError> {
+        let mut updated = Vec::with_capacity(orders.len());
+        for (skill_id, order) in orders {
+            let request = UpdateSkillRequest {
+                order: Some(*order),
+                ..UpdateSkillRequest::default()
+            };
+            updated.push(self.update_skill(world_id, skill_id, &request).await?);
+        }
+        Ok(updated)
+    }
+
     /// Delete a skill
     pub async fn delete_skill(&self, world_id: &str, skill_id: &str) -> Result<(), ApiError> {
         let path = format!("/api/worlds/{}/skills/{}", world_id, skill_id);
         self.api.delete(&path).await
     }
+
+    /// Apply a planned skill import
+    ///
+    /// Actions run in order and stop at the first failure. Returns the
+    /// created and updated skills.
+    pub async fn import_skills(
+        &self,
+        world_id: &str,
+        actions: &[SkillImportAction],
+    ) -> Result<Vec<SkillData>, ApiError> {
+        let mut imported = Vec::new();
+        for action in actions {
+            match action {
+                SkillImportAction::Create { name, description, category, base_attribute } => {
+                    let request = CreateSkillRequest {
+                        name: name.clone(),
+                        description: description.clone(),
+                        category: *category,
+                        base_attribute: base_attribute.clone(),
+                    };
+                    imported.push(self.create_skill(world_id, &request).await?);
+                }
+                SkillImportAction::Update { skill_id, name, description, category, base_attribute } => {
+                    let request = UpdateSkillRequest {
+                        name: Some(name.clone()),
+                        description: Some(description.clone()),
+                        category: Some(*category),
+                        base_attribute: base_attribute.clone(),
+                        ..UpdateSkillRequest::default()
+                    };
+                    imported.push(self.update_skill(world_id, skill_id, &request).await?);
+                }
+                SkillImportAction::Skip { .. } => {}
+            }
+        }
+        Ok(imported)
+    }
 }
 
 impl<A: ApiPort + Clone> Clone for SkillService<A> {
@@ -118,3 +213,79 @@ impl<A: ApiPort + Clone> Clone for SkillService<A> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::testing::MockApiPort;
+
+    fn skill(id: &str, category: SkillCategory, order: u32) -> SkillData {
+        SkillData {
+            id: id.to_string(),
+            world_id: "world-1".to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            category,
+            base_attribute: None,
+            is_custom: false,
+            is_hidden: false,
+            order,
+        }
+    }
+
+    #[test]
+    fn plan_skill_reorder_moves_within_category() {
+        let skills = vec![
+            skill("athletics", SkillCategory::Physical, 1),
+            skill("lore", SkillCategory::Mental, 2),
+            skill("stealth", SkillCategory::Physical, 3),
+            skill("climb", SkillCategory::Physical, 5),
+        ];
+
+        assert_eq!(
+            plan_skill_reorder(&skills, "climb", "athletics"),
+            vec![
+                ("climb".to_string(), 1),
+                ("athletics".to_string(), 3),
+                ("stealth".to_string(), 5),
+            ]
+        );
+        assert_eq!(
+            plan_skill_reorder(&skills, "athletics", "stealth"),
+            vec![("stealth".to_string(), 1), ("athletics".to_string(), 3)]
+        );
+        assert!(plan_skill_reorder(&skills, "lore", "athletics").is_empty());
+    }
+
+    #[test]
+    fn plan_skill_reorder_numbers_tied_orders() {
+        let skills = vec![
+            skill("a", SkillCategory::Custom, 0),
+            skill("b", SkillCategory::Custom, 0),
+            skill("c", SkillCategory::Custom, 0),
+        ];
+
+        assert_eq!(
+            plan_skill_reorder(&skills, "a", "c"),
+            vec![("c".to_string(), 1), ("a".to_string(), 2)]
+        );
+    }
+
+    #[tokio::test]
+    async fn reorder_skills_sends_only_the_order() {
+        let api = MockApiPort::new();
+        api.when_put_json(
+            "/api/worlds/world-1/skills/climb",
+            serde_json::to_value(skill("climb", SkillCategory::Physical, 1)).unwrap(),
+        );
+
+        let svc = SkillService::new(api.clone());
+        let updated = svc
+            .reorder_skills("world-1", &[("climb".to_string(), 1)])
+            .await
+            .expect("reorder");
+
+        assert_eq!(updated[0].order, 1);
+        assert_eq!(api.requests()[0].body, Some(serde_json::json!({ "order": 1 })));
+    }
+}
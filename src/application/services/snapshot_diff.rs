@@ -0,0 +1,223 @@
+//! Snapshot Diff - What changed between two session world snapshots
+//!
+//! When the Engine pushes a new world snapshot mid-session the DM reviews
+//! the differences before they reach game state. Entities are matched by
+//! ID; an entity present in both snapshots counts as modified when any of
+//! its fields differ.
+
+use crate::application::dto::SessionWorldSnapshot;
+
+/// Kind of world entity a change applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotEntityKind {
+    Character,
+    Location,
+    Scene,
+    Challenge,
+    Item,
+}
+
+impl SnapshotEntityKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Character => "Character",
+            Self::Location => "Location",
+            Self::Scene => "Scene",
+            Self::Challenge => "Challenge",
+            Self::Item => "Item",
+        }
+    }
+}
+
+/// How an entity changed between snapshots
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotChangeKind {
+    Added,
+    Modified,
+    Removed,
+}
+
+impl SnapshotChangeKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Added => "New",
+            Self::Modified => "Modified",
+            Self::Removed => "Removed",
+        }
+    }
+}
+
+/// One changed entity
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapshotChange {
+    pub entity: SnapshotEntityKind,
+    pub change: SnapshotChangeKind,
+    pub id: String,
+    /// Name from the new snapshot, or the old one for removals
+    pub name: String,
+}
+
+/// Every change between two snapshots, grouped by entity kind
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SnapshotDiff {
+    pub changes: Vec<SnapshotChange>,
+    /// Whether the world's own name or description changed
+    pub world_changed: bool,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && !self.world_changed
+    }
+
+    /// Total number of changes, counting the world itself as one
+    pub fn len(&self) -> usize {
+        self.changes.len() + usize::from(self.world_changed)
+    }
+
+    /// Changes to one kind of entity
+    pub fn changes_for(&self, entity: SnapshotEntityKind) -> Vec<&SnapshotChange> {
+        self.changes.iter().filter(|c| c.entity == entity).collect()
+    }
+
+    /// Number of changes of one kind (added, modified, removed)
+    pub fn count(&self, change: SnapshotChangeKind) -> usize {
+        self.changes.iter().filter(|c| c.change == change).count()
+    }
+}
+
+/// Diff the entities of a snapshot collection by ID
+fn diff_entities<T: PartialEq>(
+    entity: SnapshotEntityKind,
+    old: &[T],
+    new: &[T],
+    key: impl Fn(&T) -> (&str, &str),
+    changes: &mut Vec<SnapshotChange>,
+) {
+    let change = |change, item: &T| {
+        let (id, name) = key(item);
+        SnapshotChange { entity, change, id: id.to_string(), name: name.to_string() }
+    };
+    for item in new {
+        match old.iter().find(|o| key(o).0 == key(item).0) {
+            None => changes.push(change(SnapshotChangeKind::Added, item)),
+            Some(previous) if previous != item => changes.push(change(SnapshotChangeKind::Modified, item)),
+            Some(_) => {}
+        }
+    }
+    for item in old {
+        if !new.iter().any(|n| key(n).0 == key(item).0) {
+            changes.push(change(SnapshotChangeKind::Removed, item));
+        }
+    }
+}
+
+/// Compare the loaded snapshot with an incoming one
+pub fn diff_snapshots(old: &SessionWorldSnapshot, new: &SessionWorldSnapshot) -> SnapshotDiff {
+    let mut changes = Vec::new();
+    diff_entities(SnapshotEntityKind::Character, &old.characters, &new.characters, |c| (c.id.as_str(), c.name.as_str()), &mut changes);
+    diff_entities(SnapshotEntityKind::Location, &old.locations, &new.locations, |l| (l.id.as_str(), l.name.as_str()), &mut changes);
+    diff_entities(SnapshotEntityKind::Scene, &old.scenes, &new.scenes, |s| (s.id.as_str(), s.name.as_str()), &mut changes);
+    diff_entities(SnapshotEntityKind::Challenge, &old.challenges, &new.challenges, |c| (c.id.as_str(), c.name.as_str()), &mut changes);
+    diff_entities(SnapshotEntityKind::Item, &old.items, &new.items, |i| (i.id.as_str(), i.name.as_str()), &mut changes);
+
+    SnapshotDiff {
+        changes,
+        world_changed: old.world.name != new.world.name || old.world.description != new.world.description,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::EntityStyle;
+    use crate::application::dto::world_snapshot::{SessionCharacterData, SessionLocationData, SessionWorldData};
+
+    fn character(id: &str, name: &str) -> SessionCharacterData {
+        SessionCharacterData {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            archetype: "Mentor".to_string(),
+            sprite_asset: None,
+            portrait_asset: None,
+            is_alive: true,
+            is_active: true,
+            routine: Vec::new(),
+            style: EntityStyle::default(),
+            deleted_at: None,
+        }
+    }
+
+    fn location(id: &str, name: &str) -> SessionLocationData {
+        SessionLocationData {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            location_type: "Interior".to_string(),
+            backdrop_asset: None,
+            animated_backdrop_asset: None,
+            parent_id: None,
+            style: EntityStyle::default(),
+            deleted_at: None,
+        }
+    }
+
+    fn snapshot(characters: Vec<SessionCharacterData>, locations: Vec<SessionLocationData>) -> SessionWorldSnapshot {
+        SessionWorldSnapshot {
+            world: SessionWorldData {
+                id: "w-1".to_string(),
+                name: "Thornwood".to_string(),
+                description: String::new(),
+                rule_system: Default::default(),
+                created_at: String::new(),
+                updated_at: String::new(),
+            },
+            locations,
+            characters,
+            scenes: Vec::new(),
+            current_scene: None,
+            items: Vec::new(),
+            challenges: Vec::new(),
+            factions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn identical_snapshots_have_no_changes() {
+        let world = snapshot(vec![character("c-1", "Mira")], vec![location("l-1", "Inn")]);
+        assert!(diff_snapshots(&world, &world.clone()).is_empty());
+    }
+
+    #[test]
+    fn reports_added_modified_and_removed_entities() {
+        let old = snapshot(
+            vec![character("c-1", "Mira"), character("c-2", "Oskar")],
+            vec![location("l-1", "Inn")],
+        );
+        let mut dead_oskar = character("c-2", "Oskar");
+        dead_oskar.is_alive = false;
+        let mut new = snapshot(
+            vec![dead_oskar, character("c-3", "Vell")],
+            vec![location("l-1", "Inn"), location("l-2", "Docks")],
+        );
+        new.world.name = "Thornwood Reach".to_string();
+
+        let diff = diff_snapshots(&old, &new);
+
+        assert!(diff.world_changed);
+        let characters = diff.changes_for(SnapshotEntityKind::Character);
+        assert_eq!(
+            characters.iter().map(|c| (c.change, c.name.as_str())).collect::<Vec<_>>(),
+            vec![
+                (SnapshotChangeKind::Modified, "Oskar"),
+                (SnapshotChangeKind::Added, "Vell"),
+                (SnapshotChangeKind::Removed, "Mira"),
+            ]
+        );
+        let locations = diff.changes_for(SnapshotEntityKind::Location);
+        assert_eq!(locations.len(), 1);
+        assert_eq!(locations[0].id, "l-2");
+        assert_eq!(diff.count(SnapshotChangeKind::Added), 2);
+    }
+}
@@ -0,0 +1,426 @@
+//! Story Capture - Turning session activity into timeline story events
+//!
+//! The DM's client watches the session for moments worth keeping — challenge
+//! results, scene and region transitions, first meetings with NPCs, and
+//! quick markers — and queues a story event for each one the world's
+//! [`StoryCaptureRules`] allow. Faction standing changes are always queued. Queued events are sent to the Engine in
+//! batches; a batch that fails to send goes back on the queue.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::application::dto::{StandingLevel, StoryCaptureRules, StoryEventSourceData, StoryEventTypeData};
+use crate::application::services::CreateStoryEventRequest;
+
+/// Tag added to every automatically captured event
+pub const AUTO_CAPTURE_TAG: &str = "auto-captured";
+
+/// Where the session currently is, attached to each captured event
+#[derive(Debug, Clone, Default, PartialEq)]
+struct CaptureContext {
+    scene_id: Option<String>,
+    scene_name: Option<String>,
+    location_id: Option<String>,
+}
+
+/// A resolved challenge, as reported by the Engine
+///
+/// The result only names the character, so the name stands in for the ID.
+#[derive(Debug, Clone, Copy)]
+pub struct CapturedChallenge<'a> {
+    pub challenge_id: &'a str,
+    pub challenge_name: &'a str,
+    pub character_name: &'a str,
+    pub roll: i32,
+    pub modifier: i32,
+    pub outcome: &'a str,
+    pub outcome_description: &'a str,
+}
+
+/// The party's standing with a faction, before and after the DM changed it
+#[derive(Debug, Clone, Copy)]
+pub struct StandingChange<'a> {
+    pub faction_name: &'a str,
+    pub from: i32,
+    pub to: i32,
+    pub reason: &'a str,
+}
+
+/// Queue of captured story events waiting to be sent
+#[derive(Debug, Clone, Default)]
+pub struct StoryCapture {
+    rules: StoryCaptureRules,
+    pending: Vec<CreateStoryEventRequest>,
+    context: CaptureContext,
+    /// Current region of each player character, by PC ID
+    regions: HashMap<String, String>,
+    /// NPCs already met this session
+    met_npcs: HashSet<String>,
+}
+
+impl StoryCapture {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn rules(&self) -> &StoryCaptureRules {
+        &self.rules
+    }
+
+    /// Apply the world's capture rules to moments recorded from now on
+    pub fn set_rules(&mut self, rules: StoryCaptureRules) {
+        self.rules = rules;
+    }
+
+    /// Number of events waiting to be sent
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Take every queued event for sending
+    pub fn drain(&mut self) -> Vec<CreateStoryEventRequest> {
+        std::mem::take(&mut self.pending)
+    }
+
+    /// Put back events that failed to send, ahead of anything queued since
+    pub fn requeue(&mut self, mut events: Vec<CreateStoryEventRequest>) {
+        events.append(&mut self.pending);
+        self.pending = events;
+    }
+
+    /// Forget the session's progress, e.g. when leaving it
+    ///
+    /// Rules are kept; unsent events are dropped.
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.context = CaptureContext::default();
+        self.regions.clear();
+        self.met_npcs.clear();
+    }
+
+    /// A challenge was resolved
+    pub fn record_challenge_result(&mut self, result: &CapturedChallenge<'_>, now_unix_secs: u64) {
+        if !self.rules.challenge_results {
+            return;
+        }
+        let summary = if result.outcome_description.is_empty() {
+            format!("{} attempted {}: {}", result.character_name, result.challenge_name, result.outcome)
+        } else {
+            format!(
+                "{} attempted {}: {}. {}",
+                result.character_name, result.challenge_name, result.outcome, result.outcome_description
+            )
+        };
+        self.push(
+            StoryEventTypeData::ChallengeAttempted {
+                challenge_id: Some(result.challenge_id.to_string()),
+                challenge_name: result.challenge_name.to_string(),
+                character_id: result.character_name.to_string(),
+                skill_used: None,
+                difficulty: None,
+                roll_result: Some(result.roll),
+                modifier: Some(result.modifier),
+                outcome: result.outcome.to_string(),
+            },
+            summary,
+            Vec::new(),
+            Some(StoryEventSourceData::Challenge {
+                challenge_id: result.challenge_id.to_string(),
+                character_name: result.character_name.to_string(),
+                resolved_at: now_unix_secs,
+            }),
+            now_unix_secs,
+        );
+    }
+
+    /// The session's scene was set
+    ///
+    /// The first scene seen only sets the context, so rejoining a session
+    /// doesn't record a transition.
+    pub fn record_scene(&mut self, scene_id: &str, scene_name: &str, location_id: &str, now_unix_secs: u64) {
+        let previous = std::mem::replace(
+            &mut self.context,
+            CaptureContext {
+                scene_id: Some(scene_id.to_string()),
+                scene_name: Some(scene_name.to_string()),
+                location_id: Some(location_id.to_string()),
+            },
+        );
+        let Some(from_scene) = previous.scene_id else {
+            return;
+        };
+        if from_scene == scene_id || !self.rules.scene_transitions {
+            return;
+        }
+        let summary = match previous.scene_name.as_deref() {
+            Some(from_name) => format!("The scene moved from {} to {}", from_name, scene_name),
+            None => format!("The scene moved to {}", scene_name),
+        };
+        self.push(
+            StoryEventTypeData::SceneTransition {
+                from_scene: Some(from_scene),
+                to_scene: scene_id.to_string(),
+                from_scene_name: previous.scene_name,
+                to_scene_name: scene_name.to_string(),
+                trigger_reason: "Scene change during session".to_string(),
+            },
+            summary,
+            Vec::new(),
+            None,
+            now_unix_secs,
+        );
+    }
+
+    /// A player character arrived in a region
+    ///
+    /// As with scenes, a PC's first region only sets where they are.
+    pub fn record_region(
+        &mut self,
+        pc_id: &str,
+        region_id: &str,
+        region_name: &str,
+        location_id: &str,
+        location_name: &str,
+        now_unix_secs: u64,
+    ) {
+        self.context.location_id = Some(location_id.to_string());
+        let Some(from_region) = self.regions.insert(pc_id.to_string(), region_id.to_string()) else {
+            return;
+        };
+        if from_region == region_id || !self.rules.region_transitions {
+            return;
+        }
+        self.push(
+            StoryEventTypeData::LocationChange {
+                from_location: Some(from_region),
+                to_location: region_id.to_string(),
+                character_id: pc_id.to_string(),
+                travel_method: None,
+            },
+            format!("Entered {} ({})", region_name, location_name),
+            vec![pc_id.to_string()],
+            None,
+            now_unix_secs,
+        );
+    }
+
+    /// An NPC was present or approached the party
+    ///
+    /// Only the first sighting of each NPC in a session is recorded.
+    pub fn record_npc_seen(&mut self, npc_id: &str, npc_name: &str, now_unix_secs: u64) {
+        if !self.met_npcs.insert(npc_id.to_string()) || !self.rules.npc_first_meetings {
+            return;
+        }
+        let description = format!("The party met {}", npc_name);
+        self.push(
+            StoryEventTypeData::Custom {
+                event_subtype: "npc_first_meeting".to_string(),
+                title: format!("Met {}", npc_name),
+                description: description.clone(),
+            },
+            description,
+            vec![npc_id.to_string()],
+            None,
+            now_unix_secs,
+        );
+    }
+
+    /// The DM dropped a quick marker; returns whether it was queued
+    pub fn record_dm_marker(&mut self, title: &str, note: &str, now_unix_secs: u64) -> bool {
+        if !self.rules.dm_markers || title.trim().is_empty() {
+            return false;
+        }
+        self.push(
+            StoryEventTypeData::DmMarker {
+                title: title.trim().to_string(),
+                note: note.trim().to_string(),
+                importance: "normal".to_string(),
+                marker_type: "note".to_string(),
+            },
+            title.trim().to_string(),
+            Vec::new(),
+            None,
+            now_unix_secs,
+        );
+        true
+    }
+
+    /// The DM changed the party's standing with a faction
+    ///
+    /// Not subject to the capture rules: the timeline is the log of
+    /// standing changes.
+    pub fn record_faction_standing(&mut self, change: &StandingChange<'_>, now_unix_secs: u64) {
+        let level = StandingLevel::from_standing(change.to).display_name();
+        let mut description = format!(
+            "The party's standing with {} went from {} to {} ({})",
+            change.faction_name, change.from, change.to, level
+        );
+        if !change.reason.trim().is_empty() {
+            description = format!("{}: {}", description, change.reason.trim());
+        }
+        self.push(
+            StoryEventTypeData::Custom {
+                event_subtype: "faction_standing".to_string(),
+                title: format!("{} standing {:+}", change.faction_name, change.to - change.from),
+                description: description.clone(),
+            },
+            description,
+            Vec::new(),
+            None,
+            now_unix_secs,
+        );
+    }
+
+    fn push(
+        &mut self,
+        event_type: StoryEventTypeData,
+        summary: String,
+        involved_characters: Vec<String>,
+        source: Option<StoryEventSourceData>,
+        now_unix_secs: u64,
+    ) {
+        let occurred_at = chrono::DateTime::from_timestamp(now_unix_secs as i64, 0)
+            .unwrap_or_default()
+            .to_rfc3339();
+        self.pending.push(CreateStoryEventRequest {
+            event_type,
+            summary,
+            occurred_at,
+            scene_id: self.context.scene_id.clone(),
+            location_id: self.context.location_id.clone(),
+            involved_characters,
+            tags: vec![AUTO_CAPTURE_TAG.to_string()],
+            source,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-01-01T00:00:00Z
+    const NOW: u64 = 1_704_067_200;
+
+    #[test]
+    fn records_transitions_after_the_first_sighting() {
+        let mut capture = StoryCapture::new();
+        capture.set_rules(StoryCaptureRules {
+            region_transitions: true,
+            ..StoryCaptureRules::default()
+        });
+
+        capture.record_scene("s-1", "Tavern", "loc-1", NOW);
+        capture.record_scene("s-1", "Tavern", "loc-1", NOW);
+        capture.record_region("pc-1", "r-1", "Bar", "loc-1", "Tavern", NOW);
+        assert_eq!(capture.pending_count(), 0);
+
+        capture.record_scene("s-2", "Docks", "loc-2", NOW);
+        capture.record_region("pc-1", "r-2", "Pier", "loc-2", "Docks", NOW);
+
+        let events = capture.drain();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].summary, "The scene moved from Tavern to Docks");
+        assert_eq!(events[0].scene_id.as_deref(), Some("s-2"));
+        assert_eq!(events[0].occurred_at, "2024-01-01T00:00:00+00:00");
+        assert_eq!(events[1].involved_characters, vec!["pc-1".to_string()]);
+        assert_eq!(events[1].tags, vec![AUTO_CAPTURE_TAG.to_string()]);
+        assert_eq!(capture.pending_count(), 0);
+    }
+
+    #[test]
+    fn meets_each_npc_once_and_honours_rules() {
+        let mut capture = StoryCapture::new();
+        capture.record_npc_seen("npc-1", "Mira", NOW);
+        capture.record_npc_seen("npc-1", "Mira", NOW);
+        assert_eq!(capture.pending_count(), 1);
+
+        capture.set_rules(StoryCaptureRules {
+            challenge_results: false,
+            dm_markers: false,
+            ..StoryCaptureRules::default()
+        });
+        let result = CapturedChallenge {
+            challenge_id: "c-1",
+            challenge_name: "Pick Lock",
+            character_name: "Ada",
+            roll: 14,
+            modifier: 2,
+            outcome: "success",
+            outcome_description: "",
+        };
+        capture.record_challenge_result(&result, NOW);
+        assert!(!capture.record_dm_marker("Big reveal", "", NOW));
+        // Default rules leave region transitions off
+        capture.record_region("pc-1", "r-1", "Bar", "loc-1", "Tavern", NOW);
+        capture.record_region("pc-1", "r-2", "Pier", "loc-2", "Docks", NOW);
+        assert_eq!(capture.pending_count(), 1);
+    }
+
+    #[test]
+    fn challenge_results_link_back_to_the_roll() {
+        let mut capture = StoryCapture::new();
+        let result = CapturedChallenge {
+            challenge_id: "c-1",
+            challenge_name: "Pick Lock",
+            character_name: "Ada",
+            roll: 14,
+            modifier: 2,
+            outcome: "success",
+            outcome_description: "",
+        };
+        capture.record_challenge_result(&result, NOW);
+        capture.record_dm_marker("Aftermath", "", NOW);
+
+        let events = capture.drain();
+        assert_eq!(
+            events[0].source,
+            Some(StoryEventSourceData::Challenge {
+                challenge_id: "c-1".to_string(),
+                character_name: "Ada".to_string(),
+                resolved_at: NOW,
+            })
+        );
+        assert_eq!(events[1].source, None);
+    }
+
+    #[test]
+    fn standing_changes_are_logged_whatever_the_rules() {
+        let mut capture = StoryCapture::new();
+        capture.set_rules(StoryCaptureRules {
+            challenge_results: false,
+            scene_transitions: false,
+            region_transitions: false,
+            npc_first_meetings: false,
+            dm_markers: false,
+        });
+        let change = StandingChange {
+            faction_name: "Harbour Guild",
+            from: 10,
+            to: 25,
+            reason: " Recovered the stolen ledger ",
+        };
+        capture.record_faction_standing(&change, NOW);
+
+        let events = capture.drain();
+        assert_eq!(
+            events[0].summary,
+            "The party's standing with Harbour Guild went from 10 to 25 (Friendly): Recovered the stolen ledger"
+        );
+        assert!(matches!(
+            &events[0].event_type,
+            StoryEventTypeData::Custom { title, .. } if title == "Harbour Guild standing +15"
+        ));
+    }
+
+    #[test]
+    fn requeues_failed_batches_ahead_of_new_events() {
+        let mut capture = StoryCapture::new();
+        capture.record_dm_marker("First", "", NOW);
+        let failed = capture.drain();
+        capture.record_dm_marker("Second", "", NOW);
+
+        capture.requeue(failed);
+        let summaries: Vec<String> = capture.drain().into_iter().map(|e| e.summary).collect();
+        assert_eq!(summaries, vec!["First".to_string(), "Second".to_string()]);
+    }
+}
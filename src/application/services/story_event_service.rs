@@ -6,7 +6,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::application::dto::StoryEventData;
+use crate::application::dto::{StoryEventData, StoryEventSourceData, StoryEventTypeData};
 use crate::application::ports::outbound::{ApiError, ApiPort};
 
 /// Paginated response wrapper from Engine API
@@ -29,6 +29,31 @@ pub struct CreateDmMarkerRequest {
     pub tags: Vec<String>,
 }
 
+/// Request to create a story event, used for automatic capture
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateStoryEventRequest {
+    pub event_type: StoryEventTypeData,
+    pub summary: String,
+    /// RFC 3339 time the moment happened, which may be before it is sent
+    pub occurred_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scene_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub involved_characters: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Conversation or roll the moment came from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<StoryEventSourceData>,
+}
+
+#[derive(Serialize)]
+struct CreateStoryEventsBatch<'a> {
+    events: &'a [CreateStoryEventRequest],
+}
+
 /// Story event service for managing story events
 ///
 /// This service provides methods for story event-related operations
@@ -81,6 +106,22 @@ impl<A: ApiPort> StoryEventService<A> {
 
         self.api.post_no_response(&path, request).await
     }
+
+    /// Create several story events in one request
+    pub async fn create_story_events(
+        &self,
+        world_id: &str,
+        session_id: Option<&str>,
+        events: &[CreateStoryEventRequest],
+    ) -> Result<(), ApiError> {
+        let path = if let Some(sid) = session_id {
+            format!("/api/sessions/{}/story-events/batch", sid)
+        } else {
+            format!("/api/worlds/{}/story-events/batch", world_id)
+        };
+
+        self.api.post_no_response(&path, &CreateStoryEventsBatch { events }).await
+    }
 }
 
 impl<A: ApiPort + Clone> Clone for StoryEventService<A> {
@@ -90,3 +131,41 @@ impl<A: ApiPort + Clone> Clone for StoryEventService<A> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::testing::MockApiPort;
+
+    #[tokio::test]
+    async fn create_story_events_posts_one_batch_to_the_session() {
+        let api = MockApiPort::new();
+        api.when_post_no_response_ok("/api/sessions/s-1/story-events/batch");
+
+        let events = vec![CreateStoryEventRequest {
+            event_type: StoryEventTypeData::Custom {
+                event_subtype: "npc_first_meeting".to_string(),
+                title: "Met Mira".to_string(),
+                description: "The party met Mira".to_string(),
+            },
+            summary: "The party met Mira".to_string(),
+            occurred_at: "2024-01-01T00:00:00+00:00".to_string(),
+            scene_id: None,
+            location_id: Some("loc-1".to_string()),
+            involved_characters: vec!["npc-1".to_string()],
+            tags: vec![],
+            source: None,
+        }];
+        let svc = StoryEventService::new(api.clone());
+        svc.create_story_events("world-1", Some("s-1"), &events)
+            .await
+            .expect("batch");
+
+        let requests = api.requests();
+        assert_eq!(requests[0].path, "/api/sessions/s-1/story-events/batch");
+        let body = requests[0].body.clone().expect("body");
+        assert_eq!(body["events"][0]["event_type"]["type"], "custom");
+        assert_eq!(body["events"][0]["location_id"], "loc-1");
+        assert!(body["events"][0].get("tags").is_none());
+    }
+}
@@ -0,0 +1,138 @@
+//! Stream layout - Overlay presets for streaming the Spectator View
+//!
+//! Stream mode renders the spectator stage without any app chrome so it can
+//! be added to OBS (or any streaming tool) as a browser source. The preset
+//! and background come from the URL's query string, e.g.
+//! `/worlds/w1/watch/stream?layout=dialogue&chroma=green`, so a source can
+//! be set up once and left alone.
+
+/// Which parts of the stage a stream shows
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StreamLayout {
+    /// Backdrop, mood, drawings, characters, and dialogue
+    #[default]
+    Full,
+    /// Only the dialogue box
+    DialogueOnly,
+    /// Character portraits over the dialogue box, without the backdrop
+    DialoguePortraits,
+}
+
+impl StreamLayout {
+    pub fn all() -> Vec<Self> {
+        vec![Self::Full, Self::DialoguePortraits, Self::DialogueOnly]
+    }
+
+    /// Value of the `layout` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::DialogueOnly => "dialogue",
+            Self::DialoguePortraits => "portraits",
+        }
+    }
+
+    /// Reads a `layout` query parameter; unknown or missing values are `Full`
+    pub fn parse(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "dialogue" | "dialogue-only" => Self::DialogueOnly,
+            "portraits" | "dialogue-portraits" => Self::DialoguePortraits,
+            _ => Self::Full,
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Full => "Full scene",
+            Self::DialogueOnly => "Dialogue only",
+            Self::DialoguePortraits => "Dialogue + portraits",
+        }
+    }
+
+    pub fn shows_backdrop(&self) -> bool {
+        matches!(self, Self::Full)
+    }
+
+    pub fn shows_portraits(&self) -> bool {
+        !matches!(self, Self::DialogueOnly)
+    }
+}
+
+/// Named chroma-key colors offered in the stream setup
+pub const CHROMA_PRESETS: [(&str, &str); 3] = [("green", "#00b140"), ("blue", "#0047bb"), ("magenta", "#ff00ff")];
+
+/// CSS color for a `chroma` query parameter
+///
+/// Accepts a preset name or a hex color with or without the `#`. Anything
+/// else (including nothing) means a transparent background, which OBS
+/// browser sources composite without keying.
+pub fn chroma_color(value: &str) -> Option<String> {
+    let value = value.trim().to_ascii_lowercase();
+    if let Some((_, color)) = CHROMA_PRESETS.iter().find(|(name, _)| *name == value) {
+        return Some(color.to_string());
+    }
+    let hex = value.trim_start_matches('#');
+    let valid = matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit());
+    valid.then(|| format!("#{}", hex))
+}
+
+/// Stream mode settings, as read from the URL
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StreamSettings {
+    pub layout: StreamLayout,
+    /// Background color to key out; `None` is transparent
+    pub chroma: Option<String>,
+}
+
+impl StreamSettings {
+    pub fn from_query(layout: &str, chroma: &str) -> Self {
+        Self {
+            layout: StreamLayout::parse(layout),
+            chroma: chroma_color(chroma),
+        }
+    }
+
+    /// Query string for a stream URL, without the leading `?`
+    pub fn query_string(&self) -> String {
+        let mut query = format!("layout={}", self.layout.as_str());
+        if let Some(chroma) = &self.chroma {
+            query.push_str("&chroma=");
+            query.push_str(chroma.trim_start_matches('#'));
+        }
+        query
+    }
+
+    /// CSS background for the stream page
+    pub fn background(&self) -> String {
+        self.chroma.clone().unwrap_or_else(|| "transparent".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_parameters_pick_layout_and_chroma() {
+        let settings = StreamSettings::from_query("Dialogue", "green");
+        assert_eq!(settings.layout, StreamLayout::DialogueOnly);
+        assert_eq!(settings.chroma.as_deref(), Some("#00b140"));
+
+        assert_eq!(StreamSettings::from_query("", ""), StreamSettings::default());
+        assert_eq!(chroma_color("#FF8800").as_deref(), Some("#ff8800"));
+        assert_eq!(chroma_color("0f0").as_deref(), Some("#0f0"));
+        assert_eq!(chroma_color("chartreuse"), None);
+    }
+
+    #[test]
+    fn query_string_round_trips() {
+        for layout in StreamLayout::all() {
+            let settings = StreamSettings { layout, chroma: Some("#ff00ff".to_string()) };
+            let query = settings.query_string();
+            let mut params = query.split('&').map(|p| p.split_once('=').unwrap().1);
+            let parsed = StreamSettings::from_query(params.next().unwrap(), params.next().unwrap());
+            assert_eq!(parsed, settings);
+        }
+        assert_eq!(StreamSettings::default().query_string(), "layout=full");
+    }
+}
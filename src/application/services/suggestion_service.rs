@@ -41,6 +41,27 @@ pub struct SuggestionQueuedResponse {
     pub status: String,
 }
 
+/// A DM's verdict on a set of suggestions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SuggestionRating {
+    Up,
+    Down,
+}
+
+/// Feedback on one suggestion request, sent back so the Engine can tune its prompts
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SuggestionFeedback {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<SuggestionRating>,
+    /// Freeform comment from the DM
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub comment: String,
+    /// Index of the suggestion the DM put into the field, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_index: Option<usize>,
+}
+
 /// Suggestion service for fetching AI-powered content suggestions
 ///
 /// This service provides methods for suggestion-related operations
@@ -182,11 +203,15 @@ impl<A: ApiPort> SuggestionService<A> {
     /// Results will be delivered via WebSocket events.
     /// 
     /// The `world_id` is required for routing the response back to the correct
-    /// clients (all sessions viewing this world).
+    /// clients (all sessions viewing this world). Results are streamed, so
+    /// long text arrives as `SuggestionChunk` events before it completes.
+    /// `entity_id` ties the request to a saved entity, so the Engine's queue
+    /// snapshot can say which suggestion that entity's field took.
     pub async fn enqueue_suggestion(
         &self,
         field_type: &str,
         world_id: &str,
+        entity_id: Option<&str>,
         context: &SuggestionContext,
     ) -> Result<String, ApiError> {
         #[derive(Serialize)]
@@ -194,6 +219,9 @@ impl<A: ApiPort> SuggestionService<A> {
             #[serde(rename = "suggestion_type")]
             suggestion_type: String,
             world_id: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            entity_id: Option<String>,
+            stream: bool,
             #[serde(flatten)]
             context: SuggestionContext,
         }
@@ -201,6 +229,8 @@ impl<A: ApiPort> SuggestionService<A> {
         let request = UnifiedRequest {
             suggestion_type: field_type.to_string(),
             world_id: world_id.to_string(),
+            entity_id: entity_id.map(|id| id.to_string()),
+            stream: true,
             context: context.clone(),
         };
         
@@ -211,6 +241,17 @@ impl<A: ApiPort> SuggestionService<A> {
         Ok(response.request_id)
     }
 
+    /// Send feedback on a suggestion request's results
+    pub async fn send_feedback(
+        &self,
+        request_id: &str,
+        feedback: &SuggestionFeedback,
+    ) -> Result<(), ApiError> {
+        self.api
+            .post_no_response(&format!("/api/suggest/{}/feedback", request_id), feedback)
+            .await
+    }
+
     /// Cancel a pending suggestion request
     pub async fn cancel_suggestion(&self, request_id: &str) -> Result<(), ApiError> {
         self.api.delete(&format!("/api/suggest/{}/cancel", request_id)).await
@@ -224,3 +265,50 @@ impl<A: ApiPort + Clone> Clone for SuggestionService<A> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::testing::MockApiPort;
+
+    #[tokio::test]
+    async fn feedback_posts_only_what_was_given() {
+        let api = MockApiPort::new();
+        api.when_post_no_response_ok("/api/suggest/req-1/feedback");
+        let svc = SuggestionService::new(api.clone());
+
+        let feedback = SuggestionFeedback {
+            rating: Some(SuggestionRating::Down),
+            applied_index: Some(2),
+            ..Default::default()
+        };
+        svc.send_feedback("req-1", &feedback).await.expect("sent");
+
+        let reqs = api.requests();
+        assert_eq!(reqs[0].path, "/api/suggest/req-1/feedback");
+        assert_eq!(
+            reqs[0].body.as_ref().unwrap(),
+            &serde_json::json!({ "rating": "down", "applied_index": 2 })
+        );
+    }
+
+    #[tokio::test]
+    async fn enqueue_asks_for_a_streamed_result() {
+        let api = MockApiPort::new();
+        api.when_post_json(
+            "/api/suggest",
+            serde_json::json!({ "request_id": "req-2", "status": "queued" }),
+        );
+        let svc = SuggestionService::new(api.clone());
+
+        let context = SuggestionContext { entity_type: Some("character".to_string()), ..Default::default() };
+        let request_id = svc.enqueue_suggestion("character_backstory", "world-1", Some("char-1"), &context).await.expect("queued");
+
+        assert_eq!(request_id, "req-2");
+        let body = api.requests()[0].body.clone().unwrap();
+        assert_eq!(body["stream"], true);
+        assert_eq!(body["suggestion_type"], "character_backstory");
+        assert_eq!(body["entity_type"], "character");
+        assert_eq!(body["entity_id"], "char-1");
+    }
+}
@@ -0,0 +1,110 @@
+//! Template Variables - `{pc.name}`-style placeholders in authored text
+//!
+//! Challenge outcomes, narrative events and dialogue may reference the
+//! current game state with placeholders such as `{pc.name}`,
+//! `{location.name}` or `{skill.name}`. They're resolved when the text is
+//! shown, so the same authored line reads correctly for whoever triggers
+//! it. A placeholder is a dotted, lowercase name in braces; anything else
+//! in braces, and any variable with no value, is left as written.
+
+use std::collections::BTreeMap;
+
+/// Values available to placeholders, keyed by dotted name (`pc.name`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TemplateContext {
+    values: BTreeMap<String, String>,
+}
+
+impl TemplateContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a variable, ignoring empty values
+    pub fn set(&mut self, key: &str, value: impl Into<String>) {
+        let value = value.into();
+        if !value.is_empty() {
+            self.values.insert(key.to_string(), value);
+        }
+    }
+
+    /// Builder form of [`set`](Self::set)
+    pub fn with(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// Replace every known placeholder in `text`
+    pub fn resolve(&self, text: &str) -> String {
+        if !text.contains('{') {
+            return text.to_string();
+        }
+        let mut resolved = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(open) = rest.find('{') {
+            resolved.push_str(&rest[..open]);
+            let after = &rest[open + 1..];
+            match after.find('}') {
+                Some(close) if is_variable_name(&after[..close]) => {
+                    match self.get(&after[..close]) {
+                        Some(value) => resolved.push_str(value),
+                        None => resolved.push_str(&rest[open..open + close + 2]),
+                    }
+                    rest = &after[close + 1..];
+                }
+                _ => {
+                    resolved.push('{');
+                    rest = after;
+                }
+            }
+        }
+        resolved.push_str(rest);
+        resolved
+    }
+}
+
+/// Whether `name` looks like `scope.field`
+fn is_variable_name(name: &str) -> bool {
+    name.contains('.')
+        && name.split('.').all(|part| {
+            !part.is_empty() && part.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> TemplateContext {
+        TemplateContext::new()
+            .with("pc.name", "Mira")
+            .with("location.name", "the Rusty Anchor")
+    }
+
+    #[test]
+    fn replaces_known_variables() {
+        assert_eq!(
+            context().resolve("{pc.name} slips out of {location.name}."),
+            "Mira slips out of the Rusty Anchor."
+        );
+    }
+
+    #[test]
+    fn leaves_unknown_variables_and_other_braces_alone() {
+        assert_eq!(
+            context().resolve("{skill.name} check {not a variable} {pc.name}"),
+            "{skill.name} check {not a variable} Mira"
+        );
+        assert_eq!(context().resolve("unclosed {pc.name"), "unclosed {pc.name");
+    }
+
+    #[test]
+    fn empty_values_are_not_substituted() {
+        let ctx = TemplateContext::new().with("pc.name", "");
+        assert_eq!(ctx.resolve("{pc.name}"), "{pc.name}");
+    }
+}
@@ -0,0 +1,252 @@
+//! Trash Service - Restoring and purging soft-deleted entities
+//!
+//! Deleting a character, location, item, challenge, or narrative event moves
+//! it to its world's trash instead of removing it. The Engine keeps trashed
+//! entities for the world's retention period, then purges them. Until then
+//! they can be restored, or purged early.
+//!
+//! Trashed entities carry a `deleted_at` timestamp. List endpoints may still
+//! return them, so list calls drop them with [`without_trashed`] and pickers
+//! never offer them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::world_snapshot::{SessionCharacterData, SessionLocationData};
+use crate::application::dto::{ChallengeData, ItemData, NarrativeEventData, SessionWorldSnapshot};
+use crate::application::ports::outbound::{ApiError, ApiPort};
+use crate::application::services::{CharacterSummary, LocationSummary};
+
+/// Kinds of entity that can be trashed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashEntityKind {
+    Character,
+    Location,
+    Item,
+    Challenge,
+    NarrativeEvent,
+}
+
+impl TrashEntityKind {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Character,
+            Self::Location,
+            Self::Item,
+            Self::Challenge,
+            Self::NarrativeEvent,
+        ]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Character => "Character",
+            Self::Location => "Location",
+            Self::Item => "Item",
+            Self::Challenge => "Challenge",
+            Self::NarrativeEvent => "Narrative Event",
+        }
+    }
+
+    /// Path segment used by the trash endpoints
+    pub fn path_segment(&self) -> &'static str {
+        match self {
+            Self::Character => "characters",
+            Self::Location => "locations",
+            Self::Item => "items",
+            Self::Challenge => "challenges",
+            Self::NarrativeEvent => "narrative-events",
+        }
+    }
+}
+
+/// An entity in a world's trash
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct TrashedEntity {
+    pub id: String,
+    pub kind: TrashEntityKind,
+    pub name: String,
+    /// RFC 3339 time the entity was deleted
+    pub deleted_at: String,
+    /// RFC 3339 time the Engine will purge the entity
+    pub purge_at: String,
+}
+
+/// A world's trash
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct TrashListing {
+    /// Days a trashed entity is kept before it is purged
+    pub retention_days: u32,
+    #[serde(default)]
+    pub items: Vec<TrashedEntity>,
+}
+
+/// Whole days left before a trashed entity is purged, rounded up
+///
+/// Returns `None` if `purge_at` can't be parsed, and `Some(0)` once it has
+/// passed.
+pub fn days_until_purge(purge_at: &str, now_unix_secs: u64) -> Option<u64> {
+    let purge_at = chrono::DateTime::parse_from_rfc3339(purge_at).ok()?.timestamp();
+    let remaining = purge_at.saturating_sub(now_unix_secs as i64).max(0) as u64;
+    Some(remaining.div_ceil(24 * 60 * 60))
+}
+
+/// Entities that carry a soft-delete flag
+pub trait Trashable {
+    fn is_trashed(&self) -> bool;
+}
+
+impl Trashable for CharacterSummary {
+    fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+impl Trashable for LocationSummary {
+    fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+impl Trashable for ChallengeData {
+    fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+impl Trashable for NarrativeEventData {
+    fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+impl Trashable for SessionCharacterData {
+    fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+impl Trashable for SessionLocationData {
+    fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+impl Trashable for ItemData {
+    fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+/// Drop trashed entities from a list
+pub fn without_trashed<T: Trashable>(mut items: Vec<T>) -> Vec<T> {
+    items.retain(|item| !item.is_trashed());
+    items
+}
+
+/// Drop trashed entities from a world snapshot, so nothing built from the
+/// world (pickers, mentions, the stage) offers them
+pub fn strip_trashed(snapshot: &mut SessionWorldSnapshot) {
+    snapshot.characters.retain(|c| !c.is_trashed());
+    snapshot.locations.retain(|l| !l.is_trashed());
+    snapshot.items.retain(|i| !i.is_trashed());
+    snapshot.challenges.retain(|c| !c.is_trashed());
+}
+
+/// Trash service for restoring and purging deleted entities
+pub struct TrashService<A: ApiPort> {
+    api: A,
+}
+
+impl<A: ApiPort> TrashService<A> {
+    /// Create a new TrashService with the given API port
+    pub fn new(api: A) -> Self {
+        Self { api }
+    }
+
+    /// List a world's trash
+    pub async fn list_trash(&self, world_id: &str) -> Result<TrashListing, ApiError> {
+        let path = format!("/api/worlds/{}/trash", world_id);
+        self.api.get(&path).await
+    }
+
+    /// Restore a trashed entity
+    pub async fn restore(&self, world_id: &str, kind: TrashEntityKind, id: &str) -> Result<(), ApiError> {
+        let path = format!("/api/worlds/{}/trash/{}/{}/restore", world_id, kind.path_segment(), id);
+        self.api.post_empty(&path).await
+    }
+
+    /// Permanently delete a trashed entity
+    pub async fn purge(&self, world_id: &str, kind: TrashEntityKind, id: &str) -> Result<(), ApiError> {
+        let path = format!("/api/worlds/{}/trash/{}/{}", world_id, kind.path_segment(), id);
+        self.api.delete(&path).await
+    }
+
+    /// Permanently delete everything in a world's trash
+    pub async fn empty_trash(&self, world_id: &str) -> Result<(), ApiError> {
+        let path = format!("/api/worlds/{}/trash", world_id);
+        self.api.delete(&path).await
+    }
+}
+
+impl<A: ApiPort + Clone> Clone for TrashService<A> {
+    fn clone(&self) -> Self {
+        Self {
+            api: self.api.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::testing::MockApiPort;
+
+    #[test]
+    fn counts_days_until_purge() {
+        // 2024-01-01T00:00:00Z
+        let new_year = 1_704_067_200;
+        assert_eq!(days_until_purge("2024-01-31T00:00:00Z", new_year), Some(30));
+        assert_eq!(days_until_purge("2024-01-01T06:00:00Z", new_year), Some(1));
+        assert_eq!(days_until_purge("2023-12-31T00:00:00Z", new_year), Some(0));
+        assert_eq!(days_until_purge("soon", new_year), None);
+    }
+
+    #[test]
+    fn drops_trashed_entities() {
+        let summaries: Vec<CharacterSummary> = serde_json::from_value(serde_json::json!([
+            { "id": "a", "name": "Ada", "archetype": null },
+            { "id": "b", "name": "Bram", "archetype": null, "deleted_at": "2024-01-01T00:00:00Z" }
+        ]))
+        .expect("summaries");
+
+        let kept = without_trashed(summaries);
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].id, "a");
+    }
+
+    #[tokio::test]
+    async fn list_trash_reads_retention_and_items() {
+        let api = MockApiPort::new();
+        api.when_get_json(
+            "/api/worlds/world-1/trash",
+            serde_json::json!({
+                "retention_days": 30,
+                "items": [{
+                    "id": "ev-1",
+                    "kind": "narrative_event",
+                    "name": "The Heist",
+                    "deleted_at": "2024-01-01T00:00:00Z",
+                    "purge_at": "2024-01-31T00:00:00Z"
+                }]
+            }),
+        );
+
+        let svc = TrashService::new(api.clone());
+        let trash = svc.list_trash("world-1").await.expect("trash");
+
+        assert_eq!(trash.retention_days, 30);
+        assert_eq!(trash.items[0].kind, TrashEntityKind::NarrativeEvent);
+        assert_eq!(api.requests()[0].path, "/api/worlds/world-1/trash");
+    }
+}
@@ -0,0 +1,402 @@
+//! Tutorial World - A small pre-written world for learning the tools
+//!
+//! The tutorial world is a harbour tavern with a few NPCs, a handful of
+//! challenges and narrative events, created through the same endpoints the
+//! Creator uses so it behaves like any other world. Everything created is
+//! tagged [`TUTORIAL_TAG`], which makes it easy to find or clear out later.
+//!
+//! Creation is a sequence of requests; if one fails, the steps before it
+//! stay created and the error says how far it got.
+
+use std::fmt;
+
+use crate::application::dto::{
+    ChallengeData, ChallengeDifficulty, ChallengeOutcomes, ChallengeType, CreateNarrativeEventRequest, EntityStyle,
+    Outcome, SkillData,
+};
+use crate::application::ports::outbound::{ApiError, ApiPort};
+use crate::application::services::{
+    CharacterFormData, CharacterService, ChallengeService, LocationFormData, LocationService, NarrativeEventService,
+    SkillService, WorldService,
+};
+
+/// Tag on everything the tutorial creates
+pub const TUTORIAL_TAG: &str = "tutorial";
+
+const WORLD_NAME: &str = "Tutorial: The Crooked Lantern";
+const WORLD_DESCRIPTION: &str = "A small harbour town to learn the DM tools on. Safe to change or delete.";
+
+/// An NPC in the tutorial world
+struct SeedCharacter {
+    name: &'static str,
+    archetype: &'static str,
+    description: &'static str,
+    wants: &'static str,
+    fears: &'static str,
+}
+
+/// A challenge in the tutorial world
+struct SeedChallenge {
+    name: &'static str,
+    description: &'static str,
+    /// Skill names to look for, in order of preference
+    skills: &'static [&'static str],
+    dc: u32,
+    success: &'static str,
+    failure: &'static str,
+}
+
+/// A narrative event in the tutorial world
+struct SeedEvent {
+    name: &'static str,
+    description: &'static str,
+    scene_direction: &'static str,
+    suggested_opening: &'static str,
+}
+
+const LOCATION: (&str, &str, &str) = (
+    "The Crooked Lantern",
+    "A leaning dockside tavern where sailors, smugglers and the harbour watch all drink, if not together.",
+    "Smoky, loud, and one spilled drink away from a brawl",
+);
+
+const CHARACTERS: &[SeedCharacter] = &[
+    SeedCharacter {
+        name: "Mira Vell",
+        archetype: "Mentor",
+        description: "The tavern's owner, warm to regulars and sharp with everyone else.",
+        wants: "To pay off the debt she owes the harbour guild",
+        fears: "Losing the Lantern",
+    },
+    SeedCharacter {
+        name: "Tobin Reeve",
+        archetype: "Trickster",
+        description: "A grinning smuggler who always has a deal and never has the money.",
+        wants: "One big score to get out of town",
+        fears: "The harbour watch finding his cellar stash",
+    },
+    SeedCharacter {
+        name: "Captain Hale",
+        archetype: "Threshold Guardian",
+        description: "The watch captain, tired, honest, and watching Tobin very closely.",
+        wants: "Proof of the smuggling ring",
+        fears: "That someone in the watch is on the take",
+    },
+];
+
+const CHALLENGES: &[SeedChallenge] = &[
+    SeedChallenge {
+        name: "Calm the Brawl",
+        description: "Two sailors square up over a card game. Talk them down before the furniture suffers.",
+        skills: &["Persuasion", "Diplomacy", "Charm"],
+        dc: 12,
+        success: "The sailors laugh it off and buy a round. Mira nods her thanks.",
+        failure: "A chair flies. Mira is not pleased, and the watch will hear of it.",
+    },
+    SeedChallenge {
+        name: "Search the Cellar",
+        description: "Something behind the ale casks doesn't belong to the tavern.",
+        skills: &["Investigation", "Perception", "Spot Hidden", "Notice"],
+        dc: 14,
+        success: "A loose board hides Tobin's ledger of shipments.",
+        failure: "Nothing but dust and rats. Someone has been here recently, though.",
+    },
+    SeedChallenge {
+        name: "Read the Captain",
+        description: "Captain Hale asks what you saw. Is he asking for himself, or for someone else?",
+        skills: &["Insight", "Psychology", "Empathy"],
+        dc: 13,
+        success: "He's honest, and worried about his own people.",
+        failure: "His face gives nothing away.",
+    },
+];
+
+const EVENTS: &[SeedEvent] = &[
+    SeedEvent {
+        name: "A Stranger at the Door",
+        description: "A soaked messenger arrives looking for Mira with a guild summons.",
+        scene_direction: "The door bangs open and the room goes quiet. Give the players a reason to care who it's for.",
+        suggested_opening: "\"Mira Vell? The guild wants its money. Tonight.\"",
+    },
+    SeedEvent {
+        name: "The Lantern Goes Out",
+        description: "The great lantern over the door is shot out; in the dark, something is taken.",
+        scene_direction: "Plunge the scene into darkness for a moment, then reveal what's missing.",
+        suggested_opening: "With a crack and a shower of glass, the tavern goes dark.",
+    },
+];
+
+/// Where tutorial world creation has got to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TutorialProgress {
+    pub completed: usize,
+    pub total: usize,
+    /// What is being created now
+    pub label: String,
+}
+
+impl TutorialProgress {
+    pub fn percent(&self) -> usize {
+        self.completed * 100 / self.total.max(1)
+    }
+}
+
+/// Tutorial world creation stopped partway
+#[derive(Clone, Debug, PartialEq)]
+pub struct TutorialWorldError {
+    /// What was being created when it failed
+    pub failed_at: String,
+    pub error: ApiError,
+    /// The world, if it was created before the failure
+    pub world_id: Option<String>,
+}
+
+impl fmt::Display for TutorialWorldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stopped while creating {}: {}", self.failed_at, self.error)
+    }
+}
+
+/// Number of requests creating the tutorial world makes
+pub fn tutorial_step_count() -> usize {
+    // World, skill lookup, location, then each seeded entity
+    3 + CHARACTERS.len() + CHALLENGES.len() + EVENTS.len()
+}
+
+/// The world skill a tutorial challenge should use
+///
+/// Prefers the challenge's listed skills, then falls back to the first
+/// visible skill so the challenge exists for the DM to reassign.
+fn tutorial_skill<'a>(seed: &SeedChallenge, skills: &'a [SkillData]) -> Option<&'a SkillData> {
+    seed.skills
+        .iter()
+        .find_map(|name| skills.iter().find(|s| s.name.trim().eq_ignore_ascii_case(name)))
+        .or_else(|| skills.iter().find(|s| !s.is_hidden))
+}
+
+/// Service that creates the tutorial world through the other services
+pub struct TutorialWorldService<A: ApiPort> {
+    worlds: WorldService<A>,
+    skills: SkillService<A>,
+    locations: LocationService<A>,
+    characters: CharacterService<A>,
+    challenges: ChallengeService<A>,
+    events: NarrativeEventService<A>,
+}
+
+impl<A: ApiPort + Clone> TutorialWorldService<A> {
+    /// Create a new TutorialWorldService with the given API port
+    pub fn new(api: A) -> Self {
+        Self {
+            worlds: WorldService::new(api.clone()),
+            skills: SkillService::new(api.clone()),
+            locations: LocationService::new(api.clone()),
+            characters: CharacterService::new(api.clone()),
+            challenges: ChallengeService::new(api.clone()),
+            events: NarrativeEventService::new(api),
+        }
+    }
+
+    /// Create the tutorial world, reporting each step before it starts
+    ///
+    /// Returns the new world's ID. On failure the error names the step
+    /// that failed; anything created before it is left in place.
+    pub async fn create_tutorial_world(
+        &self,
+        mut on_progress: impl FnMut(TutorialProgress),
+    ) -> Result<String, TutorialWorldError> {
+        let total = tutorial_step_count();
+        let mut completed = 0;
+        let mut step = |label: String| {
+            on_progress(TutorialProgress { completed, total, label });
+            completed += 1;
+        };
+        let tags = vec![TUTORIAL_TAG.to_string()];
+
+        step("Creating the world".to_string());
+        let world_id = self
+            .worlds
+            .create_world(WORLD_NAME, Some(WORLD_DESCRIPTION), None)
+            .await
+            .map_err(|error| TutorialWorldError {
+                failed_at: "the world".to_string(),
+                error,
+                world_id: None,
+            })?;
+        let failed = |what: &str, error: ApiError| TutorialWorldError {
+            failed_at: what.to_string(),
+            error,
+            world_id: Some(world_id.clone()),
+        };
+
+        step("Reading the world's skills".to_string());
+        let skills = self.skills.list_skills(&world_id).await.map_err(|e| failed("the skill list", e))?;
+
+        let (name, description, atmosphere) = LOCATION;
+        step(format!("Creating {}", name));
+        let location = LocationFormData {
+            id: None,
+            name: name.to_string(),
+            description: Some(description.to_string()),
+            location_type: Some("Tavern".to_string()),
+            atmosphere: Some(atmosphere.to_string()),
+            notable_features: None,
+            hidden_secrets: Some("Tobin's stash is behind the ale casks in the cellar.".to_string()),
+            parent_location_id: None,
+            backdrop_asset: None,
+            animated_backdrop_asset: None,
+            backdrop_regions: Vec::new(),
+            tags: tags.clone(),
+            style: EntityStyle::default(),
+        };
+        self.locations
+            .create_location(&world_id, &location)
+            .await
+            .map_err(|e| failed(name, e))?;
+
+        for seed in CHARACTERS {
+            step(format!("Creating {}", seed.name));
+            let character = CharacterFormData {
+                id: None,
+                name: seed.name.to_string(),
+                description: Some(seed.description.to_string()),
+                archetype: Some(seed.archetype.to_string()),
+                wants: Some(seed.wants.to_string()),
+                fears: Some(seed.fears.to_string()),
+                backstory: None,
+                sprite_asset: None,
+                portrait_asset: None,
+                sheet_data: None,
+                tags: tags.clone(),
+                routine: Vec::new(),
+                style: EntityStyle::default(),
+            };
+            self.characters
+                .create_character(&world_id, &character)
+                .await
+                .map_err(|e| failed(seed.name, e))?;
+        }
+
+        for seed in CHALLENGES {
+            step(format!("Creating challenge {}", seed.name));
+            let Some(skill) = tutorial_skill(seed, &skills) else {
+                tracing::warn!("Tutorial world has no skills; skipping challenge {}", seed.name);
+                continue;
+            };
+            let challenge = ChallengeData {
+                id: uuid::Uuid::new_v4().to_string(),
+                world_id: world_id.clone(),
+                scene_id: None,
+                name: seed.name.to_string(),
+                description: seed.description.to_string(),
+                challenge_type: ChallengeType::SkillCheck,
+                skill_id: skill.id.clone(),
+                difficulty: ChallengeDifficulty::Dc { value: seed.dc },
+                outcomes: ChallengeOutcomes {
+                    success: Outcome { description: seed.success.to_string(), ..Default::default() },
+                    failure: Outcome { description: seed.failure.to_string(), ..Default::default() },
+                    ..Default::default()
+                },
+                trigger_conditions: Vec::new(),
+                prerequisite_challenges: Vec::new(),
+                active: true,
+                order: 0,
+                is_favorite: false,
+                tags: tags.clone(),
+                source_catalog: None,
+                deleted_at: None,
+            };
+            self.challenges
+                .create_challenge(&world_id, &challenge)
+                .await
+                .map_err(|e| failed(seed.name, e))?;
+        }
+
+        for seed in EVENTS {
+            step(format!("Creating event {}", seed.name));
+            let request = CreateNarrativeEventRequest {
+                name: seed.name.to_string(),
+                description: seed.description.to_string(),
+                scene_direction: seed.scene_direction.to_string(),
+                suggested_opening: Some(seed.suggested_opening.to_string()),
+                tags: tags.clone(),
+                ..Default::default()
+            };
+            self.events
+                .create_narrative_event(&world_id, request)
+                .await
+                .map_err(|e| failed(seed.name, e))?;
+        }
+
+        Ok(world_id)
+    }
+}
+
+impl<A: ApiPort + Clone> Clone for TutorialWorldService<A> {
+    fn clone(&self) -> Self {
+        Self {
+            worlds: self.worlds.clone(),
+            skills: self.skills.clone(),
+            locations: self.locations.clone(),
+            characters: self.characters.clone(),
+            challenges: self.challenges.clone(),
+            events: self.events.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::SkillCategory;
+    use crate::infrastructure::testing::MockApiPort;
+
+    fn skill(id: &str, name: &str, is_hidden: bool) -> SkillData {
+        SkillData {
+            id: id.to_string(),
+            world_id: "w1".to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            category: SkillCategory::Other,
+            base_attribute: None,
+            is_custom: false,
+            is_hidden,
+            order: 0,
+        }
+    }
+
+    #[test]
+    fn challenges_use_a_matching_skill_or_the_first_visible_one() {
+        let skills = vec![skill("s0", "Stealth", true), skill("s1", "Athletics", false), skill("s2", "persuasion", false)];
+        assert_eq!(tutorial_skill(&CHALLENGES[0], &skills).map(|s| s.id.as_str()), Some("s2"));
+        assert_eq!(tutorial_skill(&CHALLENGES[1], &skills).map(|s| s.id.as_str()), Some("s1"));
+        assert!(tutorial_skill(&CHALLENGES[1], &[]).is_none());
+    }
+
+    #[tokio::test]
+    async fn failure_names_the_step_and_keeps_earlier_ones() {
+        let api = MockApiPort::with_standing_responses();
+        api.when_post_json("/api/worlds", serde_json::json!({ "id": "w1", "name": WORLD_NAME }));
+        api.when_get_json("/api/worlds/w1/skills", serde_json::json!([]));
+        api.when_post_json("/api/worlds/w1/locations", serde_json::json!({ "id": "l1", "name": LOCATION.0 }));
+        // Characters are not configured, so the first one fails
+
+        let mut seen = Vec::new();
+        let svc = TutorialWorldService::new(api.clone());
+        let err = svc
+            .create_tutorial_world(|p| seen.push(p))
+            .await
+            .expect_err("character creation fails");
+
+        assert_eq!(err.failed_at, "Mira Vell");
+        assert_eq!(err.world_id.as_deref(), Some("w1"));
+        let labels: Vec<&str> = seen.iter().map(|p| p.label.as_str()).collect();
+        assert_eq!(labels.last(), Some(&"Creating Mira Vell"));
+        assert_eq!(seen[3].completed, 3);
+        assert_eq!(seen[3].total, tutorial_step_count());
+
+        let reqs = api.requests();
+        assert_eq!(reqs[0].path, "/api/worlds");
+        assert_eq!(reqs[2].body.as_ref().unwrap()["tags"], serde_json::json!([TUTORIAL_TAG]));
+    }
+}
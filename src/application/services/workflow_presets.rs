@@ -0,0 +1,77 @@
+//! Workflow Presets - Named ComfyUI workflow setups saved on this device
+//!
+//! A preset holds a slot's full export (workflow JSON, prompt mappings,
+//! defaults and locked inputs) so a new world's slots can be configured
+//! from a known-good setup instead of re-uploading and re-mapping.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::outbound::{storage_keys, Platform};
+
+use super::workflow_service::WorkflowExport;
+
+/// A saved workflow setup
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowPreset {
+    pub name: String,
+    /// Display name of the slot the preset was saved from
+    #[serde(default)]
+    pub source_slot: String,
+    pub workflow: WorkflowExport,
+}
+
+/// Add a preset, replacing any with the same name (case-insensitively)
+pub fn upsert_workflow_preset(presets: &mut Vec<WorkflowPreset>, preset: WorkflowPreset) {
+    match presets.iter_mut().find(|p| p.name.eq_ignore_ascii_case(&preset.name)) {
+        Some(existing) => *existing = preset,
+        None => presets.push(preset),
+    }
+}
+
+/// Workflow presets saved on this device
+pub fn load_workflow_presets(platform: &Platform) -> Vec<WorkflowPreset> {
+    platform
+        .storage_load(storage_keys::WORKFLOW_PRESETS)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Save workflow presets on this device
+pub fn save_workflow_presets(platform: &Platform, presets: &[WorkflowPreset]) {
+    match serde_json::to_string(presets) {
+        Ok(json) => platform.storage_save(storage_keys::WORKFLOW_PRESETS, &json),
+        Err(e) => platform.log_error(&format!("Failed to serialize workflow presets: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preset(name: &str, workflow_name: &str) -> WorkflowPreset {
+        WorkflowPreset {
+            name: name.to_string(),
+            source_slot: "Character Portrait".to_string(),
+            workflow: WorkflowExport {
+                name: workflow_name.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn upsert_replaces_by_name() {
+        let mut presets = vec![preset("SDXL Portraits", "v1")];
+        upsert_workflow_preset(&mut presets, preset("sdxl portraits", "v2"));
+        upsert_workflow_preset(&mut presets, preset("Flux Backdrops", "v1"));
+        assert_eq!(presets.len(), 2);
+        assert_eq!(presets[0].workflow.name, "v2");
+    }
+
+    #[test]
+    fn preset_round_trips_through_json() {
+        let json = serde_json::to_string(&preset("SDXL", "v1")).unwrap();
+        let parsed: WorkflowPreset = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, preset("SDXL", "v1"));
+    }
+}
@@ -121,6 +121,21 @@ pub struct TestWorkflowResponse {
     pub duration_ms: u64,
 }
 
+/// A slot's complete configuration in portable form
+///
+/// Used to clone one slot's setup into another and to save workflow presets.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowExport {
+    pub name: String,
+    pub workflow_json: serde_json::Value,
+    #[serde(default)]
+    pub prompt_mappings: Vec<PromptMapping>,
+    #[serde(default)]
+    pub input_defaults: Vec<InputDefault>,
+    #[serde(default)]
+    pub locked_inputs: Vec<String>,
+}
+
 /// Workflow service for managing ComfyUI workflows
 ///
 /// This service provides methods for workflow-related operations
@@ -238,6 +253,36 @@ impl<A: ApiPort> WorkflowService<A> {
         });
         self.api.patch(&path, &body).await
     }
+
+    /// Export a slot's workflow JSON, mappings and defaults
+    pub async fn export_workflow(&self, slot_id: &str) -> Result<WorkflowExport, ApiError> {
+        let path = format!("/api/workflows/{}/export", slot_id);
+        self.api.get(&path).await
+    }
+
+    /// Configure a slot from an exported workflow, replacing any existing config
+    pub async fn import_workflow(&self, slot_id: &str, export: &WorkflowExport) -> Result<(), ApiError> {
+        let prompt_mappings = export
+            .prompt_mappings
+            .iter()
+            .map(|m| serde_json::to_value(m).unwrap_or_default())
+            .collect();
+        self.save_workflow_config(
+            slot_id,
+            &export.name,
+            export.workflow_json.clone(),
+            prompt_mappings,
+            export.input_defaults.clone(),
+            export.locked_inputs.clone(),
+        )
+        .await
+    }
+
+    /// Copy one slot's configuration to another slot
+    pub async fn clone_workflow(&self, from_slot: &str, to_slot: &str) -> Result<(), ApiError> {
+        let export = self.export_workflow(from_slot).await?;
+        self.import_workflow(to_slot, &export).await
+    }
 }
 
 impl<A: ApiPort + Clone> Clone for WorkflowService<A> {
@@ -142,6 +142,14 @@ impl<A: ApiPort> WorldService<A> {
         self.api.get(&path).await
     }
 
+    /// List every tag in use across a world's entities
+    ///
+    /// Used to suggest existing tags in tag inputs and tag filters.
+    pub async fn list_tags(&self, world_id: &str) -> Result<Vec<String>, ApiError> {
+        let path = format!("/api/worlds/{}/tags", world_id);
+        self.api.get(&path).await
+    }
+
     /// List all active sessions across all worlds
     pub async fn list_sessions(&self) -> Result<Vec<SessionInfo>, ApiError> {
         self.api.get("/api/sessions").await
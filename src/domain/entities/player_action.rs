@@ -29,6 +29,8 @@ pub enum PlayerActionType {
     Custom,
     /// Select a dialogue choice
     DialogueChoice,
+    /// Share the result of a dice roll prompted by dialogue
+    Roll,
 }
 
 impl PlayerActionType {
@@ -41,6 +43,7 @@ impl PlayerActionType {
             PlayerActionType::Travel => "travel",
             PlayerActionType::Custom => "custom",
             PlayerActionType::DialogueChoice => "dialogue_choice",
+            PlayerActionType::Roll => "roll",
         }
     }
 }
@@ -119,6 +122,17 @@ impl PlayerAction {
         }
     }
 
+    /// Create a roll action; `notation` is the dice rolled and `result`
+    /// a readable breakdown of the outcome
+    pub fn roll(notation: &str, result: &str) -> Self {
+        Self {
+            action_type: PlayerActionType::Roll,
+            target: Some(notation.to_string()),
+            dialogue: Some(result.to_string()),
+            choice_id: None,
+        }
+    }
+
     /// Create a custom action targeting something specific
     pub fn custom_targeted(target: &str, text: &str) -> Self {
         Self {
@@ -3,6 +3,7 @@
 //! Immutable types that represent concepts in the domain.
 
 pub mod ids;
+pub mod tags;
 
 pub use ids::{
     LocationId, WorldId,
@@ -0,0 +1,66 @@
+//! Entity tag helpers
+//!
+//! Tags are free-form labels shared across characters, locations, items,
+//! narrative events and challenges. They are compared case-insensitively
+//! and stored trimmed, so "Villain" and " villain " are the same tag.
+
+/// Normalize user-entered tag text
+///
+/// Returns `None` for blank input.
+pub fn normalize_tag(raw: &str) -> Option<String> {
+    let trimmed = raw.trim().trim_start_matches('#').trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+/// Parse a comma-separated tag list, dropping blanks and duplicates
+pub fn parse_tag_list(raw: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in raw.split(',').filter_map(normalize_tag) {
+        if !contains_tag(&tags, &tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+/// Whether `tags` contains `tag` (case-insensitive)
+pub fn contains_tag(tags: &[String], tag: &str) -> bool {
+    tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+}
+
+/// Whether an entity's tags satisfy a tag filter
+///
+/// Every required tag must be present. An empty filter matches everything.
+pub fn matches_all_tags(entity_tags: &[String], required: &[String]) -> bool {
+    required.iter().all(|r| contains_tag(entity_tags, r))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_tag() {
+        assert_eq!(normalize_tag("  Villain "), Some("Villain".to_string()));
+        assert_eq!(normalize_tag("#quest"), Some("quest".to_string()));
+        assert_eq!(normalize_tag("   "), None);
+    }
+
+    #[test]
+    fn test_parse_tag_list_dedupes_case_insensitively() {
+        let tags = parse_tag_list("villain, Villain, ,act 2");
+        assert_eq!(tags, vec!["villain".to_string(), "act 2".to_string()]);
+    }
+
+    #[test]
+    fn test_matches_all_tags() {
+        let tags = vec!["Villain".to_string(), "Act 2".to_string()];
+        assert!(matches_all_tags(&tags, &[]));
+        assert!(matches_all_tags(&tags, &["villain".to_string()]));
+        assert!(!matches_all_tags(&tags, &["villain".to_string(), "act 3".to_string()]));
+    }
+}
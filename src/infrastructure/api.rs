@@ -1,8 +1,10 @@
 //! Centralized API configuration
 //!
-//! Provides the Engine API base URL for all HTTP requests.
+//! Provides the Engine API base URL and auth token for all HTTP requests.
 
-use crate::infrastructure::storage;
+use std::sync::RwLock;
+
+use crate::infrastructure::{api_cache, storage};
 
 /// Storage key for the Engine HTTP URL
 pub const STORAGE_KEY_ENGINE_URL: &str = "wrldbldr_engine_url";
@@ -10,6 +12,12 @@ pub const STORAGE_KEY_ENGINE_URL: &str = "wrldbldr_engine_url";
 /// Default Engine HTTP base URL
 pub const DEFAULT_ENGINE_HTTP_URL: &str = "http://localhost:3000";
 
+/// Active auth token (set after login, cleared on logout)
+///
+/// Kept in memory rather than read from storage on every request so that
+/// desktop builds (which have no persistent storage) can authenticate too.
+static AUTH_TOKEN: RwLock<Option<String>> = RwLock::new(None);
+
 /// Get the Engine HTTP base URL
 ///
 /// Tries to load from localStorage first, falls back to default.
@@ -26,8 +34,43 @@ pub fn get_engine_url() -> String {
 }
 
 /// Set the Engine HTTP base URL
+///
+/// Cached responses came from the old Engine, so they're dropped.
 pub fn set_engine_url(url: &str) {
     storage::save(STORAGE_KEY_ENGINE_URL, url);
+    api_cache::global().clear();
+}
+
+/// Set (or clear) the auth token attached to Engine requests
+///
+/// Cached responses were fetched as the previous user, so they're dropped.
+pub fn set_auth_token(token: Option<&str>) {
+    if let Ok(mut slot) = AUTH_TOKEN.write() {
+        *slot = token.map(|t| t.to_string());
+    }
+    api_cache::global().clear();
+}
+
+/// Get the active auth token, if logged in
+pub fn get_auth_token() -> Option<String> {
+    AUTH_TOKEN.read().ok().and_then(|t| t.clone())
+}
+
+/// Append the active auth token to a WebSocket URL
+///
+/// Browsers cannot set custom headers on the WebSocket handshake, so the
+/// token is passed as a `token` query parameter on both platforms.
+pub fn with_auth_query(ws_url: &str) -> String {
+    match get_auth_token() {
+        Some(token) => append_token_query(ws_url, &token),
+        None => ws_url.to_string(),
+    }
+}
+
+fn append_token_query(ws_url: &str, token: &str) -> String {
+    let separator = if ws_url.contains('?') { '&' } else { '?' };
+    let encoded: String = url::form_urlencoded::byte_serialize(token.as_bytes()).collect();
+    format!("{}{}token={}", ws_url, separator, encoded)
 }
 
 /// Convert WebSocket URL to HTTP URL
@@ -56,4 +99,16 @@ mod tests {
         assert_eq!(ws_to_http("wss://example.com/ws"), "https://example.com");
         assert_eq!(ws_to_http("ws://192.168.1.1:3000"), "http://192.168.1.1:3000");
     }
+
+    #[test]
+    fn test_append_token_query() {
+        assert_eq!(
+            append_token_query("ws://localhost:3000/ws", "abc"),
+            "ws://localhost:3000/ws?token=abc"
+        );
+        assert_eq!(
+            append_token_query("ws://localhost:3000/ws?x=1", "a b"),
+            "ws://localhost:3000/ws?x=1&token=a+b"
+        );
+    }
 }
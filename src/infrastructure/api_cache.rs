@@ -0,0 +1,248 @@
+//! Response cache for read-heavy Engine list endpoints
+//!
+//! Skill, challenge and character lists are fetched by several screens on
+//! every mount. `ApiAdapter` keeps their JSON here for a short time so a
+//! remount reuses the last response. Entries are dropped when they expire,
+//! when the Player writes to the same kind of resource, and when a
+//! WebSocket message says the Engine changed one.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use crate::application::dto::ServerMessage;
+
+/// How long a cached list stays fresh
+pub const CACHE_TTL_MS: u64 = 60_000;
+
+/// Kinds of resource whose lists are cached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheKind {
+    Skills,
+    Challenges,
+    Characters,
+    PlayerCharacters,
+}
+
+impl CacheKind {
+    const ALL: [CacheKind; 4] = [
+        CacheKind::Skills,
+        CacheKind::Challenges,
+        CacheKind::Characters,
+        CacheKind::PlayerCharacters,
+    ];
+
+    /// Kind named by one path segment
+    fn from_segment(segment: &str) -> Option<Self> {
+        match segment {
+            "skills" => Some(Self::Skills),
+            "challenges" | "challenge-catalogs" => Some(Self::Challenges),
+            "characters" => Some(Self::Characters),
+            "player-characters" => Some(Self::PlayerCharacters),
+            _ => None,
+        }
+    }
+
+    /// Kind of list a GET path returns, if it is one that gets cached
+    ///
+    /// Only collection endpoints are cached (`/api/worlds/{id}/skills`, with
+    /// or without page parameters), not single resources.
+    pub fn of_list(path: &str) -> Option<Self> {
+        let path = path.split('?').next().unwrap_or(path);
+        path.rsplit('/').next().and_then(|last| match last {
+            "challenge-catalogs" => None,
+            last => Self::from_segment(last),
+        })
+    }
+
+    /// Kinds a write to `path` may change
+    pub fn touched_by(path: &str) -> Vec<Self> {
+        let path = path.split('?').next().unwrap_or(path);
+        let mut kinds: Vec<Self> = Vec::new();
+        for kind in path.split('/').filter_map(Self::from_segment) {
+            if !kinds.contains(&kind) {
+                kinds.push(kind);
+            }
+        }
+        kinds
+    }
+
+    /// Kinds a server message says have changed on the Engine
+    pub fn changed_by(message: &ServerMessage) -> Vec<Self> {
+        match message {
+            ServerMessage::SessionJoined { .. } | ServerMessage::WorldSnapshotUpdated { .. } => {
+                Self::ALL.to_vec()
+            }
+            ServerMessage::AdHocChallengeCreated { .. } | ServerMessage::EncounterStarted { .. } => {
+                vec![Self::Challenges]
+            }
+            ServerMessage::GenerationComplete { .. } => vec![Self::Characters],
+            ServerMessage::PcSelected { .. }
+            | ServerMessage::AdvancementGranted { .. }
+            | ServerMessage::LevelUpApplied { .. }
+            | ServerMessage::PcSheetUpdated { .. } => vec![Self::PlayerCharacters],
+            _ => Vec::new(),
+        }
+    }
+}
+
+struct CachedResponse {
+    kind: CacheKind,
+    body: serde_json::Value,
+    fetched_at_ms: u64,
+}
+
+/// Cached list responses keyed by request path
+pub struct ResponseCache {
+    ttl_ms: u64,
+    entries: Mutex<HashMap<String, CachedResponse>>,
+}
+
+impl ResponseCache {
+    pub fn new(ttl_ms: u64) -> Self {
+        Self {
+            ttl_ms,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Cached body for `path` if it is still fresh at `now_ms`
+    pub fn get(&self, path: &str, now_ms: u64) -> Option<serde_json::Value> {
+        let mut entries = self.entries.lock().ok()?;
+        let fresh = entries
+            .get(path)
+            .is_some_and(|e| now_ms.saturating_sub(e.fetched_at_ms) < self.ttl_ms);
+        if fresh {
+            entries.get(path).map(|e| e.body.clone())
+        } else {
+            entries.remove(path);
+            None
+        }
+    }
+
+    /// Store a fetched body; paths that are not cached lists are ignored
+    pub fn insert(&self, path: &str, body: serde_json::Value, now_ms: u64) {
+        let Some(kind) = CacheKind::of_list(path) else {
+            return;
+        };
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(
+                path.to_string(),
+                CachedResponse {
+                    kind,
+                    body,
+                    fetched_at_ms: now_ms,
+                },
+            );
+        }
+    }
+
+    /// Drop every cached list of the given kinds
+    pub fn invalidate(&self, kinds: &[CacheKind]) {
+        if kinds.is_empty() {
+            return;
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|_, e| !kinds.contains(&e.kind));
+        }
+    }
+
+    /// Drop lists a server message says are out of date
+    pub fn invalidate_for_message(&self, message: &ServerMessage) {
+        self.invalidate(&CacheKind::changed_by(message));
+    }
+
+    /// Drop everything
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
+
+/// The process-wide cache used by `ApiAdapter`
+pub fn global() -> &'static ResponseCache {
+    static CACHE: OnceLock<ResponseCache> = OnceLock::new();
+    CACHE.get_or_init(|| ResponseCache::new(CACHE_TTL_MS))
+}
+
+/// Current time for cache freshness checks
+pub fn now_millis() -> u64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now() as u64
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        use std::time::{SystemTime, UNIX_EPOCH};
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_list_endpoints_are_cached() {
+        assert_eq!(CacheKind::of_list("/api/worlds/w1/skills"), Some(CacheKind::Skills));
+        assert_eq!(
+            CacheKind::of_list("/api/worlds/w1/characters?limit=50&cursor=abc"),
+            Some(CacheKind::Characters)
+        );
+        assert_eq!(
+            CacheKind::of_list("/api/sessions/s1/player-characters"),
+            Some(CacheKind::PlayerCharacters)
+        );
+        assert_eq!(CacheKind::of_list("/api/characters/c1"), None);
+        assert_eq!(CacheKind::of_list("/api/sessions/s1/player-characters/me"), None);
+        assert_eq!(CacheKind::of_list("/api/worlds/w1/challenge-catalogs"), None);
+    }
+
+    #[test]
+    fn writes_touch_every_kind_in_the_path() {
+        assert_eq!(CacheKind::touched_by("/api/challenges/c1/favorite"), vec![CacheKind::Challenges]);
+        assert_eq!(
+            CacheKind::touched_by("/api/worlds/w1/skills/s1"),
+            vec![CacheKind::Skills]
+        );
+        assert!(CacheKind::touched_by("/api/worlds/w1").is_empty());
+    }
+
+    #[test]
+    fn entries_expire_after_the_ttl() {
+        let cache = ResponseCache::new(1_000);
+        cache.insert("/api/worlds/w1/skills", serde_json::json!([1]), 5_000);
+        assert_eq!(cache.get("/api/worlds/w1/skills", 5_999), Some(serde_json::json!([1])));
+        assert_eq!(cache.get("/api/worlds/w1/skills", 6_000), None);
+    }
+
+    #[test]
+    fn invalidation_drops_only_matching_kinds() {
+        let cache = ResponseCache::new(CACHE_TTL_MS);
+        cache.insert("/api/worlds/w1/skills", serde_json::json!([]), 0);
+        cache.insert("/api/worlds/w1/challenges", serde_json::json!([]), 0);
+        cache.insert("/api/characters/c1", serde_json::json!({}), 0);
+
+        cache.invalidate(&CacheKind::touched_by("/api/challenges/c9"));
+        assert!(cache.get("/api/worlds/w1/challenges", 1).is_none());
+        assert!(cache.get("/api/worlds/w1/skills", 1).is_some());
+        assert!(cache.get("/api/characters/c1", 1).is_none());
+    }
+
+    #[test]
+    fn world_snapshot_updates_clear_every_kind() {
+        let cache = ResponseCache::new(CACHE_TTL_MS);
+        cache.insert("/api/worlds/w1/skills", serde_json::json!([]), 0);
+        cache.insert("/api/sessions/s1/player-characters", serde_json::json!([]), 0);
+
+        cache.invalidate_for_message(&ServerMessage::WorldSnapshotUpdated {
+            world_snapshot: serde_json::json!({}),
+        });
+        assert!(cache.get("/api/worlds/w1/skills", 1).is_none());
+        assert!(cache.get("/api/sessions/s1/player-characters", 1).is_none());
+    }
+}
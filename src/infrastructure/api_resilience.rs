@@ -0,0 +1,345 @@
+//! Retries and circuit breaking for Engine API requests
+//!
+//! `ApiAdapter` sends every request through here. Idempotent requests (GET,
+//! PUT, DELETE) that fail in a way that may not happen again are retried
+//! with exponential backoff. Each endpoint also has a circuit: after
+//! [`FAILURE_THRESHOLD`] failures in a row it opens and requests fail fast
+//! for [`OPEN_MS`], then a single trial request decides whether it closes
+//! again. Open circuits are reported to the UI through
+//! `ApiPort::open_circuits`.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Mutex, OnceLock};
+
+use futures_util::future::{select, Either};
+
+use crate::application::ports::outbound::{ApiError, OpenCircuit};
+
+/// How long a request may take before it counts as timed out
+pub const REQUEST_TIMEOUT_MS: u64 = 20_000;
+
+/// Consecutive failures that open an endpoint's circuit
+pub const FAILURE_THRESHOLD: u32 = 5;
+
+/// How long an open circuit rejects requests before trying one
+pub const OPEN_MS: u64 = 30_000;
+
+/// Backoff schedule for retried requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts in total, including the first
+    pub max_attempts: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+impl RetryPolicy {
+    /// Policy for idempotent requests
+    pub const IDEMPOTENT: Self = Self {
+        max_attempts: 3,
+        base_delay_ms: 300,
+        max_delay_ms: 3_000,
+    };
+
+    /// Policy for requests that must not be sent twice
+    pub const ONCE: Self = Self {
+        max_attempts: 1,
+        base_delay_ms: 0,
+        max_delay_ms: 0,
+    };
+
+    /// Delay before retry number `retry` (1 for the first retry)
+    pub fn delay_ms(&self, retry: u32) -> u64 {
+        let factor = 1u64 << retry.saturating_sub(1).min(16);
+        self.base_delay_ms.saturating_mul(factor).min(self.max_delay_ms)
+    }
+
+    /// Whether to send the request again after `attempt` attempts ended in `err`
+    pub fn should_retry(&self, attempt: u32, err: &ApiError) -> bool {
+        attempt < self.max_attempts && err.is_retryable()
+    }
+}
+
+/// Endpoint a request path belongs to, for its circuit
+///
+/// The query string is dropped and segments holding IDs (anything with a
+/// digit in it) become `*`, so `/api/worlds/w1/skills?limit=50` and
+/// `/api/worlds/w2/skills` share a circuit.
+pub fn endpoint_key(path: &str) -> String {
+    let path = path.split('?').next().unwrap_or(path);
+    path.split('/')
+        .map(|segment| if segment.chars().any(|c| c.is_ascii_digit()) { "*" } else { segment })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[derive(Debug, Default)]
+struct Circuit {
+    failures: u32,
+    opened_at_ms: Option<u64>,
+    /// When the trial request after the open period went out
+    ///
+    /// A trial that never reports back (its task was dropped) stops
+    /// blocking the endpoint once another open period has passed.
+    probe_started_ms: Option<u64>,
+}
+
+/// Per-endpoint circuits
+pub struct CircuitBreaker {
+    threshold: u32,
+    open_ms: u64,
+    circuits: Mutex<HashMap<String, Circuit>>,
+}
+
+impl CircuitBreaker {
+    pub fn new(threshold: u32, open_ms: u64) -> Self {
+        Self {
+            threshold,
+            open_ms,
+            circuits: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether a request to `endpoint` may go out at `now_ms`
+    ///
+    /// Once the open period has passed, one request is let through as a
+    /// trial; others keep failing fast until it comes back, or until the
+    /// open period passes again without an answer.
+    pub fn check(&self, endpoint: &str, now_ms: u64) -> Result<(), ApiError> {
+        let Ok(mut circuits) = self.circuits.lock() else {
+            return Ok(());
+        };
+        let Some(circuit) = circuits.get_mut(endpoint) else {
+            return Ok(());
+        };
+        let Some(opened_at) = circuit.opened_at_ms else {
+            return Ok(());
+        };
+        let waited_since = circuit.probe_started_ms.unwrap_or(opened_at);
+        let waited = now_ms.saturating_sub(waited_since);
+        if waited >= self.open_ms {
+            circuit.probe_started_ms = Some(now_ms);
+            return Ok(());
+        }
+        Err(ApiError::CircuitOpen {
+            endpoint: endpoint.to_string(),
+            retry_in_ms: self.open_ms.saturating_sub(waited),
+        })
+    }
+
+    /// Record how a request to `endpoint` ended
+    pub fn record(&self, endpoint: &str, result: Result<(), &ApiError>, now_ms: u64) {
+        let Ok(mut circuits) = self.circuits.lock() else {
+            return;
+        };
+        match result {
+            Err(err) if err.is_endpoint_failure() => {
+                let circuit = circuits.entry(endpoint.to_string()).or_default();
+                circuit.failures += 1;
+                if circuit.probe_started_ms.is_some() || circuit.failures >= self.threshold {
+                    circuit.opened_at_ms = Some(now_ms);
+                }
+                circuit.probe_started_ms = None;
+            }
+            // Anything the endpoint answered properly closes its circuit
+            _ => {
+                circuits.remove(endpoint);
+            }
+        }
+    }
+
+    /// Endpoints rejecting requests at `now_ms`
+    pub fn open_circuits(&self, now_ms: u64) -> Vec<OpenCircuit> {
+        let Ok(circuits) = self.circuits.lock() else {
+            return Vec::new();
+        };
+        let mut open: Vec<OpenCircuit> = circuits
+            .iter()
+            .filter_map(|(endpoint, circuit)| {
+                let opened_at = circuit.opened_at_ms?;
+                Some(OpenCircuit {
+                    endpoint: endpoint.clone(),
+                    retry_in_ms: self.open_ms.saturating_sub(now_ms.saturating_sub(opened_at)),
+                })
+            })
+            .collect();
+        open.sort_by(|a, b| a.endpoint.cmp(&b.endpoint));
+        open
+    }
+}
+
+/// The process-wide circuits used by `ApiAdapter`
+pub fn global() -> &'static CircuitBreaker {
+    static BREAKER: OnceLock<CircuitBreaker> = OnceLock::new();
+    BREAKER.get_or_init(|| CircuitBreaker::new(FAILURE_THRESHOLD, OPEN_MS))
+}
+
+/// Send a request with the endpoint's circuit, a timeout, and `policy`'s retries
+///
+/// `request` is called once per attempt.
+pub async fn send<T, F, Fut>(path: &str, policy: RetryPolicy, request: F) -> Result<T, ApiError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, ApiError>>,
+{
+    let breaker = global();
+    let endpoint = endpoint_key(path);
+    let mut attempt = 0;
+    loop {
+        breaker.check(&endpoint, now_millis())?;
+        attempt += 1;
+        let result = with_timeout(request()).await;
+        breaker.record(&endpoint, result.as_ref().map(|_| ()), now_millis());
+        match result {
+            Err(err) if policy.should_retry(attempt, &err) => {
+                tracing::debug!("Retrying {} after {:?} (attempt {})", path, err, attempt);
+                sleep_ms(policy.delay_ms(attempt)).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Send a DELETE with [`RetryPolicy::IDEMPOTENT`]
+///
+/// An attempt that timed out may still have deleted the resource, so a
+/// retry that finds it gone counts as success.
+pub async fn send_delete<F, Fut>(path: &str, request: F) -> Result<(), ApiError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<(), ApiError>>,
+{
+    let attempts = Cell::new(0u32);
+    let result = send(path, RetryPolicy::IDEMPOTENT, || {
+        attempts.set(attempts.get() + 1);
+        request()
+    })
+    .await;
+    match result {
+        Err(ApiError::NotFound(_)) if attempts.get() > 1 => Ok(()),
+        result => result,
+    }
+}
+
+/// `request`, or `ApiError::Timeout` if it takes longer than [`REQUEST_TIMEOUT_MS`]
+async fn with_timeout<T>(request: impl Future<Output = Result<T, ApiError>>) -> Result<T, ApiError> {
+    match select(Box::pin(request), Box::pin(sleep_ms(REQUEST_TIMEOUT_MS))).await {
+        Either::Left((result, _)) => result,
+        Either::Right(_) => Err(ApiError::Timeout),
+    }
+}
+
+async fn sleep_ms(ms: u64) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        gloo_timers::future::TimeoutFuture::new(ms as u32).await;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        tokio::time::sleep(std::time::Duration::from_millis(ms)).await;
+    }
+}
+
+fn now_millis() -> u64 {
+    crate::infrastructure::api_cache::now_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoints_group_paths_by_shape() {
+        assert_eq!(endpoint_key("/api/worlds/w1/skills?limit=50"), "/api/worlds/*/skills");
+        assert_eq!(endpoint_key("/api/worlds/w2/skills"), "/api/worlds/*/skills");
+        assert_eq!(endpoint_key("/api/worlds"), "/api/worlds");
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let policy = RetryPolicy::IDEMPOTENT;
+        assert_eq!(policy.delay_ms(1), 300);
+        assert_eq!(policy.delay_ms(2), 600);
+        assert_eq!(policy.delay_ms(10), 3_000);
+        assert!(policy.should_retry(1, &ApiError::Timeout));
+        assert!(!policy.should_retry(3, &ApiError::Timeout));
+        assert!(!policy.should_retry(1, &ApiError::from_status(422, "")));
+        assert!(!RetryPolicy::ONCE.should_retry(1, &ApiError::Timeout));
+    }
+
+    #[test]
+    fn circuit_opens_after_repeated_failures_and_probes_once() {
+        let breaker = CircuitBreaker::new(2, 1_000);
+        let down = ApiError::Network("refused".to_string());
+        let endpoint = "/api/worlds/*/skills";
+
+        breaker.record(endpoint, Err(&down), 0);
+        assert!(breaker.check(endpoint, 0).is_ok());
+        breaker.record(endpoint, Err(&down), 100);
+        assert!(matches!(
+            breaker.check(endpoint, 600),
+            Err(ApiError::CircuitOpen { retry_in_ms: 500, .. })
+        ));
+        assert_eq!(breaker.open_circuits(600).len(), 1);
+
+        // One trial after the open period; a failed trial reopens it
+        assert!(breaker.check(endpoint, 1_100).is_ok());
+        assert!(breaker.check(endpoint, 1_100).is_err());
+        breaker.record(endpoint, Err(&down), 1_200);
+        assert!(breaker.check(endpoint, 1_300).is_err());
+
+        // A successful trial closes it
+        assert!(breaker.check(endpoint, 2_200).is_ok());
+        breaker.record(endpoint, Ok(()), 2_300);
+        assert!(breaker.check(endpoint, 2_300).is_ok());
+        assert!(breaker.open_circuits(2_300).is_empty());
+    }
+
+    #[test]
+    fn abandoned_trial_stops_blocking_after_another_open_period() {
+        let breaker = CircuitBreaker::new(1, 1_000);
+        let endpoint = "/api/worlds/*/skills";
+        breaker.record(endpoint, Err(&ApiError::Network("refused".to_string())), 0);
+
+        // The trial goes out and its task is dropped before it records
+        assert!(breaker.check(endpoint, 1_000).is_ok());
+        assert!(matches!(
+            breaker.check(endpoint, 1_400),
+            Err(ApiError::CircuitOpen { retry_in_ms: 600, .. })
+        ));
+        assert!(breaker.check(endpoint, 2_000).is_ok());
+        assert!(breaker.check(endpoint, 2_000).is_err());
+    }
+
+    #[tokio::test]
+    async fn delete_retried_into_a_404_succeeds() {
+        let attempts = Cell::new(0u32);
+        let result = send_delete("/api/test-delete-retry/x1", || {
+            attempts.set(attempts.get() + 1);
+            let first = attempts.get() == 1;
+            async move {
+                if first {
+                    Err(ApiError::Timeout)
+                } else {
+                    Err(ApiError::NotFound(String::new()))
+                }
+            }
+        })
+        .await;
+        assert_eq!(result, Ok(()));
+        assert_eq!(attempts.get(), 2);
+
+        let missing = send_delete("/api/test-delete-missing/x1", || async { Err(ApiError::NotFound(String::new())) }).await;
+        assert!(matches!(missing, Err(ApiError::NotFound(_))));
+    }
+
+    #[test]
+    fn rejected_requests_do_not_count_against_the_endpoint() {
+        let breaker = CircuitBreaker::new(1, 1_000);
+        breaker.record("/api/x", Err(&ApiError::from_status(422, "")), 0);
+        assert!(breaker.check("/api/x", 0).is_ok());
+    }
+}
@@ -6,6 +6,9 @@
 
 use std::sync::Arc;
 use crate::application::ports::outbound::GameConnectionPort;
+use crate::application::services::DEMO_ENGINE_URL;
+use super::api::with_auth_query;
+use super::demo;
 use super::websocket::{EngineClient, EngineGameConnection};
 
 /// Factory for creating game connections
@@ -17,10 +20,18 @@ impl ConnectionFactory {
     /// # Arguments
     /// * `server_url` - The WebSocket URL of the Engine server
     ///
+    /// If the user is logged in, the auth token is attached to the handshake URL.
+    /// `DEMO_ENGINE_URL` starts a local demo session instead; any other URL
+    /// ends one.
+    ///
     /// # Returns
     /// An Arc-wrapped connection that implements GameConnectionPort
     pub fn create_game_connection(server_url: &str) -> Arc<dyn GameConnectionPort> {
-        let client = EngineClient::new(server_url);
+        if server_url == DEMO_ENGINE_URL {
+            return demo::start();
+        }
+        demo::stop();
+        let client = EngineClient::new(with_auth_query(server_url));
         Arc::new(EngineGameConnection::new(client))
     }
 }
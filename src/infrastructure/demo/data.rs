@@ -0,0 +1,163 @@
+//! Demo data pack - the world the demo session is played in
+//!
+//! One tavern scene with two NPCs and a ready-made player character, plus
+//! the handful of REST responses the PC and DM views ask for on load.
+
+use crate::application::dto::{
+    CharacterPosition, EntityStyle, InteractionData, RuleSystemConfig, SceneCharacterState, SceneSnapshot, SessionWorldSnapshot,
+};
+use crate::application::dto::world_snapshot::{SessionCharacterData, SessionLocationData, SessionSceneData, SessionWorldData};
+use crate::application::services::{PlayerCharacterData, DEMO_WORLD_ID};
+use crate::infrastructure::testing::MockApiPort;
+
+pub const DEMO_SESSION_ID: &str = "demo-session";
+pub const DEMO_PC_ID: &str = "demo-pc";
+pub const INNKEEPER_ID: &str = "demo-npc-innkeeper";
+pub const STRANGER_ID: &str = "demo-npc-stranger";
+
+const LOCATION_ID: &str = "demo-location-tavern";
+const SCENE_ID: &str = "demo-scene-tavern";
+const CREATED_AT: &str = "2024-01-01T00:00:00Z";
+
+/// World data sent with `SessionJoined`
+pub fn world_snapshot() -> SessionWorldSnapshot {
+    let scene = SessionSceneData {
+        id: SCENE_ID.to_string(),
+        name: "A Quiet Night at the Gilded Flagon".to_string(),
+        location_id: LOCATION_ID.to_string(),
+        time_context: "Late evening".to_string(),
+        backdrop_override: None,
+        featured_characters: vec![INNKEEPER_ID.to_string(), STRANGER_ID.to_string()],
+        directorial_notes: "Rain outside. Marta wants the stranger gone before trouble starts.".to_string(),
+    };
+
+    SessionWorldSnapshot {
+        world: SessionWorldData {
+            id: DEMO_WORLD_ID.to_string(),
+            name: "Demo: The Gilded Flagon".to_string(),
+            description: "A small tavern at the edge of a rain-soaked market town.".to_string(),
+            rule_system: RuleSystemConfig::default(),
+            created_at: CREATED_AT.to_string(),
+            updated_at: CREATED_AT.to_string(),
+        },
+        locations: vec![SessionLocationData {
+            id: LOCATION_ID.to_string(),
+            name: "The Gilded Flagon".to_string(),
+            description: "Low beams, a crackling hearth and a bar polished by years of elbows.".to_string(),
+            location_type: "Interior".to_string(),
+            backdrop_asset: None,
+            animated_backdrop_asset: None,
+            parent_id: None,
+            style: EntityStyle::default(),
+            deleted_at: None,
+        }],
+        characters: vec![
+            npc(INNKEEPER_ID, "Marta", "The innkeeper. Warm, shrewd, and protective of her regulars.", "Merchant"),
+            npc(STRANGER_ID, "The Stranger", "A hooded traveller nursing one drink all night.", "Mysterious Figure"),
+        ],
+        scenes: vec![scene.clone()],
+        current_scene: Some(scene),
+        items: Vec::new(),
+        challenges: Vec::new(),
+        factions: Vec::new(),
+    }
+}
+
+fn npc(id: &str, name: &str, description: &str, archetype: &str) -> SessionCharacterData {
+    SessionCharacterData {
+        id: id.to_string(),
+        name: name.to_string(),
+        description: description.to_string(),
+        archetype: archetype.to_string(),
+        sprite_asset: None,
+        portrait_asset: None,
+        is_alive: true,
+        is_active: true,
+        routine: Vec::new(),
+        style: EntityStyle::default(),
+        deleted_at: None,
+    }
+}
+
+/// The scene on stage when the demo starts
+pub fn opening_scene() -> (SceneSnapshot, Vec<SceneCharacterState>, Vec<InteractionData>) {
+    let scene = SceneSnapshot {
+        id: SCENE_ID.to_string(),
+        name: "A Quiet Night at the Gilded Flagon".to_string(),
+        location_id: LOCATION_ID.to_string(),
+        location_name: "The Gilded Flagon".to_string(),
+        backdrop_asset: None,
+        time_context: "Late evening".to_string(),
+        directorial_notes: String::new(),
+    };
+    let characters = vec![
+        on_stage(INNKEEPER_ID, "Marta", CharacterPosition::Left, Some(0.4)),
+        on_stage(STRANGER_ID, "The Stranger", CharacterPosition::Right, Some(-0.2)),
+    ];
+    let interactions = vec![
+        talk_to(INNKEEPER_ID, "Marta"),
+        talk_to(STRANGER_ID, "The Stranger"),
+    ];
+    (scene, characters, interactions)
+}
+
+fn on_stage(id: &str, name: &str, position: CharacterPosition, sentiment: Option<f32>) -> SceneCharacterState {
+    SceneCharacterState {
+        id: id.to_string(),
+        name: name.to_string(),
+        sprite_asset: None,
+        portrait_asset: None,
+        position,
+        is_speaking: false,
+        emotion: String::new(),
+        sentiment,
+        disposition_hidden: false,
+        style: EntityStyle::default(),
+    }
+}
+
+fn talk_to(id: &str, name: &str) -> InteractionData {
+    InteractionData {
+        id: format!("talk-{}", id),
+        name: format!("Talk to {}", name),
+        interaction_type: "talk".to_string(),
+        target_name: Some(name.to_string()),
+        is_available: true,
+    }
+}
+
+/// The player's character in the demo
+pub fn player_character() -> PlayerCharacterData {
+    PlayerCharacterData {
+        id: DEMO_PC_ID.to_string(),
+        session_id: DEMO_SESSION_ID.to_string(),
+        user_id: String::new(),
+        world_id: DEMO_WORLD_ID.to_string(),
+        name: "Rook".to_string(),
+        description: Some("A sellsword between jobs, short on coin.".to_string()),
+        sheet_data: None,
+        current_location_id: LOCATION_ID.to_string(),
+        starting_location_id: LOCATION_ID.to_string(),
+        sprite_asset: None,
+        portrait_asset: None,
+        experience: 0,
+        level: 1,
+        pending_levels: 0,
+        created_at: CREATED_AT.to_string(),
+        last_active_at: CREATED_AT.to_string(),
+    }
+}
+
+/// REST responses for the demo world; anything else fails as not configured
+pub fn api() -> MockApiPort {
+    let api = MockApiPort::with_standing_responses();
+    let world_path = format!("/api/worlds/{}", DEMO_WORLD_ID);
+
+    api.when_get_json(
+        &format!("/api/sessions/{}/player-characters/me", DEMO_SESSION_ID),
+        serde_json::to_value(player_character()).unwrap_or_default(),
+    );
+    api.when_get_json(&format!("{}/skills", world_path), serde_json::json!([]));
+    api.when_get_json(&format!("{}/challenges", world_path), serde_json::json!([]));
+    api
+}
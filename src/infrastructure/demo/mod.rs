@@ -0,0 +1,53 @@
+//! Demo session - the whole app without an Engine
+//!
+//! Connecting to `DEMO_ENGINE_URL` gives a `MockGameConnectionPort` driven
+//! by the demo script, and while that session is live `ApiAdapter` answers
+//! REST calls from the demo data pack instead of the network. Disconnecting,
+//! or connecting to a real Engine, ends it.
+
+mod data;
+mod script;
+
+use std::sync::{Arc, RwLock};
+
+use crate::application::ports::outbound::GameConnectionPort;
+use crate::application::services::DEMO_ENGINE_URL;
+use crate::infrastructure::testing::{MockApiPort, MockCall, MockGameConnectionPort};
+
+use script::DemoScript;
+
+/// REST responses of the live demo session, if there is one
+static DEMO_API: RwLock<Option<MockApiPort>> = RwLock::new(None);
+
+/// Start a demo session and return its connection
+pub fn start() -> Arc<dyn GameConnectionPort> {
+    if let Ok(mut api) = DEMO_API.write() {
+        *api = Some(data::api());
+    }
+
+    let connection = MockGameConnectionPort::new(DEMO_ENGINE_URL);
+    let mut script = DemoScript::new();
+    connection.respond_with(move |call| {
+        if matches!(call, MockCall::Disconnect) {
+            stop();
+        }
+        script
+            .respond(call)
+            .into_iter()
+            .filter_map(|message| serde_json::to_value(message).ok())
+            .collect()
+    });
+    Arc::new(connection)
+}
+
+/// End the demo session, sending REST calls to the Engine again
+pub fn stop() {
+    if let Ok(mut api) = DEMO_API.write() {
+        *api = None;
+    }
+}
+
+/// The demo's REST responses, while a demo session is live
+pub fn api() -> Option<MockApiPort> {
+    DEMO_API.read().ok().and_then(|api| api.clone())
+}
@@ -0,0 +1,329 @@
+//! Demo script - what the demo "Engine" says back
+//!
+//! Players talk to the two NPCs, who work through a few fixed lines each.
+//! A DM gets staged NPC replies to approve, edit, reject or take over, as
+//! if players were asking. No LLM is involved anywhere.
+
+use crate::application::dto::{DialogueChoice, LockedChoiceDisplay, ParticipantRole, ServerMessage, SpectatorPolicy};
+use crate::application::ports::outbound::{ApprovalDecision, DecisionTimerSettings, ParticipantRole as PortParticipantRole};
+use crate::infrastructure::testing::MockCall;
+
+use super::data::{self, DEMO_SESSION_ID, INNKEEPER_ID, STRANGER_ID};
+
+/// One NPC line, and a sentiment change it brings
+struct Line {
+    text: &'static str,
+    sentiment: Option<f32>,
+}
+
+const MARTA_LINES: &[Line] = &[
+    Line {
+        text: "Evening, love. Rain's keeping the regulars home, so you've the pick of the stools. What'll it be?",
+        sentiment: None,
+    },
+    Line {
+        text: "That one in the corner? Came in at dusk, paid in old silver, and hasn't said a word since. I don't like it.",
+        sentiment: None,
+    },
+    Line {
+        text: "If you mean to ask them yourself, mind your manners. I've only just had the tables mended.",
+        sentiment: Some(0.6),
+    },
+    Line {
+        text: "Another round? It's a long night.",
+        sentiment: None,
+    },
+];
+
+const STRANGER_LINES: &[Line] = &[
+    Line {
+        text: "The hood tilts toward you. \"You're not with the town watch. Good.\"",
+        sentiment: None,
+    },
+    Line {
+        text: "\"I need someone who doesn't ask questions. Are you that someone?\"",
+        sentiment: None,
+    },
+    Line {
+        text: "\"East gate, at dawn. Come alone.\" A silver coin slides across the table.",
+        sentiment: Some(0.2),
+    },
+    Line {
+        text: "The Stranger has said all they mean to say tonight.",
+        sentiment: None,
+    },
+];
+
+/// A reply staged for the DM to approve
+struct StagedReply {
+    npc_id: &'static str,
+    npc_name: &'static str,
+    proposed: &'static str,
+    /// Offered instead when the DM rejects the first one
+    alternative: &'static str,
+    reasoning: &'static str,
+}
+
+const STAGED_REPLIES: &[StagedReply] = &[
+    StagedReply {
+        npc_id: INNKEEPER_ID,
+        npc_name: "Marta",
+        proposed: "Him? Paid in old silver and hasn't touched his drink. I'd keep clear, if I were you.",
+        alternative: "Can't say I know. Not my business who drinks here, so long as they pay.",
+        reasoning: "A player asked Marta about the stranger. She is wary of him and protective of her regulars.",
+    },
+    StagedReply {
+        npc_id: STRANGER_ID,
+        npc_name: "The Stranger",
+        proposed: "\"You ask a lot of questions for someone who hasn't bought me a drink.\"",
+        alternative: "The Stranger says nothing, and pulls the hood a little lower.",
+        reasoning: "A player sat down at the Stranger's table. They are testing whether the party can be trusted.",
+    },
+];
+
+/// Scripted session state for one demo connection
+#[derive(Default)]
+pub struct DemoScript {
+    /// Lines spoken so far by each NPC: (Marta, Stranger)
+    spoken: (usize, usize),
+    /// NPC the player last talked to; choices continue with them
+    talking_to: Option<&'static str>,
+    /// Staged reply awaiting the DM, and whether it was already rejected once
+    staged: Option<(usize, bool)>,
+    next_staged: usize,
+}
+
+impl DemoScript {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Server messages answering one outbound call
+    pub fn respond(&mut self, call: &MockCall) -> Vec<ServerMessage> {
+        match call {
+            MockCall::Join(join) => self.join(join.role),
+            MockCall::Action(action) => {
+                if let Some(target) = action.target.as_deref() {
+                    self.talking_to = [INNKEEPER_ID, STRANGER_ID].into_iter().find(|id| *id == target);
+                }
+                self.npc_reply()
+            }
+            MockCall::Approval(approval) => self.decide(&approval.request_id, &approval.decision),
+            MockCall::Disconnect => Vec::new(),
+        }
+    }
+
+    fn join(&mut self, role: PortParticipantRole) -> Vec<ServerMessage> {
+        let role = match role {
+            PortParticipantRole::DungeonMaster => ParticipantRole::DungeonMaster,
+            PortParticipantRole::CoDungeonMaster => ParticipantRole::CoDungeonMaster,
+            PortParticipantRole::Player => ParticipantRole::Player,
+            PortParticipantRole::Spectator => ParticipantRole::Spectator,
+        };
+        let (scene, characters, interactions) = data::opening_scene();
+        let mut messages = vec![
+            ServerMessage::SessionJoined {
+                session_id: DEMO_SESSION_ID.to_string(),
+                role,
+                participants: Vec::new(),
+                world_snapshot: serde_json::to_value(data::world_snapshot()).unwrap_or_default(),
+                spectator_policy: SpectatorPolicy::default(),
+                protocol_version: None,
+                pending_level_up: None,
+                decision_timer: DecisionTimerSettings::default(),
+                conditions: Default::default(),
+                annotations: Vec::new(),
+                triggered_events: Vec::new(),
+                staged_scenes: Vec::new(),
+                session_rolls: Vec::new(),
+                directorial_context: None,
+            },
+            ServerMessage::SceneUpdate { scene, characters, interactions },
+        ];
+        if matches!(role, ParticipantRole::DungeonMaster | ParticipantRole::CoDungeonMaster) {
+            messages.extend(self.stage_next());
+        }
+        messages
+    }
+
+    /// The current NPC's next line, or a nudge toward them
+    fn npc_reply(&mut self) -> Vec<ServerMessage> {
+        let (npc_id, npc_name, lines, spoken) = match self.talking_to {
+            Some(INNKEEPER_ID) => (INNKEEPER_ID, "Marta", MARTA_LINES, &mut self.spoken.0),
+            Some(STRANGER_ID) => (STRANGER_ID, "The Stranger", STRANGER_LINES, &mut self.spoken.1),
+            _ => {
+                return vec![dialogue(
+                    "",
+                    "Narrator",
+                    "Only Marta and the Stranger have anything to say in the demo. Try talking to one of them.",
+                    Vec::new(),
+                )];
+            }
+        };
+        // The last line repeats once the NPC runs out
+        let line = &lines[(*spoken).min(lines.len() - 1)];
+        *spoken += 1;
+
+        let mut messages = Vec::new();
+        if let Some(sentiment) = line.sentiment {
+            messages.push(ServerMessage::DispositionChanged {
+                npc_id: npc_id.to_string(),
+                sentiment: Some(sentiment),
+                hidden: false,
+            });
+        }
+        messages.push(dialogue(npc_id, npc_name, line.text, reply_choices()));
+        messages
+    }
+
+    fn stage_next(&mut self) -> Option<ServerMessage> {
+        let index = self.next_staged;
+        let staged = STAGED_REPLIES.get(index)?;
+        self.next_staged += 1;
+        self.staged = Some((index, false));
+        Some(approval_request(request_id_for(index, false), staged, staged.proposed))
+    }
+
+    fn decide(&mut self, request_id: &str, decision: &ApprovalDecision) -> Vec<ServerMessage> {
+        let Some((index, rejected)) = self.staged else {
+            return Vec::new();
+        };
+        if request_id != request_id_for(index, rejected) {
+            return Vec::new();
+        }
+        let staged = &STAGED_REPLIES[index];
+        let proposed = if rejected { staged.alternative } else { staged.proposed };
+
+        let text = match decision {
+            ApprovalDecision::Accept => proposed.to_string(),
+            ApprovalDecision::AcceptWithModification { modified_dialogue, .. } => modified_dialogue.clone(),
+            ApprovalDecision::TakeOver { dm_response } => dm_response.clone(),
+            ApprovalDecision::Reject { .. } if !rejected => {
+                self.staged = Some((index, true));
+                return vec![approval_request(request_id_for(index, true), staged, staged.alternative)];
+            }
+            // A second rejection drops the reply
+            ApprovalDecision::Reject { .. } => {
+                self.staged = None;
+                return self.stage_next().into_iter().collect();
+            }
+        };
+
+        self.staged = None;
+        let mut messages = vec![
+            ServerMessage::ResponseApproved { npc_dialogue: text.clone(), executed_tools: Vec::new() },
+            dialogue(staged.npc_id, staged.npc_name, &text, Vec::new()),
+        ];
+        messages.extend(self.stage_next());
+        messages
+    }
+}
+
+fn request_id_for(index: usize, retry: bool) -> String {
+    if retry {
+        format!("demo-approval-{}-retry", index)
+    } else {
+        format!("demo-approval-{}", index)
+    }
+}
+
+fn approval_request(request_id: String, staged: &StagedReply, proposed: &str) -> ServerMessage {
+    ServerMessage::ApprovalRequired {
+        request_id,
+        npc_name: staged.npc_name.to_string(),
+        proposed_dialogue: proposed.to_string(),
+        internal_reasoning: staged.reasoning.to_string(),
+        proposed_tools: Vec::new(),
+        challenge_suggestion: None,
+        narrative_event_suggestion: None,
+        pc_id: None,
+    }
+}
+
+fn dialogue(speaker_id: &str, speaker_name: &str, text: &str, choices: Vec<DialogueChoice>) -> ServerMessage {
+    ServerMessage::DialogueResponse {
+        speaker_id: speaker_id.to_string(),
+        speaker_name: speaker_name.to_string(),
+        text: text.to_string(),
+        choices,
+        scene_id: None,
+    }
+}
+
+fn reply_choices() -> Vec<DialogueChoice> {
+    vec![
+        DialogueChoice {
+            id: "demo-continue".to_string(),
+            text: "Go on.".to_string(),
+            is_custom_input: false,
+            requirements: Vec::new(),
+            when_locked: LockedChoiceDisplay::default(),
+        },
+        DialogueChoice {
+            id: "demo-custom".to_string(),
+            text: "Say something else...".to_string(),
+            is_custom_input: true,
+            requirements: Vec::new(),
+            when_locked: LockedChoiceDisplay::default(),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::testing::mock_game_connection_port::{SentAction, SentApproval, SentJoin};
+
+    fn talk(target: Option<&str>) -> MockCall {
+        MockCall::Action(SentAction {
+            action_type: "talk".to_string(),
+            target: target.map(str::to_string),
+            dialogue: None,
+            acting_as: None,
+        })
+    }
+
+    #[test]
+    fn npcs_work_through_their_lines() {
+        let mut script = DemoScript::new();
+        let replies = script.respond(&talk(None));
+        assert!(matches!(&replies[..], [ServerMessage::DialogueResponse { speaker_name, .. }] if speaker_name == "Narrator"));
+
+        for _ in 0..STRANGER_LINES.len() + 1 {
+            script.respond(&talk(Some(STRANGER_ID)));
+        }
+        // Choices carry no target, so they continue with the same NPC
+        let replies = script.respond(&talk(None));
+        match replies.last() {
+            Some(ServerMessage::DialogueResponse { speaker_id, text, .. }) => {
+                assert_eq!(speaker_id, STRANGER_ID);
+                assert_eq!(text, STRANGER_LINES.last().unwrap().text);
+            }
+            other => panic!("expected dialogue, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn dm_rejection_offers_an_alternative_then_moves_on() {
+        let mut script = DemoScript::new();
+        let joined = script.respond(&MockCall::Join(SentJoin {
+            user_id: "dm".to_string(),
+            role: PortParticipantRole::DungeonMaster,
+        }));
+        assert!(matches!(joined.last(), Some(ServerMessage::ApprovalRequired { request_id, .. }) if request_id == "demo-approval-0"));
+
+        let reject = |request_id: &str| MockCall::Approval(SentApproval {
+            request_id: request_id.to_string(),
+            decision: ApprovalDecision::Reject { feedback: String::new() },
+        });
+        let retried = script.respond(&reject("demo-approval-0"));
+        assert!(matches!(&retried[..], [ServerMessage::ApprovalRequired { request_id, .. }] if request_id == "demo-approval-0-retry"));
+
+        let accepted = script.respond(&MockCall::Approval(SentApproval {
+            request_id: "demo-approval-0-retry".to_string(),
+            decision: ApprovalDecision::Accept,
+        }));
+        assert!(matches!(&accepted[1], ServerMessage::DialogueResponse { text, .. } if text == STAGED_REPLIES[0].alternative));
+        assert!(matches!(accepted.last(), Some(ServerMessage::ApprovalRequired { request_id, .. }) if request_id == "demo-approval-1"));
+    }
+}
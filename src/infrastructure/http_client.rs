@@ -23,8 +23,49 @@
 
 use serde::{de::DeserializeOwned, Serialize};
 
-use super::api::get_engine_url;
-use crate::application::ports::outbound::api_port::ApiError;
+use super::api::{get_auth_token, get_engine_url};
+use crate::application::ports::outbound::api_port::{error_message_from_body, ApiError};
+
+/// Error for a request that got no response
+#[cfg(not(target_arch = "wasm32"))]
+fn request_error(e: reqwest::Error) -> ApiError {
+    if e.is_timeout() {
+        ApiError::Timeout
+    } else {
+        ApiError::Network(e.to_string())
+    }
+}
+
+/// Error for a request that got no response
+#[cfg(target_arch = "wasm32")]
+fn request_error(e: gloo_net::Error) -> ApiError {
+    ApiError::Network(e.to_string())
+}
+
+/// Error for a non-success response, with the Engine's message if it sent one
+#[cfg(not(target_arch = "wasm32"))]
+async fn status_error(response: reqwest::Response) -> ApiError {
+    let status = response.status().as_u16();
+    let body = response.text().await.unwrap_or_default();
+    ApiError::from_status(status, error_message_from_body(&body).unwrap_or_default())
+}
+
+/// Error for a non-success response, with the Engine's message if it sent one
+#[cfg(target_arch = "wasm32")]
+async fn status_error(response: gloo_net::http::Response) -> ApiError {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    ApiError::from_status(status, error_message_from_body(&body).unwrap_or_default())
+}
+
+/// Scheme, host, and port of a URL, e.g. "https://engine.example:8080"
+fn origin(url: &str) -> &str {
+    let after_scheme = url.find("://").map(|i| i + 3).unwrap_or(0);
+    match url[after_scheme..].find(['/', '?', '#']) {
+        Some(i) => &url[..after_scheme + i],
+        None => url,
+    }
+}
 
 /// Unified HTTP client for Engine API
 ///
@@ -35,6 +76,10 @@ pub struct HttpClient;
 impl HttpClient {
     /// Build full URL from API path
     fn build_url(path: &str) -> String {
+        // Absolute URLs (e.g. a configured bug report endpoint) go out as-is
+        if path.starts_with("http://") || path.starts_with("https://") {
+            return path.to_string();
+        }
         let base = get_engine_url();
         if path.starts_with('/') {
             format!("{}{}", base, path)
@@ -43,6 +88,28 @@ impl HttpClient {
         }
     }
 
+    /// The active auth token, if `url` is on the Engine's origin
+    ///
+    /// Absolute URLs elsewhere (e.g. a bug report endpoint) never see it.
+    fn auth_token(url: &str) -> Option<String> {
+        if origin(url) != origin(&get_engine_url()) {
+            return None;
+        }
+        get_auth_token()
+    }
+
+    /// Authorization headers for the active auth token (desktop)
+    #[cfg(not(target_arch = "wasm32"))]
+    fn auth_headers(url: &str) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Some(token) = Self::auth_token(url) {
+            if let Ok(value) = reqwest::header::HeaderValue::from_str(&format!("Bearer {}", token)) {
+                headers.insert(reqwest::header::AUTHORIZATION, value);
+            }
+        }
+        headers
+    }
+
     /// GET request that returns deserialized JSON
     pub async fn get<T: DeserializeOwned>(path: &str) -> Result<T, ApiError> {
         let url = Self::build_url(path);
@@ -58,22 +125,22 @@ impl HttpClient {
             {
                 request = request.header("X-User-Id", &user_id);
             }
+            if let Some(token) = Self::auth_token(&url) {
+                request = request.header("Authorization", &format!("Bearer {}", token));
+            }
 
             let response = request
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
             if response.ok() {
                 response
                     .json::<T>()
                     .await
-                    .map_err(|e| ApiError::ParseError(e.to_string()))
+                    .map_err(|e| ApiError::Deserialize(e.to_string()))
             } else {
-                Err(ApiError::HttpError(
-                    response.status(),
-                    format!("GET {} failed", path),
-                ))
+                Err(status_error(response).await)
             }
         }
 
@@ -82,18 +149,18 @@ impl HttpClient {
             let client = reqwest::Client::new();
             let response = client
                 .get(&url)
+                .headers(Self::auth_headers(&url))
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
-            let status = response.status().as_u16();
             if response.status().is_success() {
                 response
                     .json::<T>()
                     .await
-                    .map_err(|e| ApiError::ParseError(e.to_string()))
+                    .map_err(|e| ApiError::Deserialize(e.to_string()))
             } else {
-                Err(ApiError::HttpError(status, format!("GET {} failed", path)))
+                Err(status_error(response).await)
             }
         }
     }
@@ -107,31 +174,31 @@ impl HttpClient {
             use gloo_net::http::Request;
 
             let body_str = serde_json::to_string(body)
-                .map_err(|e| ApiError::SerializeError(e.to_string()))?;
+                .map_err(|e| ApiError::Serialize(e.to_string()))?;
             let mut request = Request::post(&url).header("Content-Type", "application/json");
             if let Some(user_id) =
                 crate::infrastructure::storage::load(crate::infrastructure::storage::STORAGE_KEY_USER_ID)
             {
                 request = request.header("X-User-Id", &user_id);
             }
+            if let Some(token) = Self::auth_token(&url) {
+                request = request.header("Authorization", &format!("Bearer {}", token));
+            }
 
             let response = request
                 .body(body_str)
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?
+                .map_err(request_error)?
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
             if response.ok() {
                 response
                     .json::<T>()
                     .await
-                    .map_err(|e| ApiError::ParseError(e.to_string()))
+                    .map_err(|e| ApiError::Deserialize(e.to_string()))
             } else {
-                Err(ApiError::HttpError(
-                    response.status(),
-                    format!("POST {} failed", path),
-                ))
+                Err(status_error(response).await)
             }
         }
 
@@ -140,19 +207,19 @@ impl HttpClient {
             let client = reqwest::Client::new();
             let response = client
                 .post(&url)
+                .headers(Self::auth_headers(&url))
                 .json(body)
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
-            let status = response.status().as_u16();
             if response.status().is_success() {
                 response
                     .json::<T>()
                     .await
-                    .map_err(|e| ApiError::ParseError(e.to_string()))
+                    .map_err(|e| ApiError::Deserialize(e.to_string()))
             } else {
-                Err(ApiError::HttpError(status, format!("POST {} failed", path)))
+                Err(status_error(response).await)
             }
         }
     }
@@ -166,28 +233,28 @@ impl HttpClient {
             use gloo_net::http::Request;
 
             let body_str = serde_json::to_string(body)
-                .map_err(|e| ApiError::SerializeError(e.to_string()))?;
+                .map_err(|e| ApiError::Serialize(e.to_string()))?;
             let mut request = Request::post(&url).header("Content-Type", "application/json");
             if let Some(user_id) =
                 crate::infrastructure::storage::load(crate::infrastructure::storage::STORAGE_KEY_USER_ID)
             {
                 request = request.header("X-User-Id", &user_id);
             }
+            if let Some(token) = Self::auth_token(&url) {
+                request = request.header("Authorization", &format!("Bearer {}", token));
+            }
 
             let response = request
                 .body(body_str)
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?
+                .map_err(request_error)?
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
             if response.ok() {
                 Ok(())
             } else {
-                Err(ApiError::HttpError(
-                    response.status(),
-                    format!("POST {} failed", path),
-                ))
+                Err(status_error(response).await)
             }
         }
 
@@ -196,16 +263,16 @@ impl HttpClient {
             let client = reqwest::Client::new();
             let response = client
                 .post(&url)
+                .headers(Self::auth_headers(&url))
                 .json(body)
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
-            let status = response.status().as_u16();
             if response.status().is_success() {
                 Ok(())
             } else {
-                Err(ApiError::HttpError(status, format!("POST {} failed", path)))
+                Err(status_error(response).await)
             }
         }
     }
@@ -224,33 +291,32 @@ impl HttpClient {
             {
                 request = request.header("X-User-Id", &user_id);
             }
+            if let Some(token) = Self::auth_token(&url) {
+                request = request.header("Authorization", &format!("Bearer {}", token));
+            }
 
             let response = request.send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
             if response.ok() {
                 Ok(())
             } else {
-                Err(ApiError::HttpError(
-                    response.status(),
-                    format!("POST {} failed", path),
-                ))
+                Err(status_error(response).await)
             }
         }
 
         #[cfg(not(target_arch = "wasm32"))]
         {
             let client = reqwest::Client::new();
-            let response = client.post(&url).send()
+            let response = client.post(&url).headers(Self::auth_headers(&url)).send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
-            let status = response.status().as_u16();
             if response.status().is_success() {
                 Ok(())
             } else {
-                Err(ApiError::HttpError(status, format!("POST {} failed", path)))
+                Err(status_error(response).await)
             }
         }
     }
@@ -264,7 +330,7 @@ impl HttpClient {
             use gloo_net::http::Request;
 
             let body_str = serde_json::to_string(body)
-                .map_err(|e| ApiError::SerializeError(e.to_string()))?;
+                .map_err(|e| ApiError::Serialize(e.to_string()))?;
 
             let mut request = Request::put(&url).header("Content-Type", "application/json");
             if let Some(user_id) =
@@ -272,24 +338,24 @@ impl HttpClient {
             {
                 request = request.header("X-User-Id", &user_id);
             }
+            if let Some(token) = Self::auth_token(&url) {
+                request = request.header("Authorization", &format!("Bearer {}", token));
+            }
 
             let response = request
                 .body(body_str)
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?
+                .map_err(request_error)?
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
             if response.ok() {
                 response
                     .json::<T>()
                     .await
-                    .map_err(|e| ApiError::ParseError(e.to_string()))
+                    .map_err(|e| ApiError::Deserialize(e.to_string()))
             } else {
-                Err(ApiError::HttpError(
-                    response.status(),
-                    format!("PUT {} failed", path),
-                ))
+                Err(status_error(response).await)
             }
         }
 
@@ -298,19 +364,19 @@ impl HttpClient {
             let client = reqwest::Client::new();
             let response = client
                 .put(&url)
+                .headers(Self::auth_headers(&url))
                 .json(body)
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
-            let status = response.status().as_u16();
             if response.status().is_success() {
                 response
                     .json::<T>()
                     .await
-                    .map_err(|e| ApiError::ParseError(e.to_string()))
+                    .map_err(|e| ApiError::Deserialize(e.to_string()))
             } else {
-                Err(ApiError::HttpError(status, format!("PUT {} failed", path)))
+                Err(status_error(response).await)
             }
         }
     }
@@ -324,7 +390,7 @@ impl HttpClient {
             use gloo_net::http::Request;
 
             let body_str = serde_json::to_string(body)
-                .map_err(|e| ApiError::SerializeError(e.to_string()))?;
+                .map_err(|e| ApiError::Serialize(e.to_string()))?;
 
             let mut request = Request::put(&url).header("Content-Type", "application/json");
             if let Some(user_id) =
@@ -332,21 +398,21 @@ impl HttpClient {
             {
                 request = request.header("X-User-Id", &user_id);
             }
+            if let Some(token) = Self::auth_token(&url) {
+                request = request.header("Authorization", &format!("Bearer {}", token));
+            }
 
             let response = request
                 .body(body_str)
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?
+                .map_err(request_error)?
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
             if response.ok() {
                 Ok(())
             } else {
-                Err(ApiError::HttpError(
-                    response.status(),
-                    format!("PUT {} failed", path),
-                ))
+                Err(status_error(response).await)
             }
         }
 
@@ -355,16 +421,16 @@ impl HttpClient {
             let client = reqwest::Client::new();
             let response = client
                 .put(&url)
+                .headers(Self::auth_headers(&url))
                 .json(body)
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
-            let status = response.status().as_u16();
             if response.status().is_success() {
                 Ok(())
             } else {
-                Err(ApiError::HttpError(status, format!("PUT {} failed", path)))
+                Err(status_error(response).await)
             }
         }
     }
@@ -383,19 +449,19 @@ impl HttpClient {
             {
                 request = request.header("X-User-Id", &user_id);
             }
+            if let Some(token) = Self::auth_token(&url) {
+                request = request.header("Authorization", &format!("Bearer {}", token));
+            }
 
             let response = request
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
             if response.ok() {
                 Ok(())
             } else {
-                Err(ApiError::HttpError(
-                    response.status(),
-                    format!("PUT {} failed", path),
-                ))
+                Err(status_error(response).await)
             }
         }
 
@@ -404,15 +470,15 @@ impl HttpClient {
             let client = reqwest::Client::new();
             let response = client
                 .put(&url)
+                .headers(Self::auth_headers(&url))
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
-            let status = response.status().as_u16();
             if response.status().is_success() {
                 Ok(())
             } else {
-                Err(ApiError::HttpError(status, format!("PUT {} failed", path)))
+                Err(status_error(response).await)
             }
         }
     }
@@ -431,22 +497,22 @@ impl HttpClient {
             {
                 request = request.header("X-User-Id", &user_id);
             }
+            if let Some(token) = Self::auth_token(&url) {
+                request = request.header("Authorization", &format!("Bearer {}", token));
+            }
 
             let response = request
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
             if response.ok() {
                 response
                     .json::<T>()
                     .await
-                    .map_err(|e| ApiError::ParseError(e.to_string()))
+                    .map_err(|e| ApiError::Deserialize(e.to_string()))
             } else {
-                Err(ApiError::HttpError(
-                    response.status(),
-                    format!("PUT {} failed", path),
-                ))
+                Err(status_error(response).await)
             }
         }
 
@@ -455,18 +521,18 @@ impl HttpClient {
             let client = reqwest::Client::new();
             let response = client
                 .put(&url)
+                .headers(Self::auth_headers(&url))
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
-            let status = response.status().as_u16();
             if response.status().is_success() {
                 response
                     .json::<T>()
                     .await
-                    .map_err(|e| ApiError::ParseError(e.to_string()))
+                    .map_err(|e| ApiError::Deserialize(e.to_string()))
             } else {
-                Err(ApiError::HttpError(status, format!("PUT {} failed", path)))
+                Err(status_error(response).await)
             }
         }
     }
@@ -478,7 +544,7 @@ impl HttpClient {
     ) -> Result<T, ApiError> {
         let url = Self::build_url(path);
         let json_body =
-            serde_json::to_string(body).map_err(|e| ApiError::SerializeError(e.to_string()))?;
+            serde_json::to_string(body).map_err(|e| ApiError::Serialize(e.to_string()))?;
 
         #[cfg(target_arch = "wasm32")]
         {
@@ -490,24 +556,24 @@ impl HttpClient {
             {
                 request = request.header("X-User-Id", &user_id);
             }
+            if let Some(token) = Self::auth_token(&url) {
+                request = request.header("Authorization", &format!("Bearer {}", token));
+            }
 
             let response = request
                 .body(&json_body)
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?
+                .map_err(request_error)?
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
             if response.ok() {
                 response
                     .json::<T>()
                     .await
-                    .map_err(|e| ApiError::ParseError(e.to_string()))
+                    .map_err(|e| ApiError::Deserialize(e.to_string()))
             } else {
-                Err(ApiError::HttpError(
-                    response.status(),
-                    format!("PATCH {} failed", path),
-                ))
+                Err(status_error(response).await)
             }
         }
 
@@ -516,23 +582,20 @@ impl HttpClient {
             let client = reqwest::Client::new();
             let response = client
                 .patch(&url)
+                .headers(Self::auth_headers(&url))
                 .header("Content-Type", "application/json")
                 .body(json_body)
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
-            let status = response.status().as_u16();
             if response.status().is_success() {
                 response
                     .json::<T>()
                     .await
-                    .map_err(|e| ApiError::ParseError(e.to_string()))
+                    .map_err(|e| ApiError::Deserialize(e.to_string()))
             } else {
-                Err(ApiError::HttpError(
-                    status,
-                    format!("PATCH {} failed", path),
-                ))
+                Err(status_error(response).await)
             }
         }
     }
@@ -551,19 +614,19 @@ impl HttpClient {
             {
                 request = request.header("X-User-Id", &user_id);
             }
+            if let Some(token) = Self::auth_token(&url) {
+                request = request.header("Authorization", &format!("Bearer {}", token));
+            }
 
             let response = request
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
             if response.ok() {
                 Ok(())
             } else {
-                Err(ApiError::HttpError(
-                    response.status(),
-                    format!("DELETE {} failed", path),
-                ))
+                Err(status_error(response).await)
             }
         }
 
@@ -572,15 +635,15 @@ impl HttpClient {
             let client = reqwest::Client::new();
             let response = client
                 .delete(&url)
+                .headers(Self::auth_headers(&url))
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
-            let status = response.status().as_u16();
             if response.status().is_success() {
                 Ok(())
             } else {
-                Err(ApiError::HttpError(status, format!("DELETE {} failed", path)))
+                Err(status_error(response).await)
             }
         }
     }
@@ -599,11 +662,14 @@ impl HttpClient {
             {
                 request = request.header("X-User-Id", &user_id);
             }
+            if let Some(token) = Self::auth_token(&url) {
+                request = request.header("Authorization", &format!("Bearer {}", token));
+            }
 
             let response = request
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
             if response.status() == 404 {
                 return Ok(None);
@@ -613,13 +679,10 @@ impl HttpClient {
                 let data = response
                     .json::<T>()
                     .await
-                    .map_err(|e| ApiError::ParseError(e.to_string()))?;
+                    .map_err(|e| ApiError::Deserialize(e.to_string()))?;
                 Ok(Some(data))
             } else {
-                Err(ApiError::HttpError(
-                    response.status(),
-                    format!("GET {} failed", path),
-                ))
+                Err(status_error(response).await)
             }
         }
 
@@ -628,23 +691,23 @@ impl HttpClient {
             let client = reqwest::Client::new();
             let response = client
                 .get(&url)
+                .headers(Self::auth_headers(&url))
                 .send()
                 .await
-                .map_err(|e| ApiError::RequestFailed(e.to_string()))?;
+                .map_err(request_error)?;
 
             if response.status() == reqwest::StatusCode::NOT_FOUND {
                 return Ok(None);
             }
 
-            let status = response.status().as_u16();
             if response.status().is_success() {
                 let data = response
                     .json::<T>()
                     .await
-                    .map_err(|e| ApiError::ParseError(e.to_string()))?;
+                    .map_err(|e| ApiError::Deserialize(e.to_string()))?;
                 Ok(Some(data))
             } else {
-                Err(ApiError::HttpError(status, format!("GET {} failed", path)))
+                Err(status_error(response).await)
             }
         }
     }
@@ -654,12 +717,20 @@ impl HttpClient {
 // ApiPort Implementation
 // ============================================================================
 
-use crate::application::ports::outbound::ApiPort;
+use crate::application::ports::outbound::{ApiPort, OpenCircuit};
+use crate::infrastructure::api_cache::{self, CacheKind};
+use crate::infrastructure::demo;
+use crate::infrastructure::api_resilience::{self, RetryPolicy};
 
 /// API adapter that implements the ApiPort trait
 ///
 /// This adapter wraps the static HttpClient methods to provide an instance-based
-/// API that can be injected into application services.
+/// API that can be injected into application services. GETs of skill,
+/// challenge and character lists go through the shared response cache, and
+/// writes drop the cached lists they may have changed. Every request goes
+/// through its endpoint's circuit; GET, PUT and DELETE are retried when they
+/// fail in a way that may not happen again, POST and PATCH are sent once.
+/// During a demo session requests are answered by the demo instead.
 #[derive(Clone, Debug, Default)]
 pub struct ApiAdapter;
 
@@ -674,11 +745,30 @@ impl ApiAdapter {
 #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
 impl ApiPort for ApiAdapter {
     async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, ApiError> {
-        HttpClient::get(path).await
+        if let Some(demo_api) = demo::api() {
+            return demo_api.get(path).await;
+        }
+        if CacheKind::of_list(path).is_none() {
+            return api_resilience::send(path, RetryPolicy::IDEMPOTENT, || HttpClient::get(path)).await;
+        }
+        let cache = api_cache::global();
+        let body = match cache.get(path, api_cache::now_millis()) {
+            Some(body) => body,
+            None => {
+                let body: serde_json::Value =
+                    api_resilience::send(path, RetryPolicy::IDEMPOTENT, || HttpClient::get(path)).await?;
+                cache.insert(path, body.clone(), api_cache::now_millis());
+                body
+            }
+        };
+        serde_json::from_value(body).map_err(|e| ApiError::Deserialize(e.to_string()))
     }
 
     async fn get_optional<T: DeserializeOwned>(&self, path: &str) -> Result<Option<T>, ApiError> {
-        HttpClient::get_optional(path).await
+        if let Some(demo_api) = demo::api() {
+            return demo_api.get_optional(path).await;
+        }
+        api_resilience::send(path, RetryPolicy::IDEMPOTENT, || HttpClient::get_optional(path)).await
     }
 
     async fn post<T: DeserializeOwned, B: Serialize + Send + Sync>(
@@ -686,7 +776,12 @@ impl ApiPort for ApiAdapter {
         path: &str,
         body: &B,
     ) -> Result<T, ApiError> {
-        HttpClient::post(path, body).await
+        if let Some(demo_api) = demo::api() {
+            return demo_api.post(path, body).await;
+        }
+        let result = api_resilience::send(path, RetryPolicy::ONCE, || HttpClient::post(path, body)).await;
+        invalidate_written(path);
+        result
     }
 
     async fn post_no_response<B: Serialize + Send + Sync>(
@@ -694,11 +789,21 @@ impl ApiPort for ApiAdapter {
         path: &str,
         body: &B,
     ) -> Result<(), ApiError> {
-        HttpClient::post_no_response(path, body).await
+        if let Some(demo_api) = demo::api() {
+            return demo_api.post_no_response(path, body).await;
+        }
+        let result = api_resilience::send(path, RetryPolicy::ONCE, || HttpClient::post_no_response(path, body)).await;
+        invalidate_written(path);
+        result
     }
 
     async fn post_empty(&self, path: &str) -> Result<(), ApiError> {
-        HttpClient::post_empty(path).await
+        if let Some(demo_api) = demo::api() {
+            return demo_api.post_empty(path).await;
+        }
+        let result = api_resilience::send(path, RetryPolicy::ONCE, || HttpClient::post_empty(path)).await;
+        invalidate_written(path);
+        result
     }
 
     async fn put<T: DeserializeOwned, B: Serialize + Send + Sync>(
@@ -706,7 +811,12 @@ impl ApiPort for ApiAdapter {
         path: &str,
         body: &B,
     ) -> Result<T, ApiError> {
-        HttpClient::put(path, body).await
+        if let Some(demo_api) = demo::api() {
+            return demo_api.put(path, body).await;
+        }
+        let result = api_resilience::send(path, RetryPolicy::IDEMPOTENT, || HttpClient::put(path, body)).await;
+        invalidate_written(path);
+        result
     }
 
     async fn put_no_response<B: Serialize + Send + Sync>(
@@ -714,18 +824,35 @@ impl ApiPort for ApiAdapter {
         path: &str,
         body: &B,
     ) -> Result<(), ApiError> {
-        HttpClient::put_no_response(path, body).await
+        if let Some(demo_api) = demo::api() {
+            return demo_api.put_no_response(path, body).await;
+        }
+        let result =
+            api_resilience::send(path, RetryPolicy::IDEMPOTENT, || HttpClient::put_no_response(path, body)).await;
+        invalidate_written(path);
+        result
     }
 
     async fn put_empty(&self, path: &str) -> Result<(), ApiError> {
-        HttpClient::put_empty(path).await
+        if let Some(demo_api) = demo::api() {
+            return demo_api.put_empty(path).await;
+        }
+        let result = api_resilience::send(path, RetryPolicy::IDEMPOTENT, || HttpClient::put_empty(path)).await;
+        invalidate_written(path);
+        result
     }
 
     async fn put_empty_with_response<T: DeserializeOwned>(
         &self,
         path: &str,
     ) -> Result<T, ApiError> {
-        HttpClient::put_empty_with_response(path).await
+        if let Some(demo_api) = demo::api() {
+            return demo_api.put_empty_with_response(path).await;
+        }
+        let result =
+            api_resilience::send(path, RetryPolicy::IDEMPOTENT, || HttpClient::put_empty_with_response(path)).await;
+        invalidate_written(path);
+        result
     }
 
     async fn patch<T: DeserializeOwned, B: Serialize + Send + Sync>(
@@ -733,10 +860,45 @@ impl ApiPort for ApiAdapter {
         path: &str,
         body: &B,
     ) -> Result<T, ApiError> {
-        HttpClient::patch(path, body).await
+        if let Some(demo_api) = demo::api() {
+            return demo_api.patch(path, body).await;
+        }
+        let result = api_resilience::send(path, RetryPolicy::ONCE, || HttpClient::patch(path, body)).await;
+        invalidate_written(path);
+        result
     }
 
     async fn delete(&self, path: &str) -> Result<(), ApiError> {
-        HttpClient::delete(path).await
+        if let Some(demo_api) = demo::api() {
+            return demo_api.delete(path).await;
+        }
+        let result = api_resilience::send_delete(path, || HttpClient::delete(path)).await;
+        invalidate_written(path);
+        result
+    }
+
+    fn open_circuits(&self) -> Vec<OpenCircuit> {
+        api_resilience::global().open_circuits(api_cache::now_millis())
+    }
+}
+
+/// Drop cached lists a write to `path` may have changed
+///
+/// Runs whether or not the write succeeded, since a failed response can
+/// still follow a change on the Engine.
+fn invalidate_written(path: &str) {
+    api_cache::global().invalidate(&CacheKind::touched_by(path));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::origin;
+
+    #[test]
+    fn origin_drops_path_query_and_fragment() {
+        assert_eq!(origin("https://engine.example:8080/api/worlds"), "https://engine.example:8080");
+        assert_eq!(origin("http://localhost:3000?x=1"), "http://localhost:3000");
+        assert_eq!(origin("https://reports.example"), "https://reports.example");
+        assert_ne!(origin("https://engine.example.evil/api"), origin("https://engine.example"));
     }
 }
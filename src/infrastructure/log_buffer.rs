@@ -0,0 +1,121 @@
+//! Ring buffer of recent log lines
+//!
+//! Keeps the last few hundred log lines in memory so a bug report can include
+//! what happened just before the problem. Lines arrive from `tracing` through
+//! [`LogBufferLayer`] and from platform log providers that bypass `tracing`.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+
+use crate::application::ports::outbound::LogRecord;
+
+/// How many lines the global buffer keeps
+pub const LOG_BUFFER_CAPACITY: usize = 500;
+
+/// Fixed-size buffer that drops the oldest line when full
+pub struct LogRingBuffer {
+    capacity: usize,
+    records: Mutex<VecDeque<LogRecord>>,
+}
+
+impl LogRingBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            records: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Append a line, evicting the oldest if the buffer is full
+    pub fn push(&self, record: LogRecord) {
+        let Ok(mut records) = self.records.lock() else {
+            return;
+        };
+        if records.len() == self.capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+
+    /// Copy of all buffered lines, oldest first
+    pub fn snapshot(&self) -> Vec<LogRecord> {
+        self.records
+            .lock()
+            .map(|records| records.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// The process-wide buffer shared by the tracing layer and log providers
+pub fn global() -> &'static LogRingBuffer {
+    static BUFFER: OnceLock<LogRingBuffer> = OnceLock::new();
+    BUFFER.get_or_init(|| LogRingBuffer::new(LOG_BUFFER_CAPACITY))
+}
+
+/// Record a line in the global buffer
+pub fn record(level: &str, message: &str) {
+    global().push(LogRecord {
+        timestamp_ms: chrono::Utc::now().timestamp_millis().max(0) as u64,
+        level: level.to_string(),
+        message: message.to_string(),
+    });
+}
+
+/// `tracing` layer that copies every event into the global buffer
+pub struct LogBufferLayer;
+
+impl<S: Subscriber> Layer<S> for LogBufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        record(
+            metadata.level().as_str(),
+            &format!("{}: {}", metadata.target(), visitor.message),
+        );
+    }
+}
+
+/// Flattens an event's message and fields into one line
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}{}", value, self.message);
+        } else {
+            self.message.push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(message: &str) -> LogRecord {
+        LogRecord {
+            timestamp_ms: 0,
+            level: "INFO".to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_ring_buffer_drops_oldest_when_full() {
+        let buffer = LogRingBuffer::new(2);
+        buffer.push(line("a"));
+        buffer.push(line("b"));
+        buffer.push(line("c"));
+        let messages: Vec<String> = buffer.snapshot().into_iter().map(|r| r.message).collect();
+        assert_eq!(messages, vec!["b", "c"]);
+    }
+}
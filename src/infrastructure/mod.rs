@@ -1,13 +1,17 @@
 //! Infrastructure layer - External adapters
 
 pub mod api;
+pub mod api_cache;
+pub mod api_resilience;
 pub mod connection_factory;
+pub mod demo;
 pub mod http_client;
+pub mod log_buffer;
 pub mod platform;
 pub mod storage;
 pub mod url_handler;
+pub mod webrtc;
 pub mod websocket;
 
-// Test-only infrastructure fakes (ports/adapters).
-#[cfg(test)]
+// Infrastructure fakes (ports/adapters), for unit tests and the demo session.
 pub mod testing;
@@ -4,11 +4,12 @@
 //! standard library and native crates.
 
 use crate::application::ports::outbound::platform::{
-    DocumentProvider, EngineConfigProvider, ConnectionFactoryProvider, LogProvider,
-    Platform, RandomProvider, SleepProvider, StorageProvider, TimeProvider,
+    ClipboardProvider, DocumentProvider, EngineConfigProvider, ConnectionFactoryProvider, GamepadButton,
+    GamepadProvider, LogProvider, LogRecord, Platform, RandomProvider, ResizedImage, ShareOutcome, SleepProvider,
+    StorageProvider, TimeProvider,
 };
-use std::time::{SystemTime, UNIX_EPOCH};
-use std::{future::Future, pin::Pin, sync::Arc};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{future::Future, pin::Pin, sync::{Arc, Mutex}};
 
 /// Desktop time provider using std::time
 #[derive(Clone, Default)]
@@ -90,6 +91,11 @@ impl LogProvider for DesktopLogProvider {
     fn warn(&self, msg: &str) {
         tracing::warn!("{}", msg);
     }
+
+    fn recent(&self) -> Vec<LogRecord> {
+        // Filled by the LogBufferLayer installed in main
+        crate::infrastructure::log_buffer::global().snapshot()
+    }
 }
 
 /// Desktop document provider (no-op for page title)
@@ -100,6 +106,51 @@ impl DocumentProvider for DesktopDocumentProvider {
     fn set_page_title(&self, _title: &str) {
         // No-op on desktop - window title is managed by OS/Dioxus desktop
     }
+
+    fn set_transparent_page(&self, _transparent: bool) {
+        // No-op on desktop - there is no browser source to see through to
+    }
+
+    fn save_file(&self, filename: &str, contents: &str) -> Result<String, String> {
+        let path = std::env::temp_dir().join(filename);
+        std::fs::write(&path, contents).map_err(|e| e.to_string())?;
+        Ok(path.display().to_string())
+    }
+
+    fn print_document(&self, filename: &str, html: &str) -> Result<(), String> {
+        // Printing goes through the system browser, which also offers PDF
+        let path = std::env::temp_dir().join(filename);
+        std::fs::write(&path, html).map_err(|e| e.to_string())?;
+        #[cfg(target_os = "windows")]
+        let mut command = {
+            let mut command = std::process::Command::new("cmd");
+            command.args(["/C", "start", ""]);
+            command
+        };
+        #[cfg(target_os = "macos")]
+        let mut command = std::process::Command::new("open");
+        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+        let mut command = std::process::Command::new("xdg-open");
+        command.arg(&path).spawn().map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+        Ok(())
+    }
+
+    fn platform_description(&self) -> String {
+        format!("Desktop ({} {})", std::env::consts::OS, std::env::consts::ARCH)
+    }
+
+    fn app_origin(&self) -> Option<String> {
+        None
+    }
+
+    fn resize_image(
+        &self,
+        data_base64: String,
+        mime_type: &str,
+        max_dimension: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<ResizedImage, String>> + 'static>> {
+        super::image_resize::resize_image(data_base64, mime_type, max_dimension)
+    }
 }
 
 /// Desktop sleep provider using tokio timer
@@ -114,6 +165,103 @@ impl SleepProvider for DesktopSleepProvider {
     }
 }
 
+/// Stick deflection treated as a D-pad press
+const STICK_THRESHOLD: f32 = 0.5;
+
+/// Desktop gamepad provider using gilrs
+///
+/// gilrs has to be polled from the thread that owns it, so a background
+/// thread keeps the held buttons up to date.
+#[derive(Clone)]
+pub struct DesktopGamepadProvider {
+    held: Arc<Mutex<Vec<GamepadButton>>>,
+}
+
+impl DesktopGamepadProvider {
+    pub fn new() -> Self {
+        let held = Arc::new(Mutex::new(Vec::new()));
+        let shared = held.clone();
+        let spawned = std::thread::Builder::new()
+            .name("gamepad".to_string())
+            .spawn(move || {
+                let mut gilrs = match gilrs::Gilrs::new() {
+                    Ok(gilrs) => gilrs,
+                    Err(e) => {
+                        tracing::warn!("Controller support unavailable: {}", e);
+                        return;
+                    }
+                };
+                loop {
+                    // Draining events keeps gilrs' cached state current
+                    while gilrs.next_event().is_some() {}
+                    let pressed: Vec<GamepadButton> = gilrs
+                        .gamepads()
+                        .flat_map(|(_, pad)| held_buttons(&pad))
+                        .collect();
+                    if let Ok(mut held) = shared.lock() {
+                        *held = pressed;
+                    }
+                    std::thread::sleep(Duration::from_millis(16));
+                }
+            });
+        if let Err(e) = spawned {
+            tracing::warn!("Failed to start controller thread: {}", e);
+        }
+        Self { held }
+    }
+}
+
+impl Default for DesktopGamepadProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn held_buttons(pad: &gilrs::Gamepad<'_>) -> Vec<GamepadButton> {
+    use gilrs::{Axis, Button};
+
+    let x = pad.value(Axis::LeftStickX);
+    // gilrs reports up as positive
+    let y = pad.value(Axis::LeftStickY);
+    [
+        (GamepadButton::Up, pad.is_pressed(Button::DPadUp) || y > STICK_THRESHOLD),
+        (GamepadButton::Down, pad.is_pressed(Button::DPadDown) || y < -STICK_THRESHOLD),
+        (GamepadButton::Left, pad.is_pressed(Button::DPadLeft) || x < -STICK_THRESHOLD),
+        (GamepadButton::Right, pad.is_pressed(Button::DPadRight) || x > STICK_THRESHOLD),
+        (GamepadButton::A, pad.is_pressed(Button::South)),
+        (GamepadButton::B, pad.is_pressed(Button::East)),
+        (GamepadButton::Start, pad.is_pressed(Button::Start)),
+    ]
+    .into_iter()
+    .filter_map(|(button, down)| down.then_some(button))
+    .collect()
+}
+
+impl GamepadProvider for DesktopGamepadProvider {
+    fn pressed_buttons(&self) -> Vec<GamepadButton> {
+        self.held.lock().map(|held| held.clone()).unwrap_or_default()
+    }
+}
+
+/// Desktop clipboard provider using arboard
+///
+/// There is no share sheet on desktop, so sharing copies the link.
+#[derive(Clone, Default)]
+pub struct DesktopClipboardProvider;
+
+impl ClipboardProvider for DesktopClipboardProvider {
+    fn copy_text(&self, text: &str) -> Result<(), String> {
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(text))
+            .map_err(|e| e.to_string())
+    }
+
+    fn share(&self, _title: &str, text: &str, url: &str) -> Result<ShareOutcome, String> {
+        self.copy_text(&format!("{}\n{}", text, url))?;
+        Ok(ShareOutcome::Copied)
+    }
+}
+
 /// Desktop engine configuration provider
 #[derive(Clone, Default)]
 pub struct DesktopEngineConfigProvider;
@@ -137,6 +285,10 @@ impl EngineConfigProvider for DesktopEngineConfigProvider {
             url
         }
     }
+
+    fn set_auth_token(&self, token: Option<&str>) {
+        crate::infrastructure::api::set_auth_token(token);
+    }
 }
 
 /// Desktop connection factory provider
@@ -158,6 +310,8 @@ pub fn create_platform() -> Platform {
         DesktopStorageProvider,
         DesktopLogProvider,
         DesktopDocumentProvider,
+        DesktopGamepadProvider::new(),
+        DesktopClipboardProvider,
         DesktopEngineConfigProvider,
         DesktopConnectionFactoryProvider,
     )
@@ -0,0 +1,66 @@
+//! Image resizing through the page's canvas
+//!
+//! Both the browser and the desktop webview can decode and re-encode images,
+//! so the web and desktop document providers share this instead of pulling
+//! in an image codec crate.
+
+use std::{future::Future, pin::Pin};
+
+use dioxus::document;
+
+use crate::application::ports::outbound::platform::ResizedImage;
+
+/// Decodes the image, draws it scaled onto a canvas and re-encodes it
+///
+/// Receives `[data_base64, mime_type, max_dimension]` and returns
+/// `[data_base64, width, height, resized]`, or an error string.
+const RESIZE_JS: &str = r#"
+const [data, mime, max] = await dioxus.recv();
+const img = new Image();
+try {
+    await new Promise((resolve, reject) => {
+        img.onload = resolve;
+        img.onerror = () => reject(new Error('not a readable image'));
+        img.src = `data:${mime};base64,${data}`;
+    });
+} catch (e) {
+    return { error: e.message };
+}
+const scale = Math.min(1, max / Math.max(img.naturalWidth, img.naturalHeight));
+if (scale >= 1) {
+    return { ok: [data, img.naturalWidth, img.naturalHeight, false] };
+}
+const canvas = document.createElement('canvas');
+canvas.width = Math.max(1, Math.round(img.naturalWidth * scale));
+canvas.height = Math.max(1, Math.round(img.naturalHeight * scale));
+const ctx = canvas.getContext('2d');
+ctx.imageSmoothingQuality = 'high';
+ctx.drawImage(img, 0, 0, canvas.width, canvas.height);
+const url = canvas.toDataURL(mime, 0.92);
+return { ok: [url.slice(url.indexOf(',') + 1), canvas.width, canvas.height, true] };
+"#;
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ResizeResult {
+    Ok((String, u32, u32, bool)),
+    Error(String),
+}
+
+pub(super) fn resize_image(
+    data_base64: String,
+    mime_type: &str,
+    max_dimension: u32,
+) -> Pin<Box<dyn Future<Output = Result<ResizedImage, String>> + 'static>> {
+    let mime_type = mime_type.to_string();
+    Box::pin(async move {
+        let eval = document::eval(RESIZE_JS);
+        eval.send((data_base64, mime_type, max_dimension)).map_err(|e| e.to_string())?;
+        match eval.join::<ResizeResult>().await.map_err(|e| e.to_string())? {
+            ResizeResult::Ok((data_base64, width, height, resized)) => {
+                Ok(ResizedImage { data_base64, width, height, resized })
+            }
+            ResizeResult::Error(message) => Err(message),
+        }
+    })
+}
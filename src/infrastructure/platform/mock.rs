@@ -4,8 +4,9 @@
 //! for deterministic testing.
 
 use crate::application::ports::outbound::platform::{
-    DocumentProvider, EngineConfigProvider, ConnectionFactoryProvider, LogProvider,
-    Platform, RandomProvider, SleepProvider, StorageProvider, TimeProvider,
+    ClipboardProvider, DocumentProvider, EngineConfigProvider, ConnectionFactoryProvider, GamepadButton,
+    GamepadProvider, LogProvider, LogRecord, Platform, RandomProvider, ResizedImage, ShareOutcome, SleepProvider,
+    StorageProvider, TimeProvider,
 };
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
@@ -213,6 +214,17 @@ impl LogProvider for MockLogProvider {
             .unwrap()
             .push(("WARN".to_string(), msg.to_string()));
     }
+
+    fn recent(&self) -> Vec<LogRecord> {
+        self.get_logs()
+            .into_iter()
+            .map(|(level, message)| LogRecord {
+                timestamp_ms: 0,
+                level,
+                message,
+            })
+            .collect()
+    }
 }
 
 /// Mock document provider that tracks page title changes
@@ -236,6 +248,34 @@ impl DocumentProvider for MockDocumentProvider {
     fn set_page_title(&self, title: &str) {
         *self.title.write().unwrap() = Some(title.to_string());
     }
+
+    fn set_transparent_page(&self, _transparent: bool) {}
+
+    fn save_file(&self, filename: &str, _contents: &str) -> Result<String, String> {
+        Ok(filename.to_string())
+    }
+
+    fn print_document(&self, _filename: &str, _html: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn platform_description(&self) -> String {
+        "Mock".to_string()
+    }
+
+    fn app_origin(&self) -> Option<String> {
+        None
+    }
+
+    fn resize_image(
+        &self,
+        data_base64: String,
+        _mime_type: &str,
+        _max_dimension: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<ResizedImage, String>> + 'static>> {
+        // No decoder here: the image passes through with unknown dimensions
+        Box::pin(async move { Ok(ResizedImage { data_base64, width: 0, height: 0, resized: false }) })
+    }
 }
 
 /// Mock sleep provider (immediate)
@@ -248,10 +288,65 @@ impl SleepProvider for MockSleepProvider {
     }
 }
 
+/// Mock gamepad provider with controllable held buttons
+#[derive(Clone, Default)]
+pub struct MockGamepadProvider {
+    held: Arc<RwLock<Vec<GamepadButton>>>,
+}
+
+impl MockGamepadProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the buttons reported as held
+    pub fn hold(&self, buttons: &[GamepadButton]) {
+        *self.held.write().unwrap() = buttons.to_vec();
+    }
+}
+
+impl GamepadProvider for MockGamepadProvider {
+    fn pressed_buttons(&self) -> Vec<GamepadButton> {
+        self.held.read().unwrap().clone()
+    }
+}
+
+/// Mock clipboard provider that records everything copied
+///
+/// Has no share sheet, so shares are copied like on desktop.
+#[derive(Clone, Default)]
+pub struct MockClipboardProvider {
+    copied: Arc<RwLock<Vec<String>>>,
+}
+
+impl MockClipboardProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Everything copied so far, oldest first
+    pub fn copied(&self) -> Vec<String> {
+        self.copied.read().unwrap().clone()
+    }
+}
+
+impl ClipboardProvider for MockClipboardProvider {
+    fn copy_text(&self, text: &str) -> Result<(), String> {
+        self.copied.write().unwrap().push(text.to_string());
+        Ok(())
+    }
+
+    fn share(&self, _title: &str, text: &str, url: &str) -> Result<ShareOutcome, String> {
+        self.copy_text(&format!("{}\n{}", text, url))?;
+        Ok(ShareOutcome::Copied)
+    }
+}
+
 /// Mock engine configuration provider
 #[derive(Clone, Default)]
 pub struct MockEngineConfigProvider {
     configured_url: Arc<RwLock<Option<String>>>,
+    auth_token: Arc<RwLock<Option<String>>>,
 }
 
 impl MockEngineConfigProvider {
@@ -263,6 +358,11 @@ impl MockEngineConfigProvider {
     pub fn get_configured_url(&self) -> Option<String> {
         self.configured_url.read().unwrap().clone()
     }
+
+    /// Get the currently active auth token
+    pub fn get_auth_token(&self) -> Option<String> {
+        self.auth_token.read().unwrap().clone()
+    }
 }
 
 impl EngineConfigProvider for MockEngineConfigProvider {
@@ -277,6 +377,10 @@ impl EngineConfigProvider for MockEngineConfigProvider {
             .trim_end_matches("/ws")
             .to_string()
     }
+
+    fn set_auth_token(&self, token: Option<&str>) {
+        *self.auth_token.write().unwrap() = token.map(|t| t.to_string());
+    }
 }
 
 /// Mock connection factory provider
@@ -306,6 +410,8 @@ pub fn create_mock_platform() -> Platform {
         MockStorageProvider::default(),
         MockLogProvider::default(),
         MockDocumentProvider::default(),
+        MockGamepadProvider::default(),
+        MockClipboardProvider::default(),
         MockEngineConfigProvider::default(),
         MockConnectionFactoryProvider::default(),
     )
@@ -319,6 +425,8 @@ pub struct MockPlatformBuilder {
     storage: MockStorageProvider,
     log: MockLogProvider,
     document: MockDocumentProvider,
+    gamepad: MockGamepadProvider,
+    clipboard: MockClipboardProvider,
     engine_config: MockEngineConfigProvider,
     connection_factory: MockConnectionFactoryProvider,
 }
@@ -338,6 +446,8 @@ impl MockPlatformBuilder {
             storage: MockStorageProvider::default(),
             log: MockLogProvider::default(),
             document: MockDocumentProvider::default(),
+            gamepad: MockGamepadProvider::default(),
+            clipboard: MockClipboardProvider::default(),
             engine_config: MockEngineConfigProvider::default(),
             connection_factory: MockConnectionFactoryProvider::default(),
         }
@@ -358,6 +468,18 @@ impl MockPlatformBuilder {
         self
     }
 
+    /// Use `gamepad`, keeping a handle to change which buttons are held
+    pub fn with_gamepad(mut self, gamepad: MockGamepadProvider) -> Self {
+        self.gamepad = gamepad;
+        self
+    }
+
+    /// Use `clipboard`, keeping a handle to inspect what was copied
+    pub fn with_clipboard(mut self, clipboard: MockClipboardProvider) -> Self {
+        self.clipboard = clipboard;
+        self
+    }
+
     pub fn build(self) -> Platform {
         Platform::new(
             self.time,
@@ -366,6 +488,8 @@ impl MockPlatformBuilder {
             self.storage,
             self.log,
             self.document,
+            self.gamepad,
+            self.clipboard,
             self.engine_config,
             self.connection_factory,
         )
@@ -13,6 +13,9 @@ mod desktop;
 
 pub mod mock;
 
+// Canvas resizing shared by the web and desktop document providers
+mod image_resize;
+
 // Re-export the platform-specific module
 #[cfg(target_arch = "wasm32")]
 pub use wasm::*;
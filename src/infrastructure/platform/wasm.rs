@@ -4,9 +4,12 @@
 //! js_sys and web_sys crates.
 
 use crate::application::ports::outbound::platform::{
-    DocumentProvider, EngineConfigProvider, ConnectionFactoryProvider, LogProvider,
-    Platform, RandomProvider, SleepProvider, StorageProvider, TimeProvider,
+    ClipboardProvider, DocumentProvider, EngineConfigProvider, ConnectionFactoryProvider, GamepadButton,
+    GamepadProvider, LogProvider, LogRecord, Platform, RandomProvider, ResizedImage, ShareOutcome, SleepProvider,
+    StorageProvider, TimeProvider,
 };
+use crate::infrastructure::log_buffer;
+use wasm_bindgen::JsCast;
 use std::{future::Future, pin::Pin, sync::Arc};
 
 /// WASM time provider using js_sys::Date
@@ -75,33 +78,122 @@ impl StorageProvider for WasmStorageProvider {
 pub struct WasmLogProvider;
 
 impl LogProvider for WasmLogProvider {
+    // These go straight to the console, bypassing tracing, so record them here
     fn info(&self, msg: &str) {
+        log_buffer::record("INFO", msg);
         web_sys::console::log_1(&msg.into());
     }
 
     fn error(&self, msg: &str) {
+        log_buffer::record("ERROR", msg);
         web_sys::console::error_1(&msg.into());
     }
 
     fn debug(&self, msg: &str) {
+        log_buffer::record("DEBUG", msg);
         web_sys::console::debug_1(&msg.into());
     }
 
     fn warn(&self, msg: &str) {
+        log_buffer::record("WARN", msg);
         web_sys::console::warn_1(&msg.into());
     }
+
+    fn recent(&self) -> Vec<LogRecord> {
+        log_buffer::global().snapshot()
+    }
 }
 
 /// WASM document provider for browser document operations
 #[derive(Clone, Default)]
 pub struct WasmDocumentProvider;
 
+/// Content type for a downloaded file, from its extension
+fn mime_type(filename: &str) -> &'static str {
+    match filename.rsplit('.').next() {
+        Some("html") => "text/html",
+        Some("json") => "application/json",
+        _ => "text/plain",
+    }
+}
+
 impl DocumentProvider for WasmDocumentProvider {
     fn set_page_title(&self, title: &str) {
         if let Some(document) = web_sys::window().and_then(|w| w.document()) {
             document.set_title(&format!("{} | WrldBldr", title));
         }
     }
+
+    fn set_transparent_page(&self, transparent: bool) {
+        // The stylesheet clears the html and body backgrounds under this attribute
+        if let Some(root) = web_sys::window().and_then(|w| w.document()).and_then(|d| d.document_element()) {
+            let _ = if transparent {
+                root.set_attribute("data-transparent-page", "")
+            } else {
+                root.remove_attribute("data-transparent-page")
+            };
+        }
+    }
+
+    fn save_file(&self, filename: &str, contents: &str) -> Result<String, String> {
+        // Download via a temporary object URL on an invisible link
+        let document = web_sys::window()
+            .and_then(|w| w.document())
+            .ok_or("No document available")?;
+        let parts = js_sys::Array::of1(&contents.into());
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type(mime_type(filename));
+        let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)
+            .map_err(|e| format!("{:?}", e))?;
+        let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(|e| format!("{:?}", e))?;
+        let anchor = document
+            .create_element("a")
+            .map_err(|e| format!("{:?}", e))?
+            .dyn_into::<web_sys::HtmlAnchorElement>()
+            .map_err(|_| "Failed to create download link")?;
+        anchor.set_href(&url);
+        anchor.set_download(filename);
+        anchor.click();
+        let _ = web_sys::Url::revoke_object_url(&url);
+        Ok(filename.to_string())
+    }
+
+    fn print_document(&self, _filename: &str, html: &str) -> Result<(), String> {
+        // The page prints itself on load; the object URL stays alive for the
+        // new tab and is released with this document
+        let window = web_sys::window().ok_or("No window available")?;
+        let parts = js_sys::Array::of1(&html.into());
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type("text/html");
+        let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)
+            .map_err(|e| format!("{:?}", e))?;
+        let url = web_sys::Url::create_object_url_with_blob(&blob).map_err(|e| format!("{:?}", e))?;
+        match window.open_with_url_and_target(&url, "_blank") {
+            Ok(Some(_)) => Ok(()),
+            Ok(None) => Err("The print window was blocked; allow pop-ups for this site".to_string()),
+            Err(e) => Err(format!("{:?}", e)),
+        }
+    }
+
+    fn platform_description(&self) -> String {
+        web_sys::window()
+            .and_then(|w| w.navigator().user_agent().ok())
+            .map(|ua| format!("Web ({})", ua))
+            .unwrap_or_else(|| "Web".to_string())
+    }
+
+    fn app_origin(&self) -> Option<String> {
+        web_sys::window().and_then(|w| w.location().origin().ok())
+    }
+
+    fn resize_image(
+        &self,
+        data_base64: String,
+        mime_type: &str,
+        max_dimension: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<ResizedImage, String>> + 'static>> {
+        super::image_resize::resize_image(data_base64, mime_type, max_dimension)
+    }
 }
 
 /// WASM sleep provider using gloo timers
@@ -116,6 +208,118 @@ impl SleepProvider for WasmSleepProvider {
     }
 }
 
+/// Stick deflection treated as a D-pad press
+const STICK_THRESHOLD: f64 = 0.5;
+
+/// WASM gamepad provider using the browser Gamepad API
+///
+/// Button indices follow the W3C "standard" mapping.
+#[derive(Clone, Default)]
+pub struct WasmGamepadProvider;
+
+impl GamepadProvider for WasmGamepadProvider {
+    fn pressed_buttons(&self) -> Vec<GamepadButton> {
+        let Some(pads) = web_sys::window().and_then(|w| w.navigator().get_gamepads().ok()) else {
+            return Vec::new();
+        };
+
+        let mut pressed = Vec::new();
+        // Disconnected slots are null
+        for pad in pads.iter().filter_map(|p| p.dyn_into::<web_sys::Gamepad>().ok()) {
+            let buttons = pad.buttons();
+            let is_down = |index: u32| {
+                buttons
+                    .get(index)
+                    .dyn_into::<web_sys::GamepadButton>()
+                    .map(|b| b.pressed())
+                    .unwrap_or(false)
+            };
+            let axes = pad.axes();
+            let x = axes.get(0).as_f64().unwrap_or(0.0);
+            // Browsers report down as positive
+            let y = axes.get(1).as_f64().unwrap_or(0.0);
+
+            for (button, down) in [
+                (GamepadButton::Up, is_down(12) || y < -STICK_THRESHOLD),
+                (GamepadButton::Down, is_down(13) || y > STICK_THRESHOLD),
+                (GamepadButton::Left, is_down(14) || x < -STICK_THRESHOLD),
+                (GamepadButton::Right, is_down(15) || x > STICK_THRESHOLD),
+                (GamepadButton::A, is_down(0)),
+                (GamepadButton::B, is_down(1)),
+                (GamepadButton::Start, is_down(9)),
+            ] {
+                if down {
+                    pressed.push(button);
+                }
+            }
+        }
+        pressed
+    }
+}
+
+/// WASM clipboard provider using the async Clipboard and Web Share APIs
+///
+/// Both APIs are looked up at runtime since neither is available in every
+/// browser (or outside a secure context). Their promises are awaited in the
+/// background, so a late rejection is only logged.
+#[derive(Clone, Default)]
+pub struct WasmClipboardProvider;
+
+impl WasmClipboardProvider {
+    /// `navigator[name]`, when it is a method
+    fn navigator_method(name: &str) -> Option<(web_sys::Navigator, js_sys::Function)> {
+        let navigator = web_sys::window()?.navigator();
+        let method = js_sys::Reflect::get(&navigator, &name.into())
+            .ok()?
+            .dyn_into::<js_sys::Function>()
+            .ok()?;
+        Some((navigator, method))
+    }
+
+    fn settle_in_background(promise: wasm_bindgen::JsValue, what: &'static str) {
+        if let Ok(promise) = promise.dyn_into::<js_sys::Promise>() {
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Err(e) = wasm_bindgen_futures::JsFuture::from(promise).await {
+                    web_sys::console::warn_1(&format!("{} failed: {:?}", what, e).into());
+                }
+            });
+        }
+    }
+}
+
+impl ClipboardProvider for WasmClipboardProvider {
+    fn copy_text(&self, text: &str) -> Result<(), String> {
+        let navigator = web_sys::window().ok_or("No window available")?.navigator();
+        let clipboard = js_sys::Reflect::get(&navigator, &"clipboard".into())
+            .ok()
+            .filter(|c| !c.is_undefined())
+            .ok_or("Clipboard is not available (it needs HTTPS)")?;
+        let write_text = js_sys::Reflect::get(&clipboard, &"writeText".into())
+            .ok()
+            .and_then(|f| f.dyn_into::<js_sys::Function>().ok())
+            .ok_or("Clipboard is not available")?;
+        let promise = write_text
+            .call1(&clipboard, &text.into())
+            .map_err(|e| format!("{:?}", e))?;
+        Self::settle_in_background(promise, "Copy to clipboard");
+        Ok(())
+    }
+
+    fn share(&self, title: &str, text: &str, url: &str) -> Result<ShareOutcome, String> {
+        let Some((navigator, share)) = Self::navigator_method("share") else {
+            self.copy_text(&format!("{}\n{}", text, url))?;
+            return Ok(ShareOutcome::Copied);
+        };
+        let data = js_sys::Object::new();
+        for (key, value) in [("title", title), ("text", text), ("url", url)] {
+            js_sys::Reflect::set(&data, &key.into(), &value.into()).map_err(|e| format!("{:?}", e))?;
+        }
+        let promise = share.call1(&navigator, &data).map_err(|e| format!("{:?}", e))?;
+        Self::settle_in_background(promise, "Share");
+        Ok(ShareOutcome::Shared)
+    }
+}
+
 /// WASM engine configuration provider
 #[derive(Clone, Default)]
 pub struct WasmEngineConfigProvider;
@@ -129,6 +333,10 @@ impl EngineConfigProvider for WasmEngineConfigProvider {
     fn ws_to_http(&self, ws_url: &str) -> String {
         crate::infrastructure::api::ws_to_http(ws_url)
     }
+
+    fn set_auth_token(&self, token: Option<&str>) {
+        crate::infrastructure::api::set_auth_token(token);
+    }
 }
 
 /// WASM connection factory provider
@@ -150,6 +358,8 @@ pub fn create_platform() -> Platform {
         WasmStorageProvider,
         WasmLogProvider,
         WasmDocumentProvider,
+        WasmGamepadProvider,
+        WasmClipboardProvider,
         WasmEngineConfigProvider,
         WasmConnectionFactoryProvider,
     )
@@ -45,9 +45,11 @@ enum Response {
 struct State {
     responses: HashMap<Key, Response>,
     requests: Vec<RequestRecord>,
+    /// Serve responses every time instead of once
+    standing: bool,
 }
 
-/// Mock implementation of `ApiPort` for unit tests.
+/// Mock implementation of `ApiPort` for unit tests and the demo session.
 ///
 /// Stores a map of pre-programmed responses keyed by method + path,
 /// and records all requests made for assertion.
@@ -61,6 +63,14 @@ impl MockApiPort {
         Self::default()
     }
 
+    /// A mock whose responses answer every matching request, not just the
+    /// first, so it can stand in for the Engine over a whole session
+    pub fn with_standing_responses() -> Self {
+        let api = Self::default();
+        api.state.lock().unwrap().standing = true;
+        api
+    }
+
     pub fn requests(&self) -> Vec<RequestRecord> {
         self.state.lock().unwrap().requests.clone()
     }
@@ -120,9 +130,14 @@ impl MockApiPort {
 
     fn take_response(&self, key: Key) -> Result<Response, ApiError> {
         let mut state = self.state.lock().unwrap();
-        match state.responses.remove(&key) {
+        let response = if state.standing {
+            state.responses.get(&key).cloned()
+        } else {
+            state.responses.remove(&key)
+        };
+        match response {
             Some(resp) => Ok(resp),
-            None => Err(ApiError::RequestFailed(format!(
+            None => Err(ApiError::Network(format!(
                 "No mock response configured for {:?} {}",
                 key.method, key.path
             ))),
@@ -132,10 +147,10 @@ impl MockApiPort {
     fn decode<T: DeserializeOwned>(resp: Response) -> Result<T, ApiError> {
         match resp {
             Response::Json(v) => serde_json::from_value::<T>(v)
-                .map_err(|e| ApiError::ParseError(e.to_string())),
+                .map_err(|e| ApiError::Deserialize(e.to_string())),
             Response::Err(e) => Err(e),
-            Response::NotFound => Err(ApiError::HttpError(404, "Not found".to_string())),
-            Response::Ok => Err(ApiError::ParseError("Expected JSON response".to_string())),
+            Response::NotFound => Err(ApiError::NotFound("Not found".to_string())),
+            Response::Ok => Err(ApiError::Deserialize("Expected JSON response".to_string())),
         }
     }
 }
@@ -259,8 +274,8 @@ impl ApiPort for MockApiPort {
         match resp {
             Response::Ok => Ok(()),
             Response::Err(e) => Err(e),
-            Response::NotFound => Err(ApiError::HttpError(404, "Not found".to_string())),
-            Response::Json(_) => Err(ApiError::ParseError("Expected unit response".to_string())),
+            Response::NotFound => Err(ApiError::NotFound("Not found".to_string())),
+            Response::Json(_) => Err(ApiError::Deserialize("Expected unit response".to_string())),
         }
     }
 
@@ -273,8 +288,8 @@ impl ApiPort for MockApiPort {
         match resp {
             Response::Ok => Ok(()),
             Response::Err(e) => Err(e),
-            Response::NotFound => Err(ApiError::HttpError(404, "Not found".to_string())),
-            Response::Json(_) => Err(ApiError::ParseError("Expected unit response".to_string())),
+            Response::NotFound => Err(ApiError::NotFound("Not found".to_string())),
+            Response::Json(_) => Err(ApiError::Deserialize("Expected unit response".to_string())),
         }
     }
 
@@ -306,8 +321,8 @@ impl ApiPort for MockApiPort {
         match resp {
             Response::Ok => Ok(()),
             Response::Err(e) => Err(e),
-            Response::NotFound => Err(ApiError::HttpError(404, "Not found".to_string())),
-            Response::Json(_) => Err(ApiError::ParseError("Expected unit response".to_string())),
+            Response::NotFound => Err(ApiError::NotFound("Not found".to_string())),
+            Response::Json(_) => Err(ApiError::Deserialize("Expected unit response".to_string())),
         }
     }
 
@@ -320,8 +335,8 @@ impl ApiPort for MockApiPort {
         match resp {
             Response::Ok => Ok(()),
             Response::Err(e) => Err(e),
-            Response::NotFound => Err(ApiError::HttpError(404, "Not found".to_string())),
-            Response::Json(_) => Err(ApiError::ParseError("Expected unit response".to_string())),
+            Response::NotFound => Err(ApiError::NotFound("Not found".to_string())),
+            Response::Json(_) => Err(ApiError::Deserialize("Expected unit response".to_string())),
         }
     }
 
@@ -339,7 +354,8 @@ impl ApiPort for MockApiPort {
         path: &str,
         body: &B,
     ) -> Result<T, ApiError> {
-        self.record("PATCH", path, Some(body));
+        let json_body = serde_json::to_value(body).ok();
+        self.record("PATCH", path, json_body);
         let resp = self.take_response(Key {
             method: Method::Patch,
             path: path.to_string(),
@@ -356,8 +372,8 @@ impl ApiPort for MockApiPort {
         match resp {
             Response::Ok => Ok(()),
             Response::Err(e) => Err(e),
-            Response::NotFound => Err(ApiError::HttpError(404, "Not found".to_string())),
-            Response::Json(_) => Err(ApiError::ParseError("Expected unit response".to_string())),
+            Response::NotFound => Err(ApiError::NotFound("Not found".to_string())),
+            Response::Json(_) => Err(ApiError::Deserialize("Expected unit response".to_string())),
         }
     }
 }
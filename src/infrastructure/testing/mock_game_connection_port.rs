@@ -1,7 +1,9 @@
 use std::sync::{Arc, Mutex};
 
+use crate::application::dto::{Annotation, AnnotationLayer, AnnotationSurface, CameraEffect, MemoryDraft, RegionPopulation, RevealScope, SceneMood, SessionRecap, SharedNoteDoc, StagedScene};
 use crate::application::ports::outbound::{
-    ApprovalDecision, ChallengeOutcomeDecisionData, ConnectionState, DirectorialContext, GameConnectionPort, ParticipantRole,
+    AdHocOutcomes, AdvancementGrant, ApprovalDecision, ChallengeOutcomeDecisionData, ConnectionState, DecisionTimerSettings, DialoguePacing, DirectorialContext,
+    GameConnectionPort, LevelUpChoices, NewCondition, ParticipantRole, PresenceMode, PresenceSignalData, SpectatorPolicy, TimedDecision,
 };
 
 #[derive(Debug, Clone)]
@@ -9,6 +11,8 @@ pub struct SentAction {
     pub action_type: String,
     pub target: Option<String>,
     pub dialogue: Option<String>,
+    /// Character the DM possessed to send this, if any
+    pub acting_as: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -34,6 +38,27 @@ pub struct SentChallengeTrigger {
     pub target_character_id: String,
 }
 
+/// An outbound call a scripted responder can answer in place of the Engine
+#[derive(Debug, Clone)]
+pub enum MockCall {
+    Join(SentJoin),
+    Action(SentAction),
+    Approval(SentApproval),
+    Disconnect,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type StateCallback = Box<dyn FnMut(ConnectionState) + Send + 'static>;
+#[cfg(target_arch = "wasm32")]
+type StateCallback = Box<dyn FnMut(ConnectionState) + 'static>;
+
+#[cfg(not(target_arch = "wasm32"))]
+type MessageCallback = Box<dyn FnMut(serde_json::Value) + Send + 'static>;
+#[cfg(target_arch = "wasm32")]
+type MessageCallback = Box<dyn FnMut(serde_json::Value) + 'static>;
+
+type Responder = Box<dyn FnMut(&MockCall) -> Vec<serde_json::Value> + Send + 'static>;
+
 struct State {
     conn_state: ConnectionState,
     sent_joins: Vec<SentJoin>,
@@ -44,8 +69,9 @@ struct State {
     sent_challenge_triggers: Vec<SentChallengeTrigger>,
     sent_rolls: Vec<(String, i32)>,
 
-    on_state_change: Option<Box<dyn FnMut(ConnectionState) + Send + 'static>>,
-    on_message: Option<Box<dyn FnMut(serde_json::Value) + Send + 'static>>,
+    on_state_change: Option<StateCallback>,
+    on_message: Option<MessageCallback>,
+    responder: Option<Responder>,
 }
 
 impl Default for State {
@@ -61,13 +87,17 @@ impl Default for State {
             sent_rolls: Vec::new(),
             on_state_change: None,
             on_message: None,
+            responder: None,
         }
     }
 }
 
-/// Mock `GameConnectionPort` for tests.
+/// Mock `GameConnectionPort` for tests and the demo session.
 ///
 /// Lets tests drive connection state + inbound messages and assert outbound calls.
+/// With a responder installed it connects straight away, answers joins,
+/// actions and approvals with scripted server messages, and is told when
+/// the client disconnects.
 #[derive(Clone, Default)]
 pub struct MockGameConnectionPort {
     url: Arc<str>,
@@ -84,18 +114,42 @@ impl MockGameConnectionPort {
         }
     }
 
+    /// Answer joins, actions and approvals with the messages `responder` returns
+    pub fn respond_with(&self, responder: impl FnMut(&MockCall) -> Vec<serde_json::Value> + Send + 'static) {
+        self.state.lock().unwrap().responder = Some(Box::new(responder));
+    }
+
+    // Callbacks are taken out of the state while they run, since they may
+    // call back into the mock (e.g. joining on Connected)
+
     pub fn set_state(&self, new_state: ConnectionState) {
-        let mut s = self.state.lock().unwrap();
-        s.conn_state = new_state;
-        if let Some(cb) = s.on_state_change.as_mut() {
+        let callback = {
+            let mut s = self.state.lock().unwrap();
+            s.conn_state = new_state;
+            s.on_state_change.take()
+        };
+        if let Some(mut cb) = callback {
             cb(new_state);
+            self.state.lock().unwrap().on_state_change.get_or_insert(cb);
         }
     }
 
     pub fn emit_message(&self, value: serde_json::Value) {
-        let mut s = self.state.lock().unwrap();
-        if let Some(cb) = s.on_message.as_mut() {
+        let callback = self.state.lock().unwrap().on_message.take();
+        if let Some(mut cb) = callback {
             cb(value);
+            self.state.lock().unwrap().on_message.get_or_insert(cb);
+        }
+    }
+
+    fn respond(&self, call: MockCall) {
+        let responder = self.state.lock().unwrap().responder.take();
+        if let Some(mut responder) = responder {
+            let replies = responder(&call);
+            self.state.lock().unwrap().responder.get_or_insert(responder);
+            for reply in replies {
+                self.emit_message(reply);
+            }
         }
     }
 
@@ -118,21 +172,26 @@ impl GameConnectionPort for MockGameConnectionPort {
     }
 
     fn connect(&self) -> anyhow::Result<()> {
-        // Tests can drive state via `set_state`.
+        // Scripted mocks connect at once; tests drive state via `set_state`.
+        let scripted = self.state.lock().unwrap().responder.is_some();
+        if scripted {
+            self.set_state(ConnectionState::Connected);
+        }
         Ok(())
     }
 
     fn disconnect(&self) {
-        let mut s = self.state.lock().unwrap();
-        s.conn_state = ConnectionState::Disconnected;
+        self.state.lock().unwrap().conn_state = ConnectionState::Disconnected;
+        self.respond(MockCall::Disconnect);
     }
 
-    fn join_session(&self, user_id: &str, role: ParticipantRole) -> anyhow::Result<()> {
-        let mut s = self.state.lock().unwrap();
-        s.sent_joins.push(SentJoin {
+    fn join_session(&self, user_id: &str, role: ParticipantRole, _world_id: Option<String>) -> anyhow::Result<()> {
+        let join = SentJoin {
             user_id: user_id.to_string(),
             role,
-        });
+        };
+        self.state.lock().unwrap().sent_joins.push(join.clone());
+        self.respond(MockCall::Join(join));
         Ok(())
     }
 
@@ -142,12 +201,14 @@ impl GameConnectionPort for MockGameConnectionPort {
         target: Option<&str>,
         dialogue: Option<&str>,
     ) -> anyhow::Result<()> {
-        let mut s = self.state.lock().unwrap();
-        s.sent_actions.push(SentAction {
+        let action = SentAction {
             action_type: action_type.to_string(),
             target: target.map(|s| s.to_string()),
             dialogue: dialogue.map(|s| s.to_string()),
-        });
+            acting_as: None,
+        };
+        self.state.lock().unwrap().sent_actions.push(action.clone());
+        self.respond(MockCall::Action(action));
         Ok(())
     }
 
@@ -166,11 +227,12 @@ impl GameConnectionPort for MockGameConnectionPort {
     }
 
     fn send_approval_decision(&self, request_id: &str, decision: ApprovalDecision) -> anyhow::Result<()> {
-        let mut s = self.state.lock().unwrap();
-        s.sent_approvals.push(SentApproval {
+        let approval = SentApproval {
             request_id: request_id.to_string(),
             decision,
-        });
+        };
+        self.state.lock().unwrap().sent_approvals.push(approval.clone());
+        self.respond(MockCall::Approval(approval));
         Ok(())
     }
 
@@ -188,6 +250,17 @@ impl GameConnectionPort for MockGameConnectionPort {
         Ok(())
     }
 
+    fn create_adhoc_challenge(
+        &self,
+        _challenge_name: &str,
+        _skill_name: &str,
+        _difficulty: &str,
+        _target_pc_id: &str,
+        _outcomes: AdHocOutcomes,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     fn submit_challenge_roll(&self, challenge_id: &str, roll: i32) -> anyhow::Result<()> {
         let mut s = self.state.lock().unwrap();
         s.sent_rolls.push((challenge_id.to_string(), roll));
@@ -198,6 +271,7 @@ impl GameConnectionPort for MockGameConnectionPort {
         // For mock purposes, extract the value and use the existing roll tracking
         let roll_value = match &input {
             crate::application::dto::websocket_messages::DiceInputType::Manual(v) => *v,
+            crate::application::dto::websocket_messages::DiceInputType::ManualDice(entry) => entry.dice_total(),
             crate::application::dto::websocket_messages::DiceInputType::Formula(_) => 0, // Formula parsing not implemented in mock
         };
         let mut s = self.state.lock().unwrap();
@@ -209,12 +283,213 @@ impl GameConnectionPort for MockGameConnectionPort {
         Ok(())
     }
 
-    fn on_state_change(&self, callback: Box<dyn FnMut(ConnectionState) + Send + 'static>) {
+    fn move_to_region(&self, _pc_id: &str, _region_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn exit_to_location(&self, _pc_id: &str, _location_id: &str, _arrival_region_id: Option<&str>) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn set_presence_mode(&self, _mode: PresenceMode) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn send_presence_signal(&self, _to_user_id: &str, _signal: PresenceSignalData) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn pause_session(&self, _message: Option<&str>, _art_asset: Option<&str>) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn resume_session(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn assign_role(&self, _user_id: &str, _role: ParticipantRole) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn send_whisper(&self, _text: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn reply_to_whisper(&self, _to_user_id: &str, _text: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn set_spectator_policy(&self, _policy: SpectatorPolicy) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn set_staged_scenes(&self, _scenes: Vec<StagedScene>) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn focus_staged_scene(&self, _scene_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn set_async_play(&self, _enabled: bool) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn process_inbox_actions(&self, _action_ids: Vec<String>) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn discard_inbox_action(&self, _action_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn grant_advancement(&self, _pc_id: &str, _grant: AdvancementGrant) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn submit_level_up(&self, _pc_id: &str, _choices: LevelUpChoices) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn speak_as(&self, _npc_id: &str, _text: &str, _record_as_approved: bool) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn set_dialogue_pacing(&self, _pacing: DialoguePacing) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn mark_dialogue_read(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn advance_dialogue(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn set_decision_timer(&self, _timer: DecisionTimerSettings) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn report_decision_timeout(&self, _decision: TimedDecision, _defaulted: bool) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn apply_condition(&self, _character_id: &str, _condition: NewCondition) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn remove_condition(&self, _character_id: &str, _condition_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn add_annotation(&self, _surface: AnnotationSurface, _annotation: Annotation) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn remove_annotation(&self, _surface: AnnotationSurface, _annotation_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn set_annotation_layer(&self, _surface: AnnotationSurface, _layer: AnnotationLayer) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn clear_annotation_layer(&self, _surface: AnnotationSurface, _layer_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn run_encounter(&self, _encounter_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn send_possessed_action(
+        &self,
+        character_id: &str,
+        action_type: &str,
+        target: Option<&str>,
+        dialogue: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let mut s = self.state.lock().unwrap();
+        s.sent_actions.push(SentAction {
+            action_type: action_type.to_string(),
+            target: target.map(|s| s.to_string()),
+            dialogue: dialogue.map(|s| s.to_string()),
+            acting_as: Some(character_id.to_string()),
+        });
+        Ok(())
+    }
+
+    fn set_scene_mood(&self, _region_id: &str, _mood: SceneMood) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn set_region_backdrop(&self, _region_id: &str, _asset_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn request_npc_memories(&self, _npc_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn add_npc_memory(&self, _npc_id: &str, _memory: MemoryDraft) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn update_npc_memory(&self, _npc_id: &str, _memory_id: &str, _memory: MemoryDraft) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn delete_npc_memory(&self, _npc_id: &str, _memory_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn reveal_regions(&self, _region_ids: Vec<String>, _scope: RevealScope) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn hide_regions(&self, _region_ids: Vec<String>, _scope: RevealScope) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn edit_shared_note(&self, _doc: SharedNoteDoc, _text: &str, _base_revision: u64) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn send_shared_note_cursor(&self, _doc: SharedNoteDoc, _position: Option<usize>) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn attempt_craft(&self, _pc_id: &str, _recipe_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn identify_item(&self, _pc_id: &str, _item_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn play_recap(&self, _recap: SessionRecap) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn play_camera_effect(&self, _effect: CameraEffect) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn set_disposition_hidden(&self, _npc_id: &str, _hidden: bool) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn set_region_population(&self, _region_id: &str, _population: RegionPopulation) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn on_state_change(&self, callback: StateCallback) {
         let mut s = self.state.lock().unwrap();
         s.on_state_change = Some(callback);
     }
 
-    fn on_message(&self, callback: Box<dyn FnMut(serde_json::Value) + Send + 'static>) {
+    fn on_message(&self, callback: MessageCallback) {
         let mut s = self.state.lock().unwrap();
         s.on_message = Some(callback);
     }
@@ -1,12 +1,13 @@
-//! Test-only infrastructure fakes.
+//! Infrastructure fakes.
 //!
-//! These helpers implement outbound ports for unit tests (services/components),
-//! allowing tests to run without real network / websocket connections.
+//! These helpers implement outbound ports without real network / websocket
+//! connections. Unit tests use them to drive services and components, and
+//! the demo session runs the whole app against them.
 
 pub mod mock_api_port;
 pub mod mock_game_connection_port;
+#[cfg(test)]
 pub mod fixtures;
 
 pub use mock_api_port::MockApiPort;
-pub use mock_game_connection_port::MockGameConnectionPort;
-
+pub use mock_game_connection_port::{MockCall, MockGameConnectionPort};
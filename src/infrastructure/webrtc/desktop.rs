@@ -0,0 +1,43 @@
+//! Desktop media presence (no WebRTC stack)
+
+use crate::application::ports::outbound::{MediaPresencePort, PresenceSignalData};
+
+/// Media presence adapter for platforms without WebRTC
+///
+/// Webcam tiles are unavailable; avatar tiles still work since they are
+/// rendered purely from presence state.
+pub struct UnsupportedMediaPresence;
+
+impl MediaPresencePort for UnsupportedMediaPresence {
+    fn supports_video(&self) -> bool {
+        false
+    }
+
+    fn start_local_video(&self) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("Webcam presence is not supported on this platform"))
+    }
+
+    fn stop_local_video(&self) {}
+
+    fn connect_peer(&self, _peer_id: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn handle_signal(&self, _peer_id: &str, _signal: PresenceSignalData) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn disconnect_peer(&self, _peer_id: &str) {}
+
+    fn disconnect_all(&self) {}
+
+    fn on_signal(&self, _callback: Box<dyn FnMut(String, PresenceSignalData) + 'static>) {}
+
+    fn on_local_video(&self, _callback: Box<dyn FnMut(bool) + 'static>) {}
+
+    fn on_remote_video(&self, _callback: Box<dyn FnMut(String, bool) + 'static>) {}
+
+    fn attach_local_video(&self, _element_id: &str) {}
+
+    fn attach_remote_video(&self, _peer_id: &str, _element_id: &str) {}
+}
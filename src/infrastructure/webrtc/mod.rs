@@ -0,0 +1,27 @@
+//! WebRTC media presence
+//!
+//! Implements `MediaPresencePort` for the presence strip. The browser build
+//! captures the webcam and manages `RTCPeerConnection`s; the desktop build has
+//! no WebRTC stack, so it only supports avatar tiles.
+
+#[cfg(target_arch = "wasm32")]
+mod wasm;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod desktop;
+
+use std::rc::Rc;
+
+use crate::application::ports::outbound::MediaPresencePort;
+
+/// Create the media presence adapter for the current platform
+pub fn create_media_presence() -> Rc<dyn MediaPresencePort> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        Rc::new(wasm::WebRtcMediaPresence::new())
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Rc::new(desktop::UnsupportedMediaPresence)
+    }
+}
@@ -0,0 +1,381 @@
+//! Browser media presence using `getUserMedia` and `RTCPeerConnection`
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{
+    HtmlMediaElement, MediaStream, MediaStreamConstraints, MediaStreamTrack, RtcConfiguration,
+    RtcIceCandidateInit, RtcIceServer, RtcPeerConnection, RtcPeerConnectionIceEvent, RtcSdpType,
+    RtcSessionDescriptionInit, RtcTrackEvent,
+};
+
+use crate::application::ports::outbound::{MediaPresencePort, PresenceSignalData};
+
+/// Public STUN server used for NAT traversal
+const STUN_SERVER: &str = "stun:stun.l.google.com:19302";
+
+/// Callback handed each signalling message and the peer it is for
+type SignalCallback = Box<dyn FnMut(String, PresenceSignalData)>;
+/// Callback told whether the local camera is live
+type LocalVideoCallback = Box<dyn FnMut(bool)>;
+/// Callback told whether a peer's stream has video
+type RemoteVideoCallback = Box<dyn FnMut(String, bool)>;
+
+fn js_err(e: JsValue) -> anyhow::Error {
+    anyhow::anyhow!("{:?}", e)
+}
+
+/// A single peer connection and the closures that must outlive it
+struct Peer {
+    pc: RtcPeerConnection,
+    remote_stream: Option<MediaStream>,
+    /// Whether the remote description has been applied yet
+    remote_ready: bool,
+    /// ICE candidates received before the remote description
+    pending_candidates: Vec<RtcIceCandidateInit>,
+    _on_ice_candidate: Closure<dyn FnMut(RtcPeerConnectionIceEvent)>,
+    _on_track: Closure<dyn FnMut(RtcTrackEvent)>,
+}
+
+#[derive(Default)]
+struct Inner {
+    local_stream: RefCell<Option<MediaStream>>,
+    peers: RefCell<HashMap<String, Peer>>,
+    on_signal: RefCell<Option<SignalCallback>>,
+    on_local_video: RefCell<Option<LocalVideoCallback>>,
+    on_remote_video: RefCell<Option<RemoteVideoCallback>>,
+}
+
+impl Inner {
+    fn emit_signal(&self, peer_id: &str, signal: PresenceSignalData) {
+        if let Some(ref mut cb) = *self.on_signal.borrow_mut() {
+            cb(peer_id.to_string(), signal);
+        }
+    }
+
+    fn emit_local_video(&self, live: bool) {
+        if let Some(ref mut cb) = *self.on_local_video.borrow_mut() {
+            cb(live);
+        }
+    }
+
+    fn emit_remote_video(&self, peer_id: &str, has_video: bool) {
+        if let Some(ref mut cb) = *self.on_remote_video.borrow_mut() {
+            cb(peer_id.to_string(), has_video);
+        }
+    }
+}
+
+/// WebRTC media presence adapter (WASM)
+pub struct WebRtcMediaPresence {
+    inner: Rc<Inner>,
+}
+
+impl WebRtcMediaPresence {
+    pub fn new() -> Self {
+        Self {
+            inner: Rc::new(Inner::default()),
+        }
+    }
+
+    /// Create a peer connection for `peer_id`, replacing any existing one
+    fn create_peer(&self, peer_id: &str) -> anyhow::Result<RtcPeerConnection> {
+        self.disconnect_peer(peer_id);
+
+        let ice_server = RtcIceServer::new();
+        ice_server.set_urls_str(STUN_SERVER);
+        let config = RtcConfiguration::new();
+        config.set_ice_servers(&js_sys::Array::of1(&ice_server));
+        let pc = RtcPeerConnection::new_with_configuration(&config).map_err(js_err)?;
+
+        // Send our camera if it is live; otherwise still negotiate a video
+        // m-line so we can receive the peer's camera.
+        match self.inner.local_stream.borrow().as_ref() {
+            Some(stream) => {
+                for track in stream.get_tracks().iter() {
+                    let track: MediaStreamTrack = track.unchecked_into();
+                    pc.add_track(&track, stream, &js_sys::Array::new());
+                }
+            }
+            None => {
+                pc.add_transceiver_with_str("video");
+            }
+        }
+
+        let weak: Weak<Inner> = Rc::downgrade(&self.inner);
+        let ice_peer_id = peer_id.to_string();
+        let on_ice_candidate = Closure::<dyn FnMut(_)>::new(move |e: RtcPeerConnectionIceEvent| {
+            let (Some(inner), Some(candidate)) = (weak.upgrade(), e.candidate()) else {
+                return;
+            };
+            inner.emit_signal(
+                &ice_peer_id,
+                PresenceSignalData::IceCandidate {
+                    candidate: candidate.candidate(),
+                    sdp_mid: candidate.sdp_mid(),
+                    sdp_mline_index: candidate.sdp_m_line_index(),
+                },
+            );
+        });
+        pc.set_onicecandidate(Some(on_ice_candidate.as_ref().unchecked_ref()));
+
+        let weak: Weak<Inner> = Rc::downgrade(&self.inner);
+        let track_peer_id = peer_id.to_string();
+        let on_track = Closure::<dyn FnMut(_)>::new(move |e: RtcTrackEvent| {
+            let Some(inner) = weak.upgrade() else {
+                return;
+            };
+            let Ok(stream) = e.streams().get(0).dyn_into::<MediaStream>() else {
+                return;
+            };
+            if let Some(peer) = inner.peers.borrow_mut().get_mut(&track_peer_id) {
+                peer.remote_stream = Some(stream);
+            }
+            inner.emit_remote_video(&track_peer_id, true);
+        });
+        pc.set_ontrack(Some(on_track.as_ref().unchecked_ref()));
+
+        self.inner.peers.borrow_mut().insert(
+            peer_id.to_string(),
+            Peer {
+                pc: pc.clone(),
+                remote_stream: None,
+                remote_ready: false,
+                pending_candidates: Vec::new(),
+                _on_ice_candidate: on_ice_candidate,
+                _on_track: on_track,
+            },
+        );
+
+        Ok(pc)
+    }
+
+    /// Mark the remote description as applied and flush queued candidates
+    fn flush_candidates(inner: &Inner, peer_id: &str) {
+        let mut peers = inner.peers.borrow_mut();
+        let Some(peer) = peers.get_mut(peer_id) else {
+            return;
+        };
+        peer.remote_ready = true;
+        for candidate in peer.pending_candidates.drain(..) {
+            let _ = peer
+                .pc
+                .add_ice_candidate_with_opt_rtc_ice_candidate_init(Some(&candidate));
+        }
+    }
+
+    fn set_element_stream(element_id: &str, stream: Option<&MediaStream>) {
+        let element = web_sys::window()
+            .and_then(|w| w.document())
+            .and_then(|d| d.get_element_by_id(element_id))
+            .and_then(|el| el.dyn_into::<HtmlMediaElement>().ok());
+        if let Some(element) = element {
+            element.set_src_object(stream);
+        }
+    }
+}
+
+impl MediaPresencePort for WebRtcMediaPresence {
+    fn supports_video(&self) -> bool {
+        web_sys::window()
+            .and_then(|w| w.navigator().media_devices().ok())
+            .is_some()
+    }
+
+    fn start_local_video(&self) -> anyhow::Result<()> {
+        let media_devices = web_sys::window()
+            .ok_or_else(|| anyhow::anyhow!("No window"))?
+            .navigator()
+            .media_devices()
+            .map_err(js_err)?;
+
+        let constraints = MediaStreamConstraints::new();
+        constraints.set_video_bool(true);
+        constraints.set_audio_bool(false);
+        let promise = media_devices
+            .get_user_media_with_constraints(&constraints)
+            .map_err(js_err)?;
+
+        let weak = Rc::downgrade(&self.inner);
+        spawn_local(async move {
+            let result = JsFuture::from(promise).await;
+            let Some(inner) = weak.upgrade() else {
+                return;
+            };
+            match result.and_then(|v| v.dyn_into::<MediaStream>()) {
+                Ok(stream) => {
+                    *inner.local_stream.borrow_mut() = Some(stream);
+                    inner.emit_local_video(true);
+                }
+                Err(e) => {
+                    web_sys::console::warn_1(&format!("Camera access failed: {:?}", e).into());
+                    inner.emit_local_video(false);
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    fn stop_local_video(&self) {
+        if let Some(stream) = self.inner.local_stream.borrow_mut().take() {
+            for track in stream.get_tracks().iter() {
+                track.unchecked_into::<MediaStreamTrack>().stop();
+            }
+            self.inner.emit_local_video(false);
+        }
+    }
+
+    fn connect_peer(&self, peer_id: &str) -> anyhow::Result<()> {
+        let pc = self.create_peer(peer_id)?;
+        let weak = Rc::downgrade(&self.inner);
+        let peer_id = peer_id.to_string();
+
+        spawn_local(async move {
+            let result: Result<String, JsValue> = async {
+                let offer = JsFuture::from(pc.create_offer()).await?;
+                let sdp = js_sys::Reflect::get(&offer, &"sdp".into())?
+                    .as_string()
+                    .unwrap_or_default();
+                let description = RtcSessionDescriptionInit::new(RtcSdpType::Offer);
+                description.set_sdp(&sdp);
+                JsFuture::from(pc.set_local_description(&description)).await?;
+                Ok(sdp)
+            }
+            .await;
+
+            match (result, weak.upgrade()) {
+                (Ok(sdp), Some(inner)) => inner.emit_signal(&peer_id, PresenceSignalData::Offer { sdp }),
+                (Err(e), _) => {
+                    web_sys::console::warn_1(&format!("Failed to create offer: {:?}", e).into());
+                }
+                _ => {}
+            }
+        });
+
+        Ok(())
+    }
+
+    fn handle_signal(&self, peer_id: &str, signal: PresenceSignalData) -> anyhow::Result<()> {
+        match signal {
+            PresenceSignalData::Offer { sdp } => {
+                let pc = self.create_peer(peer_id)?;
+                let weak = Rc::downgrade(&self.inner);
+                let peer_id = peer_id.to_string();
+
+                spawn_local(async move {
+                    let result: Result<String, JsValue> = async {
+                        let offer = RtcSessionDescriptionInit::new(RtcSdpType::Offer);
+                        offer.set_sdp(&sdp);
+                        JsFuture::from(pc.set_remote_description(&offer)).await?;
+                        if let Some(inner) = weak.upgrade() {
+                            Self::flush_candidates(&inner, &peer_id);
+                        }
+
+                        let answer = JsFuture::from(pc.create_answer()).await?;
+                        let sdp = js_sys::Reflect::get(&answer, &"sdp".into())?
+                            .as_string()
+                            .unwrap_or_default();
+                        let description = RtcSessionDescriptionInit::new(RtcSdpType::Answer);
+                        description.set_sdp(&sdp);
+                        JsFuture::from(pc.set_local_description(&description)).await?;
+                        Ok(sdp)
+                    }
+                    .await;
+
+                    match (result, weak.upgrade()) {
+                        (Ok(sdp), Some(inner)) => inner.emit_signal(&peer_id, PresenceSignalData::Answer { sdp }),
+                        (Err(e), _) => {
+                            web_sys::console::warn_1(&format!("Failed to answer offer: {:?}", e).into());
+                        }
+                        _ => {}
+                    }
+                });
+            }
+            PresenceSignalData::Answer { sdp } => {
+                let Some(pc) = self.inner.peers.borrow().get(peer_id).map(|p| p.pc.clone()) else {
+                    return Err(anyhow::anyhow!("Answer from unknown peer {}", peer_id));
+                };
+                let weak = Rc::downgrade(&self.inner);
+                let peer_id = peer_id.to_string();
+
+                spawn_local(async move {
+                    let answer = RtcSessionDescriptionInit::new(RtcSdpType::Answer);
+                    answer.set_sdp(&sdp);
+                    match JsFuture::from(pc.set_remote_description(&answer)).await {
+                        Ok(_) => {
+                            if let Some(inner) = weak.upgrade() {
+                                Self::flush_candidates(&inner, &peer_id);
+                            }
+                        }
+                        Err(e) => {
+                            web_sys::console::warn_1(&format!("Failed to apply answer: {:?}", e).into());
+                        }
+                    }
+                });
+            }
+            PresenceSignalData::IceCandidate {
+                candidate,
+                sdp_mid,
+                sdp_mline_index,
+            } => {
+                let init = RtcIceCandidateInit::new(&candidate);
+                init.set_sdp_mid(sdp_mid.as_deref());
+                init.set_sdp_m_line_index(sdp_mline_index);
+
+                let mut peers = self.inner.peers.borrow_mut();
+                let Some(peer) = peers.get_mut(peer_id) else {
+                    return Err(anyhow::anyhow!("ICE candidate from unknown peer {}", peer_id));
+                };
+                if peer.remote_ready {
+                    let _ = peer.pc.add_ice_candidate_with_opt_rtc_ice_candidate_init(Some(&init));
+                } else {
+                    peer.pending_candidates.push(init);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn disconnect_peer(&self, peer_id: &str) {
+        let removed = self.inner.peers.borrow_mut().remove(peer_id);
+        if let Some(peer) = removed {
+            peer.pc.close();
+            if peer.remote_stream.is_some() {
+                self.inner.emit_remote_video(peer_id, false);
+            }
+        }
+    }
+
+    fn disconnect_all(&self) {
+        let peer_ids: Vec<String> = self.inner.peers.borrow().keys().cloned().collect();
+        for peer_id in peer_ids {
+            self.disconnect_peer(&peer_id);
+        }
+        self.stop_local_video();
+    }
+
+    fn on_signal(&self, callback: Box<dyn FnMut(String, PresenceSignalData) + 'static>) {
+        *self.inner.on_signal.borrow_mut() = Some(callback);
+    }
+
+    fn on_local_video(&self, callback: Box<dyn FnMut(bool) + 'static>) {
+        *self.inner.on_local_video.borrow_mut() = Some(callback);
+    }
+
+    fn on_remote_video(&self, callback: Box<dyn FnMut(String, bool) + 'static>) {
+        *self.inner.on_remote_video.borrow_mut() = Some(callback);
+    }
+
+    fn attach_local_video(&self, element_id: &str) {
+        Self::set_element_stream(element_id, self.inner.local_stream.borrow().as_ref());
+    }
+
+    fn attach_remote_video(&self, peer_id: &str, element_id: &str) {
+        let peers = self.inner.peers.borrow();
+        Self::set_element_stream(element_id, peers.get(peer_id).and_then(|p| p.remote_stream.as_ref()));
+    }
+}
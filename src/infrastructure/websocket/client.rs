@@ -4,7 +4,10 @@
 
 use anyhow::Result;
 
-use crate::application::dto::{ClientMessage, ParticipantRole, ServerMessage};
+use crate::application::dto::{
+    decode_server_message, schema_issues, ClientMessage, ParticipantRole, ServerMessage, PROTOCOL_VERSION,
+};
+use crate::infrastructure::api_cache;
 
 /// Connection state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,6 +19,18 @@ pub enum ConnectionState {
     Failed,
 }
 
+/// Decode an Engine message, logging anything the schema didn't cover
+///
+/// Returns `None` for messages that had to be skipped.
+fn decode_message(text: &str) -> Option<ServerMessage> {
+    let decoded = decode_server_message(text);
+    for issue in decoded.issues {
+        tracing::warn!("Server message schema: {}", issue.summary());
+        schema_issues().record(issue);
+    }
+    decoded.message
+}
+
 // ============================================================================
 // Desktop (Tokio) Implementation
 // ============================================================================
@@ -108,15 +123,11 @@ mod desktop {
                         while let Some(msg) = read.next().await {
                             match msg {
                                 Ok(Message::Text(text)) => {
-                                    match serde_json::from_str::<ServerMessage>(&text) {
-                                        Ok(server_msg) => {
-                                            let callback = on_message.lock().await;
-                                            if let Some(ref cb) = *callback {
-                                                cb(server_msg);
-                                            }
-                                        }
-                                        Err(e) => {
-                                            tracing::warn!("Failed to parse server message: {}", e);
+                                    if let Some(server_msg) = decode_message(&text) {
+                                        api_cache::global().invalidate_for_message(&server_msg);
+                                        let callback = on_message.lock().await;
+                                        if let Some(ref cb) = *callback {
+                                            cb(server_msg);
                                         }
                                     }
                                 }
@@ -186,6 +197,7 @@ mod desktop {
                 user_id: user_id.to_string(),
                 role,
                 world_id,
+                protocol_version: Some(PROTOCOL_VERSION),
             })
             .await
         }
@@ -307,16 +319,10 @@ mod wasm {
             let onmessage_callback = Closure::<dyn FnMut(_)>::new(move |e: MessageEvent| {
                 if let Ok(txt) = e.data().dyn_into::<js_sys::JsString>() {
                     let text: String = txt.into();
-                    match serde_json::from_str::<ServerMessage>(&text) {
-                        Ok(server_msg) => {
-                            if let Some(ref mut cb) = *on_message.borrow_mut() {
-                                cb(server_msg);
-                            }
-                        }
-                        Err(e) => {
-                            web_sys::console::warn_1(
-                                &format!("Failed to parse server message: {}", e).into(),
-                            );
+                    if let Some(server_msg) = decode_message(&text) {
+                        api_cache::global().invalidate_for_message(&server_msg);
+                        if let Some(ref mut cb) = *on_message.borrow_mut() {
+                            cb(server_msg);
                         }
                     }
                 }
@@ -389,6 +395,7 @@ mod wasm {
                 user_id: user_id.to_string(),
                 role,
                 world_id,
+                protocol_version: Some(PROTOCOL_VERSION),
             })
         }
 
@@ -10,13 +10,14 @@ use std::sync::{
 };
 
 use crate::application::ports::outbound::{
-    ApprovalDecision as PortApprovalDecision, ChallengeOutcomeDecisionData, ConnectionState as PortConnectionState,
+    AdHocOutcomes, ApprovalDecision as PortApprovalDecision, ChallengeOutcomeDecisionData, ConnectionState as PortConnectionState,
     DirectorialContext as PortDirectorialContext, GameConnectionPort, NpcMotivation as PortNpcMotivation,
-    ParticipantRole as PortParticipantRole,
+    ParticipantRole as PortParticipantRole, PresenceMode, PresenceSignalData, SpectatorPolicy,
+    AdvancementGrant, DecisionTimerSettings, DialoguePacing, LevelUpChoices, NewCondition, TimedDecision,
 };
 
 use crate::application::dto::{
-    ApprovalDecision as InfraApprovalDecision, ClientMessage, DirectorialContext as InfraDirectorialContext,
+    Annotation, AnnotationLayer, AnnotationSurface, CameraEffect, MemoryDraft, RegionPopulation, RevealScope, SceneMood, SessionRecap, SharedNoteDoc, StagedScene, ApprovalDecision as InfraApprovalDecision, ClientMessage, DirectorialContext as InfraDirectorialContext,
     NpcMotivationData as InfraNpcMotivationData, ParticipantRole as InfraParticipantRole,
 };
 use super::{ConnectionState as InfraConnectionState, EngineClient};
@@ -54,6 +55,7 @@ fn u8_to_state(v: u8) -> PortConnectionState {
 fn map_role(role: PortParticipantRole) -> InfraParticipantRole {
     match role {
         PortParticipantRole::DungeonMaster => InfraParticipantRole::DungeonMaster,
+        PortParticipantRole::CoDungeonMaster => InfraParticipantRole::CoDungeonMaster,
         PortParticipantRole::Player => InfraParticipantRole::Player,
         PortParticipantRole::Spectator => InfraParticipantRole::Spectator,
     }
@@ -74,6 +76,9 @@ fn map_directorial_context(ctx: PortDirectorialContext) -> InfraDirectorialConte
         tone: ctx.tone,
         npc_motivations: ctx.npc_motivations.into_iter().map(map_npc_motivation).collect(),
         forbidden_topics: ctx.forbidden_topics,
+        preset_name: ctx.preset_name,
+        style_notes: ctx.style_notes,
+        content_guidelines: ctx.content_guidelines,
     }
 }
 
@@ -311,6 +316,37 @@ impl GameConnectionPort for EngineGameConnection {
         }
     }
 
+    fn create_adhoc_challenge(
+        &self,
+        challenge_name: &str,
+        skill_name: &str,
+        difficulty: &str,
+        target_pc_id: &str,
+        outcomes: AdHocOutcomes,
+    ) -> Result<()> {
+        let msg = ClientMessage::CreateAdHocChallenge {
+            challenge_name: challenge_name.to_string(),
+            skill_name: skill_name.to_string(),
+            difficulty: difficulty.to_string(),
+            target_pc_id: target_pc_id.to_string(),
+            outcomes,
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to create ad-hoc challenge: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
     fn submit_challenge_roll(&self, challenge_id: &str, roll: i32) -> Result<()> {
         let msg = ClientMessage::ChallengeRoll {
             challenge_id: challenge_id.to_string(),
@@ -414,6 +450,894 @@ impl GameConnectionPort for EngineGameConnection {
         }
     }
 
+    fn set_presence_mode(&self, mode: PresenceMode) -> Result<()> {
+        let msg = ClientMessage::SetPresenceMode { mode };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send presence mode: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn send_presence_signal(&self, to_user_id: &str, signal: PresenceSignalData) -> Result<()> {
+        let msg = ClientMessage::PresenceSignal {
+            to_user_id: to_user_id.to_string(),
+            signal,
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send presence signal: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn pause_session(&self, message: Option<&str>, art_asset: Option<&str>) -> Result<()> {
+        let msg = ClientMessage::PauseSession {
+            message: message.map(|s| s.to_string()),
+            art_asset: art_asset.map(|s| s.to_string()),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send pause session: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn resume_session(&self) -> Result<()> {
+        let msg = ClientMessage::ResumeSession;
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send resume session: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn assign_role(&self, user_id: &str, role: PortParticipantRole) -> Result<()> {
+        let msg = ClientMessage::AssignRole {
+            user_id: user_id.to_string(),
+            role: map_role(role),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send role assignment: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn send_whisper(&self, text: &str) -> Result<()> {
+        let msg = ClientMessage::WhisperToDm {
+            text: text.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send whisper: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn reply_to_whisper(&self, to_user_id: &str, text: &str) -> Result<()> {
+        let msg = ClientMessage::WhisperReply {
+            to_user_id: to_user_id.to_string(),
+            text: text.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send whisper reply: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn set_spectator_policy(&self, policy: SpectatorPolicy) -> Result<()> {
+        let msg = ClientMessage::SetSpectatorPolicy { policy };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send spectator policy: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn set_staged_scenes(&self, scenes: Vec<StagedScene>) -> Result<()> {
+        let msg = ClientMessage::SetStagedScenes { scenes };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send staged scenes: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn focus_staged_scene(&self, scene_id: &str) -> Result<()> {
+        let msg = ClientMessage::FocusStagedScene {
+            scene_id: scene_id.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send scene focus: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn set_async_play(&self, enabled: bool) -> Result<()> {
+        let msg = ClientMessage::SetAsyncPlay { enabled };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send async play setting: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn process_inbox_actions(&self, action_ids: Vec<String>) -> Result<()> {
+        let msg = ClientMessage::ProcessInboxActions { action_ids };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send inbox actions to process: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn discard_inbox_action(&self, action_id: &str) -> Result<()> {
+        let msg = ClientMessage::DiscardInboxAction {
+            action_id: action_id.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send inbox discard: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn grant_advancement(&self, pc_id: &str, grant: AdvancementGrant) -> Result<()> {
+        let msg = ClientMessage::GrantAdvancement {
+            pc_id: pc_id.to_string(),
+            grant,
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send advancement grant: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn submit_level_up(&self, pc_id: &str, choices: LevelUpChoices) -> Result<()> {
+        let msg = ClientMessage::SubmitLevelUp {
+            pc_id: pc_id.to_string(),
+            choices,
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send level-up: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn speak_as(&self, npc_id: &str, text: &str, record_as_approved: bool) -> Result<()> {
+        let msg = ClientMessage::SpeakAs {
+            npc_id: npc_id.to_string(),
+            text: text.to_string(),
+            record_as_approved,
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send scripted NPC line: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn set_dialogue_pacing(&self, pacing: DialoguePacing) -> Result<()> {
+        let msg = ClientMessage::SetDialoguePacing { pacing };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to set dialogue pacing: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn mark_dialogue_read(&self) -> Result<()> {
+        let msg = ClientMessage::DialogueRead;
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send dialogue read: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn advance_dialogue(&self) -> Result<()> {
+        let msg = ClientMessage::AdvanceDialogue;
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to advance dialogue: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn set_decision_timer(&self, timer: DecisionTimerSettings) -> Result<()> {
+        let msg = ClientMessage::SetDecisionTimer { timer };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send SetDecisionTimer: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn report_decision_timeout(&self, decision: TimedDecision, defaulted: bool) -> Result<()> {
+        let msg = ClientMessage::DecisionTimedOut { decision, defaulted };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send DecisionTimedOut: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn apply_condition(&self, character_id: &str, condition: NewCondition) -> Result<()> {
+        let msg = ClientMessage::ApplyCondition {
+            character_id: character_id.to_string(),
+            condition,
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send apply condition: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn remove_condition(&self, character_id: &str, condition_id: &str) -> Result<()> {
+        let msg = ClientMessage::RemoveCondition {
+            character_id: character_id.to_string(),
+            condition_id: condition_id.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send remove condition: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn add_annotation(&self, surface: AnnotationSurface, annotation: Annotation) -> Result<()> {
+        let msg = ClientMessage::AddAnnotation { surface, annotation };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send annotation: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn remove_annotation(&self, surface: AnnotationSurface, annotation_id: &str) -> Result<()> {
+        let msg = ClientMessage::RemoveAnnotation {
+            surface,
+            annotation_id: annotation_id.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send annotation removal: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn set_annotation_layer(&self, surface: AnnotationSurface, layer: AnnotationLayer) -> Result<()> {
+        let msg = ClientMessage::SetAnnotationLayer { surface, layer };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send annotation layer: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn clear_annotation_layer(&self, surface: AnnotationSurface, layer_id: &str) -> Result<()> {
+        let msg = ClientMessage::ClearAnnotationLayer {
+            surface,
+            layer_id: layer_id.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send annotation layer clear: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn run_encounter(&self, encounter_id: &str) -> Result<()> {
+        let msg = ClientMessage::RunEncounter {
+            encounter_id: encounter_id.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to run encounter: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn send_possessed_action(&self, character_id: &str, action_type: &str, target: Option<&str>, dialogue: Option<&str>) -> Result<()> {
+        let msg = ClientMessage::PossessedAction {
+            character_id: character_id.to_string(),
+            action_type: action_type.to_string(),
+            target: target.map(|s| s.to_string()),
+            dialogue: dialogue.map(|s| s.to_string()),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send possessed action: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn set_scene_mood(&self, region_id: &str, mood: SceneMood) -> Result<()> {
+        let msg = ClientMessage::SetSceneMood {
+            region_id: region_id.to_string(),
+            mood,
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send SetSceneMood: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn set_region_backdrop(&self, region_id: &str, asset_id: &str) -> Result<()> {
+        let msg = ClientMessage::SetRegionBackdrop {
+            region_id: region_id.to_string(),
+            asset_id: asset_id.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send SetRegionBackdrop: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn request_npc_memories(&self, npc_id: &str) -> Result<()> {
+        let msg = ClientMessage::RequestNpcMemories {
+            npc_id: npc_id.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send RequestNpcMemories: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn add_npc_memory(&self, npc_id: &str, memory: MemoryDraft) -> Result<()> {
+        let msg = ClientMessage::AddNpcMemory {
+            npc_id: npc_id.to_string(),
+            memory,
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send AddNpcMemory: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn update_npc_memory(&self, npc_id: &str, memory_id: &str, memory: MemoryDraft) -> Result<()> {
+        let msg = ClientMessage::UpdateNpcMemory {
+            npc_id: npc_id.to_string(),
+            memory_id: memory_id.to_string(),
+            memory,
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send UpdateNpcMemory: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn delete_npc_memory(&self, npc_id: &str, memory_id: &str) -> Result<()> {
+        let msg = ClientMessage::DeleteNpcMemory {
+            npc_id: npc_id.to_string(),
+            memory_id: memory_id.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send DeleteNpcMemory: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn reveal_regions(&self, region_ids: Vec<String>, scope: RevealScope) -> Result<()> {
+        let msg = ClientMessage::RevealRegions { region_ids, scope };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send RevealRegions: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn hide_regions(&self, region_ids: Vec<String>, scope: RevealScope) -> Result<()> {
+        let msg = ClientMessage::HideRegions { region_ids, scope };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send HideRegions: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn edit_shared_note(&self, doc: SharedNoteDoc, text: &str, base_revision: u64) -> Result<()> {
+        let msg = ClientMessage::EditSharedNote {
+            doc,
+            text: text.to_string(),
+            base_revision,
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send EditSharedNote: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn send_shared_note_cursor(&self, doc: SharedNoteDoc, position: Option<usize>) -> Result<()> {
+        let msg = ClientMessage::SharedNoteCursor { doc, position };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send SharedNoteCursor: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn attempt_craft(&self, pc_id: &str, recipe_id: &str) -> Result<()> {
+        let msg = ClientMessage::AttemptCraft {
+            pc_id: pc_id.to_string(),
+            recipe_id: recipe_id.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send craft attempt: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn identify_item(&self, pc_id: &str, item_id: &str) -> Result<()> {
+        let msg = ClientMessage::IdentifyItem {
+            pc_id: pc_id.to_string(),
+            item_id: item_id.to_string(),
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send item identification: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn play_recap(&self, recap: SessionRecap) -> Result<()> {
+        let msg = ClientMessage::PlayRecap { recap };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send recap: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn play_camera_effect(&self, effect: CameraEffect) -> Result<()> {
+        let msg = ClientMessage::PlayCameraEffect { effect };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send camera effect: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn set_disposition_hidden(&self, npc_id: &str, hidden: bool) -> Result<()> {
+        let msg = ClientMessage::SetDispositionHidden { npc_id: npc_id.to_string(), hidden };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to set disposition visibility: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
+    fn set_region_population(&self, region_id: &str, population: RegionPopulation) -> Result<()> {
+        let msg = ClientMessage::SetRegionPopulation {
+            region_id: region_id.to_string(),
+            population,
+        };
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.client.send(msg)
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.send(msg).await {
+                    tracing::error!("Failed to send SetRegionPopulation: {}", e);
+                }
+            });
+            Ok(())
+        }
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     fn on_state_change(&self, callback: Box<dyn FnMut(PortConnectionState) + Send + 'static>) {
         let state_slot = Arc::clone(&self.state);
@@ -11,7 +11,7 @@ mod presentation;
 mod routes;
 
 use dioxus::prelude::*;
-use presentation::state::{DialogueState, GameState, GenerationState, SessionState};
+use presentation::state::{perf_probe, AuthState, DialogueState, DisplayState, GameState, GenerationState, SessionState, TagFilterState};
 use presentation::Services;
 use routes::Route;
 
@@ -19,11 +19,10 @@ use routes::Route;
 use crate::infrastructure::http_client::ApiAdapter;
 pub type ConcreteServices = Services<ApiAdapter>;
 
-#[cfg(not(target_arch = "wasm32"))]
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 fn main() {
-    // Initialize logging (desktop only - WASM uses tracing-wasm)
+    // Initialize logging; recent lines are also kept in memory for bug reports
     #[cfg(not(target_arch = "wasm32"))]
     tracing_subscriber::registry()
         .with(
@@ -31,12 +30,16 @@ fn main() {
                 .unwrap_or_else(|_| "wrldbldr_player=debug,dioxus=info".into()),
         )
         .with(tracing_subscriber::fmt::layer())
+        .with(infrastructure::log_buffer::LogBufferLayer)
         .init();
 
     #[cfg(target_arch = "wasm32")]
     {
         console_error_panic_hook::set_once();
-        tracing_wasm::set_as_global_default();
+        tracing_subscriber::registry()
+            .with(tracing_wasm::WASMLayer::new(tracing_wasm::WASMLayerConfig::default()))
+            .with(infrastructure::log_buffer::LogBufferLayer)
+            .init();
     }
 
     tracing::info!("Starting WrldBldr Player");
@@ -51,6 +54,23 @@ fn App() -> Element {
     // Provide platform services via context
     let platform = infrastructure::platform::create_platform();
 
+    // Restore a persisted login (activates the auth token for requests)
+    let restored_identity = use_hook(|| {
+        application::services::restore_auth_session(&platform).map(|session| session.identity)
+    });
+
+    // Per-device display preferences
+    let backdrop_preferences =
+        use_hook(|| application::services::load_backdrop_preferences(&platform));
+    let perf_overlay = use_hook(|| {
+        let enabled = perf_probe::load_overlay_enabled(&platform);
+        perf_probe::set_enabled(enabled);
+        enabled
+    });
+    let accessibility =
+        use_hook(|| application::services::load_accessibility_preferences(&platform));
+    let tours = use_hook(|| application::services::load_tour_progress(&platform));
+
     use_context_provider(|| platform);
 
     // Provide global state via context
@@ -58,6 +78,13 @@ fn App() -> Element {
     use_context_provider(SessionState::new);
     use_context_provider(DialogueState::new);
     use_context_provider(GenerationState::new);
+    use_context_provider(|| AuthState::new(restored_identity));
+    use_context_provider(TagFilterState::new);
+    let display_state =
+        use_context_provider(|| DisplayState::new(backdrop_preferences, perf_overlay, accessibility, tours));
+
+    // Media presence adapter for the webcam/avatar strip
+    use_context_provider(infrastructure::webrtc::create_media_presence);
 
     // Infrastructure instantiation happens HERE only (composition root)
     let api = infrastructure::http_client::ApiAdapter::new();
@@ -68,10 +95,18 @@ fn App() -> Element {
     // Non-DM routes show a simple header, DM routes use their own layout
     // Router handles all view switching
     // Wrapper provides full viewport height for child views using height: 100%
+    // and carries the accessibility classes the stylesheet keys off
+    let root_class = display_state.accessibility.read().root_class();
     rsx! {
         div {
+            class: "{root_class}",
             style: "width: 100vw; height: 100vh; overflow: hidden;",
             Router::<Route> {}
+            // Nothing but the stage belongs in a stream capture
+            if !*display_state.stream_mode.read() {
+                presentation::components::common::PerfOverlay {}
+                presentation::components::common::EngineHealthMonitor {}
+            }
         }
     }
 }
@@ -80,6 +115,7 @@ fn App() -> Element {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UserRole {
     DungeonMaster,
+    CoDungeonMaster,
     Player,
     Spectator,
 }
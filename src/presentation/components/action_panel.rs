@@ -1,10 +1,14 @@
 //! Action panel component
 //!
 //! Displays available interactions and system buttons (inventory, character sheet, etc.)
+//!
+//! Below the md breakpoint the buttons move into a bottom sheet opened from
+//! a single "Actions" button, so they don't cover the scene.
 
 use dioxus::prelude::*;
 
 use crate::application::dto::InteractionData;
+use crate::presentation::state::perf_probe;
 
 /// Props for the ActionPanel component
 #[derive(Props, Clone, PartialEq)]
@@ -29,23 +33,109 @@ pub struct ActionPanelProps {
     /// Handler for log button
     #[props(default)]
     pub on_log: Option<EventHandler<()>>,
+    /// Handler for the "My Rolls" history button
+    #[props(default)]
+    pub on_rolls: Option<EventHandler<()>>,
+    /// Handler for the roll macros drawer button
+    #[props(default)]
+    pub on_macros: Option<EventHandler<()>>,
+    /// Handler for the whisper-to-DM button
+    #[props(default)]
+    pub on_whisper: Option<EventHandler<()>>,
+    /// Unread DM replies, shown as a badge on the whisper button
+    #[props(default)]
+    pub unread_whispers: usize,
     /// Whether all action buttons should be disabled (e.g., while waiting for response)
     #[props(default = false)]
     pub disabled: bool,
+    /// Label of the action sent and awaiting the Engine's answer
+    #[props(default)]
+    pub sending: Option<String>,
+    /// Actions waiting to be sent, as (queue ID, label) in send order
+    #[props(default)]
+    pub queued: Vec<(u64, String)>,
+    /// Handler for cancelling a queued action by its queue ID
+    #[props(default)]
+    pub on_cancel_queued: Option<EventHandler<u64>>,
+    /// Button with the controller focus ring: system buttons as shown,
+    /// then available interactions
+    #[props(default)]
+    pub focused: Option<usize>,
 }
 
 /// Action panel - displays system buttons and scene interactions
 #[component]
 pub fn ActionPanel(props: ActionPanelProps) -> Element {
+    perf_probe::record_render("ActionPanel");
     let available_interactions: Vec<_> = props
         .interactions
         .iter()
         .filter(|i| i.is_available)
         .collect();
+    let mut sheet_open = use_signal(|| false);
+    let is_sheet_open = *sheet_open.read();
+    let action_count = available_interactions.len();
+
+    // Controller focus positions of the system buttons, in display order
+    let shown = [
+        props.on_inventory.is_some(),
+        props.on_character.is_some(),
+        props.on_map.is_some(),
+        props.on_people.is_some(),
+        props.on_log.is_some(),
+        props.on_rolls.is_some(),
+        props.on_macros.is_some(),
+        props.on_whisper.is_some(),
+    ];
+    let slots: Vec<Option<usize>> = shown
+        .iter()
+        .scan(0, |next, &is_shown| {
+            let slot = is_shown.then_some(*next);
+            *next += usize::from(is_shown);
+            Some(slot)
+        })
+        .collect();
+    let system_count = shown.iter().filter(|s| **s).count();
+    let focused = props.focused;
+    let is_focused = |button: usize| focused.is_some() && slots[button] == focused;
+
+    let panel_class = if is_sheet_open {
+        "action-panel fixed inset-x-0 bottom-0 z-[900] flex flex-wrap gap-2 p-4 pb-6 bg-dark-surface rounded-t-2xl border-t border-gray-700 max-h-[60vh] overflow-y-auto md:absolute md:inset-x-auto md:bottom-4 md:left-4 md:z-20 md:p-0 md:bg-transparent md:rounded-none md:border-0 md:max-h-none md:overflow-visible"
+    } else {
+        "action-panel hidden md:flex absolute bottom-4 left-4 flex-wrap gap-2 z-20"
+    };
 
     rsx! {
+        // Compact toggle and sheet backdrop
+        button {
+            class: "md:hidden absolute bottom-4 left-4 z-20 flex items-center gap-2 px-4 py-2 bg-black/70 text-white border border-gray-600 rounded-lg text-sm cursor-pointer",
+            onclick: move |_| sheet_open.set(true),
+            "☰ Actions"
+            if action_count > 0 {
+                span { class: "px-1.5 bg-blue-500 rounded-xl text-[0.625rem] font-bold", "{action_count}" }
+            }
+            if props.unread_whispers > 0 {
+                span { class: "px-1.5 bg-amber-500 rounded-xl text-[0.625rem] font-bold", "🤫 {props.unread_whispers}" }
+            }
+        }
+        if is_sheet_open {
+            div {
+                class: "md:hidden fixed inset-0 z-[899] bg-black/50",
+                onclick: move |_| sheet_open.set(false),
+            }
+        }
+
         div {
-            class: "action-panel absolute bottom-4 left-4 flex flex-wrap gap-2 z-20",
+            "data-tour": "pc-actions",
+            class: panel_class,
+            // Picking anything in the sheet closes it
+            onclick: move |_| sheet_open.set(false),
+
+            if is_sheet_open {
+                div { class: "md:hidden w-full flex justify-center pb-1",
+                    div { class: "w-10 h-1 bg-gray-600 rounded-full" }
+                }
+            }
 
             // System buttons
             if let Some(ref handler) = props.on_inventory {
@@ -53,6 +143,7 @@ pub fn ActionPanel(props: ActionPanelProps) -> Element {
                     label: "Inventory",
                     icon: "bag",
                     on_click: handler.clone(),
+                    focused: is_focused(0),
                     disabled: props.disabled,
                 }
             }
@@ -62,6 +153,7 @@ pub fn ActionPanel(props: ActionPanelProps) -> Element {
                     label: "Character",
                     icon: "person",
                     on_click: handler.clone(),
+                    focused: is_focused(1),
                     disabled: props.disabled,
                 }
             }
@@ -71,6 +163,7 @@ pub fn ActionPanel(props: ActionPanelProps) -> Element {
                     label: "Map",
                     icon: "map",
                     on_click: handler.clone(),
+                    focused: is_focused(2),
                     disabled: props.disabled,
                 }
             }
@@ -80,6 +173,7 @@ pub fn ActionPanel(props: ActionPanelProps) -> Element {
                     label: "People",
                     icon: "people",
                     on_click: handler.clone(),
+                    focused: is_focused(3),
                     disabled: props.disabled,
                 }
             }
@@ -89,10 +183,42 @@ pub fn ActionPanel(props: ActionPanelProps) -> Element {
                     label: "Log",
                     icon: "scroll",
                     on_click: handler.clone(),
+                    focused: is_focused(4),
+                    disabled: props.disabled,
+                }
+            }
+
+            // Past rolls stay viewable while waiting on the LLM
+            if let Some(ref handler) = props.on_rolls {
+                SystemButton {
+                    label: "My Rolls",
+                    icon: "dice",
+                    on_click: handler.clone(),
+                    focused: is_focused(5),
+                }
+            }
+
+            if let Some(ref handler) = props.on_macros {
+                SystemButton {
+                    label: "Macros",
+                    icon: "macro",
+                    on_click: handler.clone(),
+                    focused: is_focused(6),
                     disabled: props.disabled,
                 }
             }
 
+            // Whispering stays available while waiting on the LLM
+            if let Some(ref handler) = props.on_whisper {
+                SystemButton {
+                    label: "Whisper",
+                    icon: "whisper",
+                    on_click: handler.clone(),
+                    focused: is_focused(7),
+                    badge: props.unread_whispers,
+                }
+            }
+
             // Divider between system and scene actions
             if !available_interactions.is_empty() {
                 div {
@@ -101,12 +227,44 @@ pub fn ActionPanel(props: ActionPanelProps) -> Element {
             }
 
             // Scene-specific interactions
-            for interaction in available_interactions {
+            for (index, interaction) in available_interactions.into_iter().enumerate() {
                 InteractionButton {
                     key: "{interaction.id}",
                     interaction: interaction.clone(),
                     on_click: props.on_interaction.clone(),
                     disabled: props.disabled,
+                    focused: focused == Some(system_count + index),
+                }
+            }
+
+            // Outgoing actions: the one in flight, then the queue in send order
+            if props.sending.is_some() || !props.queued.is_empty() {
+                div {
+                    class: "action-queue flex flex-wrap items-center gap-1 w-full",
+
+                    if let Some(ref label) = props.sending {
+                        span {
+                            class: "px-2 py-1 bg-blue-500/20 text-blue-300 rounded text-xs animate-pulse",
+                            "⏳ {label}"
+                        }
+                    }
+
+                    for (position, (id, label)) in props.queued.iter().cloned().enumerate() {
+                        span {
+                            key: "{id}",
+                            class: "flex items-center gap-1 px-2 py-1 bg-gray-800/90 text-gray-300 rounded text-xs",
+                            span { class: "text-gray-500", "{position + 1}." }
+                            span { "{label}" }
+                            if let Some(handler) = props.on_cancel_queued {
+                                button {
+                                    class: "bg-transparent border-0 text-gray-500 hover:text-red-400 cursor-pointer text-xs p-0",
+                                    title: "Cancel",
+                                    onclick: move |_| handler.call(id),
+                                    "✕"
+                                }
+                            }
+                        }
+                    }
                 }
             }
         }
@@ -125,6 +283,12 @@ pub struct SystemButtonProps {
     /// Whether button is disabled
     #[props(default = false)]
     pub disabled: bool,
+    /// Notification count shown on the button
+    #[props(default)]
+    pub badge: usize,
+    /// Whether the controller focus ring is on this button
+    #[props(default = false)]
+    pub focused: bool,
 }
 
 /// System button (inventory, character, etc.)
@@ -136,16 +300,21 @@ pub fn SystemButton(props: SystemButtonProps) -> Element {
         "map" => "🗺️",
         "people" => "👥",
         "scroll" => "📜",
+        "whisper" => "🤫",
+        "dice" => "🎲",
+        "macro" => "⚡",
         _ => "⚙️",
     };
 
     // CRITICAL: Extract conditional classes BEFORE rsx! - no inline if in class strings
     let opacity_class = if props.disabled { "opacity-50" } else { "opacity-100" };
     let cursor_class = if props.disabled { "cursor-not-allowed" } else { "cursor-pointer" };
+    let focus_class = if props.focused { "ring-2 ring-amber-400" } else { "" };
 
     rsx! {
         button {
-            class: "btn btn-secondary flex items-center gap-2 px-3 py-2 {opacity_class} {cursor_class}",
+            class: "btn btn-secondary flex items-center gap-2 px-3 py-2 {opacity_class} {cursor_class} {focus_class}",
+            "data-gamepad-focus": if props.focused { "true" } else { "false" },
             disabled: props.disabled,
             onclick: move |_| {
                 if !props.disabled {
@@ -155,6 +324,12 @@ pub fn SystemButton(props: SystemButtonProps) -> Element {
 
             span { "{icon_char}" }
             span { "{props.label}" }
+            if props.badge > 0 {
+                span {
+                    class: "bg-amber-500 text-white rounded-xl py-0.5 px-1.5 text-[0.625rem] font-bold min-w-5 text-center",
+                    "{props.badge}"
+                }
+            }
         }
     }
 }
@@ -169,6 +344,9 @@ pub struct InteractionButtonProps {
     /// Whether button is disabled
     #[props(default = false)]
     pub disabled: bool,
+    /// Whether the controller focus ring is on this button
+    #[props(default = false)]
+    pub focused: bool,
 }
 
 /// Scene interaction button
@@ -180,10 +358,12 @@ pub fn InteractionButton(props: InteractionButtonProps) -> Element {
     // CRITICAL: Extract conditional classes BEFORE rsx! - no inline if in class strings
     let opacity_class = if props.disabled { "opacity-50" } else { "opacity-100" };
     let cursor_class = if props.disabled { "cursor-not-allowed" } else { "cursor-pointer" };
+    let focus_class = if props.focused { "ring-2 ring-amber-400" } else { "" };
 
     rsx! {
         button {
-            class: "btn btn-secondary flex items-center gap-2 px-3 py-2 {opacity_class} {cursor_class}",
+            class: "btn btn-secondary flex items-center gap-2 px-3 py-2 {opacity_class} {cursor_class} {focus_class}",
+            "data-gamepad-focus": if props.focused { "true" } else { "false" },
             disabled: props.disabled,
             onclick: move |_| {
                 if !props.disabled {
@@ -0,0 +1,71 @@
+//! Audio Cue Player - Plays sounds attached to narrative events and outcomes
+//!
+//! Renders hidden `audio` elements for the cues held in `GameState`: one
+//! looping ambient track plus any stings or fanfares still playing.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::AudioCue;
+use crate::presentation::state::use_game_state;
+
+/// Plays the current audio cues; renders nothing visible
+#[component]
+pub fn AudioCuePlayer() -> Element {
+    let game_state = use_game_state();
+
+    let ambient = game_state.ambient_cue.read().clone();
+    let one_shots = game_state.audio_cues.read().clone();
+
+    rsx! {
+        div {
+            class: "audio-cue-player hidden",
+
+            if let Some(cue) = ambient {
+                audio {
+                    key: "{cue.asset}",
+                    id: "audio-cue-ambient",
+                    src: "{cue.asset}",
+                    autoplay: true,
+                    r#loop: true,
+                    onmounted: move |_| set_volume("audio-cue-ambient", cue.volume),
+                }
+            }
+
+            for (id, cue) in one_shots {
+                audio {
+                    key: "{id}",
+                    id: "audio-cue-{id}",
+                    src: "{cue.asset}",
+                    autoplay: true,
+                    onmounted: {
+                        let element_id = format!("audio-cue-{}", id);
+                        let cue: AudioCue = cue.clone();
+                        move |_| set_volume(&element_id, cue.volume)
+                    },
+                    onended: {
+                        let mut game_state = game_state.clone();
+                        move |_| game_state.finish_audio_cue(id)
+                    },
+                    onerror: {
+                        let mut game_state = game_state.clone();
+                        let asset = cue.asset.clone();
+                        move |_| {
+                            tracing::warn!("Audio cue failed to play: {}", asset);
+                            game_state.finish_audio_cue(id);
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Media volume is a DOM property rather than an attribute
+fn set_volume(element_id: &str, volume: f32) {
+    let js = format!(
+        "const el = document.getElementById('{}'); if (el) {{ el.volume = {}; }}",
+        element_id,
+        volume.clamp(0.0, 1.0)
+    );
+    let _ = document::eval(&js);
+}
@@ -0,0 +1,194 @@
+//! Login modal - authenticate with the Engine via token or username/password
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{persist_auth_session, AuthIdentity, LoginCredentials};
+use crate::presentation::services::use_auth_service;
+use crate::presentation::state::use_modal_focus;
+
+/// Which login method the form is showing
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LoginMethod {
+    Password,
+    Token,
+}
+
+/// Props for LoginModal
+#[derive(Props, Clone, PartialEq)]
+pub struct LoginModalProps {
+    /// Called with the logged-in identity on success
+    pub on_logged_in: EventHandler<AuthIdentity>,
+    pub on_close: EventHandler<()>,
+}
+
+/// Login modal component
+#[component]
+pub fn LoginModal(props: LoginModalProps) -> Element {
+    use_modal_focus("login-modal");
+
+    let auth_service = use_auth_service();
+    let platform = use_context::<Platform>();
+
+    let mut method = use_signal(|| LoginMethod::Password);
+    let mut username = use_signal(String::new);
+    let mut password = use_signal(String::new);
+    let mut token = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+    let mut error_message: Signal<Option<String>> = use_signal(|| None);
+
+    let submit = move |_| {
+        let credentials = match *method.read() {
+            LoginMethod::Password => {
+                let user = username.read().trim().to_string();
+                let pass = password.read().clone();
+                if user.is_empty() || pass.is_empty() {
+                    error_message.set(Some("Username and password are required".to_string()));
+                    return;
+                }
+                LoginCredentials::Password {
+                    username: user,
+                    password: pass,
+                }
+            }
+            LoginMethod::Token => {
+                let tok = token.read().trim().to_string();
+                if tok.is_empty() {
+                    error_message.set(Some("Access token is required".to_string()));
+                    return;
+                }
+                LoginCredentials::Token { token: tok }
+            }
+        };
+
+        let svc = auth_service.clone();
+        let platform = platform.clone();
+        let on_logged_in = props.on_logged_in;
+
+        is_submitting.set(true);
+        error_message.set(None);
+
+        spawn(async move {
+            match svc.login(&credentials).await {
+                Ok(session) => {
+                    persist_auth_session(&platform, &session);
+                    // Never keep the password around longer than needed
+                    password.set(String::new());
+                    on_logged_in.call(session.identity);
+                }
+                Err(e) => {
+                    error_message.set(Some(format!("Login failed: {}", e)));
+                }
+            }
+            is_submitting.set(false);
+        });
+    };
+
+    let current_method = *method.read();
+    let submitting = *is_submitting.read();
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black bg-opacity-75 flex items-center justify-center z-[1100]",
+            onclick: move |_| props.on_close.call(()),
+
+            div {
+                id: "login-modal",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "login-modal-title",
+                class: "bg-dark-surface rounded-xl w-[90%] max-w-sm p-6",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        props.on_close.call(());
+                    }
+                },
+
+                h2 {
+                    id: "login-modal-title",
+                    class: "text-white text-lg m-0 mb-4",
+                    "Log in to Engine"
+                }
+
+                // Method toggle
+                div {
+                    class: "flex gap-1 mb-4",
+
+                    button {
+                        onclick: move |_| method.set(LoginMethod::Password),
+                        class: if current_method == LoginMethod::Password {
+                            "flex-1 py-1.5 bg-blue-500 text-white border-0 rounded-md text-sm cursor-pointer"
+                        } else {
+                            "flex-1 py-1.5 bg-transparent text-gray-400 border border-gray-700 rounded-md text-sm cursor-pointer"
+                        },
+                        "Username"
+                    }
+                    button {
+                        onclick: move |_| method.set(LoginMethod::Token),
+                        class: if current_method == LoginMethod::Token {
+                            "flex-1 py-1.5 bg-blue-500 text-white border-0 rounded-md text-sm cursor-pointer"
+                        } else {
+                            "flex-1 py-1.5 bg-transparent text-gray-400 border border-gray-700 rounded-md text-sm cursor-pointer"
+                        },
+                        "Access Token"
+                    }
+                }
+
+                if current_method == LoginMethod::Password {
+                    div {
+                        class: "flex flex-col gap-3",
+
+                        input {
+                            r#type: "text",
+                            value: "{username}",
+                            oninput: move |e| username.set(e.value()),
+                            placeholder: "Username",
+                            class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white box-border",
+                        }
+                        input {
+                            r#type: "password",
+                            value: "{password}",
+                            oninput: move |e| password.set(e.value()),
+                            placeholder: "Password",
+                            class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white box-border",
+                        }
+                    }
+                } else {
+                    input {
+                        r#type: "password",
+                        value: "{token}",
+                        oninput: move |e| token.set(e.value()),
+                        placeholder: "Paste access token",
+                        class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white box-border",
+                    }
+                }
+
+                if let Some(err) = error_message.read().as_ref() {
+                    p {
+                        class: "text-red-400 text-sm mt-3 mb-0",
+                        "{err}"
+                    }
+                }
+
+                div {
+                    class: "flex gap-3 justify-end mt-6",
+
+                    button {
+                        onclick: move |_| props.on_close.call(()),
+                        disabled: submitting,
+                        class: "py-2 px-4 bg-gray-700 text-white border-0 rounded-lg cursor-pointer text-sm",
+                        "Cancel"
+                    }
+                    button {
+                        onclick: submit,
+                        disabled: submitting,
+                        class: "py-2 px-4 bg-blue-500 text-white border-0 rounded-lg cursor-pointer text-sm font-medium",
+                        if submitting { "Logging in..." } else { "Log in" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! Authentication components - login modal and header user menu
+
+mod login_modal;
+mod user_menu;
+
+pub use login_modal::LoginModal;
+pub use user_menu::UserMenu;
@@ -0,0 +1,105 @@
+//! User menu - shows the logged-in identity with logout, accessibility
+//! options and issue reporting
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::clear_auth_session;
+use crate::presentation::components::auth::LoginModal;
+use crate::presentation::components::shared::{AccessibilityModal, BugReportModal};
+use crate::presentation::services::use_auth_service;
+use crate::presentation::state::use_auth_state;
+
+/// Header widget showing "Log in" when anonymous, or the identity and
+/// a logout button when logged in.
+#[component]
+pub fn UserMenu() -> Element {
+    let auth_service = use_auth_service();
+    let platform = use_context::<Platform>();
+    let auth_state = use_auth_state();
+    let mut show_login = use_signal(|| false);
+    let mut show_report = use_signal(|| false);
+    let mut show_accessibility = use_signal(|| false);
+
+    let identity = auth_state.identity.read().clone();
+
+    let auth_state_for_logout = auth_state.clone();
+    let logout = move |_| {
+        let svc = auth_service.clone();
+        let platform = platform.clone();
+        let mut auth_state = auth_state_for_logout.clone();
+        spawn(async move {
+            // Best-effort: the local session is cleared even if the Engine call fails
+            if let Err(e) = svc.logout().await {
+                tracing::warn!("Engine logout failed: {}", e);
+            }
+            clear_auth_session(&platform);
+            auth_state.clear();
+        });
+    };
+
+    rsx! {
+        div {
+            class: "user-menu flex items-center gap-2 text-sm",
+
+            if let Some(identity) = identity {
+                span {
+                    class: "text-gray-300",
+                    title: "{identity.user_id}",
+                    "{identity.display_name}"
+                }
+                button {
+                    onclick: logout,
+                    class: "py-1 px-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-xs",
+                    "Log out"
+                }
+            } else {
+                button {
+                    onclick: move |_| show_login.set(true),
+                    class: "py-1 px-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-xs",
+                    "Log in"
+                }
+            }
+
+            button {
+                onclick: move |_| show_accessibility.set(true),
+                class: "py-1 px-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-xs",
+                title: "Accessibility options",
+                aria_haspopup: "dialog",
+                "Aa Accessibility"
+            }
+
+            button {
+                onclick: move |_| show_report.set(true),
+                class: "py-1 px-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-xs",
+                title: "Report an issue",
+                "🐞 Report Issue"
+            }
+
+            if *show_accessibility.read() {
+                AccessibilityModal {
+                    on_close: move |_| show_accessibility.set(false),
+                }
+            }
+
+            if *show_report.read() {
+                BugReportModal {
+                    on_close: move |_| show_report.set(false),
+                }
+            }
+
+            if *show_login.read() {
+                LoginModal {
+                    on_logged_in: {
+                        let mut auth_state = auth_state.clone();
+                        move |identity| {
+                            auth_state.set_identity(identity);
+                            show_login.set(false);
+                        }
+                    },
+                    on_close: move |_| show_login.set(false),
+                }
+            }
+        }
+    }
+}
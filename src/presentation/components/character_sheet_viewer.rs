@@ -4,8 +4,11 @@ use dioxus::prelude::*;
 use std::collections::HashMap;
 
 use crate::application::dto::{
-    FieldType, FieldValue, SheetField, SheetSection, SheetTemplate,
+    ActiveCondition, FieldType, FieldValue, SheetField, SheetSection, SheetTemplate,
 };
+use crate::application::services::SheetExport;
+use crate::presentation::components::common::{ConditionBadges, SheetExportButtons};
+use crate::presentation::state::use_modal_focus;
 
 /// Props for the character sheet viewer
 #[derive(Props, Clone, PartialEq)]
@@ -16,6 +19,12 @@ pub struct CharacterSheetViewerProps {
     pub template: SheetTemplate,
     /// The character's values
     pub values: HashMap<String, FieldValue>,
+    /// Conditions currently affecting the character
+    #[props(default)]
+    pub conditions: Vec<ActiveCondition>,
+    /// The sheet to print or save; the export buttons show when set
+    #[props(default)]
+    pub export: Option<SheetExport>,
     /// Handler for closing the viewer
     pub on_close: EventHandler<()>,
 }
@@ -23,6 +32,7 @@ pub struct CharacterSheetViewerProps {
 /// Character Sheet Viewer - modal overlay showing character stats
 #[component]
 pub fn CharacterSheetViewer(props: CharacterSheetViewerProps) -> Element {
+    use_modal_focus("character-sheet-modal");
     // Sort sections by order
     let mut sorted_sections = props.template.sections.clone();
     sorted_sections.sort_by_key(|s| s.order);
@@ -35,6 +45,9 @@ pub fn CharacterSheetViewer(props: CharacterSheetViewerProps) -> Element {
 
             // Sheet container (prevent click propagation)
             div {
+                id: "character-sheet-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "character-sheet-modal bg-gradient-to-br from-dark-surface to-dark-gradient-end rounded-2xl w-full max-w-3xl max-h-[90vh] overflow-hidden flex flex-col shadow-2xl",
                 onclick: move |e| e.stop_propagation(),
 
@@ -53,10 +66,19 @@ pub fn CharacterSheetViewer(props: CharacterSheetViewerProps) -> Element {
                         }
                     }
 
-                    button {
-                        onclick: move |_| props.on_close.call(()),
-                        class: "w-9 h-9 bg-white/10 border-0 rounded-lg text-gray-400 cursor-pointer text-xl flex items-center justify-center hover:bg-white/20",
-                        "×"
+                    div {
+                        class: "flex items-center gap-2",
+
+                        if let Some(sheet) = props.export.clone() {
+                            SheetExportButtons { sheet }
+                        }
+
+                        button {
+                            aria_label: "Close",
+                            onclick: move |_| props.on_close.call(()),
+                            class: "w-9 h-9 bg-white/10 border-0 rounded-lg text-gray-400 cursor-pointer text-xl flex items-center justify-center hover:bg-white/20",
+                            "×"
+                        }
                     }
                 }
 
@@ -67,6 +89,17 @@ pub fn CharacterSheetViewer(props: CharacterSheetViewerProps) -> Element {
                     div {
                         class: "flex flex-col gap-6",
 
+                        if !props.conditions.is_empty() {
+                            div {
+                                class: "sheet-section bg-black/20 rounded-xl px-4 py-3",
+                                h3 {
+                                    class: "text-gray-200 text-sm mt-0 mb-2 font-semibold uppercase tracking-wide",
+                                    "Conditions"
+                                }
+                                ConditionBadges { conditions: props.conditions.clone(), labelled: true }
+                            }
+                        }
+
                         for section in sorted_sections {
                             SheetSectionViewer {
                                 key: "{section.id}",
@@ -0,0 +1,131 @@
+//! Annotation overlay - DM drawings over a backdrop
+//!
+//! Draws a surface's annotations in an SVG stretched over its parent, so the
+//! parent must be positioned. Players only see layers the DM has made
+//! visible; the DM sees everything.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{
+    arrow_head, stroke_path, Annotation, AnnotationShape, AnnotationSurface, VIEWBOX_SIZE,
+};
+use crate::presentation::state::{use_game_state, use_session_state};
+
+/// Read-only annotations for a surface, kept live from the Engine
+#[component]
+pub fn AnnotationOverlay(surface: AnnotationSurface) -> Element {
+    let game_state = use_game_state();
+    let session_state = use_session_state();
+    let is_dm = session_state.user_role().read().is_some_and(|r| r.is_dm());
+
+    let surface_annotations = game_state.annotations_for(&surface);
+    let visible: Vec<Annotation> = surface_annotations.visible(is_dm).into_iter().cloned().collect();
+
+    if visible.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        AnnotationSvg { annotations: visible }
+    }
+}
+
+/// Annotations drawn in an SVG that fills its positioned parent
+#[component]
+pub fn AnnotationSvg(annotations: Vec<Annotation>, #[props(default)] children: Element) -> Element {
+    let view_box = format!("0 0 {} {}", VIEWBOX_SIZE, VIEWBOX_SIZE);
+
+    rsx! {
+        svg {
+            class: "annotation-overlay absolute inset-0 w-full h-full pointer-events-none",
+            view_box: "{view_box}",
+            preserve_aspect_ratio: "none",
+            "aria-hidden": "true",
+
+            for annotation in annotations.iter() {
+                AnnotationMark { key: "{annotation.id}", annotation: annotation.clone() }
+            }
+
+            {children}
+        }
+    }
+}
+
+/// One annotation as SVG
+#[component]
+pub fn AnnotationMark(annotation: Annotation) -> Element {
+    let color = annotation.color.clone();
+    match annotation.shape {
+        AnnotationShape::Freehand { points } => rsx! {
+            path {
+                d: "{stroke_path(&points)}",
+                fill: "none",
+                stroke: "{color}",
+                stroke_width: "4",
+                stroke_linecap: "round",
+                stroke_linejoin: "round",
+            }
+        },
+        AnnotationShape::Arrow { from, to } => {
+            let (x1, y1) = from.scaled();
+            let (x2, y2) = to.scaled();
+            rsx! {
+                g {
+                    line {
+                        x1: "{x1}",
+                        y1: "{y1}",
+                        x2: "{x2}",
+                        y2: "{y2}",
+                        stroke: "{color}",
+                        stroke_width: "5",
+                        stroke_linecap: "round",
+                    }
+                    polygon { points: "{arrow_head(from, to)}", fill: "{color}" }
+                }
+            }
+        }
+        AnnotationShape::Pin { at, label } => {
+            let (x, y) = at.scaled();
+            rsx! {
+                g {
+                    circle {
+                        cx: "{x}",
+                        cy: "{y}",
+                        r: "10",
+                        fill: "{color}",
+                        stroke: "white",
+                        stroke_width: "3",
+                    }
+                    if let Some(label) = label {
+                        text {
+                            x: "{x + 16.0}",
+                            y: "{y + 8.0}",
+                            fill: "white",
+                            stroke: "black",
+                            stroke_width: "4",
+                            paint_order: "stroke",
+                            font_size: "26",
+                            "{label}"
+                        }
+                    }
+                }
+            }
+        }
+        AnnotationShape::Text { at, text } => {
+            let (x, y) = at.scaled();
+            rsx! {
+                text {
+                    x: "{x}",
+                    y: "{y}",
+                    fill: "{color}",
+                    stroke: "black",
+                    stroke_width: "4",
+                    paint_order: "stroke",
+                    font_size: "32",
+                    font_weight: "bold",
+                    "{text}"
+                }
+            }
+        }
+    }
+}
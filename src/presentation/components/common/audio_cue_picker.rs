@@ -0,0 +1,95 @@
+//! Audio cue picker for narrative events and challenge outcomes
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{AudioCue, AudioCueKind};
+
+/// Pick the sound (if any) played on players' clients
+#[component]
+pub fn AudioCuePicker(cue: Option<AudioCue>, on_change: EventHandler<Option<AudioCue>>) -> Element {
+    let Some(cue) = cue else {
+        return rsx! {
+            button {
+                r#type: "button",
+                onclick: move |_| on_change.call(Some(AudioCue::new(AudioCueKind::Sting, String::new()))),
+                class: "self-start p-1 bg-transparent border border-dashed border-gray-700 rounded text-gray-500 text-xs cursor-pointer",
+                "🔊 Add audio cue"
+            }
+        };
+    };
+
+    let volume_percent = (cue.volume * 100.0).round() as i32;
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 p-2 bg-dark-bg border border-gray-700 rounded",
+
+            div { class: "flex items-center gap-2",
+                span { class: "text-xs", "🔊" }
+                select {
+                    value: "{cue.kind.display_name()}",
+                    onchange: {
+                        let cue = cue.clone();
+                        move |e: FormEvent| {
+                            if let Some(kind) = AudioCueKind::all().into_iter().find(|k| k.display_name() == e.value()) {
+                                on_change.call(Some(AudioCue { kind, ..cue.clone() }));
+                            }
+                        }
+                    },
+                    class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    for kind in AudioCueKind::all() {
+                        option {
+                            value: "{kind.display_name()}",
+                            selected: kind == cue.kind,
+                            "{kind.display_name()}"
+                        }
+                    }
+                }
+                input {
+                    r#type: "text",
+                    value: "{cue.asset}",
+                    placeholder: "Audio URL or asset path",
+                    oninput: {
+                        let cue = cue.clone();
+                        move |e: FormEvent| on_change.call(Some(AudioCue { asset: e.value(), ..cue.clone() }))
+                    },
+                    class: "flex-1 min-w-0 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                }
+                button {
+                    r#type: "button",
+                    onclick: move |_| on_change.call(None),
+                    class: "bg-transparent border-0 text-gray-500 text-xs cursor-pointer",
+                    "Remove"
+                }
+            }
+
+            div { class: "flex items-center gap-2",
+                label { class: "text-gray-500 text-xs", "Volume" }
+                input {
+                    r#type: "range",
+                    min: "0",
+                    max: "100",
+                    value: "{volume_percent}",
+                    oninput: {
+                        let cue = cue.clone();
+                        move |e: FormEvent| {
+                            if let Ok(percent) = e.value().parse::<f32>() {
+                                on_change.call(Some(AudioCue { volume: (percent / 100.0).clamp(0.0, 1.0), ..cue.clone() }));
+                            }
+                        }
+                    },
+                    class: "flex-1",
+                }
+                span { class: "text-gray-500 text-xs w-8 text-right", "{volume_percent}%" }
+            }
+
+            if !cue.asset.trim().is_empty() {
+                audio {
+                    src: "{cue.asset}",
+                    controls: true,
+                    class: "w-full h-8",
+                }
+            }
+        }
+    }
+}
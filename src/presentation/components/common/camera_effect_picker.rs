@@ -0,0 +1,131 @@
+//! Camera effect picker for narrative events and challenge outcomes
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{CameraEffect, PanDirection, ShakeStrength};
+
+/// Pick the camera move (if any) played on the stage
+///
+/// Attached zooms have no character; they centre on whoever is speaking
+/// when the effect plays.
+#[component]
+pub fn CameraEffectPicker(effect: Option<CameraEffect>, on_change: EventHandler<Option<CameraEffect>>) -> Element {
+    let Some(effect) = effect else {
+        return rsx! {
+            button {
+                r#type: "button",
+                onclick: move |_| on_change.call(CameraEffect::templates().into_iter().next()),
+                class: "self-start p-1 bg-transparent border border-dashed border-gray-700 rounded text-gray-500 text-xs cursor-pointer",
+                "🎥 Add camera effect"
+            }
+        };
+    };
+
+    let select_class = "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs";
+    let seconds = effect.duration_ms() as f32 / 1000.0;
+
+    rsx! {
+        div {
+            class: "flex flex-wrap items-center gap-2 p-2 bg-dark-bg border border-gray-700 rounded",
+
+            span { class: "text-xs", "🎥" }
+            select {
+                aria_label: "Camera effect",
+                onchange: move |e: FormEvent| {
+                    if let Some(template) = CameraEffect::templates().into_iter().find(|t| t.display_name() == e.value()) {
+                        on_change.call(Some(template));
+                    }
+                },
+                class: select_class,
+                for template in CameraEffect::templates() {
+                    option {
+                        value: "{template.display_name()}",
+                        selected: template.display_name() == effect.display_name(),
+                        "{template.display_name()}"
+                    }
+                }
+            }
+
+            match effect.clone() {
+                CameraEffect::Shake { strength } => rsx! {
+                    select {
+                        aria_label: "Shake strength",
+                        onchange: move |e: FormEvent| {
+                            if let Some(strength) = ShakeStrength::all().into_iter().find(|s| s.display_name() == e.value()) {
+                                on_change.call(Some(CameraEffect::Shake { strength }));
+                            }
+                        },
+                        class: select_class,
+                        for option_strength in ShakeStrength::all() {
+                            option {
+                                value: "{option_strength.display_name()}",
+                                selected: option_strength == strength,
+                                "{option_strength.display_name()}"
+                            }
+                        }
+                    }
+                },
+                CameraEffect::Zoom { character_id, .. } => rsx! {
+                    DurationInput {
+                        seconds,
+                        on_change: move |duration_ms| {
+                            on_change.call(Some(CameraEffect::Zoom { character_id: character_id.clone(), duration_ms }))
+                        },
+                    }
+                },
+                CameraEffect::Pan { direction, duration_ms } => rsx! {
+                    select {
+                        aria_label: "Pan direction",
+                        onchange: move |e: FormEvent| {
+                            if let Some(direction) = PanDirection::all().into_iter().find(|d| d.display_name() == e.value()) {
+                                on_change.call(Some(CameraEffect::Pan { direction, duration_ms }));
+                            }
+                        },
+                        class: select_class,
+                        for option_direction in PanDirection::all() {
+                            option {
+                                value: "{option_direction.display_name()}",
+                                selected: option_direction == direction,
+                                "{option_direction.display_name()}"
+                            }
+                        }
+                    }
+                    DurationInput {
+                        seconds,
+                        on_change: move |duration_ms| on_change.call(Some(CameraEffect::Pan { direction, duration_ms })),
+                    }
+                },
+            }
+
+            button {
+                r#type: "button",
+                onclick: move |_| on_change.call(None),
+                class: "bg-transparent border-0 text-gray-500 text-xs cursor-pointer",
+                "Remove"
+            }
+        }
+    }
+}
+
+/// Length of a zoom or pan, edited in seconds
+#[component]
+fn DurationInput(seconds: f32, on_change: EventHandler<u32>) -> Element {
+    rsx! {
+        label { class: "flex items-center gap-1 text-gray-500 text-xs",
+            input {
+                r#type: "number",
+                min: "0.5",
+                max: "30",
+                step: "0.5",
+                value: "{seconds}",
+                oninput: move |e: FormEvent| {
+                    if let Ok(secs) = e.value().parse::<f32>() {
+                        on_change.call((secs.clamp(0.5, 30.0) * 1000.0) as u32);
+                    }
+                },
+                class: "w-16 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+            }
+            "s"
+        }
+    }
+}
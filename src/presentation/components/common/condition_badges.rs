@@ -0,0 +1,55 @@
+//! Condition badges - Icons for a character's active conditions
+
+use dioxus::prelude::*;
+
+use crate::application::dto::ActiveCondition;
+use crate::application::services::{condition_icon, condition_summary};
+
+/// Row of condition icons; hovering one shows its duration and modifiers
+#[component]
+pub fn ConditionBadges(
+    conditions: Vec<ActiveCondition>,
+    /// Show names next to the icons
+    #[props(default)]
+    labelled: bool,
+    /// When set, each badge gets a remove button that passes the condition ID
+    #[props(default)]
+    on_remove: Option<EventHandler<String>>,
+) -> Element {
+    rsx! {
+        div {
+            class: "condition-badges flex flex-wrap gap-1",
+            role: "list",
+            aria_label: "Conditions",
+
+            for condition in conditions.iter() {
+                {
+                    let condition_id = condition.id.clone();
+                    let summary = condition_summary(condition);
+                    rsx! {
+                        span {
+                            key: "{condition.id}",
+                            role: "listitem",
+                            title: "{summary}",
+                            aria_label: "{summary}",
+                            class: "inline-flex items-center gap-1 px-1.5 py-0.5 bg-black/60 border border-purple-500/40 rounded-full text-xs text-gray-200",
+                            span { aria_hidden: "true", "{condition_icon(&condition.name)}" }
+                            if labelled {
+                                span { "{condition.name}" }
+                                span { class: "text-gray-500", "{condition.duration.describe()}" }
+                            }
+                            if let Some(handler) = on_remove {
+                                button {
+                                    aria_label: "Remove {condition.name}",
+                                    onclick: move |_| handler.call(condition_id.clone()),
+                                    class: "bg-transparent border-0 text-gray-400 cursor-pointer p-0 leading-none hover:text-white",
+                                    "×"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Decision countdown - time left on a dialogue choice or challenge roll
+//!
+//! The limit comes from the DM's decision timer. Key the component on the
+//! decision so a new choice or challenge starts a fresh countdown.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+
+/// Under this many seconds the countdown turns red
+const WARNING_SECS: u32 = 5;
+
+/// Shrinking bar with the seconds left; calls `on_expire` once at zero
+#[component]
+pub fn DecisionCountdown(seconds: u32, on_expire: EventHandler<()>) -> Element {
+    let platform = use_context::<Platform>();
+    let mut remaining = use_signal(|| seconds);
+
+    use_future(move || {
+        let platform = platform.clone();
+        async move {
+            while *remaining.peek() > 0 {
+                platform.sleep_ms(1000).await;
+                let left = remaining.peek().saturating_sub(1);
+                remaining.set(left);
+            }
+            on_expire.call(());
+        }
+    });
+
+    let left = *remaining.read();
+    let percent = (left * 100).checked_div(seconds).unwrap_or(0);
+    let (bar_class, text_class) = if left <= WARNING_SECS {
+        ("bg-red-500", "text-red-400")
+    } else {
+        ("bg-amber-500", "text-gray-400")
+    };
+
+    rsx! {
+        div {
+            class: "decision-countdown flex items-center gap-2",
+            title: "Time left to decide",
+            role: "timer",
+            aria_label: "Time left to decide",
+
+            div {
+                class: "flex-1 h-1.5 bg-white/10 rounded-full overflow-hidden",
+                aria_hidden: "true",
+                div {
+                    class: "h-full {bar_class} transition-all duration-1000 ease-linear",
+                    style: "width: {percent}%",
+                }
+            }
+            span { class: "text-xs font-mono {text_class} min-w-[2.5rem] text-right", "{left}s" }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Draft restore banner - offers to bring back an autosaved form draft
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+
+/// Human-readable age of a draft or post, e.g. "2 minutes ago"
+pub fn time_ago(saved_at: u64, now: u64) -> String {
+    let secs = now.saturating_sub(saved_at);
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=119 => "1 minute ago".to_string(),
+        120..=3599 => format!("{} minutes ago", secs / 60),
+        3600..=7199 => "1 hour ago".to_string(),
+        7200..=86399 => format!("{} hours ago", secs / 3600),
+        _ => format!("{} days ago", secs / 86400),
+    }
+}
+
+/// Banner shown above a form when an unsaved draft was found
+#[component]
+pub fn DraftRestoreBanner(
+    /// When the draft was saved, in Unix seconds
+    saved_at: u64,
+    on_restore: EventHandler<()>,
+    on_discard: EventHandler<()>,
+) -> Element {
+    let platform = use_context::<Platform>();
+    let age = time_ago(saved_at, platform.now_unix_secs());
+
+    rsx! {
+        div {
+            class: "flex items-center gap-3 p-3 bg-amber-500/10 border border-amber-500/40 rounded-lg text-sm",
+            span { class: "flex-1 text-amber-200", "You have an unsaved draft from {age}." }
+            button {
+                r#type: "button",
+                class: "px-3 py-1 bg-amber-500 text-white border-none rounded cursor-pointer text-xs",
+                onclick: move |_| on_restore.call(()),
+                "Restore"
+            }
+            button {
+                r#type: "button",
+                class: "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-xs",
+                onclick: move |_| on_discard.call(()),
+                "Discard"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::time_ago;
+
+    #[test]
+    fn time_ago_is_relative_to_now() {
+        assert_eq!(time_ago(1000, 1010), "just now");
+        assert_eq!(time_ago(1000, 1090), "1 minute ago");
+        assert_eq!(time_ago(1000, 1000 + 5 * 60), "5 minutes ago");
+        assert_eq!(time_ago(1000, 1000 + 3 * 3600), "3 hours ago");
+        assert_eq!(time_ago(1000, 1000 + 2 * 86400), "2 days ago");
+        // Clock skew never underflows
+        assert_eq!(time_ago(2000, 1000), "just now");
+    }
+}
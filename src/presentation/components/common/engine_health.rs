@@ -0,0 +1,99 @@
+//! Engine health - heartbeat monitoring and degradation status
+//!
+//! `EngineHealthMonitor` sends heartbeats while connected and re-checks the
+//! LLM timeout, keeping `SessionState::engine_health` current. Views read the
+//! resulting modes to hide generation and switch to manual dialogue;
+//! `EngineHealthBanner` explains what's degraded. The monitor also picks up
+//! HTTP endpoints whose circuit has opened, so they show in the banner.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::ServiceHealth;
+use crate::presentation::services::use_open_circuits;
+use crate::presentation::state::use_session_state;
+
+/// How often a heartbeat is sent and timeouts are re-checked
+const HEARTBEAT_MS: u64 = 5_000;
+
+/// Keeps Engine health up to date; renders nothing
+#[component]
+pub fn EngineHealthMonitor() -> Element {
+    let session_state = use_session_state();
+    let platform = use_context::<Platform>();
+    let open_circuits = use_open_circuits();
+
+    {
+        let mut engine_health = session_state.engine_health();
+        let engine_client = session_state.engine_client();
+        use_hook(move || {
+            spawn(async move {
+                loop {
+                    platform.sleep_ms(HEARTBEAT_MS).await;
+                    let circuits = open_circuits();
+                    if circuits.as_slice() != engine_health.peek().open_circuits() {
+                        engine_health.write().set_open_circuits(circuits);
+                    }
+                    let Some(client) = engine_client.peek().clone() else {
+                        continue;
+                    };
+                    let now = platform.now_millis();
+                    engine_health.write().tick(now);
+                    match client.heartbeat() {
+                        Ok(()) => engine_health.write().ping_sent(now),
+                        Err(e) => tracing::warn!("Failed to send heartbeat: {}", e),
+                    }
+                }
+            })
+        });
+    }
+
+    rsx! {}
+}
+
+/// Status messages for degraded Engine services
+#[component]
+pub fn EngineHealthBanner() -> Element {
+    let session_state = use_session_state();
+    let health = session_state.engine_health().read().clone();
+    let notices = health.notices();
+
+    if notices.is_empty() {
+        return rsx! {};
+    }
+
+    let latency = match health.latency_ms() {
+        Some(ms) => format!("{} ms", ms),
+        None => "no heartbeat yet".to_string(),
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            for (index, notice) in notices.iter().enumerate() {
+                div {
+                    key: "{index}",
+                    class: if notice.severity == ServiceHealth::Unavailable {
+                        "px-3 py-2 bg-red-500/80 text-white rounded-lg text-xs flex items-center gap-2"
+                    } else {
+                        "px-3 py-2 bg-amber-500/80 text-white rounded-lg text-xs flex items-center gap-2"
+                    },
+                    span { if notice.severity == ServiceHealth::Unavailable { "🔴" } else { "⚠️" } }
+                    span { "{notice.message}" }
+                }
+            }
+            div {
+                class: "px-3 text-gray-400 text-xs",
+                "LLM: {service_label(health.llm())} · ComfyUI: {service_label(health.comfyui())} · Latency: {latency}"
+            }
+        }
+    }
+}
+
+fn service_label(health: ServiceHealth) -> &'static str {
+    match health {
+        ServiceHealth::Available => "available",
+        ServiceHealth::Degraded => "degraded",
+        ServiceHealth::Unavailable => "unavailable",
+    }
+}
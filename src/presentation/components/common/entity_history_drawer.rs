@@ -0,0 +1,233 @@
+//! Entity history drawer - saved versions of a character, location or challenge
+//!
+//! Lists versions newest first with who saved them and when. Expanding a
+//! version shows the fields it changed from the one before; earlier versions
+//! can be rolled back to.
+
+use dioxus::prelude::*;
+
+use crate::application::services::{diff_versions, display_value, EntityVersion, HistoryEntityKind};
+use crate::presentation::services::use_entity_history_service;
+use crate::presentation::state::use_modal_focus;
+
+/// Side drawer listing an entity's saved versions
+#[component]
+pub fn EntityHistoryDrawer(
+    kind: HistoryEntityKind,
+    entity_id: String,
+    /// Called after a rollback so the form can reload the entity
+    on_rolled_back: EventHandler<()>,
+    on_close: EventHandler<()>,
+) -> Element {
+    use_modal_focus("entity-history-drawer");
+
+    let history_service = use_entity_history_service();
+
+    let mut versions: Signal<Vec<EntityVersion>> = use_signal(Vec::new);
+    let mut is_loading = use_signal(|| true);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut expanded: Signal<Option<u32>> = use_signal(|| None);
+    let mut confirming: Signal<Option<u32>> = use_signal(|| None);
+    let mut is_rolling_back = use_signal(|| false);
+    let mut reload = use_signal(|| 0u32);
+
+    let history_service_for_effect = history_service.clone();
+    let entity_id_for_effect = entity_id.clone();
+    use_effect(move || {
+        let _ = *reload.read();
+        let svc = history_service_for_effect.clone();
+        let id = entity_id_for_effect.clone();
+        spawn(async move {
+            is_loading.set(true);
+            match svc.list_versions(kind, &id).await {
+                Ok(list) => {
+                    versions.set(list);
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(format!("Failed to load history: {}", e))),
+            }
+            is_loading.set(false);
+        });
+    });
+
+    let do_rollback = move |version: u32| {
+        let svc = history_service.clone();
+        let id = entity_id.clone();
+        spawn(async move {
+            is_rolling_back.set(true);
+            error.set(None);
+            match svc.rollback(kind, &id, version).await {
+                Ok(()) => {
+                    confirming.set(None);
+                    expanded.set(None);
+                    on_rolled_back.call(());
+                    let next = *reload.peek() + 1;
+                    reload.set(next);
+                }
+                Err(e) => error.set(Some(format!("Failed to roll back: {}", e))),
+            }
+            is_rolling_back.set(false);
+        });
+    };
+
+    let list = versions.read().clone();
+    let latest = list.first().map(|v| v.version);
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/50 flex justify-end z-[1200]",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                id: "entity-history-drawer",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "entity-history-drawer-title",
+                class: "entity-history-drawer bg-dark-surface w-full max-w-[420px] h-full flex flex-col border-l border-gray-700",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        on_close.call(());
+                    }
+                },
+
+                div {
+                    class: "flex justify-between items-center px-4 py-3 border-b border-gray-700",
+                    h3 { id: "entity-history-drawer-title", class: "text-white m-0 text-base", "{kind.display_name()} History" }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| on_close.call(()),
+                        class: "bg-transparent border-0 text-gray-400 text-2xl cursor-pointer",
+                        "×"
+                    }
+                }
+
+                if let Some(err) = error.read().as_ref() {
+                    div {
+                        class: "m-4 mb-0 p-3 bg-red-500 bg-opacity-10 text-red-500 text-sm rounded-md",
+                        "{err}"
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-y-auto p-4 flex flex-col gap-2",
+
+                    if *is_loading.read() && list.is_empty() {
+                        p { class: "text-gray-400 text-sm text-center m-0", "Loading history..." }
+                    } else if list.is_empty() {
+                        p { class: "text-gray-500 text-sm text-center m-0", "No saved versions yet." }
+                    }
+
+                    for (index, version) in list.iter().enumerate() {
+                        {
+                            let number = version.version;
+                            let is_latest = latest == Some(number);
+                            let is_expanded = *expanded.read() == Some(number);
+                            let author = version.author.clone().unwrap_or_else(|| "Unknown".to_string());
+                            let saved_at = format_saved_at(&version.saved_at);
+                            let changes = match list.get(index + 1) {
+                                Some(previous) => diff_versions(&previous.data, &version.data),
+                                None => Vec::new(),
+                            };
+                            let is_first = index + 1 == list.len();
+                            let summary = if is_first {
+                                "Created".to_string()
+                            } else if changes.len() == 1 {
+                                "1 field changed".to_string()
+                            } else {
+                                format!("{} fields changed", changes.len())
+                            };
+                            rsx! {
+                                div {
+                                    key: "{number}",
+                                    class: "bg-dark-bg rounded-lg",
+
+                                    button {
+                                        onclick: move |_| {
+                                            let current = *expanded.peek();
+                                            expanded.set(if current == Some(number) { None } else { Some(number) });
+                                        },
+                                        class: "w-full flex items-center gap-2 p-3 bg-transparent border-0 cursor-pointer text-left",
+                                        span { class: "text-purple-400 text-xs font-mono", "v{number}" }
+                                        div {
+                                            class: "flex-1 min-w-0",
+                                            div { class: "text-white text-sm truncate", "{author}" }
+                                            div { class: "text-gray-500 text-xs", "{saved_at} · {summary}" }
+                                        }
+                                        if is_latest {
+                                            span { class: "text-green-500 text-xs", "Current" }
+                                        }
+                                    }
+
+                                    if is_expanded {
+                                        div {
+                                            class: "px-3 pb-3 flex flex-col gap-2",
+
+                                            if !is_first && changes.is_empty() {
+                                                p { class: "text-gray-500 text-xs italic m-0", "Saved without changes" }
+                                            }
+                                            for change in changes.iter() {
+                                                div {
+                                                    key: "{change.field}",
+                                                    class: "text-xs",
+                                                    div { class: "text-gray-400 mb-1", "{change.field}" }
+                                                    div {
+                                                        class: "text-red-400 line-through break-words",
+                                                        "{display_value(change.before.as_ref())}"
+                                                    }
+                                                    div {
+                                                        class: "text-green-400 break-words",
+                                                        "{display_value(change.after.as_ref())}"
+                                                    }
+                                                }
+                                            }
+
+                                            if !is_latest {
+                                                if *confirming.read() == Some(number) {
+                                                    div {
+                                                        class: "flex items-center gap-2 mt-1",
+                                                        span { class: "text-amber-500 text-xs flex-1", "Restore v{number} as a new version?" }
+                                                        button {
+                                                            onclick: move |_| confirming.set(None),
+                                                            disabled: *is_rolling_back.read(),
+                                                            class: "px-2 py-1 bg-gray-700 text-white border-0 rounded cursor-pointer text-xs",
+                                                            "Cancel"
+                                                        }
+                                                        button {
+                                                            onclick: {
+                                                                let do_rollback = do_rollback.clone();
+                                                                move |_| do_rollback(number)
+                                                            },
+                                                            disabled: *is_rolling_back.read(),
+                                                            class: "px-2 py-1 bg-amber-500 text-white border-0 rounded cursor-pointer text-xs disabled:opacity-50",
+                                                            if *is_rolling_back.read() { "Rolling back..." } else { "Roll back" }
+                                                        }
+                                                    }
+                                                } else {
+                                                    button {
+                                                        onclick: move |_| confirming.set(Some(number)),
+                                                        class: "self-start mt-1 px-2 py-1 bg-transparent text-amber-500 border border-amber-500/50 rounded cursor-pointer text-xs",
+                                                        "Roll back to v{number}"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Format a version's RFC 3339 save time for display
+fn format_saved_at(saved_at: &str) -> String {
+    match chrono::DateTime::parse_from_rfc3339(saved_at) {
+        Ok(dt) => dt.format("%b %d, %H:%M").to_string(),
+        Err(_) => saved_at.to_string(),
+    }
+}
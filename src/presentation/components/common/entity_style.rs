@@ -0,0 +1,131 @@
+//! Entity style - An entity's icon and accent colour, and a picker for them
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{EntityStyle, ACCENT_PRESETS, ICON_PRESETS, MAX_ICON_CHARS};
+
+/// Inline style for an accent stripe down an element's left edge
+///
+/// Empty when there is no colour, so the element keeps its own border.
+pub fn accent_stripe(accent_color: Option<&str>) -> String {
+    accent_color
+        .map(|color| format!("border-left: 3px solid {};", color))
+        .unwrap_or_default()
+}
+
+/// An entity's icon in a small disc of its accent colour
+///
+/// `fallback` stands in for entities without an icon; with neither an icon
+/// nor a fallback, only the colour shows, as a dot.
+#[component]
+pub fn EntityMark(style: EntityStyle, #[props(default)] fallback: Option<&'static str>) -> Element {
+    let icon = style.icon.as_deref().or(fallback);
+    let disc = match style.accent_color.as_deref() {
+        // 8-digit hex: the accent at about 20% opacity
+        Some(color) => format!("border-color: {}; background-color: {}33;", color, color),
+        None => String::new(),
+    };
+
+    rsx! {
+        if let Some(icon) = icon {
+            span {
+                aria_hidden: "true",
+                class: "inline-flex items-center justify-center shrink-0 w-6 h-6 rounded-full border border-transparent text-sm leading-none",
+                style: "{disc}",
+                "{icon}"
+            }
+        } else if let Some(color) = style.accent_color.as_deref() {
+            span {
+                aria_hidden: "true",
+                class: "inline-block shrink-0 w-2.5 h-2.5 rounded-full",
+                style: "background-color: {color};",
+            }
+        }
+    }
+}
+
+/// Choose an icon and accent colour from presets or by hand
+#[component]
+pub fn EntityStylePicker(style: EntityStyle, on_change: EventHandler<EntityStyle>) -> Element {
+    let icon_text = style.icon.clone().unwrap_or_default();
+    let color_value = style.accent_color.clone().unwrap_or_else(|| "#808080".to_string());
+    let swatch_class = |selected: bool| {
+        if selected {
+            "w-5 h-5 rounded-full border-2 border-white cursor-pointer p-0"
+        } else {
+            "w-5 h-5 rounded-full border-2 border-transparent cursor-pointer p-0"
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+
+            div {
+                class: "flex items-center gap-2",
+                EntityMark { style: style.clone(), fallback: "?" }
+                input {
+                    r#type: "text",
+                    aria_label: "Icon",
+                    maxlength: "{MAX_ICON_CHARS}",
+                    value: "{icon_text}",
+                    placeholder: "Emoji",
+                    oninput: {
+                        let style = style.clone();
+                        move |e: FormEvent| on_change.call(EntityStyle::from_input(&e.value(), style.accent_color.as_deref().unwrap_or_default()))
+                    },
+                    class: "w-20 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                }
+                div {
+                    class: "flex flex-wrap gap-1",
+                    for preset in ICON_PRESETS.iter().copied() {
+                        button {
+                            r#type: "button",
+                            aria_label: "Use icon {preset}",
+                            onclick: {
+                                let style = style.clone();
+                                move |_| on_change.call(EntityStyle { icon: Some(preset.to_string()), ..style.clone() })
+                            },
+                            class: "w-7 h-7 bg-transparent border border-gray-700 rounded cursor-pointer text-sm p-0",
+                            "{preset}"
+                        }
+                    }
+                }
+            }
+
+            div {
+                class: "flex items-center gap-2",
+                input {
+                    r#type: "color",
+                    aria_label: "Accent colour",
+                    value: "{color_value}",
+                    oninput: {
+                        let style = style.clone();
+                        move |e: FormEvent| on_change.call(EntityStyle::from_input(style.icon.as_deref().unwrap_or_default(), &e.value()))
+                    },
+                    class: "w-8 h-7 p-0 bg-transparent border border-gray-700 rounded cursor-pointer",
+                }
+                for preset in ACCENT_PRESETS.iter().copied() {
+                    button {
+                        r#type: "button",
+                        aria_label: "Use colour {preset}",
+                        onclick: {
+                            let style = style.clone();
+                            move |_| on_change.call(EntityStyle { accent_color: Some(preset.to_string()), ..style.clone() })
+                        },
+                        class: swatch_class(style.accent_color.as_deref() == Some(preset)),
+                        style: "background-color: {preset};",
+                    }
+                }
+                if !style.is_empty() {
+                    button {
+                        r#type: "button",
+                        onclick: move |_| on_change.call(EntityStyle::default()),
+                        class: "ml-auto px-2 py-0.5 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-xs",
+                        "Clear"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Faction badges - Names of the factions a character belongs to
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{Faction, StandingLevel};
+
+/// Row of faction name chips; hovering one shows the party's standing
+#[component]
+pub fn FactionBadges(factions: Vec<Faction>) -> Element {
+    rsx! {
+        div {
+            class: "faction-badges flex flex-wrap gap-1",
+            role: "list",
+            aria_label: "Factions",
+
+            for faction in factions.iter() {
+                {
+                    let level = StandingLevel::from_standing(faction.party_standing);
+                    rsx! {
+                        span {
+                            key: "{faction.id}",
+                            role: "listitem",
+                            title: "Party standing: {level.display_name()} ({faction.party_standing})",
+                            class: "inline-flex items-center gap-1 px-1.5 py-0.5 bg-black/60 border border-amber-500/40 rounded-full text-xs text-gray-200",
+                            span { aria_hidden: "true", class: "{level.text_class()}", "⚑" }
+                            "{faction.name}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Filter preset bar - quick-apply chips for saved filter combinations
+//!
+//! Used by the Challenge Library, Generation Queue and Story Arc timeline.
+//! Presets are saved on this device per list and world
+//! (see `application::services::filter_presets`).
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{
+    load_filter_presets, save_filter_presets, upsert_preset, FilterPreset, FilterPresetScope,
+};
+
+/// Row of saved filter presets plus a control to save the current filters
+#[component]
+pub fn FilterPresetBar(
+    scope: FilterPresetScope,
+    world_id: String,
+    /// Filters currently applied to the list (name is ignored)
+    current: FilterPreset,
+    on_apply: EventHandler<FilterPreset>,
+) -> Element {
+    let platform = use_context::<Platform>();
+    let mut presets = use_signal({
+        let platform = platform.clone();
+        let world_id = world_id.clone();
+        move || load_filter_presets(&platform, scope, &world_id)
+    });
+    let mut naming: Signal<Option<String>> = use_signal(|| None);
+
+    let persist = {
+        let platform = platform.clone();
+        let world_id = world_id.clone();
+        move |next: Vec<FilterPreset>| {
+            save_filter_presets(&platform, scope, &world_id, &next);
+            presets.set(next);
+        }
+    };
+
+    let saved = presets.read().clone();
+    let can_save = !current.is_empty();
+
+    rsx! {
+        div {
+            class: "filter-preset-bar flex flex-wrap items-center gap-1",
+
+            span { class: "text-gray-500 text-xs mr-1", "Presets:" }
+
+            for preset in saved.iter().cloned() {
+                {
+                    let is_active = preset.matches(&current);
+                    let chip_class = if is_active {
+                        "bg-purple-500 text-white border-purple-500"
+                    } else {
+                        "bg-transparent text-gray-400 border-gray-700"
+                    };
+                    let name = preset.name.clone();
+                    let saved = saved.clone();
+                    let mut persist = persist.clone();
+                    rsx! {
+                        span {
+                            key: "{preset.name}",
+                            class: "flex items-center gap-1 px-2 py-0.5 border rounded-full text-xs {chip_class}",
+                            button {
+                                r#type: "button",
+                                class: "bg-transparent border-none p-0 text-inherit text-xs cursor-pointer",
+                                onclick: {
+                                    let preset = preset.clone();
+                                    move |_| on_apply.call(preset.clone())
+                                },
+                                "{preset.name}"
+                            }
+                            button {
+                                aria_label: "Delete preset",
+                                r#type: "button",
+                                class: "bg-transparent border-none p-0 text-gray-500 hover:text-red-400 text-xs cursor-pointer",
+                                title: "Delete preset",
+                                onclick: move |_| {
+                                    let next: Vec<FilterPreset> = saved
+                                        .iter()
+                                        .filter(|p| p.name != name)
+                                        .cloned()
+                                        .collect();
+                                    persist(next);
+                                },
+                                "×"
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(name) = naming.read().clone() {
+                input {
+                    r#type: "text",
+                    value: "{name}",
+                    placeholder: "Preset name",
+                    autofocus: true,
+                    oninput: move |e| naming.set(Some(e.value())),
+                    onkeydown: {
+                        let current = current.clone();
+                        let mut persist = persist.clone();
+                        move |e: KeyboardEvent| match e.key() {
+                            Key::Enter => {
+                                let name = naming.peek().clone().unwrap_or_default();
+                                let name = name.trim();
+                                if !name.is_empty() {
+                                    let mut next = presets.peek().clone();
+                                    upsert_preset(&mut next, FilterPreset { name: name.to_string(), ..current.clone() });
+                                    persist(next);
+                                    naming.set(None);
+                                }
+                            }
+                            Key::Escape => naming.set(None),
+                            _ => {}
+                        }
+                    },
+                    class: "px-2 py-0.5 bg-dark-bg border border-gray-700 rounded text-white text-xs w-32",
+                }
+                button {
+                    r#type: "button",
+                    class: "px-2 py-0.5 bg-transparent border-none text-gray-500 text-xs cursor-pointer",
+                    onclick: move |_| naming.set(None),
+                    "Cancel"
+                }
+            } else {
+                button {
+                    r#type: "button",
+                    class: "px-2 py-0.5 bg-transparent border border-dashed border-gray-700 rounded-full text-gray-500 text-xs cursor-pointer disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: !can_save,
+                    title: if can_save { "Save the current filters" } else { "Set some filters first" },
+                    onclick: move |_| naming.set(Some(String::new())),
+                    "+ Save filters"
+                }
+            }
+        }
+    }
+}
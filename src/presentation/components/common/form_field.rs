@@ -1,7 +1,14 @@
 use dioxus::prelude::*;
 
 #[component]
-pub fn FormField(label: &'static str, required: bool, children: Element) -> Element {
+pub fn FormField(
+    label: &'static str,
+    required: bool,
+    /// Validation message shown under the input
+    #[props(default)]
+    error: Option<String>,
+    children: Element,
+) -> Element {
     rsx! {
         div {
             class: "form-field flex flex-col gap-1",
@@ -13,6 +20,33 @@ pub fn FormField(label: &'static str, required: bool, children: Element) -> Elem
                 }
             }
             {children}
+            FieldError { message: error }
+        }
+    }
+}
+
+/// Inline validation message under an input
+#[component]
+pub fn FieldError(message: Option<String>) -> Element {
+    rsx! {
+        if let Some(message) = message {
+            p { role: "alert", class: "text-red-500 text-xs mt-1 mb-0", "{message}" }
+        }
+    }
+}
+
+/// Every validation error, above a form after a save attempt
+#[component]
+pub fn ValidationSummary(errors: Vec<String>) -> Element {
+    rsx! {
+        if !errors.is_empty() {
+            div {
+                role: "alert",
+                class: "px-4 py-3 bg-red-500/10 border-l-3 border-l-red-500 rounded text-red-500 text-sm",
+                for error in errors.iter() {
+                    div { class: "mb-1", "• {error}" }
+                }
+            }
         }
     }
 }
@@ -0,0 +1,86 @@
+//! Layered graph layout shared by the graph views
+//!
+//! Graphs are given as a map from each node to the nodes leading into it
+//! (prerequisites, incoming links). Nodes are laid out in columns by their
+//! longest path from a root; nodes on a cycle get a final column of their own.
+
+use std::collections::{HashMap, HashSet};
+
+/// Nodes leading into each node
+pub type Predecessors<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+/// Whether `target` is reachable from `start` walking predecessor edges
+/// (at least one step)
+pub fn reaches(predecessors: &Predecessors, start: &str, target: &str) -> bool {
+    let mut stack: Vec<&str> = predecessors.get(start).cloned().unwrap_or_default();
+    let mut visited: HashSet<&str> = HashSet::new();
+    while let Some(node) = stack.pop() {
+        if node == target {
+            return true;
+        }
+        if !visited.insert(node) {
+            continue;
+        }
+        if let Some(froms) = predecessors.get(node) {
+            stack.extend(froms.iter().copied());
+        }
+    }
+    false
+}
+
+/// IDs of nodes that sit on a cycle
+pub fn nodes_in_cycles(nodes: &[&str], predecessors: &Predecessors) -> HashSet<String> {
+    nodes
+        .iter()
+        .filter(|id| reaches(predecessors, id, id))
+        .map(|id| id.to_string())
+        .collect()
+}
+
+/// Group nodes into layers by their longest path from a root
+///
+/// Layer 0 holds nodes nothing (among `nodes`) leads to. Nodes on a cycle
+/// have no well-defined depth and are returned in a final extra layer. Order
+/// within a layer follows `nodes`.
+pub fn layer_by_depth(nodes: &[&str], predecessors: &Predecessors) -> Vec<Vec<String>> {
+    let known: HashSet<&str> = nodes.iter().copied().collect();
+    let cyclic = nodes_in_cycles(nodes, predecessors);
+    let mut depth: HashMap<&str, usize> = HashMap::new();
+
+    // Relax depths until stable; bounded by the node count since cyclic
+    // nodes are excluded
+    for _ in 0..=nodes.len() {
+        let mut changed = false;
+        for node in nodes.iter().filter(|id| !cyclic.contains(**id)) {
+            let d = predecessors
+                .get(node)
+                .into_iter()
+                .flatten()
+                .filter(|from| known.contains(**from) && !cyclic.contains(**from))
+                .map(|from| depth.get(from).copied().unwrap_or(0) + 1)
+                .max()
+                .unwrap_or(0);
+            if depth.get(node) != Some(&d) {
+                depth.insert(node, d);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let layer_count = depth.values().copied().max().map(|m| m + 1).unwrap_or(0);
+    let mut layers: Vec<Vec<String>> = vec![Vec::new(); layer_count];
+    let mut cycle_layer = Vec::new();
+    for node in nodes {
+        match depth.get(node) {
+            Some(d) => layers[*d].push(node.to_string()),
+            None => cycle_layer.push(node.to_string()),
+        }
+    }
+    if !cycle_layer.is_empty() {
+        layers.push(cycle_layer);
+    }
+    layers
+}
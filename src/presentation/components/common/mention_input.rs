@@ -0,0 +1,77 @@
+//! Mention-aware text input
+//!
+//! Typing `@` followed by part of a name offers matching entities; picking
+//! one inserts a structured `@[Label](kind:id)` reference (see
+//! `application::dto::rich_text`) that the Engine can resolve.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{active_mention_query, complete_mention, matching_mentions, EntityMention};
+
+/// Suggestions shown per `@query`
+const MAX_SUGGESTIONS: usize = 6;
+
+/// Completion popup for a trailing `@query` in `value`
+///
+/// Place inside a `relative` container; it opens below it.
+#[component]
+pub fn MentionSuggestions(
+    value: String,
+    candidates: Vec<EntityMention>,
+    on_change: EventHandler<String>,
+) -> Element {
+    let Some((at, query)) = active_mention_query(&value) else {
+        return rsx! {};
+    };
+    let matches = matching_mentions(&candidates, &query, MAX_SUGGESTIONS);
+    if matches.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "absolute left-2 right-2 top-full mt-1 bg-dark-surface border border-gray-700 rounded shadow-lg z-10 flex flex-col",
+            for candidate in matches.into_iter() {
+                button {
+                    key: "{candidate.kind.as_str()}-{candidate.id}",
+                    r#type: "button",
+                    class: "text-left px-3 py-1.5 bg-transparent border-none text-white text-sm cursor-pointer hover:bg-gray-700",
+                    onclick: {
+                        let value = value.clone();
+                        let candidate = candidate.clone();
+                        move |_| on_change.call(complete_mention(&value, at, &candidate))
+                    },
+                    span { class: "mr-2", "{candidate.kind.icon()}" }
+                    "{candidate.label}"
+                }
+            }
+        }
+    }
+}
+
+/// Textarea with `@`-mention completion
+#[component]
+pub fn MentionTextarea(
+    value: String,
+    on_change: EventHandler<String>,
+    /// Entities that can be mentioned
+    candidates: Vec<EntityMention>,
+    #[props(default)]
+    placeholder: String,
+    /// Classes for the textarea itself
+    #[props(default)]
+    class: String,
+) -> Element {
+    rsx! {
+        div {
+            class: "mention-textarea relative",
+            textarea {
+                value: "{value}",
+                placeholder: "{placeholder}",
+                oninput: move |e| on_change.call(e.value()),
+                class: "{class}",
+            }
+            MentionSuggestions { value: value.clone(), candidates, on_change }
+        }
+    }
+}
@@ -1,2 +1,42 @@
+mod annotation_overlay;
+mod audio_cue_picker;
+mod camera_effect_picker;
+mod condition_badges;
+mod decision_countdown;
+mod draft_restore_banner;
+mod engine_health;
+mod entity_history_drawer;
+mod entity_style;
+mod faction_badges;
+mod filter_preset_bar;
 mod form_field;
-pub use form_field::FormField;
+mod graph_layout;
+mod mention_input;
+mod modal_focus;
+mod perf_overlay;
+mod rich_text_editor;
+mod rich_text_view;
+mod share_buttons;
+mod tag_filter_bar;
+mod tag_input;
+pub use annotation_overlay::{AnnotationMark, AnnotationOverlay, AnnotationSvg};
+pub use audio_cue_picker::AudioCuePicker;
+pub use camera_effect_picker::CameraEffectPicker;
+pub use condition_badges::ConditionBadges;
+pub use decision_countdown::DecisionCountdown;
+pub use draft_restore_banner::{time_ago, DraftRestoreBanner};
+pub use engine_health::{EngineHealthBanner, EngineHealthMonitor};
+pub use entity_history_drawer::EntityHistoryDrawer;
+pub use entity_style::{accent_stripe, EntityMark, EntityStylePicker};
+pub use faction_badges::FactionBadges;
+pub use filter_preset_bar::FilterPresetBar;
+pub use form_field::{FieldError, FormField, ValidationSummary};
+pub use graph_layout::{layer_by_depth, nodes_in_cycles, reaches, Predecessors};
+pub use mention_input::{MentionSuggestions, MentionTextarea};
+pub use modal_focus::ModalFocus;
+pub use perf_overlay::PerfOverlay;
+pub use rich_text_editor::RichTextEditor;
+pub use rich_text_view::{mention_route, RichTextView};
+pub use share_buttons::{CopyButton, ShareButton, SheetExportButtons};
+pub use tag_filter_bar::{collect_tags, TagFilterBar};
+pub use tag_input::{TagChips, TagInput};
@@ -0,0 +1,16 @@
+//! Modal Focus - Focus trap for a modal drawn inline by a larger component
+//!
+//! `use_modal_focus` traps focus for as long as the calling component is
+//! mounted. Modals that are a block inside a bigger component render this
+//! inside the dialog instead, so the trap lasts exactly as long as the block.
+
+use dioxus::prelude::*;
+
+use crate::presentation::state::use_modal_focus;
+
+/// Trap focus in the dialog with this id while rendered; renders nothing
+#[component]
+pub fn ModalFocus(dialog_id: &'static str) -> Element {
+    use_modal_focus(dialog_id);
+    rsx! {}
+}
@@ -0,0 +1,180 @@
+//! Developer performance overlay
+//!
+//! Floating panel with the performance probe's counters: the components
+//! re-rendering most, the busiest signals, the Engine message rate, and frame
+//! timing, plus the Engine's protocol version and any messages that didn't
+//! match this client's schema. Shown when turned on in App Settings.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{schema_issues, ProtocolCompatibility, SchemaIssue, PROTOCOL_VERSION};
+use crate::application::ports::outbound::Platform;
+use crate::presentation::state::perf_probe::{self, PerfSnapshot, SLOW_FRAME_MS};
+use crate::presentation::state::{use_display_state, use_session_state};
+
+/// Target interval between sampled frames (60fps)
+const FRAME_MS: u64 = 16;
+/// How often the panel refreshes its numbers
+const REFRESH_MS: u64 = 500;
+/// Rows shown per hotspot list
+const TOP_ROWS: usize = 6;
+
+/// Performance overlay, rendered only while the toggle is on
+#[component]
+pub fn PerfOverlay() -> Element {
+    let display_state = use_display_state();
+    if !*display_state.perf_overlay.read() {
+        return rsx! {};
+    }
+    rsx! { PerfOverlayPanel {} }
+}
+
+#[component]
+fn PerfOverlayPanel() -> Element {
+    let platform = use_context::<Platform>();
+    let session_state = use_session_state();
+    let mut snapshot: Signal<PerfSnapshot> = use_signal(PerfSnapshot::default);
+    let mut issues: Signal<Vec<(SchemaIssue, u32)>> = use_signal(Vec::new);
+    let mut collapsed = use_signal(|| false);
+
+    // Sample frame intervals and refresh the panel. A frame that takes longer
+    // than FRAME_MS to come back means the UI thread was busy. The task
+    // belongs to this scope and stops when the overlay is turned off.
+    use_hook(move || {
+        spawn(async move {
+            let mut last_frame = platform.now_millis();
+            let mut last_refresh = last_frame;
+            loop {
+                platform.sleep_ms(FRAME_MS).await;
+                let now = platform.now_millis();
+                perf_probe::record_frame(now.saturating_sub(last_frame) as f64);
+                last_frame = now;
+                if now.saturating_sub(last_refresh) >= REFRESH_MS {
+                    snapshot.set(perf_probe::snapshot(now, TOP_ROWS));
+                    let latest = schema_issues().snapshot();
+                    if *issues.peek() != latest {
+                        issues.set(latest);
+                    }
+                    last_refresh = now;
+                }
+            }
+        })
+    });
+
+    let stats = snapshot.read().clone();
+    let protocol = match session_state.engine_health().read().protocol() {
+        ProtocolCompatibility::Unknown => "Engine version not reported".to_string(),
+        ProtocolCompatibility::Match => format!("v{} · matches", PROTOCOL_VERSION),
+        ProtocolCompatibility::EngineNewer(v) => format!("Engine v{} is newer than v{}", v, PROTOCOL_VERSION),
+        ProtocolCompatibility::EngineOlder(v) => format!("Engine v{} is older than v{}", v, PROTOCOL_VERSION),
+    };
+    let frame_class = if stats.frames.p95_ms > SLOW_FRAME_MS { "text-red-400" } else { "text-green-400" };
+
+    rsx! {
+        div {
+            class: "perf-overlay fixed bottom-2 right-2 z-[2000] w-64 bg-black/80 text-gray-200 text-[0.6875rem] font-mono rounded-md border border-gray-700 pointer-events-auto",
+
+            div {
+                class: "flex justify-between items-center px-2 py-1 border-b border-gray-700",
+                span { class: "text-amber-400", "⏱ Perf" }
+                div {
+                    class: "flex gap-2",
+                    button {
+                        onclick: move |_| perf_probe::reset(),
+                        class: "bg-transparent border-0 text-gray-400 cursor-pointer p-0",
+                        "reset"
+                    }
+                    button {
+                        onclick: move |_| {
+                            let now = *collapsed.read();
+                            collapsed.set(!now);
+                        },
+                        class: "bg-transparent border-0 text-gray-400 cursor-pointer p-0",
+                        if *collapsed.read() { "▴" } else { "▾" }
+                    }
+                }
+            }
+
+            if !*collapsed.read() {
+                div {
+                    class: "p-2 flex flex-col gap-2",
+
+                    div {
+                        div { class: "text-gray-500", "Frames (last {perf_probe::FRAME_WINDOW})" }
+                        div {
+                            class: "{frame_class}",
+                            "avg {stats.frames.average_ms:.1}ms · p95 {stats.frames.p95_ms:.1}ms · max {stats.frames.max_ms:.0}ms"
+                        }
+                        div { class: "text-gray-400", "{stats.frames.slow_frames} slow frame(s)" }
+                    }
+
+                    div {
+                        div { class: "text-gray-500", "WebSocket" }
+                        div { "{stats.messages_per_sec:.1} msg/s" }
+                        div { class: "text-gray-400", "Protocol {protocol}" }
+                    }
+
+                    SchemaIssueList { issues: issues.read().clone() }
+
+                    HotspotList { title: "Renders", rows: stats.renders.clone() }
+                    HotspotList { title: "Signal writes", rows: stats.signal_writes.clone() }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn HotspotList(title: &'static str, rows: Vec<(&'static str, u64)>) -> Element {
+    rsx! {
+        div {
+            div { class: "text-gray-500", "{title}" }
+            if rows.is_empty() {
+                div { class: "text-gray-600 italic", "none yet" }
+            }
+            for (name, count) in rows {
+                div {
+                    key: "{name}",
+                    class: "flex justify-between gap-2",
+                    span { class: "truncate", "{name}" }
+                    span { class: "text-amber-300", "{count}" }
+                }
+            }
+        }
+    }
+}
+
+/// Engine messages the client skipped or only partly understood
+#[component]
+fn SchemaIssueList(issues: Vec<(SchemaIssue, u32)>) -> Element {
+    rsx! {
+        div {
+            div {
+                class: "flex justify-between",
+                span { class: "text-gray-500", "Schema issues" }
+                if !issues.is_empty() {
+                    button {
+                        onclick: move |_| schema_issues().clear(),
+                        class: "bg-transparent border-0 text-gray-400 cursor-pointer p-0",
+                        "clear"
+                    }
+                }
+            }
+            if issues.is_empty() {
+                div { class: "text-gray-600 italic", "none" }
+            }
+            for (index, (issue, count)) in issues.iter().enumerate() {
+                div {
+                    key: "{index}",
+                    class: "flex justify-between gap-2",
+                    span {
+                        class: if matches!(issue, SchemaIssue::UnknownFields { .. }) { "truncate" } else { "truncate text-red-400" },
+                        title: "{issue.summary()}",
+                        "{issue.summary()}"
+                    }
+                    span { class: "text-amber-300", "{count}" }
+                }
+            }
+        }
+    }
+}
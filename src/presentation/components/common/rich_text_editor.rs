@@ -0,0 +1,254 @@
+//! Rich text editor for entity descriptions
+//!
+//! Edits the description markup (see `application::dto::rich_text`) in a
+//! textarea with a formatting toolbar, `@`-mention completion for the
+//! world's characters, locations, and narrative events, and a preview tab.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{EntityMention, MentionKind};
+use crate::presentation::components::common::{MentionSuggestions, RichTextView};
+use crate::presentation::services::{
+    use_character_service, use_location_service, use_narrative_event_service,
+};
+
+static NEXT_EDITOR_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Formatting applied by a toolbar button
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Bold,
+    Italic,
+    List,
+}
+
+/// Description editor with formatting toolbar and entity mentions
+#[component]
+pub fn RichTextEditor(
+    value: String,
+    on_change: EventHandler<String>,
+    /// World whose entities can be mentioned
+    world_id: String,
+    #[props(default)]
+    placeholder: String,
+    #[props(default)]
+    disabled: bool,
+    /// Extra mention targets beyond the world's entities (e.g. notes pages)
+    #[props(default)]
+    extra_mentions: Vec<EntityMention>,
+) -> Element {
+    let character_service = use_character_service();
+    let location_service = use_location_service();
+    let narrative_event_service = use_narrative_event_service();
+
+    let element_id = use_hook(|| format!("rich-text-editor-{}", NEXT_EDITOR_ID.fetch_add(1, Ordering::Relaxed)));
+    let mut previewing = use_signal(|| false);
+    let mut candidates: Signal<Vec<EntityMention>> = use_signal(Vec::new);
+
+    // Load mentionable entities on mount
+    let world_id_for_fetch = world_id.clone();
+    use_effect(move || {
+        let world_id = world_id_for_fetch.clone();
+        let characters = character_service.clone();
+        let locations = location_service.clone();
+        let events = narrative_event_service.clone();
+        spawn(async move {
+            let mut loaded = Vec::new();
+            if let Ok(list) = characters.list_characters(&world_id).await {
+                loaded.extend(list.into_iter().map(|c| EntityMention {
+                    kind: MentionKind::Character,
+                    id: c.id,
+                    label: c.name,
+                }));
+            }
+            if let Ok(list) = locations.list_locations(&world_id).await {
+                loaded.extend(list.into_iter().map(|l| EntityMention {
+                    kind: MentionKind::Location,
+                    id: l.id,
+                    label: l.name,
+                }));
+            }
+            if let Ok(list) = events.list_narrative_events(&world_id).await {
+                loaded.extend(list.into_iter().map(|e| EntityMention {
+                    kind: MentionKind::NarrativeEvent,
+                    id: e.id,
+                    label: e.name,
+                }));
+            }
+            candidates.set(loaded);
+        });
+    });
+
+    let apply_format = {
+        let value = value.clone();
+        let element_id = element_id.clone();
+        move |format: Format| {
+            let value = value.clone();
+            let element_id = element_id.clone();
+            spawn(async move {
+                let (start, end) = selection_range(&element_id, &value).await;
+                on_change.call(match format {
+                    Format::Bold => wrap_range(&value, start, end, "**"),
+                    Format::Italic => wrap_range(&value, start, end, "*"),
+                    Format::List => prefix_lines(&value, start, end, "- "),
+                });
+            });
+        }
+    };
+
+    let tab_class = |active: bool| {
+        if active {
+            "px-2 py-1 bg-gray-700 text-white border-none rounded text-xs cursor-pointer"
+        } else {
+            "px-2 py-1 bg-transparent text-gray-400 border-none rounded text-xs cursor-pointer"
+        }
+    };
+    let tool_class = "w-7 h-7 bg-transparent text-gray-300 border border-gray-700 rounded text-xs cursor-pointer hover:bg-gray-700 disabled:opacity-50";
+    let is_previewing = *previewing.read();
+
+    rsx! {
+        div {
+            class: "rich-text-editor relative flex flex-col bg-dark-bg border border-gray-700 rounded",
+
+            // Toolbar
+            div {
+                class: "flex items-center gap-1 p-1 border-b border-gray-700",
+                button {
+                    r#type: "button",
+                    class: "{tool_class}",
+                    title: "Bold",
+                    disabled: disabled || is_previewing,
+                    onclick: {
+                        let apply_format = apply_format.clone();
+                        move |_| apply_format(Format::Bold)
+                    },
+                    b { "B" }
+                }
+                button {
+                    r#type: "button",
+                    class: "{tool_class}",
+                    title: "Italic",
+                    disabled: disabled || is_previewing,
+                    onclick: {
+                        let apply_format = apply_format.clone();
+                        move |_| apply_format(Format::Italic)
+                    },
+                    i { "I" }
+                }
+                button {
+                    r#type: "button",
+                    class: "{tool_class}",
+                    title: "Bulleted list",
+                    disabled: disabled || is_previewing,
+                    onclick: {
+                        let apply_format = apply_format.clone();
+                        move |_| apply_format(Format::List)
+                    },
+                    "•"
+                }
+                span { class: "text-gray-600 text-xs ml-2", "Type @ to mention" }
+                div { class: "flex-1" }
+                button {
+                    r#type: "button",
+                    class: tab_class(!is_previewing),
+                    onclick: move |_| previewing.set(false),
+                    "Write"
+                }
+                button {
+                    r#type: "button",
+                    class: tab_class(is_previewing),
+                    onclick: move |_| previewing.set(true),
+                    "Preview"
+                }
+            }
+
+            if is_previewing {
+                div {
+                    class: "min-h-[80px] p-2 text-white text-sm",
+                    if value.trim().is_empty() {
+                        span { class: "text-gray-500 italic", "Nothing to preview" }
+                    } else {
+                        RichTextView { text: value.clone() }
+                    }
+                }
+            } else {
+                textarea {
+                    id: "{element_id}",
+                    value: "{value}",
+                    placeholder: "{placeholder}",
+                    disabled,
+                    oninput: move |e| on_change.call(e.value()),
+                    class: "w-full min-h-[80px] p-2 bg-transparent border-none text-white resize-y box-border",
+                }
+            }
+
+            // Mention suggestions
+            if !is_previewing {
+                MentionSuggestions {
+                    value: value.clone(),
+                    candidates: candidates.read().iter().chain(extra_mentions.iter()).cloned().collect::<Vec<_>>(),
+                    on_change,
+                }
+            }
+        }
+    }
+}
+
+/// Current selection of the textarea as byte offsets into `value`
+///
+/// Falls back to the end of the text if the selection can't be read.
+async fn selection_range(element_id: &str, value: &str) -> (usize, usize) {
+    let js = format!(
+        "const el = document.getElementById('{}'); return el ? [el.selectionStart, el.selectionEnd] : null;",
+        element_id
+    );
+    match document::eval(&js).join::<Option<(usize, usize)>>().await {
+        Ok(Some((start, end))) => {
+            let start = utf16_to_byte(value, start);
+            let end = utf16_to_byte(value, end);
+            (start.min(end), start.max(end))
+        }
+        _ => (value.len(), value.len()),
+    }
+}
+
+/// Convert a DOM (UTF-16) offset into a byte offset
+fn utf16_to_byte(value: &str, offset: usize) -> usize {
+    let mut units = 0;
+    for (i, c) in value.char_indices() {
+        if units >= offset {
+            return i;
+        }
+        units += c.len_utf16();
+    }
+    value.len()
+}
+
+/// Wrap `value[start..end]` in `marker`
+fn wrap_range(value: &str, start: usize, end: usize, marker: &str) -> String {
+    format!("{}{}{}{}{}", &value[..start], marker, &value[start..end], marker, &value[end..])
+}
+
+/// Prefix every line touched by `value[start..end]` with `prefix`
+fn prefix_lines(value: &str, start: usize, end: usize, prefix: &str) -> String {
+    let line_start = value[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let mut out = value[..line_start].to_string();
+    let touched = &value[line_start..end];
+    let rest_of_line = value[end..].find('\n').map(|i| end + i).unwrap_or(value.len());
+    let block = format!("{}{}", touched, &value[end..rest_of_line]);
+    let prefixed: Vec<String> = block
+        .split('\n')
+        .map(|line| {
+            if line.starts_with(prefix) {
+                line.to_string()
+            } else {
+                format!("{}{}", prefix, line)
+            }
+        })
+        .collect();
+    out.push_str(&prefixed.join("\n"));
+    out.push_str(&value[rest_of_line..]);
+    out
+}
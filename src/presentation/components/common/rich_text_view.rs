@@ -0,0 +1,143 @@
+//! Read-only rendering of rich text descriptions
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{EntityMention, MentionKind, RichBlock, RichSpan, RichText};
+use crate::routes::Route;
+
+/// DM route that shows a mentioned entity
+pub fn mention_route(world_id: &str, mention: &EntityMention) -> Route {
+    let world_id = world_id.to_string();
+    let creator_entity = |subtab: &str| Route::DMCreatorEntityRoute {
+        world_id: world_id.clone(),
+        subtab: subtab.to_string(),
+        entity_id: mention.id.clone(),
+    };
+    match mention.kind {
+        MentionKind::Character => creator_entity("characters"),
+        MentionKind::Location => creator_entity("locations"),
+        MentionKind::Item => creator_entity("items"),
+        MentionKind::NarrativeEvent => Route::DMNarrativeEventRoute {
+            world_id,
+            event_id: mention.id.clone(),
+        },
+        MentionKind::Note => Route::DMNoteRoute {
+            world_id,
+            note_id: mention.id.clone(),
+        },
+    }
+}
+
+/// Render description markup
+///
+/// Mentions become links when `on_mention` is set (DM views); otherwise they
+/// are shown as highlighted labels.
+#[component]
+pub fn RichTextView(
+    text: String,
+    #[props(default)]
+    on_mention: Option<EventHandler<EntityMention>>,
+    #[props(default)]
+    class: String,
+) -> Element {
+    let doc = RichText::parse(&text);
+
+    rsx! {
+        div {
+            class: "rich-text flex flex-col gap-2 {class}",
+            for (i, block) in doc.blocks.into_iter().enumerate() {
+                match block {
+                    RichBlock::Paragraph(spans) => rsx! {
+                        p {
+                            key: "{i}",
+                            class: "m-0",
+                            RichSpans { spans, on_mention }
+                        }
+                    },
+                    RichBlock::BulletList(items) => rsx! {
+                        ul {
+                            key: "{i}",
+                            class: "m-0 pl-5 list-disc",
+                            for (j, item) in items.into_iter().enumerate() {
+                                li { key: "{j}", RichSpans { spans: item, on_mention } }
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn RichSpans(spans: Vec<RichSpan>, on_mention: Option<EventHandler<EntityMention>>) -> Element {
+    rsx! {
+        for (i, span) in spans.into_iter().enumerate() {
+            match span {
+                RichSpan::Text { text, bold, italic } => {
+                    let weight = if bold { "font-semibold" } else { "" };
+                    let style = if italic { "italic" } else { "" };
+                    rsx! { span { key: "{i}", class: "{weight} {style}", "{text}" } }
+                }
+                RichSpan::LineBreak => rsx! { br { key: "{i}" } },
+                RichSpan::Mention(mention) => {
+                    let label = format!("{} {}", mention.kind.icon(), mention.label);
+                    match on_mention {
+                        Some(handler) => rsx! {
+                            button {
+                                key: "{i}",
+                                r#type: "button",
+                                class: "inline p-0 bg-transparent border-none text-blue-400 hover:underline cursor-pointer",
+                                onclick: move |e| {
+                                    e.stop_propagation();
+                                    handler.call(mention.clone());
+                                },
+                                "{label}"
+                            }
+                        },
+                        None => rsx! {
+                            span { key: "{i}", class: "text-blue-300", "{label}" }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mention(kind: MentionKind) -> EntityMention {
+        EntityMention {
+            kind,
+            id: "e-1".to_string(),
+            label: "Mira".to_string(),
+        }
+    }
+
+    #[test]
+    fn mentions_route_to_the_entity_itself() {
+        assert_eq!(
+            mention_route("w-1", &mention(MentionKind::Character)),
+            Route::DMCreatorEntityRoute {
+                world_id: "w-1".to_string(),
+                subtab: "characters".to_string(),
+                entity_id: "e-1".to_string(),
+            }
+        );
+        assert_eq!(
+            mention_route("w-1", &mention(MentionKind::Location)).to_string(),
+            "/worlds/w-1/dm/creator/locations/e-1"
+        );
+        assert_eq!(
+            mention_route("w-1", &mention(MentionKind::NarrativeEvent)).to_string(),
+            "/worlds/w-1/dm/story-arc/events/e-1"
+        );
+        assert_eq!(
+            mention_route("w-1", &mention(MentionKind::Note)).to_string(),
+            "/worlds/w-1/dm/notes/e-1"
+        );
+    }
+}
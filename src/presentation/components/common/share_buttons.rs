@@ -0,0 +1,182 @@
+//! Copy, Share and export buttons - entity text and deep links to the
+//! clipboard, character sheets to print or file
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::{Platform, ShareOutcome};
+use crate::application::services::{deep_link, SheetExport};
+use crate::routes::Route;
+
+/// How long "Copied" stays on the button
+const FEEDBACK_MS: u64 = 2000;
+
+/// Show `message` on the button for a moment
+fn flash(platform: &Platform, mut feedback: Signal<Option<String>>, message: String) {
+    feedback.set(Some(message));
+    let platform = platform.clone();
+    spawn(async move {
+        platform.sleep_ms(FEEDBACK_MS).await;
+        feedback.set(None);
+    });
+}
+
+/// Copy `text`, returning the button's feedback
+fn copy_text(platform: &Platform, text: &str) -> String {
+    match platform.copy_to_clipboard(text) {
+        Ok(()) => "✓ Copied".to_string(),
+        Err(err) => {
+            platform.log_warn(&format!("Copy failed: {}", err));
+            "Copy failed".to_string()
+        }
+    }
+}
+
+/// Share `url` after `text`, returning the button's feedback
+fn share_link(platform: &Platform, title: &str, text: &str, url: &str) -> String {
+    match platform.share_link(title, text, url) {
+        Ok(ShareOutcome::Shared) => "✓ Shared".to_string(),
+        Ok(ShareOutcome::Copied) => "✓ Link copied".to_string(),
+        Err(err) => {
+            platform.log_warn(&format!("Share failed: {}", err));
+            "Share failed".to_string()
+        }
+    }
+}
+
+/// Button that copies `text` to the clipboard
+#[component]
+pub fn CopyButton(
+    text: String,
+    /// Button text, e.g. "Copy summary"; empty for an icon-only button
+    label: String,
+    #[props(default)] class: Option<String>,
+) -> Element {
+    let platform = use_context::<Platform>();
+    let feedback: Signal<Option<String>> = use_signal(|| None);
+    let class = class.unwrap_or_else(|| {
+        "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm".to_string()
+    });
+
+    rsx! {
+        button {
+            r#type: "button",
+            class,
+            title: "Copy to clipboard",
+            aria_label: if label.is_empty() { "Copy to clipboard" } else { "{label}" },
+            onclick: move |e| {
+                e.stop_propagation();
+                let message = copy_text(&platform, &text);
+                flash(&platform, feedback, message);
+            },
+            if let Some(message) = feedback.read().as_ref() { "{message}" } else { "📋 {label}" }
+        }
+    }
+}
+
+/// Button that shares a deep link to `route`
+///
+/// Opens the share sheet where the browser has one; elsewhere the link
+/// (after `text`) is copied to the clipboard.
+#[component]
+pub fn ShareButton(
+    route: Route,
+    /// Share sheet title
+    title: String,
+    /// Text shared along with the link
+    text: String,
+    #[props(default)] class: Option<String>,
+) -> Element {
+    let platform = use_context::<Platform>();
+    let feedback: Signal<Option<String>> = use_signal(|| None);
+    let class = class.unwrap_or_else(|| {
+        "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm".to_string()
+    });
+
+    rsx! {
+        button {
+            r#type: "button",
+            class,
+            title: "Share a link",
+            onclick: move |e| {
+                e.stop_propagation();
+                let url = deep_link(platform.app_origin().as_deref(), &route.to_string());
+                let message = share_link(&platform, &title, &text, &url);
+                flash(&platform, feedback, message);
+            },
+            if let Some(message) = feedback.read().as_ref() { "{message}" } else { "🔗 Share" }
+        }
+    }
+}
+
+/// Print and Save buttons for a character sheet
+///
+/// Print opens the sheet in a print-ready page (where the browser can also
+/// save it as PDF); Save downloads it as an HTML file.
+#[component]
+pub fn SheetExportButtons(sheet: SheetExport, #[props(default)] class: Option<String>) -> Element {
+    let platform = use_context::<Platform>();
+    let feedback: Signal<Option<String>> = use_signal(|| None);
+    let class = class.unwrap_or_else(|| {
+        "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm".to_string()
+    });
+    let save_sheet = sheet.clone();
+    let save_platform = platform.clone();
+
+    rsx! {
+        button {
+            r#type: "button",
+            class: "{class}",
+            title: "Print the sheet or save it as PDF",
+            onclick: move |e| {
+                e.stop_propagation();
+                if let Err(err) = platform.print_document(&sheet.filename(), &sheet.to_html(true)) {
+                    platform.log_warn(&format!("Print failed: {}", err));
+                    flash(&platform, feedback, "Print failed".to_string());
+                }
+            },
+            "🖨 Print"
+        }
+        button {
+            r#type: "button",
+            class: "{class}",
+            title: "Save the sheet as an HTML file",
+            onclick: move |e| {
+                e.stop_propagation();
+                let message = match save_platform.save_file(&save_sheet.filename(), &save_sheet.to_html(false)) {
+                    Ok(_) => "✓ Saved".to_string(),
+                    Err(err) => {
+                        save_platform.log_warn(&format!("Saving sheet failed: {}", err));
+                        "Save failed".to_string()
+                    }
+                };
+                flash(&save_platform, feedback, message);
+            },
+            if let Some(message) = feedback.read().as_ref() { "{message}" } else { "💾 Save" }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::platform::mock::{MockClipboardProvider, MockPlatformBuilder};
+
+    #[test]
+    fn copying_and_sharing_without_a_share_sheet_fill_the_clipboard() {
+        let clipboard = MockClipboardProvider::new();
+        let platform = MockPlatformBuilder::new().with_clipboard(clipboard.clone()).build();
+
+        assert_eq!(copy_text(&platform, "Mira, innkeeper"), "✓ Copied");
+        assert_eq!(
+            share_link(&platform, "Mira", "Meet Mira", "https://play.example/characters/mira"),
+            "✓ Link copied"
+        );
+        assert_eq!(
+            clipboard.copied(),
+            vec![
+                "Mira, innkeeper".to_string(),
+                "Meet Mira\nhttps://play.example/characters/mira".to_string(),
+            ]
+        );
+    }
+}
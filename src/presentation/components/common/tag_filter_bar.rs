@@ -0,0 +1,85 @@
+//! Tag filter bar - toggles the global tag filter
+//!
+//! Rendered by Creator, Story Arc and the Challenge Library so the same
+//! tag filter applies wherever the DM navigates.
+
+use dioxus::prelude::*;
+
+use crate::presentation::state::use_tag_filter_state;
+
+/// Row of toggleable tag chips bound to the global `TagFilterState`
+#[component]
+pub fn TagFilterBar(
+    /// Tags present in the current view
+    available_tags: Vec<String>,
+) -> Element {
+    let tag_filter = use_tag_filter_state();
+    let active_tags = tag_filter.active_tags.read().clone();
+
+    // Show active tags even when no entity in this view carries them,
+    // so the DM can always see (and clear) what is filtering the list.
+    let mut all_tags = active_tags.clone();
+    for tag in available_tags {
+        if !all_tags.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+            all_tags.push(tag);
+        }
+    }
+    all_tags.sort_by_key(|t| t.to_lowercase());
+
+    if all_tags.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "tag-filter-bar flex flex-wrap items-center gap-1",
+
+            span { class: "text-gray-500 text-xs mr-1", "Tags:" }
+
+            for tag in all_tags {
+                {
+                    let is_active = tag_filter.is_active(&tag);
+                    let chip_class = if is_active {
+                        "bg-blue-500 text-white border-blue-500"
+                    } else {
+                        "bg-transparent text-gray-400 border-gray-700"
+                    };
+                    let mut tag_filter = tag_filter.clone();
+                    let tag_for_toggle = tag.clone();
+                    rsx! {
+                        button {
+                            key: "{tag}",
+                            r#type: "button",
+                            class: "px-2 py-0.5 border rounded-full text-xs cursor-pointer {chip_class}",
+                            onclick: move |_| tag_filter.toggle(&tag_for_toggle),
+                            "{tag}"
+                        }
+                    }
+                }
+            }
+
+            if !active_tags.is_empty() {
+                button {
+                    r#type: "button",
+                    class: "px-2 py-0.5 bg-transparent border-none text-gray-500 text-xs cursor-pointer underline",
+                    onclick: {
+                        let mut tag_filter = tag_filter.clone();
+                        move |_| tag_filter.clear()
+                    },
+                    "Clear"
+                }
+            }
+        }
+    }
+}
+
+/// Collect the distinct tags (case-insensitive) from a set of entities
+pub fn collect_tags<'a>(tag_lists: impl IntoIterator<Item = &'a Vec<String>>) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in tag_lists.into_iter().flatten() {
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            tags.push(tag.clone());
+        }
+    }
+    tags
+}
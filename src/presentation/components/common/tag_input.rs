@@ -0,0 +1,174 @@
+//! Tag input and tag chip components
+//!
+//! Shared by every form that edits entity tags, and by list views that
+//! display them.
+
+use dioxus::prelude::*;
+
+use crate::domain::value_objects::tags::{contains_tag, normalize_tag};
+
+/// Editable list of tags
+///
+/// Press Enter or comma to add the typed tag; click × on a chip to remove it.
+#[component]
+pub fn TagInput(
+    tags: Vec<String>,
+    on_change: EventHandler<Vec<String>>,
+    /// Existing tags to offer as completions (e.g. all tags in the world)
+    #[props(default)]
+    suggestions: Vec<String>,
+    #[props(default = "Add tag...")]
+    placeholder: &'static str,
+) -> Element {
+    let mut draft = use_signal(String::new);
+
+    let add_tag = {
+        let tags = tags.clone();
+        move |raw: String| {
+            if let Some(tag) = normalize_tag(&raw) {
+                if !contains_tag(&tags, &tag) {
+                    let mut next = tags.clone();
+                    next.push(tag);
+                    on_change.call(next);
+                }
+            }
+            draft.set(String::new());
+        }
+    };
+
+    let draft_lower = draft.read().trim().to_lowercase();
+    let matching_suggestions: Vec<String> = if draft_lower.is_empty() {
+        Vec::new()
+    } else {
+        suggestions
+            .iter()
+            .filter(|s| s.to_lowercase().starts_with(&draft_lower) && !contains_tag(&tags, s))
+            .take(5)
+            .cloned()
+            .collect()
+    };
+
+    rsx! {
+        div {
+            class: "tag-input flex flex-col gap-1",
+
+            div {
+                class: "flex flex-wrap items-center gap-1 p-1.5 bg-dark-bg border border-gray-700 rounded",
+
+                for tag in tags.iter() {
+                    span {
+                        key: "{tag}",
+                        class: "inline-flex items-center gap-1 px-2 py-0.5 bg-gray-700 text-gray-300 text-xs rounded",
+                        "{tag}"
+                        button {
+                            aria_label: "Remove {tag}",
+                            r#type: "button",
+                            class: "bg-transparent border-none text-gray-400 cursor-pointer p-0 leading-none",
+                            onclick: {
+                                let tags = tags.clone();
+                                let tag = tag.clone();
+                                move |_| {
+                                    let next: Vec<String> = tags.iter().filter(|t| **t != tag).cloned().collect();
+                                    on_change.call(next);
+                                }
+                            },
+                            "×"
+                        }
+                    }
+                }
+
+                input {
+                    r#type: "text",
+                    value: "{draft}",
+                    placeholder: placeholder,
+                    class: "flex-1 min-w-[100px] p-1 bg-transparent border-none text-white text-sm outline-none",
+                    oninput: {
+                        let mut add_tag = add_tag.clone();
+                        move |e: Event<FormData>| {
+                            let value = e.value();
+                            if value.ends_with(',') {
+                                add_tag(value.trim_end_matches(',').to_string());
+                            } else {
+                                draft.set(value);
+                            }
+                        }
+                    },
+                    onkeydown: {
+                        let mut add_tag = add_tag.clone();
+                        move |e: KeyboardEvent| {
+                            if e.key() == Key::Enter {
+                                e.prevent_default();
+                                add_tag(draft.read().clone());
+                            }
+                        }
+                    },
+                }
+            }
+
+            if !matching_suggestions.is_empty() {
+                div {
+                    class: "flex flex-wrap gap-1",
+                    for suggestion in matching_suggestions {
+                        button {
+                            key: "{suggestion}",
+                            r#type: "button",
+                            class: "px-2 py-0.5 bg-transparent border border-dashed border-gray-600 text-gray-400 text-xs rounded cursor-pointer",
+                            onclick: {
+                                let mut add_tag = add_tag.clone();
+                                let suggestion = suggestion.clone();
+                                move |_| add_tag(suggestion.clone())
+                            },
+                            "+ {suggestion}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Read-only row of tag chips
+///
+/// When `on_tag_click` is set, chips are clickable (used to toggle tag filters).
+#[component]
+pub fn TagChips(
+    tags: Vec<String>,
+    /// Maximum number of chips to show before collapsing into "+N"
+    #[props(default)]
+    max_visible: Option<usize>,
+    #[props(default)]
+    on_tag_click: Option<EventHandler<String>>,
+) -> Element {
+    if tags.is_empty() {
+        return rsx! {};
+    }
+
+    let visible = max_visible.unwrap_or(tags.len()).min(tags.len());
+    let hidden = tags.len() - visible;
+    let cursor = if on_tag_click.is_some() { "cursor-pointer" } else { "" };
+
+    rsx! {
+        div {
+            class: "flex gap-1 flex-wrap",
+            for tag in tags.iter().take(visible) {
+                span {
+                    key: "{tag}",
+                    class: "px-1.5 py-0.5 bg-gray-700 text-gray-400 text-[0.625rem] rounded {cursor}",
+                    onclick: {
+                        let tag = tag.clone();
+                        move |e: MouseEvent| {
+                            if let Some(handler) = on_tag_click {
+                                e.stop_propagation();
+                                handler.call(tag.clone());
+                            }
+                        }
+                    },
+                    "{tag}"
+                }
+            }
+            if hidden > 0 {
+                span { class: "text-gray-500 text-[0.625rem]", "+{hidden}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Asset Drop Zone - Drag an image file onto an entity to upload it
+//!
+//! Wraps a form or gallery; a dropped image is checked against the asset
+//! slot, scaled down to the slot's size and uploaded as a manual asset,
+//! skipping generation. Zones can nest: the innermost one takes the drop.
+
+use base64::Engine as _;
+use dioxus::html::HasFileData;
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{upload_mime_type, validate_upload, Asset, UploadAssetRequest};
+use crate::presentation::services::use_asset_service;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct AssetDropZoneProps {
+    pub world_id: String,
+    pub entity_type: String,
+    /// Empty while the entity is unsaved; drops are refused until it exists
+    pub entity_id: String,
+    /// Slot the upload goes into, e.g. "portrait"
+    pub asset_type: String,
+    /// Make the upload the active asset of its slot
+    pub activate: bool,
+    pub on_uploaded: EventHandler<Asset>,
+    /// Extra classes for the wrapper, e.g. to let it fill a flex layout
+    #[props(default)]
+    pub class: String,
+    pub children: Element,
+}
+
+#[component]
+pub fn AssetDropZone(props: AssetDropZoneProps) -> Element {
+    let platform = use_context::<Platform>();
+    let asset_service = use_asset_service();
+    let mut dragging = use_signal(|| false);
+    // Ok is progress, Err is why the drop failed
+    let mut status: Signal<Option<Result<String, String>>> = use_signal(|| None);
+
+    let slot_label = props.asset_type.replace('_', " ");
+
+    let handle_drop = {
+        let props = props.clone();
+        move |e: DragEvent| {
+            dragging.set(false);
+            // Dropped text goes through to the input under the pointer
+            let Some(file) = e.files().into_iter().next() else {
+                return;
+            };
+            e.prevent_default();
+            e.stop_propagation();
+
+            if props.entity_id.is_empty() {
+                status.set(Some(Err(format!("Save the {} first to upload images", props.entity_type))));
+                return;
+            }
+            let filename = file.name();
+            let mime_type = upload_mime_type(&filename, file.content_type().as_deref());
+            let max_dimension = match validate_upload(&props.asset_type, &mime_type, file.size()) {
+                Ok(max_dimension) => max_dimension,
+                Err(rejection) => {
+                    status.set(Some(Err(rejection.to_string())));
+                    return;
+                }
+            };
+
+            let platform = platform.clone();
+            let svc = asset_service.clone();
+            let props = props.clone();
+            spawn(async move {
+                status.set(Some(Ok(format!("Uploading {}…", filename))));
+                let bytes = match file.read_bytes().await {
+                    Ok(bytes) => bytes,
+                    Err(e) => {
+                        status.set(Some(Err(format!("Failed to read {}: {}", filename, e))));
+                        return;
+                    }
+                };
+                let encoded = base64::engine::general_purpose::STANDARD.encode(&bytes);
+                let image = match platform.resize_image(encoded, &mime_type, max_dimension).await {
+                    Ok(image) => image,
+                    Err(e) => {
+                        status.set(Some(Err(format!("{} isn't a usable image: {}", filename, e))));
+                        return;
+                    }
+                };
+                let label = filename.rsplit_once('.').map_or(filename.as_str(), |(stem, _)| stem).to_string();
+                let request = UploadAssetRequest {
+                    world_id: props.world_id.clone(),
+                    asset_type: props.asset_type.clone(),
+                    label: (!label.is_empty()).then_some(label),
+                    filename: filename.clone(),
+                    mime_type,
+                    data_base64: image.data_base64,
+                    activate: props.activate,
+                };
+                match svc.upload_asset(&props.entity_type, &props.entity_id, &request).await {
+                    Ok(asset) => {
+                        status.set(None);
+                        props.on_uploaded.call(asset);
+                    }
+                    Err(e) => status.set(Some(Err(format!("Failed to upload {}: {}", filename, e)))),
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "asset-drop-zone relative {props.class}",
+            ondragover: move |e: DragEvent| {
+                // Without this the browser opens the dropped file instead.
+                // Files can't be told from text until the drop, but inputs
+                // accept text drops either way, and only file drops are
+                // taken over
+                e.prevent_default();
+                e.stop_propagation();
+                dragging.set(true);
+            },
+            ondragleave: move |e: DragEvent| {
+                e.stop_propagation();
+                dragging.set(false);
+            },
+            ondrop: handle_drop,
+
+            {props.children}
+
+            if *dragging.read() {
+                div {
+                    class: "absolute inset-0 z-10 flex items-center justify-center bg-purple-500/20 border-2 border-dashed border-purple-500 rounded-lg pointer-events-none",
+                    span {
+                        class: "px-3 py-1 bg-dark-bg text-purple-300 rounded text-sm",
+                        "Drop to upload as {slot_label}"
+                    }
+                }
+            }
+
+            match status.read().as_ref() {
+                Some(Ok(progress)) => rsx! {
+                    p { class: "text-gray-400 text-xs mt-2 mb-0", role: "status", "{progress}" }
+                },
+                Some(Err(message)) => rsx! {
+                    p { class: "text-red-500 text-xs mt-2 mb-0", role: "alert", "{message}" }
+                },
+                None => rsx! {},
+            }
+        }
+    }
+}
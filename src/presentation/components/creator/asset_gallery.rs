@@ -2,34 +2,58 @@
 
 use dioxus::prelude::*;
 
-use crate::application::services::{Asset, GenerateRequest};
+use super::asset_drop_zone::AssetDropZone;
+use super::generation_params_input::GenerationParamsInput;
+use crate::application::dto::GenerationParams;
+use crate::application::services::{Asset, GenerateRequest, DEFAULT_DENOISE};
 use crate::presentation::services::use_asset_service;
+use crate::presentation::state::{use_session_state, use_modal_focus};
+use crate::presentation::components::common::ModalFocus;
 
 /// Asset types that can be generated
 const ASSET_TYPES: &[(&str, &str)] = &[
     ("portrait", "Portrait"),
     ("sprite", "Sprite"),
     ("backdrop", "Backdrop"),
+    ("animated_backdrop", "Animated Backdrop"),
     ("emotion_sheet", "Emotions"),
 ];
 
 /// Asset gallery for an entity
+///
+/// Image files dropped on the gallery are uploaded into the selected tab's
+/// slot. `uploads` is bumped by an owning form when it uploads an asset
+/// itself, so the gallery reloads.
 #[component]
-pub fn AssetGallery(world_id: String, entity_type: String, entity_id: String) -> Element {
+pub fn AssetGallery(
+    world_id: String,
+    entity_type: String,
+    entity_id: String,
+    #[props(default)] uploads: Option<Signal<u32>>,
+) -> Element {
     let asset_service = use_asset_service();
+    let generation_available = use_session_state().engine_health().read().modes().generation_available;
     let mut selected_asset_type = use_signal(|| "portrait".to_string());
     let mut show_generate_modal = use_signal(|| false);
+    // Asset picked with "Regenerate from this", used as the img2img base
+    let mut regenerate_source: Signal<Option<Asset>> = use_signal(|| None);
     let mut assets: Signal<Vec<Asset>> = use_signal(Vec::new);
     let mut is_loading = use_signal(|| true);
     let mut error: Signal<Option<String>> = use_signal(|| None);
+    // Bumped after an image is dropped on the gallery
+    let mut reload = use_signal(|| 0u32);
 
-    // Fetch assets on mount (only if entity_id is not empty)
+    // Fetch assets on mount and after uploads (only if entity_id is not empty)
     {
         let entity_type_clone = entity_type.clone();
         let entity_id_clone = entity_id.clone();
         let asset_svc = asset_service.clone();
 
         use_effect(move || {
+            reload.read();
+            if let Some(uploads) = uploads {
+                uploads.read();
+            }
             let et = entity_type_clone.clone();
             let ei = entity_id_clone.clone();
             let svc = asset_svc.clone();
@@ -63,137 +87,166 @@ pub fn AssetGallery(world_id: String, entity_type: String, entity_id: String) ->
         .filter(|a| a.asset_type == selected_type)
         .cloned()
         .collect();
+    // Drops fill an empty slot, otherwise they join it without replacing the active asset
+    let slot_has_active = filtered_assets.iter().any(|a| a.is_active);
 
     rsx! {
-        div {
-            class: "asset-gallery bg-dark-bg rounded-lg p-3",
-
-            // Error display
-            if let Some(err) = error.read().as_ref() {
-                div {
-                    class: "p-3 bg-red-500 bg-opacity-10 rounded text-red-500 text-sm mb-3",
-                    "Error: {err}"
-                }
-            }
-
-            // Asset type tabs
+        AssetDropZone {
+            world_id: world_id.clone(),
+            entity_type: entity_type.clone(),
+            entity_id: entity_id.clone(),
+            asset_type: selected_type.clone(),
+            activate: !slot_has_active,
+            on_uploaded: move |_| *reload.write() += 1,
             div {
-                class: "asset-tabs flex gap-1 mb-3",
-
-                for (type_id, type_label) in ASSET_TYPES {
-                    {
-                        let btn_class = if *selected_asset_type.read() == *type_id {
-                            "p-1 px-2 text-xs rounded cursor-pointer border-0 bg-blue-500 text-white"
-                        } else {
-                            "p-1 px-2 text-xs rounded cursor-pointer border-0 bg-transparent text-gray-400"
-                        };
-                        rsx! {
-                            button {
-                                onclick: {
-                                    let type_id = type_id.to_string();
-                                    move |_| selected_asset_type.set(type_id.clone())
-                                },
-                                class: "{btn_class}",
-                                "{type_label}"
-                            }
-                        }
+                class: "asset-gallery bg-dark-bg rounded-lg p-3",
+
+                // Error display
+                if let Some(err) = error.read().as_ref() {
+                    div {
+                        class: "p-3 bg-red-500 bg-opacity-10 rounded text-red-500 text-sm mb-3",
+                        "Error: {err}"
                     }
                 }
-            }
 
-            // Asset grid
-            div {
-                class: "asset-grid flex flex-wrap gap-2 min-h-20",
+                // Asset type tabs
+                div {
+                    class: "asset-tabs flex gap-1 mb-3",
 
-                if entity_id.is_empty() {
-                    // New entity - show message about generating assets after creation
-                    div {
-                        class: "w-full text-center text-gray-500 text-sm p-4 bg-purple-500 bg-opacity-10 rounded border border-dashed border-purple-500",
-                        "Save the {entity_type} first to generate assets"
-                    }
-                } else if *is_loading.read() {
-                    div {
-                        class: "w-full text-center text-gray-500 text-sm p-4",
-                        "Loading assets..."
-                    }
-                } else if filtered_assets.is_empty() {
-                    div {
-                        class: "w-full text-center text-gray-500 text-sm p-4",
-                        "No {selected_asset_type} assets yet"
-                    }
-                } else {
-                    for asset in filtered_assets {
+                    for (type_id, type_label) in ASSET_TYPES {
                         {
-                            let entity_type_activate = entity_type.clone();
-                            let entity_id_activate = entity_id.clone();
-                            let entity_type_delete = entity_type.clone();
-                            let entity_id_delete = entity_id.clone();
-                            let asset_svc_activate = asset_service.clone();
-                            let asset_svc_delete = asset_service.clone();
+                            let btn_class = if *selected_asset_type.read() == *type_id {
+                                "p-1 px-2 text-xs rounded cursor-pointer border-0 bg-blue-500 text-white"
+                            } else {
+                                "p-1 px-2 text-xs rounded cursor-pointer border-0 bg-transparent text-gray-400"
+                            };
                             rsx! {
-                                AssetThumbnail {
-                                    id: asset.id.clone(),
-                                    label: asset.label.clone(),
-                                    is_active: asset.is_active,
-                                    style_reference_id: asset.style_reference_id.clone(),
-                                    on_activate: move |id: String| {
-                                        let entity_type = entity_type_activate.clone();
-                                        let entity_id = entity_id_activate.clone();
-                                        let svc = asset_svc_activate.clone();
-                                        spawn(async move {
-                                            if let Err(e) = svc.activate_asset(&entity_type, &entity_id, &id).await {
-                                                tracing::error!("Failed to activate asset: {}", e);
-                                            }
-                                        });
+                                button {
+                                    onclick: {
+                                        let type_id = type_id.to_string();
+                                        move |_| selected_asset_type.set(type_id.clone())
                                     },
-                                    on_delete: move |id: String| {
-                                        let entity_type = entity_type_delete.clone();
-                                        let entity_id = entity_id_delete.clone();
-                                        let svc = asset_svc_delete.clone();
-                                        spawn(async move {
-                                            if let Err(e) = svc.delete_asset(&entity_type, &entity_id, &id).await {
-                                                tracing::error!("Failed to delete asset: {}", e);
-                                            }
-                                        });
-                                    },
-                                    on_use_as_reference: None, // TODO (Phase 18C.3): Implement "Use as Reference" for style transfer
+                                    class: "{btn_class}",
+                                    "{type_label}"
                                 }
                             }
                         }
                     }
                 }
 
-                // Generate button (only show if entity_id exists)
-                if !entity_id.is_empty() {
-                button {
-                    onclick: move |_| show_generate_modal.set(true),
-                    class: "w-16 h-16 flex flex-col items-center justify-center bg-purple-500 bg-opacity-20 border-2 border-dashed border-purple-500 rounded-lg cursor-pointer text-purple-500 text-xs",
-                    span { class: "text-2xl", "+" }
-                    span { "Generate" }
-                    }
-                }
-            }
+                // Asset grid
+                div {
+                    class: "asset-grid flex flex-wrap gap-2 min-h-20",
 
-            // Generation modal
-            if *show_generate_modal.read() {
-                GenerateAssetModal {
-                    world_id: world_id.clone(),
-                    entity_type: entity_type.clone(),
-                    entity_id: entity_id.clone(),
-                    asset_type: selected_asset_type.read().clone(),
-                    on_close: move |_| show_generate_modal.set(false),
-                    on_generate: {
-                        let asset_svc_gen = asset_service.clone();
-                        move |req| {
-                            let svc = asset_svc_gen.clone();
-                            spawn(async move {
-                                if let Err(e) = svc.generate_assets(&req).await {
-                                    tracing::error!("Failed to queue generation: {}", e);
+                    if entity_id.is_empty() {
+                        // New entity - show message about generating assets after creation
+                        div {
+                            class: "w-full text-center text-gray-500 text-sm p-4 bg-purple-500 bg-opacity-10 rounded border border-dashed border-purple-500",
+                            "Save the {entity_type} first to generate assets"
+                        }
+                    } else if *is_loading.read() {
+                        div {
+                            class: "w-full text-center text-gray-500 text-sm p-4",
+                            "Loading assets..."
+                        }
+                    } else if filtered_assets.is_empty() {
+                        div {
+                            class: "w-full text-center text-gray-500 text-sm p-4",
+                            "No {selected_asset_type} assets yet"
+                        }
+                    } else {
+                        for asset in filtered_assets {
+                            {
+                                let entity_type_activate = entity_type.clone();
+                                let entity_id_activate = entity_id.clone();
+                                let entity_type_delete = entity_type.clone();
+                                let entity_id_delete = entity_id.clone();
+                                let asset_svc_activate = asset_service.clone();
+                                let asset_svc_delete = asset_service.clone();
+                                let source = asset.clone();
+                                rsx! {
+                                    AssetThumbnail {
+                                        id: asset.id.clone(),
+                                        label: asset.label.clone(),
+                                        is_active: asset.is_active,
+                                        style_reference_id: asset.style_reference_id.clone(),
+                                        on_activate: move |id: String| {
+                                            let entity_type = entity_type_activate.clone();
+                                            let entity_id = entity_id_activate.clone();
+                                            let svc = asset_svc_activate.clone();
+                                            spawn(async move {
+                                                if let Err(e) = svc.activate_asset(&entity_type, &entity_id, &id).await {
+                                                    tracing::error!("Failed to activate asset: {}", e);
+                                                }
+                                            });
+                                        },
+                                        on_delete: move |id: String| {
+                                            let entity_type = entity_type_delete.clone();
+                                            let entity_id = entity_id_delete.clone();
+                                            let svc = asset_svc_delete.clone();
+                                            spawn(async move {
+                                                if let Err(e) = svc.delete_asset(&entity_type, &entity_id, &id).await {
+                                                    tracing::error!("Failed to delete asset: {}", e);
+                                                }
+                                            });
+                                        },
+                                        on_use_as_reference: None, // TODO (Phase 18C.3): Implement "Use as Reference" for style transfer
+                                        on_regenerate: if generation_available {
+                                            Some(EventHandler::new(move |_id: String| {
+                                                regenerate_source.set(Some(source.clone()));
+                                                show_generate_modal.set(true);
+                                            }))
+                                        } else {
+                                            None
+                                        },
+                                    }
                                 }
-                            });
-                            show_generate_modal.set(false);
+                            }
+                        }
+                    }
+
+                    // Generate button (only show if entity_id exists and ComfyUI is up)
+                    if !entity_id.is_empty() && !generation_available {
+                        div {
+                            class: "w-16 h-16 flex items-center justify-center bg-white/5 border-2 border-dashed border-gray-600 rounded-lg text-gray-500 text-[10px] text-center p-1",
+                            title: "ComfyUI is unavailable",
+                            "Generation offline"
                         }
-                    },
+                    } else if !entity_id.is_empty() {
+                    button {
+                        onclick: move |_| {
+                            regenerate_source.set(None);
+                            show_generate_modal.set(true);
+                        },
+                        class: "w-16 h-16 flex flex-col items-center justify-center bg-purple-500 bg-opacity-20 border-2 border-dashed border-purple-500 rounded-lg cursor-pointer text-purple-500 text-xs",
+                        span { class: "text-2xl", "+" }
+                        span { "Generate" }
+                        }
+                    }
+                }
+
+                // Generation modal
+                if *show_generate_modal.read() && generation_available {
+                    GenerateAssetModal {
+                        world_id: world_id.clone(),
+                        entity_type: entity_type.clone(),
+                        entity_id: entity_id.clone(),
+                        asset_type: selected_asset_type.read().clone(),
+                        source_asset: regenerate_source.read().clone(),
+                        on_close: move |_| show_generate_modal.set(false),
+                        on_generate: {
+                            let asset_svc_gen = asset_service.clone();
+                            move |req| {
+                                let svc = asset_svc_gen.clone();
+                                spawn(async move {
+                                    if let Err(e) = svc.generate_assets(&req).await {
+                                        tracing::error!("Failed to queue generation: {}", e);
+                                    }
+                                });
+                                show_generate_modal.set(false);
+                            }
+                        },
+                    }
                 }
             }
         }
@@ -210,6 +263,8 @@ struct AssetThumbnailProps {
     on_activate: EventHandler<String>,
     on_delete: EventHandler<String>,
     on_use_as_reference: Option<EventHandler<String>>,
+    /// Start an img2img generation based on this asset
+    on_regenerate: Option<EventHandler<String>>,
 }
 
 /// Individual asset thumbnail
@@ -298,6 +353,21 @@ fn AssetThumbnail(props: AssetThumbnailProps) -> Element {
                         }
                     }
 
+                    if let Some(on_regenerate) = props.on_regenerate.as_ref() {
+                        button {
+                            onclick: {
+                                let id = props.id.clone();
+                                let handler = *on_regenerate;
+                                move |_| {
+                                    handler.call(id.clone());
+                                    show_menu.set(false);
+                                }
+                            },
+                            class: "block w-full p-2 text-left bg-transparent text-blue-400 border-0 cursor-pointer text-xs border-b border-gray-700",
+                            "Regenerate from this"
+                        }
+                    }
+
                     button {
                         onclick: {
                             let id = id_for_delete.clone();
@@ -323,9 +393,12 @@ fn GenerateAssetModal(
     entity_type: String,
     entity_id: String,
     asset_type: String,
+    /// Existing asset to regenerate from (img2img)
+    source_asset: Option<Asset>,
     on_close: EventHandler<()>,
     on_generate: EventHandler<GenerateRequest>,
 ) -> Element {
+    use_modal_focus("generate-asset-modal");
     let asset_service = use_asset_service();
     let mut prompt = use_signal(|| String::new());
     let mut negative_prompt = use_signal(|| String::new());
@@ -336,6 +409,10 @@ fn GenerateAssetModal(
     let mut style_reference_label: Signal<Option<String>> = use_signal(|| None);
     let mut show_style_selector = use_signal(|| false);
     let mut available_assets: Signal<Vec<Asset>> = use_signal(Vec::new);
+    let params: Signal<GenerationParams> = use_signal(GenerationParams::default);
+    let mut source: Signal<Option<Asset>> = use_signal(|| source_asset.clone());
+    let mut denoise = use_signal(|| DEFAULT_DENOISE);
+    let denoise_label = format!("{:.2}", *denoise.read());
 
     // Load available assets for style reference selection
     let entity_type_for_assets = entity_type.clone();
@@ -357,10 +434,51 @@ fn GenerateAssetModal(
             onclick: move |_| on_close.call(()),
 
             div {
+                id: "generate-asset-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "modal-content bg-dark-surface rounded-xl p-6 w-11/12 max-w-lg",
                 onclick: move |e| e.stop_propagation(),
 
-                h3 { class: "text-white m-0 mb-4", "Generate {asset_type}" }
+                h3 { class: "text-white m-0 mb-4",
+                    if source.read().is_some() { "Regenerate {asset_type}" } else { "Generate {asset_type}" }
+                }
+
+                // Img2img source and denoise strength
+                if let Some(base) = source.read().as_ref() {
+                    div { class: "mb-4 p-3 bg-blue-500/10 border border-blue-500/40 rounded",
+                        div { class: "flex items-center gap-2 mb-2",
+                            span { class: "text-gray-400 text-xs", "Based on" }
+                            span {
+                                class: "flex-1 text-white text-sm overflow-hidden text-ellipsis whitespace-nowrap",
+                                "{base.label.as_ref().unwrap_or(&base.id)}"
+                            }
+                            button {
+                                onclick: move |_| source.set(None),
+                                class: "py-1 px-2 bg-gray-700 text-white border-0 rounded cursor-pointer text-xs",
+                                "Start from scratch"
+                            }
+                        }
+                        label { class: "block text-gray-400 text-sm mb-1", "Denoise strength: {denoise_label}" }
+                        input {
+                            r#type: "range",
+                            min: "0.05",
+                            max: "1",
+                            step: "0.05",
+                            value: "{denoise}",
+                            oninput: move |e| {
+                                if let Ok(v) = e.value().parse::<f32>() {
+                                    denoise.set(v.clamp(0.05, 1.0));
+                                }
+                            },
+                            class: "w-full",
+                        }
+                        div { class: "flex justify-between text-gray-500 text-xs",
+                            span { "Keep the original" }
+                            span { "Reimagine" }
+                        }
+                    }
+                }
 
                 // Workflow slot field (optional hint text)
                 div { class: "mb-4",
@@ -415,8 +533,13 @@ fn GenerateAssetModal(
                         class: "modal-overlay fixed inset-0 bg-black bg-opacity-90 flex items-center justify-center z-1001",
                         onclick: move |_| show_style_selector.set(false),
                         div {
+                            id: "asset-style-selector",
+                            role: "dialog",
+                            aria_modal: "true",
                             class: "bg-dark-surface rounded-xl p-6 w-11/12 max-w-2xl max-h-screen-80 overflow-y-auto",
                             onclick: move |e| e.stop_propagation(),
+
+                            ModalFocus { dialog_id: "asset-style-selector" }
                             h3 { class: "text-white m-0 mb-4", "Select Style Reference" }
                             div {
                                 class: "grid gap-3 grid-cols-[repeat(auto-fill,minmax(120px,1fr))]",
@@ -496,6 +619,9 @@ fn GenerateAssetModal(
                     }
                 }
 
+                // Seed / steps / CFG / resolution overrides
+                GenerationParamsInput { params: params }
+
                 // Action buttons
                 div { class: "flex justify-end gap-2",
                     button {
@@ -525,6 +651,12 @@ fn GenerateAssetModal(
                                     },
                                     count: *count.read(),
                                     style_reference_id: style_reference_id.read().clone(),
+                                    source_asset_id: source.read().as_ref().map(|a| a.id.clone()),
+                                    denoise: source.read().as_ref().map(|_| *denoise.read()),
+                                    params: {
+                                        let p = params.read().clone();
+                                        if p.is_empty() { None } else { Some(p) }
+                                    },
                                 });
                                 is_generating.set(false);
                             }
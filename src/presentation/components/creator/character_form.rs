@@ -1,16 +1,27 @@
 //! Character Form - Create and edit characters
 
 use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::asset_gallery::AssetGallery;
+use super::relationship_editor::RelationshipEditor;
+use super::routine_editor::RoutineEditor;
 use super::sheet_field_input::CharacterSheetForm;
 use super::suggestion_button::{SuggestionButton, SuggestionContext, SuggestionType};
-use crate::application::dto::{FieldValue, SheetTemplate};
+use crate::application::dto::{EntityStyle, FieldValue, InventoryItemData, RoutineEntry, SheetTemplate};
 use crate::application::ports::outbound::Platform;
-use crate::application::services::{CharacterFormData, CharacterSheetDataApi};
-use crate::presentation::components::common::FormField;
-use crate::presentation::services::{use_character_service, use_world_service};
+use crate::application::services::{
+    archived_stamp, entity_summary, CharacterFormData, CharacterSheetDataApi, FieldRule, FormRules, FormValues, HistoryEntityKind,
+    LocationSummary, SheetExport,
+};
+use crate::presentation::components::common::{
+    CopyButton, DraftRestoreBanner, EntityHistoryDrawer, EntityStylePicker, FormField, RichTextEditor, ShareButton,
+    SheetExportButtons, TagInput, ValidationSummary,
+};
+use crate::presentation::services::{use_character_service, use_location_service, use_world_service};
+use crate::presentation::state::{draft_key, use_draft_persistence, use_form_validation};
+use crate::routes::Route;
 
 /// Character archetypes
 const ARCHETYPES: &[&str] = &[
@@ -24,18 +35,48 @@ const ARCHETYPES: &[&str] = &[
     "Trickster",
 ];
 
+/// Field rules for the character form, including the sheet template's
+fn character_rules(template: Option<&SheetTemplate>) -> FormRules {
+    let rules = FormRules::new()
+        .field("name", "Character name", vec![FieldRule::Required, FieldRule::Length { min: None, max: Some(100) }]);
+    match template {
+        Some(template) => rules.sheet_fields(template),
+        None => rules,
+    }
+}
+
+/// Autosaved contents of the character form
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct CharacterDraft {
+    name: String,
+    description: String,
+    archetype: String,
+    wants: String,
+    fears: String,
+    backstory: String,
+    tags: Vec<String>,
+    #[serde(default)]
+    routine: Vec<RoutineEntry>,
+    #[serde(default)]
+    style: EntityStyle,
+}
+
 /// Character form for creating/editing characters
 #[component]
 pub fn CharacterForm(
     character_id: String,
     world_id: String,
     characters_signal: Signal<Vec<crate::application::services::character_service::CharacterSummary>>,
+    // Bumped by the surrounding drop zone when an image is uploaded
+    uploads: Signal<u32>,
     on_close: EventHandler<()>,
 ) -> Element {
     let is_new = character_id.is_empty();
+    let suggestion_entity_id = (!is_new).then(|| character_id.clone());
     let platform = use_context::<Platform>();
     let char_service = use_character_service();
     let world_service = use_world_service();
+    let location_service = use_location_service();
 
     // Form state
     let mut name = use_signal(|| String::new());
@@ -44,15 +85,48 @@ pub fn CharacterForm(
     let mut wants = use_signal(|| String::new());
     let mut fears = use_signal(|| String::new());
     let mut backstory = use_signal(|| String::new());
+    let mut tags: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut world_tags: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut routine: Signal<Vec<RoutineEntry>> = use_signal(Vec::new);
+    let mut style: Signal<EntityStyle> = use_signal(EntityStyle::default);
+    let mut world_locations: Signal<Vec<LocationSummary>> = use_signal(Vec::new);
     let mut is_loading = use_signal(|| !is_new);
     let mut is_saving = use_signal(|| false);
     let mut success_message: Signal<Option<String>> = use_signal(|| None);
     let mut error_message: Signal<Option<String>> = use_signal(|| None);
+    let mut show_history = use_signal(|| false);
+    let mut validation = use_form_validation();
+    // Bumped after a rollback to reload the character
+    let mut reload = use_signal(|| 0u32);
 
     // Sheet template state
     let mut sheet_template: Signal<Option<SheetTemplate>> = use_signal(|| None);
     let mut sheet_values: Signal<HashMap<String, FieldValue>> = use_signal(HashMap::new);
     let mut show_sheet_section = use_signal(|| true);
+    // Only used for the sheet export; the form doesn't edit either
+    let mut portrait_asset: Signal<Option<String>> = use_signal(|| None);
+    let mut inventory: Signal<Vec<InventoryItemData>> = use_signal(Vec::new);
+
+    // Autosave drafts; the snapshot waits until an existing character has loaded
+    let mut drafts = use_draft_persistence(
+        draft_key("character", &world_id, Some(&character_id)),
+        move || {
+            if *is_loading.peek() {
+                return None;
+            }
+            Some(CharacterDraft {
+                name: name.peek().clone(),
+                description: description.peek().clone(),
+                archetype: archetype.peek().clone(),
+                wants: wants.peek().clone(),
+                fears: fears.peek().clone(),
+                backstory: backstory.peek().clone(),
+                tags: tags.peek().clone(),
+                routine: routine.peek().clone(),
+                style: style.peek().clone(),
+            })
+        },
+    );
 
     // Load sheet template on mount
     {
@@ -85,11 +159,48 @@ pub fn CharacterForm(
         });
     }
 
+    // Load existing world tags for tag suggestions
+    {
+        let world_svc = world_service.clone();
+        let plat = platform.clone();
+        let world_id_for_tags = world_id.clone();
+        use_effect(move || {
+            let svc = world_svc.clone();
+            let platform = plat.clone();
+            let world_id_clone = world_id_for_tags.clone();
+            spawn(async move {
+                match svc.list_tags(&world_id_clone).await {
+                    Ok(loaded) => world_tags.set(loaded),
+                    Err(e) => platform.log_warn(&format!("Failed to load world tags: {}", e)),
+                }
+            });
+        });
+    }
+
+    // Load world locations for the routine editor
+    {
+        let loc_svc = location_service.clone();
+        let plat = platform.clone();
+        let world_id_for_locations = world_id.clone();
+        use_effect(move || {
+            let svc = loc_svc.clone();
+            let platform = plat.clone();
+            let world_id_clone = world_id_for_locations.clone();
+            spawn(async move {
+                match svc.list_locations(&world_id_clone).await {
+                    Ok(loaded) => world_locations.set(loaded),
+                    Err(e) => platform.log_warn(&format!("Failed to load locations: {}", e)),
+                }
+            });
+        });
+    }
+
     // Load character data if editing existing character
     {
         let char_id_for_effect = character_id.clone();
         let char_svc = char_service.clone();
         use_effect(move || {
+            let _ = *reload.read();
             let char_id = char_id_for_effect.clone();
             let svc = char_svc.clone();
             if !char_id.is_empty() {
@@ -102,10 +213,14 @@ pub fn CharacterForm(
                                 wants.set(char_data.wants.unwrap_or_default());
                                 fears.set(char_data.fears.unwrap_or_default());
                                 backstory.set(char_data.backstory.unwrap_or_default());
+                                tags.set(char_data.tags);
+                                routine.set(char_data.routine);
+                                style.set(char_data.style);
                                 // Load sheet values if present
                                 if let Some(data) = char_data.sheet_data {
                                     sheet_values.set(data.values);
                                 }
+                                portrait_asset.set(char_data.portrait_asset);
                                 is_loading.set(false);
                             }
                             Err(e) => {
@@ -113,11 +228,29 @@ pub fn CharacterForm(
                                 is_loading.set(false);
                             }
                     }
+                    match svc.get_inventory(&char_id).await {
+                        Ok(items) => inventory.set(items),
+                        Err(e) => tracing::warn!("Failed to load inventory: {}", e),
+                    }
                 });
             }
         });
     }
 
+    let errors = character_rules(sheet_template.read().as_ref())
+        .validate(&FormValues::new().with("name", name.read().as_str()).with_sheet_values(&sheet_values.read()));
+
+    // What the Copy button puts on the clipboard
+    let summary_text = entity_summary(
+        &name.read(),
+        &archetype.read(),
+        &description.read(),
+        &[("Wants", wants.read().as_str()), ("Fears", fears.read().as_str())],
+    );
+
+    // Archived characters stay editable; the browser lists them under "Archived"
+    let is_archived = characters_signal.read().iter().any(|c| c.id == character_id && c.archived_at.is_some());
+
     rsx! {
         div {
             class: "character-form flex flex-col h-full bg-dark-surface rounded-lg overflow-hidden",
@@ -131,10 +264,88 @@ pub fn CharacterForm(
                     if is_new { "New Character" } else { "Edit Character" }
                 }
 
-                button {
-                    onclick: move |_| on_close.call(()),
-                    class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
-                    "×"
+                div {
+                    class: "flex items-center gap-2",
+                    if !is_new {
+                        CopyButton { text: summary_text, label: "Copy summary" }
+                        ShareButton {
+                            route: Route::DMCreatorEntityRoute {
+                                world_id: world_id.clone(),
+                                subtab: "characters".to_string(),
+                                entity_id: character_id.clone(),
+                            },
+                            title: name.read().clone(),
+                            text: format!("{} in WrldBldr", name.read()),
+                        }
+                        if let Some(template) = sheet_template.read().as_ref() {
+                            SheetExportButtons {
+                                sheet: SheetExport {
+                                    character_name: name.read().clone(),
+                                    template: template.clone(),
+                                    values: sheet_values.read().clone(),
+                                    portrait_url: portrait_asset.read().clone(),
+                                    inventory: inventory.read().clone(),
+                                    conditions: Vec::new(),
+                                },
+                            }
+                        }
+                        button {
+                            onclick: move |_| show_history.set(true),
+                            class: "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm",
+                            "History"
+                        }
+                        button {
+                            onclick: {
+                                let svc = char_service.clone();
+                                let platform = platform.clone();
+                                let character_id = character_id.clone();
+                                move |_| {
+                                    let svc = svc.clone();
+                                    let platform = platform.clone();
+                                    let character_id = character_id.clone();
+                                    let archive = !is_archived;
+                                    spawn(async move {
+                                        match svc.set_archived(&character_id, archive).await {
+                                            Ok(()) => {
+                                                if let Some(summary) = characters_signal.write().iter_mut().find(|c| c.id == character_id) {
+                                                    summary.archived_at = archive.then(|| archived_stamp(platform.now_unix_secs()));
+                                                }
+                                                success_message.set(Some(if archive {
+                                                    "Character archived".to_string()
+                                                } else {
+                                                    "Character restored from the archive".to_string()
+                                                }));
+                                            }
+                                            Err(e) => error_message.set(Some(format!("Archive failed: {}", e))),
+                                        }
+                                    });
+                                }
+                            },
+                            title: if is_archived { "Show this character in lists and pickers again" } else { "Hide this character from lists and pickers" },
+                            class: "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm",
+                            if is_archived { "Unarchive" } else { "Archive" }
+                        }
+                    }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| on_close.call(()),
+                        class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
+                        "×"
+                    }
+                }
+            }
+
+            if *show_history.read() {
+                EntityHistoryDrawer {
+                    kind: HistoryEntityKind::Character,
+                    entity_id: character_id.clone(),
+                    on_rolled_back: move |_| {
+                        validation.reset();
+                        success_message.set(Some("Rolled back to an earlier version".to_string()));
+                        let next = *reload.peek() + 1;
+                        reload.set(next);
+                    },
+                    on_close: move |_| show_history.set(false),
                 }
             }
 
@@ -156,6 +367,26 @@ pub fn CharacterForm(
             div {
                 class: "form-content flex-1 overflow-y-auto p-4 flex flex-col gap-4",
 
+                if let Some(draft) = drafts.pending.read().as_ref() {
+                    DraftRestoreBanner {
+                        saved_at: draft.saved_at,
+                        on_restore: move |_| {
+                            if let Some(draft) = drafts.restore() {
+                                name.set(draft.name);
+                                description.set(draft.description);
+                                archetype.set(draft.archetype);
+                                wants.set(draft.wants);
+                                fears.set(draft.fears);
+                                backstory.set(draft.backstory);
+                                tags.set(draft.tags);
+                                routine.set(draft.routine);
+                                style.set(draft.style);
+                            }
+                        },
+                        on_discard: move |_| drafts.discard(),
+                    }
+                }
+
                 if *is_loading.read() {
                     div {
                         class: "flex items-center justify-center p-8 text-gray-500",
@@ -164,21 +395,28 @@ pub fn CharacterForm(
                 } else {
 
                 // Name field with suggest button
+                ValidationSummary { errors: validation.summary(&errors) }
+
                 FormField {
                     label: "Name",
                     required: true,
+                    error: validation.error(&errors, "name"),
                     children: rsx! {
                         div { class: "flex gap-2",
                             input {
                                 r#type: "text",
                                 value: "{name}",
-                                oninput: move |e| name.set(e.value()),
+                                oninput: move |e| {
+                                    name.set(e.value());
+                                    validation.touch("name");
+                                },
                                 placeholder: "Enter character name...",
                                 class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded text-white",
                             }
                             SuggestionButton {
                                 suggestion_type: SuggestionType::CharacterName,
                                 world_id: world_id.clone(),
+                                entity_id: suggestion_entity_id.clone(),
                                 context: SuggestionContext {
                                     hints: Some(archetype.read().clone()),
                                     ..Default::default()
@@ -212,16 +450,17 @@ pub fn CharacterForm(
                     required: false,
                     children: rsx! {
                         div { class: "flex flex-col gap-2",
-                            textarea {
-                                value: "{description}",
-                                oninput: move |e| description.set(e.value()),
+                            RichTextEditor {
+                                value: description.read().clone(),
+                                on_change: move |value| description.set(value),
+                                world_id: world_id.clone(),
                                 placeholder: "Physical appearance, mannerisms, voice...",
-                                class: "w-full min-h-[80px] p-2 bg-dark-bg border border-gray-700 rounded text-white resize-y box-border",
                             }
                             div { class: "flex justify-end",
                                 SuggestionButton {
                                     suggestion_type: SuggestionType::CharacterDescription,
                                     world_id: world_id.clone(),
+                                    entity_id: suggestion_entity_id.clone(),
                                     context: SuggestionContext {
                                         entity_name: if name.read().is_empty() { None } else { Some(name.read().clone()) },
                                         hints: Some(archetype.read().clone()),
@@ -250,6 +489,7 @@ pub fn CharacterForm(
                             SuggestionButton {
                                 suggestion_type: SuggestionType::CharacterWants,
                                 world_id: world_id.clone(),
+                                entity_id: suggestion_entity_id.clone(),
                                 context: SuggestionContext {
                                     entity_name: if name.read().is_empty() { None } else { Some(name.read().clone()) },
                                     hints: Some(archetype.read().clone()),
@@ -278,6 +518,7 @@ pub fn CharacterForm(
                             SuggestionButton {
                                 suggestion_type: SuggestionType::CharacterFears,
                                 world_id: world_id.clone(),
+                                entity_id: suggestion_entity_id.clone(),
                                 context: SuggestionContext {
                                     entity_name: if name.read().is_empty() { None } else { Some(name.read().clone()) },
                                     hints: Some(archetype.read().clone()),
@@ -306,6 +547,7 @@ pub fn CharacterForm(
                                 SuggestionButton {
                                     suggestion_type: SuggestionType::CharacterBackstory,
                                     world_id: world_id.clone(),
+                                    entity_id: suggestion_entity_id.clone(),
                                     context: SuggestionContext {
                                         entity_name: if name.read().is_empty() { None } else { Some(name.read().clone()) },
                                         hints: Some(archetype.read().clone()),
@@ -320,6 +562,44 @@ pub fn CharacterForm(
                     }
                 }
 
+                // Tags field
+                FormField {
+                    label: "Tags",
+                    required: false,
+                    children: rsx! {
+                        TagInput {
+                            tags: tags.read().clone(),
+                            suggestions: world_tags.read().clone(),
+                            on_change: move |next| tags.set(next),
+                        }
+                    }
+                }
+
+                // Icon and colour shown in lists, the scene and graphs
+                FormField {
+                    label: "Icon & Colour",
+                    required: false,
+                    children: rsx! {
+                        EntityStylePicker {
+                            style: style.read().clone(),
+                            on_change: move |next| style.set(next),
+                        }
+                    }
+                }
+
+                // Daily routine (where the NPC is at each time of day)
+                FormField {
+                    label: "Daily Routine",
+                    required: false,
+                    children: rsx! {
+                        RoutineEditor {
+                            routine: routine.read().clone(),
+                            locations: world_locations.read().clone(),
+                            on_change: move |next| routine.set(next),
+                        }
+                    }
+                }
+
                     // Character Sheet section (if template available)
                     if let Some(template) = sheet_template.read().as_ref() {
                         div {
@@ -348,14 +628,33 @@ pub fn CharacterForm(
                                 CharacterSheetForm {
                                     template: template.clone(),
                                     values: sheet_values.read().clone(),
-                                    on_change: move |(field_id, value)| {
+                                    on_change: move |(field_id, value): (String, FieldValue)| {
+                                        validation.touch(&field_id);
                                         sheet_values.write().insert(field_id, value);
                                     },
+                                    errors: validation.visible(&errors),
                                 }
                             }
                         }
                     }
 
+                    // Relationships section
+                    div {
+                        class: "relationships-section mt-4",
+
+                        h3 { class: "text-gray-400 text-sm uppercase mb-3", "Relationships" }
+
+                        if is_new {
+                            p { class: "text-gray-500 text-sm m-0", "Save the character first to add relationships" }
+                        } else {
+                            RelationshipEditor {
+                                world_id: world_id.clone(),
+                                character_id: character_id.clone(),
+                                characters: characters_signal.read().clone(),
+                            }
+                        }
+                    }
+
                     // Asset Gallery section
                     div {
                         class: "assets-section mt-4",
@@ -366,6 +665,7 @@ pub fn CharacterForm(
                             world_id: world_id.clone(),
                             entity_type: "character".to_string(),
                             entity_id: character_id.clone(),
+                            uploads: Some(uploads),
                         }
                     }
                 }
@@ -390,10 +690,9 @@ pub fn CharacterForm(
                     disabled: *is_saving.read(),
                     onclick: {
                         let char_svc = char_service.clone();
+                        let errors = errors.clone();
                         move |_| {
-                            let char_name = name.read().clone();
-                            if char_name.is_empty() {
-                                error_message.set(Some("Character name is required".to_string()));
+                            if !validation.gate(&errors) {
                                 return;
                             }
 
@@ -443,6 +742,9 @@ pub fn CharacterForm(
                                         sprite_asset: None,
                                         portrait_asset: None,
                                         sheet_data: sheet_data_to_save,
+                                        tags: tags.read().clone(),
+                                        routine: routine.read().clone(),
+                                        style: style.read().clone(),
                                     };
 
                                     match if is_new {
@@ -458,6 +760,10 @@ pub fn CharacterForm(
                                                     id: saved_character.id.clone().unwrap_or_default(),
                                                     name: saved_character.name.clone(),
                                                     archetype: saved_character.archetype.clone(),
+                                                    tags: saved_character.tags.clone(),
+                                                    deleted_at: None,
+                                                    archived_at: None,
+                                                    style: saved_character.style.clone(),
                                                 };
                                                 characters_signal.write().push(summary);
                                             } else {
@@ -467,6 +773,8 @@ pub fn CharacterForm(
                                                     if let Some(existing) = chars.iter_mut().find(|c| c.id == *id) {
                                                         existing.name = saved_character.name.clone();
                                                         existing.archetype = saved_character.archetype.clone();
+                                                        existing.tags = saved_character.tags.clone();
+                                                        existing.style = saved_character.style.clone();
                                                     }
                                                 }
                                             }
@@ -477,6 +785,7 @@ pub fn CharacterForm(
                                                 "Character saved successfully".to_string()
                                             }));
                                             is_saving.set(false);
+                                            drafts.clear();
                                             // Close form - let the user see the success message
                                             on_close.call(());
                                         }
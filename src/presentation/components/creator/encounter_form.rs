@@ -0,0 +1,366 @@
+//! Encounter Form - Create and edit encounters
+//!
+//! An encounter picks NPCs, challenges and a region to stage together,
+//! plus notes for the DM. The Director panel runs it in one step.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::ChallengeData;
+use crate::application::services::{
+    CharacterSummary, Encounter, LocationSummary, RegionData, SaveEncounterRequest,
+};
+use crate::presentation::components::common::FormField;
+use crate::presentation::services::{
+    use_challenge_service, use_character_service, use_encounter_service, use_location_service,
+};
+
+/// Encounter form for creating/editing encounters
+#[component]
+pub fn EncounterForm(
+    encounter_id: String,
+    world_id: String,
+    encounters_signal: Signal<Vec<Encounter>>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let is_new = encounter_id.is_empty();
+    let encounter_service = use_encounter_service();
+    let character_service = use_character_service();
+    let challenge_service = use_challenge_service();
+    let location_service = use_location_service();
+
+    // The browser has already loaded every encounter, so edit from that copy
+    let existing = encounters_signal
+        .peek()
+        .iter()
+        .find(|e| e.id == encounter_id)
+        .cloned()
+        .unwrap_or_default();
+    let mut name = use_signal(|| existing.name.clone());
+    let mut npc_ids = use_signal(|| existing.npc_ids.clone());
+    let mut challenge_ids = use_signal(|| existing.challenge_ids.clone());
+    let mut location_id = use_signal(|| existing.location_id.clone());
+    let mut region_id = use_signal(|| existing.region_id.clone());
+    let mut notes = use_signal(|| existing.notes.clone());
+
+    let mut characters: Signal<Vec<CharacterSummary>> = use_signal(Vec::new);
+    let mut challenges: Signal<Vec<ChallengeData>> = use_signal(Vec::new);
+    let mut locations: Signal<Vec<LocationSummary>> = use_signal(Vec::new);
+    let mut regions: Signal<Vec<RegionData>> = use_signal(Vec::new);
+    let mut is_saving = use_signal(|| false);
+    let mut error_message: Signal<Option<String>> = use_signal(|| None);
+
+    // Load what can be staged
+    {
+        let world_id = world_id.clone();
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let character_service = character_service.clone();
+            let challenge_service = challenge_service.clone();
+            let location_service = location_service.clone();
+            spawn(async move {
+                match character_service.list_characters(&world_id).await {
+                    Ok(list) => characters.set(list),
+                    Err(e) => error_message.set(Some(format!("Failed to load characters: {}", e))),
+                }
+                match challenge_service.list_challenges(&world_id).await {
+                    Ok(list) => challenges.set(list),
+                    Err(e) => error_message.set(Some(format!("Failed to load challenges: {}", e))),
+                }
+                match location_service.list_locations(&world_id).await {
+                    Ok(list) => locations.set(list),
+                    Err(e) => error_message.set(Some(format!("Failed to load locations: {}", e))),
+                }
+            });
+        });
+    }
+
+    // Regions of the chosen location
+    {
+        let location_service = use_location_service();
+        use_effect(move || {
+            let Some(location) = location_id.read().clone() else {
+                regions.set(Vec::new());
+                return;
+            };
+            let svc = location_service.clone();
+            spawn(async move {
+                match svc.get_regions(&location).await {
+                    Ok(list) => regions.set(list),
+                    Err(e) => error_message.set(Some(format!("Failed to load regions: {}", e))),
+                }
+            });
+        });
+    }
+
+    let save = {
+        let encounter_service = encounter_service.clone();
+        let encounter_id = encounter_id.clone();
+        let world_id = world_id.clone();
+        move |_| {
+            let request = SaveEncounterRequest {
+                name: name.read().trim().to_string(),
+                npc_ids: npc_ids.read().clone(),
+                challenge_ids: challenge_ids.read().clone(),
+                location_id: location_id.read().clone(),
+                region_id: region_id.read().clone(),
+                notes: notes.read().clone(),
+            };
+            if let Err(e) = request.validate() {
+                error_message.set(Some(e));
+                return;
+            }
+            error_message.set(None);
+            is_saving.set(true);
+
+            let svc = encounter_service.clone();
+            let encounter_id = encounter_id.clone();
+            let world_id = world_id.clone();
+            spawn(async move {
+                let result = if is_new {
+                    svc.create_encounter(&world_id, &request).await
+                } else {
+                    svc.update_encounter(&encounter_id, &request).await
+                };
+                is_saving.set(false);
+                match result {
+                    Ok(saved) => {
+                        let mut list = encounters_signal.write();
+                        match list.iter_mut().find(|e| e.id == saved.id) {
+                            Some(existing) => *existing = saved,
+                            None => list.push(saved),
+                        }
+                        drop(list);
+                        on_close.call(());
+                    }
+                    Err(e) => error_message.set(Some(format!("Save failed: {}", e))),
+                }
+            });
+        }
+    };
+
+    let delete = {
+        let encounter_service = encounter_service.clone();
+        let encounter_id = encounter_id.clone();
+        move |_| {
+            let svc = encounter_service.clone();
+            let encounter_id = encounter_id.clone();
+            spawn(async move {
+                match svc.delete_encounter(&encounter_id).await {
+                    Ok(()) => {
+                        encounters_signal.write().retain(|e| e.id != encounter_id);
+                        on_close.call(());
+                    }
+                    Err(e) => error_message.set(Some(format!("Delete failed: {}", e))),
+                }
+            });
+        }
+    };
+
+    let select_class = "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white";
+
+    rsx! {
+        div {
+            class: "encounter-form flex flex-col h-full bg-dark-surface rounded-lg overflow-hidden",
+
+            // Header
+            div {
+                class: "form-header flex justify-between items-center p-4 border-b border-gray-700",
+                h2 {
+                    class: "text-white m-0 text-xl",
+                    if is_new { "New Encounter" } else { "Edit Encounter" }
+                }
+                button {
+                    aria_label: "Close",
+                    onclick: move |_| on_close.call(()),
+                    class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
+                    "×"
+                }
+            }
+
+            if let Some(msg) = error_message.read().as_ref() {
+                div {
+                    class: "px-4 py-3 bg-red-500/10 border-b border-red-500/30 text-red-500 text-sm",
+                    "{msg}"
+                }
+            }
+
+            // Form content (scrollable)
+            div {
+                class: "form-content flex-1 overflow-y-auto p-4 flex flex-col gap-4",
+
+                FormField {
+                    label: "Name",
+                    required: true,
+                    children: rsx! {
+                        input {
+                            r#type: "text",
+                            value: "{name}",
+                            oninput: move |e| name.set(e.value()),
+                            placeholder: "Ambush at the bridge...",
+                            class: "{select_class}",
+                        }
+                    }
+                }
+
+                FormField {
+                    label: "Staging Location",
+                    required: false,
+                    children: rsx! {
+                        div { class: "flex flex-col gap-2",
+                            select {
+                                value: location_id.read().as_deref().unwrap_or(""),
+                                onchange: move |e| {
+                                    let val = e.value();
+                                    location_id.set((!val.is_empty()).then_some(val));
+                                    region_id.set(None);
+                                },
+                                class: select_class,
+                                option { value: "", "No scene change" }
+                                for location in locations.read().iter() {
+                                    option { key: "{location.id}", value: "{location.id}", "{location.name}" }
+                                }
+                            }
+                            if location_id.read().is_some() {
+                                select {
+                                    value: region_id.read().as_deref().unwrap_or(""),
+                                    onchange: move |e| {
+                                        let val = e.value();
+                                        region_id.set((!val.is_empty()).then_some(val));
+                                    },
+                                    aria_label: "Region",
+                                    class: select_class,
+                                    option { value: "", "Choose a region..." }
+                                    for region in regions.read().iter() {
+                                        option {
+                                            key: "{region.id}",
+                                            value: "{region.id}",
+                                            if region.backdrop_asset.is_some() { "{region.name} 🖼" } else { "{region.name}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                FormField {
+                    label: "NPCs",
+                    required: false,
+                    children: rsx! {
+                        PickList {
+                            options: characters.read().iter().map(|c| (c.id.clone(), c.name.clone())).collect::<Vec<_>>(),
+                            selected: npc_ids.read().clone(),
+                            empty_label: "No characters in this world yet",
+                            on_change: move |ids| npc_ids.set(ids),
+                        }
+                    }
+                }
+
+                FormField {
+                    label: "Challenges",
+                    required: false,
+                    children: rsx! {
+                        PickList {
+                            options: challenges.read().iter().map(|c| (c.id.clone(), c.name.clone())).collect::<Vec<_>>(),
+                            selected: challenge_ids.read().clone(),
+                            empty_label: "No challenges in this world yet",
+                            on_change: move |ids| challenge_ids.set(ids),
+                        }
+                    }
+                }
+
+                FormField {
+                    label: "DM Notes",
+                    required: false,
+                    children: rsx! {
+                        textarea {
+                            value: "{notes}",
+                            oninput: move |e| notes.set(e.value()),
+                            placeholder: "Tactics, read-aloud text, what triggers reinforcements...",
+                            class: "w-full min-h-[100px] p-2 bg-dark-bg border border-gray-700 rounded text-white resize-y box-border",
+                        }
+                    }
+                }
+            }
+
+            // Footer with action buttons
+            div {
+                class: "form-footer flex justify-between gap-2 p-4 border-t border-gray-700",
+
+                if is_new {
+                    div {}
+                } else {
+                    button {
+                        onclick: delete,
+                        class: "px-4 py-2 bg-transparent text-red-400 border border-red-500/40 rounded cursor-pointer",
+                        "Delete"
+                    }
+                }
+
+                div {
+                    class: "flex gap-2",
+                    button {
+                        onclick: move |_| on_close.call(()),
+                        class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer",
+                        disabled: *is_saving.read(),
+                        "Cancel"
+                    }
+                    button {
+                        onclick: save,
+                        disabled: *is_saving.read(),
+                        class: "px-4 py-2 bg-green-500 text-white border-none rounded cursor-pointer font-medium disabled:opacity-60",
+                        if *is_saving.read() { "Saving..." } else if is_new { "Create" } else { "Save" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Checkbox list of `(id, label)` options
+#[component]
+fn PickList(
+    options: Vec<(String, String)>,
+    selected: Vec<String>,
+    empty_label: &'static str,
+    on_change: EventHandler<Vec<String>>,
+) -> Element {
+    if options.is_empty() {
+        return rsx! {
+            p { class: "text-gray-500 text-sm italic m-0", "{empty_label}" }
+        };
+    }
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 max-h-48 overflow-y-auto p-2 bg-dark-bg border border-gray-700 rounded",
+            for (id, label) in options.into_iter() {
+                {
+                    let checked = selected.contains(&id);
+                    let selected = selected.clone();
+                    let toggle_id = id.clone();
+                    rsx! {
+                        label {
+                            key: "{id}",
+                            class: "flex items-center gap-2 text-white text-sm cursor-pointer",
+                            input {
+                                r#type: "checkbox",
+                                checked,
+                                onchange: move |_| {
+                                    let mut next = selected.clone();
+                                    if checked {
+                                        next.retain(|s| s != &toggle_id);
+                                    } else {
+                                        next.push(toggle_id.clone());
+                                    }
+                                    on_change.call(next);
+                                },
+                            }
+                            "{label}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
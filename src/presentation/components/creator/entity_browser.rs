@@ -3,8 +3,12 @@
 use dioxus::prelude::*;
 
 use super::EntityTypeTab;
+use crate::application::dto::{factions_of, CraftingRecipe, EntityStyle, Faction};
 use crate::application::services::character_service::CharacterSummary;
 use crate::application::services::location_service::LocationSummary;
+use crate::application::services::{tree_order, ArchiveFilter, Encounter};
+use crate::presentation::components::common::{accent_stripe, collect_tags, EntityMark, FactionBadges, TagChips, TagFilterBar};
+use crate::presentation::state::{is_near_bottom, use_tag_filter_state};
 use crate::routes::Route;
 
 /// Props for the EntityBrowser component
@@ -15,12 +19,32 @@ pub fn EntityBrowser(
     selected_id: Option<String>,
     characters: Signal<Vec<CharacterSummary>>,
     locations: Signal<Vec<LocationSummary>>,
+    encounters: Signal<Vec<Encounter>>,
+    recipes: Signal<Vec<CraftingRecipe>>,
+    factions: Signal<Vec<Faction>>,
     characters_loading: Signal<bool>,
     locations_loading: Signal<bool>,
     characters_error: Signal<Option<String>>,
     locations_error: Signal<Option<String>>,
+    /// Whether the selected list has more pages to load
+    has_more: bool,
+    /// Load the next page of the selected list
+    on_load_more: EventHandler<()>,
     on_select: EventHandler<String>,
+    /// Open the bulk "generate from outline" tool
+    on_generate_from_outline: EventHandler<()>,
 ) -> Element {
+    let mut archive_filter = use_signal(ArchiveFilter::default);
+    let available_tags = match selected_type {
+        EntityTypeTab::Characters => collect_tags(characters.read().iter().map(|c| &c.tags)),
+        EntityTypeTab::Locations => collect_tags(locations.read().iter().map(|l| &l.tags)),
+        EntityTypeTab::Items
+        | EntityTypeTab::Maps
+        | EntityTypeTab::Encounters
+        | EntityTypeTab::Recipes
+        | EntityTypeTab::Factions => Vec::new(),
+    };
+
     rsx! {
         div {
             class: "entity-browser flex-1 flex flex-col bg-dark-surface rounded-lg overflow-hidden",
@@ -49,6 +73,21 @@ pub fn EntityBrowser(
                     tab: EntityTypeTab::Maps,
                     active: selected_type == EntityTypeTab::Maps,
                 }
+                EntityTypeTabLink {
+                    world_id: world_id.clone(),
+                    tab: EntityTypeTab::Encounters,
+                    active: selected_type == EntityTypeTab::Encounters,
+                }
+                EntityTypeTabLink {
+                    world_id: world_id.clone(),
+                    tab: EntityTypeTab::Recipes,
+                    active: selected_type == EntityTypeTab::Recipes,
+                }
+                EntityTypeTabLink {
+                    world_id: world_id.clone(),
+                    tab: EntityTypeTab::Factions,
+                    active: selected_type == EntityTypeTab::Factions,
+                }
             }
 
             // Search/filter bar
@@ -62,14 +101,49 @@ pub fn EntityBrowser(
                 }
             }
 
+            // Global tag filter
+            div {
+                class: "px-2 pb-2",
+                TagFilterBar { available_tags: available_tags }
+            }
+
+            // Current / archived toggle
+            if matches!(selected_type, EntityTypeTab::Characters | EntityTypeTab::Locations) {
+                div {
+                    role: "group",
+                    aria_label: "Archive filter",
+                    class: "flex gap-1 px-2 pb-2",
+                    for filter in [ArchiveFilter::Current, ArchiveFilter::Archived] {
+                        button {
+                            key: "{filter.label()}",
+                            aria_pressed: *archive_filter.read() == filter,
+                            onclick: move |_| archive_filter.set(filter),
+                            class: if *archive_filter.read() == filter {
+                                "flex-1 py-1 bg-gray-700 text-white border-0 rounded cursor-pointer text-xs"
+                            } else {
+                                "flex-1 py-1 bg-transparent text-gray-500 border-0 rounded cursor-pointer text-xs"
+                            },
+                            "{filter.label()}"
+                        }
+                    }
+                }
+            }
+
             // Entity list
             div {
                 class: "browser-list flex-1 overflow-y-auto p-2",
+                onscroll: move |e| {
+                    if has_more && is_near_bottom(&e) {
+                        on_load_more.call(());
+                    }
+                },
 
                 match selected_type {
                     EntityTypeTab::Characters => rsx! {
                         CharacterList {
                             characters: characters,
+                            factions: factions,
+                            archive_filter: *archive_filter.read(),
                             selected_id: selected_id.clone(),
                             loading: characters_loading,
                             error: characters_error,
@@ -79,6 +153,7 @@ pub fn EntityBrowser(
                     EntityTypeTab::Locations => rsx! {
                         LocationList {
                             locations: locations,
+                            archive_filter: *archive_filter.read(),
                             selected_id: selected_id.clone(),
                             loading: locations_loading,
                             error: locations_error,
@@ -95,6 +170,86 @@ pub fn EntityBrowser(
                             "No maps yet"
                         }
                     },
+                    EntityTypeTab::Encounters => rsx! {
+                        div {
+                            class: "flex flex-col gap-1",
+                            for encounter in encounters.read().iter() {
+                                EntityListItem {
+                                    key: "{encounter.id}",
+                                    id: encounter.id.clone(),
+                                    name: encounter.name.clone(),
+                                    subtitle: encounter.summary(),
+                                    selected: selected_id.as_deref() == Some(&encounter.id),
+                                    on_click: {
+                                        let encounter_id = encounter.id.clone();
+                                        move |_| on_select.call(encounter_id.clone())
+                                    },
+                                }
+                            }
+                            if encounters.read().is_empty() {
+                                div {
+                                    class: "text-gray-500 text-center p-4 text-sm",
+                                    "No encounters yet"
+                                }
+                            }
+                        }
+                    },
+                    EntityTypeTab::Recipes => rsx! {
+                        div {
+                            class: "flex flex-col gap-1",
+                            for recipe in recipes.read().iter() {
+                                EntityListItem {
+                                    key: "{recipe.id}",
+                                    id: recipe.id.clone(),
+                                    name: recipe.name.clone(),
+                                    subtitle: recipe.formula(),
+                                    selected: selected_id.as_deref() == Some(&recipe.id),
+                                    on_click: {
+                                        let recipe_id = recipe.id.clone();
+                                        move |_| on_select.call(recipe_id.clone())
+                                    },
+                                }
+                            }
+                            if recipes.read().is_empty() {
+                                div {
+                                    class: "text-gray-500 text-center p-4 text-sm",
+                                    "No recipes yet"
+                                }
+                            }
+                        }
+                    },
+                    EntityTypeTab::Factions => rsx! {
+                        div {
+                            class: "flex flex-col gap-1",
+                            for faction in factions.read().iter() {
+                                EntityListItem {
+                                    key: "{faction.id}",
+                                    id: faction.id.clone(),
+                                    name: faction.name.clone(),
+                                    subtitle: format!("{} member(s)", faction.member_ids.len()),
+                                    selected: selected_id.as_deref() == Some(&faction.id),
+                                    on_click: {
+                                        let faction_id = faction.id.clone();
+                                        move |_| on_select.call(faction_id.clone())
+                                    },
+                                }
+                            }
+                            if factions.read().is_empty() {
+                                div {
+                                    class: "text-gray-500 text-center p-4 text-sm",
+                                    "No factions yet"
+                                }
+                            }
+                        }
+                    },
+                }
+
+                if has_more {
+                    button {
+                        class: "w-full p-2 mt-1 bg-transparent text-gray-500 border-0 text-xs cursor-pointer",
+                        onclick: move |_| on_load_more.call(()),
+                        "Load more..."
+                    }
                 }
             }
 
@@ -104,9 +259,16 @@ pub fn EntityBrowser(
 
                 button {
                     class: "w-full p-2 bg-blue-500 text-white border-0 rounded cursor-pointer font-medium",
-                    onclick: move |_| on_select.call(String::new()),
+                    onclick: move |_| on_select.call(super::NEW_ENTITY_ID.to_string()),
                     "+ New {selected_type.label()}"
                 }
+                if matches!(selected_type, EntityTypeTab::Characters | EntityTypeTab::Locations) {
+                    button {
+                        class: "w-full p-2 mt-2 bg-transparent text-purple-400 border border-purple-500 rounded cursor-pointer text-sm",
+                        onclick: move |_| on_generate_from_outline.call(()),
+                        "Generate from outline"
+                    }
+                }
             }
         }
     }
@@ -121,12 +283,18 @@ fn EntityTypeTabLink(world_id: String, tab: EntityTypeTab, active: bool) -> Elem
         EntityTypeTab::Locations => "Loc",
         EntityTypeTab::Items => "Item",
         EntityTypeTab::Maps => "Map",
+        EntityTypeTab::Encounters => "Enc",
+        EntityTypeTab::Recipes => "Rcp",
+        EntityTypeTab::Factions => "Fac",
     };
     let subtab = match tab {
         EntityTypeTab::Characters => "characters",
         EntityTypeTab::Locations => "locations",
         EntityTypeTab::Items => "items",
         EntityTypeTab::Maps => "maps",
+        EntityTypeTab::Encounters => "encounters",
+        EntityTypeTab::Recipes => "recipes",
+        EntityTypeTab::Factions => "factions",
     };
 
     rsx! {
@@ -145,11 +313,15 @@ fn EntityTypeTabLink(world_id: String, tab: EntityTypeTab, active: bool) -> Elem
 #[component]
 fn CharacterList(
     characters: Signal<Vec<CharacterSummary>>,
+    factions: Signal<Vec<Faction>>,
+    archive_filter: ArchiveFilter,
     selected_id: Option<String>,
     loading: Signal<bool>,
     error: Signal<Option<String>>,
     on_select: EventHandler<String>,
 ) -> Element {
+    let tag_filter = use_tag_filter_state();
+
     rsx! {
         if *loading.read() {
             div {
@@ -165,11 +337,14 @@ fn CharacterList(
             div {
                 class: "flex flex-col gap-1",
 
-                for character in characters.read().iter() {
+                for character in characters.read().iter().filter(|c| archive_filter.matches(*c) && tag_filter.matches(&c.tags)) {
                     EntityListItem {
                         id: character.id.clone(),
                         name: character.name.clone(),
                         subtitle: character.archetype.clone().unwrap_or_else(|| "Unknown".to_string()),
+                        tags: character.tags.clone(),
+                        style: character.style.clone(),
+                        factions: factions_of(&character.id, &factions.read()).into_iter().cloned().collect::<Vec<_>>(),
                         selected: selected_id.as_deref() == Some(&character.id),
                         on_click: {
                             let char_id = character.id.clone();
@@ -178,10 +353,10 @@ fn CharacterList(
                     }
                 }
 
-                if characters.read().is_empty() {
+                if !characters.read().iter().any(|c| archive_filter.matches(c)) {
                     div {
                         class: "text-gray-500 text-center p-4 text-sm",
-                        "No characters yet"
+                        if archive_filter == ArchiveFilter::Archived { "No archived characters" } else { "No characters yet" }
                     }
                 }
             }
@@ -193,11 +368,24 @@ fn CharacterList(
 #[component]
 fn LocationList(
     locations: Signal<Vec<LocationSummary>>,
+    archive_filter: ArchiveFilter,
     selected_id: Option<String>,
     loading: Signal<bool>,
     error: Signal<Option<String>>,
     on_select: EventHandler<String>,
 ) -> Element {
+    let tag_filter = use_tag_filter_state();
+    // Nested locations are indented under their parents
+    let rows: Vec<(String, LocationSummary)> = {
+        let all = locations.read();
+        let visible: Vec<&LocationSummary> =
+            all.iter().filter(|l| archive_filter.matches(*l) && tag_filter.matches(&l.tags)).collect();
+        tree_order(&visible)
+            .into_iter()
+            .map(|(depth, l)| (format!("padding-left: {}px", depth * 12), (*l).clone()))
+            .collect()
+    };
+
     rsx! {
         if *loading.read() {
             div {
@@ -213,23 +401,29 @@ fn LocationList(
             div {
                 class: "flex flex-col gap-1",
 
-                for location in locations.read().iter() {
-                    EntityListItem {
-                        id: location.id.clone(),
-                        name: location.name.clone(),
-                        subtitle: location.location_type.clone().unwrap_or_else(|| "Unknown".to_string()),
-                        selected: selected_id.as_deref() == Some(&location.id),
-                        on_click: {
-                            let loc_id = location.id.clone();
-                            move |_| on_select.call(loc_id.clone())
-                        },
+                for (indent, location) in rows.iter() {
+                    div {
+                        key: "{location.id}",
+                        style: "{indent}",
+                        EntityListItem {
+                            id: location.id.clone(),
+                            name: location.name.clone(),
+                            subtitle: location.location_type.clone().unwrap_or_else(|| "Unknown".to_string()),
+                            tags: location.tags.clone(),
+                            style: location.style.clone(),
+                            selected: selected_id.as_deref() == Some(&location.id),
+                            on_click: {
+                                let loc_id = location.id.clone();
+                                move |_| on_select.call(loc_id.clone())
+                            },
+                        }
                     }
                 }
 
-                if locations.read().is_empty() {
+                if !locations.read().iter().any(|l| archive_filter.matches(l)) {
                     div {
                         class: "text-gray-500 text-center p-4 text-sm",
-                        "No locations yet"
+                        if archive_filter == ArchiveFilter::Archived { "No archived locations" } else { "No locations yet" }
                     }
                 }
             }
@@ -243,9 +437,18 @@ fn EntityListItem(
     id: String,
     name: String,
     subtitle: String,
+    #[props(default)]
+    tags: Vec<String>,
+    /// Icon and accent colour picked in the entity's form
+    #[props(default)]
+    style: EntityStyle,
+    /// Factions the entity belongs to, shown as badges
+    #[props(default)]
+    factions: Vec<Faction>,
     selected: bool,
     on_click: EventHandler<()>,
 ) -> Element {
+    let tag_filter = use_tag_filter_state();
     let bg_class = if selected { "bg-blue-500 bg-opacity-20" } else { "bg-transparent" };
     let border_class = if selected { "border border-blue-500" } else { "border border-transparent" };
 
@@ -253,9 +456,30 @@ fn EntityListItem(
         div {
             onclick: move |_| on_click.call(()),
             class: format!("p-2 {} {} rounded cursor-pointer", bg_class, border_class),
+            style: accent_stripe(style.accent_color.as_deref()),
 
-            div { class: "text-white text-sm", "{name}" }
+            div {
+                class: "flex items-center gap-2 text-white text-sm",
+                EntityMark { style: style.clone() }
+                "{name}"
+            }
             div { class: "text-gray-500 text-xs", "{subtitle}" }
+            if !factions.is_empty() {
+                div {
+                    class: "mt-1",
+                    FactionBadges { factions: factions.clone() }
+                }
+            }
+            if !tags.is_empty() {
+                div {
+                    class: "mt-1",
+                    TagChips {
+                        tags: tags.clone(),
+                        max_visible: 3,
+                        on_tag_click: move |tag: String| tag_filter.clone().toggle(&tag),
+                    }
+                }
+            }
         }
     }
 }
@@ -0,0 +1,348 @@
+//! Faction Form - Create and edit factions
+//!
+//! A faction has goals, member characters, and a standing toward each of
+//! the world's other factions. Standings are one-way: the guild can hate
+//! the watch while the watch barely notices the guild.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{Faction, StandingLevel, MAX_STANDING, MIN_STANDING};
+use crate::application::services::character_service::CharacterSummary;
+use crate::application::services::SaveFactionRequest;
+use crate::presentation::components::common::FormField;
+use crate::presentation::services::use_faction_service;
+
+/// Faction form for creating/editing factions
+#[component]
+pub fn FactionForm(
+    faction_id: String,
+    world_id: String,
+    factions_signal: Signal<Vec<Faction>>,
+    characters_signal: Signal<Vec<CharacterSummary>>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let is_new = faction_id.is_empty();
+    let faction_service = use_faction_service();
+
+    // Form state, filled in once the browser's list has the faction; a
+    // deep link can open the form before the list has loaded
+    let mut seeded = use_signal(|| is_new);
+    let mut name = use_signal(String::new);
+    let mut description = use_signal(String::new);
+    let mut goals: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut member_ids: Signal<Vec<String>> = use_signal(Vec::new);
+    // Edited on a working copy so the helpers keep values in range
+    let mut standings = use_signal(Faction::default);
+    {
+        let faction_id = faction_id.clone();
+        use_effect(move || {
+            let existing = factions_signal.read().iter().find(|f| f.id == faction_id).cloned();
+            if let Some(existing) = existing.filter(|_| !*seeded.peek()) {
+                name.set(existing.name.clone());
+                description.set(existing.description.clone());
+                goals.set(existing.goals.clone());
+                member_ids.set(existing.member_ids.clone());
+                standings.set(existing);
+                seeded.set(true);
+            }
+        });
+    }
+
+    let mut is_saving = use_signal(|| false);
+    let mut error_message: Signal<Option<String>> = use_signal(|| None);
+
+    let save = {
+        let faction_service = faction_service.clone();
+        let faction_id = faction_id.clone();
+        let world_id = world_id.clone();
+        move |_| {
+            // Saving a form that never loaded would wipe the faction
+            if !*seeded.peek() {
+                return;
+            }
+            let request = SaveFactionRequest {
+                name: name.read().trim().to_string(),
+                description: description.read().clone(),
+                goals: goals
+                    .read()
+                    .iter()
+                    .map(|g| g.trim().to_string())
+                    .filter(|g| !g.is_empty())
+                    .collect(),
+                member_ids: member_ids.read().clone(),
+                standings: standings.read().standings.clone(),
+            };
+            if let Err(e) = request.validate(&faction_id) {
+                error_message.set(Some(e));
+                return;
+            }
+            error_message.set(None);
+            is_saving.set(true);
+
+            let svc = faction_service.clone();
+            let faction_id = faction_id.clone();
+            let world_id = world_id.clone();
+            spawn(async move {
+                let result = if is_new {
+                    svc.create_faction(&world_id, &request).await
+                } else {
+                    svc.update_faction(&faction_id, &request).await
+                };
+                is_saving.set(false);
+                match result {
+                    Ok(saved) => {
+                        let mut list = factions_signal.write();
+                        match list.iter_mut().find(|f| f.id == saved.id) {
+                            Some(existing) => *existing = saved,
+                            None => list.push(saved),
+                        }
+                        drop(list);
+                        on_close.call(());
+                    }
+                    Err(e) => error_message.set(Some(format!("Save failed: {}", e))),
+                }
+            });
+        }
+    };
+
+    let delete = {
+        let faction_service = faction_service.clone();
+        let faction_id = faction_id.clone();
+        move |_| {
+            let svc = faction_service.clone();
+            let faction_id = faction_id.clone();
+            spawn(async move {
+                match svc.delete_faction(&faction_id).await {
+                    Ok(()) => {
+                        factions_signal.write().retain(|f| f.id != faction_id);
+                        on_close.call(());
+                    }
+                    Err(e) => error_message.set(Some(format!("Delete failed: {}", e))),
+                }
+            });
+        }
+    };
+
+    let others: Vec<Faction> = factions_signal
+        .read()
+        .iter()
+        .filter(|f| f.id != faction_id)
+        .cloned()
+        .collect();
+    let input_class = "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white";
+
+    rsx! {
+        div {
+            class: "faction-form flex flex-col h-full bg-dark-surface rounded-lg overflow-hidden",
+
+            // Header
+            div {
+                class: "form-header flex justify-between items-center p-4 border-b border-gray-700",
+                h2 {
+                    class: "text-white m-0 text-xl",
+                    if is_new { "New Faction" } else { "Edit Faction" }
+                }
+                button {
+                    aria_label: "Close",
+                    onclick: move |_| on_close.call(()),
+                    class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
+                    "×"
+                }
+            }
+
+            if let Some(msg) = error_message.read().as_ref() {
+                div {
+                    class: "px-4 py-3 bg-red-500/10 border-b border-red-500/30 text-red-500 text-sm",
+                    "{msg}"
+                }
+            }
+
+            // Form content (scrollable)
+            div {
+                class: "form-content flex-1 overflow-y-auto p-4 flex flex-col gap-4",
+
+                if !*seeded.read() {
+                    div {
+                        class: "flex items-center justify-center p-8 text-gray-500",
+                        "Loading faction..."
+                    }
+                } else {
+
+                FormField {
+                    label: "Name",
+                    required: true,
+                    children: rsx! {
+                        input {
+                            r#type: "text",
+                            value: "{name}",
+                            oninput: move |e| name.set(e.value()),
+                            placeholder: "Harbour Guild...",
+                            class: "{input_class}",
+                        }
+                    }
+                }
+
+                FormField {
+                    label: "Description",
+                    required: false,
+                    children: rsx! {
+                        textarea {
+                            value: "{description}",
+                            oninput: move |e| description.set(e.value()),
+                            placeholder: "Who they are, how they operate...",
+                            class: "w-full min-h-[80px] p-2 bg-dark-bg border border-gray-700 rounded text-white resize-y box-border",
+                        }
+                    }
+                }
+
+                FormField {
+                    label: "Goals",
+                    required: false,
+                    children: rsx! {
+                        div { class: "flex flex-col gap-2",
+                            for (i, goal) in goals.read().iter().enumerate() {
+                                div {
+                                    key: "{i}",
+                                    class: "flex gap-2 items-center",
+                                    input {
+                                        r#type: "text",
+                                        aria_label: "Goal",
+                                        value: "{goal}",
+                                        oninput: move |e| goals.write()[i] = e.value(),
+                                        placeholder: "Control the spice trade",
+                                        class: "{input_class}",
+                                    }
+                                    button {
+                                        aria_label: "Remove goal",
+                                        onclick: move |_| {
+                                            goals.write().remove(i);
+                                        },
+                                        class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer",
+                                        "×"
+                                    }
+                                }
+                            }
+                            button {
+                                onclick: move |_| goals.write().push(String::new()),
+                                class: "self-start px-3 py-1 bg-transparent text-blue-400 border border-blue-500/40 rounded cursor-pointer text-sm",
+                                "+ Add goal"
+                            }
+                        }
+                    }
+                }
+
+                FormField {
+                    label: "Members",
+                    required: false,
+                    children: rsx! {
+                        div { class: "flex flex-col gap-1 max-h-48 overflow-y-auto",
+                            // Archived characters stay listed only while they're still members
+                            for character in characters_signal.read().iter().filter(|c| {
+                                c.deleted_at.is_none() && (c.archived_at.is_none() || member_ids.read().contains(&c.id))
+                            }) {
+                                {
+                                    let character_id = character.id.clone();
+                                    let is_member = member_ids.read().contains(&character.id);
+                                    rsx! {
+                                        label {
+                                            key: "{character.id}",
+                                            class: "flex items-center gap-2 text-white text-sm cursor-pointer",
+                                            input {
+                                                r#type: "checkbox",
+                                                checked: is_member,
+                                                onchange: move |e| {
+                                                    let mut members = member_ids.write();
+                                                    members.retain(|id| id != &character_id);
+                                                    if e.checked() {
+                                                        members.push(character_id.clone());
+                                                    }
+                                                },
+                                            }
+                                            "{character.name}"
+                                        }
+                                    }
+                                }
+                            }
+                            if characters_signal.read().is_empty() {
+                                p { class: "text-gray-500 text-sm m-0", "No characters yet" }
+                            }
+                        }
+                    }
+                }
+
+                FormField {
+                    label: "Standing toward other factions",
+                    required: false,
+                    children: rsx! {
+                        div { class: "flex flex-col gap-2",
+                            for other in others.iter() {
+                                {
+                                    let other_id = other.id.clone();
+                                    let value = standings.read().standing_toward(&other.id);
+                                    let level = StandingLevel::from_standing(value);
+                                    rsx! {
+                                        label {
+                                            key: "{other.id}",
+                                            class: "flex items-center gap-2 text-sm",
+                                            span { class: "text-white w-32 truncate", "{other.name}" }
+                                            input {
+                                                r#type: "range",
+                                                min: "{MIN_STANDING}",
+                                                max: "{MAX_STANDING}",
+                                                step: "5",
+                                                aria_label: "Standing toward {other.name}",
+                                                value: "{value}",
+                                                oninput: move |e| {
+                                                    if let Ok(value) = e.value().parse() {
+                                                        standings.write().set_standing_toward(&other_id, value);
+                                                    }
+                                                },
+                                                class: "flex-1",
+                                            }
+                                            span { class: "w-28 text-right text-xs {level.text_class()}", "{level.display_name()} ({value})" }
+                                        }
+                                    }
+                                }
+                            }
+                            if others.is_empty() {
+                                p { class: "text-gray-500 text-sm m-0", "No other factions yet" }
+                            }
+                        }
+                    }
+                }
+                }
+            }
+
+            // Footer with action buttons
+            div {
+                class: "form-footer flex justify-between gap-2 p-4 border-t border-gray-700",
+
+                if is_new {
+                    div {}
+                } else {
+                    button {
+                        onclick: delete,
+                        class: "px-4 py-2 bg-transparent text-red-400 border border-red-500/40 rounded cursor-pointer",
+                        "Delete"
+                    }
+                }
+
+                div {
+                    class: "flex gap-2",
+                    button {
+                        onclick: move |_| on_close.call(()),
+                        class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer",
+                        disabled: *is_saving.read(),
+                        "Cancel"
+                    }
+                    button {
+                        onclick: save,
+                        disabled: *is_saving.read() || !*seeded.read(),
+                        class: "px-4 py-2 bg-green-500 text-white border-none rounded cursor-pointer font-medium disabled:opacity-60",
+                        if *is_saving.read() { "Saving..." } else if is_new { "Create" } else { "Save" }
+                    }
+                }
+            }
+        }
+    }
+}
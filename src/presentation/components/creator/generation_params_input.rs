@@ -0,0 +1,157 @@
+//! Generation Params Input - Per-request overrides for image generation
+//!
+//! Collapsible "Advanced" section used by the asset generation modals.
+//! Empty fields fall back to the workflow slot defaults.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::GenerationParams;
+
+/// Common resolution presets (width, height)
+const RESOLUTION_PRESETS: &[(u32, u32)] = &[
+    (512, 512),
+    (768, 768),
+    (1024, 1024),
+    (832, 1216),
+    (1216, 832),
+];
+
+/// Parse an optional numeric field; blank or invalid input means "use default"
+fn parse_opt<T: std::str::FromStr>(value: &str) -> Option<T> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        trimmed.parse().ok()
+    }
+}
+
+fn display_opt<T: std::fmt::Display>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Seed / steps / CFG / resolution override fields
+#[component]
+pub fn GenerationParamsInput(params: Signal<GenerationParams>) -> Element {
+    let mut expanded = use_signal(|| false);
+    let current = params.read().clone();
+    let summary = if current.is_empty() {
+        "Workflow defaults".to_string()
+    } else {
+        current.summary()
+    };
+    let resolution_value = match (current.width, current.height) {
+        (Some(w), Some(h)) => format!("{}x{}", w, h),
+        _ => String::new(),
+    };
+
+    rsx! {
+        div { class: "mb-4",
+            button {
+                r#type: "button",
+                onclick: move |_| {
+                    let now = *expanded.read();
+                    expanded.set(!now);
+                },
+                class: "w-full flex justify-between items-center p-0 bg-transparent border-0 cursor-pointer text-gray-400 text-sm",
+                span { if *expanded.read() { "▾ Advanced" } else { "▸ Advanced" } }
+                span { class: "text-gray-500 text-xs", "{summary}" }
+            }
+
+            if *expanded.read() {
+                div {
+                    class: "grid grid-cols-2 gap-3 mt-2 p-3 bg-dark-bg border border-gray-700 rounded",
+
+                    // Seed
+                    div {
+                        label { class: "block text-gray-400 text-xs mb-1", "Seed" }
+                        div { class: "flex gap-1",
+                            input {
+                                r#type: "number",
+                                min: "0",
+                                value: "{display_opt(current.seed)}",
+                                oninput: move |e| params.write().seed = parse_opt(&e.value()),
+                                placeholder: "Random",
+                                class: "flex-1 min-w-0 p-1.5 bg-dark-surface border border-gray-700 rounded text-white text-sm",
+                            }
+                            button {
+                                r#type: "button",
+                                title: "Clear seed (random each run)",
+                                onclick: move |_| params.write().seed = None,
+                                class: "px-2 bg-gray-700 text-white border-0 rounded cursor-pointer text-xs",
+                                "🎲"
+                            }
+                        }
+                    }
+
+                    // Steps
+                    div {
+                        label { class: "block text-gray-400 text-xs mb-1", "Steps" }
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            max: "150",
+                            value: "{display_opt(current.steps)}",
+                            oninput: move |e| params.write().steps = parse_opt(&e.value()),
+                            placeholder: "Default",
+                            class: "w-full p-1.5 bg-dark-surface border border-gray-700 rounded text-white text-sm box-border",
+                        }
+                    }
+
+                    // CFG scale
+                    div {
+                        label { class: "block text-gray-400 text-xs mb-1", "CFG Scale" }
+                        input {
+                            r#type: "number",
+                            min: "1",
+                            max: "30",
+                            step: "0.5",
+                            value: "{display_opt(current.cfg_scale)}",
+                            oninput: move |e| params.write().cfg_scale = parse_opt(&e.value()),
+                            placeholder: "Default",
+                            class: "w-full p-1.5 bg-dark-surface border border-gray-700 rounded text-white text-sm box-border",
+                        }
+                    }
+
+                    // Resolution
+                    div {
+                        label { class: "block text-gray-400 text-xs mb-1", "Resolution" }
+                        select {
+                            value: "{resolution_value}",
+                            onchange: move |e| {
+                                let value = e.value();
+                                let mut p = params.write();
+                                match value.split_once('x') {
+                                    Some((w, h)) => {
+                                        p.width = parse_opt(w);
+                                        p.height = parse_opt(h);
+                                    }
+                                    None => {
+                                        p.width = None;
+                                        p.height = None;
+                                    }
+                                }
+                            },
+                            class: "w-full p-1.5 bg-dark-surface border border-gray-700 rounded text-white text-sm",
+                            option { value: "", "Default" }
+                            for (w, h) in RESOLUTION_PRESETS.iter() {
+                                option { value: "{w}x{h}", "{w} × {h}" }
+                            }
+                        }
+                    }
+
+                    if !current.is_empty() {
+                        div { class: "col-span-2 flex justify-end",
+                            button {
+                                r#type: "button",
+                                onclick: move |_| params.set(GenerationParams::default()),
+                                class: "px-2 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-xs",
+                                "Reset to defaults"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
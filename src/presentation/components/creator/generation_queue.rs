@@ -3,7 +3,9 @@
 use dioxus::prelude::*;
 
 use crate::application::ports::outbound::Platform;
-use crate::presentation::state::{use_generation_state, use_game_state, BatchStatus, GenerationBatch, SuggestionStatus, SuggestionTask};
+use crate::application::services::{FilterPreset, FilterPresetScope, SuggestionFeedback, SuggestionRating};
+use crate::presentation::components::common::FilterPresetBar;
+use crate::presentation::state::{format_eta, use_generation_state, use_game_state, BatchEta, BatchStatus, GenerationBatch, SuggestionStatus, SuggestionTask, use_modal_focus};
 use crate::presentation::services::{
     visible_batches,
     visible_suggestions,
@@ -23,6 +25,25 @@ enum QueueFilter {
     Suggestions,
 }
 
+impl QueueFilter {
+    /// Value stored in filter presets (`None` for All)
+    fn preset_value(&self) -> Option<String> {
+        match self {
+            Self::All => None,
+            Self::Images => Some("images".to_string()),
+            Self::Suggestions => Some("suggestions".to_string()),
+        }
+    }
+
+    fn from_preset_value(value: Option<&str>) -> Self {
+        match value {
+            Some("images") => Self::Images,
+            Some("suggestions") => Self::Suggestions,
+            _ => Self::All,
+        }
+    }
+}
+
 /// Sort order for the generation queue
 #[derive(Clone, Copy, PartialEq, Eq, Default)]
 enum SortOrder {
@@ -53,11 +74,28 @@ pub fn GenerationQueuePanel(props: GenerationQueuePanelProps) -> Element {
     let mut show_read: Signal<bool> = use_signal(|| false);
     let mut active_filter: Signal<QueueFilter> = use_signal(|| QueueFilter::All);
     let mut sort_order: Signal<SortOrder> = use_signal(|| SortOrder::NewestFirst);
+    let mut search_query = use_signal(String::new);
 
     let show_read_val = *show_read.read();
     let filter_val = *active_filter.read();
-    let all_batches = visible_batches(&generation_state, show_read_val);
-    let all_suggestions = visible_suggestions(&generation_state, show_read_val);
+    let search = search_query.read().trim().to_lowercase();
+    let all_batches: Vec<GenerationBatch> = visible_batches(&generation_state, show_read_val)
+        .into_iter()
+        .filter(|b| {
+            search.is_empty()
+                || [&b.entity_type, &b.entity_id, &b.asset_type]
+                    .iter()
+                    .any(|field| field.to_lowercase().contains(&search))
+        })
+        .collect();
+    let all_suggestions: Vec<SuggestionTask> = visible_suggestions(&generation_state, show_read_val)
+        .into_iter()
+        .filter(|s| {
+            search.is_empty()
+                || s.field_type.to_lowercase().contains(&search)
+                || s.entity_id.as_deref().is_some_and(|id| id.to_lowercase().contains(&search))
+        })
+        .collect();
     
     // Compute counts before filtering
     let batch_count = all_batches.len();
@@ -118,6 +156,9 @@ pub fn GenerationQueuePanel(props: GenerationQueuePanelProps) -> Element {
     let active_suggestion_count = generation_state.active_suggestion_count();
     let total_active = active_batch_count + active_suggestion_count;
 
+    // Estimated times, learned from recent completions per workflow slot
+    let queue_eta = generation_state.queue_eta(platform.now_unix_secs());
+
     // Derive world_id from game state if available (for scoping read markers)
     let world_id = game_state
         .world
@@ -125,8 +166,16 @@ pub fn GenerationQueuePanel(props: GenerationQueuePanelProps) -> Element {
         .as_ref()
         .map(|w| w.world.id.clone());
 
+    // Queue filter and search as a preset
+    let current_filters = FilterPreset {
+        type_filter: filter_val.preset_value(),
+        search: search_query.read().clone(),
+        ..Default::default()
+    };
+
     rsx! {
         div {
+            "data-tour": "creator-queue",
             class: "generation-queue bg-dark-surface rounded-lg p-3",
 
             // Header with filter tabs and toggle for read items
@@ -188,6 +237,37 @@ pub fn GenerationQueuePanel(props: GenerationQueuePanelProps) -> Element {
                         span { "Show read" }
                     }
                 }
+
+                if active_batch_count > 0 {
+                    div {
+                        class: "mb-2 px-2 py-1 bg-amber-500/10 rounded text-amber-400 text-xs",
+                        "⏱ Queue will drain in {format_eta(queue_eta.drain_secs)}"
+                    }
+                }
+
+                input {
+                    r#type: "text",
+                    placeholder: "Search by entity or field...",
+                    value: "{search_query}",
+                    oninput: move |e| search_query.set(e.value()),
+                    class: "w-full mb-2 px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-xs box-border",
+                }
+
+                // Saved filter presets (per world)
+                if let Some(ref world_id) = world_id {
+                    div {
+                        class: "mb-2",
+                        FilterPresetBar {
+                            scope: FilterPresetScope::GenerationQueue,
+                            world_id: world_id.clone(),
+                            current: current_filters,
+                            on_apply: move |preset: FilterPreset| {
+                                active_filter.set(QueueFilter::from_preset_value(preset.type_filter.as_deref()));
+                                search_query.set(preset.search);
+                            },
+                        }
+                    }
+                }
                 
                 // Filter tabs and sort dropdown
                 div {
@@ -253,6 +333,7 @@ pub fn GenerationQueuePanel(props: GenerationQueuePanelProps) -> Element {
                     for batch in visible_batches.iter() {
                         QueueItemRow {
                             batch: batch.clone(),
+                            eta: queue_eta.batches.get(&batch.batch_id).copied(),
                             show_read: show_read_val,
                             world_id: world_id.clone(),
                             on_navigate_to_entity: props.on_navigate_to_entity.clone(),
@@ -299,7 +380,7 @@ fn status_priority(status: &BatchStatus) -> u8 {
 /// Helper function to get suggestion status priority for sorting
 fn suggestion_status_priority(status: &SuggestionStatus) -> u8 {
     match status {
-        SuggestionStatus::Queued | SuggestionStatus::Processing => 3, // Active items first
+        SuggestionStatus::Queued | SuggestionStatus::Processing | SuggestionStatus::Streaming { .. } => 3, // Active items first
         SuggestionStatus::Ready { .. } => 2,
         SuggestionStatus::Failed { .. } => 1,
     }
@@ -335,6 +416,9 @@ fn FilterTab(
 #[component]
 fn QueueItemRow(
     batch: GenerationBatch,
+    /// Estimated timing while the batch is queued or generating
+    #[props(default)]
+    eta: Option<BatchEta>,
     #[props(default = false)] show_read: bool,
     world_id: Option<String>,
     #[props(default)]
@@ -346,8 +430,14 @@ fn QueueItemRow(
     let mut expanded_details: Signal<bool> = use_signal(|| false);
     let batch_id = batch.batch_id.clone();
     let (status_icon, status_color, status_text) = match &batch.status {
-                    BatchStatus::Queued { position } => ("🖼️", "#9ca3af", format!("#{} in queue", position)),
-                    BatchStatus::Generating { progress } => ("⚙️", "#f59e0b", format!("{}%", progress)),
+                    BatchStatus::Queued { position } => ("🖼️", "#9ca3af", match eta {
+                        Some(eta) => format!("#{} in queue · starts in {}", position, format_eta(eta.wait_secs)),
+                        None => format!("#{} in queue", position),
+                    }),
+                    BatchStatus::Generating { progress } => ("⚙️", "#f59e0b", match eta {
+                        Some(eta) => format!("{}% · {} left", progress, format_eta(eta.done_secs)),
+                        None => format!("{}%", progress),
+                    }),
                     BatchStatus::Ready { asset_count } => ("✅", "#22c55e", format!("{} ready", asset_count)),
                     BatchStatus::Failed { error: _ } => ("❌", "#ef4444", "Failed".into()),
                 };
@@ -375,7 +465,8 @@ fn QueueItemRow(
                         "{display_name}"
                     }
                     div { class: "text-gray-500 text-xs",
-                        "{batch.asset_type}"
+                        "{batch.asset_type} · "
+                        span { style: format!("color: {};", status_color), "{status_text}" }
                     }
                 }
 
@@ -608,6 +699,12 @@ fn QueueItemRow(
                         class: "text-gray-400 text-xs",
                         "Batch ID: {batch.batch_id}"
                     }
+                    if let Some(params) = batch.params.as_ref().filter(|p| !p.is_empty()) {
+                        div {
+                            class: "text-gray-400 text-xs mt-2",
+                            "Parameters: {params.summary()}"
+                        }
+                    }
                 }
             }
         }
@@ -631,6 +728,7 @@ fn SuggestionQueueRow(
     let (status_icon, status_color, status_text) = match &suggestion.status {
         SuggestionStatus::Queued => ("💭", "#9ca3af", "Queued".to_string()),
         SuggestionStatus::Processing => ("⚙️", "#f59e0b", "Processing".to_string()),
+        SuggestionStatus::Streaming { .. } => ("✍️", "#a855f7", "Writing".to_string()),
         SuggestionStatus::Ready { suggestions: results } => {
             ("✅", "#22c55e", format!("{} ready", results.len()))
         }
@@ -719,8 +817,19 @@ fn SuggestionQueueRow(
                             "Clear"
                         }
                     },
-                    SuggestionStatus::Queued | SuggestionStatus::Processing => rsx! {
+                    SuggestionStatus::Queued | SuggestionStatus::Processing | SuggestionStatus::Streaming { .. } => rsx! {
                         span { style: format!("color: {}; font-size: 0.75rem;", status_color), "{status_text}" }
+                        // Watch the text arrive
+                        if matches!(suggestion.status, SuggestionStatus::Streaming { .. }) {
+                            button {
+                                onclick: {
+                                    let suggestion = suggestion.clone();
+                                    move |_| selected_suggestion.set(Some(suggestion.clone()))
+                                },
+                                class: "px-1.5 py-0.5 bg-purple-500 text-white border-none rounded cursor-pointer text-[0.625rem]",
+                                "View"
+                            }
+                        }
                         button {
                             onclick: {
                                 let request_id = suggestion.request_id.clone();
@@ -760,6 +869,7 @@ fn SuggestionQueueRow(
                             onclick: {
                                 let request_id = suggestion.request_id.clone();
                                 let field_type = suggestion.field_type.clone();
+                                let entity_id = suggestion.entity_id.clone();
                                 let context = suggestion.context.clone();
                                 let world_id = suggestion.world_id.clone();
                                 let suggestion_service = use_suggestion_service();
@@ -768,10 +878,11 @@ fn SuggestionQueueRow(
                                     if let (Some(ctx), Some(wid)) = (context.clone(), world_id.clone()) {
                                         let req_id = request_id.clone();
                                         let field = field_type.clone();
+                                        let entity_id = entity_id.clone();
                                         let svc = suggestion_service.clone();
                                         let mut gen_state = state;
                                         spawn(async move {
-                                            match svc.enqueue_suggestion(&field, &wid, &ctx).await {
+                                            match svc.enqueue_suggestion(&field, &wid, entity_id.as_deref(), &ctx).await {
                                                 Ok(new_request_id) => {
                                                     tracing::info!("Retried suggestion {} -> {}", req_id, new_request_id);
                                                     // Remove old failed suggestion
@@ -780,7 +891,7 @@ fn SuggestionQueueRow(
                                                     gen_state.add_suggestion_task(
                                                         new_request_id,
                                                         field,
-                                                        None,
+                                                        entity_id,
                                                         Some(ctx),
                                                         Some(wid),
                                                     );
@@ -834,16 +945,66 @@ fn SuggestionQueueRow(
     }
 }
 
-/// Modal displaying full suggestion options for a selected task
+/// Modal displaying full suggestion options for a selected task, with
+/// feedback on them for the Engine
 #[component]
 fn SuggestionViewModal(suggestion: SuggestionTask, on_close: EventHandler<()>) -> Element {
-    // Extract suggestions if ready
-    let suggestions = match &suggestion.status {
-        SuggestionStatus::Ready { suggestions } => suggestions.clone(),
-        _ => Vec::new(),
+    use_modal_focus("suggestion-view-modal");
+    let platform = use_context::<Platform>();
+    let suggestion_service = use_suggestion_service();
+    let mut generation_state = use_generation_state();
+    let mut comment = use_signal(String::new);
+    let mut feedback_sent = use_signal(|| false);
+
+    // Status, applied option and rating may change while the modal is open,
+    // e.g. as streamed text arrives
+    let request_id = suggestion.request_id.clone();
+    let current = generation_state
+        .get_suggestions()
+        .into_iter()
+        .find(|s| s.request_id == request_id);
+    let status = current.as_ref().map(|s| s.status.clone()).unwrap_or_else(|| suggestion.status.clone());
+    let suggestions = status.texts().to_vec();
+    let is_streaming = matches!(status, SuggestionStatus::Streaming { .. });
+    let cancel_service = suggestion_service.clone();
+    let applied_index = current.as_ref().and_then(|s| s.applied_index);
+    let rating = current.as_ref().and_then(|s| s.rating);
+
+    let send_feedback = {
+        let request_id = request_id.clone();
+        move |feedback: SuggestionFeedback| {
+            let service = suggestion_service.clone();
+            let platform = platform.clone();
+            let request_id = request_id.clone();
+            spawn(async move {
+                match service.send_feedback(&request_id, &feedback).await {
+                    Ok(()) => feedback_sent.set(true),
+                    Err(e) => platform.log_warn(&format!("Failed to send suggestion feedback: {}", e)),
+                }
+            });
+        }
+    };
+    let rate = {
+        let request_id = request_id.clone();
+        let send_feedback = send_feedback.clone();
+        move |choice: SuggestionRating| {
+            // Clicking the active thumb again clears it
+            let next = (rating != Some(choice)).then_some(choice);
+            generation_state.rate_suggestion(&request_id, next);
+            if next.is_some() {
+                send_feedback(SuggestionFeedback { rating: next, ..Default::default() });
+            }
+        }
     };
 
     let title = format!("Suggestions for {}", suggestion.field_type.replace("_", " "));
+    let thumb_class = |active: bool| {
+        if active {
+            "px-2 py-1 bg-purple-500/30 border border-purple-400 rounded-md text-sm cursor-pointer"
+        } else {
+            "px-2 py-1 bg-transparent border border-gray-700 rounded-md text-sm cursor-pointer"
+        }
+    };
 
     rsx! {
         // Backdrop
@@ -853,6 +1014,9 @@ fn SuggestionViewModal(suggestion: SuggestionTask, on_close: EventHandler<()>) -
 
             // Modal content
             div {
+                id: "suggestion-view-modal",
+                role: "dialog",
+                aria_modal: "true",
                 onclick: move |evt| evt.stop_propagation(),
                 class: "bg-gray-900 rounded-lg p-4 px-5 max-w-[480px] w-full max-h-[70vh] overflow-y-auto shadow-2xl",
 
@@ -879,13 +1043,102 @@ fn SuggestionViewModal(suggestion: SuggestionTask, on_close: EventHandler<()>) -
                         for (idx, text) in suggestions.iter().enumerate() {
                             div {
                                 key: "{idx}",
-                                class: "px-3 py-2 bg-gray-800 rounded-md text-gray-200 text-sm",
+                                class: if applied_index == Some(idx) {
+                                    "px-3 py-2 bg-gray-800 rounded-md text-gray-200 text-sm border border-green-500/60"
+                                } else {
+                                    "px-3 py-2 bg-gray-800 rounded-md text-gray-200 text-sm"
+                                },
+                                if applied_index == Some(idx) {
+                                    span { class: "float-right ml-2 text-green-400 text-xs", "✓ Applied" }
+                                }
                                 "{text}"
                             }
                         }
                     }
                 }
 
+                if is_streaming {
+                    div {
+                        class: "flex items-center justify-between gap-2 mt-3",
+                        span { class: "text-purple-400 text-xs animate-pulse", "Still writing..." }
+                        button {
+                            onclick: {
+                                let request_id = request_id.clone();
+                                move |_| {
+                                    let svc = cancel_service.clone();
+                                    let request_id = request_id.clone();
+                                    // The Engine answers with SuggestionFailed
+                                    spawn(async move {
+                                        if let Err(e) = svc.cancel_suggestion(&request_id).await {
+                                            tracing::error!("Failed to cancel suggestion {}: {}", request_id, e);
+                                        }
+                                    });
+                                }
+                            },
+                            class: "px-2 py-1 bg-red-500 text-white border-none rounded-md text-[0.8rem] cursor-pointer",
+                            "Cancel"
+                        }
+                    }
+                } else if !suggestions.is_empty() {
+                    // Feedback on these suggestions
+                    div {
+                        class: "flex flex-col gap-2 mt-3 pt-3 border-t border-gray-700",
+                        div {
+                            class: "flex items-center gap-2",
+                            span { class: "text-gray-400 text-xs", "Were these useful?" }
+                            button {
+                                class: thumb_class(rating == Some(SuggestionRating::Up)),
+                                aria_label: "Good suggestions",
+                                aria_pressed: "{rating == Some(SuggestionRating::Up)}",
+                                onclick: {
+                                    let mut rate = rate.clone();
+                                    move |_| rate(SuggestionRating::Up)
+                                },
+                                "👍"
+                            }
+                            button {
+                                class: thumb_class(rating == Some(SuggestionRating::Down)),
+                                aria_label: "Poor suggestions",
+                                aria_pressed: "{rating == Some(SuggestionRating::Down)}",
+                                onclick: {
+                                    let mut rate = rate.clone();
+                                    move |_| rate(SuggestionRating::Down)
+                                },
+                                "👎"
+                            }
+                        }
+                        textarea {
+                            value: "{comment}",
+                            placeholder: "What would have made these better?",
+                            aria_label: "Suggestion feedback",
+                            oninput: move |e| comment.set(e.value()),
+                            class: "w-full h-16 p-2 bg-gray-800 border border-gray-700 rounded-md text-gray-200 text-xs resize-y box-border",
+                        }
+                        div {
+                            class: "flex items-center justify-end gap-2",
+                            if *feedback_sent.read() {
+                                span { class: "text-green-400 text-xs", "Thanks, feedback sent" }
+                            }
+                            button {
+                                disabled: comment.read().trim().is_empty(),
+                                onclick: {
+                                    let send_feedback = send_feedback.clone();
+                                    move |_| {
+                                        send_feedback(SuggestionFeedback {
+                                            rating,
+                                            comment: comment.read().trim().to_string(),
+                                            applied_index,
+                                        });
+                                        comment.set(String::new());
+                                    }
+                                },
+                                class: "px-3 py-1 bg-purple-600 text-white border-none rounded-md text-[0.8rem] cursor-pointer disabled:opacity-50",
+                                "Send feedback"
+                            }
+                        }
+                    }
+                }
+
                 div {
                     class: "flex justify-end mt-3",
                     button {
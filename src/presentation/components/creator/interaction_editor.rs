@@ -0,0 +1,381 @@
+//! Interaction Editor - Author the actions players can take in a location
+//!
+//! Each interaction is saved on its own as soon as it is edited, like the
+//! asset gallery, so it only appears once the location itself exists.
+
+use dioxus::prelude::*;
+
+use crate::application::services::{InteractionTemplateData, RegionData, SkillData};
+use crate::presentation::services::{use_location_service, use_skill_service};
+
+/// Interaction types offered in the editor
+const INTERACTION_TYPES: &[&str] = &["Examine", "Search", "Use Object", "Talk", "Travel", "Other"];
+
+/// List and edit the interactions for a location
+#[component]
+pub fn InteractionEditor(world_id: String, location_id: String) -> Element {
+    let loc_service = use_location_service();
+    let skill_service = use_skill_service();
+
+    let mut interactions: Signal<Vec<InteractionTemplateData>> = use_signal(Vec::new);
+    let mut regions: Signal<Vec<RegionData>> = use_signal(Vec::new);
+    let mut skills: Signal<Vec<SkillData>> = use_signal(Vec::new);
+    let mut is_loading = use_signal(|| true);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    // Interaction being edited; `Some` with `id: None` for a new one
+    let mut editing: Signal<Option<InteractionTemplateData>> = use_signal(|| None);
+
+    {
+        let loc_svc = loc_service.clone();
+        let skill_svc = skill_service.clone();
+        let location_id = location_id.clone();
+        let world_id = world_id.clone();
+        use_effect(move || {
+            let loc_svc = loc_svc.clone();
+            let skill_svc = skill_svc.clone();
+            let location_id = location_id.clone();
+            let world_id = world_id.clone();
+            spawn(async move {
+                match loc_svc.list_interactions(&location_id).await {
+                    Ok(list) => interactions.set(list),
+                    Err(e) => error.set(Some(format!("Failed to load interactions: {}", e))),
+                }
+                // Regions and skills only populate the pickers; failures are non-critical
+                if let Ok(list) = loc_svc.get_regions(&location_id).await {
+                    regions.set(list);
+                }
+                if let Ok(list) = skill_svc.list_skills(&world_id).await {
+                    skills.set(list);
+                }
+                is_loading.set(false);
+            });
+        });
+    }
+
+    let region_name = move |id: &Option<String>| -> String {
+        match id {
+            Some(id) => regions
+                .read()
+                .iter()
+                .find(|r| r.id == *id)
+                .map(|r| r.name.clone())
+                .unwrap_or_else(|| "Unknown region".to_string()),
+            None => "Whole location".to_string(),
+        }
+    };
+    let skill_name = move |id: &Option<String>| -> Option<String> {
+        let id = id.as_ref()?;
+        skills.read().iter().find(|s| s.id == *id).map(|s| s.name.clone())
+    };
+
+    let new_interaction = {
+        let location_id = location_id.clone();
+        move || InteractionTemplateData {
+            id: None,
+            location_id: location_id.clone(),
+            region_id: None,
+            name: String::new(),
+            interaction_type: INTERACTION_TYPES[0].to_string(),
+            target_name: None,
+            required_skill_id: None,
+            difficulty: None,
+            success_outcome: String::new(),
+            failure_outcome: String::new(),
+            is_available: true,
+        }
+    };
+
+    rsx! {
+        div {
+            class: "interaction-editor flex flex-col gap-2",
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "p-2 bg-red-500 bg-opacity-10 text-red-500 text-sm rounded", "{err}" }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-500 text-sm", "Loading interactions..." }
+            } else {
+                if interactions.read().is_empty() && editing.read().is_none() {
+                    div { class: "text-gray-500 text-sm italic", "No interactions yet. Players can only move and talk here." }
+                }
+
+                for interaction in interactions.read().iter().cloned() {
+                    div {
+                        key: "{interaction.id.clone().unwrap_or_default()}",
+                        class: "flex items-start gap-3 p-2 bg-dark-bg rounded",
+                        div {
+                            class: "flex-1 min-w-0",
+                            div {
+                                class: "flex items-center gap-2",
+                                span { class: "text-white text-sm font-medium", "{interaction.name}" }
+                                span { class: "text-xs text-gray-500", "{interaction.interaction_type}" }
+                                if !interaction.is_available {
+                                    span { class: "text-xs text-amber-500", "Hidden" }
+                                }
+                            }
+                            div {
+                                class: "text-xs text-gray-400",
+                                "{region_name(&interaction.region_id)}"
+                                if let Some(target) = interaction.target_name.as_ref() {
+                                    " · {target}"
+                                }
+                                if let Some(skill) = skill_name(&interaction.required_skill_id) {
+                                    " · {skill}"
+                                    if let Some(dc) = interaction.difficulty {
+                                        " DC {dc}"
+                                    }
+                                }
+                            }
+                        }
+                        button {
+                            r#type: "button",
+                            class: "px-2 py-1 bg-transparent text-blue-400 border-0 text-xs cursor-pointer",
+                            onclick: {
+                                let interaction = interaction.clone();
+                                move |_| editing.set(Some(interaction.clone()))
+                            },
+                            "Edit"
+                        }
+                        button {
+                            r#type: "button",
+                            class: "px-2 py-1 bg-transparent text-red-400 border-0 text-xs cursor-pointer",
+                            onclick: {
+                                let loc_svc = loc_service.clone();
+                                let id = interaction.id.clone();
+                                move |_| {
+                                    let Some(id) = id.clone() else { return };
+                                    let svc = loc_svc.clone();
+                                    spawn(async move {
+                                        match svc.delete_interaction(&id).await {
+                                            Ok(()) => interactions.write().retain(|i| i.id.as_deref() != Some(id.as_str())),
+                                            Err(e) => error.set(Some(format!("Delete failed: {}", e))),
+                                        }
+                                    });
+                                }
+                            },
+                            "Delete"
+                        }
+                    }
+                }
+
+                if let Some(draft) = editing.read().clone() {
+                    InteractionForm {
+                        key: "{draft.id.clone().unwrap_or_default()}",
+                        interaction: draft,
+                        regions: regions.read().clone(),
+                        skills: skills.read().clone(),
+                        on_cancel: move |_| editing.set(None),
+                        on_save: {
+                            let loc_svc = loc_service.clone();
+                            let location_id = location_id.clone();
+                            move |data: InteractionTemplateData| {
+                                let svc = loc_svc.clone();
+                                let location_id = location_id.clone();
+                                spawn(async move {
+                                    let result = match data.id.clone() {
+                                        Some(id) => svc.update_interaction(&id, &data).await,
+                                        None => svc.create_interaction(&location_id, &data).await,
+                                    };
+                                    match result {
+                                        Ok(saved) => {
+                                            let mut list = interactions.write();
+                                            match list.iter_mut().find(|i| i.id.is_some() && i.id == saved.id) {
+                                                Some(existing) => *existing = saved,
+                                                None => list.push(saved),
+                                            }
+                                            drop(list);
+                                            error.set(None);
+                                            editing.set(None);
+                                        }
+                                        Err(e) => error.set(Some(format!("Save failed: {}", e))),
+                                    }
+                                });
+                            }
+                        },
+                    }
+                } else {
+                    button {
+                        r#type: "button",
+                        class: "self-start px-3 py-1.5 bg-blue-500 text-white border-0 rounded text-sm cursor-pointer",
+                        onclick: move |_| editing.set(Some(new_interaction())),
+                        "+ Add Interaction"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Inline form for a single interaction
+#[component]
+fn InteractionForm(
+    interaction: InteractionTemplateData,
+    regions: Vec<RegionData>,
+    skills: Vec<SkillData>,
+    on_save: EventHandler<InteractionTemplateData>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let mut draft = use_signal(|| interaction.clone());
+    let mut validation: Signal<Option<String>> = use_signal(|| None);
+
+    let d = draft.read().clone();
+    let needs_check = d.required_skill_id.is_some();
+    let input_class = "w-full p-2 bg-dark-surface border border-gray-700 rounded text-white text-sm box-border";
+    let label_class = "block text-gray-400 text-xs mb-1";
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-3 p-3 bg-dark-bg border border-gray-700 rounded",
+
+            div { class: "grid grid-cols-2 gap-3",
+                div {
+                    label { class: "{label_class}", "Name *" }
+                    input {
+                        r#type: "text",
+                        class: "{input_class}",
+                        placeholder: "e.g., Read the ledger",
+                        value: "{d.name}",
+                        oninput: move |e| draft.write().name = e.value(),
+                    }
+                }
+                div {
+                    label { class: "{label_class}", "Type" }
+                    select {
+                        class: "{input_class}",
+                        value: "{d.interaction_type}",
+                        onchange: move |e| draft.write().interaction_type = e.value(),
+                        for t in INTERACTION_TYPES.iter() {
+                            option { value: "{t}", "{t}" }
+                        }
+                    }
+                }
+                div {
+                    label { class: "{label_class}", "Target" }
+                    input {
+                        r#type: "text",
+                        class: "{input_class}",
+                        placeholder: "e.g., Dusty ledger",
+                        value: "{d.target_name.clone().unwrap_or_default()}",
+                        oninput: move |e| {
+                            let v = e.value();
+                            draft.write().target_name = if v.trim().is_empty() { None } else { Some(v) };
+                        },
+                    }
+                }
+                div {
+                    label { class: "{label_class}", "Where" }
+                    select {
+                        class: "{input_class}",
+                        value: "{d.region_id.clone().unwrap_or_default()}",
+                        onchange: move |e| {
+                            let v = e.value();
+                            draft.write().region_id = if v.is_empty() { None } else { Some(v) };
+                        },
+                        option { value: "", "Whole location" }
+                        for region in regions.iter() {
+                            option { value: "{region.id}", "{region.name}" }
+                        }
+                    }
+                }
+                div {
+                    label { class: "{label_class}", "Required Skill" }
+                    select {
+                        class: "{input_class}",
+                        value: "{d.required_skill_id.clone().unwrap_or_default()}",
+                        onchange: move |e| {
+                            let v = e.value();
+                            let mut w = draft.write();
+                            w.required_skill_id = if v.is_empty() { None } else { Some(v) };
+                            if w.required_skill_id.is_none() {
+                                w.difficulty = None;
+                            }
+                        },
+                        option { value: "", "None (always succeeds)" }
+                        for skill in skills.iter().filter(|s| !s.is_hidden) {
+                            option { value: "{skill.id}", "{skill.name}" }
+                        }
+                    }
+                }
+                div {
+                    label { class: "{label_class}", "Difficulty" }
+                    input {
+                        r#type: "number",
+                        min: "0",
+                        class: "{input_class}",
+                        disabled: !needs_check,
+                        placeholder: "DC",
+                        value: "{d.difficulty.map(|v| v.to_string()).unwrap_or_default()}",
+                        oninput: move |e| draft.write().difficulty = e.value().trim().parse().ok(),
+                    }
+                }
+            }
+
+            div {
+                label {
+                    class: "{label_class}",
+                    if needs_check { "Success Outcome" } else { "Outcome" }
+                }
+                textarea {
+                    class: "{input_class} min-h-[60px] resize-y",
+                    placeholder: "What the player learns or what happens...",
+                    value: "{d.success_outcome}",
+                    oninput: move |e| draft.write().success_outcome = e.value(),
+                }
+            }
+
+            if needs_check {
+                div {
+                    label { class: "{label_class}", "Failure Outcome" }
+                    textarea {
+                        class: "{input_class} min-h-[60px] resize-y",
+                        placeholder: "What happens on a failed check...",
+                        value: "{d.failure_outcome}",
+                        oninput: move |e| draft.write().failure_outcome = e.value(),
+                    }
+                }
+            }
+
+            label {
+                class: "flex items-center gap-2 text-gray-400 text-sm cursor-pointer",
+                input {
+                    r#type: "checkbox",
+                    checked: d.is_available,
+                    onchange: move |e| draft.write().is_available = e.checked(),
+                }
+                "Available to players"
+            }
+
+            if let Some(msg) = validation.read().as_ref() {
+                div { class: "text-red-500 text-xs", "{msg}" }
+            }
+
+            div { class: "flex gap-2",
+                button {
+                    r#type: "button",
+                    class: "px-3 py-1.5 bg-green-500 text-white border-0 rounded text-sm cursor-pointer",
+                    onclick: move |_| {
+                        let data = draft.read().clone();
+                        if data.name.trim().is_empty() {
+                            validation.set(Some("Interaction name is required".to_string()));
+                            return;
+                        }
+                        if data.required_skill_id.is_some() && data.difficulty.is_none() {
+                            validation.set(Some("Set a difficulty for the skill check".to_string()));
+                            return;
+                        }
+                        validation.set(None);
+                        on_save.call(data);
+                    },
+                    if interaction.id.is_some() { "Save Interaction" } else { "Add Interaction" }
+                }
+                button {
+                    r#type: "button",
+                    class: "px-3 py-1.5 bg-gray-700 text-white border-0 rounded text-sm cursor-pointer",
+                    onclick: move |_| on_cancel.call(()),
+                    "Cancel"
+                }
+            }
+        }
+    }
+}
@@ -3,10 +3,20 @@
 use dioxus::prelude::*;
 
 use super::asset_gallery::AssetGallery;
+use super::interaction_editor::InteractionEditor;
 use super::suggestion_button::{SuggestionButton, SuggestionContext, SuggestionType};
-use crate::application::services::LocationFormData;
-use crate::presentation::components::common::FormField;
-use crate::presentation::services::use_location_service;
+use crate::application::dto::EntityStyle;
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{
+    archived_stamp, descendant_ids, entity_summary, tree_order, FieldRule, FormRules, FormValues, HistoryEntityKind,
+    LocationFormData, LocationSummary,
+};
+use crate::presentation::components::common::{
+    CopyButton, EntityHistoryDrawer, EntityStylePicker, FormField, RichTextEditor, ShareButton, TagInput, ValidationSummary,
+};
+use crate::presentation::services::{use_location_service, use_world_service};
+use crate::presentation::state::use_form_validation;
+use crate::routes::Route;
 
 /// Location types
 const LOCATION_TYPES: &[&str] = &[
@@ -24,16 +34,35 @@ const LOCATION_TYPES: &[&str] = &[
     "Temple",
 ];
 
+/// Field rules for the location form; "id" is the location's own ID
+fn location_rules() -> FormRules {
+    FormRules::new()
+        .field("name", "Location name", vec![FieldRule::Required, FieldRule::Length { min: None, max: Some(100) }])
+        .field(
+            "animated_backdrop",
+            "Animated backdrop",
+            vec![FieldRule::Pattern { pattern: r"^(https?://|/)\S+$", message: "must be a web address or an asset path" }],
+        )
+        .cross("parent", "A location can't be inside itself", |v| {
+            v.get("parent").is_empty() || v.get("parent") != v.get("id")
+        })
+}
+
 /// Location form for creating/editing locations
 #[component]
 pub fn LocationForm(
     location_id: String,
     world_id: String,
     locations_signal: Signal<Vec<crate::application::services::location_service::LocationSummary>>,
+    // Bumped by the surrounding drop zone when an image is uploaded
+    uploads: Signal<u32>,
     on_close: EventHandler<()>,
 ) -> Element {
     let is_new = location_id.is_empty();
+    let suggestion_entity_id = (!is_new).then(|| location_id.clone());
     let loc_service = use_location_service();
+    let platform = use_context::<Platform>();
+    let world_service = use_world_service();
 
     // Form state
     let mut name = use_signal(|| String::new());
@@ -43,11 +72,34 @@ pub fn LocationForm(
     let mut notable_features = use_signal(|| String::new());
     let mut hidden_secrets = use_signal(|| String::new());
     let mut parent_location_id: Signal<Option<String>> = use_signal(|| None);
-    let mut parent_locations: Signal<Vec<LocationFormData>> = use_signal(Vec::new);
+    let mut animated_backdrop = use_signal(|| String::new());
+    let mut parent_locations: Signal<Vec<LocationSummary>> = use_signal(Vec::new);
+    let mut tags: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut style: Signal<EntityStyle> = use_signal(EntityStyle::default);
+    let mut world_tags: Signal<Vec<String>> = use_signal(Vec::new);
     let mut is_loading = use_signal(|| !is_new);
     let mut is_saving = use_signal(|| false);
     let mut success_message: Signal<Option<String>> = use_signal(|| None);
     let mut error_message: Signal<Option<String>> = use_signal(|| None);
+    let mut show_history = use_signal(|| false);
+    let mut validation = use_form_validation();
+    // Bumped after a rollback to reload the location
+    let mut reload = use_signal(|| 0u32);
+
+    // Load existing world tags for tag suggestions (non-critical)
+    {
+        let world_svc = world_service.clone();
+        let world_id_for_tags = world_id.clone();
+        use_effect(move || {
+            let svc = world_svc.clone();
+            let world_id_clone = world_id_for_tags.clone();
+            spawn(async move {
+                if let Ok(loaded) = svc.list_tags(&world_id_clone).await {
+                    world_tags.set(loaded);
+                }
+            });
+        });
+    }
 
     // Load location data if editing existing location
     {
@@ -55,6 +107,7 @@ pub fn LocationForm(
         let loc_svc = loc_service.clone();
         let world_id_for_effect = world_id.clone();
         use_effect(move || {
+            let _ = *reload.read();
             let loc_id = loc_id_for_effect.clone();
             let load_existing = !loc_id.is_empty();
             let world_id_clone = world_id_for_effect.clone();
@@ -63,22 +116,7 @@ pub fn LocationForm(
             spawn(async move {
                     // Load parent locations list
                 if let Ok(parents) = svc.list_locations(&world_id_clone).await {
-                        // Convert LocationSummary to LocationFormData for the dropdown
-                        let parent_data: Vec<LocationFormData> = parents.iter().map(|summary| {
-                            LocationFormData {
-                                id: Some(summary.id.clone()),
-                                name: summary.name.clone(),
-                                description: None,
-                                location_type: summary.location_type.clone(),
-                                atmosphere: None,
-                                notable_features: None,
-                                hidden_secrets: None,
-                                parent_location_id: None,
-                                backdrop_asset: None,
-                                backdrop_regions: Vec::new(),
-                            }
-                        }).collect();
-                        parent_locations.set(parent_data);
+                        parent_locations.set(parents);
                     }
 
                     // Load location data if editing
@@ -92,6 +130,9 @@ pub fn LocationForm(
                             notable_features.set(loc_data.notable_features.unwrap_or_default());
                             hidden_secrets.set(loc_data.hidden_secrets.unwrap_or_default());
                             parent_location_id.set(loc_data.parent_location_id);
+                            animated_backdrop.set(loc_data.animated_backdrop_asset.unwrap_or_default());
+                            tags.set(loc_data.tags);
+                            style.set(loc_data.style);
                             is_loading.set(false);
                         }
                         Err(e) => {
@@ -106,6 +147,40 @@ pub fn LocationForm(
         });
     }
 
+    // Parent choices as an indented tree, without this location or anything inside it
+    let parent_options: Vec<(String, String)> = {
+        let locations = parent_locations.read();
+        let locations: &[LocationSummary] = &locations;
+        let excluded = descendant_ids(locations, &location_id);
+        tree_order(locations)
+            .into_iter()
+            .filter(|(_, l)| l.id != location_id && !excluded.contains(&l.id))
+            .map(|(depth, l)| (l.id.clone(), format!("{}{}", "— ".repeat(depth), l.name)))
+            .collect()
+    };
+
+    let errors = location_rules().validate(
+        &FormValues::new()
+            .with("id", location_id.as_str())
+            .with("name", name.read().as_str())
+            .with("animated_backdrop", animated_backdrop.read().as_str())
+            .with("parent", parent_location_id.read().as_deref().unwrap_or("")),
+    );
+
+    // What the Copy button puts on the clipboard; hidden secrets stay out
+    let summary_text = entity_summary(
+        &name.read(),
+        &location_type.read(),
+        &description.read(),
+        &[
+            ("Atmosphere", atmosphere.read().as_str()),
+            ("Notable features", notable_features.read().as_str()),
+        ],
+    );
+
+    // Archived locations stay editable; the browser lists them under "Archived"
+    let is_archived = locations_signal.read().iter().any(|l| l.id == location_id && l.archived_at.is_some());
+
     rsx! {
         div {
             class: "location-form flex flex-col h-full bg-dark-surface rounded-lg overflow-hidden",
@@ -119,10 +194,76 @@ pub fn LocationForm(
                     if is_new { "New Location" } else { "Edit Location" }
                 }
 
-                button {
-                    onclick: move |_| on_close.call(()),
-                    class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
-                    "×"
+                div {
+                    class: "flex items-center gap-2",
+                    if !is_new {
+                        CopyButton { text: summary_text, label: "Copy summary" }
+                        ShareButton {
+                            route: Route::DMCreatorEntityRoute {
+                                world_id: world_id.clone(),
+                                subtab: "locations".to_string(),
+                                entity_id: location_id.clone(),
+                            },
+                            title: name.read().clone(),
+                            text: format!("{} in WrldBldr", name.read()),
+                        }
+                        button {
+                            onclick: move |_| show_history.set(true),
+                            class: "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm",
+                            "History"
+                        }
+                        button {
+                            onclick: {
+                                let svc = loc_service.clone();
+                                let platform = platform.clone();
+                                let location_id = location_id.clone();
+                                move |_| {
+                                    let svc = svc.clone();
+                                    let platform = platform.clone();
+                                    let location_id = location_id.clone();
+                                    let archive = !is_archived;
+                                    spawn(async move {
+                                        match svc.set_archived(&location_id, archive).await {
+                                            Ok(()) => {
+                                                if let Some(summary) = locations_signal.write().iter_mut().find(|l| l.id == location_id) {
+                                                    summary.archived_at = archive.then(|| archived_stamp(platform.now_unix_secs()));
+                                                }
+                                                success_message.set(Some(if archive {
+                                                    "Location archived".to_string()
+                                                } else {
+                                                    "Location restored from the archive".to_string()
+                                                }));
+                                            }
+                                            Err(e) => error_message.set(Some(format!("Archive failed: {}", e))),
+                                        }
+                                    });
+                                }
+                            },
+                            title: if is_archived { "Show this location in lists and pickers again" } else { "Hide this location from lists and pickers" },
+                            class: "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm",
+                            if is_archived { "Unarchive" } else { "Archive" }
+                        }
+                    }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| on_close.call(()),
+                        class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
+                        "×"
+                    }
+                }
+            }
+
+            if *show_history.read() {
+                EntityHistoryDrawer {
+                    kind: HistoryEntityKind::Location,
+                    entity_id: location_id.clone(),
+                    on_rolled_back: move |_| {
+                        validation.reset();
+                        success_message.set(Some("Rolled back to an earlier version".to_string()));
+                        let next = *reload.peek() + 1;
+                        reload.set(next);
+                    },
+                    on_close: move |_| show_history.set(false),
                 }
             }
 
@@ -152,21 +293,28 @@ pub fn LocationForm(
                 } else {
 
                 // Name field with suggest button
+                ValidationSummary { errors: validation.summary(&errors) }
+
                 FormField {
                     label: "Name",
                     required: true,
+                    error: validation.error(&errors, "name"),
                     children: rsx! {
                         div { class: "flex gap-2",
                             input {
                                 r#type: "text",
                                 value: "{name}",
-                                oninput: move |e| name.set(e.value()),
+                                oninput: move |e| {
+                                    name.set(e.value());
+                                    validation.touch("name");
+                                },
                                 placeholder: "Enter location name...",
                                 class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded text-white",
                             }
                             SuggestionButton {
                                 suggestion_type: SuggestionType::LocationName,
                                 world_id: world_id.clone(),
+                                entity_id: suggestion_entity_id.clone(),
                                 context: SuggestionContext {
                                     entity_type: Some(location_type.read().clone()),
                                     ..Default::default()
@@ -200,16 +348,17 @@ pub fn LocationForm(
                     required: false,
                     children: rsx! {
                         div { class: "flex flex-col gap-2",
-                            textarea {
-                                value: "{description}",
-                                oninput: move |e| description.set(e.value()),
+                            RichTextEditor {
+                                value: description.read().clone(),
+                                on_change: move |value| description.set(value),
+                                world_id: world_id.clone(),
                                 placeholder: "What does this place look like? What stands out?",
-                                class: "w-full min-h-[80px] p-2 bg-dark-bg border border-gray-700 rounded text-white resize-y box-border",
                             }
                             div { class: "flex justify-end",
                                 SuggestionButton {
                                     suggestion_type: SuggestionType::LocationDescription,
                                     world_id: world_id.clone(),
+                                    entity_id: suggestion_entity_id.clone(),
                                     context: SuggestionContext {
                                         entity_name: if name.read().is_empty() { None } else { Some(name.read().clone()) },
                                         entity_type: Some(location_type.read().clone()),
@@ -238,6 +387,7 @@ pub fn LocationForm(
                             SuggestionButton {
                                 suggestion_type: SuggestionType::LocationAtmosphere,
                                 world_id: world_id.clone(),
+                                entity_id: suggestion_entity_id.clone(),
                                 context: SuggestionContext {
                                     entity_name: if name.read().is_empty() { None } else { Some(name.read().clone()) },
                                     entity_type: Some(location_type.read().clone()),
@@ -266,6 +416,7 @@ pub fn LocationForm(
                                 SuggestionButton {
                                     suggestion_type: SuggestionType::LocationFeatures,
                                     world_id: world_id.clone(),
+                                    entity_id: suggestion_entity_id.clone(),
                                     context: SuggestionContext {
                                         entity_name: if name.read().is_empty() { None } else { Some(name.read().clone()) },
                                         entity_type: Some(location_type.read().clone()),
@@ -295,6 +446,7 @@ pub fn LocationForm(
                                 SuggestionButton {
                                     suggestion_type: SuggestionType::LocationSecrets,
                                     world_id: world_id.clone(),
+                                    entity_id: suggestion_entity_id.clone(),
                                     context: SuggestionContext {
                                         entity_name: if name.read().is_empty() { None } else { Some(name.read().clone()) },
                                         entity_type: Some(location_type.read().clone()),
@@ -312,29 +464,88 @@ pub fn LocationForm(
                     FormField {
                         label: "Parent Location",
                         required: false,
+                        error: validation.error(&errors, "parent"),
                         children: rsx! {
                             select {
                                 value: parent_location_id.read().as_deref().unwrap_or(""),
                                 onchange: move |e| {
                                     let val = e.value();
                                     parent_location_id.set(if val.is_empty() { None } else { Some(val) });
+                                    validation.touch("parent");
                                 },
                                 class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white",
 
                                 option { value: "", "None" }
-                                for parent in parent_locations.read().iter() {
-                                    // Don't allow selecting self as parent
-                                    if parent.id.as_ref() != Some(&location_id) {
-                                        option {
-                                            value: "{parent.id.as_ref().unwrap_or(&String::new())}",
-                                            "{parent.name}"
-                                        }
+                                for (id, label) in parent_options.iter() {
+                                    option {
+                                        value: "{id}",
+                                        "{label}"
                                     }
                                 }
                             }
                         }
                     }
 
+                    // Animated backdrop field
+                    FormField {
+                        label: "Animated Backdrop",
+                        required: false,
+                        error: validation.error(&errors, "animated_backdrop"),
+                        children: rsx! {
+                            input {
+                                r#type: "text",
+                                value: "{animated_backdrop}",
+                                oninput: move |e| {
+                                    animated_backdrop.set(e.value());
+                                    validation.touch("animated_backdrop");
+                                },
+                                placeholder: "Looping video or animated image URL, shown instead of the backdrop",
+                                class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white",
+                            }
+                        }
+                    }
+
+                    // Tags field
+                    FormField {
+                        label: "Tags",
+                        required: false,
+                        children: rsx! {
+                            TagInput {
+                                tags: tags.read().clone(),
+                                suggestions: world_tags.read().clone(),
+                                on_change: move |next| tags.set(next),
+                            }
+                        }
+                    }
+
+                    // Icon and colour shown in lists, the timeline and graphs
+                    FormField {
+                        label: "Icon & Colour",
+                        required: false,
+                        children: rsx! {
+                            EntityStylePicker {
+                                style: style.read().clone(),
+                                on_change: move |next| style.set(next),
+                            }
+                        }
+                    }
+
+                    // Interactions section
+                    div {
+                        class: "interactions-section mt-4",
+
+                        h3 { class: "text-gray-400 text-sm uppercase mb-3", "Interactions" }
+
+                        if is_new {
+                            p { class: "text-gray-500 text-sm italic m-0", "Create the location first to add interactions." }
+                        } else {
+                            InteractionEditor {
+                                world_id: world_id.clone(),
+                                location_id: location_id.clone(),
+                            }
+                        }
+                    }
+
                     // Asset Gallery section
                     div {
                         class: "assets-section mt-4",
@@ -345,6 +556,7 @@ pub fn LocationForm(
                             world_id: world_id.clone(),
                             entity_type: "location".to_string(),
                             entity_id: location_id.clone(),
+                            uploads: Some(uploads),
                         }
                     }
                 }
@@ -369,10 +581,9 @@ pub fn LocationForm(
                     disabled: *is_saving.read(),
                     onclick: {
                         let loc_svc = loc_service.clone();
+                        let errors = errors.clone();
                         move |_| {
-                            let loc_name = name.read().clone();
-                            if loc_name.is_empty() {
-                                error_message.set(Some("Location name is required".to_string()));
+                            if !validation.gate(&errors) {
                                 return;
                             }
 
@@ -411,7 +622,14 @@ pub fn LocationForm(
                                         },
                                         parent_location_id: parent_location_id.read().clone(),
                                         backdrop_asset: None,
+                                        // An empty value clears an existing animated backdrop
+                                        animated_backdrop_asset: {
+                                            let ab = animated_backdrop.read().trim().to_string();
+                                            if ab.is_empty() && is_new { None } else { Some(ab) }
+                                        },
                                         backdrop_regions: Vec::new(),
+                                        tags: tags.read().clone(),
+                                        style: style.read().clone(),
                                     };
 
                                     match if is_new {
@@ -427,6 +645,11 @@ pub fn LocationForm(
                                                     id: saved_location.id.clone().unwrap_or_default(),
                                                     name: saved_location.name.clone(),
                                                     location_type: saved_location.location_type.clone(),
+                                                    parent_location_id: saved_location.parent_location_id.clone(),
+                                                    tags: saved_location.tags.clone(),
+                                                    deleted_at: None,
+                                                    archived_at: None,
+                                                    style: saved_location.style.clone(),
                                                 };
                                                 locations_signal.write().push(summary);
                                             } else {
@@ -436,6 +659,9 @@ pub fn LocationForm(
                                                     if let Some(existing) = locs.iter_mut().find(|l| l.id == *id) {
                                                         existing.name = saved_location.name.clone();
                                                         existing.location_type = saved_location.location_type.clone();
+                                                        existing.parent_location_id = saved_location.parent_location_id.clone();
+                                                        existing.tags = saved_location.tags.clone();
+                                                        existing.style = saved_location.style.clone();
                                                     }
                                                 }
                                             }
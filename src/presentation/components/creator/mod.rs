@@ -6,26 +6,46 @@
 pub mod entity_browser;
 pub mod character_form;
 pub mod location_form;
+pub mod interaction_editor;
 pub mod asset_gallery;
+pub mod asset_drop_zone;
 pub mod generation_queue;
+pub mod generation_params_input;
 pub mod suggestion_button;
 pub mod sheet_field_input;
 pub mod comfyui_banner;
+pub mod outline_generator;
+pub mod routine_editor;
+pub mod relationship_editor;
+pub mod encounter_form;
+pub mod recipe_form;
+pub mod faction_form;
 
 use dioxus::prelude::*;
 use crate::application::ports::outbound::Platform;
 use crate::presentation::state::use_session_state;
 use crate::presentation::state::use_generation_state;
+use crate::presentation::state::use_paged_list;
+use crate::application::ports::outbound::PageRequest;
 use crate::presentation::services::use_generation_service;
+use crate::application::services::TourView;
+use crate::presentation::components::shared::OnboardingTour;
+use crate::routes::Route;
+
+/// Entity ID in the route that opens an empty form, e.g. `.../creator/characters/new`
+pub const NEW_ENTITY_ID: &str = "new";
 
 /// Props for CreatorMode
 #[derive(Props, Clone, PartialEq)]
 pub struct CreatorModeProps {
     /// World ID from the route
     pub world_id: String,
-    /// Currently selected tab from route (characters, locations, items, maps)
+    /// Currently selected tab from route (characters, locations, items, maps, encounters, recipes, factions)
     #[props(default)]
     pub selected_tab: Option<String>,
+    /// Entity whose form is open, from the route
+    #[props(default)]
+    pub selected_entity_id: Option<String>,
 }
 
 /// The main Creator Mode container component
@@ -37,63 +57,140 @@ pub fn CreatorMode(props: CreatorModeProps) -> Element {
         Some("locations") => EntityTypeTab::Locations,
         Some("items") => EntityTypeTab::Items,
         Some("maps") => EntityTypeTab::Maps,
+        Some("encounters") => EntityTypeTab::Encounters,
+        Some("recipes") => EntityTypeTab::Recipes,
+        Some("factions") => EntityTypeTab::Factions,
         _ => EntityTypeTab::Characters,
     };
 
-    // Track the currently selected entity ID for editing
-    let mut selected_entity_id: Signal<Option<String>> = use_signal(|| None);
+    // The entity being edited lives in the route so it can be linked to;
+    // an empty ID opens the form for a new entity
+    let selected_entity_id = props
+        .selected_entity_id
+        .clone()
+        .map(|id| if id == NEW_ENTITY_ID { String::new() } else { id });
+    let navigator = use_navigator();
+    let subtab = props.selected_tab.clone().unwrap_or_else(|| "characters".to_string());
+    // Whether the entity browser is shown on narrow screens
+    let mut browser_open = use_signal(|| selected_entity_id.is_none());
+    let open_entity = {
+        let world_id = props.world_id.clone();
+        move |subtab: String, entity_id: String| {
+            navigator.push(Route::DMCreatorEntityRoute {
+                world_id: world_id.clone(),
+                subtab,
+                entity_id,
+            });
+        }
+    };
+    let close_entity = {
+        let world_id = props.world_id.clone();
+        let subtab = subtab.clone();
+        move |_: ()| {
+            navigator.push(Route::DMCreatorSubTabRoute {
+                world_id: world_id.clone(),
+                subtab: subtab.clone(),
+            });
+        }
+    };
+    let mut show_outline_generator = use_signal(|| false);
+    // Bumped when an image dropped on a form is uploaded, to reload its gallery
+    let mut uploads = use_signal(|| 0u32);
+
+    // Entity lists - loaded a page at a time (items are the single source of truth)
+    let character_pages = use_paged_list::<crate::application::services::character_service::CharacterSummary>();
+    let location_pages = use_paged_list::<crate::application::services::location_service::LocationSummary>();
+    let characters = character_pages.items;
+    let locations = location_pages.items;
+    // Encounters are few per world, so they load in one go
+    let mut encounters: Signal<Vec<crate::application::services::Encounter>> = use_signal(Vec::new);
+    let mut recipes: Signal<Vec<crate::application::dto::CraftingRecipe>> = use_signal(Vec::new);
+    // Factions also badge characters, so they load whatever the tab
+    let mut factions: Signal<Vec<crate::application::dto::Faction>> = use_signal(Vec::new);
 
-    // Entity lists - stored as reactive signals (single source of truth)
-    let mut characters: Signal<Vec<crate::application::services::character_service::CharacterSummary>> = use_signal(Vec::new);
-    let mut locations: Signal<Vec<crate::application::services::location_service::LocationSummary>> = use_signal(Vec::new);
-    
-    // Loading and error states
-    let mut characters_loading = use_signal(|| true);
-    let mut locations_loading = use_signal(|| true);
-    let mut characters_error: Signal<Option<String>> = use_signal(|| None);
-    let mut locations_error: Signal<Option<String>> = use_signal(|| None);
-    
-    // Initial data fetching on mount
+    // Page fetchers shared by the initial load and infinite scrolling
     let character_service = crate::presentation::services::use_character_service();
     let location_service = crate::presentation::services::use_location_service();
-    let world_id_for_fetch = props.world_id.clone();
-    
-    // Fetch characters on mount
-    use_effect(move || {
-        let world_id = world_id_for_fetch.clone();
-        let svc = character_service.clone();
-        spawn(async move {
-            match svc.list_characters(&world_id).await {
-                Ok(fetched) => {
-                    characters.set(fetched);
-                    characters_loading.set(false);
-                }
-                Err(e) => {
-                    characters_error.set(Some(e.to_string()));
-                    characters_loading.set(false);
+    let fetch_characters = {
+        let world_id = props.world_id.clone();
+        move |page: PageRequest| {
+            let svc = character_service.clone();
+            let world_id = world_id.clone();
+            async move { svc.list_characters_page(&world_id, &page).await }
+        }
+    };
+    let fetch_locations = {
+        let world_id = props.world_id.clone();
+        move |page: PageRequest| {
+            let svc = location_service.clone();
+            let world_id = world_id.clone();
+            async move { svc.list_locations_page(&world_id, &page).await }
+        }
+    };
+
+    // Fetch the first page of characters on mount
+    {
+        let fetch = fetch_characters.clone();
+        use_effect(move || {
+            spawn(character_pages.load_first(fetch.clone()));
+        });
+    }
+
+    // Fetch the first page of locations on mount
+    {
+        let fetch = fetch_locations.clone();
+        use_effect(move || {
+            spawn(location_pages.load_first(fetch.clone()));
+        });
+    }
+
+    // Fetch encounters on mount
+    {
+        let encounter_service = crate::presentation::services::use_encounter_service();
+        let world_id = props.world_id.clone();
+        use_effect(move || {
+            let svc = encounter_service.clone();
+            let world_id = world_id.clone();
+            spawn(async move {
+                match svc.list_encounters(&world_id).await {
+                    Ok(list) => encounters.set(list),
+                    Err(e) => tracing::warn!("Failed to load encounters: {}", e),
                 }
-            }
+            });
         });
-    });
-    
-    // Fetch locations on mount
-    let world_id_for_locations = props.world_id.clone();
-    use_effect(move || {
-        let world_id = world_id_for_locations.clone();
-        let svc = location_service.clone();
-        spawn(async move {
-            match svc.list_locations(&world_id).await {
-                Ok(fetched) => {
-                    locations.set(fetched);
-                    locations_loading.set(false);
+    }
+
+    // Fetch recipes on mount
+    {
+        let crafting_service = crate::presentation::services::use_crafting_service();
+        let world_id = props.world_id.clone();
+        use_effect(move || {
+            let svc = crafting_service.clone();
+            let world_id = world_id.clone();
+            spawn(async move {
+                match svc.list_recipes(&world_id).await {
+                    Ok(list) => recipes.set(list),
+                    Err(e) => tracing::warn!("Failed to load recipes: {}", e),
                 }
-                Err(e) => {
-                    locations_error.set(Some(e.to_string()));
-                    locations_loading.set(false);
+            });
+        });
+    }
+
+    // Fetch factions on mount
+    {
+        let faction_service = crate::presentation::services::use_faction_service();
+        let world_id = props.world_id.clone();
+        use_effect(move || {
+            let svc = faction_service.clone();
+            let world_id = world_id.clone();
+            spawn(async move {
+                match svc.list_factions(&world_id).await {
+                    Ok(list) => factions.set(list),
+                    Err(e) => tracing::warn!("Failed to load factions: {}", e),
                 }
-            }
+            });
         });
-    });
+    }
 
     // Hydrate generation queue from Engine on mount
     let platform = use_context::<Platform>();
@@ -125,7 +222,13 @@ pub fn CreatorMode(props: CreatorModeProps) -> Element {
     });
 
     let session_state = use_session_state();
-    
+
+    let left_panel_class = if *browser_open.read() {
+        "left-panel flex flex-col gap-4 overflow-hidden max-h-[40vh] lg:max-h-none"
+    } else {
+        "left-panel hidden lg:flex flex-col gap-4 overflow-hidden"
+    };
+
     rsx! {
         div {
             class: "creator-mode h-full flex flex-col gap-4 p-4",
@@ -139,37 +242,80 @@ pub fn CreatorMode(props: CreatorModeProps) -> Element {
                 }
             }
 
+            // Below the lg breakpoint the browser collapses behind a toggle
+            button {
+                class: "lg:hidden self-start px-3 py-1.5 bg-dark-surface text-gray-300 border border-gray-700 rounded-md text-sm cursor-pointer",
+                onclick: move |_| {
+                    let open = *browser_open.read();
+                    browser_open.set(!open);
+                },
+                if *browser_open.read() { "◂ Hide browser" } else { "▸ Show browser" }
+            }
+
             div {
-                class: "grid gap-4 flex-1 overflow-hidden",
-                style: "grid-template-columns: 280px 1fr;",
+                class: "grid gap-4 flex-1 overflow-hidden grid-cols-1 lg:grid-cols-[280px_1fr]",
                 // Left panel - Entity browser and generation queue
             div {
-                class: "left-panel flex flex-col gap-4 overflow-hidden",
+                "data-tour": "creator-browser",
+                class: left_panel_class,
 
                 // Entity browser (tree view) - now uses router for tab changes
                 entity_browser::EntityBrowser {
                     world_id: props.world_id.clone(),
                     selected_type: selected_entity_type,
-                    selected_id: selected_entity_id.read().clone(),
+                    selected_id: selected_entity_id.clone(),
                     characters: characters,
                     locations: locations,
-                    characters_loading: characters_loading,
-                    locations_loading: locations_loading,
-                    characters_error: characters_error,
-                    locations_error: locations_error,
-                    on_select: move |id| selected_entity_id.set(Some(id)),
+                    encounters: encounters,
+                    recipes: recipes,
+                    factions: factions,
+                    characters_loading: character_pages.loading,
+                    locations_loading: location_pages.loading,
+                    characters_error: character_pages.error,
+                    locations_error: location_pages.error,
+                    has_more: match selected_entity_type {
+                        EntityTypeTab::Characters => character_pages.has_more(),
+                        EntityTypeTab::Locations => location_pages.has_more(),
+                        EntityTypeTab::Items
+                        | EntityTypeTab::Maps
+                        | EntityTypeTab::Encounters
+                        | EntityTypeTab::Recipes
+                        | EntityTypeTab::Factions => false,
+                    },
+                    on_load_more: move |_| match selected_entity_type {
+                        EntityTypeTab::Characters => {
+                            spawn(character_pages.load_more(fetch_characters.clone()));
+                        }
+                        EntityTypeTab::Locations => {
+                            spawn(location_pages.load_more(fetch_locations.clone()));
+                        }
+                        EntityTypeTab::Items
+                        | EntityTypeTab::Maps
+                        | EntityTypeTab::Encounters
+                        | EntityTypeTab::Recipes
+                        | EntityTypeTab::Factions => {}
+                    },
+                    on_select: {
+                        let open_entity = open_entity.clone();
+                        let subtab = subtab.clone();
+                        move |id| {
+                            browser_open.set(false);
+                            open_entity(subtab.clone(), id)
+                        }
+                    },
+                    on_generate_from_outline: move |_| show_outline_generator.set(true),
                 }
 
                 // Generation queue panel - navigation handled via entity selection
                 generation_queue::GenerationQueuePanel {
                     on_navigate_to_entity: {
-                        let mut selected_id = selected_entity_id;
-                        let world_id = props.world_id.clone();
+                        let open_entity = open_entity.clone();
                         move |(entity_type, entity_id): (String, String)| {
-                            // Set the selected entity ID so the form opens
-                            selected_id.set(Some(entity_id.clone()));
-                            // Note: Navigation to the correct tab is handled by the route
-                            // The entity will be selected when the form loads
+                            let subtab = match entity_type.as_str() {
+                                "location" | "locations" => "locations",
+                                _ => "characters",
+                            };
+                            open_entity(subtab.to_string(), entity_id);
                         }
                     },
                 }
@@ -177,40 +323,55 @@ pub fn CreatorMode(props: CreatorModeProps) -> Element {
 
             // Right panel - Editor/Form area
             div {
+                "data-tour": "creator-editor",
                 class: "editor-panel flex flex-col gap-4 overflow-hidden",
 
-                match (selected_entity_type, selected_entity_id.read().clone()) {
+                match (selected_entity_type, selected_entity_id) {
                     (EntityTypeTab::Characters, Some(id)) => rsx! {
-                        character_form::CharacterForm {
-                            character_id: id,
+                        // Image files dropped anywhere on the form become the portrait
+                        asset_drop_zone::AssetDropZone {
+                            key: "{id}",
                             world_id: props.world_id.clone(),
-                            characters_signal: characters,
-                            on_close: move |_| selected_entity_id.set(None),
+                            entity_type: "character".to_string(),
+                            entity_id: id.clone(),
+                            asset_type: "portrait".to_string(),
+                            activate: true,
+                            on_uploaded: move |_| *uploads.write() += 1,
+                            class: "flex-1 min-h-0 flex flex-col",
+                            character_form::CharacterForm {
+                                character_id: id,
+                                world_id: props.world_id.clone(),
+                                characters_signal: characters,
+                                uploads,
+                                on_close: close_entity.clone(),
+                            }
                         }
                     },
                     (EntityTypeTab::Characters, None) => rsx! {
-                        character_form::CharacterForm {
-                            character_id: String::new(),
-                            world_id: props.world_id.clone(),
-                            characters_signal: characters,
-                            on_close: move |_| {},
-                        }
+                        PlaceholderPanel { title: "Characters", message: "Select a character, or create a new one" }
                     },
                     (EntityTypeTab::Locations, Some(id)) => rsx! {
-                        location_form::LocationForm {
-                            location_id: id,
+                        // Image files dropped anywhere on the form become the backdrop
+                        asset_drop_zone::AssetDropZone {
+                            key: "{id}",
                             world_id: props.world_id.clone(),
-                            locations_signal: locations,
-                            on_close: move |_| selected_entity_id.set(None),
+                            entity_type: "location".to_string(),
+                            entity_id: id.clone(),
+                            asset_type: "backdrop".to_string(),
+                            activate: true,
+                            on_uploaded: move |_| *uploads.write() += 1,
+                            class: "flex-1 min-h-0 flex flex-col",
+                            location_form::LocationForm {
+                                location_id: id,
+                                world_id: props.world_id.clone(),
+                                locations_signal: locations,
+                                uploads,
+                                on_close: close_entity.clone(),
+                            }
                         }
                     },
                     (EntityTypeTab::Locations, None) => rsx! {
-                        location_form::LocationForm {
-                            location_id: String::new(),
-                            world_id: props.world_id.clone(),
-                            locations_signal: locations,
-                            on_close: move |_| {},
-                        }
+                        PlaceholderPanel { title: "Locations", message: "Select a location, or create a new one" }
                     },
                     (EntityTypeTab::Items, _) => rsx! {
                         PlaceholderPanel { title: "Item Editor", message: "Item editing coming soon" }
@@ -218,9 +379,59 @@ pub fn CreatorMode(props: CreatorModeProps) -> Element {
                     (EntityTypeTab::Maps, _) => rsx! {
                         PlaceholderPanel { title: "Map Editor", message: "Map editing coming soon" }
                     },
+                    (EntityTypeTab::Encounters, Some(id)) => rsx! {
+                        encounter_form::EncounterForm {
+                            key: "{id}",
+                            encounter_id: id,
+                            world_id: props.world_id.clone(),
+                            encounters_signal: encounters,
+                            on_close: close_entity.clone(),
+                        }
+                    },
+                    (EntityTypeTab::Encounters, None) => rsx! {
+                        PlaceholderPanel { title: "Encounters", message: "Select an encounter, or create a new one" }
+                    },
+                    (EntityTypeTab::Recipes, Some(id)) => rsx! {
+                        recipe_form::RecipeForm {
+                            key: "{id}",
+                            recipe_id: id,
+                            world_id: props.world_id.clone(),
+                            recipes_signal: recipes,
+                            on_close: close_entity.clone(),
+                        }
+                    },
+                    (EntityTypeTab::Recipes, None) => rsx! {
+                        PlaceholderPanel { title: "Recipes", message: "Select a recipe, or create a new one" }
+                    },
+                    (EntityTypeTab::Factions, Some(id)) => rsx! {
+                        faction_form::FactionForm {
+                            key: "{id}",
+                            faction_id: id,
+                            world_id: props.world_id.clone(),
+                            factions_signal: factions,
+                            characters_signal: characters,
+                            on_close: close_entity.clone(),
+                        }
+                    },
+                    (EntityTypeTab::Factions, None) => rsx! {
+                        PlaceholderPanel { title: "Factions", message: "Select a faction, or create a new one" }
+                    },
                 }
             }
             }
+
+            if *show_outline_generator.read() {
+                outline_generator::OutlineGeneratorModal {
+                    world_id: props.world_id.clone(),
+                    kind: selected_entity_type,
+                    characters_signal: characters,
+                    locations_signal: locations,
+                    on_close: move |_| show_outline_generator.set(false),
+                }
+            }
+
+            // First-run walkthrough of the browser, editor and queue
+            OnboardingTour { view: TourView::Creator }
         }
     }
 }
@@ -233,6 +444,9 @@ pub enum EntityTypeTab {
     Locations,
     Items,
     Maps,
+    Encounters,
+    Recipes,
+    Factions,
 }
 
 impl EntityTypeTab {
@@ -242,6 +456,9 @@ impl EntityTypeTab {
             EntityTypeTab::Locations => "Locations",
             EntityTypeTab::Items => "Items",
             EntityTypeTab::Maps => "Maps",
+            EntityTypeTab::Encounters => "Encounter",
+            EntityTypeTab::Recipes => "Recipe",
+            EntityTypeTab::Factions => "Faction",
         }
     }
 }
@@ -0,0 +1,445 @@
+//! Outline Generator - Create draft entities in bulk from a pasted outline
+//!
+//! The DM pastes one entity per line, the LLM suggestion service fleshes out
+//! each line into a description, and the DM reviews the drafts before they
+//! are created. Accepted entities can have image generation queued for them.
+
+use dioxus::prelude::*;
+
+use super::EntityTypeTab;
+use crate::application::dto::EntityStyle;
+use crate::application::services::{
+    outline_image_prompt, parse_outline, CharacterFormData, GenerateRequest, LocationFormData,
+    LocationSummary, SuggestionContext,
+};
+use crate::application::services::character_service::CharacterSummary;
+use crate::presentation::services::{
+    use_asset_service, use_character_service, use_location_service, use_suggestion_service,
+};
+use crate::presentation::state::use_modal_focus;
+
+/// Where the generator is in its flow
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Paste,
+    Drafting,
+    Review,
+    Committing,
+    Done,
+}
+
+/// Outcome of creating one draft
+#[derive(Clone, Debug, PartialEq)]
+enum DraftStatus {
+    Pending,
+    Created,
+    Failed(String),
+}
+
+/// A drafted entity awaiting review
+#[derive(Clone, Debug, PartialEq)]
+struct OutlineDraft {
+    name: String,
+    description: String,
+    accepted: bool,
+    queue_image: bool,
+    status: DraftStatus,
+}
+
+/// Props for OutlineGeneratorModal
+#[derive(Props, Clone, PartialEq)]
+pub struct OutlineGeneratorModalProps {
+    pub world_id: String,
+    /// Characters or Locations
+    pub kind: EntityTypeTab,
+    pub characters_signal: Signal<Vec<CharacterSummary>>,
+    pub locations_signal: Signal<Vec<LocationSummary>>,
+    pub on_close: EventHandler<()>,
+}
+
+/// Bulk entity generation modal
+#[component]
+pub fn OutlineGeneratorModal(props: OutlineGeneratorModalProps) -> Element {
+    use_modal_focus("outline-generator-modal");
+    let suggestion_service = use_suggestion_service();
+    let character_service = use_character_service();
+    let location_service = use_location_service();
+    let asset_service = use_asset_service();
+
+    let mut outline = use_signal(String::new);
+    let mut stage = use_signal(|| Stage::Paste);
+    let mut drafts: Signal<Vec<OutlineDraft>> = use_signal(Vec::new);
+    let mut drafted = use_signal(|| 0usize);
+
+    let is_characters = props.kind == EntityTypeTab::Characters;
+    let entity_type = if is_characters { "character" } else { "location" };
+    let (asset_type, image_label, prompt_prefix) = if is_characters {
+        ("portrait", "Queue portrait", "portrait of")
+    } else {
+        ("backdrop", "Queue backdrop", "scenic view of")
+    };
+    let entries = parse_outline(&outline.read());
+    let entry_count = entries.len();
+    let current_stage = *stage.read();
+
+    let generate_drafts = move |_| {
+        let entries = parse_outline(&outline.read());
+        if entries.is_empty() {
+            return;
+        }
+        let svc = suggestion_service.clone();
+        drafts.set(Vec::new());
+        drafted.set(0);
+        stage.set(Stage::Drafting);
+        spawn(async move {
+            // One at a time so a long outline doesn't flood the LLM queue
+            for entry in entries {
+                let context = SuggestionContext {
+                    entity_type: Some(entity_type.to_string()),
+                    entity_name: Some(entry.name.clone()),
+                    hints: (!entry.summary.is_empty()).then(|| entry.summary.clone()),
+                    ..Default::default()
+                };
+                let result = if is_characters {
+                    svc.suggest_character_description(&context).await
+                } else {
+                    svc.suggest_location_description(&context).await
+                };
+                let description = match result {
+                    Ok(suggestions) => suggestions.into_iter().next().unwrap_or(entry.summary),
+                    Err(e) => {
+                        tracing::warn!("Suggestion failed for {}: {}", entry.name, e);
+                        entry.summary
+                    }
+                };
+                drafts.write().push(OutlineDraft {
+                    name: entry.name,
+                    description,
+                    accepted: true,
+                    queue_image: true,
+                    status: DraftStatus::Pending,
+                });
+                *drafted.write() += 1;
+            }
+            stage.set(Stage::Review);
+        });
+    };
+
+    let commit = {
+        let world_id = props.world_id.clone();
+        let mut characters_signal = props.characters_signal;
+        let mut locations_signal = props.locations_signal;
+        move |_| {
+            let world_id = world_id.clone();
+            let char_svc = character_service.clone();
+            let loc_svc = location_service.clone();
+            let asset_svc = asset_service.clone();
+            stage.set(Stage::Committing);
+            spawn(async move {
+                let count = drafts.read().len();
+                for i in 0..count {
+                    let draft = drafts.read()[i].clone();
+                    if !draft.accepted || draft.status == DraftStatus::Created {
+                        continue;
+                    }
+                    let description = (!draft.description.trim().is_empty()).then(|| draft.description.clone());
+                    let created = if is_characters {
+                        let data = CharacterFormData {
+                            id: None,
+                            name: draft.name.clone(),
+                            description,
+                            archetype: None,
+                            wants: None,
+                            fears: None,
+                            backstory: None,
+                            sprite_asset: None,
+                            portrait_asset: None,
+                            sheet_data: None,
+                            tags: Vec::new(),
+                            routine: Vec::new(),
+                            style: EntityStyle::default(),
+                        };
+                        char_svc.create_character(&world_id, &data).await.map(|saved| {
+                            let id = saved.id.clone().unwrap_or_default();
+                            characters_signal.write().push(CharacterSummary {
+                                id: id.clone(),
+                                name: saved.name,
+                                archetype: saved.archetype,
+                                tags: saved.tags,
+                                deleted_at: None,
+                                archived_at: None,
+                                style: saved.style,
+                            });
+                            id
+                        })
+                    } else {
+                        let data = LocationFormData {
+                            id: None,
+                            name: draft.name.clone(),
+                            description,
+                            location_type: None,
+                            atmosphere: None,
+                            notable_features: None,
+                            hidden_secrets: None,
+                            parent_location_id: None,
+                            backdrop_asset: None,
+                            animated_backdrop_asset: None,
+                            backdrop_regions: Vec::new(),
+                            tags: Vec::new(),
+                            style: EntityStyle::default(),
+                        };
+                        loc_svc.create_location(&world_id, &data).await.map(|saved| {
+                            let id = saved.id.clone().unwrap_or_default();
+                            locations_signal.write().push(LocationSummary {
+                                id: id.clone(),
+                                name: saved.name,
+                                location_type: saved.location_type,
+                                parent_location_id: saved.parent_location_id,
+                                tags: saved.tags,
+                                deleted_at: None,
+                                archived_at: None,
+                                style: saved.style,
+                            });
+                            id
+                        })
+                    };
+
+                    let status = match created {
+                        Ok(entity_id) => {
+                            if draft.queue_image && !entity_id.is_empty() {
+                                let request = GenerateRequest {
+                                    world_id: world_id.clone(),
+                                    entity_type: entity_type.to_string(),
+                                    entity_id,
+                                    asset_type: asset_type.to_string(),
+                                    prompt: outline_image_prompt(prompt_prefix, &draft.name, &draft.description),
+                                    negative_prompt: None,
+                                    count: 1,
+                                    style_reference_id: None,
+                                    source_asset_id: None,
+                                    denoise: None,
+                                    params: None,
+                                };
+                                if let Err(e) = asset_svc.generate_assets(&request).await {
+                                    tracing::error!("Failed to queue {} for {}: {}", asset_type, draft.name, e);
+                                }
+                            }
+                            DraftStatus::Created
+                        }
+                        Err(e) => DraftStatus::Failed(e.to_string()),
+                    };
+                    if let Some(d) = drafts.write().get_mut(i) {
+                        d.status = status;
+                    }
+                }
+                stage.set(Stage::Done);
+            });
+        }
+    };
+
+    let accepted_count = drafts.read().iter().filter(|d| d.accepted && d.status != DraftStatus::Created).count();
+    let failed_count = drafts.read().iter().filter(|d| matches!(d.status, DraftStatus::Failed(_))).count();
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/80 flex items-center justify-center z-[1000]",
+            onclick: move |_| {
+                if !matches!(current_stage, Stage::Drafting | Stage::Committing) {
+                    props.on_close.call(());
+                }
+            },
+
+            div {
+                id: "outline-generator-modal",
+                role: "dialog",
+                aria_modal: "true",
+                class: "bg-dark-surface rounded-xl p-6 w-[90%] max-w-[720px] max-h-[90vh] overflow-y-auto flex flex-col gap-4",
+                onclick: move |e| e.stop_propagation(),
+
+                div {
+                    class: "flex justify-between items-center",
+                    h2 {
+                        class: "m-0 text-white text-xl",
+                        "Generate {props.kind.label()} from Outline"
+                    }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| props.on_close.call(()),
+                        disabled: matches!(current_stage, Stage::Drafting | Stage::Committing),
+                        class: "px-2 py-1 bg-transparent text-gray-400 border-0 cursor-pointer text-xl disabled:opacity-50",
+                        "×"
+                    }
+                }
+
+                match current_stage {
+                    Stage::Paste => rsx! {
+                        p {
+                            class: "m-0 text-gray-400 text-sm",
+                            "One per line, optionally with a short description: "
+                            code { class: "text-gray-300", "Mira Vell - a nervous innkeeper" }
+                        }
+                        textarea {
+                            value: "{outline}",
+                            oninput: move |e| outline.set(e.value()),
+                            placeholder: "- Mira Vell - a nervous innkeeper hiding a debt\n- Brother Oswin: a monk who knows too much",
+                            class: "w-full min-h-[200px] p-2 bg-dark-bg border border-gray-700 rounded text-white text-sm font-mono resize-y box-border",
+                        }
+                        div {
+                            class: "flex justify-between items-center",
+                            span { class: "text-gray-500 text-xs", "{entry_count} found" }
+                            button {
+                                onclick: generate_drafts,
+                                disabled: entry_count == 0,
+                                class: "px-4 py-2 bg-purple-500 text-white border-0 rounded cursor-pointer font-medium disabled:opacity-50",
+                                "Generate Drafts"
+                            }
+                        }
+                    },
+                    Stage::Drafting => rsx! {
+                        div {
+                            class: "p-6 text-center text-gray-400",
+                            "Drafting {drafted} of {entry_count}..."
+                        }
+                    },
+                    Stage::Review | Stage::Committing | Stage::Done => rsx! {
+                        div {
+                            class: "flex flex-col gap-3",
+                            for (i, draft) in drafts.read().iter().cloned().enumerate() {
+                                DraftCard {
+                                    key: "{i}",
+                                    draft,
+                                    image_label,
+                                    editable: current_stage == Stage::Review,
+                                    on_change: move |updated: OutlineDraft| {
+                                        if let Some(d) = drafts.write().get_mut(i) {
+                                            *d = updated;
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                        div {
+                            class: "flex justify-between items-center",
+                            if current_stage == Stage::Done {
+                                span {
+                                    class: "text-gray-400 text-sm",
+                                    if failed_count > 0 {
+                                        "{failed_count} failed to save"
+                                    } else {
+                                        "All accepted drafts created"
+                                    }
+                                }
+                            } else {
+                                span { class: "text-gray-500 text-xs", "{accepted_count} to create" }
+                            }
+                            div {
+                                class: "flex gap-2",
+                                if current_stage == Stage::Review {
+                                    button {
+                                        onclick: move |_| stage.set(Stage::Paste),
+                                        class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer",
+                                        "Back"
+                                    }
+                                }
+                                if current_stage == Stage::Done {
+                                    if failed_count > 0 {
+                                        button {
+                                            onclick: move |_| stage.set(Stage::Review),
+                                            class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer",
+                                            "Review Failures"
+                                        }
+                                    }
+                                    button {
+                                        onclick: move |_| props.on_close.call(()),
+                                        class: "px-4 py-2 bg-blue-500 text-white border-0 rounded cursor-pointer font-medium",
+                                        "Close"
+                                    }
+                                } else {
+                                    button {
+                                        onclick: commit,
+                                        disabled: current_stage == Stage::Committing || accepted_count == 0,
+                                        class: "px-4 py-2 bg-green-500 text-white border-0 rounded cursor-pointer font-medium disabled:opacity-50",
+                                        if current_stage == Stage::Committing { "Creating..." } else { "Create {accepted_count}" }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// One reviewable draft
+#[component]
+fn DraftCard(
+    draft: OutlineDraft,
+    image_label: &'static str,
+    editable: bool,
+    on_change: EventHandler<OutlineDraft>,
+) -> Element {
+    let border = match draft.status {
+        DraftStatus::Created => "border-green-500",
+        DraftStatus::Failed(_) => "border-red-500",
+        DraftStatus::Pending if draft.accepted => "border-gray-600",
+        DraftStatus::Pending => "border-gray-800 opacity-60",
+    };
+    let editable = editable && draft.status != DraftStatus::Created;
+
+    rsx! {
+        div {
+            class: "p-3 bg-dark-bg rounded-lg border {border} flex flex-col gap-2",
+
+            div {
+                class: "flex items-center gap-2",
+                input {
+                    r#type: "checkbox",
+                    checked: draft.accepted,
+                    disabled: !editable,
+                    onchange: {
+                        let draft = draft.clone();
+                        move |e: FormEvent| on_change.call(OutlineDraft { accepted: e.checked(), ..draft.clone() })
+                    },
+                }
+                input {
+                    r#type: "text",
+                    value: "{draft.name}",
+                    disabled: !editable,
+                    oninput: {
+                        let draft = draft.clone();
+                        move |e: FormEvent| on_change.call(OutlineDraft { name: e.value(), ..draft.clone() })
+                    },
+                    class: "flex-1 p-1 bg-transparent border-0 border-b border-gray-700 text-white font-medium",
+                }
+                match &draft.status {
+                    DraftStatus::Created => rsx! { span { class: "text-green-400 text-xs", "Created" } },
+                    DraftStatus::Failed(e) => rsx! { span { class: "text-red-400 text-xs", title: "{e}", "Failed" } },
+                    DraftStatus::Pending => rsx! {},
+                }
+            }
+            textarea {
+                value: "{draft.description}",
+                disabled: !editable,
+                oninput: {
+                    let draft = draft.clone();
+                    move |e: FormEvent| on_change.call(OutlineDraft { description: e.value(), ..draft.clone() })
+                },
+                class: "w-full min-h-[60px] p-2 bg-dark-surface border border-gray-700 rounded text-gray-300 text-sm resize-y box-border",
+            }
+            label {
+                class: "flex items-center gap-2 text-gray-400 text-xs cursor-pointer",
+                input {
+                    r#type: "checkbox",
+                    checked: draft.queue_image,
+                    disabled: !editable,
+                    onchange: {
+                        let draft = draft.clone();
+                        move |e: FormEvent| on_change.call(OutlineDraft { queue_image: e.checked(), ..draft.clone() })
+                    },
+                }
+                "{image_label}"
+            }
+        }
+    }
+}
@@ -0,0 +1,383 @@
+//! Recipe Form - Create and edit crafting recipes
+//!
+//! A recipe lists ingredient items, an optional skill check, and the item it
+//! makes. Items are named rather than picked, the same way challenge
+//! outcomes give items, so names used in other recipes are suggested.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{ChallengeDifficulty, CraftingRecipe, ItemStack, SkillData};
+use crate::application::services::SaveRecipeRequest;
+use crate::presentation::components::common::FormField;
+use crate::presentation::services::{use_crafting_service, use_skill_service};
+
+/// Recipe form for creating/editing crafting recipes
+#[component]
+pub fn RecipeForm(
+    recipe_id: String,
+    world_id: String,
+    recipes_signal: Signal<Vec<CraftingRecipe>>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let is_new = recipe_id.is_empty();
+    let crafting_service = use_crafting_service();
+    let skill_service = use_skill_service();
+
+    // Recipes arrive with the browser's list; a new one starts with one
+    // empty ingredient row
+    let existing = recipes_signal
+        .peek()
+        .iter()
+        .find(|r| r.id == recipe_id)
+        .cloned()
+        .unwrap_or_else(|| CraftingRecipe {
+            ingredients: vec![ItemStack::default()],
+            ..Default::default()
+        });
+    let mut name = use_signal(|| existing.name.clone());
+    let mut description = use_signal(|| existing.description.clone());
+    let mut ingredients = use_signal(|| existing.ingredients.clone());
+    let mut skill_id = use_signal(|| existing.skill_id.clone());
+    let mut difficulty = use_signal(|| existing.difficulty.clone());
+    let mut result = use_signal(|| existing.result.clone());
+    let mut result_description = use_signal(|| existing.result_description.clone().unwrap_or_default());
+    let mut consume_on_failure = use_signal(|| existing.consume_on_failure);
+
+    let mut skills: Signal<Vec<SkillData>> = use_signal(Vec::new);
+    let mut is_saving = use_signal(|| false);
+    let mut error_message: Signal<Option<String>> = use_signal(|| None);
+
+    // Load the skills a check can use
+    {
+        let world_id = world_id.clone();
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let skill_service = skill_service.clone();
+            spawn(async move {
+                match skill_service.list_skills(&world_id).await {
+                    Ok(list) => skills.set(list),
+                    Err(e) => error_message.set(Some(format!("Failed to load skills: {}", e))),
+                }
+            });
+        });
+    }
+
+    // Item names already used by recipes, offered as suggestions
+    let known_items: Vec<String> = {
+        let mut names: Vec<String> = recipes_signal
+            .read()
+            .iter()
+            .flat_map(|r| r.ingredients.iter().chain(std::iter::once(&r.result)))
+            .map(|stack| stack.item_name.clone())
+            .filter(|n| !n.trim().is_empty())
+            .collect();
+        names.sort_by_key(|n| n.to_lowercase());
+        names.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        names
+    };
+    let dc_value = match &*difficulty.read() {
+        ChallengeDifficulty::Dc { value } => *value,
+        _ => 10,
+    };
+
+    let save = {
+        let crafting_service = crafting_service.clone();
+        let recipe_id = recipe_id.clone();
+        let world_id = world_id.clone();
+        move |_| {
+            let result_description = result_description.read().trim().to_string();
+            let request = SaveRecipeRequest {
+                name: name.read().trim().to_string(),
+                description: description.read().clone(),
+                ingredients: ingredients
+                    .read()
+                    .iter()
+                    .map(|i| ItemStack::new(i.item_name.trim(), i.quantity))
+                    .collect(),
+                skill_id: skill_id.read().clone(),
+                difficulty: difficulty.read().clone(),
+                result: ItemStack::new(result.read().item_name.trim(), result.read().quantity),
+                result_description: (!result_description.is_empty()).then_some(result_description),
+                consume_on_failure: *consume_on_failure.read(),
+            };
+            if let Err(e) = request.validate() {
+                error_message.set(Some(e));
+                return;
+            }
+            error_message.set(None);
+            is_saving.set(true);
+
+            let svc = crafting_service.clone();
+            let recipe_id = recipe_id.clone();
+            let world_id = world_id.clone();
+            spawn(async move {
+                let result = if is_new {
+                    svc.create_recipe(&world_id, &request).await
+                } else {
+                    svc.update_recipe(&recipe_id, &request).await
+                };
+                is_saving.set(false);
+                match result {
+                    Ok(saved) => {
+                        let mut list = recipes_signal.write();
+                        match list.iter_mut().find(|r| r.id == saved.id) {
+                            Some(existing) => *existing = saved,
+                            None => list.push(saved),
+                        }
+                        drop(list);
+                        on_close.call(());
+                    }
+                    Err(e) => error_message.set(Some(format!("Save failed: {}", e))),
+                }
+            });
+        }
+    };
+
+    let delete = {
+        let crafting_service = crafting_service.clone();
+        let recipe_id = recipe_id.clone();
+        move |_| {
+            let svc = crafting_service.clone();
+            let recipe_id = recipe_id.clone();
+            spawn(async move {
+                match svc.delete_recipe(&recipe_id).await {
+                    Ok(()) => {
+                        recipes_signal.write().retain(|r| r.id != recipe_id);
+                        on_close.call(());
+                    }
+                    Err(e) => error_message.set(Some(format!("Delete failed: {}", e))),
+                }
+            });
+        }
+    };
+
+    let input_class = "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white";
+    let quantity_class = "w-20 p-2 bg-dark-bg border border-gray-700 rounded text-white";
+
+    rsx! {
+        div {
+            class: "recipe-form flex flex-col h-full bg-dark-surface rounded-lg overflow-hidden",
+
+            // Header
+            div {
+                class: "form-header flex justify-between items-center p-4 border-b border-gray-700",
+                h2 {
+                    class: "text-white m-0 text-xl",
+                    if is_new { "New Recipe" } else { "Edit Recipe" }
+                }
+                button {
+                    aria_label: "Close",
+                    onclick: move |_| on_close.call(()),
+                    class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
+                    "×"
+                }
+            }
+
+            if let Some(msg) = error_message.read().as_ref() {
+                div {
+                    class: "px-4 py-3 bg-red-500/10 border-b border-red-500/30 text-red-500 text-sm",
+                    "{msg}"
+                }
+            }
+
+            datalist {
+                id: "recipe-known-items",
+                for item in known_items.iter() {
+                    option { key: "{item}", value: "{item}" }
+                }
+            }
+
+            // Form content (scrollable)
+            div {
+                class: "form-content flex-1 overflow-y-auto p-4 flex flex-col gap-4",
+
+                FormField {
+                    label: "Name",
+                    required: true,
+                    children: rsx! {
+                        input {
+                            r#type: "text",
+                            value: "{name}",
+                            oninput: move |e| name.set(e.value()),
+                            placeholder: "Forge a sword...",
+                            class: "{input_class}",
+                        }
+                    }
+                }
+
+                FormField {
+                    label: "Ingredients",
+                    required: true,
+                    children: rsx! {
+                        div { class: "flex flex-col gap-2",
+                            for (i, ingredient) in ingredients.read().iter().enumerate() {
+                                div {
+                                    key: "{i}",
+                                    class: "flex gap-2 items-center",
+                                    input {
+                                        r#type: "text",
+                                        list: "recipe-known-items",
+                                        aria_label: "Ingredient item",
+                                        value: "{ingredient.item_name}",
+                                        oninput: move |e| ingredients.write()[i].item_name = e.value(),
+                                        placeholder: "Item name",
+                                        class: "{input_class}",
+                                    }
+                                    input {
+                                        r#type: "number",
+                                        min: "1",
+                                        aria_label: "Quantity",
+                                        value: "{ingredient.quantity}",
+                                        oninput: move |e| ingredients.write()[i].quantity = e.value().parse().unwrap_or(0),
+                                        class: quantity_class,
+                                    }
+                                    button {
+                                        aria_label: "Remove ingredient",
+                                        onclick: move |_| {
+                                            ingredients.write().remove(i);
+                                        },
+                                        class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer",
+                                        "×"
+                                    }
+                                }
+                            }
+                            button {
+                                onclick: move |_| ingredients.write().push(ItemStack::default()),
+                                class: "self-start px-3 py-1 bg-transparent text-blue-400 border border-blue-500/40 rounded cursor-pointer text-sm",
+                                "+ Add ingredient"
+                            }
+                        }
+                    }
+                }
+
+                FormField {
+                    label: "Makes",
+                    required: true,
+                    children: rsx! {
+                        div { class: "flex flex-col gap-2",
+                            div { class: "flex gap-2 items-center",
+                                input {
+                                    r#type: "text",
+                                    list: "recipe-known-items",
+                                    aria_label: "Result item",
+                                    value: "{result.read().item_name}",
+                                    oninput: move |e| result.write().item_name = e.value(),
+                                    placeholder: "Item name",
+                                    class: "{input_class}",
+                                }
+                                input {
+                                    r#type: "number",
+                                    min: "1",
+                                    aria_label: "Quantity",
+                                    value: "{result.read().quantity}",
+                                    oninput: move |e| result.write().quantity = e.value().parse().unwrap_or(0),
+                                    class: quantity_class,
+                                }
+                            }
+                            input {
+                                r#type: "text",
+                                value: "{result_description}",
+                                oninput: move |e| result_description.set(e.value()),
+                                placeholder: "Description for a new item (optional)",
+                                class: "{input_class}",
+                            }
+                        }
+                    }
+                }
+
+                FormField {
+                    label: "Skill Check",
+                    required: false,
+                    children: rsx! {
+                        div { class: "flex gap-2 items-center",
+                            select {
+                                value: skill_id.read().as_deref().unwrap_or(""),
+                                onchange: move |e| {
+                                    let val = e.value();
+                                    skill_id.set((!val.is_empty()).then_some(val));
+                                },
+                                aria_label: "Skill",
+                                class: input_class,
+                                option { value: "", "No check - always succeeds" }
+                                for skill in skills.read().iter().filter(|s| !s.is_hidden) {
+                                    option { key: "{skill.id}", value: "{skill.id}", "{skill.name}" }
+                                }
+                            }
+                            if skill_id.read().is_some() {
+                                label { class: "text-gray-400 text-sm", "DC" }
+                                input {
+                                    r#type: "number",
+                                    min: "1",
+                                    aria_label: "Difficulty class",
+                                    value: "{dc_value}",
+                                    oninput: move |e| {
+                                        if let Ok(value) = e.value().parse() {
+                                            difficulty.set(ChallengeDifficulty::Dc { value });
+                                        }
+                                    },
+                                    class: quantity_class,
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if skill_id.read().is_some() {
+                    label {
+                        class: "flex items-center gap-2 text-white text-sm cursor-pointer",
+                        input {
+                            r#type: "checkbox",
+                            checked: *consume_on_failure.read(),
+                            onchange: move |e| consume_on_failure.set(e.checked()),
+                        }
+                        "A failed check still uses up the ingredients"
+                    }
+                }
+
+                FormField {
+                    label: "Description",
+                    required: false,
+                    children: rsx! {
+                        textarea {
+                            value: "{description}",
+                            oninput: move |e| description.set(e.value()),
+                            placeholder: "Where the recipe comes from, tools needed...",
+                            class: "w-full min-h-[80px] p-2 bg-dark-bg border border-gray-700 rounded text-white resize-y box-border",
+                        }
+                    }
+                }
+            }
+
+            // Footer with action buttons
+            div {
+                class: "form-footer flex justify-between gap-2 p-4 border-t border-gray-700",
+
+                if is_new {
+                    div {}
+                } else {
+                    button {
+                        onclick: delete,
+                        class: "px-4 py-2 bg-transparent text-red-400 border border-red-500/40 rounded cursor-pointer",
+                        "Delete"
+                    }
+                }
+
+                div {
+                    class: "flex gap-2",
+                    button {
+                        onclick: move |_| on_close.call(()),
+                        class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer",
+                        disabled: *is_saving.read(),
+                        "Cancel"
+                    }
+                    button {
+                        onclick: save,
+                        disabled: *is_saving.read(),
+                        class: "px-4 py-2 bg-green-500 text-white border-none rounded cursor-pointer font-medium disabled:opacity-60",
+                        if *is_saving.read() { "Saving..." } else if is_new { "Create" } else { "Save" }
+                    }
+                }
+            }
+        }
+    }
+}
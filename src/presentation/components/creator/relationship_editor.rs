@@ -0,0 +1,299 @@
+//! Relationship Editor - A character's typed ties to other characters
+//!
+//! Lists the relationships a character is part of, from either end, and
+//! edits one at a time in an inline form: the other character, the kind,
+//! a 1-5 strength and the DM's notes. Changes are saved straight away.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{RelationshipData, RelationshipKind};
+use crate::application::services::character_service::CharacterSummary;
+use crate::application::services::SaveRelationshipRequest;
+use crate::presentation::services::use_relationship_service;
+
+/// Which relationship the inline form is editing
+#[derive(Clone, PartialEq)]
+enum Editing {
+    New,
+    Existing(String),
+}
+
+/// Relationships section for the character form
+#[component]
+pub fn RelationshipEditor(world_id: String, character_id: String, characters: Vec<CharacterSummary>) -> Element {
+    let relationship_service = use_relationship_service();
+    let mut relationships: Signal<Vec<RelationshipData>> = use_signal(Vec::new);
+    let mut is_loading = use_signal(|| true);
+    let mut error_message: Signal<Option<String>> = use_signal(|| None);
+
+    // Inline form state
+    let mut editing: Signal<Option<Editing>> = use_signal(|| None);
+    let mut draft = use_signal(|| SaveRelationshipRequest::new(&character_id, "", RelationshipKind::Ally));
+    let mut is_saving = use_signal(|| false);
+
+    {
+        let character_id = character_id.clone();
+        let svc = relationship_service.clone();
+        use_effect(move || {
+            let character_id = character_id.clone();
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.list_for_character(&character_id).await {
+                    Ok(list) => relationships.set(list),
+                    Err(e) => error_message.set(Some(format!("Failed to load relationships: {}", e))),
+                }
+                is_loading.set(false);
+            });
+        });
+    }
+
+    let name_of = {
+        let characters = characters.clone();
+        move |id: &str| {
+            characters
+                .iter()
+                .find(|c| c.id == id)
+                .map(|c| c.name.clone())
+                .unwrap_or_else(|| "Unknown character".to_string())
+        }
+    };
+
+    let save = {
+        let svc = relationship_service.clone();
+        let world_id = world_id.clone();
+        move |_| {
+            let request = draft.read().clone();
+            if let Err(e) = request.validate() {
+                error_message.set(Some(e));
+                return;
+            }
+            let Some(target) = editing.read().clone() else {
+                return;
+            };
+            error_message.set(None);
+            is_saving.set(true);
+
+            let svc = svc.clone();
+            let world_id = world_id.clone();
+            spawn(async move {
+                let result = match &target {
+                    Editing::New => svc.create_relationship(&world_id, &request).await,
+                    Editing::Existing(id) => svc.update_relationship(id, &request).await,
+                };
+                is_saving.set(false);
+                match result {
+                    Ok(saved) => {
+                        let mut list = relationships.write();
+                        match list.iter_mut().find(|r| r.id == saved.id) {
+                            Some(existing) => *existing = saved,
+                            None => list.push(saved),
+                        }
+                        drop(list);
+                        editing.set(None);
+                    }
+                    Err(e) => error_message.set(Some(format!("Save failed: {}", e))),
+                }
+            });
+        }
+    };
+
+    let others: Vec<&CharacterSummary> = characters
+        .iter()
+        .filter(|c| c.id != character_id && c.deleted_at.is_none() && c.archived_at.is_none())
+        .collect();
+    let input_class = "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white text-sm";
+
+    rsx! {
+        div {
+            class: "relationship-editor flex flex-col gap-2",
+
+            if let Some(msg) = error_message.read().as_ref() {
+                div { class: "p-2 bg-red-500/10 rounded text-red-500 text-sm", role: "alert", "{msg}" }
+            }
+
+            if *is_loading.read() {
+                p { class: "text-gray-500 text-sm m-0", "Loading relationships..." }
+            } else if relationships.read().is_empty() && editing.read().is_none() {
+                p { class: "text-gray-500 text-sm m-0", "No relationships yet" }
+            }
+
+            for relationship in relationships.read().iter().cloned() {
+                {
+                    let kind = relationship.kind();
+                    let outgoing = relationship.from_character_id == character_id;
+                    let other_name = name_of(relationship.other_end(&character_id));
+                    let direction = if outgoing { "→" } else { "←" };
+                    let strength = "●".repeat(relationship.strength as usize);
+                    let edit_source = relationship.clone();
+                    let delete_id = relationship.id.clone();
+                    let svc = relationship_service.clone();
+                    rsx! {
+                        div {
+                            key: "{relationship.id}",
+                            class: "p-2 bg-dark-bg rounded flex items-start gap-2 text-sm",
+                            span { title: "{kind.display_name()}", "{kind.icon()}" }
+                            div {
+                                class: "flex-1 min-w-0",
+                                div {
+                                    class: "flex items-center gap-2",
+                                    span { class: "text-white", "{direction} {other_name}" }
+                                    span { class: "text-gray-400 text-xs", "{kind.display_name()}" }
+                                    if kind == RelationshipKind::Other && !relationship.relationship_type.is_empty() {
+                                        span { class: "text-gray-500 text-xs italic", "({relationship.relationship_type})" }
+                                    }
+                                    span { class: "text-amber-400 text-xs", title: "Strength {relationship.strength}/5", "{strength}" }
+                                    if relationship.known_to_player {
+                                        span { class: "text-blue-300 text-xs", title: "Known to players", "👁" }
+                                    }
+                                }
+                                if !relationship.notes.is_empty() {
+                                    p { class: "text-gray-400 text-xs m-0 mt-1", "{relationship.notes}" }
+                                }
+                            }
+                            button {
+                                r#type: "button",
+                                onclick: move |_| {
+                                    draft.set(SaveRelationshipRequest::from(&edit_source));
+                                    editing.set(Some(Editing::Existing(edit_source.id.clone())));
+                                },
+                                class: "px-2 py-0.5 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-xs",
+                                "Edit"
+                            }
+                            button {
+                                r#type: "button",
+                                aria_label: "Delete relationship",
+                                onclick: move |_| {
+                                    let svc = svc.clone();
+                                    let id = delete_id.clone();
+                                    spawn(async move {
+                                        match svc.delete_relationship(&id).await {
+                                            Ok(()) => relationships.write().retain(|r| r.id != id),
+                                            Err(e) => error_message.set(Some(format!("Delete failed: {}", e))),
+                                        }
+                                    });
+                                },
+                                class: "px-2 py-0.5 bg-transparent text-red-400 border-none cursor-pointer text-sm",
+                                "×"
+                            }
+                        }
+                    }
+                }
+            }
+
+            if editing.read().is_some() {
+                div {
+                    class: "p-3 bg-dark-bg rounded border border-gray-700 flex flex-col gap-2",
+
+                    div {
+                        class: "flex gap-2",
+                        // The ends of a saved relationship are fixed; delete it to relink
+                        if matches!(*editing.read(), Some(Editing::Existing(_))) {
+                            span {
+                                class: "flex-1 p-2 text-white text-sm",
+                                {
+                                    let draft = draft.read();
+                                    let other = if draft.from_character_id == character_id { &draft.to_character_id } else { &draft.from_character_id };
+                                    name_of(other)
+                                }
+                            }
+                        } else {
+                            select {
+                                aria_label: "Other character",
+                                value: "{draft.read().to_character_id}",
+                                onchange: move |e| draft.write().to_character_id = e.value(),
+                                class: "{input_class} flex-1",
+                                option { value: "", "Pick a character..." }
+                                for other in others.iter() {
+                                    option { key: "{other.id}", value: "{other.id}", "{other.name}" }
+                                }
+                            }
+                        }
+                        select {
+                            aria_label: "Kind",
+                            value: "{RelationshipKind::from_type(&draft.read().relationship_type).as_str()}",
+                            onchange: move |e| {
+                                let kind = RelationshipKind::from_type(&e.value());
+                                let mut draft = draft.write();
+                                draft.relationship_type = kind.as_str().to_string();
+                                // A saved relationship keeps the sentiment the Engine has tracked
+                                if matches!(*editing.peek(), Some(Editing::New)) {
+                                    draft.sentiment = kind.default_sentiment();
+                                }
+                            },
+                            class: "{input_class} w-32",
+                            for kind in RelationshipKind::all() {
+                                option { value: "{kind.as_str()}", "{kind.icon()} {kind.display_name()}" }
+                            }
+                        }
+                    }
+
+                    label {
+                        class: "flex items-center gap-2 text-gray-400 text-sm",
+                        "Strength"
+                        input {
+                            r#type: "range",
+                            min: "1",
+                            max: "5",
+                            value: "{draft.read().strength}",
+                            oninput: move |e| {
+                                if let Ok(strength) = e.value().parse::<u8>() {
+                                    draft.write().strength = strength;
+                                }
+                            },
+                            class: "flex-1",
+                        }
+                        span { class: "text-white w-6 text-right", "{draft.read().strength}" }
+                    }
+
+                    textarea {
+                        aria_label: "Notes",
+                        placeholder: "Notes, e.g. owes 200 gold since the harbour fire",
+                        value: "{draft.read().notes}",
+                        oninput: move |e| draft.write().notes = e.value(),
+                        class: "{input_class} min-h-[3rem] resize-y",
+                    }
+
+                    label {
+                        class: "flex items-center gap-2 text-gray-400 text-sm cursor-pointer",
+                        input {
+                            r#type: "checkbox",
+                            checked: draft.read().known_to_player,
+                            onchange: move |e| draft.write().known_to_player = e.checked(),
+                        }
+                        "Known to players"
+                    }
+
+                    div {
+                        class: "flex justify-end gap-2",
+                        button {
+                            r#type: "button",
+                            onclick: move |_| editing.set(None),
+                            class: "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm",
+                            "Cancel"
+                        }
+                        button {
+                            r#type: "button",
+                            disabled: *is_saving.read(),
+                            onclick: save,
+                            class: "px-3 py-1 bg-green-500 text-white border-none rounded cursor-pointer text-sm disabled:opacity-60",
+                            if *is_saving.read() { "Saving..." } else { "Save" }
+                        }
+                    }
+                }
+            } else {
+                button {
+                    r#type: "button",
+                    onclick: {
+                        let character_id = character_id.clone();
+                        move |_| {
+                            draft.set(SaveRelationshipRequest::new(&character_id, "", RelationshipKind::Ally));
+                            editing.set(Some(Editing::New));
+                        }
+                    },
+                    class: "self-start px-3 py-1 bg-purple-500/20 text-purple-300 border border-dashed border-purple-500 rounded cursor-pointer text-sm",
+                    "+ Add relationship"
+                }
+            }
+        }
+    }
+}
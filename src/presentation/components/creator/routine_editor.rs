@@ -0,0 +1,94 @@
+//! Routine editor - an NPC's day laid out as a grid
+//!
+//! One column per part of the day, each with a location and an activity.
+//! The Director's whereabouts panel reads the same routine against the
+//! world clock.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{routine_entry_for, set_routine_entry, DayPeriod, RoutineEntry};
+use crate::application::services::LocationSummary;
+
+/// Day grid for editing a character's routine
+#[component]
+pub fn RoutineEditor(
+    routine: Vec<RoutineEntry>,
+    /// Locations in the world, for the location pickers
+    locations: Vec<LocationSummary>,
+    on_change: EventHandler<Vec<RoutineEntry>>,
+) -> Element {
+    rsx! {
+        div {
+            class: "routine-editor grid grid-cols-2 md:grid-cols-4 gap-2",
+
+            for period in DayPeriod::all() {
+                {
+                    let entry = routine_entry_for(&routine, period).cloned().unwrap_or(RoutineEntry {
+                        period,
+                        location_id: None,
+                        activity: String::new(),
+                    });
+                    let filled = !entry.is_empty();
+                    let cell_class = if filled {
+                        "border-purple-500/60 bg-purple-500/10"
+                    } else {
+                        "border-gray-700 bg-dark-bg"
+                    };
+                    let location_value = entry.location_id.clone().unwrap_or_default();
+                    rsx! {
+                        div {
+                            key: "{period.display_name()}",
+                            class: "flex flex-col gap-2 p-2 border rounded {cell_class}",
+
+                            div { class: "text-gray-400 text-xs uppercase", "{period.display_name()}" }
+
+                            select {
+                                value: "{location_value}",
+                                onchange: {
+                                    let routine = routine.clone();
+                                    let entry = entry.clone();
+                                    move |e: FormEvent| {
+                                        let value = e.value();
+                                        let mut next = routine.clone();
+                                        set_routine_entry(&mut next, RoutineEntry {
+                                            location_id: if value.is_empty() { None } else { Some(value) },
+                                            ..entry.clone()
+                                        });
+                                        on_change.call(next);
+                                    }
+                                },
+                                class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+
+                                option { value: "", "No location" }
+                                for location in locations.iter() {
+                                    option {
+                                        key: "{location.id}",
+                                        value: "{location.id}",
+                                        selected: location.id == location_value,
+                                        "{location.name}"
+                                    }
+                                }
+                            }
+
+                            input {
+                                r#type: "text",
+                                value: "{entry.activity}",
+                                placeholder: "Activity...",
+                                oninput: {
+                                    let routine = routine.clone();
+                                    let entry = entry.clone();
+                                    move |e: FormEvent| {
+                                        let mut next = routine.clone();
+                                        set_routine_entry(&mut next, RoutineEntry { activity: e.value(), ..entry.clone() });
+                                        on_change.call(next);
+                                    }
+                                },
+                                class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
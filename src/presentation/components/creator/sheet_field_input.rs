@@ -6,6 +6,7 @@ use std::collections::HashMap;
 use crate::application::dto::{
     FieldType, FieldValue, SheetField, SheetSection, SheetTemplate,
 };
+use crate::presentation::components::common::FieldError;
 
 /// Props for the sheet section renderer
 #[derive(Props, Clone, PartialEq)]
@@ -15,6 +16,9 @@ pub struct SheetSectionProps {
     pub on_change: EventHandler<(String, FieldValue)>,
     #[props(default = false)]
     pub read_only: bool,
+    /// Validation messages to show, keyed by field ID
+    #[props(default)]
+    pub errors: HashMap<String, String>,
 }
 
 /// Renders a section of the character sheet
@@ -78,6 +82,7 @@ pub fn SheetSectionInput(props: SheetSectionProps) -> Element {
                         {
                             let field_id = field.id.clone();
                             let current_value = props.values.get(&field_id).cloned();
+                            let error = props.errors.get(&field_id).cloned();
                             let on_change = props.on_change.clone();
                             rsx! {
                                 SheetFieldInput {
@@ -86,6 +91,7 @@ pub fn SheetSectionInput(props: SheetSectionProps) -> Element {
                                     value: current_value,
                                     on_change: move |value| on_change.call((field_id.clone(), value)),
                                     read_only: props.read_only,
+                                    error,
                                 }
                             }
                         }
@@ -104,6 +110,8 @@ pub struct SheetFieldInputProps {
     pub on_change: EventHandler<FieldValue>,
     #[props(default = false)]
     pub read_only: bool,
+    #[props(default)]
+    pub error: Option<String>,
 }
 
 /// Renders a single field input based on its type
@@ -399,6 +407,8 @@ pub fn SheetFieldInput(props: SheetFieldInputProps) -> Element {
                     "{desc}"
                 }
             }
+
+            FieldError { message: props.error.clone() }
         }
     }
 }
@@ -414,6 +424,9 @@ pub struct CharacterSheetFormProps {
     pub on_values_change: EventHandler<HashMap<String, FieldValue>>,
     #[props(default = false)]
     pub read_only: bool,
+    /// Validation messages to show, keyed by field ID
+    #[props(default)]
+    pub errors: HashMap<String, String>,
 }
 
 /// Renders the entire character sheet form based on a template
@@ -437,6 +450,7 @@ pub fn CharacterSheetForm(props: CharacterSheetFormProps) -> Element {
                             values: props.values.clone(),
                             on_change: props.on_change.clone(),
                             read_only: props.read_only,
+                            errors: props.errors.clone(),
                         }
                     }
                 }
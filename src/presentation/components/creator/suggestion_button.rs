@@ -1,14 +1,16 @@
 //! Suggestion Button - LLM-powered content suggestions
 //!
 //! A reusable button component that fetches suggestions from the Engine
-//! and displays them in a dropdown for selection.
+//! and displays them in a dropdown for selection. Text streams into the
+//! dropdown as it is written and can be cancelled part way.
 
 use dioxus::prelude::*;
 
 pub use crate::application::services::SuggestionContext;
+use crate::application::services::SuggestionFeedback;
 use crate::application::ports::outbound::Platform;
 use crate::presentation::services::use_suggestion_service;
-use crate::presentation::state::use_generation_state;
+use crate::presentation::state::{use_generation_state, use_session_state};
 
 /// Types of suggestions that can be requested
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -52,27 +54,54 @@ pub fn SuggestionButton(
     suggestion_type: SuggestionType,
     /// World ID for routing - required to receive WebSocket response
     world_id: String,
+    /// ID of the entity being edited; unset while it's still unsaved
+    #[props(default)]
+    entity_id: Option<String>,
     context: SuggestionContext,
     on_select: EventHandler<String>,
 ) -> Element {
     let platform = use_context::<Platform>();
     let suggestion_service = use_suggestion_service();
     let mut generation_state = use_generation_state();
+    let engine_health = use_session_state().engine_health();
     let mut loading = use_signal(|| false);
     let mut request_id: Signal<Option<String>> = use_signal(|| None);
     let mut suggestions: Signal<Vec<String>> = use_signal(Vec::new);
     let mut show_dropdown = use_signal(|| false);
+    // Text is still arriving; options can't be picked yet
+    let mut streaming = use_signal(|| false);
     let mut error: Signal<Option<String>> = use_signal(|| None);
+    // Which suggestion ended up in the field (1-based for display)
+    let mut applied_number: Signal<Option<usize>> = use_signal(|| None);
+    // After a reload, fall back to what the Engine's queue says this
+    // entity's field took
+    let field_type = suggestion_type.to_field_type();
+    let badge_entity_id = entity_id.clone();
+    let restored_number = use_memo(move || {
+        let entity_id = badge_entity_id.as_deref()?;
+        generation_state
+            .get_suggestions()
+            .iter()
+            .rev()
+            .filter(|s| s.field_type == field_type && s.entity_id.as_deref() == Some(entity_id))
+            .find_map(|s| s.applied_index)
+            .map(|i| i + 1)
+    });
 
     // Watch for suggestion completion from queue
-    let field_type = suggestion_type.to_field_type();
     use_effect(move || {
         if let Some(req_id) = request_id.read().as_ref() {
             // Check if this suggestion is ready
             let all_suggestions = generation_state.get_suggestions();
             if let Some(task) = all_suggestions.iter().find(|s| s.request_id == *req_id) {
                 match &task.status {
+                    crate::presentation::state::SuggestionStatus::Streaming { partial } => {
+                        suggestions.set(partial.clone());
+                        show_dropdown.set(true);
+                        streaming.set(true);
+                    }
                     crate::presentation::state::SuggestionStatus::Ready { suggestions: results } => {
+                        streaming.set(false);
                         if !results.is_empty() {
                             suggestions.set(results.clone());
                             show_dropdown.set(true);
@@ -81,6 +110,8 @@ pub fn SuggestionButton(
                     }
                     crate::presentation::state::SuggestionStatus::Failed { error: err } => {
                         error.set(Some(err.clone()));
+                        streaming.set(false);
+                        show_dropdown.set(false);
                         loading.set(false);
                     }
                     _ => {
@@ -101,12 +132,14 @@ pub fn SuggestionButton(
         let plat = platform.clone();
         let field_type_str = field_type.to_string();
         let world_id = world_id.clone();
+        let entity_id = entity_id.clone();
         move |_| {
             let context = context.clone();
             let field_type = field_type_str.clone();
             let service = svc.clone();
             let platform = plat.clone();
             let world_id = world_id.clone();
+            let entity_id = entity_id.clone();
 
             spawn(async move {
                 loading.set(true);
@@ -116,7 +149,7 @@ pub fn SuggestionButton(
                 platform.log_info(&format!("Enqueueing suggestion request for {}", field_type));
 
                 // Enqueue the suggestion request
-                match service.enqueue_suggestion(&field_type, &world_id, &context).await {
+                match service.enqueue_suggestion(&field_type, &world_id, entity_id.as_deref(), &context).await {
                     Ok(req_id) => {
                         platform.log_info(&format!("Suggestion request queued: {}", req_id));
                         request_id.set(Some(req_id.clone()));
@@ -125,7 +158,7 @@ pub fn SuggestionButton(
                         generation_state.add_suggestion_task(
                             req_id,
                             field_type,
-                            None, // entity_id not available here
+                            entity_id,
                             Some(context.clone()), // Store context for retry
                             Some(world_id.clone()), // Store world_id for retry
                         );
@@ -140,6 +173,36 @@ pub fn SuggestionButton(
         }
     };
 
+    // Stop a request mid-stream; the Engine reports it as failed, which
+    // this button no longer watches for
+    let cancel_request = {
+        let svc = suggestion_service.clone();
+        let plat = platform.clone();
+        move |_| {
+            let Some(req_id) = request_id.read().clone() else {
+                return;
+            };
+            request_id.set(None);
+            loading.set(false);
+            streaming.set(false);
+            show_dropdown.set(false);
+            suggestions.set(Vec::new());
+            let service = svc.clone();
+            let platform = plat.clone();
+            spawn(async move {
+                if let Err(e) = service.cancel_suggestion(&req_id).await {
+                    platform.log_error(&format!("Failed to cancel suggestion {}: {}", req_id, e));
+                }
+            });
+        }
+    };
+
+    // Suggestions come from the LLM; hide the button while it's down
+    // unless a request is already in flight
+    if engine_health.read().modes().manual_dialogue && request_id.read().is_none() {
+        return rsx! {};
+    }
+
     rsx! {
         div {
             class: "suggestion-button-container relative inline-block",
@@ -150,13 +213,34 @@ pub fn SuggestionButton(
                 disabled: *loading.read() || request_id.read().is_some(),
                 class: "py-2 px-3 bg-purple-500 text-white border-0 rounded cursor-pointer text-xs whitespace-nowrap transition-colors",
                 onmouseenter: move |_| {},  // Could add hover state
-                if *loading.read() || request_id.read().is_some() {
+                if *streaming.read() {
+                    "Writing..."
+                } else if *loading.read() || request_id.read().is_some() {
                     "Queued..."
                 } else {
                     "Suggest"
                 }
             }
 
+            // Cancel while the request is queued or streaming
+            if *loading.read() {
+                button {
+                    onclick: cancel_request,
+                    aria_label: "Cancel suggestion",
+                    class: "ml-1 py-2 px-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-xs",
+                    "×"
+                }
+            }
+
+            // Which suggestion this field took
+            if let Some(number) = applied_number.read().or(*restored_number.read()) {
+                span {
+                    class: "ml-1 px-1.5 py-0.5 bg-green-500/15 text-green-400 rounded text-[0.65rem] whitespace-nowrap",
+                    title: "Suggestion {number} was applied to this field",
+                    "✓ #{number}"
+                }
+            }
+
             // Error tooltip
             if let Some(err) = error.read().as_ref() {
                 div {
@@ -181,11 +265,32 @@ pub fn SuggestionButton(
                         SuggestionItem {
                             key: "{i}",
                             text: suggestion.clone(),
+                            streaming: *streaming.read(),
                             on_click: {
                                 let suggestion = suggestion.clone();
+                                let service = suggestion_service.clone();
+                                let platform = platform.clone();
                                 move |_| {
+                                    if *streaming.read() {
+                                        return;
+                                    }
                                     on_select.call(suggestion.clone());
                                     show_dropdown.set(false);
+                                    applied_number.set(Some(i + 1));
+
+                                    // Tell the Engine which option was used
+                                    let Some(req_id) = request_id.read().clone() else {
+                                        return;
+                                    };
+                                    generation_state.mark_suggestion_applied(&req_id, i);
+                                    let service = service.clone();
+                                    let platform = platform.clone();
+                                    spawn(async move {
+                                        let feedback = SuggestionFeedback { applied_index: Some(i), ..Default::default() };
+                                        if let Err(e) = service.send_feedback(&req_id, &feedback).await {
+                                            platform.log_warn(&format!("Failed to send suggestion feedback: {}", e));
+                                        }
+                                    });
                                 }
                             },
                         }
@@ -197,17 +302,31 @@ pub fn SuggestionButton(
 }
 
 /// Individual suggestion item in the dropdown
+///
+/// While `streaming`, the text is still being written and shows a cursor.
 #[component]
-fn SuggestionItem(text: String, on_click: EventHandler<()>) -> Element {
+fn SuggestionItem(
+    text: String,
+    #[props(default)]
+    streaming: bool,
+    on_click: EventHandler<()>,
+) -> Element {
     rsx! {
         div {
             onclick: move |_| on_click.call(()),
-            class: "py-3 px-4 text-gray-200 cursor-pointer border-b border-gray-700 transition-colors",
+            class: if streaming {
+                "py-3 px-4 text-gray-400 cursor-default border-b border-gray-700 transition-colors"
+            } else {
+                "py-3 px-4 text-gray-200 cursor-pointer border-b border-gray-700 transition-colors"
+            },
             onmouseenter: move |_evt| {
                 // Would be nice to highlight on hover, but we can't easily change style here
                 // In real app, would use a class and CSS hover state
             },
             "{text}"
+            if streaming {
+                span { class: "animate-pulse text-purple-400", "▍" }
+            }
         }
     }
 }
@@ -218,6 +337,9 @@ pub fn SuggestIcon(
     suggestion_type: SuggestionType,
     /// World ID for routing - required to receive WebSocket response
     world_id: String,
+    /// ID of the entity being edited; unset while it's still unsaved
+    #[props(default)]
+    entity_id: Option<String>,
     context: SuggestionContext,
     on_select: EventHandler<String>,
 ) -> Element {
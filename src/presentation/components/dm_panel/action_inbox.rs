@@ -0,0 +1,138 @@
+//! Action inbox - Answer play-by-post actions posted while the DM was away
+//!
+//! The DM turns play-by-post on here, then works through the posted
+//! actions oldest first, a batch at a time. The Engine generates each
+//! response and sends it for approval like any other, so a batch's
+//! responses can be accepted together from the approvals list.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{next_inbox_batch, SessionCommandService};
+use crate::presentation::components::common::time_ago;
+use crate::presentation::state::use_session_state;
+
+/// Batch sizes offered; the last answers everything waiting
+const BATCH_SIZES: &[usize] = &[1, 3, 5, usize::MAX];
+
+/// Play-by-post switch and the inbox of posted actions
+#[component]
+pub fn ActionInboxPanel() -> Element {
+    let session_state = use_session_state();
+    let platform = use_context::<Platform>();
+    let mut enabled = session_state.async_play.enabled;
+    let mut batch_size = use_signal(|| 3usize);
+
+    let inbox = session_state.async_play.inbox.read().clone();
+    let has_client = session_state.has_client();
+    let waiting = inbox.iter().filter(|a| !a.processing).count();
+    let now = platform.now_unix_secs();
+
+    let engine_client = session_state.engine_client();
+    let command = move || engine_client.read().as_ref().map(|c| SessionCommandService::new(std::sync::Arc::clone(c)));
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 text-sm",
+
+            label {
+                class: "flex items-center gap-2 text-gray-300 cursor-pointer",
+                input {
+                    r#type: "checkbox",
+                    checked: *enabled.read(),
+                    disabled: !has_client,
+                    onchange: move |e| {
+                        let on = e.checked();
+                        let Some(service) = command() else {
+                            return;
+                        };
+                        match service.set_async_play(on) {
+                            Ok(()) => enabled.set(on),
+                            Err(e) => tracing::error!("Failed to change play-by-post: {}", e),
+                        }
+                    },
+                }
+                "Play-by-post: players can act while I'm away"
+            }
+
+            if inbox.is_empty() {
+                p { class: "text-gray-500 text-xs m-0", "No posted actions waiting" }
+            } else {
+                div {
+                    class: "flex items-center gap-2",
+                    select {
+                        aria_label: "Actions per batch",
+                        value: "{batch_size}",
+                        onchange: move |e| batch_size.set(e.value().parse().unwrap_or(1)),
+                        class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                        for size in BATCH_SIZES.iter().copied() {
+                            option {
+                                value: "{size}",
+                                if size == usize::MAX { "All" } else { "{size}" }
+                            }
+                        }
+                    }
+                    button {
+                        r#type: "button",
+                        disabled: !has_client || waiting == 0,
+                        onclick: {
+                            let inbox = inbox.clone();
+                            move |_| {
+                                let batch = next_inbox_batch(&inbox, *batch_size.read());
+                                if batch.is_empty() {
+                                    return;
+                                }
+                                if let Some(service) = command() {
+                                    if let Err(e) = service.process_inbox_actions(batch) {
+                                        tracing::error!("Failed to process inbox actions: {}", e);
+                                    }
+                                }
+                            }
+                        },
+                        class: "flex-1 px-3 py-1 bg-transparent text-blue-400 border border-blue-500/40 rounded cursor-pointer text-xs disabled:opacity-50",
+                        "Answer next batch ({waiting} waiting)"
+                    }
+                }
+
+                ol {
+                    class: "list-none m-0 p-0 flex flex-col gap-1",
+                    for action in inbox.iter() {
+                        li {
+                            key: "{action.action_id}",
+                            class: "p-2 bg-dark-bg rounded flex items-start gap-2",
+                            div {
+                                class: "flex-1 min-w-0",
+                                div {
+                                    class: "flex items-center gap-2",
+                                    span { class: "text-white font-medium", "{action.pc_name}" }
+                                    span { class: "text-gray-500 text-xs", "{time_ago(action.posted_at, now)}" }
+                                    if action.processing {
+                                        span { class: "text-amber-400 text-xs", "answering…" }
+                                    }
+                                }
+                                p { class: "text-gray-300 text-xs m-0 break-words", "{action.summary()}" }
+                            }
+                            button {
+                                r#type: "button",
+                                aria_label: "Discard {action.pc_name}'s action",
+                                disabled: !has_client || action.processing,
+                                onclick: {
+                                    let action_id = action.action_id.clone();
+                                    move |_| {
+                                        if let Some(service) = command() {
+                                            if let Err(e) = service.discard_inbox_action(&action_id) {
+                                                tracing::error!("Failed to discard inbox action: {}", e);
+                                            }
+                                        }
+                                    }
+                                },
+                                class: "px-2 py-0.5 bg-transparent text-gray-400 border-none cursor-pointer disabled:opacity-50",
+                                "×"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,11 +1,16 @@
 //! Ad-hoc Challenge Modal Component
 //!
 //! Allows DM to create custom challenges on-the-fly without LLM involvement.
-//! The DM specifies all challenge details including outcomes.
+//! The DM specifies all challenge details including outcomes. Opened from
+//! a conversation line, the form starts from a `ChallengeDraft` guessed
+//! from what was said.
 
 use dioxus::prelude::*;
 use crate::application::dto::AdHocOutcomes;
 use crate::application::dto::websocket_messages::SceneCharacterState;
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{ChallengeDraft, SessionCommandService};
+use crate::presentation::state::{use_game_state, use_session_state, use_modal_focus};
 
 /// Data for an ad-hoc challenge creation
 #[derive(Debug, Clone, PartialEq)]
@@ -26,6 +31,9 @@ pub struct AdHocChallengeModalProps {
     pub on_create: EventHandler<AdHocChallengeData>,
     /// Called when modal should close
     pub on_close: EventHandler<()>,
+    /// Starting values taken from conversation text
+    #[props(default)]
+    pub draft: Option<ChallengeDraft>,
 }
 
 /// AdHocChallengeModal component
@@ -38,10 +46,12 @@ pub struct AdHocChallengeModalProps {
 /// - Custom outcomes (success, failure, optional criticals)
 #[component]
 pub fn AdHocChallengeModal(props: AdHocChallengeModalProps) -> Element {
-    let mut challenge_name = use_signal(|| String::new());
-    let mut skill_name = use_signal(|| String::new());
-    let mut difficulty = use_signal(|| String::new());
-    let mut selected_pc = use_signal(|| String::new());
+    use_modal_focus("adhoc-modal");
+    let draft = props.draft.clone().unwrap_or_default();
+    let mut challenge_name = use_signal(|| draft.challenge_name.clone());
+    let mut skill_name = use_signal(|| draft.skill_name.clone());
+    let mut difficulty = use_signal(|| draft.difficulty.clone());
+    let mut selected_pc = use_signal(|| draft.target_pc_id.clone().unwrap_or_default());
     let mut success_outcome = use_signal(|| String::new());
     let mut failure_outcome = use_signal(|| String::new());
     let mut critical_success = use_signal(|| String::new());
@@ -75,6 +85,8 @@ pub fn AdHocChallengeModal(props: AdHocChallengeModalProps) -> Element {
             // Modal content
             div {
                 id: "adhoc-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "bg-gradient-to-br from-dark-surface to-dark-bg p-8 rounded-2xl max-w-[600px] w-[90%] border-2 border-purple-600 max-h-[90vh] overflow-y-auto",
                 onclick: move |evt| evt.stop_propagation(),
 
@@ -94,6 +106,14 @@ pub fn AdHocChallengeModal(props: AdHocChallengeModalProps) -> Element {
                     }
                 }
 
+                // The line the draft was taken from
+                if !draft.context.is_empty() {
+                    blockquote {
+                        class: "m-0 mb-6 pl-3 border-l-2 border-purple-600 text-gray-400 text-sm italic",
+                        "\"{draft.context}\""
+                    }
+                }
+
                 // Challenge details section
                 div {
                     class: "grid grid-cols-2 gap-4 mb-6",
@@ -157,7 +177,7 @@ pub fn AdHocChallengeModal(props: AdHocChallengeModalProps) -> Element {
                             option {
                                 value: "",
                                 disabled: true,
-                                selected: true,
+                                selected: selected_pc.read().is_empty(),
                                 "Select PC..."
                             }
 
@@ -322,3 +342,55 @@ pub fn AdHocChallengeModal(props: AdHocChallengeModalProps) -> Element {
         }
     }
 }
+
+/// AdHocChallengeModal wired to the SessionCommandService and the current
+/// scene's characters
+#[component]
+pub fn AdHocChallengeEntryPoint(
+    on_close: EventHandler<()>,
+    #[props(default)] draft: Option<ChallengeDraft>,
+) -> Element {
+    let mut session_state = use_session_state();
+    let game_state = use_game_state();
+    let platform = use_context::<Platform>();
+
+    let player_characters = game_state.scene_characters.read().clone();
+    let engine_client = session_state.engine_client();
+
+    rsx! {
+        AdHocChallengeModal {
+            player_characters: player_characters.clone(),
+            draft,
+            on_create: move |data: AdHocChallengeData| {
+                let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+                    tracing::warn!("No Engine client available for ad-hoc challenge");
+                    return;
+                };
+                let svc = SessionCommandService::new(client);
+                if let Err(e) = svc.create_adhoc_challenge(
+                    &data.challenge_name,
+                    &data.skill_name,
+                    &data.difficulty,
+                    &data.target_pc_id,
+                    data.outcomes,
+                ) {
+                    tracing::error!("Failed to create ad-hoc challenge: {}", e);
+                    return;
+                }
+
+                // Add a quick log entry for instant feedback
+                let target = player_characters
+                    .iter()
+                    .find(|c| c.id == data.target_pc_id)
+                    .map_or(data.target_pc_id.as_str(), |c| c.name.as_str());
+                session_state.add_challenge_log_entry(
+                    format!("Ad-hoc challenge '{}' created for {}", data.challenge_name, target),
+                    &platform,
+                );
+
+                on_close.call(());
+            },
+            on_close: move |_| on_close.call(()),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Advancement grant - DM awards XP or milestones to a player character
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::AdvancementGrant;
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::use_session_state;
+
+/// Inline form for granting XP or a milestone
+#[component]
+pub fn AdvancementGrantForm(pc_id: String) -> Element {
+    let session_state = use_session_state();
+    let mut milestone_mode = use_signal(|| false);
+    let mut amount = use_signal(String::new);
+    let mut milestone_name = use_signal(String::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let has_client = session_state.has_client();
+    let engine_client = session_state.engine_client();
+
+    let grant = move |_| {
+        let next = if *milestone_mode.read() {
+            let name = milestone_name.read().trim().to_string();
+            if name.is_empty() {
+                error.set(Some("Name the milestone".to_string()));
+                return;
+            }
+            AdvancementGrant::Milestone { name }
+        } else {
+            match amount.read().trim().parse::<u32>() {
+                Ok(amount) if amount > 0 => AdvancementGrant::Experience { amount },
+                _ => {
+                    error.set(Some("Enter a positive amount of XP".to_string()));
+                    return;
+                }
+            }
+        };
+        let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+            return;
+        };
+        match SessionCommandService::new(client).grant_advancement(&pc_id, next) {
+            Ok(()) => {
+                error.set(None);
+                amount.set(String::new());
+                milestone_name.set(String::new());
+            }
+            Err(e) => error.set(Some(format!("Failed to grant advancement: {}", e))),
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            div {
+                class: "flex items-center gap-2",
+                select {
+                    value: if *milestone_mode.read() { "milestone" } else { "xp" },
+                    onchange: move |e| milestone_mode.set(e.value() == "milestone"),
+                    class: "p-1 bg-dark-surface border border-gray-700 rounded text-white text-xs",
+                    option { value: "xp", "XP" }
+                    option { value: "milestone", "Milestone" }
+                }
+                if *milestone_mode.read() {
+                    input {
+                        r#type: "text",
+                        placeholder: "Milestone name",
+                        value: "{milestone_name}",
+                        oninput: move |e| milestone_name.set(e.value()),
+                        class: "flex-1 p-1 bg-dark-surface border border-gray-700 rounded text-white text-xs",
+                    }
+                } else {
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        placeholder: "Amount",
+                        value: "{amount}",
+                        oninput: move |e| amount.set(e.value()),
+                        class: "flex-1 p-1 bg-dark-surface border border-gray-700 rounded text-white text-xs",
+                    }
+                }
+                button {
+                    disabled: !has_client,
+                    onclick: grant,
+                    class: "px-3 py-1 bg-amber-500 text-white border-0 rounded cursor-pointer text-xs disabled:opacity-50",
+                    "Grant"
+                }
+            }
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-500 text-xs", "{err}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,493 @@
+//! Annotation studio - DM draws over the current backdrop
+//!
+//! Freehand strokes, arrows, pins and text labels go to the Engine as each
+//! one is finished and come back to every client as `AnnotationsUpdated`,
+//! so players see them appear live. Each mark belongs to a layer, and
+//! layers can be hidden from players or cleared in one go.
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{
+    annotation_at, simplify_stroke, Annotation, AnnotationLayer, AnnotationPoint, AnnotationShape,
+    AnnotationSurface, SurfaceAnnotations,
+};
+use crate::application::services::SessionCommandService;
+use crate::presentation::components::common::{AnnotationMark, AnnotationSvg};
+use crate::presentation::state::{use_game_state, use_modal_focus, use_session_state, SessionState};
+
+/// Colors offered in the toolbar
+const PALETTE: &[&str] = &["#ef4444", "#f59e0b", "#22c55e", "#3b82f6", "#a855f7", "#ffffff"];
+
+/// How close (as a fraction of the surface) the eraser must be to a mark
+const ERASE_RADIUS: f32 = 0.03;
+
+/// Freehand points closer together than this are dropped before sending
+const STROKE_RESOLUTION: f32 = 0.004;
+
+/// Drawing tools
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AnnotationTool {
+    Freehand,
+    Arrow,
+    Pin,
+    Text,
+    Erase,
+}
+
+impl AnnotationTool {
+    fn all() -> [Self; 5] {
+        [Self::Freehand, Self::Arrow, Self::Pin, Self::Text, Self::Erase]
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Freehand => "✏️ Draw",
+            Self::Arrow => "➜ Arrow",
+            Self::Pin => "📍 Pin",
+            Self::Text => "T Text",
+            Self::Erase => "🧽 Erase",
+        }
+    }
+}
+
+/// Modal for drawing over the backdrop of the region in play
+#[component]
+pub fn AnnotationStudio(on_close: EventHandler<()>) -> Element {
+    use_modal_focus("annotation-studio");
+
+    let game_state = use_game_state();
+    let session_state = use_session_state();
+    let mut tool = use_signal(|| AnnotationTool::Freehand);
+    let mut color = use_signal(|| PALETTE[0].to_string());
+    let mut label = use_signal(String::new);
+    let mut active_layer: Signal<Option<String>> = use_signal(|| None);
+    let mut stroke: Signal<Vec<AnnotationPoint>> = use_signal(Vec::new);
+    let mut arrow: Signal<Option<(AnnotationPoint, AnnotationPoint)>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut canvas: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    // Canvas position and size on screen: (left, top, width, height)
+    let mut canvas_rect: Signal<Option<(f64, f64, f64, f64)>> = use_signal(|| None);
+
+    let region = game_state.current_region.read().clone();
+    let Some(region) = region else {
+        return rsx! {
+            StudioFrame {
+                on_close,
+                p { class: "text-gray-400 text-sm text-center m-0 p-8", "No region is in play yet. Annotations are drawn on the backdrop of the region the party is in." }
+            }
+        };
+    };
+
+    let surface = AnnotationSurface::Backdrop { region_id: region.id.clone() };
+    let surface_annotations = game_state.annotations_for(&surface);
+    let backdrop = region.backdrop_asset.clone().or_else(|| game_state.backdrop_url());
+    let layers = surface_annotations.layers.clone();
+    let current_layer = active_layer
+        .read()
+        .clone()
+        .filter(|id| layers.iter().any(|l| &l.id == id))
+        .or_else(|| layers.first().map(|l| l.id.clone()));
+
+    let refresh_rect = move || {
+        spawn(async move {
+            let Some(element) = canvas.peek().clone() else {
+                return;
+            };
+            if let Ok(rect) = element.get_client_rect().await {
+                canvas_rect.set(Some((rect.origin.x, rect.origin.y, rect.size.width, rect.size.height)));
+            }
+        });
+    };
+    let point_at = move |x: f64, y: f64| {
+        let (left, top, width, height) = (*canvas_rect.peek())?;
+        if width <= 0.0 || height <= 0.0 {
+            return None;
+        }
+        Some(AnnotationPoint::new(((x - left) / width) as f32, ((y - top) / height) as f32))
+    };
+
+    // The layer new marks go on, creating a first layer if there is none
+    let mut target_layer = {
+        let session_state = session_state.clone();
+        let surface = surface.clone();
+        let current_layer = current_layer.clone();
+        move || -> Result<String, String> {
+            if let Some(id) = current_layer.clone() {
+                return Ok(id);
+            }
+            let layer = AnnotationLayer {
+                id: uuid::Uuid::new_v4().to_string(),
+                name: "Layer 1".to_string(),
+                visible_to_players: true,
+            };
+            let id = layer.id.clone();
+            send(&session_state, |svc| svc.set_annotation_layer(surface.clone(), layer))?;
+            active_layer.set(Some(id.clone()));
+            Ok(id)
+        }
+    };
+    let commit = {
+        let session_state = session_state.clone();
+        let surface = surface.clone();
+        move |shape: AnnotationShape| {
+            let result = target_layer().and_then(|layer_id| {
+                let annotation = Annotation {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    layer_id,
+                    color: color.peek().clone(),
+                    shape,
+                };
+                send(&session_state, |svc| svc.add_annotation(surface.clone(), annotation))
+            });
+            if let Err(e) = result {
+                error.set(Some(e));
+            }
+        }
+    };
+
+    let on_down = {
+        let session_state = session_state.clone();
+        let surface = surface.clone();
+        let surface_annotations = surface_annotations.clone();
+        let mut commit = commit.clone();
+        move |e: PointerEvent| {
+            let coords = e.client_coordinates();
+            let Some(point) = point_at(coords.x, coords.y) else {
+                return;
+            };
+            error.set(None);
+            match *tool.peek() {
+                AnnotationTool::Freehand => stroke.set(vec![point]),
+                AnnotationTool::Arrow => arrow.set(Some((point, point))),
+                AnnotationTool::Pin => {
+                    let text = label.peek().trim().to_string();
+                    commit(AnnotationShape::Pin { at: point, label: (!text.is_empty()).then_some(text) });
+                }
+                AnnotationTool::Text => {
+                    let text = label.peek().trim().to_string();
+                    if text.is_empty() {
+                        error.set(Some("Type the label text first".to_string()));
+                    } else {
+                        commit(AnnotationShape::Text { at: point, text });
+                    }
+                }
+                AnnotationTool::Erase => {
+                    let marks = surface_annotations.visible(true);
+                    if let Some(mark) = annotation_at(&marks, &point, ERASE_RADIUS) {
+                        let id = mark.id.clone();
+                        if let Err(e) = send(&session_state, |svc| svc.remove_annotation(surface.clone(), &id)) {
+                            error.set(Some(e));
+                        }
+                    }
+                }
+            }
+        }
+    };
+    let on_move = move |e: PointerEvent| {
+        if stroke.peek().is_empty() && arrow.peek().is_none() {
+            return;
+        }
+        let coords = e.client_coordinates();
+        let Some(point) = point_at(coords.x, coords.y) else {
+            return;
+        };
+        if !stroke.peek().is_empty() {
+            stroke.write().push(point);
+        }
+        let drawing_arrow = *arrow.peek();
+        if let Some((from, _)) = drawing_arrow {
+            arrow.set(Some((from, point)));
+        }
+    };
+    let mut finish = {
+        let mut commit = commit.clone();
+        move || {
+            let points = stroke.peek().clone();
+            stroke.set(Vec::new());
+            if points.len() > 1 {
+                commit(AnnotationShape::Freehand { points: simplify_stroke(&points, STROKE_RESOLUTION) });
+            }
+            let drawn_arrow = *arrow.peek();
+            if let Some((from, to)) = drawn_arrow {
+                arrow.set(None);
+                if from != to {
+                    commit(AnnotationShape::Arrow { from, to });
+                }
+            }
+        }
+    };
+    let mut finish_on_leave = finish.clone();
+
+    // The mark being drawn, shown before it goes to the Engine
+    let preview = {
+        let points = stroke.read().clone();
+        let shape = if points.len() > 1 {
+            Some(AnnotationShape::Freehand { points })
+        } else {
+            arrow.read().as_ref().map(|&(from, to)| AnnotationShape::Arrow { from, to })
+        };
+        shape.map(|shape| Annotation {
+            id: "preview".to_string(),
+            layer_id: String::new(),
+            color: color.read().clone(),
+            shape,
+        })
+    };
+    let marks: Vec<Annotation> = surface_annotations.visible(true).into_iter().cloned().collect();
+    let canvas_style = match &backdrop {
+        Some(url) => format!("background-image: url('{}'); background-size: cover; background-position: center;", url),
+        None => String::new(),
+    };
+    let cursor_class = if *tool.read() == AnnotationTool::Erase { "cursor-cell" } else { "cursor-crosshair" };
+
+    rsx! {
+        StudioFrame {
+            on_close,
+
+            // Toolbar
+            div {
+                class: "flex flex-wrap items-center gap-2",
+                role: "toolbar",
+                aria_label: "Annotation tools",
+
+                for t in AnnotationTool::all() {
+                    button {
+                        key: "{t.label()}",
+                        onclick: move |_| tool.set(t),
+                        aria_pressed: if *tool.read() == t { "true" } else { "false" },
+                        class: if *tool.read() == t {
+                            "px-2 py-1 bg-purple-600 text-white border-0 rounded text-xs cursor-pointer"
+                        } else {
+                            "px-2 py-1 bg-dark-bg text-gray-300 border border-gray-700 rounded text-xs cursor-pointer"
+                        },
+                        "{t.label()}"
+                    }
+                }
+
+                div {
+                    class: "flex gap-1 ml-2",
+                    for swatch in PALETTE.iter() {
+                        button {
+                            key: "{swatch}",
+                            onclick: move |_| color.set(swatch.to_string()),
+                            aria_label: "Color {swatch}",
+                            aria_pressed: if color.read().as_str() == *swatch { "true" } else { "false" },
+                            class: if color.read().as_str() == *swatch {
+                                "w-5 h-5 rounded-full border-2 border-white cursor-pointer"
+                            } else {
+                                "w-5 h-5 rounded-full border border-gray-600 cursor-pointer"
+                            },
+                            style: "background-color: {swatch};",
+                        }
+                    }
+                }
+
+                if matches!(*tool.read(), AnnotationTool::Pin | AnnotationTool::Text) {
+                    input {
+                        r#type: "text",
+                        placeholder: if *tool.read() == AnnotationTool::Pin { "Pin label (optional)" } else { "Label text" },
+                        aria_label: "Label",
+                        value: "{label}",
+                        oninput: move |e| label.set(e.value()),
+                        class: "flex-1 min-w-[140px] p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-500 text-xs", "{err}" }
+            }
+
+            // Canvas
+            div {
+                class: "relative w-full aspect-video bg-dark-bg rounded-lg overflow-hidden select-none touch-none {cursor_class}",
+                style: "{canvas_style}",
+                onmounted: move |e| {
+                    canvas.set(Some(e.data()));
+                    refresh_rect();
+                },
+                onpointerenter: move |_| refresh_rect(),
+                onpointerdown: on_down,
+                onpointermove: on_move,
+                onpointerup: move |_| finish(),
+                onpointerleave: move |_| finish_on_leave(),
+
+                AnnotationSvg {
+                    annotations: marks,
+                    if let Some(preview) = preview {
+                        AnnotationMark { annotation: preview }
+                    }
+                }
+            }
+
+            AnnotationLayers {
+                surface: surface.clone(),
+                annotations: surface_annotations.clone(),
+                active: current_layer.clone(),
+                on_select: move |id: String| active_layer.set(Some(id)),
+            }
+        }
+    }
+}
+
+/// Modal chrome shared by the studio and its empty state
+#[component]
+fn StudioFrame(on_close: EventHandler<()>, children: Element) -> Element {
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/80 flex items-center justify-center z-[1100] p-4",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                id: "annotation-studio",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "annotation-studio-title",
+                class: "bg-dark-surface rounded-xl w-full max-w-5xl max-h-[95vh] overflow-y-auto p-4 flex flex-col gap-3",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        on_close.call(());
+                    }
+                },
+
+                div {
+                    class: "flex justify-between items-center",
+                    h2 { id: "annotation-studio-title", class: "text-white text-lg m-0", "Annotate Backdrop" }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| on_close.call(()),
+                        class: "bg-transparent border-0 text-gray-400 text-2xl cursor-pointer",
+                        "×"
+                    }
+                }
+
+                {children}
+            }
+        }
+    }
+}
+
+/// Layer list: pick the layer to draw on, show or hide it, clear it
+#[component]
+fn AnnotationLayers(
+    surface: AnnotationSurface,
+    annotations: SurfaceAnnotations,
+    active: Option<String>,
+    on_select: EventHandler<String>,
+) -> Element {
+    let session_state = use_session_state();
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let add_layer = {
+        let session_state = session_state.clone();
+        let surface = surface.clone();
+        let count = annotations.layers.len();
+        move |_| {
+            let layer = AnnotationLayer {
+                id: uuid::Uuid::new_v4().to_string(),
+                name: format!("Layer {}", count + 1),
+                visible_to_players: true,
+            };
+            let id = layer.id.clone();
+            match send(&session_state, |svc| svc.set_annotation_layer(surface.clone(), layer)) {
+                Ok(()) => on_select.call(id),
+                Err(e) => error.set(Some(e)),
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1",
+            div {
+                class: "flex items-center justify-between",
+                h3 { class: "text-gray-400 text-sm uppercase m-0", "Layers" }
+                button {
+                    onclick: add_layer,
+                    class: "px-2 py-1 bg-transparent text-purple-400 border border-purple-500/50 rounded text-xs cursor-pointer",
+                    "+ Layer"
+                }
+            }
+
+            if annotations.layers.is_empty() {
+                p { class: "text-gray-500 text-xs m-0", "Your first mark creates a layer players can see." }
+            }
+
+            for layer in annotations.layers.iter() {
+                {
+                    let is_active = active.as_deref() == Some(layer.id.as_str());
+                    let count = annotations.annotations.iter().filter(|a| a.layer_id == layer.id).count();
+                    let layer_id = layer.id.clone();
+                    let toggled = AnnotationLayer { visible_to_players: !layer.visible_to_players, ..layer.clone() };
+                    let clear_id = layer.id.clone();
+                    let session_for_toggle = session_state.clone();
+                    let session_for_clear = session_state.clone();
+                    let surface_for_toggle = surface.clone();
+                    let surface_for_clear = surface.clone();
+                    rsx! {
+                        div {
+                            key: "{layer.id}",
+                            class: if is_active {
+                                "flex items-center gap-2 p-2 rounded bg-purple-500/10 border border-purple-500/40"
+                            } else {
+                                "flex items-center gap-2 p-2 rounded bg-dark-bg border border-gray-700"
+                            },
+                            input {
+                                r#type: "radio",
+                                name: "annotation-layer",
+                                checked: is_active,
+                                aria_label: "Draw on {layer.name}",
+                                onchange: move |_| on_select.call(layer_id.clone()),
+                            }
+                            span { class: "flex-1 text-white text-sm", "{layer.name}" }
+                            span { class: "text-gray-500 text-xs", "{count} marks" }
+                            label {
+                                class: "flex items-center gap-1 text-gray-300 text-xs cursor-pointer",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: layer.visible_to_players,
+                                    onchange: move |_| {
+                                        if let Err(e) = send(&session_for_toggle, |svc| svc.set_annotation_layer(surface_for_toggle.clone(), toggled.clone())) {
+                                            error.set(Some(e));
+                                        }
+                                    },
+                                }
+                                "Players see"
+                            }
+                            button {
+                                onclick: move |_| {
+                                    if let Err(e) = send(&session_for_clear, |svc| svc.clear_annotation_layer(surface_for_clear.clone(), &clear_id)) {
+                                        error.set(Some(e));
+                                    }
+                                },
+                                disabled: count == 0,
+                                class: "px-2 py-0.5 bg-transparent text-red-400 border border-red-500/40 rounded text-xs cursor-pointer disabled:opacity-40",
+                                "Clear"
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-500 text-xs", "{err}" }
+            }
+        }
+    }
+}
+
+/// Send an annotation command, returning a message to show on failure
+fn send(
+    session_state: &SessionState,
+    command: impl FnOnce(SessionCommandService) -> anyhow::Result<()>,
+) -> Result<(), String> {
+    let Some(client) = session_state.engine_client().peek().as_ref().map(Arc::clone) else {
+        return Err("Not connected to the session".to_string());
+    };
+    command(SessionCommandService::new(client)).map_err(|e| format!("Failed to update annotations: {}", e))
+}
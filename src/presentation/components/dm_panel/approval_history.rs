@@ -0,0 +1,195 @@
+//! Approval History - the session's full audit log of DM decisions
+//!
+//! Lists every accept, modify, reject and take-over recorded this session
+//! (see `application::services::approval_audit`), newest first, with what
+//! was proposed, what went out instead, the feedback given and which tools
+//! were approved. Challenge outcomes applied by the world's auto-resolve
+//! rules are listed too, with the roll that qualified them.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{
+    load_approval_audit, outcome_counts, ApprovalAuditEntry, AuditFilter, AuditOutcome,
+};
+use crate::presentation::state::{format_elapsed, use_modal_focus, use_session_state};
+
+/// Modal with the session's approval audit log
+#[component]
+pub fn ApprovalHistoryModal(on_close: EventHandler<()>) -> Element {
+    use_modal_focus("approval-history");
+
+    let session_state = use_session_state();
+    let platform = use_context::<Platform>();
+    let mut outcome_filter: Signal<Option<AuditOutcome>> = use_signal(|| None);
+    let mut search = use_signal(String::new);
+
+    // Reading the decision history re-renders (and reloads) on each new decision
+    let _decisions_made = session_state.decision_history().read().len();
+    let entries = session_state
+        .session_id()
+        .read()
+        .as_ref()
+        .map(|id| load_approval_audit(&platform, id))
+        .unwrap_or_default();
+
+    let filter = AuditFilter {
+        outcome: *outcome_filter.read(),
+        search: search.read().clone(),
+    };
+    let shown = filter.apply(&entries);
+    let counts = outcome_counts(&entries);
+    let session_start = entries.first().map(|e| e.timestamp).unwrap_or(0);
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/80 flex items-center justify-center z-[1100] p-4",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                id: "approval-history",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "approval-history-title",
+                class: "bg-dark-surface rounded-xl w-full max-w-3xl max-h-[90vh] flex flex-col",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        on_close.call(());
+                    }
+                },
+
+                // Header
+                div {
+                    class: "flex justify-between items-center p-4 border-b border-gray-700",
+                    h2 { id: "approval-history-title", class: "text-white text-lg m-0", "Approval History" }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| on_close.call(()),
+                        class: "bg-transparent border-0 text-gray-400 text-2xl cursor-pointer",
+                        "×"
+                    }
+                }
+
+                // Filters
+                div {
+                    class: "flex flex-wrap items-center gap-2 p-4 border-b border-gray-700",
+                    input {
+                        r#type: "text",
+                        value: "{search}",
+                        placeholder: "Search NPC, dialogue or feedback...",
+                        aria_label: "Search decisions",
+                        oninput: move |e| search.set(e.value()),
+                        class: "flex-1 min-w-[12rem] p-2 bg-dark-bg border border-gray-700 rounded-md text-white text-sm",
+                    }
+                    OutcomeChip {
+                        label: format!("All ({})", entries.len()),
+                        active: outcome_filter.read().is_none(),
+                        on_click: move |_| outcome_filter.set(None),
+                    }
+                    for (outcome, count) in counts {
+                        OutcomeChip {
+                            key: "{outcome.label()}",
+                            label: format!("{} ({})", outcome.label(), count),
+                            active: *outcome_filter.read() == Some(outcome),
+                            on_click: move |_| outcome_filter.set(Some(outcome)),
+                        }
+                    }
+                }
+
+                // Entries
+                div {
+                    class: "flex-1 overflow-y-auto p-4 flex flex-col gap-2",
+
+                    if entries.is_empty() {
+                        p { class: "text-gray-500 text-sm text-center m-0 p-6", "No decisions recorded this session yet." }
+                    } else if shown.is_empty() {
+                        p { class: "text-gray-500 text-sm text-center m-0 p-6", "No decisions match these filters." }
+                    }
+
+                    for entry in shown {
+                        AuditEntryCard {
+                            key: "{entry.request_id}-{entry.timestamp}",
+                            entry: entry.clone(),
+                            elapsed: format_elapsed(entry.timestamp.saturating_sub(session_start)),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn OutcomeChip(label: String, active: bool, on_click: EventHandler<()>) -> Element {
+    let class = if active {
+        "px-2 py-1 rounded-full text-xs capitalize cursor-pointer border border-blue-400 bg-blue-500/20 text-blue-200"
+    } else {
+        "px-2 py-1 rounded-full text-xs capitalize cursor-pointer border border-gray-700 bg-transparent text-gray-400"
+    };
+    rsx! {
+        button {
+            class,
+            aria_pressed: "{active}",
+            onclick: move |_| on_click.call(()),
+            "{label}"
+        }
+    }
+}
+
+/// One decision: outcome, NPC, and what changed
+#[component]
+fn AuditEntryCard(entry: ApprovalAuditEntry, elapsed: String) -> Element {
+    let outcome_class = match entry.outcome {
+        AuditOutcome::Accepted => "text-green-400",
+        AuditOutcome::Modified => "text-blue-300",
+        AuditOutcome::Rejected => "text-red-400",
+        AuditOutcome::TakeOver => "text-amber-400",
+        AuditOutcome::AutoApplied => "text-purple-300",
+    };
+
+    rsx! {
+        div {
+            class: "p-3 bg-dark-bg rounded-lg flex flex-col gap-1.5 text-sm",
+
+            div {
+                class: "flex justify-between items-center gap-2",
+                span { class: "text-white font-medium", "{entry.npc_name}" }
+                div {
+                    class: "flex items-center gap-2",
+                    span { class: "text-xs capitalize {outcome_class}", "{entry.outcome.label()}" }
+                    span { class: "text-gray-500 text-xs", title: "Since the first decision this session", "{elapsed}" }
+                }
+            }
+
+            if !entry.proposed_dialogue.is_empty() {
+                p {
+                    class: if entry.final_dialogue.is_some() { "text-gray-500 m-0 line-through" } else { "text-gray-300 m-0" },
+                    "“{entry.proposed_dialogue}”"
+                }
+            }
+            if let Some(final_dialogue) = entry.final_dialogue.as_ref() {
+                p { class: "text-gray-200 m-0", "→ “{final_dialogue}”" }
+            }
+            if let Some(rule) = entry.auto_rule.as_ref() {
+                p { class: "text-purple-300 text-xs m-0", "Auto-applied: {rule}" }
+            }
+            if let Some(feedback) = entry.feedback.as_ref() {
+                p { class: "text-amber-300 text-xs m-0 italic", "Feedback: {feedback}" }
+            }
+            if !entry.approved_tools.is_empty() || !entry.rejected_tools.is_empty() {
+                div {
+                    class: "flex flex-wrap gap-1",
+                    for tool in entry.approved_tools.iter() {
+                        span { class: "px-1.5 py-0.5 rounded bg-green-500/10 text-green-400 text-xs", "✓ {tool}" }
+                    }
+                    for tool in entry.rejected_tools.iter() {
+                        span { class: "px-1.5 py-0.5 rounded bg-red-500/10 text-red-400 text-xs", "✗ {tool}" }
+                    }
+                }
+            }
+            span { class: "text-gray-600 text-xs", "#{entry.request_id}" }
+        }
+    }
+}
@@ -0,0 +1,299 @@
+//! Backdrop studio - generate or swap the backdrop of the region in play
+//!
+//! Generation goes through the usual queue, for the region on stage. Its
+//! images, old and new, are listed here; picking one previews it on the
+//! Director's scene preview only, where "Push live" shows it to players.
+
+use dioxus::prelude::*;
+
+use crate::application::services::{Asset, GenerateRequest};
+use crate::presentation::services::{use_asset_service, use_workflow_service};
+use crate::presentation::state::{
+    use_game_state, use_generation_state, use_modal_focus, use_session_state, BatchStatus,
+};
+
+/// Entity type backdrops are generated for
+const REGION_ENTITY: &str = "region";
+
+/// Workflow slot used when it is configured
+const DEFAULT_SLOT: &str = "backdrop";
+
+/// Modal for generating backdrops and picking one to preview
+#[component]
+pub fn BackdropStudio(world_id: String, on_preview: EventHandler<Asset>, on_close: EventHandler<()>) -> Element {
+    use_modal_focus("backdrop-studio");
+
+    let game_state = use_game_state();
+    let generation_state = use_generation_state();
+    let generation_available = use_session_state().engine_health().read().modes().generation_available;
+    let asset_service = use_asset_service();
+    let workflow_service = use_workflow_service();
+
+    let region = game_state.current_region.read().clone();
+    let initial_prompt = region
+        .as_ref()
+        .map(|r| {
+            let mut prompt = format!("{}, {}", r.name, r.location_name);
+            if let Some(atmosphere) = r.atmosphere.as_ref().filter(|a| !a.trim().is_empty()) {
+                prompt.push_str(". ");
+                prompt.push_str(atmosphere.trim());
+            }
+            prompt
+        })
+        .unwrap_or_default();
+    let mut prompt = use_signal(|| initial_prompt);
+    let mut negative_prompt = use_signal(String::new);
+    let mut count = use_signal(|| 2u8);
+    // Configured backdrop slots as (slot, display name)
+    let mut slots: Signal<Vec<(String, String)>> = use_signal(Vec::new);
+    let mut slot = use_signal(|| DEFAULT_SLOT.to_string());
+    let mut assets: Signal<Vec<Asset>> = use_signal(Vec::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    use_effect(move || {
+        let svc = workflow_service.clone();
+        spawn(async move {
+            match svc.list_workflows().await {
+                Ok(response) => {
+                    let configured: Vec<(String, String)> = response
+                        .categories
+                        .into_iter()
+                        .flat_map(|c| c.slots)
+                        .filter(|s| s.configured && s.slot.contains("backdrop"))
+                        .map(|s| (s.slot, s.display_name))
+                        .collect();
+                    if !configured.iter().any(|(id, _)| id == DEFAULT_SLOT) {
+                        if let Some((first, _)) = configured.first() {
+                            slot.set(first.clone());
+                        }
+                    }
+                    slots.set(configured);
+                }
+                Err(e) => tracing::warn!("Failed to load workflow slots: {}", e),
+            }
+        });
+    });
+
+    // The region's images, refreshed whenever another batch finishes
+    let mut seen: Signal<Option<(String, usize)>> = use_signal(|| None);
+    {
+        let game_state = game_state.clone();
+        let svc = asset_service.clone();
+        use_effect(move || {
+            let Some(region_id) = game_state.current_region.read().as_ref().map(|r| r.id.clone()) else {
+                return;
+            };
+            let ready = generation_state
+                .get_batches_for_entity(REGION_ENTITY, &region_id)
+                .iter()
+                .filter(|b| matches!(b.status, BatchStatus::Ready { .. }))
+                .count();
+            // Progress updates also land here; only fetch when something finished
+            let key = Some((region_id.clone(), ready));
+            if *seen.peek() == key {
+                return;
+            }
+            seen.set(key);
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.get_assets(REGION_ENTITY, &region_id).await {
+                    Ok(list) => assets.set(list),
+                    Err(e) => error.set(Some(format!("Failed to load backdrops: {}", e))),
+                }
+            });
+        });
+    }
+
+    let Some(region) = region else {
+        return rsx! {
+            StudioFrame {
+                on_close,
+                p { class: "text-gray-400 text-sm text-center m-0 p-8", "No region is in play yet. Backdrops are generated for the region the party is in." }
+            }
+        };
+    };
+
+    let generate = {
+        let svc = asset_service.clone();
+        let region_id = region.id.clone();
+        move |_| {
+            let text = prompt.read().trim().to_string();
+            if text.is_empty() {
+                error.set(Some("Describe the backdrop first".to_string()));
+                return;
+            }
+            let negative = negative_prompt.read().trim().to_string();
+            let request = GenerateRequest {
+                world_id: world_id.clone(),
+                entity_type: REGION_ENTITY.to_string(),
+                entity_id: region_id.clone(),
+                asset_type: slot.read().clone(),
+                prompt: text,
+                negative_prompt: (!negative.is_empty()).then_some(negative),
+                count: *count.read(),
+                style_reference_id: None,
+                source_asset_id: None,
+                denoise: None,
+                params: None,
+            };
+            let svc = svc.clone();
+            spawn(async move {
+                error.set(None);
+                if let Err(e) = svc.generate_assets(&request).await {
+                    error.set(Some(format!("Failed to queue generation: {}", e)));
+                }
+            });
+        }
+    };
+
+    let batches = generation_state.get_batches_for_entity(REGION_ENTITY, &region.id);
+    let images: Vec<Asset> = assets.read().iter().filter(|a| a.file_path.is_some()).cloned().collect();
+    let in_progress = batches
+        .iter()
+        .filter(|b| matches!(b.status, BatchStatus::Queued { .. } | BatchStatus::Generating { .. }))
+        .count();
+
+    rsx! {
+        StudioFrame {
+            on_close,
+
+            p { class: "text-gray-400 text-sm m-0", "Backdrops for {region.name}. Picking one previews it on your scene preview; players see it once you push it live." }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "p-2 bg-red-500/10 rounded text-red-500 text-sm", "{err}" }
+            }
+
+            if generation_available {
+                div {
+                    class: "flex flex-col gap-2 p-3 bg-dark-bg rounded-lg",
+
+                    label { class: "text-gray-400 text-xs", r#for: "backdrop-prompt", "Prompt" }
+                    textarea {
+                        id: "backdrop-prompt",
+                        value: "{prompt}",
+                        oninput: move |e| prompt.set(e.value()),
+                        class: "w-full min-h-[80px] p-2 bg-dark-surface border border-gray-700 rounded text-white text-sm resize-y box-border",
+                    }
+                    input {
+                        r#type: "text",
+                        value: "{negative_prompt}",
+                        oninput: move |e| negative_prompt.set(e.value()),
+                        placeholder: "Things to avoid (optional)...",
+                        aria_label: "Negative prompt",
+                        class: "w-full p-2 bg-dark-surface border border-gray-700 rounded text-white text-sm box-border",
+                    }
+
+                    div {
+                        class: "flex items-center gap-2",
+                        select {
+                            value: "{slot}",
+                            aria_label: "Workflow slot",
+                            onchange: move |e: FormEvent| slot.set(e.value()),
+                            class: "flex-1 p-2 bg-dark-surface border border-gray-700 rounded text-white text-sm",
+                            if slots.read().is_empty() {
+                                option { value: DEFAULT_SLOT, "Backdrop" }
+                            }
+                            for (id, name) in slots.read().iter() {
+                                option { value: "{id}", selected: *id == *slot.read(), "{name}" }
+                            }
+                        }
+                        select {
+                            value: "{count}",
+                            aria_label: "Variations",
+                            onchange: move |e: FormEvent| {
+                                if let Ok(v) = e.value().parse::<u8>() {
+                                    count.set(v);
+                                }
+                            },
+                            class: "p-2 bg-dark-surface border border-gray-700 rounded text-white text-sm",
+                            for n in 1..=4u8 {
+                                option { value: "{n}", selected: n == *count.read(), "{n} variations" }
+                            }
+                        }
+                        button {
+                            onclick: generate,
+                            class: "px-4 py-2 bg-purple-500 text-white border-0 rounded cursor-pointer text-sm",
+                            "Generate"
+                        }
+                    }
+
+                    if in_progress > 0 {
+                        p { class: "text-gray-400 text-xs m-0", "⏳ {in_progress} batch(es) generating..." }
+                    }
+                    for batch in batches.iter() {
+                        if let BatchStatus::Failed { error } = &batch.status {
+                            p { key: "{batch.batch_id}", class: "text-red-400 text-xs m-0", "A batch failed: {error}" }
+                        }
+                    }
+                }
+            } else {
+                p { class: "text-gray-500 text-sm m-0", "Image generation is unavailable right now; you can still swap to an existing backdrop." }
+            }
+
+            if images.is_empty() {
+                p { class: "text-gray-500 text-sm text-center m-0 p-6", "No backdrops for this region yet" }
+            } else {
+                div {
+                    class: "grid grid-cols-[repeat(auto-fill,minmax(180px,1fr))] gap-3",
+                    for asset in images {
+                        button {
+                            key: "{asset.id}",
+                            onclick: {
+                                let asset = asset.clone();
+                                move |_| on_preview.call(asset.clone())
+                            },
+                            title: "Preview on the scene",
+                            class: "relative p-0 bg-dark-bg border border-gray-700 rounded-lg overflow-hidden cursor-pointer hover:border-purple-500",
+                            img {
+                                src: "{asset.file_path.clone().unwrap_or_default()}",
+                                alt: "{asset.label.clone().unwrap_or_default()}",
+                                class: "w-full h-[110px] object-cover block",
+                            }
+                            if asset.is_active {
+                                span { class: "absolute top-1 left-1 px-2 py-0.5 bg-green-600 text-white rounded text-xs", "Live" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn StudioFrame(on_close: EventHandler<()>, children: Element) -> Element {
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/80 flex items-center justify-center z-[1100] p-4",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                id: "backdrop-studio",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "backdrop-studio-title",
+                class: "bg-dark-surface rounded-xl w-full max-w-3xl max-h-[95vh] overflow-y-auto p-4 flex flex-col gap-3",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        on_close.call(());
+                    }
+                },
+
+                div {
+                    class: "flex justify-between items-center",
+                    h2 { id: "backdrop-studio-title", class: "text-white text-lg m-0", "Backdrop" }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| on_close.call(()),
+                        class: "bg-transparent border-0 text-gray-400 text-2xl cursor-pointer",
+                        "×"
+                    }
+                }
+
+                {children}
+            }
+        }
+    }
+}
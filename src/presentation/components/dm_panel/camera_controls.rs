@@ -0,0 +1,51 @@
+//! Camera controls - DM quick actions that move everyone's stage
+//!
+//! Each button sends one effect to the Engine, which broadcasts it to every
+//! client (the Director's own stage included), so nothing plays locally here.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{CameraEffect, PanDirection, ShakeStrength, DEFAULT_PAN_MS, DEFAULT_ZOOM_MS};
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::use_session_state;
+
+/// Quick camera effects: shakes, a zoom on the speaker, and pans
+#[component]
+pub fn CameraControls() -> Element {
+    let session_state = use_session_state();
+    let has_client = session_state.has_client();
+    let engine_client = session_state.engine_client();
+
+    let actions = [
+        ("💥", "Shake", CameraEffect::Shake { strength: ShakeStrength::Light }),
+        ("💥", "Heavy shake", CameraEffect::Shake { strength: ShakeStrength::Heavy }),
+        ("🔍", "Zoom on speaker", CameraEffect::Zoom { character_id: None, duration_ms: DEFAULT_ZOOM_MS }),
+        ("➡️", "Pan right", CameraEffect::Pan { direction: PanDirection::LeftToRight, duration_ms: DEFAULT_PAN_MS }),
+        ("⬅️", "Pan left", CameraEffect::Pan { direction: PanDirection::RightToLeft, duration_ms: DEFAULT_PAN_MS }),
+    ];
+
+    rsx! {
+        div {
+            class: "flex flex-wrap gap-1",
+            role: "group",
+            aria_label: "Camera effects",
+            for (icon, label, effect) in actions {
+                button {
+                    key: "{label}",
+                    r#type: "button",
+                    disabled: !has_client,
+                    class: "px-2 py-1 bg-dark-bg text-gray-300 border border-gray-700 rounded text-xs cursor-pointer disabled:opacity-50",
+                    onclick: move |_| {
+                        let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+                            return;
+                        };
+                        if let Err(e) = SessionCommandService::new(client).play_camera_effect(effect.clone()) {
+                            tracing::error!("Failed to play camera effect: {}", e);
+                        }
+                    },
+                    "{icon} {label}"
+                }
+            }
+        }
+    }
+}
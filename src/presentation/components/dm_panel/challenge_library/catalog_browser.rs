@@ -0,0 +1,250 @@
+//! Shared catalog browser - copy challenges from Engine-wide catalogs
+
+use dioxus::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::application::dto::{ChallengeData, SkillData};
+use crate::application::services::{
+    copy_catalog_challenge, match_catalog_skill, CatalogChallenge, ChallengeCatalog,
+};
+use crate::presentation::services::use_challenge_service;
+
+/// Props for CatalogBrowser
+#[derive(Props, Clone, PartialEq)]
+pub struct CatalogBrowserProps {
+    pub world_id: String,
+    /// Skills for this world, used to remap catalog skills
+    pub skills: Vec<SkillData>,
+    /// Search text from the library's filter bar
+    #[props(default)]
+    pub search: String,
+    /// Called with the world's copy once it has been created
+    pub on_copied: EventHandler<ChallengeData>,
+}
+
+/// Browse shared catalogs and copy challenges into this world
+#[component]
+pub fn CatalogBrowser(props: CatalogBrowserProps) -> Element {
+    let challenge_service = use_challenge_service();
+
+    let mut catalogs: Signal<Vec<ChallengeCatalog>> = use_signal(Vec::new);
+    let mut selected_catalog: Signal<Option<String>> = use_signal(|| None);
+    let mut entries: Signal<Vec<CatalogChallenge>> = use_signal(Vec::new);
+    let mut is_loading = use_signal(|| true);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    // Skill picked by the DM for entries with no matching skill, by catalog challenge ID
+    let mut skill_overrides: Signal<HashMap<String, String>> = use_signal(HashMap::new);
+    let mut copying: Signal<HashSet<String>> = use_signal(HashSet::new);
+    let mut copied: Signal<HashSet<String>> = use_signal(HashSet::new);
+
+    // Load the catalog list on mount and open the first one
+    {
+        let service = challenge_service.clone();
+        use_effect(move || {
+            let service = service.clone();
+            spawn(async move {
+                match service.list_catalogs().await {
+                    Ok(list) => {
+                        if let Some(first) = list.first() {
+                            selected_catalog.set(Some(first.id.clone()));
+                        } else {
+                            is_loading.set(false);
+                        }
+                        catalogs.set(list);
+                    }
+                    Err(e) => {
+                        error.set(Some(format!("Failed to load catalogs: {}", e)));
+                        is_loading.set(false);
+                    }
+                }
+            });
+        });
+    }
+
+    // Load the selected catalog's challenges
+    {
+        let service = challenge_service.clone();
+        use_effect(move || {
+            let Some(catalog_id) = selected_catalog.read().clone() else {
+                return;
+            };
+            let service = service.clone();
+            is_loading.set(true);
+            spawn(async move {
+                match service.list_catalog_challenges(&catalog_id).await {
+                    Ok(list) => {
+                        entries.set(list);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to load catalog: {}", e))),
+                }
+                is_loading.set(false);
+            });
+        });
+    }
+
+    let search = props.search.to_lowercase();
+    let visible: Vec<CatalogChallenge> = entries
+        .read()
+        .iter()
+        .filter(|e| {
+            search.is_empty()
+                || e.challenge.name.to_lowercase().contains(&search)
+                || e.challenge.description.to_lowercase().contains(&search)
+                || e.challenge.tags.iter().any(|t| t.to_lowercase().contains(&search))
+        })
+        .cloned()
+        .collect();
+
+    let selected_value = selected_catalog.read().clone().unwrap_or_default();
+    let catalog_description = catalogs
+        .read()
+        .iter()
+        .find(|c| c.id == selected_value)
+        .map(|c| c.description.clone())
+        .unwrap_or_default();
+
+    rsx! {
+        div { class: "flex flex-col gap-3",
+
+            if !catalogs.read().is_empty() {
+                div { class: "flex items-center gap-3",
+                    select {
+                        value: "{selected_value}",
+                        onchange: move |e| {
+                            skill_overrides.write().clear();
+                            selected_catalog.set(Some(e.value()));
+                        },
+                        class: "p-2 bg-dark-bg border border-gray-700 rounded text-white",
+                        for catalog in catalogs.read().iter() {
+                            option {
+                                value: "{catalog.id}",
+                                "{catalog.name} ({catalog.challenge_count})"
+                            }
+                        }
+                    }
+                    if !catalog_description.is_empty() {
+                        span { class: "text-gray-500 text-xs", "{catalog_description}" }
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "p-3 bg-red-500/10 text-red-500 text-sm rounded", "{err}" }
+            }
+
+            if *is_loading.read() {
+                div { class: "flex items-center justify-center p-12 text-gray-400", "Loading catalog..." }
+            } else if catalogs.read().is_empty() {
+                div { class: "flex items-center justify-center p-12 text-gray-500", "No shared catalogs on this Engine" }
+            } else if visible.is_empty() {
+                div { class: "flex items-center justify-center p-12 text-gray-500", "No catalog challenges found" }
+            } else {
+                for entry in visible {
+                    {
+                        let catalog_challenge_id = entry.challenge.id.clone();
+                        let matched = match_catalog_skill(&entry, &props.skills).cloned();
+                        let chosen_skill = matched
+                            .as_ref()
+                            .map(|s| s.id.clone())
+                            .or_else(|| skill_overrides.read().get(&catalog_challenge_id).cloned());
+                        let is_copying = copying.read().contains(&catalog_challenge_id);
+                        let is_copied = copied.read().contains(&catalog_challenge_id);
+                        let original_skill = if entry.skill_name.is_empty() {
+                            "unknown skill".to_string()
+                        } else {
+                            entry.skill_name.clone()
+                        };
+                        let copy = {
+                            let entry = entry.clone();
+                            let world_id = props.world_id.clone();
+                            let service = challenge_service.clone();
+                            let chosen_skill = chosen_skill.clone();
+                            let on_copied = props.on_copied;
+                            move |_| {
+                                let Some(skill_id) = chosen_skill.clone() else {
+                                    return;
+                                };
+                                let data = copy_catalog_challenge(&entry, &world_id, &skill_id);
+                                let catalog_challenge_id = entry.challenge.id.clone();
+                                let world_id = world_id.clone();
+                                let service = service.clone();
+                                copying.write().insert(catalog_challenge_id.clone());
+                                spawn(async move {
+                                    match service.create_challenge(&world_id, &data).await {
+                                        Ok(created) => {
+                                            copied.write().insert(catalog_challenge_id.clone());
+                                            on_copied.call(created);
+                                        }
+                                        Err(e) => error.set(Some(format!("Failed to copy challenge: {}", e))),
+                                    }
+                                    copying.write().remove(&catalog_challenge_id);
+                                });
+                            }
+                        };
+                        rsx! {
+                            div {
+                                key: "{entry.catalog_id}-{entry.challenge.id}",
+                                class: "flex items-center gap-3 p-3 bg-dark-bg border border-gray-700 rounded",
+
+                                div { class: "flex-1 min-w-0",
+                                    div { class: "flex items-center gap-2 mb-1",
+                                        span { class: "text-white font-medium truncate", "{entry.challenge.name}" }
+                                        span { class: "text-gray-400 text-xs", "{entry.challenge.challenge_type.display_name()}" }
+                                        span { class: "text-gray-400 text-xs", "{entry.challenge.difficulty.display()}" }
+                                        span {
+                                            class: "px-1.5 py-0.5 bg-indigo-500/20 text-indigo-300 text-[0.625rem] rounded",
+                                            "📚 {entry.catalog_name}"
+                                        }
+                                    }
+                                    if !entry.challenge.description.is_empty() {
+                                        div { class: "text-gray-500 text-xs truncate", "{entry.challenge.description}" }
+                                    }
+                                }
+
+                                // Skill remapping
+                                if let Some(skill) = matched.as_ref() {
+                                    span { class: "text-blue-400 text-xs whitespace-nowrap", "{skill.name}" }
+                                } else {
+                                    div { class: "flex flex-col items-end gap-0.5",
+                                        span { class: "text-amber-400 text-[0.625rem]", "No match for {original_skill}" }
+                                        select {
+                                            value: "{chosen_skill.clone().unwrap_or_default()}",
+                                            onchange: {
+                                                let catalog_challenge_id = catalog_challenge_id.clone();
+                                                move |e: FormEvent| {
+                                                    let value = e.value();
+                                                    if value.is_empty() {
+                                                        skill_overrides.write().remove(&catalog_challenge_id);
+                                                    } else {
+                                                        skill_overrides.write().insert(catalog_challenge_id.clone(), value);
+                                                    }
+                                                }
+                                            },
+                                            class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                                            option { value: "", "Choose a skill..." }
+                                            for skill in props.skills.iter() {
+                                                option { value: "{skill.id}", "{skill.name}" }
+                                            }
+                                        }
+                                    }
+                                }
+
+                                if is_copied {
+                                    span { class: "px-2 py-1.5 text-emerald-400 text-xs whitespace-nowrap", "✓ Copied" }
+                                } else {
+                                    button {
+                                        onclick: copy,
+                                        disabled: chosen_skill.is_none() || is_copying,
+                                        class: "px-2 py-1.5 bg-emerald-500 text-white border-0 rounded cursor-pointer text-xs whitespace-nowrap disabled:opacity-50",
+                                        if is_copying { "Copying..." } else { "Copy to my world" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
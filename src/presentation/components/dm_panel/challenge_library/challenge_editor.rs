@@ -1,10 +1,45 @@
 //! Challenge editor form component
 
 use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
 use crate::application::dto::{
-    ChallengeData, ChallengeType, ChallengeDifficulty, SkillData, ChallengeOutcomes,
+    trigger_condition_errors, ChallengeData, ChallengeType, ChallengeDifficulty, SkillData,
+    ChallengeOutcomes, Outcome, TriggerCondition,
+};
+use super::outcome_editor::OutcomeEditor;
+use super::prerequisite_graph::would_create_cycle;
+use super::trigger_condition_editor::TriggerConditionEditor;
+use crate::application::services::{FieldRule, FormRules, FormValues, HistoryEntityKind};
+use crate::presentation::components::common::{
+    DraftRestoreBanner, EntityHistoryDrawer, FieldError, TagInput, ValidationSummary,
 };
 use crate::presentation::services::use_challenge_service;
+use crate::presentation::state::{draft_key, use_draft_persistence, use_form_validation, use_modal_focus};
+
+/// Autosaved contents of the challenge form
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ChallengeDraft {
+    name: String,
+    description: String,
+    skill_id: String,
+    challenge_type: ChallengeType,
+    difficulty: ChallengeDifficulty,
+    outcomes: ChallengeOutcomes,
+    tags: Vec<String>,
+    prerequisites: Vec<String>,
+    #[serde(default)]
+    trigger_conditions: Vec<TriggerCondition>,
+}
+
+/// Field rules for the challenge form; the difficulty value is checked
+/// under "dc" or "percentage" depending on the difficulty kind
+fn challenge_rules() -> FormRules {
+    FormRules::new()
+        .field("name", "Challenge name", vec![FieldRule::Required, FieldRule::Length { min: None, max: Some(200) }])
+        .field("skill", "Skill", vec![FieldRule::Required])
+        .field("dc", "DC value", vec![FieldRule::Range { min: Some(1), max: None }])
+        .field("percentage", "Percentage", vec![FieldRule::Range { min: Some(0), max: Some(100) }])
+}
 
 /// Props for ChallengeFormModal
 #[derive(Props, Clone, PartialEq)]
@@ -12,6 +47,9 @@ pub struct ChallengeFormModalProps {
     pub world_id: String,
     pub challenge: Option<ChallengeData>,
     pub skills: Vec<SkillData>,
+    /// All challenges in the world (for choosing prerequisites)
+    #[props(default)]
+    pub all_challenges: Vec<ChallengeData>,
     pub on_save: EventHandler<ChallengeData>,
     pub on_close: EventHandler<()>,
 }
@@ -19,6 +57,8 @@ pub struct ChallengeFormModalProps {
 /// Modal for creating/editing a challenge
 #[component]
 pub fn ChallengeFormModal(props: ChallengeFormModalProps) -> Element {
+    use_modal_focus("challenge-form-modal");
+
     let is_edit = props.challenge.is_some();
     let initial = props.challenge.clone().unwrap_or_default_challenge(&props.world_id);
 
@@ -27,56 +67,94 @@ pub fn ChallengeFormModal(props: ChallengeFormModalProps) -> Element {
     let mut skill_id = use_signal(|| initial.skill_id.clone());
     let mut challenge_type = use_signal(|| initial.challenge_type);
     let mut difficulty = use_signal(|| initial.difficulty.clone());
-    let mut success_desc = use_signal(|| initial.outcomes.success.description.clone());
-    let mut failure_desc = use_signal(|| initial.outcomes.failure.description.clone());
-    let mut tags_str = use_signal(|| initial.tags.join(", "));
+    let mut outcomes = use_signal(|| initial.outcomes.clone());
+    let mut tags: Signal<Vec<String>> = use_signal(|| initial.tags.clone());
+    let mut prerequisites: Signal<Vec<String>> = use_signal(|| initial.prerequisite_challenges.clone());
+    let mut prerequisite_search = use_signal(String::new);
+    let mut trigger_conditions: Signal<Vec<TriggerCondition>> = use_signal(|| initial.trigger_conditions.clone());
     let mut is_saving = use_signal(|| false);
     let mut save_error: Signal<Option<String>> = use_signal(|| None);
-    let mut validation_errors: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut validation = use_form_validation();
+    let mut show_history = use_signal(|| false);
 
     let challenge_id = initial.id.clone();
     let world_id = props.world_id.clone();
 
+    let mut drafts = use_draft_persistence(
+        draft_key("challenge", &world_id, Some(&challenge_id)),
+        move || {
+            Some(ChallengeDraft {
+                name: name.peek().clone(),
+                description: description.peek().clone(),
+                skill_id: skill_id.peek().clone(),
+                challenge_type: *challenge_type.peek(),
+                difficulty: difficulty.peek().clone(),
+                outcomes: outcomes.peek().clone(),
+                tags: tags.peek().clone(),
+                prerequisites: prerequisites.peek().clone(),
+                trigger_conditions: trigger_conditions.peek().clone(),
+            })
+        },
+    );
+
     // Get challenge service
     let challenge_service = use_challenge_service();
 
+    // Reload the form from the Engine after rolling back to an earlier version
+    let challenge_service_for_history = challenge_service.clone();
+    let challenge_id_for_history = challenge_id.clone();
+    let reload_after_rollback = move |_: ()| {
+        validation.reset();
+        let service = challenge_service_for_history.clone();
+        let id = challenge_id_for_history.clone();
+        spawn(async move {
+            match service.get_challenge(&id).await {
+                Ok(restored) => {
+                    name.set(restored.name);
+                    description.set(restored.description);
+                    skill_id.set(restored.skill_id);
+                    challenge_type.set(restored.challenge_type);
+                    difficulty.set(restored.difficulty);
+                    outcomes.set(restored.outcomes);
+                    tags.set(restored.tags);
+                    prerequisites.set(restored.prerequisite_challenges);
+                    trigger_conditions.set(restored.trigger_conditions);
+                }
+                Err(e) => save_error.set(Some(format!("Failed to reload challenge: {}", e))),
+            }
+        });
+    };
+
     let world_id_for_save = world_id.clone();
     let challenge_id_for_save = challenge_id.clone();
+    let source_catalog_for_save = initial.source_catalog.clone();
     let challenge_service_for_save = challenge_service.clone();
 
-    let handle_save = move |_| {
-        // Validate inputs
-        let mut errors = Vec::new();
-
-        if name.read().trim().is_empty() {
-            errors.push("Challenge name is required".to_string());
-        }
-
-        if skill_id.read().is_empty() {
-            errors.push("Skill is required".to_string());
-        }
-
-        // Validate difficulty-specific values
-        match &*difficulty.read() {
-            ChallengeDifficulty::Dc { value } => {
-                if *value == 0 {
-                    errors.push("DC value must be greater than 0".to_string());
-                }
-            }
-            ChallengeDifficulty::Percentage { value } => {
-                if *value > 100 {
-                    errors.push("Percentage must be between 0 and 100".to_string());
-                }
-            }
-            _ => {}
-        }
+    // Checked every render so errors update as the user types
+    let mut values = FormValues::new()
+        .with("name", name.read().as_str())
+        .with("skill", skill_id.read().as_str());
+    match &*difficulty.read() {
+        ChallengeDifficulty::Dc { value } => values = values.with("dc", value),
+        ChallengeDifficulty::Percentage { value } => values = values.with("percentage", value),
+        _ => {}
+    }
+    let mut errors = challenge_rules().validate(&values);
+    errors.push_if(
+        would_create_cycle(&props.all_challenges, &challenge_id, &prerequisites.read()),
+        "prerequisites",
+        "Prerequisites would create a cycle",
+    );
+    for error in trigger_condition_errors(&trigger_conditions.read()) {
+        errors.push("trigger_conditions", error);
+    }
+    let errors_for_save = errors.clone();
 
-        if !errors.is_empty() {
-            validation_errors.set(errors);
+    let handle_save = move |_| {
+        if !validation.gate(&errors_for_save) {
             return;
         }
 
-        validation_errors.set(Vec::new());
         is_saving.set(true);
         save_error.set(None);
 
@@ -89,30 +167,15 @@ pub fn ChallengeFormModal(props: ChallengeFormModalProps) -> Element {
             challenge_type: *challenge_type.read(),
             skill_id: skill_id.read().clone(),
             difficulty: difficulty.read().clone(),
-            outcomes: ChallengeOutcomes {
-                success: crate::application::dto::Outcome {
-                    description: success_desc.read().clone(),
-                    triggers: vec![],
-                },
-                failure: crate::application::dto::Outcome {
-                    description: failure_desc.read().clone(),
-                    triggers: vec![],
-                },
-                partial: None,
-                critical_success: None,
-                critical_failure: None,
-            },
-            trigger_conditions: vec![],
-            prerequisite_challenges: vec![],
+            outcomes: outcomes.read().clone(),
+            trigger_conditions: trigger_conditions.read().clone(),
+            prerequisite_challenges: prerequisites.read().clone(),
             active: true,
             order: 0,
             is_favorite: false,
-            tags: tags_str
-                .read()
-                .split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect(),
+            tags: tags.read().clone(),
+            source_catalog: source_catalog_for_save.clone(),
+            deleted_at: None,
         };
 
         let on_save = props.on_save.clone();
@@ -129,6 +192,7 @@ pub fn ChallengeFormModal(props: ChallengeFormModalProps) -> Element {
 
             match result {
                 Ok(saved) => {
+                    drafts.clear();
                     on_save.call(saved);
                 }
                 Err(e) => {
@@ -140,7 +204,7 @@ pub fn ChallengeFormModal(props: ChallengeFormModalProps) -> Element {
     };
 
     let header_text = if is_edit { "Edit Challenge" } else { "New Challenge" };
-    let is_disabled = *is_saving.read() || name.read().is_empty() || skill_id.read().is_empty();
+    let is_disabled = *is_saving.read();
     let opacity_class = if is_disabled { "opacity-50" } else { "opacity-100" };
     let button_text = if *is_saving.read() { "Saving..." } else if is_edit { "Update" } else { "Create" };
 
@@ -150,19 +214,49 @@ pub fn ChallengeFormModal(props: ChallengeFormModalProps) -> Element {
             onclick: move |_| props.on_close.call(()),
 
             div {
+                id: "challenge-form-modal",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "challenge-form-modal-title",
                 class: "bg-dark-surface rounded-xl w-[90%] max-w-[600px] max-h-[90vh] overflow-y-auto",
                 onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        props.on_close.call(());
+                    }
+                },
 
                 // Header
                 div {
                     class: "flex justify-between items-center px-6 py-4 border-b border-gray-700",
-                    h3 { class: "text-white m-0",
+                    h3 { id: "challenge-form-modal-title", class: "text-white m-0",
                         "{header_text}"
                     }
-                    button {
-                        onclick: move |_| props.on_close.call(()),
-                        class: "bg-transparent border-0 text-gray-400 text-2xl cursor-pointer",
-                        "×"
+                    div {
+                        class: "flex items-center gap-2",
+                        if is_edit {
+                            button {
+                                onclick: move |_| show_history.set(true),
+                                class: "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm",
+                                "History"
+                            }
+                        }
+                        button {
+                            aria_label: "Close",
+                            onclick: move |_| props.on_close.call(()),
+                            class: "bg-transparent border-0 text-gray-400 text-2xl cursor-pointer",
+                            "×"
+                        }
+                    }
+                }
+
+                if *show_history.read() {
+                    EntityHistoryDrawer {
+                        kind: HistoryEntityKind::Challenge,
+                        entity_id: challenge_id.clone(),
+                        on_rolled_back: reload_after_rollback,
+                        on_close: move |_| show_history.set(false),
                     }
                 }
 
@@ -170,26 +264,43 @@ pub fn ChallengeFormModal(props: ChallengeFormModalProps) -> Element {
                 div {
                     class: "p-6 flex flex-col gap-4",
 
-                    // Validation errors
-                    if !validation_errors.read().is_empty() {
-                        div {
-                            class: "px-4 py-3 bg-red-500/10 border-l-3 border-l-red-500 rounded text-red-500 text-sm",
-                            for error in validation_errors.read().iter() {
-                                div { class: "mb-1", "• {error}" }
-                            }
+                    if let Some(draft) = drafts.pending.read().as_ref() {
+                        DraftRestoreBanner {
+                            saved_at: draft.saved_at,
+                            on_restore: move |_| {
+                                if let Some(draft) = drafts.restore() {
+                                    name.set(draft.name);
+                                    description.set(draft.description);
+                                    skill_id.set(draft.skill_id);
+                                    challenge_type.set(draft.challenge_type);
+                                    difficulty.set(draft.difficulty);
+                                    outcomes.set(draft.outcomes);
+                                    tags.set(draft.tags);
+                                    prerequisites.set(draft.prerequisites);
+                                    trigger_conditions.set(draft.trigger_conditions);
+                                }
+                            },
+                            on_discard: move |_| drafts.discard(),
                         }
                     }
 
+                    // Validation errors, after a save attempt
+                    ValidationSummary { errors: validation.summary(&errors) }
+
                     // Name
                     div {
                         label { class: "block text-gray-400 text-xs mb-1", "Name *" }
                         input {
                             r#type: "text",
                             value: "{name}",
-                            oninput: move |e| name.set(e.value()),
+                            oninput: move |e| {
+                                name.set(e.value());
+                                validation.touch("name");
+                            },
                             placeholder: "e.g., Investigate the Crime Scene",
                             class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white box-border",
                         }
+                        FieldError { message: validation.error(&errors, "name") }
                     }
 
                     // Description
@@ -234,13 +345,17 @@ pub fn ChallengeFormModal(props: ChallengeFormModalProps) -> Element {
                             label { class: "block text-gray-400 text-xs mb-1", "Skill *" }
                             select {
                                 value: "{skill_id}",
-                                onchange: move |e| skill_id.set(e.value()),
+                                onchange: move |e| {
+                                    skill_id.set(e.value());
+                                    validation.touch("skill");
+                                },
                                 class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white",
                                 option { value: "", "Select a skill..." }
                                 for skill in props.skills.iter() {
                                     option { value: "{skill.id}", "{skill.name}" }
                                 }
                             }
+                            FieldError { message: validation.error(&errors, "skill") }
                         }
                     }
 
@@ -284,6 +399,7 @@ pub fn ChallengeFormModal(props: ChallengeFormModalProps) -> Element {
                                             if let Ok(v) = e.value().parse() {
                                                 difficulty.set(ChallengeDifficulty::Dc { value: v });
                                             }
+                                            validation.touch("dc");
                                         },
                                         class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded text-white",
                                     }
@@ -298,6 +414,7 @@ pub fn ChallengeFormModal(props: ChallengeFormModalProps) -> Element {
                                             if let Ok(v) = e.value().parse() {
                                                 difficulty.set(ChallengeDifficulty::Percentage { value: v });
                                             }
+                                            validation.touch("percentage");
                                         },
                                         class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded text-white",
                                     }
@@ -335,41 +452,174 @@ pub fn ChallengeFormModal(props: ChallengeFormModalProps) -> Element {
                                 },
                             }
                         }
+                        FieldError { message: validation.error(&errors, "dc").or(validation.error(&errors, "percentage")) }
                     }
 
-                    // Success outcome
+                    // Trigger conditions
                     div {
-                        label { class: "block text-emerald-500 text-xs mb-1", "Success Outcome" }
-                        textarea {
-                            value: "{success_desc}",
-                            oninput: move |e| success_desc.set(e.value()),
-                            placeholder: "What happens on success...",
-                            rows: "2",
-                            class: "w-full p-2 bg-dark-bg border border-emerald-500 rounded text-white resize-y box-border",
+                        label { class: "block text-gray-400 text-xs mb-1", "Trigger Conditions" }
+                        TriggerConditionEditor {
+                            world_id: world_id.clone(),
+                            conditions: trigger_conditions.read().clone(),
+                            challenges: props.all_challenges.iter().filter(|c| c.id != challenge_id).cloned().collect::<Vec<_>>(),
+                            on_change: move |next| {
+                                trigger_conditions.set(next);
+                                validation.touch("trigger_conditions");
+                            },
                         }
+                        FieldError { message: validation.error(&errors, "trigger_conditions") }
                     }
 
-                    // Failure outcome
-                    div {
-                        label { class: "block text-red-500 text-xs mb-1", "Failure Outcome" }
-                        textarea {
-                            value: "{failure_desc}",
-                            oninput: move |e| failure_desc.set(e.value()),
+                    // Outcome branches
+                    div { class: "flex flex-col gap-3",
+                        OutcomeEditor {
+                            label: "Critical Success",
+                            text_class: "text-yellow-400",
+                            border_class: "border-yellow-400",
+                            outcome: outcomes.read().critical_success.clone(),
+                            optional: true,
+                            placeholder: "What happens on an exceptional success...",
+                            challenges: props.all_challenges.clone(),
+                            on_change: move |o| outcomes.write().critical_success = o,
+                        }
+                        OutcomeEditor {
+                            label: "Success",
+                            text_class: "text-emerald-500",
+                            border_class: "border-emerald-500",
+                            outcome: Some(outcomes.read().success.clone()),
+                            placeholder: "What happens on success...",
+                            challenges: props.all_challenges.clone(),
+                            on_change: move |o: Option<Outcome>| outcomes.write().success = o.unwrap_or_default(),
+                        }
+                        OutcomeEditor {
+                            label: "Partial Success",
+                            text_class: "text-amber-500",
+                            border_class: "border-amber-500",
+                            outcome: outcomes.read().partial.clone(),
+                            optional: true,
+                            placeholder: "Success at a cost...",
+                            challenges: props.all_challenges.clone(),
+                            on_change: move |o| outcomes.write().partial = o,
+                        }
+                        OutcomeEditor {
+                            label: "Failure",
+                            text_class: "text-red-500",
+                            border_class: "border-red-500",
+                            outcome: Some(outcomes.read().failure.clone()),
                             placeholder: "What happens on failure...",
-                            rows: "2",
-                            class: "w-full p-2 bg-dark-bg border border-red-500 rounded text-white resize-y box-border",
+                            challenges: props.all_challenges.clone(),
+                            on_change: move |o: Option<Outcome>| outcomes.write().failure = o.unwrap_or_default(),
+                        }
+                        OutcomeEditor {
+                            label: "Critical Failure",
+                            text_class: "text-red-700",
+                            border_class: "border-red-700",
+                            outcome: outcomes.read().critical_failure.clone(),
+                            optional: true,
+                            placeholder: "What happens on a disastrous failure...",
+                            challenges: props.all_challenges.clone(),
+                            on_change: move |o| outcomes.write().critical_failure = o,
                         }
                     }
 
                     // Tags
                     div {
-                        label { class: "block text-gray-400 text-xs mb-1", "Tags (comma-separated)" }
-                        input {
-                            r#type: "text",
-                            value: "{tags_str}",
-                            oninput: move |e| tags_str.set(e.value()),
+                        label { class: "block text-gray-400 text-xs mb-1", "Tags" }
+                        TagInput {
+                            tags: tags.read().clone(),
+                            on_change: move |next| tags.set(next),
                             placeholder: "investigation, social, combat",
-                            class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white box-border",
+                        }
+                    }
+
+                    // Prerequisites (searchable multi-select)
+                    {
+                        let selected = prerequisites.read().clone();
+                        let search = prerequisite_search.read().to_lowercase();
+                        let candidates: Vec<ChallengeData> = props
+                            .all_challenges
+                            .iter()
+                            .filter(|c| c.id != challenge_id)
+                            .filter(|c| search.is_empty() || c.name.to_lowercase().contains(&search))
+                            .cloned()
+                            .collect();
+                        let creates_cycle = errors.for_field("prerequisites").is_some();
+                        rsx! {
+                            div {
+                                label { class: "block text-gray-400 text-xs mb-1", "Prerequisites" }
+
+                                // Selected prerequisites
+                                if !selected.is_empty() {
+                                    div { class: "flex flex-wrap gap-1 mb-2",
+                                        for prereq_id in selected.iter() {
+                                            span {
+                                                key: "{prereq_id}",
+                                                class: "inline-flex items-center gap-1 px-2 py-0.5 bg-gray-700 text-gray-300 text-xs rounded",
+                                                {
+                                                    props.all_challenges
+                                                        .iter()
+                                                        .find(|c| &c.id == prereq_id)
+                                                        .map(|c| c.name.clone())
+                                                        .unwrap_or_else(|| prereq_id.clone())
+                                                }
+                                                button {
+                                                    aria_label: "Remove prerequisite",
+                                                    r#type: "button",
+                                                    class: "bg-transparent border-none text-gray-400 cursor-pointer p-0 leading-none",
+                                                    onclick: {
+                                                        let prereq_id = prereq_id.clone();
+                                                        move |_| prerequisites.write().retain(|p| *p != prereq_id)
+                                                    },
+                                                    "×"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+
+                                if creates_cycle {
+                                    div { class: "text-red-500 text-xs mb-2", "⚠ These prerequisites create a cycle" }
+                                }
+
+                                input {
+                                    r#type: "text",
+                                    value: "{prerequisite_search}",
+                                    oninput: move |e| prerequisite_search.set(e.value()),
+                                    placeholder: "Search challenges...",
+                                    class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white box-border text-sm",
+                                }
+
+                                div { class: "max-h-[120px] overflow-y-auto mt-1 border border-gray-700 rounded",
+                                    for candidate in candidates.iter() {
+                                        {
+                                            let is_selected = selected.contains(&candidate.id);
+                                            let candidate_id = candidate.id.clone();
+                                            rsx! {
+                                                label {
+                                                    key: "{candidate.id}",
+                                                    class: "flex items-center gap-2 px-2 py-1 text-sm text-white cursor-pointer",
+                                                    input {
+                                                        r#type: "checkbox",
+                                                        checked: is_selected,
+                                                        onchange: move |_| {
+                                                            let mut prereqs = prerequisites.write();
+                                                            if let Some(pos) = prereqs.iter().position(|p| *p == candidate_id) {
+                                                                prereqs.remove(pos);
+                                                            } else {
+                                                                prereqs.push(candidate_id.clone());
+                                                            }
+                                                        },
+                                                    }
+                                                    "{candidate.name}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if candidates.is_empty() {
+                                        div { class: "px-2 py-1 text-gray-500 text-xs", "No matching challenges" }
+                                    }
+                                }
+                            }
                         }
                     }
 
@@ -421,6 +671,8 @@ impl DefaultChallenge for Option<ChallengeData> {
             order: 0,
             is_favorite: false,
             tags: vec![],
+            source_catalog: None,
+            deleted_at: None,
         })
     }
 }
@@ -3,6 +3,8 @@
 use dioxus::prelude::*;
 use std::collections::HashMap;
 use crate::application::dto::{ChallengeData, ChallengeType};
+use crate::application::services::challenge_stat_block;
+use crate::presentation::components::common::CopyButton;
 
 /// Section for a challenge type
 #[derive(Props, Clone, PartialEq)]
@@ -90,6 +92,7 @@ pub fn ChallengeCard(props: ChallengeCardProps) -> Element {
     let id_for_delete = id.clone();
     let challenge_for_edit = challenge.clone();
     let challenge_for_trigger = challenge.clone();
+    let stat_block = challenge_stat_block(&challenge, &props.skill_name);
 
     let opacity_class = if challenge.active { "opacity-100" } else { "opacity-60" };
     let border_class = if challenge.is_favorite { "border-amber-500" } else { "border-gray-700" };
@@ -97,6 +100,19 @@ pub fn ChallengeCard(props: ChallengeCardProps) -> Element {
     let active_bg = if challenge.active { "bg-emerald-500" } else { "bg-gray-700" };
     let active_text = if challenge.active { "Active" } else { "Inactive" };
     let extra_tags = if challenge.tags.len() > 2 { challenge.tags.len() - 2 } else { 0 };
+    let extra_triggers = challenge.trigger_conditions.len().saturating_sub(2);
+    let trigger_list = challenge
+        .trigger_conditions
+        .iter()
+        .map(|c| if c.required { format!("{} (required)", c.summary()) } else { c.summary() })
+        .collect::<Vec<_>>()
+        .join("\n");
+    // Triggers only fire while the challenge is active
+    let trigger_class = if challenge.active {
+        "px-1.5 py-0.5 bg-purple-500/20 text-purple-300 text-[0.625rem] rounded whitespace-nowrap max-w-[160px] overflow-hidden text-ellipsis"
+    } else {
+        "px-1.5 py-0.5 bg-gray-700 text-gray-500 text-[0.625rem] rounded whitespace-nowrap max-w-[160px] overflow-hidden text-ellipsis line-through"
+    };
 
     rsx! {
         div {
@@ -118,6 +134,13 @@ pub fn ChallengeCard(props: ChallengeCardProps) -> Element {
                     span { class: "text-gray-400 text-xs",
                         "{challenge.difficulty.display()}"
                     }
+                    if let Some(ref catalog) = challenge.source_catalog {
+                        span {
+                            class: "px-1.5 py-0.5 bg-indigo-500/20 text-indigo-300 text-[0.625rem] rounded whitespace-nowrap",
+                            title: "Copied from a shared catalog",
+                            "📚 {catalog}"
+                        }
+                    }
                 }
                 div { class: "flex gap-2 flex-wrap",
                     span { class: "text-blue-400 text-xs",
@@ -129,6 +152,22 @@ pub fn ChallengeCard(props: ChallengeCardProps) -> Element {
                         }
                     }
                 }
+                if !challenge.trigger_conditions.is_empty() {
+                    div {
+                        class: "flex gap-1 flex-wrap mt-1",
+                        title: "{trigger_list}",
+                        for (index, condition) in challenge.trigger_conditions.iter().take(2).enumerate() {
+                            span {
+                                key: "{index}",
+                                class: "{trigger_class}",
+                                if condition.required { "⚡ {condition.summary()} *" } else { "⚡ {condition.summary()}" }
+                            }
+                        }
+                        if extra_triggers > 0 {
+                            span { class: "text-gray-500 text-[0.625rem]", "+{extra_triggers}" }
+                        }
+                    }
+                }
             }
 
             // Tags
@@ -166,6 +205,12 @@ pub fn ChallengeCard(props: ChallengeCardProps) -> Element {
                     }
                 }
 
+                CopyButton {
+                    text: stat_block,
+                    label: "",
+                    class: "px-2 py-1.5 bg-gray-700 text-white border-0 rounded cursor-pointer text-xs",
+                }
+
                 button {
                     onclick: move |_| props.on_edit.call(challenge_for_edit.clone()),
                     class: "px-2 py-1.5 bg-blue-500 text-white border-0 rounded cursor-pointer text-xs",
@@ -173,6 +218,7 @@ pub fn ChallengeCard(props: ChallengeCardProps) -> Element {
                 }
 
                 button {
+                    aria_label: "Delete challenge",
                     onclick: move |_| props.on_delete.call(id_for_delete.clone()),
                     class: "px-2 py-1.5 bg-red-500 text-white border-0 rounded cursor-pointer text-xs",
                     "×"
@@ -1,6 +1,7 @@
 //! Delete confirmation modal for challenges
 
 use dioxus::prelude::*;
+use crate::presentation::state::use_modal_focus;
 
 /// Props for delete confirmation modal
 #[derive(Props, Clone, PartialEq)]
@@ -14,12 +15,16 @@ pub struct ConfirmDeleteChallengeModalProps {
 /// Confirmation dialog for challenge deletion
 #[component]
 pub fn ConfirmDeleteChallengeModal(props: ConfirmDeleteChallengeModalProps) -> Element {
+    use_modal_focus("delete-challenge-modal");
     rsx! {
         div {
             class: "fixed inset-0 bg-black bg-opacity-75 flex items-center justify-center z-[1101]",
             onclick: move |_| props.on_cancel.call(()),
 
             div {
+                id: "delete-challenge-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "bg-dark-surface rounded-xl w-[90%] max-w-md p-6 overflow-hidden",
                 onclick: move |e| e.stop_propagation(),
 
@@ -34,14 +39,14 @@ pub fn ConfirmDeleteChallengeModal(props: ConfirmDeleteChallengeModalProps) -> E
 
                     h2 {
                         class: "text-red-600 text-lg m-0",
-                        "Delete Challenge"
+                        "Move Challenge to Trash"
                     }
                 }
 
                 // Message
                 p {
                     class: "text-gray-400 my-4",
-                    "Are you sure you want to delete \"{props.challenge_name}\"? This action cannot be undone."
+                    "Move \"{props.challenge_name}\" to the trash? It can be restored from Settings → Trash until it is purged."
                 }
 
                 // Buttons
@@ -59,7 +64,7 @@ pub fn ConfirmDeleteChallengeModal(props: ConfirmDeleteChallengeModalProps) -> E
                         onclick: move |_| props.on_confirm.call(()),
                         disabled: props.is_deleting,
                         class: "py-2 px-4 bg-red-600 text-white border-0 rounded-lg cursor-pointer text-sm font-medium",
-                        if props.is_deleting { "Deleting..." } else { "Delete Challenge" }
+                        if props.is_deleting { "Moving..." } else { "Move to Trash" }
                     }
                 }
             }
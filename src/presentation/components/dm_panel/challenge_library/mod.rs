@@ -5,14 +5,23 @@
 //! - Quick access to favorites
 //! - Create, edit, and delete challenges
 //! - Toggle active/favorite status
+//! - Visualize the prerequisite graph
+//! - Set the conditions that trigger a challenge automatically
+//! - Copy challenges from the Engine's shared catalogs
 
+mod catalog_browser;
 mod challenge_list;
 mod challenge_editor;
 mod delete_modal;
+mod outcome_editor;
+mod prerequisite_graph;
+mod trigger_condition_editor;
 
+pub use catalog_browser::CatalogBrowser;
 pub use challenge_list::ChallengeTypeSection;
 pub use challenge_editor::ChallengeFormModal;
 pub use delete_modal::ConfirmDeleteChallengeModal;
+pub use prerequisite_graph::PrerequisiteGraphView;
 
 use dioxus::prelude::*;
 use std::collections::HashMap;
@@ -20,7 +29,11 @@ use std::collections::HashMap;
 use crate::application::dto::{
     ChallengeData, ChallengeType, SkillData,
 };
+use crate::application::services::{FilterPreset, FilterPresetScope};
+use crate::presentation::components::common::{collect_tags, FilterPresetBar, TagFilterBar};
 use crate::presentation::services::use_challenge_service;
+use crate::application::ports::outbound::PageRequest;
+use crate::presentation::state::{is_near_bottom, use_paged_list, use_tag_filter_state, use_modal_focus};
 
 /// Props for ChallengeLibrary
 #[derive(Props, Clone, PartialEq)]
@@ -33,14 +46,22 @@ pub struct ChallengeLibraryProps {
     pub on_close: EventHandler<()>,
     /// Called when a challenge should be triggered
     pub on_trigger_challenge: Option<EventHandler<ChallengeData>>,
+    /// Challenge to open in the editor on mount, e.g. from a deep link
+    #[props(default)]
+    pub selected_challenge_id: Option<String>,
+    /// Called with the challenge ID when the editor opens, and `None` when it closes
+    #[props(default)]
+    pub on_select_challenge: Option<EventHandler<Option<String>>>,
 }
 
 /// Challenge Library component
 #[component]
 pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
-    let mut challenges: Signal<Vec<ChallengeData>> = use_signal(Vec::new);
-    let mut is_loading = use_signal(|| true);
-    let mut error: Signal<Option<String>> = use_signal(|| None);
+    use_modal_focus("challenge-library");
+    let challenge_pages = use_paged_list::<ChallengeData>();
+    let mut challenges = challenge_pages.items;
+    let is_loading = challenge_pages.loading;
+    let error = challenge_pages.error;
     let mut filter_type: Signal<Option<ChallengeType>> = use_signal(|| None);
     let mut search_query = use_signal(String::new);
     let mut show_only_favorites = use_signal(|| false);
@@ -49,6 +70,9 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
     let mut editing_challenge: Signal<Option<ChallengeData>> = use_signal(|| None);
     let mut show_delete_confirmation: Signal<Option<String>> = use_signal(|| None);
     let mut is_deleting = use_signal(|| false);
+    let mut show_graph = use_signal(|| false);
+    let mut show_catalog = use_signal(|| false);
+    let tag_filter = use_tag_filter_state();
 
     // Build skill lookup map
     let skills_map: HashMap<String, String> = props
@@ -58,28 +82,60 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
         .collect();
 
     let world_id = props.world_id.clone();
-    let world_id_for_effect = world_id.clone();
 
     // Get challenge service
     let challenge_service = use_challenge_service();
-    let challenge_service_for_effect = challenge_service.clone();
-
-    // Load challenges on mount
-    use_effect(move || {
-        let world_id = world_id_for_effect.clone();
-        let service = challenge_service_for_effect.clone();
-        spawn(async move {
-            match service.list_challenges(&world_id).await {
-                Ok(list) => {
-                    challenges.set(list);
-                    is_loading.set(false);
-                }
-                Err(e) => {
-                    error.set(Some(format!("Failed to load challenges: {}", e)));
-                    is_loading.set(false);
-                }
-            }
+
+    // Page fetcher shared by the initial load and infinite scrolling
+    let fetch_challenges = {
+        let world_id = world_id.clone();
+        let service = challenge_service.clone();
+        move |page: PageRequest| {
+            let world_id = world_id.clone();
+            let service = service.clone();
+            async move { service.list_challenges_page(&world_id, &page).await }
+        }
+    };
+
+    // Load the first page of challenges on mount
+    {
+        let fetch = fetch_challenges.clone();
+        use_effect(move || {
+            spawn(challenge_pages.load_first(fetch.clone()));
         });
+    }
+
+    // Follow the linked challenge as the route changes
+    {
+        let service = challenge_service.clone();
+        let selected_challenge_id = props.selected_challenge_id.clone();
+        use_effect(use_reactive!(|selected_challenge_id| {
+            let Some(challenge_id) = selected_challenge_id else {
+                editing_challenge.set(None);
+                return;
+            };
+            // Already open, e.g. the editor itself updated the route
+            if editing_challenge.peek().as_ref().is_some_and(|c| c.id == challenge_id) {
+                return;
+            }
+            let service = service.clone();
+            spawn(async move {
+                match service.get_challenge(&challenge_id).await {
+                    Ok(challenge) => editing_challenge.set(Some(challenge)),
+                    Err(e) => tracing::error!("Failed to load challenge {}: {}", challenge_id, e),
+                }
+            });
+        }));
+    }
+
+    // Open or close the editor, keeping the route in step
+    let on_select_challenge = props.on_select_challenge;
+    let select_challenge = EventHandler::new(move |challenge: Option<ChallengeData>| {
+        let challenge_id = challenge.as_ref().map(|c| c.id.clone());
+        editing_challenge.set(challenge);
+        if let Some(handler) = on_select_challenge {
+            handler.call(challenge_id);
+        }
     });
 
     // Filter challenges based on current filters
@@ -107,6 +163,10 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
                 if active_only && !c.active {
                     return false;
                 }
+                // Global tag filter
+                if !tag_filter.matches(&c.tags) {
+                    return false;
+                }
                 // Search filter
                 if !search.is_empty() {
                     let name_match = c.name.to_lowercase().contains(&search);
@@ -251,12 +311,24 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
         None => String::new(),
     };
 
+    // Current filters, for saving and highlighting presets
+    let current_filters = FilterPreset {
+        type_filter: filter_type.read().as_ref().map(|t| format!("{:?}", t)),
+        tags: tag_filter.active_tags.read().clone(),
+        favorites_only: *show_only_favorites.read(),
+        search: search_query.read().clone(),
+        ..Default::default()
+    };
+
     rsx! {
         div {
             class: "fixed inset-0 bg-black/85 flex items-center justify-center z-[1000]",
             onclick: move |_| props.on_close.call(()),
 
             div {
+                id: "challenge-library",
+                role: "dialog",
+                aria_modal: "true",
                 class: "bg-dark-surface rounded-xl w-[95%] max-w-[1000px] max-h-[90vh] overflow-hidden flex flex-col",
                 onclick: move |e| e.stop_propagation(),
 
@@ -267,6 +339,33 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
                     h2 { class: "text-white m-0 text-xl", "Challenge Library" }
 
                     div { class: "flex gap-3 items-center",
+                        button {
+                            onclick: move |_| {
+                                let current = *show_catalog.read();
+                                show_catalog.set(!current);
+                                show_graph.set(false);
+                            },
+                            class: "px-4 py-2 bg-indigo-500 text-white border-0 rounded-lg cursor-pointer text-sm",
+                            if *show_catalog.read() { "This World" } else { "Shared Catalog" }
+                        }
+
+                        button {
+                            onclick: {
+                                let fetch = fetch_challenges.clone();
+                                move |_| {
+                                    let current = *show_graph.read();
+                                    show_graph.set(!current);
+                                    show_catalog.set(false);
+                                    // The graph needs every challenge to draw prerequisite edges
+                                    if !current {
+                                        spawn(challenge_pages.load_all(fetch.clone()));
+                                    }
+                                }
+                            },
+                            class: "px-4 py-2 bg-gray-700 text-white border-0 rounded-lg cursor-pointer text-sm",
+                            if *show_graph.read() { "List View" } else { "Prerequisite Graph" }
+                        }
+
                         button {
                             onclick: move |_| show_create_form.set(true),
                             class: "px-4 py-2 bg-emerald-500 text-white border-0 rounded-lg cursor-pointer text-sm",
@@ -274,6 +373,7 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
                         }
 
                         button {
+                            aria_label: "Close",
                             onclick: move |_| props.on_close.call(()),
                             class: "p-2 bg-transparent border-0 text-gray-400 cursor-pointer text-2xl",
                             "×"
@@ -299,18 +399,7 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
                         value: "{type_value}",
                         onchange: move |e| {
                             let val = e.value();
-                            filter_type.set(if val.is_empty() {
-                                None
-                            } else {
-                                match val.as_str() {
-                                    "SkillCheck" => Some(ChallengeType::SkillCheck),
-                                    "AbilityCheck" => Some(ChallengeType::AbilityCheck),
-                                    "SavingThrow" => Some(ChallengeType::SavingThrow),
-                                    "OpposedCheck" => Some(ChallengeType::OpposedCheck),
-                                    "ComplexChallenge" => Some(ChallengeType::ComplexChallenge),
-                                    _ => None,
-                                }
-                            });
+                            filter_type.set(parse_challenge_type(&val));
                         },
                         class: "p-2 bg-dark-bg border border-gray-700 rounded text-white",
                         option { value: "", "All Types" }
@@ -337,31 +426,79 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
                         }
                         "Active Only"
                     }
+
+                    // Global tag filter
+                    div {
+                        class: "w-full",
+                        TagFilterBar {
+                            available_tags: collect_tags(challenges.read().iter().map(|c| &c.tags)),
+                        }
+                    }
+
+                    // Saved filter presets
+                    div {
+                        class: "w-full",
+                        FilterPresetBar {
+                            scope: FilterPresetScope::ChallengeLibrary,
+                            world_id: world_id.clone(),
+                            current: current_filters,
+                            on_apply: {
+                                let mut tag_filter = tag_filter.clone();
+                                move |preset: FilterPreset| {
+                                    filter_type.set(preset.type_filter.as_deref().and_then(parse_challenge_type));
+                                    show_only_favorites.set(preset.favorites_only);
+                                    search_query.set(preset.search);
+                                    tag_filter.active_tags.set(preset.tags);
+                                }
+                            },
+                        }
+                    }
                 }
 
                 // Error message
                 if let Some(err) = error.read().as_ref() {
                     div {
                         class: "px-6 py-3 bg-red-500/10 text-red-500 text-sm",
-                        "{err}"
+                        "Failed to load challenges: {err}"
                     }
                 }
 
                 // Content
                 div {
                     class: "flex-1 overflow-y-auto p-4 px-6",
+                    onscroll: {
+                        let fetch = fetch_challenges.clone();
+                        move |e: ScrollEvent| {
+                            if !*show_graph.read() && !*show_catalog.read() && challenge_pages.has_more() && is_near_bottom(&e) {
+                                spawn(challenge_pages.load_more(fetch.clone()));
+                            }
+                        }
+                    },
 
-                    if *is_loading.read() {
+                    if *show_catalog.read() {
+                        CatalogBrowser {
+                            world_id: world_id.clone(),
+                            skills: props.skills.clone(),
+                            search: search_query.read().clone(),
+                            on_copied: move |challenge: ChallengeData| challenges.write().push(challenge),
+                        }
+                    } else if *is_loading.read() {
                         div {
                             class: "flex items-center justify-center p-12 text-gray-400",
                             "Loading challenges..."
                         }
+                    } else if *show_graph.read() {
+                        PrerequisiteGraphView {
+                            world_id: world_id.clone(),
+                            challenges: challenges.read().clone(),
+                            on_edit: move |c: ChallengeData| select_challenge.call(Some(c)),
+                        }
                     } else if filtered_challenges.is_empty() {
                         div {
                             class: "flex flex-col items-center justify-center p-12 text-gray-500 text-center",
                             div { class: "text-4xl mb-2", "🎲" }
                             p { class: "m-0", "No challenges found" }
-                            if !search_query.read().is_empty() || filter_type.read().is_some() {
+                            if !search_query.read().is_empty() || filter_type.read().is_some() || tag_filter.is_filtering() {
                                 p { class: "m-0 mt-2 text-sm", "Try adjusting your filters" }
                             } else {
                                 button {
@@ -384,16 +521,19 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
                                             skills_map: skills_map.clone(),
                                             on_toggle_favorite: handle_toggle_favorite.clone(),
                                             on_toggle_active: handle_toggle_active.clone(),
-                                            on_edit: {
-                                                let mut editing = editing_challenge.clone();
-                                                move |c: ChallengeData| editing.set(Some(c))
-                                            },
+                                            on_edit: move |c: ChallengeData| select_challenge.call(Some(c)),
                                             on_delete: handle_delete.clone(),
                                             on_trigger: props.on_trigger_challenge.clone(),
                                         }
                                     }
                                 }
                             }
+                            if challenge_pages.has_more() {
+                                div {
+                                    class: "text-center text-gray-500 text-xs py-2",
+                                    if challenge_pages.is_fetching() { "Loading more challenges..." } else { "Scroll for more" }
+                                }
+                            }
                         }
                     }
                 }
@@ -405,6 +545,7 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
                     world_id: world_id.clone(),
                     challenge: None,
                     skills: props.skills.clone(),
+                    all_challenges: challenges.read().clone(),
                     on_save: {
                         let mut challenges = challenges.clone();
                         move |challenge: ChallengeData| {
@@ -422,6 +563,7 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
                     world_id: world_id.clone(),
                     challenge: Some(challenge.clone()),
                     skills: props.skills.clone(),
+                    all_challenges: challenges.read().clone(),
                     on_save: {
                         let mut challenges = challenges.clone();
                         let challenge_id = challenge.id.clone();
@@ -430,10 +572,10 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
                             if let Some(c) = write.iter_mut().find(|c| c.id == challenge_id) {
                                 *c = updated;
                             }
-                            editing_challenge.set(None);
+                            select_challenge.call(None);
                         }
                     },
-                    on_close: move |_| editing_challenge.set(None),
+                    on_close: move |_| select_challenge.call(None),
                 }
             }
 
@@ -451,3 +593,15 @@ pub fn ChallengeLibrary(props: ChallengeLibraryProps) -> Element {
         }
     }
 }
+
+/// Parse a type filter value (the variant name, as in the dropdown)
+fn parse_challenge_type(value: &str) -> Option<ChallengeType> {
+    match value {
+        "SkillCheck" => Some(ChallengeType::SkillCheck),
+        "AbilityCheck" => Some(ChallengeType::AbilityCheck),
+        "SavingThrow" => Some(ChallengeType::SavingThrow),
+        "OpposedCheck" => Some(ChallengeType::OpposedCheck),
+        "ComplexChallenge" => Some(ChallengeType::ComplexChallenge),
+        _ => None,
+    }
+}
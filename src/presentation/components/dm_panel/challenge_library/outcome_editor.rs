@@ -0,0 +1,301 @@
+//! Outcome editor - narrative text and triggers for one challenge result branch
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{ChallengeData, Outcome, OutcomeTrigger};
+use crate::presentation::components::common::{AudioCuePicker, CameraEffectPicker};
+
+/// Props for OutcomeEditor
+#[derive(Props, Clone, PartialEq)]
+pub struct OutcomeEditorProps {
+    pub label: &'static str,
+    /// Label color, e.g. "text-emerald-500"
+    pub text_class: &'static str,
+    /// Textarea border color, e.g. "border-emerald-500"
+    pub border_class: &'static str,
+    /// `None` means the branch is not authored (optional branches only)
+    pub outcome: Option<Outcome>,
+    /// Whether the branch can be switched off
+    #[props(default)]
+    pub optional: bool,
+    #[props(default)]
+    pub placeholder: &'static str,
+    /// Challenges that enable/disable triggers can point at
+    #[props(default)]
+    pub challenges: Vec<ChallengeData>,
+    pub on_change: EventHandler<Option<Outcome>>,
+}
+
+/// Editor for a single outcome branch (success, partial, critical, ...)
+#[component]
+pub fn OutcomeEditor(props: OutcomeEditorProps) -> Element {
+    let Some(outcome) = props.outcome.clone() else {
+        return rsx! {
+            button {
+                r#type: "button",
+                onclick: move |_| props.on_change.call(Some(Outcome::default())),
+                class: "w-full p-2 bg-transparent border border-dashed border-gray-700 rounded text-gray-500 text-xs cursor-pointer text-left",
+                "+ Add {props.label} outcome"
+            }
+        };
+    };
+
+    let on_change = props.on_change;
+    let update = move |next: Outcome| on_change.call(Some(next));
+
+    rsx! {
+        div {
+            div { class: "flex justify-between items-center mb-1",
+                label { class: "{props.text_class} text-xs", "{props.label} Outcome" }
+                if props.optional {
+                    button {
+                        r#type: "button",
+                        onclick: move |_| on_change.call(None),
+                        class: "bg-transparent border-0 text-gray-500 text-xs cursor-pointer",
+                        "Remove"
+                    }
+                }
+            }
+            textarea {
+                value: "{outcome.description}",
+                oninput: {
+                    let outcome = outcome.clone();
+                    move |e: FormEvent| {
+                        let mut next = outcome.clone();
+                        next.description = e.value();
+                        update(next);
+                    }
+                },
+                placeholder: "{props.placeholder}",
+                rows: "2",
+                class: "w-full p-2 bg-dark-bg border {props.border_class} rounded text-white resize-y box-border",
+            }
+
+            // Triggers
+            div { class: "flex flex-col gap-1 mt-1",
+                for (index, trigger) in outcome.triggers.iter().enumerate() {
+                    TriggerRow {
+                        key: "{index}",
+                        trigger: trigger.clone(),
+                        challenges: props.challenges.clone(),
+                        on_change: {
+                            let outcome = outcome.clone();
+                            move |changed: OutcomeTrigger| {
+                                let mut next = outcome.clone();
+                                next.triggers[index] = changed;
+                                update(next);
+                            }
+                        },
+                        on_remove: {
+                            let outcome = outcome.clone();
+                            move |_| {
+                                let mut next = outcome.clone();
+                                next.triggers.remove(index);
+                                update(next);
+                            }
+                        },
+                    }
+                }
+                select {
+                    value: "",
+                    onchange: {
+                        let outcome = outcome.clone();
+                        move |e: FormEvent| {
+                            let picked = e.value();
+                            if let Some(template) = OutcomeTrigger::templates()
+                                .into_iter()
+                                .find(|t| t.display_name() == picked)
+                            {
+                                let mut next = outcome.clone();
+                                next.triggers.push(template);
+                                update(next);
+                            }
+                        }
+                    },
+                    class: "self-start p-1 bg-dark-bg border border-gray-700 rounded text-gray-400 text-xs",
+                    option { value: "", "+ Add trigger..." }
+                    for template in OutcomeTrigger::templates() {
+                        option { value: "{template.display_name()}", "{template.display_name()}" }
+                    }
+                }
+                AudioCuePicker {
+                    cue: outcome.audio_cue.clone(),
+                    on_change: {
+                        let outcome = outcome.clone();
+                        move |cue| {
+                            let mut next = outcome.clone();
+                            next.audio_cue = cue;
+                            update(next);
+                        }
+                    },
+                }
+                CameraEffectPicker {
+                    effect: outcome.camera_effect.clone(),
+                    on_change: {
+                        let outcome = outcome.clone();
+                        move |effect| {
+                            let mut next = outcome.clone();
+                            next.camera_effect = effect;
+                            update(next);
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Props for TriggerRow
+#[derive(Props, Clone, PartialEq)]
+struct TriggerRowProps {
+    trigger: OutcomeTrigger,
+    challenges: Vec<ChallengeData>,
+    on_change: EventHandler<OutcomeTrigger>,
+    on_remove: EventHandler<()>,
+}
+
+/// Inline fields for one outcome trigger
+#[component]
+fn TriggerRow(props: TriggerRowProps) -> Element {
+    let on_change = props.on_change;
+    let input_class = "flex-1 min-w-0 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs";
+
+    let fields = match props.trigger.clone() {
+        OutcomeTrigger::RevealInformation { info, persist } => rsx! {
+            input {
+                r#type: "text",
+                value: "{info}",
+                placeholder: "Information revealed...",
+                oninput: move |e| on_change.call(OutcomeTrigger::RevealInformation { info: e.value(), persist }),
+                class: "{input_class}",
+            }
+            label { class: "flex items-center gap-1 text-gray-400 text-xs",
+                input {
+                    r#type: "checkbox",
+                    checked: persist,
+                    onchange: {
+                        let info = info.clone();
+                        move |e: FormEvent| on_change.call(OutcomeTrigger::RevealInformation { info: info.clone(), persist: e.checked() })
+                    },
+                }
+                "Persist"
+            }
+        },
+        OutcomeTrigger::EnableChallenge { challenge_id } => rsx! {
+            ChallengePicker {
+                selected: challenge_id,
+                challenges: props.challenges.clone(),
+                on_change: move |id| on_change.call(OutcomeTrigger::EnableChallenge { challenge_id: id }),
+            }
+        },
+        OutcomeTrigger::DisableChallenge { challenge_id } => rsx! {
+            ChallengePicker {
+                selected: challenge_id,
+                challenges: props.challenges.clone(),
+                on_change: move |id| on_change.call(OutcomeTrigger::DisableChallenge { challenge_id: id }),
+            }
+        },
+        OutcomeTrigger::ModifyCharacterStat { stat, modifier } => rsx! {
+            input {
+                r#type: "text",
+                value: "{stat}",
+                placeholder: "Stat",
+                oninput: move |e| on_change.call(OutcomeTrigger::ModifyCharacterStat { stat: e.value(), modifier }),
+                class: "{input_class}",
+            }
+            input {
+                r#type: "number",
+                value: "{modifier}",
+                oninput: {
+                    let stat = stat.clone();
+                    move |e: FormEvent| {
+                        if let Ok(modifier) = e.value().parse() {
+                            on_change.call(OutcomeTrigger::ModifyCharacterStat { stat: stat.clone(), modifier });
+                        }
+                    }
+                },
+                class: "w-16 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+            }
+        },
+        OutcomeTrigger::TriggerScene { scene_id } => rsx! {
+            input {
+                r#type: "text",
+                value: "{scene_id}",
+                placeholder: "Scene ID",
+                oninput: move |e| on_change.call(OutcomeTrigger::TriggerScene { scene_id: e.value() }),
+                class: "{input_class}",
+            }
+        },
+        OutcomeTrigger::GiveItem { item_name, item_description } => rsx! {
+            input {
+                r#type: "text",
+                value: "{item_name}",
+                placeholder: "Item name",
+                oninput: {
+                    let item_description = item_description.clone();
+                    move |e: FormEvent| on_change.call(OutcomeTrigger::GiveItem {
+                        item_name: e.value(),
+                        item_description: item_description.clone(),
+                    })
+                },
+                class: "{input_class}",
+            }
+            input {
+                r#type: "text",
+                value: "{item_description.clone().unwrap_or_default()}",
+                placeholder: "Description (optional)",
+                oninput: move |e| {
+                    let value = e.value();
+                    on_change.call(OutcomeTrigger::GiveItem {
+                        item_name: item_name.clone(),
+                        item_description: if value.is_empty() { None } else { Some(value) },
+                    })
+                },
+                class: "{input_class}",
+            }
+        },
+        OutcomeTrigger::Custom { description } => rsx! {
+            input {
+                r#type: "text",
+                value: "{description}",
+                placeholder: "Describe the effect...",
+                oninput: move |e| on_change.call(OutcomeTrigger::Custom { description: e.value() }),
+                class: "{input_class}",
+            }
+        },
+    };
+
+    rsx! {
+        div { class: "flex items-center gap-1 pl-2 border-l-2 border-gray-700",
+            span { class: "text-gray-500 text-xs whitespace-nowrap", "{props.trigger.display_name()}" }
+            {fields}
+            button {
+                aria_label: "Remove trigger",
+                r#type: "button",
+                onclick: move |_| props.on_remove.call(()),
+                class: "bg-transparent border-0 text-gray-500 cursor-pointer p-0 leading-none",
+                "×"
+            }
+        }
+    }
+}
+
+/// Dropdown of challenges in the world
+#[component]
+fn ChallengePicker(
+    selected: String,
+    challenges: Vec<ChallengeData>,
+    on_change: EventHandler<String>,
+) -> Element {
+    rsx! {
+        select {
+            value: "{selected}",
+            onchange: move |e| on_change.call(e.value()),
+            class: "flex-1 min-w-0 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+            option { value: "", "Select a challenge..." }
+            for challenge in challenges.iter() {
+                option { value: "{challenge.id}", "{challenge.name}" }
+            }
+        }
+    }
+}
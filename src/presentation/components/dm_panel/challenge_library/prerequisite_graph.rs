@@ -0,0 +1,281 @@
+//! Challenge prerequisite graph
+//!
+//! Lays out challenges as a DAG by prerequisite depth, flags cycles, and
+//! marks which challenges are unlocked given the story so far.
+
+use dioxus::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::application::dto::{ChallengeData, StoryEventData, StoryEventTypeData};
+use crate::presentation::components::common::{
+    layer_by_depth, nodes_in_cycles, reaches, Predecessors,
+};
+use crate::presentation::services::use_story_event_service;
+
+/// IDs of challenges that have been completed successfully in the story
+pub fn completed_challenge_ids(events: &[StoryEventData]) -> HashSet<String> {
+    events
+        .iter()
+        .filter_map(|event| match &event.event_type {
+            StoryEventTypeData::ChallengeAttempted {
+                challenge_id: Some(id),
+                outcome,
+                ..
+            } if !outcome.to_lowercase().contains("fail") => Some(id.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Prerequisite edges of the challenge graph
+fn prerequisite_edges(challenges: &[ChallengeData]) -> Predecessors<'_> {
+    challenges
+        .iter()
+        .map(|c| {
+            let prereqs = c
+                .prerequisite_challenges
+                .iter()
+                .map(String::as_str)
+                .collect();
+            (c.id.as_str(), prereqs)
+        })
+        .collect()
+}
+
+/// IDs of challenges that sit on a prerequisite cycle
+pub fn challenges_in_cycles(challenges: &[ChallengeData]) -> HashSet<String> {
+    let ids: Vec<&str> = challenges.iter().map(|c| c.id.as_str()).collect();
+    nodes_in_cycles(&ids, &prerequisite_edges(challenges))
+}
+
+/// Whether giving `challenge_id` the prerequisites `prerequisites` would
+/// introduce a cycle into the graph
+pub fn would_create_cycle(
+    challenges: &[ChallengeData],
+    challenge_id: &str,
+    prerequisites: &[String],
+) -> bool {
+    let mut edges = prerequisite_edges(challenges);
+    edges.insert(
+        challenge_id,
+        prerequisites.iter().map(String::as_str).collect(),
+    );
+    reaches(&edges, challenge_id, challenge_id)
+}
+
+/// Group challenges into layers by prerequisite depth
+///
+/// Layer 0 holds challenges with no (known) prerequisites. Challenges on a
+/// cycle have no well-defined depth and are returned in a final extra layer.
+pub fn layer_challenges(challenges: &[ChallengeData]) -> Vec<Vec<ChallengeData>> {
+    let ids: Vec<&str> = challenges.iter().map(|c| c.id.as_str()).collect();
+    let by_id: HashMap<&str, &ChallengeData> =
+        challenges.iter().map(|c| (c.id.as_str(), c)).collect();
+    layer_by_depth(&ids, &prerequisite_edges(challenges))
+        .into_iter()
+        .map(|layer| {
+            layer
+                .iter()
+                .filter_map(|id| by_id.get(id.as_str()).map(|c| (*c).clone()))
+                .collect()
+        })
+        .collect()
+}
+
+/// Props for PrerequisiteGraphView
+#[derive(Props, Clone, PartialEq)]
+pub struct PrerequisiteGraphViewProps {
+    pub world_id: String,
+    pub challenges: Vec<ChallengeData>,
+    pub on_edit: EventHandler<ChallengeData>,
+}
+
+/// DAG view of challenge prerequisites
+#[component]
+pub fn PrerequisiteGraphView(props: PrerequisiteGraphViewProps) -> Element {
+    let mut completed: Signal<HashSet<String>> = use_signal(HashSet::new);
+    let story_event_service = use_story_event_service();
+
+    // Load story events to determine which challenges are completed
+    let world_id = props.world_id.clone();
+    use_effect(move || {
+        let world_id = world_id.clone();
+        let service = story_event_service.clone();
+        spawn(async move {
+            if let Ok(events) = service.list_story_events(&world_id, None).await {
+                completed.set(completed_challenge_ids(&events));
+            }
+        });
+    });
+
+    let names: HashMap<String, String> = props
+        .challenges
+        .iter()
+        .map(|c| (c.id.clone(), c.name.clone()))
+        .collect();
+    let cyclic = challenges_in_cycles(&props.challenges);
+    let layers = layer_challenges(&props.challenges);
+    let completed_ids = completed.read().clone();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-4",
+
+            if !cyclic.is_empty() {
+                div {
+                    class: "p-3 bg-red-500/10 border border-red-500 rounded-lg text-red-500 text-sm",
+                    "⚠ Prerequisite cycle detected: "
+                    {
+                        let mut cycle_names: Vec<&str> = cyclic
+                            .iter()
+                            .filter_map(|id| names.get(id).map(String::as_str))
+                            .collect();
+                        cycle_names.sort();
+                        cycle_names.join(", ")
+                    }
+                    ". These challenges can never be unlocked."
+                }
+            }
+
+            // Legend
+            div {
+                class: "flex gap-4 text-xs text-gray-400",
+                span { class: "flex items-center gap-1", span { class: "w-2 h-2 rounded-full bg-green-500" } "Completed" }
+                span { class: "flex items-center gap-1", span { class: "w-2 h-2 rounded-full bg-blue-500" } "Unlocked" }
+                span { class: "flex items-center gap-1", span { class: "w-2 h-2 rounded-full bg-gray-600" } "Locked" }
+                span { class: "flex items-center gap-1", span { class: "w-2 h-2 rounded-full bg-red-500" } "Cycle" }
+            }
+
+            div {
+                class: "flex gap-6 overflow-x-auto pb-2",
+
+                for (index, layer) in layers.iter().enumerate() {
+                    div {
+                        key: "{index}",
+                        class: "flex flex-col gap-2 min-w-[200px]",
+
+                        div {
+                            class: "text-gray-500 text-xs uppercase",
+                            if layer.iter().any(|c| cyclic.contains(&c.id)) {
+                                "Cycle"
+                            } else if index == 0 {
+                                "Available from start"
+                            } else {
+                                "Tier {index}"
+                            }
+                        }
+
+                        for challenge in layer.iter() {
+                            {
+                                let is_cyclic = cyclic.contains(&challenge.id);
+                                let is_completed = completed_ids.contains(&challenge.id);
+                                let is_unlocked = !is_cyclic
+                                    && challenge
+                                        .prerequisite_challenges
+                                        .iter()
+                                        .all(|p| completed_ids.contains(p));
+                                let (border, dot) = if is_cyclic {
+                                    ("border-red-500", "bg-red-500")
+                                } else if is_completed {
+                                    ("border-green-500", "bg-green-500")
+                                } else if is_unlocked {
+                                    ("border-blue-500", "bg-blue-500")
+                                } else {
+                                    ("border-gray-700", "bg-gray-600")
+                                };
+                                let requires_text = challenge
+                                    .prerequisite_challenges
+                                    .iter()
+                                    .map(|p| names.get(p).cloned().unwrap_or_else(|| format!("Unknown ({})", p)))
+                                    .collect::<Vec<_>>()
+                                    .join(", ");
+                                let challenge_for_edit = challenge.clone();
+                                rsx! {
+                                    div {
+                                        key: "{challenge.id}",
+                                        class: "p-2 bg-dark-bg border {border} rounded-lg cursor-pointer",
+                                        onclick: move |_| props.on_edit.call(challenge_for_edit.clone()),
+
+                                        div {
+                                            class: "flex items-center gap-2",
+                                            span { class: "w-2 h-2 rounded-full {dot}" }
+                                            span { class: "text-white text-sm", "{challenge.name}" }
+                                        }
+                                        if !requires_text.is_empty() {
+                                            div {
+                                                class: "text-gray-500 text-[0.625rem] mt-1",
+                                                "Requires: {requires_text}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if layers.is_empty() {
+                div {
+                    class: "text-gray-500 text-center p-8",
+                    "No challenges to show"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn challenge(id: &str, prereqs: &[&str]) -> ChallengeData {
+        ChallengeData {
+            id: id.to_string(),
+            world_id: "w".to_string(),
+            scene_id: None,
+            name: id.to_uppercase(),
+            description: String::new(),
+            challenge_type: Default::default(),
+            skill_id: String::new(),
+            difficulty: Default::default(),
+            outcomes: Default::default(),
+            trigger_conditions: vec![],
+            prerequisite_challenges: prereqs.iter().map(|s| s.to_string()).collect(),
+            active: true,
+            order: 0,
+            is_favorite: false,
+            tags: vec![],
+            source_catalog: None,
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_layers_follow_prerequisite_depth() {
+        let challenges = vec![challenge("a", &[]), challenge("b", &["a"]), challenge("c", &["a", "b"])];
+        let layers = layer_challenges(&challenges);
+        let ids: Vec<Vec<&str>> = layers
+            .iter()
+            .map(|l| l.iter().map(|c| c.id.as_str()).collect())
+            .collect();
+        assert_eq!(ids, vec![vec!["a"], vec!["b"], vec!["c"]]);
+    }
+
+    #[test]
+    fn test_cycles_are_detected() {
+        let challenges = vec![challenge("a", &["c"]), challenge("b", &["a"]), challenge("c", &["b"]), challenge("d", &[])];
+        let cyclic = challenges_in_cycles(&challenges);
+        assert_eq!(cyclic.len(), 3);
+        assert!(!cyclic.contains("d"));
+        assert_eq!(layer_challenges(&challenges).len(), 2);
+    }
+
+    #[test]
+    fn test_would_create_cycle() {
+        let challenges = vec![challenge("a", &[]), challenge("b", &["a"])];
+        assert!(would_create_cycle(&challenges, "a", &["b".to_string()]));
+        assert!(!would_create_cycle(&challenges, "a", &[]));
+        assert!(would_create_cycle(&challenges, "a", &["a".to_string()]));
+    }
+}
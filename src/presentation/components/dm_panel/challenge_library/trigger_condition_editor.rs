@@ -0,0 +1,459 @@
+//! Trigger condition editor - when the Engine should bring a challenge up
+//!
+//! Conditions that point at a region, NPC, item, or narrative event let the
+//! Engine trigger the challenge on its own; keyword conditions are hints for
+//! the LLM.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{ChallengeData, TriggerCondition, TriggerType};
+use crate::presentation::services::{
+    use_character_service, use_location_service, use_narrative_event_service,
+};
+use crate::presentation::state::use_game_state;
+
+/// Something a condition can point at
+#[derive(Debug, Clone, PartialEq)]
+struct TriggerTarget {
+    id: String,
+    label: String,
+}
+
+/// Everything in the world conditions can point at
+#[derive(Debug, Clone, Default, PartialEq)]
+struct TriggerTargets {
+    regions: Vec<TriggerTarget>,
+    npcs: Vec<TriggerTarget>,
+    items: Vec<TriggerTarget>,
+    narrative_events: Vec<TriggerTarget>,
+}
+
+/// Props for TriggerConditionEditor
+#[derive(Props, Clone, PartialEq)]
+pub struct TriggerConditionEditorProps {
+    pub world_id: String,
+    pub conditions: Vec<TriggerCondition>,
+    /// Challenges that "Challenge Complete" conditions can point at
+    #[props(default)]
+    pub challenges: Vec<ChallengeData>,
+    pub on_change: EventHandler<Vec<TriggerCondition>>,
+}
+
+/// List of a challenge's trigger conditions with an "add" picker
+#[component]
+pub fn TriggerConditionEditor(props: TriggerConditionEditorProps) -> Element {
+    let game_state = use_game_state();
+    let character_service = use_character_service();
+    let location_service = use_location_service();
+    let narrative_event_service = use_narrative_event_service();
+    let mut targets: Signal<TriggerTargets> = use_signal(TriggerTargets::default);
+
+    // Items come with the world snapshot; the rest are loaded
+    {
+        let world_id = props.world_id.clone();
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let character_service = character_service.clone();
+            let location_service = location_service.clone();
+            let narrative_event_service = narrative_event_service.clone();
+            let items = game_state
+                .world
+                .peek()
+                .as_ref()
+                .map(|world| {
+                    world
+                        .items
+                        .iter()
+                        .map(|i| TriggerTarget { id: i.id.clone(), label: i.name.clone() })
+                        .collect()
+                })
+                .unwrap_or_default();
+            spawn(async move {
+                let mut loaded = TriggerTargets { items, ..TriggerTargets::default() };
+
+                match character_service.list_characters(&world_id).await {
+                    Ok(characters) => {
+                        loaded.npcs = characters
+                            .into_iter()
+                            .map(|c| TriggerTarget { id: c.id, label: c.name })
+                            .collect();
+                    }
+                    Err(e) => tracing::warn!("Failed to load NPCs for trigger conditions: {}", e),
+                }
+
+                match location_service.list_locations(&world_id).await {
+                    Ok(locations) => {
+                        for location in locations {
+                            match location_service.get_regions(&location.id).await {
+                                Ok(regions) => loaded.regions.extend(regions.into_iter().map(|r| TriggerTarget {
+                                    id: r.id,
+                                    label: format!("{} › {}", location.name, r.name),
+                                })),
+                                Err(e) => tracing::warn!("Failed to load regions of {}: {}", location.name, e),
+                            }
+                        }
+                    }
+                    Err(e) => tracing::warn!("Failed to load locations for trigger conditions: {}", e),
+                }
+
+                match narrative_event_service.list_narrative_events(&world_id).await {
+                    Ok(events) => {
+                        loaded.narrative_events = events
+                            .into_iter()
+                            .map(|e| TriggerTarget { id: e.id, label: e.name })
+                            .collect();
+                    }
+                    Err(e) => tracing::warn!("Failed to load narrative events for trigger conditions: {}", e),
+                }
+
+                targets.set(loaded);
+            });
+        });
+    }
+
+    let conditions = props.conditions.clone();
+    let on_change = props.on_change;
+
+    rsx! {
+        div { class: "flex flex-col gap-1",
+            for (index, condition) in conditions.iter().enumerate() {
+                ConditionRow {
+                    key: "{index}",
+                    condition: condition.clone(),
+                    targets: targets.read().clone(),
+                    challenges: props.challenges.clone(),
+                    on_change: {
+                        let conditions = conditions.clone();
+                        move |changed: TriggerCondition| {
+                            let mut next = conditions.clone();
+                            next[index] = changed;
+                            on_change.call(next);
+                        }
+                    },
+                    on_remove: {
+                        let conditions = conditions.clone();
+                        move |_| {
+                            let mut next = conditions.clone();
+                            next.remove(index);
+                            on_change.call(next);
+                        }
+                    },
+                }
+            }
+            if conditions.is_empty() {
+                p { class: "m-0 text-gray-500 text-xs italic", "No triggers - the DM starts this challenge by hand." }
+            }
+            select {
+                value: "",
+                onchange: {
+                    let conditions = conditions.clone();
+                    move |e: FormEvent| {
+                        let picked = e.value();
+                        if let Some(template) = TriggerType::templates()
+                            .into_iter()
+                            .find(|t| t.display_name() == picked)
+                        {
+                            let mut next = conditions.clone();
+                            next.push(TriggerCondition::new(template));
+                            on_change.call(next);
+                        }
+                    }
+                },
+                class: "self-start p-1 bg-dark-bg border border-gray-700 rounded text-gray-400 text-xs",
+                option { value: "", "+ Add trigger condition..." }
+                for template in TriggerType::templates() {
+                    option { value: "{template.display_name()}", "{template.display_name()}" }
+                }
+            }
+        }
+    }
+}
+
+/// Props for ConditionRow
+#[derive(Props, Clone, PartialEq)]
+struct ConditionRowProps {
+    condition: TriggerCondition,
+    targets: TriggerTargets,
+    challenges: Vec<ChallengeData>,
+    on_change: EventHandler<TriggerCondition>,
+    on_remove: EventHandler<()>,
+}
+
+/// Inline fields for one trigger condition
+#[component]
+fn ConditionRow(props: ConditionRowProps) -> Element {
+    let on_change = props.on_change;
+    let condition = props.condition.clone();
+    let input_class = "flex-1 min-w-0 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs";
+
+    // Swap in a new condition type, naming the target in the description
+    // when the DM hasn't written one
+    let set_type = {
+        let condition = condition.clone();
+        move |condition_type: TriggerType, label: Option<String>| {
+            let mut next = condition.clone();
+            if next.description.trim().is_empty() {
+                if let Some(label) = label {
+                    next.description = format!("{}: {}", condition_type.display_name(), label);
+                }
+            }
+            next.condition_type = condition_type;
+            on_change.call(next);
+        }
+    };
+
+    let fields = match condition.condition_type.clone() {
+        TriggerType::EnterRegion { region_id } => rsx! {
+            TargetPicker {
+                selected: region_id,
+                targets: props.targets.regions.clone(),
+                placeholder: "Select a region...",
+                on_change: {
+                    let set_type = set_type.clone();
+                    move |(id, label)| set_type(TriggerType::EnterRegion { region_id: id }, Some(label))
+                },
+            }
+        },
+        TriggerType::TalkToNpc { npc_id } => rsx! {
+            TargetPicker {
+                selected: npc_id,
+                targets: props.targets.npcs.clone(),
+                placeholder: "Select an NPC...",
+                on_change: {
+                    let set_type = set_type.clone();
+                    move |(id, label)| set_type(TriggerType::TalkToNpc { npc_id: id }, Some(label))
+                },
+            }
+        },
+        TriggerType::ItemAcquired { item_id } => rsx! {
+            TargetPicker {
+                selected: item_id,
+                targets: props.targets.items.clone(),
+                placeholder: "Select an item...",
+                on_change: {
+                    let set_type = set_type.clone();
+                    move |(id, label)| set_type(TriggerType::ItemAcquired { item_id: id }, Some(label))
+                },
+            }
+        },
+        TriggerType::StoryEventOccurred { narrative_event_id } => rsx! {
+            TargetPicker {
+                selected: narrative_event_id,
+                targets: props.targets.narrative_events.clone(),
+                placeholder: "Select a narrative event...",
+                on_change: {
+                    let set_type = set_type.clone();
+                    move |(id, label)| set_type(TriggerType::StoryEventOccurred { narrative_event_id: id }, Some(label))
+                },
+            }
+        },
+        TriggerType::ChallengeComplete { challenge_id, requires_success } => {
+            let challenge_targets: Vec<TriggerTarget> = props
+                .challenges
+                .iter()
+                .map(|c| TriggerTarget { id: c.id.clone(), label: c.name.clone() })
+                .collect();
+            let outcome_value = match requires_success {
+                Some(true) => "success",
+                Some(false) => "failure",
+                None => "any",
+            };
+            rsx! {
+                TargetPicker {
+                    selected: challenge_id.clone(),
+                    targets: challenge_targets,
+                    placeholder: "Select a challenge...",
+                    on_change: {
+                        let set_type = set_type.clone();
+                        move |(id, label)| set_type(TriggerType::ChallengeComplete { challenge_id: id, requires_success }, Some(label))
+                    },
+                }
+                select {
+                    value: "{outcome_value}",
+                    onchange: {
+                        let set_type = set_type.clone();
+                        move |e: FormEvent| {
+                            let requires_success = match e.value().as_str() {
+                                "success" => Some(true),
+                                "failure" => Some(false),
+                                _ => None,
+                            };
+                            set_type(TriggerType::ChallengeComplete { challenge_id: challenge_id.clone(), requires_success }, None)
+                        }
+                    },
+                    class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                    option { value: "any", "Any result" }
+                    option { value: "success", "Succeeded" }
+                    option { value: "failure", "Failed" }
+                }
+            }
+        }
+        TriggerType::TimeBased { turns } => rsx! {
+            input {
+                r#type: "number",
+                min: "1",
+                value: "{turns}",
+                oninput: {
+                    let set_type = set_type.clone();
+                    move |e: FormEvent| {
+                        if let Ok(turns) = e.value().parse() {
+                            set_type(TriggerType::TimeBased { turns }, None);
+                        }
+                    }
+                },
+                class: "w-16 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+            }
+            span { class: "text-gray-500 text-xs", "turns" }
+        },
+        TriggerType::ObjectInteraction { keywords } => rsx! {
+            KeywordsInput {
+                keywords,
+                on_change: {
+                    let set_type = set_type.clone();
+                    move |keywords| set_type(TriggerType::ObjectInteraction { keywords }, None)
+                },
+            }
+        },
+        TriggerType::EnterArea { area_keywords } => rsx! {
+            KeywordsInput {
+                keywords: area_keywords,
+                on_change: {
+                    let set_type = set_type.clone();
+                    move |area_keywords| set_type(TriggerType::EnterArea { area_keywords }, None)
+                },
+            }
+        },
+        TriggerType::DialogueTopic { topic_keywords } => rsx! {
+            KeywordsInput {
+                keywords: topic_keywords,
+                on_change: {
+                    let set_type = set_type.clone();
+                    move |topic_keywords| set_type(TriggerType::DialogueTopic { topic_keywords }, None)
+                },
+            }
+        },
+        TriggerType::NpcPresent { npc_keywords } => rsx! {
+            KeywordsInput {
+                keywords: npc_keywords,
+                on_change: {
+                    let set_type = set_type.clone();
+                    move |npc_keywords| set_type(TriggerType::NpcPresent { npc_keywords }, None)
+                },
+            }
+        },
+        TriggerType::Custom { description } => rsx! {
+            input {
+                r#type: "text",
+                value: "{description}",
+                placeholder: "Describe when this applies...",
+                oninput: {
+                    let set_type = set_type.clone();
+                    move |e: FormEvent| set_type(TriggerType::Custom { description: e.value() }, None)
+                },
+                class: "{input_class}",
+            }
+        },
+    };
+
+    let problem = condition.condition_type.problem();
+    let border_class = if problem.is_some() { "border-amber-500" } else { "border-gray-700" };
+
+    rsx! {
+        div { class: "flex flex-col gap-1 pl-2 border-l-2 {border_class}",
+            div { class: "flex items-center gap-1",
+                span { class: "text-gray-500 text-xs whitespace-nowrap", "{condition.condition_type.display_name()}" }
+                {fields}
+                button {
+                    aria_label: "Remove condition",
+                    r#type: "button",
+                    onclick: move |_| props.on_remove.call(()),
+                    class: "bg-transparent border-0 text-gray-500 cursor-pointer p-0 leading-none",
+                    "×"
+                }
+            }
+            div { class: "flex items-center gap-2",
+                input {
+                    r#type: "text",
+                    value: "{condition.description}",
+                    placeholder: "Description shown in the library (optional)",
+                    oninput: {
+                        let condition = condition.clone();
+                        move |e: FormEvent| {
+                            let mut next = condition.clone();
+                            next.description = e.value();
+                            on_change.call(next);
+                        }
+                    },
+                    class: "{input_class}",
+                }
+                label { class: "flex items-center gap-1 text-gray-400 text-xs whitespace-nowrap",
+                    input {
+                        r#type: "checkbox",
+                        checked: condition.required,
+                        onchange: {
+                            let condition = condition.clone();
+                            move |e: FormEvent| {
+                                let mut next = condition.clone();
+                                next.required = e.checked();
+                                on_change.call(next);
+                            }
+                        },
+                    }
+                    "Required"
+                }
+            }
+            if let Some(problem) = problem {
+                span { class: "text-amber-500 text-xs", "⚠ This trigger {problem}" }
+            }
+        }
+    }
+}
+
+/// Dropdown of trigger targets; reports the picked ID and its label
+#[component]
+fn TargetPicker(
+    selected: String,
+    targets: Vec<TriggerTarget>,
+    placeholder: &'static str,
+    on_change: EventHandler<(String, String)>,
+) -> Element {
+    let lookup = targets.clone();
+    rsx! {
+        select {
+            value: "{selected}",
+            onchange: move |e| {
+                let id = e.value();
+                let label = lookup.iter().find(|t| t.id == id).map(|t| t.label.clone()).unwrap_or_default();
+                on_change.call((id, label));
+            },
+            class: "flex-1 min-w-0 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+            option { value: "", "{placeholder}" }
+            for target in targets.iter() {
+                option { value: "{target.id}", "{target.label}" }
+            }
+        }
+    }
+}
+
+/// Comma-separated keyword list
+#[component]
+fn KeywordsInput(keywords: Vec<String>, on_change: EventHandler<Vec<String>>) -> Element {
+    let joined = keywords.join(",");
+    rsx! {
+        input {
+            r#type: "text",
+            value: "{joined}",
+            placeholder: "Keywords, comma separated",
+            oninput: move |e| {
+                let value = e.value();
+                let keywords = if value.is_empty() {
+                    Vec::new()
+                } else {
+                    value.split(',').map(str::to_string).collect()
+                };
+                on_change.call(keywords);
+            },
+            class: "flex-1 min-w-0 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+        }
+    }
+}
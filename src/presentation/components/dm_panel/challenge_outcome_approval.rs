@@ -34,6 +34,7 @@ pub fn ChallengeOutcomeApprovalCard(props: ChallengeOutcomeApprovalCardProps) ->
     let border_color = match outcome.outcome_type.as_str() {
         "critical_success" => "border-yellow-400",
         "success" => "border-green-500",
+        "partial" | "partial_success" => "border-orange-400",
         "failure" => "border-red-500",
         "critical_failure" => "border-red-700",
         _ => "border-amber-500",
@@ -43,6 +44,7 @@ pub fn ChallengeOutcomeApprovalCard(props: ChallengeOutcomeApprovalCardProps) ->
     let outcome_display = match outcome.outcome_type.as_str() {
         "critical_success" => ("CRITICAL SUCCESS", "text-yellow-400"),
         "success" => ("SUCCESS", "text-green-500"),
+        "partial" | "partial_success" => ("PARTIAL SUCCESS", "text-orange-400"),
         "failure" => ("FAILURE", "text-red-500"),
         "critical_failure" => ("CRITICAL FAILURE", "text-red-700"),
         _ => ("RESULT", "text-amber-500"),
@@ -90,6 +92,25 @@ pub fn ChallengeOutcomeApprovalCard(props: ChallengeOutcomeApprovalCardProps) ->
                         "{breakdown}"
                     }
                 }
+
+                if let Some(entry) = &outcome.manual_entry {
+                    div {
+                        class: "mt-2 flex items-center gap-2 text-xs",
+                        span {
+                            class: "px-1.5 py-0.5 bg-amber-500/20 text-amber-400 rounded font-semibold",
+                            title: "Rolled on physical dice and entered by the player",
+                            "✋ Manual"
+                        }
+                        span { class: "text-gray-400 font-mono", "{entry.summary()}" }
+                    }
+                    for flag in entry.flags.iter() {
+                        p {
+                            class: "text-amber-400 text-xs mt-1 m-0",
+                            role: "note",
+                            "⚠ {flag}"
+                        }
+                    }
+                }
             }
 
             // Outcome description (editable)
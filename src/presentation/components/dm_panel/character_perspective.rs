@@ -168,7 +168,7 @@ fn CharacterCard(props: CharacterCardProps) -> Element {
             button {
                 onclick: move |_| props.on_view_as.call(()),
                 class: "py-2 px-4 bg-blue-500 text-white border-0 rounded-lg cursor-pointer text-sm",
-                "View as"
+                "Play as"
             }
         }
     }
@@ -0,0 +1,241 @@
+//! Condition control - DM applies and removes conditions on PCs and NPCs
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{ActiveCondition, ConditionDuration, ConditionModifier};
+use crate::application::ports::outbound::NewCondition;
+use crate::application::services::{SessionCommandService, CONDITION_PRESETS};
+use crate::presentation::components::common::ConditionBadges;
+use crate::presentation::state::{use_game_state, use_session_state};
+
+/// Form for applying a condition, with every affected character listed below
+#[component]
+pub fn ConditionControl() -> Element {
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+    let mut target = use_signal(String::new);
+    let mut name = use_signal(String::new);
+    let mut duration_kind = use_signal(|| "rounds".to_string());
+    let mut amount = use_signal(|| "3".to_string());
+    let mut modifier = use_signal(|| "0".to_string());
+    let mut skill = use_signal(String::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // PCs first, then NPCs in the current scene
+    let mut targets: Vec<(String, String)> = session_state
+        .party
+        .members
+        .read()
+        .iter()
+        .map(|m| (m.pc_id.clone(), m.name.clone()))
+        .collect();
+    for character in game_state.scene_characters.read().iter() {
+        if !targets.iter().any(|(id, _)| id == &character.id) {
+            targets.push((character.id.clone(), character.name.clone()));
+        }
+    }
+    let name_of = {
+        let targets = targets.clone();
+        let world = game_state.world.read().clone();
+        move |id: &str| {
+            targets
+                .iter()
+                .find(|(t, _)| t == id)
+                .map(|(_, n)| n.clone())
+                .or_else(|| {
+                    world
+                        .as_ref()
+                        .and_then(|w| w.characters.iter().find(|c| c.id == id).map(|c| c.name.clone()))
+                })
+                .unwrap_or_else(|| id.to_string())
+        }
+    };
+    let mut affected: Vec<(String, String, Vec<ActiveCondition>)> = session_state
+        .conditions
+        .read()
+        .iter()
+        .map(|(id, conditions)| (id.clone(), name_of(id), conditions.clone()))
+        .collect();
+    affected.sort_by(|a, b| a.1.cmp(&b.1));
+
+    let has_client = session_state.has_client();
+    let engine_client = session_state.engine_client();
+
+    let apply = move |_| {
+        let character_id = target.read().clone();
+        if character_id.is_empty() {
+            error.set(Some("Pick a character".to_string()));
+            return;
+        }
+        let condition_name = name.read().trim().to_string();
+        if condition_name.is_empty() {
+            error.set(Some("Name the condition".to_string()));
+            return;
+        }
+        let duration = match duration_kind.read().as_str() {
+            "until_removed" => ConditionDuration::UntilRemoved,
+            kind => match amount.read().trim().parse::<u32>() {
+                Ok(n) if n > 0 && kind == "minutes" => ConditionDuration::Minutes { minutes: n },
+                Ok(n) if n > 0 => ConditionDuration::Rounds { rounds: n },
+                _ => {
+                    error.set(Some("Enter a positive duration".to_string()));
+                    return;
+                }
+            },
+        };
+        let value = match modifier.read().trim().parse::<i32>() {
+            Ok(v) => v,
+            Err(_) => {
+                error.set(Some("Modifier must be a whole number".to_string()));
+                return;
+            }
+        };
+        let skill_name = skill.read().trim().to_string();
+        let modifiers = if value == 0 {
+            Vec::new()
+        } else {
+            vec![ConditionModifier {
+                skill: (!skill_name.is_empty()).then_some(skill_name),
+                value,
+            }]
+        };
+        let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+            return;
+        };
+        let condition = NewCondition { name: condition_name, duration, modifiers };
+        match SessionCommandService::new(client).apply_condition(&character_id, condition) {
+            Ok(()) => {
+                error.set(None);
+                name.set(String::new());
+            }
+            Err(e) => error.set(Some(format!("Failed to apply condition: {}", e))),
+        }
+    };
+
+    let input_class = "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs";
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+
+            select {
+                value: "{target}",
+                onchange: move |e| target.set(e.value()),
+                aria_label: "Character",
+                class: input_class,
+                option { value: "", "Choose a character..." }
+                for (id, label) in targets.iter() {
+                    option { key: "{id}", value: "{id}", "{label}" }
+                }
+            }
+
+            div {
+                class: "flex flex-wrap gap-1",
+                for preset in CONDITION_PRESETS.iter() {
+                    button {
+                        key: "{preset.name}",
+                        onclick: move |_| {
+                            name.set(preset.name.to_string());
+                            modifier.set(preset.roll_modifier.to_string());
+                            skill.set(String::new());
+                        },
+                        title: "{preset.name}",
+                        class: "px-1.5 py-0.5 bg-dark-bg border border-gray-700 rounded text-xs text-gray-300 cursor-pointer hover:border-purple-500",
+                        "{preset.icon} {preset.name}"
+                    }
+                }
+            }
+
+            input {
+                r#type: "text",
+                placeholder: "Condition name",
+                aria_label: "Condition name",
+                value: "{name}",
+                oninput: move |e| name.set(e.value()),
+                class: input_class,
+            }
+
+            div {
+                class: "flex items-center gap-2",
+                select {
+                    value: "{duration_kind}",
+                    onchange: move |e| duration_kind.set(e.value()),
+                    aria_label: "Duration",
+                    class: input_class,
+                    option { value: "rounds", "Rounds" }
+                    option { value: "minutes", "Minutes" }
+                    option { value: "until_removed", "Until removed" }
+                }
+                if duration_kind.read().as_str() != "until_removed" {
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        aria_label: "Duration amount",
+                        value: "{amount}",
+                        oninput: move |e| amount.set(e.value()),
+                        class: "w-16 {input_class}",
+                    }
+                }
+            }
+
+            div {
+                class: "flex items-center gap-2",
+                input {
+                    r#type: "number",
+                    aria_label: "Roll modifier",
+                    title: "Roll modifier",
+                    value: "{modifier}",
+                    oninput: move |e| modifier.set(e.value()),
+                    class: "w-16 {input_class}",
+                }
+                input {
+                    r#type: "text",
+                    placeholder: "Skill (blank for all rolls)",
+                    aria_label: "Skill the modifier applies to",
+                    value: "{skill}",
+                    oninput: move |e| skill.set(e.value()),
+                    class: "flex-1 {input_class}",
+                }
+                button {
+                    disabled: !has_client,
+                    onclick: apply,
+                    class: "px-3 py-1 bg-purple-600 text-white border-0 rounded cursor-pointer text-xs disabled:opacity-50",
+                    "Apply"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-500 text-xs", "{err}" }
+            }
+
+            if !affected.is_empty() {
+                div {
+                    class: "flex flex-col gap-2 mt-2 pt-2 border-t border-gray-700",
+                    for (character_id, character_name, conditions) in affected.into_iter() {
+                        div {
+                            key: "{character_id}",
+                            class: "flex flex-col gap-1",
+                            span { class: "text-gray-400 text-xs", "{character_name}" }
+                            ConditionBadges {
+                                conditions,
+                                labelled: true,
+                                on_remove: Some(EventHandler::new({
+                                    let engine_client = session_state.engine_client();
+                                    let character_id = character_id.clone();
+                                    move |condition_id: String| {
+                                        let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+                                            return;
+                                        };
+                                        if let Err(e) = SessionCommandService::new(client).remove_condition(&character_id, &condition_id) {
+                                            error.set(Some(format!("Failed to remove condition: {}", e)));
+                                        }
+                                    }
+                                })),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
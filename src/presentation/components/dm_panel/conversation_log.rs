@@ -1,217 +1,316 @@
 //! Conversation log component
 //!
-//! Displays a scrollable log of dialogue turns with speakers and timestamps.
+//! The DM's running log of dialogue, challenges and system messages, with
+//! search, speaker and kind filters and a jump to a point in the session.
+//! Only the rows around the viewport are rendered, so long sessions stay
+//! responsive.
 
+use dioxus::html::geometry::PixelsVector2D;
+use dioxus::html::ScrollBehavior;
 use dioxus::prelude::*;
 
-/// Challenge result information for special rendering
-#[derive(Clone, PartialEq)]
-pub struct ChallengeResultInfo {
-    /// Name of the challenge
-    pub challenge_name: String,
-    /// Character who performed the challenge
-    pub character_name: String,
-    /// The roll value
-    pub roll: i32,
-    /// The modifier applied
-    pub modifier: i32,
-    /// Total (roll + modifier)
-    pub total: i32,
-    /// Outcome type (success, failure, critical_success, critical_failure)
-    pub outcome_type: String,
-    /// Description of the outcome
-    pub outcome_description: String,
-}
+use crate::presentation::state::{
+    format_elapsed, log_speakers, parse_elapsed, row_at_elapsed, use_session_state, visible_range,
+    ConversationLogEntry, LogEntryKind, LogFilter, LOG_ROW_HEIGHT_PX,
+};
 
-/// A single turn in the conversation
-#[derive(Clone, PartialEq)]
-pub struct ConversationTurn {
-    /// Who is speaking (character name or "System")
-    pub speaker: String,
-    /// The spoken text
-    pub text: String,
-    /// Timestamp of the turn
-    pub timestamp: String,
-    /// Whether this is a system message
-    pub is_system: bool,
-    /// Optional challenge result data for special rendering
-    pub challenge_result: Option<ChallengeResultInfo>,
-}
+/// Viewport height assumed until the first scroll event reports it
+const DEFAULT_VIEWPORT_PX: f64 = 480.0;
 
-/// Props for the ConversationLog component
-#[derive(Props, Clone, PartialEq)]
-pub struct ConversationLogProps {
-    /// History of conversation turns
-    pub turns: Vec<ConversationTurn>,
-    /// Optional CSS class for styling
-    #[props(default = "")]
-    pub class: &'static str,
-}
+/// Entries longer than this may be clamped and offer to expand
+const CLAMP_HINT_CHARS: usize = 80;
 
-/// ConversationLog component - Scrollable dialogue history
+/// ConversationLog component - Searchable, filterable session log
 ///
-/// Shows a log of all dialogue exchanges with speaker names and timestamps.
-/// Useful for reviewing what has been said during the session.
+/// Follows new entries while scrolled to the bottom; scrolling up stops
+/// following until the DM returns to the end. With `on_create_challenge`,
+/// dialogue rows offer to turn the line into a challenge.
 #[component]
-pub fn ConversationLog(props: ConversationLogProps) -> Element {
-    // Auto-scroll to bottom when new turns are added (CSS scroll-behavior handles this)
-    use_effect({
-        let _turn_count = props.turns.len();
-        move || {
-            // Future: trigger scroll to bottom when new turns are added
+pub fn ConversationLog(#[props(default)] on_create_challenge: Option<EventHandler<ConversationLogEntry>>) -> Element {
+    let session_state = use_session_state();
+    let conversation_log = session_state.conversation_log();
+    let mut filter = use_signal(LogFilter::default);
+    let mut jump_input = use_signal(String::new);
+    let mut jump_error = use_signal(|| false);
+    let mut scroll_top = use_signal(|| 0.0f64);
+    let mut viewport_height = use_signal(|| DEFAULT_VIEWPORT_PX);
+    let mut follow_tail = use_signal(|| true);
+    let mut container: Signal<Option<std::rc::Rc<MountedData>>> = use_signal(|| None);
+    // Log index of the entry shown in full below the list
+    let mut expanded: Signal<Option<usize>> = use_signal(|| None);
+
+    let log = conversation_log.read();
+    let current_filter = filter.read().clone();
+    let rows = current_filter.apply(&log);
+    let speakers = log_speakers(&log);
+    let session_start = log.first().map(|e| e.timestamp).unwrap_or(0);
+    let total_height = rows.len() as f64 * LOG_ROW_HEIGHT_PX;
+    let window = visible_range(rows.len(), *scroll_top.read(), *viewport_height.read());
+    let top_spacer = window.start as f64 * LOG_ROW_HEIGHT_PX;
+    let bottom_spacer = total_height - window.end as f64 * LOG_ROW_HEIGHT_PX;
+    let visible: Vec<(usize, ConversationLogEntry)> = rows[window.clone()].iter().map(|&i| (i, log[i].clone())).collect();
+    let row_count = rows.len();
+    let expanded_entry = expanded.read().and_then(|i| log.get(i).cloned());
+
+    // Keep the newest entry in view while following
+    use_effect(move || {
+        let count = conversation_log.read().len();
+        if count > 0 && *follow_tail.peek() {
+            if let Some(el) = container.peek().clone() {
+                spawn(async move {
+                    let _ = el.scroll(PixelsVector2D::new(0.0, 1e9), ScrollBehavior::Instant).await;
+                });
+            }
         }
     });
 
+    let scroll_to_row = move |row: usize| {
+        if let Some(el) = container.peek().clone() {
+            spawn(async move {
+                let y = row as f64 * LOG_ROW_HEIGHT_PX;
+                let _ = el.scroll(PixelsVector2D::new(0.0, y), ScrollBehavior::Smooth).await;
+            });
+        }
+    };
+
+    let mut jump = {
+        let rows = rows.clone();
+        move || {
+            let Some(elapsed) = parse_elapsed(&jump_input.read()) else {
+                jump_error.set(true);
+                return;
+            };
+            jump_error.set(false);
+            if let Some(row) = row_at_elapsed(&conversation_log.peek(), &rows, elapsed) {
+                follow_tail.set(false);
+                scroll_to_row(row);
+            }
+        }
+    };
+
+    let speaker_value = current_filter.speaker.clone().unwrap_or_default();
+    let jump_class = if *jump_error.read() {
+        "w-20 p-1.5 bg-dark-bg border border-red-500 rounded text-white text-xs"
+    } else {
+        "w-20 p-1.5 bg-dark-bg border border-gray-700 rounded text-white text-xs"
+    };
+
     rsx! {
         div {
-            class: "conversation-log {props.class} flex flex-col h-full bg-dark-surface rounded-lg overflow-hidden",
+            class: "conversation-log flex-1 min-h-0 bg-dark-surface rounded-lg flex flex-col overflow-hidden",
 
-            // Header
+            // Header and filters
             div {
-                class: "py-3 px-4 border-b border-gray-700",
+                class: "p-4 pb-3 border-b border-gray-700 flex flex-col gap-2",
 
-                h3 {
-                    class: "text-gray-400 text-sm uppercase m-0",
-                    "Conversation Log"
+                div {
+                    class: "flex items-center justify-between",
+                    h3 { class: "text-gray-400 text-sm uppercase m-0", "Conversation Log" }
+                    span {
+                        class: "text-gray-500 text-xs",
+                        if current_filter.is_active() {
+                            "{row_count} of {log.len()}"
+                        } else {
+                            "{log.len()} entries"
+                        }
+                    }
                 }
-            }
-
-            // Log entries (scrollable)
-            div {
-                class: "log-entries flex-1 overflow-y-auto p-4 flex flex-col gap-3",
 
-                // Empty state
-                if props.turns.is_empty() {
-                    div {
-                        class: "flex items-center justify-center h-full text-gray-500 text-sm",
-                        "Waiting for dialogue..."
+                div {
+                    class: "flex flex-wrap gap-2 items-center",
+                    input {
+                        r#type: "search",
+                        value: "{current_filter.query}",
+                        placeholder: "Search the log...",
+                        oninput: move |e| filter.write().query = e.value(),
+                        class: "flex-1 min-w-[140px] p-1.5 bg-dark-bg border border-gray-700 rounded text-white text-xs",
                     }
-                } else {
-                    for turn in props.turns.iter() {
-                        ConversationEntry {
-                            turn: turn.clone(),
+                    select {
+                        value: "{speaker_value}",
+                        onchange: move |e| {
+                            let value = e.value();
+                            filter.write().speaker = if value.is_empty() { None } else { Some(value) };
+                        },
+                        class: "p-1.5 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                        option { value: "", "All speakers" }
+                        for speaker in speakers.iter() {
+                            option { key: "{speaker}", value: "{speaker}", "{speaker}" }
                         }
                     }
                 }
-            }
-        }
-    }
-}
 
-/// Individual conversation entry
-#[component]
-fn ConversationEntry(turn: ConversationTurn) -> Element {
-    // Check if this is a challenge result
-    if let Some(ref result) = turn.challenge_result {
-        return rsx! { ChallengeResultEntry { result: result.clone(), timestamp: turn.timestamp.clone() } };
-    }
-
-    let (bg_class, text_class, border_class, speaker_class) = if turn.is_system {
-        ("bg-blue-500 bg-opacity-10", "text-blue-400", "border-blue-400", "text-blue-400")
-    } else {
-        ("bg-black bg-opacity-30", "text-white", "border-blue-500", "text-blue-500")
-    };
+                div {
+                    class: "flex flex-wrap gap-2 items-center",
+                    for kind in LogEntryKind::ALL {
+                        button {
+                            key: "{kind.label()}",
+                            onclick: move |_| filter.write().toggle_kind(kind),
+                            class: if current_filter.hidden_kinds.contains(&kind) {
+                                "px-2 py-0.5 bg-transparent text-gray-500 border border-gray-700 rounded-full text-xs cursor-pointer line-through"
+                            } else {
+                                "px-2 py-0.5 bg-blue-500/20 text-blue-300 border border-blue-500/40 rounded-full text-xs cursor-pointer"
+                            },
+                            "{kind.label()}"
+                        }
+                    }
+                    if current_filter.is_active() {
+                        button {
+                            onclick: move |_| filter.set(LogFilter::default()),
+                            class: "px-2 py-0.5 bg-transparent text-gray-400 border-none text-xs cursor-pointer underline",
+                            "Clear filters"
+                        }
+                    }
 
-    rsx! {
-        div {
-            class: "log-entry p-3 rounded-md border-l-2 {bg_class} {border_class}",
+                    // Jump to a point in the session
+                    div {
+                        class: "flex items-center gap-1 ml-auto",
+                        input {
+                            r#type: "text",
+                            value: "{jump_input}",
+                            placeholder: "h:mm:ss",
+                            title: "Time since the first log entry",
+                            oninput: move |e| jump_input.set(e.value()),
+                            onkeydown: {
+                                let mut jump = jump.clone();
+                                move |e: KeyboardEvent| {
+                                    if e.key() == Key::Enter {
+                                        jump();
+                                    }
+                                }
+                            },
+                            class: jump_class,
+                        }
+                        button {
+                            onclick: move |_| jump(),
+                            class: "px-2 py-1 bg-gray-700 text-white border-none rounded text-xs cursor-pointer",
+                            "Jump"
+                        }
+                    }
+                }
+            }
 
-            // Header with speaker and timestamp
+            // Log entries (windowed)
             div {
-                class: "flex items-center gap-2 mb-1",
+                class: "log-entries flex-1 overflow-y-auto px-4",
+                onmounted: move |e| container.set(Some(e.data())),
+                onscroll: move |e| {
+                    let top = e.scroll_top();
+                    let height = e.client_height() as f64;
+                    scroll_top.set(top);
+                    viewport_height.set(height);
+                    let at_bottom = e.scroll_height() as f64 - top - height <= LOG_ROW_HEIGHT_PX;
+                    if *follow_tail.peek() != at_bottom {
+                        follow_tail.set(at_bottom);
+                    }
+                },
 
-                span {
-                    class: "font-semibold text-sm {speaker_class}",
-                    "{turn.speaker}"
+                if log.is_empty() {
+                    div { class: "text-gray-500 italic text-center p-8",
+                        "Waiting for session activity..."
+                    }
+                } else if row_count == 0 {
+                    div { class: "text-gray-500 italic text-center p-8",
+                        "No entries match these filters"
+                    }
+                } else {
+                    div { style: "height: {top_spacer}px" }
+                    for (index, entry) in visible {
+                        LogRow {
+                            key: "{index}",
+                            elapsed: format_elapsed(entry.timestamp.saturating_sub(session_start)),
+                            entry,
+                            on_create_challenge,
+                            on_expand: move |_| expanded.set(Some(index)),
+                        }
+                    }
+                    div { style: "height: {bottom_spacer}px" }
                 }
+            }
 
-                span {
-                    class: "text-gray-500 text-xs ml-auto",
-                    "{turn.timestamp}"
+            // Full text of an expanded row
+            if let Some(entry) = expanded_entry {
+                div {
+                    class: "mx-4 mb-2 p-3 bg-dark-bg border border-gray-700 rounded-lg max-h-48 overflow-y-auto",
+                    div {
+                        class: "flex items-center gap-2 mb-1",
+                        span { class: "font-semibold text-xs text-gray-300", "{entry.speaker}" }
+                        button {
+                            aria_label: "Close",
+                            onclick: move |_| expanded.set(None),
+                            class: "ml-auto px-1.5 bg-transparent text-gray-400 border-none text-xs cursor-pointer",
+                            "✕"
+                        }
+                    }
+                    p { class: "text-sm leading-snug m-0 text-white whitespace-pre-wrap", "{entry.text}" }
                 }
             }
 
-            // Message text
-            p {
-                class: "text-sm leading-snug m-0 {text_class}",
-                "{turn.text}"
+            if !*follow_tail.read() && row_count > 0 {
+                button {
+                    onclick: move |_| {
+                        follow_tail.set(true);
+                        scroll_to_row(row_count.saturating_sub(1));
+                    },
+                    class: "m-2 py-1 bg-blue-500/20 text-blue-300 border border-blue-500/40 rounded text-xs cursor-pointer",
+                    "↓ Latest"
+                }
             }
         }
     }
 }
 
-/// Challenge result entry with special amber styling (P3.3/P3.4)
+/// One fixed-height log row; long text is clamped, shown in full on hover,
+/// and can be expanded below the list
 #[component]
-fn ChallengeResultEntry(result: ChallengeResultInfo, timestamp: String) -> Element {
-    // Determine colors based on outcome type
-    let (border_color, outcome_color, outcome_label) = match result.outcome_type.as_str() {
-        "critical_success" => ("border-yellow-400", "text-yellow-400", "CRITICAL SUCCESS"),
-        "success" => ("border-green-500", "text-green-500", "SUCCESS"),
-        "failure" => ("border-red-500", "text-red-500", "FAILURE"),
-        "critical_failure" => ("border-red-700", "text-red-700", "CRITICAL FAILURE"),
-        _ => ("border-amber-500", "text-amber-500", "RESULT"),
-    };
-
-    // Format modifier with sign
-    let modifier_display = if result.modifier >= 0 {
-        format!("+{}", result.modifier)
-    } else {
-        format!("{}", result.modifier)
+fn LogRow(
+    entry: ConversationLogEntry,
+    elapsed: String,
+    on_create_challenge: Option<EventHandler<ConversationLogEntry>>,
+    on_expand: EventHandler<()>,
+) -> Element {
+    let (row_class, speaker_class) = match entry.kind {
+        LogEntryKind::Dialogue => ("border-blue-500", "text-blue-400"),
+        LogEntryKind::Challenge => ("border-amber-500 bg-amber-500/5", "text-amber-500"),
+        LogEntryKind::System => ("border-gray-600", "text-gray-500"),
     };
+    let text_class = if entry.kind == LogEntryKind::Dialogue { "text-white" } else { "text-gray-300" };
+    let create_challenge = on_create_challenge.filter(|_| entry.kind == LogEntryKind::Dialogue);
+    let is_long = entry.text.chars().count() > CLAMP_HINT_CHARS;
 
     rsx! {
         div {
-            class: "log-entry p-3 rounded-md border-l-2 bg-amber-500 bg-opacity-10 {border_color}",
-
-            // Header with challenge name and timestamp
+            class: "log-entry h-16 box-border py-1.5 pl-2 border-l-2 overflow-hidden {row_class}",
+            title: "{entry.text}",
             div {
-                class: "flex items-center justify-between mb-2",
-
-                div {
-                    span {
-                        class: "font-semibold text-sm text-amber-500",
-                        "{result.challenge_name}"
-                    }
-                    span {
-                        class: "text-gray-400 text-xs ml-2",
-                        "by {result.character_name}"
+                class: "flex items-center gap-2",
+                span { class: "font-semibold text-xs {speaker_class}", "{entry.speaker}" }
+                span { class: "text-gray-600 text-[0.65rem] ml-auto tabular-nums", "{elapsed}" }
+                if is_long {
+                    button {
+                        title: "Show the full text",
+                        aria_label: "Show the full text",
+                        onclick: move |_| on_expand.call(()),
+                        class: "px-1.5 bg-transparent text-gray-400 border border-gray-700 rounded text-[0.65rem] cursor-pointer",
+                        "⤢"
                     }
                 }
-
-                span {
-                    class: "text-gray-500 text-xs",
-                    "{timestamp}"
-                }
-            }
-
-            // Roll breakdown
-            div {
-                class: "flex items-center gap-4 bg-black/30 rounded px-3 py-2 mb-2",
-
-                span {
-                    class: "text-gray-400 text-sm",
-                    "Roll: {result.roll}"
-                }
-                span {
-                    class: "text-gray-400 text-sm",
-                    "Mod: {modifier_display}"
-                }
-                span {
-                    class: "text-white text-sm font-bold",
-                    "Total: {result.total}"
-                }
-                span {
-                    class: "text-xs font-bold uppercase ml-auto {outcome_color}",
-                    "{outcome_label}"
+                if let Some(handler) = create_challenge {
+                    button {
+                        title: "Create challenge from this",
+                        aria_label: "Create challenge from this",
+                        onclick: {
+                            let entry = entry.clone();
+                            move |_| handler.call(entry.clone())
+                        },
+                        class: "px-1.5 bg-transparent text-amber-500 border border-amber-500/40 rounded text-[0.65rem] cursor-pointer",
+                        "⚔ Challenge"
+                    }
                 }
             }
-
-            // Outcome description
             p {
-                class: "text-sm leading-snug m-0 text-gray-300 italic",
-                "{result.outcome_description}"
+                class: "text-sm leading-snug m-0 line-clamp-2 {text_class}",
+                "{entry.text}"
             }
         }
     }
 }
-
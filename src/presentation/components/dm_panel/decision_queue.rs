@@ -3,6 +3,7 @@
 use dioxus::prelude::*;
 
 use crate::application::dto::websocket_messages::ChallengeOutcomeDecisionData;
+use crate::presentation::components::dm_panel::approval_history::ApprovalHistoryModal;
 use crate::presentation::components::dm_panel::challenge_outcome_approval::ChallengeOutcomesSection;
 use crate::presentation::state::use_session_state;
 
@@ -16,6 +17,7 @@ pub fn DecisionQueuePanel() -> Element {
     let history = session_state.get_approval_history();
 
     let mut show_history_only: Signal<bool> = use_signal(|| false);
+    let mut show_full_history = use_signal(|| false);
 
     let has_pending = !pending.is_empty();
     let has_pending_outcomes = !pending_outcomes.is_empty();
@@ -35,21 +37,33 @@ pub fn DecisionQueuePanel() -> Element {
                 }
 
                 if has_history {
-                    label {
-                        class: "inline-flex items-center gap-1 text-gray-400 text-xs",
-                        input {
-                            r#type: "checkbox",
-                            checked: *show_history_only.read(),
-                            onchange: move |_| {
-                                let current = *show_history_only.read();
-                                show_history_only.set(!current);
-                            },
+                    div {
+                        class: "flex items-center gap-2",
+                        label {
+                            class: "inline-flex items-center gap-1 text-gray-400 text-xs",
+                            input {
+                                r#type: "checkbox",
+                                checked: *show_history_only.read(),
+                                onchange: move |_| {
+                                    let current = *show_history_only.read();
+                                    show_history_only.set(!current);
+                                },
+                            }
+                            span { "Show history only" }
+                        }
+                        button {
+                            class: "bg-transparent border-0 p-0 text-blue-300 text-xs cursor-pointer hover:underline",
+                            onclick: move |_| show_full_history.set(true),
+                            "Full history"
                         }
-                        span { "Show history only" }
                     }
                 }
             }
 
+            if *show_full_history.read() {
+                ApprovalHistoryModal { on_close: move |_| show_full_history.set(false) }
+            }
+
             // Content
             if !has_pending && !has_pending_outcomes && !has_history {
                 div {
@@ -0,0 +1,89 @@
+//! Decision timer control - time limits on player decisions
+//!
+//! When on, players see a countdown on dialogue choices and challenge rolls.
+//! On expiry the Director is notified in the log, or the player's client
+//! takes the first choice / rolls the suggested dice.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::{DecisionTimerSettings, TimerExpiry};
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::use_session_state;
+
+/// Time limits offered, in seconds
+const TIMER_OPTIONS: [u32; 6] = [10, 15, 30, 45, 60, 120];
+
+/// Time limit and expiry behaviour for the Director panel
+#[component]
+pub fn DecisionTimerControl() -> Element {
+    let session_state = use_session_state();
+
+    let mut decision_timer = session_state.decision_timer;
+    let timer = *decision_timer.read();
+    let has_client = session_state.has_client();
+
+    let engine_client = session_state.engine_client();
+    let set_timer = move |next: DecisionTimerSettings| {
+        let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+            return;
+        };
+        match SessionCommandService::new(client).set_decision_timer(next) {
+            Ok(()) => decision_timer.set(next),
+            Err(e) => tracing::error!("Failed to set decision timer: {}", e),
+        }
+    };
+
+    let seconds_value = timer.limit().map(|s| s.to_string()).unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 text-sm",
+
+            select {
+                value: "{seconds_value}",
+                disabled: !has_client,
+                onchange: move |e: FormEvent| {
+                    let mut set_timer = set_timer;
+                    let seconds = e.value().parse::<u32>().ok();
+                    set_timer(DecisionTimerSettings { seconds, ..timer });
+                },
+                class: "p-2 bg-dark-bg border border-gray-700 rounded-md text-white text-sm cursor-pointer disabled:opacity-50",
+
+                option { value: "", selected: timer.limit().is_none(), "Off" }
+                for secs in TIMER_OPTIONS {
+                    option {
+                        value: "{secs}",
+                        selected: timer.limit() == Some(secs),
+                        "{secs} seconds"
+                    }
+                }
+            }
+
+            if timer.limit().is_some() {
+                label {
+                    class: "flex items-center gap-2 text-gray-400 text-xs",
+                    "When time runs out"
+                    select {
+                        value: "{timer.on_expiry.display_name()}",
+                        disabled: !has_client,
+                        onchange: move |e: FormEvent| {
+                            let mut set_timer = set_timer;
+                            if let Some(on_expiry) = TimerExpiry::all().into_iter().find(|x| x.display_name() == e.value()) {
+                                set_timer(DecisionTimerSettings { on_expiry, ..timer });
+                            }
+                        },
+                        class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded-md text-white text-xs cursor-pointer disabled:opacity-50",
+
+                        for expiry in TimerExpiry::all() {
+                            option {
+                                value: "{expiry.display_name()}",
+                                selected: expiry == timer.on_expiry,
+                                "{expiry.display_name()}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
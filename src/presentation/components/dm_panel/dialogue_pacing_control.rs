@@ -0,0 +1,118 @@
+//! Dialogue pacing control - keep the table reading the same line
+//!
+//! In paced modes a line's choices are held back until the DM releases it
+//! (or, in "wait for all players" mode, until everyone has read it). The
+//! Director sees who has finished reading and can release the line early.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::{DialoguePacing, ParticipantRole};
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::{use_dialogue_state, use_session_state};
+
+/// Pacing mode and per-player read status for the Director panel
+#[component]
+pub fn DialoguePacingControl() -> Element {
+    let session_state = use_session_state();
+    let mut dialogue_state = use_dialogue_state();
+
+    let pacing = *dialogue_state.pacing.read();
+    let held = *dialogue_state.held.read();
+    let finished = dialogue_state.finished_readers.read().clone();
+    let has_client = session_state.has_client();
+
+    // Players at the table, labelled by character name when they have one
+    let roles = session_state.participant_roles().read().clone();
+    let participants = session_state.presence.participants.read().clone();
+    let mut readers: Vec<(String, String, bool)> = roles
+        .into_iter()
+        .filter(|(_, role)| *role == ParticipantRole::Player)
+        .map(|(user_id, _)| {
+            let name = participants
+                .iter()
+                .find(|p| p.user_id == user_id)
+                .map(|p| p.display_name().to_string())
+                .unwrap_or_else(|| user_id.clone());
+            let done = finished.contains(&user_id);
+            (user_id, name, done)
+        })
+        .collect();
+    readers.sort_by(|a, b| a.1.cmp(&b.1));
+    let done_count = readers.iter().filter(|(_, _, done)| *done).count();
+    let reader_count = readers.len();
+
+    let engine_client = session_state.engine_client();
+    let set_pacing = move |next: DialoguePacing| {
+        let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+            return;
+        };
+        match SessionCommandService::new(client).set_dialogue_pacing(next) {
+            Ok(()) => dialogue_state.set_pacing(next),
+            Err(e) => tracing::error!("Failed to set dialogue pacing: {}", e),
+        }
+    };
+    let advance = move |_| {
+        let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+            return;
+        };
+        if let Err(e) = SessionCommandService::new(client).advance_dialogue() {
+            tracing::error!("Failed to advance dialogue: {}", e);
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 text-sm",
+
+            select {
+                value: "{pacing.display_name()}",
+                disabled: !has_client,
+                onchange: {
+                    let mut set_pacing = set_pacing;
+                    move |e: FormEvent| {
+                        if let Some(next) = DialoguePacing::all().into_iter().find(|p| p.display_name() == e.value()) {
+                            set_pacing(next);
+                        }
+                    }
+                },
+                class: "p-2 bg-dark-bg border border-gray-700 rounded-md text-white text-sm cursor-pointer disabled:opacity-50",
+
+                for option_pacing in DialoguePacing::all() {
+                    option {
+                        value: "{option_pacing.display_name()}",
+                        selected: option_pacing == pacing,
+                        "{option_pacing.display_name()}"
+                    }
+                }
+            }
+
+            if pacing.is_paced() {
+                if held {
+                    div { class: "text-gray-400 text-xs", "{done_count}/{reader_count} finished reading" }
+
+                    for (user_id, name, done) in readers.into_iter() {
+                        div {
+                            key: "{user_id}",
+                            class: "flex items-center gap-2 px-2 py-1 bg-dark-bg rounded",
+                            span { class: "text-white flex-1 truncate", "{name}" }
+                            if done {
+                                span { class: "text-green-400 text-xs", "✓ Read" }
+                            } else {
+                                span { class: "text-gray-500 text-xs", "Reading…" }
+                            }
+                        }
+                    }
+
+                    button {
+                        class: "p-2 bg-blue-600 text-white border-none rounded-lg cursor-pointer text-sm disabled:opacity-50",
+                        disabled: !has_client,
+                        onclick: advance,
+                        "Advance"
+                    }
+                } else {
+                    div { class: "text-gray-500 text-xs italic", "No line is being held" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Dice stats - How the session's dice have been rolling
+//!
+//! Face distributions per player or per die type, built from the rolls
+//! resolved this session (see `application::services::dice_stats`). A
+//! group whose average is unlikely for fair dice gets a badge; the whole
+//! log can be saved as CSV.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{DiceGroupStats, DiceSkew, SessionDiceLog};
+use crate::presentation::state::use_session_state;
+
+/// Session dice distributions with a CSV export
+#[component]
+pub fn DiceStatsPanel() -> Element {
+    let session_state = use_session_state();
+    let platform = use_context::<Platform>();
+    let mut by_player = use_signal(|| true);
+    let mut status: Signal<Option<Result<String, String>>> = use_signal(|| None);
+
+    let log = session_state.session_dice().read().clone();
+    if log.is_empty() {
+        return rsx! {
+            p { class: "text-gray-500 text-sm m-0", "No rolls yet this session" }
+        };
+    }
+    let groups = if *by_player.read() { log.per_player() } else { log.per_die() };
+    let session_id = session_state.session_id().read().clone();
+
+    let export = {
+        let log = log.clone();
+        move |_| {
+            let filename = SessionDiceLog::csv_filename(session_id.as_deref());
+            status.set(Some(match platform.save_file(&filename, &log.to_csv()) {
+                Ok(location) => Ok(format!("Saved rolls to {}", location)),
+                Err(e) => Err(format!("Failed to save rolls: {}", e)),
+            }));
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 text-sm",
+
+            div {
+                class: "flex items-center gap-2",
+                div {
+                    role: "group",
+                    aria_label: "Group rolls by",
+                    class: "flex gap-1 p-0.5 bg-dark-bg rounded",
+                    button {
+                        r#type: "button",
+                        aria_pressed: *by_player.read(),
+                        onclick: move |_| by_player.set(true),
+                        class: toggle_class(*by_player.read()),
+                        "Per player"
+                    }
+                    button {
+                        r#type: "button",
+                        aria_pressed: !*by_player.read(),
+                        onclick: move |_| by_player.set(false),
+                        class: toggle_class(!*by_player.read()),
+                        "Per die"
+                    }
+                }
+                span { class: "text-gray-500 text-xs flex-1", "{log.rolls().len()} roll(s)" }
+                button {
+                    r#type: "button",
+                    onclick: export,
+                    class: "px-2 py-0.5 bg-transparent text-blue-400 border border-blue-500/40 rounded cursor-pointer text-xs",
+                    "Export CSV"
+                }
+            }
+
+            match status.read().as_ref() {
+                Some(Ok(msg)) => rsx! { p { class: "text-green-500 text-xs m-0", role: "status", "{msg}" } },
+                Some(Err(msg)) => rsx! { p { class: "text-red-500 text-xs m-0", role: "alert", "{msg}" } },
+                None => rsx! {},
+            }
+
+            if groups.is_empty() {
+                p { class: "text-gray-500 text-xs m-0", "No rolls with known dice yet" }
+            }
+
+            for group in groups.into_iter() {
+                DiceGroupCard { key: "{group.label}-{group.sides}", group }
+            }
+        }
+    }
+}
+
+fn toggle_class(active: bool) -> &'static str {
+    if active {
+        "px-2 py-0.5 bg-gray-700 text-white border-none rounded text-xs cursor-pointer"
+    } else {
+        "px-2 py-0.5 bg-transparent text-gray-400 border-none rounded text-xs cursor-pointer"
+    }
+}
+
+/// One group's face histogram and average
+#[component]
+fn DiceGroupCard(group: DiceGroupStats) -> Element {
+    let tallest = group.face_counts.iter().copied().max().unwrap_or(0).max(1);
+    let average = format!("{:.1}", group.average());
+    let expected = format!("{:.1}", group.expected_average());
+    let title = if group.label == format!("d{}", group.sides) {
+        group.label.clone()
+    } else {
+        format!("{} · d{}", group.label, group.sides)
+    };
+
+    rsx! {
+        div {
+            class: "p-2 bg-dark-bg rounded flex flex-col gap-1",
+
+            div {
+                class: "flex items-center gap-2",
+                span { class: "text-white flex-1 truncate", "{title}" }
+                span { class: "text-gray-400 text-xs", "avg {average} (fair {expected}) · {group.dice()} dice" }
+                match group.skew() {
+                    Some(DiceSkew::High) => rsx! {
+                        span {
+                            class: "px-1.5 py-0.5 bg-amber-500/20 text-amber-400 rounded text-xs",
+                            title: "The average is unusually high for fair dice",
+                            "⚠ Running hot"
+                        }
+                    },
+                    Some(DiceSkew::Low) => rsx! {
+                        span {
+                            class: "px-1.5 py-0.5 bg-sky-500/20 text-sky-400 rounded text-xs",
+                            title: "The average is unusually low for fair dice",
+                            "⚠ Running cold"
+                        }
+                    },
+                    None => rsx! {},
+                }
+            }
+
+            div {
+                role: "img",
+                aria_label: "Faces rolled: {face_summary(&group)}",
+                class: "flex items-end gap-px h-12",
+                for (i, count) in group.face_counts.iter().copied().enumerate() {
+                    div {
+                        key: "{i}",
+                        title: "{i + 1}: {count}",
+                        class: "flex-1 bg-blue-500/70 rounded-t-sm min-h-px",
+                        style: "height: {count * 100 / tallest}%;",
+                    }
+                }
+            }
+            div {
+                class: "flex justify-between text-gray-500 text-xs",
+                span { "1" }
+                span { "{group.sides}" }
+            }
+        }
+    }
+}
+
+/// e.g. "1×2, 2×0, 3×5", for screen readers
+fn face_summary(group: &DiceGroupStats) -> String {
+    group
+        .face_counts
+        .iter()
+        .enumerate()
+        .map(|(i, count)| format!("{}×{}", i + 1, count))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -5,8 +5,12 @@
 
 use dioxus::prelude::*;
 
+use crate::application::dto::GenerationParams;
 use crate::application::services::{Asset, GenerateRequest};
+use crate::presentation::components::creator::generation_params_input::GenerationParamsInput;
 use crate::presentation::services::use_asset_service;
+use crate::presentation::state::use_modal_focus;
+use crate::presentation::components::common::ModalFocus;
 
 /// Props for DirectorGenerateModal
 #[derive(Props, Clone, PartialEq)]
@@ -30,6 +34,7 @@ pub struct DirectorGenerateModalProps {
 /// Director Mode generation modal with pre-populated prompt
 #[component]
 pub fn DirectorGenerateModal(props: DirectorGenerateModalProps) -> Element {
+    use_modal_focus("director-generate-modal");
     let asset_service = use_asset_service();
     let mut prompt = use_signal(|| props.initial_prompt.clone());
     let mut negative_prompt = use_signal(|| String::new());
@@ -40,6 +45,7 @@ pub fn DirectorGenerateModal(props: DirectorGenerateModalProps) -> Element {
     let mut style_reference_label: Signal<Option<String>> = use_signal(|| None);
     let mut show_style_selector = use_signal(|| false);
     let mut available_assets: Signal<Vec<Asset>> = use_signal(Vec::new);
+    let params: Signal<GenerationParams> = use_signal(GenerationParams::default);
 
     // Load available assets for style reference selection
     let entity_type_for_assets = props.entity_type.clone();
@@ -64,6 +70,9 @@ pub fn DirectorGenerateModal(props: DirectorGenerateModalProps) -> Element {
             onclick: move |_| props.on_close.call(()),
 
             div {
+                id: "director-generate-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "modal-content bg-dark-surface rounded-xl p-6 w-[90%] max-w-[500px] max-h-[90vh] overflow-y-auto",
                 onclick: move |e| e.stop_propagation(),
 
@@ -71,6 +80,7 @@ pub fn DirectorGenerateModal(props: DirectorGenerateModalProps) -> Element {
                     class: "flex justify-between items-center mb-4",
                     h3 { class: "text-white m-0", "Generate {props.asset_type} for {props.character_name}" }
                     button {
+                        aria_label: "Close",
                         onclick: move |_| props.on_close.call(()),
                         class: "px-2 py-1 bg-transparent text-gray-400 border-0 cursor-pointer text-xl",
                         "×"
@@ -130,8 +140,13 @@ pub fn DirectorGenerateModal(props: DirectorGenerateModalProps) -> Element {
                         class: "modal-overlay fixed inset-0 bg-black/90 flex items-center justify-center z-[1001]",
                         onclick: move |_| show_style_selector.set(false),
                         div {
+                            id: "director-style-selector",
+                            role: "dialog",
+                            aria_modal: "true",
                             class: "bg-dark-surface rounded-xl p-6 w-[90%] max-w-[600px] max-h-[80vh] overflow-y-auto",
                             onclick: move |e| e.stop_propagation(),
+
+                            ModalFocus { dialog_id: "director-style-selector" }
                             h3 { class: "text-white m-0 mb-4", "Select Style Reference" }
                             div {
                                 class: "grid grid-cols-[repeat(auto-fill,minmax(120px,1fr))] gap-3",
@@ -208,6 +223,9 @@ pub fn DirectorGenerateModal(props: DirectorGenerateModalProps) -> Element {
                     }
                 }
 
+                // Seed / steps / CFG / resolution overrides
+                GenerationParamsInput { params: params }
+
                 // Action buttons
                 div { class: "flex justify-end gap-2",
                     button {
@@ -238,6 +256,12 @@ pub fn DirectorGenerateModal(props: DirectorGenerateModalProps) -> Element {
                                     },
                                     count: *count.read(),
                                     style_reference_id: style_reference_id.read().clone(),
+                                    source_asset_id: None,
+                                    denoise: None,
+                                    params: {
+                                        let p = params.read().clone();
+                                        if p.is_empty() { None } else { Some(p) }
+                                    },
                                 };
                                 let svc_clone = svc.clone();
                                 spawn(async move {
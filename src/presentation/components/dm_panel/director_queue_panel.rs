@@ -28,18 +28,19 @@ pub fn DirectorQueuePanel(props: DirectorQueuePanelProps) -> Element {
         .collect();
     let active_suggestions: Vec<_> = suggestions
         .iter()
-        .filter(|s| matches!(s.status, SuggestionStatus::Queued | SuggestionStatus::Processing))
+        .filter(|s| s.status.is_active())
         .collect();
 
     rsx! {
         div {
-            class: "director-queue-panel fixed top-0 right-0 bottom-0 w-[400px] bg-dark-surface border-l border-gray-700 z-[1000] flex flex-col shadow-[-4px_0_6px_rgba(0,0,0,0.3)]",
+            class: "director-queue-panel fixed top-0 right-0 bottom-0 w-full sm:w-[400px] bg-dark-surface border-l border-gray-700 z-[1000] flex flex-col shadow-[-4px_0_6px_rgba(0,0,0,0.3)]",
 
             // Header
             div {
                 class: "flex justify-between items-center p-4 border-b border-gray-700",
                 h3 { class: "text-white m-0 text-base", "Generation Queue" }
                 button {
+                    aria_label: "Close",
                     onclick: move |_| props.on_close.call(()),
                     class: "py-1 px-2 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
                     "×"
@@ -102,6 +103,7 @@ pub fn DirectorQueuePanel(props: DirectorQueuePanelProps) -> Element {
                                             match &suggestion.status {
                                                 SuggestionStatus::Queued => "Queued",
                                                 SuggestionStatus::Processing => "Processing...",
+                                                SuggestionStatus::Streaming { .. } => "Writing...",
                                                 _ => "",
                                             }
                                         }
@@ -0,0 +1,114 @@
+//! Directorial preset - Switch the table's tone from the Director panel
+//!
+//! Offers the world's presets (edited in World Settings) and the plain
+//! tones. Each pick goes straight to the Engine as the directorial
+//! context, so the next NPC response already follows it. What is shown as
+//! live comes from the Engine, so a reload or another DM's pick shows up.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{DirectorialContext, DirectorialPreset};
+use crate::application::services::{directorial_context, SessionCommandService};
+use crate::presentation::components::settings::directorial_presets::PRESET_TONES;
+use crate::presentation::services::use_settings_service;
+use crate::presentation::state::use_session_state;
+
+/// Preset and tone dropdowns for the running scene
+#[component]
+pub fn DirectorialPresetControl(world_id: String) -> Element {
+    let session_state = use_session_state();
+    let settings_service = use_settings_service();
+    let mut presets: Signal<Vec<DirectorialPreset>> = use_signal(Vec::new);
+    let mut live = session_state.directorial_context;
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    use_effect(move || {
+        let world_id = world_id.clone();
+        let svc = settings_service.clone();
+        spawn(async move {
+            match svc.get_for_world(&world_id).await {
+                Ok(settings) => presets.set(settings.directorial_presets),
+                Err(e) => tracing::warn!("Failed to load directorial presets: {}", e),
+            }
+        });
+    });
+
+    let engine_client = session_state.engine_client();
+    let mut apply = move |next_tone: String, preset: Option<DirectorialPreset>| {
+        let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+            error.set(Some("Not connected to the session".to_string()));
+            return;
+        };
+        let context = directorial_context(&next_tone, preset.as_ref());
+        let sent = DirectorialContext {
+            scene_notes: context.scene_notes.clone(),
+            tone: context.tone.clone(),
+            npc_motivations: Vec::new(),
+            forbidden_topics: context.forbidden_topics.clone(),
+            preset_name: context.preset_name.clone(),
+            style_notes: context.style_notes.clone(),
+            content_guidelines: context.content_guidelines.clone(),
+        };
+        match SessionCommandService::new(client).send_directorial_update(context) {
+            Ok(()) => {
+                live.set(Some(sent));
+                error.set(None);
+            }
+            Err(e) => error.set(Some(format!("Failed to change the tone: {}", e))),
+        }
+    };
+
+    let preset_list = presets.read().clone();
+    let (tone, active) = match live.read().as_ref() {
+        Some(context) => (context.tone.clone(), context.preset_name.clone()),
+        None => (PRESET_TONES[0].to_string(), None),
+    };
+    let active_notes = preset_list
+        .iter()
+        .find(|p| Some(&p.name) == active.as_ref())
+        .map(|p| p.style_notes.clone())
+        .filter(|notes| !notes.is_empty());
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+
+            if !preset_list.is_empty() {
+                select {
+                    aria_label: "Directorial preset",
+                    value: "{active.clone().unwrap_or_default()}",
+                    onchange: {
+                        let preset_list = preset_list.clone();
+                        let tone = tone.clone();
+                        move |e: FormEvent| {
+                            let picked = preset_list.iter().find(|p| p.name == e.value()).cloned();
+                            apply(tone.clone(), picked);
+                        }
+                    },
+                    class: "w-full p-2 bg-dark-bg border border-gray-700 rounded-lg text-white",
+                    option { value: "", "No preset" }
+                    for preset in preset_list.iter().filter(|p| !p.name.is_empty()) {
+                        option { key: "{preset.name}", value: "{preset.name}", "{preset.name}" }
+                    }
+                }
+            }
+
+            select {
+                aria_label: "Scene tone",
+                value: "{tone}",
+                onchange: move |e: FormEvent| apply(e.value(), None),
+                class: "w-full p-2 bg-dark-bg border border-gray-700 rounded-lg text-white",
+                for t in PRESET_TONES.iter() {
+                    option { value: "{t}", "{t}" }
+                }
+            }
+
+            if let Some(notes) = active_notes {
+                p { class: "text-gray-400 text-xs italic m-0", "{notes}" }
+            }
+            if let Some(msg) = error.read().as_ref() {
+                p { class: "text-red-500 text-xs m-0", role: "alert", "{msg}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Disposition toggle - hide an NPC's feelings from players
+//!
+//! Shows the DM the meter players see (or would see) for an on-stage NPC,
+//! with a button to hide it for mystery characters. The Engine confirms
+//! with `DispositionChanged`, which updates every client.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{Disposition, SceneCharacterState};
+use crate::application::services::SessionCommandService;
+use crate::presentation::components::visual_novel::DispositionMeter;
+use crate::presentation::state::{use_game_state, use_session_state};
+
+/// Meter and hide/show button for one NPC
+#[component]
+pub fn DispositionToggle(character: SceneCharacterState) -> Element {
+    let session_state = use_session_state();
+    let mut game_state = use_game_state();
+    let has_client = session_state.has_client();
+    let engine_client = session_state.engine_client();
+
+    let Some(sentiment) = character.sentiment else {
+        return rsx! {};
+    };
+    let hidden = character.disposition_hidden;
+    let npc_id = character.id.clone();
+
+    rsx! {
+        div {
+            class: "flex items-center gap-2 mb-1",
+            span {
+                class: if hidden { "opacity-40" } else { "" },
+                DispositionMeter { disposition: Disposition::from_sentiment(sentiment) }
+            }
+            button {
+                r#type: "button",
+                disabled: !has_client,
+                aria_pressed: if hidden { "true" } else { "false" },
+                title: if hidden { "Players can't see how this NPC feels" } else { "Players see this meter" },
+                class: "px-2 py-0.5 bg-dark-bg text-gray-400 border border-gray-700 rounded text-xs cursor-pointer disabled:opacity-50",
+                onclick: move |_| {
+                    let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+                        return;
+                    };
+                    match SessionCommandService::new(client).set_disposition_hidden(&npc_id, !hidden) {
+                        Ok(()) => game_state.set_character_disposition(&npc_id, Some(sentiment), !hidden),
+                        Err(e) => tracing::error!("Failed to set disposition visibility: {}", e),
+                    }
+                },
+                if hidden { "🙈 Hidden from players" } else { "👁 Shown to players" }
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Encounter runner - stage a prepared encounter in one step
+//!
+//! Encounters are built in Creator Mode. Running one asks the Engine to
+//! put its NPCs in the scene, activate its challenges and switch to its
+//! region's backdrop for everyone at the table.
+
+use dioxus::prelude::*;
+
+use crate::application::services::{Encounter, SessionCommandService};
+use crate::presentation::services::use_encounter_service;
+use crate::presentation::state::{use_game_state, use_session_state};
+
+/// Encounter picker and "Run" button for the Director panel
+#[component]
+pub fn EncounterRunner(world_id: String) -> Element {
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+    let encounter_service = use_encounter_service();
+
+    let mut encounters: Signal<Vec<Encounter>> = use_signal(Vec::new);
+    let mut selected_id = use_signal(String::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    use_effect(move || {
+        let svc = encounter_service.clone();
+        let world_id = world_id.clone();
+        spawn(async move {
+            match svc.list_encounters(&world_id).await {
+                Ok(list) => encounters.set(list),
+                Err(e) => error.set(Some(format!("Failed to load encounters: {}", e))),
+            }
+        });
+    });
+
+    let selected = encounters
+        .read()
+        .iter()
+        .find(|e| e.id == *selected_id.read())
+        .cloned();
+
+    // Names from the session snapshot where it knows them
+    let (npc_names, location_name) = {
+        let world = game_state.world.read();
+        match (world.as_ref(), selected.as_ref()) {
+            (Some(w), Some(encounter)) => (
+                encounter
+                    .npc_ids
+                    .iter()
+                    .map(|id| {
+                        w.get_character(id)
+                            .map(|c| c.name.clone())
+                            .unwrap_or_else(|| "Unknown NPC".to_string())
+                    })
+                    .collect::<Vec<_>>(),
+                encounter
+                    .location_id
+                    .as_deref()
+                    .and_then(|id| w.get_location(id))
+                    .map(|l| l.name.clone()),
+            ),
+            _ => (Vec::new(), None),
+        }
+    };
+
+    let has_client = session_state.has_client();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 text-sm",
+
+            if encounters.read().is_empty() {
+                p { class: "text-gray-500 text-xs italic m-0", "No encounters yet. Build them in Creator Mode." }
+            } else {
+                select {
+                    value: "{selected_id}",
+                    onchange: move |e: FormEvent| {
+                        error.set(None);
+                        selected_id.set(e.value());
+                    },
+                    aria_label: "Encounter",
+                    class: "w-full p-2 bg-dark-bg border border-gray-700 rounded-md text-white text-sm cursor-pointer",
+                    option { value: "", "Choose an encounter..." }
+                    for encounter in encounters.read().iter() {
+                        option { key: "{encounter.id}", value: "{encounter.id}", "{encounter.name}" }
+                    }
+                }
+            }
+
+            if let Some(encounter) = selected.as_ref() {
+                div {
+                    class: "p-2 bg-black/20 rounded flex flex-col gap-1",
+                    div { class: "text-gray-400 text-xs", "{encounter.summary()}" }
+                    if let Some(location) = location_name.as_ref() {
+                        div { class: "text-gray-300 text-xs", "📍 {location}" }
+                    }
+                    if !npc_names.is_empty() {
+                        div { class: "text-gray-300 text-xs", "👥 {npc_names.join(\", \")}" }
+                    }
+                    if !encounter.notes.is_empty() {
+                        p { class: "text-gray-400 text-xs italic m-0 whitespace-pre-wrap", "{encounter.notes}" }
+                    }
+                }
+
+                button {
+                    class: "p-2 bg-red-700 hover:bg-red-600 text-white border-none rounded-lg cursor-pointer disabled:opacity-50",
+                    disabled: !has_client,
+                    onclick: {
+                        let encounter_id = encounter.id.clone();
+                        move |_| {
+                            let result = session_state
+                                .engine_client()
+                                .read()
+                                .as_ref()
+                                .map(|client| SessionCommandService::new(std::sync::Arc::clone(client)))
+                                .ok_or_else(|| "Not connected to a session".to_string())
+                                .and_then(|svc| svc.run_encounter(&encounter_id).map_err(|e| e.to_string()));
+                            if let Err(e) = result {
+                                error.set(Some(format!("Failed to run encounter: {}", e)));
+                            }
+                        }
+                    },
+                    "⚔ Run Encounter"
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-500 text-xs", "{err}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Faction standing - DM moves the party's standing with each faction
+//!
+//! Each change goes to the Engine as a delta, and the faction it returns
+//! replaces the one shown. Changes are logged to the timeline with the
+//! optional reason; like quick markers, they are explicit, so a co-DM's
+//! changes are logged too.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::StandingLevel;
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{AdjustStandingRequest, StandingChange};
+use crate::presentation::services::use_faction_service;
+use crate::presentation::state::{use_game_state, use_session_state};
+
+/// Steps offered for each faction
+const STEPS: &[i32] = &[-10, -5, 5, 10];
+
+/// Party standing per faction, with buttons to move it
+#[component]
+pub fn FactionStandingTracker() -> Element {
+    let game_state = use_game_state();
+    let session_state = use_session_state();
+    let faction_service = use_faction_service();
+    let platform = use_context::<Platform>();
+    let mut reason = use_signal(String::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let factions = game_state.factions.read().clone();
+    if factions.is_empty() {
+        return rsx! {
+            p { class: "text-gray-500 text-sm m-0", "No factions in this world. Add them in Creator Mode." }
+        };
+    }
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 text-sm",
+
+            input {
+                r#type: "text",
+                aria_label: "Reason for the change",
+                placeholder: "Why, e.g. returned the stolen ledger (optional)",
+                value: "{reason}",
+                oninput: move |e| reason.set(e.value()),
+                class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white text-xs box-border",
+            }
+
+            if let Some(msg) = error.read().as_ref() {
+                p { class: "text-red-500 text-xs m-0", role: "alert", "{msg}" }
+            }
+
+            for faction in factions.into_iter() {
+                {
+                    let level = StandingLevel::from_standing(faction.party_standing);
+                    // -100..=100 mapped onto the bar's width
+                    let fill = format!("width: {}%", (faction.party_standing + 100) / 2);
+                    rsx! {
+                        div {
+                            key: "{faction.id}",
+                            class: "p-2 bg-dark-bg rounded flex flex-col gap-1",
+                            div {
+                                class: "flex items-center gap-2",
+                                span { class: "text-white flex-1 truncate", "{faction.name}" }
+                                span { class: "text-xs {level.text_class()}", "{level.display_name()} ({faction.party_standing})" }
+                            }
+                            div {
+                                class: "h-1.5 bg-gray-700 rounded overflow-hidden",
+                                div { class: "h-full bg-amber-500", style: "{fill}" }
+                            }
+                            div {
+                                class: "flex gap-1",
+                                for step in STEPS.iter().copied() {
+                                    button {
+                                        r#type: "button",
+                                        aria_label: "Change standing with {faction.name} by {step:+}",
+                                        onclick: {
+                                            let faction = faction.clone();
+                                            let svc = faction_service.clone();
+                                            let platform = platform.clone();
+                                            let game_state = game_state.clone();
+                                            let mut story_capture = session_state.story_capture;
+                                            move |_| {
+                                                let faction = faction.clone();
+                                                let svc = svc.clone();
+                                                let platform = platform.clone();
+                                                let mut game_state = game_state.clone();
+                                                let request = AdjustStandingRequest {
+                                                    delta: step,
+                                                    reason: reason.read().trim().to_string(),
+                                                };
+                                                spawn(async move {
+                                                    match svc.adjust_standing(&faction.id, &request).await {
+                                                        Ok(updated) => {
+                                                            error.set(None);
+                                                            story_capture.write().record_faction_standing(
+                                                                &StandingChange {
+                                                                    faction_name: &updated.name,
+                                                                    from: faction.party_standing,
+                                                                    to: updated.party_standing,
+                                                                    reason: &request.reason,
+                                                                },
+                                                                platform.now_unix_secs(),
+                                                            );
+                                                            game_state.set_faction(updated);
+                                                            reason.set(String::new());
+                                                        }
+                                                        Err(e) => error.set(Some(format!("Failed to change standing: {}", e))),
+                                                    }
+                                                });
+                                            }
+                                        },
+                                        class: if step < 0 {
+                                            "flex-1 py-0.5 bg-transparent text-red-400 border border-red-500/40 rounded cursor-pointer text-xs"
+                                        } else {
+                                            "flex-1 py-0.5 bg-transparent text-green-400 border border-green-500/40 rounded cursor-pointer text-xs"
+                                        },
+                                        "{step:+}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,17 @@
 //! Location Navigator - DM tool to preview any location
+//!
+//! Each location's regions can be listed to reveal them on the players' map,
+//! or hide them again, for the whole party or one PC.
+
+use std::sync::Arc;
 
 use dioxus::prelude::*;
 
+use crate::application::dto::RevealScope;
+use crate::application::services::location_service::RegionData;
+use crate::application::services::SessionCommandService;
 use crate::presentation::services::use_location_service;
+use crate::presentation::state::{use_game_state, use_session_state};
 
 /// Props for LocationNavigator
 #[derive(Props, Clone, PartialEq)]
@@ -18,6 +27,9 @@ pub fn LocationNavigator(props: LocationNavigatorProps) -> Element {
     let mut locations: Signal<Vec<crate::application::services::location_service::LocationSummary>> = use_signal(Vec::new);
     let mut loading = use_signal(|| true);
     let mut error: Signal<Option<String>> = use_signal(|| None);
+    // Empty for the whole party, otherwise a PC id
+    let mut reveal_for = use_signal(String::new);
+    let session_state = use_session_state();
 
     // Load locations on mount
     {
@@ -44,14 +56,41 @@ pub fn LocationNavigator(props: LocationNavigatorProps) -> Element {
 
     let locs = locations.read().clone();
     let err = error.read().clone();
+    let pcs: Vec<(String, String)> = session_state
+        .party
+        .members
+        .read()
+        .iter()
+        .map(|m| (m.pc_id.clone(), m.name.clone()))
+        .collect();
+    let scope = match reveal_for.read().as_str() {
+        "" => RevealScope::Party,
+        pc_id => RevealScope::Player { pc_id: pc_id.to_string() },
+    };
 
     rsx! {
         div {
             class: "flex flex-col gap-4 p-4 bg-dark-surface rounded-lg",
 
-            h3 {
-                class: "m-0 text-white text-lg",
-                "Location Navigator"
+            div {
+                class: "flex justify-between items-center gap-3",
+                h3 {
+                    class: "m-0 text-white text-lg",
+                    "Location Navigator"
+                }
+                label {
+                    class: "flex items-center gap-2 text-gray-400 text-xs",
+                    "Map reveals for"
+                    select {
+                        value: "{reveal_for}",
+                        onchange: move |e: FormEvent| reveal_for.set(e.value()),
+                        class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+                        option { value: "", "Whole party" }
+                        for (id, name) in pcs.iter() {
+                            option { key: "{id}", value: "{id}", selected: *id == *reveal_for.read(), "{name}" }
+                        }
+                    }
+                }
             }
 
             if let Some(e) = err.as_ref() {
@@ -79,6 +118,7 @@ pub fn LocationNavigator(props: LocationNavigatorProps) -> Element {
                         rsx! {
                             LocationCard {
                                 location,
+                                scope: scope.clone(),
                                 on_preview: move |_| props.on_preview.call(loc_id.clone()),
                             }
                         }
@@ -93,31 +133,185 @@ pub fn LocationNavigator(props: LocationNavigatorProps) -> Element {
 #[derive(Props, Clone, PartialEq)]
 struct LocationCardProps {
     location: crate::application::services::LocationSummary,
+    /// Who the region reveal buttons apply to
+    scope: RevealScope,
     on_preview: EventHandler<()>,
 }
 
 #[component]
 fn LocationCard(props: LocationCardProps) -> Element {
+    let location_service = use_location_service();
+    // Loaded the first time the region list is opened
+    let mut regions: Signal<Option<Vec<RegionData>>> = use_signal(|| None);
+    let mut show_regions = use_signal(|| false);
+
+    let toggle_regions = {
+        let location_id = props.location.id.clone();
+        move |_| {
+            let open = !*show_regions.read();
+            show_regions.set(open);
+            if open && regions.read().is_none() {
+                let svc = location_service.clone();
+                let location_id = location_id.clone();
+                spawn(async move {
+                    match svc.get_regions(&location_id).await {
+                        Ok(list) => regions.set(Some(list)),
+                        Err(e) => {
+                            tracing::warn!("Failed to load regions: {}", e);
+                            regions.set(Some(Vec::new()));
+                        }
+                    }
+                });
+            }
+        }
+    };
+
     rsx! {
         div {
-            class: "p-4 bg-dark-bg rounded-lg border border-gray-700 flex justify-between items-center",
-
+            class: "bg-dark-bg rounded-lg border border-gray-700",
             div {
-                h4 {
-                    class: "m-0 mb-1 text-white text-base",
-                    "{props.location.name}"
+                class: "p-4 flex justify-between items-center",
+
+                div {
+                    h4 {
+                        class: "m-0 mb-1 text-white text-base",
+                        "{props.location.name}"
+                    }
+                    if let Some(loc_type) = props.location.location_type.as_ref() {
+                        div {
+                            class: "text-gray-400 text-xs",
+                            "{loc_type}"
+                        }
+                    }
                 }
-                if let Some(loc_type) = props.location.location_type.as_ref() {
-                    div {
-                        class: "text-gray-400 text-xs",
-                        "{loc_type}"
+                div {
+                    class: "flex gap-2",
+                    button {
+                        onclick: toggle_regions,
+                        aria_expanded: "{show_regions}",
+                        class: "py-2 px-4 bg-transparent text-gray-300 border border-gray-700 rounded-lg cursor-pointer text-sm",
+                        "Regions"
+                    }
+                    button {
+                        onclick: move |_| props.on_preview.call(()),
+                        class: "py-2 px-4 bg-blue-500 text-white border-0 rounded-lg cursor-pointer text-sm",
+                        "Preview"
                     }
                 }
             }
-            button {
-                onclick: move |_| props.on_preview.call(()),
-                class: "py-2 px-4 bg-blue-500 text-white border-0 rounded-lg cursor-pointer text-sm",
-                "Preview"
+            if *show_regions.read() {
+                match regions.read().as_ref() {
+                    None => rsx! {
+                        div { class: "px-4 pb-4 text-gray-400 text-sm", "Loading regions..." }
+                    },
+                    Some(list) if list.is_empty() => rsx! {
+                        div { class: "px-4 pb-4 text-gray-400 text-sm", "No regions in this location" }
+                    },
+                    Some(list) => rsx! {
+                        RegionRevealList { regions: list.clone(), scope: props.scope.clone() }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// A location's regions, each revealed or hidden on the players' map
+#[component]
+fn RegionRevealList(regions: Vec<RegionData>, scope: RevealScope) -> Element {
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+    let engine_client = session_state.engine_client();
+    let has_client = session_state.has_client();
+
+    let pc_id = match &scope {
+        RevealScope::Party => None,
+        RevealScope::Player { pc_id } => Some(pc_id.clone()),
+    };
+    let reveals = game_state.map_reveals.read().clone();
+    let region_ids: Vec<String> = regions.iter().map(|r| r.id.clone()).collect();
+
+    let send = move |region_ids: Vec<String>, scope: RevealScope, reveal: bool| {
+        let Some(client) = engine_client.read().as_ref().map(Arc::clone) else {
+            return;
+        };
+        let svc = SessionCommandService::new(client);
+        let result = if reveal {
+            svc.reveal_regions(region_ids, scope)
+        } else {
+            svc.hide_regions(region_ids, scope)
+        };
+        if let Err(e) = result {
+            tracing::error!("Failed to update map reveals: {}", e);
+        }
+    };
+
+    rsx! {
+        div {
+            class: "px-4 pb-4 flex flex-col gap-1",
+            div {
+                class: "flex justify-end gap-2 text-xs",
+                button {
+                    disabled: !has_client,
+                    onclick: {
+                        let region_ids = region_ids.clone();
+                        let scope = scope.clone();
+                        move |_| send(region_ids.clone(), scope.clone(), true)
+                    },
+                    class: "bg-transparent border-0 text-blue-400 cursor-pointer p-0 disabled:opacity-50",
+                    "Reveal all"
+                }
+                button {
+                    disabled: !has_client,
+                    onclick: {
+                        let scope = scope.clone();
+                        move |_| send(region_ids.clone(), scope.clone(), false)
+                    },
+                    class: "bg-transparent border-0 text-gray-400 cursor-pointer p-0 disabled:opacity-50",
+                    "Hide all"
+                }
+            }
+            for region in regions.iter() {
+                {
+                    // Party scope shows party-wide reveals only; a PC's own
+                    // visits don't make a region visible to everyone
+                    let revealed = match &pc_id {
+                        None => reveals.party.contains(&region.id),
+                        Some(pc) => reveals.is_revealed(&region.id, Some(pc)),
+                    };
+                    // A party-wide reveal can't be hidden from just one PC
+                    let party_wide = pc_id.is_some() && reveals.party.contains(&region.id);
+                    let region_id = region.id.clone();
+                    let scope = scope.clone();
+                    rsx! {
+                        div {
+                            key: "{region.id}",
+                            class: "flex justify-between items-center py-1 text-sm",
+                            span {
+                                class: if revealed { "text-white" } else { "text-gray-500" },
+                                if revealed { "👁 " } else { "🌫 " }
+                                "{region.name}"
+                                if !region.population.is_empty() {
+                                    span { class: "ml-2 text-gray-500 text-xs", "👥 {region.population.label()}" }
+                                }
+                            }
+                            if party_wide {
+                                span {
+                                    title: "Revealed to the whole party; hide it from the party view",
+                                    class: "px-2 py-0.5 text-xs text-gray-500",
+                                    "Party"
+                                }
+                            } else {
+                                button {
+                                    disabled: !has_client,
+                                    onclick: move |_| send(vec![region_id.clone()], scope.clone(), !revealed),
+                                    class: "px-2 py-0.5 bg-transparent border border-gray-700 rounded text-xs text-gray-300 cursor-pointer disabled:opacity-50",
+                                    if revealed { "Hide" } else { "Reveal" }
+                                }
+                            }
+                        }
+                    }
+                }
             }
         }
     }
@@ -4,24 +4,52 @@
 //! directorial notes, NPC motivation tracking, LLM response approval,
 //! and challenge management.
 
+pub mod action_inbox;
 pub mod adhoc_challenge_modal;
+pub mod advancement_grant;
+pub mod annotation_studio;
+pub mod approval_history;
 pub mod approval_popup;
+pub mod backdrop_studio;
+pub mod camera_controls;
 pub mod challenge_library;
 pub mod challenge_outcome_approval;
 pub mod character_perspective;
+pub mod condition_control;
 pub mod conversation_log;
 pub mod decision_queue;
+pub mod decision_timer_control;
+pub mod dice_stats;
+pub mod dialogue_pacing_control;
 pub mod directorial_notes;
+pub mod directorial_preset;
+pub mod disposition_toggle;
+pub mod encounter_runner;
+pub mod faction_standing;
 pub mod director_generate_modal;
 pub mod director_queue_panel;
 pub mod location_navigator;
-pub mod log_entry;
+pub mod npc_memory_inspector;
 pub mod npc_motivation;
+pub mod npc_whereabouts;
+pub mod party_strip;
 pub mod pc_management;
+pub mod region_population_control;
+pub mod rules_reference;
+pub mod scene_mood_control;
 pub mod scene_preview;
+pub mod scene_staging;
+pub mod session_pause_control;
+pub mod session_roles;
+pub mod shared_note_editor;
+pub mod social_graph;
+pub mod speak_as;
+pub mod spectator_policy_control;
+pub mod story_capture;
 pub mod tone_selector;
 pub mod trigger_challenge_modal;
+pub mod whisper_panel;
+pub mod world_update_review;
 
 // Re-export key types for external use
 pub use challenge_outcome_approval::{ChallengeOutcomeApprovalCard, ChallengeOutcomesSection};
-pub use conversation_log::{ChallengeResultInfo, ConversationLog, ConversationTurn};
@@ -0,0 +1,368 @@
+//! NPC memory inspector - what an NPC remembers, as the LLM will see it
+//!
+//! Picking an NPC asks the Engine for its memories. The DM can rewrite or
+//! delete any of them, or inject new ones; the Engine answers every change
+//! with the NPC's full list, which replaces what is shown here.
+
+use std::sync::Arc;
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{filter_memories, MemoryDraft, MemoryKind, NpcMemory, MAX_MEMORY_IMPORTANCE};
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::{use_game_state, use_session_state};
+
+const INPUT: &str = "p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs";
+
+/// NPC picker with that NPC's memories and a form for injecting new ones
+#[component]
+pub fn NpcMemoryInspector() -> Element {
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+    let mut npc_id = use_signal(String::new);
+    let mut search = use_signal(String::new);
+    let mut subject_filter = use_signal(String::new);
+    let mut editing: Signal<Option<String>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Fetch the picked NPC's memories; later changes arrive on their own
+    {
+        let engine_client = session_state.engine_client();
+        use_effect(move || {
+            let id = npc_id.read().clone();
+            if id.is_empty() {
+                return;
+            }
+            let Some(client) = engine_client.peek().as_ref().map(Arc::clone) else {
+                return;
+            };
+            if let Err(e) = SessionCommandService::new(client).request_npc_memories(&id) {
+                error.set(Some(format!("Failed to load memories: {}", e)));
+            }
+        });
+    }
+
+    // NPCs on stage first, then the rest of the world's
+    let mut npcs: Vec<(String, String)> = game_state
+        .scene_characters
+        .read()
+        .iter()
+        .map(|c| (c.id.clone(), c.name.clone()))
+        .collect();
+    if let Some(world) = game_state.world.read().as_ref() {
+        for character in world.characters.iter() {
+            if !npcs.iter().any(|(id, _)| id == &character.id) {
+                npcs.push((character.id.clone(), character.name.clone()));
+            }
+        }
+    }
+    let pcs: Vec<(String, String)> = session_state
+        .party
+        .members
+        .read()
+        .iter()
+        .map(|m| (m.pc_id.clone(), m.name.clone()))
+        .collect();
+
+    let selected = npc_id.read().clone();
+    let memories = if selected.is_empty() { None } else { game_state.memories_for(&selected) };
+    let has_client = session_state.has_client();
+    let engine_client = session_state.engine_client();
+
+    let commands = move || engine_client.read().as_ref().map(|c| SessionCommandService::new(Arc::clone(c)));
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+
+            select {
+                value: "{selected}",
+                aria_label: "NPC",
+                onchange: move |e: FormEvent| {
+                    editing.set(None);
+                    subject_filter.set(String::new());
+                    npc_id.set(e.value());
+                },
+                class: INPUT,
+                option { value: "", "Choose an NPC..." }
+                for (id, name) in npcs.iter() {
+                    option { key: "{id}", value: "{id}", selected: *id == selected, "{name}" }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-500 text-xs", "{err}" }
+            }
+
+            if !selected.is_empty() {
+                match memories {
+                    None => rsx! {
+                        p { class: "text-gray-500 text-xs italic m-0", "Loading memories..." }
+                    },
+                    Some(memories) => {
+                        let subject = subject_filter.read().clone();
+                        let visible = filter_memories(&memories, &search.read(), (!subject.is_empty()).then_some(subject.as_str()));
+                        rsx! {
+                            div {
+                                class: "flex gap-1",
+                                input {
+                                    r#type: "search",
+                                    placeholder: "Search memories...",
+                                    aria_label: "Search memories",
+                                    value: "{search}",
+                                    oninput: move |e| search.set(e.value()),
+                                    class: "flex-1 min-w-0 {INPUT}",
+                                }
+                                select {
+                                    value: "{subject}",
+                                    aria_label: "About",
+                                    onchange: move |e: FormEvent| subject_filter.set(e.value()),
+                                    class: INPUT,
+                                    option { value: "", "About anyone" }
+                                    for (id, name) in pcs.iter() {
+                                        option { key: "{id}", value: "{id}", selected: *id == subject, "{name}" }
+                                    }
+                                }
+                            }
+
+                            if memories.is_empty() {
+                                p { class: "text-gray-500 text-xs italic m-0", "This NPC doesn't remember anything yet" }
+                            } else if visible.is_empty() {
+                                p { class: "text-gray-500 text-xs italic m-0", "No memories match" }
+                            }
+
+                            div {
+                                class: "flex flex-col gap-1 max-h-[320px] overflow-y-auto",
+                                for memory in visible.into_iter().cloned() {
+                                    if editing.read().as_ref() == Some(&memory.id) {
+                                        MemoryForm {
+                                            key: "{memory.id}",
+                                            initial: MemoryDraft::from_memory(&memory),
+                                            pcs: pcs.clone(),
+                                            submit_label: "Save",
+                                            disabled: !has_client,
+                                            on_save: {
+                                                let npc_id = selected.clone();
+                                                let memory_id = memory.id.clone();
+                                                move |draft: MemoryDraft| {
+                                                    let Some(svc) = commands() else {
+                                                        return;
+                                                    };
+                                                    match svc.update_npc_memory(&npc_id, &memory_id, draft) {
+                                                        Ok(()) => editing.set(None),
+                                                        Err(e) => error.set(Some(format!("Failed to update memory: {}", e))),
+                                                    }
+                                                }
+                                            },
+                                            on_cancel: Some(EventHandler::new(move |_| editing.set(None))),
+                                        }
+                                    } else {
+                                        MemoryRow {
+                                            key: "{memory.id}",
+                                            memory: memory.clone(),
+                                            disabled: !has_client,
+                                            on_edit: {
+                                                let memory_id = memory.id.clone();
+                                                move |_| editing.set(Some(memory_id.clone()))
+                                            },
+                                            on_delete: {
+                                                let npc_id = selected.clone();
+                                                let memory_id = memory.id.clone();
+                                                move |_| {
+                                                    let Some(svc) = commands() else {
+                                                        return;
+                                                    };
+                                                    if let Err(e) = svc.delete_npc_memory(&npc_id, &memory_id) {
+                                                        error.set(Some(format!("Failed to delete memory: {}", e)));
+                                                    }
+                                                }
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+
+                            div {
+                                class: "pt-2 border-t border-gray-700",
+                                span { class: "text-gray-400 text-xs", "Inject a memory" }
+                                MemoryForm {
+                                    key: "new-{selected}",
+                                    initial: MemoryDraft {
+                                        content: String::new(),
+                                        kind: MemoryKind::default(),
+                                        subject_id: None,
+                                        importance: 3,
+                                    },
+                                    pcs: pcs.clone(),
+                                    submit_label: "Add",
+                                    disabled: !has_client,
+                                    clear_on_save: true,
+                                    on_save: {
+                                        let npc_id = selected.clone();
+                                        move |draft: MemoryDraft| {
+                                            let Some(svc) = commands() else {
+                                                return;
+                                            };
+                                            if let Err(e) = svc.add_npc_memory(&npc_id, draft) {
+                                                error.set(Some(format!("Failed to add memory: {}", e)));
+                                            }
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One memory with its kind, subject and importance
+#[component]
+fn MemoryRow(memory: NpcMemory, disabled: bool, on_edit: EventHandler<()>, on_delete: EventHandler<()>) -> Element {
+    let stars = "★".repeat(memory.importance as usize);
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 p-2 bg-dark-bg rounded text-xs",
+            div {
+                class: "flex items-center gap-1 text-gray-400",
+                span { title: "{memory.kind.display_name()}", "{memory.kind.icon()}" }
+                if let Some(name) = memory.subject_name.as_ref() {
+                    span { "About {name}" }
+                }
+                if memory.injected {
+                    span { class: "px-1 bg-purple-700 text-white rounded", title: "Injected by the DM", "DM" }
+                }
+                span { class: "ml-auto text-amber-400", title: "Importance {memory.importance} of {MAX_MEMORY_IMPORTANCE}", "{stars}" }
+            }
+            p { class: "text-white m-0 whitespace-pre-wrap", "{memory.content}" }
+            div {
+                class: "flex items-center gap-2",
+                if let Some(time) = memory.game_time.as_ref() {
+                    span { class: "text-gray-500", "{time}" }
+                }
+                button {
+                    disabled,
+                    onclick: move |_| on_edit.call(()),
+                    class: "ml-auto bg-transparent border-0 text-gray-400 cursor-pointer p-0 hover:text-white disabled:opacity-50",
+                    "Edit"
+                }
+                button {
+                    disabled,
+                    onclick: move |_| on_delete.call(()),
+                    class: "bg-transparent border-0 text-red-400 cursor-pointer p-0 hover:text-red-300 disabled:opacity-50",
+                    "Forget"
+                }
+            }
+        }
+    }
+}
+
+/// Content, kind, subject and importance of a memory being written
+#[component]
+fn MemoryForm(
+    initial: MemoryDraft,
+    pcs: Vec<(String, String)>,
+    submit_label: &'static str,
+    disabled: bool,
+    #[props(default)] clear_on_save: bool,
+    on_save: EventHandler<MemoryDraft>,
+    on_cancel: Option<EventHandler<()>>,
+) -> Element {
+    let mut content = use_signal(|| initial.content.clone());
+    let mut kind = use_signal(|| initial.kind);
+    let mut subject = use_signal(|| initial.subject_id.clone().unwrap_or_default());
+    let mut importance = use_signal(|| initial.importance);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let save = move |_| {
+        let subject_id = subject.read().clone();
+        let draft = MemoryDraft {
+            content: content.read().trim().to_string(),
+            kind: *kind.read(),
+            subject_id: (!subject_id.is_empty()).then_some(subject_id),
+            importance: *importance.read(),
+        };
+        if let Err(e) = draft.validate() {
+            error.set(Some(e));
+            return;
+        }
+        error.set(None);
+        on_save.call(draft);
+        if clear_on_save {
+            content.set(String::new());
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-1 mt-1",
+            textarea {
+                value: "{content}",
+                placeholder: "What does the NPC remember?",
+                aria_label: "Memory",
+                oninput: move |e| content.set(e.value()),
+                class: "w-full min-h-[48px] resize-y box-border {INPUT}",
+            }
+            div {
+                class: "flex items-center gap-1",
+                select {
+                    value: "{kind.read().as_str()}",
+                    aria_label: "Kind",
+                    onchange: move |e: FormEvent| {
+                        if let Some(k) = MemoryKind::parse(&e.value()) {
+                            kind.set(k);
+                        }
+                    },
+                    class: INPUT,
+                    for k in MemoryKind::all() {
+                        option { value: "{k.as_str()}", selected: k == *kind.read(), "{k.icon()} {k.display_name()}" }
+                    }
+                }
+                select {
+                    value: "{subject}",
+                    aria_label: "About",
+                    onchange: move |e: FormEvent| subject.set(e.value()),
+                    class: "flex-1 min-w-0 {INPUT}",
+                    option { value: "", "About no one" }
+                    for (id, name) in pcs.iter() {
+                        option { key: "{id}", value: "{id}", selected: *id == *subject.read(), "{name}" }
+                    }
+                }
+                select {
+                    value: "{importance}",
+                    aria_label: "Importance",
+                    onchange: move |e: FormEvent| {
+                        if let Ok(v) = e.value().parse::<u8>() {
+                            importance.set(v);
+                        }
+                    },
+                    class: INPUT,
+                    for n in 1..=MAX_MEMORY_IMPORTANCE {
+                        option { value: "{n}", selected: n == *importance.read(), "{n}★" }
+                    }
+                }
+            }
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-500 text-xs", "{err}" }
+            }
+            div {
+                class: "flex justify-end gap-2",
+                if let Some(on_cancel) = on_cancel {
+                    button {
+                        onclick: move |_| on_cancel.call(()),
+                        class: "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-xs",
+                        "Cancel"
+                    }
+                }
+                button {
+                    disabled,
+                    onclick: save,
+                    class: "px-3 py-1 bg-purple-600 text-white border-0 rounded cursor-pointer text-xs disabled:opacity-50",
+                    "{submit_label}"
+                }
+            }
+        }
+    }
+}
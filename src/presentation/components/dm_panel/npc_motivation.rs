@@ -6,6 +6,7 @@ use dioxus::prelude::*;
 
 use crate::application::dto::websocket_messages::SceneCharacterState;
 use crate::presentation::components::dm_panel::director_generate_modal::DirectorGenerateModal;
+use crate::presentation::state::use_session_state;
 
 /// NPC motivation state
 #[derive(Clone, PartialEq)]
@@ -58,6 +59,7 @@ pub fn NPCMotivation(props: NPCMotivationProps) -> Element {
     let motivation_goal = props.motivation.goal.clone();
     let mut show_generate_modal = use_signal(|| false);
     let mut generate_asset_type = use_signal(|| "portrait".to_string());
+    let generation_available = use_session_state().engine_health().read().modes().generation_available;
 
     // Clone for each closure to avoid move conflicts
     let motivation_for_mood = props.motivation.clone();
@@ -125,24 +127,26 @@ pub fn NPCMotivation(props: NPCMotivationProps) -> Element {
                 }
             }
 
-            // Generate asset buttons
-            div {
-                class: "flex gap-2 mt-3",
-                button {
-                    onclick: move |_| {
-                        generate_asset_type.set("portrait".to_string());
-                        show_generate_modal.set(true);
-                    },
-                    class: "flex-1 p-2 bg-purple-500 text-white border-0 rounded-md cursor-pointer text-xs font-medium",
-                    "🎨 Generate Portrait"
-                }
-                button {
-                    onclick: move |_| {
-                        generate_asset_type.set("sprite".to_string());
-                        show_generate_modal.set(true);
-                    },
-                    class: "flex-1 p-2 bg-purple-500 text-white border-0 rounded-md cursor-pointer text-xs font-medium",
-                    "🖼️ Generate Sprite"
+            // Generate asset buttons (hidden while ComfyUI is down)
+            if generation_available {
+                div {
+                    class: "flex gap-2 mt-3",
+                    button {
+                        onclick: move |_| {
+                            generate_asset_type.set("portrait".to_string());
+                            show_generate_modal.set(true);
+                        },
+                        class: "flex-1 p-2 bg-purple-500 text-white border-0 rounded-md cursor-pointer text-xs font-medium",
+                        "🎨 Generate Portrait"
+                    }
+                    button {
+                        onclick: move |_| {
+                            generate_asset_type.set("sprite".to_string());
+                            show_generate_modal.set(true);
+                        },
+                        class: "flex-1 p-2 bg-purple-500 text-white border-0 rounded-md cursor-pointer text-xs font-medium",
+                        "🖼️ Generate Sprite"
+                    }
                 }
             }
         }
@@ -0,0 +1,119 @@
+//! NPC whereabouts - where would each NPC be right now?
+//!
+//! Reads character routines from the session snapshot against the world
+//! clock's time of day. The DM can also look ahead to another part of the day.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{routine_entry_for, DayPeriod};
+use crate::presentation::state::use_game_state;
+
+/// Routine lookup for the Director panel
+#[component]
+pub fn NpcWhereabouts() -> Element {
+    let game_state = use_game_state();
+    // None follows the world clock
+    let mut period_override: Signal<Option<DayPeriod>> = use_signal(|| None);
+    let mut search = use_signal(String::new);
+
+    let clock_period = game_state
+        .game_time
+        .read()
+        .as_ref()
+        .and_then(|t| DayPeriod::from_time_of_day(&t.time_of_day));
+    let period = (*period_override.read()).or(clock_period);
+
+    let world = game_state.world.read().clone();
+    let query = search.read().trim().to_lowercase();
+    let rows: Vec<(String, String, String, String)> = world
+        .as_ref()
+        .map(|w| {
+            w.characters
+                .iter()
+                .filter(|c| !c.routine.is_empty())
+                .filter(|c| query.is_empty() || c.name.to_lowercase().contains(&query))
+                .map(|c| {
+                    let entry = period.and_then(|p| routine_entry_for(&c.routine, p));
+                    let location = entry
+                        .and_then(|e| e.location_id.as_deref())
+                        .map(|id| {
+                            w.locations
+                                .iter()
+                                .find(|l| l.id == id)
+                                .map(|l| l.name.clone())
+                                .unwrap_or_else(|| "Unknown location".to_string())
+                        })
+                        .unwrap_or_default();
+                    let activity = entry.map(|e| e.activity.clone()).unwrap_or_default();
+                    (c.id.clone(), c.name.clone(), location, activity)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let clock_label = clock_period
+        .map(|p| format!("Now ({})", p.display_name()))
+        .unwrap_or_else(|| "Now (clock not set)".to_string());
+    let period_value = period_override.read().as_ref().map(|p| p.display_name()).unwrap_or("");
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 text-sm",
+
+            div {
+                class: "flex gap-2",
+                input {
+                    r#type: "text",
+                    value: "{search}",
+                    placeholder: "Find an NPC...",
+                    oninput: move |e| search.set(e.value()),
+                    class: "flex-1 min-w-0 p-2 bg-dark-bg border border-gray-700 rounded-md text-white text-sm",
+                }
+                select {
+                    value: "{period_value}",
+                    onchange: move |e: FormEvent| {
+                        let value = e.value();
+                        period_override.set(DayPeriod::all().into_iter().find(|p| p.display_name() == value));
+                    },
+                    class: "p-2 bg-dark-bg border border-gray-700 rounded-md text-white text-sm cursor-pointer",
+
+                    option { value: "", "{clock_label}" }
+                    for p in DayPeriod::all() {
+                        option {
+                            value: "{p.display_name()}",
+                            selected: *period_override.read() == Some(p),
+                            "{p.display_name()}"
+                        }
+                    }
+                }
+            }
+
+            if rows.is_empty() {
+                div { class: "text-gray-500 text-xs italic", "No NPCs with a routine" }
+            } else if period.is_none() {
+                div { class: "text-gray-500 text-xs italic", "Pick a time of day to look up routines" }
+            }
+
+            if period.is_some() {
+                for (id, name, location, activity) in rows.into_iter() {
+                    div {
+                        key: "{id}",
+                        class: "flex flex-col p-2 bg-dark-bg rounded",
+                        div {
+                            class: "flex items-center gap-2",
+                            span { class: "text-white flex-1 truncate", "{name}" }
+                            if !location.is_empty() {
+                                span { class: "text-purple-400 text-xs truncate", "📍 {location}" }
+                            }
+                        }
+                        if !activity.is_empty() {
+                            span { class: "text-gray-400 text-xs", "{activity}" }
+                        } else if location.is_empty() {
+                            span { class: "text-gray-500 text-xs italic", "Nothing scheduled" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
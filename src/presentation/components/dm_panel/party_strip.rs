@@ -0,0 +1,217 @@
+//! Party strip - At-a-glance view of every PC for the Director panel
+//!
+//! One card per player character with portrait, vital sheet fields (those the
+//! template flags as vital), where they are, and whether their player is
+//! connected. Clicking a card opens the full character sheet.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::SheetTemplate;
+use crate::presentation::components::character_sheet_viewer::CharacterSheetViewer;
+use crate::presentation::components::common::ConditionBadges;
+use crate::presentation::services::{use_player_character_service, use_world_service};
+use crate::presentation::state::{use_game_state, use_session_state, PartyMember};
+
+/// Whether a PC's player is at the table
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PlayerConnection {
+    Online,
+    /// Dropped, with their character held for the grace period
+    Reconnecting,
+    Offline,
+}
+
+impl PlayerConnection {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Online => "Online",
+            Self::Reconnecting => "Reconnecting",
+            Self::Offline => "Offline",
+        }
+    }
+
+    fn dot_class(&self) -> &'static str {
+        match self {
+            Self::Online => "bg-green-500",
+            Self::Reconnecting => "bg-orange-400",
+            Self::Offline => "bg-gray-600",
+        }
+    }
+}
+
+/// Props for PartyStrip
+#[derive(Props, Clone, PartialEq)]
+pub struct PartyStripProps {
+    pub session_id: String,
+    pub world_id: String,
+}
+
+/// Horizontal strip of party member cards
+#[component]
+pub fn PartyStrip(props: PartyStripProps) -> Element {
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+    let pc_service = use_player_character_service();
+    let world_service = use_world_service();
+    let mut template: Signal<Option<SheetTemplate>> = use_signal(|| None);
+    let mut viewing: Signal<Option<String>> = use_signal(|| None);
+
+    // Seed the party; server messages keep it current from here
+    {
+        let session_id = props.session_id.clone();
+        let party = session_state.party.clone();
+        use_effect(move || {
+            let sid = session_id.clone();
+            let svc = pc_service.clone();
+            let mut party = party.clone();
+            spawn(async move {
+                match svc.list_pcs(&sid).await {
+                    Ok(pcs) => party.seed(pcs),
+                    Err(e) => tracing::warn!("Failed to load party: {}", e),
+                }
+            });
+        });
+    }
+
+    {
+        let world_id = props.world_id.clone();
+        use_effect(move || {
+            let wid = world_id.clone();
+            let svc = world_service.clone();
+            spawn(async move {
+                if let Ok(json) = svc.get_sheet_template(&wid).await {
+                    if let Ok(t) = serde_json::from_value::<SheetTemplate>(json) {
+                        template.set(Some(t));
+                    }
+                }
+            });
+        });
+    }
+
+    let members = session_state.party.members.read().clone();
+    let online: Vec<String> = session_state
+        .presence
+        .participants
+        .read()
+        .iter()
+        .map(|p| p.user_id.clone())
+        .collect();
+    let reconnecting = session_state.reconnecting.read().clone();
+    let connection_of = |member: &PartyMember| {
+        if reconnecting
+            .iter()
+            .any(|r| r.user_id == member.user_id || r.pc_id.as_deref() == Some(member.pc_id.as_str()))
+        {
+            PlayerConnection::Reconnecting
+        } else if online.contains(&member.user_id) {
+            PlayerConnection::Online
+        } else {
+            PlayerConnection::Offline
+        }
+    };
+    let location_name = |id: &str| {
+        game_state
+            .world
+            .read()
+            .as_ref()
+            .and_then(|w| w.locations.iter().find(|l| l.id == id).map(|l| l.name.clone()))
+    };
+
+    let template_value = template.read().clone();
+    let vitals: Vec<(String, String)> = template_value
+        .as_ref()
+        .map(|t| t.vital_fields().into_iter().map(|f| (f.id.clone(), f.name.clone())).collect())
+        .unwrap_or_default();
+    let viewed = viewing
+        .read()
+        .as_ref()
+        .and_then(|id| members.iter().find(|m| &m.pc_id == id).cloned());
+
+    rsx! {
+        div {
+            class: "party-strip flex gap-2 overflow-x-auto pb-1",
+
+            if members.is_empty() {
+                div { class: "text-gray-500 text-sm italic", "No player characters in this session" }
+            }
+
+            for member in members.iter() {
+                {
+                    let pc_id = member.pc_id.clone();
+                    let connection = connection_of(member);
+                    let place = member
+                        .region_name
+                        .clone()
+                        .or_else(|| location_name(&member.location_id))
+                        .unwrap_or_else(|| "Unknown location".to_string());
+                    let initial = member.name.chars().next().unwrap_or('?');
+                    let conditions = session_state.conditions_for(&member.pc_id);
+                    rsx! {
+                        button {
+                            key: "{member.pc_id}",
+                            onclick: move |_| viewing.set(Some(pc_id.clone())),
+                            disabled: template_value.is_none(),
+                            title: "Open character sheet",
+                            class: "flex gap-2 min-w-[200px] p-2 bg-dark-bg border border-gray-700 rounded-lg text-left cursor-pointer hover:border-purple-500 disabled:cursor-default",
+
+                            div {
+                                class: "relative w-12 h-12 shrink-0",
+                                if let Some(portrait) = member.portrait_asset.as_ref() {
+                                    img { src: "{portrait}", alt: "", class: "w-12 h-12 rounded-md object-cover" }
+                                } else {
+                                    div {
+                                        class: "w-12 h-12 rounded-md bg-purple-500/20 text-purple-300 flex items-center justify-center text-lg font-bold",
+                                        "{initial}"
+                                    }
+                                }
+                                span {
+                                    class: "absolute -bottom-0.5 -right-0.5 w-3 h-3 rounded-full border-2 border-dark-bg {connection.dot_class()}",
+                                    title: connection.label(),
+                                    aria_label: connection.label(),
+                                }
+                            }
+
+                            div {
+                                class: "flex-1 min-w-0",
+                                div {
+                                    class: "flex items-baseline gap-1",
+                                    span { class: "text-white text-sm font-medium truncate", "{member.name}" }
+                                    span { class: "text-gray-500 text-xs", "Lv {member.level}" }
+                                }
+                                div { class: "text-gray-400 text-xs truncate", "📍 {place}" }
+                                div {
+                                    class: "flex flex-wrap gap-x-2 text-xs",
+                                    for (field_id, field_name) in vitals.iter() {
+                                        if let Some(value) = member.sheet_values.get(field_id) {
+                                            span {
+                                                key: "{field_id}",
+                                                class: if value.is_low() { "text-red-400" } else { "text-gray-300" },
+                                                "{field_name}: {value.summary()}"
+                                            }
+                                        }
+                                    }
+                                }
+                                if !conditions.is_empty() {
+                                    div { class: "mt-1", ConditionBadges { conditions } }
+                                }
+                                if connection != PlayerConnection::Online {
+                                    div { class: "text-orange-400 text-[0.625rem] uppercase", "{connection.label()}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if let (Some(member), Some(template)) = (viewed, template_value.clone()) {
+            CharacterSheetViewer {
+                character_name: member.name.clone(),
+                template,
+                values: member.sheet_values.clone(),
+                conditions: session_state.conditions_for(&member.pc_id),
+                on_close: move |_| viewing.set(None),
+            }
+        }
+    }
+}
@@ -2,8 +2,14 @@
 
 use dioxus::prelude::*;
 
-use crate::application::services::PlayerCharacterData;
-use crate::presentation::services::use_player_character_service;
+use crate::application::dto::{EntityStyle, InventoryItemData};
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{PlayerCharacterData, SessionCommandService};
+use crate::presentation::components::inventory_panel::InventoryPanel;
+use crate::presentation::services::{use_character_service, use_player_character_service};
+use crate::presentation::state::{use_session_state, ReconnectGrace};
+
+use super::advancement_grant::AdvancementGrantForm;
 
 /// Props for PCManagementPanel
 #[derive(Props, Clone, PartialEq)]
@@ -101,6 +107,15 @@ struct PCManagementCardProps {
 
 #[component]
 fn PCManagementCard(props: PCManagementCardProps) -> Element {
+    let session_state = use_session_state();
+    let character_service = use_character_service();
+    let mut show_inventory = use_signal(|| false);
+    let mut inventory: Signal<Vec<InventoryItemData>> = use_signal(Vec::new);
+    let mut inventory_loading = use_signal(|| false);
+    // A disconnected player keeps their PC until the grace period ends
+    let grace = session_state.reconnect_grace(&props.pc.user_id, &props.pc.id);
+    let locked = grace.is_some();
+
     rsx! {
         div {
             class: "p-4 bg-dark-bg rounded-lg border border-gray-700",
@@ -115,12 +130,111 @@ fn PCManagementCard(props: PCManagementCardProps) -> Element {
                     div {
                         class: "text-gray-400 text-xs",
                         "User: {props.pc.user_id}"
+                        if let Some(grace) = grace {
+                            ReconnectingBadge { grace }
+                        }
+                    }
+                    div {
+                        class: "text-gray-300 text-xs mt-1",
+                        "Level {props.pc.level} · {props.pc.experience} XP"
+                        if props.pc.pending_levels > 0 {
+                            span {
+                                class: "ml-2 px-1.5 py-0.5 bg-amber-500/20 text-amber-400 rounded",
+                                "{props.pc.pending_levels} level-up pending"
+                            }
+                        }
                     }
                 }
-                button {
-                    onclick: move |_| props.on_view_as.call(()),
-                    class: "px-4 py-2 bg-blue-500 text-white border-0 rounded-lg cursor-pointer text-sm",
-                    "View as"
+                div {
+                    class: "flex gap-2",
+                    button {
+                        onclick: {
+                            let pc_id = props.pc.id.clone();
+                            let char_svc = character_service.clone();
+                            move |_| {
+                                show_inventory.set(true);
+                                inventory_loading.set(true);
+                                let pc_id = pc_id.clone();
+                                let char_svc = char_svc.clone();
+                                spawn(async move {
+                                    match char_svc.get_inventory(&pc_id).await {
+                                        Ok(items) => inventory.set(items),
+                                        Err(e) => tracing::warn!("Failed to load inventory: {}", e),
+                                    }
+                                    inventory_loading.set(false);
+                                });
+                            }
+                        },
+                        class: "px-4 py-2 bg-transparent text-gray-300 border border-gray-700 rounded-lg cursor-pointer text-sm",
+                        "Inventory"
+                    }
+                    button {
+                        onclick: move |_| props.on_view_as.call(()),
+                        disabled: locked,
+                        title: if locked { "Locked to its player until they reconnect or the grace period ends" } else { "" },
+                        class: "px-4 py-2 bg-blue-500 text-white border-0 rounded-lg cursor-pointer text-sm disabled:opacity-50 disabled:cursor-not-allowed",
+                        "Play as"
+                    }
+                }
+            }
+
+            if *show_inventory.read() {
+                InventoryPanel {
+                    character_name: props.pc.name.clone(),
+                    items: inventory.read().clone(),
+                    is_loading: *inventory_loading.read(),
+                    on_close: move |_| show_inventory.set(false),
+                    on_identify: Some(EventHandler::new({
+                        let pc_id = props.pc.id.clone();
+                        let engine_client = session_state.engine_client();
+                        move |item_id: String| {
+                            let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+                                return;
+                            };
+                            match SessionCommandService::new(client).identify_item(&pc_id, &item_id) {
+                                Ok(()) => {
+                                    if let Some(held) = inventory.write().iter_mut().find(|i| i.item.id == item_id) {
+                                        held.identified = true;
+                                    }
+                                }
+                                Err(e) => tracing::error!("Failed to identify item: {}", e),
+                            }
+                        }
+                    })),
+                    on_save_hidden_notes: Some(EventHandler::new({
+                        let char_svc = character_service.clone();
+                        move |(item_id, notes): (String, String)| {
+                            let char_svc = char_svc.clone();
+                            spawn(async move {
+                                match char_svc.set_item_hidden_notes(&item_id, &notes).await {
+                                    Ok(()) => {
+                                        let notes = Some(notes.trim().to_string()).filter(|n| !n.is_empty());
+                                        if let Some(held) = inventory.write().iter_mut().find(|i| i.item.id == item_id) {
+                                            held.item.has_hidden_notes = notes.is_some();
+                                            held.item.hidden_notes = notes;
+                                        }
+                                    }
+                                    Err(e) => tracing::error!("Failed to save item notes: {}", e),
+                                }
+                            });
+                        }
+                    })),
+                    on_save_style: Some(EventHandler::new({
+                        let char_svc = character_service.clone();
+                        move |(item_id, style): (String, EntityStyle)| {
+                            let char_svc = char_svc.clone();
+                            spawn(async move {
+                                match char_svc.set_item_style(&item_id, &style).await {
+                                    Ok(()) => {
+                                        if let Some(held) = inventory.write().iter_mut().find(|i| i.item.id == item_id) {
+                                            held.item.style = style;
+                                        }
+                                    }
+                                    Err(e) => tracing::error!("Failed to save item style: {}", e),
+                                }
+                            });
+                        }
+                    })),
                 }
             }
 
@@ -151,11 +265,48 @@ fn PCManagementCard(props: PCManagementCardProps) -> Element {
                         }
                     }
                 }
+                div {
+                    div {
+                        class: "text-gray-400 text-xs mb-1",
+                        "Advancement"
+                    }
+                    AdvancementGrantForm { pc_id: props.pc.id.clone() }
+                }
             }
         }
     }
 }
 
+/// "Reconnecting…" badge counting down the player's grace period
+#[component]
+fn ReconnectingBadge(grace: ReconnectGrace) -> Element {
+    let platform = use_context::<Platform>();
+    let mut now_ms = use_signal({
+        let platform = platform.clone();
+        move || platform.now_millis()
+    });
+
+    use_future(move || {
+        let platform = platform.clone();
+        async move {
+            loop {
+                platform.sleep_ms(1000).await;
+                now_ms.set(platform.now_millis());
+            }
+        }
+    });
+
+    let remaining = grace.remaining_secs(*now_ms.read());
+
+    rsx! {
+        span {
+            class: "ml-2 px-1.5 py-0.5 bg-orange-500/20 text-orange-400 rounded",
+            title: "Character is locked to this player until they reconnect or the grace period ends",
+            "reconnecting… {remaining}s"
+        }
+    }
+}
+
 /// Props for PCLocationsWidget
 #[derive(Props, Clone, PartialEq)]
 pub struct PCLocationsWidgetProps {
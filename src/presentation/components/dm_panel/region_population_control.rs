@@ -0,0 +1,94 @@
+//! Region population control - DM sets how crowded the region on stage is
+//!
+//! Density buttons go to the Engine right away; the crowd description is
+//! sent when the field loses focus. The Engine saves the population with
+//! the region and broadcasts it to every client.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{CrowdDensity, RegionPopulation};
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::{use_game_state, use_session_state};
+
+/// Density buttons and crowd description for the current region
+#[component]
+pub fn RegionPopulationControl() -> Element {
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+
+    let Some(region) = game_state.current_region.read().clone() else {
+        return rsx! {
+            p { class: "text-gray-500 text-sm m-0", "No region on stage" }
+        };
+    };
+    let population = region.population.clone();
+    let has_client = session_state.has_client();
+
+    let engine_client = session_state.engine_client();
+    let send = {
+        let mut game_state = game_state.clone();
+        let region_id = region.id.clone();
+        move |next: RegionPopulation| {
+            let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+                return;
+            };
+            match SessionCommandService::new(client).set_region_population(&region_id, next.clone()) {
+                Ok(()) => game_state.set_region_population(&region_id, next),
+                Err(e) => tracing::error!("Failed to set region population: {}", e),
+            }
+        }
+    };
+
+    let description = population.description.clone().unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-3 text-sm",
+
+            div {
+                class: "flex flex-wrap gap-1",
+                role: "radiogroup",
+                aria_label: "Crowd density",
+                for density in CrowdDensity::all() {
+                    button {
+                        r#type: "button",
+                        role: "radio",
+                        aria_checked: if density == population.density { "true" } else { "false" },
+                        disabled: !has_client,
+                        onclick: {
+                            let mut send = send.clone();
+                            let population = population.clone();
+                            move |_| send(RegionPopulation { density, ..population.clone() })
+                        },
+                        class: if density == population.density {
+                            "px-2 py-1 bg-purple-500 text-white border border-purple-500 rounded text-xs cursor-pointer disabled:opacity-50"
+                        } else {
+                            "px-2 py-1 bg-dark-bg text-gray-300 border border-gray-700 rounded text-xs cursor-pointer disabled:opacity-50"
+                        },
+                        "{density.display_name()}"
+                    }
+                }
+            }
+
+            input {
+                r#type: "text",
+                aria_label: "Who the crowd is",
+                placeholder: "Who's here, e.g. dock workers",
+                disabled: !has_client,
+                value: "{description}",
+                onchange: {
+                    let mut send = send.clone();
+                    let population = population.clone();
+                    move |e: FormEvent| {
+                        let text = e.value().trim().to_string();
+                        send(RegionPopulation {
+                            description: (!text.is_empty()).then_some(text),
+                            ..population.clone()
+                        });
+                    }
+                },
+                class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white text-xs box-border",
+            }
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! Rules reference drawer - Searchable rules digest for the DM mid-session
+//!
+//! Lists the world's rule system, difficulty ladder, stats and skills, plus
+//! the DM's own house rules, which can be added and edited here. Opened from
+//! the DM View with Ctrl+/ (Cmd+/ on macOS).
+
+use dioxus::prelude::*;
+
+use crate::application::dto::SkillData;
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{
+    build_reference, load_house_rules, save_house_rules, search_reference, upsert_house_rule, HouseRule,
+    RuleCategory,
+};
+use crate::presentation::services::use_skill_service;
+use crate::presentation::state::{use_game_state, use_modal_focus};
+
+/// Side drawer with the world's rules and house rules
+#[component]
+pub fn RulesReferenceDrawer(world_id: String, on_close: EventHandler<()>) -> Element {
+    use_modal_focus("rules-reference");
+    let game_state = use_game_state();
+    let skill_service = use_skill_service();
+    let platform = use_context::<Platform>();
+
+    let mut query = use_signal(String::new);
+    let mut skills: Signal<Vec<SkillData>> = use_signal(Vec::new);
+    let mut house_rules = use_signal({
+        let platform = platform.clone();
+        let world_id = world_id.clone();
+        move || load_house_rules(&platform, &world_id)
+    });
+    // House rule being written: (ID, title, body)
+    let mut editing: Signal<Option<(String, String, String)>> = use_signal(|| None);
+    let mut edit_error: Signal<Option<String>> = use_signal(|| None);
+
+    {
+        let world_id = world_id.clone();
+        use_effect(move || {
+            let svc = skill_service.clone();
+            let world_id = world_id.clone();
+            spawn(async move {
+                match svc.list_skills(&world_id).await {
+                    Ok(list) => skills.set(list),
+                    Err(e) => tracing::warn!("Failed to load skills for the rules reference: {}", e),
+                }
+            });
+        });
+    }
+
+    let rule_system = game_state.world.read().as_ref().map(|w| w.world.rule_system.clone()).unwrap_or_default();
+    let entries = build_reference(&rule_system, &skills.read(), &house_rules.read());
+    let matches = search_reference(&entries, &query.read());
+
+    let save_rules = {
+        let platform = platform.clone();
+        let world_id = world_id.clone();
+        move |rules: Vec<HouseRule>| {
+            save_house_rules(&platform, &world_id, &rules);
+            house_rules.set(rules);
+        }
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/60 flex justify-end z-[1100]",
+            onclick: move |_| on_close.call(()),
+
+            aside {
+                id: "rules-reference",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "rules-reference-title",
+                class: "bg-dark-surface w-full max-w-md h-full overflow-y-auto p-4 flex flex-col gap-3",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        on_close.call(());
+                    }
+                },
+
+                div {
+                    class: "flex justify-between items-center",
+                    h2 { id: "rules-reference-title", class: "text-white text-lg m-0", "📖 Rules Reference" }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| on_close.call(()),
+                        class: "bg-transparent border-0 text-gray-400 text-2xl cursor-pointer",
+                        "×"
+                    }
+                }
+
+                input {
+                    r#type: "search",
+                    value: "{query}",
+                    placeholder: "Search rules, stats, skills...",
+                    aria_label: "Search rules",
+                    oninput: move |e| query.set(e.value()),
+                    class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                }
+
+                // House rule form
+                if let Some((id, title, body)) = editing.read().clone() {
+                    div {
+                        class: "flex flex-col gap-2 p-3 bg-dark-bg rounded-lg",
+                        input {
+                            value: "{title}",
+                            placeholder: "Rule title",
+                            aria_label: "House rule title",
+                            oninput: move |e| {
+                                if let Some(draft) = editing.write().as_mut() {
+                                    draft.1 = e.value();
+                                }
+                            },
+                            class: "w-full p-2 bg-dark-surface border border-gray-700 rounded text-white text-sm box-border",
+                        }
+                        textarea {
+                            value: "{body}",
+                            placeholder: "How it works at this table",
+                            aria_label: "House rule text",
+                            rows: 4,
+                            oninput: move |e| {
+                                if let Some(draft) = editing.write().as_mut() {
+                                    draft.2 = e.value();
+                                }
+                            },
+                            class: "w-full p-2 bg-dark-surface border border-gray-700 rounded text-white text-sm box-border resize-y",
+                        }
+                        if let Some(error) = edit_error.read().as_ref() {
+                            p { role: "alert", class: "text-red-500 text-xs m-0", "{error}" }
+                        }
+                        div {
+                            class: "flex justify-end gap-2",
+                            button {
+                                onclick: move |_| {
+                                    editing.set(None);
+                                    edit_error.set(None);
+                                },
+                                class: "py-1.5 px-3 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm",
+                                "Cancel"
+                            }
+                            button {
+                                onclick: {
+                                    let mut save_rules = save_rules.clone();
+                                    move |_| {
+                                        match HouseRule::new(id.clone(), &title, &body) {
+                                            Ok(rule) => {
+                                                let mut rules = house_rules.read().clone();
+                                                upsert_house_rule(&mut rules, rule);
+                                                save_rules(rules);
+                                                editing.set(None);
+                                                edit_error.set(None);
+                                            }
+                                            Err(e) => edit_error.set(Some(e)),
+                                        }
+                                    }
+                                },
+                                class: "py-1.5 px-3 bg-blue-500 text-white border-none rounded cursor-pointer text-sm",
+                                "Save Rule"
+                            }
+                        }
+                    }
+                } else {
+                    button {
+                        onclick: move |_| {
+                            editing.set(Some((uuid::Uuid::new_v4().to_string(), String::new(), String::new())));
+                        },
+                        class: "py-1.5 px-3 bg-transparent text-blue-400 border border-dashed border-gray-700 rounded cursor-pointer text-sm",
+                        "+ Add House Rule"
+                    }
+                }
+
+                if matches.is_empty() {
+                    p { class: "text-gray-500 text-sm text-center m-0 py-4", "No rules match \"{query}\"" }
+                }
+
+                for category in RuleCategory::all() {
+                    if matches.iter().any(|e| e.category == category) {
+                        section {
+                            key: "{category.display_name()}",
+                            class: "flex flex-col gap-1",
+                            h3 { class: "text-gray-400 text-xs uppercase m-0 mb-1", "{category.display_name()}" }
+                            for entry in matches.iter().filter(|e| e.category == category) {
+                                div {
+                                    key: "{entry.id}",
+                                    class: "p-2 bg-dark-bg rounded",
+                                    div {
+                                        class: "flex justify-between items-start gap-2",
+                                        span { class: "text-white text-sm font-medium", "{entry.title}" }
+                                        if let Some(rule_id) = entry.house_rule_id.clone() {
+                                            div {
+                                                class: "flex gap-1 flex-shrink-0",
+                                                button {
+                                                    onclick: {
+                                                        let rule_id = rule_id.clone();
+                                                        move |_| {
+                                                            let rule = house_rules.read().iter().find(|r| r.id == rule_id).cloned();
+                                                            if let Some(rule) = rule {
+                                                                editing.set(Some((rule.id, rule.title, rule.body)));
+                                                                edit_error.set(None);
+                                                            }
+                                                        }
+                                                    },
+                                                    aria_label: "Edit house rule",
+                                                    class: "bg-transparent border-0 text-gray-400 cursor-pointer text-xs",
+                                                    "Edit"
+                                                }
+                                                button {
+                                                    onclick: {
+                                                        let mut save_rules = save_rules.clone();
+                                                        move |_| {
+                                                            let mut rules = house_rules.read().clone();
+                                                            rules.retain(|r| r.id != rule_id);
+                                                            save_rules(rules);
+                                                        }
+                                                    },
+                                                    aria_label: "Delete house rule",
+                                                    class: "bg-transparent border-0 text-red-400 cursor-pointer text-xs",
+                                                    "Delete"
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if !entry.body.is_empty() {
+                                        p { class: "text-gray-400 text-xs m-0 mt-1 whitespace-pre-wrap", "{entry.body}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Scene mood control - DM sets lighting and weather for the region on stage
+//!
+//! Sliders preview on the Director's stage while dragging and go to the
+//! Engine on release; presets and weather buttons go right away. The Engine
+//! saves the mood with the region and broadcasts it to every client.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{SceneMood, Weather, MAX_MOOD_LEVEL};
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::{use_game_state, use_session_state};
+
+/// Tint shown in the color picker before one is chosen
+const DEFAULT_TINT: &str = "#1e3a8a";
+
+/// Presets, tint, darkness, vignette and weather for the current region
+#[component]
+pub fn SceneMoodControl() -> Element {
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+
+    let Some(region) = game_state.current_region.read().clone() else {
+        return rsx! {
+            p { class: "text-gray-500 text-sm m-0", "No region on stage" }
+        };
+    };
+    let mood = region.mood.clone();
+    let has_client = session_state.has_client();
+
+    let preview = {
+        let mut game_state = game_state.clone();
+        let region_id = region.id.clone();
+        move |next: SceneMood| game_state.set_region_mood(&region_id, next.normalized())
+    };
+
+    let engine_client = session_state.engine_client();
+    let send = {
+        let preview = preview.clone();
+        let region_id = region.id.clone();
+        move |next: SceneMood| {
+            let mut preview = preview.clone();
+            let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+                return;
+            };
+            let next = next.normalized();
+            match SessionCommandService::new(client).set_scene_mood(&region_id, next.clone()) {
+                Ok(()) => preview(next),
+                Err(e) => tracing::error!("Failed to set scene mood: {}", e),
+            }
+        }
+    };
+
+    let tint_value = mood.tint.clone().unwrap_or_else(|| DEFAULT_TINT.to_string());
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-3 text-sm",
+
+            div {
+                class: "flex items-center justify-between gap-2",
+                span { class: "text-white truncate", "{region.name}" }
+                span { class: "text-gray-500 text-xs truncate", "{mood.summary()}" }
+            }
+
+            // Presets
+            div {
+                class: "flex flex-wrap gap-1",
+                for (label, preset) in SceneMood::presets() {
+                    button {
+                        key: "{label}",
+                        r#type: "button",
+                        disabled: !has_client,
+                        class: if preset == mood {
+                            "px-2 py-1 bg-purple-600 text-white border border-purple-500 rounded text-xs cursor-pointer"
+                        } else {
+                            "px-2 py-1 bg-dark-bg text-gray-300 border border-gray-700 rounded text-xs cursor-pointer disabled:opacity-50"
+                        },
+                        onclick: {
+                            let send = send.clone();
+                            move |_| {
+                                send(preset.clone());
+                            }
+                        },
+                        "{label}"
+                    }
+                }
+            }
+
+            // Tint
+            div {
+                class: "flex items-center gap-2",
+                label { class: "text-gray-400 text-xs w-16", "Tint" }
+                input {
+                    r#type: "color",
+                    value: "{tint_value}",
+                    disabled: !has_client,
+                    aria_label: "Tint color",
+                    class: "w-8 h-6 p-0 bg-transparent border-0 cursor-pointer",
+                    onchange: {
+                        let send = send.clone();
+                        let mood = mood.clone();
+                        move |e: FormEvent| {
+                            // Picking a color with no strength would show nothing
+                            let tint_strength = if mood.tint_strength == 0 { 30 } else { mood.tint_strength };
+                            send(SceneMood { tint: Some(e.value()), tint_strength, ..mood.clone() });
+                        }
+                    },
+                }
+                MoodSlider {
+                    label: "Tint strength",
+                    value: mood.tint_strength,
+                    disabled: !has_client,
+                    on_input: {
+                        let preview = preview.clone();
+                        let mood = mood.clone();
+                        let tint = mood.tint.clone().or_else(|| Some(DEFAULT_TINT.to_string()));
+                        move |tint_strength| {
+                            let mut preview = preview.clone();
+                            preview(SceneMood { tint: tint.clone(), tint_strength, ..mood.clone() });
+                        }
+                    },
+                    on_change: {
+                        let send = send.clone();
+                        let mood = mood.clone();
+                        let tint = mood.tint.clone().or_else(|| Some(DEFAULT_TINT.to_string()));
+                        move |tint_strength| {
+                            send(SceneMood { tint: tint.clone(), tint_strength, ..mood.clone() });
+                        }
+                    },
+                }
+            }
+
+            div {
+                class: "flex items-center gap-2",
+                label { class: "text-gray-400 text-xs w-16", "Darkness" }
+                MoodSlider {
+                    label: "Darkness",
+                    value: mood.darkness,
+                    disabled: !has_client,
+                    on_input: {
+                        let preview = preview.clone();
+                        let mood = mood.clone();
+                        move |darkness| {
+                            let mut preview = preview.clone();
+                            preview(SceneMood { darkness, ..mood.clone() });
+                        }
+                    },
+                    on_change: {
+                        let send = send.clone();
+                        let mood = mood.clone();
+                        move |darkness| {
+                            send(SceneMood { darkness, ..mood.clone() });
+                        }
+                    },
+                }
+            }
+
+            div {
+                class: "flex items-center gap-2",
+                label { class: "text-gray-400 text-xs w-16", "Vignette" }
+                MoodSlider {
+                    label: "Vignette",
+                    value: mood.vignette,
+                    disabled: !has_client,
+                    on_input: {
+                        let preview = preview.clone();
+                        let mood = mood.clone();
+                        move |vignette| {
+                            let mut preview = preview.clone();
+                            preview(SceneMood { vignette, ..mood.clone() });
+                        }
+                    },
+                    on_change: {
+                        let send = send.clone();
+                        let mood = mood.clone();
+                        move |vignette| {
+                            send(SceneMood { vignette, ..mood.clone() });
+                        }
+                    },
+                }
+            }
+
+            // Weather
+            div {
+                class: "flex gap-1",
+                role: "radiogroup",
+                aria_label: "Weather",
+                for weather in Weather::all() {
+                    button {
+                        key: "{weather.display_name()}",
+                        r#type: "button",
+                        role: "radio",
+                        aria_checked: "{weather == mood.weather}",
+                        disabled: !has_client,
+                        title: "{weather.display_name()}",
+                        class: if weather == mood.weather {
+                            "flex-1 py-1 bg-purple-600 text-white border border-purple-500 rounded text-xs cursor-pointer"
+                        } else {
+                            "flex-1 py-1 bg-dark-bg text-gray-300 border border-gray-700 rounded text-xs cursor-pointer disabled:opacity-50"
+                        },
+                        onclick: {
+                            let send = send.clone();
+                            let mood = mood.clone();
+                            move |_| {
+                                send(SceneMood { weather, ..mood.clone() });
+                            }
+                        },
+                        "{weather.icon()} {weather.display_name()}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 0-100 range with its value, previewing while dragged
+#[component]
+fn MoodSlider(
+    label: String,
+    value: u8,
+    disabled: bool,
+    on_input: EventHandler<u8>,
+    on_change: EventHandler<u8>,
+) -> Element {
+    rsx! {
+        input {
+            r#type: "range",
+            min: "0",
+            max: "{MAX_MOOD_LEVEL}",
+            value: "{value}",
+            disabled,
+            aria_label: "{label}",
+            class: "flex-1 accent-purple-500 cursor-pointer disabled:opacity-50",
+            oninput: move |e: FormEvent| {
+                if let Ok(v) = e.value().parse::<u8>() {
+                    on_input.call(v);
+                }
+            },
+            onchange: move |e: FormEvent| {
+                if let Ok(v) = e.value().parse::<u8>() {
+                    on_change.call(v);
+                }
+            },
+        }
+        span { class: "text-gray-500 text-xs w-8 text-right", "{value}%" }
+    }
+}
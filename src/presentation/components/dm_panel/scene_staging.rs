@@ -0,0 +1,220 @@
+//! Scene staging - Run parallel scenes while the party is split
+//!
+//! The DM stages scenes from the world and puts each player in one of
+//! them. Players then see only their own scene, while the Director panel
+//! switches between scenes with the tabs above the preview. Every change
+//! sends the whole set to the Engine, which broadcasts it back.
+
+use dioxus::prelude::*;
+
+use crate::application::services::{SceneStaging, SessionCommandService};
+use crate::presentation::state::{use_game_state, use_session_state};
+
+/// Tabs for switching the Director panel between staged scenes
+///
+/// Shown only while more than one scene is running.
+#[component]
+pub fn SceneSwitcher() -> Element {
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+    let mut staging_signal = session_state.scene_staging;
+
+    let staging = staging_signal.read().clone();
+    if !staging.is_split() {
+        return rsx! {};
+    }
+    let focused_id = staging.focused().map(|s| s.scene_id.clone());
+    let engine_client = session_state.engine_client();
+
+    rsx! {
+        div {
+            role: "tablist",
+            aria_label: "Staged scenes",
+            class: "flex gap-1 p-1 bg-dark-surface rounded-lg overflow-x-auto",
+            for scene in staging.scenes().iter().cloned() {
+                {
+                    let active = focused_id.as_deref() == Some(scene.scene_id.as_str());
+                    let players = scene.user_ids.len();
+                    let scene_id = scene.scene_id.clone();
+                    let mut game_state = game_state.clone();
+                    rsx! {
+                        button {
+                            key: "{scene.scene_id}",
+                            role: "tab",
+                            aria_selected: active,
+                            onclick: move |_| {
+                                if !staging_signal.write().focus(&scene_id) {
+                                    return;
+                                }
+                                if let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) {
+                                    if let Err(e) = SessionCommandService::new(client).focus_staged_scene(&scene_id) {
+                                        tracing::error!("Failed to switch scene: {}", e);
+                                    }
+                                }
+                                // Nothing to show until the scene's first update arrives
+                                game_state.show_staged_scene(&scene_id);
+                            },
+                            class: if active {
+                                "px-3 py-1.5 bg-gray-700 text-white border-none rounded-md text-sm cursor-pointer whitespace-nowrap"
+                            } else {
+                                "px-3 py-1.5 bg-transparent text-gray-400 border-none rounded-md text-sm cursor-pointer whitespace-nowrap"
+                            },
+                            "{scene.scene_name} ({players})"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Stage scenes and assign players to them
+#[component]
+pub fn SceneStagingPanel() -> Element {
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+    let mut staging_signal = session_state.scene_staging;
+    let mut to_stage = use_signal(String::new);
+
+    let staging = staging_signal.read().clone();
+    let has_client = session_state.has_client();
+    let members = session_state.party.members.read().clone();
+    let world_scenes: Vec<(String, String)> = game_state
+        .world
+        .read()
+        .as_ref()
+        .map(|w| {
+            w.scenes
+                .iter()
+                .filter(|s| !staging.scenes().iter().any(|staged| staged.scene_id == s.id))
+                .map(|s| (s.id.clone(), s.name.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let engine_client = session_state.engine_client();
+    let mut apply = move |next: SceneStaging| {
+        let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+            return;
+        };
+        match SessionCommandService::new(client).set_staged_scenes(next.scenes().to_vec()) {
+            Ok(()) => staging_signal.set(next),
+            Err(e) => tracing::error!("Failed to update staged scenes: {}", e),
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 text-sm",
+
+            if staging.scenes().is_empty() {
+                p { class: "text-gray-500 text-xs m-0", "Everyone shares one scene. Stage two or more to split the party." }
+            }
+
+            for scene in staging.scenes().iter().cloned() {
+                div {
+                    key: "{scene.scene_id}",
+                    class: "p-2 bg-dark-bg rounded flex items-center gap-2",
+                    span { class: "text-white flex-1 truncate", "{scene.scene_name}" }
+                    span { class: "text-gray-500 text-xs", "{scene.user_ids.len()} player(s)" }
+                    button {
+                        r#type: "button",
+                        aria_label: "Unstage {scene.scene_name}",
+                        disabled: !has_client,
+                        onclick: {
+                            let staging = staging.clone();
+                            let scene_id = scene.scene_id.clone();
+                            move |_| {
+                                let mut next = staging.clone();
+                                next.unstage(&scene_id);
+                                apply(next);
+                            }
+                        },
+                        class: "px-2 py-0.5 bg-transparent text-gray-400 border-none cursor-pointer disabled:opacity-50",
+                        "×"
+                    }
+                }
+            }
+
+            if !world_scenes.is_empty() {
+                div {
+                    class: "flex gap-2",
+                    select {
+                        aria_label: "Scene to stage",
+                        value: "{to_stage}",
+                        onchange: move |e| to_stage.set(e.value()),
+                        class: "flex-1 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                        option { value: "", "Choose a scene..." }
+                        for (id, name) in world_scenes.iter() {
+                            option { key: "{id}", value: "{id}", "{name}" }
+                        }
+                    }
+                    button {
+                        r#type: "button",
+                        disabled: !has_client || to_stage.read().is_empty(),
+                        onclick: {
+                            let staging = staging.clone();
+                            let world_scenes = world_scenes.clone();
+                            move |_| {
+                                let id = to_stage.read().clone();
+                                let Some((_, name)) = world_scenes.iter().find(|(scene_id, _)| *scene_id == id) else {
+                                    return;
+                                };
+                                let mut next = staging.clone();
+                                next.stage(&id, name);
+                                apply(next);
+                                to_stage.set(String::new());
+                            }
+                        },
+                        class: "px-3 py-1 bg-transparent text-blue-400 border border-blue-500/40 rounded cursor-pointer text-sm disabled:opacity-50",
+                        "Stage"
+                    }
+                }
+            }
+
+            if !staging.scenes().is_empty() {
+                h4 { class: "text-gray-400 text-xs uppercase mt-2 mb-0", "Who is where" }
+                if members.is_empty() {
+                    p { class: "text-gray-500 text-xs m-0", "No player characters in the session" }
+                }
+                for member in members.into_iter() {
+                    {
+                        let current = staging
+                            .scene_of(&member.user_id)
+                            .map(|s| s.scene_id.clone())
+                            .unwrap_or_default();
+                        rsx! {
+                            label {
+                                key: "{member.pc_id}",
+                                class: "flex items-center gap-2 text-gray-300",
+                                span { class: "flex-1 truncate", "{member.name}" }
+                                select {
+                                    aria_label: "Scene for {member.name}",
+                                    value: "{current}",
+                                    disabled: !has_client,
+                                    onchange: {
+                                        let staging = staging.clone();
+                                        let user_id = member.user_id.clone();
+                                        move |e: Event<FormData>| {
+                                            let mut next = staging.clone();
+                                            match e.value().as_str() {
+                                                "" => next.unassign(&user_id),
+                                                scene_id => next.assign(&user_id, scene_id),
+                                            }
+                                            apply(next);
+                                        }
+                                    },
+                                    class: "w-40 p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs disabled:opacity-50",
+                                    option { value: "", "Not assigned" }
+                                    for scene in staging.scenes().iter() {
+                                        option { key: "{scene.scene_id}", value: "{scene.scene_id}", "{scene.scene_name}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
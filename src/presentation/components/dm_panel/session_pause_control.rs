@@ -0,0 +1,93 @@
+//! Session pause control - "Pause World" for the Director
+//!
+//! Pausing shows players an intermission screen (with an optional message
+//! and artwork) and blocks their input until the DM resumes.
+
+use dioxus::prelude::*;
+
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::{use_game_state, use_session_state};
+
+/// Pause/resume control for the Director panel
+#[component]
+pub fn SessionPauseControl() -> Element {
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+
+    let mut message = use_signal(String::new);
+    let mut art_asset = use_signal(String::new);
+
+    let intermission = game_state.intermission.read().clone();
+    let has_client = session_state.has_client();
+
+    let command_service = move || {
+        session_state
+            .engine_client()
+            .read()
+            .as_ref()
+            .map(|client| SessionCommandService::new(std::sync::Arc::clone(client)))
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+
+            if let Some(ref paused) = intermission {
+                div {
+                    class: "p-2 bg-amber-500/10 border border-amber-500 rounded text-amber-500 text-sm",
+                    "⏸ World paused"
+                    if let Some(ref m) = paused.message {
+                        div { class: "text-gray-400 text-xs mt-1 italic", "“{m}”" }
+                    }
+                }
+                button {
+                    class: "p-2 bg-green-600 text-white border-none rounded-lg cursor-pointer disabled:opacity-50",
+                    disabled: !has_client,
+                    onclick: {
+                        let command_service = command_service.clone();
+                        move |_| {
+                            if let Some(svc) = command_service() {
+                                if let Err(e) = svc.resume_session() {
+                                    tracing::error!("Failed to resume session: {}", e);
+                                }
+                            }
+                        }
+                    },
+                    "▶ Resume"
+                }
+            } else {
+                textarea {
+                    class: "w-full h-16 p-2 bg-dark-bg border border-gray-700 rounded-lg text-white text-sm resize-y box-border",
+                    placeholder: "Intermission message (optional)",
+                    value: "{message}",
+                    oninput: move |e| message.set(e.value()),
+                }
+                input {
+                    r#type: "text",
+                    class: "w-full p-2 bg-dark-bg border border-gray-700 rounded-lg text-white text-sm box-border",
+                    placeholder: "Artwork asset path (optional)",
+                    value: "{art_asset}",
+                    oninput: move |e| art_asset.set(e.value()),
+                }
+                button {
+                    class: "p-2 bg-amber-600 text-white border-none rounded-lg cursor-pointer disabled:opacity-50",
+                    disabled: !has_client,
+                    onclick: move |_| {
+                        let msg = message.read().trim().to_string();
+                        let art = art_asset.read().trim().to_string();
+                        if let Some(svc) = command_service() {
+                            let result = svc.pause_session(
+                                (!msg.is_empty()).then_some(msg.as_str()),
+                                (!art.is_empty()).then_some(art.as_str()),
+                            );
+                            if let Err(e) = result {
+                                tracing::error!("Failed to pause session: {}", e);
+                            }
+                        }
+                    },
+                    "⏸ Pause World"
+                }
+            }
+        }
+    }
+}
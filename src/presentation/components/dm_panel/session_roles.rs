@@ -0,0 +1,95 @@
+//! Session roles - promote participants to co-DM from the Director
+//!
+//! A co-DM shares the Director panel (dialogue approval, challenges, scene
+//! direction) but cannot edit world entities or manage the session.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::ParticipantRole;
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::use_session_state;
+
+/// Participant list with co-DM assignment for the Director panel
+#[component]
+pub fn SessionRolesPanel() -> Element {
+    let session_state = use_session_state();
+
+    let roles = session_state.participant_roles().read().clone();
+    let participants = session_state.presence.participants.read().clone();
+    let has_client = session_state.has_client();
+
+    let reconnecting = session_state.reconnecting.read().clone();
+
+    // Everyone with a known role, labelled by character name when they have one
+    let mut roster: Vec<(String, String, ParticipantRole, bool)> = roles
+        .into_iter()
+        .filter(|(_, role)| *role != ParticipantRole::DungeonMaster)
+        .map(|(user_id, role)| {
+            let name = participants
+                .iter()
+                .find(|p| p.user_id == user_id)
+                .map(|p| p.display_name().to_string())
+                .unwrap_or_else(|| user_id.clone());
+            let is_reconnecting = reconnecting.iter().any(|r| r.user_id == user_id);
+            (user_id, name, role, is_reconnecting)
+        })
+        .collect();
+    roster.sort_by(|a, b| a.1.cmp(&b.1));
+
+    let engine_client = session_state.engine_client();
+    let assign = move |user_id: String, role: ParticipantRole| {
+        let client = engine_client.read().clone();
+        if let Some(client) = client {
+            if let Err(e) = SessionCommandService::new(client).assign_role(&user_id, role) {
+                tracing::error!("Failed to assign role: {}", e);
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+
+            if roster.is_empty() {
+                div { class: "text-gray-500 text-sm italic", "No one else has joined yet" }
+            }
+
+            for (user_id, name, role, is_reconnecting) in roster.into_iter() {
+                div {
+                    key: "{user_id}",
+                    class: "flex items-center gap-2 p-2 bg-dark-bg rounded",
+                    span { class: "text-white text-sm flex-1 truncate", "{name}" }
+                    // Roles can't change hands while a player is reconnecting
+                    if is_reconnecting {
+                        span { class: "text-xs text-orange-400", "reconnecting…" }
+                    }
+                    span {
+                        class: if role == ParticipantRole::CoDungeonMaster { "text-xs text-amber-500" } else { "text-xs text-gray-500" },
+                        "{role.display_name()}"
+                    }
+                    if role == ParticipantRole::CoDungeonMaster {
+                        button {
+                            class: "px-2 py-1 bg-transparent text-red-400 border border-gray-700 rounded text-xs cursor-pointer disabled:opacity-50",
+                            disabled: !has_client || is_reconnecting,
+                            onclick: {
+                                let user_id = user_id.clone();
+                                move |_| assign(user_id.clone(), ParticipantRole::Player)
+                            },
+                            "Revoke"
+                        }
+                    } else {
+                        button {
+                            class: "px-2 py-1 bg-transparent text-amber-500 border border-gray-700 rounded text-xs cursor-pointer disabled:opacity-50",
+                            disabled: !has_client || is_reconnecting,
+                            onclick: {
+                                let user_id = user_id.clone();
+                                move |_| assign(user_id.clone(), ParticipantRole::CoDungeonMaster)
+                            },
+                            "Make Co-DM"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Shared note editor - Scene notes and NPC motivations edited by DM and co-DMs
+//!
+//! Typing updates the note here at once and goes to the Engine after a short
+//! pause; the Engine keeps the last write and broadcasts it. Other editors
+//! show up by name with the line they are on, and the author of the latest
+//! revision is named under the note. If someone else's edit arrives over
+//! unsent changes, a merge preview lets the DM keep theirs, mine, or both.
+
+use std::sync::Arc;
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{EntityMention, SharedNoteDoc};
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{
+    attribution, edit_position, line_at, merge_preview, MergeSide, SessionCommandService, SharedNote,
+    NOTE_SEND_DELAY_MS,
+};
+use crate::presentation::components::common::MentionTextarea;
+use crate::presentation::state::{use_game_state, use_session_state};
+
+/// Textarea for one shared note, with presence, attribution and conflicts
+#[component]
+pub fn SharedNoteEditor(
+    doc: SharedNoteDoc,
+    /// Entities that can be `@`-mentioned
+    #[props(default)]
+    candidates: Vec<EntityMention>,
+    #[props(default)]
+    placeholder: String,
+    /// Classes for the textarea itself
+    #[props(default)]
+    class: String,
+) -> Element {
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+    let platform = use_context::<Platform>();
+    let engine_client = session_state.engine_client();
+    // Bumped on every keystroke; only the last one's timer sends
+    let mut pending_send = use_signal(|| 0u64);
+
+    // Leaving the editor clears our cursor for the others
+    {
+        let doc = doc.clone();
+        use_drop(move || {
+            if let Some(client) = engine_client.peek().as_ref().map(Arc::clone) {
+                let _ = SessionCommandService::new(client).send_shared_note_cursor(doc.clone(), None);
+            }
+        });
+    }
+
+    let note = game_state.shared_note(&doc);
+    let cursors = game_state.note_cursors(&doc);
+    let author = attribution(&note.synced);
+    let conflict_author = note
+        .conflict
+        .as_ref()
+        .and_then(|c| c.editor_name.clone())
+        .unwrap_or_else(|| "Someone".to_string());
+
+    // Send the note's local text, if it still needs sending
+    let send_note = {
+        let doc = doc.clone();
+        let game_state = game_state.clone();
+        move || {
+            let note: SharedNote = game_state.shared_note(&doc);
+            if !note.needs_send() {
+                return;
+            }
+            let Some(client) = engine_client.peek().as_ref().map(Arc::clone) else {
+                return;
+            };
+            if let Err(e) = SessionCommandService::new(client).edit_shared_note(doc.clone(), &note.local, note.synced.revision) {
+                tracing::error!("Failed to send shared note: {}", e);
+            }
+        }
+    };
+
+    let on_change = {
+        let doc = doc.clone();
+        let mut game_state = game_state.clone();
+        let send_note = send_note.clone();
+        move |text: String| {
+            let position = edit_position(&game_state.shared_note(&doc).local, &text);
+            game_state.update_shared_note(&doc, |note| note.local = text);
+            if let Some(client) = engine_client.peek().as_ref().map(Arc::clone) {
+                let _ = SessionCommandService::new(client).send_shared_note_cursor(doc.clone(), Some(position));
+            }
+
+            let generation = *pending_send.peek() + 1;
+            pending_send.set(generation);
+            let platform = platform.clone();
+            let send_note = send_note.clone();
+            spawn(async move {
+                platform.sleep_ms(NOTE_SEND_DELAY_MS).await;
+                if *pending_send.peek() == generation {
+                    send_note();
+                }
+            });
+        }
+    };
+
+    let resolve = {
+        let doc = doc.clone();
+        let mut game_state = game_state.clone();
+        move |resolution: fn(&mut SharedNote)| {
+            let send_note = send_note.clone();
+            game_state.update_shared_note(&doc, resolution);
+            send_note();
+        }
+    };
+
+    rsx! {
+        div {
+            class: "shared-note-editor flex flex-col gap-1",
+
+            // Who else is here
+            if !cursors.is_empty() {
+                div {
+                    class: "flex flex-wrap gap-1",
+                    aria_live: "polite",
+                    for cursor in cursors.iter() {
+                        span {
+                            key: "{cursor.user_id}",
+                            class: "px-2 py-0.5 bg-purple-500/20 text-purple-300 rounded text-xs",
+                            "✎ {cursor.user_name} · line {line_at(&note.local, cursor.position)}"
+                        }
+                    }
+                }
+            }
+
+            MentionTextarea {
+                value: note.local.clone(),
+                on_change: on_change,
+                candidates: candidates,
+                placeholder: placeholder,
+                class: class,
+            }
+
+            if let Some(theirs) = note.conflict.as_ref() {
+                div {
+                    role: "alert",
+                    class: "flex flex-col gap-2 p-2 bg-amber-500/10 border border-amber-500/40 rounded-lg",
+                    p {
+                        class: "text-amber-400 text-xs m-0",
+                        "{conflict_author} changed this note while you were typing."
+                    }
+                    div {
+                        class: "max-h-32 overflow-y-auto bg-dark-bg rounded p-2 font-mono text-xs",
+                        for (i, line) in merge_preview(&note.local, &theirs.text).into_iter().enumerate() {
+                            div {
+                                key: "{i}",
+                                class: "{side_class(line.side)} whitespace-pre-wrap",
+                                "{side_marker(line.side)}{line.text}"
+                            }
+                        }
+                    }
+                    div {
+                        class: "flex gap-2 text-xs",
+                        span { class: "text-green-400", "+ yours" }
+                        span { class: "text-blue-400", "~ theirs" }
+                    }
+                    div {
+                        class: "flex gap-2 justify-end",
+                        button {
+                            onclick: {
+                                let mut resolve = resolve.clone();
+                                move |_| resolve(SharedNote::take_theirs)
+                            },
+                            class: "py-1 px-2 bg-transparent text-gray-300 border border-gray-700 rounded cursor-pointer text-xs",
+                            "Use theirs"
+                        }
+                        button {
+                            onclick: {
+                                let mut resolve = resolve.clone();
+                                move |_| resolve(SharedNote::keep_mine)
+                            },
+                            class: "py-1 px-2 bg-transparent text-gray-300 border border-gray-700 rounded cursor-pointer text-xs",
+                            "Keep mine"
+                        }
+                        button {
+                            onclick: {
+                                let mut resolve = resolve.clone();
+                                move |_| resolve(SharedNote::merge_both)
+                            },
+                            class: "py-1 px-2 bg-amber-500 text-white border-none rounded cursor-pointer text-xs",
+                            "Merge both"
+                        }
+                    }
+                }
+            }
+
+            if let Some(author) = author {
+                span { class: "text-gray-500 text-xs", "{author}" }
+            }
+        }
+    }
+}
+
+/// Text color of a merge preview line
+fn side_class(side: MergeSide) -> &'static str {
+    match side {
+        MergeSide::Both => "text-gray-400",
+        MergeSide::Mine => "text-green-400 bg-green-500/10",
+        MergeSide::Theirs => "text-blue-400 bg-blue-500/10",
+    }
+}
+
+/// Gutter mark of a merge preview line
+fn side_marker(side: MergeSide) -> &'static str {
+    match side {
+        MergeSide::Both => "  ",
+        MergeSide::Mine => "+ ",
+        MergeSide::Theirs => "~ ",
+    }
+}
@@ -0,0 +1,183 @@
+//! Social graph - Every character's ties at a glance
+//!
+//! Loads the world's relationships and lists them per character, from
+//! either end, with the kind and strength set in the relationship editor.
+//! Kind chips narrow the view, e.g. to debts only.
+
+use std::collections::BTreeMap;
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{EntityStyle, RelationshipData, RelationshipKind};
+use crate::presentation::components::common::{accent_stripe, EntityMark};
+use crate::presentation::services::use_relationship_service;
+use crate::presentation::state::{use_game_state, use_modal_focus};
+
+/// Modal listing the world's relationships, grouped by character
+#[component]
+pub fn SocialGraphModal(world_id: String, on_close: EventHandler<()>) -> Element {
+    use_modal_focus("social-graph");
+
+    let game_state = use_game_state();
+    let relationship_service = use_relationship_service();
+    let mut relationships: Signal<Vec<RelationshipData>> = use_signal(Vec::new);
+    let mut is_loading = use_signal(|| true);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    // None shows every kind
+    let mut kind_filter: Signal<Option<RelationshipKind>> = use_signal(|| None);
+
+    {
+        let world_id = world_id.clone();
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let svc = relationship_service.clone();
+            spawn(async move {
+                match svc.list_relationships(&world_id).await {
+                    Ok(list) => relationships.set(list),
+                    Err(e) => error.set(Some(format!("Failed to load relationships: {}", e))),
+                }
+                is_loading.set(false);
+            });
+        });
+    }
+
+    let names: BTreeMap<String, String> = game_state
+        .world
+        .read()
+        .as_ref()
+        .map(|w| w.characters.iter().map(|c| (c.id.clone(), c.name.clone())).collect())
+        .unwrap_or_default();
+    let name_of = |id: &str| names.get(id).cloned().unwrap_or_else(|| "Unknown character".to_string());
+    let styles: BTreeMap<String, EntityStyle> = game_state
+        .world
+        .read()
+        .as_ref()
+        .map(|w| w.characters.iter().map(|c| (c.id.clone(), c.style.clone())).collect())
+        .unwrap_or_default();
+    let style_of = |id: &str| styles.get(id).cloned().unwrap_or_default();
+
+    // Each relationship appears under both of its ends
+    let filter = *kind_filter.read();
+    // Keyed by (name, id) so the list reads alphabetically
+    let mut by_character: BTreeMap<(String, String), Vec<RelationshipData>> = BTreeMap::new();
+    for relationship in relationships.read().iter().filter(|r| filter.is_none_or(|k| r.kind() == k)) {
+        for end in [&relationship.from_character_id, &relationship.to_character_id] {
+            by_character.entry((name_of(end), end.clone())).or_default().push(relationship.clone());
+        }
+    }
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/80 flex items-center justify-center z-[1100] p-4",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                id: "social-graph",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "social-graph-title",
+                class: "bg-dark-surface rounded-xl w-full max-w-3xl max-h-[90vh] overflow-y-auto p-4 flex flex-col gap-3",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        on_close.call(());
+                    }
+                },
+
+                div {
+                    class: "flex justify-between items-center",
+                    h2 { id: "social-graph-title", class: "text-white text-lg m-0", "Social Graph" }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| on_close.call(()),
+                        class: "bg-transparent border-0 text-gray-400 text-2xl cursor-pointer",
+                        "×"
+                    }
+                }
+
+                div {
+                    class: "flex flex-wrap gap-1",
+                    role: "radiogroup",
+                    aria_label: "Relationship kind",
+                    button {
+                        r#type: "button",
+                        role: "radio",
+                        aria_checked: if filter.is_none() { "true" } else { "false" },
+                        onclick: move |_| kind_filter.set(None),
+                        class: if filter.is_none() {
+                            "px-2 py-1 bg-purple-500 text-white border border-purple-500 rounded text-xs cursor-pointer"
+                        } else {
+                            "px-2 py-1 bg-dark-bg text-gray-300 border border-gray-700 rounded text-xs cursor-pointer"
+                        },
+                        "All"
+                    }
+                    for kind in RelationshipKind::all() {
+                        button {
+                            r#type: "button",
+                            role: "radio",
+                            aria_checked: if filter == Some(kind) { "true" } else { "false" },
+                            onclick: move |_| kind_filter.set(Some(kind)),
+                            class: if filter == Some(kind) {
+                                "px-2 py-1 bg-purple-500 text-white border border-purple-500 rounded text-xs cursor-pointer"
+                            } else {
+                                "px-2 py-1 bg-dark-bg text-gray-300 border border-gray-700 rounded text-xs cursor-pointer"
+                            },
+                            "{kind.icon()} {kind.display_name()}"
+                        }
+                    }
+                }
+
+                if let Some(msg) = error.read().as_ref() {
+                    div { class: "p-2 bg-red-500/10 rounded text-red-500 text-sm", role: "alert", "{msg}" }
+                }
+
+                if *is_loading.read() {
+                    p { class: "text-gray-500 text-sm m-0", "Loading relationships..." }
+                } else if by_character.is_empty() {
+                    p { class: "text-gray-500 text-sm m-0", "No relationships yet. Add them from a character's Relationships section in the creator." }
+                }
+
+                for ((name, character_id), ties) in by_character.into_iter() {
+                    div {
+                        key: "{character_id}",
+                        class: "p-3 bg-dark-bg rounded-lg",
+                        style: accent_stripe(style_of(&character_id).accent_color.as_deref()),
+                        h3 {
+                            class: "flex items-center gap-2 text-white text-sm m-0 mb-2",
+                            EntityMark { style: style_of(&character_id) }
+                            "{name}"
+                        }
+                        for tie in ties.into_iter() {
+                            {
+                                let kind = tie.kind();
+                                let direction = if tie.from_character_id == character_id { "→" } else { "←" };
+                                let other = name_of(tie.other_end(&character_id));
+                                let other_style = style_of(tie.other_end(&character_id));
+                                let strength = "●".repeat(tie.strength as usize);
+                                rsx! {
+                                    div {
+                                        key: "{tie.id}",
+                                        class: "flex items-center gap-2 text-xs py-0.5",
+                                        span { title: "{kind.display_name()}", "{kind.icon()}" }
+                                        span { class: "text-gray-300", "{direction}" }
+                                        EntityMark { style: other_style }
+                                        span { class: "text-gray-300", "{other}" }
+                                        span { class: "text-gray-500", "{kind.display_name()}" }
+                                        span { class: "text-amber-400", title: "Strength {tie.strength}/5", "{strength}" }
+                                        if !tie.known_to_player {
+                                            span { class: "text-gray-600", title: "Hidden from players", "🔒" }
+                                        }
+                                        if !tie.notes.is_empty() {
+                                            span { class: "text-gray-500 italic truncate", "{tie.notes}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
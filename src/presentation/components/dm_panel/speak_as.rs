@@ -0,0 +1,96 @@
+//! Speak As - DM makes a scene NPC say a line directly
+//!
+//! For scripted moments the DM can put words in an NPC's mouth without
+//! going through the LLM. The Engine delivers the line like any other
+//! dialogue response, so it reaches the conversation log from there.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::websocket_messages::SceneCharacterState;
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::use_session_state;
+
+/// Compose box for an immediate NPC line
+#[component]
+pub fn SpeakAsForm(characters: Vec<SceneCharacterState>) -> Element {
+    let session_state = use_session_state();
+    let mut npc_id = use_signal(String::new);
+    let mut text = use_signal(String::new);
+    let mut record_as_approved = use_signal(|| true);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let engine_client = session_state.engine_client();
+    let has_client = session_state.has_client();
+
+    // Default to the first NPC, and drop a selection that left the scene
+    let selected = npc_id.read().clone();
+    let selected = if characters.iter().any(|c| c.id == selected) {
+        selected
+    } else {
+        characters.first().map(|c| c.id.clone()).unwrap_or_default()
+    };
+    let can_send = has_client && !selected.is_empty() && !text.read().trim().is_empty();
+
+    let send = {
+        let selected = selected.clone();
+        move |_| {
+            let line = text.read().trim().to_string();
+            if line.is_empty() || selected.is_empty() {
+                return;
+            }
+            let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+                return;
+            };
+            match SessionCommandService::new(client).speak_as(&selected, &line, *record_as_approved.read()) {
+                Ok(()) => {
+                    error.set(None);
+                    text.set(String::new());
+                }
+                Err(e) => error.set(Some(format!("Failed to send line: {}", e))),
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 mt-3 pt-3 border-t border-gray-700",
+            div { class: "text-gray-400 text-xs uppercase", "Speak as NPC" }
+            select {
+                value: "{selected}",
+                onchange: move |e| npc_id.set(e.value()),
+                class: "p-2 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                for character in characters.iter() {
+                    option { key: "{character.id}", value: "{character.id}", "{character.name}" }
+                }
+            }
+            textarea {
+                value: "{text}",
+                oninput: move |e| text.set(e.value()),
+                placeholder: "What should they say?",
+                class: "w-full min-h-[60px] p-2 bg-dark-bg border border-gray-700 rounded text-white text-sm resize-y box-border italic",
+            }
+            div {
+                class: "flex items-center gap-2",
+                label {
+                    class: "flex items-center gap-1.5 text-gray-400 text-xs cursor-pointer",
+                    title: "Add the line to the decision history as an approved response",
+                    input {
+                        r#type: "checkbox",
+                        checked: *record_as_approved.read(),
+                        onchange: move |e| record_as_approved.set(e.checked()),
+                    }
+                    "Record as approved"
+                }
+                button {
+                    onclick: send,
+                    disabled: !can_send,
+                    class: "ml-auto px-3 py-1.5 bg-blue-500 text-white border-none rounded text-sm cursor-pointer disabled:opacity-50 disabled:cursor-not-allowed",
+                    "Say it"
+                }
+            }
+            if let Some(err) = error.read().as_ref() {
+                p { class: "text-red-400 text-xs m-0", "{err}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Spectator policy control - curate what spectators see
+//!
+//! Lets the Director hide whispers, challenge numbers and DM notes from
+//! spectators, and delay their feed so it can't be used to coach players.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::SpectatorPolicy;
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::use_session_state;
+
+/// Longest feed delay offered, in seconds
+const MAX_FEED_DELAY_SECS: u32 = 300;
+
+/// Spectator visibility settings for the Director panel
+#[component]
+pub fn SpectatorPolicyControl() -> Element {
+    let session_state = use_session_state();
+    let mut policy_signal = session_state.spectator_policy;
+
+    let policy = *policy_signal.read();
+    let has_client = session_state.has_client();
+
+    let engine_client = session_state.engine_client();
+    let mut apply = move |next: SpectatorPolicy| {
+        let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+            return;
+        };
+        match SessionCommandService::new(client).set_spectator_policy(next) {
+            Ok(()) => policy_signal.set(next),
+            Err(e) => tracing::error!("Failed to update spectator policy: {}", e),
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2 text-sm",
+
+            PolicyToggle {
+                label: "Hide whispers",
+                checked: policy.hide_whispers,
+                disabled: !has_client,
+                on_change: move |on| apply(SpectatorPolicy { hide_whispers: on, ..policy }),
+            }
+            PolicyToggle {
+                label: "Hide challenge numbers",
+                checked: policy.hide_challenge_numbers,
+                disabled: !has_client,
+                on_change: move |on| apply(SpectatorPolicy { hide_challenge_numbers: on, ..policy }),
+            }
+            PolicyToggle {
+                label: "Hide DM notes",
+                checked: policy.hide_dm_notes,
+                disabled: !has_client,
+                on_change: move |on| apply(SpectatorPolicy { hide_dm_notes: on, ..policy }),
+            }
+
+            label {
+                class: "flex items-center gap-2 text-gray-300",
+                "Feed delay"
+                input {
+                    r#type: "number",
+                    min: "0",
+                    max: "{MAX_FEED_DELAY_SECS}",
+                    value: "{policy.feed_delay_secs}",
+                    disabled: !has_client,
+                    onchange: move |e| {
+                        if let Ok(secs) = e.value().parse::<u32>() {
+                            apply(SpectatorPolicy {
+                                feed_delay_secs: secs.min(MAX_FEED_DELAY_SECS),
+                                ..policy
+                            });
+                        }
+                    },
+                    class: "w-20 p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm disabled:opacity-50",
+                }
+                span { class: "text-gray-500 text-xs", "seconds" }
+            }
+        }
+    }
+}
+
+/// One checkbox row
+#[component]
+fn PolicyToggle(
+    label: &'static str,
+    checked: bool,
+    disabled: bool,
+    on_change: EventHandler<bool>,
+) -> Element {
+    rsx! {
+        label {
+            class: "flex items-center gap-2 text-gray-300 cursor-pointer",
+            input {
+                r#type: "checkbox",
+                checked: checked,
+                disabled: disabled,
+                onchange: move |e| on_change.call(e.checked()),
+            }
+            "{label}"
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Story capture - Sending auto-captured story events, and quick markers
+//!
+//! The session message handler queues story events on the DM's client as
+//! the session plays out. `StoryCaptureSync` loads the world's capture rules
+//! (and its challenge auto-resolve rules, from the same settings) and sends
+//! the queue to the Engine in batches; `QuickMarkerControl` lets
+//! the DM drop a marker on the timeline without leaving the Director.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::presentation::services::{use_settings_service, use_story_event_service};
+use crate::presentation::state::use_session_state;
+
+/// How often queued story events are sent
+const FLUSH_MS: u64 = 10_000;
+
+/// Props for StoryCaptureSync
+#[derive(Props, Clone, PartialEq)]
+pub struct StoryCaptureSyncProps {
+    pub world_id: String,
+}
+
+/// Loads the capture rules and sends captured events; renders nothing
+#[component]
+pub fn StoryCaptureSync(props: StoryCaptureSyncProps) -> Element {
+    let session_state = use_session_state();
+    let settings_service = use_settings_service();
+    let story_event_service = use_story_event_service();
+    let platform = use_context::<Platform>();
+
+    {
+        let world_id = props.world_id.clone();
+        let mut story_capture = session_state.story_capture;
+        let mut auto_resolve = session_state.challenge_auto_resolve();
+        let session_id = session_state.session_id();
+        // Reloaded as each session starts, before its challenges come in;
+        // until then nothing is auto-applied
+        use_effect(move || {
+            session_id.read();
+            let world_id = world_id.clone();
+            let svc = settings_service.clone();
+            spawn(async move {
+                match svc.get_for_world(&world_id).await {
+                    Ok(settings) => {
+                        story_capture.write().set_rules(settings.story_capture);
+                        auto_resolve.set(settings.challenge_auto_resolve);
+                    }
+                    Err(e) => tracing::warn!("Failed to load story capture rules: {}", e),
+                }
+            });
+        });
+    }
+
+    // Send whatever has been captured every FLUSH_MS. Failed batches go
+    // back on the queue and are retried on the next pass.
+    {
+        let world_id = props.world_id.clone();
+        let mut story_capture = session_state.story_capture;
+        let session_id = session_state.session_id();
+        use_hook(move || {
+            spawn(async move {
+                loop {
+                    platform.sleep_ms(FLUSH_MS).await;
+                    if story_capture.peek().pending_count() == 0 {
+                        continue;
+                    }
+                    let events = story_capture.write().drain();
+                    let session_id = session_id.peek().clone();
+                    if let Err(e) = story_event_service
+                        .create_story_events(&world_id, session_id.as_deref(), &events)
+                        .await
+                    {
+                        tracing::warn!("Failed to send {} captured story events: {}", events.len(), e);
+                        story_capture.write().requeue(events);
+                    }
+                }
+            })
+        });
+    }
+
+    rsx! {}
+}
+
+/// Quick marker input for the Director panel
+///
+/// Markers are explicit, so co-DMs can drop them too.
+#[component]
+pub fn QuickMarkerControl() -> Element {
+    let session_state = use_session_state();
+    let platform = use_context::<Platform>();
+    let mut story_capture = session_state.story_capture;
+    let mut title = use_signal(String::new);
+
+    let (enabled, pending) = {
+        let capture = story_capture.read();
+        (capture.rules().dm_markers, capture.pending_count())
+    };
+
+    if !enabled {
+        return rsx! {
+            p { class: "m-0 text-gray-500 text-xs italic", "Quick markers are turned off in World Settings." }
+        };
+    }
+
+    let mut add_marker = move |platform: &Platform| {
+        let text = title.read().clone();
+        if story_capture.write().record_dm_marker(&text, "", platform.now_unix_secs()) {
+            title.set(String::new());
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            div {
+                class: "flex gap-2",
+                input {
+                    r#type: "text",
+                    class: "flex-1 min-w-0 p-2 bg-dark-bg border border-gray-700 rounded-lg text-white text-sm box-border",
+                    placeholder: "Mark this moment...",
+                    value: "{title}",
+                    oninput: move |e| title.set(e.value()),
+                    onkeydown: {
+                        let platform = platform.clone();
+                        move |e: KeyboardEvent| {
+                            if e.key() == Key::Enter {
+                                add_marker(&platform);
+                            }
+                        }
+                    },
+                }
+                button {
+                    class: "px-3 py-2 bg-purple-600 text-white border-none rounded-lg cursor-pointer text-sm disabled:opacity-50",
+                    disabled: title.read().trim().is_empty(),
+                    onclick: move |_| add_marker(&platform),
+                    "📍 Mark"
+                }
+            }
+            if pending > 0 {
+                span { class: "text-gray-500 text-xs", "{pending} event(s) waiting to be added to the timeline" }
+            }
+        }
+    }
+}
@@ -5,6 +5,7 @@
 use dioxus::prelude::*;
 use crate::application::dto::ChallengeData;
 use crate::application::dto::websocket_messages::SceneCharacterState;
+use crate::presentation::state::use_modal_focus;
 
 /// Props for TriggerChallengeModal
 #[derive(Props, Clone, PartialEq)]
@@ -27,6 +28,7 @@ pub struct TriggerChallengeModalProps {
 /// - Trigger the challenge
 #[component]
 pub fn TriggerChallengeModal(props: TriggerChallengeModalProps) -> Element {
+    use_modal_focus("trigger-modal");
     let mut selected_challenge = use_signal(|| String::new());
     let mut selected_character = use_signal(|| String::new());
 
@@ -49,6 +51,8 @@ pub fn TriggerChallengeModal(props: TriggerChallengeModalProps) -> Element {
             // Modal content
             div {
                 id: "trigger-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "bg-gradient-to-br from-dark-surface to-dark-bg p-8 rounded-2xl max-w-[500px] w-[90%] border-2 border-amber-500",
                 onclick: move |evt| evt.stop_propagation(),
 
@@ -62,6 +66,7 @@ pub fn TriggerChallengeModal(props: TriggerChallengeModalProps) -> Element {
                     }
 
                     button {
+                        aria_label: "Close",
                         onclick: move |_| props.on_close.call(()),
                         class: "bg-transparent border-0 text-gray-400 cursor-pointer text-2xl p-0",
                         "×"
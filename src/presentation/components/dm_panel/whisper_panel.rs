@@ -0,0 +1,184 @@
+//! Whisper panel - private player messages in the Director
+//!
+//! Shows one thread per player with unread badges and an inline reply box,
+//! separate from the shared conversation log.
+
+use dioxus::prelude::*;
+
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::use_session_state;
+
+/// Canned replies for answering quickly mid-scene
+const QUICK_REPLIES: &[&str] = &[
+    "Noted 👍",
+    "Yes, you can try that.",
+    "Not right now.",
+    "Roll for it.",
+    "Let's talk after the scene.",
+];
+
+/// Player whisper threads with quick-reply for the Director
+#[component]
+pub fn WhisperPanel() -> Element {
+    let session_state = use_session_state();
+    let whispers = session_state.whispers.clone();
+    let engine_client = session_state.engine_client();
+
+    let mut selected: Signal<Option<String>> = use_signal(|| None);
+    let mut draft = use_signal(String::new);
+
+    let threads = whispers.threads();
+    // Default to the most recent thread
+    let active = selected
+        .read()
+        .clone()
+        .filter(|id| threads.iter().any(|t| &t.player_user_id == id))
+        .or_else(|| threads.first().map(|t| t.player_user_id.clone()));
+    let messages = active
+        .as_deref()
+        .map(|id| whispers.thread(id))
+        .unwrap_or_default();
+    // The Engine holds replies to a disconnected player until they're back
+    let active_reconnecting = active
+        .as_deref()
+        .is_some_and(|id| session_state.reconnecting.read().iter().any(|r| r.user_id == id));
+
+    // Opening a thread clears its badge; the default thread stays unread
+    // until the DM picks it, since the panel may be off screen
+    {
+        let mut whispers = whispers.clone();
+        use_effect(move || {
+            let opened = selected.read().clone();
+            // Subscribe to new messages in the open thread
+            let _ = whispers.messages.read().len();
+            if let Some(id) = opened {
+                whispers.mark_read(&id);
+            }
+        });
+    }
+
+    let reply = {
+        let active = active.clone();
+        move |text: String| {
+            let text = text.trim().to_string();
+            let (Some(to), false) = (active.clone(), text.is_empty()) else {
+                return;
+            };
+            let Some(client) = engine_client.read().clone() else {
+                return;
+            };
+            let mut draft = draft;
+            let mut selected = selected;
+            match SessionCommandService::new(client).reply_to_whisper(&to, &text) {
+                Ok(()) => {
+                    draft.set(String::new());
+                    // Replying means the thread has been read
+                    selected.set(Some(to));
+                }
+                Err(e) => tracing::error!("Failed to send whisper reply: {}", e),
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+
+            if threads.is_empty() {
+                div { class: "text-gray-500 text-sm italic", "No whispers yet" }
+            } else {
+                // Thread tabs
+                div {
+                    class: "flex flex-wrap gap-1",
+                    for thread in threads.iter() {
+                        button {
+                            key: "{thread.player_user_id}",
+                            class: if active.as_deref() == Some(thread.player_user_id.as_str()) {
+                                "flex items-center gap-1 px-2 py-1 bg-purple-600 text-white border-none rounded text-xs cursor-pointer"
+                            } else {
+                                "flex items-center gap-1 px-2 py-1 bg-dark-bg text-gray-300 border-none rounded text-xs cursor-pointer"
+                            },
+                            onclick: {
+                                let id = thread.player_user_id.clone();
+                                move |_| selected.set(Some(id.clone()))
+                            },
+                            "{thread.display_name}"
+                            if thread.unread > 0 {
+                                span {
+                                    class: "bg-amber-500 text-white rounded-xl px-1.5 text-[0.625rem] font-bold",
+                                    "{thread.unread}"
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Messages
+                div {
+                    class: "flex flex-col gap-1 max-h-[200px] overflow-y-auto",
+                    for message in messages.iter() {
+                        div {
+                            key: "{message.id}",
+                            class: if message.from_dm {
+                                "self-end max-w-[85%] px-2 py-1 bg-blue-500/30 text-white rounded text-sm"
+                            } else {
+                                "self-start max-w-[85%] px-2 py-1 bg-purple-500/20 text-purple-100 rounded text-sm"
+                            },
+                            "{message.text}"
+                        }
+                    }
+                }
+
+                if active_reconnecting {
+                    div {
+                        class: "text-orange-400 text-xs",
+                        "Reconnecting… replies will be delivered when they're back"
+                    }
+                }
+
+                // Quick replies
+                div {
+                    class: "flex flex-wrap gap-1",
+                    for text in QUICK_REPLIES.iter() {
+                        button {
+                            key: "{text}",
+                            class: "px-2 py-0.5 bg-transparent text-gray-400 border border-gray-700 rounded-full text-xs cursor-pointer hover:text-white",
+                            onclick: {
+                                let reply = reply.clone();
+                                move |_| reply(text.to_string())
+                            },
+                            "{text}"
+                        }
+                    }
+                }
+
+                div {
+                    class: "flex gap-2",
+                    input {
+                        r#type: "text",
+                        class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded-lg text-white text-sm",
+                        placeholder: "Reply privately...",
+                        value: "{draft}",
+                        oninput: move |e| draft.set(e.value()),
+                        onkeydown: {
+                            let reply = reply.clone();
+                            move |e: KeyboardEvent| {
+                                if e.key() == Key::Enter {
+                                    reply(draft.read().clone());
+                                }
+                            }
+                        },
+                    }
+                    button {
+                        class: "px-3 py-2 bg-purple-600 text-white border-none rounded-lg cursor-pointer text-sm",
+                        onclick: {
+                            let reply = reply.clone();
+                            move |_| reply(draft.read().clone())
+                        },
+                        "Reply"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! World update review - Diff of a mid-session world snapshot
+//!
+//! When the world is edited during play the new snapshot is held back and
+//! the DM sees what it changes. They can apply it now or let it land at the
+//! next scene break.
+
+use dioxus::prelude::*;
+
+use crate::application::services::{SnapshotChange, SnapshotChangeKind, SnapshotEntityKind};
+use crate::presentation::state::use_game_state;
+
+/// Entity kinds in the order they're listed
+const ENTITY_ORDER: [SnapshotEntityKind; 5] = [
+    SnapshotEntityKind::Character,
+    SnapshotEntityKind::Location,
+    SnapshotEntityKind::Challenge,
+    SnapshotEntityKind::Scene,
+    SnapshotEntityKind::Item,
+];
+
+/// Review card for a pending world snapshot
+///
+/// Renders nothing when no update is waiting.
+#[component]
+pub fn WorldUpdateReview() -> Element {
+    let game_state = use_game_state();
+    let Some(pending) = game_state.pending_world.read().clone() else {
+        return rsx! {};
+    };
+
+    let diff = pending.diff;
+    let summary = format!(
+        "{} new · {} modified · {} removed",
+        diff.count(SnapshotChangeKind::Added),
+        diff.count(SnapshotChangeKind::Modified),
+        diff.count(SnapshotChangeKind::Removed),
+    );
+    let groups: Vec<(SnapshotEntityKind, Vec<SnapshotChange>)> = ENTITY_ORDER
+        .iter()
+        .map(|kind| (*kind, diff.changes_for(*kind).into_iter().cloned().collect::<Vec<_>>()))
+        .filter(|(_, changes)| !changes.is_empty())
+        .collect();
+
+    rsx! {
+        div {
+            class: "panel-section bg-dark-surface rounded-lg p-4 border border-blue-500/40",
+
+            h3 { class: "text-blue-300 mb-1 text-sm uppercase", "🌍 World Updated" }
+            p { class: "text-gray-400 text-xs mb-3", "{summary}" }
+
+            if diff.world_changed {
+                div { class: "text-gray-300 text-sm mb-2", "World name or description changed" }
+            }
+
+            for (kind, changes) in groups.into_iter() {
+                div {
+                    key: "{kind.display_name()}",
+                    class: "mb-2",
+                    div { class: "text-gray-500 text-xs uppercase mb-1", "{kind.display_name()}s" }
+                    for change in changes.iter() {
+                        div {
+                            key: "{change.id}",
+                            class: "flex items-center gap-2 text-sm",
+                            span {
+                                class: change_badge_class(change.change),
+                                "{change.change.display_name()}"
+                            }
+                            span { class: "text-white", "{change.name}" }
+                        }
+                    }
+                }
+            }
+
+            div { class: "flex gap-2 mt-3",
+                button {
+                    class: "flex-1 p-2 bg-green-600 text-white border-none rounded-lg cursor-pointer text-sm",
+                    onclick: {
+                        let mut game_state = game_state.clone();
+                        move |_| game_state.apply_pending_world()
+                    },
+                    "Apply Now"
+                }
+                if pending.deferred {
+                    div {
+                        class: "flex-1 p-2 text-amber-400 text-sm text-center",
+                        "Applies at next scene"
+                    }
+                } else {
+                    button {
+                        class: "flex-1 p-2 bg-gray-700 text-white border-none rounded-lg cursor-pointer text-sm",
+                        onclick: {
+                            let mut game_state = game_state.clone();
+                            move |_| game_state.defer_pending_world()
+                        },
+                        "Defer to Scene Break"
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn change_badge_class(change: SnapshotChangeKind) -> &'static str {
+    match change {
+        SnapshotChangeKind::Added => "px-1.5 rounded text-[0.625rem] font-bold bg-green-500/20 text-green-400",
+        SnapshotChangeKind::Modified => "px-1.5 rounded text-[0.625rem] font-bold bg-amber-500/20 text-amber-400",
+        SnapshotChangeKind::Removed => "px-1.5 rounded text-[0.625rem] font-bold bg-red-500/20 text-red-400",
+    }
+}
@@ -2,10 +2,11 @@
 //!
 //! US-NPC-008: ApproachEventOverlay - NPC approaching player
 //! US-NPC-009: LocationEventBanner - Location-wide events
+//! IntermissionOverlay - Session paused by the DM
 
 use dioxus::prelude::*;
 
-use crate::presentation::state::{ApproachEventData, LocationEventData};
+use crate::presentation::state::{use_template_context, ApproachEventData, IntermissionData, LocationEventData, use_modal_focus};
 
 // =============================================================================
 // US-NPC-008: Approach Event Overlay
@@ -26,6 +27,9 @@ pub struct ApproachEventOverlayProps {
 /// with the approach description and a Continue button.
 #[component]
 pub fn ApproachEventOverlay(props: ApproachEventOverlayProps) -> Element {
+    use_modal_focus("approach-event");
+    let description = use_template_context().resolve(&props.event.description);
+
     rsx! {
         // Semi-transparent overlay
         div {
@@ -34,6 +38,9 @@ pub fn ApproachEventOverlay(props: ApproachEventOverlayProps) -> Element {
 
             // Event card
             div {
+                id: "approach-event",
+                role: "dialog",
+                aria_modal: "true",
                 class: "approach-event-card bg-gradient-to-br from-dark-surface to-dark-bg rounded-2xl max-w-lg w-full overflow-hidden shadow-2xl border border-amber-500/30 animate-slide-in",
                 onclick: move |e| e.stop_propagation(),
 
@@ -87,7 +94,7 @@ pub fn ApproachEventOverlay(props: ApproachEventOverlayProps) -> Element {
 
                         p {
                             class: "text-gray-200 leading-relaxed m-0 italic",
-                            "{props.event.description}"
+                            "{description}"
                         }
                     }
 
@@ -122,6 +129,9 @@ pub struct LocationEventBannerProps {
 /// auto-dismisses after a timeout or on click.
 #[component]
 pub fn LocationEventBanner(props: LocationEventBannerProps) -> Element {
+    use_modal_focus("location-event");
+    let description = use_template_context().resolve(&props.event.description);
+
     rsx! {
         // Full-screen click target (semi-transparent)
         div {
@@ -130,6 +140,9 @@ pub fn LocationEventBanner(props: LocationEventBannerProps) -> Element {
 
             // Banner card
             div {
+                id: "location-event",
+                role: "dialog",
+                aria_modal: "true",
                 class: "location-event-banner bg-gradient-to-r from-blue-900/90 via-dark-surface/95 to-blue-900/90 rounded-xl max-w-2xl w-full p-6 shadow-2xl border border-blue-500/30 backdrop-blur-sm animate-slide-down",
                 onclick: move |e| e.stop_propagation(),
 
@@ -152,7 +165,7 @@ pub fn LocationEventBanner(props: LocationEventBannerProps) -> Element {
                 // Description
                 p {
                     class: "text-gray-100 text-lg leading-relaxed m-0 italic",
-                    "{props.event.description}"
+                    "{description}"
                 }
 
                 // Dismiss hint
@@ -164,3 +177,63 @@ pub fn LocationEventBanner(props: LocationEventBannerProps) -> Element {
         }
     }
 }
+
+// =============================================================================
+// Intermission Overlay
+// =============================================================================
+
+/// Props for IntermissionOverlay
+#[derive(Props, Clone, PartialEq)]
+pub struct IntermissionOverlayProps {
+    /// The intermission data
+    pub intermission: IntermissionData,
+}
+
+/// Full-screen overlay shown while the DM has paused the session
+///
+/// Blocks all input to the view beneath it. Cannot be dismissed by the
+/// player; it disappears when the DM resumes.
+#[component]
+pub fn IntermissionOverlay(props: IntermissionOverlayProps) -> Element {
+    let message = props
+        .intermission
+        .message
+        .clone()
+        .unwrap_or_else(|| "The story will continue shortly.".to_string());
+
+    rsx! {
+        div {
+            class: "intermission-overlay fixed inset-0 z-[880] flex flex-col items-center justify-center bg-black/90 animate-fade-in",
+            onclick: move |e| e.stop_propagation(),
+
+            // Optional artwork behind the title
+            if let Some(ref art) = props.intermission.art_asset {
+                img {
+                    src: "{art}",
+                    alt: "Intermission",
+                    class: "absolute inset-0 w-full h-full object-cover opacity-30",
+                }
+            }
+
+            div {
+                class: "relative flex flex-col items-center gap-4 max-w-xl p-8 text-center",
+
+                div {
+                    class: "text-[#d4af37] text-5xl font-['Cinzel',serif] tracking-widest",
+                    "Intermission"
+                }
+                div {
+                    class: "w-32 h-px bg-gradient-to-r from-transparent via-[#d4af37] to-transparent",
+                }
+                p {
+                    class: "text-gray-200 text-lg leading-relaxed italic m-0 whitespace-pre-wrap",
+                    "{message}"
+                }
+                p {
+                    class: "text-gray-500 text-xs uppercase tracking-wider m-0 animate-pulse",
+                    "Paused by the Game Master"
+                }
+            }
+        }
+    }
+}
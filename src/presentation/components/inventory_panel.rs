@@ -1,10 +1,19 @@
 //! Inventory Panel - Player UI for viewing and managing inventory
 //!
 //! US-CHAR-009: Player inventory with equipped items and actions.
+//! Recipes the held items can make are listed under Crafting. Each item
+//! opens a detail view with its art, provenance and, once identified, the
+//! DM's hidden notes; the DM's copy of the panel edits and reveals those,
+//! and sets the item's icon and colour.
 
 use dioxus::prelude::*;
 
-use crate::application::dto::InventoryItemData;
+use crate::application::dto::{
+    held_quantity, CraftOutcome, CraftingRecipe, EntityStyle, EquipmentSlotDefinition, InventoryItemData,
+};
+use crate::application::services::slot_for_item;
+use crate::presentation::components::common::{EntityMark, EntityStylePicker};
+use crate::presentation::state::use_modal_focus;
 
 /// Props for the InventoryPanel component
 #[derive(Props, Clone, PartialEq)]
@@ -13,6 +22,9 @@ pub struct InventoryPanelProps {
     pub character_name: String,
     /// Inventory items
     pub items: Vec<InventoryItemData>,
+    /// Equipment slots from the world's rule system
+    #[props(default)]
+    pub slots: Vec<EquipmentSlotDefinition>,
     /// Whether data is still loading
     #[props(default = false)]
     pub is_loading: bool,
@@ -27,11 +39,40 @@ pub struct InventoryPanelProps {
     /// Handler for dropping an item
     #[props(default)]
     pub on_drop_item: Option<EventHandler<String>>,
+    /// The world's crafting recipes
+    #[props(default)]
+    pub recipes: Vec<CraftingRecipe>,
+    /// Handler for attempting a recipe, by id
+    #[props(default)]
+    pub on_craft: Option<EventHandler<String>>,
+    /// How the last craft attempt ended
+    #[props(default)]
+    pub craft_outcome: Option<CraftOutcome>,
+    /// Why the last craft attempt was refused
+    #[props(default)]
+    pub craft_rejection: Option<String>,
+    /// Handler for revealing an item's hidden notes to its holder (DM only)
+    #[props(default)]
+    pub on_identify: Option<EventHandler<String>>,
+    /// Handler for saving an item's hidden notes, as (item ID, notes) (DM only)
+    #[props(default)]
+    pub on_save_hidden_notes: Option<EventHandler<(String, String)>>,
+    /// Handler for saving an item's icon and colour, as (item ID, style) (DM only)
+    #[props(default)]
+    pub on_save_style: Option<EventHandler<(String, EntityStyle)>>,
 }
 
 /// Inventory Panel - modal overlay showing character inventory
 #[component]
 pub fn InventoryPanel(props: InventoryPanelProps) -> Element {
+    let mut detail_item_id: Signal<Option<String>> = use_signal(|| None);
+    let detail_item = detail_item_id
+        .read()
+        .as_ref()
+        .and_then(|id| props.items.iter().find(|i| &i.item.id == id))
+        .cloned();
+    let on_details = EventHandler::new(move |item_id: String| detail_item_id.set(Some(item_id)));
+
     // Group items by type
     let equipped_items: Vec<_> = props.items.iter().filter(|i| i.equipped).collect();
     let weapon_items: Vec<_> = props.items.iter().filter(|i| !i.equipped && i.is_weapon()).collect();
@@ -42,14 +83,14 @@ pub fn InventoryPanel(props: InventoryPanelProps) -> Element {
     }).collect();
 
     rsx! {
-        // Overlay background
+        // Overlay background; a bottom sheet below the md breakpoint
         div {
-            class: "inventory-overlay fixed inset-0 bg-black/85 z-[1000] flex items-center justify-center p-4",
+            class: "inventory-overlay fixed inset-0 bg-black/85 z-[1000] flex items-end md:items-center justify-center md:p-4",
             onclick: move |_| props.on_close.call(()),
 
             // Panel container
             div {
-                class: "inventory-panel bg-gradient-to-br from-dark-surface to-dark-bg rounded-2xl w-full max-w-2xl max-h-[85vh] overflow-hidden flex flex-col shadow-2xl border border-amber-500/20",
+                class: "inventory-panel bg-gradient-to-br from-dark-surface to-dark-bg rounded-t-2xl md:rounded-2xl w-full max-w-2xl max-h-[85vh] overflow-hidden flex flex-col shadow-2xl border border-amber-500/20",
                 onclick: move |e| e.stop_propagation(),
 
                 // Header
@@ -108,9 +149,11 @@ pub fn InventoryPanel(props: InventoryPanelProps) -> Element {
                                     title: "Equipped",
                                     icon: "*",
                                     items: equipped_items.into_iter().cloned().collect(),
+                                    slots: props.slots.clone(),
                                     on_use: props.on_use_item.clone(),
                                     on_toggle_equip: props.on_toggle_equip.clone(),
                                     on_drop: props.on_drop_item.clone(),
+                                    on_details,
                                 }
                             }
 
@@ -120,9 +163,11 @@ pub fn InventoryPanel(props: InventoryPanelProps) -> Element {
                                     title: "Weapons",
                                     icon: "+",
                                     items: weapon_items.into_iter().cloned().collect(),
+                                    slots: props.slots.clone(),
                                     on_use: props.on_use_item.clone(),
                                     on_toggle_equip: props.on_toggle_equip.clone(),
                                     on_drop: props.on_drop_item.clone(),
+                                    on_details,
                                 }
                             }
 
@@ -132,9 +177,11 @@ pub fn InventoryPanel(props: InventoryPanelProps) -> Element {
                                     title: "Consumables",
                                     icon: "o",
                                     items: consumable_items.into_iter().cloned().collect(),
+                                    slots: props.slots.clone(),
                                     on_use: props.on_use_item.clone(),
                                     on_toggle_equip: props.on_toggle_equip.clone(),
                                     on_drop: props.on_drop_item.clone(),
+                                    on_details,
                                 }
                             }
 
@@ -144,9 +191,11 @@ pub fn InventoryPanel(props: InventoryPanelProps) -> Element {
                                     title: "Key Items",
                                     icon: "#",
                                     items: key_items.into_iter().cloned().collect(),
+                                    slots: props.slots.clone(),
                                     on_use: props.on_use_item.clone(),
                                     on_toggle_equip: props.on_toggle_equip.clone(),
                                     on_drop: props.on_drop_item.clone(),
+                                    on_details,
                                 }
                             }
 
@@ -156,9 +205,128 @@ pub fn InventoryPanel(props: InventoryPanelProps) -> Element {
                                     title: "Items",
                                     icon: ".",
                                     items: other_items.into_iter().cloned().collect(),
+                                    slots: props.slots.clone(),
                                     on_use: props.on_use_item.clone(),
                                     on_toggle_equip: props.on_toggle_equip.clone(),
                                     on_drop: props.on_drop_item.clone(),
+                                    on_details,
+                                }
+                            }
+                        }
+                    }
+
+                    if !props.is_loading && !props.recipes.is_empty() {
+                        CraftingSection {
+                            recipes: props.recipes.clone(),
+                            items: props.items.clone(),
+                            on_craft: props.on_craft,
+                            outcome: props.craft_outcome.clone(),
+                            rejection: props.craft_rejection.clone(),
+                        }
+                    }
+                }
+            }
+
+            if let Some(item) = detail_item {
+                ItemDetailModal {
+                    key: "{item.item.id}",
+                    item,
+                    on_identify: props.on_identify,
+                    on_save_hidden_notes: props.on_save_hidden_notes,
+                    on_save_style: props.on_save_style,
+                    on_close: move |_| detail_item_id.set(None),
+                }
+            }
+        }
+    }
+}
+
+/// Props for CraftingSection
+#[derive(Props, Clone, PartialEq)]
+struct CraftingSectionProps {
+    recipes: Vec<CraftingRecipe>,
+    items: Vec<InventoryItemData>,
+    on_craft: Option<EventHandler<String>>,
+    outcome: Option<CraftOutcome>,
+    rejection: Option<String>,
+}
+
+/// Recipes with the ingredients held for each, and the last attempt's result
+#[component]
+fn CraftingSection(props: CraftingSectionProps) -> Element {
+    rsx! {
+        div {
+            class: "inventory-section mt-6",
+
+            h3 {
+                class: "text-sm font-semibold text-gray-400 uppercase tracking-wider mb-3 flex items-center gap-2",
+                span { "&" }
+                "Crafting"
+            }
+
+            if let Some(reason) = props.rejection.as_ref() {
+                p {
+                    role: "status",
+                    class: "text-red-400 text-sm m-0 mb-3",
+                    "{reason}"
+                }
+            } else if let Some(outcome) = props.outcome.as_ref() {
+                p {
+                    role: "status",
+                    class: if outcome.success { "text-green-400 text-sm m-0 mb-3" } else { "text-amber-400 text-sm m-0 mb-3" },
+                    "{outcome.summary()}"
+                }
+            }
+
+            div {
+                class: "grid gap-2",
+
+                for recipe in props.recipes.iter() {
+                    {
+                        let can_craft = recipe.can_craft(&props.items);
+                        let recipe_id = recipe.id.clone();
+                        let on_craft = props.on_craft;
+                        rsx! {
+                            div {
+                                key: "{recipe.id}",
+                                class: "bg-black/30 rounded-lg border border-white/10 p-3 flex items-start gap-3",
+
+                                div {
+                                    class: "flex-1 min-w-0",
+                                    div { class: "text-white font-medium", "{recipe.name}" }
+                                    div {
+                                        class: "flex flex-wrap gap-1 mt-1",
+                                        for ingredient in recipe.ingredients.iter() {
+                                            {
+                                                let have = held_quantity(&props.items, &ingredient.item_name);
+                                                let enough = have >= ingredient.quantity;
+                                                rsx! {
+                                                    span {
+                                                        key: "{ingredient.item_name}",
+                                                        class: if enough { "text-xs text-gray-300 bg-white/5 px-1.5 py-0.5 rounded" } else { "text-xs text-red-300 bg-red-500/10 px-1.5 py-0.5 rounded" },
+                                                        "{ingredient.item_name} {have}/{ingredient.quantity}"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    div {
+                                        class: "text-xs text-gray-500 mt-1",
+                                        "Makes {recipe.result.label()}"
+                                        if recipe.skill_id.is_some() {
+                                            " · {recipe.difficulty.display()} check"
+                                        }
+                                    }
+                                }
+
+                                if let Some(handler) = on_craft {
+                                    button {
+                                        class: "px-3 py-1.5 bg-amber-500/20 hover:bg-amber-500/30 text-amber-400 rounded text-sm transition-colors disabled:opacity-40 disabled:cursor-not-allowed",
+                                        disabled: !can_craft,
+                                        title: if can_craft { "" } else { "Missing ingredients" },
+                                        onclick: move |_| handler.call(recipe_id.clone()),
+                                        "Craft"
+                                    }
                                 }
                             }
                         }
@@ -175,9 +343,11 @@ struct InventorySectionProps {
     title: &'static str,
     icon: &'static str,
     items: Vec<InventoryItemData>,
+    slots: Vec<EquipmentSlotDefinition>,
     on_use: Option<EventHandler<String>>,
     on_toggle_equip: Option<EventHandler<String>>,
     on_drop: Option<EventHandler<String>>,
+    on_details: EventHandler<String>,
 }
 
 /// A section of the inventory (e.g., Weapons, Consumables)
@@ -206,9 +376,11 @@ fn InventorySection(props: InventorySectionProps) -> Element {
                     InventoryItemCard {
                         key: "{item.item.id}",
                         item: item.clone(),
+                        slots: props.slots.clone(),
                         on_use: props.on_use.clone(),
                         on_toggle_equip: props.on_toggle_equip.clone(),
                         on_drop: props.on_drop.clone(),
+                        on_details: props.on_details,
                     }
                 }
             }
@@ -220,9 +392,11 @@ fn InventorySection(props: InventorySectionProps) -> Element {
 #[derive(Props, Clone, PartialEq)]
 struct InventoryItemCardProps {
     item: InventoryItemData,
+    slots: Vec<EquipmentSlotDefinition>,
     on_use: Option<EventHandler<String>>,
     on_toggle_equip: Option<EventHandler<String>>,
     on_drop: Option<EventHandler<String>>,
+    on_details: EventHandler<String>,
 }
 
 /// Card displaying a single inventory item
@@ -237,6 +411,21 @@ fn InventoryItemCard(props: InventoryItemCardProps) -> Element {
     };
 
     let item_id = props.item.item.id.clone();
+    let fits_slot = slot_for_item(&props.slots, &props.item).is_some();
+    let equipped_label = props
+        .item
+        .slot
+        .as_ref()
+        .and_then(|id| props.slots.iter().find(|s| &s.id == id))
+        .map(|s| format!("Equipped · {}", s.name))
+        .unwrap_or_else(|| "Equipped".to_string());
+    let modifiers: Vec<String> = props
+        .item
+        .item
+        .modifiers
+        .iter()
+        .map(|m| format!("{:+} {}", m.value, m.target))
+        .collect();
 
     rsx! {
         div {
@@ -251,15 +440,19 @@ fn InventoryItemCard(props: InventoryItemCardProps) -> Element {
                 },
 
                 // Item icon/type indicator
-                span {
-                    class: "text-lg w-6 text-center",
-                    match props.item.item.item_type.as_deref() {
-                        Some("Weapon") => "+",
-                        Some("Consumable") => "o",
-                        Some("Key") => "#",
-                        Some("Quest") => "!",
-                        _ => ".",
+                if props.item.item.style.is_empty() {
+                    span {
+                        class: "text-lg w-6 text-center",
+                        match props.item.item.item_type.as_deref() {
+                            Some("Weapon") => "+",
+                            Some("Consumable") => "o",
+                            Some("Key") => "#",
+                            Some("Quest") => "!",
+                            _ => ".",
+                        }
                     }
+                } else {
+                    EntityMark { style: props.item.item.style.clone() }
                 }
 
                 // Item name and quantity
@@ -274,7 +467,7 @@ fn InventoryItemCard(props: InventoryItemCardProps) -> Element {
                         if props.item.equipped {
                             span {
                                 class: "text-xs text-amber-400/70 bg-amber-500/20 px-1.5 py-0.5 rounded",
-                                "Equipped"
+                                "{equipped_label}"
                             }
                         }
                     }
@@ -321,10 +514,34 @@ fn InventoryItemCard(props: InventoryItemCardProps) -> Element {
                         }
                     }
 
+                    // Bonuses while equipped
+                    if !modifiers.is_empty() {
+                        div {
+                            class: "flex flex-wrap gap-1 mb-3",
+                            for modifier in modifiers.iter() {
+                                span {
+                                    class: "text-xs text-blue-300 bg-blue-500/10 px-1.5 py-0.5 rounded",
+                                    "{modifier}"
+                                }
+                            }
+                        }
+                    }
+
                     // Action buttons
                     div {
                         class: "flex gap-2",
 
+                        {
+                            let id = item_id.clone();
+                            rsx! {
+                                button {
+                                    class: "px-3 py-1.5 bg-white/5 hover:bg-white/10 text-gray-300 rounded text-sm transition-colors",
+                                    onclick: move |_| props.on_details.call(id.clone()),
+                                    "Details"
+                                }
+                            }
+                        }
+
                         // Use button (for consumables)
                         if props.item.is_consumable() {
                             if let Some(ref handler) = props.on_use {
@@ -342,8 +559,8 @@ fn InventoryItemCard(props: InventoryItemCardProps) -> Element {
                             }
                         }
 
-                        // Equip/Unequip button (for weapons, etc.)
-                        if props.item.is_weapon() || props.item.item.item_type.as_deref() == Some("Armor") {
+                        // Equip/Unequip button (for items that fit an equipment slot)
+                        if fits_slot || props.item.equipped {
                             if let Some(ref handler) = props.on_toggle_equip {
                                 {
                                     let handler = handler.clone();
@@ -386,3 +603,200 @@ fn InventoryItemCard(props: InventoryItemCardProps) -> Element {
         }
     }
 }
+
+/// Props for ItemDetailModal
+#[derive(Props, Clone, PartialEq)]
+struct ItemDetailModalProps {
+    item: InventoryItemData,
+    on_identify: Option<EventHandler<String>>,
+    on_save_hidden_notes: Option<EventHandler<(String, String)>>,
+    on_save_style: Option<EventHandler<(String, EntityStyle)>>,
+    on_close: EventHandler<()>,
+}
+
+/// Everything known about one held item; the DM's copy edits its hidden
+/// notes and its icon and colour
+#[component]
+fn ItemDetailModal(props: ItemDetailModalProps) -> Element {
+    use_modal_focus("item-detail-modal");
+    let mut notes_draft = use_signal(|| props.item.item.hidden_notes.clone().unwrap_or_default());
+    let mut style_draft = use_signal(|| props.item.item.style.clone());
+
+    let item = &props.item;
+    let item_id = item.item.id.clone();
+    let is_dm = props.on_save_hidden_notes.is_some();
+    let notes_changed = notes_draft.read().trim() != item.item.hidden_notes.as_deref().unwrap_or_default().trim();
+    let modifiers: Vec<String> = item.item.modifiers.iter().map(|m| format!("{:+} {}", m.value, m.target)).collect();
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/75 z-[1100] flex items-center justify-center p-4",
+            onclick: move |e| {
+                e.stop_propagation();
+                props.on_close.call(());
+            },
+
+            div {
+                id: "item-detail-modal",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "item-detail-modal-title",
+                class: "bg-dark-surface rounded-xl w-full max-w-md max-h-[85vh] overflow-y-auto flex flex-col border border-amber-500/20",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        props.on_close.call(());
+                    }
+                },
+
+                if let Some(url) = item.item.image_url.as_ref() {
+                    img {
+                        src: "{url}",
+                        alt: "{item.item.name}",
+                        class: "w-full max-h-64 object-contain bg-black/40 rounded-t-xl",
+                    }
+                }
+
+                div {
+                    class: "p-5 flex flex-col gap-3",
+
+                    div {
+                        class: "flex items-start gap-3",
+                        if !item.item.style.is_empty() {
+                            EntityMark { style: item.item.style.clone() }
+                        }
+                        div {
+                            class: "flex-1",
+                            h2 { id: "item-detail-modal-title", class: "text-white text-lg m-0", "{item.item.name}" }
+                            p {
+                                class: "text-gray-500 text-xs m-0 mt-1",
+                                "{item.type_display()}"
+                                if item.quantity > 1 { " · x{item.quantity}" }
+                                if item.item.is_unique { " · Unique" }
+                            }
+                        }
+                        button {
+                            aria_label: "Close",
+                            class: "w-8 h-8 flex items-center justify-center bg-white/5 hover:bg-white/10 rounded-lg text-gray-400 hover:text-white transition-colors",
+                            onclick: move |_| props.on_close.call(()),
+                            "x"
+                        }
+                    }
+
+                    if let Some(desc) = item.item.description.as_ref() {
+                        p { class: "text-gray-300 text-sm m-0 leading-relaxed whitespace-pre-line", "{desc}" }
+                    }
+
+                    if item.item.weight.is_some() || item.item.value.is_some() {
+                        div {
+                            class: "flex gap-4 text-sm text-gray-400",
+                            if let Some(weight) = item.item.weight {
+                                span { "Weight " span { class: "text-white", "{weight}" } }
+                            }
+                            if let Some(value) = item.item.value {
+                                span { "Value " span { class: "text-white", "{value}" } }
+                            }
+                        }
+                    }
+
+                    if !modifiers.is_empty() {
+                        div {
+                            class: "flex flex-wrap gap-1",
+                            for modifier in modifiers.iter() {
+                                span { class: "text-xs text-blue-300 bg-blue-500/10 px-1.5 py-0.5 rounded", "{modifier}" }
+                            }
+                        }
+                    }
+
+                    if let Some(provenance) = item.provenance() {
+                        p { class: "text-gray-400 text-xs italic m-0", "{provenance}" }
+                    } else if let Some(method) = item.acquisition_method.as_ref() {
+                        p { class: "text-gray-400 text-xs italic m-0", "{method}" }
+                    }
+
+                    if let Some(handler) = props.on_save_style {
+                        div {
+                            class: "flex flex-col gap-2 pt-3 border-t border-white/10",
+                            span { class: "text-gray-400 text-xs uppercase", "Icon & Colour" }
+                            EntityStylePicker {
+                                style: style_draft.read().clone(),
+                                on_change: move |next| style_draft.set(next),
+                            }
+                            {
+                                let id = item_id.clone();
+                                rsx! {
+                                    button {
+                                        disabled: *style_draft.read() == item.item.style,
+                                        onclick: move |_| handler.call((id.clone(), style_draft.read().clone())),
+                                        class: "self-start px-3 py-1.5 bg-blue-500/20 hover:bg-blue-500/30 text-blue-400 rounded text-sm transition-colors disabled:opacity-40",
+                                        "Save style"
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if is_dm {
+                        div {
+                            class: "flex flex-col gap-2 pt-3 border-t border-white/10",
+                            label {
+                                r#for: "item-hidden-notes",
+                                class: "text-gray-400 text-xs uppercase",
+                                "Hidden notes"
+                                if item.identified { " · identified" } else { " · not identified" }
+                            }
+                            textarea {
+                                id: "item-hidden-notes",
+                                rows: "3",
+                                placeholder: "What the holder learns when the item is identified",
+                                value: "{notes_draft}",
+                                oninput: move |e| notes_draft.set(e.value()),
+                                class: "px-2 py-1 bg-gray-900 border border-gray-700 rounded text-white text-sm resize-y",
+                            }
+                            div {
+                                class: "flex gap-2",
+                                if let Some(handler) = props.on_save_hidden_notes {
+                                    {
+                                        let id = item_id.clone();
+                                        rsx! {
+                                            button {
+                                                disabled: !notes_changed,
+                                                onclick: move |_| handler.call((id.clone(), notes_draft.read().clone())),
+                                                class: "px-3 py-1.5 bg-blue-500/20 hover:bg-blue-500/30 text-blue-400 rounded text-sm transition-colors disabled:opacity-40",
+                                                "Save notes"
+                                            }
+                                        }
+                                    }
+                                }
+                                if let Some(handler) = props.on_identify.filter(|_| !item.identified) {
+                                    {
+                                        let id = item_id.clone();
+                                        rsx! {
+                                            button {
+                                                // The holder receives the saved notes, not the draft
+                                                disabled: notes_changed || !item.is_unidentified(),
+                                                title: if notes_changed { "Save the notes first" } else { "" },
+                                                onclick: move |_| handler.call(id.clone()),
+                                                class: "px-3 py-1.5 bg-amber-500/20 hover:bg-amber-500/30 text-amber-400 rounded text-sm transition-colors disabled:opacity-40",
+                                                "Identify"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    } else if let Some(notes) = item.revealed_notes() {
+                        div {
+                            class: "pt-3 border-t border-white/10",
+                            h3 { class: "text-amber-400 text-xs uppercase m-0 mb-1", "Identified" }
+                            p { class: "text-gray-300 text-sm m-0 leading-relaxed whitespace-pre-line", "{notes}" }
+                        }
+                    } else if item.is_unidentified() {
+                        p { class: "text-gray-500 text-xs m-0 pt-3 border-t border-white/10", "There is more to this item than you know." }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@
 //! US-OBS-004/005: Player view of observed NPCs with last seen info.
 
 use dioxus::prelude::*;
+use crate::presentation::state::use_modal_focus;
 
 /// Observation data for a known NPC
 #[derive(Clone, Debug, PartialEq)]
@@ -36,6 +37,7 @@ pub struct KnownNpcsPanelProps {
 /// Known NPCs Panel - modal showing NPCs the player has observed
 #[component]
 pub fn KnownNpcsPanel(props: KnownNpcsPanelProps) -> Element {
+    use_modal_focus("known-npcs-panel");
     // Group observations by observation type for filtering
     let direct_obs: Vec<_> = props.observations.iter()
         .filter(|o| o.observation_type == "direct")
@@ -55,6 +57,9 @@ pub fn KnownNpcsPanel(props: KnownNpcsPanelProps) -> Element {
 
             // Panel container
             div {
+                id: "known-npcs-panel",
+                role: "dialog",
+                aria_modal: "true",
                 class: "known-npcs-panel bg-gradient-to-br from-dark-surface to-dark-bg rounded-2xl w-full max-w-2xl max-h-[85vh] overflow-hidden flex flex-col shadow-2xl border border-purple-500/20",
                 onclick: move |e| e.stop_propagation(),
 
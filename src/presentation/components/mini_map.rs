@@ -1,8 +1,11 @@
 //! Mini-Map Component - Visual map with clickable regions
 //!
 //! US-NAV-010: Visual map showing regions with click-to-navigate.
+//! With fog of war, regions the player hasn't uncovered are drawn fogged
+//! and nameless; they can still be walked into when a path leads there.
 
 use dioxus::prelude::*;
+use crate::presentation::state::use_modal_focus;
 
 /// Region data for mini-map display (includes bounds)
 #[derive(Clone, Debug, PartialEq)]
@@ -41,6 +44,9 @@ pub struct MiniMapProps {
     pub navigable_region_ids: Vec<String>,
     /// IDs of locked regions
     pub locked_region_ids: Vec<String>,
+    /// IDs of regions the player has uncovered; `None` shows the whole map
+    #[props(default)]
+    pub revealed_region_ids: Option<Vec<String>>,
     /// Whether data is loading
     #[props(default = false)]
     pub is_loading: bool,
@@ -53,6 +59,7 @@ pub struct MiniMapProps {
 /// Mini-Map modal showing location with clickable regions
 #[component]
 pub fn MiniMap(props: MiniMapProps) -> Element {
+    use_modal_focus("mini-map");
     // Calculate the map dimensions based on region bounds
     let (map_width, map_height) = calculate_map_dimensions(&props.regions);
 
@@ -64,6 +71,9 @@ pub fn MiniMap(props: MiniMapProps) -> Element {
 
             // Map container
             div {
+                id: "mini-map",
+                role: "dialog",
+                aria_modal: "true",
                 class: "mini-map-container bg-gradient-to-br from-dark-surface to-dark-bg rounded-2xl w-full max-w-4xl max-h-[90vh] overflow-hidden flex flex-col shadow-2xl border border-blue-500/20",
                 onclick: move |e| e.stop_propagation(),
 
@@ -108,6 +118,13 @@ pub fn MiniMap(props: MiniMapProps) -> Element {
                         span { class: "w-3 h-3 bg-gray-600 rounded-sm inline-block" }
                         "Locked"
                     }
+                    if props.revealed_region_ids.is_some() {
+                        span {
+                            class: "flex items-center gap-1",
+                            span { class: "w-3 h-3 bg-slate-900 border border-slate-600 rounded-sm inline-block" }
+                            "Unexplored"
+                        }
+                    }
                 }
 
                 // Map area
@@ -145,10 +162,13 @@ pub fn MiniMap(props: MiniMapProps) -> Element {
                                         let is_current = props.current_region_id.as_ref() == Some(&region.id);
                                         let is_navigable = props.navigable_region_ids.contains(&region.id);
                                         let is_locked = props.locked_region_ids.contains(&region.id);
+                                        let is_hidden = !is_current && is_fogged(&props.revealed_region_ids, &region.id);
                                         let region_id = region.id.clone();
 
                                         let bg_color = if is_current {
                                             "bg-blue-500/60"
+                                        } else if is_hidden {
+                                            "bg-slate-900/95"
                                         } else if is_locked {
                                             "bg-gray-600/40"
                                         } else if is_navigable {
@@ -159,6 +179,8 @@ pub fn MiniMap(props: MiniMapProps) -> Element {
 
                                         let border_color = if is_current {
                                             "border-blue-400"
+                                        } else if is_hidden {
+                                            "border-slate-700 border-dashed"
                                         } else if is_locked {
                                             "border-gray-500"
                                         } else if is_navigable {
@@ -187,9 +209,13 @@ pub fn MiniMap(props: MiniMapProps) -> Element {
                                                 div {
                                                     class: "text-center p-1",
                                                     
-                                                    span {
-                                                        class: if is_current { "text-white font-bold text-sm" } else { "text-gray-200 text-sm" },
-                                                        "{region.name}"
+                                                    if is_hidden {
+                                                        span { class: "text-slate-500 text-sm italic", "Unexplored" }
+                                                    } else {
+                                                        span {
+                                                            class: if is_current { "text-white font-bold text-sm" } else { "text-gray-200 text-sm" },
+                                                            "{region.name}"
+                                                        }
                                                     }
 
                                                     if is_locked {
@@ -219,6 +245,7 @@ pub fn MiniMap(props: MiniMapProps) -> Element {
                             current_region_id: props.current_region_id.clone(),
                             navigable_region_ids: props.navigable_region_ids.clone(),
                             locked_region_ids: props.locked_region_ids.clone(),
+                            revealed_region_ids: props.revealed_region_ids.clone(),
                             on_region_click: props.on_region_click.clone(),
                         }
                     }
@@ -228,6 +255,11 @@ pub fn MiniMap(props: MiniMapProps) -> Element {
     }
 }
 
+/// Whether fog of war hides a region
+fn is_fogged(revealed_region_ids: &Option<Vec<String>>, region_id: &str) -> bool {
+    revealed_region_ids.as_ref().is_some_and(|revealed| !revealed.iter().any(|id| id == region_id))
+}
+
 /// Calculate map dimensions from region bounds
 fn calculate_map_dimensions(regions: &[MapRegionData]) -> (u32, u32) {
     let mut max_x = 400u32;
@@ -256,6 +288,7 @@ struct MapGridViewProps {
     current_region_id: Option<String>,
     navigable_region_ids: Vec<String>,
     locked_region_ids: Vec<String>,
+    revealed_region_ids: Option<Vec<String>>,
     on_region_click: EventHandler<String>,
 }
 
@@ -271,10 +304,13 @@ fn MapGridView(props: MapGridViewProps) -> Element {
                     let is_current = props.current_region_id.as_ref() == Some(&region.id);
                     let is_navigable = props.navigable_region_ids.contains(&region.id);
                     let is_locked = props.locked_region_ids.contains(&region.id);
+                    let is_hidden = !is_current && is_fogged(&props.revealed_region_ids, &region.id);
                     let region_id = region.id.clone();
 
                     let card_class = if is_current {
                         "bg-blue-500/30 border-blue-400"
+                    } else if is_hidden {
+                        "bg-slate-900 border-slate-700 border-dashed"
                     } else if is_locked {
                         "bg-gray-700/30 border-gray-600 opacity-60"
                     } else if is_navigable {
@@ -302,9 +338,13 @@ fn MapGridView(props: MapGridViewProps) -> Element {
                             div {
                                 class: "flex items-center gap-2 mb-1",
 
-                                span {
-                                    class: if is_current { "text-blue-400 font-bold" } else { "text-white font-medium" },
-                                    "{region.name}"
+                                if is_hidden {
+                                    span { class: "text-slate-500 italic", "Unexplored" }
+                                } else {
+                                    span {
+                                        class: if is_current { "text-blue-400 font-bold" } else { "text-white font-medium" },
+                                        "{region.name}"
+                                    }
                                 }
 
                                 if is_current {
@@ -322,7 +362,7 @@ fn MapGridView(props: MapGridViewProps) -> Element {
                                 }
                             }
 
-                            if !region.description.is_empty() {
+                            if !is_hidden && !region.description.is_empty() {
                                 p {
                                     class: "text-xs text-gray-400 m-0 line-clamp-2",
                                     "{region.description}"
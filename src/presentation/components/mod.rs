@@ -1,6 +1,8 @@
 //! Reusable UI components
 
 pub mod action_panel;
+pub mod audio_cue_player;
+pub mod auth;
 pub mod character_sheet_viewer;
 pub mod common;
 pub mod creator;
@@ -10,7 +12,9 @@ pub mod inventory_panel;
 pub mod known_npcs_panel;
 pub mod mini_map;
 pub mod navigation_panel;
+pub mod notes;
 pub mod pc;
+pub mod presence_strip;
 pub mod settings;
 pub mod shared;
 pub mod story_arc;
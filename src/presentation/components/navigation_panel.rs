@@ -8,7 +8,7 @@
 use dioxus::prelude::*;
 
 use crate::application::dto::{NavigationData, NavigationTarget, NavigationExit};
-use crate::presentation::state::GameTimeData;
+use crate::presentation::state::{GameTimeData, use_modal_focus};
 
 /// Props for NavigationPanel component
 #[derive(Props, Clone, PartialEq)]
@@ -33,6 +33,7 @@ pub struct NavigationPanelProps {
 /// Navigation Panel - Modal overlay for navigation options
 #[component]
 pub fn NavigationPanel(props: NavigationPanelProps) -> Element {
+    use_modal_focus("navigation-panel");
     let has_regions = !props.navigation.connected_regions.is_empty();
     let has_exits = !props.navigation.exits.is_empty();
     let has_any_navigation = has_regions || has_exits;
@@ -45,6 +46,9 @@ pub fn NavigationPanel(props: NavigationPanelProps) -> Element {
 
             // Modal content
             div {
+                id: "navigation-panel",
+                role: "dialog",
+                aria_modal: "true",
                 class: "navigation-panel bg-gradient-to-br from-dark-surface to-dark-bg rounded-2xl w-full max-w-lg max-h-[80vh] overflow-hidden flex flex-col shadow-2xl border border-white/10",
                 onclick: move |e| e.stop_propagation(),
 
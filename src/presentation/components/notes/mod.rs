@@ -0,0 +1,10 @@
+//! DM Notes Components
+//!
+//! Components for the Notes tab in the DM View:
+//! - Wiki browser with full-text search and quick-open
+//! - Page editor with entity and page mentions
+
+pub mod note_editor;
+pub mod notes_wiki;
+
+pub use notes_wiki::NotesWiki;
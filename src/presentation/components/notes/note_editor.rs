@@ -0,0 +1,77 @@
+//! Note Editor - Create or edit a wiki page
+
+use dioxus::prelude::*;
+
+use crate::application::dto::EntityMention;
+use crate::application::services::SaveNoteRequest;
+use crate::presentation::components::common::{RichTextEditor, TagInput};
+
+/// Editor for one page; `on_save` receives the page's new content
+#[component]
+pub fn NoteEditor(
+    world_id: String,
+    #[props(default)]
+    title: String,
+    #[props(default)]
+    body: String,
+    #[props(default)]
+    tags: Vec<String>,
+    /// Other pages, offered as `@` mentions
+    page_mentions: Vec<EntityMention>,
+    is_saving: bool,
+    on_save: EventHandler<SaveNoteRequest>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let mut title = use_signal(|| title);
+    let mut body = use_signal(|| body);
+    let mut tags = use_signal(|| tags);
+
+    let can_save = !title.read().trim().is_empty() && !is_saving;
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-3",
+
+            input {
+                r#type: "text",
+                value: "{title}",
+                placeholder: "Page title",
+                oninput: move |e| title.set(e.value()),
+                class: "p-2 bg-dark-bg border border-gray-700 rounded text-white text-lg",
+            }
+
+            TagInput {
+                tags: tags.read().clone(),
+                on_change: move |next| tags.set(next),
+            }
+
+            RichTextEditor {
+                value: body.read().clone(),
+                on_change: move |next| body.set(next),
+                world_id: world_id.clone(),
+                placeholder: "Write notes… type @ to link characters, locations, events or pages",
+                extra_mentions: page_mentions.clone(),
+            }
+
+            div { class: "flex justify-end gap-2",
+                button {
+                    class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded-lg cursor-pointer text-sm",
+                    onclick: move |_| on_cancel.call(()),
+                    "Cancel"
+                }
+                button {
+                    class: "px-4 py-2 bg-blue-500 text-white border-none rounded-lg cursor-pointer text-sm disabled:opacity-50",
+                    disabled: !can_save,
+                    onclick: move |_| {
+                        on_save.call(SaveNoteRequest {
+                            title: title.read().trim().to_string(),
+                            body: body.read().clone(),
+                            tags: tags.read().clone(),
+                        });
+                    },
+                    if is_saving { "Saving..." } else { "Save" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,415 @@
+//! Notes Wiki - Browse, search and edit the world's DM notes
+//!
+//! Pages are listed on the left with full-text search; Ctrl+K opens a
+//! quick-open box from anywhere in the wiki. The open page comes from the
+//! route, so page links and deep links share one navigation path. When the
+//! Engine can't be reached pages are read from and saved to this device,
+//! then pushed to the Engine once the connection is back.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::EntityMention;
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{
+    load_local_notes, merge_note, note_backlinks, save_local_notes, search_notes, NotePage,
+    SaveNoteRequest, LOCAL_NOTE_PREFIX,
+};
+use crate::presentation::components::common::{mention_route, RichTextView, ModalFocus};
+use crate::presentation::components::notes::note_editor::NoteEditor;
+use crate::presentation::services::use_notes_service;
+use crate::presentation::state::use_session_state;
+use crate::routes::Route;
+
+/// Most results shown in the quick-open box
+const QUICK_OPEN_LIMIT: usize = 8;
+
+/// Editor key for a page that hasn't been saved yet
+const NEW_PAGE_KEY: &str = "new";
+
+/// What the main pane is editing
+#[derive(Clone, PartialEq)]
+enum EditTarget {
+    New,
+    Existing(NotePage),
+}
+
+#[component]
+pub fn NotesWiki(world_id: String, selected_note_id: Option<String>) -> Element {
+    let notes_service = use_notes_service();
+    let session_state = use_session_state();
+    let platform = use_context::<Platform>();
+    let navigator = use_navigator();
+
+    let mut pages: Signal<Vec<NotePage>> = use_signal(Vec::new);
+    let mut is_loading = use_signal(|| true);
+    let mut offline = use_signal(|| false);
+    let mut search_text = use_signal(String::new);
+    let mut editing: Signal<Option<EditTarget>> = use_signal(|| None);
+    let mut is_saving = use_signal(|| false);
+    let mut quick_open = use_signal(|| false);
+    let mut quick_query = use_signal(String::new);
+
+    // Load pages, falling back to the copy on this device. Runs again when
+    // the connection comes back, pushing pages saved offline in the meantime.
+    {
+        let world_id = world_id.clone();
+        let service = notes_service.clone();
+        let platform = platform.clone();
+        use_effect(move || {
+            if !session_state.connection_status().read().is_connected() && !pages.peek().is_empty() {
+                return;
+            }
+            let world_id = world_id.clone();
+            let service = service.clone();
+            let platform = platform.clone();
+            spawn(async move {
+                is_loading.set(true);
+                match service.list_notes(&world_id).await {
+                    Ok(remote) => {
+                        let local = load_local_notes(&platform, &world_id);
+                        let loaded = service.sync_local_notes(&world_id, remote, &local).await;
+                        save_local_notes(&platform, &world_id, &loaded);
+                        pages.set(loaded);
+                        offline.set(false);
+                    }
+                    Err(e) => {
+                        tracing::warn!("Notes unavailable from Engine, using local copy: {}", e);
+                        pages.set(load_local_notes(&platform, &world_id));
+                        offline.set(true);
+                    }
+                }
+                is_loading.set(false);
+            });
+        });
+    }
+
+    let save = {
+        let world_id = world_id.clone();
+        let service = notes_service.clone();
+        let platform = platform.clone();
+        move |request: SaveNoteRequest| {
+            let target = editing.read().clone();
+            let world_id = world_id.clone();
+            let service = service.clone();
+            let platform = platform.clone();
+            spawn(async move {
+                is_saving.set(true);
+                let existing = match &target {
+                    Some(EditTarget::Existing(page)) => Some(page.clone()),
+                    _ => None,
+                };
+                let remote = if *offline.peek() {
+                    None
+                } else {
+                    let result = match &existing {
+                        Some(page) if !page.is_local_only() => service.update_note(&page.id, &request).await,
+                        _ => service.create_note(&world_id, &request).await,
+                    };
+                    match result {
+                        Ok(saved) => Some(saved),
+                        Err(e) => {
+                            tracing::warn!("Failed to save note to Engine, keeping it locally: {}", e);
+                            offline.set(true);
+                            None
+                        }
+                    }
+                };
+                let saved = remote.unwrap_or_else(|| NotePage {
+                    id: existing
+                        .as_ref()
+                        .map(|p| p.id.clone())
+                        .unwrap_or_else(|| format!("{}{}", LOCAL_NOTE_PREFIX, platform.now_millis())),
+                    world_id: world_id.clone(),
+                    title: request.title.clone(),
+                    body: request.body.clone(),
+                    tags: request.tags.clone(),
+                    updated_at: String::new(),
+                });
+
+                let mut all = pages.read().clone();
+                if let Some(page) = existing.as_ref().filter(|p| p.id != saved.id) {
+                    all.retain(|p| p.id != page.id);
+                }
+                merge_note(&mut all, saved.clone());
+                save_local_notes(&platform, &world_id, &all);
+                pages.set(all);
+                is_saving.set(false);
+                editing.set(None);
+                navigator.push(Route::DMNoteRoute { world_id, note_id: saved.id });
+            });
+        }
+    };
+
+    let delete = {
+        let world_id = world_id.clone();
+        let service = notes_service.clone();
+        let platform = platform.clone();
+        move |note_id: String| {
+            let world_id = world_id.clone();
+            let service = service.clone();
+            let platform = platform.clone();
+            spawn(async move {
+                if !*offline.peek() && !note_id.starts_with(LOCAL_NOTE_PREFIX) {
+                    if let Err(e) = service.delete_note(&note_id).await {
+                        tracing::error!("Failed to delete note: {}", e);
+                        return;
+                    }
+                }
+                let remaining: Vec<NotePage> = pages.read().iter().filter(|p| p.id != note_id).cloned().collect();
+                save_local_notes(&platform, &world_id, &remaining);
+                pages.set(remaining);
+                navigator.push(Route::DMViewTabRoute { world_id, tab: "notes".to_string() });
+            });
+        }
+    };
+
+    let all_pages = pages.read().clone();
+    let listed: Vec<NotePage> = search_notes(&all_pages, &search_text.read()).into_iter().cloned().collect();
+    let quick_matches: Vec<NotePage> = search_notes(&all_pages, &quick_query.read())
+        .into_iter()
+        .take(QUICK_OPEN_LIMIT)
+        .cloned()
+        .collect();
+    let selected = selected_note_id
+        .as_ref()
+        .and_then(|id| all_pages.iter().find(|p| &p.id == id))
+        .cloned();
+    let backlinks: Vec<NotePage> = selected
+        .as_ref()
+        .map(|page| note_backlinks(&all_pages, &page.id).into_iter().cloned().collect())
+        .unwrap_or_default();
+    let edit_target = editing.read().clone();
+    let page_mentions = |exclude: Option<&str>| -> Vec<EntityMention> {
+        all_pages
+            .iter()
+            .filter(|p| Some(p.id.as_str()) != exclude)
+            .map(NotePage::mention)
+            .collect()
+    };
+
+    rsx! {
+        div {
+            class: "notes-wiki h-full flex",
+            onkeydown: move |e: KeyboardEvent| {
+                let modifiers = e.modifiers();
+                if (modifiers.ctrl() || modifiers.meta()) && e.key() == Key::Character("k".to_string()) {
+                    e.prevent_default();
+                    quick_query.set(String::new());
+                    quick_open.set(true);
+                } else if e.key() == Key::Escape {
+                    quick_open.set(false);
+                }
+            },
+
+            // Page list
+            aside {
+                class: "w-72 flex flex-col gap-2 p-4 border-r border-gray-700 overflow-y-auto",
+
+                div { class: "flex justify-between items-center",
+                    h2 { class: "text-white m-0 text-lg", "Notes" }
+                    button {
+                        class: "px-3 py-1 bg-blue-500 text-white border-none rounded-lg cursor-pointer text-sm",
+                        onclick: move |_| editing.set(Some(EditTarget::New)),
+                        "+ New Page"
+                    }
+                }
+
+                input {
+                    r#type: "text",
+                    value: "{search_text}",
+                    placeholder: "Search notes... (Ctrl+K to jump)",
+                    oninput: move |e| search_text.set(e.value()),
+                    class: "p-2 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                }
+
+                if *offline.read() {
+                    div { class: "text-amber-500 text-xs", "Engine unavailable — notes are saved on this device" }
+                }
+
+                if *is_loading.read() {
+                    div { class: "text-gray-500 text-sm", "Loading notes..." }
+                } else if listed.is_empty() {
+                    div {
+                        class: "text-gray-500 text-sm italic",
+                        if all_pages.is_empty() { "No pages yet" } else { "No pages match your search" }
+                    }
+                }
+
+                for page in listed.iter() {
+                    Link {
+                        key: "{page.id}",
+                        to: Route::DMNoteRoute { world_id: world_id.clone(), note_id: page.id.clone() },
+                        class: if selected_note_id.as_deref() == Some(page.id.as_str()) {
+                            "block p-2 rounded bg-blue-500/20 text-white no-underline text-sm"
+                        } else {
+                            "block p-2 rounded text-gray-300 no-underline text-sm hover:bg-white/5"
+                        },
+                        "{page.title}"
+                    }
+                }
+            }
+
+            // Open page
+            main {
+                class: "flex-1 p-6 overflow-y-auto",
+
+                match (edit_target, selected) {
+                    (Some(EditTarget::New), _) => rsx! {
+                        NoteEditor {
+                            key: "{NEW_PAGE_KEY}",
+                            world_id: world_id.clone(),
+                            page_mentions: page_mentions(None),
+                            is_saving: *is_saving.read(),
+                            on_save: save.clone(),
+                            on_cancel: move |_| editing.set(None),
+                        }
+                    },
+                    (Some(EditTarget::Existing(page)), _) => rsx! {
+                        NoteEditor {
+                            key: "{page.id}",
+                            world_id: world_id.clone(),
+                            title: page.title.clone(),
+                            body: page.body.clone(),
+                            tags: page.tags.clone(),
+                            page_mentions: page_mentions(Some(&page.id)),
+                            is_saving: *is_saving.read(),
+                            on_save: save.clone(),
+                            on_cancel: move |_| editing.set(None),
+                        }
+                    },
+                    (None, Some(page)) => rsx! {
+                        article {
+                            class: "flex flex-col gap-4 max-w-3xl",
+
+                            div { class: "flex justify-between items-start gap-4",
+                                h1 { class: "text-white m-0 text-2xl", "{page.title}" }
+                                div { class: "flex gap-2",
+                                    button {
+                                        class: "px-3 py-1 bg-transparent text-gray-300 border border-gray-700 rounded cursor-pointer text-sm",
+                                        onclick: {
+                                            let page = page.clone();
+                                            move |_| editing.set(Some(EditTarget::Existing(page.clone())))
+                                        },
+                                        "Edit"
+                                    }
+                                    button {
+                                        class: "px-3 py-1 bg-transparent text-red-400 border border-gray-700 rounded cursor-pointer text-sm",
+                                        onclick: {
+                                            let note_id = page.id.clone();
+                                            let delete = delete.clone();
+                                            move |_| delete(note_id.clone())
+                                        },
+                                        "Delete"
+                                    }
+                                }
+                            }
+
+                            if !page.tags.is_empty() {
+                                div { class: "flex flex-wrap gap-1",
+                                    for tag in page.tags.iter() {
+                                        span {
+                                            key: "{tag}",
+                                            class: "px-2 py-0.5 bg-gray-700 text-gray-300 rounded text-xs",
+                                            "{tag}"
+                                        }
+                                    }
+                                }
+                            }
+
+                            if page.body.trim().is_empty() {
+                                p { class: "text-gray-500 italic m-0", "This page is empty" }
+                            } else {
+                                RichTextView {
+                                    text: page.body.clone(),
+                                    class: "text-gray-200",
+                                    on_mention: {
+                                        let world_id = world_id.clone();
+                                        move |mention| {
+                                            navigator.push(mention_route(&world_id, &mention));
+                                        }
+                                    },
+                                }
+                            }
+
+                            if !backlinks.is_empty() {
+                                div { class: "border-t border-gray-700 pt-3",
+                                    h3 { class: "text-gray-400 text-xs uppercase m-0 mb-2", "Linked from" }
+                                    div { class: "flex flex-wrap gap-2",
+                                        for linking in backlinks.iter() {
+                                            Link {
+                                                key: "{linking.id}",
+                                                to: Route::DMNoteRoute { world_id: world_id.clone(), note_id: linking.id.clone() },
+                                                class: "text-blue-400 text-sm no-underline",
+                                                "📝 {linking.title}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    (None, None) => rsx! {
+                        div {
+                            class: "h-full flex flex-col items-center justify-center text-gray-500",
+                            div { class: "text-5xl mb-4", "📝" }
+                            if selected_note_id.is_some() && !*is_loading.read() {
+                                p { "That page doesn't exist anymore" }
+                            } else {
+                                p { "Pick a page, or start a new one" }
+                            }
+                        }
+                    },
+                }
+            }
+
+            // Quick-open
+            if *quick_open.read() {
+                div {
+                    class: "fixed inset-0 bg-black/60 z-[1000] flex items-start justify-center pt-24",
+                    onclick: move |_| quick_open.set(false),
+
+                    div {
+                        id: "notes-quick-open",
+                        role: "dialog",
+                        aria_modal: "true",
+                        class: "w-full max-w-lg bg-dark-surface rounded-lg border border-gray-700 shadow-2xl",
+                        onclick: move |e| e.stop_propagation(),
+
+                        ModalFocus { dialog_id: "notes-quick-open" }
+
+                        input {
+                            r#type: "text",
+                            autofocus: true,
+                            value: "{quick_query}",
+                            placeholder: "Open page...",
+                            oninput: move |e| quick_query.set(e.value()),
+                            onkeydown: {
+                                let world_id = world_id.clone();
+                                let first = quick_matches.first().map(|p| p.id.clone());
+                                move |e: KeyboardEvent| {
+                                    if e.key() == Key::Enter {
+                                        if let Some(note_id) = first.clone() {
+                                            quick_open.set(false);
+                                            navigator.push(Route::DMNoteRoute { world_id: world_id.clone(), note_id });
+                                        }
+                                    }
+                                }
+                            },
+                            class: "w-full p-3 bg-transparent border-none border-b border-gray-700 text-white box-border",
+                        }
+
+                        for page in quick_matches.iter() {
+                            Link {
+                                key: "{page.id}",
+                                to: Route::DMNoteRoute { world_id: world_id.clone(), note_id: page.id.clone() },
+                                onclick: move |_| quick_open.set(false),
+                                class: "block px-3 py-2 text-gray-200 no-underline text-sm hover:bg-white/5",
+                                "📝 {page.title}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -7,6 +7,7 @@ use crate::application::dto::{FieldValue, SheetTemplate};
 use crate::application::services::{PlayerCharacterData, UpdatePlayerCharacterRequest};
 use crate::application::services::player_character_service::CharacterSheetDataApi;
 use crate::presentation::services::{use_player_character_service, use_world_service};
+use crate::presentation::state::use_modal_focus;
 
 /// Props for EditCharacterModal
 #[derive(Props, Clone, PartialEq)]
@@ -19,6 +20,7 @@ pub struct EditCharacterModalProps {
 /// Edit Character Modal component
 #[component]
 pub fn EditCharacterModal(props: EditCharacterModalProps) -> Element {
+    use_modal_focus("edit-character-modal");
     let pc_service = use_player_character_service();
     let world_service = use_world_service();
 
@@ -115,6 +117,9 @@ pub fn EditCharacterModal(props: EditCharacterModalProps) -> Element {
                 props.on_close.call(());
             },
             div {
+                id: "edit-character-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "bg-dark-surface rounded-lg w-[90%] max-w-[800px] max-h-[90vh] overflow-y-auto flex flex-col",
                 onclick: |e| e.stop_propagation(),
 
@@ -126,6 +131,7 @@ pub fn EditCharacterModal(props: EditCharacterModalProps) -> Element {
                         "Edit Character"
                     }
                     button {
+                        aria_label: "Close",
                         onclick: move |_| props.on_close.call(()),
                         class: "px-2 py-1 bg-transparent text-gray-400 border-0 cursor-pointer text-xl",
                         "×"
@@ -0,0 +1,451 @@
+//! Level-Up Wizard - guided advancement for a player character
+//!
+//! Walks the player through the choices their rule system grants at the new
+//! level (stat increases, new skills), checks them against the rules, and
+//! sends them to the Engine, which updates the character sheet.
+
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+use crate::application::dto::{
+    validate_level_up, FieldValue, LevelUpAllowance, LevelUpChoices, RuleSystemType, SkillData,
+    StatDefinition,
+};
+use crate::application::services::{PlayerCharacterData, SessionCommandService};
+use crate::presentation::services::{use_player_character_service, use_skill_service};
+use crate::presentation::state::session_state::PendingLevelUp;
+use crate::presentation::state::{use_game_state, use_session_state, use_modal_focus};
+
+/// Steps of the wizard, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Stats,
+    Skills,
+    Review,
+}
+
+/// Props for LevelUpWizard
+#[derive(Props, Clone, PartialEq)]
+pub struct LevelUpWizardProps {
+    pub pending: PendingLevelUp,
+    pub on_close: EventHandler<()>,
+}
+
+/// Level-Up Wizard modal
+#[component]
+pub fn LevelUpWizard(props: LevelUpWizardProps) -> Element {
+    use_modal_focus("level-up-wizard");
+    let game_state = use_game_state();
+    let session_state = use_session_state();
+    let pc_service = use_player_character_service();
+    let skill_service = use_skill_service();
+
+    let mut pc: Signal<Option<PlayerCharacterData>> = use_signal(|| None);
+    let mut world_skills: Signal<Vec<SkillData>> = use_signal(Vec::new);
+    let mut loading = use_signal(|| true);
+    let mut choices = use_signal(LevelUpChoices::default);
+    let mut error_message: Signal<Option<String>> = use_signal(|| None);
+    let mut is_submitting = use_signal(|| false);
+
+    let world = game_state.world.read().clone();
+    let (system_type, stats) = world
+        .as_ref()
+        .map(|w| (w.world.rule_system.system_type, w.world.rule_system.stat_definitions.clone()))
+        .unwrap_or((RuleSystemType::D20, Vec::new()));
+    let new_level = props.pending.level + 1;
+    let mut allowance = LevelUpAllowance::for_level(system_type, new_level);
+    if stats.is_empty() {
+        // No stats defined for this world, so there is nothing to raise
+        allowance.stat_points = 0;
+        allowance.max_per_stat = 0;
+    }
+    let steps: Vec<Step> = [
+        (Step::Stats, allowance.stat_points > 0),
+        (Step::Skills, allowance.new_skills > 0),
+        (Step::Review, true),
+    ]
+    .into_iter()
+    .filter_map(|(s, shown)| shown.then_some(s))
+    .collect();
+    let mut step = use_signal(|| steps[0]);
+
+    // Load the character and the world's skills
+    {
+        let pc_id = props.pending.pc_id.clone();
+        let world_id = world.as_ref().map(|w| w.world.id.clone());
+        use_effect(move || {
+            let pc_svc = pc_service.clone();
+            let skill_svc = skill_service.clone();
+            let pc_id = pc_id.clone();
+            let world_id = world_id.clone();
+            spawn(async move {
+                match pc_svc.get_pc(&pc_id).await {
+                    Ok(data) => pc.set(Some(data)),
+                    Err(e) => error_message.set(Some(format!("Failed to load character: {}", e))),
+                }
+                if let Some(world_id) = world_id {
+                    match skill_svc.list_skills(&world_id).await {
+                        Ok(skills) => world_skills.set(skills),
+                        Err(e) => tracing::warn!("Failed to load skills for level-up: {}", e),
+                    }
+                }
+                loading.set(false);
+            });
+        });
+    }
+
+    let current_stats = pc.read().as_ref().map(|pc| stat_values(pc, &stats)).unwrap_or_default();
+    let known_skills = pc.read().as_ref().map(known_skill_ids).unwrap_or_default();
+    let step_index = steps.iter().position(|s| *s == *step.read()).unwrap_or(0);
+    let current_step = steps[step_index];
+    let previous_step = steps[step_index.saturating_sub(1)];
+    let next_step = steps.get(step_index + 1).copied();
+
+    let submit = {
+        let stats = stats.clone();
+        let current_stats = current_stats.clone();
+        let known_skills = known_skills.clone();
+        let pc_id = props.pending.pc_id.clone();
+        let session_state = session_state.clone();
+        move |_| {
+            let picked = choices.read().clone();
+            if let Err(e) = validate_level_up(&picked, &allowance, &stats, &current_stats, &known_skills) {
+                error_message.set(Some(e));
+                return;
+            }
+            let Some(client) = session_state.engine_client().read().as_ref().map(std::sync::Arc::clone) else {
+                error_message.set(Some("Not connected to the Engine".to_string()));
+                return;
+            };
+            is_submitting.set(true);
+            match SessionCommandService::new(client).submit_level_up(&pc_id, picked) {
+                Ok(()) => props.on_close.call(()),
+                Err(e) => {
+                    error_message.set(Some(format!("Failed to submit level-up: {}", e)));
+                    is_submitting.set(false);
+                }
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black bg-opacity-75 flex items-center justify-center z-[1000]",
+            onclick: move |_| props.on_close.call(()),
+            div {
+                id: "level-up-wizard",
+                role: "dialog",
+                aria_modal: "true",
+                class: "bg-dark-surface rounded-lg w-[90%] max-w-[560px] max-h-[90vh] overflow-y-auto flex flex-col",
+                onclick: |e| e.stop_propagation(),
+
+                // Header
+                div {
+                    class: "flex justify-between items-center p-6 border-b border-gray-700",
+                    div {
+                        h2 {
+                            class: "m-0 text-white text-xl",
+                            "Level Up: {props.pending.pc_name}"
+                        }
+                        div {
+                            class: "text-gray-400 text-sm mt-1",
+                            "Level {props.pending.level} → {new_level}"
+                            if props.pending.pending_levels > 1 {
+                                " ({props.pending.pending_levels} levels waiting)"
+                            }
+                        }
+                    }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| props.on_close.call(()),
+                        class: "px-2 py-1 bg-transparent text-gray-400 border-0 cursor-pointer text-xl",
+                        "×"
+                    }
+                }
+
+                if let Some(err) = error_message.read().as_ref() {
+                    div {
+                        class: "px-6 py-3 bg-red-500 bg-opacity-10 border-b border-red-500 border-opacity-30 text-red-500 text-sm",
+                        "{err}"
+                    }
+                }
+
+                div {
+                    class: "p-6",
+                    if *loading.read() {
+                        div { class: "text-center text-gray-400", "Loading character..." }
+                    } else {
+                        match current_step {
+                            Step::Stats => rsx! {
+                                StatStep {
+                                    stats: stats.clone(),
+                                    current: current_stats.clone(),
+                                    allowance,
+                                    increases: choices.read().stat_increases.clone(),
+                                    on_change: move |(abbreviation, value): (String, u32)| {
+                                        choices.write().stat_increases.insert(abbreviation, value);
+                                    },
+                                }
+                            },
+                            Step::Skills => rsx! {
+                                SkillStep {
+                                    skills: world_skills.read().iter()
+                                        .filter(|s| !s.is_hidden && !known_skills.contains(&s.id))
+                                        .cloned()
+                                        .collect::<Vec<_>>(),
+                                    allowance,
+                                    selected: choices.read().new_skill_ids.clone(),
+                                    on_toggle: move |skill_id: String| {
+                                        let mut picked = choices.write();
+                                        if let Some(i) = picked.new_skill_ids.iter().position(|id| *id == skill_id) {
+                                            picked.new_skill_ids.remove(i);
+                                        } else if picked.new_skill_ids.len() < allowance.new_skills as usize {
+                                            picked.new_skill_ids.push(skill_id);
+                                        }
+                                    },
+                                }
+                            },
+                            Step::Review => rsx! {
+                                ReviewStep {
+                                    stats: stats.clone(),
+                                    skills: world_skills.read().clone(),
+                                    choices: choices.read().clone(),
+                                    allowance,
+                                }
+                            },
+                        }
+                    }
+                }
+
+                // Footer
+                div {
+                    class: "flex justify-between gap-2 p-6 border-t border-gray-700",
+                    button {
+                        disabled: step_index == 0,
+                        onclick: move |_| {
+                            error_message.set(None);
+                            step.set(previous_step);
+                        },
+                        class: "px-4 py-2 bg-gray-700 text-white border-0 rounded-lg cursor-pointer disabled:opacity-50 disabled:cursor-default",
+                        "Back"
+                    }
+                    if let Some(next) = next_step {
+                        button {
+                            onclick: move |_| {
+                                error_message.set(None);
+                                step.set(next);
+                            },
+                            class: "px-4 py-2 bg-blue-500 text-white border-0 rounded-lg cursor-pointer",
+                            "Next"
+                        }
+                    } else {
+                        button {
+                            disabled: *loading.read() || *is_submitting.read(),
+                            onclick: submit,
+                            class: "px-4 py-2 bg-amber-500 text-white border-0 rounded-lg cursor-pointer font-semibold disabled:opacity-50",
+                            if *is_submitting.read() { "Applying..." } else { "Level Up" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Spread stat points
+#[component]
+fn StatStep(
+    stats: Vec<StatDefinition>,
+    current: HashMap<String, i32>,
+    allowance: LevelUpAllowance,
+    increases: HashMap<String, u32>,
+    on_change: EventHandler<(String, u32)>,
+) -> Element {
+    let spent: u32 = increases.values().sum();
+    let remaining = allowance.stat_points.saturating_sub(spent);
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-3",
+            p {
+                class: "m-0 text-gray-300 text-sm",
+                "Spend {allowance.stat_points} point(s), at most {allowance.max_per_stat} on any one stat. {remaining} left."
+            }
+            for stat in stats {
+                {
+                    let base = current.get(&stat.abbreviation).copied().unwrap_or(stat.default_value);
+                    let added = increases.get(&stat.abbreviation).copied().unwrap_or(0);
+                    let can_add = remaining > 0
+                        && added < allowance.max_per_stat
+                        && base + (added as i32) < stat.max_value;
+                    let dec_key = stat.abbreviation.clone();
+                    let inc_key = stat.abbreviation.clone();
+                    rsx! {
+                        div {
+                            class: "flex items-center justify-between p-2 bg-dark-bg rounded",
+                            span { class: "text-white text-sm", "{stat.name} ({stat.abbreviation})" }
+                            div {
+                                class: "flex items-center gap-2",
+                                button {
+                                    disabled: added == 0,
+                                    onclick: move |_| on_change.call((dec_key.clone(), added - 1)),
+                                    class: "w-7 h-7 bg-gray-700 text-white border-0 rounded cursor-pointer disabled:opacity-40",
+                                    "−"
+                                }
+                                span {
+                                    class: "w-16 text-center text-white font-mono",
+                                    "{base + added as i32}"
+                                    if added > 0 {
+                                        span { class: "text-green-400 text-xs", " +{added}" }
+                                    }
+                                }
+                                button {
+                                    disabled: !can_add,
+                                    onclick: move |_| on_change.call((inc_key.clone(), added + 1)),
+                                    class: "w-7 h-7 bg-gray-700 text-white border-0 rounded cursor-pointer disabled:opacity-40",
+                                    "+"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Pick new skills
+#[component]
+fn SkillStep(
+    skills: Vec<SkillData>,
+    allowance: LevelUpAllowance,
+    selected: Vec<String>,
+    on_toggle: EventHandler<String>,
+) -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col gap-3",
+            p {
+                class: "m-0 text-gray-300 text-sm",
+                "Choose up to {allowance.new_skills} new skill(s). {selected.len()} chosen."
+            }
+            if skills.is_empty() {
+                div { class: "text-gray-500 text-sm italic", "No new skills available" }
+            }
+            div {
+                class: "grid grid-cols-2 gap-2",
+                for skill in skills {
+                    {
+                        let is_selected = selected.contains(&skill.id);
+                        let skill_id = skill.id.clone();
+                        let class = if is_selected {
+                            "p-2 text-left bg-blue-500/20 border border-blue-500 rounded cursor-pointer"
+                        } else {
+                            "p-2 text-left bg-dark-bg border border-gray-700 rounded cursor-pointer hover:border-gray-500"
+                        };
+                        rsx! {
+                            button {
+                                onclick: move |_| on_toggle.call(skill_id.clone()),
+                                class: "{class}",
+                                div { class: "text-white text-sm", "{skill.name}" }
+                                div { class: "text-gray-500 text-xs", "{skill.category.display_name()}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Summary of the choices before submitting
+#[component]
+fn ReviewStep(
+    stats: Vec<StatDefinition>,
+    skills: Vec<SkillData>,
+    choices: LevelUpChoices,
+    allowance: LevelUpAllowance,
+) -> Element {
+    let stat_lines: Vec<String> = stats
+        .iter()
+        .filter_map(|s| {
+            let added = choices.stat_increases.get(&s.abbreviation).copied().unwrap_or(0);
+            (added > 0).then(|| format!("{} +{}", s.name, added))
+        })
+        .collect();
+    let skill_names: Vec<String> = choices
+        .new_skill_ids
+        .iter()
+        .map(|id| {
+            skills
+                .iter()
+                .find(|s| &s.id == id)
+                .map(|s| s.name.clone())
+                .unwrap_or_else(|| id.clone())
+        })
+        .collect();
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-3 text-sm",
+            if allowance.is_empty() {
+                p { class: "m-0 text-gray-300", "No choices to make at this level." }
+            }
+            if !stat_lines.is_empty() {
+                div {
+                    div { class: "text-gray-400 text-xs uppercase mb-1", "Stat increases" }
+                    for line in stat_lines {
+                        div { class: "text-white", "{line}" }
+                    }
+                }
+            }
+            if !skill_names.is_empty() {
+                div {
+                    div { class: "text-gray-400 text-xs uppercase mb-1", "New skills" }
+                    for name in skill_names {
+                        div { class: "text-white", "{name}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Stat values from the character sheet, keyed by abbreviation
+///
+/// Sheet fields are matched to stats by abbreviation or name.
+fn stat_values(pc: &PlayerCharacterData, stats: &[StatDefinition]) -> HashMap<String, i32> {
+    let Some(sheet) = pc.sheet_data.as_ref() else {
+        return HashMap::new();
+    };
+    stats
+        .iter()
+        .filter_map(|stat| {
+            sheet.values.iter().find_map(|(key, value)| match value {
+                FieldValue::Number(n)
+                    if key.eq_ignore_ascii_case(&stat.abbreviation) || key.eq_ignore_ascii_case(&stat.name) =>
+                {
+                    Some((stat.abbreviation.clone(), *n))
+                }
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+/// IDs of skills already on the character sheet
+fn known_skill_ids(pc: &PlayerCharacterData) -> Vec<String> {
+    pc.sheet_data
+        .as_ref()
+        .map(|sheet| {
+            sheet
+                .values
+                .values()
+                .filter_map(|value| match value {
+                    FieldValue::SkillEntry { skill_id, .. } => Some(skill_id.clone()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
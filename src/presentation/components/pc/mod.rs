@@ -2,5 +2,11 @@
 
 pub mod character_panel;
 pub mod edit_character_modal;
+pub mod level_up_wizard;
+pub mod posted_actions;
+pub mod recap_overlay;
+pub mod roll_history;
+pub mod roll_macros;
+pub mod whisper_dialog;
 
 
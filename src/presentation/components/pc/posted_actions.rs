@@ -0,0 +1,87 @@
+//! Posted actions - The player's play-by-post actions and their answers
+//!
+//! Actions the Engine stored for an absent DM are listed here until the DM
+//! answers them; answers are announced until the player dismisses them
+//! (see `application::services::action_inbox`).
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::presentation::state::{use_game_state, use_session_state};
+
+/// Notice of posted actions waiting for the DM and of new answers
+#[component]
+pub fn PostedActionsNotice() -> Element {
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+    let platform = use_context::<Platform>();
+    let mut expanded = use_signal(|| false);
+
+    let world_id = game_state.world.read().as_ref().map(|w| w.world.id.clone());
+    {
+        // The world may arrive after mount, e.g. on a reload
+        let mut async_play = session_state.async_play.clone();
+        let world = game_state.world;
+        let platform = platform.clone();
+        use_effect(move || {
+            let world_id = world.read().as_ref().map(|w| w.world.id.clone());
+            if let Some(world_id) = world_id {
+                async_play.load_posted(&platform, &world_id);
+            }
+        });
+    }
+
+    let posted = session_state.async_play.posted.read().clone();
+    let waiting: Vec<String> = posted.waiting().map(|a| a.label.clone()).collect();
+    let answers = posted.unseen_answers();
+    if waiting.is_empty() && answers == 0 {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            role: "status",
+            class: "max-w-xs px-4 py-2 bg-black/70 text-white rounded-lg text-xs flex flex-col gap-1",
+
+            if answers > 0 {
+                div {
+                    class: "flex items-center gap-2",
+                    span { class: "flex-1 text-green-400 font-medium",
+                        if answers == 1 { "✉ The DM answered your posted action" } else { "✉ The DM answered {answers} posted actions" }
+                    }
+                    button {
+                        r#type: "button",
+                        onclick: {
+                            let mut async_play = session_state.async_play.clone();
+                            move |_| {
+                                if let Some(world_id) = world_id.as_deref() {
+                                    async_play.update_posted(&platform, world_id, |posted| posted.acknowledge_answers());
+                                }
+                            }
+                        },
+                        class: "px-2 py-0.5 bg-transparent text-gray-300 border border-gray-600 rounded cursor-pointer text-xs",
+                        "Got it"
+                    }
+                }
+            }
+
+            if !waiting.is_empty() {
+                button {
+                    r#type: "button",
+                    aria_expanded: *expanded.read(),
+                    onclick: move |_| expanded.toggle(),
+                    class: "p-0 bg-transparent text-gray-300 border-none cursor-pointer text-left text-xs",
+                    "⏳ {waiting.len()} posted action(s) waiting for the DM"
+                }
+                if *expanded.read() {
+                    ul {
+                        class: "m-0 pl-4 text-gray-400",
+                        for (i, label) in waiting.iter().enumerate() {
+                            li { key: "{i}", "{label}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Recap overlay - Plays a DM's "previously on…" recap over the stage
+//!
+//! Cards advance on their own after `RecapCard::duration_ms`; players can
+//! pause, step back and forth, or skip the rest. The DM previews the same
+//! overlay from the timeline's recap composer.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::SessionRecap;
+use crate::application::ports::outbound::Platform;
+use crate::presentation::state::use_modal_focus;
+
+/// Full-screen playback of a recap, one card at a time
+#[component]
+pub fn RecapOverlay(recap: SessionRecap, on_close: EventHandler<()>) -> Element {
+    use_modal_focus("recap-overlay");
+    let platform = use_context::<Platform>();
+    let mut index = use_signal(|| 0usize);
+    let mut paused = use_signal(|| false);
+    let card_count = recap.cards.len();
+
+    // Each card waits its own duration; stepping or pausing restarts the wait
+    {
+        let recap = recap.clone();
+        use_effect(move || {
+            let current = *index.read();
+            if *paused.read() {
+                return;
+            }
+            let Some(card) = recap.cards.get(current) else {
+                return;
+            };
+            let wait = platform.sleep_ms(card.duration_ms());
+            spawn(async move {
+                wait.await;
+                if *index.peek() != current || *paused.peek() {
+                    return;
+                }
+                if current + 1 < card_count {
+                    index.set(current + 1);
+                } else {
+                    on_close.call(());
+                }
+            });
+        });
+    }
+
+    let current = (*index.read()).min(card_count.saturating_sub(1));
+    let Some(card) = recap.cards.get(current).cloned() else {
+        return rsx! {};
+    };
+    let is_last = current + 1 >= card_count;
+
+    rsx! {
+        div {
+            id: "recap-overlay",
+            role: "dialog",
+            aria_modal: "true",
+            aria_label: "{recap.title}",
+            class: "fixed inset-0 z-[1050] bg-black flex flex-col justify-between",
+            onkeydown: move |e: KeyboardEvent| match e.key() {
+                Key::Escape => on_close.call(()),
+                Key::ArrowRight if !is_last => index.set(current + 1),
+                Key::ArrowLeft => index.set(current.saturating_sub(1)),
+                _ => {}
+            },
+
+            if let Some(url) = card.backdrop_url.as_ref() {
+                img {
+                    key: "{card.event_id}",
+                    src: "{url}",
+                    alt: "",
+                    class: "absolute inset-0 w-full h-full object-cover opacity-60 animate-fade-in",
+                }
+            }
+
+            div {
+                class: "relative p-6 text-center",
+                h2 { class: "text-amber-300 text-sm uppercase tracking-[0.3em] m-0", "{recap.title}" }
+            }
+
+            div {
+                class: "relative p-6 flex flex-col items-center gap-4",
+
+                div {
+                    key: "{card.event_id}",
+                    class: "max-w-2xl w-full bg-black/70 border border-white/10 rounded-xl p-5 animate-fade-in",
+                    aria_live: "polite",
+                    if let Some(caption) = card.caption.as_ref() {
+                        p { class: "text-gray-400 text-xs uppercase m-0 mb-2", "{caption}" }
+                    }
+                    p { class: "text-white text-lg leading-relaxed m-0 whitespace-pre-line", "{card.text}" }
+                }
+
+                div {
+                    class: "flex items-center gap-3",
+                    button {
+                        aria_label: "Previous",
+                        disabled: current == 0,
+                        onclick: move |_| index.set(current.saturating_sub(1)),
+                        class: "px-3 py-1 bg-white/10 text-white border-none rounded cursor-pointer disabled:opacity-40",
+                        "‹"
+                    }
+                    button {
+                        onclick: move |_| paused.toggle(),
+                        class: "px-3 py-1 bg-white/10 text-white border-none rounded cursor-pointer text-sm",
+                        if *paused.read() { "Play" } else { "Pause" }
+                    }
+                    span { class: "text-gray-400 text-xs", "{current + 1} / {card_count}" }
+                    button {
+                        aria_label: "Next",
+                        onclick: move |_| if is_last { on_close.call(()) } else { index.set(current + 1) },
+                        class: "px-3 py-1 bg-white/10 text-white border-none rounded cursor-pointer",
+                        "›"
+                    }
+                    button {
+                        onclick: move |_| on_close.call(()),
+                        class: "px-3 py-1 bg-transparent text-gray-400 border border-white/20 rounded cursor-pointer text-sm",
+                        "Skip recap"
+                    }
+                }
+            }
+        }
+    }
+}
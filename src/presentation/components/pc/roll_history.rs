@@ -0,0 +1,192 @@
+//! My Rolls - the player's past challenge attempts in this world
+//!
+//! Rolls are recorded on this device as challenges the player was prompted
+//! for are resolved (see `application::services::roll_history`).
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{skill_stats, RollRecord};
+use crate::presentation::state::{use_game_state, use_modal_focus, use_session_state, use_template_context};
+
+/// Modal listing the player's rolls with per-skill success rates
+#[component]
+pub fn RollHistoryDialog(on_close: EventHandler<()>) -> Element {
+    use_modal_focus("roll-history-dialog");
+
+    let session_state = use_session_state();
+    let game_state = use_game_state();
+    let platform = use_context::<Platform>();
+    let mut this_session_only = use_signal(|| true);
+
+    let world_id = game_state.world.read().as_ref().map(|w| w.world.id.clone());
+    {
+        let mut session_state = session_state.clone();
+        let world_id = world_id.clone();
+        use_hook(move || {
+            if let Some(world_id) = world_id {
+                session_state.load_roll_history(&platform, &world_id);
+            }
+        });
+    }
+
+    let session_id = session_state.session_id().read().clone();
+    let history = session_state.roll_history().read().clone();
+    let scope = if *this_session_only.read() { session_id.as_deref() } else { None };
+    let rolls: Vec<&RollRecord> = history.newest_first(scope);
+    let stats = skill_stats(&rolls);
+    let successes = rolls.iter().filter(|r| r.is_success()).count();
+    let show_character = rolls.iter().any(|r| r.character_name != rolls[0].character_name);
+
+    let session_tab_class = if *this_session_only.read() {
+        "px-3 py-1 bg-purple-600 text-white border-none rounded-lg text-xs cursor-pointer"
+    } else {
+        "px-3 py-1 bg-white/5 text-gray-400 border-none rounded-lg text-xs cursor-pointer"
+    };
+    let world_tab_class = if *this_session_only.read() {
+        "px-3 py-1 bg-white/5 text-gray-400 border-none rounded-lg text-xs cursor-pointer"
+    } else {
+        "px-3 py-1 bg-purple-600 text-white border-none rounded-lg text-xs cursor-pointer"
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/85 z-[1000] flex items-center justify-center p-4",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                id: "roll-history-dialog",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "roll-history-dialog-title",
+                class: "bg-gradient-to-br from-dark-surface to-dark-bg rounded-2xl w-full max-w-2xl max-h-[85vh] overflow-hidden flex flex-col shadow-2xl border border-purple-500/20",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        on_close.call(());
+                    }
+                },
+
+                // Header
+                div {
+                    class: "p-4 border-b border-white/10 flex justify-between items-center",
+                    div {
+                        h2 { id: "roll-history-dialog-title", class: "text-xl font-bold text-white m-0", "My Rolls" }
+                        p { class: "text-gray-400 text-sm m-0 mt-1",
+                            if rolls.is_empty() {
+                                "No rolls yet"
+                            } else {
+                                "{successes} of {rolls.len()} succeeded"
+                            }
+                        }
+                    }
+                    button {
+                        class: "w-8 h-8 flex items-center justify-center bg-white/5 hover:bg-white/10 rounded-lg text-gray-400 hover:text-white transition-colors",
+                        onclick: move |_| on_close.call(()),
+                        "x"
+                    }
+                }
+
+                // Scope
+                div {
+                    class: "px-4 py-2 bg-black/20 border-b border-white/5 flex gap-2",
+                    button {
+                        class: "{session_tab_class}",
+                        onclick: move |_| this_session_only.set(true),
+                        "This session"
+                    }
+                    button {
+                        class: "{world_tab_class}",
+                        onclick: move |_| this_session_only.set(false),
+                        "All sessions"
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-y-auto p-4 flex flex-col gap-4",
+
+                    if rolls.is_empty() {
+                        div {
+                            class: "flex flex-col items-center justify-center py-12 text-center",
+                            span { class: "text-4xl mb-4", "🎲" }
+                            p { class: "text-gray-400 m-0", "Challenges you roll for will show up here." }
+                        }
+                    } else {
+                        // Success rate per skill
+                        div {
+                            class: "grid grid-cols-1 sm:grid-cols-2 gap-2",
+                            for stat in stats.iter() {
+                                div {
+                                    key: "{stat.skill_name}",
+                                    class: "p-2 bg-black/20 rounded-lg",
+                                    div { class: "flex justify-between text-xs mb-1",
+                                        span { class: "text-white font-medium", "{stat.skill_name}" }
+                                        span { class: "text-gray-400", "{stat.successes}/{stat.attempts} · {stat.success_percent()}%" }
+                                    }
+                                    div { class: "h-1.5 bg-gray-700 rounded-full overflow-hidden",
+                                        div {
+                                            class: "h-full bg-emerald-500",
+                                            style: "width: {stat.success_percent()}%",
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        // Individual rolls
+                        div {
+                            class: "flex flex-col gap-2",
+                            for (index, roll) in rolls.iter().enumerate() {
+                                RollRow {
+                                    key: "{index}",
+                                    roll: (*roll).clone(),
+                                    show_character,
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One past roll
+#[component]
+fn RollRow(roll: RollRecord, show_character: bool) -> Element {
+    let outcome_class = match roll.outcome.as_str() {
+        "critical_success" => "text-yellow-400",
+        "success" => "text-emerald-500",
+        "partial" => "text-amber-500",
+        "critical_failure" => "text-red-700",
+        _ => "text-red-500",
+    };
+    let outcome_label = roll.outcome.replace('_', " ");
+    let modifier = if roll.modifier >= 0 { format!("+{}", roll.modifier) } else { roll.modifier.to_string() };
+    let outcome_description = use_template_context()
+        .with("pc.name", roll.character_name.clone())
+        .with("challenge.name", roll.challenge_name.clone())
+        .with("skill.name", roll.skill_name.clone().unwrap_or_default())
+        .resolve(&roll.outcome_description);
+
+    rsx! {
+        div {
+            class: "p-3 bg-black/20 rounded-lg border border-white/5",
+            div { class: "flex justify-between items-start gap-2",
+                div { class: "min-w-0",
+                    div { class: "text-white text-sm font-medium", "{roll.challenge_name}" }
+                    div { class: "text-gray-500 text-xs",
+                        if let Some(skill) = &roll.skill_name { "{skill} · " }
+                        "{roll.roll} {modifier} = {roll.total}"
+                        if show_character { " · {roll.character_name}" }
+                    }
+                }
+                span { class: "{outcome_class} text-xs font-semibold uppercase whitespace-nowrap", "{outcome_label}" }
+            }
+            if !roll.outcome_description.is_empty() {
+                p { class: "text-gray-300 text-xs italic m-0 mt-2", "{outcome_description}" }
+            }
+        }
+    }
+}
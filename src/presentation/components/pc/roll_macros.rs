@@ -0,0 +1,248 @@
+//! Roll macros drawer - the player's saved dice rolls
+//!
+//! Each macro is a button; clicking it rolls every step and hands the
+//! result to `on_roll`, which sends it to the session like any other roll.
+//! Macros are saved on this device per character (see
+//! `application::services::roll_macros`).
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{
+    load_roll_macros, parse_macro_formula, save_roll_macros, upsert_macro, MacroRoll, RollMacro,
+};
+use crate::presentation::state::use_modal_focus;
+
+/// Side drawer listing a character's macros, with an editor for adding and changing them
+#[component]
+pub fn RollMacrosDrawer(
+    pc_id: String,
+    on_roll: EventHandler<MacroRoll>,
+    on_close: EventHandler<()>,
+) -> Element {
+    use_modal_focus("roll-macros-drawer");
+
+    let platform = use_context::<Platform>();
+    let mut macros: Signal<Vec<RollMacro>> = use_signal({
+        let platform = platform.clone();
+        let pc_id = pc_id.clone();
+        move || load_roll_macros(&platform, &pc_id)
+    });
+    // None: list; Some(None): new macro; Some(Some(m)): editing m
+    let mut editing: Signal<Option<Option<RollMacro>>> = use_signal(|| None);
+    let mut last_roll: Signal<Option<MacroRoll>> = use_signal(|| None);
+
+    let save = {
+        let platform = platform.clone();
+        let pc_id = pc_id.clone();
+        move |updated: Vec<RollMacro>| {
+            save_roll_macros(&platform, &pc_id, &updated);
+            macros.set(updated);
+        }
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/50 z-[1000]",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                id: "roll-macros-drawer",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "roll-macros-drawer-title",
+                class: "absolute top-0 right-0 h-full w-full max-w-sm bg-dark-surface border-l border-purple-500/20 shadow-2xl flex flex-col",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        on_close.call(());
+                    }
+                },
+
+                // Header
+                div {
+                    class: "p-4 border-b border-white/10 flex justify-between items-center",
+                    h2 { id: "roll-macros-drawer-title", class: "text-xl font-bold text-white m-0", "Macros" }
+                    button {
+                        class: "w-8 h-8 flex items-center justify-center bg-white/5 hover:bg-white/10 rounded-lg text-gray-400 hover:text-white transition-colors",
+                        aria_label: "Close",
+                        onclick: move |_| on_close.call(()),
+                        "x"
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-y-auto p-4 flex flex-col gap-2",
+
+                    if let Some(initial) = editing.read().clone() {
+                        RollMacroEditor {
+                            initial,
+                            on_save: {
+                                let mut save = save.clone();
+                                move |roll_macro: RollMacro| {
+                                    let mut updated = macros.peek().clone();
+                                    upsert_macro(&mut updated, roll_macro);
+                                    save(updated);
+                                    editing.set(None);
+                                }
+                            },
+                            on_cancel: move |_| editing.set(None),
+                        }
+                    } else {
+                        if macros.read().is_empty() {
+                            div {
+                                class: "flex flex-col items-center justify-center py-8 text-center",
+                                span { class: "text-4xl mb-4", "⚡" }
+                                p { class: "text-gray-400 text-sm m-0", "Save rolls you make often, like \"Sneak Attack: 1d20+7, then 3d6\"." }
+                            }
+                        }
+
+                        for roll_macro in macros.read().iter().cloned() {
+                            div {
+                                key: "{roll_macro.id}",
+                                class: "flex items-center gap-2",
+                                button {
+                                    class: "flex-1 text-left p-3 bg-black/20 hover:bg-purple-500/20 border border-white/5 rounded-lg cursor-pointer",
+                                    title: "Roll {roll_macro.name}",
+                                    onclick: {
+                                        let platform = platform.clone();
+                                        let roll_macro = roll_macro.clone();
+                                        move |_| {
+                                            let result = roll_macro.roll(|min, max| platform.random_range(min, max));
+                                            last_roll.set(Some(result.clone()));
+                                            on_roll.call(result);
+                                        }
+                                    },
+                                    div { class: "text-white text-sm font-medium", "🎲 {roll_macro.name}" }
+                                    div { class: "text-gray-500 text-xs", "{roll_macro.formula()}" }
+                                }
+                                button {
+                                    class: "px-2 py-1 bg-transparent text-gray-400 hover:text-white border border-gray-700 rounded text-xs cursor-pointer",
+                                    aria_label: "Edit {roll_macro.name}",
+                                    onclick: {
+                                        let roll_macro = roll_macro.clone();
+                                        move |_| editing.set(Some(Some(roll_macro.clone())))
+                                    },
+                                    "Edit"
+                                }
+                                button {
+                                    class: "px-2 py-1 bg-transparent text-red-400 border border-red-500/40 rounded text-xs cursor-pointer",
+                                    aria_label: "Delete {roll_macro.name}",
+                                    onclick: {
+                                        let mut save = save.clone();
+                                        let id = roll_macro.id.clone();
+                                        move |_| {
+                                            let updated = macros.peek().iter().filter(|m| m.id != id).cloned().collect();
+                                            save(updated);
+                                        }
+                                    },
+                                    "×"
+                                }
+                            }
+                        }
+
+                        button {
+                            class: "mt-2 px-3 py-2 bg-purple-600 hover:bg-purple-500 text-white border-0 rounded-lg text-sm cursor-pointer",
+                            onclick: move |_| editing.set(Some(None)),
+                            "+ New macro"
+                        }
+                    }
+                }
+
+                // Result of the last macro rolled from the drawer
+                if let Some(result) = last_roll.read().as_ref() {
+                    div {
+                        class: "p-4 border-t border-white/10 bg-black/20",
+                        role: "status",
+                        div { class: "text-gray-400 text-xs uppercase mb-1", "Last roll" }
+                        p { class: "text-white text-sm m-0", "{result.breakdown()}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Form for a macro's name and dice formula
+#[component]
+fn RollMacroEditor(
+    initial: Option<RollMacro>,
+    on_save: EventHandler<RollMacro>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let id = initial
+        .as_ref()
+        .map(|m| m.id.clone())
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let mut name = use_signal({
+        let initial = initial.clone();
+        move || initial.map(|m| m.name).unwrap_or_default()
+    });
+    let mut formula = use_signal(move || initial.map(|m| m.formula()).unwrap_or_default());
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Live check of the formula as it is typed
+    let preview = {
+        let formula = formula.read();
+        if formula.trim().is_empty() {
+            None
+        } else {
+            Some(parse_macro_formula(&formula).map(|steps| {
+                steps.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(" → ")
+            }))
+        }
+    };
+
+    let input_class = "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white text-sm";
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-2",
+            label { class: "text-gray-400 text-xs", r#for: "roll-macro-name", "Name" }
+            input {
+                id: "roll-macro-name",
+                r#type: "text",
+                placeholder: "Sneak Attack",
+                value: "{name}",
+                oninput: move |e| name.set(e.value()),
+                class: input_class,
+            }
+            label { class: "text-gray-400 text-xs", r#for: "roll-macro-formula", "Rolls" }
+            input {
+                id: "roll-macro-formula",
+                r#type: "text",
+                placeholder: "1d20+7, then 3d6",
+                value: "{formula}",
+                oninput: move |e| formula.set(e.value()),
+                class: input_class,
+            }
+            match preview {
+                Some(Ok(steps)) => rsx! { p { class: "text-emerald-400 text-xs m-0", "{steps}" } },
+                Some(Err(e)) => rsx! { p { class: "text-amber-400 text-xs m-0", "{e}" } },
+                None => rsx! { p { class: "text-gray-500 text-xs m-0", "Separate rolls with commas or \"then\"." } },
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div { class: "text-red-500 text-xs", "{err}" }
+            }
+
+            div {
+                class: "flex justify-end gap-2 mt-2",
+                button {
+                    class: "px-3 py-1.5 bg-transparent text-gray-400 border border-gray-700 rounded text-sm cursor-pointer",
+                    onclick: move |_| on_cancel.call(()),
+                    "Cancel"
+                }
+                button {
+                    class: "px-3 py-1.5 bg-purple-600 text-white border-0 rounded text-sm cursor-pointer",
+                    onclick: move |_| match RollMacro::new(id.clone(), &name.read(), &formula.read()) {
+                        Ok(roll_macro) => on_save.call(roll_macro),
+                        Err(e) => error.set(Some(e)),
+                    },
+                    "Save"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Whisper dialog - private messages from a player to the DM
+//!
+//! Whispers never enter the shared conversation log; the Engine only delivers
+//! them to the sending player and the session's DMs.
+
+use dioxus::prelude::*;
+
+use crate::application::services::SessionCommandService;
+use crate::presentation::state::{use_session_state, use_modal_focus};
+
+/// Modal for whispering to the DM and reading their replies
+#[component]
+pub fn WhisperDialog(on_close: EventHandler<()>) -> Element {
+    use_modal_focus("whisper-dialog");
+
+    let session_state = use_session_state();
+    let mut draft = use_signal(String::new);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let user_id = session_state.user_id().read().clone().unwrap_or_default();
+    let messages = session_state.whispers.thread(&user_id);
+
+    // Replies are read while the dialog is open
+    {
+        let user_id = user_id.clone();
+        let mut whispers = session_state.whispers.clone();
+        use_effect(move || {
+            let _ = whispers.messages.read().len();
+            whispers.mark_read(&user_id);
+        });
+    }
+
+    let engine_client = session_state.engine_client();
+    let mut send = move || {
+        let text = draft.read().trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+        let Some(client) = engine_client.read().clone() else {
+            error.set(Some("Not connected".to_string()));
+            return;
+        };
+        match SessionCommandService::new(client).send_whisper(&text) {
+            Ok(()) => {
+                draft.set(String::new());
+                error.set(None);
+            }
+            Err(e) => error.set(Some(format!("Failed to send: {}", e))),
+        }
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/85 z-[1000] flex items-center justify-center p-4",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                id: "whisper-dialog",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "whisper-dialog-title",
+                class: "bg-gradient-to-br from-dark-surface to-dark-bg rounded-2xl w-full max-w-lg max-h-[80vh] overflow-hidden flex flex-col shadow-2xl border border-purple-500/20",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        on_close.call(());
+                    }
+                },
+
+                // Header
+                div {
+                    class: "p-4 border-b border-white/10 flex justify-between items-center",
+                    div {
+                        h2 { id: "whisper-dialog-title", class: "m-0 text-white text-lg", "🤫 Whisper to DM" }
+                        p { class: "m-0 text-gray-500 text-xs", "Only the DM can see these messages" }
+                    }
+                    button {
+                        aria_label: "Close",
+                        class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
+                        onclick: move |_| on_close.call(()),
+                        "×"
+                    }
+                }
+
+                // Thread
+                div {
+                    class: "flex-1 overflow-y-auto p-4 flex flex-col gap-2 min-h-[160px]",
+                    if messages.is_empty() {
+                        div { class: "text-gray-500 text-sm italic text-center my-auto",
+                            "Share a secret plan or ask the DM a question."
+                        }
+                    }
+                    for message in messages.iter() {
+                        div {
+                            key: "{message.id}",
+                            class: if message.from_dm {
+                                "self-start max-w-[80%] px-3 py-2 bg-purple-500/20 text-purple-100 rounded-lg text-sm"
+                            } else {
+                                "self-end max-w-[80%] px-3 py-2 bg-blue-500/30 text-white rounded-lg text-sm"
+                            },
+                            if message.from_dm {
+                                div { class: "text-xs text-purple-300 mb-0.5", "DM" }
+                            }
+                            "{message.text}"
+                        }
+                    }
+                }
+
+                if let Some(err) = error.read().as_ref() {
+                    div { class: "px-4 text-red-400 text-xs", "{err}" }
+                }
+
+                // Composer
+                div {
+                    class: "p-3 border-t border-white/10 flex gap-2",
+                    input {
+                        r#type: "text",
+                        class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded-lg text-white text-sm",
+                        placeholder: "Whisper...",
+                        value: "{draft}",
+                        oninput: move |e| draft.set(e.value()),
+                        onkeydown: move |e| {
+                            if e.key() == Key::Enter {
+                                send();
+                            }
+                        },
+                    }
+                    button {
+                        class: "px-4 py-2 bg-purple-600 text-white border-none rounded-lg cursor-pointer text-sm disabled:opacity-50",
+                        disabled: draft.read().trim().is_empty(),
+                        onclick: move |_| send(),
+                        "Send"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,385 @@
+//! Presence strip - floating webcam/avatar tiles for remote play
+//!
+//! Each participant can show a live webcam or an animated avatar tile.
+//! The strip floats over the current view and can be dragged anywhere.
+//! Video travels peer-to-peer via the `MediaPresencePort`; signaling is
+//! relayed through the Engine WebSocket.
+
+use dioxus::prelude::*;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::application::ports::outbound::{MediaPresencePort, PresenceMode};
+use crate::presentation::state::presence_state::{is_offerer, should_link};
+use crate::presentation::state::SessionState;
+
+/// DOM id of the `<video>` element showing the local camera
+const LOCAL_VIDEO_ID: &str = "presence-video-local";
+
+fn remote_video_id(user_id: &str) -> String {
+    format!("presence-video-{}", user_id)
+}
+
+fn initials(name: &str) -> String {
+    name.split_whitespace()
+        .filter_map(|w| w.chars().next())
+        .take(2)
+        .collect::<String>()
+        .to_uppercase()
+}
+
+/// Change the local presence mode
+///
+/// Webcam mode is only announced once the camera is live (see the
+/// `on_local_video` callback in `PresenceStrip`).
+fn select_mode(media: &Rc<dyn MediaPresencePort>, session_state: &SessionState, mode: PresenceMode) {
+    let mut presence = session_state.presence.clone();
+    if mode == PresenceMode::Webcam {
+        match media.start_local_video() {
+            Ok(()) => presence.camera_pending.set(true),
+            Err(e) => tracing::warn!("Could not start camera: {}", e),
+        }
+        return;
+    }
+
+    media.stop_local_video();
+    presence.camera_pending.set(false);
+    presence.local_mode.set(mode);
+    if let Some(client) = session_state.engine_client().read().as_ref() {
+        if let Err(e) = client.set_presence_mode(mode) {
+            tracing::error!("Failed to send presence mode: {}", e);
+        }
+    }
+}
+
+/// Floating, draggable strip of presence tiles
+#[component]
+pub fn PresenceStrip() -> Element {
+    let session_state = use_context::<SessionState>();
+    let media = use_context::<Rc<dyn MediaPresencePort>>();
+    let presence = session_state.presence.clone();
+    let mut strip_offset = presence.strip_offset;
+
+    let mut collapsed = use_signal(|| false);
+    // (mouse x, mouse y, offset x, offset y) at drag start
+    let mut drag_start: Signal<Option<(f64, f64, f64, f64)>> = use_signal(|| None);
+    // Peer links currently established, keyed by peer with the modes they were made for
+    let mut links: Signal<HashMap<String, (PresenceMode, PresenceMode)>> = use_signal(HashMap::new);
+
+    // Wire adapter callbacks once
+    use_hook({
+        let media = media.clone();
+        let session_state = session_state.clone();
+        move || {
+            let mut presence = session_state.presence.clone();
+            media.on_remote_video(Box::new(move |peer_id, live| {
+                presence.set_remote_video(peer_id, live);
+            }));
+
+            let engine_client = session_state.engine_client();
+            media.on_signal(Box::new(move |peer_id, signal| {
+                if let Some(client) = engine_client.peek().as_ref() {
+                    if let Err(e) = client.send_presence_signal(&peer_id, signal) {
+                        tracing::error!("Failed to relay presence signal: {}", e);
+                    }
+                }
+            }));
+
+            let engine_client = session_state.engine_client();
+            let mut camera_pending = session_state.presence.camera_pending;
+            let mut local_mode = session_state.presence.local_mode;
+            media.on_local_video(Box::new(move |live| {
+                if !*camera_pending.peek() {
+                    return;
+                }
+                camera_pending.set(false);
+                if live {
+                    local_mode.set(PresenceMode::Webcam);
+                    if let Some(client) = engine_client.peek().as_ref() {
+                        if let Err(e) = client.set_presence_mode(PresenceMode::Webcam) {
+                            tracing::error!("Failed to send presence mode: {}", e);
+                        }
+                    }
+                }
+            }));
+        }
+    });
+
+    // Hand incoming signaling messages to the adapter
+    {
+        let media = media.clone();
+        let mut presence = presence.clone();
+        use_effect(move || {
+            if presence.incoming_signals.read().is_empty() {
+                return;
+            }
+            for (peer_id, signal) in presence.take_signals() {
+                if let Err(e) = media.handle_signal(&peer_id, signal) {
+                    tracing::warn!("Ignoring presence signal from {}: {}", peer_id, e);
+                }
+            }
+        });
+    }
+
+    // Open, renegotiate, or close peer links as presence modes change
+    {
+        let media = media.clone();
+        let presence = presence.clone();
+        let user_id = session_state.user_id();
+        use_effect(move || {
+            let local_mode = *presence.local_mode.read();
+            let Some(local_id) = user_id.read().clone() else {
+                return;
+            };
+            let wanted: HashMap<String, (PresenceMode, PresenceMode)> = presence
+                .participants
+                .read()
+                .iter()
+                .filter(|p| should_link(local_mode, p.mode))
+                .map(|p| (p.user_id.clone(), (local_mode, p.mode)))
+                .collect();
+
+            let current = links.peek().clone();
+            for peer_id in current.keys().filter(|id| !wanted.contains_key(*id)) {
+                media.disconnect_peer(peer_id);
+            }
+            for (peer_id, key) in wanted.iter() {
+                if current.get(peer_id) == Some(key) {
+                    continue;
+                }
+                // The non-offering side drops its stale link and waits for a new offer
+                media.disconnect_peer(peer_id);
+                if is_offerer(&local_id, peer_id) {
+                    if let Err(e) = media.connect_peer(peer_id) {
+                        tracing::warn!("Failed to connect to {}: {}", peer_id, e);
+                    }
+                }
+            }
+            links.set(wanted);
+        });
+    }
+
+    // Release the camera and peer connections when leaving the session
+    {
+        let media = media.clone();
+        use_drop(move || media.disconnect_all());
+    }
+
+    let local_mode = *presence.local_mode.read();
+    let camera_pending = *presence.camera_pending.read();
+    let supports_video = media.supports_video();
+    let (offset_x, offset_y) = *strip_offset.read();
+    let remote_video = presence.remote_video.read().clone();
+    let hidden = presence.hidden_users.read().clone();
+    let participants = presence.participants.read().clone();
+    let visible: Vec<_> = participants
+        .iter()
+        .filter(|p| p.mode != PresenceMode::Off && !hidden.contains(&p.user_id))
+        .cloned()
+        .collect();
+    let hidden_participants: Vec<_> = participants
+        .iter()
+        .filter(|p| p.mode != PresenceMode::Off && hidden.contains(&p.user_id))
+        .cloned()
+        .collect();
+
+    rsx! {
+        // Capture pointer movement anywhere on screen while dragging
+        if drag_start.read().is_some() {
+            div {
+                class: "fixed inset-0 z-[950] cursor-grabbing",
+                onmousemove: move |e| {
+                    if let Some((mx, my, ox, oy)) = *drag_start.peek() {
+                        let point = e.client_coordinates();
+                        strip_offset.set((ox + point.x - mx, oy + point.y - my));
+                    }
+                },
+                onmouseup: move |_| drag_start.set(None),
+            }
+        }
+
+        div {
+            class: "presence-strip fixed bottom-4 right-4 z-[900] flex flex-col gap-2 p-2 bg-dark-surface/90 border border-gray-700 rounded-lg shadow-lg select-none",
+            style: "transform: translate({offset_x}px, {offset_y}px);",
+
+            // Header: drag handle and mode controls
+            div {
+                class: "flex items-center gap-2",
+
+                span {
+                    class: "text-gray-500 text-xs cursor-grab px-1",
+                    title: "Drag to move",
+                    onmousedown: move |e| {
+                        let point = e.client_coordinates();
+                        let (ox, oy) = *strip_offset.peek();
+                        drag_start.set(Some((point.x, point.y, ox, oy)));
+                    },
+                    "⠿"
+                }
+
+                for (mode, label) in [
+                    (PresenceMode::Off, "Off"),
+                    (PresenceMode::Avatar, "Avatar"),
+                    (PresenceMode::Webcam, "Camera"),
+                ] {
+                    {
+                        let media = media.clone();
+                        let session_state = session_state.clone();
+                        let active = local_mode == mode;
+                        let disabled = mode == PresenceMode::Webcam && (!supports_video || camera_pending);
+                        rsx! {
+                            button {
+                                key: "{label}",
+                                class: format!(
+                                    "py-0.5 px-2 text-xs rounded border-none cursor-pointer {}",
+                                    if active { "bg-blue-500 text-white" } else { "bg-dark-bg text-gray-400" }
+                                ),
+                                disabled: disabled,
+                                title: if mode == PresenceMode::Webcam && !supports_video { "Webcam is not available on this platform" } else { "" },
+                                onclick: move |_| select_mode(&media, &session_state, mode),
+                                if mode == PresenceMode::Webcam && camera_pending { "Starting…" } else { "{label}" }
+                            }
+                        }
+                    }
+                }
+
+                button {
+                    class: "ml-auto py-0.5 px-1.5 text-xs bg-transparent text-gray-400 border-none cursor-pointer",
+                    title: if *collapsed.read() { "Show tiles" } else { "Hide tiles" },
+                    onclick: move |_| {
+                        let next = !*collapsed.peek();
+                        collapsed.set(next);
+                    },
+                    if *collapsed.read() { "▴" } else { "▾" }
+                }
+            }
+
+            if !*collapsed.read() {
+                div {
+                    class: "flex gap-2",
+
+                    // Local tile
+                    if local_mode == PresenceMode::Webcam {
+                        PresenceTile {
+                            label: "You".to_string(),
+                            video_id: Some(LOCAL_VIDEO_ID.to_string()),
+                            on_video_mounted: {
+                                let media = media.clone();
+                                move |_| media.attach_local_video(LOCAL_VIDEO_ID)
+                            },
+                        }
+                    } else if local_mode == PresenceMode::Avatar {
+                        PresenceTile {
+                            label: "You".to_string(),
+                        }
+                    }
+
+                    // Remote tiles
+                    for participant in visible.iter() {
+                        {
+                            let has_video = participant.mode == PresenceMode::Webcam
+                                && remote_video.contains(&participant.user_id);
+                            let user_id = participant.user_id.clone();
+                            let video_id = remote_video_id(&user_id);
+                            let media = media.clone();
+                            let mut presence = presence.clone();
+                            let hide_user_id = user_id.clone();
+                            rsx! {
+                                PresenceTile {
+                                    key: "{user_id}-{has_video}",
+                                    label: participant.display_name().to_string(),
+                                    video_id: if has_video { Some(video_id.clone()) } else { None },
+                                    on_video_mounted: move |_| media.attach_remote_video(&user_id, &video_id),
+                                    on_hide: move |_| presence.toggle_hidden(&hide_user_id),
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Per-user toggles for hidden tiles
+                if !hidden_participants.is_empty() {
+                    div {
+                        class: "flex flex-wrap gap-1 items-center",
+                        span { class: "text-gray-500 text-[0.625rem]", "Hidden:" }
+                        for participant in hidden_participants.iter() {
+                            {
+                                let mut presence = presence.clone();
+                                let user_id = participant.user_id.clone();
+                                rsx! {
+                                    button {
+                                        key: "{participant.user_id}",
+                                        class: "py-0.5 px-1.5 text-[0.625rem] bg-dark-bg text-gray-400 border border-gray-700 rounded cursor-pointer",
+                                        title: "Show tile",
+                                        onclick: move |_| presence.toggle_hidden(&user_id),
+                                        "{participant.display_name()}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Props for PresenceTile
+#[derive(Props, Clone, PartialEq)]
+struct PresenceTileProps {
+    label: String,
+    /// DOM id for the video element; `None` renders the animated avatar
+    video_id: Option<String>,
+    on_video_mounted: Option<EventHandler<()>>,
+    on_hide: Option<EventHandler<()>>,
+}
+
+/// A single webcam or avatar tile
+#[component]
+fn PresenceTile(props: PresenceTileProps) -> Element {
+    let avatar_initials = initials(&props.label);
+
+    rsx! {
+        div {
+            class: "relative w-32 h-24 bg-black rounded-md overflow-hidden group",
+
+            if let Some(video_id) = props.video_id.clone() {
+                video {
+                    id: "{video_id}",
+                    class: "w-full h-full object-cover",
+                    autoplay: true,
+                    muted: true,
+                    playsinline: true,
+                    onmounted: move |_| {
+                        if let Some(handler) = props.on_video_mounted.as_ref() {
+                            handler.call(());
+                        }
+                    },
+                }
+            } else {
+                div {
+                    class: "w-full h-full flex items-center justify-center bg-gradient-to-br from-purple-900 to-dark-bg",
+                    div {
+                        class: "w-12 h-12 rounded-full bg-purple-500/30 border-2 border-purple-400 flex items-center justify-center text-white font-semibold animate-pulse",
+                        "{avatar_initials}"
+                    }
+                }
+            }
+
+            span {
+                class: "absolute bottom-0 left-0 right-0 px-1.5 py-0.5 bg-black/60 text-white text-[0.625rem] truncate",
+                "{props.label}"
+            }
+
+            if let Some(on_hide) = props.on_hide {
+                button {
+                    aria_label: "Hide tile",
+                    class: "absolute top-0.5 right-0.5 w-5 h-5 bg-black/60 text-gray-300 border-none rounded-full cursor-pointer text-xs leading-none opacity-0 group-hover:opacity-100",
+                    title: "Hide tile",
+                    onclick: move |_| on_hide.call(()),
+                    "×"
+                }
+            }
+        }
+    }
+}
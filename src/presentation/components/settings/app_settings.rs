@@ -6,7 +6,11 @@
 
 use dioxus::prelude::*;
 use crate::application::dto::AppSettings;
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{save_backdrop_preferences, save_tour_progress, TourView};
 use crate::presentation::services::use_settings_service;
+use crate::presentation::state::perf_probe::{self, save_overlay_enabled};
+use crate::presentation::state::use_display_state;
 
 /// Application Settings Panel component
 ///
@@ -15,6 +19,11 @@ use crate::presentation::services::use_settings_service;
 #[component]
 pub fn AppSettingsPanel() -> Element {
     let settings_service = use_settings_service();
+    let platform = use_context::<Platform>();
+    let display_state = use_display_state();
+    let mut backdrop_prefs = display_state.backdrop;
+    let mut perf_overlay = display_state.perf_overlay;
+    let mut tours = display_state.tours;
 
     // State for the form fields
     let mut settings = use_signal(|| AppSettings::default());
@@ -151,6 +160,85 @@ pub fn AppSettingsPanel() -> Element {
                 div {
                     class: "flex-1 overflow-y-auto bg-gray-900 rounded-lg p-6 space-y-6",
 
+                    // Display preferences are stored locally, not on the Engine
+                    SettingsSection {
+                        title: "Display (this device)",
+                        description: "Saved in this browser only; other players keep their own choices",
+
+                        BooleanField {
+                            label: "Animated Backdrops",
+                            description: "Play looping video and animated image backdrops where locations have them",
+                            value: backdrop_prefs.read().animated,
+                            onchange: {
+                                let platform = platform.clone();
+                                move |val: bool| {
+                                    backdrop_prefs.with_mut(|p| p.animated = val);
+                                    save_backdrop_preferences(&platform, &backdrop_prefs.peek());
+                                }
+                            }
+                        }
+
+                        BooleanField {
+                            label: "Data Saver",
+                            description: "Skip video backdrops; animated images and static backdrops still load",
+                            value: backdrop_prefs.read().data_saver,
+                            onchange: {
+                                let platform = platform.clone();
+                                move |val: bool| {
+                                    backdrop_prefs.with_mut(|p| p.data_saver = val);
+                                    save_backdrop_preferences(&platform, &backdrop_prefs.peek());
+                                }
+                            }
+                        }
+
+                        BooleanField {
+                            label: "Performance Overlay",
+                            description: "Developer overlay with re-render counts, signal writes, message rate, and frame timing",
+                            value: *perf_overlay.read(),
+                            onchange: {
+                                let platform = platform.clone();
+                                move |val: bool| {
+                                    perf_overlay.set(val);
+                                    perf_probe::set_enabled(val);
+                                    save_overlay_enabled(&platform, val);
+                                }
+                            }
+                        }
+                    }
+
+                    // Onboarding tours, also tracked per device
+                    SettingsSection {
+                        title: "Guided Tours",
+                        description: "Walkthroughs shown the first time each view opens",
+
+                        for view in TourView::all() {
+                            div {
+                                key: "{view.display_name()}",
+                                class: "flex items-center justify-between gap-3",
+                                div {
+                                    class: "flex flex-col",
+                                    span { class: "text-gray-300 text-sm font-medium", "{view.display_name()}" }
+                                    span {
+                                        class: "text-gray-500 text-xs",
+                                        if tours.read().is_complete(view) { "Finished" } else { "Shows next time the view opens" }
+                                    }
+                                }
+                                button {
+                                    disabled: !tours.read().is_complete(view),
+                                    onclick: {
+                                        let platform = platform.clone();
+                                        move |_| {
+                                            tours.with_mut(|p| p.restart(view));
+                                            save_tour_progress(&platform, &tours.peek());
+                                        }
+                                    },
+                                    class: "px-3 py-1 bg-gray-700 text-white border-0 rounded text-sm cursor-pointer disabled:opacity-50",
+                                    "Restart tour"
+                                }
+                            }
+                        }
+                    }
+
                     // Session Settings
                     SettingsSection {
                         title: "Session Settings",
@@ -561,6 +649,7 @@ fn BooleanField(props: BooleanFieldProps) -> Element {
 
                     input {
                         r#type: "checkbox",
+                        role: "switch",
                         class: "sr-only peer",
                         checked: props.value,
                         onchange: move |evt| {
@@ -0,0 +1,291 @@
+//! Asset Storage Panel - Storage usage and cleanup for generated assets
+//!
+//! Shows how much of the world's Engine-side quota is used, broken down by
+//! entity and asset type, and lets the DM bulk-delete assets that are no
+//! longer in use.
+
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+
+use crate::application::services::{
+    cleanup_candidates, format_bytes, usage_by, AssetStorageReport, CleanupFilter, QuotaLevel,
+};
+use crate::presentation::services::use_asset_service;
+
+/// Props for AssetStoragePanel
+#[derive(Props, Clone, PartialEq)]
+pub struct AssetStoragePanelProps {
+    pub world_id: String,
+}
+
+/// Asset storage tab content
+#[component]
+pub fn AssetStoragePanel(props: AssetStoragePanelProps) -> Element {
+    let asset_service = use_asset_service();
+
+    let mut report: Signal<AssetStorageReport> = use_signal(AssetStorageReport::default);
+    let mut is_loading = use_signal(|| true);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut filter = use_signal(CleanupFilter::default);
+    // Candidates the DM unticked; everything else is selected by default
+    let mut excluded: Signal<HashSet<String>> = use_signal(HashSet::new);
+    let mut confirming = use_signal(|| false);
+    let mut is_deleting = use_signal(|| false);
+    let mut notice: Signal<Option<String>> = use_signal(|| None);
+
+    {
+        let world_id = props.world_id.clone();
+        let svc = asset_service.clone();
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.get_storage_report(&world_id).await {
+                    Ok(r) => report.set(r),
+                    Err(e) => error.set(Some(format!("Failed to load storage usage: {}", e))),
+                }
+                is_loading.set(false);
+            });
+        });
+    }
+
+    let report_read = report.read();
+    let level = QuotaLevel::for_usage(report_read.used_bytes, report_read.quota_bytes);
+    let percent = report_read
+        .quota_bytes
+        .filter(|q| *q > 0)
+        .map(|q| (report_read.used_bytes as f64 / q as f64 * 100.0).min(100.0));
+    let by_entity_type = usage_by(&report_read.assets, |a| a.entity_type.clone());
+    let by_asset_type = usage_by(&report_read.assets, |a| a.asset_type.clone());
+    let current_filter = *filter.read();
+    let candidates: Vec<_> = cleanup_candidates(&report_read.assets, current_filter)
+        .into_iter()
+        .cloned()
+        .collect();
+    drop(report_read);
+
+    let selected: Vec<String> = candidates
+        .iter()
+        .filter(|a| !excluded.read().contains(&a.id))
+        .map(|a| a.id.clone())
+        .collect();
+    let selected_bytes: u64 = candidates
+        .iter()
+        .filter(|a| selected.contains(&a.id))
+        .map(|a| a.size_bytes)
+        .sum();
+
+    let (bar_class, level_text) = match level {
+        QuotaLevel::Ok => ("bg-green-500", None),
+        QuotaLevel::Warning => ("bg-amber-500", Some("Approaching the storage quota. Consider cleaning up unused assets.")),
+        QuotaLevel::Critical => ("bg-red-500", Some("Storage is nearly full. New generations may fail until space is freed.")),
+    };
+
+    let delete_selected = {
+        let world_id = props.world_id.clone();
+        let ids = selected.clone();
+        move |_| {
+            let world_id = world_id.clone();
+            let ids = ids.clone();
+            let svc = asset_service.clone();
+            is_deleting.set(true);
+            spawn(async move {
+                match svc.bulk_delete_assets(&world_id, &ids).await {
+                    Ok(result) => {
+                        let deleted: HashSet<String> = result.deleted.into_iter().collect();
+                        let mut r = report.write();
+                        r.assets.retain(|a| !deleted.contains(&a.id));
+                        r.used_bytes = r.used_bytes.saturating_sub(result.freed_bytes);
+                        notice.set(Some(format!(
+                            "Deleted {} asset(s), freed {}",
+                            deleted.len(),
+                            format_bytes(result.freed_bytes)
+                        )));
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to delete assets: {}", e))),
+                }
+                is_deleting.set(false);
+                confirming.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "asset-storage-panel h-full flex flex-col gap-4 p-4 overflow-y-auto",
+
+            h2 { class: "text-white m-0 text-xl", "Asset Storage" }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-500 bg-opacity-10 text-red-500 text-sm rounded-md",
+                    "{err}"
+                }
+            }
+            if let Some(msg) = notice.read().as_ref() {
+                div {
+                    class: "p-3 bg-green-500 bg-opacity-10 text-green-400 text-sm rounded-md",
+                    "{msg}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-center text-gray-500 py-8", "Loading storage usage..." }
+            } else {
+                // Usage against quota
+                div {
+                    class: "p-4 bg-dark-surface rounded-lg flex flex-col gap-2",
+                    div {
+                        class: "flex justify-between text-sm",
+                        span { class: "text-white", "{format_bytes(report.read().used_bytes)} used" }
+                        if let Some(quota) = report.read().quota_bytes {
+                            span { class: "text-gray-400", "of {format_bytes(quota)}" }
+                        } else {
+                            span { class: "text-gray-500", "No quota configured" }
+                        }
+                    }
+                    if let Some(percent) = percent {
+                        div {
+                            class: "h-2 bg-dark-bg rounded-full overflow-hidden",
+                            div {
+                                class: "h-full {bar_class}",
+                                style: "width: {percent:.1}%;",
+                            }
+                        }
+                    }
+                    if let Some(text) = level_text {
+                        div { class: "text-amber-400 text-xs", "⚠ {text}" }
+                    }
+                }
+
+                // Breakdowns
+                div {
+                    class: "grid grid-cols-2 gap-4",
+                    UsageTable { title: "By entity type", rows: by_entity_type }
+                    UsageTable { title: "By asset type", rows: by_asset_type }
+                }
+
+                // Cleanup
+                div {
+                    class: "p-4 bg-dark-surface rounded-lg flex flex-col gap-3",
+                    div {
+                        class: "flex justify-between items-center",
+                        h3 { class: "text-white m-0 text-base", "Cleanup" }
+                        select {
+                            value: "{current_filter.display_name()}",
+                            onchange: move |e| {
+                                if let Some(f) = CleanupFilter::all().into_iter().find(|f| f.display_name() == e.value()) {
+                                    filter.set(f);
+                                    excluded.write().clear();
+                                    confirming.set(false);
+                                }
+                            },
+                            class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                            for f in CleanupFilter::all() {
+                                option { value: "{f.display_name()}", "{f.display_name()}" }
+                            }
+                        }
+                    }
+                    p { class: "m-0 text-gray-500 text-xs", "{current_filter.description()}" }
+
+                    if candidates.is_empty() {
+                        div { class: "text-gray-500 text-sm italic", "Nothing to clean up" }
+                    } else {
+                        div {
+                            class: "flex flex-col gap-1 max-h-[320px] overflow-y-auto",
+                            for asset in candidates.iter().cloned() {
+                                {
+                                    let is_selected = selected.contains(&asset.id);
+                                    let id = asset.id.clone();
+                                    let owner = asset.entity_name.clone().unwrap_or_else(|| asset.entity_id.clone());
+                                    rsx! {
+                                        label {
+                                            key: "{asset.id}",
+                                            class: "flex items-center gap-2 py-1 px-2 bg-dark-bg rounded text-sm cursor-pointer",
+                                            input {
+                                                r#type: "checkbox",
+                                                checked: is_selected,
+                                                onchange: move |e| {
+                                                    if e.checked() {
+                                                        excluded.write().remove(&id);
+                                                    } else {
+                                                        excluded.write().insert(id.clone());
+                                                    }
+                                                },
+                                            }
+                                            span { class: "flex-1 text-white truncate", "{owner}" }
+                                            span { class: "text-gray-400 text-xs", "{asset.entity_type} · {asset.asset_type}" }
+                                            if let Some(label) = asset.label.as_ref() {
+                                                span { class: "text-gray-500 text-xs truncate max-w-[120px]", "{label}" }
+                                            }
+                                            span { class: "text-gray-300 text-xs font-mono w-20 text-right", "{format_bytes(asset.size_bytes)}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        div {
+                            class: "flex justify-between items-center",
+                            span {
+                                class: "text-gray-400 text-sm",
+                                "{selected.len()} selected · {format_bytes(selected_bytes)}"
+                            }
+                            if *confirming.read() {
+                                div {
+                                    class: "flex gap-2 items-center",
+                                    span { class: "text-red-400 text-sm", "Delete permanently?" }
+                                    button {
+                                        onclick: move |_| confirming.set(false),
+                                        disabled: *is_deleting.read(),
+                                        class: "py-2 px-4 bg-gray-700 text-white border-0 rounded-md cursor-pointer text-sm",
+                                        "Cancel"
+                                    }
+                                    button {
+                                        onclick: delete_selected,
+                                        disabled: *is_deleting.read(),
+                                        class: "py-2 px-4 bg-red-500 text-white border-0 rounded-md cursor-pointer text-sm disabled:opacity-50",
+                                        if *is_deleting.read() { "Deleting..." } else { "Delete" }
+                                    }
+                                }
+                            } else {
+                                button {
+                                    onclick: move |_| {
+                                        notice.set(None);
+                                        confirming.set(true);
+                                    },
+                                    disabled: selected.is_empty(),
+                                    class: "py-2 px-4 bg-red-500 text-white border-0 rounded-md cursor-pointer text-sm disabled:opacity-50",
+                                    "Delete Selected"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Size totals for one grouping
+#[component]
+fn UsageTable(title: &'static str, rows: Vec<(String, u64)>) -> Element {
+    rsx! {
+        div {
+            class: "p-4 bg-dark-surface rounded-lg",
+            h3 { class: "text-gray-400 text-xs uppercase m-0 mb-2", "{title}" }
+            if rows.is_empty() {
+                div { class: "text-gray-500 text-sm", "No assets" }
+            }
+            for (name, bytes) in rows {
+                div {
+                    class: "flex justify-between py-1 text-sm",
+                    span { class: "text-white capitalize", "{name}" }
+                    span { class: "text-gray-300 font-mono", "{format_bytes(bytes)}" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Directorial presets editor - The tone presets offered in the Director panel
+//!
+//! Edits the world's `directorial_presets` setting in place; the World
+//! Settings panel saves them with the rest of its form.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::DirectorialPreset;
+use crate::application::services::{guidelines_text, parse_guidelines};
+
+/// Tones offered for a preset; the Director panel's tone list
+pub const PRESET_TONES: &[&str] = &[
+    "Serious",
+    "Lighthearted",
+    "Tense",
+    "Mysterious",
+    "Comedic",
+    "Romantic",
+    "Tragic",
+    "Suspenseful",
+];
+
+/// List editor for a world's directorial presets
+#[component]
+pub fn DirectorialPresetsEditor(presets: Vec<DirectorialPreset>, on_change: EventHandler<Vec<DirectorialPreset>>) -> Element {
+    rsx! {
+        div {
+            class: "flex flex-col gap-3",
+
+            if presets.is_empty() {
+                p { class: "text-gray-500 text-sm m-0", "No presets. The Director panel offers tones only." }
+            }
+
+            for (index, preset) in presets.iter().cloned().enumerate() {
+                div {
+                    key: "{index}",
+                    class: "p-3 bg-gray-800 rounded-lg flex flex-col gap-2",
+
+                    div {
+                        class: "flex items-center gap-2",
+                        input {
+                            r#type: "text",
+                            aria_label: "Preset name",
+                            placeholder: "Name, e.g. Gritty",
+                            value: "{preset.name}",
+                            oninput: {
+                                let presets = presets.clone();
+                                move |e: FormEvent| on_change.call(with_preset(&presets, index, |p| p.name = e.value()))
+                            },
+                            class: "flex-1 px-2 py-1 bg-gray-900 border border-gray-700 rounded text-white text-sm",
+                        }
+                        select {
+                            aria_label: "Tone for {preset.name}",
+                            value: "{preset.tone}",
+                            onchange: {
+                                let presets = presets.clone();
+                                move |e: FormEvent| on_change.call(with_preset(&presets, index, |p| p.tone = e.value()))
+                            },
+                            class: "px-2 py-1 bg-gray-900 border border-gray-700 rounded text-white text-sm",
+                            for tone in PRESET_TONES.iter() {
+                                option { value: "{tone}", "{tone}" }
+                            }
+                        }
+                        button {
+                            r#type: "button",
+                            aria_label: "Remove {preset.name}",
+                            onclick: {
+                                let presets = presets.clone();
+                                move |_| {
+                                    let mut next = presets.clone();
+                                    next.remove(index);
+                                    on_change.call(next);
+                                }
+                            },
+                            class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer",
+                            "×"
+                        }
+                    }
+
+                    textarea {
+                        aria_label: "Style notes for {preset.name}",
+                        placeholder: "Style notes: how NPCs speak and describe things",
+                        rows: "2",
+                        value: "{preset.style_notes}",
+                        oninput: {
+                            let presets = presets.clone();
+                            move |e: FormEvent| on_change.call(with_preset(&presets, index, |p| p.style_notes = e.value()))
+                        },
+                        class: "px-2 py-1 bg-gray-900 border border-gray-700 rounded text-white text-sm resize-y",
+                    }
+                    textarea {
+                        aria_label: "Content guidelines for {preset.name}",
+                        placeholder: "Content guidelines, one per line",
+                        rows: "2",
+                        value: "{guidelines_text(&preset.content_guidelines)}",
+                        // Parsed when the field loses focus, so blank lines can be typed
+                        onchange: {
+                            let presets = presets.clone();
+                            move |e: FormEvent| {
+                                on_change.call(with_preset(&presets, index, |p| p.content_guidelines = parse_guidelines(&e.value())))
+                            }
+                        },
+                        class: "px-2 py-1 bg-gray-900 border border-gray-700 rounded text-white text-sm resize-y",
+                    }
+                }
+            }
+
+            button {
+                r#type: "button",
+                onclick: {
+                    let presets = presets.clone();
+                    move |_| {
+                        let mut next = presets.clone();
+                        next.push(DirectorialPreset {
+                            name: String::new(),
+                            tone: PRESET_TONES[0].to_string(),
+                            style_notes: String::new(),
+                            content_guidelines: Vec::new(),
+                        });
+                        on_change.call(next);
+                    }
+                },
+                class: "self-start px-3 py-1 bg-transparent text-blue-400 border border-blue-500/40 rounded cursor-pointer text-sm",
+                "+ Add preset"
+            }
+        }
+    }
+}
+
+/// The presets with one of them changed
+fn with_preset(
+    presets: &[DirectorialPreset],
+    index: usize,
+    change: impl FnOnce(&mut DirectorialPreset),
+) -> Vec<DirectorialPreset> {
+    let mut next = presets.to_vec();
+    if let Some(preset) = next.get_mut(index) {
+        change(preset);
+    }
+    next
+}
@@ -5,8 +5,11 @@
 //! where DMs can tune settings for the current world/session.
 
 use dioxus::prelude::*;
-use crate::application::dto::AppSettings;
+use crate::application::dto::{AppSettings, DirectorialPreset};
+use crate::application::services::validate_presets;
+use crate::presentation::components::settings::directorial_presets::DirectorialPresetsEditor;
 use crate::presentation::services::use_settings_service;
+use crate::presentation::state::use_session_state;
 
 /// Props for the Game Settings Panel
 #[derive(Props, Clone, PartialEq)]
@@ -22,6 +25,9 @@ pub struct GameSettingsPanelProps {
 #[component]
 pub fn GameSettingsPanel(props: GameSettingsPanelProps) -> Element {
     let settings_service = use_settings_service();
+    let session_state = use_session_state();
+    let mut story_capture = session_state.story_capture;
+    let mut auto_resolve = session_state.challenge_auto_resolve();
 
     // State for the form fields
     let mut settings = use_signal(|| AppSettings::default());
@@ -64,6 +70,11 @@ pub fn GameSettingsPanel(props: GameSettingsPanelProps) -> Element {
         let svc = service_for_save.clone();
         let wid = world_id_for_save.clone();
         let current_settings = settings.read().clone();
+        if let Err(e) = validate_presets(&current_settings.directorial_presets) {
+            error.set(Some(e));
+            success_message.set(None);
+            return;
+        }
         spawn(async move {
             is_saving.set(true);
             error.set(None);
@@ -71,6 +82,9 @@ pub fn GameSettingsPanel(props: GameSettingsPanelProps) -> Element {
 
             match svc.update_for_world(&wid, &current_settings).await {
                 Ok(updated_settings) => {
+                    // Capture rules apply to the running session straight away
+                    story_capture.write().set_rules(updated_settings.story_capture.clone());
+                    auto_resolve.set(updated_settings.challenge_auto_resolve.clone());
                     settings.set(updated_settings);
                     success_message.set(Some("World settings saved!".to_string()));
                     is_saving.set(false);
@@ -94,6 +108,8 @@ pub fn GameSettingsPanel(props: GameSettingsPanelProps) -> Element {
 
             match svc.reset_for_world(&wid).await {
                 Ok(reset_settings) => {
+                    story_capture.write().set_rules(reset_settings.story_capture.clone());
+                    auto_resolve.set(reset_settings.challenge_auto_resolve.clone());
                     settings.set(reset_settings);
                     success_message.set(Some("Reset to global defaults!".to_string()));
                     is_saving.set(false);
@@ -222,6 +238,26 @@ pub fn GameSettingsPanel(props: GameSettingsPanelProps) -> Element {
                                 success_message.set(None);
                             }
                         }
+
+                        BooleanField {
+                            label: "Auto-Apply Clear Successes",
+                            description: "Apply successes that beat the DC by the margin below without approval",
+                            value: settings.read().challenge_auto_resolve.enabled,
+                            onchange: move |val: bool| {
+                                settings.with_mut(|s| s.challenge_auto_resolve.enabled = val);
+                                success_message.set(None);
+                            }
+                        }
+
+                        NumberField {
+                            label: "Auto-Apply Margin",
+                            description: "How far over the DC a roll must be; closer results still queue for approval",
+                            value: settings.read().challenge_auto_resolve.min_margin as usize,
+                            onchange: move |val: usize| {
+                                settings.with_mut(|s| s.challenge_auto_resolve.min_margin = val as u32);
+                                success_message.set(None);
+                            }
+                        }
                     }
 
                     // Animation Settings
@@ -250,6 +286,76 @@ pub fn GameSettingsPanel(props: GameSettingsPanelProps) -> Element {
                         }
                     }
 
+                    // Tone presets for the Director panel
+                    SettingsSection {
+                        title: "Directorial Presets",
+                        description: "Tone, style notes and content guidelines the DM can switch between in the Director panel",
+
+                        DirectorialPresetsEditor {
+                            presets: settings.read().directorial_presets.clone(),
+                            on_change: move |presets: Vec<DirectorialPreset>| {
+                                settings.with_mut(|s| s.directorial_presets = presets);
+                                success_message.set(None);
+                            }
+                        }
+                    }
+
+                    // Story Capture
+                    SettingsSection {
+                        title: "Story Capture",
+                        description: "Session moments added to the timeline automatically",
+
+                        BooleanField {
+                            label: "Challenge Results",
+                            description: "Each resolved challenge with its roll and outcome",
+                            value: settings.read().story_capture.challenge_results,
+                            onchange: move |val: bool| {
+                                settings.with_mut(|s| s.story_capture.challenge_results = val);
+                                success_message.set(None);
+                            }
+                        }
+
+                        BooleanField {
+                            label: "Scene Transitions",
+                            description: "Moving from one scene to another",
+                            value: settings.read().story_capture.scene_transitions,
+                            onchange: move |val: bool| {
+                                settings.with_mut(|s| s.story_capture.scene_transitions = val);
+                                success_message.set(None);
+                            }
+                        }
+
+                        BooleanField {
+                            label: "Region Transitions",
+                            description: "A player character entering a new region",
+                            value: settings.read().story_capture.region_transitions,
+                            onchange: move |val: bool| {
+                                settings.with_mut(|s| s.story_capture.region_transitions = val);
+                                success_message.set(None);
+                            }
+                        }
+
+                        BooleanField {
+                            label: "NPC First Meetings",
+                            description: "The first time the party meets each NPC in a session",
+                            value: settings.read().story_capture.npc_first_meetings,
+                            onchange: move |val: bool| {
+                                settings.with_mut(|s| s.story_capture.npc_first_meetings = val);
+                                success_message.set(None);
+                            }
+                        }
+
+                        BooleanField {
+                            label: "DM Quick Markers",
+                            description: "Markers dropped from the Director panel",
+                            value: settings.read().story_capture.dm_markers,
+                            onchange: move |val: bool| {
+                                settings.with_mut(|s| s.story_capture.dm_markers = val);
+                                success_message.set(None);
+                            }
+                        }
+                    }
+
                     // LLM Context Budget (collapsible for advanced users)
                     CollapsibleSettingsSection {
                         title: "Advanced: LLM Token Budgets",
@@ -1,13 +1,20 @@
 //! Settings components - Application configuration interface
 //!
 //! Components for the Settings view, providing workflow configuration,
-//! ComfyUI integration settings, skills management, and general application preferences.
+//! ComfyUI integration settings, skills management, asset storage, the trash, and general
+//! application preferences.
 
 pub mod app_settings;
+pub mod asset_storage_panel;
+pub mod directorial_presets;
 pub mod game_settings;
+pub mod skill_import_modal;
 pub mod skills_panel;
+pub mod trash_panel;
 pub mod workflow_slot_list;
+pub mod workflow_clone_modal;
 pub mod workflow_config_editor;
+pub mod workflow_preset_modal;
 pub mod workflow_upload_modal;
 
 // Re-export the game settings panel for easy access
@@ -21,7 +28,7 @@ use crate::routes::Route;
 pub struct SettingsViewProps {
     /// World ID for skills management and routing
     pub world_id: String,
-    /// Selected sub-tab from route (workflows, skills)
+    /// Selected sub-tab from route (workflows, skills, assets, trash)
     #[props(default)]
     pub selected_tab: Option<String>,
 }
@@ -52,12 +59,24 @@ pub fn SettingsView(props: SettingsViewProps) -> Element {
                     world_id: props.world_id.clone(),
                     active: active_tab == "skills",
                 }
+                SettingsTabLink {
+                    label: "Asset Storage",
+                    subtab: "assets",
+                    world_id: props.world_id.clone(),
+                    active: active_tab == "assets",
+                }
                 SettingsTabLink {
                     label: "World Settings",
                     subtab: "world-settings",
                     world_id: props.world_id.clone(),
                     active: active_tab == "world-settings",
                 }
+                SettingsTabLink {
+                    label: "Trash",
+                    subtab: "trash",
+                    world_id: props.world_id.clone(),
+                    active: active_tab == "trash",
+                }
                 SettingsTabLink {
                     label: "App Settings",
                     subtab: "app-settings",
@@ -74,12 +93,18 @@ pub fn SettingsView(props: SettingsViewProps) -> Element {
                     "skills" => rsx! {
                         SkillsManagementTab { world_id: props.world_id.clone() }
                     },
+                    "assets" => rsx! {
+                        asset_storage_panel::AssetStoragePanel { world_id: props.world_id.clone() }
+                    },
                     "world-settings" => rsx! {
                         div {
                             class: "p-4",
                             game_settings::GameSettingsPanel { world_id: props.world_id.clone() }
                         }
                     },
+                    "trash" => rsx! {
+                        trash_panel::TrashPanel { world_id: props.world_id.clone() }
+                    },
                     "app-settings" => rsx! {
                         app_settings::AppSettingsPanel {}
                     },
@@ -130,6 +155,8 @@ fn AssetWorkflowsTab() -> Element {
     let mut show_upload_modal = use_signal(|| false);
     // Track which slot we're uploading for
     let mut upload_target_slot: Signal<Option<String>> = use_signal(|| None);
+    // Bumped to reload the slot list after a clone configures another slot
+    let mut slot_list_version = use_signal(|| 0u32);
 
     rsx! {
         div {
@@ -141,6 +168,7 @@ fn AssetWorkflowsTab() -> Element {
                 class: "left-panel flex flex-col gap-4 overflow-hidden",
 
                 workflow_slot_list::WorkflowSlotList {
+                    key: "{slot_list_version}",
                     selected_slot: selected_slot.read().clone(),
                     on_select: move |slot: String| selected_slot.set(Some(slot)),
                     on_configure: move |slot: String| {
@@ -156,6 +184,7 @@ fn AssetWorkflowsTab() -> Element {
 
                 if let Some(slot) = selected_slot.read().clone() {
                     workflow_config_editor::WorkflowConfigEditor {
+                        key: "{slot}",
                         slot: slot.clone(),
                         on_close: move |_| selected_slot.set(None),
                         on_reconfigure: move |_| {
@@ -166,6 +195,11 @@ fn AssetWorkflowsTab() -> Element {
                             // Deselect the slot and refresh the list
                             selected_slot.set(None);
                         },
+                        on_cloned: move |target: String| {
+                            selected_slot.set(Some(target));
+                            let next = *slot_list_version.peek() + 1;
+                            slot_list_version.set(next);
+                        },
                     }
                 } else {
                     WorkflowEmptyStatePanel {}
@@ -213,7 +247,10 @@ fn SkillsManagementTab(props: SkillsManagementTabProps) -> Element {
     let mut error: Signal<Option<String>> = use_signal(|| None);
     let mut show_hidden = use_signal(|| false);
     let mut show_add_form = use_signal(|| false);
-    let _editing_skill: Signal<Option<String>> = use_signal(|| None);
+    let mut show_import = use_signal(|| false);
+    let mut editing_skill: Signal<Option<String>> = use_signal(|| None);
+    // Skill being dragged to a new position
+    let dragging: Signal<Option<String>> = use_signal(|| None);
 
     // Clone world_id for handlers
     let world_id = props.world_id.clone();
@@ -270,6 +307,18 @@ fn SkillsManagementTab(props: SkillsManagementTabProps) -> Element {
         SkillCategory::Custom => 11,
     });
 
+    let handle_skill_created = move |skill: crate::application::services::SkillData| {
+        skills.write().push(skill);
+        show_add_form.set(false);
+    };
+
+    let handle_skill_updated = move |skill: crate::application::services::SkillData| {
+        if let Some(existing) = skills.write().iter_mut().find(|s| s.id == skill.id) {
+            *existing = skill;
+        }
+        editing_skill.set(None);
+    };
+
     rsx! {
         div {
             class: "skills-management-tab h-full flex flex-col p-4",
@@ -286,6 +335,8 @@ fn SkillsManagementTab(props: SkillsManagementTabProps) -> Element {
                 div {
                     class: "flex gap-4 items-center",
 
+                    span { class: "text-gray-500 text-xs", "Drag skills to reorder them" }
+
                     // Show hidden toggle
                     label {
                         class: "flex items-center gap-2 text-gray-400 text-sm cursor-pointer",
@@ -297,6 +348,13 @@ fn SkillsManagementTab(props: SkillsManagementTabProps) -> Element {
                         "Show Hidden"
                     }
 
+                    // Import skills button
+                    button {
+                        onclick: move |_| show_import.set(true),
+                        class: "py-2 px-4 bg-gray-700 text-white border-0 rounded-md cursor-pointer text-sm",
+                        "Import Skills"
+                    }
+
                     // Add skill button
                     button {
                         onclick: move |_| show_add_form.set(true),
@@ -324,15 +382,10 @@ fn SkillsManagementTab(props: SkillsManagementTabProps) -> Element {
                         "Loading skills..."
                     }
                 } else if *show_add_form.read() {
-                    // Add form would go here - simplified for now
-                    div {
-                        class: "p-4 bg-dark-bg rounded-lg",
-                        p { class: "text-gray-400", "Add skill form (coming soon)" }
-                        button {
-                            onclick: move |_| show_add_form.set(false),
-                            class: "py-2 px-4 bg-gray-700 text-white border-0 rounded cursor-pointer",
-                            "Cancel"
-                        }
+                    skills_panel::AddSkillForm {
+                        world_id: world_id.clone(),
+                        on_created: handle_skill_created,
+                        on_cancel: move |_| show_add_form.set(false),
                     }
                 } else if skills.read().is_empty() {
                     div {
@@ -360,9 +413,24 @@ fn SkillsManagementTab(props: SkillsManagementTabProps) -> Element {
                                         div {
                                             class: "flex flex-col gap-1",
                                             for skill in cat_skills.iter() {
-                                                SkillRowInline {
-                                                    key: "{skill.id}",
-                                                    skill: skill.clone(),
+                                                if editing_skill.read().as_deref() == Some(skill.id.as_str()) {
+                                                    skills_panel::EditSkillForm {
+                                                        key: "{skill.id}",
+                                                        world_id: world_id.clone(),
+                                                        skill: skill.clone(),
+                                                        on_updated: handle_skill_updated,
+                                                        on_cancel: move |_| editing_skill.set(None),
+                                                    }
+                                                } else {
+                                                    skills_panel::SkillRow {
+                                                        key: "{skill.id}",
+                                                        world_id: world_id.clone(),
+                                                        skill: skill.clone(),
+                                                        skills_signal: skills,
+                                                        error_signal: error,
+                                                        dragging,
+                                                        on_edit: move |id| editing_skill.set(Some(id)),
+                                                    }
                                                 }
                                             }
                                         }
@@ -375,77 +443,29 @@ fn SkillsManagementTab(props: SkillsManagementTabProps) -> Element {
                     }
                 }
             }
-        }
-    }
-}
-
-/// Inline skill row for Skills Management tab
-#[derive(Props, Clone, PartialEq)]
-struct SkillRowInlineProps {
-    skill: crate::application::services::SkillData,
-}
-
-#[component]
-fn SkillRowInline(props: SkillRowInlineProps) -> Element {
-    let row_class = if props.skill.is_hidden {
-        "flex items-center gap-3 py-2 px-3 bg-gray-500 bg-opacity-20 rounded"
-    } else {
-        "flex items-center gap-3 py-2 px-3 bg-dark-bg rounded"
-    };
-    let name_color = if props.skill.is_hidden { "text-gray-500" } else { "text-white" };
-
-    rsx! {
-        div {
-            class: "{row_class}",
-
-            // Visibility indicator
-            div {
-                class: if props.skill.is_hidden {
-                    "w-2 h-2 rounded-full bg-gray-500"
-                } else {
-                    "w-2 h-2 rounded-full bg-green-500"
-                }
-            }
 
-            // Skill info
-            div {
-                class: "flex-1 min-w-0",
-
-                div {
-                    class: "flex items-center gap-2",
-
-                    span {
-                        class: "{name_color} font-medium",
-                        "{props.skill.name}"
-                    }
-
-                    if let Some(attr) = &props.skill.base_attribute {
-                        span {
-                            class: "text-purple-500 text-xs bg-purple-500 bg-opacity-10 py-0.5 px-1.5 rounded",
-                            "{attr}"
-                        }
-                    }
-
-                    if props.skill.is_custom {
-                        span {
-                            class: "text-amber-500 text-xs bg-amber-500 bg-opacity-10 py-0.5 px-1.5 rounded",
-                            "Custom"
+            // Import modal overlay
+            if *show_import.read() {
+                skill_import_modal::SkillImportModal {
+                    world_id: world_id.clone(),
+                    existing: skills.read().clone(),
+                    on_close: move |_| show_import.set(false),
+                    on_imported: move |imported: Vec<crate::application::services::SkillData>| {
+                        let mut list = skills.write();
+                        for skill in imported {
+                            match list.iter_mut().find(|s| s.id == skill.id) {
+                                Some(existing) => *existing = skill,
+                                None => list.push(skill),
+                            }
                         }
-                    }
-                }
-
-                if !props.skill.description.is_empty() {
-                    p {
-                        class: "text-gray-500 text-xs mt-1 mb-0 whitespace-nowrap overflow-hidden text-ellipsis",
-                        "{props.skill.description}"
-                    }
+                        show_import.set(false);
+                    },
                 }
             }
         }
     }
 }
 
-
 /// Empty state panel when no workflow is selected
 #[component]
 fn WorkflowEmptyStatePanel() -> Element {
@@ -0,0 +1,287 @@
+//! Skill Import Modal Component
+//!
+//! Modal for importing a batch of skills from a bundled preset library or
+//! pasted JSON, with a preview of how each skill will be applied.
+
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use crate::application::services::{
+    map_category, parse_skill_json, plan_import, unmapped_categories, ConflictResolution,
+    SkillCategory, SkillData, SkillImportAction, SkillPreset,
+};
+use crate::presentation::services::use_skill_service;
+use crate::presentation::state::use_modal_focus;
+
+/// Where the imported skills come from
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ImportSource {
+    Preset,
+    Json,
+}
+
+/// Props for the SkillImportModal component
+#[derive(Props, Clone, PartialEq)]
+pub struct SkillImportModalProps {
+    pub world_id: String,
+    /// Skills already in the world, used to detect name conflicts
+    pub existing: Vec<SkillData>,
+    /// Callback when modal is closed
+    pub on_close: EventHandler<()>,
+    /// Callback with the created and updated skills after a successful import
+    pub on_imported: EventHandler<Vec<SkillData>>,
+}
+
+/// Skill import modal
+#[component]
+pub fn SkillImportModal(props: SkillImportModalProps) -> Element {
+    use_modal_focus("skill-import-modal");
+    let skill_service = use_skill_service();
+
+    let mut source = use_signal(|| ImportSource::Preset);
+    let mut preset = use_signal(|| SkillPreset::Dnd5e);
+    let mut json_text = use_signal(String::new);
+    let mut resolution = use_signal(ConflictResolution::default);
+    let mut category_overrides: Signal<HashMap<String, SkillCategory>> = use_signal(HashMap::new);
+    let mut is_importing = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    // Resolve the selected source into entries
+    let entries = match *source.read() {
+        ImportSource::Preset => Ok(preset.read().entries()),
+        ImportSource::Json if json_text.read().trim().is_empty() => Ok(Vec::new()),
+        ImportSource::Json => parse_skill_json(&json_text.read()),
+    };
+    let (entries, parse_error) = match entries {
+        Ok(entries) => (entries, None),
+        Err(e) => (Vec::new(), Some(e)),
+    };
+
+    let unmapped = unmapped_categories(&entries);
+    let actions = {
+        let overrides = category_overrides.read();
+        plan_import(&props.existing, &entries, *resolution.read(), |raw| {
+            overrides.get(raw.trim()).copied()
+        })
+    };
+    let create_count = actions.iter().filter(|a| matches!(a, SkillImportAction::Create { .. })).count();
+    let update_count = actions.iter().filter(|a| matches!(a, SkillImportAction::Update { .. })).count();
+    let skip_count = actions.iter().filter(|a| matches!(a, SkillImportAction::Skip { .. })).count();
+    let can_import = create_count + update_count > 0 && !*is_importing.read();
+
+    let handle_import = {
+        let world_id = props.world_id.clone();
+        let actions = actions.clone();
+        move |_| {
+            let world_id = world_id.clone();
+            let actions = actions.clone();
+            let service = skill_service.clone();
+            spawn(async move {
+                is_importing.set(true);
+                error.set(None);
+                match service.import_skills(&world_id, &actions).await {
+                    Ok(imported) => props.on_imported.call(imported),
+                    Err(e) => {
+                        error.set(Some(format!("Import stopped: {}", e)));
+                        is_importing.set(false);
+                    }
+                }
+            });
+        }
+    };
+
+    let tab_class = |active: bool| {
+        if active {
+            "py-2 px-4 bg-purple-500 text-white border-0 rounded-md cursor-pointer text-sm"
+        } else {
+            "py-2 px-4 bg-transparent text-gray-400 border-0 rounded-md cursor-pointer text-sm"
+        }
+    };
+
+    rsx! {
+        // Modal backdrop
+        div {
+            class: "modal-backdrop fixed inset-0 bg-black bg-opacity-75 flex items-center justify-center z-50",
+            onclick: move |_| props.on_close.call(()),
+
+            div {
+                id: "skill-import-modal",
+                role: "dialog",
+                aria_modal: "true",
+                class: "modal-content bg-dark-surface rounded-xl w-11/12 max-w-2xl max-h-screen-80 flex flex-col overflow-hidden",
+                onclick: move |e| e.stop_propagation(),
+
+                // Header
+                div {
+                    class: "flex items-center justify-between py-4 px-6 border-b border-gray-700",
+                    h2 { class: "text-white text-xl m-0", "Import Skills" }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| props.on_close.call(()),
+                        class: "bg-transparent border-0 text-gray-500 text-2xl cursor-pointer p-1",
+                        "×"
+                    }
+                }
+
+                // Content
+                div {
+                    class: "flex-1 overflow-y-auto p-6 flex flex-col gap-4",
+
+                    // Source selection
+                    div {
+                        class: "flex gap-2",
+                        button {
+                            class: tab_class(*source.read() == ImportSource::Preset),
+                            onclick: move |_| source.set(ImportSource::Preset),
+                            "Preset Library"
+                        }
+                        button {
+                            class: tab_class(*source.read() == ImportSource::Json),
+                            onclick: move |_| source.set(ImportSource::Json),
+                            "JSON File"
+                        }
+                    }
+
+                    if *source.read() == ImportSource::Preset {
+                        select {
+                            value: "{preset.read().display_name()}",
+                            onchange: move |e| {
+                                if let Some(p) = SkillPreset::all().into_iter().find(|p| p.display_name() == e.value()) {
+                                    preset.set(p);
+                                }
+                            },
+                            class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white",
+                            for p in SkillPreset::all() {
+                                option { value: "{p.display_name()}", "{p.display_name()}" }
+                            }
+                        }
+                    } else {
+                        textarea {
+                            value: "{json_text}",
+                            oninput: move |e| json_text.set(e.value()),
+                            placeholder: "Paste the contents of a skills JSON file: an array of {{ name, description, category, base_attribute }} objects",
+                            class: "w-full h-40 p-2 bg-dark-bg border border-gray-700 rounded text-white font-mono text-xs resize-y box-border",
+                        }
+                        if let Some(err) = parse_error.as_ref() {
+                            div { class: "text-red-500 text-xs", "{err}" }
+                        }
+                    }
+
+                    // Conflict resolution
+                    div {
+                        label { class: "block text-gray-400 text-xs mb-1", "When a skill already exists" }
+                        select {
+                            value: "{resolution.read().display_name()}",
+                            onchange: move |e| {
+                                if let Some(r) = ConflictResolution::all().into_iter().find(|r| r.display_name() == e.value()) {
+                                    resolution.set(r);
+                                }
+                            },
+                            class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white",
+                            for r in ConflictResolution::all() {
+                                option { value: "{r.display_name()}", "{r.display_name()}" }
+                            }
+                        }
+                    }
+
+                    // Category mapping for names we don't recognize
+                    if !unmapped.is_empty() {
+                        div {
+                            class: "flex flex-col gap-2",
+                            label { class: "block text-gray-400 text-xs", "Map unknown categories" }
+                            for raw in unmapped.iter() {
+                                {
+                                    let raw = raw.clone();
+                                    let label = if raw.is_empty() { "(none)".to_string() } else { raw.clone() };
+                                    let current = category_overrides.read().get(&raw).copied().unwrap_or(SkillCategory::Custom);
+                                    rsx! {
+                                        div {
+                                            key: "{label}",
+                                            class: "flex items-center gap-3",
+                                            span { class: "flex-1 text-white text-sm", "{label}" }
+                                            select {
+                                                value: "{current.display_name()}",
+                                                onchange: move |e| {
+                                                    let cat = map_category(&e.value()).unwrap_or(SkillCategory::Custom);
+                                                    category_overrides.write().insert(raw.clone(), cat);
+                                                },
+                                                class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                                                for cat in SkillCategory::all() {
+                                                    option { value: "{cat.display_name()}", "{cat.display_name()}" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Preview
+                    if !actions.is_empty() {
+                        div {
+                            class: "flex flex-col gap-1",
+                            div {
+                                class: "text-gray-400 text-xs mb-1",
+                                "{create_count} new · {update_count} overwritten · {skip_count} skipped"
+                            }
+                            for (i, action) in actions.iter().enumerate() {
+                                ImportPreviewRow { key: "{i}", action: action.clone() }
+                            }
+                        }
+                    }
+
+                    if let Some(err) = error.read().as_ref() {
+                        div {
+                            class: "p-2 bg-red-500 bg-opacity-10 text-red-500 text-sm rounded",
+                            "{err}"
+                        }
+                    }
+                }
+
+                // Footer
+                div {
+                    class: "flex justify-end gap-2 py-4 px-6 border-t border-gray-700",
+                    button {
+                        onclick: move |_| props.on_close.call(()),
+                        disabled: *is_importing.read(),
+                        class: "py-2 px-4 bg-gray-700 text-white border-0 rounded cursor-pointer",
+                        "Cancel"
+                    }
+                    button {
+                        onclick: handle_import,
+                        disabled: !can_import,
+                        class: "py-2 px-4 bg-purple-500 text-white border-0 rounded cursor-pointer disabled:opacity-50",
+                        if *is_importing.read() { "Importing..." } else { "Import" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One planned import step in the preview list
+#[component]
+fn ImportPreviewRow(action: SkillImportAction) -> Element {
+    let (name, category, badge, badge_class) = match &action {
+        SkillImportAction::Create { name, category, .. } => {
+            (name.clone(), Some(*category), "New", "text-green-500")
+        }
+        SkillImportAction::Update { name, category, .. } => {
+            (name.clone(), Some(*category), "Overwrite", "text-amber-500")
+        }
+        SkillImportAction::Skip { name } => (name.clone(), None, "Skip", "text-gray-500"),
+    };
+
+    rsx! {
+        div {
+            class: "flex items-center gap-3 py-1 px-3 bg-dark-bg rounded text-sm",
+            span { class: "flex-1 text-white", "{name}" }
+            if let Some(category) = category {
+                span { class: "text-gray-500 text-xs", "{category.display_name()}" }
+            }
+            span { class: "{badge_class} text-xs w-16 text-right", "{badge}" }
+        }
+    }
+}
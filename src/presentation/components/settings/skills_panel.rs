@@ -4,14 +4,16 @@
 //! - View default skills from the rule system
 //! - Hide/show default skills
 //! - Create custom skills
-//! - Edit and delete custom skills
+//! - Edit custom skills in place and delete them
+//! - Drag skills to reorder them within their category
 
 use dioxus::prelude::*;
 use std::collections::HashMap;
 
 use crate::application::dto::{SkillCategory, SkillData};
-use crate::application::services::{CreateSkillRequest, UpdateSkillRequest};
+use crate::application::services::{plan_skill_reorder, CreateSkillRequest, UpdateSkillRequest};
 use crate::presentation::services::use_skill_service;
+use crate::presentation::state::use_modal_focus;
 
 /// Props for SkillsPanel
 #[derive(Props, Clone, PartialEq)]
@@ -25,12 +27,15 @@ pub struct SkillsPanelProps {
 /// Skills Panel component
 #[component]
 pub fn SkillsPanel(props: SkillsPanelProps) -> Element {
+    use_modal_focus("skills-panel");
     let mut skills: Signal<Vec<SkillData>> = use_signal(Vec::new);
     let mut is_loading = use_signal(|| true);
     let mut error: Signal<Option<String>> = use_signal(|| None);
     let mut show_hidden = use_signal(|| false);
     let mut show_add_form = use_signal(|| false);
     let mut editing_skill: Signal<Option<String>> = use_signal(|| None);
+    // Skill being dragged to a new position
+    let dragging: Signal<Option<String>> = use_signal(|| None);
 
     // Clone world_id once for all handlers
     let world_id = props.world_id.clone();
@@ -113,6 +118,9 @@ pub fn SkillsPanel(props: SkillsPanelProps) -> Element {
             onclick: move |_| props.on_close.call(()),
 
             div {
+                id: "skills-panel",
+                role: "dialog",
+                aria_modal: "true",
                 class: "bg-dark-surface rounded-lg w-11/12 max-w-3xl max-h-screen-90 overflow-hidden flex flex-col",
                 onclick: move |e| e.stop_propagation(),
 
@@ -164,30 +172,15 @@ pub fn SkillsPanel(props: SkillsPanelProps) -> Element {
                             on_created: handle_skill_created,
                             on_cancel: move |_| show_add_form.set(false),
                         }
-                    } else if let Some(skill_id) = editing_skill.read().clone() {
-                        {
-                            let skill = skills.read().iter().find(|s| s.id == skill_id).cloned();
-                            if let Some(skill) = skill {
-                                rsx! {
-                                    EditSkillForm {
-                                        world_id: world_id_for_edit.clone(),
-                                        skill: skill,
-                                        on_updated: handle_skill_updated,
-                                        on_cancel: move |_| editing_skill.set(None),
-                                    }
-                                }
-                            } else {
-                                rsx! { div { "Skill not found" } }
-                            }
-                        }
                     } else {
                         // Add skill button
-                        div { class: "mb-4",
+                        div { class: "mb-4 flex items-center justify-between gap-2",
                             button {
                                 onclick: move |_| show_add_form.set(true),
                                 class: "py-2 px-4 bg-purple-500 text-white border-0 rounded cursor-pointer text-sm",
                                 "+ Add Custom Skill"
                             }
+                            span { class: "text-gray-500 text-xs", "Drag skills to reorder them" }
                         }
 
                         // Skills by category
@@ -203,13 +196,24 @@ pub fn SkillsPanel(props: SkillsPanelProps) -> Element {
 
                                             div { class: "flex flex-col gap-1",
                                                 for skill in cat_skills.iter() {
-                                                    SkillRow {
-                                                        key: "{skill.id}",
-                                                        world_id: world_id_for_rows.clone(),
-                                                        skill: skill.clone(),
-                                                        skills_signal: skills,
-                                                        error_signal: error,
-                                                        on_edit: move |id| editing_skill.set(Some(id)),
+                                                    if editing_skill.read().as_deref() == Some(skill.id.as_str()) {
+                                                        EditSkillForm {
+                                                            key: "{skill.id}",
+                                                            world_id: world_id_for_edit.clone(),
+                                                            skill: skill.clone(),
+                                                            on_updated: handle_skill_updated,
+                                                            on_cancel: move |_| editing_skill.set(None),
+                                                        }
+                                                    } else {
+                                                        SkillRow {
+                                                            key: "{skill.id}",
+                                                            world_id: world_id_for_rows.clone(),
+                                                            skill: skill.clone(),
+                                                            skills_signal: skills,
+                                                            error_signal: error,
+                                                            dragging,
+                                                            on_edit: move |id| editing_skill.set(Some(id)),
+                                                        }
                                                     }
                                                 }
                                             }
@@ -237,14 +241,17 @@ pub fn SkillsPanel(props: SkillsPanelProps) -> Element {
 
 /// Individual skill row
 #[component]
-fn SkillRow(
+pub(super) fn SkillRow(
     world_id: String,
     skill: SkillData,
     skills_signal: Signal<Vec<SkillData>>,
     error_signal: Signal<Option<String>>,
+    dragging: Signal<Option<String>>,
     on_edit: EventHandler<String>,
 ) -> Element {
     let skill_id_for_toggle = skill.id.clone();
+    let skill_id_for_drag = skill.id.clone();
+    let skill_id_for_drop = skill.id.clone();
     let skill_id_for_edit = skill.id.clone();
     let skill_id_for_delete = skill.id.clone();
     let is_hidden = skill.is_hidden;
@@ -252,15 +259,19 @@ fn SkillRow(
 
     let world_id_for_toggle = world_id.clone();
     let world_id_for_delete = world_id.clone();
+    let world_id_for_drop = world_id.clone();
 
     // Get skill service
     let skill_service = use_skill_service();
 
     // Pre-compute classes based on hidden state
-    let row_class = if skill.is_hidden {
-        "flex items-center gap-3 py-2 px-3 bg-gray-500 bg-opacity-20 rounded"
+    let is_drag_source = dragging.read().as_deref() == Some(skill.id.as_str());
+    let row_class = if is_drag_source {
+        "flex items-center gap-3 py-2 px-3 bg-purple-500 bg-opacity-20 rounded opacity-60 cursor-grab"
+    } else if skill.is_hidden {
+        "flex items-center gap-3 py-2 px-3 bg-gray-500 bg-opacity-20 rounded cursor-grab"
     } else {
-        "flex items-center gap-3 py-2 px-3 bg-dark-bg rounded"
+        "flex items-center gap-3 py-2 px-3 bg-dark-bg rounded cursor-grab"
     };
     let icon_class = if skill.is_hidden {
         "p-1 bg-transparent border-0 text-gray-500 cursor-pointer text-sm"
@@ -292,6 +303,43 @@ fn SkillRow(
         }
     };
 
+    // Dropping another skill here moves it into this skill's place. Orders
+    // are applied straight away; if saving fails part-way, the Engine's
+    // order is reloaded.
+    let handle_drop = {
+        let service = skill_service.clone();
+        move |e: DragEvent| {
+            e.prevent_default();
+            let Some(dragged_id) = dragging.write().take() else {
+                return;
+            };
+            let previous = skills_signal.read().clone();
+            let orders = plan_skill_reorder(&previous, &dragged_id, &skill_id_for_drop);
+            if orders.is_empty() {
+                return;
+            }
+            {
+                let mut skills_write = skills_signal.write();
+                for (skill_id, order) in &orders {
+                    if let Some(skill) = skills_write.iter_mut().find(|s| &s.id == skill_id) {
+                        skill.order = *order;
+                    }
+                }
+            }
+            let world_id = world_id_for_drop.clone();
+            let service = service.clone();
+            spawn(async move {
+                if let Err(e) = service.reorder_skills(&world_id, &orders).await {
+                    error_signal.set(Some(format!("Failed to reorder skills: {}", e)));
+                    match service.list_skills(&world_id).await {
+                        Ok(list) => skills_signal.set(list),
+                        Err(_) => skills_signal.set(previous),
+                    }
+                }
+            });
+        }
+    };
+
     let handle_delete = {
         let service = skill_service.clone();
         move |_| {
@@ -314,13 +362,20 @@ fn SkillRow(
     rsx! {
         div {
             class: "{row_class}",
+            draggable: "true",
+            ondragstart: move |_| dragging.set(Some(skill_id_for_drag.clone())),
+            ondragend: move |_| dragging.set(None),
+            ondragover: move |e: DragEvent| e.prevent_default(),
+            ondrop: handle_drop,
+
+            span { class: "text-gray-600 text-sm select-none", title: "Drag to reorder", "⋮⋮" }
 
             // Visibility toggle
             button {
                 onclick: handle_toggle,
                 class: "{icon_class}",
                 title: if is_hidden { "Show skill" } else { "Hide skill" },
-                if is_hidden { "👁" } else { "👁" }
+                if is_hidden { "🙈" } else { "👁" }
             }
 
             // Skill info
@@ -366,7 +421,7 @@ fn SkillRow(
 
 /// Form to add a new custom skill
 #[component]
-fn AddSkillForm(
+pub(super) fn AddSkillForm(
     world_id: String,
     on_created: EventHandler<SkillData>,
     on_cancel: EventHandler<()>,
@@ -519,7 +574,7 @@ fn AddSkillForm(
 
 /// Form to edit an existing skill
 #[component]
-fn EditSkillForm(
+pub(super) fn EditSkillForm(
     world_id: String,
     skill: SkillData,
     on_updated: EventHandler<SkillData>,
@@ -561,6 +616,7 @@ fn EditSkillForm(
                 category: Some(cat),
                 base_attribute: if attr.is_empty() { None } else { Some(attr) },
                 is_hidden: None,
+                order: None,
             };
 
             match service.update_skill(&world_id, &skill_id, &request).await {
@@ -0,0 +1,246 @@
+//! Trash Panel - Restore or purge deleted entities
+//!
+//! Lists the world's trashed characters, locations, items, challenges, and
+//! narrative events with how long each has left before the Engine purges it.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{days_until_purge, TrashEntityKind, TrashListing, TrashedEntity};
+use crate::presentation::services::use_trash_service;
+
+/// Props for TrashPanel
+#[derive(Props, Clone, PartialEq)]
+pub struct TrashPanelProps {
+    pub world_id: String,
+}
+
+/// Trash tab content
+#[component]
+pub fn TrashPanel(props: TrashPanelProps) -> Element {
+    let trash_service = use_trash_service();
+    let platform = use_context::<Platform>();
+
+    let mut listing: Signal<TrashListing> = use_signal(TrashListing::default);
+    let mut is_loading = use_signal(|| true);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut kind_filter: Signal<Option<TrashEntityKind>> = use_signal(|| None);
+    // Entity ID awaiting permanent-delete confirmation
+    let mut confirming_purge: Signal<Option<String>> = use_signal(|| None);
+    let mut confirming_empty = use_signal(|| false);
+    let mut busy = use_signal(|| false);
+
+    {
+        let world_id = props.world_id.clone();
+        let svc = trash_service.clone();
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let svc = svc.clone();
+            spawn(async move {
+                match svc.list_trash(&world_id).await {
+                    Ok(l) => listing.set(l),
+                    Err(e) => error.set(Some(format!("Failed to load trash: {}", e))),
+                }
+                is_loading.set(false);
+            });
+        });
+    }
+
+    let now = platform.now_unix_secs();
+    let filter = *kind_filter.read();
+    let retention_days = listing.read().retention_days;
+    let visible: Vec<TrashedEntity> = listing
+        .read()
+        .items
+        .iter()
+        .filter(|item| filter.is_none() || filter == Some(item.kind))
+        .cloned()
+        .collect();
+    let has_items = !listing.read().items.is_empty();
+    let filter_value = filter.map(|k| k.display_name()).unwrap_or("All");
+
+    let restore = {
+        let world_id = props.world_id.clone();
+        let svc = trash_service.clone();
+        move |item: TrashedEntity| {
+            let world_id = world_id.clone();
+            let svc = svc.clone();
+            busy.set(true);
+            spawn(async move {
+                match svc.restore(&world_id, item.kind, &item.id).await {
+                    Ok(()) => {
+                        listing.write().items.retain(|i| i.id != item.id);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to restore {}: {}", item.name, e))),
+                }
+                busy.set(false);
+            });
+        }
+    };
+
+    let purge = {
+        let world_id = props.world_id.clone();
+        let svc = trash_service.clone();
+        move |item: TrashedEntity| {
+            let world_id = world_id.clone();
+            let svc = svc.clone();
+            busy.set(true);
+            spawn(async move {
+                match svc.purge(&world_id, item.kind, &item.id).await {
+                    Ok(()) => {
+                        listing.write().items.retain(|i| i.id != item.id);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to delete {}: {}", item.name, e))),
+                }
+                confirming_purge.set(None);
+                busy.set(false);
+            });
+        }
+    };
+
+    let empty_trash = {
+        let world_id = props.world_id.clone();
+        let svc = trash_service.clone();
+        move |_| {
+            let world_id = world_id.clone();
+            let svc = svc.clone();
+            busy.set(true);
+            spawn(async move {
+                match svc.empty_trash(&world_id).await {
+                    Ok(()) => {
+                        listing.write().items.clear();
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to empty trash: {}", e))),
+                }
+                confirming_empty.set(false);
+                busy.set(false);
+            });
+        }
+    };
+
+    rsx! {
+        div {
+            class: "trash-panel h-full flex flex-col gap-4 p-4 overflow-y-auto",
+
+            div {
+                class: "flex justify-between items-center",
+                div {
+                    h2 { class: "text-white m-0 text-xl", "Trash" }
+                    if retention_days > 0 {
+                        p { class: "m-0 mt-1 text-gray-500 text-xs", "Deleted items are kept for {retention_days} days, then permanently deleted." }
+                    }
+                }
+                div {
+                    class: "flex gap-2 items-center",
+                    select {
+                        value: "{filter_value}",
+                        onchange: move |e| {
+                            let value = e.value();
+                            kind_filter.set(TrashEntityKind::all().into_iter().find(|k| k.display_name() == value));
+                        },
+                        class: "p-1 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                        option { value: "All", "All types" }
+                        for kind in TrashEntityKind::all() {
+                            option { value: "{kind.display_name()}", "{kind.display_name()}" }
+                        }
+                    }
+                    if *confirming_empty.read() {
+                        button {
+                            onclick: empty_trash,
+                            disabled: *busy.read(),
+                            class: "py-1.5 px-3 bg-red-600 text-white border-0 rounded-md cursor-pointer text-sm disabled:opacity-50",
+                            "Confirm: delete everything"
+                        }
+                        button {
+                            onclick: move |_| confirming_empty.set(false),
+                            class: "py-1.5 px-3 bg-gray-700 text-white border-0 rounded-md cursor-pointer text-sm",
+                            "Cancel"
+                        }
+                    } else {
+                        button {
+                            onclick: move |_| confirming_empty.set(true),
+                            disabled: !has_items,
+                            class: "py-1.5 px-3 bg-transparent text-red-500 border border-red-500 rounded-md cursor-pointer text-sm disabled:opacity-50",
+                            "Empty Trash"
+                        }
+                    }
+                }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-500 bg-opacity-10 text-red-500 text-sm rounded-md",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-center text-gray-500 py-8", "Loading trash..." }
+            } else if visible.is_empty() {
+                div {
+                    class: "text-center text-gray-500 py-8",
+                    div { class: "text-5xl mb-4 opacity-50", "🗑" }
+                    p { class: "m-0", "The trash is empty" }
+                }
+            } else {
+                div {
+                    class: "flex flex-col gap-1",
+                    for item in visible {
+                        {
+                            let days_left = days_until_purge(&item.purge_at, now);
+                            let is_confirming = confirming_purge.read().as_ref() == Some(&item.id);
+                            let mut restore = restore.clone();
+                            let mut purge = purge.clone();
+                            let item_for_restore = item.clone();
+                            let item_for_purge = item.clone();
+                            let item_id = item.id.clone();
+                            rsx! {
+                                div {
+                                    key: "{item.kind.path_segment()}-{item.id}",
+                                    class: "flex items-center gap-3 py-2 px-3 bg-dark-surface rounded",
+
+                                    span { class: "text-gray-400 text-xs w-28 shrink-0", "{item.kind.display_name()}" }
+                                    span { class: "flex-1 min-w-0 text-white truncate", "{item.name}" }
+                                    match days_left {
+                                        Some(0) => rsx! { span { class: "text-red-400 text-xs whitespace-nowrap", "Purging soon" } },
+                                        Some(1) => rsx! { span { class: "text-amber-400 text-xs whitespace-nowrap", "1 day left" } },
+                                        Some(days) => rsx! { span { class: "text-gray-500 text-xs whitespace-nowrap", "{days} days left" } },
+                                        None => rsx! {},
+                                    }
+                                    button {
+                                        onclick: move |_| restore(item_for_restore.clone()),
+                                        disabled: *busy.read(),
+                                        class: "py-1 px-2 bg-blue-500 text-white border-0 rounded cursor-pointer text-xs disabled:opacity-50",
+                                        "Restore"
+                                    }
+                                    if is_confirming {
+                                        button {
+                                            onclick: move |_| purge(item_for_purge.clone()),
+                                            disabled: *busy.read(),
+                                            class: "py-1 px-2 bg-red-600 text-white border-0 rounded cursor-pointer text-xs disabled:opacity-50",
+                                            "Confirm"
+                                        }
+                                        button {
+                                            onclick: move |_| confirming_purge.set(None),
+                                            class: "py-1 px-2 bg-gray-700 text-white border-0 rounded cursor-pointer text-xs",
+                                            "Cancel"
+                                        }
+                                    } else {
+                                        button {
+                                            onclick: move |_| confirming_purge.set(Some(item_id.clone())),
+                                            class: "py-1 px-2 bg-transparent text-red-500 border border-red-500 rounded cursor-pointer text-xs",
+                                            "Delete Forever"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
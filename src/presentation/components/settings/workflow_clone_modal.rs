@@ -0,0 +1,160 @@
+//! Workflow Clone Modal Component
+//!
+//! Copies a configured slot's workflow, prompt mappings and defaults into
+//! another slot.
+
+use dioxus::prelude::*;
+
+use crate::application::services::WorkflowSlotStatus;
+use crate::presentation::services::use_workflow_service;
+use crate::presentation::state::use_modal_focus;
+
+/// Props for the WorkflowCloneModal component
+#[derive(Props, Clone, PartialEq)]
+pub struct WorkflowCloneModalProps {
+    /// Slot to copy from
+    pub source_slot: String,
+    /// Display name of the source slot
+    pub source_display_name: String,
+    /// Callback when modal is closed
+    pub on_close: EventHandler<()>,
+    /// Callback with the target slot once the clone is saved
+    pub on_cloned: EventHandler<String>,
+}
+
+/// Pick a target slot and clone the source configuration into it
+#[component]
+pub fn WorkflowCloneModal(props: WorkflowCloneModalProps) -> Element {
+    use_modal_focus("workflow-clone-modal");
+    let workflow_service = use_workflow_service();
+
+    let mut slots: Signal<Vec<WorkflowSlotStatus>> = use_signal(Vec::new);
+    let mut target: Signal<Option<String>> = use_signal(|| None);
+    let mut is_cloning = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let workflow_service_for_effect = workflow_service.clone();
+    let source_for_effect = props.source_slot.clone();
+    use_effect(move || {
+        let svc = workflow_service_for_effect.clone();
+        let source = source_for_effect.clone();
+        spawn(async move {
+            match svc.list_workflows().await {
+                Ok(response) => slots.set(
+                    response
+                        .categories
+                        .into_iter()
+                        .flat_map(|c| c.slots)
+                        .filter(|s| s.slot != source)
+                        .collect(),
+                ),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+        });
+    });
+
+    let source_slot = props.source_slot.clone();
+    let on_cloned = props.on_cloned;
+    let do_clone = move |_| {
+        let Some(to_slot) = target.read().clone() else {
+            return;
+        };
+        let from_slot = source_slot.clone();
+        let svc = workflow_service.clone();
+        spawn(async move {
+            is_cloning.set(true);
+            error.set(None);
+            match svc.clone_workflow(&from_slot, &to_slot).await {
+                Ok(()) => on_cloned.call(to_slot),
+                Err(e) => {
+                    error.set(Some(format!("Failed to clone: {}", e)));
+                    is_cloning.set(false);
+                }
+            }
+        });
+    };
+
+    let selected = target.read().clone();
+    let replaces_existing = selected
+        .as_ref()
+        .and_then(|t| slots.read().iter().find(|s| &s.slot == t).map(|s| s.configured))
+        .unwrap_or(false);
+
+    rsx! {
+        div {
+            class: "modal-backdrop fixed inset-0 bg-black bg-opacity-75 flex items-center justify-center z-[1000]",
+            onclick: move |_| props.on_close.call(()),
+
+            div {
+                id: "workflow-clone-modal",
+                role: "dialog",
+                aria_modal: "true",
+                class: "modal-content bg-dark-surface rounded-xl w-[90%] max-w-[440px] p-6 overflow-hidden",
+                onclick: move |e| e.stop_propagation(),
+
+                h2 {
+                    class: "text-white text-lg m-0 mb-2",
+                    "Clone Workflow"
+                }
+                p {
+                    class: "text-gray-400 text-sm m-0 mb-4",
+                    "Copy the workflow, prompt mappings and defaults from {props.source_display_name} to another slot."
+                }
+
+                select {
+                    class: "w-full p-2 bg-dark-bg border border-gray-700 rounded-md text-white text-sm",
+                    value: selected.clone().unwrap_or_default(),
+                    onchange: move |e| {
+                        let value = e.value();
+                        target.set(if value.is_empty() { None } else { Some(value) });
+                    },
+
+                    option { value: "", "Select a target slot..." }
+                    for slot in slots.read().iter() {
+                        option {
+                            key: "{slot.slot}",
+                            value: "{slot.slot}",
+                            if slot.configured {
+                                "{slot.display_name} (configured)"
+                            } else {
+                                "{slot.display_name}"
+                            }
+                        }
+                    }
+                }
+
+                if replaces_existing {
+                    p {
+                        class: "text-amber-500 text-xs mt-2 mb-0",
+                        "The target slot's current configuration will be replaced."
+                    }
+                }
+
+                if let Some(err) = error.read().as_ref() {
+                    div {
+                        class: "p-3 bg-red-500 bg-opacity-10 text-red-500 text-sm rounded-md mt-4",
+                        "{err}"
+                    }
+                }
+
+                div {
+                    class: "flex gap-3 justify-end mt-6",
+
+                    button {
+                        onclick: move |_| props.on_close.call(()),
+                        disabled: *is_cloning.read(),
+                        class: "px-4 py-2 bg-gray-700 text-white border-none rounded-lg cursor-pointer text-sm",
+                        "Cancel"
+                    }
+
+                    button {
+                        onclick: do_clone,
+                        disabled: *is_cloning.read() || selected.is_none(),
+                        class: "px-4 py-2 bg-blue-500 text-white border-none rounded-lg cursor-pointer text-sm font-medium disabled:opacity-50",
+                        if *is_cloning.read() { "Cloning..." } else { "Clone" }
+                    }
+                }
+            }
+        }
+    }
+}
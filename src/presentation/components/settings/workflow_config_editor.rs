@@ -6,10 +6,13 @@
 use dioxus::prelude::*;
 
 use crate::presentation::services::use_workflow_service;
+use super::workflow_clone_modal::WorkflowCloneModal;
+use super::workflow_preset_modal::WorkflowPresetModal;
 use crate::application::services::{
     WorkflowConfig, WorkflowAnalysis, WorkflowInput, PromptMapping, InputDefault,
     TestWorkflowResponse,
 };
+use crate::presentation::state::use_modal_focus;
 
 /// Props for the WorkflowConfigEditor component
 #[derive(Props, Clone, PartialEq)]
@@ -22,6 +25,8 @@ pub struct WorkflowConfigEditorProps {
     pub on_reconfigure: EventHandler<()>,
     /// Callback when workflow is deleted
     pub on_deleted: EventHandler<()>,
+    /// Callback with the target slot after this slot is cloned into it
+    pub on_cloned: EventHandler<String>,
 }
 
 // Type aliases for service types to minimize changes
@@ -60,6 +65,11 @@ pub fn WorkflowConfigEditor(props: WorkflowConfigEditorProps) -> Element {
     let mut is_testing = use_signal(|| false);
     let mut test_result: Signal<Option<WorkflowTestResult>> = use_signal(|| None);
     let mut test_error: Signal<Option<String>> = use_signal(|| None);
+    // Track clone and preset modal visibility
+    let mut show_clone_modal = use_signal(|| false);
+    let mut show_preset_modal = use_signal(|| false);
+    // Bumped to refetch the config after a preset is applied
+    let mut reload = use_signal(|| 0u32);
 
     let slot_id = props.slot.clone();
     let slot_id_for_effect = slot_id.clone();
@@ -67,6 +77,7 @@ pub fn WorkflowConfigEditor(props: WorkflowConfigEditorProps) -> Element {
 
     // Fetch config on mount or when slot changes
     use_effect(move || {
+        let _ = *reload.read();
         let slot = slot_id_for_effect.clone();
         let svc = workflow_service_for_effect.clone();
         spawn(async move {
@@ -230,6 +241,18 @@ pub fn WorkflowConfigEditor(props: WorkflowConfigEditorProps) -> Element {
                                 "Test Workflow"
                             }
 
+                            button {
+                                onclick: move |_| show_clone_modal.set(true),
+                                class: "py-2 px-4 bg-gray-700 text-white border-0 rounded-lg cursor-pointer text-sm",
+                                "Clone to..."
+                            }
+
+                            button {
+                                onclick: move |_| show_preset_modal.set(true),
+                                class: "py-2 px-4 bg-gray-700 text-white border-0 rounded-lg cursor-pointer text-sm",
+                                "Presets"
+                            }
+
                             button {
                                 onclick: move |_| props.on_reconfigure.call(()),
                                 class: "py-2 px-4 bg-gray-700 text-white border-0 rounded-lg cursor-pointer text-sm",
@@ -387,6 +410,12 @@ pub fn WorkflowConfigEditor(props: WorkflowConfigEditorProps) -> Element {
                             class: "py-3 px-6 bg-blue-500 text-white border-0 rounded-lg cursor-pointer font-medium",
                             "Configure Workflow"
                         }
+
+                        button {
+                            onclick: move |_| show_preset_modal.set(true),
+                            class: "block mx-auto mt-3 py-2 px-4 bg-transparent text-gray-400 border border-gray-700 rounded-lg cursor-pointer text-sm",
+                            "Load Preset"
+                        }
                     }
                 }
 
@@ -419,6 +448,36 @@ pub fn WorkflowConfigEditor(props: WorkflowConfigEditorProps) -> Element {
                     }
                 }
             }
+
+            // Clone modal
+            if *show_clone_modal.read() {
+                if let Some(cfg) = config.read().as_ref() {
+                    WorkflowCloneModal {
+                        source_slot: props.slot.clone(),
+                        source_display_name: cfg.slot_display_name.clone(),
+                        on_close: move |_| show_clone_modal.set(false),
+                        on_cloned: move |target: String| {
+                            show_clone_modal.set(false);
+                            props.on_cloned.call(target);
+                        },
+                    }
+                }
+            }
+
+            // Preset modal
+            if *show_preset_modal.read() {
+                WorkflowPresetModal {
+                    slot: props.slot.clone(),
+                    slot_display_name: config.read().as_ref().map(|c| c.slot_display_name.clone()).unwrap_or_else(|| props.slot.clone()),
+                    configured: config.read().is_some(),
+                    on_close: move |_| show_preset_modal.set(false),
+                    on_applied: move |_| {
+                        show_preset_modal.set(false);
+                        let next = *reload.peek() + 1;
+                        reload.set(next);
+                    },
+                }
+            }
         }
     }
 }
@@ -631,12 +690,16 @@ struct ConfirmDeleteModalProps {
 
 #[component]
 fn ConfirmDeleteModal(props: ConfirmDeleteModalProps) -> Element {
+    use_modal_focus("workflow-delete-modal");
     rsx! {
         div {
             class: "modal-backdrop fixed inset-0 bg-black bg-opacity-75 flex items-center justify-center z-[1000]",
             onclick: move |_| props.on_cancel.call(()),
 
             div {
+                id: "workflow-delete-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "modal-content bg-dark-surface rounded-xl w-[90%] max-w-[400px] p-6 overflow-hidden",
                 onclick: move |e| e.stop_propagation(),
 
@@ -699,6 +762,7 @@ struct TestWorkflowModalProps {
 
 #[component]
 fn TestWorkflowModal(props: TestWorkflowModalProps) -> Element {
+    use_modal_focus("workflow-test-modal");
     let has_result = props.test_result.is_some();
 
     rsx! {
@@ -707,6 +771,9 @@ fn TestWorkflowModal(props: TestWorkflowModalProps) -> Element {
             onclick: move |_| props.on_close.call(()),
 
             div {
+                id: "workflow-test-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "modal-content bg-dark-surface rounded-xl w-[90%] max-w-[700px] max-h-[80vh] flex flex-col overflow-hidden",
                 onclick: move |e| e.stop_propagation(),
 
@@ -720,6 +787,7 @@ fn TestWorkflowModal(props: TestWorkflowModalProps) -> Element {
                     }
 
                     button {
+                        aria_label: "Close",
                         onclick: move |_| props.on_close.call(()),
                         class: "bg-transparent border-none text-gray-500 text-2xl cursor-pointer p-1",
                         "×"
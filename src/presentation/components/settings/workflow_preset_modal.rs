@@ -0,0 +1,221 @@
+//! Workflow Preset Modal Component
+//!
+//! Saves a slot's configuration as a named preset on this device, and
+//! configures the slot from a saved preset.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{
+    load_workflow_presets, save_workflow_presets, upsert_workflow_preset, WorkflowPreset,
+};
+use crate::presentation::services::use_workflow_service;
+use crate::presentation::state::use_modal_focus;
+
+/// Props for the WorkflowPresetModal component
+#[derive(Props, Clone, PartialEq)]
+pub struct WorkflowPresetModalProps {
+    /// Slot to save from or apply to
+    pub slot: String,
+    /// Display name of the slot
+    pub slot_display_name: String,
+    /// Whether the slot has a configuration that can be saved
+    pub configured: bool,
+    /// Callback when modal is closed
+    pub on_close: EventHandler<()>,
+    /// Callback once a preset has been applied to the slot
+    pub on_applied: EventHandler<()>,
+}
+
+/// Save the slot as a preset, or apply a saved preset to it
+#[component]
+pub fn WorkflowPresetModal(props: WorkflowPresetModalProps) -> Element {
+    use_modal_focus("workflow-preset-modal");
+    let workflow_service = use_workflow_service();
+    let platform = use_context::<Platform>();
+
+    let mut presets = use_signal({
+        let platform = platform.clone();
+        move || load_workflow_presets(&platform)
+    });
+    let mut preset_name = use_signal(String::new);
+    let mut is_busy = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut status: Signal<Option<String>> = use_signal(|| None);
+
+    let save_preset = {
+        let platform = platform.clone();
+        let svc = workflow_service.clone();
+        let slot = props.slot.clone();
+        let source_slot = props.slot_display_name.clone();
+        move |_| {
+            let name = preset_name.read().trim().to_string();
+            if name.is_empty() {
+                return;
+            }
+            let platform = platform.clone();
+            let svc = svc.clone();
+            let slot = slot.clone();
+            let source_slot = source_slot.clone();
+            spawn(async move {
+                is_busy.set(true);
+                error.set(None);
+                match svc.export_workflow(&slot).await {
+                    Ok(workflow) => {
+                        let mut next = presets.peek().clone();
+                        upsert_workflow_preset(&mut next, WorkflowPreset { name: name.clone(), source_slot, workflow });
+                        save_workflow_presets(&platform, &next);
+                        presets.set(next);
+                        preset_name.set(String::new());
+                        status.set(Some(format!("Saved preset \"{}\"", name)));
+                    }
+                    Err(e) => error.set(Some(format!("Failed to export workflow: {}", e))),
+                }
+                is_busy.set(false);
+            });
+        }
+    };
+
+    let saved = presets.read().clone();
+
+    rsx! {
+        div {
+            class: "modal-backdrop fixed inset-0 bg-black bg-opacity-75 flex items-center justify-center z-[1000]",
+            onclick: move |_| props.on_close.call(()),
+
+            div {
+                id: "workflow-preset-modal",
+                role: "dialog",
+                aria_modal: "true",
+                class: "modal-content bg-dark-surface rounded-xl w-[90%] max-w-[500px] max-h-[80vh] p-6 flex flex-col overflow-hidden",
+                onclick: move |e| e.stop_propagation(),
+
+                div {
+                    class: "flex justify-between items-center mb-4",
+                    h2 {
+                        class: "text-white text-lg m-0",
+                        "Workflow Presets"
+                    }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| props.on_close.call(()),
+                        class: "bg-transparent border-none text-gray-400 cursor-pointer text-xl p-0",
+                        "×"
+                    }
+                }
+
+                // Save the current slot
+                if props.configured {
+                    div {
+                        class: "flex gap-2 mb-4",
+                        input {
+                            r#type: "text",
+                            value: "{preset_name}",
+                            placeholder: "Preset name",
+                            oninput: move |e| preset_name.set(e.value()),
+                            class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded-md text-white text-sm",
+                        }
+                        button {
+                            onclick: save_preset,
+                            disabled: *is_busy.read() || preset_name.read().trim().is_empty(),
+                            class: "px-4 py-2 bg-green-500 text-white border-none rounded-lg cursor-pointer text-sm disabled:opacity-50",
+                            "Save {props.slot_display_name}"
+                        }
+                    }
+                }
+
+                if let Some(msg) = status.read().as_ref() {
+                    p { class: "text-green-500 text-xs m-0 mb-2", "{msg}" }
+                }
+                if let Some(err) = error.read().as_ref() {
+                    div {
+                        class: "p-3 bg-red-500 bg-opacity-10 text-red-500 text-sm rounded-md mb-2",
+                        "{err}"
+                    }
+                }
+
+                // Saved presets
+                div {
+                    class: "flex-1 overflow-y-auto flex flex-col gap-2",
+
+                    if saved.is_empty() {
+                        p {
+                            class: "text-gray-500 text-sm text-center py-4 m-0",
+                            "No presets saved on this device yet."
+                        }
+                    }
+
+                    for preset in saved.iter().cloned() {
+                        div {
+                            key: "{preset.name}",
+                            class: "flex items-center gap-3 p-3 bg-dark-bg rounded-lg",
+
+                            div {
+                                class: "flex-1 min-w-0",
+                                div { class: "text-white text-sm font-medium truncate", "{preset.name}" }
+                                div {
+                                    class: "text-gray-500 text-xs truncate",
+                                    "{preset.workflow.name} · from {preset.source_slot}"
+                                }
+                            }
+
+                            button {
+                                disabled: *is_busy.read(),
+                                onclick: {
+                                    let svc = workflow_service.clone();
+                                    let slot = props.slot.clone();
+                                    let workflow = preset.workflow.clone();
+                                    let on_applied = props.on_applied;
+                                    move |_| {
+                                        let svc = svc.clone();
+                                        let slot = slot.clone();
+                                        let workflow = workflow.clone();
+                                        spawn(async move {
+                                            is_busy.set(true);
+                                            error.set(None);
+                                            match svc.import_workflow(&slot, &workflow).await {
+                                                Ok(()) => on_applied.call(()),
+                                                Err(e) => error.set(Some(format!("Failed to apply preset: {}", e))),
+                                            }
+                                            is_busy.set(false);
+                                        });
+                                    }
+                                },
+                                class: "px-3 py-1 bg-blue-500 text-white border-none rounded cursor-pointer text-xs disabled:opacity-50",
+                                "Apply"
+                            }
+
+                            button {
+                                aria_label: "Delete preset",
+                                title: "Delete preset",
+                                onclick: {
+                                    let platform = platform.clone();
+                                    let name = preset.name.clone();
+                                    move |_| {
+                                        let next: Vec<WorkflowPreset> = presets
+                                            .peek()
+                                            .iter()
+                                            .filter(|p| p.name != name)
+                                            .cloned()
+                                            .collect();
+                                        save_workflow_presets(&platform, &next);
+                                        presets.set(next);
+                                    }
+                                },
+                                class: "px-2 py-1 bg-transparent text-gray-500 hover:text-red-400 border-none cursor-pointer text-sm",
+                                "×"
+                            }
+                        }
+                    }
+                }
+
+                if props.configured {
+                    p {
+                        class: "text-gray-500 text-xs mt-4 mb-0",
+                        "Applying a preset replaces this slot's current configuration."
+                    }
+                }
+            }
+        }
+    }
+}
@@ -7,6 +7,7 @@ use dioxus::prelude::*;
 
 use crate::presentation::services::use_workflow_service;
 use crate::application::services::AnalyzeWorkflowResponse;
+use crate::presentation::state::use_modal_focus;
 
 /// Props for the WorkflowUploadModal component
 #[derive(Props, Clone, PartialEq)]
@@ -50,6 +51,7 @@ pub enum UploadStep {
 /// Workflow upload modal
 #[component]
 pub fn WorkflowUploadModal(props: WorkflowUploadModalProps) -> Element {
+    use_modal_focus("workflow-upload-modal");
     let workflow_service = use_workflow_service();
 
     // Track wizard step
@@ -172,6 +174,9 @@ pub fn WorkflowUploadModal(props: WorkflowUploadModalProps) -> Element {
 
             // Modal content
             div {
+                id: "workflow-upload-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "modal-content bg-dark-surface rounded-xl w-11/12 max-w-2xl max-h-screen-80 flex flex-col overflow-hidden",
                 onclick: move |e| e.stop_propagation(),
 
@@ -191,6 +196,7 @@ pub fn WorkflowUploadModal(props: WorkflowUploadModalProps) -> Element {
                     }
 
                     button {
+                        aria_label: "Close",
                         onclick: move |_| props.on_close.call(()),
                         class: "bg-transparent border-0 text-gray-500 text-2xl cursor-pointer p-1",
                         "×"
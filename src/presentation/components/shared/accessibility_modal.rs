@@ -0,0 +1,115 @@
+//! Accessibility modal - reduced motion and reading options for this device
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{save_accessibility_preferences, AccessibilityPreferences};
+use crate::presentation::state::{use_display_state, use_modal_focus};
+
+/// Props for AccessibilityModal
+#[derive(Props, Clone, PartialEq)]
+pub struct AccessibilityModalProps {
+    pub on_close: EventHandler<()>,
+}
+
+/// Modal with the per-device accessibility toggles
+#[component]
+pub fn AccessibilityModal(props: AccessibilityModalProps) -> Element {
+    use_modal_focus("accessibility-modal");
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black bg-opacity-75 flex items-center justify-center z-[1100]",
+            onclick: move |_| props.on_close.call(()),
+
+            div {
+                id: "accessibility-modal",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "accessibility-modal-title",
+                class: "bg-dark-surface rounded-xl w-[90%] max-w-md p-6 flex flex-col gap-4",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        props.on_close.call(());
+                    }
+                },
+
+                h2 { id: "accessibility-modal-title", class: "text-white text-lg m-0", "Accessibility" }
+                p { class: "text-gray-500 text-xs m-0", "Saved on this device only." }
+
+                AccessibilityToggles {}
+
+                div {
+                    class: "flex justify-end",
+                    button {
+                        onclick: move |_| props.on_close.call(()),
+                        class: "py-2 px-4 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm",
+                        "Done"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Reduced motion and dyslexia-friendly font checkboxes, saved as they change
+#[component]
+pub fn AccessibilityToggles() -> Element {
+    let platform = use_context::<Platform>();
+    let mut accessibility = use_display_state().accessibility;
+
+    let prefs = *accessibility.read();
+    let mut update = move |change: fn(&mut AccessibilityPreferences, bool), value: bool| {
+        accessibility.with_mut(|p| change(p, value));
+        save_accessibility_preferences(&platform, &accessibility.peek());
+    };
+
+    rsx! {
+        div {
+            class: "flex flex-col gap-3",
+
+            label {
+                class: "flex items-start gap-3 cursor-pointer",
+                input {
+                    r#type: "checkbox",
+                    role: "switch",
+                    checked: prefs.reduced_motion,
+                    onchange: {
+                        let mut update = update.clone();
+                        move |e: FormEvent| update(|p, v| p.reduced_motion = v, e.checked())
+                    },
+                    class: "mt-1",
+                }
+                div {
+                    class: "flex flex-col",
+                    span { class: "text-gray-300 text-sm font-medium", "Reduce motion" }
+                    span {
+                        class: "text-gray-500 text-xs",
+                        "Show dialogue all at once, keep backdrops still, and turn off transitions"
+                    }
+                }
+            }
+
+            label {
+                class: "flex items-start gap-3 cursor-pointer",
+                input {
+                    r#type: "checkbox",
+                    role: "switch",
+                    checked: prefs.dyslexia_font,
+                    onchange: move |e: FormEvent| update(|p, v| p.dyslexia_font = v, e.checked()),
+                    class: "mt-1",
+                }
+                div {
+                    class: "flex flex-col",
+                    span { class: "text-gray-300 text-sm font-medium", "Dyslexia-friendly font" }
+                    span {
+                        class: "text-gray-500 text-xs",
+                        "Use a more legible typeface with wider letter and word spacing"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! Bug report modal - describe a problem and send or save diagnostics
+
+use base64::Engine as _;
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::{storage_keys, Platform};
+use crate::application::services::{
+    bug_report_endpoint, ConnectionSnapshot, DiagnosticBundle, ScreenshotAttachment,
+};
+use crate::presentation::services::use_bug_report_service;
+use crate::presentation::state::{use_modal_focus, use_session_state};
+
+/// Screenshots larger than this are rejected to keep reports small
+const MAX_SCREENSHOT_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Props for BugReportModal
+#[derive(Props, Clone, PartialEq)]
+pub struct BugReportModalProps {
+    pub on_close: EventHandler<()>,
+}
+
+/// Modal that collects a description and optional screenshot, then bundles
+/// them with recent logs and connection info
+#[component]
+pub fn BugReportModal(props: BugReportModalProps) -> Element {
+    use_modal_focus("bug-report-modal");
+
+    let bug_report_service = use_bug_report_service();
+    let platform = use_context::<Platform>();
+    let session_state = use_session_state();
+
+    let mut description = use_signal(String::new);
+    let mut screenshot: Signal<Option<ScreenshotAttachment>> = use_signal(|| None);
+    let mut endpoint = use_signal(|| bug_report_endpoint(&platform));
+    let mut show_details = use_signal(|| false);
+    let mut is_sending = use_signal(|| false);
+    let mut status: Signal<Option<Result<String, String>>> = use_signal(|| None);
+
+    let log_count = platform.recent_logs().len();
+
+    // Assemble the bundle from whatever is current when the user acts
+    let build_bundle = {
+        let platform = platform.clone();
+        let session_state = session_state.clone();
+        move || {
+            let role = session_state
+                .user_role()
+                .read()
+                .as_ref()
+                .map(|r| format!("{:?}", r));
+            let connection = ConnectionSnapshot::redacted(
+                session_state.connection_status().read().display_text(),
+                session_state.server_url().read().as_deref(),
+                session_state.session_id().read().as_deref(),
+                session_state.user_id().read().as_deref(),
+                role.as_deref(),
+            );
+            DiagnosticBundle::collect(
+                &platform,
+                description.read().trim().to_string(),
+                connection,
+                screenshot.read().clone(),
+            )
+        }
+    };
+
+    let save = {
+        let platform = platform.clone();
+        let build_bundle = build_bundle.clone();
+        move |_| {
+            let bundle = build_bundle();
+            let result = serde_json::to_string_pretty(&bundle)
+                .map_err(|e| e.to_string())
+                .and_then(|json| platform.save_file(&bundle.filename(), &json));
+            status.set(Some(match result {
+                Ok(location) => Ok(format!("Saved report to {}", location)),
+                Err(e) => Err(format!("Failed to save report: {}", e)),
+            }));
+        }
+    };
+
+    let send = {
+        let platform = platform.clone();
+        move |_| {
+            if description.read().trim().is_empty() {
+                status.set(Some(Err("Please describe what went wrong".to_string())));
+                return;
+            }
+            let bundle = build_bundle();
+            let target = endpoint.read().trim().to_string();
+            platform.storage_save(storage_keys::BUG_REPORT_ENDPOINT, &target);
+            let svc = bug_report_service.clone();
+            is_sending.set(true);
+            status.set(None);
+            spawn(async move {
+                match svc.submit(&target, &bundle).await {
+                    Ok(()) => {
+                        description.set(String::new());
+                        screenshot.set(None);
+                        status.set(Some(Ok("Report sent. Thank you!".to_string())));
+                    }
+                    Err(e) => status.set(Some(Err(format!(
+                        "Failed to send report: {}. You can save it and share the file instead.",
+                        e
+                    )))),
+                }
+                is_sending.set(false);
+            });
+        }
+    };
+
+    let attach = move |e: FormEvent| {
+        let Some(file) = e.files().into_iter().next() else {
+            return;
+        };
+        if file.size() > MAX_SCREENSHOT_BYTES {
+            status.set(Some(Err("Screenshot is too large (max 5 MB)".to_string())));
+            return;
+        }
+        spawn(async move {
+            match file.read_bytes().await {
+                Ok(bytes) => screenshot.set(Some(ScreenshotAttachment {
+                    filename: file.name(),
+                    mime_type: file.content_type().unwrap_or_else(|| "image/png".to_string()),
+                    data_base64: base64::engine::general_purpose::STANDARD.encode(&bytes),
+                })),
+                Err(e) => status.set(Some(Err(format!("Failed to read screenshot: {}", e)))),
+            }
+        });
+    };
+
+    let sending = *is_sending.read();
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black bg-opacity-75 flex items-center justify-center z-[1100]",
+            onclick: move |_| props.on_close.call(()),
+
+            div {
+                id: "bug-report-modal",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "bug-report-modal-title",
+                class: "bg-dark-surface rounded-xl w-[90%] max-w-md p-6 flex flex-col gap-3",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        props.on_close.call(());
+                    }
+                },
+
+                h2 { id: "bug-report-modal-title", class: "text-white text-lg m-0", "Report an Issue" }
+
+                textarea {
+                    value: "{description}",
+                    oninput: move |e| description.set(e.value()),
+                    placeholder: "What happened? What did you expect?",
+                    rows: "5",
+                    class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white resize-y box-border",
+                }
+
+                div {
+                    class: "flex items-center gap-2 text-sm",
+                    label {
+                        class: "py-1 px-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-xs",
+                        "📎 Attach screenshot"
+                        input {
+                            r#type: "file",
+                            accept: "image/*",
+                            class: "hidden",
+                            onchange: attach,
+                        }
+                    }
+                    if let Some(shot) = screenshot.read().as_ref() {
+                        span { class: "text-gray-300 text-xs truncate flex-1", "{shot.filename}" }
+                        button {
+                            aria_label: "Remove screenshot",
+                            onclick: move |_| screenshot.set(None),
+                            class: "bg-transparent border-0 text-gray-500 cursor-pointer",
+                            "×"
+                        }
+                    }
+                }
+
+                // What gets included
+                div {
+                    class: "text-gray-500 text-xs",
+                    "Includes the app version, platform, {log_count} recent log lines, and connection status. "
+                    "Server paths, tokens, and full user IDs are removed."
+                    button {
+                        onclick: move |_| {
+                            let current = *show_details.read();
+                            show_details.set(!current);
+                        },
+                        class: "ml-1 bg-transparent border-0 text-blue-400 cursor-pointer text-xs p-0",
+                        if *show_details.read() { "Hide options" } else { "Options" }
+                    }
+                }
+
+                if *show_details.read() {
+                    div {
+                        label { class: "block text-gray-400 text-xs mb-1", "Report endpoint" }
+                        input {
+                            r#type: "text",
+                            value: "{endpoint}",
+                            oninput: move |e| endpoint.set(e.value()),
+                            class: "w-full p-2 bg-dark-bg border border-gray-700 rounded text-white text-sm box-border",
+                        }
+                    }
+                }
+
+                match status.read().as_ref() {
+                    Some(Ok(msg)) => rsx! { div { class: "text-green-500 text-sm", "{msg}" } },
+                    Some(Err(msg)) => rsx! { div { class: "text-red-500 text-sm", "{msg}" } },
+                    None => rsx! {},
+                }
+
+                div {
+                    class: "flex justify-end gap-2",
+                    button {
+                        onclick: move |_| props.on_close.call(()),
+                        class: "py-2 px-4 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer text-sm",
+                        "Close"
+                    }
+                    button {
+                        onclick: save,
+                        class: "py-2 px-4 bg-gray-700 text-white border-0 rounded cursor-pointer text-sm",
+                        "Save Bundle"
+                    }
+                    button {
+                        onclick: send,
+                        disabled: sending,
+                        class: "py-2 px-4 bg-blue-500 text-white border-0 rounded cursor-pointer text-sm disabled:opacity-50",
+                        if sending { "Sending..." } else { "Send Report" }
+                    }
+                }
+            }
+        }
+    }
+}
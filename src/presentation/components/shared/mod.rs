@@ -1 +1,9 @@
 //! Shared UI components
+
+mod accessibility_modal;
+mod bug_report_modal;
+mod onboarding_tour;
+
+pub use accessibility_modal::AccessibilityModal;
+pub use bug_report_modal::BugReportModal;
+pub use onboarding_tour::OnboardingTour;
@@ -0,0 +1,228 @@
+//! Onboarding tour - spotlight walkthrough of a view's key controls
+//!
+//! Shown the first time a view opens on this device. Each step dims the
+//! page around its control, found by `data-tour` attribute, and puts a card
+//! beside it. Finishing or skipping marks the tour done.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{save_tour_progress, tour_steps, TourView};
+use crate::presentation::state::{use_display_state, use_modal_focus};
+
+/// Space between a control and its spotlight ring, in pixels
+const SPOTLIGHT_PADDING: f64 = 6.0;
+
+/// Width of the step card, in pixels
+const CARD_WIDTH: f64 = 320.0;
+
+/// Where a step's control sits in the viewport, and the viewport size
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct AnchorRect {
+    left: f64,
+    top: f64,
+    width: f64,
+    height: f64,
+    viewport_width: f64,
+    viewport_height: f64,
+}
+
+/// Tour for `view`, if it hasn't been finished on this device
+#[component]
+pub fn OnboardingTour(view: TourView) -> Element {
+    let display_state = use_display_state();
+    let mut tours = display_state.tours;
+
+    if tours.read().is_complete(view) {
+        return rsx! {};
+    }
+
+    rsx! {
+        TourOverlay {
+            view,
+            on_done: move |_| {
+                tours.with_mut(|p| p.mark_complete(view));
+            },
+        }
+    }
+}
+
+#[component]
+fn TourOverlay(view: TourView, on_done: EventHandler<()>) -> Element {
+    let platform = use_context::<Platform>();
+    let display_state = use_display_state();
+    let mut step_index = use_signal(|| 0usize);
+    let mut anchor: Signal<Option<AnchorRect>> = use_signal(|| None);
+    use_modal_focus("onboarding-tour");
+
+    let steps = tour_steps(view);
+    let index = (*step_index.read()).min(steps.len().saturating_sub(1));
+    let step = steps[index];
+    let is_last = index + 1 == steps.len();
+
+    // Measure the step's control, scrolling it into view first
+    use_effect(move || {
+        let step = tour_steps(view)[*step_index.read()];
+        spawn(async move {
+            anchor.set(measure_anchor(step.anchor).await);
+        });
+    });
+
+    // Follow the control when the window is resized or the page scrolls
+    use_future(move || async move {
+        let mut moved = document::eval(
+            r#"
+            let timer;
+            const notify = () => {
+                clearTimeout(timer);
+                timer = setTimeout(() => dioxus.send(true), 100);
+            };
+            window.addEventListener('resize', notify);
+            window.addEventListener('scroll', notify, true);
+            await new Promise(() => {});
+            "#,
+        );
+        while moved.recv::<bool>().await.is_ok() {
+            let step = tour_steps(view)[*step_index.peek()];
+            anchor.set(measure_anchor(step.anchor).await);
+        }
+    });
+
+    let finish = {
+        let platform = platform.clone();
+        move |_| {
+            on_done.call(());
+            save_tour_progress(&platform, &display_state.tours.peek());
+        }
+    };
+
+    let (spotlight_style, card_style) = match *anchor.read() {
+        Some(rect) => (Some(spotlight_style(&rect)), card_style(&rect)),
+        None => (None, "left: 50%; top: 50%; transform: translate(-50%, -50%);".to_string()),
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-[1200]",
+            // Clicks outside the card don't reach the page underneath
+            onclick: move |e| e.stop_propagation(),
+
+            match spotlight_style {
+                Some(style) => rsx! {
+                    div {
+                        class: "fixed rounded-lg border-2 border-purple-400 pointer-events-none transition-all duration-200",
+                        style: "{style}",
+                    }
+                },
+                None => rsx! {
+                    div { class: "fixed inset-0 bg-black/65 pointer-events-none" }
+                },
+            }
+
+            div {
+                id: "onboarding-tour",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "onboarding-tour-title",
+                class: "fixed bg-dark-surface border border-purple-500/50 rounded-xl p-4 shadow-2xl flex flex-col gap-2",
+                style: "width: {CARD_WIDTH}px; max-width: calc(100vw - 32px); {card_style}",
+                onkeydown: {
+                    let finish = finish.clone();
+                    move |e: KeyboardEvent| {
+                        if e.key() == Key::Escape {
+                            e.stop_propagation();
+                            finish(());
+                        }
+                    }
+                },
+
+                div {
+                    class: "flex justify-between items-center",
+                    span { class: "text-purple-400 text-xs uppercase", "{view.display_name()} tour" }
+                    span { class: "text-gray-500 text-xs", "{index + 1} of {steps.len()}" }
+                }
+                h3 { id: "onboarding-tour-title", class: "text-white text-base m-0", "{step.title}" }
+                p { class: "text-gray-300 text-sm m-0 leading-relaxed", "{step.body}" }
+
+                div {
+                    class: "flex justify-between items-center mt-2",
+                    button {
+                        onclick: {
+                            let finish = finish.clone();
+                            move |_| finish(())
+                        },
+                        class: "bg-transparent border-0 text-gray-500 text-xs cursor-pointer",
+                        "Skip tour"
+                    }
+                    div {
+                        class: "flex gap-2",
+                        if index > 0 {
+                            button {
+                                onclick: move |_| step_index.set(index - 1),
+                                class: "px-3 py-1 bg-transparent text-gray-300 border border-gray-700 rounded text-sm cursor-pointer",
+                                "Back"
+                            }
+                        }
+                        button {
+                            onclick: {
+                                let finish = finish.clone();
+                                move |_| {
+                                    if is_last {
+                                        finish(());
+                                    } else {
+                                        step_index.set(index + 1);
+                                    }
+                                }
+                            },
+                            class: "px-3 py-1 bg-purple-600 text-white border-0 rounded text-sm cursor-pointer",
+                            if is_last { "Done" } else { "Next" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Find the visible control for a step; hidden or missing ones give `None`
+async fn measure_anchor(anchor: &str) -> Option<AnchorRect> {
+    let js = format!(
+        r#"
+        const el = Array.from(document.querySelectorAll('[data-tour="{anchor}"]'))
+            .find(e => e.getClientRects().length > 0);
+        if (!el) {{ return null; }}
+        el.scrollIntoView({{ block: 'nearest', inline: 'nearest' }});
+        const r = el.getBoundingClientRect();
+        return [r.left, r.top, r.width, r.height, window.innerWidth, window.innerHeight];
+        "#,
+        anchor = anchor,
+    );
+    let rect = document::eval(&js)
+        .join::<Option<(f64, f64, f64, f64, f64, f64)>>()
+        .await
+        .ok()??;
+    let (left, top, width, height, viewport_width, viewport_height) = rect;
+    Some(AnchorRect { left, top, width, height, viewport_width, viewport_height })
+}
+
+/// Ring around the control, with everything else dimmed by its shadow
+fn spotlight_style(rect: &AnchorRect) -> String {
+    format!(
+        "left: {}px; top: {}px; width: {}px; height: {}px; box-shadow: 0 0 0 9999px rgba(0, 0, 0, 0.65);",
+        rect.left - SPOTLIGHT_PADDING,
+        rect.top - SPOTLIGHT_PADDING,
+        rect.width + SPOTLIGHT_PADDING * 2.0,
+        rect.height + SPOTLIGHT_PADDING * 2.0,
+    )
+}
+
+/// Card below the control, or above it when the control is low on screen
+fn card_style(rect: &AnchorRect) -> String {
+    let gap = SPOTLIGHT_PADDING + 10.0;
+    let left = rect.left.min(rect.viewport_width - CARD_WIDTH - 16.0).max(16.0);
+    if rect.top + rect.height / 2.0 > rect.viewport_height / 2.0 {
+        format!("left: {}px; bottom: {}px;", left, rect.viewport_height - rect.top + gap)
+    } else {
+        format!("left: {}px; top: {}px;", left, rect.top + rect.height + gap)
+    }
+}
@@ -3,7 +3,9 @@
 use dioxus::prelude::*;
 
 use crate::application::services::CreateDmMarkerRequest;
+use crate::domain::value_objects::tags::parse_tag_list;
 use crate::presentation::services::use_story_event_service;
+use crate::presentation::state::use_modal_focus;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct AddDmMarkerModalProps {
@@ -16,6 +18,7 @@ pub struct AddDmMarkerModalProps {
 
 #[component]
 pub fn AddDmMarkerModal(props: AddDmMarkerModalProps) -> Element {
+    use_modal_focus("add-dm-marker-modal");
     // Get story event service
     let story_event_service = use_story_event_service();
     let mut title = use_signal(|| String::new());
@@ -50,6 +53,9 @@ pub fn AddDmMarkerModal(props: AddDmMarkerModalProps) -> Element {
             onclick: move |_| props.on_close.call(()),
 
             div {
+                id: "add-dm-marker-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "modal-content bg-dark-surface rounded-xl p-6 max-w-[500px] w-[90%]",
                 onclick: move |e| e.stop_propagation(),
 
@@ -60,6 +66,7 @@ pub fn AddDmMarkerModal(props: AddDmMarkerModalProps) -> Element {
                     h2 { class: "text-white m-0 text-xl", "📝 Add DM Marker" }
 
                     button {
+                        aria_label: "Close",
                         onclick: move |_| props.on_close.call(()),
                         class: "bg-transparent border-none text-gray-400 text-2xl cursor-pointer",
                         "×"
@@ -217,11 +224,7 @@ pub fn AddDmMarkerModal(props: AddDmMarkerModalProps) -> Element {
                                             let note_val = note.read().trim().to_string();
                                             let importance_val = importance.read().clone();
                                             let marker_type_val = marker_type.read().clone();
-                                            let tags: Vec<String> = tags_input.read()
-                                                .split(',')
-                                                .map(|s| s.trim().to_string())
-                                                .filter(|s| !s.is_empty())
-                                                .collect();
+                                            let tags = parse_tag_list(&tags_input.read());
 
                                             let world_id = world_id.clone();
                                             let session_id = session_id.clone();
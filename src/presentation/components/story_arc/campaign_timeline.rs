@@ -0,0 +1,350 @@
+//! Campaign Timeline - Story events from every world of a campaign
+//!
+//! Shows the campaigns the current world belongs to, their story events
+//! merged newest first with the world each happened in, and the PCs shared
+//! across the campaign, which the DM can move into another of its worlds.
+
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use crate::application::dto::StoryEventData;
+use crate::application::services::{Campaign, CampaignEvent, MigratePcRequest, PlayerCharacterData};
+use crate::presentation::components::story_arc::timeline_event_card::TimelineEventCard;
+use crate::presentation::components::story_arc::timeline_view::{open_event, source_panel, EventDetailModal};
+use crate::presentation::services::{use_campaign_service, use_story_event_service, use_world_service};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CampaignTimelineViewProps {
+    pub world_id: String,
+}
+
+#[component]
+pub fn CampaignTimelineView(props: CampaignTimelineViewProps) -> Element {
+    let campaign_service = use_campaign_service();
+    let world_service = use_world_service();
+    let story_event_service = use_story_event_service();
+
+    let mut campaigns: Signal<Vec<Campaign>> = use_signal(Vec::new);
+    let mut world_names: Signal<HashMap<String, String>> = use_signal(HashMap::new);
+    let mut selected_campaign: Signal<Option<String>> = use_signal(|| None);
+    let mut events: Signal<Vec<CampaignEvent>> = use_signal(Vec::new);
+    let mut shared_pcs: Signal<Vec<PlayerCharacterData>> = use_signal(Vec::new);
+    let mut world_filter: Signal<Option<String>> = use_signal(|| None);
+    let mut selected_event: Signal<Option<StoryEventData>> = use_signal(|| None);
+    let source_event: Signal<Option<StoryEventData>> = use_signal(|| None);
+    let mut is_loading = use_signal(|| true);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut reload = use_signal(|| 0u32);
+
+    // Every world's name, then the campaigns containing this world
+    {
+        let world_id = props.world_id.clone();
+        let campaign_service = campaign_service.clone();
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let campaign_service = campaign_service.clone();
+            let world_service = world_service.clone();
+            spawn(async move {
+                match world_service.list_worlds().await {
+                    Ok(worlds) => world_names.set(worlds.into_iter().map(|w| (w.id, w.name)).collect()),
+                    Err(e) => tracing::warn!("Failed to load world names: {}", e),
+                }
+                match campaign_service.list_campaigns().await {
+                    Ok(list) => {
+                        let mine: Vec<Campaign> = list.into_iter().filter(|c| c.contains(&world_id)).collect();
+                        selected_campaign.set(mine.first().map(|c| c.id.clone()));
+                        if mine.is_empty() {
+                            is_loading.set(false);
+                        }
+                        campaigns.set(mine);
+                    }
+                    Err(e) => {
+                        error.set(Some(format!("Failed to load campaigns: {}", e)));
+                        is_loading.set(false);
+                    }
+                }
+            });
+        });
+    }
+
+    // Timeline and shared PCs of the selected campaign
+    {
+        let campaign_service = campaign_service.clone();
+        use_effect(move || {
+            let _ = reload.read();
+            let names = world_names.peek().clone();
+            let Some(campaign) = selected_campaign
+                .read()
+                .as_ref()
+                .and_then(|id| campaigns.read().iter().find(|c| &c.id == id).cloned())
+            else {
+                return;
+            };
+            let campaign_service = campaign_service.clone();
+            spawn(async move {
+                is_loading.set(true);
+                error.set(None);
+                match campaign_service.campaign_timeline(&campaign, |id| names.get(id).cloned()).await {
+                    Ok(merged) => events.set(merged),
+                    Err(e) => error.set(Some(format!("Failed to load the campaign timeline: {}", e))),
+                }
+                match campaign_service.list_shared_pcs(&campaign.id).await {
+                    Ok(pcs) => shared_pcs.set(pcs),
+                    Err(e) => tracing::warn!("Failed to load shared PCs: {}", e),
+                }
+                is_loading.set(false);
+            });
+        });
+    }
+
+    let campaigns_snapshot = campaigns.read().clone();
+    let campaign = selected_campaign
+        .read()
+        .as_ref()
+        .and_then(|id| campaigns_snapshot.iter().find(|c| &c.id == id).cloned());
+    let names = world_names.read().clone();
+    let name_of = |id: &str| names.get(id).cloned().unwrap_or_else(|| id.to_string());
+
+    let Some(campaign) = campaign else {
+        return rsx! {
+            div {
+                class: "flex flex-col items-center justify-center h-full p-12 text-gray-500",
+                div { class: "text-5xl mb-4", "🗺" }
+                if *is_loading.read() {
+                    p { "Loading campaigns..." }
+                } else if let Some(err) = error.read().as_ref() {
+                    p { class: "text-red-500", "{err}" }
+                } else {
+                    p { "This world isn't part of a campaign" }
+                    p { class: "text-sm", "Group worlds into a campaign from the world list" }
+                }
+            }
+        };
+    };
+
+    let filter = world_filter.read().clone();
+    let visible: Vec<CampaignEvent> = events
+        .read()
+        .iter()
+        .filter(|e| filter.as_ref().is_none_or(|w| &e.world_id == w))
+        .cloned()
+        .collect();
+
+    rsx! {
+        div {
+            class: "campaign-timeline h-full flex gap-4 p-4",
+
+            div {
+                class: "flex-1 flex flex-col gap-4 min-w-0",
+
+                // Header with campaign picker
+                div {
+                    class: "flex justify-between items-center gap-3",
+                    div {
+                        h2 { class: "text-white m-0 text-xl", "{campaign.name}" }
+                        if let Some(desc) = campaign.description.as_ref() {
+                            p { class: "text-gray-400 m-0 text-sm", "{desc}" }
+                        }
+                    }
+                    if campaigns_snapshot.len() > 1 {
+                        select {
+                            value: "{campaign.id}",
+                            aria_label: "Campaign",
+                            onchange: move |e: FormEvent| {
+                                world_filter.set(None);
+                                selected_campaign.set(Some(e.value()));
+                            },
+                            class: "p-2 bg-dark-bg border border-gray-700 rounded-lg text-white text-sm",
+                            for c in campaigns_snapshot.iter() {
+                                option { value: "{c.id}", selected: c.id == campaign.id, "{c.name}" }
+                            }
+                        }
+                    }
+                }
+
+                // World filter, in play order
+                div {
+                    class: "flex flex-wrap gap-2",
+                    button {
+                        onclick: move |_| world_filter.set(None),
+                        class: if filter.is_none() { CHIP_ACTIVE } else { CHIP },
+                        "All worlds"
+                    }
+                    for (i, world_id) in campaign.world_ids.iter().enumerate() {
+                        button {
+                            key: "{world_id}",
+                            onclick: {
+                                let world_id = world_id.clone();
+                                move |_| world_filter.set(Some(world_id.clone()))
+                            },
+                            class: if filter.as_ref() == Some(world_id) { CHIP_ACTIVE } else { CHIP },
+                            "{i + 1}. {name_of(world_id)}"
+                        }
+                    }
+                }
+
+                // Event list
+                div {
+                    class: "flex-1 overflow-y-auto flex flex-col gap-3",
+
+                    if *is_loading.read() {
+                        div {
+                            class: "flex justify-center items-center p-12 text-gray-400",
+                            "Loading campaign timeline..."
+                        }
+                    } else if let Some(err) = error.read().as_ref() {
+                        div {
+                            class: "bg-red-500 bg-opacity-10 border border-red-500 rounded-lg p-4 text-red-500",
+                            "{err}"
+                        }
+                    } else if visible.is_empty() {
+                        div {
+                            class: "flex flex-col items-center justify-center p-12 text-gray-500",
+                            div { class: "text-5xl mb-4", "📜" }
+                            p { "No events recorded in these worlds yet" }
+                        }
+                    } else {
+                        for entry in visible.iter() {
+                            div {
+                                key: "{entry.event.id}",
+                                class: "flex flex-col gap-1",
+                                span {
+                                    class: if entry.world_id == props.world_id { "text-purple-300 text-xs" } else { "text-gray-500 text-xs" },
+                                    if let Some(n) = campaign.chapter_of(&entry.world_id) {
+                                        "Chapter {n} · {entry.world_name}"
+                                    } else {
+                                        "{entry.world_name}"
+                                    }
+                                }
+                                TimelineEventCard {
+                                    event: entry.event.clone(),
+                                    on_click: {
+                                        let event = entry.event.clone();
+                                        move |_| open_event(event.clone(), source_event, selected_event)
+                                    },
+                                    on_toggle_visibility: {
+                                        let event_id = entry.event.id.clone();
+                                        let service = story_event_service.clone();
+                                        move |_| {
+                                            let event_id = event_id.clone();
+                                            let service = service.clone();
+                                            spawn(async move {
+                                                if let Err(e) = service.toggle_event_visibility(&event_id).await {
+                                                    tracing::error!("Failed to toggle visibility: {}", e);
+                                                }
+                                                *reload.write() += 1;
+                                            });
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            SharedPcsPanel {
+                campaign: campaign.clone(),
+                pcs: shared_pcs.read().clone(),
+                world_names: names.clone(),
+                on_migrated: move |pc: PlayerCharacterData| {
+                    if let Some(existing) = shared_pcs.write().iter_mut().find(|p| p.id == pc.id) {
+                        *existing = pc;
+                    }
+                },
+            }
+
+            {source_panel(source_event, selected_event)}
+
+            if let Some(event) = selected_event.read().as_ref() {
+                EventDetailModal {
+                    event: event.clone(),
+                    on_close: move |_| selected_event.set(None),
+                }
+            }
+        }
+    }
+}
+
+const CHIP: &str = "px-3 py-1 bg-dark-surface text-gray-300 border border-gray-700 rounded-full cursor-pointer text-xs";
+const CHIP_ACTIVE: &str = "px-3 py-1 bg-purple-500 text-white border border-purple-500 rounded-full cursor-pointer text-xs";
+
+/// PCs shared across the campaign, each movable into another of its worlds
+#[component]
+fn SharedPcsPanel(
+    campaign: Campaign,
+    pcs: Vec<PlayerCharacterData>,
+    world_names: HashMap<String, String>,
+    on_migrated: EventHandler<PlayerCharacterData>,
+) -> Element {
+    let campaign_service = use_campaign_service();
+    let mut moving: Signal<Option<String>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let name_of = |id: &str| world_names.get(id).cloned().unwrap_or_else(|| id.to_string());
+
+    rsx! {
+        div {
+            class: "w-[280px] shrink-0 bg-dark-surface rounded-lg p-4 overflow-y-auto",
+
+            h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Shared PCs" }
+
+            if let Some(err) = error.read().as_ref() {
+                p { class: "text-red-500 text-xs mb-2", "{err}" }
+            }
+
+            if pcs.is_empty() {
+                p { class: "text-gray-500 text-sm", "No player characters in this campaign yet" }
+            }
+
+            for pc in pcs.iter() {
+                div {
+                    key: "{pc.id}",
+                    class: "py-2 border-b border-gray-700 last:border-b-0",
+
+                    div { class: "text-white text-sm", "{pc.name}" }
+                    div { class: "text-gray-500 text-xs mb-1", "In {name_of(&pc.world_id)}" }
+
+                    select {
+                        aria_label: "Move {pc.name} to another world",
+                        disabled: moving.read().is_some(),
+                        onchange: {
+                            let svc = campaign_service.clone();
+                            let campaign_id = campaign.id.clone();
+                            let pc_id = pc.id.clone();
+                            move |e: FormEvent| {
+                                let target_world_id = e.value();
+                                if target_world_id.is_empty() {
+                                    return;
+                                }
+                                let svc = svc.clone();
+                                let campaign_id = campaign_id.clone();
+                                let pc_id = pc_id.clone();
+                                spawn(async move {
+                                    moving.set(Some(pc_id.clone()));
+                                    error.set(None);
+                                    let request = MigratePcRequest { target_world_id, starting_location_id: None };
+                                    match svc.migrate_pc(&campaign_id, &pc_id, &request).await {
+                                        Ok(moved) => on_migrated.call(moved),
+                                        Err(e) => error.set(Some(format!("Failed to move PC: {}", e))),
+                                    }
+                                    moving.set(None);
+                                });
+                            }
+                        },
+                        class: "w-full p-1 bg-dark-bg border border-gray-700 rounded text-white text-xs",
+
+                        option {
+                            value: "",
+                            selected: true,
+                            if moving.read().as_ref() == Some(&pc.id) { "Moving..." } else { "Move to world..." }
+                        }
+                        for world_id in campaign.world_ids.iter().filter(|id| **id != pc.world_id) {
+                            option { value: "{world_id}", "{name_of(world_id)}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
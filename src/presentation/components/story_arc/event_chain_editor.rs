@@ -1,7 +1,9 @@
 //! Event Chain Editor - Edit chain info and manage events
 
 use dioxus::prelude::*;
+use crate::application::dto::EntityStyle;
 use crate::application::services::{EventChainData, CreateEventChainRequest, UpdateEventChainRequest};
+use crate::presentation::components::common::EntityStylePicker;
 use crate::presentation::services::use_event_chain_service;
 
 #[derive(Props, Clone, PartialEq)]
@@ -21,7 +23,13 @@ pub fn EventChainEditor(props: EventChainEditorProps) -> Element {
     let mut name = use_signal(|| props.chain.as_ref().map(|c| c.name.clone()).unwrap_or_default());
     let mut description = use_signal(|| props.chain.as_ref().map(|c| c.description.clone()).unwrap_or_default());
     let mut tags = use_signal(|| props.chain.as_ref().map(|c| c.tags.clone()).unwrap_or_default());
-    let mut color = use_signal(|| props.chain.as_ref().and_then(|c| c.color.clone()));
+    let mut style = use_signal(|| {
+        props
+            .chain
+            .as_ref()
+            .map(|c| EntityStyle { icon: c.icon.clone(), accent_color: c.color.clone() })
+            .unwrap_or_default()
+    });
     let mut is_active = use_signal(|| props.chain.as_ref().map(|c| c.is_active).unwrap_or(true));
     let mut new_tag = use_signal(String::new);
     let is_saving = use_signal(|| false);
@@ -38,7 +46,7 @@ pub fn EventChainEditor(props: EventChainEditorProps) -> Element {
             let name_val = name.read().clone();
             let desc_val = description.read().clone();
             let tags_val = tags.read().clone();
-            let color_val = color.read().clone();
+            let EntityStyle { icon: icon_val, accent_color: color_val } = style.read().clone();
             let active_val = *is_active.read();
             let mut saving = is_saving;
             let mut err = error;
@@ -52,7 +60,8 @@ pub fn EventChainEditor(props: EventChainEditorProps) -> Element {
                         name: Some(name_val),
                         description: Some(desc_val),
                         tags: Some(tags_val),
-                        color: color_val,
+                        color: Some(color_val),
+                        icon: Some(icon_val),
                         is_active: Some(active_val),
                         events: None,
                         act_id: None,
@@ -67,6 +76,7 @@ pub fn EventChainEditor(props: EventChainEditorProps) -> Element {
                         act_id: None,
                         tags: tags_val,
                         color: color_val,
+                        icon: icon_val,
                         is_active: active_val,
                     };
                     service.create_chain(&world_id, &request).await
@@ -137,18 +147,15 @@ pub fn EventChainEditor(props: EventChainEditorProps) -> Element {
                     }
                 }
 
-                // Color
+                // Icon and color
                 div {
                     label {
                         class: "block text-gray-400 text-sm mb-2",
-                        "Color (hex)"
+                        "Icon & Color"
                     }
-                    input {
-                        r#type: "text",
-                        value: "{color.read().as_ref().map(|c| c.as_str()).unwrap_or(\"\")}",
-                        oninput: move |evt| color.set(if evt.value().is_empty() { None } else { Some(evt.value()) }),
-                        placeholder: "#8b5cf6",
-                        class: "w-full px-2 py-2 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                    EntityStylePicker {
+                        style: style.read().clone(),
+                        on_change: move |next| style.set(next),
                     }
                 }
 
@@ -165,6 +172,7 @@ pub fn EventChainEditor(props: EventChainEditorProps) -> Element {
                                 class: "inline-flex items-center gap-1 px-2 py-1 bg-gray-700 text-white rounded text-xs",
                                 "{tag}"
                                 button {
+                                    aria_label: "Remove tag",
                                     onclick: {
                                         let tag_to_remove = tag.clone();
                                         move |_| {
@@ -1,8 +1,9 @@
 //! Event Chain List - Display all event chains with progress indicators
 
 use dioxus::prelude::*;
-use tracing::info;
+use crate::application::dto::EntityStyle;
 use crate::application::services::EventChainData;
+use crate::presentation::components::common::{accent_stripe, CopyButton, EntityMark};
 use crate::presentation::services::use_event_chain_service;
 
 #[derive(Props, Clone, PartialEq)]
@@ -88,31 +89,13 @@ pub fn EventChainList(props: EventChainListProps) -> Element {
                 }
             }
 
-            // Export/Import buttons (simplified - copy to clipboard for export)
+            // Export: the chains as JSON on the clipboard
             div {
                 class: "flex gap-2 mb-2",
-                button {
-                    onclick: {
-                        let chains_signal = chains;
-                        move |_| {
-                            let chains_data = chains_signal.read().clone();
-                            if let Ok(json) = serde_json::to_string_pretty(&chains_data) {
-                                // Copy to clipboard (simplified export)
-                                #[cfg(target_arch = "wasm32")]
-                                {
-                                    // In WASM, we'd use web_sys to copy to clipboard
-                                    // For now, just log - can be enhanced later
-                                    info!("Export JSON:\n{}", json);
-                                }
-                                #[cfg(not(target_arch = "wasm32"))]
-                                {
-                                    info!("Export JSON:\n{}", json);
-                                }
-                            }
-                        }
-                    },
+                CopyButton {
+                    text: serde_json::to_string_pretty(&*chains.read()).unwrap_or_default(),
+                    label: "Copy JSON",
                     class: "px-3 py-1.5 bg-gray-700 text-white border-none rounded cursor-pointer text-xs",
-                    "📥 Export JSON"
                 }
             }
 
@@ -228,11 +211,16 @@ fn EventChainCard(
 
     let bg_class = if is_selected { "bg-dark-surface" } else { "bg-dark-bg" };
     let border_class = if is_selected { "border-purple-500" } else { "border-gray-700" };
+    let style = EntityStyle {
+        icon: chain.icon.clone(),
+        accent_color: chain.color.clone(),
+    };
 
     rsx! {
         div {
             onclick: move |_| on_select.call(chain.id.clone()),
             class: "p-4 {bg_class} border-2 {border_class} rounded-lg cursor-pointer transition-all duration-200",
+            style: accent_stripe(style.accent_color.as_deref()),
 
             // Header
             div {
@@ -240,7 +228,8 @@ fn EventChainCard(
                 div {
                     class: "flex-1",
                     h3 {
-                        class: "text-white m-0 mb-1 text-base font-medium",
+                        class: "flex items-center gap-2 text-white m-0 mb-1 text-base font-medium",
+                        EntityMark { style: style.clone() }
                         "{chain.name}"
                         if chain.is_favorite {
                             span { class: "text-amber-500 ml-2", "⭐" }
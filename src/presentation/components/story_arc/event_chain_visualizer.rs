@@ -2,7 +2,7 @@
 
 use dioxus::prelude::*;
 use crate::application::services::EventChainData;
-use crate::application::dto::NarrativeEventData;
+use crate::application::dto::{description_plain_text, NarrativeEventData};
 
 #[derive(Props, Clone, PartialEq)]
 pub struct EventChainVisualizerProps {
@@ -50,8 +50,14 @@ pub fn EventChainVisualizer(props: EventChainVisualizerProps) -> Element {
                     chain_position: None,
                     outcome_count: 0,
                     trigger_condition_count: 0,
+                    trigger_conditions: Vec::new(),
+                    outcomes: Vec::new(),
+                    audio_cue: None,
+                    camera_effect: None,
                     created_at: String::new(),
                     updated_at: String::new(),
+                    deleted_at: None,
+                    archived_at: None,
                 })
                 .collect();
             events.set(placeholder_events);
@@ -172,7 +178,7 @@ fn EventNode(
             if !event.description.is_empty() {
                 p {
                     class: "text-white/70 m-0 text-xs text-center overflow-hidden text-ellipsis line-clamp-2",
-                    "{event.description}"
+                    "{description_plain_text(&event.description)}"
                 }
             }
             div {
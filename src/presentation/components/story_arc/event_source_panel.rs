@@ -0,0 +1,175 @@
+//! Event Source Panel - The conversation or roll behind a timeline event
+//!
+//! Opens beside the timeline when a dialogue or challenge event is clicked.
+//! The source is looked up in the session's conversation index; events from
+//! earlier sessions fall back to what the event itself recorded.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{StoryEventData, StoryEventSourceData, StoryEventTypeData};
+use crate::application::services::{IndexedLine, IndexedRoll, SourceDetail};
+use crate::presentation::state::use_session_state;
+
+#[derive(Props, Clone)]
+pub struct EventSourcePanelProps {
+    /// An event with a source reference
+    pub event: StoryEventData,
+    pub on_close: EventHandler<()>,
+    /// Open the full event detail modal
+    pub on_show_details: EventHandler<()>,
+}
+
+impl PartialEq for EventSourcePanelProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.event.id == other.event.id && self.event.source == other.event.source
+    }
+}
+
+#[component]
+pub fn EventSourcePanel(props: EventSourcePanelProps) -> Element {
+    let session_state = use_session_state();
+    // Rolls are indexed by PC name
+    let party = session_state.party.members.read().clone();
+    let pc_name = |pc_id: &str| party.iter().find(|m| m.pc_id == pc_id).map(|m| m.name.clone());
+    let Some(source) = props.event.source_reference(pc_name) else {
+        return rsx! {};
+    };
+    let index = session_state.conversation_index.read();
+    let detail = index.resolve(&source);
+
+    let (title, npc_id) = match &source {
+        StoryEventSourceData::Conversation { npc_id, npc_name, .. } => (format!("Conversation with {}", npc_name), Some(npc_id.clone())),
+        StoryEventSourceData::Challenge { character_name, .. } => (format!("{}'s roll", character_name), None),
+    };
+
+    rsx! {
+        aside {
+            class: "event-source-panel fixed top-0 right-0 bottom-0 w-full sm:w-[400px] bg-dark-surface border-l border-gray-700 z-[1000] flex flex-col shadow-[-4px_0_6px_rgba(0,0,0,0.3)]",
+            aria_label: "{title}",
+
+            // Header
+            div {
+                class: "flex justify-between items-center p-4 border-b border-gray-700",
+                h3 { class: "text-white m-0 text-base", "{title}" }
+                div {
+                    class: "flex items-center gap-2",
+                    button {
+                        onclick: move |_| props.on_show_details.call(()),
+                        class: "py-1 px-2 bg-gray-700 text-white border-none rounded cursor-pointer text-xs",
+                        "Event details"
+                    }
+                    button {
+                        aria_label: "Close",
+                        onclick: move |_| props.on_close.call(()),
+                        class: "py-1 px-2 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
+                        "×"
+                    }
+                }
+            }
+
+            // Content
+            div {
+                class: "flex-1 overflow-y-auto p-4 flex flex-col gap-3",
+                p { class: "text-gray-400 text-sm m-0", "{props.event.summary}" }
+
+                match detail {
+                    SourceDetail::Conversation(lines) => rsx! {
+                        for (i, line) in lines.into_iter().enumerate() {
+                            ExcerptLine {
+                                key: "{i}",
+                                line: line.clone(),
+                                is_source_npc: npc_id.as_deref() == Some(line.speaker_id.as_str()),
+                            }
+                        }
+                    },
+                    SourceDetail::Roll(roll) => rsx! {
+                        RollDetail { roll: roll.clone() }
+                    },
+                    SourceDetail::NotFound => rsx! {
+                        p {
+                            class: "text-amber-500 text-xs m-0",
+                            "Not heard in this session; showing what the event recorded."
+                        }
+                        {recorded_source(&props.event.event_type)}
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// One line of the excerpt, highlighted when spoken by the event's NPC
+#[component]
+fn ExcerptLine(line: IndexedLine, is_source_npc: bool) -> Element {
+    let border = if is_source_npc { "border-l-blue-400" } else { "border-l-gray-700" };
+    let time = chrono::DateTime::from_timestamp(line.timestamp as i64, 0)
+        .map(|t| t.format("%H:%M").to_string())
+        .unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "p-3 bg-dark-bg rounded-lg border-l-[3px] {border}",
+            div {
+                class: "flex justify-between mb-1",
+                span { class: "text-blue-400 text-[0.8125rem]", "{line.speaker_name}" }
+                span { class: "text-gray-500 text-xs", "{time}" }
+            }
+            p { class: "text-gray-300 text-sm m-0", "{line.text}" }
+        }
+    }
+}
+
+#[component]
+fn RollDetail(roll: IndexedRoll) -> Element {
+    let signed_modifier = if roll.modifier >= 0 { format!("+{}", roll.modifier) } else { roll.modifier.to_string() };
+    let outcome = roll.outcome.replace('_', " ");
+
+    rsx! {
+        div {
+            class: "p-3 bg-dark-bg rounded-lg flex flex-col gap-2",
+            span { class: "text-white text-sm", "{roll.challenge_name}" }
+            span {
+                class: "text-white font-mono text-lg",
+                "🎲 {roll.roll} {signed_modifier} = {roll.total}"
+            }
+            if let Some(breakdown) = roll.roll_breakdown.as_ref() {
+                span { class: "text-gray-500 font-mono text-xs", "{breakdown}" }
+            }
+            span { class: "text-amber-400 text-sm capitalize", "{outcome}" }
+            if !roll.outcome_description.is_empty() {
+                p { class: "text-gray-300 text-sm m-0", "{roll.outcome_description}" }
+            }
+        }
+    }
+}
+
+/// The source as the event itself recorded it
+fn recorded_source(event_type: &StoryEventTypeData) -> Element {
+    match event_type {
+        StoryEventTypeData::DialogueExchange { npc_name, player_dialogue, npc_response, .. } => rsx! {
+            div {
+                class: "p-3 bg-dark-bg rounded-lg flex flex-col gap-2",
+                if !player_dialogue.is_empty() {
+                    p { class: "text-gray-400 text-sm m-0", "“{player_dialogue}”" }
+                }
+                span { class: "text-blue-400 text-[0.8125rem]", "{npc_name}" }
+                p { class: "text-gray-300 text-sm m-0 italic", "“{npc_response}”" }
+            }
+        },
+        StoryEventTypeData::ChallengeAttempted { challenge_name, roll_result, modifier, outcome, .. } => {
+            let roll = roll_result.map(|roll| format!("🎲 {} ({:+})", roll, modifier.unwrap_or(0)));
+            let outcome = outcome.replace('_', " ");
+            rsx! {
+                div {
+                    class: "p-3 bg-dark-bg rounded-lg flex flex-col gap-2",
+                    span { class: "text-white text-sm", "{challenge_name}" }
+                    if let Some(roll) = roll {
+                        span { class: "text-white font-mono", "{roll}" }
+                    }
+                    span { class: "text-amber-400 text-sm capitalize", "{outcome}" }
+                }
+            }
+        }
+        _ => rsx! {},
+    }
+}
@@ -2,16 +2,23 @@
 //!
 //! Components for the Story Arc tab in the DM View:
 //! - Timeline view for past events (StoryEvents)
+//! - Recap composer for "previously on…" playback
+//! - Campaign timeline across every world of a campaign
 //! - Narrative Events library and designer
 //! - Event chain visualizer
+//! - Branching view of possible narrative paths
 
 pub mod timeline_view;
+pub mod campaign_timeline;
 pub mod timeline_event_card;
+pub mod event_source_panel;
 pub mod timeline_filters;
 pub mod add_dm_marker;
+pub mod recap_composer;
 pub mod narrative_event_library;
 pub mod narrative_event_card;
 pub mod pending_events_widget;
 pub mod event_chain_list;
 pub mod event_chain_visualizer;
 pub mod event_chain_editor;
+pub mod narrative_branch_view;
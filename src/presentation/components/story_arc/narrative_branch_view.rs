@@ -0,0 +1,498 @@
+//! Narrative branch view - possible story paths as a branching graph
+//!
+//! Merges event chains, "event completed" triggers, and outcome follow-ups
+//! into one graph of narrative events, laid out by depth. Branches the story
+//! has already taken (per the story event log) are highlighted.
+
+use dioxus::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+use crate::application::dto::{NarrativeEventData, StoryEventData, StoryEventTypeData};
+use crate::application::services::EventChainData;
+use crate::presentation::components::common::{layer_by_depth, nodes_in_cycles, Predecessors};
+use crate::presentation::services::{
+    use_event_chain_service, use_narrative_event_service, use_story_event_service,
+};
+
+/// Where a link between two narrative events comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BranchLinkKind {
+    /// Consecutive events in an event chain
+    Chain,
+    /// The target has an "event completed" trigger on the source
+    Trigger,
+    /// An outcome of the source queues up the target
+    Outcome,
+}
+
+impl BranchLinkKind {
+    fn icon(&self) -> &'static str {
+        match self {
+            Self::Chain => "🔗",
+            Self::Trigger => "⚡",
+            Self::Outcome => "↳",
+        }
+    }
+}
+
+/// A directed link from one narrative event to another
+#[derive(Debug, Clone, PartialEq)]
+pub struct BranchLink {
+    pub from: String,
+    pub to: String,
+    pub kind: BranchLinkKind,
+    /// Chain name, trigger description, or outcome label
+    pub label: String,
+    /// The story actually went this way
+    pub taken: bool,
+}
+
+/// Narrative events that have fired, with the outcome they resolved to
+///
+/// Story events are authoritative; events flagged as triggered but missing
+/// from the loaded log fall back to their own `selected_outcome`.
+pub fn fired_events(
+    events: &[NarrativeEventData],
+    story: &[StoryEventData],
+) -> HashMap<String, Option<String>> {
+    let mut fired: HashMap<String, Option<String>> = HashMap::new();
+    for story_event in story {
+        if let StoryEventTypeData::NarrativeEventTriggered {
+            narrative_event_id,
+            outcome_branch,
+            ..
+        } = &story_event.event_type
+        {
+            fired.insert(narrative_event_id.clone(), outcome_branch.clone());
+        }
+    }
+    for event in events.iter().filter(|e| e.is_triggered) {
+        fired
+            .entry(event.id.clone())
+            .or_insert_with(|| event.selected_outcome.clone());
+    }
+    fired
+}
+
+/// All links between known narrative events
+pub fn branch_links(
+    events: &[NarrativeEventData],
+    chains: &[EventChainData],
+    fired: &HashMap<String, Option<String>>,
+) -> Vec<BranchLink> {
+    let known: HashSet<&str> = events.iter().map(|e| e.id.as_str()).collect();
+    let mut seen: HashSet<(String, String, BranchLinkKind)> = HashSet::new();
+    let mut links = Vec::new();
+    let mut push = |link: BranchLink| {
+        if link.from != link.to
+            && known.contains(link.from.as_str())
+            && known.contains(link.to.as_str())
+            && seen.insert((link.from.clone(), link.to.clone(), link.kind))
+        {
+            links.push(link);
+        }
+    };
+
+    for chain in chains {
+        for pair in chain.events.windows(2) {
+            push(BranchLink {
+                from: pair[0].clone(),
+                to: pair[1].clone(),
+                kind: BranchLinkKind::Chain,
+                label: match &chain.icon {
+                    Some(icon) => format!("{} {}", icon, chain.name),
+                    None => chain.name.clone(),
+                },
+                taken: fired.contains_key(&pair[0]) && fired.contains_key(&pair[1]),
+            });
+        }
+    }
+
+    for event in events {
+        for trigger in &event.trigger_conditions {
+            let Some(source) = trigger.event_id.as_ref() else {
+                continue;
+            };
+            let outcome_matches = match (fired.get(source), &trigger.outcome_name) {
+                (Some(_), None) => true,
+                (Some(Some(outcome)), Some(required)) => outcome == required,
+                _ => false,
+            };
+            push(BranchLink {
+                from: source.clone(),
+                to: event.id.clone(),
+                kind: BranchLinkKind::Trigger,
+                label: trigger.description.clone(),
+                taken: outcome_matches && fired.contains_key(&event.id),
+            });
+        }
+
+        let chosen = fired.get(&event.id).cloned().flatten();
+        for outcome in &event.outcomes {
+            for next in &outcome.chain_events {
+                push(BranchLink {
+                    from: event.id.clone(),
+                    to: next.clone(),
+                    kind: BranchLinkKind::Outcome,
+                    label: outcome.label.clone(),
+                    taken: chosen.as_deref() == Some(outcome.name.as_str())
+                        && fired.contains_key(next),
+                });
+            }
+        }
+    }
+    links
+}
+
+/// Links grouped by target event
+fn incoming_links(links: &[BranchLink]) -> Predecessors<'_> {
+    let mut incoming: Predecessors = HashMap::new();
+    for link in links {
+        incoming.entry(link.to.as_str()).or_default().push(link.from.as_str());
+    }
+    incoming
+}
+
+/// IDs of events that sit on a cycle of links
+pub fn events_in_cycles(events: &[NarrativeEventData], links: &[BranchLink]) -> HashSet<String> {
+    let ids: Vec<&str> = events.iter().map(|e| e.id.as_str()).collect();
+    nodes_in_cycles(&ids, &incoming_links(links))
+}
+
+/// Group events into layers by their longest path from a root
+///
+/// Layer 0 holds events nothing leads to. Events on a cycle have no
+/// well-defined depth and are returned in a final extra layer.
+pub fn layer_branches(events: &[NarrativeEventData], links: &[BranchLink]) -> Vec<Vec<String>> {
+    let ids: Vec<&str> = events.iter().map(|e| e.id.as_str()).collect();
+    layer_by_depth(&ids, &incoming_links(links))
+}
+
+/// Branching graph of all narrative events in a world
+#[component]
+pub fn NarrativeBranchView(world_id: String) -> Element {
+    let narrative_event_service = use_narrative_event_service();
+    let event_chain_service = use_event_chain_service();
+    let story_event_service = use_story_event_service();
+
+    let mut events: Signal<Vec<NarrativeEventData>> = use_signal(Vec::new);
+    let mut chains: Signal<Vec<EventChainData>> = use_signal(Vec::new);
+    let mut story: Signal<Vec<StoryEventData>> = use_signal(Vec::new);
+    let mut is_loading = use_signal(|| true);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+    let mut only_reachable = use_signal(|| false);
+
+    {
+        let world_id = world_id.clone();
+        use_effect(move || {
+            let world_id = world_id.clone();
+            let narrative_svc = narrative_event_service.clone();
+            let chain_svc = event_chain_service.clone();
+            let story_svc = story_event_service.clone();
+            spawn(async move {
+                is_loading.set(true);
+                match narrative_svc.list_narrative_events(&world_id).await {
+                    Ok(list) => events.set(list),
+                    Err(e) => error.set(Some(format!("Failed to load events: {}", e))),
+                }
+                // Chains and story history only enrich the graph
+                if let Ok(list) = chain_svc.list_chains(&world_id).await {
+                    chains.set(list);
+                }
+                if let Ok(list) = story_svc.list_story_events(&world_id, None).await {
+                    story.set(list);
+                }
+                is_loading.set(false);
+            });
+        });
+    }
+
+    let all_events = events.read().clone();
+    let fired = fired_events(&all_events, &story.read());
+    let links = branch_links(&all_events, &chains.read(), &fired);
+
+    // Optionally hide events no branch reaches or leaves
+    let linked: HashSet<&str> = links
+        .iter()
+        .flat_map(|l| [l.from.as_str(), l.to.as_str()])
+        .collect();
+    let shown: Vec<NarrativeEventData> = if *only_reachable.read() {
+        all_events
+            .iter()
+            .filter(|e| linked.contains(e.id.as_str()))
+            .cloned()
+            .collect()
+    } else {
+        all_events.clone()
+    };
+    let layers = layer_branches(&shown, &links);
+    let by_id: HashMap<&str, &NarrativeEventData> =
+        all_events.iter().map(|e| (e.id.as_str(), e)).collect();
+    let cyclic = events_in_cycles(&shown, &links);
+
+    rsx! {
+        div {
+            class: "h-full flex flex-col gap-4 p-4 overflow-y-auto",
+
+            div {
+                class: "flex justify-between items-center",
+                h2 { class: "text-white m-0 text-2xl", "Story Branches" }
+                label {
+                    class: "inline-flex items-center gap-2 text-gray-400 text-sm",
+                    input {
+                        r#type: "checkbox",
+                        checked: *only_reachable.read(),
+                        onchange: move |_| {
+                            let current = *only_reachable.read();
+                            only_reachable.set(!current);
+                        },
+                    }
+                    "Only linked events"
+                }
+            }
+
+            // Legend
+            div {
+                class: "flex flex-wrap gap-4 text-xs text-gray-400",
+                span { class: "flex items-center gap-1", span { class: "w-2 h-2 rounded-full bg-green-500" } "Fired" }
+                span { class: "flex items-center gap-1", span { class: "w-2 h-2 rounded-full bg-blue-500" } "Active" }
+                span { class: "flex items-center gap-1", span { class: "w-2 h-2 rounded-full bg-gray-600" } "Inactive" }
+                span { "🔗 Chain" }
+                span { "⚡ Trigger" }
+                span { "↳ Outcome" }
+                span { class: "text-green-400", "Highlighted = branch taken" }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-500/10 border border-red-500 rounded-lg text-red-500 text-sm",
+                    "{err}"
+                }
+            }
+
+            if *is_loading.read() {
+                div { class: "text-gray-500 text-center p-8", "Loading story branches..." }
+            } else if layers.is_empty() {
+                div { class: "text-gray-500 text-center p-8", "No narrative events to show" }
+            } else {
+                div {
+                    class: "flex gap-6 overflow-x-auto pb-2",
+
+                    for (index, layer) in layers.iter().enumerate() {
+                        div {
+                            key: "{index}",
+                            class: "flex flex-col gap-2 min-w-[220px] max-w-[260px]",
+
+                            div {
+                                class: "text-gray-500 text-xs uppercase",
+                                if layer.iter().any(|id| cyclic.contains(id)) {
+                                    "Cycle"
+                                } else if index == 0 {
+                                    "Openings"
+                                } else {
+                                    "Step {index}"
+                                }
+                            }
+
+                            for event_id in layer.iter() {
+                                if let Some(event) = by_id.get(event_id.as_str()) {
+                                    BranchNode {
+                                        key: "{event_id}",
+                                        event: (*event).clone(),
+                                        fired_outcome: fired.get(event_id).cloned(),
+                                        incoming: links
+                                            .iter()
+                                            .filter(|l| &l.to == event_id)
+                                            .map(|l| {
+                                                let from = by_id
+                                                    .get(l.from.as_str())
+                                                    .map(|e| e.name.clone())
+                                                    .unwrap_or_else(|| l.from.clone());
+                                                (from, l.clone())
+                                            })
+                                            .collect::<Vec<_>>(),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One narrative event in the branch graph
+#[component]
+fn BranchNode(
+    event: NarrativeEventData,
+    /// `Some` once the event has fired, holding the outcome it resolved to
+    fired_outcome: Option<Option<String>>,
+    /// Links leading here, with the source event's name
+    incoming: Vec<(String, BranchLink)>,
+) -> Element {
+    let is_fired = fired_outcome.is_some();
+    let chosen = fired_outcome.clone().flatten();
+    let (border, dot) = if is_fired {
+        ("border-green-500", "bg-green-500")
+    } else if event.is_active {
+        ("border-blue-500", "bg-blue-500")
+    } else {
+        ("border-gray-700", "bg-gray-600")
+    };
+
+    rsx! {
+        div {
+            class: "p-2 bg-dark-bg border {border} rounded-lg flex flex-col gap-1",
+
+            div {
+                class: "flex items-center gap-2",
+                span { class: "w-2 h-2 rounded-full flex-shrink-0 {dot}" }
+                span { class: "text-white text-sm", "{event.name}" }
+            }
+
+            for (from, link) in incoming.iter() {
+                div {
+                    key: "{link.from}-{link.label}",
+                    class: if link.taken {
+                        "text-[0.625rem] text-green-400 font-semibold"
+                    } else {
+                        "text-[0.625rem] text-gray-500"
+                    },
+                    "{link.kind.icon()} from {from}"
+                    if !link.label.is_empty() {
+                        span { class: "italic", " · {link.label}" }
+                    }
+                }
+            }
+
+            if !event.outcomes.is_empty() {
+                div {
+                    class: "flex flex-wrap gap-1 mt-1",
+                    for outcome in event.outcomes.iter() {
+                        span {
+                            key: "{outcome.name}",
+                            class: if chosen.as_deref() == Some(outcome.name.as_str()) {
+                                "px-1.5 py-0.5 rounded text-[0.625rem] bg-green-500/20 text-green-400"
+                            } else {
+                                "px-1.5 py-0.5 rounded text-[0.625rem] bg-gray-700/50 text-gray-400"
+                            },
+                            "{outcome.label}"
+                        }
+                    }
+                }
+            } else if let Some(outcome) = chosen.as_ref() {
+                div { class: "text-[0.625rem] text-green-400", "Outcome: {outcome}" }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::world_snapshot::{NarrativeOutcomeData, NarrativeTriggerData};
+
+    fn event(id: &str) -> NarrativeEventData {
+        NarrativeEventData {
+            id: id.to_string(),
+            world_id: "w".to_string(),
+            name: id.to_uppercase(),
+            description: String::new(),
+            scene_direction: String::new(),
+            suggested_opening: None,
+            trigger_count: 0,
+            is_active: true,
+            is_triggered: false,
+            triggered_at: None,
+            selected_outcome: None,
+            is_repeatable: false,
+            delay_turns: 0,
+            expires_after_turns: None,
+            priority: 0,
+            is_favorite: false,
+            tags: vec![],
+            scene_id: None,
+            location_id: None,
+            act_id: None,
+            chain_id: None,
+            chain_position: None,
+            outcome_count: 0,
+            trigger_condition_count: 0,
+            trigger_conditions: vec![],
+            outcomes: vec![],
+            audio_cue: None,
+            camera_effect: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+            deleted_at: None,
+            archived_at: None,
+        }
+    }
+
+    fn outcome(name: &str, next: &[&str]) -> NarrativeOutcomeData {
+        NarrativeOutcomeData {
+            name: name.to_string(),
+            label: name.to_string(),
+            description: String::new(),
+            chain_events: next.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// "a" branches to "b" (spare) or "c" (kill); "d" triggers after "c"
+    fn branching_story() -> Vec<NarrativeEventData> {
+        let mut a = event("a");
+        a.outcomes = vec![outcome("spare", &["b"]), outcome("kill", &["c"])];
+        let mut d = event("d");
+        d.trigger_conditions = vec![NarrativeTriggerData {
+            trigger_type: "event_completed".to_string(),
+            description: "After the duel".to_string(),
+            event_id: Some("c".to_string()),
+            outcome_name: None,
+            is_required: true,
+        }];
+        vec![a, event("b"), event("c"), d]
+    }
+
+    #[test]
+    fn test_taken_branches_follow_chosen_outcome() {
+        let events = branching_story();
+        let fired: HashMap<String, Option<String>> = [
+            ("a".to_string(), Some("kill".to_string())),
+            ("c".to_string(), None),
+        ]
+        .into_iter()
+        .collect();
+
+        let links = branch_links(&events, &[], &fired);
+        let taken: Vec<(&str, &str)> = links
+            .iter()
+            .filter(|l| l.taken)
+            .map(|l| (l.from.as_str(), l.to.as_str()))
+            .collect();
+        assert_eq!(links.len(), 3);
+        assert_eq!(taken, vec![("a", "c")]);
+    }
+
+    #[test]
+    fn test_layers_merge_outcomes_and_triggers() {
+        let events = branching_story();
+        let links = branch_links(&events, &[], &HashMap::new());
+        assert_eq!(
+            layer_branches(&events, &links),
+            vec![vec!["a".to_string()], vec!["b".to_string(), "c".to_string()], vec!["d".to_string()]]
+        );
+    }
+
+    #[test]
+    fn test_cycles_get_their_own_layer() {
+        let mut a = event("a");
+        a.outcomes = vec![outcome("loop", &["b"])];
+        let mut b = event("b");
+        b.outcomes = vec![outcome("back", &["a"])];
+        let events = vec![a, b, event("c")];
+        let links = branch_links(&events, &[], &HashMap::new());
+        let layers = layer_branches(&events, &links);
+        assert_eq!(layers, vec![vec!["c".to_string()], vec!["a".to_string(), "b".to_string()]]);
+    }
+}
@@ -2,7 +2,9 @@
 
 use dioxus::prelude::*;
 
-use crate::application::dto::NarrativeEventData;
+use crate::application::dto::{EntityMention, NarrativeEventData};
+use crate::application::services::Archivable;
+use crate::presentation::components::common::RichTextView;
 
 #[derive(Props, Clone)]
 pub struct NarrativeEventCardProps {
@@ -10,6 +12,15 @@ pub struct NarrativeEventCardProps {
     pub on_click: EventHandler<()>,
     pub on_toggle_favorite: EventHandler<()>,
     pub on_toggle_active: EventHandler<()>,
+    /// Called when a mention in the description is clicked
+    #[props(default)]
+    pub on_mention: Option<EventHandler<EntityMention>>,
+    /// Called when the event should be archived or unarchived
+    #[props(default)]
+    pub on_toggle_archived: Option<EventHandler<()>>,
+    /// Called when the event should be moved to the trash
+    #[props(default)]
+    pub on_delete: Option<EventHandler<()>>,
 }
 
 impl PartialEq for NarrativeEventCardProps {
@@ -18,6 +29,7 @@ impl PartialEq for NarrativeEventCardProps {
             && self.event.is_favorite == other.event.is_favorite
             && self.event.is_active == other.event.is_active
             && self.event.is_triggered == other.event.is_triggered
+            && self.event.archived_at == other.event.archived_at
     }
 }
 
@@ -25,8 +37,12 @@ impl PartialEq for NarrativeEventCardProps {
 pub fn NarrativeEventCard(props: NarrativeEventCardProps) -> Element {
     let event = &props.event;
 
+    let is_archived = event.is_archived();
+
     // Determine status color and label
-    let (status_label, status_color_class) = if event.is_triggered {
+    let (status_label, status_color_class) = if is_archived {
+        ("Archived", "text-gray-500")
+    } else if event.is_triggered {
         ("Triggered", "text-green-500")
     } else if event.is_active {
         ("Active", "text-blue-500")
@@ -112,14 +128,41 @@ pub fn NarrativeEventCard(props: NarrativeEventCardProps) -> Element {
                         title: if event.is_active { "Deactivate" } else { "Activate" },
                         if event.is_active { "●" } else { "○" }
                     }
+
+                    // Archive toggle
+                    if let Some(on_toggle_archived) = props.on_toggle_archived {
+                        button {
+                            onclick: move |e| {
+                                e.stop_propagation();
+                                on_toggle_archived.call(());
+                            },
+                            class: "bg-transparent border-none cursor-pointer p-1 text-sm text-gray-500 hover:text-white",
+                            title: if is_archived { "Unarchive" } else { "Archive" },
+                            if is_archived { "📤" } else { "🗄" }
+                        }
+                    }
+
+                    // Move to trash
+                    if let Some(on_delete) = props.on_delete {
+                        button {
+                            onclick: move |e| {
+                                e.stop_propagation();
+                                on_delete.call(());
+                            },
+                            class: "bg-transparent border-none cursor-pointer p-1 text-sm text-gray-500 hover:text-red-500",
+                            title: "Move to trash",
+                            "🗑"
+                        }
+                    }
                 }
             }
 
             // Description
             if !event.description.is_empty() {
-                p {
-                    class: "text-gray-400 text-[0.8125rem] m-0 mb-3 overflow-hidden text-ellipsis line-clamp-2",
-                    "{event.description}"
+                RichTextView {
+                    text: event.description.clone(),
+                    on_mention: props.on_mention,
+                    class: "text-gray-400 text-[0.8125rem] mb-3 max-h-[3.25rem] overflow-hidden",
                 }
             }
 
@@ -1,14 +1,26 @@
 //! Narrative Event Library - Browse and manage future narrative events
 
 use dioxus::prelude::*;
-
-use crate::application::dto::{CreateNarrativeEventRequest, NarrativeEventData};
+use serde::{Deserialize, Serialize};
+
+use crate::application::dto::{
+    description_plain_text, AudioCue, CameraEffect, CreateNarrativeEventRequest, NarrativeEventData,
+};
+use crate::application::services::{Archivable, FieldRule, FormRules, FormValues};
+use crate::presentation::components::common::{
+    collect_tags, mention_route, AudioCuePicker, CameraEffectPicker, DraftRestoreBanner, FieldError, RichTextEditor, TagFilterBar,
+    TagInput,
+};
 use crate::presentation::components::story_arc::narrative_event_card::NarrativeEventCard;
 use crate::presentation::services::use_narrative_event_service;
+use crate::presentation::state::{draft_key, use_draft_persistence, use_form_validation, use_tag_filter_state, use_modal_focus};
 
 #[derive(Props, Clone, PartialEq)]
 pub struct NarrativeEventLibraryProps {
     pub world_id: String,
+    /// Event to open once the list has loaded (deep link)
+    #[props(default)]
+    pub focus_event_id: Option<String>,
 }
 
 #[component]
@@ -21,6 +33,8 @@ pub fn NarrativeEventLibrary(props: NarrativeEventLibraryProps) -> Element {
     let mut show_favorites_only = use_signal(|| false);
     let mut selected_event: Signal<Option<NarrativeEventData>> = use_signal(|| None);
     let mut show_create_form = use_signal(|| false);
+    let tag_filter = use_tag_filter_state();
+    let navigator = use_navigator();
 
     // Get narrative event service
     let narrative_event_service = use_narrative_event_service();
@@ -28,15 +42,22 @@ pub fn NarrativeEventLibrary(props: NarrativeEventLibraryProps) -> Element {
 
     // Load events
     let world_id = props.world_id.clone();
+    let focus_event_id = props.focus_event_id.clone();
     use_effect(move || {
         let world_id = world_id.clone();
+        let focus_event_id = focus_event_id.clone();
         let service = narrative_event_service_for_effect.clone();
         spawn(async move {
             is_loading.set(true);
             error.set(None);
 
-            match service.list_narrative_events(&world_id).await {
-                Ok(loaded) => events.set(loaded),
+            match service.list_all_narrative_events(&world_id).await {
+                Ok(loaded) => {
+                    if let Some(focused) = focus_event_id.and_then(|id| loaded.iter().find(|e| e.id == id).cloned()) {
+                        selected_event.set(Some(focused));
+                    }
+                    events.set(loaded);
+                }
                 Err(e) => error.set(Some(format!("Failed to load narrative events: {}", e))),
             }
             is_loading.set(false);
@@ -51,11 +72,21 @@ pub fn NarrativeEventLibrary(props: NarrativeEventLibraryProps) -> Element {
         let all_events = events.read().clone();
 
         all_events.into_iter().filter(|event| {
+            // Filter by global tag filter
+            if !tag_filter.matches(&event.tags) {
+                return false;
+            }
+
             // Filter by favorites
             if favorites_only && !event.is_favorite {
                 return false;
             }
 
+            // Archived events only show under their own filter
+            if event.is_archived() != (status == "archived") {
+                return false;
+            }
+
             // Filter by status
             match status.as_str() {
                 "active" => {
@@ -79,7 +110,7 @@ pub fn NarrativeEventLibrary(props: NarrativeEventLibraryProps) -> Element {
             // Filter by search
             if !search.is_empty() {
                 let matches_name = event.name.to_lowercase().contains(&search);
-                let matches_desc = event.description.to_lowercase().contains(&search);
+                let matches_desc = description_plain_text(&event.description).to_lowercase().contains(&search);
                 let matches_tags = event.tags.iter().any(|t| t.to_lowercase().contains(&search));
                 if !matches_name && !matches_desc && !matches_tags {
                     return false;
@@ -131,6 +162,7 @@ pub fn NarrativeEventLibrary(props: NarrativeEventLibraryProps) -> Element {
                     option { value: "active", "Active Only" }
                     option { value: "pending", "Pending" }
                     option { value: "triggered", "Triggered" }
+                    option { value: "archived", "Archived" }
                 }
 
                 // Favorites toggle
@@ -149,6 +181,14 @@ pub fn NarrativeEventLibrary(props: NarrativeEventLibraryProps) -> Element {
                         }
                     }
                 }
+
+                // Tag filter
+                div {
+                    class: "w-full",
+                    TagFilterBar {
+                        available_tags: collect_tags(events.read().iter().map(|e| &e.tags)),
+                    }
+                }
             }
 
             // Stats bar
@@ -159,6 +199,7 @@ pub fn NarrativeEventLibrary(props: NarrativeEventLibraryProps) -> Element {
                 span { "{events.read().iter().filter(|e| e.is_active).count()} active" }
                 span { "{events.read().iter().filter(|e| e.is_triggered).count()} triggered" }
                 span { "{events.read().iter().filter(|e| e.is_favorite).count()} favorites" }
+                span { "{events.read().iter().filter(|e| e.is_archived()).count()} archived" }
             }
 
             // Event list
@@ -196,6 +237,12 @@ pub fn NarrativeEventLibrary(props: NarrativeEventLibraryProps) -> Element {
                             NarrativeEventCard {
                                 key: "{event.id}",
                                 event: event.clone(),
+                                on_mention: {
+                                    let world_id = props.world_id.clone();
+                                    move |mention| {
+                                        navigator.push(mention_route(&world_id, &mention));
+                                    }
+                                },
                                 on_click: {
                                     let event = event.clone();
                                     move |_| selected_event.set(Some(event.clone()))
@@ -212,7 +259,7 @@ pub fn NarrativeEventLibrary(props: NarrativeEventLibraryProps) -> Element {
                                             if let Err(e) = service.toggle_favorite(&event_id).await {
                                                 tracing::error!("Failed to toggle favorite: {}", e);
                                             }
-                                            if let Ok(reloaded) = service.list_narrative_events(&world_id).await {
+                                            if let Ok(reloaded) = service.list_all_narrative_events(&world_id).await {
                                                 events.set(reloaded);
                                             }
                                         });
@@ -231,12 +278,45 @@ pub fn NarrativeEventLibrary(props: NarrativeEventLibraryProps) -> Element {
                                             if let Err(e) = service.set_active(&event_id, !is_active).await {
                                                 tracing::error!("Failed to toggle active: {}", e);
                                             }
-                                            if let Ok(reloaded) = service.list_narrative_events(&world_id).await {
+                                            if let Ok(reloaded) = service.list_all_narrative_events(&world_id).await {
                                                 events.set(reloaded);
                                             }
                                         });
                                     }
                                 },
+                                on_toggle_archived: {
+                                    let event_id = event.id.clone();
+                                    let is_archived = event.is_archived();
+                                    let world_id = props.world_id.clone();
+                                    let service = narrative_event_service.clone();
+                                    move |_| {
+                                        let event_id = event_id.clone();
+                                        let world_id = world_id.clone();
+                                        let service = service.clone();
+                                        spawn(async move {
+                                            if let Err(e) = service.set_archived(&event_id, !is_archived).await {
+                                                tracing::error!("Failed to toggle archived: {}", e);
+                                            }
+                                            if let Ok(reloaded) = service.list_all_narrative_events(&world_id).await {
+                                                events.set(reloaded);
+                                            }
+                                        });
+                                    }
+                                },
+                                on_delete: {
+                                    let event_id = event.id.clone();
+                                    let service = narrative_event_service.clone();
+                                    move |_| {
+                                        let event_id = event_id.clone();
+                                        let service = service.clone();
+                                        spawn(async move {
+                                            match service.delete_narrative_event(&event_id).await {
+                                                Ok(()) => events.write().retain(|e| e.id != event_id),
+                                                Err(e) => tracing::error!("Failed to move event to trash: {}", e),
+                                            }
+                                        });
+                                    }
+                                },
                             }
                         }
                     }
@@ -269,17 +349,63 @@ struct NarrativeEventFormModalProps {
     on_close: EventHandler<()>,
 }
 
+/// Autosaved contents of the new narrative event form
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct NarrativeEventDraft {
+    name: String,
+    description: String,
+    scene_direction: String,
+    tags: Vec<String>,
+    #[serde(default)]
+    audio_cue: Option<AudioCue>,
+    #[serde(default)]
+    camera_effect: Option<CameraEffect>,
+}
+
+/// Field rules for the new narrative event form
+fn narrative_event_rules() -> FormRules {
+    FormRules::new()
+        .field("name", "Name", vec![FieldRule::Required, FieldRule::Length { min: None, max: Some(200) }])
+        .field("scene_direction", "Scene direction", vec![FieldRule::Length { min: None, max: Some(2000) }])
+}
+
 #[component]
 fn NarrativeEventFormModal(props: NarrativeEventFormModalProps) -> Element {
+    use_modal_focus("narrative-event-form");
     let narrative_event_service = use_narrative_event_service();
 
     let mut name = use_signal(|| String::new());
     let mut description = use_signal(|| String::new());
     let mut scene_direction = use_signal(|| String::new());
+    let mut tags: Signal<Vec<String>> = use_signal(Vec::new);
+    let mut audio_cue: Signal<Option<AudioCue>> = use_signal(|| None);
+    let mut camera_effect: Signal<Option<CameraEffect>> = use_signal(|| None);
     let mut is_saving = use_signal(|| false);
     let mut save_error: Signal<Option<String>> = use_signal(|| None);
+    let mut validation = use_form_validation();
+
+    let mut drafts = use_draft_persistence(
+        draft_key("narrative_event", &props.world_id, None),
+        move || {
+            Some(NarrativeEventDraft {
+                name: name.peek().clone(),
+                description: description.peek().clone(),
+                scene_direction: scene_direction.peek().clone(),
+                tags: tags.peek().clone(),
+                audio_cue: audio_cue.peek().clone(),
+                camera_effect: camera_effect.peek().clone(),
+            })
+        },
+    );
+
+    let errors = narrative_event_rules().validate(
+        &FormValues::new()
+            .with("name", name.read().as_str())
+            .with("scene_direction", scene_direction.read().as_str()),
+    );
 
     let save_event = {
+        let errors = errors.clone();
         let world_id = props.world_id.clone();
         let service = narrative_event_service.clone();
         let on_save = props.on_save.clone();
@@ -290,9 +416,12 @@ fn NarrativeEventFormModal(props: NarrativeEventFormModalProps) -> Element {
             let name_val = name.read().clone();
             let desc_val = description.read().clone();
             let direction_val = scene_direction.read().clone();
+            let tags_val = tags.read().clone();
+            // A cue without a sound file is treated as no cue
+            let cue_val = audio_cue.read().clone().filter(|c| !c.asset.trim().is_empty());
+            let effect_val = camera_effect.read().clone();
 
-            if name_val.trim().is_empty() {
-                save_error.set(Some("Name is required".to_string()));
+            if !validation.gate(&errors) {
                 return;
             }
 
@@ -304,11 +433,15 @@ fn NarrativeEventFormModal(props: NarrativeEventFormModalProps) -> Element {
                     name: name_val,
                     description: desc_val,
                     scene_direction: direction_val,
+                    tags: tags_val,
+                    audio_cue: cue_val,
+                    camera_effect: effect_val,
                     ..Default::default()
                 };
 
                 match service.create_narrative_event(&world_id, request).await {
                     Ok(new_event) => {
+                        drafts.clear();
                         on_save.call(new_event);
                     }
                     Err(e) => {
@@ -326,6 +459,9 @@ fn NarrativeEventFormModal(props: NarrativeEventFormModalProps) -> Element {
             onclick: move |_| props.on_close.call(()),
 
             div {
+                id: "narrative-event-form",
+                role: "dialog",
+                aria_modal: "true",
                 class: "bg-dark-surface rounded-xl max-w-[500px] w-[90%] max-h-[90vh] overflow-y-auto",
                 onclick: move |e| e.stop_propagation(),
 
@@ -334,6 +470,7 @@ fn NarrativeEventFormModal(props: NarrativeEventFormModalProps) -> Element {
                     class: "flex justify-between items-center px-6 py-4 border-b border-gray-700",
                     h3 { class: "text-white m-0", "New Narrative Event" }
                     button {
+                        aria_label: "Close",
                         onclick: move |_| props.on_close.call(()),
                         class: "bg-transparent border-none text-gray-400 text-2xl cursor-pointer",
                         "×"
@@ -344,6 +481,23 @@ fn NarrativeEventFormModal(props: NarrativeEventFormModalProps) -> Element {
                 div {
                     class: "p-6 flex flex-col gap-4",
 
+                    if let Some(draft) = drafts.pending.read().as_ref() {
+                        DraftRestoreBanner {
+                            saved_at: draft.saved_at,
+                            on_restore: move |_| {
+                                if let Some(draft) = drafts.restore() {
+                                    name.set(draft.name);
+                                    description.set(draft.description);
+                                    scene_direction.set(draft.scene_direction);
+                                    tags.set(draft.tags);
+                                    audio_cue.set(draft.audio_cue);
+                                    camera_effect.set(draft.camera_effect);
+                                }
+                            },
+                            on_discard: move |_| drafts.discard(),
+                        }
+                    }
+
                     // Name field
                     div {
                         label {
@@ -354,9 +508,13 @@ fn NarrativeEventFormModal(props: NarrativeEventFormModalProps) -> Element {
                             r#type: "text",
                             placeholder: "Enter event name...",
                             value: "{name}",
-                            oninput: move |e| name.set(e.value()),
+                            oninput: move |e| {
+                                name.set(e.value());
+                                validation.touch("name");
+                            },
                             class: "w-full px-3 py-3 bg-dark-bg border border-gray-700 rounded-lg text-white box-border",
                         }
+                        FieldError { message: validation.error(&errors, "name") }
                     }
 
                     // Description field
@@ -365,11 +523,11 @@ fn NarrativeEventFormModal(props: NarrativeEventFormModalProps) -> Element {
                             class: "block text-gray-400 text-sm mb-1",
                             "Description"
                         }
-                        textarea {
+                        RichTextEditor {
+                            value: description.read().clone(),
+                            on_change: move |value| description.set(value),
+                            world_id: props.world_id.clone(),
                             placeholder: "What happens when this event triggers?",
-                            value: "{description}",
-                            oninput: move |e| description.set(e.value()),
-                            class: "w-full min-h-[80px] px-3 py-3 bg-dark-bg border border-gray-700 rounded-lg text-white resize-y box-border",
                         }
                     }
 
@@ -382,9 +540,49 @@ fn NarrativeEventFormModal(props: NarrativeEventFormModalProps) -> Element {
                         textarea {
                             placeholder: "How should the DM/AI present this event?",
                             value: "{scene_direction}",
-                            oninput: move |e| scene_direction.set(e.value()),
+                            oninput: move |e| {
+                                scene_direction.set(e.value());
+                                validation.touch("scene_direction");
+                            },
                             class: "w-full min-h-[60px] px-3 py-3 bg-dark-bg border border-gray-700 rounded-lg text-white resize-y box-border",
                         }
+                        FieldError { message: validation.error(&errors, "scene_direction") }
+                    }
+
+                    // Tags field
+                    div {
+                        label {
+                            class: "block text-gray-400 text-sm mb-1",
+                            "Tags"
+                        }
+                        TagInput {
+                            tags: tags.read().clone(),
+                            on_change: move |next| tags.set(next),
+                        }
+                    }
+
+                    // Audio cue
+                    div {
+                        label {
+                            class: "block text-gray-400 text-sm mb-1",
+                            "Audio Cue"
+                        }
+                        AudioCuePicker {
+                            cue: audio_cue.read().clone(),
+                            on_change: move |next| audio_cue.set(next),
+                        }
+                    }
+
+                    // Camera effect
+                    div {
+                        label {
+                            class: "block text-gray-400 text-sm mb-1",
+                            "Camera Effect"
+                        }
+                        CameraEffectPicker {
+                            effect: camera_effect.read().clone(),
+                            on_change: move |next| camera_effect.set(next),
+                        }
                     }
 
                     // Error message
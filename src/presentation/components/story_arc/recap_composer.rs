@@ -0,0 +1,168 @@
+//! Recap Composer - Turn timeline events into a "previously on…" recap
+//!
+//! The DM ticks events on the timeline; each becomes a card narrated with
+//! the event's summary until the DM rewords it. The recap can be previewed
+//! here and then played on every player's stage.
+
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{RecapCard, SessionRecap, StoryEventData, DEFAULT_RECAP_TITLE};
+use crate::application::ports::outbound::Platform;
+use crate::application::services::SessionCommandService;
+use crate::presentation::components::pc::recap_overlay::RecapOverlay;
+use crate::presentation::state::{use_game_state, use_session_state, use_template_context};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RecapComposerProps {
+    /// Picked events, in the order they will play
+    pub events: Vec<StoryEventData>,
+    pub on_remove: EventHandler<String>,
+    pub on_close: EventHandler<()>,
+}
+
+#[component]
+pub fn RecapComposer(props: RecapComposerProps) -> Element {
+    let game_state = use_game_state();
+    let session_state = use_session_state();
+    let platform = use_context::<Platform>();
+    let templates = use_template_context();
+    let mut title = use_signal(|| DEFAULT_RECAP_TITLE.to_string());
+    let mut narration: Signal<HashMap<String, String>> = use_signal(HashMap::new);
+    let mut preview: Signal<Option<SessionRecap>> = use_signal(|| None);
+    let mut status: Signal<Option<String>> = use_signal(|| None);
+
+    let cards: Vec<RecapCard> = {
+        let world = game_state.world.read();
+        let narration = narration.read();
+        props
+            .events
+            .iter()
+            .map(|event| {
+                let text = narration
+                    .get(&event.id)
+                    .cloned()
+                    .unwrap_or_else(|| templates.resolve(&event.summary));
+                RecapCard::from_event(event, text, world.as_deref())
+            })
+            .collect()
+    };
+    let recap = SessionRecap {
+        id: platform.now_millis().to_string(),
+        title: title.read().trim().to_string(),
+        cards: cards.clone(),
+    };
+    let has_client = session_state.has_client();
+    let engine_client = session_state.engine_client();
+
+    rsx! {
+        div {
+            class: "recap-composer bg-dark-surface rounded-lg p-4 flex flex-col gap-3 border border-amber-500/30",
+
+            div {
+                class: "flex justify-between items-center",
+                h3 { class: "text-white m-0 text-base", "Compose Recap" }
+                button {
+                    aria_label: "Close recap composer",
+                    onclick: move |_| props.on_close.call(()),
+                    class: "bg-transparent border-none text-gray-400 text-xl cursor-pointer",
+                    "×"
+                }
+            }
+
+            input {
+                r#type: "text",
+                aria_label: "Recap title",
+                value: "{title}",
+                oninput: move |e| title.set(e.value()),
+                class: "px-3 py-2 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+            }
+
+            if cards.is_empty() {
+                p { class: "text-gray-500 text-sm m-0", "Tick events on the timeline to add them to the recap." }
+            }
+
+            ol {
+                class: "list-none m-0 p-0 flex flex-col gap-2",
+                for (i, card) in cards.iter().enumerate() {
+                    li {
+                        key: "{card.event_id}",
+                        class: "flex items-start gap-2",
+                        span { class: "text-gray-500 text-xs pt-2 w-5 text-right", "{i + 1}." }
+                        div {
+                            class: "flex-1 flex flex-col gap-1",
+                            textarea {
+                                aria_label: "Narration for card {i + 1}",
+                                rows: "2",
+                                value: "{card.text}",
+                                oninput: {
+                                    let event_id = card.event_id.clone();
+                                    move |e: FormEvent| {
+                                        narration.write().insert(event_id.clone(), e.value());
+                                    }
+                                },
+                                class: "px-2 py-1 bg-dark-bg border border-gray-700 rounded text-white text-sm resize-y",
+                            }
+                            if let Some(caption) = card.caption.as_ref() {
+                                span { class: "text-gray-500 text-xs", "{caption}" }
+                            }
+                        }
+                        button {
+                            aria_label: "Remove card {i + 1}",
+                            onclick: {
+                                let event_id = card.event_id.clone();
+                                move |_| {
+                                    narration.write().remove(&event_id);
+                                    props.on_remove.call(event_id.clone());
+                                }
+                            },
+                            class: "bg-transparent border-none text-gray-400 cursor-pointer pt-1",
+                            "×"
+                        }
+                    }
+                }
+            }
+
+            if let Some(msg) = status.read().as_ref() {
+                p { class: "text-gray-400 text-xs m-0", role: "status", "{msg}" }
+            }
+
+            div {
+                class: "flex justify-end gap-2",
+                button {
+                    disabled: cards.is_empty(),
+                    onclick: {
+                        let recap = recap.clone();
+                        move |_| preview.set(Some(recap.clone()))
+                    },
+                    class: "px-4 py-2 bg-transparent text-gray-300 border border-gray-600 rounded cursor-pointer text-sm disabled:opacity-40",
+                    "Preview"
+                }
+                button {
+                    disabled: cards.is_empty() || !has_client,
+                    title: if has_client { "" } else { "Join a session to play the recap" },
+                    onclick: move |_| {
+                        let Some(client) = engine_client.read().as_ref().map(std::sync::Arc::clone) else {
+                            return;
+                        };
+                        match SessionCommandService::new(client).play_recap(recap.clone()) {
+                            Ok(()) => status.set(Some(format!("Playing \"{}\" for the players", recap.title))),
+                            Err(e) => status.set(Some(format!("Failed to play the recap: {}", e))),
+                        }
+                    },
+                    class: "px-4 py-2 bg-amber-500 text-white border-none rounded cursor-pointer text-sm disabled:opacity-40",
+                    "Play for players"
+                }
+            }
+
+            if let Some(recap) = preview.read().clone() {
+                RecapOverlay {
+                    key: "{recap.id}",
+                    recap,
+                    on_close: move |_| preview.set(None),
+                }
+            }
+        }
+    }
+}
@@ -2,19 +2,31 @@
 
 use dioxus::prelude::*;
 
-use crate::application::dto::{StoryEventData, StoryEventTypeData};
+use crate::application::dto::{EntityStyle, StoryEventData, StoryEventSourceData, StoryEventTypeData};
+use crate::presentation::components::common::EntityMark;
 use crate::presentation::components::story_arc::timeline_view::get_event_type_icon;
+use crate::presentation::state::use_template_context;
+
+/// A character or location the event involves, with its icon and colour
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvolvedMark {
+    pub name: String,
+    pub style: EntityStyle,
+}
 
 #[derive(Props, Clone)]
 pub struct TimelineEventCardProps {
     pub event: StoryEventData,
+    /// Styled entities the event involves
+    #[props(default)]
+    pub involved: Vec<InvolvedMark>,
     pub on_click: EventHandler<()>,
     pub on_toggle_visibility: EventHandler<()>,
 }
 
 impl PartialEq for TimelineEventCardProps {
     fn eq(&self, other: &Self) -> bool {
-        self.event.id == other.event.id
+        self.event.id == other.event.id && self.involved == other.involved
     }
 }
 
@@ -28,6 +40,12 @@ pub fn TimelineEventCard(props: TimelineEventCardProps) -> Element {
     let formatted_time = format_timestamp(&event.timestamp);
 
     let opacity_class = if event.is_hidden { "opacity-50" } else { "opacity-100" };
+    let summary = use_template_context().resolve(&event.summary);
+    // Only the kind of source is shown, so names needn't resolve
+    let source_label = event.source_reference(|_| None).map(|source| match source {
+        StoryEventSourceData::Conversation { .. } => "🔗 Conversation",
+        StoryEventSourceData::Challenge { .. } => "🔗 Roll",
+    });
 
     rsx! {
         div {
@@ -53,7 +71,7 @@ pub fn TimelineEventCard(props: TimelineEventCardProps) -> Element {
                     // Summary
                     p {
                         class: "text-white m-0 mb-1 text-[0.9375rem] leading-normal",
-                        "{event.summary}"
+                        "{summary}"
                     }
 
                     // Metadata row
@@ -74,6 +92,24 @@ pub fn TimelineEventCard(props: TimelineEventCardProps) -> Element {
                             }
                         }
 
+                        // Who and where, for scanning by entity
+                        for mark in props.involved.iter() {
+                            span {
+                                class: "inline-flex items-center gap-1 text-gray-400 text-xs",
+                                title: "{mark.name}",
+                                EntityMark { style: mark.style.clone() }
+                                "{mark.name}"
+                            }
+                        }
+
+                        // Opens the source in a side panel
+                        if let Some(label) = source_label {
+                            span {
+                                class: "text-blue-400 text-xs",
+                                "{label}"
+                            }
+                        }
+
                         // Tags
                         for tag in event.tags.iter().take(3) {
                             span {
@@ -209,6 +245,8 @@ fn get_event_type_color(event_type: &StoryEventTypeData) -> &'static str {
         StoryEventTypeData::NarrativeEventTriggered { .. } => "#14b8a6",
         StoryEventTypeData::SessionStarted { .. } => "#22c55e",
         StoryEventTypeData::SessionEnded { .. } => "#6b7280",
+        StoryEventTypeData::SessionPaused { .. } => "#f59e0b",
+        StoryEventTypeData::SessionResumed { .. } => "#22c55e",
         StoryEventTypeData::Custom { .. } => "#9ca3af",
     }
 }
@@ -2,12 +2,19 @@
 
 use dioxus::prelude::*;
 
-use crate::application::dto::{StoryEventData, StoryEventTypeData};
+use crate::application::dto::{SessionWorldSnapshot, StoryEventData, StoryEventTypeData};
+use crate::application::services::{story_event_text, FilterPreset, FilterPresetScope};
+use crate::presentation::components::common::{
+    collect_tags, CopyButton, FilterPresetBar, ShareButton, TagFilterBar,
+};
 use crate::presentation::components::story_arc::add_dm_marker::AddDmMarkerModal;
-use crate::presentation::components::story_arc::timeline_event_card::TimelineEventCard;
+use crate::presentation::components::story_arc::event_source_panel::EventSourcePanel;
+use crate::presentation::components::story_arc::recap_composer::RecapComposer;
+use crate::presentation::components::story_arc::timeline_event_card::{InvolvedMark, TimelineEventCard};
 use crate::presentation::components::story_arc::timeline_filters::{CharacterOption, LocationOption, TimelineFilters};
 use crate::presentation::services::use_story_event_service;
-use crate::presentation::state::use_game_state;
+use crate::presentation::state::{use_game_state, use_tag_filter_state, use_template_context, use_modal_focus};
+use crate::routes::Route;
 
 /// Filter options for the timeline
 #[derive(Debug, Clone, Default)]
@@ -91,11 +98,15 @@ pub struct TimelineViewProps {
     pub world_id: String,
     #[props(default)]
     pub session_id: Option<String>,
+    /// Event to open once the timeline loads (from a shared link)
+    #[props(default)]
+    pub focus_event_id: Option<String>,
 }
 
 #[component]
 pub fn TimelineView(props: TimelineViewProps) -> Element {
     let game_state = use_game_state();
+    let tag_filter = use_tag_filter_state();
 
     let mut events: Signal<Vec<StoryEventData>> = use_signal(Vec::new);
     let mut is_loading = use_signal(|| true);
@@ -103,6 +114,9 @@ pub fn TimelineView(props: TimelineViewProps) -> Element {
     let mut filters = use_signal(TimelineFilterState::default);
     let mut show_add_marker = use_signal(|| false);
     let mut selected_event: Signal<Option<StoryEventData>> = use_signal(|| None);
+    let source_event: Signal<Option<StoryEventData>> = use_signal(|| None);
+    let mut composing_recap = use_signal(|| false);
+    let mut recap_selection: Signal<Vec<String>> = use_signal(Vec::new);
 
     // Get story event service
     let story_event_service = use_story_event_service();
@@ -110,8 +124,10 @@ pub fn TimelineView(props: TimelineViewProps) -> Element {
 
     // Load events when component mounts or world changes
     let world_id = props.world_id.clone();
+    let focus_event_id = props.focus_event_id.clone();
     use_effect(move || {
         let world_id = world_id.clone();
+        let focus_event_id = focus_event_id.clone();
         let service = story_event_service_for_effect.clone();
         spawn(async move {
             is_loading.set(true);
@@ -119,6 +135,11 @@ pub fn TimelineView(props: TimelineViewProps) -> Element {
 
             match service.list_story_events(&world_id, None).await {
                 Ok(loaded_events) => {
+                    if let Some(focus) = focus_event_id.as_ref() {
+                        if let Some(event) = loaded_events.iter().find(|e| &e.id == focus) {
+                            selected_event.set(Some(event.clone()));
+                        }
+                    }
                     events.set(loaded_events);
                 }
                 Err(e) => {
@@ -135,6 +156,19 @@ pub fn TimelineView(props: TimelineViewProps) -> Element {
         let all_events = events.read().clone();
         let vm = TimelineViewModel::new(&all_events, &filter_state);
         vm.filtered_events()
+            .into_iter()
+            .filter(|event| tag_filter.matches(&event.tags))
+            .collect::<Vec<_>>()
+    };
+
+    let current_filters = {
+        let filter_state = filters.read();
+        FilterPreset {
+            type_filter: filter_state.event_type.clone(),
+            tags: tag_filter.active_tags.read().clone(),
+            search: filter_state.search_text.clone(),
+            ..Default::default()
+        }
     };
 
     rsx! {
@@ -147,11 +181,41 @@ pub fn TimelineView(props: TimelineViewProps) -> Element {
 
                 h2 { class: "text-white m-0 text-xl", "Timeline" }
 
-                button {
-                    onclick: move |_| show_add_marker.set(true),
-                    class: "px-4 py-2 bg-purple-500 text-white border-none rounded-lg cursor-pointer flex items-center gap-2",
-                    span { "+" }
-                    span { "Add DM Marker" }
+                div {
+                    class: "flex items-center gap-2",
+                    button {
+                        aria_pressed: *composing_recap.read(),
+                        onclick: move |_| composing_recap.toggle(),
+                        class: "px-4 py-2 bg-transparent text-amber-400 border border-amber-500/50 rounded-lg cursor-pointer",
+                        "🎬 Recap"
+                    }
+                    button {
+                        onclick: move |_| show_add_marker.set(true),
+                        class: "px-4 py-2 bg-purple-500 text-white border-none rounded-lg cursor-pointer flex items-center gap-2",
+                        span { "+" }
+                        span { "Add DM Marker" }
+                    }
+                }
+            }
+
+            // Recap built from the ticked events, oldest first
+            if *composing_recap.read() {
+                {
+                    let selection = recap_selection.read();
+                    let mut picked: Vec<StoryEventData> = events
+                        .read()
+                        .iter()
+                        .filter(|e| !e.is_hidden && selection.contains(&e.id))
+                        .cloned()
+                        .collect();
+                    picked.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+                    rsx! {
+                        RecapComposer {
+                            events: picked,
+                            on_remove: move |event_id: String| recap_selection.write().retain(|id| id != &event_id),
+                            on_close: move |_| composing_recap.set(false),
+                        }
+                    }
                 }
             }
 
@@ -183,6 +247,27 @@ pub fn TimelineView(props: TimelineViewProps) -> Element {
                 }
             }
 
+            // Global tag filter
+            TagFilterBar {
+                available_tags: collect_tags(events.read().iter().map(|e| &e.tags)),
+            }
+
+            // Saved filter presets
+            FilterPresetBar {
+                scope: FilterPresetScope::Timeline,
+                world_id: props.world_id.clone(),
+                current: current_filters,
+                on_apply: {
+                    let mut tag_filter = tag_filter.clone();
+                    move |preset: FilterPreset| {
+                        let mut filter_state = filters.write();
+                        filter_state.event_type = preset.type_filter;
+                        filter_state.search_text = preset.search;
+                        tag_filter.active_tags.set(preset.tags);
+                    }
+                },
+            }
+
             // Event list
             div {
                 class: "flex-1 overflow-y-auto flex flex-col gap-3",
@@ -229,32 +314,58 @@ pub fn TimelineView(props: TimelineViewProps) -> Element {
                     }
 
                     for event in filtered_events.iter() {
-                        TimelineEventCard {
+                        div {
                             key: "{event.id}",
-                            event: event.clone(),
-                            on_click: {
-                                let event = event.clone();
-                                move |_| selected_event.set(Some(event.clone()))
-                            },
-                            on_toggle_visibility: {
-                                let event_id = event.id.clone();
-                                let world_id = props.world_id.clone();
-                                let service = story_event_service.clone();
-                                move |_| {
-                                    let event_id = event_id.clone();
-                                    let world_id = world_id.clone();
-                                    let service = service.clone();
-                                    spawn(async move {
-                                        if let Err(e) = service.toggle_event_visibility(&event_id).await {
-                                            tracing::error!("Failed to toggle visibility: {}", e);
+                            class: "flex items-start gap-2",
+                            // Recaps play on every player's stage, so hidden events stay out
+                            if *composing_recap.read() && !event.is_hidden {
+                                input {
+                                    r#type: "checkbox",
+                                    aria_label: "Add to recap",
+                                    checked: recap_selection.read().contains(&event.id),
+                                    onchange: {
+                                        let event_id = event.id.clone();
+                                        move |e: FormEvent| {
+                                            let mut selection = recap_selection.write();
+                                            selection.retain(|id| id != &event_id);
+                                            if e.checked() {
+                                                selection.push(event_id.clone());
+                                            }
                                         }
-                                        // Reload events
-                                        if let Ok(reloaded) = service.list_story_events(&world_id, None).await {
-                                            events.set(reloaded);
+                                    },
+                                    class: "mt-5 w-4 h-4 accent-amber-500 cursor-pointer",
+                                }
+                            }
+                            div {
+                                class: "flex-1 min-w-0",
+                                TimelineEventCard {
+                                    event: event.clone(),
+                                    involved: involved_marks(event, game_state.world.read().as_deref()),
+                                    on_click: {
+                                        let event = event.clone();
+                                        move |_| open_event(event.clone(), source_event, selected_event)
+                                    },
+                                    on_toggle_visibility: {
+                                        let event_id = event.id.clone();
+                                        let world_id = props.world_id.clone();
+                                        let service = story_event_service.clone();
+                                        move |_| {
+                                            let event_id = event_id.clone();
+                                            let world_id = world_id.clone();
+                                            let service = service.clone();
+                                            spawn(async move {
+                                                if let Err(e) = service.toggle_event_visibility(&event_id).await {
+                                                    tracing::error!("Failed to toggle visibility: {}", e);
+                                                }
+                                                // Reload events
+                                                if let Ok(reloaded) = service.list_story_events(&world_id, None).await {
+                                                    events.set(reloaded);
+                                                }
+                                            });
                                         }
-                                    });
+                                    },
                                 }
-                            },
+                            }
                         }
                     }
                 }
@@ -284,6 +395,9 @@ pub fn TimelineView(props: TimelineViewProps) -> Element {
                 }
             }
 
+            // Conversation or roll behind the clicked event
+            {source_panel(source_event, selected_event)}
+
             // Event detail modal
             if let Some(event) = selected_event.read().as_ref() {
                 EventDetailModal {
@@ -295,6 +409,42 @@ pub fn TimelineView(props: TimelineViewProps) -> Element {
     }
 }
 
+/// Open a clicked event: its source in the side panel when it links back
+/// to a conversation or roll, otherwise the detail modal
+pub fn open_event(
+    event: StoryEventData,
+    mut source_event: Signal<Option<StoryEventData>>,
+    mut selected_event: Signal<Option<StoryEventData>>,
+) {
+    if event.source_reference(|_| None).is_some() {
+        source_event.set(Some(event));
+    } else {
+        source_event.set(None);
+        selected_event.set(Some(event));
+    }
+}
+
+/// Side panel for the event in `source_event`, if any
+pub fn source_panel(
+    mut source_event: Signal<Option<StoryEventData>>,
+    mut selected_event: Signal<Option<StoryEventData>>,
+) -> Element {
+    let Some(event) = source_event.read().clone() else {
+        return rsx! {};
+    };
+    if event.source_reference(|_| None).is_none() {
+        return rsx! {};
+    }
+    let details = event.clone();
+    rsx! {
+        EventSourcePanel {
+            event,
+            on_close: move |_| source_event.set(None),
+            on_show_details: move |_| selected_event.set(Some(details.clone())),
+        }
+    }
+}
+
 /// Get a human-readable name for an event type
 fn get_event_type_name(event_type: &StoryEventTypeData) -> String {
     match event_type {
@@ -310,15 +460,17 @@ fn get_event_type_name(event_type: &StoryEventTypeData) -> String {
         StoryEventTypeData::NarrativeEventTriggered { .. } => "Narrative Event".to_string(),
         StoryEventTypeData::SessionStarted { .. } => "Session Start".to_string(),
         StoryEventTypeData::SessionEnded { .. } => "Session End".to_string(),
+        StoryEventTypeData::SessionPaused { .. } => "Session Paused".to_string(),
+        StoryEventTypeData::SessionResumed { .. } => "Session Resumed".to_string(),
         StoryEventTypeData::Custom { .. } => "Custom".to_string(),
     }
 }
 
 /// Event detail modal
 #[derive(Props, Clone)]
-struct EventDetailModalProps {
-    event: StoryEventData,
-    on_close: EventHandler<()>,
+pub struct EventDetailModalProps {
+    pub event: StoryEventData,
+    pub on_close: EventHandler<()>,
 }
 
 impl PartialEq for EventDetailModalProps {
@@ -328,10 +480,13 @@ impl PartialEq for EventDetailModalProps {
 }
 
 #[component]
-fn EventDetailModal(props: EventDetailModalProps) -> Element {
+pub fn EventDetailModal(props: EventDetailModalProps) -> Element {
+    use_modal_focus("event-detail-modal");
     let event = &props.event;
     let type_name = get_event_type_name(&event.event_type);
     let icon = get_event_type_icon(&event.event_type);
+    let summary = use_template_context().resolve(&event.summary);
+    let share_text = story_event_text(event, &summary);
 
     rsx! {
         div {
@@ -339,6 +494,9 @@ fn EventDetailModal(props: EventDetailModalProps) -> Element {
             onclick: move |_| props.on_close.call(()),
 
             div {
+                id: "event-detail-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "modal-content bg-dark-surface rounded-xl p-6 max-w-[600px] w-[90%] max-h-[80vh] overflow-y-auto",
                 onclick: move |e| e.stop_propagation(),
 
@@ -355,10 +513,23 @@ fn EventDetailModal(props: EventDetailModalProps) -> Element {
                         }
                     }
 
-                    button {
-                        onclick: move |_| props.on_close.call(()),
-                        class: "bg-transparent border-none text-gray-400 text-2xl cursor-pointer",
-                        "×"
+                    div {
+                        class: "flex items-center gap-2",
+                        CopyButton { text: share_text.clone(), label: "Copy" }
+                        ShareButton {
+                            route: Route::DMTimelineEventRoute {
+                                world_id: event.world_id.clone(),
+                                event_id: event.id.clone(),
+                            },
+                            title: type_name.clone(),
+                            text: summary.clone(),
+                        }
+                        button {
+                            aria_label: "Close",
+                            onclick: move |_| props.on_close.call(()),
+                            class: "bg-transparent border-none text-gray-400 text-2xl cursor-pointer",
+                            "×"
+                        }
                     }
                 }
 
@@ -458,6 +629,28 @@ fn DetailRow(props: DetailRowProps) -> Element {
     }
 }
 
+/// Characters and location of an event that have an icon or colour
+fn involved_marks(event: &StoryEventData, world: Option<&SessionWorldSnapshot>) -> Vec<InvolvedMark> {
+    let Some(world) = world else {
+        return Vec::new();
+    };
+    let characters = event
+        .involved_characters
+        .iter()
+        .filter_map(|id| world.characters.iter().find(|c| &c.id == id))
+        .map(|c| (c.name.clone(), c.style.clone()));
+    let location = event
+        .location_id
+        .as_ref()
+        .and_then(|id| world.locations.iter().find(|l| &l.id == id))
+        .map(|l| (l.name.clone(), l.style.clone()));
+    characters
+        .chain(location)
+        .filter(|(_, style)| !style.is_empty())
+        .map(|(name, style)| InvolvedMark { name, style })
+        .collect()
+}
+
 /// Get an icon for an event type
 pub fn get_event_type_icon(event_type: &StoryEventTypeData) -> &'static str {
     match event_type {
@@ -473,6 +666,8 @@ pub fn get_event_type_icon(event_type: &StoryEventTypeData) -> &'static str {
         StoryEventTypeData::NarrativeEventTriggered { .. } => "⭐",
         StoryEventTypeData::SessionStarted { .. } => "▶️",
         StoryEventTypeData::SessionEnded { .. } => "⏹️",
+        StoryEventTypeData::SessionPaused { .. } => "⏸️",
+        StoryEventTypeData::SessionResumed { .. } => "⏯️",
         StoryEventTypeData::Custom { .. } => "📌",
     }
 }
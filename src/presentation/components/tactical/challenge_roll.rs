@@ -7,12 +7,17 @@
 //! 3. ResultDisplay - Animated outcome with description
 //!
 //! Supports both formula-based rolls (e.g., "1d20+5") and manual result entry
-//! for physical dice rolls.
+//! for physical dice rolls. Physical dice are entered one die at a time and
+//! checked against the suggested dice; the result reaches the DM marked as
+//! manual, with any unlikely-looking entry flagged.
 
 use dioxus::prelude::*;
-use crate::application::dto::websocket_messages::DiceInputType;
+use crate::application::dto::websocket_messages::{DiceInputType, ManualDiceEntry};
+use crate::application::dto::DiceNotation;
 use crate::application::ports::outbound::Platform;
-use crate::presentation::state::{RollSubmissionStatus, use_session_state};
+use crate::application::services::{check_die, check_manual_dice};
+use crate::presentation::components::common::DecisionCountdown;
+use crate::presentation::state::{RollSubmissionStatus, use_modal_focus, use_session_state, use_template_context};
 use crate::presentation::state::challenge_state::ChallengeResultData;
 
 /// Props for the ChallengeRollModal component
@@ -43,6 +48,12 @@ pub struct ChallengeRollModalProps {
     /// Called when user clicks "Continue" after viewing result (P3.3/P3.4)
     #[props(default)]
     pub on_continue: Option<EventHandler<()>>,
+    /// Seconds to roll before `on_timeout` fires (DM decision timer)
+    #[props(default)]
+    pub time_limit: Option<u32>,
+    /// Called once if the time limit runs out before a roll is submitted
+    #[props(default)]
+    pub on_timeout: Option<EventHandler<()>>,
 }
 
 /// ChallengeRollModal component (P3.3/P3.4)
@@ -53,6 +64,8 @@ pub struct ChallengeRollModalProps {
 /// 3. ResultDisplay - Animated result with outcome description
 #[component]
 pub fn ChallengeRollModal(props: ChallengeRollModalProps) -> Element {
+    use_modal_focus("challenge-modal");
+
     // Get roll status from session state (P3.3/P3.4)
     let session_state = use_session_state();
     let roll_status = session_state.roll_status();
@@ -70,6 +83,7 @@ pub fn ChallengeRollModal(props: ChallengeRollModalProps) -> Element {
             match result.outcome.as_str() {
                 "critical_success" => "border-2 border-yellow-400 shadow-[0_20px_60px_rgba(250,204,21,0.3)]",
                 "success" => "border-2 border-green-500 shadow-[0_20px_60px_rgba(34,197,94,0.3)]",
+                "partial" | "partial_success" => "border-2 border-orange-400 shadow-[0_20px_60px_rgba(251,146,60,0.3)]",
                 "failure" => "border-2 border-red-500 shadow-[0_20px_60px_rgba(239,68,68,0.3)]",
                 "critical_failure" => "border-2 border-red-700 shadow-[0_20px_60px_rgba(185,28,28,0.3)]",
                 _ => "border-2 border-amber-500 shadow-[0_20px_60px_rgba(245,158,11,0.2)]",
@@ -94,6 +108,9 @@ pub fn ChallengeRollModal(props: ChallengeRollModalProps) -> Element {
             // Modal content - stop propagation to prevent closing when clicking inside
             div {
                 id: "challenge-modal",
+                role: "dialog",
+                aria_modal: "true",
+                aria_label: "Skill challenge: {props.challenge_name}",
                 class: "bg-gradient-to-br from-dark-surface to-dark-bg p-8 rounded-2xl max-w-[500px] w-[90%] {border_class}",
                 onclick: |e| e.stop_propagation(),
 
@@ -131,6 +148,20 @@ pub fn ChallengeRollModal(props: ChallengeRollModalProps) -> Element {
                     // Phase 1: Roll Input (NotSubmitted or Dismissed)
                     RollSubmissionStatus::NotSubmitted | RollSubmissionStatus::Dismissed => {
                         rsx! {
+                            if let Some(seconds) = props.time_limit {
+                                div {
+                                    class: "mb-4",
+                                    DecisionCountdown {
+                                        key: "{props.challenge_id}",
+                                        seconds: seconds,
+                                        on_expire: move |_| {
+                                            if let Some(handler) = &props.on_timeout {
+                                                handler.call(());
+                                            }
+                                        },
+                                    }
+                                }
+                            }
                             RollInputPhase {
                                 challenge_name: props.challenge_name.clone(),
                                 description: props.description.clone(),
@@ -165,8 +196,12 @@ fn RollInputPhase(
 ) -> Element {
     // Input mode: true = use formula roll, false = manual input
     let mut use_formula_mode = use_signal(|| true);
+    // Physical dice are checked against the suggested dice
+    let manual_notation = DiceNotation::parse(&suggested_dice_display)
+        .unwrap_or(DiceNotation { count: 1, sides: 20, modifier: 0 });
     let mut formula_input = use_signal(move || suggested_dice_display.clone());
-    let mut manual_input = use_signal(|| String::new());
+    // One entry per physical die
+    let mut manual_inputs = use_signal(move || vec![String::new(); manual_notation.count as usize]);
     let mut roll_result = use_signal(|| None::<RollDisplayState>);
     let mut is_rolling = use_signal(|| false);
     let mut error_message = use_signal(|| None::<String>);
@@ -214,6 +249,7 @@ fn RollInputPhase(
             }
 
             button {
+                aria_label: "Close",
                 onclick: move |_| on_close.call(()),
                 class: "bg-transparent border-none text-gray-400 cursor-pointer text-2xl p-0",
                 "×"
@@ -317,8 +353,8 @@ fn RollInputPhase(
                 result: result.clone(),
                 on_submit: move |_| {
                     let result = result.clone();
-                    if result.is_manual {
-                        on_roll.call(DiceInputType::Manual(result.total - character_modifier));
+                    if let Some(entry) = result.manual {
+                        on_roll.call(DiceInputType::ManualDice(entry));
                     } else {
                         on_roll.call(DiceInputType::Formula(result.formula.clone()));
                     }
@@ -381,7 +417,7 @@ fn RollInputPhase(
                                     formula_modifier: modifier,
                                     character_modifier,
                                     total,
-                                    is_manual: false,
+                                    manual: None,
                                 }));
 
                                 is_rolling.set(false);
@@ -406,23 +442,36 @@ fn RollInputPhase(
             div {
                 p {
                     class: "text-gray-400 text-sm text-center m-0 mb-4",
-                    "Enter the result from your physical dice roll"
+                    "Roll {manual_notation} and enter each die"
                 }
 
                 div {
-                    class: "mb-4",
-
-                    label {
-                        class: "text-gray-400 text-xs block mb-2",
-                        "Dice Result (before modifiers)"
-                    }
-
-                    input {
-                        r#type: "number",
-                        value: "{manual_input}",
-                        oninput: move |e| manual_input.set(e.value().to_string()),
-                        placeholder: "Enter roll result",
-                        class: "w-full p-4 bg-black/30 border border-white/20 rounded-lg text-white text-2xl text-center box-border",
+                    class: "flex flex-wrap justify-center gap-2 mb-4",
+
+                    for (i, entry) in manual_inputs.read().iter().enumerate() {
+                        {
+                            // Missing dice are only reported on submit
+                            let impossible = entry.trim().parse::<i32>().is_ok()
+                                && check_die(entry, manual_notation.sides).is_err();
+                            rsx! {
+                                input {
+                                    key: "{i}",
+                                    r#type: "number",
+                                    min: "1",
+                                    max: "{manual_notation.sides}",
+                                    aria_label: "Die {i + 1} (d{manual_notation.sides})",
+                                    aria_invalid: "{impossible}",
+                                    value: "{entry}",
+                                    oninput: move |e| manual_inputs.write()[i] = e.value(),
+                                    placeholder: "d{manual_notation.sides}",
+                                    class: if impossible {
+                                        "w-20 p-3 bg-black/30 border-2 border-red-500 rounded-lg text-white text-2xl text-center box-border"
+                                    } else {
+                                        "w-20 p-3 bg-black/30 border border-white/20 rounded-lg text-white text-2xl text-center box-border"
+                                    },
+                                }
+                            }
+                        }
                     }
                 }
 
@@ -430,6 +479,7 @@ fn RollInputPhase(
                 if let Some(err) = error_message.read().as_ref() {
                     p {
                         class: "text-red-500 text-sm text-center m-0 mb-4",
+                        role: "alert",
                         "{err}"
                     }
                 }
@@ -437,30 +487,24 @@ fn RollInputPhase(
                 // Submit button
                 button {
                     onclick: move |_| {
-                        let input = manual_input.read().clone();
-                        match input.trim().parse::<i32>() {
-                            Ok(value) if value >= 1 => {
+                        match check_manual_dice(&manual_notation, &manual_inputs.read()) {
+                            Ok(entry) => {
                                 error_message.set(None);
-                                let total = value + character_modifier;
+                                let dice_total = entry.dice_total();
                                 roll_result.set(Some(RollDisplayState {
-                                    formula: "Manual".to_string(),
-                                    individual_rolls: vec![value],
-                                    dice_total: value,
-                                    formula_modifier: 0,
+                                    formula: entry.formula.clone(),
+                                    individual_rolls: entry.dice.clone(),
+                                    dice_total,
+                                    formula_modifier: manual_notation.modifier,
                                     character_modifier,
-                                    total,
-                                    is_manual: true,
+                                    total: dice_total + manual_notation.modifier + character_modifier,
+                                    manual: Some(entry),
                                 }));
                             }
-                            Ok(_) => {
-                                error_message.set(Some("Value must be at least 1".to_string()));
-                            }
-                            Err(_) => {
-                                error_message.set(Some("Please enter a valid number".to_string()));
-                            }
+                            Err(e) => error_message.set(Some(e)),
                         }
                     },
-                    disabled: manual_input.read().is_empty(),
+                    disabled: manual_inputs.read().iter().any(|d| d.trim().is_empty()),
                     class: "w-full p-6 bg-gradient-to-br from-amber-500 to-amber-600 text-white border-none rounded-lg cursor-pointer text-xl font-bold transition-all",
                     "Submit Result"
                 }
@@ -534,10 +578,15 @@ fn ResultDisplayPhase(
     let (outcome_text, outcome_class, glow_class) = match result.outcome.as_str() {
         "critical_success" => ("CRITICAL SUCCESS", "text-yellow-400", "shadow-[0_0_30px_rgba(250,204,21,0.5)]"),
         "success" => ("SUCCESS", "text-green-500", "shadow-[0_0_20px_rgba(34,197,94,0.5)]"),
+        "partial" | "partial_success" => ("PARTIAL SUCCESS", "text-orange-400", "shadow-[0_0_20px_rgba(251,146,60,0.5)]"),
         "failure" => ("FAILURE", "text-red-500", "shadow-[0_0_20px_rgba(239,68,68,0.5)]"),
         "critical_failure" => ("CRITICAL FAILURE", "text-red-700", "shadow-[0_0_30px_rgba(185,28,28,0.5)]"),
         _ => ("RESULT", "text-amber-500", "shadow-[0_0_20px_rgba(245,158,11,0.5)]"),
     };
+    let outcome_description = use_template_context()
+        .with("pc.name", result.character_name.clone())
+        .with("challenge.name", result.challenge_name.clone())
+        .resolve(&result.outcome_description);
 
     rsx! {
         div {
@@ -593,7 +642,7 @@ fn ResultDisplayPhase(
 
                 p {
                     class: "text-gray-300 leading-relaxed italic",
-                    "{result.outcome_description}"
+                    "{outcome_description}"
                 }
             }
 
@@ -616,7 +665,8 @@ struct RollDisplayState {
     formula_modifier: i32,
     character_modifier: i32,
     total: i32,
-    is_manual: bool,
+    /// Set when the dice were rolled physically and entered by hand
+    manual: Option<ManualDiceEntry>,
 }
 
 /// Component for displaying roll results
@@ -659,12 +709,25 @@ fn RollResultDisplay(
                         "Natural 20!"
                     } else if is_nat_1 {
                         "Natural 1!"
-                    } else if result.is_manual {
+                    } else if result.manual.is_some() {
                         "Manual: {result.dice_total}"
                     } else {
                         "{result.formula}({rolls_display})"
                     }
                 }
+
+                if let Some(entry) = &result.manual {
+                    p {
+                        class: "text-gray-400 text-sm m-0 font-mono",
+                        "{entry.formula}({rolls_display})"
+                    }
+                    for flag in entry.flags.iter() {
+                        p {
+                            class: "text-amber-400 text-xs m-0 mt-1",
+                            "⚠ {flag} — the DM will see this"
+                        }
+                    }
+                }
             }
 
             // Calculation breakdown
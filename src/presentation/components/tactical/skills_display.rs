@@ -3,6 +3,7 @@
 //! Displays player's skills with modifiers in a compact panel.
 
 use dioxus::prelude::*;
+use crate::presentation::state::use_modal_focus;
 
 /// A player skill with its properties
 #[derive(Clone, Debug, PartialEq)]
@@ -34,6 +35,7 @@ pub struct SkillsDisplayProps {
 /// with modifiers highlighted and proficient skills marked.
 #[component]
 pub fn SkillsDisplay(props: SkillsDisplayProps) -> Element {
+    use_modal_focus("skills-modal");
     // Group skills by category
     let mut categories: std::collections::BTreeMap<String, Vec<PlayerSkillData>> =
         std::collections::BTreeMap::new();
@@ -57,6 +59,8 @@ pub fn SkillsDisplay(props: SkillsDisplayProps) -> Element {
             // Modal content
             div {
                 id: "skills-modal",
+                role: "dialog",
+                aria_modal: "true",
                 class: "bg-gradient-to-br from-dark-surface to-dark-bg p-8 rounded-2xl max-w-2xl w-[90%] max-h-[80vh] overflow-y-auto border-2 border-blue-500 shadow-[0_20px_60px_rgba(59,130,246,0.2)]",
                 onclick: move |e: dioxus::prelude::MouseEvent| {
                     e.stop_propagation();
@@ -72,6 +76,7 @@ pub fn SkillsDisplay(props: SkillsDisplayProps) -> Element {
                     }
 
                     button {
+                        aria_label: "Close",
                         onclick: move |_| props.on_close.call(()),
                         class: "bg-transparent border-0 text-gray-400 cursor-pointer text-2xl p-0 hover:text-gray-300",
                         "×"
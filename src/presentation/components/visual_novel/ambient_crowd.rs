@@ -0,0 +1,54 @@
+//! Ambient crowd - background figures for a region's population
+//!
+//! Rendered as a Backdrop child under the mood overlay, so the DM's
+//! lighting falls on the crowd too, and under the characters, who stay the
+//! focus. Figures are plain silhouettes and ignore the pointer; a small
+//! indicator names the crowd for players who can't make it out.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{CrowdFigure, RegionPopulation};
+
+/// Silhouettes and crowd indicator for a region
+#[component]
+pub fn AmbientCrowd(region_id: String, population: RegionPopulation) -> Element {
+    if population.is_empty() {
+        return rsx! {};
+    }
+    let figures = population.figures(&region_id);
+    let label = population.label();
+
+    rsx! {
+        div {
+            class: "ambient-crowd absolute inset-0 pointer-events-none overflow-hidden",
+            aria_hidden: "true",
+
+            for (i, style) in figures.iter().map(figure_style).enumerate() {
+                div {
+                    key: "{i}",
+                    class: "absolute bottom-[18%] flex flex-col items-center opacity-60 blur-[1px]",
+                    style: "{style}",
+                    div { class: "w-[38%] aspect-square rounded-full bg-black" }
+                    div { class: "w-full flex-1 -mt-[4%] rounded-t-[45%] bg-black" }
+                }
+            }
+        }
+
+        span {
+            class: "absolute top-2 left-2 z-[5] px-2 py-0.5 bg-black/60 text-gray-300 rounded-full text-xs pointer-events-none",
+            role: "note",
+            "👥 {label}"
+        }
+    }
+}
+
+/// Position and size of one figure; full scale is half the stage's height
+fn figure_style(figure: &CrowdFigure) -> String {
+    let mirror = if figure.mirrored { " scaleX(-1)" } else { "" };
+    format!(
+        "left: {}%; height: {}%; aspect-ratio: 1 / 2.6; transform: translateX(-50%){};",
+        figure.left,
+        figure.scale / 2,
+        mirror
+    )
+}
@@ -1,15 +1,27 @@
 //! Backdrop component for visual novel scenes
 //!
-//! Displays the background image for the current scene.
+//! Displays the background image for the current scene, or an animated
+//! backdrop (looping video or animated image) when the location has one and
+//! this device's display and reduced motion preferences allow it. The image
+//! and the children sit in a [`CameraFrame`] so the DM's camera effects
+//! move the whole stage together.
 
 use dioxus::prelude::*;
 
+use crate::application::services::{choose_animated_backdrop, BackdropMediaKind};
+use crate::presentation::state::{perf_probe, use_display_state};
+
+use super::CameraFrame;
+
 /// Props for the Backdrop component
 #[derive(Props, Clone, PartialEq)]
 pub struct BackdropProps {
     /// URL or asset path for the backdrop image
     #[props(default)]
     pub image_url: Option<String>,
+    /// URL of a looping video or animated image played over the static image
+    #[props(default)]
+    pub animated_url: Option<String>,
     /// Whether to show fade transition animation
     #[props(default = false)]
     pub transitioning: bool,
@@ -21,9 +33,16 @@ pub struct BackdropProps {
 /// Backdrop component - displays the scene background
 ///
 /// Uses the `.vn-backdrop` Tailwind class for styling.
-/// Falls back to a gradient if no image is provided.
+/// Falls back to a gradient if no image is provided. The static image stays
+/// underneath an animated backdrop, so it shows while the animation loads
+/// and whenever it fails to play.
 #[component]
 pub fn Backdrop(props: BackdropProps) -> Element {
+    perf_probe::record_render("Backdrop");
+    let display_state = use_display_state();
+    // Animated URL that failed to load on this device
+    let mut failed_url: Signal<Option<String>> = use_signal(|| None);
+
     // Extract conditionals BEFORE rsx! block (CRITICAL for Dioxus)
     let (bg_class, bg_style) = match &props.image_url {
         Some(url) => (
@@ -36,10 +55,68 @@ pub fn Backdrop(props: BackdropProps) -> Element {
         ),
     };
 
+    let preferences = *display_state.backdrop.read();
+    // Reduced motion keeps the static image
+    let reduced_motion = display_state.accessibility.read().reduced_motion;
+    let animated = choose_animated_backdrop(props.animated_url.as_deref(), &preferences)
+        .filter(|_| !reduced_motion)
+        .filter(|(url, _)| failed_url.read().as_ref() != Some(url));
+    let poster = props.image_url.clone().unwrap_or_default();
+
     rsx! {
         div {
-            class: "vn-backdrop absolute inset-0 {bg_class}",
-            style: if !bg_style.is_empty() { "{bg_style}" } else { "" },
+            class: "vn-backdrop absolute inset-0 overflow-hidden",
+
+            CameraFrame {
+                // Static image; the animated backdrop is layered over it
+                div {
+                    class: "absolute inset-0 {bg_class}",
+                    style: if !bg_style.is_empty() { "{bg_style}" } else { "" },
+                }
+
+                // Animated backdrop, layered over the static image
+                if let Some((url, kind)) = animated {
+                    match kind {
+                        BackdropMediaKind::Video => rsx! {
+                            video {
+                                key: "{url}",
+                                class: "backdrop-animated absolute inset-0 w-full h-full object-cover pointer-events-none",
+                                src: "{url}",
+                                poster: "{poster}",
+                                autoplay: true,
+                                r#loop: true,
+                                muted: true,
+                                playsinline: true,
+                                onerror: {
+                                    let url = url.clone();
+                                    move |_| {
+                                        tracing::warn!("Animated backdrop failed to play, using static image: {}", url);
+                                        failed_url.set(Some(url.clone()));
+                                    }
+                                },
+                            }
+                        },
+                        BackdropMediaKind::Image => rsx! {
+                            img {
+                                key: "{url}",
+                                class: "backdrop-animated absolute inset-0 w-full h-full object-cover pointer-events-none",
+                                src: "{url}",
+                                alt: "",
+                                onerror: {
+                                    let url = url.clone();
+                                    move |_| {
+                                        tracing::warn!("Animated backdrop failed to load, using static image: {}", url);
+                                        failed_url.set(Some(url.clone()));
+                                    }
+                                },
+                            }
+                        },
+                    }
+                }
+
+                // Children (character sprites, etc.)
+                {props.children}
+            }
 
             // Fade overlay for scene transitions
             if props.transitioning {
@@ -53,8 +130,6 @@ pub fn Backdrop(props: BackdropProps) -> Element {
                 class: "backdrop-vignette absolute inset-0 pointer-events-none shadow-[inset_0_0_150px_rgba(0,0,0,0.5)]",
             }
 
-            // Children (character sprites, etc.)
-            {props.children}
         }
     }
 }
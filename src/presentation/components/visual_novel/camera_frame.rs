@@ -0,0 +1,70 @@
+//! Camera frame - plays the DM's camera effects on the stage
+//!
+//! `Backdrop` wraps its image and children (mood, drawings, characters) in
+//! one, so they move together while the vignette and the dialogue box
+//! outside keep still. Reduced motion skips the effects.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{CameraEffect, CharacterPosition, SceneCharacterState};
+use crate::application::ports::outbound::Platform;
+use crate::presentation::state::{use_display_state, use_game_state};
+
+/// Full-stage layer that moves with the current camera effect
+#[component]
+pub fn CameraFrame(children: Element) -> Element {
+    let game_state = use_game_state();
+    let display_state = use_display_state();
+    let platform = use_context::<Platform>();
+    // Start time of the last effect that has finished here
+    let mut finished: Signal<Option<u64>> = use_signal(|| None);
+
+    // Drop the style once the effect ends, so the same effect again restarts it
+    {
+        let platform = platform.clone();
+        use_effect(move || {
+            let Some((started, effect)) = game_state.camera_effect.read().clone() else {
+                return;
+            };
+            let platform = platform.clone();
+            spawn(async move {
+                platform.sleep_ms(u64::from(effect.duration_ms())).await;
+                finished.set(Some(started));
+            });
+        });
+    }
+
+    let reduced_motion = display_state.accessibility.read().reduced_motion;
+    let style = game_state
+        .camera_effect
+        .read()
+        .as_ref()
+        .filter(|_| !reduced_motion)
+        .filter(|(started, effect)| {
+            *finished.read() != Some(*started) && effect.is_playing(*started, platform.now_millis())
+        })
+        .map(|(_, effect)| effect.stage_style(camera_focus(effect, &game_state.scene_characters.read())))
+        .unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "camera-frame absolute inset-0",
+            style: "{style}",
+            {children}
+        }
+    }
+}
+
+/// Where a zoom centres: its character, or else whoever is speaking
+fn camera_focus(effect: &CameraEffect, characters: &[SceneCharacterState]) -> Option<CharacterPosition> {
+    let CameraEffect::Zoom { character_id, .. } = effect else {
+        return None;
+    };
+    characters
+        .iter()
+        .find(|c| match character_id {
+            Some(id) => &c.id == id,
+            None => c.is_speaking,
+        })
+        .map(|c| c.position)
+}
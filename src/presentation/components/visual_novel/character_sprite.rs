@@ -1,10 +1,140 @@
 //! Character sprite component for visual novel scenes
 //!
-//! Displays character sprites at different positions on screen.
+//! Displays character sprites at different positions on screen. The
+//! `CharacterLayer` arranges them around whoever is speaking: the speaker
+//! moves to center front, everyone else dims and steps back, and characters
+//! beyond the on-screen limit collapse into an overflow group.
 
 use dioxus::prelude::*;
 
 use crate::application::dto::websocket_messages::{SceneCharacterState, CharacterPosition};
+use crate::presentation::state::perf_probe;
+
+use super::disposition_meter::DispositionMeter;
+
+/// Default number of sprites shown before the rest are grouped
+pub const DEFAULT_MAX_ON_SCREEN: usize = 5;
+
+/// Horizontal gap between sprite slots, as a percentage of the layer width
+const SLOT_SPACING_PERCENT: f64 = 16.0;
+
+/// Where and how a sprite is drawn in the layer
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpritePlacement {
+    /// Horizontal center, as a percentage of the layer width
+    pub x_percent: f64,
+    /// Stacking order; the speaker is always on top
+    pub z_index: i32,
+    pub scale: f64,
+    /// Dimmed because someone else is speaking
+    pub dimmed: bool,
+    pub is_speaker: bool,
+}
+
+/// Result of arranging the characters in a scene
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SpriteLayout {
+    /// Characters drawn as sprites, with their placement
+    pub placed: Vec<(SceneCharacterState, SpritePlacement)>,
+    /// Characters that didn't fit on screen
+    pub overflow: Vec<SceneCharacterState>,
+}
+
+/// Arrange on-screen characters around the active speaker
+///
+/// The speaker is taken from `speaker_id` when it names a character on
+/// screen, otherwise from the characters' `is_speaking` flags. Other
+/// characters keep their authored left-to-right order and fan out to either
+/// side of center. Once more than `max_on_screen` characters are present, the
+/// ones furthest from the speaker are moved to `overflow`.
+pub fn arrange_sprites(
+    characters: &[SceneCharacterState],
+    speaker_id: Option<&str>,
+    max_on_screen: usize,
+) -> SpriteLayout {
+    let mut visible: Vec<&SceneCharacterState> = characters
+        .iter()
+        .filter(|c| c.position != CharacterPosition::OffScreen)
+        .collect();
+    // Stable sort keeps list order within each authored position
+    visible.sort_by_key(|c| position_rank(c.position));
+
+    let speaker_index = speaker_id
+        .and_then(|id| visible.iter().position(|c| c.id == id))
+        .or_else(|| visible.iter().position(|c| c.is_speaking));
+    let speaker = speaker_index.map(|i| visible.remove(i));
+
+    // Split the rest either side of center, then drop the outermost slots
+    // first so overflow trims both sides evenly
+    let split = visible.len().div_ceil(2);
+    let (mut left, mut right) = (visible[..split].to_vec(), visible[split..].to_vec());
+    let capacity = max_on_screen.max(1) - usize::from(speaker.is_some());
+    let mut overflow = Vec::new();
+    while left.len() + right.len() > capacity {
+        if left.len() >= right.len() {
+            overflow.push(left.remove(0).clone());
+        } else {
+            overflow.push(right.pop().expect("right is non-empty").clone());
+        }
+    }
+
+    let mut placed = Vec::new();
+    let has_speaker = speaker.is_some();
+    let background = |slot: usize| SpritePlacement {
+        x_percent: 0.0,
+        z_index: 10 - slot as i32,
+        scale: if has_speaker { 0.9 - 0.04 * slot.saturating_sub(1) as f64 } else { 1.0 },
+        dimmed: has_speaker,
+        is_speaker: false,
+    };
+
+    if let Some(speaker) = speaker {
+        placed.push((
+            speaker.clone(),
+            SpritePlacement {
+                x_percent: 50.0,
+                z_index: 20,
+                scale: 1.05,
+                dimmed: false,
+                is_speaker: true,
+            },
+        ));
+        // Fan out from the speaker: nearest slot is 1
+        for (i, c) in left.iter().rev().enumerate() {
+            let slot = i + 1;
+            placed.push(((*c).clone(), SpritePlacement {
+                x_percent: 50.0 - SLOT_SPACING_PERCENT * slot as f64,
+                ..background(slot)
+            }));
+        }
+        for (i, c) in right.iter().enumerate() {
+            let slot = i + 1;
+            placed.push(((*c).clone(), SpritePlacement {
+                x_percent: 50.0 + SLOT_SPACING_PERCENT * slot as f64,
+                ..background(slot)
+            }));
+        }
+    } else {
+        // Nobody speaking: spread everyone evenly across the stage
+        let row: Vec<&SceneCharacterState> = left.into_iter().chain(right).collect();
+        let n = row.len();
+        for (i, c) in row.into_iter().enumerate() {
+            let x_percent = 50.0 + SLOT_SPACING_PERCENT * (i as f64 - (n as f64 - 1.0) / 2.0);
+            placed.push((c.clone(), SpritePlacement { x_percent, ..background(0) }));
+        }
+    }
+
+    SpriteLayout { placed, overflow }
+}
+
+fn position_rank(position: CharacterPosition) -> u8 {
+    match position {
+        CharacterPosition::Left => 0,
+        CharacterPosition::Center => 1,
+        CharacterPosition::Right => 2,
+        CharacterPosition::OffScreen => 3,
+    }
+}
 
 /// Props for the CharacterSprite component
 #[derive(Props, Clone, PartialEq)]
@@ -14,12 +144,17 @@ pub struct CharacterSpriteProps {
     /// Optional click handler
     #[props(default)]
     pub on_click: Option<EventHandler<String>>,
+    /// Placement computed by the layer; falls back to the character's
+    /// authored position when absent
+    #[props(default)]
+    pub placement: Option<SpritePlacement>,
 }
 
 /// Character sprite component - displays a character at their position
 ///
-/// Uses `.sprite-left`, `.sprite-center`, `.sprite-right` Tailwind classes.
-/// Characters who are speaking are highlighted with brightness and scale.
+/// Without a placement, uses `.sprite-left`, `.sprite-center`, `.sprite-right`
+/// Tailwind classes. Characters who are speaking are highlighted with
+/// brightness and scale.
 #[component]
 pub fn CharacterSprite(props: CharacterSpriteProps) -> Element {
     // Don't render off-screen characters
@@ -27,25 +162,47 @@ pub fn CharacterSprite(props: CharacterSpriteProps) -> Element {
         return rsx! {};
     }
 
-    let position_class = match props.character.position {
-        CharacterPosition::Left => "sprite-left",
-        CharacterPosition::Center => "sprite-center",
-        CharacterPosition::Right => "sprite-right",
-        CharacterPosition::OffScreen => return rsx! {},
-    };
-
-    // Speaking characters get highlighted
-    let speaking_style = if props.character.is_speaking {
-        "filter: brightness(1.1) drop-shadow(0 0 10px rgba(212, 175, 55, 0.5)); transform: scale(1.02);"
-    } else {
-        "filter: brightness(0.85);"
+    let (position_class, placement_style, is_speaking) = match &props.placement {
+        Some(p) => {
+            let filter = if p.is_speaker {
+                "brightness(1.1) drop-shadow(0 0 10px rgba(212, 175, 55, 0.5))"
+            } else if p.dimmed {
+                "brightness(0.55) saturate(0.8)"
+            } else {
+                "brightness(0.95)"
+            };
+            // Stepping back: smaller and slightly raised toward the horizon
+            let lift = if p.dimmed { 16.0 } else { 0.0 };
+            let style = format!(
+                "position: absolute; bottom: 200px; left: {:.2}%; z-index: {}; filter: {}; transform: translateX(-50%) translateY(-{}px) scale({:.3}); transform-origin: bottom center; transition: left 0.45s ease, transform 0.45s ease, filter 0.3s;",
+                p.x_percent, p.z_index, filter, lift, p.scale
+            );
+            ("", style, p.is_speaker)
+        }
+        None => {
+            let position_class = match props.character.position {
+                CharacterPosition::Left => "sprite-left",
+                CharacterPosition::Center => "sprite-center",
+                CharacterPosition::Right => "sprite-right",
+                CharacterPosition::OffScreen => return rsx! {},
+            };
+            // Speaking characters get highlighted
+            let speaking_style = if props.character.is_speaking {
+                "filter: brightness(1.1) drop-shadow(0 0 10px rgba(212, 175, 55, 0.5)); transform: scale(1.02);"
+            } else {
+                "filter: brightness(0.85);"
+            };
+            let style = format!("{} transition: filter 0.3s, transform 0.3s;", speaking_style);
+            (position_class, style, props.character.is_speaking)
+        }
     };
 
     let character_id = props.character.id.clone();
     let character_name = props.character.name.clone();
+    let disposition = props.character.disposition();
     let has_click = props.on_click.is_some();
     let cursor_style = if has_click { "pointer" } else { "default" };
-    let full_style = format!("{} transition: filter 0.3s, transform 0.3s; cursor: {};", speaking_style, cursor_style);
+    let full_style = format!("{} cursor: {};", placement_style, cursor_style);
 
     rsx! {
         div {
@@ -57,6 +214,14 @@ pub fn CharacterSprite(props: CharacterSpriteProps) -> Element {
                 }
             },
 
+            // How the NPC feels about the party, just above their head
+            if let Some(disposition) = disposition {
+                div {
+                    class: "absolute -top-7 left-1/2 -translate-x-1/2 pointer-events-none",
+                    DispositionMeter { disposition }
+                }
+            }
+
             if let Some(ref sprite_url) = props.character.sprite_asset {
                 img {
                     src: "{sprite_url}",
@@ -67,7 +232,7 @@ pub fn CharacterSprite(props: CharacterSpriteProps) -> Element {
                 // Placeholder sprite when no image is available
                 PlaceholderSprite {
                     name: props.character.name.clone(),
-                    is_speaking: props.character.is_speaking,
+                    is_speaking: is_speaking,
                 }
             }
         }
@@ -104,7 +269,8 @@ fn PlaceholderSprite(name: String, is_speaking: bool) -> Element {
 
 /// Character layer component - container for all character sprites
 ///
-/// Provides proper z-indexing and positioning context for sprites.
+/// Provides proper z-indexing and positioning context for sprites, and
+/// rearranges them whenever the speaker changes.
 #[derive(Props, Clone, PartialEq)]
 pub struct CharacterLayerProps {
     /// Characters to display
@@ -112,21 +278,177 @@ pub struct CharacterLayerProps {
     /// Optional click handler for characters
     #[props(default)]
     pub on_character_click: Option<EventHandler<String>>,
+    /// Character currently speaking; defaults to the `is_speaking` flags
+    #[props(default)]
+    pub speaker_id: Option<String>,
+    /// Sprites shown before the rest are grouped into an overflow badge
+    #[props(default = DEFAULT_MAX_ON_SCREEN)]
+    pub max_on_screen: usize,
 }
 
 #[component]
 pub fn CharacterLayer(props: CharacterLayerProps) -> Element {
+    perf_probe::record_render("CharacterLayer");
+    let SpriteLayout { placed, overflow } =
+        arrange_sprites(&props.characters, props.speaker_id.as_deref(), props.max_on_screen);
+    let overflow_names = overflow
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let overflow_count = overflow.len();
+
     rsx! {
         div {
             class: "character-layer absolute inset-0 pointer-events-none z-[1]",
 
-            for character in props.characters.iter() {
+            for (character, placement) in placed.into_iter() {
                 CharacterSprite {
                     key: "{character.id}",
-                    character: character.clone(),
-                    on_click: props.on_character_click.clone(),
+                    character: character,
+                    on_click: props.on_character_click,
+                    placement: placement,
+                }
+            }
+
+            if overflow_count > 0 {
+                OverflowGroup {
+                    characters: overflow.clone(),
+                    label: format!("+{}", overflow_count),
+                    title: overflow_names,
+                    on_click: props.on_character_click,
                 }
             }
         }
     }
 }
+
+/// Badge for characters that didn't fit on screen
+#[component]
+fn OverflowGroup(
+    characters: Vec<SceneCharacterState>,
+    label: String,
+    title: String,
+    on_click: Option<EventHandler<String>>,
+) -> Element {
+    let mut expanded = use_signal(|| false);
+
+    rsx! {
+        div {
+            class: "absolute bottom-[210px] right-4 z-[30] flex flex-col items-end gap-1 pointer-events-auto",
+            title: "{title}",
+
+            if *expanded.read() {
+                div {
+                    class: "flex flex-col gap-1 p-2 bg-black/70 rounded-lg",
+                    for character in characters.iter() {
+                        button {
+                            key: "{character.id}",
+                            class: "flex items-center gap-2 px-2 py-1 bg-transparent border-none text-white text-sm text-left cursor-pointer hover:bg-white/10 rounded",
+                            onclick: {
+                                let id = character.id.clone();
+                                move |_| {
+                                    if let Some(handler) = on_click {
+                                        handler.call(id.clone());
+                                    }
+                                }
+                            },
+                            if let Some(ref portrait) = character.portrait_asset {
+                                img { src: "{portrait}", class: "w-6 h-6 rounded-full object-cover" }
+                            } else {
+                                span { class: "w-6 h-6 rounded-full bg-white/10 flex items-center justify-center text-xs", "👤" }
+                            }
+                            "{character.name}"
+                        }
+                    }
+                }
+            }
+
+            button {
+                class: "px-3 py-1 bg-black/60 border border-white/20 rounded-full text-white text-sm cursor-pointer",
+                onclick: move |_| {
+                    let next = !*expanded.read();
+                    expanded.set(next);
+                },
+                "👥 {label}"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::dto::EntityStyle;
+
+    fn character(id: &str, position: CharacterPosition, is_speaking: bool) -> SceneCharacterState {
+        SceneCharacterState {
+            id: id.to_string(),
+            name: id.to_uppercase(),
+            sprite_asset: None,
+            portrait_asset: None,
+            position,
+            is_speaking,
+            emotion: String::new(),
+            sentiment: None,
+            disposition_hidden: false,
+            style: EntityStyle::default(),
+        }
+    }
+
+    #[test]
+    fn speaker_moves_to_center_front_and_others_dim() {
+        let chars = vec![
+            character("a", CharacterPosition::Center, false),
+            character("b", CharacterPosition::Left, false),
+            character("c", CharacterPosition::Right, true),
+        ];
+        let layout = arrange_sprites(&chars, None, DEFAULT_MAX_ON_SCREEN);
+
+        let (speaker, placement) = &layout.placed[0];
+        assert_eq!(speaker.id, "c");
+        assert_eq!(placement.x_percent, 50.0);
+        assert!(placement.is_speaker && !placement.dimmed);
+
+        let others: Vec<_> = layout.placed[1..].iter().collect();
+        assert!(others.iter().all(|(_, p)| p.dimmed && p.z_index < placement.z_index));
+        // Authored order is kept: b (left) stays left of a (center)
+        let x = |id: &str| others.iter().find(|(c, _)| c.id == id).unwrap().1.x_percent;
+        assert!(x("b") < 50.0 && x("a") > 50.0);
+    }
+
+    #[test]
+    fn explicit_speaker_overrides_flags_and_no_speaker_spreads_evenly() {
+        let chars = vec![
+            character("a", CharacterPosition::Left, true),
+            character("b", CharacterPosition::Right, false),
+        ];
+        let layout = arrange_sprites(&chars, Some("b"), DEFAULT_MAX_ON_SCREEN);
+        assert_eq!(layout.placed[0].0.id, "b");
+
+        let idle = vec![
+            character("a", CharacterPosition::Left, false),
+            character("b", CharacterPosition::Right, false),
+        ];
+        let layout = arrange_sprites(&idle, None, DEFAULT_MAX_ON_SCREEN);
+        assert!(layout.placed.iter().all(|(_, p)| !p.dimmed));
+        let mid = (layout.placed[0].1.x_percent + layout.placed[1].1.x_percent) / 2.0;
+        assert_eq!(mid, 50.0);
+    }
+
+    #[test]
+    fn extra_characters_overflow_but_speaker_stays_on_screen() {
+        let mut chars: Vec<_> = (0..6)
+            .map(|i| character(&format!("c{}", i), CharacterPosition::Left, false))
+            .collect();
+        chars.push(character("speaker", CharacterPosition::OffScreen, false));
+        chars.push(character("talker", CharacterPosition::Right, true));
+
+        let layout = arrange_sprites(&chars, None, 3);
+        assert_eq!(layout.placed.len(), 3);
+        assert_eq!(layout.overflow.len(), 4);
+        assert_eq!(layout.placed[0].0.id, "talker");
+        // Off-screen characters are neither placed nor grouped
+        assert!(!layout.overflow.iter().any(|c| c.id == "speaker"));
+    }
+}
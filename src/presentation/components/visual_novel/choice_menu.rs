@@ -1,20 +1,37 @@
 //! Choice menu component for dialogue choices
 //!
-//! Displays dialogue choices and handles custom input.
+//! Displays dialogue choices and handles custom input. Given the player's
+//! character, choices whose requirements it doesn't meet are hidden or
+//! shown locked, as each choice asks.
 
 use dioxus::prelude::*;
 
 use crate::application::dto::DialogueChoice;
+use crate::application::services::{choice_availability, ChoiceAvailability, ChoiceContext};
+use crate::presentation::components::common::DecisionCountdown;
+use crate::presentation::state::perf_probe;
 
 /// Props for the ChoiceMenu component
 #[derive(Props, Clone, PartialEq)]
 pub struct ChoiceMenuProps {
     /// Available dialogue choices
     pub choices: Vec<DialogueChoice>,
+    /// The player's character; without it requirements aren't checked
+    #[props(default)]
+    pub context: Option<ChoiceContext>,
     /// Handler for when a choice is selected (receives choice ID)
     pub on_select: EventHandler<String>,
     /// Handler for custom text input
     pub on_custom_input: EventHandler<String>,
+    /// Index (among the standard choices) with the controller focus ring
+    #[props(default)]
+    pub focused: Option<usize>,
+    /// Seconds to choose before `on_timeout` fires (DM decision timer)
+    #[props(default)]
+    pub time_limit: Option<u32>,
+    /// Called once if the time limit runs out
+    #[props(default)]
+    pub on_timeout: Option<EventHandler<()>>,
 }
 
 /// Choice menu component - displays dialogue choices
@@ -23,19 +40,55 @@ pub struct ChoiceMenuProps {
 /// Includes a text input field for custom responses when available.
 #[component]
 pub fn ChoiceMenu(props: ChoiceMenuProps) -> Element {
+    perf_probe::record_render("ChoiceMenu");
     let mut custom_text = use_signal(|| String::new());
     let has_custom = props.choices.iter().any(|c| c.is_custom_input);
+    // A new set of choices restarts the countdown
+    let countdown_key = props.choices.iter().map(|c| c.id.as_str()).collect::<Vec<_>>().join(",");
+    let on_timeout = props.on_timeout;
+    let standard: Vec<(DialogueChoice, ChoiceAvailability)> = props
+        .choices
+        .iter()
+        .filter(|c| !c.is_custom_input)
+        .map(|c| {
+            let availability = props
+                .context
+                .as_ref()
+                .map_or(ChoiceAvailability::Available, |context| choice_availability(c, context));
+            (c.clone(), availability)
+        })
+        .filter(|(_, availability)| *availability != ChoiceAvailability::Hidden)
+        .collect();
 
     rsx! {
         div {
             class: "choice-menu flex flex-col gap-2 mt-4",
+            role: "group",
+            aria_label: "Dialogue choices",
+
+            if let Some(seconds) = props.time_limit {
+                DecisionCountdown {
+                    key: "{countdown_key}",
+                    seconds: seconds,
+                    on_expire: move |_| {
+                        if let Some(handler) = &on_timeout {
+                            handler.call(());
+                        }
+                    },
+                }
+            }
 
             // Standard choice buttons
-            for choice in props.choices.iter().filter(|c| !c.is_custom_input) {
+            for (index, (choice, availability)) in standard.into_iter().enumerate() {
                 ChoiceButton {
                     key: "{choice.id}",
-                    choice: choice.clone(),
+                    choice,
+                    locked: match availability {
+                        ChoiceAvailability::Locked { reasons } => Some(reasons),
+                        _ => None,
+                    },
                     on_click: props.on_select.clone(),
+                    focused: props.focused == Some(index),
                 }
             }
 
@@ -62,18 +115,36 @@ pub struct ChoiceButtonProps {
     pub choice: DialogueChoice,
     /// Click handler
     pub on_click: EventHandler<String>,
+    /// Whether the controller focus ring is on this choice
+    #[props(default = false)]
+    pub focused: bool,
+    /// Set when the character can't take the choice, with the unmet
+    /// requirements if the choice explains them
+    #[props(default)]
+    pub locked: Option<Vec<String>>,
 }
 
 /// Individual choice button
 #[component]
 pub fn ChoiceButton(props: ChoiceButtonProps) -> Element {
     let choice_id = props.choice.id.clone();
+    let focus_class = if props.focused { "ring-2 ring-amber-400" } else { "" };
+    let is_locked = props.locked.is_some();
+    let requirements = props.locked.clone().unwrap_or_default();
+    let tooltip = if requirements.is_empty() { String::new() } else { format!("Requires: {}", requirements.join(", ")) };
 
     rsx! {
         button {
-            class: "vn-choice",
+            class: if is_locked { "vn-choice {focus_class} opacity-50 cursor-not-allowed" } else { "vn-choice {focus_class}" },
+            "data-gamepad-focus": if props.focused { "true" } else { "false" },
+            disabled: is_locked,
+            aria_disabled: "{is_locked}",
+            title: "{tooltip}",
             onclick: move |_| props.on_click.call(choice_id.clone()),
 
+            if is_locked {
+                span { aria_hidden: "true", class: "mr-2", "🔒" }
+            }
             "{props.choice.text}"
         }
     }
@@ -101,6 +172,7 @@ pub fn CustomInputField(props: CustomInputFieldProps) -> Element {
                 class: "input flex-1",
                 r#type: "text",
                 placeholder: "Type your response...",
+                aria_label: "Your response",
                 value: "{value}",
                 oninput: move |e| value.set(e.value()),
                 onkeypress: move |e: KeyboardEvent| {
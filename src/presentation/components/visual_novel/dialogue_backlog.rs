@@ -0,0 +1,68 @@
+//! Dialogue backlog - lines already shown in this session
+//!
+//! Opened from the action panel's Log button or a controller's B button.
+
+use dioxus::prelude::*;
+
+use crate::presentation::state::{BacklogLine, use_modal_focus};
+
+use super::dialogue_text::DialogueText;
+
+/// Modal listing past dialogue lines, newest at the bottom
+#[component]
+pub fn DialogueBacklog(lines: Vec<BacklogLine>, on_close: EventHandler<()>) -> Element {
+    use_modal_focus("dialogue-backlog");
+
+    rsx! {
+        div {
+            class: "fixed inset-0 bg-black/85 z-[1000] flex items-center justify-center p-4",
+            onclick: move |_| on_close.call(()),
+
+            div {
+                id: "dialogue-backlog",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "dialogue-backlog-title",
+                class: "bg-gradient-to-br from-dark-surface to-dark-bg rounded-2xl w-full max-w-2xl max-h-[85vh] overflow-hidden flex flex-col shadow-2xl border border-white/10",
+                onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        on_close.call(());
+                    }
+                },
+
+                div {
+                    class: "p-4 border-b border-white/10 flex justify-between items-center",
+                    h2 { id: "dialogue-backlog-title", class: "text-xl font-bold text-white m-0", "Backlog" }
+                    button {
+                        class: "w-8 h-8 flex items-center justify-center bg-white/5 hover:bg-white/10 rounded-lg text-gray-400 hover:text-white transition-colors",
+                        onclick: move |_| on_close.call(()),
+                        "x"
+                    }
+                }
+
+                div {
+                    class: "flex-1 overflow-y-auto p-4 flex flex-col-reverse gap-3",
+
+                    if lines.is_empty() {
+                        p { class: "text-gray-500 text-sm italic text-center m-0", "Nothing has been said yet" }
+                    }
+                    // Reversed column keeps the newest line in view
+                    for (index, line) in lines.iter().enumerate().rev() {
+                        div {
+                            key: "{index}",
+                            if !line.speaker_name.is_empty() {
+                                div { class: "text-amber-400 text-xs font-semibold mb-0.5", "{line.speaker_name}" }
+                            }
+                            p {
+                                class: "text-gray-200 text-sm m-0",
+                                DialogueText { text: line.text.clone() }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
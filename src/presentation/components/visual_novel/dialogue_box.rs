@@ -4,9 +4,14 @@
 
 use dioxus::prelude::*;
 
-use crate::application::dto::DialogueChoice;
+use crate::application::dto::{DiceRoll, DialogueChoice, Disposition};
+use crate::application::services::ChoiceContext;
+use crate::presentation::components::tactical::PlayerSkillData;
+use crate::presentation::state::perf_probe;
 
 use super::choice_menu::{ChoiceMenu, ContinuePrompt};
+use super::dialogue_text::DialogueText;
+use super::disposition_meter::DispositionMeter;
 
 /// Props for the DialogueBox component
 #[derive(Props, Clone, PartialEq)]
@@ -30,6 +35,30 @@ pub struct DialogueBoxProps {
     /// Whether NPC is currently thinking (LLM processing)
     #[props(default = false)]
     pub is_llm_processing: bool,
+    /// The LLM is down and the DM is writing replies by hand
+    #[props(default = false)]
+    pub manual_dialogue: bool,
+    /// Handler for dice chips in the text (`[roll:2d6+3]`)
+    #[props(default)]
+    pub on_roll: Option<EventHandler<DiceRoll>>,
+    /// Player skills, for inspecting skill chips (`{skill:Stealth}`)
+    #[props(default)]
+    pub skills: Vec<PlayerSkillData>,
+    /// The player's character, for choices with requirements
+    #[props(default)]
+    pub choice_context: Option<ChoiceContext>,
+    /// Choice with the controller focus ring
+    #[props(default)]
+    pub focused_choice: Option<usize>,
+    /// Seconds to pick a choice (DM decision timer)
+    #[props(default)]
+    pub choice_time_limit: Option<u32>,
+    /// Called once if the choice time limit runs out
+    #[props(default)]
+    pub on_choice_timeout: Option<EventHandler<()>>,
+    /// How the speaking NPC feels about the party, unless hidden
+    #[props(default)]
+    pub speaker_disposition: Option<Disposition>,
 }
 
 /// Dialogue box component - displays dialogue with typewriter effect
@@ -37,6 +66,7 @@ pub struct DialogueBoxProps {
 /// Uses `.vn-dialogue-box`, `.vn-character-name`, `.vn-dialogue-text` Tailwind classes.
 #[component]
 pub fn DialogueBox(props: DialogueBoxProps) -> Element {
+    perf_probe::record_render("DialogueBox");
     let has_speaker = !props.speaker_name.is_empty();
     let has_choices = !props.choices.is_empty();
     let show_continue = !props.is_typing && !has_choices;
@@ -44,18 +74,26 @@ pub fn DialogueBox(props: DialogueBoxProps) -> Element {
     rsx! {
         div {
             class: "vn-dialogue-box",
+            role: "region",
+            aria_label: "Dialogue",
 
             // Speaker name plate
             if has_speaker {
                 div {
-                    class: "vn-character-name",
+                    class: "vn-character-name flex items-center gap-2",
                     "{props.speaker_name}"
+                    if let Some(disposition) = props.speaker_disposition {
+                        DispositionMeter { disposition }
+                    }
                 }
             }
 
             // Dialogue text with typewriter cursor or loading indicator
+            // Announced once the line has finished typing
             div {
                 class: "dialogue-text-container min-h-[60px]",
+                aria_live: "polite",
+                aria_busy: if props.is_typing { "true" } else { "false" },
                 onclick: move |_| {
                     if props.is_typing && !props.is_llm_processing {
                         props.on_advance.call(());
@@ -66,11 +104,16 @@ pub fn DialogueBox(props: DialogueBoxProps) -> Element {
                     p {
                         class: "vn-dialogue-text text-gray-400 italic",
 
-                        "NPC is thinking"
+                        if props.manual_dialogue {
+                            "The DM is writing a reply"
+                        } else {
+                            "NPC is thinking"
+                        }
 
                         // Animated ellipsis
                         span {
                             class: "animate-ellipsis",
+                            aria_hidden: "true",
                             "..."
                         }
                     }
@@ -78,12 +121,17 @@ pub fn DialogueBox(props: DialogueBoxProps) -> Element {
                     p {
                         class: "vn-dialogue-text",
 
-                        "{props.dialogue_text}"
+                        DialogueText {
+                            text: props.dialogue_text.clone(),
+                            on_roll: props.on_roll,
+                            skills: props.skills.clone(),
+                        }
 
                         // Blinking cursor during typing
                         if props.is_typing {
                             span {
                                 class: "typewriter-cursor animate-blink ml-0.5",
+                                aria_hidden: "true",
                                 "▌"
                             }
                         }
@@ -96,8 +144,12 @@ pub fn DialogueBox(props: DialogueBoxProps) -> Element {
                 if has_choices {
                     ChoiceMenu {
                         choices: props.choices.clone(),
+                        context: props.choice_context.clone(),
                         on_select: props.on_choice_selected,
                         on_custom_input: props.on_custom_input,
+                        focused: props.focused_choice,
+                        time_limit: props.choice_time_limit,
+                        on_timeout: props.on_choice_timeout,
                     }
                 } else if show_continue {
                     ContinuePrompt {
@@ -131,7 +183,7 @@ pub fn NarrationBox(props: NarrationBoxProps) -> Element {
             p {
                 class: "vn-dialogue-text italic text-gray-300",
 
-                "{props.text}"
+                DialogueText { text: props.text.clone() }
 
                 if props.is_typing {
                     span {
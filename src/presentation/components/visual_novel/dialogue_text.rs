@@ -0,0 +1,142 @@
+//! Dialogue text with inline dice and skill chips
+//!
+//! Renders `[roll:2d6+3]` as a chip the player can click to roll and
+//! `{skill:Stealth}` as a chip that shows their modifier for that skill.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{parse_dialogue, DiceNotation, DiceRoll, DialogueSegment};
+use crate::application::ports::outbound::Platform;
+use crate::presentation::components::tactical::PlayerSkillData;
+
+/// Props for DialogueText
+#[derive(Props, Clone, PartialEq)]
+pub struct DialogueTextProps {
+    pub text: String,
+    /// Called when the player rolls a dice chip; chips are read-only without it
+    #[props(default)]
+    pub on_roll: Option<EventHandler<DiceRoll>>,
+    /// The player's skills, for inspecting skill chips
+    #[props(default)]
+    pub skills: Vec<PlayerSkillData>,
+}
+
+/// Dialogue text with interactive markup rendered inline
+#[component]
+pub fn DialogueText(props: DialogueTextProps) -> Element {
+    let segments = parse_dialogue(&props.text);
+
+    rsx! {
+        for (index, segment) in segments.into_iter().enumerate() {
+            match segment {
+                DialogueSegment::Text(text) => rsx! { span { key: "{index}", "{text}" } },
+                DialogueSegment::Roll(notation) => rsx! {
+                    DiceChip {
+                        key: "{index}",
+                        notation: notation,
+                        on_roll: props.on_roll,
+                    }
+                },
+                DialogueSegment::Skill(name) => {
+                    let skill = props
+                        .skills
+                        .iter()
+                        .find(|s| s.name.eq_ignore_ascii_case(&name))
+                        .cloned();
+                    rsx! {
+                        SkillChip {
+                            key: "{index}",
+                            name: name,
+                            skill: skill,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Clickable dice roll; shows the total once rolled
+#[component]
+fn DiceChip(notation: DiceNotation, on_roll: Option<EventHandler<DiceRoll>>) -> Element {
+    let platform = use_context::<Platform>();
+    let mut result: Signal<Option<DiceRoll>> = use_signal(|| None);
+
+    let rolled = result.read().clone();
+    let can_roll = on_roll.is_some() && rolled.is_none();
+    let title = match &rolled {
+        Some(roll) => roll.breakdown(),
+        None if can_roll => format!("Roll {}", notation),
+        None => notation.to_string(),
+    };
+    let cursor = if can_roll { "cursor-pointer hover:bg-amber-500/30" } else { "cursor-default" };
+
+    rsx! {
+        button {
+            r#type: "button",
+            title: "{title}",
+            disabled: !can_roll,
+            onclick: move |e| {
+                // Don't let the click advance the dialogue
+                e.stop_propagation();
+                let Some(handler) = on_roll else {
+                    return;
+                };
+                let roll = notation.roll(|min, max| platform.random_range(min, max));
+                result.set(Some(roll.clone()));
+                handler.call(roll);
+            },
+            class: "inline-flex items-center gap-1 mx-0.5 px-1.5 py-0 align-baseline bg-amber-500/15 text-amber-400 border border-amber-500/60 rounded text-[0.9em] not-italic {cursor}",
+            "🎲 {notation}"
+            if let Some(roll) = rolled {
+                span { class: "font-bold text-white", "→ {roll.total}" }
+            }
+        }
+    }
+}
+
+/// Skill reference; click to see the player's modifier
+#[component]
+fn SkillChip(name: String, skill: Option<PlayerSkillData>) -> Element {
+    let mut open = use_signal(|| false);
+
+    rsx! {
+        span {
+            class: "relative inline-block",
+            button {
+                r#type: "button",
+                onclick: move |e| {
+                    e.stop_propagation();
+                    let current = *open.read();
+                    open.set(!current);
+                },
+                class: "inline-flex items-center mx-0.5 px-1.5 py-0 align-baseline bg-blue-500/15 text-blue-300 border border-blue-500/60 rounded text-[0.9em] not-italic cursor-pointer hover:bg-blue-500/30",
+                "✦ {name}"
+            }
+            if *open.read() {
+                span {
+                    class: "absolute bottom-full left-0 mb-1 z-20 w-max max-w-[220px] px-2 py-1 bg-dark-surface border border-gray-700 rounded text-xs text-gray-300 not-italic shadow-lg",
+                    match skill {
+                        Some(skill) => {
+                            let modifier = if skill.modifier >= 0 {
+                                format!("+{}", skill.modifier)
+                            } else {
+                                skill.modifier.to_string()
+                            };
+                            rsx! {
+                                span { class: "font-semibold text-white", "{skill.name} {modifier}" }
+                                if !skill.category.is_empty() {
+                                    span { class: "text-gray-500", " · {skill.category}" }
+                                }
+                                if skill.proficient {
+                                    span { class: "text-green-400", " · proficient" }
+                                }
+                            }
+                        }
+                        None => rsx! { "{name} isn't on your character sheet" },
+                    }
+                }
+            }
+        }
+    }
+}
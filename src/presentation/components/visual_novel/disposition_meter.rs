@@ -0,0 +1,27 @@
+//! Disposition meter - how an NPC feels about the party
+//!
+//! A mood icon and a row of hearts, shown above sprites and beside the
+//! speaker's name. Absent when the DM has hidden the NPC's disposition.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::{Disposition, MAX_HEARTS};
+
+/// Icon plus filled and empty hearts for a disposition
+#[component]
+pub fn DispositionMeter(disposition: Disposition) -> Element {
+    let filled = disposition.hearts();
+    let hearts: String = (0..MAX_HEARTS).map(|i| if i < filled { '♥' } else { '♡' }).collect();
+    let label = disposition.display_name();
+
+    rsx! {
+        span {
+            class: "inline-flex items-center gap-1 px-2 py-0.5 bg-black/60 rounded-full text-xs whitespace-nowrap {disposition.color_class()}",
+            role: "img",
+            aria_label: "{label} toward the party",
+            title: "{label}",
+            span { aria_hidden: "true", "{disposition.icon()}" }
+            span { aria_hidden: "true", class: "tracking-tight", "{hearts}" }
+        }
+    }
+}
@@ -2,11 +2,24 @@
 //!
 //! Components for the visual novel-style gameplay interface.
 
+pub mod ambient_crowd;
 pub mod backdrop;
+pub mod camera_frame;
 pub mod character_sprite;
 pub mod choice_menu;
+pub mod dialogue_backlog;
 pub mod dialogue_box;
+pub mod dialogue_text;
+pub mod disposition_meter;
+pub mod mood_overlay;
+pub mod stage_effects;
 
+pub use ambient_crowd::AmbientCrowd;
 pub use backdrop::Backdrop;
+pub use camera_frame::CameraFrame;
 pub use character_sprite::CharacterLayer;
+pub use dialogue_backlog::DialogueBacklog;
 pub use dialogue_box::{DialogueBox, EmptyDialogueBox};
+pub use disposition_meter::DispositionMeter;
+pub use mood_overlay::MoodOverlay;
+pub use stage_effects::StageEffects;
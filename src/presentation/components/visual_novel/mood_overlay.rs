@@ -0,0 +1,37 @@
+//! Mood overlay - the DM's lighting and weather over the backdrop
+//!
+//! Rendered as a Backdrop child below annotations and character sprites,
+//! so drawings stay readable and characters stay lit. The weather layer is
+//! a CSS animation, which reduced motion stops.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::SceneMood;
+
+/// Darkness, tint, vignette and weather layers for `mood`
+#[component]
+pub fn MoodOverlay(mood: SceneMood) -> Element {
+    if mood.is_clear() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div {
+            class: "mood-overlay absolute inset-0 pointer-events-none overflow-hidden",
+            aria_hidden: "true",
+
+            if let Some(style) = mood.darkness_style() {
+                div { class: "absolute inset-0 transition-all duration-1000", style: "{style}" }
+            }
+            if let Some(style) = mood.tint_style() {
+                div { class: "absolute inset-0 transition-all duration-1000", style: "{style}" }
+            }
+            if let Some(style) = mood.vignette_style() {
+                div { class: "absolute inset-0 transition-all duration-1000", style: "{style}" }
+            }
+            if let Some(weather_class) = mood.weather.layer_class() {
+                div { key: "{weather_class}", class: "absolute inset-0 {weather_class}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Stage effects - everything between the backdrop and the characters
+//!
+//! The background crowd, then the DM's lighting and weather, then the DM's
+//! drawings on the backdrop. Every view of the scene stacks them this way,
+//! so crowds sit under the lighting and drawings stay readable.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::AnnotationSurface;
+use crate::presentation::components::common::AnnotationOverlay;
+use crate::presentation::state::use_game_state;
+
+use super::{AmbientCrowd, MoodOverlay};
+
+/// Crowd, mood and annotation layers for the current region
+#[component]
+pub fn StageEffects() -> Element {
+    let game_state = use_game_state();
+    let region = game_state.current_region.read().clone();
+
+    rsx! {
+        if let Some(region) = region.as_ref() {
+            AmbientCrowd { region_id: region.id.clone(), population: region.population.clone() }
+        }
+
+        MoodOverlay { mood: game_state.scene_mood() }
+
+        if let Some(region) = region.as_ref() {
+            AnnotationOverlay { surface: AnnotationSurface::Backdrop { region_id: region.id.clone() } }
+        }
+    }
+}
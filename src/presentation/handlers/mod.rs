@@ -5,6 +5,7 @@
 
 pub mod session_event_handler;
 pub mod session_message_handler;
+pub mod spectator_filter;
 
 pub use session_event_handler::handle_session_event;
 
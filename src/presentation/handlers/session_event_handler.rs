@@ -5,11 +5,12 @@
 //! boundary is properly maintained.
 
 use crate::application::services::SessionEvent;
-use crate::application::ports::outbound::{ConnectionState as PortConnectionState, Platform};
-use crate::application::services::port_connection_state_to_status;
+use crate::application::ports::outbound::{ConnectionState as PortConnectionState, ParticipantRole, Platform};
+use crate::application::services::{message_scene_id, port_connection_state_to_status};
 use crate::presentation::state::{ConnectionStatus, DialogueState, GameState, GenerationState, SessionState};
-use dioxus::prelude::WritableExt;
+use dioxus::prelude::{spawn, ReadableExt, WritableExt};
 use crate::presentation::handlers::handle_server_message;
+use crate::presentation::handlers::spectator_filter::{filter_for_spectator, spectator_delay_ms};
 
 /// Process a session event and update presentation state
 ///
@@ -45,7 +46,50 @@ pub fn handle_session_event(
         }
         SessionEvent::MessageReceived(message) => {
             match serde_json::from_value::<crate::application::dto::ServerMessage>(message) {
-                Ok(msg) => handle_server_message(msg, session_state, game_state, dialogue_state, generation_state, platform),
+                Ok(msg) => {
+                    // Players in a staged scene only follow that scene. The
+                    // Engine routes each scene's traffic to its own players;
+                    // this only drops what reaches us around a change
+                    if let (Some(scene_id), Some(user_id)) = (message_scene_id(&msg), session_state.user_id().peek().as_ref()) {
+                        if !session_state.scene_staging.peek().reaches(user_id, scene_id) {
+                            return;
+                        }
+                    }
+
+                    let is_spectator = *session_state.user_role().peek() == Some(ParticipantRole::Spectator);
+                    if !is_spectator {
+                        handle_server_message(msg, session_state, game_state, dialogue_state, generation_state, platform);
+                        return;
+                    }
+
+                    let policy = *session_state.spectator_policy.peek();
+                    let Some(msg) = filter_for_spectator(msg, &policy) else {
+                        return;
+                    };
+                    let delay_ms = spectator_delay_ms(&msg, &policy);
+                    if delay_ms == 0 {
+                        handle_server_message(msg, session_state, game_state, dialogue_state, generation_state, platform);
+                        return;
+                    }
+
+                    // Hold the message back to give the spectator feed its delay
+                    let mut session_state = session_state.clone();
+                    let mut game_state = game_state.clone();
+                    let mut dialogue_state = dialogue_state.clone();
+                    let mut generation_state = *generation_state;
+                    let platform = platform.clone();
+                    spawn(async move {
+                        platform.sleep_ms(delay_ms).await;
+                        handle_server_message(
+                            msg,
+                            &mut session_state,
+                            &mut game_state,
+                            &mut dialogue_state,
+                            &mut generation_state,
+                            &platform,
+                        );
+                    });
+                }
                 Err(e) => tracing::warn!("Failed to parse server message JSON: {}", e),
             }
         }
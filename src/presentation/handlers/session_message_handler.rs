@@ -4,12 +4,15 @@
 //! presentation state mutations. Keeping this here avoids application→presentation
 //! dependencies and keeps the WebSocket transport parsing separate from UI state.
 
-use crate::application::ports::outbound::Platform;
+use crate::application::ports::outbound::{Capability, ParticipantRole, Platform, PresenceMode};
 use crate::application::dto::{ProposedTool, ServerMessage, SessionWorldSnapshot};
+use crate::application::services::{
+    is_llm_error, recheck_manual_dice, CapturedChallenge, IndexedLine, IndexedRoll, RollRecord, SessionRoll,
+};
 use dioxus::prelude::{ReadableExt, WritableExt};
 use crate::presentation::state::{
-    DialogueState, GameState, GenerationState, PendingApproval, SessionState,
-    session_state::{ChallengePromptData, ChallengeResultData},
+    perf_probe, template_context, DialogueState, GameState, GenerationState, PendingApproval, SessionState, WhisperMessage,
+    session_state::{ChallengePromptData, ChallengeResultData, PendingLevelUp},
     approval_state::PendingChallengeOutcome,
 };
 
@@ -22,16 +25,78 @@ pub fn handle_server_message(
     generation_state: &mut GenerationState,
     platform: &Platform,
 ) {
+    perf_probe::record_message(platform.now_millis());
     match message {
         ServerMessage::SessionJoined {
             session_id,
             role,
-            participants: _,
+            participants,
             world_snapshot,
+            spectator_policy,
+            protocol_version,
+            pending_level_up,
+            decision_timer,
+            conditions,
+            annotations,
+            triggered_events,
+            staged_scenes,
+            session_rolls,
+            directorial_context,
         } => {
             tracing::info!("SessionJoined received");
 
             session_state.set_session_joined(session_id.clone());
+            // A reconnecting player is back in their scene, the DM back to the split
+            session_state.scene_staging.write().set_scenes(staged_scenes);
+            // Rolls from before a reload, or made while we were away
+            session_state.session_dice().write().seed(&session_rolls);
+            session_state.directorial_context.set(directorial_context);
+            session_state.spectator_policy.set(spectator_policy);
+            session_state.decision_timer.set(decision_timer);
+            session_state.conditions.set(conditions.into_iter().filter(|(_, active)| !active.is_empty()).collect());
+            game_state.annotations.set(
+                annotations
+                    .into_iter()
+                    .map(|drawn| (drawn.surface.key(), drawn.annotations))
+                    .collect(),
+            );
+            // Choices unlocked by events from earlier sessions stay unlocked
+            game_state.triggered_events.set(triggered_events.into_iter().collect());
+
+            session_state.engine_health().write().set_engine_protocol(protocol_version);
+            if let Some(warning) = session_state.engine_health().peek().protocol().warning() {
+                tracing::warn!("{}", warning);
+                session_state.add_log_entry("System".to_string(), warning, true, platform);
+            }
+
+            let local_user_id = session_state.user_id().read().clone();
+            // The Engine may have assigned a different role than we asked for
+            if let Some(user_id) = local_user_id.clone() {
+                session_state.set_participant_role(user_id, role.into());
+            }
+            // A reconnecting player picks up the level-up they hadn't taken
+            let is_dm_view = session_state.user_role().read().is_some_and(|r| r.is_dm());
+            if !is_dm_view {
+                session_state.pending_level_up.set(pending_level_up.map(|pending| {
+                    session_state.party.set_level(&pending.pc_id, pending.level, pending.pending_levels);
+                    PendingLevelUp {
+                        pc_id: pending.pc_id,
+                        pc_name: pending.pc_name,
+                        level: pending.level,
+                        pending_levels: pending.pending_levels,
+                    }
+                }));
+            }
+            for participant in participants {
+                if Some(&participant.user_id) != local_user_id.as_ref() {
+                    session_state.set_participant_role(participant.user_id.clone(), participant.role.into());
+                    session_state.presence.upsert_participant(
+                        participant.user_id,
+                        participant.character_name,
+                        participant.presence_mode,
+                    );
+                }
+            }
             session_state.add_log_entry(
                 "System".to_string(),
                 format!("Joined session: {}", session_id),
@@ -77,6 +142,10 @@ pub fn handle_server_message(
                                         position: crate::application::dto::websocket_messages::CharacterPosition::Center,
                                         is_speaking: false,
                                         emotion: String::new(),
+                                        // Arrives with the Engine's scene update
+                                        sentiment: None,
+                                        disposition_hidden: false,
+                                        style: c.style.clone(),
                                     }
                                 })
                             })
@@ -107,6 +176,12 @@ pub fn handle_server_message(
             character_name,
         } => {
             tracing::info!("Player joined: {} as {:?}", user_id, role);
+            session_state.set_participant_role(user_id.clone(), role.into());
+            session_state.presence.upsert_participant(
+                user_id.clone(),
+                character_name.clone(),
+                PresenceMode::Off,
+            );
             session_state.add_log_entry(
                 "System".to_string(),
                 format!(
@@ -124,6 +199,9 @@ pub fn handle_server_message(
 
         ServerMessage::PlayerLeft { user_id } => {
             tracing::info!("Player left: {}", user_id);
+            session_state.end_reconnect_grace(&user_id);
+            session_state.presence.remove_participant(&user_id);
+            session_state.remove_participant_role(&user_id);
             session_state.add_log_entry(
                 "System".to_string(),
                 format!("Player {} left", user_id),
@@ -132,6 +210,40 @@ pub fn handle_server_message(
             );
         }
 
+        ServerMessage::PlayerDisconnected {
+            user_id,
+            pc_id,
+            grace_secs,
+        } => {
+            tracing::info!("Player disconnected: {} ({}s grace)", user_id, grace_secs);
+            session_state.mark_reconnecting(user_id.clone(), pc_id, grace_secs, platform);
+            session_state.add_log_entry(
+                "System".to_string(),
+                format!("Player {} disconnected; holding their character for {}s", user_id, grace_secs),
+                true,
+                platform,
+            );
+        }
+
+        ServerMessage::PlayerReconnected {
+            user_id,
+            delivered_messages,
+        } => {
+            tracing::info!("Player reconnected: {}", user_id);
+            session_state.end_reconnect_grace(&user_id);
+            let delivered = match delivered_messages {
+                0 => String::new(),
+                1 => " (1 queued message delivered)".to_string(),
+                n => format!(" ({} queued messages delivered)", n),
+            };
+            session_state.add_log_entry(
+                "System".to_string(),
+                format!("Player {} reconnected{}", user_id, delivered),
+                true,
+                platform,
+            );
+        }
+
         ServerMessage::ActionReceived {
             action_id,
             player_id,
@@ -144,6 +256,7 @@ pub fn handle_server_message(
                 true,
                 platform,
             );
+            session_state.action_received(&action_id, &player_id);
         }
 
         ServerMessage::SceneUpdate {
@@ -152,6 +265,23 @@ pub fn handle_server_message(
             interactions,
         } => {
             tracing::info!("SceneUpdate: {}", scene.name);
+            // While the party is split the DM hears from every scene but
+            // only shows the one being directed
+            if session_state.can(Capability::DirectScene) {
+                let staging = session_state.scene_staging.peek();
+                if staging.scenes().iter().any(|s| s.scene_id == scene.id) {
+                    let directed = staging.focused().is_some_and(|f| f.scene_id == scene.id);
+                    drop(staging);
+                    game_state.cache_staged_scene(scene.clone(), characters.clone(), interactions.clone());
+                    if !directed {
+                        return;
+                    }
+                }
+            }
+            let now = platform.now_unix_secs();
+            session_state.capture_story(|capture| {
+                capture.record_scene(&scene.id, &scene.name, &scene.location_id, now);
+            });
             game_state.apply_scene_update(scene, characters, interactions);
         }
 
@@ -160,14 +290,29 @@ pub fn handle_server_message(
             speaker_name,
             text,
             choices,
+            ..
         } => {
             // Add to conversation log for DM view
             session_state.add_log_entry(speaker_name.clone(), text.clone(), false, platform);
+            session_state.conversation_index.write().record_line(IndexedLine {
+                speaker_id: speaker_id.clone(),
+                speaker_name: speaker_name.clone(),
+                text: text.clone(),
+                timestamp: platform.now_unix_secs(),
+            });
+            let text = template_context(game_state, session_state).resolve(&text);
             dialogue_state.apply_dialogue(speaker_id, speaker_name, text, choices);
+            session_state.engine_health().write().llm_responded();
         }
 
         ServerMessage::LLMProcessing { action_id } => {
             dialogue_state.is_llm_processing.set(true);
+            // Only the DM sees generation end (ApprovalRequired); for players
+            // the wait also covers the DM's review, so it isn't timed
+            let is_dm_view = session_state.user_role().read().is_some_and(|r| r.is_dm());
+            if is_dm_view {
+                session_state.engine_health().write().llm_request_started(platform.now_millis());
+            }
             session_state.add_log_entry(
                 "System".to_string(),
                 format!("Processing action: {}", action_id),
@@ -184,7 +329,14 @@ pub fn handle_server_message(
             proposed_tools,
             challenge_suggestion,
             narrative_event_suggestion,
+            pc_id,
         } => {
+            // An approval with nothing proposed means the LLM produced nothing
+            if proposed_dialogue.trim().is_empty() {
+                session_state.engine_health().write().llm_failed();
+            } else {
+                session_state.engine_health().write().llm_responded();
+            }
             session_state.add_pending_approval(PendingApproval {
                 request_id,
                 npc_name,
@@ -193,6 +345,7 @@ pub fn handle_server_message(
                 proposed_tools,
                 challenge_suggestion,
                 narrative_event_suggestion,
+                pc_id,
             });
         }
 
@@ -206,10 +359,16 @@ pub fn handle_server_message(
         ServerMessage::Error { code, message } => {
             let error_msg = format!("Server error [{}]: {}", code, message);
             tracing::error!("{}", error_msg);
+            if is_llm_error(&code, &message) {
+                session_state.engine_health().write().llm_failed();
+            }
             session_state.error_message().set(Some(error_msg));
+            session_state.settle_action();
         }
 
-        ServerMessage::Pong => {}
+        ServerMessage::Pong => {
+            session_state.engine_health().write().pong_received(platform.now_millis());
+        }
 
         // Generation events (Creator Mode)
         ServerMessage::GenerationQueued {
@@ -218,6 +377,7 @@ pub fn handle_server_message(
             entity_id,
             asset_type,
             position,
+            params,
         } => {
             tracing::info!(
                 "Generation queued: {} {} ({}) at position {}",
@@ -232,17 +392,19 @@ pub fn handle_server_message(
                 entity_id,
                 asset_type,
                 position,
+                params,
+                platform.now_unix_secs(),
             );
         }
 
         ServerMessage::GenerationProgress { batch_id, progress } => {
             tracing::info!("Generation progress: {} at {}%", batch_id, progress);
-            generation_state.batch_progress(&batch_id, progress);
+            generation_state.batch_progress(&batch_id, progress, platform.now_unix_secs());
         }
 
         ServerMessage::GenerationComplete { batch_id, asset_count } => {
             tracing::info!("Generation complete: {} ({} assets)", batch_id, asset_count);
-            generation_state.batch_complete(&batch_id, asset_count);
+            generation_state.batch_complete(&batch_id, asset_count, platform.now_unix_secs());
         }
 
         ServerMessage::GenerationFailed { batch_id, error } => {
@@ -264,16 +426,24 @@ pub fn handle_server_message(
             generation_state.suggestion_progress(&request_id, &status);
         }
 
+        ServerMessage::SuggestionChunk { request_id, index, delta } => {
+            generation_state.suggestion_chunk(&request_id, index, &delta);
+        }
+
         ServerMessage::SuggestionComplete {
             request_id,
             suggestions,
         } => {
             tracing::info!("Suggestion complete: {} ({} suggestions)", request_id, suggestions.len());
+            session_state.engine_health().write().llm_responded();
             generation_state.suggestion_complete(&request_id, suggestions);
         }
 
         ServerMessage::SuggestionFailed { request_id, error } => {
             tracing::error!("Suggestion failed: {} - {}", request_id, error);
+            if is_llm_error("", &error) {
+                session_state.engine_health().write().llm_failed();
+            }
             generation_state.suggestion_failed(&request_id, error);
         }
 
@@ -283,6 +453,7 @@ pub fn handle_server_message(
             retry_in_seconds,
         } => {
             tracing::info!("ComfyUI state changed: {} - {:?}", state, message);
+            session_state.engine_health().write().set_comfyui_state(&state, message.clone());
             session_state.comfyui_state().set(state);
             session_state.comfyui_message().set(message);
             session_state.comfyui_retry_in_seconds().set(retry_in_seconds);
@@ -322,13 +493,23 @@ pub fn handle_server_message(
             outcome_description,
             roll_breakdown,
             individual_rolls,
+            manual_entry,
+            audio_cue,
+            camera_effect,
         } => {
-            // Clear active challenge if it matches
+            if let Some(cue) = audio_cue {
+                game_state.play_audio_cue(cue);
+            }
+            if let Some(effect) = camera_effect {
+                game_state.play_camera_effect(platform.now_millis(), effect);
+            }
+
+            // Clear active challenge if it matches; a matching prompt
+            // means this was the player's own roll
             let active = { session_state.active_challenge().read().clone() };
-            if let Some(active_challenge) = active {
-                if active_challenge.challenge_id == challenge_id {
-                    session_state.clear_active_challenge();
-                }
+            let own_prompt = active.filter(|a| a.challenge_id == challenge_id);
+            if own_prompt.is_some() {
+                session_state.clear_active_challenge();
             }
 
             let timestamp = platform.now_unix_secs();
@@ -345,6 +526,83 @@ pub fn handle_server_message(
                 individual_rolls: individual_rolls.clone(),
             };
             
+            session_state.capture_story(|capture| {
+                let captured = CapturedChallenge {
+                    challenge_id: &challenge_id,
+                    challenge_name: &challenge_name,
+                    character_name: &character_name,
+                    roll,
+                    modifier,
+                    outcome: &outcome,
+                    outcome_description: &outcome_description,
+                };
+                capture.record_challenge_result(&captured, timestamp);
+            });
+            session_state.conversation_index.write().record_roll(IndexedRoll {
+                challenge_id: challenge_id.clone(),
+                challenge_name: challenge_name.clone(),
+                character_name: character_name.clone(),
+                roll,
+                modifier,
+                total,
+                outcome: outcome.clone(),
+                outcome_description: outcome_description.clone(),
+                roll_breakdown: roll_breakdown.clone(),
+                timestamp,
+            });
+            let session_roll = SessionRoll {
+                character_name: character_name.clone(),
+                challenge_name: challenge_name.clone(),
+                dice: None,
+                faces: Vec::new(),
+                modifier,
+                total,
+                outcome: outcome.clone(),
+                manual: false,
+                timestamp,
+            }
+            .with_dice(roll, roll_breakdown.as_deref(), individual_rolls.as_deref(), manual_entry.as_ref());
+            session_state.session_dice().write().record(session_roll);
+
+            let world_id = game_state.world.peek().as_ref().map(|w| w.world.id.clone());
+            let is_player = *session_state.user_role().peek() == Some(ParticipantRole::Player);
+            if let (Some(prompt), Some(world_id), true) = (own_prompt, world_id, is_player) {
+                let record = RollRecord {
+                    challenge_name: challenge_name.clone(),
+                    character_name: character_name.clone(),
+                    skill_name: Some(prompt.skill_name).filter(|s| !s.is_empty()),
+                    roll,
+                    modifier,
+                    total,
+                    outcome: outcome.clone(),
+                    outcome_description: outcome_description.clone(),
+                    timestamp,
+                    session_id: session_state.session_id().peek().clone(),
+                };
+                session_state.record_roll(platform, &world_id, record);
+            }
+
+            let signed_modifier = if modifier >= 0 { format!("+{}", modifier) } else { modifier.to_string() };
+            // Physical dice are marked so the DM knows the roll wasn't digital
+            let manual_note = match &manual_entry {
+                Some(entry) if entry.flags.is_empty() => format!(" [manual {}]", entry.summary()),
+                Some(entry) => format!(" [manual {}; ⚠ {}]", entry.summary(), entry.flags.join("; ")),
+                None => String::new(),
+            };
+            session_state.add_challenge_log_entry(
+                format!(
+                    "{} - {}: {} {} = {} ({}){}",
+                    character_name,
+                    challenge_name,
+                    roll,
+                    signed_modifier,
+                    total,
+                    outcome.replace('_', " "),
+                    manual_note
+                ),
+                platform,
+            );
+
             // Add to history
             session_state.add_challenge_result(result.clone());
             
@@ -353,11 +611,22 @@ pub fn handle_server_message(
         }
 
         ServerMessage::NarrativeEventTriggered {
-            event_id: _,
+            event_id,
             event_name,
             outcome_description,
             scene_direction,
+            audio_cue,
+            camera_effect,
         } => {
+            // Dialogue choices can require an event to have happened
+            game_state.triggered_events.write().insert(event_id);
+            if let Some(cue) = audio_cue {
+                game_state.play_audio_cue(cue);
+            }
+            if let Some(effect) = camera_effect {
+                game_state.play_camera_effect(platform.now_millis(), effect);
+            }
+
             // Log the narrative event trigger for DMs
             tracing::info!(
                 "Narrative event '{}' triggered: {} ({})",
@@ -467,14 +736,7 @@ pub fn handle_server_message(
                 "[AD-HOC CHALLENGE] '{}' created for PC {} (ID: {})",
                 challenge_name, target_pc_id, challenge_id
             );
-            session_state.conversation_log().write().push(
-                crate::presentation::state::ConversationLogEntry {
-                    speaker: "System".to_string(),
-                    text: msg,
-                    is_system: true,
-                    timestamp: platform.now_unix_secs(),
-                },
-            );
+            session_state.add_challenge_log_entry(msg, platform);
         }
 
         // P3.3/P3.4: Player's roll is awaiting DM approval
@@ -511,7 +773,14 @@ pub fn handle_server_message(
             outcome_description,
             outcome_triggers,
             roll_breakdown,
+            difficulty_class,
+            manual_entry,
         } => {
+            // The auto-resolve rules let unflagged entries through
+            let manual_entry = manual_entry.map(|mut entry| {
+                entry.flags = recheck_manual_dice(&entry);
+                entry
+            });
             tracing::info!(
                 "Challenge outcome pending: {} for {} ({} + {} = {})",
                 challenge_name,
@@ -534,12 +803,16 @@ pub fn handle_server_message(
                 outcome_description,
                 outcome_triggers,
                 roll_breakdown,
+                manual_entry,
                 suggestions: None,
                 branches: None,
                 is_generating_suggestions: false,
                 timestamp,
             };
-            session_state.add_pending_challenge_outcome(pending);
+            // Clear successes skip the queue when the world's rules allow it
+            if !session_state.auto_apply_challenge_outcome(&pending, difficulty_class, platform) {
+                session_state.add_pending_challenge_outcome(pending);
+            }
         }
 
         // P3.3/P3.4: LLM suggestions ready for challenge outcome (DM only)
@@ -552,6 +825,7 @@ pub fn handle_server_message(
                 resolution_id,
                 suggestions.len()
             );
+            session_state.engine_health().write().llm_responded();
             session_state.update_challenge_suggestions(&resolution_id, suggestions);
         }
 
@@ -567,6 +841,7 @@ pub fn handle_server_message(
                 outcome_type,
                 branches.len()
             );
+            session_state.engine_health().write().llm_responded();
             session_state.update_challenge_branches(&resolution_id, outcome_type, branches);
         }
 
@@ -581,6 +856,8 @@ pub fn handle_server_message(
             description,
         } => {
             tracing::info!("NPC approach event: {} ({})", npc_name, npc_id);
+            let now = platform.now_unix_secs();
+            session_state.capture_story(|capture| capture.record_npc_seen(&npc_id, &npc_name, now));
             
             // Add to log
             session_state.add_log_entry(
@@ -656,7 +933,7 @@ pub fn handle_server_message(
                 true,
                 platform,
             );
-            // TODO (Phase 23 Player UI): Update selected PC state
+            session_state.party.set_location(&pc_id, location_id);
         }
 
         ServerMessage::SceneChanged {
@@ -675,6 +952,17 @@ pub fn handle_server_message(
                 navigation.exits.len()
             );
             
+            let now = platform.now_unix_secs();
+            session_state.capture_story(|capture| {
+                capture.record_region(&pc_id, &region.id, &region.name, &region.location_id, &region.location_name, now);
+                for npc in &npcs_present {
+                    capture.record_npc_seen(&npc.character_id, &npc.name, now);
+                }
+            });
+
+            session_state.party.set_region(&pc_id, region.location_id.clone(), region.name.clone());
+            game_state.mark_region_visited(&pc_id, &region.id);
+
             // Update game state with navigation data
             game_state.apply_scene_changed(
                 pc_id.clone(),
@@ -731,6 +1019,396 @@ pub fn handle_server_message(
                 platform,
             );
         }
+
+        // =========================================================================
+        // Presence (webcam / avatar tiles)
+        // =========================================================================
+
+        ServerMessage::PresenceUpdated { user_id, mode } => {
+            tracing::debug!("Presence updated: {} -> {:?}", user_id, mode);
+            if session_state.user_id().read().as_deref() == Some(user_id.as_str()) {
+                session_state.presence.local_mode.set(mode);
+            } else {
+                session_state.presence.upsert_participant(user_id, None, mode);
+            }
+        }
+
+        ServerMessage::PresenceSignal { from_user_id, signal } => {
+            session_state.presence.queue_signal(from_user_id, signal);
+        }
+
+        // =========================================================================
+        // Session Pause
+        // =========================================================================
+
+        ServerMessage::SessionPaused { message, art_asset } => {
+            tracing::info!("Session paused");
+            session_state.add_log_entry(
+                "System".to_string(),
+                match &message {
+                    Some(m) => format!("Session paused: {}", m),
+                    None => "Session paused".to_string(),
+                },
+                true,
+                platform,
+            );
+            game_state.set_intermission(message, art_asset);
+        }
+
+        ServerMessage::SessionResumed => {
+            tracing::info!("Session resumed");
+            game_state.clear_intermission();
+            session_state.add_log_entry(
+                "System".to_string(),
+                "Session resumed".to_string(),
+                true,
+                platform,
+            );
+        }
+
+        ServerMessage::ParticipantRoleChanged { user_id, role } => {
+            tracing::info!("Participant {} is now {:?}", user_id, role);
+            let role: ParticipantRole = role.into();
+            session_state.set_participant_role(user_id.clone(), role);
+            session_state.add_log_entry(
+                "System".to_string(),
+                format!("{} is now {}", user_id, role.display_name()),
+                true,
+                platform,
+            );
+        }
+
+        ServerMessage::Whisper {
+            whisper_id,
+            player_user_id,
+            player_name,
+            text,
+            from_dm,
+        } => {
+            // Our own messages come back as echoes and are already read
+            let is_dm_view = session_state.user_role().read().is_some_and(|r| r.is_dm());
+            let unread = from_dm != is_dm_view;
+            session_state.whispers.add_whisper(
+                WhisperMessage {
+                    id: whisper_id,
+                    player_user_id,
+                    player_name,
+                    text,
+                    from_dm,
+                    timestamp: platform.now_unix_secs(),
+                },
+                unread,
+            );
+        }
+
+        ServerMessage::SpectatorPolicyChanged { policy } => {
+            tracing::info!("Spectator policy changed: {:?}", policy);
+            session_state.spectator_policy.set(policy);
+        }
+
+        ServerMessage::StagedScenesChanged { scenes } => {
+            tracing::info!("{} scene(s) staged", scenes.len());
+            let mut staging = session_state.scene_staging.write();
+            let before = staging.focused().map(|s| s.scene_id.clone());
+            staging.set_scenes(scenes);
+            let after = staging.focused().map(|s| s.scene_id.clone());
+            drop(staging);
+            // The directed scene was unstaged; follow the focus to the next one
+            if session_state.can(Capability::DirectScene) && before != after {
+                if let Some(scene_id) = after {
+                    game_state.show_staged_scene(&scene_id);
+                }
+            }
+        }
+
+        ServerMessage::AsyncPlayChanged { enabled } => {
+            if *session_state.async_play.enabled.peek() != enabled {
+                let text = if enabled {
+                    "Play-by-post on: actions wait for the DM while they're away"
+                } else {
+                    "Play-by-post off"
+                };
+                session_state.add_log_entry("System".to_string(), text.to_string(), true, platform);
+            }
+            session_state.async_play.enabled.set(enabled);
+        }
+
+        ServerMessage::ActionPosted { action_id } => {
+            tracing::info!("Action {} posted for the DM", action_id);
+            let world_id = game_state.world.peek().as_ref().map(|w| w.world.id.clone());
+            match world_id {
+                Some(world_id) => session_state.action_posted(&action_id, platform, &world_id),
+                None => session_state.settle_action(),
+            }
+        }
+
+        ServerMessage::ActionInbox { actions } => {
+            tracing::info!("{} action(s) in the inbox", actions.len());
+            session_state.async_play.inbox.set(actions);
+        }
+
+        ServerMessage::PostedActionAnswered { action_id } => {
+            let Some(world_id) = game_state.world.peek().as_ref().map(|w| w.world.id.clone()) else {
+                return;
+            };
+            let mut answered = false;
+            session_state
+                .async_play
+                .update_posted(platform, &world_id, |posted| answered = posted.answer(&action_id));
+            if answered {
+                session_state.add_log_entry(
+                    "System".to_string(),
+                    "The DM answered one of your posted actions".to_string(),
+                    true,
+                    platform,
+                );
+            }
+        }
+
+        ServerMessage::AdvancementGranted {
+            pc_id,
+            pc_name,
+            grant,
+            experience,
+            level,
+            pending_levels,
+        } => {
+            session_state.add_log_entry(
+                "System".to_string(),
+                format!("{} received {} ({} XP total)", pc_name, grant.describe(), experience),
+                true,
+                platform,
+            );
+            session_state.party.set_level(&pc_id, level, pending_levels);
+            // Players only receive grants for their own character
+            let is_dm_view = session_state.user_role().read().is_some_and(|r| r.is_dm());
+            if !is_dm_view && pending_levels > 0 {
+                session_state.pending_level_up.set(Some(PendingLevelUp {
+                    pc_id,
+                    pc_name,
+                    level,
+                    pending_levels,
+                }));
+            }
+        }
+
+        ServerMessage::LevelUpApplied {
+            pc_id,
+            pc_name,
+            level,
+            pending_levels,
+        } => {
+            session_state.add_log_entry(
+                "System".to_string(),
+                format!("{} reached level {}", pc_name, level),
+                true,
+                platform,
+            );
+            session_state.party.set_level(&pc_id, level, pending_levels);
+            let is_dm_view = session_state.user_role().read().is_some_and(|r| r.is_dm());
+            if !is_dm_view {
+                let next = (pending_levels > 0).then_some(PendingLevelUp {
+                    pc_id,
+                    pc_name,
+                    level,
+                    pending_levels,
+                });
+                session_state.pending_level_up.set(next);
+            }
+        }
+
+        ServerMessage::AnnotationsUpdated { surface, annotations } => {
+            tracing::debug!("Annotations updated on {}: {} marks", surface.key(), annotations.annotations.len());
+            game_state.set_annotations(&surface, annotations);
+        }
+
+        ServerMessage::DirectorialContextChanged { context } => {
+            tracing::debug!("Directorial context changed: {}", context.tone);
+            session_state.directorial_context.set(Some(context));
+        }
+
+        ServerMessage::SceneMoodChanged { region_id, mood } => {
+            tracing::debug!("Mood of region {} changed: {}", region_id, mood.summary());
+            game_state.set_region_mood(&region_id, mood);
+        }
+
+        ServerMessage::RegionPopulationChanged { region_id, population } => {
+            tracing::debug!("Population of region {} changed: {}", region_id, population.label());
+            game_state.set_region_population(&region_id, population);
+        }
+
+        ServerMessage::CameraEffectPlayed { effect } => {
+            tracing::debug!("Camera effect: {}", effect.display_name());
+            game_state.play_camera_effect(platform.now_millis(), effect);
+        }
+
+        ServerMessage::RegionBackdropChanged { region_id, backdrop_asset } => {
+            tracing::debug!("Backdrop of region {} changed", region_id);
+            game_state.set_region_backdrop(&region_id, backdrop_asset);
+        }
+
+        ServerMessage::MapRevealsChanged { reveals } => {
+            tracing::debug!("Map reveals updated: {} party-wide", reveals.party.len());
+            game_state.map_reveals.set(reveals);
+        }
+
+        ServerMessage::NpcMemories { npc_id, memories } => {
+            tracing::debug!("NPC {} has {} memories", npc_id, memories.len());
+            game_state.set_npc_memories(&npc_id, memories);
+        }
+
+        ServerMessage::DispositionChanged { npc_id, sentiment, hidden } => {
+            tracing::debug!("Disposition of {} changed (hidden: {})", npc_id, hidden);
+            game_state.set_character_disposition(&npc_id, sentiment, hidden);
+        }
+
+        ServerMessage::SharedNoteUpdated { doc, note } => {
+            tracing::debug!("Shared note {} at revision {}", doc.key(), note.revision);
+            let own_user_id = session_state.user_id().peek().clone();
+            game_state.apply_shared_note(&doc, note, own_user_id.as_deref());
+        }
+
+        ServerMessage::SharedNotePresence { doc, cursors } => {
+            let own_user_id = session_state.user_id().peek().clone();
+            let others = cursors.into_iter().filter(|c| Some(&c.user_id) != own_user_id.as_ref()).collect();
+            game_state.note_presence.write().insert(doc.key(), others);
+        }
+
+        ServerMessage::EncounterStarted {
+            encounter_id,
+            encounter_name,
+        } => {
+            tracing::info!("Encounter '{}' ({}) staged", encounter_name, encounter_id);
+            session_state.add_log_entry(
+                "System".to_string(),
+                format!("Encounter started: {}", encounter_name),
+                true,
+                platform,
+            );
+        }
+
+        ServerMessage::CraftResolved { outcome } => {
+            tracing::info!("Craft '{}' by {} resolved: {}", outcome.recipe_name, outcome.pc_name, outcome.success);
+            session_state.add_log_entry("System".to_string(), outcome.summary(), true, platform);
+            game_state.craft_rejection.set(None);
+            game_state.craft_outcome.set(Some(outcome));
+        }
+
+        ServerMessage::CraftRejected { recipe_id, reason } => {
+            tracing::info!("Craft attempt at recipe {} refused: {}", recipe_id, reason);
+            game_state.craft_rejection.set(Some(reason));
+        }
+
+        ServerMessage::ItemIdentified { pc_id, item_id, item_name } => {
+            tracing::info!("Item {} identified for PC {}", item_id, pc_id);
+            session_state.add_log_entry("System".to_string(), format!("{} identified", item_name), true, platform);
+            game_state.identified_item.set(Some(item_id));
+        }
+
+        ServerMessage::RecapPlaying { recap } => {
+            tracing::info!("Recap '{}' playing ({} cards)", recap.title, recap.cards.len());
+            game_state.recap.set(Some(recap));
+        }
+
+        ServerMessage::ConditionsChanged {
+            character_id,
+            character_name,
+            conditions,
+        } => {
+            let previous = session_state.conditions_for(&character_id);
+            let gained: Vec<&str> = conditions
+                .iter()
+                .filter(|c| !previous.iter().any(|p| p.id == c.id))
+                .map(|c| c.name.as_str())
+                .collect();
+            let lost: Vec<&str> = previous
+                .iter()
+                .filter(|p| !conditions.iter().any(|c| c.id == p.id))
+                .map(|p| p.name.as_str())
+                .collect();
+            if !gained.is_empty() {
+                session_state.add_log_entry(
+                    "System".to_string(),
+                    format!("{} is now {}", character_name, gained.join(", ")),
+                    true,
+                    platform,
+                );
+            }
+            if !lost.is_empty() {
+                session_state.add_log_entry(
+                    "System".to_string(),
+                    format!("{} is no longer {}", character_name, lost.join(", ")),
+                    true,
+                    platform,
+                );
+            }
+            session_state.set_conditions(character_id, conditions);
+        }
+
+        ServerMessage::PcSheetUpdated { pc_id, values } => {
+            tracing::debug!("Sheet updated for PC {}: {} fields", pc_id, values.len());
+            session_state.party.update_sheet(&pc_id, values);
+        }
+
+        ServerMessage::WorldSnapshotUpdated { world_snapshot } => {
+            let snapshot = match serde_json::from_value::<SessionWorldSnapshot>(world_snapshot) {
+                Ok(snapshot) => snapshot,
+                Err(e) => {
+                    tracing::error!("Failed to parse updated world snapshot: {}", e);
+                    return;
+                }
+            };
+            // Players take the new world as-is; the DM reviews the changes first
+            let is_dm_view = session_state.user_role().read().is_some_and(|r| r.is_dm());
+            if !is_dm_view {
+                game_state.load_world(snapshot);
+                return;
+            }
+            if let Some(diff) = game_state.stage_world_update(snapshot) {
+                session_state.add_log_entry(
+                    "System".to_string(),
+                    format!("World updated: {} change(s) waiting for review", diff.len()),
+                    true,
+                    platform,
+                );
+            }
+        }
+
+        ServerMessage::DialoguePacingChanged { pacing } => {
+            dialogue_state.set_pacing(pacing);
+        }
+
+        ServerMessage::DialogueReadUpdated { finished_user_ids } => {
+            dialogue_state.finished_readers.set(finished_user_ids);
+        }
+
+        ServerMessage::DialogueAdvanced => {
+            dialogue_state.release();
+        }
+
+        ServerMessage::DecisionTimerChanged { timer } => {
+            tracing::info!("Decision timer changed: {:?}", timer);
+            session_state.decision_timer.set(timer);
+        }
+
+        ServerMessage::DecisionTimedOut {
+            user_id,
+            character_name,
+            decision,
+            defaulted,
+        } => {
+            let who = character_name.unwrap_or(user_id);
+            let outcome = if defaulted { "default action taken" } else { "waiting on the player" };
+            session_state.add_log_entry(
+                "System".to_string(),
+                format!("{} ran out of time on their {} ({})", who, decision.describe(), outcome),
+                true,
+                platform,
+            );
+        }
+
+        // Dropped while decoding; see decode_server_message
+        ServerMessage::Unknown => {}
     }
 }
 
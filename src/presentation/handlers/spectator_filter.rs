@@ -0,0 +1,209 @@
+//! Spectator feed filtering
+//!
+//! Applies the DM's [`SpectatorPolicy`] to server messages before a spectator
+//! client turns them into state. The Engine is expected to filter as well;
+//! this keeps older Engines (and anything they let through) in line.
+
+use crate::application::dto::{ServerMessage, SpectatorPolicy};
+
+/// Apply `policy` to a message bound for a spectator
+///
+/// Returns `None` when the message must not be shown at all. A
+/// `SessionJoined` message is filtered with the policy it carries.
+pub fn filter_for_spectator(message: ServerMessage, policy: &SpectatorPolicy) -> Option<ServerMessage> {
+    let policy = match &message {
+        ServerMessage::SessionJoined { spectator_policy, .. } => *spectator_policy,
+        _ => *policy,
+    };
+
+    match message {
+        ServerMessage::Whisper { .. } if policy.hide_whispers => None,
+
+        ServerMessage::ChallengeResolved {
+            challenge_id,
+            challenge_name,
+            character_name,
+            outcome,
+            outcome_description,
+            audio_cue,
+            camera_effect,
+            ..
+        } if policy.hide_challenge_numbers => Some(ServerMessage::ChallengeResolved {
+            challenge_id,
+            challenge_name,
+            character_name,
+            roll: 0,
+            modifier: 0,
+            total: 0,
+            outcome,
+            outcome_description,
+            roll_breakdown: None,
+            individual_rolls: None,
+            manual_entry: None,
+            audio_cue,
+            camera_effect,
+        }),
+
+        ServerMessage::SceneUpdate {
+            mut scene,
+            characters,
+            interactions,
+        } if policy.hide_dm_notes => {
+            scene.directorial_notes.clear();
+            Some(ServerMessage::SceneUpdate {
+                scene,
+                characters,
+                interactions,
+            })
+        }
+
+        ServerMessage::NarrativeEventTriggered {
+            event_id,
+            event_name,
+            outcome_description,
+            audio_cue,
+            camera_effect,
+            ..
+        } if policy.hide_dm_notes => Some(ServerMessage::NarrativeEventTriggered {
+            event_id,
+            event_name,
+            outcome_description,
+            scene_direction: String::new(),
+            audio_cue,
+            camera_effect,
+        }),
+
+        mut joined @ ServerMessage::SessionJoined { .. } if policy.hide_dm_notes => {
+            if let ServerMessage::SessionJoined { world_snapshot, .. } = &mut joined {
+                clear_directorial_notes(world_snapshot);
+            }
+            Some(joined)
+        }
+
+        ServerMessage::WorldSnapshotUpdated { mut world_snapshot } if policy.hide_dm_notes => {
+            clear_directorial_notes(&mut world_snapshot);
+            Some(ServerMessage::WorldSnapshotUpdated { world_snapshot })
+        }
+
+        other => Some(other),
+    }
+}
+
+/// Blank the directorial notes of every scene in a world snapshot
+fn clear_directorial_notes(world_snapshot: &mut serde_json::Value) {
+    if let Some(scenes) = world_snapshot.get_mut("scenes").and_then(|s| s.as_array_mut()) {
+        for scene in scenes {
+            if let Some(notes) = scene.get_mut("directorial_notes") {
+                *notes = serde_json::Value::String(String::new());
+            }
+        }
+    }
+}
+
+/// How long to hold a spectator message back, in milliseconds
+///
+/// Session bookkeeping (joins, policy changes, errors) is never delayed.
+pub fn spectator_delay_ms(message: &ServerMessage, policy: &SpectatorPolicy) -> u64 {
+    match message {
+        ServerMessage::SessionJoined { .. }
+        | ServerMessage::SpectatorPolicyChanged { .. }
+        | ServerMessage::Error { .. }
+        | ServerMessage::Pong => 0,
+        _ => u64::from(policy.feed_delay_secs) * 1000,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolved() -> ServerMessage {
+        ServerMessage::ChallengeResolved {
+            challenge_id: "c1".to_string(),
+            challenge_name: "Pick Lock".to_string(),
+            character_name: "Ana".to_string(),
+            roll: 14,
+            modifier: 3,
+            total: 17,
+            outcome: "success".to_string(),
+            outcome_description: "The lock clicks open.".to_string(),
+            roll_breakdown: Some("1d20(14) + 3 = 17".to_string()),
+            individual_rolls: Some(vec![14]),
+            manual_entry: None,
+            audio_cue: None,
+            camera_effect: None,
+        }
+    }
+
+    #[test]
+    fn test_default_policy_passes_everything_through() {
+        let policy = SpectatorPolicy::default();
+        let Some(ServerMessage::ChallengeResolved { total, .. }) = filter_for_spectator(resolved(), &policy) else {
+            panic!("challenge result should pass through");
+        };
+        assert_eq!(total, 17);
+        assert_eq!(spectator_delay_ms(&resolved(), &policy), 0);
+    }
+
+    #[test]
+    fn test_policy_hides_numbers_and_whispers() {
+        let policy = SpectatorPolicy {
+            hide_whispers: true,
+            hide_challenge_numbers: true,
+            hide_dm_notes: true,
+            feed_delay_secs: 5,
+        };
+        let Some(ServerMessage::ChallengeResolved { roll, total, roll_breakdown, outcome, .. }) =
+            filter_for_spectator(resolved(), &policy)
+        else {
+            panic!("challenge result should still be shown");
+        };
+        assert_eq!((roll, total, roll_breakdown), (0, 0, None));
+        assert_eq!(outcome, "success");
+
+        let whisper = ServerMessage::Whisper {
+            whisper_id: "w1".to_string(),
+            player_user_id: "p1".to_string(),
+            player_name: None,
+            text: "psst".to_string(),
+            from_dm: false,
+        };
+        assert!(filter_for_spectator(whisper, &policy).is_none());
+        assert_eq!(spectator_delay_ms(&resolved(), &policy), 5000);
+        assert_eq!(
+            spectator_delay_ms(&ServerMessage::SpectatorPolicyChanged { policy }, &policy),
+            0
+        );
+    }
+
+    #[test]
+    fn test_session_joined_strips_scene_notes_using_its_own_policy() {
+        let joined = ServerMessage::SessionJoined {
+            session_id: "s1".to_string(),
+            role: crate::application::dto::ParticipantRole::Spectator,
+            participants: vec![],
+            world_snapshot: serde_json::json!({
+                "scenes": [{ "id": "sc1", "directorial_notes": "The butler did it" }]
+            }),
+            spectator_policy: SpectatorPolicy {
+                hide_dm_notes: true,
+                ..SpectatorPolicy::default()
+            },
+            protocol_version: None,
+            pending_level_up: None,
+            decision_timer: Default::default(),
+            conditions: Default::default(),
+            annotations: Vec::new(),
+            triggered_events: Vec::new(),
+            staged_scenes: Vec::new(),
+            session_rolls: Vec::new(),
+            directorial_context: None,
+        };
+        let Some(ServerMessage::SessionJoined { world_snapshot, .. }) =
+            filter_for_spectator(joined, &SpectatorPolicy::default())
+        else {
+            panic!("session join should pass through");
+        };
+        assert_eq!(world_snapshot["scenes"][0]["directorial_notes"], "");
+    }
+}
@@ -18,10 +18,10 @@ use dioxus::prelude::*;
 use std::sync::Arc;
 
 use crate::application::services::{
-    AssetService, CharacterService, ChallengeService, EventChainService, GenerationService, LocationService, NarrativeEventService,
-    ObservationService, PlayerCharacterService, SettingsService, SkillService, StoryEventService, SuggestionService, WorkflowService, WorldService,
+    AssetService, AuthService, BugReportService, CampaignService, CharacterService, ChallengeService, CraftingService, EncounterService, EntityHistoryService, EventChainService, FactionService, GenerationService, LocationService, NarrativeEventService,
+    NotesService, ObservationService, PlayerCharacterService, RelationshipService, SettingsService, SkillService, StoryEventService, SuggestionService, TrashService, TutorialWorldService, WorkflowService, WorldService,
 };
-use crate::application::ports::outbound::ApiPort;
+use crate::application::ports::outbound::{ApiError, ApiPort, OpenCircuit};
 // Import ConcreteServices from the composition root (main.rs)
 // This is acceptable as main.rs wires up the concrete types
 use crate::ConcreteServices;
@@ -29,6 +29,8 @@ use crate::ConcreteServices;
 /// All services wrapped for context provision
 #[derive(Clone)]
 pub struct Services<A: ApiPort> {
+    /// The API port itself, for its request health
+    pub api: A,
     pub world: Arc<WorldService<A>>,
     pub character: Arc<CharacterService<A>>,
     pub location: Arc<LocationService<A>>,
@@ -41,9 +43,20 @@ pub struct Services<A: ApiPort> {
     pub asset: Arc<AssetService<A>>,
     pub suggestion: Arc<SuggestionService<A>>,
     pub event_chain: Arc<EventChainService<A>>,
+    pub notes: Arc<NotesService<A>>,
     pub generation: Arc<GenerationService<A>>,
     pub settings: Arc<SettingsService<A>>,
     pub observation: Arc<ObservationService<A>>,
+    pub auth: Arc<AuthService<A>>,
+    pub bug_report: Arc<BugReportService<A>>,
+    pub trash: Arc<TrashService<A>>,
+    pub history: Arc<EntityHistoryService<A>>,
+    pub encounter: Arc<EncounterService<A>>,
+    pub campaign: Arc<CampaignService<A>>,
+    pub crafting: Arc<CraftingService<A>>,
+    pub relationship: Arc<RelationshipService<A>>,
+    pub faction: Arc<FactionService<A>>,
+    pub tutorial_world: Arc<TutorialWorldService<A>>,
 }
 
 impl<A: ApiPort + Clone> Services<A> {
@@ -62,9 +75,21 @@ impl<A: ApiPort + Clone> Services<A> {
             asset: Arc::new(AssetService::new(api.clone())),
             suggestion: Arc::new(SuggestionService::new(api.clone())),
             event_chain: Arc::new(EventChainService::new(api.clone())),
+            notes: Arc::new(NotesService::new(api.clone())),
             generation: Arc::new(GenerationService::new(api.clone())),
             settings: Arc::new(SettingsService::new(api.clone())),
-            observation: Arc::new(ObservationService::new(api)),
+            observation: Arc::new(ObservationService::new(api.clone())),
+            auth: Arc::new(AuthService::new(api.clone())),
+            bug_report: Arc::new(BugReportService::new(api.clone())),
+            trash: Arc::new(TrashService::new(api.clone())),
+            history: Arc::new(EntityHistoryService::new(api.clone())),
+            encounter: Arc::new(EncounterService::new(api.clone())),
+            campaign: Arc::new(CampaignService::new(api.clone())),
+            crafting: Arc::new(CraftingService::new(api.clone())),
+            relationship: Arc::new(RelationshipService::new(api.clone())),
+            faction: Arc::new(FactionService::new(api.clone())),
+            tutorial_world: Arc::new(TutorialWorldService::new(api.clone())),
+            api,
         }
     }
 }
@@ -83,9 +108,26 @@ type ConcreteWorkflowService = Arc<WorkflowService<crate::infrastructure::http_c
 type ConcreteAssetService = Arc<AssetService<crate::infrastructure::http_client::ApiAdapter>>;
 type ConcreteSuggestionService = Arc<SuggestionService<crate::infrastructure::http_client::ApiAdapter>>;
 type ConcreteEventChainService = Arc<EventChainService<crate::infrastructure::http_client::ApiAdapter>>;
+type ConcreteNotesService = Arc<NotesService<crate::infrastructure::http_client::ApiAdapter>>;
 type ConcreteGenerationService = Arc<GenerationService<crate::infrastructure::http_client::ApiAdapter>>;
 type ConcreteSettingsService = Arc<SettingsService<crate::infrastructure::http_client::ApiAdapter>>;
 type ConcreteObservationService = Arc<ObservationService<crate::infrastructure::http_client::ApiAdapter>>;
+type ConcreteAuthService = Arc<AuthService<crate::infrastructure::http_client::ApiAdapter>>;
+type ConcreteBugReportService = Arc<BugReportService<crate::infrastructure::http_client::ApiAdapter>>;
+type ConcreteTrashService = Arc<TrashService<crate::infrastructure::http_client::ApiAdapter>>;
+type ConcreteEntityHistoryService = Arc<EntityHistoryService<crate::infrastructure::http_client::ApiAdapter>>;
+type ConcreteEncounterService = Arc<EncounterService<crate::infrastructure::http_client::ApiAdapter>>;
+type ConcreteCampaignService = Arc<CampaignService<crate::infrastructure::http_client::ApiAdapter>>;
+type ConcreteCraftingService = Arc<CraftingService<crate::infrastructure::http_client::ApiAdapter>>;
+type ConcreteRelationshipService = Arc<RelationshipService<crate::infrastructure::http_client::ApiAdapter>>;
+type ConcreteFactionService = Arc<FactionService<crate::infrastructure::http_client::ApiAdapter>>;
+type ConcreteTutorialWorldService = Arc<TutorialWorldService<crate::infrastructure::http_client::ApiAdapter>>;
+
+/// Hook to read which Engine endpoints are paused after repeated failures
+pub fn use_open_circuits() -> impl Fn() -> Vec<OpenCircuit> + Clone {
+    let services = use_context::<ConcreteServices>();
+    move || services.api.open_circuits()
+}
 
 /// Hook to access the WorldService from context
 pub fn use_world_service() -> ConcreteWorldService {
@@ -159,6 +201,12 @@ pub fn use_event_chain_service() -> ConcreteEventChainService {
     services.event_chain.clone()
 }
 
+/// Hook to access the NotesService from context
+pub fn use_notes_service() -> ConcreteNotesService {
+    let services = use_context::<ConcreteServices>();
+    services.notes.clone()
+}
+
 /// Hook to access the GenerationService from context
 pub fn use_generation_service() -> ConcreteGenerationService {
     let services = use_context::<ConcreteServices>();
@@ -177,9 +225,68 @@ pub fn use_observation_service() -> ConcreteObservationService {
     services.observation.clone()
 }
 
+/// Hook to access the AuthService from context
+pub fn use_auth_service() -> ConcreteAuthService {
+    let services = use_context::<ConcreteServices>();
+    services.auth.clone()
+}
+
+/// Hook to access the BugReportService from context
+pub fn use_bug_report_service() -> ConcreteBugReportService {
+    let services = use_context::<ConcreteServices>();
+    services.bug_report.clone()
+}
+
+/// Hook to access the TrashService from context
+pub fn use_trash_service() -> ConcreteTrashService {
+    let services = use_context::<ConcreteServices>();
+    services.trash.clone()
+}
+
+/// Hook to access the EntityHistoryService from context
+pub fn use_entity_history_service() -> ConcreteEntityHistoryService {
+    let services = use_context::<ConcreteServices>();
+    services.history.clone()
+}
+
+/// Hook to access the EncounterService from context
+pub fn use_encounter_service() -> ConcreteEncounterService {
+    let services = use_context::<ConcreteServices>();
+    services.encounter.clone()
+}
+
+/// Hook to access the CampaignService from context
+pub fn use_campaign_service() -> ConcreteCampaignService {
+    let services = use_context::<ConcreteServices>();
+    services.campaign.clone()
+}
+
+/// Hook to access the CraftingService from context
+pub fn use_crafting_service() -> ConcreteCraftingService {
+    let services = use_context::<ConcreteServices>();
+    services.crafting.clone()
+}
+
+/// Hook to access the RelationshipService from context
+pub fn use_relationship_service() -> ConcreteRelationshipService {
+    let services = use_context::<ConcreteServices>();
+    services.relationship.clone()
+}
+
+/// Hook to access the FactionService from context
+pub fn use_faction_service() -> ConcreteFactionService {
+    let services = use_context::<ConcreteServices>();
+    services.faction.clone()
+}
+
+/// Hook to access the TutorialWorldService from context
+pub fn use_tutorial_world_service() -> ConcreteTutorialWorldService {
+    let services = use_context::<ConcreteServices>();
+    services.tutorial_world.clone()
+}
+
 use crate::presentation::state::{BatchStatus, GenerationBatch, GenerationState, SuggestionStatus, SuggestionTask};
 use crate::application::ports::outbound::Platform;
-use anyhow::Result;
 
 /// Hydrate GenerationState from the Engine's unified generation queue endpoint.
 ///
@@ -195,7 +302,7 @@ pub async fn hydrate_generation_queue<A: ApiPort>(
     user_id: Option<&str>,
     world_id: &str,
     platform: &Platform,
-) -> Result<()> {
+) -> Result<(), ApiError> {
     let snapshot = generation_service.fetch_queue(user_id, world_id).await?;
 
     // Clear existing state and repopulate from snapshot
@@ -225,6 +332,9 @@ pub async fn hydrate_generation_queue<A: ApiPort>(
             asset_type: b.asset_type,
             status,
             is_read: b.is_read,
+            params: b.params,
+            queued_at: None,
+            started_at: None,
         });
     }
 
@@ -232,6 +342,9 @@ pub async fn hydrate_generation_queue<A: ApiPort>(
         let status = match s.status.as_str() {
             "queued" => SuggestionStatus::Queued,
             "processing" => SuggestionStatus::Processing,
+            "streaming" => SuggestionStatus::Streaming {
+                partial: s.suggestions.unwrap_or_default(),
+            },
             "ready" => SuggestionStatus::Ready {
                 suggestions: s.suggestions.unwrap_or_default(),
             },
@@ -250,11 +363,19 @@ pub async fn hydrate_generation_queue<A: ApiPort>(
         );
         // Override status if needed using the same request_id
         let req_id = s.request_id;
+        if let Some(index) = s.applied_index {
+            generation_state.mark_suggestion_applied(&req_id, index);
+        }
         match status {
             SuggestionStatus::Queued => {}
             SuggestionStatus::Processing => {
                 generation_state.suggestion_progress(&req_id, "processing");
             }
+            SuggestionStatus::Streaming { partial } => {
+                for (index, text) in partial.iter().enumerate() {
+                    generation_state.suggestion_chunk(&req_id, index, text);
+                }
+            }
             SuggestionStatus::Ready { suggestions } => {
                 generation_state.suggestion_complete(&req_id, suggestions);
             }
@@ -324,7 +445,7 @@ pub async fn sync_generation_read_state<A: ApiPort>(
     generation_service: &GenerationService<A>,
     state: &GenerationState,
     world_id: Option<&str>,
-) -> Result<()> {
+) -> Result<(), ApiError> {
     let read_batches: Vec<String> = state
         .get_batches()
         .into_iter()
@@ -347,9 +468,6 @@ pub async fn sync_generation_read_state<A: ApiPort>(
     generation_service
         .sync_read_state(read_batches, read_suggestions, world_id)
         .await
-        .map_err(|e| anyhow::anyhow!("Failed to sync generation read state: {}", e))?;
-
-    Ok(())
 }
 
 /// View-model helpers for generation queue filtering and actions
@@ -392,7 +510,7 @@ pub async fn mark_batch_read_and_sync<A: ApiPort>(
     batch_id: &str,
     world_id: Option<&str>,
     platform: &Platform,
-) -> Result<()> {
+) -> Result<(), ApiError> {
     state.mark_batch_read(batch_id);
     persist_generation_read_state(platform, state);
     sync_generation_read_state(generation_service, state, world_id).await
@@ -412,7 +530,7 @@ pub async fn mark_suggestion_read_and_sync<A: ApiPort>(
     request_id: &str,
     world_id: Option<&str>,
     platform: &Platform,
-) -> Result<()> {
+) -> Result<(), ApiError> {
     state.mark_suggestion_read(request_id);
     persist_generation_read_state(platform, state);
     sync_generation_read_state(generation_service, state, world_id).await
@@ -5,8 +5,13 @@
 use dioxus::prelude::*;
 use std::sync::Arc;
 
-use crate::application::dto::{ProposedTool, ChallengeSuggestionInfo, NarrativeEventSuggestionInfo};
+use crate::application::dto::websocket_messages::ChallengeOutcomeDecisionData;
+use crate::application::dto::{
+    ChallengeAutoResolveRules, ChallengeSuggestionInfo, NarrativeEventSuggestionInfo, ProposedTool,
+};
 use crate::application::ports::outbound::{ApprovalDecision, GameConnectionPort, Platform};
+use crate::application::services::{append_approval_audit, auto_apply_margin, ApprovalAuditEntry, AuditOutcome};
+use crate::presentation::state::perf_probe;
 
 /// A pending approval request from the LLM that the DM needs to review
 #[derive(Debug, Clone, PartialEq)]
@@ -25,6 +30,8 @@ pub struct PendingApproval {
     pub challenge_suggestion: Option<ChallengeSuggestionInfo>,
     /// Optional narrative event suggestion from the Engine
     pub narrative_event_suggestion: Option<NarrativeEventSuggestionInfo>,
+    /// Player character whose action the NPC is answering, if the Engine says
+    pub pc_id: Option<String>,
 }
 
 /// A past approval decision for lightweight decision history in the DM view
@@ -40,6 +47,26 @@ pub struct ApprovalHistoryEntry {
     pub timestamp: u64,
 }
 
+/// What a conversation log entry records, for filtering
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogEntryKind {
+    System,
+    Dialogue,
+    Challenge,
+}
+
+impl LogEntryKind {
+    pub const ALL: [LogEntryKind; 3] = [LogEntryKind::Dialogue, LogEntryKind::Challenge, LogEntryKind::System];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogEntryKind::System => "System",
+            LogEntryKind::Dialogue => "Dialogue",
+            LogEntryKind::Challenge => "Challenges",
+        }
+    }
+}
+
 /// A log entry for the conversation
 #[derive(Debug, Clone, PartialEq)]
 pub struct ConversationLogEntry {
@@ -49,6 +76,8 @@ pub struct ConversationLogEntry {
     pub text: String,
     /// Whether this is a system message
     pub is_system: bool,
+    /// Kind of entry, for filtering
+    pub kind: LogEntryKind,
     /// Timestamp (for ordering)
     pub timestamp: u64,
 }
@@ -78,6 +107,8 @@ pub struct PendingChallengeOutcome {
     pub outcome_triggers: Vec<crate::application::dto::ProposedTool>,
     /// Roll breakdown string (e.g., "1d20(18) + 3 = 21")
     pub roll_breakdown: Option<String>,
+    /// The dice as entered, when the player rolled physical dice
+    pub manual_entry: Option<crate::application::dto::ManualDiceEntry>,
     /// LLM-generated alternative suggestions
     pub suggestions: Option<Vec<String>>,
     /// LLM-generated outcome branches for selection (Phase 22C)
@@ -99,6 +130,8 @@ pub struct ApprovalState {
     pub conversation_log: Signal<Vec<ConversationLogEntry>>,
     /// Pending challenge outcomes awaiting DM approval (P3.3/P3.4)
     pub pending_challenge_outcomes: Signal<Vec<PendingChallengeOutcome>>,
+    /// The world's rules for applying clear successes without the DM
+    pub challenge_auto_resolve: Signal<ChallengeAutoResolveRules>,
 }
 
 impl ApprovalState {
@@ -109,6 +142,7 @@ impl ApprovalState {
             decision_history: Signal::new(Vec::new()),
             conversation_log: Signal::new(Vec::new()),
             pending_challenge_outcomes: Signal::new(Vec::new()),
+            challenge_auto_resolve: Signal::new(ChallengeAutoResolveRules::default()),
         }
     }
 
@@ -134,23 +168,39 @@ impl ApprovalState {
 
     /// Add a conversation log entry
     pub fn add_log_entry(&mut self, speaker: String, text: String, is_system: bool, platform: &Platform) {
+        perf_probe::record_signal_write("ApprovalState.conversation_log");
         let timestamp = platform.now_unix_secs();
         self.conversation_log.write().push(ConversationLogEntry {
             speaker,
             text,
             is_system,
+            kind: if is_system { LogEntryKind::System } else { LogEntryKind::Dialogue },
             timestamp,
         });
     }
 
+    /// Add a system entry about a challenge
+    pub fn add_challenge_log_entry(&mut self, text: String, platform: &Platform) {
+        perf_probe::record_signal_write("ApprovalState.conversation_log");
+        self.conversation_log.write().push(ConversationLogEntry {
+            speaker: "System".to_string(),
+            text,
+            is_system: true,
+            kind: LogEntryKind::Challenge,
+            timestamp: platform.now_unix_secs(),
+        });
+    }
+
     /// Record an approval decision: send it to the Engine, log it locally with
-    /// a real timestamp, and remove it from the pending queue.
+    /// a real timestamp, add it to the session's audit log, and remove it from
+    /// the pending queue.
     pub fn record_approval_decision(
         &mut self,
         request_id: String,
         decision: &ApprovalDecision,
         platform: &Platform,
         engine_client: &Option<Arc<dyn GameConnectionPort>>,
+        session_id: Option<&str>,
     ) {
         // Send to Engine if we have a client
         if let Some(client) = engine_client.as_ref() {
@@ -161,26 +211,35 @@ impl ApprovalState {
         }
 
         // Normalize outcome label
-        let outcome_label = match decision {
-            ApprovalDecision::Accept => "accepted",
-            ApprovalDecision::AcceptWithModification { .. } => "modified",
-            ApprovalDecision::Reject { .. } => "rejected",
-            ApprovalDecision::TakeOver { .. } => "takeover",
-        }
-        .to_string();
+        let outcome_label = AuditOutcome::of(decision).label().to_string();
 
-        // Resolve NPC name from current pending approvals
-        let npc_name = self
+        // Resolve NPC name and the proposal from current pending approvals
+        let pending = self
             .pending_approvals
             .read()
             .iter()
             .find(|a| a.request_id == request_id)
+            .cloned();
+        let npc_name = pending
+            .as_ref()
             .map(|a| a.npc_name.clone())
             .unwrap_or_else(|| "Unknown".to_string());
 
         // Use Platform to get a real timestamp
         let timestamp = platform.now_unix_secs();
 
+        if let Some(session_id) = session_id {
+            let (dialogue, tools) = pending
+                .as_ref()
+                .map(|a| (a.proposed_dialogue.as_str(), a.proposed_tools.as_slice()))
+                .unwrap_or_default();
+            append_approval_audit(
+                platform,
+                session_id,
+                ApprovalAuditEntry::from_decision(&request_id, &npc_name, dialogue, tools, decision, timestamp),
+            );
+        }
+
         let entry = ApprovalHistoryEntry {
             request_id: request_id.clone(),
             npc_name,
@@ -206,6 +265,68 @@ impl ApprovalState {
         self.pending_challenge_outcomes.write().push(outcome);
     }
 
+    /// Margin by which the auto-resolve rules cover a challenge outcome, or
+    /// None when it has to go to the DM: the rules don't cover it or its
+    /// physical dice were flagged as unlikely
+    pub fn auto_resolve_margin(&self, outcome: &PendingChallengeOutcome, difficulty_class: Option<i32>) -> Option<i32> {
+        // Unlikely physical dice are for the DM to judge
+        if outcome.manual_entry.as_ref().is_some_and(|entry| !entry.flags.is_empty()) {
+            return None;
+        }
+        let rules = self.challenge_auto_resolve.peek().clone();
+        auto_apply_margin(&rules, &outcome.outcome_type, outcome.total, difficulty_class)
+    }
+
+    /// Apply a challenge outcome without the DM if the auto-resolve rules
+    /// allow it: accept it on the Engine and record it in the decision
+    /// history and the session's audit log.
+    ///
+    /// Returns false when the outcome has to go to the DM after all: the
+    /// rules don't cover it or it couldn't be sent.
+    pub fn auto_apply_challenge_outcome(
+        &mut self,
+        outcome: &PendingChallengeOutcome,
+        difficulty_class: Option<i32>,
+        platform: &Platform,
+        engine_client: &Option<Arc<dyn GameConnectionPort>>,
+        session_id: Option<&str>,
+    ) -> bool {
+        let Some(margin) = self.auto_resolve_margin(outcome, difficulty_class) else {
+            return false;
+        };
+        let Some(client) = engine_client.as_ref() else {
+            return false;
+        };
+        if let Err(e) = client.send_challenge_outcome_decision(&outcome.resolution_id, ChallengeOutcomeDecisionData::Accept) {
+            tracing::error!("Failed to auto-apply challenge outcome: {}", e);
+            return false;
+        }
+
+        let timestamp = platform.now_unix_secs();
+        if let Some(session_id) = session_id {
+            append_approval_audit(
+                platform,
+                session_id,
+                ApprovalAuditEntry::auto_applied_challenge(
+                    &outcome.resolution_id,
+                    &outcome.character_name,
+                    &outcome.challenge_name,
+                    &outcome.outcome_description,
+                    outcome.total,
+                    margin,
+                    timestamp,
+                ),
+            );
+        }
+        self.add_approval_history_entry(ApprovalHistoryEntry {
+            request_id: outcome.resolution_id.clone(),
+            npc_name: outcome.character_name.clone(),
+            outcome: AuditOutcome::AutoApplied.label().to_string(),
+            timestamp,
+        });
+        true
+    }
+
     /// Remove a pending challenge outcome by resolution_id (P3.3/P3.4)
     pub fn remove_pending_challenge_outcome(&mut self, resolution_id: &str) {
         self.pending_challenge_outcomes
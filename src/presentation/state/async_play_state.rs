@@ -0,0 +1,76 @@
+//! Async play state management using Dioxus signals
+//!
+//! Play-by-post: whether it is on, the DM's inbox of posted actions, and
+//! the local player's own posted actions (see
+//! `application::services::action_inbox`). The posted actions are saved
+//! per world so the ones still waiting survive a reload.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::InboxAction;
+use crate::application::ports::outbound::{storage_keys, Platform};
+use crate::application::services::PostedActions;
+
+/// Async play state for play-by-post sessions
+#[derive(Clone)]
+pub struct AsyncPlayState {
+    /// Whether actions wait for the DM when none is connected
+    pub enabled: Signal<bool>,
+    /// Posted actions waiting for the DM, oldest first (DM only)
+    pub inbox: Signal<Vec<InboxAction>>,
+    /// This player's posted actions in the current world
+    pub posted: Signal<PostedActions>,
+    /// World the posted actions were loaded for
+    pub posted_world: Signal<Option<String>>,
+}
+
+impl AsyncPlayState {
+    /// Create an AsyncPlayState with play-by-post off
+    pub fn new() -> Self {
+        Self {
+            enabled: Signal::new(false),
+            inbox: Signal::new(Vec::new()),
+            posted: Signal::new(PostedActions::new()),
+            posted_world: Signal::new(None),
+        }
+    }
+
+    /// Load this device's posted actions for a world, unless already loaded
+    pub fn load_posted(&mut self, platform: &Platform, world_id: &str) {
+        if self.posted_world.peek().as_deref() == Some(world_id) {
+            return;
+        }
+        let posted = platform
+            .storage_load(&posted_actions_key(world_id))
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        self.posted.set(posted);
+        self.posted_world.set(Some(world_id.to_string()));
+    }
+
+    /// Change the posted actions and save them for the world
+    pub fn update_posted(&mut self, platform: &Platform, world_id: &str, change: impl FnOnce(&mut PostedActions)) {
+        self.load_posted(platform, world_id);
+        change(&mut self.posted.write());
+        match serde_json::to_string(&*self.posted.peek()) {
+            Ok(json) => platform.storage_save(&posted_actions_key(world_id), &json),
+            Err(e) => tracing::warn!("Failed to save posted actions: {}", e),
+        }
+    }
+
+    /// Clear session state; saved posted actions stay on the device
+    pub fn clear(&mut self) {
+        self.enabled.set(false);
+        self.inbox.set(Vec::new());
+    }
+}
+
+fn posted_actions_key(world_id: &str) -> String {
+    format!("{}{}", storage_keys::POSTED_ACTIONS_PREFIX, world_id)
+}
+
+impl Default for AsyncPlayState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
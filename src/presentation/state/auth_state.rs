@@ -0,0 +1,44 @@
+//! Authentication state management using Dioxus signals
+//!
+//! Tracks the identity of the user logged in to the Engine.
+
+use dioxus::prelude::*;
+
+use crate::application::services::AuthIdentity;
+
+/// Authentication state for the logged-in user
+#[derive(Clone)]
+pub struct AuthState {
+    /// Identity of the logged-in user (None = anonymous)
+    pub identity: Signal<Option<AuthIdentity>>,
+}
+
+impl AuthState {
+    /// Create a new AuthState with the given (possibly restored) identity
+    pub fn new(identity: Option<AuthIdentity>) -> Self {
+        Self {
+            identity: Signal::new(identity),
+        }
+    }
+
+    /// User ID of the logged-in user, if any
+    pub fn user_id(&self) -> Option<String> {
+        self.identity.read().as_ref().map(|i| i.user_id.clone())
+    }
+
+    /// Set the logged-in identity
+    pub fn set_identity(&mut self, identity: AuthIdentity) {
+        self.identity.set(Some(identity));
+    }
+
+    /// Clear the logged-in identity
+    pub fn clear(&mut self) {
+        self.identity.set(None);
+    }
+}
+
+impl Default for AuthState {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
@@ -1,9 +1,12 @@
 //! Challenge state management using Dioxus signals
 //!
-//! Tracks active challenges, challenge results, and player skills.
+//! Tracks active challenges, challenge results, player skills, and the
+//! player's own roll history.
 
 use dioxus::prelude::*;
 
+use crate::application::ports::outbound::{storage_keys, Platform};
+use crate::application::services::{RollHistory, RollRecord, SessionDiceLog};
 use crate::presentation::components::tactical::PlayerSkillData;
 
 /// Roll submission status for challenge outcomes (P3.3/P3.4)
@@ -66,7 +69,7 @@ pub struct ChallengeResultData {
     pub modifier: i32,
     /// Total result (roll + modifier)
     pub total: i32,
-    /// Outcome type ("success", "failure", "critical_success", etc.)
+    /// Outcome type ("success", "partial", "failure", "critical_success", etc.)
     pub outcome: String,
     /// Descriptive outcome text
     pub outcome_description: String,
@@ -89,6 +92,12 @@ pub struct ChallengeState {
     pub player_skills: Signal<Vec<PlayerSkillData>>,
     /// Roll submission status for the active challenge (P3.3/P3.4)
     pub roll_status: Signal<RollSubmissionStatus>,
+    /// This player's rolls in the current world, kept across sessions
+    pub roll_history: Signal<RollHistory>,
+    /// World the roll history was loaded for
+    pub roll_history_world: Signal<Option<String>>,
+    /// Everyone's rolls this session, for the DM's dice statistics
+    pub session_dice: Signal<SessionDiceLog>,
 }
 
 impl ChallengeState {
@@ -99,6 +108,9 @@ impl ChallengeState {
             challenge_results: Signal::new(Vec::new()),
             player_skills: Signal::new(Vec::new()),
             roll_status: Signal::new(RollSubmissionStatus::default()),
+            roll_history: Signal::new(RollHistory::new()),
+            roll_history_world: Signal::new(None),
+            session_dice: Signal::new(SessionDiceLog::new()),
         }
     }
 
@@ -117,6 +129,29 @@ impl ChallengeState {
         self.challenge_results.write().push(result);
     }
 
+    /// Load this device's roll history for a world, unless already loaded
+    pub fn load_roll_history(&mut self, platform: &Platform, world_id: &str) {
+        if self.roll_history_world.peek().as_deref() == Some(world_id) {
+            return;
+        }
+        let history = platform
+            .storage_load(&roll_history_key(world_id))
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        self.roll_history.set(history);
+        self.roll_history_world.set(Some(world_id.to_string()));
+    }
+
+    /// Remember a roll the player made and save the world's history
+    pub fn record_roll(&mut self, platform: &Platform, world_id: &str, record: RollRecord) {
+        self.load_roll_history(platform, world_id);
+        self.roll_history.write().record(record);
+        match serde_json::to_string(&*self.roll_history.peek()) {
+            Ok(json) => platform.storage_save(&roll_history_key(world_id), &json),
+            Err(e) => tracing::warn!("Failed to save roll history: {}", e),
+        }
+    }
+
     /// Set player skills
     pub fn set_player_skills(&mut self, skills: Vec<PlayerSkillData>) {
         self.player_skills.set(skills);
@@ -133,6 +168,7 @@ impl ChallengeState {
         self.challenge_results.set(Vec::new());
         self.player_skills.set(Vec::new());
         self.roll_status.set(RollSubmissionStatus::NotSubmitted);
+        self.session_dice.write().clear();
     }
 
     /// Set roll as awaiting DM approval (P3.3/P3.4)
@@ -161,6 +197,10 @@ impl ChallengeState {
     }
 }
 
+fn roll_history_key(world_id: &str) -> String {
+    format!("{}{}", storage_keys::ROLL_HISTORY_PREFIX, world_id)
+}
+
 impl Default for ChallengeState {
     fn default() -> Self {
         Self::new()
@@ -3,9 +3,11 @@
 //! Tracks connection status, server URL, and user session information.
 
 use dioxus::prelude::*;
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::application::ports::outbound::{GameConnectionPort, ParticipantRole};
+use crate::application::ports::outbound::{Capability, GameConnectionPort, ParticipantRole};
+use crate::application::services::EngineHealth;
 
 /// Connection status to the Engine server
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -60,8 +62,10 @@ pub struct ConnectionState {
     pub session_id: Signal<Option<String>>,
     /// User ID (local identifier)
     pub user_id: Signal<Option<String>>,
-    /// User role (DungeonMaster, Player, Spectator)
+    /// User role (DungeonMaster, CoDungeonMaster, Player, Spectator)
     pub user_role: Signal<Option<ParticipantRole>>,
+    /// Roles of the other participants, keyed by user ID
+    pub participant_roles: Signal<HashMap<String, ParticipantRole>>,
     /// Server URL we're connected to
     pub server_url: Signal<Option<String>>,
     /// Game connection handle (if connected)
@@ -72,6 +76,8 @@ pub struct ConnectionState {
     pub comfyui_state: Signal<String>, // "connected", "degraded", "disconnected", "circuit_open"
     pub comfyui_message: Signal<Option<String>>,
     pub comfyui_retry_in_seconds: Signal<Option<u32>>,
+    /// ComfyUI, LLM and latency health, driving degradation modes
+    pub engine_health: Signal<EngineHealth>,
 }
 
 impl ConnectionState {
@@ -82,12 +88,14 @@ impl ConnectionState {
             session_id: Signal::new(None),
             user_id: Signal::new(None),
             user_role: Signal::new(None),
+            participant_roles: Signal::new(HashMap::new()),
             server_url: Signal::new(None),
             engine_client: Signal::new(None),
             error_message: Signal::new(None),
             comfyui_state: Signal::new("connected".to_string()),
             comfyui_message: Signal::new(None),
             comfyui_retry_in_seconds: Signal::new(None),
+            engine_health: Signal::new(EngineHealth::new()),
         }
     }
 
@@ -124,6 +132,25 @@ impl ConnectionState {
         self.user_role.set(Some(role));
     }
 
+    /// Record a participant's role; updates our own role if it is us
+    pub fn set_participant_role(&mut self, user_id: String, role: ParticipantRole) {
+        if self.user_id.peek().as_deref() == Some(user_id.as_str()) {
+            self.user_role.set(Some(role));
+        } else {
+            self.participant_roles.write().insert(user_id, role);
+        }
+    }
+
+    /// Forget a participant who left the session
+    pub fn remove_participant_role(&mut self, user_id: &str) {
+        self.participant_roles.write().remove(user_id);
+    }
+
+    /// Whether the local user's role allows the given action
+    pub fn can(&self, capability: Capability) -> bool {
+        ParticipantRole::allows(*self.user_role.read(), capability)
+    }
+
     /// Set the connection to disconnected state
     pub fn set_disconnected(&mut self) {
         self.connection_status.set(ConnectionStatus::Disconnected);
@@ -154,9 +181,11 @@ impl ConnectionState {
         self.session_id.set(None);
         self.user_id.set(None);
         self.user_role.set(None);
+        self.participant_roles.set(HashMap::new());
         self.server_url.set(None);
         self.engine_client.set(None);
         self.error_message.set(None);
+        self.engine_health.set(EngineHealth::new());
     }
 }
 
@@ -4,8 +4,19 @@
 
 use dioxus::prelude::*;
 
-use crate::application::dto::DialogueChoice;
+use crate::application::dto::{DialogueChoice, DialoguePacing};
 use crate::application::ports::outbound::Platform;
+use crate::presentation::state::{perf_probe, use_display_state};
+
+/// Lines kept in the dialogue backlog
+const BACKLOG_LIMIT: usize = 200;
+
+/// A past line of dialogue, for the backlog
+#[derive(Clone, Debug, PartialEq)]
+pub struct BacklogLine {
+    pub speaker_name: String,
+    pub text: String,
+}
 
 /// Dialogue state for the visual novel UI
 #[derive(Clone)]
@@ -28,6 +39,16 @@ pub struct DialogueState {
     pub speaker_id: Signal<Option<String>>,
     /// Whether LLM is processing (show loading indicator)
     pub is_llm_processing: Signal<bool>,
+    /// How dialogue advances in group scenes (set by the DM)
+    pub pacing: Signal<DialoguePacing>,
+    /// Whether choices are held back until the line is released
+    pub held: Signal<bool>,
+    /// Whether this player has reported finishing the current line
+    pub finished_reading: Signal<bool>,
+    /// User IDs of players who have finished the current line
+    pub finished_readers: Signal<Vec<String>>,
+    /// Lines received this session, oldest first (including the current one)
+    pub backlog: Signal<Vec<BacklogLine>>,
 }
 
 impl DialogueState {
@@ -43,6 +64,11 @@ impl DialogueState {
             custom_input: Signal::new(String::new()),
             speaker_id: Signal::new(None),
             is_llm_processing: Signal::new(false),
+            pacing: Signal::new(DialoguePacing::default()),
+            held: Signal::new(false),
+            finished_reading: Signal::new(false),
+            finished_readers: Signal::new(Vec::new()),
+            backlog: Signal::new(Vec::new()),
         }
     }
 
@@ -54,6 +80,15 @@ impl DialogueState {
         text: String,
         choices: Vec<DialogueChoice>,
     ) {
+        {
+            let mut backlog = self.backlog.write();
+            backlog.push(BacklogLine {
+                speaker_name: speaker_name.clone(),
+                text: text.clone(),
+            });
+            let overflow = backlog.len().saturating_sub(BACKLOG_LIMIT);
+            backlog.drain(..overflow);
+        }
         self.speaker_id.set(Some(speaker_id));
         self.speaker_name.set(speaker_name);
         self.full_text.set(text);
@@ -63,6 +98,23 @@ impl DialogueState {
         self.awaiting_input.set(false);
         self.custom_input.set(String::new());
         self.is_llm_processing.set(false); // Clear processing indicator when response arrives
+        let paced = self.pacing.read().is_paced();
+        self.held.set(paced);
+        self.finished_reading.set(false);
+        self.finished_readers.set(Vec::new());
+    }
+
+    /// Release a held line so players can respond
+    pub fn release(&mut self) {
+        self.held.set(false);
+    }
+
+    /// Change the pacing mode; switching to free pacing releases any held line
+    pub fn set_pacing(&mut self, pacing: DialoguePacing) {
+        self.pacing.set(pacing);
+        if !pacing.is_paced() {
+            self.release();
+        }
     }
 
     /// Skip to the end of the typewriter animation
@@ -122,6 +174,10 @@ impl DialogueState {
         self.awaiting_input.set(false);
         self.custom_input.set(String::new());
         self.is_llm_processing.set(false);
+        self.held.set(false);
+        self.finished_reading.set(false);
+        self.finished_readers.set(Vec::new());
+        self.backlog.set(Vec::new());
     }
 
     /// Check if there's active dialogue to display
@@ -149,9 +205,11 @@ impl Default for DialogueState {
 /// Hook for running the typewriter effect
 ///
 /// Call this in a component to drive the typewriter animation.
-/// Returns true while typing is in progress.
+/// Returns true while typing is in progress. With reduced motion on, the
+/// full line is shown at once.
 pub fn use_typewriter_effect(dialogue_state: &mut DialogueState) {
     let platform = use_context::<Platform>();
+    let accessibility = use_display_state().accessibility;
     let is_typing = *dialogue_state.is_typing.read();
     let full_text = dialogue_state.full_text.clone();
     let displayed_text = dialogue_state.displayed_text.clone();
@@ -171,6 +229,14 @@ pub fn use_typewriter_effect(dialogue_state: &mut DialogueState) {
             }
 
             let text = full_text.read().clone();
+
+            if accessibility.peek().reduced_motion {
+                displayed_text.set(text);
+                is_typing_signal.set(false);
+                awaiting_signal.set(true);
+                return;
+            }
+
             let mut current = String::new();
 
             for ch in text.chars() {
@@ -180,6 +246,7 @@ pub fn use_typewriter_effect(dialogue_state: &mut DialogueState) {
                 }
 
                 current.push(ch);
+                perf_probe::record_signal_write("DialogueState.displayed_text");
                 displayed_text.set(current.clone());
 
                 // Variable delay based on punctuation
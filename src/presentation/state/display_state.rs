@@ -0,0 +1,53 @@
+//! Display state management using Dioxus signals
+//!
+//! Per-device presentation preferences, persisted in local storage rather
+//! than on the Engine so each player can tune them for their own connection.
+
+use dioxus::prelude::*;
+
+use crate::application::services::{AccessibilityPreferences, BackdropPreferences, TourProgress};
+
+/// Display preferences for this device
+#[derive(Clone)]
+pub struct DisplayState {
+    /// Animated backdrop and data saver toggles
+    pub backdrop: Signal<BackdropPreferences>,
+    /// Developer overlay with render, signal, message, and frame timings
+    pub perf_overlay: Signal<bool>,
+    /// Reduced motion and dyslexia-friendly font
+    pub accessibility: Signal<AccessibilityPreferences>,
+    /// Onboarding tours already finished or skipped
+    pub tours: Signal<TourProgress>,
+    /// Set while a stream route is showing, which hides the app overlays
+    /// (not saved)
+    pub stream_mode: Signal<bool>,
+}
+
+impl DisplayState {
+    /// Create a new DisplayState with the given (possibly restored) preferences
+    pub fn new(
+        backdrop: BackdropPreferences,
+        perf_overlay: bool,
+        accessibility: AccessibilityPreferences,
+        tours: TourProgress,
+    ) -> Self {
+        Self {
+            backdrop: Signal::new(backdrop),
+            perf_overlay: Signal::new(perf_overlay),
+            accessibility: Signal::new(accessibility),
+            tours: Signal::new(tours),
+            stream_mode: Signal::new(false),
+        }
+    }
+}
+
+impl Default for DisplayState {
+    fn default() -> Self {
+        Self::new(
+            BackdropPreferences::default(),
+            false,
+            AccessibilityPreferences::default(),
+            TourProgress::default(),
+        )
+    }
+}
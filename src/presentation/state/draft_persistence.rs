@@ -0,0 +1,159 @@
+//! Local draft persistence for long-form editors
+//!
+//! Forms snapshot their fields every few seconds into local storage, keyed by
+//! world and entity, so an in-progress edit survives a crash or refresh. When
+//! the form is opened again and a draft exists, the form offers to restore it.
+
+use dioxus::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::outbound::{storage_keys, Platform};
+
+/// How often form snapshots are written to local storage
+pub const DRAFT_SAVE_INTERVAL_MS: u64 = 3000;
+
+/// Storage key for a form draft
+///
+/// `entity_id` is `None` (or empty) for a form creating a new entity.
+pub fn draft_key(kind: &str, world_id: &str, entity_id: Option<&str>) -> String {
+    let entity = entity_id.filter(|id| !id.is_empty()).unwrap_or("new");
+    format!("{}{}_{}_{}", storage_keys::DRAFT_PREFIX, kind, world_id, entity)
+}
+
+/// A draft as stored in local storage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedDraft<T> {
+    pub value: T,
+    /// When the draft was last written, in Unix seconds
+    pub saved_at: u64,
+}
+
+/// Draft persistence for one form
+pub struct DraftPersistence<T: 'static> {
+    /// Draft found in storage when the form opened, awaiting restore/discard
+    pub pending: Signal<Option<SavedDraft<T>>>,
+    key: Signal<String>,
+    /// Form contents when editing began; matching it means nothing to save
+    baseline: Signal<Option<T>>,
+    last_saved: Signal<Option<T>>,
+    /// A stored draft was restored; it stays stored until saved or discarded
+    restored: Signal<bool>,
+    platform: Signal<Platform>,
+}
+
+impl<T: 'static> Clone for DraftPersistence<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for DraftPersistence<T> {}
+
+impl<T> DraftPersistence<T>
+where
+    T: Clone + PartialEq + Serialize + DeserializeOwned + 'static,
+{
+    /// Take the pending draft so the form can apply it
+    pub fn restore(&mut self) -> Option<T> {
+        let draft = self.pending.write().take()?;
+        self.restored.set(true);
+        Some(draft.value)
+    }
+
+    /// Drop the pending draft and remove it from storage
+    pub fn discard(&mut self) {
+        self.pending.set(None);
+        self.restored.set(false);
+        self.platform.peek().storage_remove(&self.key.peek());
+    }
+
+    /// Remove the stored draft, e.g. after the form was saved
+    ///
+    /// The next snapshot becomes the new unedited state.
+    pub fn clear(&mut self) {
+        self.pending.set(None);
+        self.baseline.set(None);
+        self.last_saved.set(None);
+        self.restored.set(false);
+        self.platform.peek().storage_remove(&self.key.peek());
+    }
+
+    /// Compare a snapshot against the baseline and save or remove the draft
+    fn persist(&mut self, snapshot: T) {
+        // Don't overwrite an older draft the user hasn't decided on yet
+        if self.pending.peek().is_some() {
+            return;
+        }
+        if self.baseline.peek().is_none() {
+            self.baseline.set(Some(snapshot));
+            return;
+        }
+        if self.last_saved.peek().as_ref() == Some(&snapshot) {
+            return;
+        }
+
+        let platform = self.platform.peek().clone();
+        let key = self.key.peek().clone();
+        if self.baseline.peek().as_ref() == Some(&snapshot) {
+            // Edits were undone; nothing worth restoring, unless this is a
+            // restored draft, which only a save or discard removes
+            if !*self.restored.peek() {
+                platform.storage_remove(&key);
+            }
+        } else {
+            let draft = SavedDraft {
+                value: snapshot.clone(),
+                saved_at: platform.now_unix_secs(),
+            };
+            match serde_json::to_string(&draft) {
+                Ok(json) => platform.storage_save(&key, &json),
+                Err(e) => tracing::warn!("Failed to serialize draft {}: {}", key, e),
+            }
+        }
+        self.last_saved.set(Some(snapshot));
+    }
+}
+
+/// Autosave a form's contents under `key`
+///
+/// `snapshot` returns the current form contents, or `None` while the form is
+/// still loading; the first snapshot it returns is treated as the unedited
+/// state. The returned handle exposes any draft left over from a previous
+/// visit in `pending`.
+pub fn use_draft_persistence<T, F>(key: String, snapshot: F) -> DraftPersistence<T>
+where
+    T: Clone + PartialEq + Serialize + DeserializeOwned + 'static,
+    F: Fn() -> Option<T> + 'static,
+{
+    let platform = use_context::<Platform>();
+
+    let pending = use_signal(|| {
+        platform
+            .storage_load(&key)
+            .and_then(|json| serde_json::from_str::<SavedDraft<T>>(&json).ok())
+    });
+    let drafts = DraftPersistence {
+        pending,
+        key: use_signal(|| key.clone()),
+        baseline: use_signal(|| None),
+        last_saved: use_signal(|| None),
+        restored: use_signal(|| false),
+        platform: use_signal(|| platform.clone()),
+    };
+
+    // The task belongs to the form's scope and stops when the form closes
+    use_hook(move || {
+        let mut drafts = drafts;
+        spawn(async move {
+            loop {
+                platform.sleep_ms(DRAFT_SAVE_INTERVAL_MS).await;
+                if let Some(value) = snapshot() {
+                    drafts.persist(value);
+                }
+            }
+        })
+    });
+
+    drafts
+}
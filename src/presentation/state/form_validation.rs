@@ -0,0 +1,76 @@
+//! Form validation state - when a form's errors are shown
+//!
+//! Forms validate their values every render with
+//! [`FormRules`](crate::application::services::FormRules). This handle
+//! decides which of those errors the user sees: a field's error shows once
+//! the field has been edited, and every error shows after a save attempt.
+
+use std::collections::{HashMap, HashSet};
+
+use dioxus::prelude::*;
+
+use crate::application::services::ValidationErrors;
+
+/// Which errors of one form are visible
+#[derive(Clone, Copy, PartialEq)]
+pub struct FormValidation {
+    touched: Signal<HashSet<String>>,
+    submitted: Signal<bool>,
+}
+
+impl FormValidation {
+    /// Mark a field as edited, e.g. from its `oninput`
+    pub fn touch(&mut self, field: &str) {
+        if !self.touched.peek().contains(field) {
+            self.touched.write().insert(field.to_string());
+        }
+    }
+
+    /// The error to show under `field`, if any
+    pub fn error(&self, errors: &ValidationErrors, field: &str) -> Option<String> {
+        let visible = *self.submitted.read() || self.touched.read().contains(field);
+        visible.then(|| errors.for_field(field).map(str::to_string)).flatten()
+    }
+
+    /// Every visible error, keyed by field (first error per field)
+    pub fn visible(&self, errors: &ValidationErrors) -> HashMap<String, String> {
+        let submitted = *self.submitted.read();
+        let touched = self.touched.read();
+        let mut visible = HashMap::new();
+        for (field, message) in errors.iter() {
+            if submitted || touched.contains(field) {
+                visible.entry(field.to_string()).or_insert_with(|| message.to_string());
+            }
+        }
+        visible
+    }
+
+    /// Every error, once a save has been attempted
+    pub fn summary(&self, errors: &ValidationErrors) -> Vec<String> {
+        if *self.submitted.read() {
+            errors.messages()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Save gate: reveal every error and report whether the form may save
+    pub fn gate(&mut self, errors: &ValidationErrors) -> bool {
+        self.submitted.set(true);
+        errors.is_empty()
+    }
+
+    /// Hide everything again, e.g. after the form was saved or reloaded
+    pub fn reset(&mut self) {
+        self.touched.write().clear();
+        self.submitted.set(false);
+    }
+}
+
+/// Validation state for a form; nothing is shown until the user edits
+pub fn use_form_validation() -> FormValidation {
+    FormValidation {
+        touched: use_signal(HashSet::new),
+        submitted: use_signal(|| false),
+    }
+}
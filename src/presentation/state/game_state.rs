@@ -3,14 +3,19 @@
 //! Central game state for the Player application.
 
 use dioxus::prelude::*;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use crate::application::dto::{
-    SessionWorldSnapshot, InteractionData, NavigationData, NpcPresenceData,
+    AnnotationSurface, AudioCue, AudioCueKind, CameraEffect, CraftOutcome, Faction, SessionWorldSnapshot, InteractionData, NavigationData, NpcPresenceData,
+    MapReveals, NoteCursor, NoteRevision, NpcMemory, RegionPopulation, RevealScope, SceneMood, SessionRecap, SharedNoteDoc, SurfaceAnnotations,
 };
 use crate::application::dto::websocket_messages::{
     SceneCharacterState, SceneSnapshot, SceneRegionInfo,
 };
+use crate::application::services::{diff_snapshots, strip_trashed, SharedNote, SnapshotDiff};
+use crate::presentation::state::perf_probe;
 
 /// Game time display data
 #[derive(Clone, Debug, PartialEq)]
@@ -45,6 +50,34 @@ pub struct LocationEventData {
     pub description: String,
 }
 
+/// Intermission screen shown while the DM has paused the session
+#[derive(Clone, Debug, PartialEq)]
+pub struct IntermissionData {
+    /// Custom message from the DM
+    pub message: Option<String>,
+    /// Artwork asset path
+    pub art_asset: Option<String>,
+}
+
+/// A world snapshot received mid-session, held back for the DM's review
+#[derive(Clone, Debug)]
+pub struct PendingWorldUpdate {
+    /// The incoming snapshot
+    pub snapshot: Arc<SessionWorldSnapshot>,
+    /// What it changes compared to the loaded world
+    pub diff: SnapshotDiff,
+    /// Apply at the next scene break rather than on the DM's say-so
+    pub deferred: bool,
+}
+
+/// The last update received for a staged scene
+#[derive(Clone, Debug)]
+pub struct StagedSceneView {
+    pub scene: SceneSnapshot,
+    pub characters: Vec<SceneCharacterState>,
+    pub interactions: Vec<InteractionData>,
+}
+
 /// Central game state stored as Dioxus signals
 #[derive(Clone)]
 pub struct GameState {
@@ -70,6 +103,42 @@ pub struct GameState {
     pub approach_event: Signal<Option<ApproachEventData>>,
     /// Active location event (location-wide event)
     pub location_event: Signal<Option<LocationEventData>>,
+    /// Intermission screen while the session is paused
+    pub intermission: Signal<Option<IntermissionData>>,
+    /// World snapshot waiting to be applied
+    pub pending_world: Signal<Option<PendingWorldUpdate>>,
+    /// Looping ambient track set by the last ambient switch cue
+    pub ambient_cue: Signal<Option<AudioCue>>,
+    /// One-shot cues (stings, fanfares) still playing, keyed by id
+    pub audio_cues: Signal<Vec<(u64, AudioCue)>>,
+    /// Id for the next one-shot cue
+    next_audio_cue_id: Signal<u64>,
+    /// Latest camera move, with when it started
+    pub camera_effect: Signal<Option<(u64, CameraEffect)>>,
+    /// DM drawings on backdrops, keyed by `AnnotationSurface::key`
+    pub annotations: Signal<HashMap<String, SurfaceAnnotations>>,
+    /// NPC memories the DM has looked at, keyed by NPC id
+    pub npc_memories: Signal<HashMap<String, Vec<NpcMemory>>>,
+    /// Map regions uncovered by visits or by the DM
+    pub map_reveals: Signal<MapReveals>,
+    /// Notes shared between DM and co-DMs, keyed by `SharedNoteDoc::key`
+    pub shared_notes: Signal<HashMap<String, SharedNote>>,
+    /// Other editors' cursors in shared notes, keyed by `SharedNoteDoc::key`
+    pub note_presence: Signal<HashMap<String, Vec<NoteCursor>>>,
+    /// How the last craft attempt here ended
+    pub craft_outcome: Signal<Option<CraftOutcome>>,
+    /// Why the last craft attempt was refused, if it was
+    pub craft_rejection: Signal<Option<String>>,
+    /// Item most recently identified for a PC here, by ID
+    pub identified_item: Signal<Option<String>>,
+    /// Recap the DM is playing on the stage
+    pub recap: Signal<Option<SessionRecap>>,
+    /// Factions of the loaded world, with standings as the DM adjusts them
+    pub factions: Signal<Vec<Faction>>,
+    /// IDs of narrative events that have triggered, seeded on joining
+    pub triggered_events: Signal<HashSet<String>>,
+    /// Latest update of each parallel scene, keyed by scene ID (DM only)
+    pub staged_scene_views: Signal<HashMap<String, StagedSceneView>>,
 }
 
 impl GameState {
@@ -87,14 +156,73 @@ impl GameState {
             game_time: Signal::new(None),
             approach_event: Signal::new(None),
             location_event: Signal::new(None),
+            intermission: Signal::new(None),
+            pending_world: Signal::new(None),
+            ambient_cue: Signal::new(None),
+            audio_cues: Signal::new(Vec::new()),
+            next_audio_cue_id: Signal::new(0),
+            camera_effect: Signal::new(None),
+            annotations: Signal::new(HashMap::new()),
+            npc_memories: Signal::new(HashMap::new()),
+            map_reveals: Signal::new(MapReveals::default()),
+            shared_notes: Signal::new(HashMap::new()),
+            note_presence: Signal::new(HashMap::new()),
+            craft_outcome: Signal::new(None),
+            craft_rejection: Signal::new(None),
+            identified_item: Signal::new(None),
+            recap: Signal::new(None),
+            factions: Signal::new(Vec::new()),
+            triggered_events: Signal::new(HashSet::new()),
+            staged_scene_views: Signal::new(HashMap::new()),
         }
     }
 
     /// Load a session world snapshot
-    pub fn load_world(&mut self, snapshot: SessionWorldSnapshot) {
+    pub fn load_world(&mut self, mut snapshot: SessionWorldSnapshot) {
+        strip_trashed(&mut snapshot);
+        self.factions.set(snapshot.factions.clone());
         self.world.set(Some(Arc::new(snapshot)));
     }
 
+    /// Hold a mid-session world snapshot back until the DM reviews it
+    ///
+    /// Returns the diff when there is something to review. With no world
+    /// loaded yet, or nothing changed, the snapshot is loaded straight away.
+    pub fn stage_world_update(&mut self, mut snapshot: SessionWorldSnapshot) -> Option<SnapshotDiff> {
+        strip_trashed(&mut snapshot);
+        let diff = match self.world.read().as_ref() {
+            Some(current) => diff_snapshots(current, &snapshot),
+            None => SnapshotDiff::default(),
+        };
+        if diff.is_empty() {
+            self.pending_world.set(None);
+            self.load_world(snapshot);
+            return None;
+        }
+        self.pending_world.set(Some(PendingWorldUpdate {
+            snapshot: Arc::new(snapshot),
+            diff: diff.clone(),
+            deferred: false,
+        }));
+        Some(diff)
+    }
+
+    /// Apply the pending world snapshot now
+    pub fn apply_pending_world(&mut self) {
+        let pending = self.pending_world.write().take();
+        if let Some(pending) = pending {
+            self.factions.set(pending.snapshot.factions.clone());
+            self.world.set(Some(pending.snapshot));
+        }
+    }
+
+    /// Leave the pending world snapshot until the next scene break
+    pub fn defer_pending_world(&mut self) {
+        if let Some(pending) = self.pending_world.write().as_mut() {
+            pending.deferred = true;
+        }
+    }
+
     /// Update from ServerMessage::SceneUpdate
     pub fn apply_scene_update(
         &mut self,
@@ -102,11 +230,58 @@ impl GameState {
         characters: Vec<SceneCharacterState>,
         interactions: Vec<InteractionData>,
     ) {
+        perf_probe::record_signal_write("GameState.current_scene");
+        perf_probe::record_signal_write("GameState.scene_characters");
+        perf_probe::record_signal_write("GameState.interactions");
+        // A deferred world update lands when the scene changes
+        let scene_break = self
+            .current_scene
+            .read()
+            .as_ref()
+            .is_some_and(|current| current.id != scene.id);
+        let deferred = self.pending_world.read().as_ref().is_some_and(|p| p.deferred);
+        if scene_break && deferred {
+            self.apply_pending_world();
+        }
         self.current_scene.set(Some(scene));
         self.scene_characters.set(characters);
         self.interactions.set(interactions);
     }
 
+    /// Keep a parallel scene's update for when the DM switches to it
+    pub fn cache_staged_scene(
+        &mut self,
+        scene: SceneSnapshot,
+        characters: Vec<SceneCharacterState>,
+        interactions: Vec<InteractionData>,
+    ) {
+        self.staged_scene_views.write().insert(
+            scene.id.clone(),
+            StagedSceneView { scene, characters, interactions },
+        );
+    }
+
+    /// Show a parallel scene's latest update as the current scene
+    ///
+    /// Returns false if no update for it has arrived yet.
+    pub fn show_staged_scene(&mut self, scene_id: &str) -> bool {
+        let Some(view) = self.staged_scene_views.peek().get(scene_id).cloned() else {
+            return false;
+        };
+        self.apply_scene_update(view.scene, view.characters, view.interactions);
+        true
+    }
+
+    /// Update an on-stage character from ServerMessage::DispositionChanged
+    pub fn set_character_disposition(&mut self, character_id: &str, sentiment: Option<f32>, hidden: bool) {
+        perf_probe::record_signal_write("GameState.scene_characters");
+        let mut characters = self.scene_characters.write();
+        if let Some(character) = characters.iter_mut().find(|c| c.id == character_id) {
+            character.sentiment = sentiment;
+            character.disposition_hidden = hidden;
+        }
+    }
+
     /// Update from ServerMessage::SceneChanged (navigation)
     pub fn apply_scene_changed(
         &mut self,
@@ -115,6 +290,9 @@ impl GameState {
         npcs_present: Vec<NpcPresenceData>,
         navigation: NavigationData,
     ) {
+        perf_probe::record_signal_write("GameState.current_region");
+        perf_probe::record_signal_write("GameState.npcs_present");
+        perf_probe::record_signal_write("GameState.navigation");
         self.selected_pc_id.set(Some(pc_id));
         self.current_region.set(Some(region));
         self.npcs_present.set(npcs_present);
@@ -128,6 +306,7 @@ impl GameState {
         time_of_day: String,
         is_paused: bool,
     ) {
+        perf_probe::record_signal_write("GameState.game_time");
         self.game_time.set(Some(GameTimeData {
             display,
             time_of_day,
@@ -169,6 +348,152 @@ impl GameState {
         self.location_event.set(None);
     }
 
+    /// Pause the session and show the intermission screen
+    pub fn set_intermission(&mut self, message: Option<String>, art_asset: Option<String>) {
+        self.intermission.set(Some(IntermissionData { message, art_asset }));
+    }
+
+    /// Resume the session
+    pub fn clear_intermission(&mut self) {
+        self.intermission.set(None);
+    }
+
+    /// Play an audio cue from a narrative event or challenge outcome
+    ///
+    /// Ambient switches replace the looping track; other cues play once
+    /// over it. Cues without a sound file are ignored.
+    pub fn play_audio_cue(&mut self, cue: AudioCue) {
+        if cue.asset.trim().is_empty() {
+            return;
+        }
+        match cue.kind {
+            AudioCueKind::Ambient => self.ambient_cue.set(Some(cue)),
+            AudioCueKind::Sting | AudioCueKind::Fanfare => {
+                let id = *self.next_audio_cue_id.peek();
+                self.next_audio_cue_id.set(id + 1);
+                self.audio_cues.write().push((id, cue));
+            }
+        }
+    }
+
+    /// Play a camera move on the stage, replacing any still running
+    ///
+    /// `started_at` (milliseconds) also keys the animation, so the same
+    /// effect twice in a row plays twice.
+    pub fn play_camera_effect(&mut self, started_at: u64, effect: CameraEffect) {
+        self.camera_effect.set(Some((started_at, effect)));
+    }
+
+    /// Drop a one-shot cue once it has finished playing
+    pub fn finish_audio_cue(&mut self, id: u64) {
+        self.audio_cues.write().retain(|(cue_id, _)| *cue_id != id);
+    }
+
+    /// Everything drawn on a surface
+    pub fn annotations_for(&self, surface: &AnnotationSurface) -> SurfaceAnnotations {
+        self.annotations.read().get(&surface.key()).cloned().unwrap_or_default()
+    }
+
+    /// Replace a surface's annotations with the Engine's latest
+    pub fn set_annotations(&mut self, surface: &AnnotationSurface, annotations: SurfaceAnnotations) {
+        self.annotations.write().insert(surface.key(), annotations);
+    }
+
+    /// An NPC's memories, if they've been loaded
+    pub fn memories_for(&self, npc_id: &str) -> Option<Vec<NpcMemory>> {
+        self.npc_memories.read().get(npc_id).cloned()
+    }
+
+    /// Replace an NPC's memories with the Engine's latest
+    pub fn set_npc_memories(&mut self, npc_id: &str, memories: Vec<NpcMemory>) {
+        self.npc_memories.write().insert(npc_id.to_string(), memories);
+    }
+
+    /// A shared note as this editor has it; empty before the Engine sends it
+    pub fn shared_note(&self, doc: &SharedNoteDoc) -> SharedNote {
+        self.shared_notes.read().get(&doc.key()).cloned().unwrap_or_default()
+    }
+
+    /// Change a shared note locally (typing, resolving a conflict)
+    pub fn update_shared_note(&mut self, doc: &SharedNoteDoc, update: impl FnOnce(&mut SharedNote)) {
+        update(self.shared_notes.write().entry(doc.key()).or_default());
+    }
+
+    /// Take in a shared note revision from the Engine; the first one starts the note
+    pub fn apply_shared_note(&mut self, doc: &SharedNoteDoc, revision: NoteRevision, own_user_id: Option<&str>) {
+        match self.shared_notes.write().entry(doc.key()) {
+            Entry::Occupied(mut note) => note.get_mut().apply_remote(revision, own_user_id),
+            Entry::Vacant(slot) => {
+                slot.insert(SharedNote::new(revision));
+            }
+        }
+    }
+
+    /// Other editors' cursors in a shared note
+    pub fn note_cursors(&self, doc: &SharedNoteDoc) -> Vec<NoteCursor> {
+        self.note_presence.read().get(&doc.key()).cloned().unwrap_or_default()
+    }
+
+    /// Mark a region visited by `pc_id`, ahead of the Engine's reveal update
+    pub fn mark_region_visited(&mut self, pc_id: &str, region_id: &str) {
+        let scope = RevealScope::Player { pc_id: pc_id.to_string() };
+        if !self.map_reveals.peek().is_revealed(region_id, Some(pc_id)) {
+            self.map_reveals.write().reveal(&[region_id.to_string()], &scope);
+        }
+    }
+
+    /// Lighting and weather of the region on stage
+    pub fn scene_mood(&self) -> SceneMood {
+        self.current_region
+            .read()
+            .as_ref()
+            .map(|r| r.mood.clone())
+            .unwrap_or_default()
+    }
+
+    /// Apply a region's new mood when it is the region on stage
+    pub fn set_region_mood(&mut self, region_id: &str, mood: SceneMood) {
+        let on_stage = self.current_region.peek().as_ref().is_some_and(|r| r.id == region_id);
+        if on_stage {
+            if let Some(region) = self.current_region.write().as_mut() {
+                region.mood = mood;
+            }
+        }
+    }
+
+    /// Replace a faction with its saved version
+    pub fn set_faction(&mut self, faction: Faction) {
+        let mut factions = self.factions.write();
+        match factions.iter_mut().find(|f| f.id == faction.id) {
+            Some(existing) => *existing = faction,
+            None => factions.push(faction),
+        }
+    }
+
+    /// Apply a region's new crowd when it is the region on stage
+    pub fn set_region_population(&mut self, region_id: &str, population: RegionPopulation) {
+        let on_stage = self.current_region.peek().as_ref().is_some_and(|r| r.id == region_id);
+        if on_stage {
+            if let Some(region) = self.current_region.write().as_mut() {
+                region.population = population;
+            }
+        }
+    }
+
+    /// Show a region's new backdrop when it is the region on stage
+    pub fn set_region_backdrop(&mut self, region_id: &str, backdrop_asset: Option<String>) {
+        let on_stage = self.current_region.peek().as_ref().is_some_and(|r| r.id == region_id);
+        if !on_stage {
+            return;
+        }
+        if let Some(region) = self.current_region.write().as_mut() {
+            region.backdrop_asset = backdrop_asset.clone();
+        }
+        if let Some(scene) = self.current_scene.write().as_mut() {
+            scene.backdrop_asset = backdrop_asset;
+        }
+    }
+
     /// Get the backdrop URL for the current scene
     pub fn backdrop_url(&self) -> Option<String> {
         // First check scene override, then location backdrop
@@ -190,6 +515,23 @@ impl GameState {
         None
     }
 
+    /// Get the animated backdrop URL for the current scene
+    ///
+    /// A scene's backdrop override is a static image, so the location's
+    /// animated backdrop only applies when the scene shows the location's
+    /// own backdrop.
+    pub fn animated_backdrop_url(&self) -> Option<String> {
+        let scene_binding = self.current_scene.read();
+        let scene = scene_binding.as_ref()?;
+        let world_binding = self.world.read();
+        let location = world_binding.as_ref()?.get_location(&scene.location_id)?;
+
+        if scene.backdrop_asset.is_some() && scene.backdrop_asset != location.backdrop_asset {
+            return None;
+        }
+        location.animated_backdrop_asset.clone().filter(|a| !a.is_empty())
+    }
+
     /// Clear all scene data (e.g., when disconnecting)
     pub fn clear_scene(&mut self) {
         self.current_scene.set(None);
@@ -201,11 +543,27 @@ impl GameState {
         self.game_time.set(None);
         self.approach_event.set(None);
         self.location_event.set(None);
+        self.staged_scene_views.set(HashMap::new());
     }
 
     /// Clear all state
     pub fn clear(&mut self) {
         self.world.set(None);
+        self.pending_world.set(None);
+        self.ambient_cue.set(None);
+        self.audio_cues.set(Vec::new());
+        self.camera_effect.set(None);
+        self.annotations.set(HashMap::new());
+        self.npc_memories.set(HashMap::new());
+        self.map_reveals.set(MapReveals::default());
+        self.shared_notes.set(HashMap::new());
+        self.note_presence.set(HashMap::new());
+        self.craft_outcome.set(None);
+        self.craft_rejection.set(None);
+        self.identified_item.set(None);
+        self.recap.set(None);
+        self.factions.set(Vec::new());
+        self.triggered_events.set(HashSet::new());
         self.clear_scene();
     }
 }
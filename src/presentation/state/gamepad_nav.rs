@@ -0,0 +1,228 @@
+//! Controller navigation for the PC View
+//!
+//! The platform reports which controller buttons are held; `GamepadTracker`
+//! turns that into presses (with key-repeat for held directions), and
+//! `FocusRing` tracks which dialogue choice or action panel button the
+//! controller has focused.
+
+use dioxus::prelude::*;
+
+use crate::application::ports::outbound::{GamepadButton, Platform};
+
+/// How often controllers are polled
+const POLL_INTERVAL_MS: u64 = 50;
+
+/// A held direction starts repeating after this long
+const REPEAT_DELAY_MS: u64 = 400;
+
+/// Time between repeats of a held direction
+const REPEAT_INTERVAL_MS: u64 = 150;
+
+fn is_direction(button: GamepadButton) -> bool {
+    matches!(
+        button,
+        GamepadButton::Up | GamepadButton::Down | GamepadButton::Left | GamepadButton::Right
+    )
+}
+
+/// Edge detection over polled controller state
+#[derive(Debug, Default)]
+pub struct GamepadTracker {
+    /// Buttons held at the last poll, with when each next repeats
+    held: Vec<(GamepadButton, u64)>,
+}
+
+impl GamepadTracker {
+    /// Buttons pressed since the last poll
+    ///
+    /// A button fires once when it goes down; directions fire again while
+    /// held, after a short delay.
+    pub fn update(&mut self, pressed: &[GamepadButton], now_ms: u64) -> Vec<GamepadButton> {
+        let mut fired = Vec::new();
+        let mut held = Vec::new();
+        for &button in pressed {
+            if held.iter().any(|(b, _)| *b == button) {
+                continue;
+            }
+            match self.held.iter().find(|(b, _)| *b == button) {
+                None => {
+                    fired.push(button);
+                    held.push((button, now_ms + REPEAT_DELAY_MS));
+                }
+                Some(&(_, repeat_at)) if is_direction(button) && now_ms >= repeat_at => {
+                    fired.push(button);
+                    held.push((button, now_ms + REPEAT_INTERVAL_MS));
+                }
+                Some(&entry) => held.push(entry),
+            }
+        }
+        self.held = held;
+        fired
+    }
+
+    /// Read the platform's controllers and return the new presses
+    pub fn poll(&mut self, platform: &Platform) -> Vec<GamepadButton> {
+        self.update(&platform.gamepad_buttons(), platform.now_millis())
+    }
+}
+
+/// Part of the PC View that has controller focus
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FocusZone {
+    /// Dialogue choices, stacked vertically
+    #[default]
+    Choices,
+    /// Action panel buttons, laid out in a row
+    Actions,
+}
+
+/// The controller's focus: a zone and an item within it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FocusRing {
+    pub zone: FocusZone,
+    pub index: usize,
+}
+
+impl FocusRing {
+    /// Keep focus on an item that exists, moving to the other zone when
+    /// this one is empty
+    pub fn normalized(self, choice_count: usize, action_count: usize) -> Self {
+        let zone = match self.zone {
+            FocusZone::Choices if choice_count == 0 && action_count > 0 => FocusZone::Actions,
+            FocusZone::Actions if action_count == 0 && choice_count > 0 => FocusZone::Choices,
+            zone => zone,
+        };
+        let count = match zone {
+            FocusZone::Choices => choice_count,
+            FocusZone::Actions => action_count,
+        };
+        let index = if zone == self.zone { self.index.min(count.saturating_sub(1)) } else { 0 };
+        Self { zone, index }
+    }
+
+    /// Move focus in response to a direction
+    ///
+    /// Up/Down step through choices and cross between the choices and the
+    /// action panel below them; Left/Right step along the action panel.
+    pub fn navigate(self, button: GamepadButton, choice_count: usize, action_count: usize) -> Self {
+        let ring = self.normalized(choice_count, action_count);
+        match (ring.zone, button) {
+            (FocusZone::Choices, GamepadButton::Up) => Self { index: ring.index.saturating_sub(1), ..ring },
+            (FocusZone::Choices, GamepadButton::Down) if ring.index + 1 < choice_count => {
+                Self { index: ring.index + 1, ..ring }
+            }
+            (FocusZone::Choices, GamepadButton::Down) if action_count > 0 => {
+                Self { zone: FocusZone::Actions, index: 0 }
+            }
+            (FocusZone::Actions, GamepadButton::Up) if choice_count > 0 => {
+                Self { zone: FocusZone::Choices, index: choice_count - 1 }
+            }
+            (FocusZone::Actions, GamepadButton::Left) => Self { index: ring.index.saturating_sub(1), ..ring },
+            (FocusZone::Actions, GamepadButton::Right) if ring.index + 1 < action_count => {
+                Self { index: ring.index + 1, ..ring }
+            }
+            _ => ring,
+        }
+    }
+
+    /// Index of the focused choice, if focus is on the choices
+    pub fn choice(self) -> Option<usize> {
+        (self.zone == FocusZone::Choices).then_some(self.index)
+    }
+
+    /// Index of the focused action panel button, if focus is on the panel
+    pub fn action(self) -> Option<usize> {
+        (self.zone == FocusZone::Actions).then_some(self.index)
+    }
+}
+
+/// Poll connected controllers while the calling component is mounted,
+/// calling `on_press` for each button press
+pub fn use_gamepad(on_press: impl FnMut(GamepadButton) + 'static) {
+    let platform = use_context::<Platform>();
+    let on_press = use_callback(on_press);
+
+    use_future(move || {
+        let platform = platform.clone();
+        async move {
+            let mut tracker = GamepadTracker::default();
+            loop {
+                for button in tracker.poll(&platform) {
+                    on_press.call(button);
+                }
+                platform.sleep_ms(POLL_INTERVAL_MS).await;
+            }
+        }
+    });
+}
+
+/// Click the element carrying the controller focus ring
+///
+/// Activating through the DOM keeps each button's own click handling
+/// (disabled states, closing the mobile sheet) in one place.
+pub fn click_focused() {
+    let _ = document::eval("document.querySelector('[data-gamepad-focus=\"true\"]')?.click();");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::platform::mock::{MockGamepadProvider, MockPlatformBuilder};
+
+    #[test]
+    fn tracker_fires_once_then_repeats_directions() {
+        let mut tracker = GamepadTracker::default();
+        let held = [GamepadButton::Down, GamepadButton::A];
+        assert_eq!(tracker.update(&held, 0), vec![GamepadButton::Down, GamepadButton::A]);
+        assert!(tracker.update(&held, 100).is_empty());
+        assert_eq!(tracker.update(&held, REPEAT_DELAY_MS), vec![GamepadButton::Down]);
+        assert!(tracker.update(&held, REPEAT_DELAY_MS + 50).is_empty());
+        assert_eq!(
+            tracker.update(&held, REPEAT_DELAY_MS + REPEAT_INTERVAL_MS),
+            vec![GamepadButton::Down]
+        );
+
+        // Releasing and pressing again fires straight away
+        assert!(tracker.update(&[], 1000).is_empty());
+        assert_eq!(tracker.update(&[GamepadButton::A], 1010), vec![GamepadButton::A]);
+    }
+
+    #[test]
+    fn focus_moves_from_choices_into_action_panel() {
+        let ring = FocusRing::default();
+        let ring = ring.navigate(GamepadButton::Down, 2, 3);
+        assert_eq!(ring.choice(), Some(1));
+        let ring = ring.navigate(GamepadButton::Down, 2, 3);
+        assert_eq!(ring.action(), Some(0));
+        let ring = ring.navigate(GamepadButton::Right, 2, 3).navigate(GamepadButton::Right, 2, 3);
+        assert_eq!(ring.action(), Some(2));
+        assert_eq!(ring.navigate(GamepadButton::Right, 2, 3).action(), Some(2));
+        assert_eq!(ring.navigate(GamepadButton::Up, 2, 3).choice(), Some(1));
+    }
+
+    #[test]
+    fn polled_presses_move_focus() {
+        let gamepad = MockGamepadProvider::new();
+        let platform = MockPlatformBuilder::new().with_gamepad(gamepad.clone()).build();
+        let mut tracker = GamepadTracker::default();
+        let mut ring = FocusRing::default();
+
+        for held in [&[GamepadButton::Down][..], &[], &[GamepadButton::Down]] {
+            gamepad.hold(held);
+            for button in tracker.poll(&platform) {
+                ring = ring.navigate(button, 2, 3);
+            }
+        }
+        assert_eq!(ring.action(), Some(0));
+
+        // Still held at the same moment, so no repeat yet
+        assert!(tracker.poll(&platform).is_empty());
+    }
+
+    #[test]
+    fn focus_leaves_an_empty_zone() {
+        let ring = FocusRing { zone: FocusZone::Choices, index: 3 };
+        assert_eq!(ring.normalized(0, 4), FocusRing { zone: FocusZone::Actions, index: 0 });
+        assert_eq!(ring.normalized(2, 4), FocusRing { zone: FocusZone::Choices, index: 1 });
+    }
+}
@@ -0,0 +1,193 @@
+//! Generation ETA estimation
+//!
+//! Learns how long batches take per workflow slot (the batch's asset type)
+//! from recent completions and turns that into per-batch wait times and an
+//! overall drain time. ComfyUI works through the queue one batch at a time,
+//! so a batch's wait is the remaining time of everything ahead of it.
+
+use std::collections::{HashMap, VecDeque};
+
+use super::generation_state::{BatchStatus, GenerationBatch};
+
+/// Assumed duration of a batch before any completions have been observed
+pub const DEFAULT_BATCH_SECS: u64 = 60;
+
+/// How many recent completions are remembered per workflow slot
+const MAX_SAMPLES: usize = 10;
+
+/// Recent completion durations per workflow slot
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SlotTimings {
+    samples: HashMap<String, VecDeque<u64>>,
+}
+
+impl SlotTimings {
+    /// Record how long a batch for `slot` took, in seconds
+    pub fn record(&mut self, slot: &str, secs: u64) {
+        let samples = self.samples.entry(slot.to_string()).or_default();
+        if samples.len() == MAX_SAMPLES {
+            samples.pop_front();
+        }
+        samples.push_back(secs);
+    }
+
+    /// Average duration for a slot, if it has completed before
+    pub fn average(&self, slot: &str) -> Option<u64> {
+        let samples = self.samples.get(slot).filter(|s| !s.is_empty())?;
+        Some(samples.iter().sum::<u64>() / samples.len() as u64)
+    }
+
+    /// Best guess for a slot: its own average, else the average over all
+    /// slots, else [`DEFAULT_BATCH_SECS`]
+    pub fn estimate(&self, slot: &str) -> u64 {
+        self.average(slot).unwrap_or_else(|| {
+            let all: Vec<u64> = self.samples.values().flatten().copied().collect();
+            if all.is_empty() {
+                DEFAULT_BATCH_SECS
+            } else {
+                all.iter().sum::<u64>() / all.len() as u64
+            }
+        })
+    }
+}
+
+/// Estimated timing for one active batch
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatchEta {
+    /// Seconds until the batch starts (0 once generating)
+    pub wait_secs: u64,
+    /// Seconds until the batch is ready
+    pub done_secs: u64,
+}
+
+/// Estimates for the whole queue
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueueEta {
+    pub batches: HashMap<String, BatchEta>,
+    /// Seconds until every active batch is done
+    pub drain_secs: u64,
+}
+
+/// Estimate wait and completion times for all active batches at `now`
+pub fn estimate_queue(batches: &[GenerationBatch], timings: &SlotTimings, now: u64) -> QueueEta {
+    let mut active: Vec<&GenerationBatch> = batches
+        .iter()
+        .filter(|b| {
+            matches!(
+                b.status,
+                BatchStatus::Queued { .. } | BatchStatus::Generating { .. }
+            )
+        })
+        .collect();
+    // Generating first, then by queue position
+    active.sort_by_key(|b| match b.status {
+        BatchStatus::Generating { .. } => (0, 0),
+        BatchStatus::Queued { position } => (1, position),
+        _ => (2, 0),
+    });
+
+    let mut eta = QueueEta::default();
+    let mut elapsed = 0;
+    for batch in active {
+        let expected = timings.estimate(&batch.asset_type);
+        let remaining = match batch.status {
+            BatchStatus::Generating { progress } => match batch.started_at {
+                Some(started) => expected.saturating_sub(now.saturating_sub(started)),
+                None => expected * u64::from(100 - progress.min(100)) / 100,
+            },
+            _ => expected,
+        };
+        let wait_secs = if matches!(batch.status, BatchStatus::Generating { .. }) {
+            0
+        } else {
+            elapsed
+        };
+        elapsed += remaining;
+        eta.batches.insert(
+            batch.batch_id.clone(),
+            BatchEta {
+                wait_secs,
+                done_secs: elapsed,
+            },
+        );
+    }
+    eta.drain_secs = elapsed;
+    eta
+}
+
+/// Short human-readable duration, e.g. "<1 min", "~4 min", "~1h 20m"
+pub fn format_eta(secs: u64) -> String {
+    if secs < 60 {
+        "<1 min".to_string()
+    } else if secs < 3600 {
+        format!("~{} min", secs.div_ceil(60))
+    } else {
+        format!("~{}h {}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(id: &str, slot: &str, status: BatchStatus) -> GenerationBatch {
+        GenerationBatch {
+            batch_id: id.to_string(),
+            entity_type: "character".to_string(),
+            entity_id: "c1".to_string(),
+            asset_type: slot.to_string(),
+            status,
+            is_read: false,
+            params: None,
+            queued_at: None,
+            started_at: None,
+        }
+    }
+
+    #[test]
+    fn estimates_fall_back_from_slot_to_overall_to_default() {
+        let mut timings = SlotTimings::default();
+        assert_eq!(timings.estimate("portrait"), DEFAULT_BATCH_SECS);
+
+        timings.record("portrait", 30);
+        timings.record("portrait", 50);
+        timings.record("sprite", 100);
+        assert_eq!(timings.estimate("portrait"), 40);
+        assert_eq!(timings.estimate("backdrop"), 60);
+
+        for _ in 0..MAX_SAMPLES {
+            timings.record("portrait", 20);
+        }
+        assert_eq!(timings.average("portrait"), Some(20));
+    }
+
+    #[test]
+    fn queue_waits_accumulate_in_position_order() {
+        let mut timings = SlotTimings::default();
+        timings.record("portrait", 100);
+        timings.record("sprite", 40);
+
+        let mut running = batch("a", "portrait", BatchStatus::Generating { progress: 50 });
+        running.started_at = Some(1000);
+        let batches = vec![
+            batch("c", "portrait", BatchStatus::Queued { position: 2 }),
+            running,
+            batch("b", "sprite", BatchStatus::Queued { position: 1 }),
+            batch("done", "sprite", BatchStatus::Ready { asset_count: 4 }),
+        ];
+
+        let eta = estimate_queue(&batches, &timings, 1030);
+        assert_eq!(eta.batches["a"], BatchEta { wait_secs: 0, done_secs: 70 });
+        assert_eq!(eta.batches["b"], BatchEta { wait_secs: 70, done_secs: 110 });
+        assert_eq!(eta.batches["c"], BatchEta { wait_secs: 110, done_secs: 210 });
+        assert!(!eta.batches.contains_key("done"));
+        assert_eq!(eta.drain_secs, 210);
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_eta(30), "<1 min");
+        assert_eq!(format_eta(61), "~2 min");
+        assert_eq!(format_eta(4800), "~1h 20m");
+    }
+}
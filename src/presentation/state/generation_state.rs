@@ -5,6 +5,11 @@
 
 use dioxus::prelude::*;
 
+use crate::application::dto::GenerationParams;
+use crate::application::services::SuggestionRating;
+
+use super::generation_estimate::{estimate_queue, QueueEta, SlotTimings};
+
 /// Status of a generation batch
 #[derive(Debug, Clone, PartialEq)]
 pub enum BatchStatus {
@@ -25,12 +30,46 @@ pub enum SuggestionStatus {
     Queued,
     /// Suggestion is being processed
     Processing,
+    /// Suggestion text is arriving; `partial` holds what has streamed so far
+    Streaming { partial: Vec<String> },
     /// Suggestion is ready with results
     Ready { suggestions: Vec<String> },
     /// Suggestion failed
     Failed { error: String },
 }
 
+impl SuggestionStatus {
+    /// Whether the request is still waiting or producing text
+    pub fn is_active(&self) -> bool {
+        matches!(self, Self::Queued | Self::Processing | Self::Streaming { .. })
+    }
+
+    /// The text so far: the partial stream, or the finished suggestions
+    pub fn texts(&self) -> &[String] {
+        match self {
+            Self::Streaming { partial } => partial,
+            Self::Ready { suggestions } => suggestions,
+            _ => &[],
+        }
+    }
+
+    /// Add streamed text to the suggestion at `index`
+    ///
+    /// Chunks arriving after the request finished or failed are dropped.
+    pub fn append_chunk(&mut self, index: usize, delta: &str) {
+        if matches!(self, Self::Queued | Self::Processing) {
+            *self = Self::Streaming { partial: Vec::new() };
+        }
+        let Self::Streaming { partial } = self else {
+            return;
+        };
+        if partial.len() <= index {
+            partial.resize(index + 1, String::new());
+        }
+        partial[index].push_str(delta);
+    }
+}
+
 /// A generation batch in the queue (for images)
 #[derive(Debug, Clone, PartialEq)]
 pub struct GenerationBatch {
@@ -40,6 +79,12 @@ pub struct GenerationBatch {
     pub asset_type: String,
     pub status: BatchStatus,
     pub is_read: bool,
+    /// Parameter overrides used for this batch, kept for reproducibility
+    pub params: Option<GenerationParams>,
+    /// When the batch was queued, in Unix seconds (unknown after hydration)
+    pub queued_at: Option<u64>,
+    /// When generation started, in Unix seconds
+    pub started_at: Option<u64>,
 }
 
 /// A suggestion task in the queue (for text suggestions)
@@ -54,6 +99,10 @@ pub struct SuggestionTask {
     pub context: Option<crate::application::services::suggestion_service::SuggestionContext>,
     /// World ID for routing (needed for retries)
     pub world_id: Option<String>,
+    /// Index of the suggestion the DM put into the field
+    pub applied_index: Option<usize>,
+    /// The DM's rating of these suggestions
+    pub rating: Option<SuggestionRating>,
 }
 
 /// State for managing asset generation and suggestions
@@ -67,6 +116,8 @@ pub struct GenerationState {
     has_ready_batches: Signal<bool>,
     /// Whether there are suggestions ready for selection
     has_ready_suggestions: Signal<bool>,
+    /// Recent completion durations per workflow slot, for ETAs
+    timings: Signal<SlotTimings>,
 }
 
 impl GenerationState {
@@ -77,6 +128,7 @@ impl GenerationState {
             suggestions: Signal::new(Vec::new()),
             has_ready_batches: Signal::new(false),
             has_ready_suggestions: Signal::new(false),
+            timings: Signal::new(SlotTimings::default()),
         }
     }
 
@@ -94,6 +146,8 @@ impl GenerationState {
         entity_id: String,
         asset_type: String,
         position: u32,
+        params: Option<GenerationParams>,
+        now: u64,
     ) {
         let batch = GenerationBatch {
             batch_id,
@@ -102,25 +156,37 @@ impl GenerationState {
             asset_type,
             status: BatchStatus::Queued { position },
             is_read: false,
+            params,
+            queued_at: Some(now),
+            started_at: None,
         };
         self.add_batch(batch);
     }
 
-    /// Update batch progress
-    pub fn batch_progress(&mut self, batch_id: &str, progress: u8) {
+    /// Update batch progress; the first update marks when generation started
+    pub fn batch_progress(&mut self, batch_id: &str, progress: u8, now: u64) {
         let mut batches = self.batches.write();
         if let Some(batch) = batches.iter_mut().find(|b| b.batch_id == batch_id) {
             batch.status = BatchStatus::Generating { progress };
+            batch.started_at.get_or_insert(now);
         }
     }
 
-    /// Mark batch as complete
-    pub fn batch_complete(&mut self, batch_id: &str, asset_count: u32) {
-        {
+    /// Mark batch as complete and learn how long its workflow slot takes
+    pub fn batch_complete(&mut self, batch_id: &str, asset_count: u32, now: u64) {
+        let duration = {
             let mut batches = self.batches.write();
-            if let Some(batch) = batches.iter_mut().find(|b| b.batch_id == batch_id) {
+            batches.iter_mut().find(|b| b.batch_id == batch_id).and_then(|batch| {
                 batch.status = BatchStatus::Ready { asset_count };
-            }
+                // Without a start time (e.g. the first progress update was
+                // missed) the queue wait would skew the sample
+                batch
+                    .started_at
+                    .map(|started| (batch.asset_type.clone(), now.saturating_sub(started)))
+            })
+        };
+        if let Some((slot, secs)) = duration {
+            self.timings.write().record(&slot, secs);
         }
         self.update_ready_flag();
     }
@@ -183,6 +249,11 @@ impl GenerationState {
             .count()
     }
 
+    /// Estimated wait and completion times for active batches at `now`
+    pub fn queue_eta(&self, now: u64) -> QueueEta {
+        estimate_queue(&self.batches.read(), &self.timings.read(), now)
+    }
+
     fn update_ready_flag(&mut self) {
         let has_ready = self
             .batches
@@ -218,6 +289,8 @@ impl GenerationState {
             is_read: false,
             context,
             world_id,
+            applied_index: None,
+            rating: None,
         };
         self.suggestions.write().push(task);
         self.update_ready_flag();
@@ -245,6 +318,8 @@ impl GenerationState {
                     is_read: false,
                     context: None,
                     world_id: None, // Not available when receiving queued event from server
+                    applied_index: None,
+                    rating: None,
                 });
                 true
             }
@@ -262,6 +337,14 @@ impl GenerationState {
         }
     }
 
+    /// Add streamed text to a suggestion
+    pub fn suggestion_chunk(&mut self, request_id: &str, index: usize, delta: &str) {
+        let mut suggestions = self.suggestions.write();
+        if let Some(task) = suggestions.iter_mut().find(|s| s.request_id == request_id) {
+            task.status.append_chunk(index, delta);
+        }
+    }
+
     /// Mark suggestion as complete
     pub fn suggestion_complete(&mut self, request_id: &str, suggestions: Vec<String>) {
         let needs_update = {
@@ -312,17 +395,12 @@ impl GenerationState {
         *self.has_ready_suggestions.read()
     }
 
-    /// Get count of active (queued or processing) suggestions
+    /// Get count of active (queued, processing or streaming) suggestions
     pub fn active_suggestion_count(&self) -> usize {
         self.suggestions
             .read()
             .iter()
-            .filter(|s| {
-                matches!(
-                    s.status,
-                    SuggestionStatus::Queued | SuggestionStatus::Processing
-                )
-            })
+            .filter(|s| s.status.is_active())
             .count()
     }
 
@@ -342,7 +420,25 @@ impl GenerationState {
         }
     }
 
+    /// Record which suggestion the DM put into the field
+    pub fn mark_suggestion_applied(&mut self, request_id: &str, index: usize) {
+        let mut suggestions = self.suggestions.write();
+        if let Some(task) = suggestions.iter_mut().find(|s| s.request_id == request_id) {
+            task.applied_index = Some(index);
+        }
+    }
+
+    /// Record the DM's rating of a suggestion request
+    pub fn rate_suggestion(&mut self, request_id: &str, rating: Option<SuggestionRating>) {
+        let mut suggestions = self.suggestions.write();
+        if let Some(task) = suggestions.iter_mut().find(|s| s.request_id == request_id) {
+            task.rating = rating;
+        }
+    }
+
     /// Clear all batches and suggestions (used when hydrating from snapshot)
+    ///
+    /// Learned slot timings are kept.
     pub fn clear(&mut self) {
         self.batches.set(Vec::new());
         self.suggestions.set(Vec::new());
@@ -356,3 +452,23 @@ impl Default for GenerationState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_build_up_each_suggestion_until_it_finishes() {
+        let mut status = SuggestionStatus::Processing;
+        status.append_chunk(0, "A scarred ");
+        status.append_chunk(1, "A quiet");
+        status.append_chunk(0, "veteran");
+        assert_eq!(status.texts(), ["A scarred veteran".to_string(), "A quiet".to_string()]);
+        assert!(status.is_active());
+
+        let mut failed = SuggestionStatus::Failed { error: "cancelled".to_string() };
+        failed.append_chunk(0, "late");
+        assert!(failed.texts().is_empty());
+        assert!(!failed.is_active());
+    }
+}
@@ -0,0 +1,193 @@
+//! Conversation log filtering and windowing
+//!
+//! The DM's conversation log grows for the whole session. `LogFilter`
+//! narrows it by text, speaker and entry kind; `visible_range` picks the
+//! rows to render for the current scroll position so long logs stay cheap;
+//! the elapsed-time helpers back "jump to" by session time.
+
+use std::ops::Range;
+
+use crate::presentation::state::approval_state::{ConversationLogEntry, LogEntryKind};
+
+/// Fixed height of one log row, in pixels, used for windowing
+pub const LOG_ROW_HEIGHT_PX: f64 = 64.0;
+
+/// Rows rendered above and below the viewport
+const OVERSCAN_ROWS: usize = 8;
+
+/// Search, speaker and kind filters for the conversation log
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogFilter {
+    /// Case-insensitive text matched against speaker and text
+    pub query: String,
+    /// Only entries from this speaker
+    pub speaker: Option<String>,
+    /// Entry kinds that are hidden
+    pub hidden_kinds: Vec<LogEntryKind>,
+}
+
+impl LogFilter {
+    /// Whether any filter is narrowing the log
+    pub fn is_active(&self) -> bool {
+        !self.query.trim().is_empty() || self.speaker.is_some() || !self.hidden_kinds.is_empty()
+    }
+
+    /// Show or hide one kind of entry
+    pub fn toggle_kind(&mut self, kind: LogEntryKind) {
+        if let Some(pos) = self.hidden_kinds.iter().position(|k| *k == kind) {
+            self.hidden_kinds.remove(pos);
+        } else {
+            self.hidden_kinds.push(kind);
+        }
+    }
+
+    pub fn matches(&self, entry: &ConversationLogEntry) -> bool {
+        if self.hidden_kinds.contains(&entry.kind) {
+            return false;
+        }
+        if self.speaker.as_ref().is_some_and(|s| *s != entry.speaker) {
+            return false;
+        }
+        let query = self.query.trim().to_lowercase();
+        query.is_empty()
+            || entry.text.to_lowercase().contains(&query)
+            || entry.speaker.to_lowercase().contains(&query)
+    }
+
+    /// Indices of the entries that pass the filter, in log order
+    pub fn apply(&self, entries: &[ConversationLogEntry]) -> Vec<usize> {
+        entries
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| self.matches(e))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// Distinct speakers in the log, sorted
+pub fn log_speakers(entries: &[ConversationLogEntry]) -> Vec<String> {
+    let mut speakers: Vec<String> = entries.iter().map(|e| e.speaker.clone()).collect();
+    speakers.sort();
+    speakers.dedup();
+    speakers
+}
+
+/// Rows to render for a scroll position, with some overscan either side
+pub fn visible_range(row_count: usize, scroll_top: f64, viewport_height: f64) -> Range<usize> {
+    let first = (scroll_top.max(0.0) / LOG_ROW_HEIGHT_PX).floor() as usize;
+    let shown = (viewport_height.max(0.0) / LOG_ROW_HEIGHT_PX).ceil() as usize + 1;
+    let start = first.saturating_sub(OVERSCAN_ROWS).min(row_count);
+    let end = (first + shown + OVERSCAN_ROWS).min(row_count);
+    start..end
+}
+
+/// Session time as "m:ss" or "h:mm:ss"
+pub fn format_elapsed(secs: u64) -> String {
+    let (h, m, s) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{}:{:02}", m, s)
+    }
+}
+
+/// Parse "m", "m:ss" or "h:mm:ss" into seconds
+pub fn parse_elapsed(input: &str) -> Option<u64> {
+    let parts: Vec<u64> = input
+        .trim()
+        .split(':')
+        .map(|p| p.trim().parse::<u64>().ok())
+        .collect::<Option<_>>()?;
+    match parts.as_slice() {
+        [m] => Some(m * 60),
+        [m, s] if *s < 60 => Some(m * 60 + s),
+        [h, m, s] if *m < 60 && *s < 60 => Some(h * 3600 + m * 60 + s),
+        _ => None,
+    }
+}
+
+/// Row (within `rows`) of the first entry at or after `elapsed` seconds
+/// into the log, or the last row if the log ends earlier
+pub fn row_at_elapsed(entries: &[ConversationLogEntry], rows: &[usize], elapsed: u64) -> Option<usize> {
+    let start = entries.first()?.timestamp;
+    let target = start.saturating_add(elapsed);
+    rows.iter()
+        .position(|&i| entries[i].timestamp >= target)
+        .or_else(|| rows.len().checked_sub(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(speaker: &str, text: &str, kind: LogEntryKind, timestamp: u64) -> ConversationLogEntry {
+        ConversationLogEntry {
+            speaker: speaker.to_string(),
+            text: text.to_string(),
+            is_system: kind != LogEntryKind::Dialogue,
+            kind,
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn filters_by_query_speaker_and_kind() {
+        let log = vec![
+            entry("System", "Joined session", LogEntryKind::System, 100),
+            entry("Mira", "The cellar key is gone", LogEntryKind::Dialogue, 110),
+            entry("System", "[CHALLENGE] Ada - Pick Lock: success", LogEntryKind::Challenge, 120),
+            entry("Oswin", "Ask Mira about the key", LogEntryKind::Dialogue, 130),
+        ];
+
+        let mut filter = LogFilter::default();
+        assert!(!filter.is_active());
+        assert_eq!(filter.apply(&log), vec![0, 1, 2, 3]);
+
+        filter.query = "KEY".to_string();
+        assert_eq!(filter.apply(&log), vec![1, 3]);
+
+        filter.speaker = Some("Mira".to_string());
+        assert_eq!(filter.apply(&log), vec![1]);
+
+        let mut filter = LogFilter::default();
+        filter.toggle_kind(LogEntryKind::System);
+        filter.toggle_kind(LogEntryKind::Dialogue);
+        assert_eq!(filter.apply(&log), vec![2]);
+        filter.toggle_kind(LogEntryKind::Dialogue);
+        assert_eq!(filter.apply(&log), vec![1, 2, 3]);
+
+        assert_eq!(log_speakers(&log), vec!["Mira", "Oswin", "System"]);
+    }
+
+    #[test]
+    fn window_covers_viewport_with_overscan() {
+        assert_eq!(visible_range(0, 0.0, 600.0), 0..0);
+        assert_eq!(visible_range(1000, 0.0, 640.0), 0..19);
+        let range = visible_range(1000, 64.0 * 500.0, 640.0);
+        assert_eq!(range, 492..519);
+        assert_eq!(visible_range(20, 64.0 * 500.0, 640.0), 20..20);
+    }
+
+    #[test]
+    fn jumps_by_elapsed_session_time() {
+        assert_eq!(parse_elapsed("5"), Some(300));
+        assert_eq!(parse_elapsed("12:05"), Some(725));
+        assert_eq!(parse_elapsed("1:02:03"), Some(3723));
+        assert_eq!(parse_elapsed("1:75"), None);
+        assert_eq!(parse_elapsed("soon"), None);
+        assert_eq!(format_elapsed(725), "12:05");
+        assert_eq!(format_elapsed(3723), "1:02:03");
+
+        let log = vec![
+            entry("System", "a", LogEntryKind::System, 1_000),
+            entry("Mira", "b", LogEntryKind::Dialogue, 1_300),
+            entry("Mira", "c", LogEntryKind::Dialogue, 1_900),
+        ];
+        let rows = vec![1, 2];
+        assert_eq!(row_at_elapsed(&log, &rows, 400), Some(1));
+        assert_eq!(row_at_elapsed(&log, &rows, 0), Some(0));
+        assert_eq!(row_at_elapsed(&log, &rows, 9_999), Some(1));
+        assert_eq!(row_at_elapsed(&log, &[], 0), None);
+    }
+}
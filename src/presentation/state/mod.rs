@@ -3,26 +3,55 @@
 //! Central state management using Dioxus signals and context.
 
 pub mod approval_state;
+pub mod async_play_state;
+pub mod auth_state;
 pub mod challenge_state;
 pub mod connection_state;
 pub mod dialogue_state;
+pub mod display_state;
+pub mod draft_persistence;
+pub mod form_validation;
 pub mod game_state;
+pub mod gamepad_nav;
+pub mod generation_estimate;
 pub mod generation_state;
+pub mod log_filter;
+pub mod modal_focus;
+pub mod paged_list;
+pub mod party_state;
+pub mod perf_probe;
+pub mod presence_state;
 pub mod session_state;
+pub mod tag_filter_state;
+pub mod whisper_state;
 
 // Export individual substates
-pub use approval_state::{ConversationLogEntry, PendingApproval, PendingChallengeOutcome};
+pub use approval_state::{ConversationLogEntry, LogEntryKind, PendingApproval, PendingChallengeOutcome};
+pub use auth_state::AuthState;
 pub use challenge_state::RollSubmissionStatus;
 pub use connection_state::ConnectionStatus;
-pub use dialogue_state::{use_typewriter_effect, DialogueState};
-pub use game_state::{GameState, GameTimeData, ApproachEventData, LocationEventData};
+pub use dialogue_state::{use_typewriter_effect, BacklogLine, DialogueState};
+pub use display_state::DisplayState;
+pub use draft_persistence::{draft_key, use_draft_persistence};
+pub use form_validation::use_form_validation;
+pub use gamepad_nav::{click_focused, use_gamepad, FocusRing};
+pub use game_state::{GameState, GameTimeData, ApproachEventData, IntermissionData, LocationEventData};
+pub use generation_estimate::{format_eta, BatchEta};
 pub use generation_state::{BatchStatus, GenerationBatch, GenerationState, SuggestionStatus, SuggestionTask};
+pub use log_filter::{format_elapsed, log_speakers, parse_elapsed, row_at_elapsed, visible_range, LogFilter, LOG_ROW_HEIGHT_PX};
+pub use modal_focus::use_modal_focus;
+pub use paged_list::{is_near_bottom, use_paged_list};
+pub use party_state::PartyMember;
+pub use tag_filter_state::TagFilterState;
+pub use whisper_state::WhisperMessage;
 
 // SessionState is the facade that composes the substates (backward-compatible)
-pub use session_state::SessionState;
+pub use session_state::{Possession, ReconnectGrace, SessionState};
 
 use dioxus::prelude::*;
 
+use crate::application::services::TemplateContext;
+
 /// Get the game state from context
 ///
 /// # Panics
@@ -47,6 +76,22 @@ pub fn use_dialogue_state() -> DialogueState {
     use_context::<DialogueState>()
 }
 
+/// Get the auth state from context
+///
+/// # Panics
+/// Panics if AuthState has not been provided via use_context_provider
+pub fn use_auth_state() -> AuthState {
+    use_context::<AuthState>()
+}
+
+/// Get the tag filter state from context
+///
+/// # Panics
+/// Panics if TagFilterState has not been provided via use_context_provider
+pub fn use_tag_filter_state() -> TagFilterState {
+    use_context::<TagFilterState>()
+}
+
 /// Get the generation state from context
 ///
 /// # Panics
@@ -54,3 +99,52 @@ pub fn use_dialogue_state() -> DialogueState {
 pub fn use_generation_state() -> GenerationState {
     use_context::<GenerationState>()
 }
+
+/// Get the display state from context
+///
+/// # Panics
+/// Panics if DisplayState has not been provided via use_context_provider
+pub fn use_display_state() -> DisplayState {
+    use_context::<DisplayState>()
+}
+
+/// Template variables for the current game state
+///
+/// Covers `world.name`, `scene.name`, `location.name`, `region.name`,
+/// `pc.name` (the local player's character) and, while a challenge is
+/// active, `challenge.name` and `skill.name`.
+pub fn template_context(game_state: &GameState, session_state: &SessionState) -> TemplateContext {
+    let mut context = TemplateContext::new();
+    if let Some(world) = game_state.world.read().as_ref() {
+        context.set("world.name", world.world.name.clone());
+    }
+    if let Some(scene) = game_state.current_scene.read().as_ref() {
+        context.set("scene.name", scene.name.clone());
+        context.set("location.name", scene.location_name.clone());
+    }
+    if let Some(region) = game_state.current_region.read().as_ref() {
+        context.set("region.name", region.name.clone());
+        context.set("location.name", region.location_name.clone());
+    }
+    let local_user_id = session_state.user_id().read().clone();
+    if let Some(pc_name) = session_state
+        .presence
+        .participants
+        .read()
+        .iter()
+        .find(|p| Some(&p.user_id) == local_user_id.as_ref())
+        .and_then(|p| p.character_name.clone())
+    {
+        context.set("pc.name", pc_name);
+    }
+    if let Some(challenge) = session_state.active_challenge().read().as_ref() {
+        context.set("challenge.name", challenge.challenge_name.clone());
+        context.set("skill.name", challenge.skill_name.clone());
+    }
+    context
+}
+
+/// Template variables for the current game state, from context
+pub fn use_template_context() -> TemplateContext {
+    template_context(&use_game_state(), &use_session_state())
+}
@@ -0,0 +1,64 @@
+//! Keyboard focus for modals
+//!
+//! While a modal is open, Tab and Shift+Tab cycle through its controls
+//! instead of escaping to the page behind it. Focus moves into the modal
+//! when it opens and goes back to whatever had it when the modal closes.
+//!
+//! The modal's dialog element needs a stable `id`; pass the same id here.
+
+use dioxus::prelude::*;
+
+/// Elements Tab can land on
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+/// Trap focus in the dialog with this id for as long as the calling
+/// component is mounted, then restore it
+pub fn use_modal_focus(dialog_id: &'static str) {
+    use_effect(move || {
+        let js = format!(
+            r#"
+            const root = document.getElementById('{id}');
+            if (root && !root.dataset.focusTrap) {{
+                root.dataset.focusTrap = 'true';
+                window.__wrldbldrFocusReturn = window.__wrldbldrFocusReturn || {{}};
+                window.__wrldbldrFocusReturn['{id}'] = document.activeElement;
+                const items = () => Array.from(root.querySelectorAll("{focusable}"));
+                if (!root.hasAttribute('tabindex')) {{ root.setAttribute('tabindex', '-1'); }}
+                (items()[0] || root).focus();
+                root.addEventListener('keydown', (e) => {{
+                    if (e.key !== 'Tab') {{ return; }}
+                    // Nested modals handle their own Tab
+                    e.stopPropagation();
+                    const list = items();
+                    if (list.length === 0) {{ e.preventDefault(); return; }}
+                    const first = list[0];
+                    const last = list[list.length - 1];
+                    if (e.shiftKey && document.activeElement === first) {{
+                        e.preventDefault();
+                        last.focus();
+                    }} else if (!e.shiftKey && document.activeElement === last) {{
+                        e.preventDefault();
+                        first.focus();
+                    }}
+                }});
+            }}
+            "#,
+            id = dialog_id,
+            focusable = FOCUSABLE,
+        );
+        let _ = document::eval(&js);
+    });
+
+    use_drop(move || {
+        let js = format!(
+            r#"
+            const store = window.__wrldbldrFocusReturn || {{}};
+            const previous = store['{id}'];
+            delete store['{id}'];
+            if (previous && document.contains(previous) && previous.focus) {{ previous.focus(); }}
+            "#,
+            id = dialog_id,
+        );
+        let _ = document::eval(&js);
+    });
+}
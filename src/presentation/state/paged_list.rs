@@ -0,0 +1,250 @@
+//! Incrementally loaded lists backed by paginated Engine endpoints
+//!
+//! `PagedList` keeps the pages loaded so far plus one prefetched page, so
+//! scrolling to the end of a list appends the next page without waiting on
+//! the network while the page after it loads in the background.
+
+use std::future::Future;
+
+use dioxus::prelude::*;
+use futures_channel::oneshot;
+
+use crate::application::ports::outbound::{ApiError, Page, PageRequest};
+
+/// Distance from the bottom of a scroll container, in pixels, at which the
+/// next page is appended
+pub const LOAD_MORE_THRESHOLD_PX: f64 = 200.0;
+
+/// Whether a scroll event happened close enough to the bottom to load more
+pub fn is_near_bottom(event: &ScrollEvent) -> bool {
+    let remaining = event.scroll_height() as f64 - event.scroll_top() - event.client_height() as f64;
+    remaining <= LOAD_MORE_THRESHOLD_PX
+}
+
+/// Reactive state for a paginated list
+pub struct PagedList<T: 'static> {
+    /// Items loaded so far, in Engine order
+    pub items: Signal<Vec<T>>,
+    /// First page in flight
+    pub loading: Signal<bool>,
+    /// Error from loading the first page
+    pub error: Signal<Option<String>>,
+    next_cursor: Signal<Option<String>>,
+    prefetched: Signal<Option<Page<T>>>,
+    fetching: Signal<bool>,
+    /// Woken when the fetch in flight settles
+    fetch_waiters: Signal<Vec<oneshot::Sender<()>>>,
+}
+
+impl<T: 'static> Clone for PagedList<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for PagedList<T> {}
+
+impl<T: 'static> PartialEq for PagedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
+impl<T: 'static> PagedList<T> {
+    /// Empty list owned by the current scope
+    fn new() -> Self {
+        Self {
+            items: Signal::new(Vec::new()),
+            loading: Signal::new(true),
+            error: Signal::new(None),
+            next_cursor: Signal::new(None),
+            prefetched: Signal::new(None),
+            fetching: Signal::new(false),
+            fetch_waiters: Signal::new(Vec::new()),
+        }
+    }
+}
+
+impl<T: Clone + 'static> PagedList<T> {
+    /// Whether more items are available beyond those loaded
+    pub fn has_more(&self) -> bool {
+        self.prefetched.read().is_some() || self.next_cursor.read().is_some()
+    }
+
+    /// Whether a page is currently being fetched in the background
+    pub fn is_fetching(&self) -> bool {
+        *self.fetching.read()
+    }
+
+    /// Discard loaded items and load the first page, then prefetch the second
+    pub async fn load_first<F, Fut>(mut self, fetch: F)
+    where
+        F: Fn(PageRequest) -> Fut,
+        Fut: Future<Output = Result<Page<T>, ApiError>>,
+    {
+        self.loading.set(true);
+        self.error.set(None);
+        self.prefetched.set(None);
+        self.next_cursor.set(None);
+
+        match fetch(PageRequest::default()).await {
+            Ok(page) => {
+                self.items.set(page.items);
+                self.next_cursor.set(page.next_cursor);
+                self.loading.set(false);
+                self.prefetch(&fetch).await;
+            }
+            Err(e) => {
+                self.error.set(Some(e.to_string()));
+                self.loading.set(false);
+            }
+        }
+    }
+
+    /// Append the next page (from the prefetch if it has arrived), then
+    /// prefetch the one after it
+    pub async fn load_more<F, Fut>(mut self, fetch: F)
+    where
+        F: Fn(PageRequest) -> Fut,
+        Fut: Future<Output = Result<Page<T>, ApiError>>,
+    {
+        if *self.fetching.peek() {
+            return;
+        }
+        if self.prefetched.peek().is_none() {
+            self.prefetch(&fetch).await;
+        }
+        let Some(page) = self.prefetched.write().take() else {
+            return;
+        };
+        self.items.write().extend(page.items);
+        self.next_cursor.set(page.next_cursor);
+        self.prefetch(&fetch).await;
+    }
+
+    /// Load every remaining page, for views that need the whole collection
+    ///
+    /// A page already being fetched (say, the prefetch started by
+    /// `load_first`) is waited for rather than treated as the end.
+    pub async fn load_all<F, Fut>(mut self, fetch: F)
+    where
+        F: Fn(PageRequest) -> Fut,
+        Fut: Future<Output = Result<Page<T>, ApiError>>,
+    {
+        loop {
+            self.fetch_settled().await;
+            if self.prefetched.peek().is_none() && self.next_cursor.peek().is_none() {
+                break;
+            }
+            let loaded = self.items.peek().len();
+            self.load_more(&fetch).await;
+            if self.items.peek().len() == loaded && self.prefetched.peek().is_none() {
+                // Fetch failed or returned nothing; stop rather than spin
+                break;
+            }
+        }
+    }
+
+    /// Wait for the fetch in flight, if any
+    async fn fetch_settled(&mut self) {
+        if !*self.fetching.peek() {
+            return;
+        }
+        let (settled, wait) = oneshot::channel();
+        self.fetch_waiters.write().push(settled);
+        let _ = wait.await;
+    }
+
+    async fn prefetch<F, Fut>(&mut self, fetch: &F)
+    where
+        F: Fn(PageRequest) -> Fut,
+        Fut: Future<Output = Result<Page<T>, ApiError>>,
+    {
+        let Some(cursor) = self.next_cursor.peek().clone() else {
+            return;
+        };
+        self.fetching.set(true);
+        match fetch(PageRequest::after(cursor, PageRequest::DEFAULT_LIMIT)).await {
+            Ok(page) => {
+                self.next_cursor.set(None);
+                self.prefetched.set(Some(page));
+            }
+            // Keep the cursor so the next scroll retries
+            Err(e) => tracing::warn!("Failed to prefetch next page: {}", e),
+        }
+        self.fetching.set(false);
+        for settled in self.fetch_waiters.write().drain(..) {
+            let _ = settled.send(());
+        }
+    }
+}
+
+/// Create an empty paged list owned by the calling component
+pub fn use_paged_list<T: 'static>() -> PagedList<T> {
+    use_hook(PagedList::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use futures_util::future::join3;
+
+    use super::*;
+
+    /// Pages of 1-2, 3-4 and 5; `gate`, while set, holds back the second page
+    fn pages(gate: Rc<RefCell<Option<oneshot::Receiver<()>>>>) -> impl Fn(PageRequest) -> std::pin::Pin<Box<dyn Future<Output = Result<Page<u32>, ApiError>>>> {
+        move |req: PageRequest| {
+            let gate = if req.cursor.as_deref() == Some("2") { gate.borrow_mut().take() } else { None };
+            Box::pin(async move {
+                if let Some(gate) = gate {
+                    let _ = gate.await;
+                }
+                let (items, next_cursor) = match req.cursor.as_deref() {
+                    None => (vec![1, 2], Some("2")),
+                    Some("2") => (vec![3, 4], Some("4")),
+                    _ => (vec![5], None),
+                };
+                Ok(Page { items, next_cursor: next_cursor.map(str::to_string) })
+            })
+        }
+    }
+
+    fn run<O>(f: impl FnOnce() -> O) -> O {
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+        dom.in_scope(ScopeId::ROOT, f)
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(future)
+    }
+
+    #[test]
+    fn load_all_fetches_every_page() {
+        run(|| {
+            let list = PagedList::<u32>::new();
+            let fetch = pages(Rc::new(RefCell::new(None)));
+            block_on(async {
+                list.load_first(&fetch).await;
+                list.load_all(&fetch).await;
+            });
+            assert_eq!(*list.items.peek(), vec![1, 2, 3, 4, 5]);
+            assert!(!list.has_more());
+        });
+    }
+
+    #[test]
+    fn load_all_waits_for_the_prefetch_in_flight() {
+        run(|| {
+            let list = PagedList::<u32>::new();
+            let (release, gate) = oneshot::channel();
+            let fetch = pages(Rc::new(RefCell::new(Some(gate))));
+            block_on(join3(list.load_first(&fetch), list.load_all(&fetch), async move {
+                let _ = release.send(());
+            }));
+            assert_eq!(*list.items.peek(), vec![1, 2, 3, 4, 5]);
+        });
+    }
+}
@@ -0,0 +1,213 @@
+//! Party state management using Dioxus signals
+//!
+//! Live per-PC state for the DM's party overview. Members are seeded from the
+//! session's PC list, then kept current from server messages: PC selection
+//! and movement, level changes, and sheet updates.
+
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+use crate::application::dto::FieldValue;
+use crate::application::services::PlayerCharacterData;
+
+/// One player character as shown in the party overview
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartyMember {
+    pub pc_id: String,
+    pub name: String,
+    /// The player controlling this PC
+    pub user_id: String,
+    pub portrait_asset: Option<String>,
+    pub location_id: String,
+    /// Region the PC is standing in, once it has moved this session
+    pub region_name: Option<String>,
+    pub level: u32,
+    pub pending_levels: u32,
+    /// Current sheet values, keyed by field ID
+    pub sheet_values: HashMap<String, FieldValue>,
+}
+
+impl PartyMember {
+    fn from_pc(pc: PlayerCharacterData) -> Self {
+        Self {
+            pc_id: pc.id,
+            name: pc.name,
+            user_id: pc.user_id,
+            portrait_asset: pc.portrait_asset,
+            location_id: pc.current_location_id,
+            region_name: None,
+            level: pc.level,
+            pending_levels: pc.pending_levels,
+            sheet_values: pc.sheet_data.map(|s| s.values).unwrap_or_default(),
+        }
+    }
+}
+
+/// Party state for the DM's party overview
+#[derive(Clone)]
+pub struct PartyState {
+    /// Player characters in the session, in the order the Engine lists them
+    pub members: Signal<Vec<PartyMember>>,
+}
+
+impl PartyState {
+    /// Create an empty PartyState
+    pub fn new() -> Self {
+        Self {
+            members: Signal::new(Vec::new()),
+        }
+    }
+
+    /// Replace the party with a freshly loaded PC list
+    ///
+    /// Regions learned from movement are kept, since the PC list doesn't
+    /// carry them.
+    pub fn seed(&mut self, pcs: Vec<PlayerCharacterData>) {
+        let previous = self.members.peek().clone();
+        let members = pcs
+            .into_iter()
+            .map(|pc| {
+                let mut member = PartyMember::from_pc(pc);
+                if let Some(known) = previous.iter().find(|m| m.pc_id == member.pc_id) {
+                    if known.location_id == member.location_id {
+                        member.region_name = known.region_name.clone();
+                    }
+                }
+                member
+            })
+            .collect();
+        self.members.set(members);
+    }
+
+    /// A PC was selected for play at a location
+    pub fn set_location(&mut self, pc_id: &str, location_id: String) {
+        self.update(pc_id, |m| {
+            if m.location_id != location_id {
+                m.region_name = None;
+            }
+            m.location_id = location_id;
+        });
+    }
+
+    /// A PC moved into a region
+    pub fn set_region(&mut self, pc_id: &str, location_id: String, region_name: String) {
+        self.update(pc_id, |m| {
+            m.location_id = location_id;
+            m.region_name = Some(region_name);
+        });
+    }
+
+    /// A PC's level or pending level-ups changed
+    pub fn set_level(&mut self, pc_id: &str, level: u32, pending_levels: u32) {
+        self.update(pc_id, |m| {
+            m.level = level;
+            m.pending_levels = pending_levels;
+        });
+    }
+
+    /// Merge changed sheet values into a PC's sheet
+    pub fn update_sheet(&mut self, pc_id: &str, values: HashMap<String, FieldValue>) {
+        self.update(pc_id, |m| m.sheet_values.extend(values));
+    }
+
+    /// Clear the party
+    pub fn clear(&mut self) {
+        self.members.set(Vec::new());
+    }
+
+    fn update(&mut self, pc_id: &str, change: impl FnOnce(&mut PartyMember)) {
+        let mut members = self.members.write();
+        if let Some(member) = members.iter_mut().find(|m| m.pc_id == pc_id) {
+            change(member);
+        }
+    }
+}
+
+impl Default for PartyState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::services::player_character_service::CharacterSheetDataApi;
+
+    fn run<O>(f: impl FnOnce() -> O) -> O {
+        let mut dom = VirtualDom::new(|| rsx! {});
+        dom.rebuild_in_place();
+        dom.in_scope(ScopeId::ROOT, f)
+    }
+
+    fn pc(id: &str, location_id: &str) -> PlayerCharacterData {
+        PlayerCharacterData {
+            id: id.to_string(),
+            session_id: "s-1".to_string(),
+            user_id: format!("user-{}", id),
+            world_id: "w-1".to_string(),
+            name: id.to_uppercase(),
+            description: None,
+            sheet_data: Some(CharacterSheetDataApi {
+                values: HashMap::from([("hp".to_string(), FieldValue::Number(10))]),
+            }),
+            current_location_id: location_id.to_string(),
+            starting_location_id: location_id.to_string(),
+            sprite_asset: None,
+            portrait_asset: None,
+            experience: 0,
+            level: 1,
+            pending_levels: 0,
+            created_at: String::new(),
+            last_active_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn reseeding_keeps_regions_only_for_pcs_that_stayed_put() {
+        run(|| {
+            let mut party = PartyState::new();
+            party.seed(vec![pc("a", "inn"), pc("b", "inn")]);
+            party.set_region("a", "inn".to_string(), "Taproom".to_string());
+            party.set_region("b", "inn".to_string(), "Cellar".to_string());
+
+            party.seed(vec![pc("a", "inn"), pc("b", "docks")]);
+            let members = party.members.peek();
+            assert_eq!(members[0].region_name.as_deref(), Some("Taproom"));
+            assert_eq!(members[1].region_name, None);
+        });
+    }
+
+    #[test]
+    fn changing_location_drops_the_old_region() {
+        run(|| {
+            let mut party = PartyState::new();
+            party.seed(vec![pc("a", "inn")]);
+            party.set_region("a", "inn".to_string(), "Taproom".to_string());
+
+            party.set_location("a", "inn".to_string());
+            assert_eq!(party.members.peek()[0].region_name.as_deref(), Some("Taproom"));
+            party.set_location("a", "docks".to_string());
+            assert_eq!(party.members.peek()[0].region_name, None);
+            assert_eq!(party.members.peek()[0].location_id, "docks");
+        });
+    }
+
+    #[test]
+    fn level_and_sheet_updates_apply_to_one_pc() {
+        run(|| {
+            let mut party = PartyState::new();
+            party.seed(vec![pc("a", "inn"), pc("b", "inn")]);
+
+            party.set_level("a", 2, 1);
+            party.update_sheet("a", HashMap::from([("hp".to_string(), FieldValue::Number(7))]));
+            party.set_level("missing", 9, 9);
+
+            let members = party.members.peek();
+            assert_eq!((members[0].level, members[0].pending_levels), (2, 1));
+            assert_eq!(members[0].sheet_values.get("hp"), Some(&FieldValue::Number(7)));
+            assert_eq!((members[1].level, members[1].pending_levels), (1, 0));
+            assert_eq!(members[1].sheet_values.get("hp"), Some(&FieldValue::Number(10)));
+        });
+    }
+}
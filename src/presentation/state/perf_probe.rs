@@ -0,0 +1,236 @@
+//! Performance probe - Lightweight counters for the developer overlay
+//!
+//! Components, state containers, and the WebSocket handler report into one
+//! process-wide probe: renders per component, signal writes per state field,
+//! received server messages, and frame intervals. While the overlay is off,
+//! recording is a single atomic load, so the calls can stay in hot paths.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use crate::application::ports::outbound::{storage_keys, Platform};
+
+/// How many recent frame intervals are kept
+pub const FRAME_WINDOW: usize = 120;
+/// Window over which the message rate is averaged
+pub const MESSAGE_WINDOW_MS: u64 = 5_000;
+/// Frames slower than this (about 30fps) count as slow
+pub const SLOW_FRAME_MS: f64 = 33.4;
+
+/// Frame interval statistics over the recent window
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameStats {
+    pub average_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+    pub slow_frames: usize,
+}
+
+/// Point-in-time view of the counters, hottest first
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PerfSnapshot {
+    pub renders: Vec<(&'static str, u64)>,
+    pub signal_writes: Vec<(&'static str, u64)>,
+    pub messages_per_sec: f64,
+    pub frames: FrameStats,
+}
+
+/// The raw counters behind the probe
+#[derive(Debug, Default)]
+pub struct PerfCounters {
+    renders: HashMap<&'static str, u64>,
+    signal_writes: HashMap<&'static str, u64>,
+    /// Receive times of recent messages, oldest first
+    messages: VecDeque<u64>,
+    frames: VecDeque<f64>,
+}
+
+impl PerfCounters {
+    pub fn record_render(&mut self, component: &'static str) {
+        *self.renders.entry(component).or_default() += 1;
+    }
+
+    pub fn record_signal_write(&mut self, signal: &'static str) {
+        *self.signal_writes.entry(signal).or_default() += 1;
+    }
+
+    pub fn record_message(&mut self, now_ms: u64) {
+        self.messages.push_back(now_ms);
+        self.prune_messages(now_ms);
+    }
+
+    pub fn record_frame(&mut self, interval_ms: f64) {
+        if self.frames.len() == FRAME_WINDOW {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(interval_ms);
+    }
+
+    /// Summarise the counters, keeping the `top` busiest components and signals
+    pub fn snapshot(&mut self, now_ms: u64, top: usize) -> PerfSnapshot {
+        self.prune_messages(now_ms);
+        PerfSnapshot {
+            renders: hottest(&self.renders, top),
+            signal_writes: hottest(&self.signal_writes, top),
+            messages_per_sec: self.messages.len() as f64 * 1000.0 / MESSAGE_WINDOW_MS as f64,
+            frames: frame_stats(&self.frames),
+        }
+    }
+
+    fn prune_messages(&mut self, now_ms: u64) {
+        let cutoff = now_ms.saturating_sub(MESSAGE_WINDOW_MS);
+        while self.messages.front().is_some_and(|t| *t < cutoff) {
+            self.messages.pop_front();
+        }
+    }
+}
+
+fn hottest(counts: &HashMap<&'static str, u64>, top: usize) -> Vec<(&'static str, u64)> {
+    let mut counts: Vec<(&'static str, u64)> = counts.iter().map(|(k, v)| (*k, *v)).collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    counts.truncate(top);
+    counts
+}
+
+fn frame_stats(frames: &VecDeque<f64>) -> FrameStats {
+    if frames.is_empty() {
+        return FrameStats::default();
+    }
+    let mut sorted: Vec<f64> = frames.iter().copied().collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let p95_index = ((sorted.len() as f64 * 0.95).ceil() as usize).clamp(1, sorted.len()) - 1;
+    FrameStats {
+        average_ms: sorted.iter().sum::<f64>() / sorted.len() as f64,
+        p95_ms: sorted[p95_index],
+        max_ms: sorted[sorted.len() - 1],
+        slow_frames: sorted.iter().filter(|f| **f > SLOW_FRAME_MS).count(),
+    }
+}
+
+/// Counters plus the switch that turns recording on
+pub struct PerfProbe {
+    enabled: AtomicBool,
+    counters: Mutex<PerfCounters>,
+}
+
+/// The process-wide probe
+pub fn global() -> &'static PerfProbe {
+    static PROBE: OnceLock<PerfProbe> = OnceLock::new();
+    PROBE.get_or_init(|| PerfProbe {
+        enabled: AtomicBool::new(false),
+        counters: Mutex::new(PerfCounters::default()),
+    })
+}
+
+/// Start or stop recording; counters restart from zero when turned on
+pub fn set_enabled(enabled: bool) {
+    let probe = global();
+    if enabled && !probe.enabled.load(Ordering::Relaxed) {
+        reset();
+    }
+    probe.enabled.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    global().enabled.load(Ordering::Relaxed)
+}
+
+fn with_counters(f: impl FnOnce(&mut PerfCounters)) {
+    if !is_enabled() {
+        return;
+    }
+    if let Ok(mut counters) = global().counters.lock() {
+        f(&mut counters);
+    }
+}
+
+/// Count a render; call at the top of a component body
+pub fn record_render(component: &'static str) {
+    with_counters(|c| c.record_render(component));
+}
+
+/// Count a write to a state container's signal
+pub fn record_signal_write(signal: &'static str) {
+    with_counters(|c| c.record_signal_write(signal));
+}
+
+/// Count a message received from the Engine
+pub fn record_message(now_ms: u64) {
+    with_counters(|c| c.record_message(now_ms));
+}
+
+/// Record the time between two frames
+pub fn record_frame(interval_ms: f64) {
+    with_counters(|c| c.record_frame(interval_ms));
+}
+
+/// Summarise the global counters
+pub fn snapshot(now_ms: u64, top: usize) -> PerfSnapshot {
+    global()
+        .counters
+        .lock()
+        .map(|mut c| c.snapshot(now_ms, top))
+        .unwrap_or_default()
+}
+
+/// Clear the global counters
+pub fn reset() {
+    if let Ok(mut counters) = global().counters.lock() {
+        *counters = PerfCounters::default();
+    }
+}
+
+/// Whether the overlay is turned on for this device
+pub fn load_overlay_enabled(platform: &Platform) -> bool {
+    platform.storage_load(storage_keys::PERF_OVERLAY).as_deref() == Some("true")
+}
+
+/// Save the overlay toggle for this device
+pub fn save_overlay_enabled(platform: &Platform, enabled: bool) {
+    platform.storage_save(storage_keys::PERF_OVERLAY, if enabled { "true" } else { "false" });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranks_hotspots_and_rates_messages() {
+        let mut counters = PerfCounters::default();
+        for _ in 0..3 {
+            counters.record_render("Backdrop");
+        }
+        counters.record_render("DialogueBox");
+        counters.record_render("ActionPanel");
+        counters.record_signal_write("DialogueState.displayed_text");
+        for t in [0, 4_000, 6_000, 9_000] {
+            counters.record_message(t);
+        }
+
+        let snapshot = counters.snapshot(10_000, 2);
+        assert_eq!(snapshot.renders, vec![("Backdrop", 3), ("ActionPanel", 1)]);
+        assert_eq!(snapshot.signal_writes, vec![("DialogueState.displayed_text", 1)]);
+        // Only the messages at 6s and 9s fall in the last five seconds
+        assert_eq!(snapshot.messages_per_sec, 0.4);
+    }
+
+    #[test]
+    fn summarises_recent_frames() {
+        let mut counters = PerfCounters::default();
+        assert_eq!(counters.snapshot(0, 5).frames, FrameStats::default());
+
+        for _ in 0..FRAME_WINDOW {
+            counters.record_frame(100.0);
+        }
+        for _ in 0..19 {
+            counters.record_frame(16.0);
+        }
+        counters.record_frame(50.0);
+
+        let frames = counters.snapshot(0, 5).frames;
+        assert_eq!(frames.max_ms, 100.0);
+        assert_eq!(frames.p95_ms, 100.0);
+        assert_eq!(frames.slow_frames, FRAME_WINDOW - 19);
+    }
+}
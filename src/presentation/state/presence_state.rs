@@ -0,0 +1,142 @@
+//! Presence state management using Dioxus signals
+//!
+//! Tracks who is showing a webcam or avatar tile in the presence strip,
+//! which remote videos are live, and the local user's per-tile preferences.
+
+use dioxus::prelude::*;
+use std::collections::HashSet;
+
+use crate::application::ports::outbound::{PresenceMode, PresenceSignalData};
+
+/// A remote participant as shown in the presence strip
+#[derive(Debug, Clone, PartialEq)]
+pub struct PresenceParticipant {
+    pub user_id: String,
+    /// Character name, if the participant is playing one
+    pub character_name: Option<String>,
+    pub mode: PresenceMode,
+}
+
+impl PresenceParticipant {
+    /// Label for the participant's tile
+    pub fn display_name(&self) -> &str {
+        self.character_name.as_deref().unwrap_or(&self.user_id)
+    }
+}
+
+/// Whether two participants should hold a peer connection
+///
+/// Both must have a tile, and at least one must be sending video.
+pub fn should_link(local: PresenceMode, remote: PresenceMode) -> bool {
+    local != PresenceMode::Off
+        && remote != PresenceMode::Off
+        && (local == PresenceMode::Webcam || remote == PresenceMode::Webcam)
+}
+
+/// Whether the local user sends the offer for a link with `peer_id`
+///
+/// The lexicographically smaller user ID always offers, so both sides agree
+/// without negotiating.
+pub fn is_offerer(local_user_id: &str, peer_id: &str) -> bool {
+    local_user_id < peer_id
+}
+
+/// Presence state for the webcam/avatar strip
+#[derive(Clone)]
+pub struct PresenceState {
+    /// Other participants in the session
+    pub participants: Signal<Vec<PresenceParticipant>>,
+    /// How the local user appears to others
+    pub local_mode: Signal<PresenceMode>,
+    /// Camera access requested but not granted yet
+    pub camera_pending: Signal<bool>,
+    /// Peers whose video stream is currently live
+    pub remote_video: Signal<HashSet<String>>,
+    /// Participants whose tiles the local user has hidden
+    pub hidden_users: Signal<HashSet<String>>,
+    /// Strip offset from its default corner, in pixels
+    pub strip_offset: Signal<(f64, f64)>,
+    /// Signaling messages waiting to be handed to the media adapter
+    pub incoming_signals: Signal<Vec<(String, PresenceSignalData)>>,
+}
+
+impl PresenceState {
+    /// Create a new PresenceState with presence off
+    pub fn new() -> Self {
+        Self {
+            participants: Signal::new(Vec::new()),
+            local_mode: Signal::new(PresenceMode::Off),
+            camera_pending: Signal::new(false),
+            remote_video: Signal::new(HashSet::new()),
+            hidden_users: Signal::new(HashSet::new()),
+            strip_offset: Signal::new((0.0, 0.0)),
+            incoming_signals: Signal::new(Vec::new()),
+        }
+    }
+
+    /// Add or update a participant
+    pub fn upsert_participant(&mut self, user_id: String, character_name: Option<String>, mode: PresenceMode) {
+        let mut participants = self.participants.write();
+        match participants.iter_mut().find(|p| p.user_id == user_id) {
+            Some(existing) => {
+                if character_name.is_some() {
+                    existing.character_name = character_name;
+                }
+                existing.mode = mode;
+            }
+            None => participants.push(PresenceParticipant {
+                user_id,
+                character_name,
+                mode,
+            }),
+        }
+    }
+
+    /// Remove a participant who left the session
+    pub fn remove_participant(&mut self, user_id: &str) {
+        self.participants.write().retain(|p| p.user_id != user_id);
+        self.remote_video.write().remove(user_id);
+    }
+
+    /// Queue a signaling message received from a peer
+    pub fn queue_signal(&mut self, from_user_id: String, signal: PresenceSignalData) {
+        self.incoming_signals.write().push((from_user_id, signal));
+    }
+
+    /// Take all queued signaling messages
+    pub fn take_signals(&mut self) -> Vec<(String, PresenceSignalData)> {
+        std::mem::take(&mut *self.incoming_signals.write())
+    }
+
+    /// Record whether a peer's video is live
+    pub fn set_remote_video(&mut self, user_id: String, live: bool) {
+        if live {
+            self.remote_video.write().insert(user_id);
+        } else {
+            self.remote_video.write().remove(&user_id);
+        }
+    }
+
+    /// Show or hide a participant's tile locally
+    pub fn toggle_hidden(&mut self, user_id: &str) {
+        let mut hidden = self.hidden_users.write();
+        if !hidden.remove(user_id) {
+            hidden.insert(user_id.to_string());
+        }
+    }
+
+    /// Clear all presence state
+    pub fn clear(&mut self) {
+        self.participants.set(Vec::new());
+        self.local_mode.set(PresenceMode::Off);
+        self.camera_pending.set(false);
+        self.remote_video.set(HashSet::new());
+        self.incoming_signals.set(Vec::new());
+    }
+}
+
+impl Default for PresenceState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
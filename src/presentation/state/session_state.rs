@@ -5,15 +5,29 @@
 //! for more focused functionality.
 
 use dioxus::prelude::*;
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::application::ports::outbound::{ApprovalDecision, GameConnectionPort, ParticipantRole, Platform};
+use crate::application::dto::{ActiveCondition, ChallengeAutoResolveRules, DirectorialContext};
+use crate::application::ports::outbound::{
+    ApprovalDecision, Capability, DecisionTimerSettings, GameConnectionPort, ParticipantRole, Platform,
+    SpectatorPolicy,
+};
+use crate::application::services::{
+    ActionQueue, ActionService, ConversationIndex, EngineHealth, RollHistory, RollRecord, SceneStaging, SessionDiceLog,
+    StoryCapture,
+};
+use crate::domain::entities::PlayerAction;
 use crate::presentation::components::tactical::PlayerSkillData;
 
 // Re-export substates and their types
 pub use crate::presentation::state::connection_state::{ConnectionState, ConnectionStatus};
+pub use crate::presentation::state::async_play_state::AsyncPlayState;
 pub use crate::presentation::state::approval_state::{ApprovalState, PendingApproval, ApprovalHistoryEntry, ConversationLogEntry};
 pub use crate::presentation::state::challenge_state::{ChallengeState, ChallengePromptData, ChallengeResultData};
+pub use crate::presentation::state::party_state::PartyState;
+pub use crate::presentation::state::presence_state::PresenceState;
+pub use crate::presentation::state::whisper_state::WhisperState;
 
 /// Session state for connection and user information
 ///
@@ -28,6 +42,76 @@ pub struct SessionState {
     pub approval: ApprovalState,
     /// Challenge-related state (active challenge, results, skills)
     pub challenge: ChallengeState,
+    /// Presence strip state (webcam/avatar tiles)
+    pub presence: PresenceState,
+    /// Private player↔DM messages
+    pub whispers: WhisperState,
+    /// Live per-PC state for the DM's party overview
+    pub party: PartyState,
+    /// Play-by-post setting, the DM's action inbox and the player's posted actions
+    pub async_play: AsyncPlayState,
+    /// What spectators are allowed to see, as set by the DM
+    pub spectator_policy: Signal<SpectatorPolicy>,
+    /// Time limit on dialogue choices and challenge rolls, as set by the DM
+    pub decision_timer: Signal<DecisionTimerSettings>,
+    /// Tone and preset the NPCs follow, as last set by any DM (DM only)
+    pub directorial_context: Signal<Option<DirectorialContext>>,
+    /// Level-up waiting to be taken by the local player
+    pub pending_level_up: Signal<Option<PendingLevelUp>>,
+    /// Player actions waiting to be sent, and the one awaiting an answer
+    pub action_queue: Signal<ActionQueue>,
+    /// Story events captured from session activity, waiting to be sent
+    pub story_capture: Signal<StoryCapture>,
+    /// Dialogue lines and rolls of the session, for linking timeline events back
+    pub conversation_index: Signal<ConversationIndex>,
+    /// Players whose connection dropped, still within their grace period
+    pub reconnecting: Signal<Vec<ReconnectGrace>>,
+    /// Active conditions on PCs and NPCs, keyed by character ID
+    pub conditions: Signal<HashMap<String, Vec<ActiveCondition>>>,
+    /// Character the DM is currently acting as, if any
+    pub possession: Signal<Option<Possession>>,
+    /// Scenes running in parallel while the party is split, and who is in each
+    pub scene_staging: Signal<SceneStaging>,
+}
+
+/// A character the DM has taken control of ("possess NPC")
+///
+/// While set, queued actions go out as that character and are marked
+/// DM-controlled.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Possession {
+    pub character_id: String,
+    pub character_name: String,
+    /// Whether the character is a player's PC rather than an NPC
+    pub is_pc: bool,
+}
+
+/// A disconnected player whose place is held while they reconnect
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectGrace {
+    pub user_id: String,
+    /// Their PC, which stays locked to them until the grace period ends
+    pub pc_id: Option<String>,
+    /// When the grace period ends, in `Platform::now_millis` time
+    pub expires_at_ms: u64,
+}
+
+impl ReconnectGrace {
+    /// Whole seconds left in the grace period
+    pub fn remaining_secs(&self, now_ms: u64) -> u64 {
+        self.expires_at_ms.saturating_sub(now_ms).div_ceil(1000)
+    }
+}
+
+/// A level-up the local player has earned but not yet taken
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingLevelUp {
+    pub pc_id: String,
+    pub pc_name: String,
+    /// Level before this level-up
+    pub level: u32,
+    /// Levels earned and waiting, including this one
+    pub pending_levels: u32,
 }
 
 impl SessionState {
@@ -37,6 +121,21 @@ impl SessionState {
             connection: ConnectionState::new(),
             approval: ApprovalState::new(),
             challenge: ChallengeState::new(),
+            presence: PresenceState::new(),
+            whispers: WhisperState::new(),
+            party: PartyState::new(),
+            async_play: AsyncPlayState::new(),
+            spectator_policy: Signal::new(SpectatorPolicy::default()),
+            decision_timer: Signal::new(DecisionTimerSettings::default()),
+            directorial_context: Signal::new(None),
+            pending_level_up: Signal::new(None),
+            action_queue: Signal::new(ActionQueue::new()),
+            story_capture: Signal::new(StoryCapture::new()),
+            conversation_index: Signal::new(ConversationIndex::new()),
+            reconnecting: Signal::new(Vec::new()),
+            conditions: Signal::new(HashMap::new()),
+            possession: Signal::new(None),
+            scene_staging: Signal::new(SceneStaging::new()),
         }
     }
 
@@ -59,11 +158,21 @@ impl SessionState {
         self.connection.user_id.clone()
     }
 
-    /// User role (DungeonMaster, Player, Spectator)
+    /// User role (DungeonMaster, CoDungeonMaster, Player, Spectator)
     pub fn user_role(&self) -> Signal<Option<ParticipantRole>> {
         self.connection.user_role.clone()
     }
 
+    /// Roles of the other participants, keyed by user ID
+    pub fn participant_roles(&self) -> Signal<HashMap<String, ParticipantRole>> {
+        self.connection.participant_roles
+    }
+
+    /// Whether the local user's role allows the given action
+    pub fn can(&self, capability: Capability) -> bool {
+        self.connection.can(capability)
+    }
+
     /// Server URL we're connected to
     pub fn server_url(&self) -> Signal<Option<String>> {
         self.connection.server_url.clone()
@@ -122,6 +231,11 @@ impl SessionState {
         self.connection.comfyui_retry_in_seconds.clone()
     }
 
+    /// Engine dependency health (ComfyUI, LLM, latency)
+    pub fn engine_health(&self) -> Signal<EngineHealth> {
+        self.connection.engine_health
+    }
+
     // =========================================================================
     // Backward-compatible methods (delegate to substates)
     // =========================================================================
@@ -134,6 +248,7 @@ impl SessionState {
     /// Set the connection to connected state
     pub fn set_connected(&mut self, client: Arc<dyn GameConnectionPort>) {
         self.connection.set_connected(client);
+        self.send_next_action();
     }
 
     /// Store the connection handle without changing UI status.
@@ -151,19 +266,33 @@ impl SessionState {
         self.connection.set_user(user_id, role);
     }
 
+    /// Record a participant's role; updates our own role if it is us
+    pub fn set_participant_role(&mut self, user_id: String, role: ParticipantRole) {
+        self.connection.set_participant_role(user_id, role);
+    }
+
+    /// Forget a participant who left the session
+    pub fn remove_participant_role(&mut self, user_id: &str) {
+        self.connection.remove_participant_role(user_id);
+    }
+
     /// Set the connection to disconnected state
     pub fn set_disconnected(&mut self) {
         self.connection.set_disconnected();
+        // Any answer to the in-flight action is lost with the connection
+        self.action_queue.write().settle();
     }
 
     /// Set the connection to failed state with error
     pub fn set_failed(&mut self, error: String) {
         self.connection.set_failed(error);
+        self.action_queue.write().settle();
     }
 
     /// Set the connection to reconnecting state
     pub fn set_reconnecting(&mut self) {
         self.connection.set_reconnecting();
+        self.action_queue.write().settle();
     }
 
     /// Clear all session state
@@ -171,6 +300,156 @@ impl SessionState {
         self.connection.clear();
         self.approval.clear();
         self.challenge.clear();
+        self.presence.clear();
+        self.whispers.clear();
+        self.party.clear();
+        self.async_play.clear();
+        self.spectator_policy.set(SpectatorPolicy::default());
+        self.decision_timer.set(DecisionTimerSettings::default());
+        self.directorial_context.set(None);
+        self.pending_level_up.set(None);
+        self.action_queue.write().clear();
+        self.story_capture.write().clear();
+        self.conversation_index.write().clear();
+        self.reconnecting.set(Vec::new());
+        self.conditions.set(HashMap::new());
+        self.possession.set(None);
+        self.scene_staging.set(SceneStaging::new());
+    }
+
+    /// Hold a disconnected player's place for the grace period
+    pub fn mark_reconnecting(&mut self, user_id: String, pc_id: Option<String>, grace_secs: u32, platform: &Platform) {
+        let expires_at_ms = platform.now_millis() + u64::from(grace_secs) * 1000;
+        let mut reconnecting = self.reconnecting.write();
+        reconnecting.retain(|r| r.user_id != user_id);
+        reconnecting.push(ReconnectGrace { user_id, pc_id, expires_at_ms });
+    }
+
+    /// Release a player's grace period (they reconnected or left for good)
+    pub fn end_reconnect_grace(&mut self, user_id: &str) {
+        self.reconnecting.write().retain(|r| r.user_id != user_id);
+    }
+
+    /// The grace period holding a PC for its reconnecting player, if any
+    pub fn reconnect_grace(&self, user_id: &str, pc_id: &str) -> Option<ReconnectGrace> {
+        self.reconnecting
+            .read()
+            .iter()
+            .find(|r| r.user_id == user_id || r.pc_id.as_deref() == Some(pc_id))
+            .cloned()
+    }
+
+    /// A character's active conditions, in the order the Engine sent them
+    pub fn conditions_for(&self, character_id: &str) -> Vec<ActiveCondition> {
+        self.conditions.read().get(character_id).cloned().unwrap_or_default()
+    }
+
+    /// Replace a character's conditions with the Engine's latest list
+    pub fn set_conditions(&mut self, character_id: String, conditions: Vec<ActiveCondition>) {
+        let mut all = self.conditions.write();
+        if conditions.is_empty() {
+            all.remove(&character_id);
+        } else {
+            all.insert(character_id, conditions);
+        }
+    }
+
+    /// Record a session moment for the timeline
+    ///
+    /// Only the DM's client captures, so co-DMs don't create duplicates.
+    pub fn capture_story(&mut self, record: impl FnOnce(&mut StoryCapture)) {
+        if *self.user_role().peek() == Some(ParticipantRole::DungeonMaster) {
+            record(&mut self.story_capture.write());
+        }
+    }
+
+    /// Queue a player action; it is sent once earlier actions are answered
+    pub fn queue_action(&mut self, action: PlayerAction, label: impl Into<String>) {
+        self.action_queue.write().enqueue(action, label);
+        self.send_next_action();
+    }
+
+    /// Start acting as a character; pending actions are dropped so nothing
+    /// queued as the DM goes out under the character's name
+    pub fn possess(&mut self, possession: Possession) {
+        self.action_queue.write().clear();
+        self.possession.set(Some(possession));
+    }
+
+    /// Stop acting as a character
+    pub fn release_possession(&mut self) {
+        self.action_queue.write().clear();
+        self.possession.set(None);
+    }
+
+    /// Cancel a queued action that hasn't been sent yet
+    pub fn cancel_queued_action(&mut self, id: u64) {
+        self.action_queue.write().cancel(id);
+    }
+
+    /// The in-flight action failed; send the next one
+    pub fn settle_action(&mut self) {
+        if self.action_queue.write().settle().is_some() {
+            self.send_next_action();
+        }
+    }
+
+    /// The Engine received an action; if it was ours, send the next one
+    ///
+    /// Every action type is acknowledged, whether or not it is answered with
+    /// dialogue, so this is what releases the queue. Acknowledgements for
+    /// other players' actions are ignored.
+    pub fn action_received(&mut self, action_id: &str, player_id: &str) {
+        if self.user_id().peek().as_deref() != Some(player_id) {
+            return;
+        }
+        if self.action_queue.write().acknowledge(action_id).is_some() {
+            self.send_next_action();
+        }
+    }
+
+    /// The Engine stored one of our actions for the DM to answer later
+    ///
+    /// It is remembered as posted, and the next queued action goes out
+    /// without waiting for an answer.
+    pub fn action_posted(&mut self, action_id: &str, platform: &Platform, world_id: &str) {
+        let acknowledged = self
+            .action_queue
+            .peek()
+            .acknowledged(action_id)
+            .map(|sent| sent.label.clone());
+        let label = match acknowledged {
+            Some(label) => label,
+            None => {
+                let Some(sent) = self.action_queue.write().settle() else {
+                    return;
+                };
+                self.send_next_action();
+                sent.label
+            }
+        };
+        let now = platform.now_unix_secs();
+        self.async_play
+            .update_posted(platform, world_id, |posted| posted.post(action_id, &label, now));
+    }
+
+    /// Send the next queued action if none is in flight
+    fn send_next_action(&mut self) {
+        let Some(client) = self.connection.engine_client.peek().clone() else {
+            return;
+        };
+        let Some(action) = self.action_queue.write().take_next() else {
+            return;
+        };
+        let service = ActionService::new(client);
+        let result = match self.possession.peek().as_ref() {
+            Some(possession) => service.send_possessed_action(&possession.character_id, action),
+            None => service.send_action(action),
+        };
+        if let Err(e) = result {
+            tracing::error!("Failed to send action: {}", e);
+            self.action_queue.write().settle();
+        }
     }
 
     /// Add a pending approval request
@@ -188,6 +467,11 @@ impl SessionState {
         self.approval.add_log_entry(speaker, text, is_system, platform);
     }
 
+    /// Add a challenge entry to the conversation log
+    pub fn add_challenge_log_entry(&mut self, text: String, platform: &Platform) {
+        self.approval.add_challenge_log_entry(text, platform);
+    }
+
     /// Check if we have an active client
     pub fn has_client(&self) -> bool {
         self.connection.has_client()
@@ -208,6 +492,26 @@ impl SessionState {
         self.challenge.add_challenge_result(result);
     }
 
+    /// This player's roll history for the current world
+    pub fn roll_history(&self) -> Signal<RollHistory> {
+        self.challenge.roll_history
+    }
+
+    /// Load this device's roll history for a world, unless already loaded
+    pub fn load_roll_history(&mut self, platform: &Platform, world_id: &str) {
+        self.challenge.load_roll_history(platform, world_id);
+    }
+
+    /// Remember a roll the player made
+    pub fn record_roll(&mut self, platform: &Platform, world_id: &str, record: RollRecord) {
+        self.challenge.record_roll(platform, world_id, record);
+    }
+
+    /// Everyone's rolls this session
+    pub fn session_dice(&self) -> Signal<SessionDiceLog> {
+        self.challenge.session_dice
+    }
+
     /// Set player skills
     pub fn set_player_skills(&mut self, skills: Vec<PlayerSkillData>) {
         self.challenge.set_player_skills(skills);
@@ -229,7 +533,8 @@ impl SessionState {
     }
 
     /// Record an approval decision: send it to the Engine, log it locally with
-    /// a real timestamp, and remove it from the pending queue.
+    /// a real timestamp, add it to the session's audit log, and remove it from
+    /// the pending queue.
     pub fn record_approval_decision(
         &mut self,
         request_id: String,
@@ -237,7 +542,14 @@ impl SessionState {
         platform: &Platform,
     ) {
         let engine_client = self.connection.engine_client.read().clone();
-        self.approval.record_approval_decision(request_id, decision, platform, &engine_client);
+        let session_id = self.connection.session_id.read().clone();
+        self.approval.record_approval_decision(
+            request_id,
+            decision,
+            platform,
+            &engine_client,
+            session_id.as_deref(),
+        );
     }
 
     // =========================================================================
@@ -274,6 +586,50 @@ impl SessionState {
         self.approval.add_pending_challenge_outcome(outcome);
     }
 
+    /// Apply a challenge outcome without the DM if the world's auto-resolve
+    /// rules allow it; false means it still needs the DM's approval
+    ///
+    /// Only one client sends the Accept: the DM's, or while no DM is
+    /// connected, the co-DM's with the lowest user ID. The others just
+    /// leave covered outcomes out of the queue.
+    pub fn auto_apply_challenge_outcome(
+        &mut self,
+        outcome: &crate::presentation::state::approval_state::PendingChallengeOutcome,
+        difficulty_class: Option<i32>,
+        platform: &Platform,
+    ) -> bool {
+        if !self.sends_auto_accepts() {
+            return self.approval.auto_resolve_margin(outcome, difficulty_class).is_some();
+        }
+        let engine_client = self.connection.engine_client.read().clone();
+        let session_id = self.connection.session_id.read().clone();
+        self.approval.auto_apply_challenge_outcome(
+            outcome,
+            difficulty_class,
+            platform,
+            &engine_client,
+            session_id.as_deref(),
+        )
+    }
+
+    /// Whether this client is the one that accepts auto-resolved outcomes
+    fn sends_auto_accepts(&self) -> bool {
+        match *self.user_role().peek() {
+            Some(ParticipantRole::DungeonMaster) => true,
+            Some(ParticipantRole::CoDungeonMaster) => {
+                let Some(user_id) = self.connection.user_id.peek().clone() else {
+                    return false;
+                };
+                let roles = self.connection.participant_roles.peek();
+                !roles.iter().any(|(id, role)| {
+                    *role == ParticipantRole::DungeonMaster
+                        || (*role == ParticipantRole::CoDungeonMaster && id.as_str() < user_id.as_str())
+                })
+            }
+            _ => false,
+        }
+    }
+
     /// Remove a pending challenge outcome by resolution_id
     pub fn remove_pending_challenge_outcome(&mut self, resolution_id: &str) {
         self.approval.remove_pending_challenge_outcome(resolution_id);
@@ -299,6 +655,11 @@ impl SessionState {
         self.approval.set_challenge_generating_suggestions(resolution_id, generating);
     }
 
+    /// The world's challenge auto-resolve rules
+    pub fn challenge_auto_resolve(&self) -> Signal<ChallengeAutoResolveRules> {
+        self.approval.challenge_auto_resolve
+    }
+
     /// Pending challenge outcomes accessor
     pub fn pending_challenge_outcomes(&self) -> Signal<Vec<crate::presentation::state::approval_state::PendingChallengeOutcome>> {
         self.approval.pending_challenge_outcomes.clone()
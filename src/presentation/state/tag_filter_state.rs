@@ -0,0 +1,63 @@
+//! Tag filter state management using Dioxus signals
+//!
+//! Holds the tags the DM is currently filtering by. The filter is global
+//! so that it carries across Creator, Story Arc and the Challenge Library.
+
+use dioxus::prelude::*;
+
+use crate::domain::value_objects::tags::{contains_tag, matches_all_tags, normalize_tag};
+
+/// Global tag filter shared by the DM views
+#[derive(Clone)]
+pub struct TagFilterState {
+    /// Tags an entity must carry to be shown (empty = no filtering)
+    pub active_tags: Signal<Vec<String>>,
+}
+
+impl TagFilterState {
+    /// Create a new TagFilterState with no active tags
+    pub fn new() -> Self {
+        Self {
+            active_tags: Signal::new(Vec::new()),
+        }
+    }
+
+    /// Whether any tag filter is active
+    pub fn is_filtering(&self) -> bool {
+        !self.active_tags.read().is_empty()
+    }
+
+    /// Whether the given tag is part of the active filter
+    pub fn is_active(&self, tag: &str) -> bool {
+        contains_tag(&self.active_tags.read(), tag)
+    }
+
+    /// Whether an entity with the given tags passes the filter
+    pub fn matches(&self, entity_tags: &[String]) -> bool {
+        matches_all_tags(entity_tags, &self.active_tags.read())
+    }
+
+    /// Add a tag to the filter, or remove it if already active
+    pub fn toggle(&mut self, tag: &str) {
+        let Some(tag) = normalize_tag(tag) else {
+            return;
+        };
+        let mut active = self.active_tags.write();
+        if let Some(pos) = active.iter().position(|t| t.eq_ignore_ascii_case(&tag)) {
+            active.remove(pos);
+        } else {
+            active.push(tag);
+        }
+    }
+
+    /// Clear all tag filters
+    pub fn clear(&mut self) {
+        self.active_tags.set(Vec::new());
+    }
+}
+
+impl Default for TagFilterState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
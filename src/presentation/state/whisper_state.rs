@@ -0,0 +1,122 @@
+//! Whisper state management using Dioxus signals
+//!
+//! Private player↔DM messages. They are kept apart from the conversation log,
+//! which mirrors what every participant sees, and grouped into one thread per
+//! player.
+
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// A single private message between a player and the DM
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhisperMessage {
+    pub id: String,
+    /// The player whose thread this belongs to
+    pub player_user_id: String,
+    /// Character name of the player, if known
+    pub player_name: Option<String>,
+    pub text: String,
+    /// Written by the DM rather than the player
+    pub from_dm: bool,
+    /// Timestamp (for ordering)
+    pub timestamp: u64,
+}
+
+/// Summary of one player's whisper thread
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhisperThread {
+    pub player_user_id: String,
+    pub display_name: String,
+    pub last_message: String,
+    pub unread: usize,
+}
+
+/// Whisper state for private player↔DM messages
+#[derive(Clone)]
+pub struct WhisperState {
+    /// All whispers this client may see, oldest first
+    pub messages: Signal<Vec<WhisperMessage>>,
+    /// Unread counts per player thread
+    pub unread: Signal<HashMap<String, usize>>,
+}
+
+impl WhisperState {
+    /// Create an empty WhisperState
+    pub fn new() -> Self {
+        Self {
+            messages: Signal::new(Vec::new()),
+            unread: Signal::new(HashMap::new()),
+        }
+    }
+
+    /// Record a whisper; `unread` is false for messages we sent ourselves
+    pub fn add_whisper(&mut self, message: WhisperMessage, unread: bool) {
+        if self.messages.peek().iter().any(|m| m.id == message.id) {
+            return;
+        }
+        if unread {
+            *self.unread.write().entry(message.player_user_id.clone()).or_insert(0) += 1;
+        }
+        self.messages.write().push(message);
+    }
+
+    /// Messages in one player's thread, oldest first
+    pub fn thread(&self, player_user_id: &str) -> Vec<WhisperMessage> {
+        self.messages
+            .read()
+            .iter()
+            .filter(|m| m.player_user_id == player_user_id)
+            .cloned()
+            .collect()
+    }
+
+    /// One summary per player, most recent activity first
+    pub fn threads(&self) -> Vec<WhisperThread> {
+        let messages = self.messages.read();
+        let unread = self.unread.read();
+        let mut threads: Vec<WhisperThread> = Vec::new();
+        for message in messages.iter().rev() {
+            if threads.iter().any(|t| t.player_user_id == message.player_user_id) {
+                continue;
+            }
+            let display_name = messages
+                .iter()
+                .rev()
+                .filter(|m| m.player_user_id == message.player_user_id)
+                .find_map(|m| m.player_name.clone())
+                .unwrap_or_else(|| message.player_user_id.clone());
+            threads.push(WhisperThread {
+                player_user_id: message.player_user_id.clone(),
+                display_name,
+                last_message: message.text.clone(),
+                unread: unread.get(&message.player_user_id).copied().unwrap_or(0),
+            });
+        }
+        threads
+    }
+
+    /// Total unread whispers across all threads
+    pub fn unread_total(&self) -> usize {
+        self.unread.read().values().sum()
+    }
+
+    /// Mark a player's thread as read
+    pub fn mark_read(&mut self, player_user_id: &str) {
+        // Only write when something changes so effects that call this don't loop
+        if self.unread.peek().contains_key(player_user_id) {
+            self.unread.write().remove(player_user_id);
+        }
+    }
+
+    /// Clear all whispers
+    pub fn clear(&mut self) {
+        self.messages.set(Vec::new());
+        self.unread.set(HashMap::new());
+    }
+}
+
+impl Default for WhisperState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
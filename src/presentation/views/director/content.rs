@@ -2,19 +2,67 @@
 
 use dioxus::prelude::*;
 
-use crate::application::dto::{ChallengeData, SkillData};
-use crate::application::ports::outbound::{ApprovalDecision, Platform};
-use crate::application::services::SessionCommandService;
+use crate::application::dto::{factions_of, ChallengeData, EntityMention, SharedNoteDoc, SkillData};
+use crate::application::ports::outbound::{ApprovalDecision, Capability, Platform};
+use crate::application::services::{draft_challenge, Asset, ChallengeDraft, SessionCommandService, TourView};
+use crate::presentation::components::common::{accent_stripe, EntityMark, FactionBadges, MentionTextarea, ShareButton, ModalFocus};
+use crate::presentation::components::dm_panel::action_inbox::ActionInboxPanel;
+use crate::presentation::components::dm_panel::adhoc_challenge_modal::AdHocChallengeEntryPoint;
+use crate::presentation::components::dm_panel::annotation_studio::AnnotationStudio;
+use crate::presentation::components::dm_panel::backdrop_studio::BackdropStudio;
 use crate::presentation::components::dm_panel::challenge_library::ChallengeLibrary;
 use crate::presentation::components::dm_panel::decision_queue::DecisionQueuePanel;
 use crate::presentation::components::dm_panel::trigger_challenge_modal::TriggerChallengeModal;
-use crate::presentation::components::dm_panel::log_entry::DynamicLogEntry;
+use crate::presentation::components::dm_panel::condition_control::ConditionControl;
+use crate::presentation::components::dm_panel::camera_controls::CameraControls;
+use crate::presentation::components::dm_panel::conversation_log::ConversationLog;
+use crate::presentation::components::dm_panel::decision_timer_control::DecisionTimerControl;
+use crate::presentation::components::dm_panel::dialogue_pacing_control::DialoguePacingControl;
+use crate::presentation::components::dm_panel::dice_stats::DiceStatsPanel;
+use crate::presentation::components::dm_panel::directorial_preset::DirectorialPresetControl;
+use crate::presentation::components::dm_panel::disposition_toggle::DispositionToggle;
+use crate::presentation::components::dm_panel::encounter_runner::EncounterRunner;
+use crate::presentation::components::dm_panel::faction_standing::FactionStandingTracker;
+use crate::presentation::components::dm_panel::npc_memory_inspector::NpcMemoryInspector;
+use crate::presentation::components::dm_panel::npc_whereabouts::NpcWhereabouts;
+use crate::presentation::components::dm_panel::party_strip::PartyStrip;
+use crate::presentation::components::dm_panel::region_population_control::RegionPopulationControl;
+use crate::presentation::components::dm_panel::scene_mood_control::SceneMoodControl;
+use crate::presentation::components::dm_panel::scene_staging::{SceneStagingPanel, SceneSwitcher};
+use crate::presentation::components::dm_panel::session_pause_control::SessionPauseControl;
+use crate::presentation::components::dm_panel::speak_as::SpeakAsForm;
+use crate::presentation::components::dm_panel::spectator_policy_control::SpectatorPolicyControl;
+use crate::presentation::components::dm_panel::story_capture::QuickMarkerControl;
+use crate::presentation::components::dm_panel::session_roles::SessionRolesPanel;
+use crate::presentation::components::dm_panel::shared_note_editor::SharedNoteEditor;
+use crate::presentation::components::dm_panel::social_graph::SocialGraphModal;
+use crate::presentation::components::dm_panel::whisper_panel::WhisperPanel;
+use crate::presentation::components::dm_panel::world_update_review::WorldUpdateReview;
+use crate::presentation::components::shared::OnboardingTour;
+use crate::presentation::components::visual_novel::StageEffects;
 use crate::presentation::services::{use_challenge_service, use_skill_service};
-use crate::presentation::state::{use_game_state, use_session_state, use_generation_state, PendingApproval};
+use crate::presentation::state::{use_game_state, use_session_state, use_generation_state, ConversationLogEntry, GameState, PendingApproval};
+use crate::routes::Route;
+
+/// Props for DirectorModeContent
+#[derive(Props, Clone, PartialEq)]
+pub struct DirectorModeContentProps {
+    /// World ID from the route
+    pub world_id: String,
+    /// Whether the Challenge Library is open, from the route
+    #[props(default)]
+    pub challenges_open: bool,
+    /// Challenge open in the library's editor, from the route
+    #[props(default)]
+    pub challenge_id: Option<String>,
+    /// Opens the rules reference drawer
+    #[props(default)]
+    pub on_open_rules: Option<EventHandler<()>>,
+}
 
 /// The original Director mode content (directing gameplay)
 #[component]
-pub fn DirectorModeContent() -> Element {
+pub fn DirectorModeContent(props: DirectorModeContentProps) -> Element {
     let session_state = use_session_state();
     let game_state = use_game_state();
     let skill_service = use_skill_service();
@@ -22,16 +70,68 @@ pub fn DirectorModeContent() -> Element {
     let generation_state = use_generation_state();
     let mut show_queue_panel = use_signal(|| false);
 
-    // Local state for directorial inputs
-    let mut scene_notes = use_signal(|| String::new());
-    let mut current_tone = use_signal(|| "Serious".to_string());
-    let mut show_challenge_library = use_signal(|| false);
+    let navigator = use_navigator();
+    // The Challenge Library lives in the route so it can be linked to
+    let set_library_open = {
+        let world_id = props.world_id.clone();
+        EventHandler::new(move |open: bool| {
+            navigator.push(Route::DMViewTabRoute {
+                world_id: world_id.clone(),
+                tab: if open { "challenges" } else { "director" }.to_string(),
+            });
+        })
+    };
+    // "View as" on a character opens possession mode for it
+    let possess = {
+        let world_id = props.world_id.clone();
+        EventHandler::new(move |character_id: String| {
+            navigator.push(Route::DMPossessRoute {
+                world_id: world_id.clone(),
+                character_id,
+            });
+        })
+    };
+    let on_select_challenge = {
+        let world_id = props.world_id.clone();
+        EventHandler::new(move |challenge_id: Option<String>| {
+            let route = match challenge_id {
+                Some(challenge_id) => Route::DMChallengeRoute {
+                    world_id: world_id.clone(),
+                    challenge_id,
+                },
+                None => Route::DMViewTabRoute {
+                    world_id: world_id.clone(),
+                    tab: "challenges".to_string(),
+                },
+            };
+            navigator.push(route);
+        })
+    };
     let mut show_trigger_challenge = use_signal(|| false);
+    // Compact layout: whether the control column's tab is showing
+    let mut compact_controls = use_signal(|| false);
     let mut show_pc_management = use_signal(|| false);
     let mut show_location_navigator = use_signal(|| false);
     let mut show_character_perspective = use_signal(|| false);
+    let mut show_annotation_studio = use_signal(|| false);
+    let mut show_social_graph = use_signal(|| false);
+    let mut show_backdrop_studio = use_signal(|| false);
+    // Backdrop shown on the scene preview only, until pushed live, with
+    // the region it was picked for
+    let mut backdrop_preview: Signal<Option<(String, Asset)>> = use_signal(|| None);
     let mut skills: Signal<Vec<SkillData>> = use_signal(Vec::new);
     let mut challenges: Signal<Vec<ChallengeData>> = use_signal(Vec::new);
+    // Ad-hoc challenge drafted from a log line or approval card
+    let mut challenge_draft: Signal<Option<ChallengeDraft>> = use_signal(|| None);
+    // A log line targets its speaker; an approval card targets the PC the
+    // NPC is answering, never the NPC
+    let mut draft_from = move |speaker: Option<String>, target_pc_id: Option<String>, text: String| {
+        let mut draft = draft_challenge(&text, speaker.as_deref(), &skills.peek(), &game_state.scene_characters.peek());
+        if target_pc_id.is_some() {
+            draft.target_pc_id = target_pc_id;
+        }
+        challenge_draft.set(Some(draft));
+    };
 
     // Load skills and challenges when world is available
     let world_id_for_skills = game_state.world.read().as_ref().map(|w| w.world.id.clone());
@@ -69,23 +169,172 @@ pub fn DirectorModeContent() -> Element {
 
     // Get pending approvals from state
     let pending_approvals = session_state.pending_approvals().read().clone();
-    let conversation_log = session_state.conversation_log().read().clone();
+    // Accepting everything takes live responses and their tool calls too,
+    // so it asks first
+    let mut confirming_accept_all = use_signal(|| false);
+    let proposed_tool_count: usize = pending_approvals.iter().map(|a| a.proposed_tools.len()).sum();
+    let accept_all = {
+        let mut session_state = session_state.clone();
+        let platform = use_context::<Platform>();
+        let request_ids: Vec<String> = pending_approvals.iter().map(|a| a.request_id.clone()).collect();
+        move |_| {
+            for request_id in &request_ids {
+                session_state.record_approval_decision(request_id.clone(), &ApprovalDecision::Accept, &platform);
+            }
+            confirming_accept_all.set(false);
+        }
+    };
+    let has_log_entries = !session_state.conversation_log().read().is_empty();
 
     // Get scene characters from game state
     let scene_characters = game_state.scene_characters.read().clone();
 
+    let unread_whispers = session_state.whispers.unread_total();
+    let mention_candidates = world_mention_candidates(&game_state);
+
+    // Co-DMs run the table but leave world edits and session management to the DM
+    let can_approve = session_state.can(Capability::ApproveDialogue);
+    let can_trigger = session_state.can(Capability::TriggerChallenges);
+    let can_direct = session_state.can(Capability::DirectScene);
+    let can_edit_world = session_state.can(Capability::EditWorld);
+    let can_manage_session = session_state.can(Capability::ManageSession);
+
+    // Below the lg breakpoint the two columns become tabs
+    let controls_active = *compact_controls.read();
+    let (main_class, control_class) = if controls_active {
+        ("main-panel hidden lg:flex flex-col gap-4", "control-panel flex flex-col gap-4 overflow-y-auto")
+    } else {
+        ("main-panel flex flex-col gap-4", "control-panel hidden lg:flex flex-col gap-4 overflow-y-auto")
+    };
+    let compact_tab_class = |active: bool| {
+        if active {
+            "flex-1 py-2 bg-gray-700 text-white border-none rounded-md text-sm cursor-pointer"
+        } else {
+            "flex-1 py-2 bg-transparent text-gray-400 border-none rounded-md text-sm cursor-pointer"
+        }
+    };
+
     rsx! {
         div {
-            class: "h-full grid grid-cols-[1fr_350px] gap-4 p-4",
+            class: "h-full grid grid-cols-1 grid-rows-[auto_1fr] lg:grid-cols-[1fr_350px] lg:grid-rows-1 gap-4 p-4 overflow-y-auto lg:overflow-hidden",
+
+            // Compact mode tabs
+            div {
+                class: "lg:hidden flex gap-1 p-1 bg-dark-surface rounded-lg",
+                button {
+                    class: compact_tab_class(!controls_active),
+                    onclick: move |_| compact_controls.set(false),
+                    if pending_approvals.is_empty() {
+                        "Scene"
+                    } else {
+                        "Scene ({pending_approvals.len()})"
+                    }
+                }
+                button {
+                    class: compact_tab_class(controls_active),
+                    onclick: move |_| compact_controls.set(true),
+                    "Controls"
+                }
+            }
 
             // Left panel - Scene preview and conversation
             div {
-                class: "main-panel flex flex-col gap-4",
+                class: main_class,
+
+                // Party overview, always in view while directing
+                if let Some(session_id) = session_state.session_id().read().clone() {
+                    div {
+                        class: "bg-dark-surface rounded-lg p-3",
+                        h3 { class: "text-gray-400 mb-2 text-sm uppercase", "Party" }
+                        PartyStrip { session_id, world_id: props.world_id.clone() }
+                    }
+                }
+
+                // Parallel scenes while the party is split
+                SceneSwitcher {}
 
                 // Scene preview (smaller version of what players see)
                 div {
+                    "data-tour": "director-scene",
                     class: "scene-preview h-[200px] bg-gradient-to-b from-dark-surface to-dark-purple-end rounded-lg relative overflow-hidden",
 
+                    {
+                        let current_region_id = game_state.current_region.read().as_ref().map(|r| r.id.clone());
+                        let preview = backdrop_preview
+                            .read()
+                            .as_ref()
+                            .filter(|(region_id, _)| current_region_id.as_ref() == Some(region_id))
+                            .and_then(|(_, a)| a.file_path.clone());
+                        let backdrop = preview.or_else(|| game_state.backdrop_url());
+                        rsx! {
+                            if let Some(url) = backdrop {
+                                div {
+                                    class: "absolute inset-0 bg-cover bg-center",
+                                    style: "background-image: url('{url}');",
+                                }
+                            }
+                        }
+                    }
+
+                    // Crowd, DM lighting and drawings, under the characters
+                    StageEffects {}
+
+                    if can_direct {
+                        div {
+                            class: "absolute top-2 right-2 z-10 flex gap-2",
+                            button {
+                                onclick: move |_| show_backdrop_studio.set(true),
+                                title: "Generate or swap the backdrop",
+                                class: "px-2 py-1 bg-black/60 text-gray-200 border border-gray-600 rounded text-xs cursor-pointer hover:border-purple-500",
+                                "🎨 Backdrop"
+                            }
+                            button {
+                                onclick: move |_| show_annotation_studio.set(true),
+                                title: "Draw on the backdrop for players",
+                                class: "px-2 py-1 bg-black/60 text-gray-200 border border-gray-600 rounded text-xs cursor-pointer hover:border-purple-500",
+                                "🖍 Annotate"
+                            }
+                        }
+                    }
+
+                    // A backdrop only the DM sees until it is pushed live
+                    if let Some((region_id, asset)) = backdrop_preview.read().clone() {
+                        div {
+                            class: "absolute top-2 left-2 z-10 flex items-center gap-2 px-2 py-1 bg-black/70 border border-amber-500/60 rounded text-xs",
+                            role: "status",
+                            if game_state.current_region.read().as_ref().is_some_and(|r| r.id == region_id) {
+                                span { class: "text-amber-300", "Preview · players can't see this" }
+                            } else {
+                                span { class: "text-amber-300", "Preview for another region" }
+                            }
+                            button {
+                                onclick: {
+                                    let session_state = session_state.clone();
+                                    let mut game_state = game_state.clone();
+                                    move |_| {
+                                        let Some(client) = session_state.engine_client().read().as_ref().map(std::sync::Arc::clone) else {
+                                            return;
+                                        };
+                                        match SessionCommandService::new(client).set_region_backdrop(&region_id, &asset.id) {
+                                            Ok(()) => {
+                                                game_state.set_region_backdrop(&region_id, asset.file_path.clone());
+                                                backdrop_preview.set(None);
+                                            }
+                                            Err(e) => tracing::error!("Failed to push backdrop live: {}", e),
+                                        }
+                                    }
+                                },
+                                class: "px-2 py-0.5 bg-purple-500 text-white border-0 rounded cursor-pointer text-xs",
+                                "Push live"
+                            }
+                            button {
+                                onclick: move |_| backdrop_preview.set(None),
+                                class: "px-2 py-0.5 bg-transparent text-gray-300 border border-gray-600 rounded cursor-pointer text-xs",
+                                "Discard"
+                            }
+                        }
+                    }
+
                     // Show actual characters in scene
                     div {
                         class: "absolute bottom-[20%] left-1/2 -translate-x-1/2 flex gap-8",
@@ -112,53 +361,96 @@ pub fn DirectorModeContent() -> Element {
                 }
 
                 // Conversation log
-                div {
-                    class: "conversation-log flex-1 bg-dark-surface rounded-lg p-4 overflow-y-auto",
-
-                    h3 { class: "text-gray-400 mb-4 text-sm uppercase", "Conversation Log" }
+                ConversationLog {
+                    on_create_challenge: can_trigger.then_some(EventHandler::new(move |entry: ConversationLogEntry| {
+                        draft_from(Some(entry.speaker), None, entry.text)
+                    })),
+                }
 
-                    div {
-                        class: "flex flex-col gap-3",
+                // Private player whispers, kept out of the shared log
+                div {
+                    "data-tour": "director-whispers",
+                    class: "bg-dark-surface rounded-lg p-4 border border-purple-500/30",
 
-                        if conversation_log.is_empty() {
-                            div { class: "text-gray-500 italic text-center p-8",
-                                "Waiting for session activity..."
+                    h3 {
+                        class: "text-purple-300 mb-3 text-sm uppercase flex items-center gap-2",
+                        "🤫 Whispers"
+                        if unread_whispers > 0 {
+                            span {
+                                class: "bg-amber-500 text-white rounded-xl px-1.5 text-[0.625rem] font-bold",
+                                "{unread_whispers}"
                             }
                         }
+                    }
 
-                        for (idx, entry) in conversation_log.iter().enumerate() {
-                            DynamicLogEntry {
-                                key: "{idx}",
-                                speaker: entry.speaker.clone(),
-                                text: entry.text.clone(),
-                                is_system: entry.is_system,
+                    WhisperPanel {}
+                }
+
+                if can_approve {
+                    // A play-by-post batch brings several responses at once
+                    if pending_approvals.len() > 1 {
+                        div {
+                            class: "flex justify-end items-center gap-2",
+                            if *confirming_accept_all.read() {
+                                span {
+                                    class: "text-gray-400 text-sm",
+                                    if proposed_tool_count > 0 {
+                                        "Accept every pending response, with {proposed_tool_count} tool calls?"
+                                    } else {
+                                        "Accept every pending response?"
+                                    }
+                                }
+                                button {
+                                    r#type: "button",
+                                    onclick: accept_all,
+                                    class: "px-3 py-1.5 bg-green-600 text-white border-0 rounded-lg cursor-pointer text-sm",
+                                    "Confirm"
+                                }
+                                button {
+                                    r#type: "button",
+                                    onclick: move |_| confirming_accept_all.set(false),
+                                    class: "px-3 py-1.5 bg-gray-700 text-white border-0 rounded-lg cursor-pointer text-sm",
+                                    "Cancel"
+                                }
+                            } else {
+                                button {
+                                    r#type: "button",
+                                    onclick: move |_| confirming_accept_all.set(true),
+                                    class: "px-3 py-1.5 bg-green-500/20 text-green-400 border border-green-500/40 rounded-lg cursor-pointer text-sm",
+                                    "Accept all {pending_approvals.len()} responses"
+                                }
                             }
                         }
                     }
-                }
 
-                // Approval popup(s)
-                for approval in pending_approvals.iter() {
-                    ApprovalPopup {
-                        key: "{approval.request_id}",
-                        approval: approval.clone(),
+                    // Approval popup(s)
+                    for approval in pending_approvals.iter() {
+                        ApprovalPopup {
+                            key: "{approval.request_id}",
+                            approval: approval.clone(),
+                            on_create_challenge: can_trigger.then(|| {
+                                let pc_id = approval.pc_id.clone();
+                                EventHandler::new(move |text: String| draft_from(None, pc_id.clone(), text))
+                            }),
+                        }
                     }
-                }
 
-                if pending_approvals.is_empty() && !conversation_log.is_empty() {
-                    div {
-                        class: "bg-gray-800 border border-gray-700 rounded-lg p-4 text-center text-gray-400",
-                        "No pending approvals"
+                    if pending_approvals.is_empty() && has_log_entries {
+                        div {
+                            class: "bg-gray-800 border border-gray-700 rounded-lg p-4 text-center text-gray-400",
+                            "No pending approvals"
+                        }
                     }
                 }
             }
 
             // Right panel - Directorial controls
             div {
-                class: "control-panel flex flex-col gap-4 overflow-y-auto",
+                class: control_class,
 
                 // Connection status
                 div {
+                    "data-tour": "director-session",
                     class: "panel-section bg-dark-surface rounded-lg p-4",
 
                     h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Session Info" }
@@ -166,46 +458,219 @@ pub fn DirectorModeContent() -> Element {
                     div { class: "text-white text-sm",
                         if let Some(session_id) = session_state.session_id().read().as_ref() {
                             p { class: "my-1", "Session: {session_id}" }
+                            // Link that drops a spectator straight into the live scene
+                            div {
+                                class: "mt-2",
+                                ShareButton {
+                                    route: Route::SpectatorViewRoute { world_id: props.world_id.clone() },
+                                    title: "Watch the scene",
+                                    text: match game_state.current_region.read().as_ref() {
+                                        Some(region) => format!("Watch {} — {} live", region.location_name, region.name),
+                                        None => "Watch the session live".to_string(),
+                                    },
+                                }
+                            }
                         } else {
                             p { class: "my-1 text-amber-500", "Not connected to session" }
                         }
                     }
                 }
 
-                // Decision queue (pending approvals + recent decisions)
-                div {
-                    class: "panel-section bg-dark-surface rounded-lg p-4",
+                if can_manage_session {
+                    // Pause world / intermission
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Intermission" }
+
+                        SessionPauseControl {}
+                    }
+
+                    // Co-DM assignment
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Session Roles" }
+
+                        SessionRolesPanel {}
+                    }
+
+                    // What spectators may see
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Spectators" }
 
-                    DecisionQueuePanel {}
+                        SpectatorPolicyControl {}
+                    }
                 }
 
-                // Scene notes
-                div {
-                    class: "panel-section bg-dark-surface rounded-lg p-4",
+                if can_approve {
+                    // Actions players posted while the DM was away
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Play-by-Post" }
+
+                        ActionInboxPanel {}
+                    }
+                }
+
+                if can_direct {
+                    // Split the party across parallel scenes
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
 
-                    h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Scene Notes" }
-                    textarea {
-                        value: "{scene_notes}",
-                        oninput: move |e| scene_notes.set(e.value()),
-                        placeholder: "Add notes for the current scene...",
-                        class: "w-full h-[100px] p-3 bg-dark-bg border border-gray-700 rounded-lg text-white resize-y box-border",
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Parallel Scenes" }
+
+                        SceneStagingPanel {}
                     }
                 }
 
-                // Tone selection
+                if can_trigger {
+                    // Prepared encounters
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Encounters" }
+
+                        EncounterRunner { world_id: props.world_id.clone() }
+                    }
+                }
+
+                // How the session's dice have been rolling
                 div {
                     class: "panel-section bg-dark-surface rounded-lg p-4",
 
-                    h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Tone" }
-                    select {
-                        value: "{current_tone}",
-                        onchange: move |e| current_tone.set(e.value()),
-                        class: "w-full p-2 bg-dark-bg border border-gray-700 rounded-lg text-white",
-                        option { value: "Serious", "Serious" }
-                        option { value: "Lighthearted", "Lighthearted" }
-                        option { value: "Tense", "Tense" }
-                        option { value: "Mysterious", "Mysterious" }
-                        option { value: "Comedic", "Comedic" }
+                    h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Dice Stats" }
+
+                    DiceStatsPanel {}
+                }
+
+                if can_direct {
+                    // Status effects on PCs and NPCs
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Conditions" }
+
+                        ConditionControl {}
+                    }
+
+                    // How dialogue advances for the table
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Dialogue Pacing" }
+
+                        DialoguePacingControl {}
+                    }
+
+                    // Time limits on player choices and rolls
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Decision Timers" }
+
+                        DecisionTimerControl {}
+                    }
+                }
+
+                if can_edit_world {
+                    // Mid-session world changes awaiting review
+                    WorldUpdateReview {}
+                }
+
+                if can_approve {
+                    // Decision queue (pending approvals + recent decisions)
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        DecisionQueuePanel {}
+                    }
+                }
+
+                if can_direct {
+                    // Quick timeline markers
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Timeline Marker" }
+
+                        QuickMarkerControl {}
+                    }
+
+                    // Scene notes, shared with co-DMs
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Scene Notes" }
+                        SharedNoteEditor {
+                            doc: SharedNoteDoc::SceneNotes,
+                            candidates: mention_candidates.clone(),
+                            placeholder: "Add notes for the current scene... (@ to mention)",
+                            class: "w-full h-[100px] p-3 bg-dark-bg border border-gray-700 rounded-lg text-white resize-y box-border",
+                        }
+                    }
+
+                    // What each NPC on stage is after, shared with co-DMs
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "NPC Motivations" }
+                        if game_state.scene_characters.read().is_empty() {
+                            p { class: "text-gray-500 text-sm m-0", "No NPCs on stage" }
+                        }
+                        for character in game_state.scene_characters.read().iter() {
+                            div {
+                                key: "{character.id}",
+                                class: "mb-3",
+                                h4 { class: "text-purple-500 text-sm m-0 mb-1", "{character.name}" }
+                                DispositionToggle { character: character.clone() }
+                                SharedNoteEditor {
+                                    doc: SharedNoteDoc::NpcMotivation { npc_id: character.id.clone() },
+                                    candidates: mention_candidates.clone(),
+                                    placeholder: "Mood, immediate goal, secret agenda...",
+                                    class: "w-full h-[60px] p-2 bg-dark-bg border border-gray-700 rounded-lg text-white text-sm resize-y box-border",
+                                }
+                            }
+                        }
+                    }
+
+                    // Lighting and weather over the stage
+                    div {
+                        "data-tour": "director-mood",
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Scene Mood" }
+
+                        SceneMoodControl {}
+                    }
+
+                    // Background crowd of the region on stage
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Crowd" }
+
+                        RegionPopulationControl {}
+                    }
+
+                    // Where the party stands with each faction
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Factions" }
+
+                        FactionStandingTracker {}
+                    }
+
+                    // Tone selection
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Tone" }
+                        DirectorialPresetControl { world_id: props.world_id.clone() }
                     }
                 }
 
@@ -223,42 +688,92 @@ pub fn DirectorModeContent() -> Element {
                             div {
                                 key: "{character.id}",
                                 class: "flex items-center gap-2 p-2 bg-dark-bg rounded",
-                                span { class: "text-blue-400", "🧑" }
+                                style: accent_stripe(character.style.accent_color.as_deref()),
+                                EntityMark { style: character.style.clone(), fallback: "🧑" }
                                 span { class: "text-white", "{character.name}" }
+                                FactionBadges {
+                                    factions: factions_of(&character.id, &game_state.factions.read()).into_iter().cloned().collect::<Vec<_>>(),
+                                }
                                 if character.is_speaking {
                                     span { class: "text-green-400 text-xs ml-auto", "(speaking)" }
                                 }
                             }
                         }
                     }
+
+                    if can_direct && !scene_characters.is_empty() {
+                        SpeakAsForm { characters: scene_characters.clone() }
+                    }
+                }
+
+                if can_direct {
+                    // Where NPCs would be at this time of day
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "NPC Whereabouts" }
+
+                        NpcWhereabouts {}
+                    }
+
+                    // What NPCs remember, as fed to the LLM
+                    div {
+                        class: "panel-section bg-dark-surface rounded-lg p-4",
+
+                        h3 { class: "text-gray-400 mb-3 text-sm uppercase", "NPC Memory" }
+
+                        NpcMemoryInspector {}
+                    }
                 }
 
                 // Quick actions
                 div {
+                    "data-tour": "director-quick-actions",
                     class: "panel-section bg-dark-surface rounded-lg p-4",
 
                     h3 { class: "text-gray-400 mb-3 text-sm uppercase", "Quick Actions" }
 
                     div { class: "flex flex-col gap-2",
-                        button {
-                            onclick: move |_| show_challenge_library.set(true),
-                            class: "p-2 bg-amber-500 text-white border-none rounded-lg cursor-pointer",
-                            "Manage Challenges"
+                        if can_edit_world {
+                            button {
+                                onclick: move |_| set_library_open.call(true),
+                                class: "p-2 bg-amber-500 text-white border-none rounded-lg cursor-pointer",
+                                "Manage Challenges"
+                            }
+                        }
+                        if can_trigger {
+                            button {
+                                onclick: move |_| show_trigger_challenge.set(true),
+                                class: "p-2 bg-pink-500 text-white border-none rounded-lg cursor-pointer",
+                                "⚔️ Trigger Challenge"
+                            }
+                        }
+                        if let Some(on_open_rules) = props.on_open_rules {
+                            button {
+                                onclick: move |_| on_open_rules.call(()),
+                                title: "Ctrl+/",
+                                class: "p-2 bg-teal-600 text-white border-none rounded-lg cursor-pointer",
+                                "📖 Rules Reference"
+                            }
                         }
                         button {
-                            onclick: move |_| show_trigger_challenge.set(true),
-                            class: "p-2 bg-pink-500 text-white border-none rounded-lg cursor-pointer",
-                            "⚔️ Trigger Challenge"
+                            onclick: move |_| show_social_graph.set(true),
+                            class: "p-2 bg-blue-500 text-white border-none rounded-lg cursor-pointer",
+                            "View Social Graph"
                         }
-                        button { class: "p-2 bg-blue-500 text-white border-none rounded-lg cursor-pointer", "View Social Graph" }
                         button { class: "p-2 bg-purple-500 text-white border-none rounded-lg cursor-pointer", "View Timeline" }
                         button { class: "p-2 bg-red-500 text-white border-none rounded-lg cursor-pointer", "Start Combat" }
                     }
+
+                    if can_direct {
+                        h4 { class: "text-gray-500 mt-3 mb-2 text-xs uppercase", "Camera" }
+                        CameraControls {}
+                    }
                 }
             }
 
             // Challenge Library Modal
-            if *show_challenge_library.read() {
+            if props.challenges_open {
                 {
                     let world_id = game_state.world.read().as_ref().map(|w| w.world.id.clone());
                     if let Some(world_id) = world_id {
@@ -266,21 +781,28 @@ pub fn DirectorModeContent() -> Element {
                             ChallengeLibrary {
                                 world_id: world_id,
                                 skills: skills.read().clone(),
-                                on_close: move |_| show_challenge_library.set(false),
+                                on_close: move |_| set_library_open.call(false),
                                 on_trigger_challenge: None,
+                                selected_challenge_id: props.challenge_id.clone(),
+                                on_select_challenge: Some(on_select_challenge),
                             }
                         }
                     } else {
                         rsx! {
                             div {
                                 class: "fixed inset-0 bg-black/80 flex items-center justify-center z-[1000]",
-                                onclick: move |_| show_challenge_library.set(false),
+                                onclick: move |_| set_library_open.call(false),
                                 div {
+                                    id: "library-unavailable",
+                                    role: "dialog",
+                                    aria_modal: "true",
                                     class: "bg-dark-surface p-8 rounded-lg text-center",
                                     onclick: move |e| e.stop_propagation(),
+
+                                    ModalFocus { dialog_id: "library-unavailable" }
                                     p { class: "text-red-500", "No world loaded. Start a session first." }
                                     button {
-                                        onclick: move |_| show_challenge_library.set(false),
+                                        onclick: move |_| set_library_open.call(false),
                                         class: "mt-4 px-4 py-2 bg-gray-700 text-white border-none rounded cursor-pointer",
                                         "Close"
                                     }
@@ -298,8 +820,13 @@ pub fn DirectorModeContent() -> Element {
                         class: "fixed inset-0 bg-black/80 flex items-center justify-center z-[1000]",
                         onclick: move |_| show_pc_management.set(false),
                         div {
+                            id: "pc-management-modal",
+                            role: "dialog",
+                            aria_modal: "true",
                             class: "bg-dark-surface rounded-lg w-[90%] max-w-[800px] max-h-[90vh] overflow-y-auto p-6",
                             onclick: move |e| e.stop_propagation(),
+
+                            ModalFocus { dialog_id: "pc-management-modal" }
                             div {
                                 class: "flex justify-between items-center mb-4",
                                 h2 {
@@ -307,6 +834,7 @@ pub fn DirectorModeContent() -> Element {
                                     "Player Character Management"
                                 }
                                 button {
+                                    aria_label: "Close",
                                     onclick: move |_| show_pc_management.set(false),
                                     class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
                                     "×"
@@ -315,9 +843,8 @@ pub fn DirectorModeContent() -> Element {
                             crate::presentation::components::dm_panel::pc_management::PCManagementPanel {
                                 session_id: session_id.clone(),
                                 on_view_as_character: move |character_id| {
-                                    // TODO (Phase 23 Player Perspective): Implement view-as-character mode
-                                    tracing::info!("View as character: {}", character_id);
                                     show_pc_management.set(false);
+                                    possess.call(character_id);
                                 },
                             }
                         }
@@ -325,6 +852,29 @@ pub fn DirectorModeContent() -> Element {
                 }
             }
 
+            if *show_annotation_studio.read() {
+                AnnotationStudio { on_close: move |_| show_annotation_studio.set(false) }
+            }
+
+            if *show_social_graph.read() {
+                SocialGraphModal {
+                    world_id: props.world_id.clone(),
+                    on_close: move |_| show_social_graph.set(false),
+                }
+            }
+
+            if *show_backdrop_studio.read() {
+                BackdropStudio {
+                    world_id: props.world_id.clone(),
+                    on_preview: move |asset: Asset| {
+                        let region_id = game_state.current_region.peek().as_ref().map(|r| r.id.clone());
+                        backdrop_preview.set(region_id.map(|region_id| (region_id, asset)));
+                        show_backdrop_studio.set(false);
+                    },
+                    on_close: move |_| show_backdrop_studio.set(false),
+                }
+            }
+
             // Director Queue Panel
             if *show_queue_panel.read() {
                 crate::presentation::components::dm_panel::director_queue_panel::DirectorQueuePanel {
@@ -339,8 +889,13 @@ pub fn DirectorModeContent() -> Element {
                         class: "fixed inset-0 bg-black/80 flex items-center justify-center z-[1000]",
                         onclick: move |_| show_location_navigator.set(false),
                         div {
+                            id: "location-navigator-modal",
+                            role: "dialog",
+                            aria_modal: "true",
                             class: "bg-dark-surface rounded-lg w-[90%] max-w-[800px] max-h-[90vh] overflow-y-auto p-6",
                             onclick: move |e| e.stop_propagation(),
+
+                            ModalFocus { dialog_id: "location-navigator-modal" }
                             div {
                                 class: "flex justify-between items-center mb-4",
                                 h2 {
@@ -348,6 +903,7 @@ pub fn DirectorModeContent() -> Element {
                                     "Location Navigator"
                                 }
                                 button {
+                                    aria_label: "Close",
                                     onclick: move |_| show_location_navigator.set(false),
                                     class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
                                     "×"
@@ -376,8 +932,13 @@ pub fn DirectorModeContent() -> Element {
                         class: "fixed inset-0 bg-black/80 flex items-center justify-center z-[1000]",
                         onclick: move |_| show_character_perspective.set(false),
                         div {
+                            id: "character-perspective-modal",
+                            role: "dialog",
+                            aria_modal: "true",
                             class: "bg-dark-surface rounded-lg w-[90%] max-w-[800px] max-h-[90vh] overflow-y-auto p-6",
                             onclick: move |e| e.stop_propagation(),
+
+                            ModalFocus { dialog_id: "character-perspective-modal" }
                             div {
                                 class: "flex justify-between items-center mb-4",
                                 h2 {
@@ -385,6 +946,7 @@ pub fn DirectorModeContent() -> Element {
                                     "Character Perspective Viewer"
                                 }
                                 button {
+                                    aria_label: "Close",
                                     onclick: move |_| show_character_perspective.set(false),
                                     class: "px-2 py-1 bg-transparent text-gray-400 border-none cursor-pointer text-xl",
                                     "×"
@@ -394,9 +956,8 @@ pub fn DirectorModeContent() -> Element {
                                 session_id: session_id.clone(),
                                 world_id: world_id.clone(),
                                 on_view_as: move |character_id| {
-                                    // TODO (Phase 23 Player Perspective): Implement view-as-character mode
-                                    tracing::info!("View as character: {}", character_id);
                                     show_character_perspective.set(false);
+                                    possess.call(character_id);
                                 },
                             }
                         }
@@ -419,14 +980,19 @@ pub fn DirectorModeContent() -> Element {
                                 class: "fixed inset-0 bg-black/80 flex items-center justify-center z-[1000]",
                                 onclick: move |_| show_trigger_challenge.set(false),
                                 div {
+                                    id: "no-challenges-modal",
+                                    role: "dialog",
+                                    aria_modal: "true",
                                     class: "bg-dark-surface p-8 rounded-lg text-center max-w-[400px]",
                                     onclick: move |e| e.stop_propagation(),
+
+                                    ModalFocus { dialog_id: "no-challenges-modal" }
                                     h3 { class: "text-amber-500 mb-4", "⚔️ No Active Challenges" }
                                     p { class: "text-gray-400 mb-4", "Create and activate challenges in the Challenge Library first." }
                                     button {
                                         onclick: move |_| {
                                             show_trigger_challenge.set(false);
-                                            show_challenge_library.set(true);
+                                            set_library_open.call(true);
                                         },
                                         class: "px-4 py-2 bg-amber-500 text-white border-none rounded cursor-pointer mr-2",
                                         "Open Challenge Library"
@@ -462,6 +1028,17 @@ pub fn DirectorModeContent() -> Element {
                     }
                 }
             }
+
+            // Ad-hoc challenge form, filled in from conversation
+            if let Some(draft) = challenge_draft.read().clone() {
+                AdHocChallengeEntryPoint {
+                    draft,
+                    on_close: move |_| challenge_draft.set(None),
+                }
+            }
+
+            // First-run walkthrough of the controls above
+            OnboardingTour { view: TourView::Director }
         }
     }
 }
@@ -470,6 +1047,9 @@ pub fn DirectorModeContent() -> Element {
 #[derive(Props, Clone, PartialEq)]
 struct ApprovalPopupProps {
     approval: PendingApproval,
+    /// Opens the ad-hoc challenge form drafted from the dialogue
+    #[props(default)]
+    on_create_challenge: Option<EventHandler<String>>,
 }
 
 #[component]
@@ -480,6 +1060,8 @@ fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
     let mut show_reasoning = use_signal(|| false);
     let mut rejection_feedback = use_signal(|| String::new());
     let mut show_reject_input = use_signal(|| false);
+    let game_state = use_game_state();
+    let mention_candidates = world_mention_candidates(&game_state);
 
     // Track which tools are approved
     let mut approved_tools = use_signal(|| {
@@ -488,6 +1070,9 @@ fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
 
     let request_id = props.approval.request_id.clone();
     let npc_name = props.approval.npc_name.clone();
+    // With the LLM down there's nothing to accept; the DM writes the reply
+    let manual_dialogue = session_state.engine_health().read().modes().manual_dialogue
+        || props.approval.proposed_dialogue.trim().is_empty();
 
     rsx! {
         div {
@@ -498,11 +1083,29 @@ fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
                 span { class: "text-xs text-gray-400 font-normal", "{props.approval.request_id}" }
             }
 
+            if manual_dialogue {
+                p {
+                    class: "mb-3 p-2 bg-red-500/10 border border-red-500/40 rounded-lg text-red-300 text-xs",
+                    "Manual dialogue mode: the LLM is unavailable, so write {npc_name}'s reply yourself and send it as written."
+                }
+            }
+
             div { class: "mb-4",
-                p { class: "text-gray-400 text-sm mb-1", "{npc_name} will say:" }
+                div { class: "flex justify-between items-center mb-1",
+                    p { class: "text-gray-400 text-sm m-0", "{npc_name} will say:" }
+                    if let Some(on_create_challenge) = props.on_create_challenge {
+                        button {
+                            title: "Create challenge from this",
+                            onclick: move |_| on_create_challenge.call(modified_dialogue.read().clone()),
+                            class: "px-2 py-0.5 bg-transparent text-amber-500 border border-amber-500/40 rounded text-xs cursor-pointer",
+                            "⚔ Challenge"
+                        }
+                    }
+                }
                 textarea {
                     value: "{modified_dialogue}",
                     oninput: move |e| modified_dialogue.set(e.value()),
+                    placeholder: "Write {npc_name}'s reply...",
                     class: "w-full min-h-[80px] p-3 bg-dark-bg border border-gray-700 rounded-lg text-white resize-y box-border italic",
                 }
             }
@@ -607,10 +1210,11 @@ fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
             if *show_reject_input.read() {
                 div { class: "mb-4",
                     p { class: "text-gray-400 text-sm mb-1", "Feedback for LLM:" }
-                    textarea {
-                        value: "{rejection_feedback}",
-                        oninput: move |e| rejection_feedback.set(e.value()),
-                        placeholder: "Tell the LLM what to change...",
+                    MentionTextarea {
+                        value: rejection_feedback.read().clone(),
+                        on_change: move |v: String| rejection_feedback.set(v),
+                        candidates: mention_candidates,
+                        placeholder: "Tell the LLM what to change... (@ to mention)",
                         class: "w-full min-h-[60px] p-2 bg-dark-bg border border-red-500 rounded-lg text-white resize-y box-border",
                     }
                     div { class: "flex gap-2 mt-2",
@@ -644,8 +1248,43 @@ fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
                 }
             }
 
+            // Manual dialogue: send the DM's own reply
+            if manual_dialogue && !*show_reject_input.read() {
+                {
+                    let request_id = request_id.clone();
+                    let mut session_state = session_state.clone();
+                    let platform = platform.clone();
+                    let dialogue = modified_dialogue.read().clone();
+                    let is_empty = dialogue.trim().is_empty();
+
+                    rsx! {
+                        div { class: "flex gap-2",
+                            button {
+                                disabled: is_empty,
+                                onclick: move |_| {
+                                    session_state.record_approval_decision(
+                                        request_id.clone(),
+                                        &ApprovalDecision::TakeOver {
+                                            dm_response: dialogue.trim().to_string(),
+                                        },
+                                        &platform,
+                                    );
+                                },
+                                class: "flex-1 p-3 bg-green-500 text-white border-none rounded-lg cursor-pointer font-semibold disabled:opacity-50 disabled:cursor-not-allowed",
+                                "Send as Written"
+                            }
+                            button {
+                                onclick: move |_| show_reject_input.set(true),
+                                class: "flex-1 p-3 bg-red-500 text-white border-none rounded-lg cursor-pointer font-semibold",
+                                "Reject"
+                            }
+                        }
+                    }
+                }
+            }
+
             // Action buttons
-            if !*show_reject_input.read() {
+            if !manual_dialogue && !*show_reject_input.read() {
                 {
                     let request_id_accept = request_id.clone();
                     let mut session_state_accept = session_state.clone();
@@ -724,3 +1363,13 @@ fn ApprovalPopup(props: ApprovalPopupProps) -> Element {
         }
     }
 }
+
+/// Entities from the loaded world snapshot that notes can mention
+fn world_mention_candidates(game_state: &GameState) -> Vec<EntityMention> {
+    game_state
+        .world
+        .read()
+        .as_ref()
+        .map(|world| world.mention_candidates())
+        .unwrap_or_default()
+}
@@ -2,14 +2,17 @@
 
 use dioxus::prelude::*;
 
-use crate::application::services::SessionCommandService;
+use crate::application::ports::outbound::Capability;
+use crate::presentation::components::common::EngineHealthBanner;
 use crate::presentation::components::creator::CreatorMode;
-use crate::presentation::components::dm_panel::adhoc_challenge_modal::{
-    AdHocChallengeModal, AdHocChallengeData,
-};
+use crate::presentation::components::dm_panel::adhoc_challenge_modal::AdHocChallengeEntryPoint;
+use crate::presentation::components::dm_panel::rules_reference::RulesReferenceDrawer;
+use crate::presentation::components::dm_panel::story_capture::StoryCaptureSync;
+use crate::presentation::components::notes::NotesWiki;
 use crate::presentation::components::settings::SettingsView;
 use crate::presentation::views::director::DirectorModeContent;
 use crate::presentation::views::story_arc::StoryArcContent;
+use crate::presentation::state::perf_probe;
 
 // Re-export for backward compatibility
 
@@ -20,6 +23,7 @@ pub enum DMMode {
     Director,
     Creator,
     StoryArc,
+    Notes,
     Settings,
 }
 
@@ -33,41 +37,107 @@ pub struct DMViewProps {
     /// Optional Creator sub-tab (characters, locations, items, maps)
     #[props(default)]
     pub creator_subtab: Option<String>,
+    /// Optional Creator entity whose form is open
+    #[props(default)]
+    pub creator_entity_id: Option<String>,
     /// Optional Settings sub-tab (workflows, skills)
     #[props(default)]
     pub settings_subtab: Option<String>,
     /// Optional Story Arc sub-tab (timeline, events, chains)
     #[props(default)]
     pub story_arc_subtab: Option<String>,
+    /// Optional Story Arc entry to open: a timeline event or, on the
+    /// events sub-tab, a narrative event
+    #[props(default)]
+    pub story_arc_event_id: Option<String>,
+    /// Whether the Director's Challenge Library is open
+    #[props(default)]
+    pub challenges_open: bool,
+    /// Optional challenge open in the Challenge Library's editor
+    #[props(default)]
+    pub challenge_id: Option<String>,
+    /// Optional notes page open in the wiki
+    #[props(default)]
+    pub note_id: Option<String>,
 }
 
 #[component]
 pub fn DMView(props: DMViewProps) -> Element {
+    perf_probe::record_render("DMView");
     // Local UI state for ad-hoc challenge modal visibility
     let mut show_adhoc_modal = use_signal(|| false);
+    let mut show_rules = use_signal(|| false);
+    let session_state = crate::presentation::state::use_session_state();
+
+    // Co-DMs only get the Director; world editing stays with the DM
+    let can_view_director = session_state.can(Capability::ViewDirector);
+    let can_edit_world = session_state.can(Capability::EditWorld);
+    let has_health_notices = !session_state.engine_health().read().notices().is_empty();
 
     rsx! {
         div {
             class: "dm-view h-full flex flex-col bg-dark-bg",
+            // Ctrl+/ (Cmd+/ on macOS) toggles the rules reference from any tab
+            onkeydown: move |e: KeyboardEvent| {
+                let modifiers = e.modifiers();
+                if (modifiers.ctrl() || modifiers.meta()) && e.key() == Key::Character("/".to_string()) {
+                    e.prevent_default();
+                    let open = *show_rules.peek();
+                    show_rules.set(!open);
+                }
+            },
+
+            // Degraded Engine services (ComfyUI, LLM, latency)
+            if has_health_notices {
+                div { class: "px-4 pt-2", EngineHealthBanner {} }
+            }
 
             // Content area - no header, tabs are in main AppHeader
             div {
                 class: "dm-content flex-1 overflow-hidden",
 
                 match props.active_mode {
+                    DMMode::Director if !can_view_director => rsx! {
+                        div {
+                            class: "h-full flex flex-col items-center justify-center gap-2 text-gray-400",
+                            div { class: "text-4xl", "🔒" }
+                            p { class: "m-0", "The Director is only open to the Dungeon Master and co-DMs." }
+                        }
+                    },
                     DMMode::Director => rsx! {
-                        DirectorModeContent {}
+                        DirectorModeContent {
+                            world_id: props.world_id.clone(),
+                            challenges_open: props.challenges_open,
+                            challenge_id: props.challenge_id.clone(),
+                            on_open_rules: move |_| show_rules.set(true),
+                        }
+                    },
+                    _ if !can_edit_world => rsx! {
+                        div {
+                            class: "h-full flex flex-col items-center justify-center gap-2 text-gray-400",
+                            div { class: "text-4xl", "🔒" }
+                            p { class: "m-0", "Only the Dungeon Master can edit the world." }
+                            p { class: "m-0 text-sm text-gray-500", "As co-DM you can approve dialogue and trigger challenges from the Director." }
+                        }
                     },
                     DMMode::Creator => rsx! {
                         CreatorMode {
                             world_id: props.world_id.clone(),
                             selected_tab: props.creator_subtab.clone(),
+                            selected_entity_id: props.creator_entity_id.clone(),
                         }
                     },
                     DMMode::StoryArc => rsx! {
                         StoryArcContent {
                             world_id: props.world_id.clone(),
                             selected_tab: props.story_arc_subtab.clone(),
+                            selected_event_id: props.story_arc_event_id.clone(),
+                        }
+                    },
+                    DMMode::Notes => rsx! {
+                        NotesWiki {
+                            world_id: props.world_id.clone(),
+                            selected_note_id: props.note_id.clone(),
                         }
                     },
                     DMMode::Settings => rsx! {
@@ -78,6 +148,17 @@ pub fn DMView(props: DMViewProps) -> Element {
                     },
                 }
             }
+            // Sends story events captured from the session
+            StoryCaptureSync { world_id: props.world_id.clone() }
+
+            // Rules reference drawer
+            if *show_rules.read() {
+                RulesReferenceDrawer {
+                    world_id: props.world_id.clone(),
+                    on_close: move |_| show_rules.set(false),
+                }
+            }
+
             // Global ad-hoc challenge modal overlay
             if *show_adhoc_modal.read() {
                 AdHocChallengeEntryPoint {
@@ -87,56 +168,3 @@ pub fn DMView(props: DMViewProps) -> Element {
         }
     }
 }
-
-/// Thin wrapper that wires the AdHocChallengeModal to the SessionCommandService
-/// and current session state.
-#[component]
-fn AdHocChallengeEntryPoint(on_close: EventHandler<()>) -> Element {
-    let mut session_state = crate::presentation::state::use_session_state();
-    let game_state = use_context::<crate::presentation::state::GameState>();
-    let platform = use_context::<crate::application::ports::outbound::Platform>();
-
-    let player_characters = game_state.scene_characters.read().clone();
-
-    // Build a command service if we have a live client
-    let client = session_state.engine_client().read().clone();
-    let command_svc = client.map(|c| SessionCommandService::new(c));
-
-    rsx! {
-        AdHocChallengeModal {
-            player_characters: player_characters,
-            on_create: move |data: AdHocChallengeData| {
-                if let Some(_svc) = command_svc.as_ref() {
-                    // TODO (Phase 14 Ad-hoc Challenges): Wire create_adhoc_challenge via GameConnectionPort
-                    // Backend handler is ready (P0.2), needs: 1) add method to GameConnectionPort trait
-                    // 2) implement in EngineClient 3) call from here with data fields
-                    tracing::warn!(
-                        "Ad-hoc challenge creation not yet implemented: {} for {}",
-                        data.challenge_name,
-                        data.target_pc_id
-                    );
-                    let _ = (data.skill_name, data.difficulty, data.outcomes); // suppress unused warnings
-                    if false {
-                        tracing::error!("Placeholder error");
-                    }
-                } else {
-                    tracing::warn!("No Engine client available for ad-hoc challenge");
-                }
-
-                // Add a quick log entry for instant feedback
-                session_state.add_log_entry(
-                    "System".to_string(),
-                    format!(
-                        "Ad-hoc challenge '{}' created for PC {}",
-                        data.challenge_name, data.target_pc_id
-                    ),
-                    true,
-                    &platform,
-                );
-
-                on_close.call(());
-            },
-            on_close: move |_| on_close.call(()),
-        }
-    }
-}
@@ -5,6 +5,7 @@ pub mod dm_view;
 pub mod main_menu;
 pub mod pc_creation;
 pub mod pc_view;
+pub mod possession_view;
 pub mod role_select;
 pub mod spectator_view;
 pub mod story_arc;
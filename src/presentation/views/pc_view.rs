@@ -4,28 +4,54 @@
 //! with backdrop, character sprites, dialogue, and choices.
 
 use dioxus::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::domain::entities::PlayerAction;
-use crate::application::dto::{FieldValue, SheetTemplate, InteractionData, DiceInputType};
+use crate::application::dto::{DiceInputType, DiceRoll, FieldValue, InteractionData, SheetTemplate};
 use crate::presentation::components::action_panel::ActionPanel;
+use crate::presentation::components::audio_cue_player::AudioCuePlayer;
 use crate::presentation::components::character_sheet_viewer::CharacterSheetViewer;
-use crate::presentation::components::event_overlays::{ApproachEventOverlay, LocationEventBanner};
+use crate::presentation::components::common::{ConditionBadges, EngineHealthBanner, ModalFocus};
+use crate::presentation::components::event_overlays::{ApproachEventOverlay, IntermissionOverlay, LocationEventBanner};
 use crate::presentation::components::inventory_panel::InventoryPanel;
 use crate::presentation::components::known_npcs_panel::{KnownNpcsPanel, NpcObservationData};
 use crate::presentation::components::mini_map::{MiniMap, MapRegionData, MapBounds};
 use crate::presentation::components::navigation_panel::NavigationPanel;
-use crate::presentation::components::tactical::ChallengeRollModal;
-use crate::presentation::components::visual_novel::{Backdrop, CharacterLayer, DialogueBox, EmptyDialogueBox};
-use crate::application::dto::InventoryItemData;
-use crate::presentation::services::{use_character_service, use_location_service, use_observation_service, use_world_service};
-use crate::presentation::state::{use_dialogue_state, use_game_state, use_session_state, use_typewriter_effect, RollSubmissionStatus};
+use crate::presentation::components::pc::level_up_wizard::LevelUpWizard;
+use crate::presentation::components::shared::OnboardingTour;
+use crate::presentation::components::pc::whisper_dialog::WhisperDialog;
+use crate::presentation::components::pc::posted_actions::PostedActionsNotice;
+use crate::presentation::components::pc::recap_overlay::RecapOverlay;
+use crate::presentation::components::pc::roll_history::RollHistoryDialog;
+use crate::presentation::components::pc::roll_macros::RollMacrosDrawer;
+use crate::presentation::components::tactical::{ChallengeRollModal, PlayerSkillData};
+use crate::presentation::components::visual_novel::{
+    Backdrop, CharacterLayer, DialogueBacklog, DialogueBox, EmptyDialogueBox, StageEffects,
+};
+use crate::application::dto::{CraftingRecipe, InventoryItemData};
+use crate::application::services::{
+    ancestry, apply_equip_change, choice_availability, plan_equip_toggle,
+    sheet_values_with_equipment, ChoiceAvailability, ChoiceContext, MacroRoll, SheetExport, TourView,
+};
+use crate::presentation::services::{
+    use_character_service, use_crafting_service, use_location_service, use_observation_service, use_world_service,
+};
+use crate::application::ports::outbound::{GamepadButton, TimedDecision, TimerExpiry};
+use crate::presentation::state::{
+    click_focused, perf_probe, use_dialogue_state, use_game_state, use_gamepad, use_session_state,
+    use_typewriter_effect, FocusRing, GameState, RollSubmissionStatus, use_modal_focus,
+};
+
+/// Action panel system buttons: Inventory, Character, Map, People, Log,
+/// My Rolls, Macros (once a character is selected) and Whisper
+const SYSTEM_BUTTON_COUNT: usize = 8;
 
 /// Player Character View - visual novel gameplay interface
 ///
 /// Connection handling and back navigation are provided by WorldSessionLayout wrapper.
 #[component]
 pub fn PCView() -> Element {
+    perf_probe::record_render("PCView");
     // Get global state from context
     let game_state = use_game_state();
     let mut dialogue_state = use_dialogue_state();
@@ -36,13 +62,14 @@ pub fn PCView() -> Element {
     let character_service = use_character_service();
     let observation_service = use_observation_service();
     let location_service = use_location_service();
+    let crafting_service = use_crafting_service();
 
     // Character sheet viewer state
     let mut show_character_sheet = use_signal(|| false);
     let mut character_sheet_template: Signal<Option<SheetTemplate>> = use_signal(|| None);
     let mut character_sheet_values: Signal<HashMap<String, FieldValue>> = use_signal(HashMap::new);
     let mut player_character_name = use_signal(|| "Your Character".to_string());
-    let mut selected_character_id: Signal<Option<String>> = use_signal(|| None);
+    let mut player_character_portrait: Signal<Option<String>> = use_signal(|| None);
     let mut is_loading_sheet = use_signal(|| false);
 
     // Navigation panel state
@@ -52,9 +79,36 @@ pub fn PCView() -> Element {
     let mut show_inventory_panel = use_signal(|| false);
     let mut inventory_items: Signal<Vec<InventoryItemData>> = use_signal(Vec::new);
     let mut is_loading_inventory = use_signal(|| false);
+    let mut recipes: Signal<Vec<CraftingRecipe>> = use_signal(Vec::new);
+
+    // Keep the sheet PC's inventory loaded so equipped-item bonuses apply
+    // to the sheet and challenge rolls without opening the panel first.
+    // A finished craft attempt or an identified item changed it on the
+    // Engine, so it reloads then too.
+    use_effect({
+        let game_state = game_state.clone();
+        let character_service = character_service.clone();
+        move || {
+            let _ = game_state.craft_outcome.read();
+            let _ = game_state.identified_item.read();
+            let Some(pc_id) = sheet_pc_id(&game_state) else {
+                return;
+            };
+            let char_svc = character_service.clone();
+            spawn(async move {
+                match char_svc.get_inventory(&pc_id).await {
+                    Ok(items) => inventory_items.set(items),
+                    Err(e) => tracing::warn!("Failed to load inventory: {}", e),
+                }
+            });
+        }
+    });
 
     // Known NPCs panel state
     let mut show_known_npcs_panel = use_signal(|| false);
+    let mut show_whisper_dialog = use_signal(|| false);
+    let mut show_roll_history = use_signal(|| false);
+    let mut show_roll_macros = use_signal(|| false);
     let mut known_npcs: Signal<Vec<NpcObservationData>> = use_signal(Vec::new);
     let mut is_loading_npcs = use_signal(|| false);
 
@@ -63,6 +117,23 @@ pub fn PCView() -> Element {
     let mut map_regions: Signal<Vec<MapRegionData>> = use_signal(Vec::new);
     let mut is_loading_map = use_signal(|| false);
 
+    // Level-up wizard state
+    let mut show_level_up = use_signal(|| false);
+
+    // Dialogue backlog (Log button or controller B)
+    let mut show_backlog = use_signal(|| false);
+
+    // Controller focus ring; only drawn once a controller has been used
+    let mut focus_ring = use_signal(FocusRing::default);
+    let mut gamepad_active = use_signal(|| false);
+
+    // New lines put focus back on the dialogue
+    let dialogue_text = dialogue_state.full_text;
+    use_effect(move || {
+        let _ = dialogue_text.read();
+        focus_ring.set(FocusRing::default());
+    });
+
     // Run typewriter effect
     use_typewriter_effect(&mut dialogue_state);
 
@@ -71,17 +142,50 @@ pub fn PCView() -> Element {
 
     // Get current dialogue state
     let speaker_name = dialogue_state.speaker_name.read().clone();
+    let speaker_disposition = {
+        let speaker_id = dialogue_state.speaker_id.read().clone();
+        scene_characters
+            .iter()
+            .find(|c| match &speaker_id {
+                Some(id) => &c.id == id,
+                None => c.is_speaking,
+            })
+            .and_then(|c| c.disposition())
+    };
     let displayed_text = dialogue_state.displayed_text.read().clone();
     let is_typing = *dialogue_state.is_typing.read();
-    let choices = dialogue_state.choices.read().clone();
+    // Paced dialogue holds choices back until the table is released
+    let dialogue_held = *dialogue_state.held.read();
+    let finished_reading = *dialogue_state.finished_reading.read();
+    let choices = if dialogue_held { Vec::new() } else { dialogue_state.choices.read().clone() };
+    // What the character brings to gated choices; hidden ones are dropped
+    let choice_context = build_choice_context(
+        &session_state.player_skills().read(),
+        &inventory_items.read(),
+        &game_state.triggered_events.read(),
+    );
+    let choices: Vec<_> = choices
+        .into_iter()
+        .filter(|c| choice_availability(c, &choice_context) != ChoiceAvailability::Hidden)
+        .collect();
     let has_dialogue = dialogue_state.has_dialogue();
     let is_llm_processing = *dialogue_state.is_llm_processing.read();
+    let manual_dialogue = session_state.engine_health().read().modes().manual_dialogue;
+    let has_health_notices = !session_state.engine_health().read().notices().is_empty();
 
     // Get interactions from game state
     let interactions = game_state.interactions.read().clone();
 
     // Get active challenge if any
     let active_challenge = session_state.active_challenge().read().clone();
+    let equipment_slots = game_state.world.read().as_ref()
+        .map(|w| w.world.rule_system.equipment_slots.clone())
+        .unwrap_or_default();
+
+    // DM decision timer for choices and challenge rolls
+    let decision_timer = *session_state.decision_timer.read();
+    let decision_time_limit = decision_timer.limit();
+    let take_default_on_timeout = decision_timer.on_expiry == TimerExpiry::DefaultAction;
 
     // Get roll status for result popup (Phase D)
     let roll_status = session_state.roll_status().read().clone();
@@ -93,17 +197,85 @@ pub fn PCView() -> Element {
     let current_region = game_state.current_region.read().clone();
     let navigation = game_state.navigation.read().clone();
     let selected_pc_id = game_state.selected_pc_id.read().clone();
+    let pc_conditions = selected_pc_id
+        .as_deref()
+        .map(|id| session_state.conditions_for(id))
+        .unwrap_or_default();
+
+    // Enclosing locations, outermost first, for the breadcrumb
+    let location_trail: Vec<String> = current_region
+        .as_ref()
+        .map(|region| {
+            let trail: Vec<String> = game_state.world.read().as_ref()
+                .map(|w| ancestry(&w.locations, &region.location_id).into_iter().map(|l| l.name.clone()).collect())
+                .unwrap_or_default();
+            if trail.is_empty() { vec![region.location_name.clone()] } else { trail }
+        })
+        .unwrap_or_default();
 
     // Get event data from game state
     let approach_event = game_state.approach_event.read().clone();
     let location_event = game_state.location_event.read().clone();
 
+    // Intermission while the DM has paused the session
+    let intermission = game_state.intermission.read().clone();
+
+    // Outgoing player actions
+    let action_queue = session_state.action_queue.read().clone();
+
+    // Level-up earned but not yet taken
+    let pending_level_up = session_state.pending_level_up.read().clone();
+
+    // Controller focus targets: the shown choices (or the dialogue line
+    // itself while it can be advanced), then the action panel
+    let choices_shown = has_dialogue && !is_typing && !is_llm_processing;
+    let choice_count = if choices_shown { choices.iter().filter(|c| !c.is_custom_input).count() } else { 0 };
+    let advance_slots = if has_dialogue && choice_count == 0 { 1 } else { 0 };
+    let system_buttons = SYSTEM_BUTTON_COUNT - usize::from(selected_pc_id.is_none());
+    let action_count = system_buttons + interactions.iter().filter(|i| i.is_available).count();
+    let ring = focus_ring.read().normalized(choice_count + advance_slots, action_count);
+    let focused_choice = if *gamepad_active.read() && choice_count > 0 { ring.choice() } else { None };
+    let focused_action = if *gamepad_active.read() { ring.action() } else { None };
+
+    use_gamepad({
+        let session_state = session_state.clone();
+        let mut dialogue_state = dialogue_state.clone();
+        move |button: GamepadButton| {
+            gamepad_active.set(true);
+            if *show_backlog.peek() {
+                if matches!(button, GamepadButton::A | GamepadButton::B) {
+                    show_backlog.set(false);
+                }
+                return;
+            }
+            match button {
+                GamepadButton::Up | GamepadButton::Down | GamepadButton::Left | GamepadButton::Right => {
+                    let next = focus_ring.peek().navigate(button, choice_count + advance_slots, action_count);
+                    focus_ring.set(next);
+                }
+                GamepadButton::A if ring.choice().is_some() && choice_count == 0 => {
+                    handle_advance(&session_state, &mut dialogue_state);
+                }
+                GamepadButton::A => click_focused(),
+                GamepadButton::B => show_backlog.set(true),
+                GamepadButton::Start => {}
+            }
+        }
+    });
+
     rsx! {
         div {
             class: "pc-view h-full flex flex-col relative",
+            // Hide the focus ring again once the mouse is back in use
+            onmousemove: move |_| {
+                if *gamepad_active.peek() {
+                    gamepad_active.set(false);
+                }
+            },
 
             // Location and status indicator (top right)
             div {
+                "data-tour": "pc-location",
                 class: "absolute top-4 right-4 z-[100] flex flex-col gap-2 items-end",
 
                 // Location/Region name - prefer region data if available
@@ -112,9 +284,17 @@ pub fn PCView() -> Element {
                         class: "px-4 py-2 bg-black/70 text-white rounded-lg text-sm font-medium",
                         "📍 {region.name}"
                     }
-                    div {
-                        class: "px-3 py-1 bg-black/50 text-gray-300 rounded-lg text-xs",
-                        "{region.location_name}"
+                    nav {
+                        class: "px-3 py-1 bg-black/50 text-gray-300 rounded-lg text-xs flex items-center gap-1",
+                        for (i, name) in location_trail.iter().enumerate() {
+                            if i > 0 {
+                                span { class: "text-gray-500", "›" }
+                            }
+                            span {
+                                class: if i + 1 == location_trail.len() { "text-gray-200" } else { "" },
+                                "{name}"
+                            }
+                        }
                     }
                 } else if let Some(scene) = game_state.current_scene.read().as_ref() {
                     div {
@@ -123,6 +303,14 @@ pub fn PCView() -> Element {
                     }
                 }
 
+                // Degraded Engine services
+                if has_health_notices {
+                    div { class: "max-w-xs", EngineHealthBanner {} }
+                }
+
+                // Play-by-post actions waiting for, or answered by, the DM
+                PostedActionsNotice {}
+
                 // Connection status
             if !is_connected {
                 div {
@@ -130,23 +318,43 @@ pub fn PCView() -> Element {
                     "Disconnected"
                     }
                 }
+
+                // Level-up prompt
+                if pending_level_up.is_some() {
+                    button {
+                        onclick: move |_| show_level_up.set(true),
+                        class: "px-4 py-2 bg-amber-500 text-white border-0 rounded-lg text-sm font-semibold cursor-pointer animate-pulse",
+                        "⬆ Level up available"
+                    }
+                }
             }
 
             // Visual novel stage
             Backdrop {
                 image_url: game_state.backdrop_url(),
+                animated_url: game_state.animated_backdrop_url(),
+
+                // Crowd, DM lighting and drawings, under the characters
+                StageEffects {}
 
                 // Character layer with real scene characters
                 CharacterLayer {
                     characters: scene_characters,
+                    speaker_id: dialogue_state.speaker_id.read().clone(),
                     on_character_click: {
                         let session_state = session_state.clone();
+                        let game_state = game_state.clone();
                         move |character_id: String| {
                             tracing::info!("Clicked character: {}", character_id);
+                            let label = game_state.scene_characters.peek().iter()
+                                .find(|c| c.id == character_id)
+                                .map(|c| format!("Talk to {}", c.name))
+                                .unwrap_or_else(|| "Talk".to_string());
                             // Send a talk action when clicking a character
                             send_player_action(
                                 &session_state,
                                 PlayerAction::talk(&character_id, None),
+                                label,
                             );
                         }
                     }
@@ -155,14 +363,25 @@ pub fn PCView() -> Element {
 
             // Dialogue box (fixed at bottom)
             div {
+                "data-tour": "pc-dialogue",
                 class: "dialogue-container absolute bottom-0 left-0 right-0 z-10",
 
+                // Conditions on the player's character, just above the dialogue
+                if !pc_conditions.is_empty() {
+                    div {
+                        class: "absolute -top-8 left-4",
+                        ConditionBadges { conditions: pc_conditions.clone() }
+                    }
+                }
+
                 if has_dialogue {
                     DialogueBox {
                         speaker_name: speaker_name,
+                        speaker_disposition: speaker_disposition,
                         dialogue_text: displayed_text,
                         is_typing: is_typing,
                         is_llm_processing: is_llm_processing,
+                        manual_dialogue: manual_dialogue,
                         choices: choices,
                         on_choice_selected: {
                             let session_state = session_state.clone();
@@ -179,21 +398,69 @@ pub fn PCView() -> Element {
                             }
                         },
                         on_advance: {
+                            let session_state = session_state.clone();
                             let mut dialogue_state = dialogue_state.clone();
                             move |_| {
-                                handle_advance(&mut dialogue_state);
+                                handle_advance(&session_state, &mut dialogue_state);
+                            }
+                        },
+                        on_roll: {
+                            let session_state = session_state.clone();
+                            move |roll: DiceRoll| {
+                                send_player_action(
+                                    &session_state,
+                                    PlayerAction::roll(&roll.notation.to_string(), &roll.breakdown()),
+                                    format!("Roll {}", roll.notation),
+                                );
                             }
                         },
+                        skills: session_state.player_skills().read().clone(),
+                        choice_context: Some(choice_context.clone()),
+                        focused_choice: focused_choice,
+                        choice_time_limit: decision_time_limit,
+                        on_choice_timeout: Some(EventHandler::new({
+                            let session_state = session_state.clone();
+                            let mut dialogue_state = dialogue_state.clone();
+                            let choice_context = choice_context.clone();
+                            move |_| {
+                                handle_choice_timeout(
+                                    &session_state,
+                                    &mut dialogue_state,
+                                    &choice_context,
+                                    take_default_on_timeout,
+                                );
+                            }
+                        })),
                     }
                 } else {
                     EmptyDialogueBox {}
                 }
+
+                if has_dialogue && dialogue_held && !is_typing {
+                    div {
+                        class: "absolute -top-8 right-4 px-3 py-1 bg-black/70 text-gray-300 text-xs rounded-full",
+                        if finished_reading {
+                            "Waiting for the table…"
+                        } else {
+                            "Click to mark as read"
+                        }
+                    }
+                }
             }
 
-            // Action panel with scene interactions (disabled while LLM is processing)
+            // Action panel with scene interactions (disabled while paused; inputs
+            // made while the LLM is processing are queued)
             ActionPanel {
                 interactions: interactions,
-                disabled: is_llm_processing,
+                disabled: intermission.is_some(),
+                sending: action_queue.in_flight().map(|q| q.label.clone()),
+                queued: action_queue.pending().map(|q| (q.id, q.label.clone())).collect::<Vec<_>>(),
+                on_cancel_queued: Some(EventHandler::new({
+                    let mut session_state = session_state.clone();
+                    move |id: u64| session_state.cancel_queued_action(id)
+                })),
+                on_whisper: Some(EventHandler::new(move |_| show_whisper_dialog.set(true))),
+                unread_whispers: session_state.whispers.unread_total(),
                 on_interaction: {
                     let session_state = session_state.clone();
                     move |interaction: InteractionData| {
@@ -203,20 +470,23 @@ pub fn PCView() -> Element {
                 on_inventory: Some(EventHandler::new({
                     let game_state = game_state.clone();
                     let character_service = character_service.clone();
+                    let crafting_service = crafting_service.clone();
                     move |_| {
                         tracing::info!("Open inventory");
                         show_inventory_panel.set(true);
                         is_loading_inventory.set(true);
 
-                        // Get the selected PC or first character
-                        let characters = game_state.world.read().as_ref()
-                            .map(|w| w.characters.clone())
-                            .unwrap_or_default();
-                        let char_id = selected_character_id.read().clone()
-                            .or_else(|| characters.first().map(|c| c.id.clone()));
+                        if let Some(wid) = game_state.world.read().as_ref().map(|w| w.world.id.clone()) {
+                            let crafting_svc = crafting_service.clone();
+                            spawn(async move {
+                                match crafting_svc.list_recipes(&wid).await {
+                                    Ok(list) => recipes.set(list),
+                                    Err(e) => tracing::warn!("Failed to load recipes: {}", e),
+                                }
+                            });
+                        }
 
-                        if let Some(cid) = char_id {
-                            selected_character_id.set(Some(cid.clone()));
+                        if let Some(cid) = sheet_pc_id(&game_state) {
                             let char_svc = character_service.clone();
                             spawn(async move {
                                 match char_svc.get_inventory(&cid).await {
@@ -245,19 +515,10 @@ pub fn PCView() -> Element {
                         show_character_sheet.set(true);
                         is_loading_sheet.set(true);
 
-                        // Get world ID and first available character
                         let world_id = game_state.world.read().as_ref()
                             .map(|w| w.world.id.clone());
-                        let characters = game_state.world.read().as_ref()
-                            .map(|w| w.characters.clone())
-                            .unwrap_or_default();
-
-                        // Auto-select first character if none selected
-                        let char_id = selected_character_id.read().clone()
-                            .or_else(|| characters.first().map(|c| c.id.clone()));
 
-                        if let (Some(wid), Some(cid)) = (world_id, char_id.clone()) {
-                            selected_character_id.set(Some(cid.clone()));
+                        if let (Some(wid), Some(cid)) = (world_id, sheet_pc_id(&game_state)) {
                             let world_svc = world_service.clone();
                             let char_svc = character_service.clone();
                             spawn(async move {
@@ -274,6 +535,7 @@ pub fn PCView() -> Element {
                                 match char_svc.get_character(&cid).await {
                                     Ok(char_data) => {
                                         player_character_name.set(char_data.name);
+                                        player_character_portrait.set(char_data.portrait_asset);
                                         if let Some(sheet_data) = char_data.sheet_data {
                                             character_sheet_values.set(sheet_data.values);
                                         }
@@ -383,9 +645,10 @@ pub fn PCView() -> Element {
                         }
                     }
                 })),
-                on_log: Some(EventHandler::new(move |_| {
-                    tracing::info!("Open log");
-                })),
+                on_log: Some(EventHandler::new(move |_| show_backlog.set(true))),
+                on_rolls: Some(EventHandler::new(move |_| show_roll_history.set(true))),
+                on_macros: selected_pc_id.is_some().then(|| EventHandler::new(move |_| show_roll_macros.set(true))),
+                focused: focused_action,
             }
 
             // Character sheet viewer modal
@@ -397,9 +660,14 @@ pub fn PCView() -> Element {
                         onclick: move |_| show_character_sheet.set(false),
 
                         div {
+                            id: "character-sheet-loading",
+                            role: "dialog",
+                            aria_modal: "true",
                             class: "bg-dark-surface rounded-xl p-8 max-w-md text-center",
                             onclick: move |e| e.stop_propagation(),
 
+                            ModalFocus { dialog_id: "character-sheet-loading" }
+
                             div {
                                 class: "text-gray-400 text-xl",
                                 "Loading character sheet..."
@@ -410,7 +678,20 @@ pub fn PCView() -> Element {
                     CharacterSheetViewer {
                         character_name: player_character_name.read().clone(),
                         template: template.clone(),
-                        values: character_sheet_values.read().clone(),
+                        values: sheet_values_with_equipment(
+                            template,
+                            &character_sheet_values.read(),
+                            &inventory_items.read(),
+                        ),
+                        conditions: pc_conditions.clone(),
+                        export: Some(SheetExport {
+                            character_name: player_character_name.read().clone(),
+                            template: template.clone(),
+                            values: character_sheet_values.read().clone(),
+                            portrait_url: player_character_portrait.read().clone(),
+                            inventory: inventory_items.read().clone(),
+                            conditions: pc_conditions.clone(),
+                        }),
                         on_close: move |_| show_character_sheet.set(false),
                     }
                 } else {
@@ -425,9 +706,14 @@ pub fn PCView() -> Element {
                                 onclick: move |_| show_character_sheet.set(false),
 
                                 div {
+                                    id: "character-sheet-placeholder",
+                                    role: "dialog",
+                                    aria_modal: "true",
                                     class: "bg-dark-surface rounded-xl p-8 max-w-md text-center",
                                     onclick: move |e| e.stop_propagation(),
 
+                                    ModalFocus { dialog_id: "character-sheet-placeholder" }
+
                                     h2 {
                                         class: "text-gray-100 m-0 mb-4",
                                         "Character Sheet"
@@ -481,6 +767,26 @@ pub fn PCView() -> Element {
                             session_state.clear_active_challenge();
                         }
                     },
+                    time_limit: decision_time_limit,
+                    on_timeout: Some(EventHandler::new({
+                        let session_state = session_state.clone();
+                        let challenge = challenge.clone();
+                        move |_| {
+                            let decision = TimedDecision::Challenge {
+                                challenge_id: challenge.challenge_id.clone(),
+                                challenge_name: challenge.challenge_name.clone(),
+                            };
+                            send_decision_timeout(&session_state, decision, take_default_on_timeout);
+                            if take_default_on_timeout {
+                                let formula = challenge.suggested_dice.clone().unwrap_or_else(|| "1d20".to_string());
+                                send_challenge_roll_input(
+                                    &session_state,
+                                    &challenge.challenge_id,
+                                    DiceInputType::Formula(formula),
+                                );
+                            }
+                        }
+                    })),
                 }
             }
 
@@ -544,6 +850,7 @@ pub fn PCView() -> Element {
                 InventoryPanel {
                     character_name: player_character_name.read().clone(),
                     items: inventory_items.read().clone(),
+                    slots: equipment_slots.clone(),
                     is_loading: *is_loading_inventory.read(),
                     on_close: move |_| {
                         show_inventory_panel.set(false);
@@ -552,14 +859,114 @@ pub fn PCView() -> Element {
                         let session_state = session_state.clone();
                         move |item_id: String| {
                             tracing::info!("Use item: {}", item_id);
+                            let label = inventory_items.peek().iter()
+                                .find(|i| i.item.id == item_id)
+                                .map(|i| format!("Use {}", i.item.name))
+                                .unwrap_or_else(|| "Use item".to_string());
                             send_player_action(
                                 &session_state,
                                 PlayerAction::use_item(&item_id, None),
+                                label,
                             );
                         }
                     })),
-                    on_toggle_equip: None, // TODO: Implement equip toggle
+                    on_toggle_equip: Some(EventHandler::new({
+                        let game_state = game_state.clone();
+                        let character_service = character_service.clone();
+                        let equipment_slots = equipment_slots.clone();
+                        move |item_id: String| {
+                            let Some(cid) = sheet_pc_id(&game_state) else {
+                                return;
+                            };
+                            let Some(change) = plan_equip_toggle(&inventory_items.peek(), &equipment_slots, &item_id) else {
+                                tracing::warn!("No equipment slot for item {}", item_id);
+                                return;
+                            };
+                            let char_svc = character_service.clone();
+                            spawn(async move {
+                                // Empty the slot before filling it
+                                for swapped in &change.unequip {
+                                    if let Err(e) = char_svc.set_equipped(&cid, swapped, None).await {
+                                        tracing::warn!("Failed to unequip item: {}", e);
+                                        return;
+                                    }
+                                }
+                                match char_svc.set_equipped(&cid, &change.item_id, change.slot.as_deref()).await {
+                                    Ok(()) => apply_equip_change(&mut inventory_items.write(), &change),
+                                    Err(e) => tracing::warn!("Failed to toggle equip: {}", e),
+                                }
+                            });
+                        }
+                    })),
                     on_drop_item: None, // TODO: Implement drop item
+                    recipes: recipes.read().clone(),
+                    on_craft: Some(EventHandler::new({
+                        let session_state = session_state.clone();
+                        let mut game_state = game_state.clone();
+                        move |recipe_id: String| {
+                            let Some(pc_id) = sheet_pc_id(&game_state) else {
+                                return;
+                            };
+                            game_state.craft_rejection.set(None);
+                            send_craft_attempt(&session_state, &pc_id, &recipe_id);
+                        }
+                    })),
+                    craft_outcome: game_state.craft_outcome.read().clone(),
+                    craft_rejection: game_state.craft_rejection.read().clone(),
+                }
+            }
+
+            // "Previously on…" recap from the DM
+            if let Some(recap) = game_state.recap.read().clone() {
+                RecapOverlay {
+                    key: "{recap.id}",
+                    recap,
+                    on_close: {
+                        let mut recap_signal = game_state.recap;
+                        move |_| recap_signal.set(None)
+                    },
+                }
+            }
+
+            // Whisper to DM modal
+            if *show_whisper_dialog.read() {
+                WhisperDialog {
+                    on_close: move |_| show_whisper_dialog.set(false),
+                }
+            }
+
+            // Dialogue backlog
+            if *show_backlog.read() {
+                DialogueBacklog {
+                    lines: dialogue_state.backlog.read().clone(),
+                    on_close: move |_| show_backlog.set(false),
+                }
+            }
+
+            // Past challenge rolls
+            if *show_roll_history.read() {
+                RollHistoryDialog {
+                    on_close: move |_| show_roll_history.set(false),
+                }
+            }
+
+            // Saved dice rolls for the selected character
+            if *show_roll_macros.read() {
+                if let Some(pc_id) = selected_pc_id.clone() {
+                    RollMacrosDrawer {
+                        pc_id,
+                        on_roll: {
+                            let session_state = session_state.clone();
+                            move |result: MacroRoll| {
+                                send_player_action(
+                                    &session_state,
+                                    PlayerAction::roll(&result.notation(), &result.breakdown()),
+                                    format!("Roll {}", result.name),
+                                );
+                            }
+                        },
+                        on_close: move |_| show_roll_macros.set(false),
+                    }
                 }
             }
 
@@ -575,10 +982,15 @@ pub fn PCView() -> Element {
                         let session_state = session_state.clone();
                         move |npc_id: String| {
                             tracing::info!("Clicked NPC: {}", npc_id);
+                            let label = known_npcs.peek().iter()
+                                .find(|n| n.npc_id == npc_id)
+                                .map(|n| format!("Talk to {}", n.npc_name))
+                                .unwrap_or_else(|| "Talk".to_string());
                             // Could open NPC details or start a talk action
                             send_player_action(
                                 &session_state,
                                 PlayerAction::talk(&npc_id, None),
+                                label,
                             );
                             show_known_npcs_panel.set(false);
                         }
@@ -605,6 +1017,7 @@ pub fn PCView() -> Element {
                             .map(|r| r.region_id.clone())
                             .collect())
                         .unwrap_or_default(),
+                    revealed_region_ids: Some(game_state.map_reveals.read().revealed_for(selected_pc_id.as_deref())),
                     is_loading: *is_loading_map.read(),
                     on_region_click: {
                         let session_state = session_state.clone();
@@ -647,6 +1060,29 @@ pub fn PCView() -> Element {
                     },
                 }
             }
+
+            // Intermission overlay (session paused by DM)
+            if let Some(ref intermission) = intermission {
+                IntermissionOverlay {
+                    intermission: intermission.clone(),
+                }
+            }
+
+            // Sounds attached to narrative events and challenge outcomes
+            AudioCuePlayer {}
+
+            // Level-up wizard
+            if *show_level_up.read() {
+                if let Some(pending) = pending_level_up {
+                    LevelUpWizard {
+                        pending,
+                        on_close: move |_| show_level_up.set(false),
+                    }
+                }
+            }
+
+            // First-run walkthrough of the stage and actions
+            OnboardingTour { view: TourView::Pc }
         }
     }
 }
@@ -658,10 +1094,12 @@ fn ChallengeResultPopup(
     result: crate::presentation::state::challenge_state::ChallengeResultData,
     on_dismiss: EventHandler<()>,
 ) -> Element {
+    use_modal_focus("challenge-result-popup");
     // Determine display colors and text based on outcome
     let (outcome_text, outcome_class, border_class) = match result.outcome.as_str() {
         "critical_success" => ("CRITICAL SUCCESS", "text-yellow-400", "border-yellow-400"),
         "success" => ("SUCCESS", "text-green-500", "border-green-500"),
+        "partial" | "partial_success" => ("PARTIAL SUCCESS", "text-orange-400", "border-orange-400"),
         "failure" => ("FAILURE", "text-red-500", "border-red-500"),
         "critical_failure" => ("CRITICAL FAILURE", "text-red-700", "border-red-700"),
         _ => ("RESULT", "text-amber-500", "border-amber-500"),
@@ -675,6 +1113,9 @@ fn ChallengeResultPopup(
 
             // Modal content
             div {
+                id: "challenge-result-popup",
+                role: "dialog",
+                aria_modal: "true",
                 class: "bg-gradient-to-br from-dark-surface to-dark-bg p-8 rounded-2xl max-w-[450px] w-[90%] border-2 {border_class}",
                 onclick: |e| e.stop_propagation(),
 
@@ -754,21 +1195,17 @@ fn ChallengeResultPopup(
     }
 }
 
-/// Send a player action via WebSocket
+/// Queue a player action; it is sent once earlier actions are answered
 fn send_player_action(
     session_state: &crate::presentation::state::SessionState,
     action: PlayerAction,
+    label: impl Into<String>,
 ) {
-    let engine_client_signal = session_state.engine_client();
-    let client_binding = engine_client_signal.read();
-    if let Some(ref client) = *client_binding {
-        let svc = crate::application::services::ActionService::new(std::sync::Arc::clone(client));
-        if let Err(e) = svc.send_action(action) {
-            tracing::error!("Failed to send action: {}", e);
-        }
-    } else {
+    if !session_state.has_client() {
         tracing::warn!("Cannot send action: not connected to server");
+        return;
     }
+    session_state.clone().queue_action(action, label);
 }
 
 /// Handle a dialogue choice being selected
@@ -782,8 +1219,44 @@ fn handle_choice_selected(
     // Clear awaiting state since we're making a choice
     dialogue_state.awaiting_input.set(false);
 
+    let label = dialogue_state.choices.peek().iter()
+        .find(|c| c.id == choice_id)
+        .map(|c| c.text.clone())
+        .unwrap_or_else(|| "Choice".to_string());
+
     // Send dialogue choice action to the server
-    send_player_action(session_state, PlayerAction::dialogue_choice(choice_id));
+    send_player_action(session_state, PlayerAction::dialogue_choice(choice_id), label);
+}
+
+/// The choice timer ran out: tell the DM, and pick the first choice the
+/// character can take if the DM asked for a default action
+fn handle_choice_timeout(
+    session_state: &crate::presentation::state::SessionState,
+    dialogue_state: &mut crate::presentation::state::DialogueState,
+    choice_context: &ChoiceContext,
+    take_default: bool,
+) {
+    let default_choice = dialogue_state.choices.peek().iter()
+        .find(|c| !c.is_custom_input && choice_availability(c, choice_context).is_available())
+        .map(|c| c.id.clone());
+    let defaulted = take_default && default_choice.is_some();
+    send_decision_timeout(session_state, TimedDecision::Dialogue, defaulted);
+    if let Some(choice_id) = default_choice.filter(|_| defaulted) {
+        handle_choice_selected(session_state, dialogue_state, &choice_id);
+    }
+}
+
+/// What the client knows of the character, for checking choice requirements
+fn build_choice_context(
+    skills: &[PlayerSkillData],
+    inventory: &[InventoryItemData],
+    triggered_events: &HashSet<String>,
+) -> ChoiceContext {
+    let context = skills
+        .iter()
+        .fold(ChoiceContext::default(), |c, s| c.with_skill(&s.id, &s.name, s.modifier));
+    let context = inventory.iter().fold(context, |c, i| c.with_item(&i.item.id, &i.item.name));
+    triggered_events.iter().fold(context, |c, id| c.with_event(id))
 }
 
 /// Handle custom text input
@@ -798,14 +1271,23 @@ fn handle_custom_input(
     dialogue_state.awaiting_input.set(false);
 
     // Send custom action to the server
-    send_player_action(session_state, PlayerAction::custom(text));
+    send_player_action(session_state, PlayerAction::custom(text), text);
 }
 
 /// Handle advancing dialogue (clicking to continue or skipping typewriter)
-fn handle_advance(dialogue_state: &mut crate::presentation::state::DialogueState) {
+fn handle_advance(
+    session_state: &crate::presentation::state::SessionState,
+    dialogue_state: &mut crate::presentation::state::DialogueState,
+) {
     if *dialogue_state.is_typing.read() {
         // Skip typewriter animation
         dialogue_state.skip_typewriter();
+    } else if *dialogue_state.held.read() {
+        // Paced dialogue: let the table know this player is done reading
+        if !*dialogue_state.finished_reading.read() {
+            send_dialogue_read(session_state);
+            dialogue_state.finished_reading.set(true);
+        }
     } else {
         // If no choices and dialogue is done, the server will send next content
         if !dialogue_state.has_choices() {
@@ -842,7 +1324,16 @@ fn handle_interaction(
         }
     };
 
-    send_player_action(session_state, action);
+    send_player_action(session_state, action, interaction.name.clone());
+}
+
+/// The PC whose sheet and inventory this view shows: the selected PC, or
+/// the world's first character before one is picked
+fn sheet_pc_id(game_state: &GameState) -> Option<String> {
+    game_state.selected_pc_id.read().clone().or_else(|| {
+        game_state.world.read().as_ref()
+            .and_then(|w| w.characters.first().map(|c| c.id.clone()))
+    })
 }
 
 /// Send a challenge roll with dice input via WebSocket
@@ -863,6 +1354,56 @@ fn send_challenge_roll_input(
     }
 }
 
+/// Tell the DM this player's decision timer ran out
+fn send_decision_timeout(
+    session_state: &crate::presentation::state::SessionState,
+    decision: TimedDecision,
+    defaulted: bool,
+) {
+    let engine_client_signal = session_state.engine_client();
+    let client_binding = engine_client_signal.read();
+    if let Some(ref client) = *client_binding {
+        let svc = crate::application::services::SessionCommandService::new(std::sync::Arc::clone(client));
+        if let Err(e) = svc.report_decision_timeout(decision, defaulted) {
+            tracing::error!("Failed to send decision timeout: {}", e);
+        }
+    } else {
+        tracing::warn!("Cannot send decision timeout: not connected to server");
+    }
+}
+
+/// Attempt a crafting recipe with a character's inventory
+fn send_craft_attempt(
+    session_state: &crate::presentation::state::SessionState,
+    pc_id: &str,
+    recipe_id: &str,
+) {
+    let engine_client_signal = session_state.engine_client();
+    let client_binding = engine_client_signal.read();
+    if let Some(ref client) = *client_binding {
+        let svc = crate::application::services::SessionCommandService::new(std::sync::Arc::clone(client));
+        if let Err(e) = svc.attempt_craft(pc_id, recipe_id) {
+            tracing::error!("Failed to send craft attempt: {}", e);
+        }
+    } else {
+        tracing::warn!("Cannot craft: not connected to server");
+    }
+}
+
+/// Tell the table this player has finished reading the current line
+fn send_dialogue_read(session_state: &crate::presentation::state::SessionState) {
+    let engine_client_signal = session_state.engine_client();
+    let client_binding = engine_client_signal.read();
+    if let Some(ref client) = *client_binding {
+        let svc = crate::application::services::SessionCommandService::new(std::sync::Arc::clone(client));
+        if let Err(e) = svc.mark_dialogue_read() {
+            tracing::error!("Failed to send dialogue read: {}", e);
+        }
+    } else {
+        tracing::warn!("Cannot send dialogue read: not connected to server");
+    }
+}
+
 /// Send a move to region command via WebSocket
 fn send_move_to_region(
     session_state: &crate::presentation::state::SessionState,
@@ -0,0 +1,274 @@
+//! Possession View - the DM plays as one of the characters
+//!
+//! A PC-View-like stage for the DM acting as an NPC or a player's PC. While
+//! it is open, `SessionState::possession` is set, so every action the view
+//! queues goes to the Engine as that character and is marked DM-controlled.
+//! A banner across the top keeps it obvious who the DM is playing and
+//! offers the way back to the Director.
+
+use dioxus::prelude::*;
+
+use crate::application::dto::DiceRoll;
+use crate::domain::entities::PlayerAction;
+use crate::presentation::components::visual_novel::{
+    Backdrop, CharacterLayer, DialogueBox, EmptyDialogueBox, StageEffects,
+};
+use crate::presentation::state::{
+    perf_probe, use_dialogue_state, use_game_state, use_session_state, use_typewriter_effect, GameState,
+    Possession, SessionState,
+};
+use crate::routes::Route;
+
+/// Stage for acting as `character_id`
+#[component]
+pub fn PossessionView(world_id: String, character_id: String) -> Element {
+    perf_probe::record_render("PossessionView");
+    let game_state = use_game_state();
+    let mut dialogue_state = use_dialogue_state();
+    let session_state = use_session_state();
+    let navigator = use_navigator();
+
+    use_typewriter_effect(&mut dialogue_state);
+
+    let possession = resolve_possession(&game_state, &session_state, &character_id);
+
+    // Hold the possession for as long as this view is open; the name is
+    // refreshed once the world snapshot or party arrives
+    {
+        let mut session_state = session_state.clone();
+        let game_state = game_state.clone();
+        let character_id = character_id.clone();
+        use_effect(move || {
+            let possession = resolve_possession(&game_state, &session_state, &character_id);
+            if session_state.possession.peek().as_ref() != Some(&possession) {
+                session_state.possess(possession);
+            }
+        });
+    }
+    {
+        let mut session_state = session_state.clone();
+        use_drop(move || session_state.release_possession());
+    }
+
+    let exit = {
+        let world_id = world_id.clone();
+        move || {
+            navigator.push(Route::DMViewTabRoute {
+                world_id: world_id.clone(),
+                tab: "director".to_string(),
+            });
+        }
+    };
+
+    let queue_action = {
+        let session_state = session_state.clone();
+        move |action: PlayerAction, label: String| {
+            if !session_state.has_client() {
+                tracing::warn!("Cannot send action: not connected to server");
+                return;
+            }
+            session_state.clone().queue_action(action, label);
+        }
+    };
+
+    // The possessed character stays off the stage list of people to talk to
+    let scene_characters: Vec<_> = game_state
+        .scene_characters
+        .read()
+        .iter()
+        .filter(|c| c.id != character_id)
+        .cloned()
+        .collect();
+    let speaker_name = dialogue_state.speaker_name.read().clone();
+    let displayed_text = dialogue_state.displayed_text.read().clone();
+    let is_typing = *dialogue_state.is_typing.read();
+    let is_llm_processing = *dialogue_state.is_llm_processing.read();
+    let choices = dialogue_state.choices.read().clone();
+    let has_dialogue = dialogue_state.has_dialogue();
+    let sending = session_state.action_queue.read().in_flight().map(|q| q.label.clone());
+    let name = possession.character_name.clone();
+    let kind = if possession.is_pc { "PC" } else { "NPC" };
+
+    rsx! {
+        div {
+            class: "possession-view h-full flex flex-col relative bg-dark-bg",
+            onkeydown: {
+                let exit = exit.clone();
+                move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape && e.modifiers().shift() {
+                        e.stop_propagation();
+                        exit();
+                    }
+                }
+            },
+
+            // Possession banner
+            div {
+                class: "flex items-center justify-between gap-3 px-4 py-2 bg-purple-800 border-b-2 border-purple-400 text-white z-20",
+                role: "status",
+                div {
+                    class: "flex items-center gap-2 text-sm",
+                    span { class: "text-lg", "👁" }
+                    span { "Acting as " strong { "{name}" } " ({kind})" }
+                    span { class: "text-purple-200 text-xs", "· actions are marked DM-controlled" }
+                    if let Some(label) = sending.as_ref() {
+                        span { class: "text-purple-200 text-xs italic", "· sending “{label}”…" }
+                    }
+                }
+                button {
+                    class: "px-4 py-1.5 bg-white text-purple-800 border-0 rounded-lg text-sm font-semibold cursor-pointer",
+                    title: "Back to the Director (Shift+Esc)",
+                    onclick: {
+                        let exit = exit.clone();
+                        move |_| exit()
+                    },
+                    "Exit possession"
+                }
+            }
+
+            div {
+                class: "flex-1 relative overflow-hidden",
+
+                Backdrop {
+                    image_url: game_state.backdrop_url(),
+                    animated_url: game_state.animated_backdrop_url(),
+
+                    // Crowd, DM lighting and drawings, under the characters
+                    StageEffects {}
+
+                    CharacterLayer {
+                        characters: scene_characters,
+                        speaker_id: dialogue_state.speaker_id.read().clone(),
+                        on_character_click: {
+                            let queue_action = queue_action.clone();
+                            let game_state = game_state.clone();
+                            move |target_id: String| {
+                                let label = game_state.scene_characters.peek().iter()
+                                    .find(|c| c.id == target_id)
+                                    .map(|c| format!("Talk to {}", c.name))
+                                    .unwrap_or_else(|| "Talk".to_string());
+                                queue_action(PlayerAction::talk(&target_id, None), label);
+                            }
+                        },
+                    }
+                }
+
+                div {
+                    class: "dialogue-container absolute bottom-0 left-0 right-0 z-10",
+
+                    if has_dialogue {
+                        DialogueBox {
+                            speaker_name: speaker_name,
+                            dialogue_text: displayed_text,
+                            is_typing: is_typing,
+                            is_llm_processing: is_llm_processing,
+                            choices: choices,
+                            on_choice_selected: {
+                                let queue_action = queue_action.clone();
+                                let mut dialogue_state = dialogue_state.clone();
+                                move |choice_id: String| {
+                                    dialogue_state.awaiting_input.set(false);
+                                    let label = dialogue_state.choices.peek().iter()
+                                        .find(|c| c.id == choice_id)
+                                        .map(|c| c.text.clone())
+                                        .unwrap_or_else(|| "Choice".to_string());
+                                    queue_action(PlayerAction::dialogue_choice(&choice_id), label);
+                                }
+                            },
+                            on_custom_input: {
+                                let queue_action = queue_action.clone();
+                                let mut dialogue_state = dialogue_state.clone();
+                                move |text: String| {
+                                    dialogue_state.awaiting_input.set(false);
+                                    queue_action(PlayerAction::custom(&text), text.clone());
+                                }
+                            },
+                            on_advance: {
+                                let mut dialogue_state = dialogue_state.clone();
+                                move |_| {
+                                    if *dialogue_state.is_typing.read() {
+                                        dialogue_state.skip_typewriter();
+                                    }
+                                }
+                            },
+                            on_roll: {
+                                let queue_action = queue_action.clone();
+                                move |roll: DiceRoll| {
+                                    queue_action(
+                                        PlayerAction::roll(&roll.notation.to_string(), &roll.breakdown()),
+                                        format!("Roll {}", roll.notation),
+                                    );
+                                }
+                            },
+                        }
+                    } else {
+                        EmptyDialogueBox {}
+                    }
+                }
+            }
+
+            // Say something as the character without waiting for a prompt
+            SpeakAsPossessed {
+                name: name.clone(),
+                on_say: move |text: String| queue_action(PlayerAction::custom(&text), text.clone()),
+            }
+        }
+    }
+}
+
+/// Who `character_id` is: an NPC from the world, or a PC in the party
+fn resolve_possession(game_state: &GameState, session_state: &SessionState, character_id: &str) -> Possession {
+    let npc_name = game_state
+        .world
+        .read()
+        .as_ref()
+        .and_then(|w| w.get_character(character_id).map(|c| c.name.clone()));
+    let pc_name = session_state
+        .party
+        .members
+        .read()
+        .iter()
+        .find(|m| m.pc_id == character_id)
+        .map(|m| m.name.clone());
+    Possession {
+        character_id: character_id.to_string(),
+        is_pc: npc_name.is_none() && pc_name.is_some(),
+        character_name: npc_name.or(pc_name).unwrap_or_else(|| "Unknown character".to_string()),
+    }
+}
+
+/// One-line input for speaking as the possessed character
+#[component]
+fn SpeakAsPossessed(name: String, on_say: EventHandler<String>) -> Element {
+    let mut text = use_signal(String::new);
+    let mut submit = move || {
+        let line = text.read().trim().to_string();
+        if !line.is_empty() {
+            on_say.call(line);
+            text.set(String::new());
+        }
+    };
+
+    rsx! {
+        form {
+            class: "flex gap-2 p-3 bg-dark-surface border-t border-purple-500/40 z-20",
+            onsubmit: move |e| {
+                e.prevent_default();
+                submit();
+            },
+            input {
+                r#type: "text",
+                value: "{text}",
+                placeholder: "Say or do something as {name}...",
+                aria_label: "Speak as {name}",
+                oninput: move |e| text.set(e.value()),
+                class: "flex-1 p-2 bg-dark-bg border border-gray-700 rounded-lg text-white text-sm",
+            }
+            button {
+                r#type: "submit",
+                class: "px-4 py-2 bg-purple-600 text-white border-0 rounded-lg text-sm cursor-pointer",
+                "Send"
+            }
+        }
+    }
+}
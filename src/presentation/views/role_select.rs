@@ -4,8 +4,9 @@ use dioxus::prelude::*;
 
 use crate::UserRole;
 
+/// `on_try_demo` opens the built-in demo session in the given role
 #[component]
-pub fn RoleSelect(on_select_role: EventHandler<UserRole>) -> Element {
+pub fn RoleSelect(on_select_role: EventHandler<UserRole>, on_try_demo: EventHandler<UserRole>) -> Element {
     rsx! {
         div {
             class: "role-select flex flex-col items-center justify-center h-full bg-gradient-to-br from-dark-surface to-dark-gradient-end",
@@ -27,6 +28,15 @@ pub fn RoleSelect(on_select_role: EventHandler<UserRole>) -> Element {
                     on_click: move |_| on_select_role.call(UserRole::DungeonMaster)
                 }
 
+                // Co-DM card
+                RoleCard {
+                    title: "Co-DM",
+                    description: "Help run a live session: approve NPC dialogue and trigger challenges.",
+                    icon: "🎬",
+                    color: "#f59e0b",
+                    on_click: move |_| on_select_role.call(UserRole::CoDungeonMaster)
+                }
+
                 // Player card
                 RoleCard {
                     title: "Player",
@@ -45,6 +55,25 @@ pub fn RoleSelect(on_select_role: EventHandler<UserRole>) -> Element {
                     on_click: move |_| on_select_role.call(UserRole::Spectator)
                 }
             }
+
+            // Demo session: a scripted world that needs no Engine
+            div {
+                class: "mt-10 flex flex-col items-center gap-3",
+                p { class: "text-gray-400 text-sm m-0", "No Engine running? Try a scripted demo scene." }
+                div {
+                    class: "flex gap-3",
+                    button {
+                        onclick: move |_| on_try_demo.call(UserRole::Player),
+                        class: "px-4 py-2 bg-transparent text-blue-400 border border-blue-500/60 rounded-lg text-sm cursor-pointer hover:bg-blue-500/10",
+                        "⚔️ Play the demo"
+                    }
+                    button {
+                        onclick: move |_| on_try_demo.call(UserRole::DungeonMaster),
+                        class: "px-4 py-2 bg-transparent text-red-400 border border-red-500/60 rounded-lg text-sm cursor-pointer hover:bg-red-500/10",
+                        "🎭 Run the demo as DM"
+                    }
+                }
+            }
         }
     }
 }
@@ -60,6 +89,7 @@ fn RoleCard(
     // Extract conditional classes before rsx! block
     let (border_class, title_class) = match color {
         "#ef4444" => ("border-red-500", "text-red-500"),
+        "#f59e0b" => ("border-amber-500", "text-amber-500"),
         "#3b82f6" => ("border-blue-500", "text-blue-500"),
         "#8b5cf6" => ("border-purple-500", "text-purple-500"),
         _ => ("border-gray-500", "text-gray-500"),
@@ -2,17 +2,28 @@
 //!
 //! Displays the same scene as PC view but without interaction capabilities.
 //! Spectators can see the backdrop, characters, and dialogue but cannot make choices.
+//!
+//! In stream mode (the `/watch/stream` route) the view drops its badge, log,
+//! and hints and renders only the elements the [`StreamLayout`] asks for,
+//! over a chroma-key color or a transparent page, for OBS browser sources.
 
 use dioxus::prelude::*;
 
-use crate::presentation::components::visual_novel::{Backdrop, CharacterLayer, EmptyDialogueBox};
-use crate::presentation::state::{use_dialogue_state, use_game_state, use_typewriter_effect};
+use crate::application::ports::outbound::Platform;
+use crate::application::services::{deep_link, StreamLayout, StreamSettings, CHROMA_PRESETS};
+use crate::presentation::components::audio_cue_player::AudioCuePlayer;
+use crate::presentation::components::common::CopyButton;
+use crate::presentation::components::event_overlays::IntermissionOverlay;
+use crate::presentation::components::visual_novel::{Backdrop, CharacterLayer, EmptyDialogueBox, StageEffects};
+use crate::presentation::state::{perf_probe, use_dialogue_state, use_game_state, use_typewriter_effect};
 
 /// Spectator View - read-only view of the game
 ///
 /// Connection handling and back navigation are provided by WorldSessionLayout wrapper.
+/// `stream` switches to stream mode with the given layout and background.
 #[component]
-pub fn SpectatorView() -> Element {
+pub fn SpectatorView(#[props(default)] stream: Option<StreamSettings>) -> Element {
+    perf_probe::record_render("SpectatorView");
     // Get global state from context
     let game_state = use_game_state();
     let mut dialogue_state = use_dialogue_state();
@@ -60,26 +71,55 @@ pub fn SpectatorView() -> Element {
     let is_typing = *dialogue_state.is_typing.read();
     let has_dialogue = dialogue_state.has_dialogue();
     let is_llm_processing = *dialogue_state.is_llm_processing.read();
+    let intermission = game_state.intermission.read().clone();
+
+    let layout = stream.as_ref().map(|s| s.layout).unwrap_or_default();
+    let root_class = if stream.is_some() {
+        "spectator-view spectator-stream h-full flex flex-col relative overflow-hidden"
+    } else {
+        "spectator-view h-full flex flex-col relative bg-gradient-to-b from-dark-surface to-dark-purple-end"
+    };
+    let root_style = stream.as_ref().map(|s| format!("background: {};", s.background())).unwrap_or_default();
+    let characters = rsx! {
+        CharacterLayer {
+            characters: scene_characters,
+            on_character_click: None, // Spectators cannot interact
+            speaker_id: dialogue_state.speaker_id.read().clone(),
+        }
+    };
 
     rsx! {
         div {
-            class: "spectator-view h-full flex flex-col relative bg-gradient-to-b from-dark-surface to-dark-purple-end",
+            class: root_class,
+            style: root_style,
 
-            // Spectator badge (top right)
-            div {
-                class: "absolute top-4 right-4 z-[100] px-4 py-2 bg-purple-500/20 text-purple-300 border border-purple-500 rounded-lg text-sm",
-                "Spectating"
+            // Spectator badge and stream setup (top right)
+            if stream.is_none() {
+                div {
+                    class: "absolute top-4 right-4 z-[100] flex flex-col items-end gap-2",
+                    div {
+                        class: "px-4 py-2 bg-purple-500/20 text-purple-300 border border-purple-500 rounded-lg text-sm",
+                        "Spectating"
+                    }
+                    StreamSetup {}
+                }
             }
 
             // Visual novel stage (2.3.1 - Scene display)
-            Backdrop {
-                image_url: game_state.backdrop_url(),
+            if layout.shows_backdrop() {
+                Backdrop {
+                    image_url: game_state.backdrop_url(),
+                    animated_url: game_state.animated_backdrop_url(),
 
-                // Character layer with real scene characters
-                CharacterLayer {
-                    characters: scene_characters,
-                    on_character_click: None, // Spectators cannot interact
+                    // Crowd, DM lighting and drawings, under the characters
+                    StageEffects {}
+
+                    // Character layer with real scene characters
+                    {characters}
                 }
+            } else if layout.shows_portraits() {
+                // Portraits straight over the stream background
+                {characters}
             }
 
             // Dialogue box (fixed at bottom) - 2.3.2 Read-only dialogue display
@@ -92,18 +132,29 @@ pub fn SpectatorView() -> Element {
                         dialogue_text: displayed_text.clone(),
                         is_typing: is_typing,
                         is_llm_processing: is_llm_processing,
+                        show_hint: stream.is_none(),
                     }
-                } else {
+                } else if stream.is_none() {
                     EmptyDialogueBox {}
                 }
             }
 
             // Conversation log (2.3.3 - Scrollable history) - only show if log has entries
-            if !conversation_log.read().is_empty() {
+            if stream.is_none() && !conversation_log.read().is_empty() {
                 ConversationLog {
                     entries: conversation_log.read().clone(),
                 }
             }
+
+            // Intermission overlay (session paused by DM)
+            if let Some(ref intermission) = intermission {
+                IntermissionOverlay {
+                    intermission: intermission.clone(),
+                }
+            }
+
+            // Sounds attached to narrative events and challenge outcomes
+            AudioCuePlayer {}
         }
     }
 }
@@ -124,6 +175,9 @@ pub struct SpectatorDialogueBoxProps {
     /// Whether NPC is currently thinking
     #[props(default = false)]
     pub is_llm_processing: bool,
+    /// Whether to say that spectators have no choices (off on streams)
+    #[props(default = true)]
+    pub show_hint: bool,
 }
 
 /// Spectator-specific dialogue box (no interaction)
@@ -177,9 +231,80 @@ fn SpectatorDialogueBox(props: SpectatorDialogueBoxProps) -> Element {
             }
 
             // Spectator indicator (instead of choices)
+            if props.show_hint {
+                div {
+                    class: "mt-3 pt-3 border-t border-gray-700 text-purple-500 text-xs text-center italic",
+                    "Spectating - No choices available"
+                }
+            }
+        }
+    }
+}
+
+/// Stream setup - builds the stream-mode URL to paste into OBS
+///
+/// Picks a layout and chroma-key background and copies the matching
+/// `/watch/stream` link for a browser source.
+#[component]
+fn StreamSetup() -> Element {
+    let game_state = use_game_state();
+    let platform = use_context::<Platform>();
+    let mut open = use_signal(|| false);
+    let mut settings = use_signal(StreamSettings::default);
+
+    let Some(world_id) = game_state.world.read().as_ref().map(|w| w.world.id.clone()) else {
+        return rsx! {};
+    };
+    let current = settings.read().clone();
+    let path = format!("/worlds/{}/watch/stream?{}", world_id, current.query_string());
+    let url = deep_link(platform.app_origin().as_deref(), &path);
+    let chroma_value = current.chroma.clone().unwrap_or_default();
+
+    rsx! {
+        button {
+            r#type: "button",
+            aria_expanded: "{open}",
+            onclick: move |_| open.toggle(),
+            class: "px-3 py-1 bg-black/60 text-gray-300 border border-gray-700 rounded cursor-pointer text-xs",
+            "🎥 Stream mode"
+        }
+
+        if *open.read() {
             div {
-                class: "mt-3 pt-3 border-t border-gray-700 text-purple-500 text-xs text-center italic",
-                "Spectating - No choices available"
+                class: "flex flex-col gap-2 p-3 bg-dark-surface border border-gray-700 rounded-lg w-64 text-sm",
+                label {
+                    class: "flex flex-col gap-1 text-gray-400 text-xs",
+                    "Layout"
+                    select {
+                        value: "{current.layout.as_str()}",
+                        onchange: move |e| settings.write().layout = StreamLayout::parse(&e.value()),
+                        class: "p-1.5 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                        for option in StreamLayout::all() {
+                            option { key: "{option.as_str()}", value: "{option.as_str()}", "{option.display_name()}" }
+                        }
+                    }
+                }
+                label {
+                    class: "flex flex-col gap-1 text-gray-400 text-xs",
+                    "Background"
+                    select {
+                        value: "{chroma_value}",
+                        onchange: move |e| {
+                            let value = e.value();
+                            settings.write().chroma = (!value.is_empty()).then_some(value);
+                        },
+                        class: "p-1.5 bg-dark-bg border border-gray-700 rounded text-white text-sm",
+                        option { value: "", "Transparent" }
+                        for (name, color) in CHROMA_PRESETS {
+                            option { key: "{name}", value: "{color}", "Chroma {name}" }
+                        }
+                    }
+                }
+                p {
+                    class: "m-0 p-2 bg-dark-bg rounded text-gray-300 text-xs break-all font-mono",
+                    "{url}"
+                }
+                CopyButton { text: url.clone(), label: "Copy OBS URL".to_string() }
             }
         }
     }
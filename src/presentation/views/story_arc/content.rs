@@ -1,8 +1,10 @@
-//! Story Arc mode content - Timeline, Narrative Events, Event Chains
+//! Story Arc mode content - Timeline, Narrative Events, Event Chains, Branches, Campaign
 
 use dioxus::prelude::*;
 
+use crate::presentation::components::story_arc::campaign_timeline::CampaignTimelineView;
 use crate::presentation::components::story_arc::timeline_view::TimelineView;
+use crate::presentation::components::story_arc::narrative_branch_view::NarrativeBranchView;
 use crate::presentation::components::story_arc::narrative_event_library::NarrativeEventLibrary;
 use super::{StoryArcSubTab, StoryArcTabLink, EventChainsView};
 
@@ -12,6 +14,10 @@ pub struct StoryArcContentProps {
     pub world_id: String,
     #[props(default)]
     pub selected_tab: Option<String>,
+    /// Story event to open on the timeline, or narrative event to open in
+    /// the library
+    #[props(default)]
+    pub selected_event_id: Option<String>,
 }
 
 #[component]
@@ -51,6 +57,20 @@ pub fn StoryArcContent(props: StoryArcContentProps) -> Element {
                     world_id: props.world_id.clone(),
                     is_active: active_tab == StoryArcSubTab::EventChains,
                 }
+                StoryArcTabLink {
+                    label: "Branches",
+                    icon: "🌳",
+                    subtab: "branches",
+                    world_id: props.world_id.clone(),
+                    is_active: active_tab == StoryArcSubTab::Branches,
+                }
+                StoryArcTabLink {
+                    label: "Campaign",
+                    icon: "🗺",
+                    subtab: "campaign",
+                    world_id: props.world_id.clone(),
+                    is_active: active_tab == StoryArcSubTab::Campaign,
+                }
             }
 
             // Content area
@@ -59,16 +79,28 @@ pub fn StoryArcContent(props: StoryArcContentProps) -> Element {
 
                 match active_tab {
                     StoryArcSubTab::Timeline => rsx! {
-                        TimelineView { world_id: props.world_id.clone() }
+                        TimelineView {
+                            world_id: props.world_id.clone(),
+                            focus_event_id: props.selected_event_id.clone(),
+                        }
                     },
                     StoryArcSubTab::NarrativeEvents => rsx! {
-                        NarrativeEventLibrary { world_id: props.world_id.clone() }
+                        NarrativeEventLibrary {
+                            world_id: props.world_id.clone(),
+                            focus_event_id: props.selected_event_id.clone(),
+                        }
                     },
                     StoryArcSubTab::EventChains => rsx! {
                         EventChainsView {
                             world_id: props.world_id.clone(),
                         }
                     },
+                    StoryArcSubTab::Branches => rsx! {
+                        NarrativeBranchView { world_id: props.world_id.clone() }
+                    },
+                    StoryArcSubTab::Campaign => rsx! {
+                        CampaignTimelineView { world_id: props.world_id.clone() }
+                    },
                 }
             }
         }
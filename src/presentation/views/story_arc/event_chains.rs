@@ -77,10 +77,16 @@ pub fn EventChainsView(world_id: String) -> Element {
             } else if let Some(chain) = chain_data.as_ref() {
                 // Show visualizer for selected chain
                 div {
-                    class: "flex gap-4 h-full",
+                    class: "flex flex-col lg:flex-row gap-4 h-full",
+                    // On narrow screens the list gives way to the selected chain
+                    button {
+                        class: "lg:hidden self-start px-3 py-1.5 bg-transparent text-gray-400 border border-gray-700 rounded text-sm cursor-pointer",
+                        onclick: move |_| selected_chain.set(None),
+                        "◂ All chains"
+                    }
                     // Sidebar with chain list
                     div {
-                        class: "w-[300px] flex-shrink-0 overflow-y-auto",
+                        class: "hidden lg:block w-[300px] flex-shrink-0 overflow-y-auto",
                         EventChainList {
                             world_id: world_id.clone(),
                             filter: *filter.read(),
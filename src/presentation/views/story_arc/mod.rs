@@ -1,4 +1,4 @@
-//! Story Arc module - Timeline, Narrative Events, Event Chains
+//! Story Arc module - Timeline, Narrative Events, Event Chains, Branches, Campaign
 
 mod content;
 mod event_chains;
@@ -15,6 +15,8 @@ pub enum StoryArcSubTab {
     Timeline,
     NarrativeEvents,
     EventChains,
+    Branches,
+    Campaign,
 }
 
 impl StoryArcSubTab {
@@ -23,6 +25,8 @@ impl StoryArcSubTab {
             "timeline" => Self::Timeline,
             "events" => Self::NarrativeEvents,
             "chains" => Self::EventChains,
+            "branches" => Self::Branches,
+            "campaign" => Self::Campaign,
             _ => Self::Timeline,
         }
     }
@@ -32,6 +36,8 @@ impl StoryArcSubTab {
             Self::Timeline => "timeline",
             Self::NarrativeEvents => "events",
             Self::EventChains => "chains",
+            Self::Branches => "branches",
+            Self::Campaign => "campaign",
         }
     }
 }
@@ -4,6 +4,10 @@
 //! - DM: Can create new worlds or continue existing ones
 //! - Player: Can join existing worlds
 //! - Spectator: Can watch existing worlds
+//!
+//! Worlds can be narrowed to one campaign, listed in its play order; DMs
+//! create and edit campaigns here too. DMs can also create the tutorial
+//! world, a small pre-written world for learning the tools.
 
 use dioxus::prelude::*;
 
@@ -12,8 +16,9 @@ use crate::application::dto::{
     StatDefinition, SuccessComparison, SessionWorldSnapshot,
 };
 use crate::application::services::world_service::{WorldSummary, SessionInfo};
+use crate::application::services::{Campaign, SaveCampaignRequest, TutorialProgress};
 use crate::application::ports::outbound::Platform;
-use crate::presentation::services::use_world_service;
+use crate::presentation::services::{use_campaign_service, use_tutorial_world_service, use_world_service};
 use crate::presentation::state::GameState;
 use crate::UserRole;
 
@@ -40,6 +45,12 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
     let mut error: Signal<Option<String>> = use_signal(|| None);
     let mut show_create_form = use_signal(|| false);
     let mut world_to_load: Signal<Option<String>> = use_signal(|| None);
+    let mut campaigns: Signal<Vec<Campaign>> = use_signal(Vec::new);
+    let mut selected_campaign: Signal<Option<String>> = use_signal(|| None);
+    // Campaign open in the form; a default campaign is a new one
+    let mut editing_campaign: Signal<Option<Campaign>> = use_signal(|| None);
+    // Set while the tutorial world is being created
+    let mut tutorial_progress: Signal<Option<TutorialProgress>> = use_signal(|| None);
 
     let is_dm = props.role == UserRole::DungeonMaster;
 
@@ -64,6 +75,38 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
         });
     });
 
+    let create_tutorial = {
+        let tutorial_service = use_tutorial_world_service();
+        let world_service = world_service.clone();
+        move |_| {
+            let svc = tutorial_service.clone();
+            let world_svc = world_service.clone();
+            error.set(None);
+            spawn(async move {
+                let result = svc
+                    .create_tutorial_world(|progress| tutorial_progress.set(Some(progress)))
+                    .await;
+                tutorial_progress.set(None);
+                match result {
+                    Ok(world_id) => world_to_load.set(Some(world_id)),
+                    Err(e) => {
+                        let partial = e.world_id.is_some();
+                        error.set(Some(if partial {
+                            format!("{}. What was created so far is in your world list.", e)
+                        } else {
+                            e.to_string()
+                        }));
+                        if partial {
+                            if let Ok(list) = world_svc.list_worlds().await {
+                                worlds.set(list);
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    };
+
     // Fetch active sessions for all worlds (for DM "Continue" and Player/Spectator views)
     let user_id = platform.get_user_id();
     let world_service_for_sessions = world_service.clone();
@@ -81,6 +124,18 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
         });
     });
 
+    // Fetch campaigns; without any the list is just worlds
+    let campaign_service = use_campaign_service();
+    use_effect(move || {
+        let svc = campaign_service.clone();
+        spawn(async move {
+            match svc.list_campaigns().await {
+                Ok(list) => campaigns.set(list),
+                Err(e) => tracing::warn!("Failed to load campaigns: {}", e),
+            }
+        });
+    });
+
     // Effect to load world when world_to_load is set
     use_effect(move || {
         if let Some(world_id) = world_to_load.read().clone() {
@@ -117,18 +172,21 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
     // Title based on role
     let title = match props.role {
         UserRole::DungeonMaster => "Select World",
+        UserRole::CoDungeonMaster => "Assist a Session",
         UserRole::Player => "Join a World",
         UserRole::Spectator => "Watch a World",
     };
 
     let subtitle = match props.role {
         UserRole::DungeonMaster => "Continue an existing campaign or create a new one",
+        UserRole::CoDungeonMaster => "Choose a running session to help direct",
         UserRole::Player => "Choose a world to join as a player",
         UserRole::Spectator => "Choose a world to watch",
     };
 
     let action_label = match props.role {
         UserRole::DungeonMaster => "Continue",
+        UserRole::CoDungeonMaster => "Assist",
         UserRole::Player => "Join",
         UserRole::Spectator => "Watch",
     };
@@ -149,6 +207,21 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
             .collect()
     };
 
+    // Narrow to the selected campaign, in its play order
+    let campaigns_snapshot = campaigns.read().clone();
+    let campaign = selected_campaign
+        .read()
+        .as_ref()
+        .and_then(|id| campaigns_snapshot.iter().find(|c| &c.id == id).cloned());
+    let filtered_worlds: Vec<WorldSummary> = match campaign.as_ref() {
+        Some(campaign) => campaign
+            .world_ids
+            .iter()
+            .filter_map(|id| filtered_worlds.iter().find(|w| &w.id == id).cloned())
+            .collect(),
+        None => filtered_worlds,
+    };
+
     rsx! {
         div {
             class: "world-select-view h-full flex flex-col items-center justify-center p-8 bg-gradient-to-br from-dark-surface to-dark-gradient-end",
@@ -186,6 +259,23 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
                         class: "text-center text-gray-500 p-8",
                         "Loading worlds..."
                     }
+                } else if let Some(progress) = tutorial_progress.read().clone() {
+                    div {
+                        class: "bg-dark-surface rounded-lg p-6 flex flex-col gap-3",
+                        h2 { class: "text-white m-0 text-lg", "Creating Tutorial World" }
+                        div {
+                            class: "h-2 bg-gray-700 rounded overflow-hidden",
+                            role: "progressbar",
+                            aria_valuemin: "0",
+                            aria_valuemax: "{progress.total}",
+                            aria_valuenow: "{progress.completed}",
+                            div { class: "h-full bg-purple-500 transition-all", style: "width: {progress.percent()}%" }
+                        }
+                        p {
+                            class: "text-gray-400 text-sm m-0",
+                            "{progress.label}... ({progress.completed} of {progress.total})"
+                        }
+                    }
                 } else if *show_create_form.read() && is_dm {
                     // Create form (DM only)
                     CreateWorldForm {
@@ -195,7 +285,67 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
                         },
                         on_cancel: move |_| show_create_form.set(false),
                     }
+                } else if let Some(editing) = editing_campaign.read().clone().filter(|_| is_dm) {
+                    CampaignForm {
+                        campaign: editing,
+                        worlds: worlds_val_snapshot.clone(),
+                        on_saved: move |saved: Campaign| {
+                            let mut list = campaigns.write();
+                            match list.iter_mut().find(|c| c.id == saved.id) {
+                                Some(existing) => *existing = saved.clone(),
+                                None => list.push(saved.clone()),
+                            }
+                            selected_campaign.set(Some(saved.id));
+                            editing_campaign.set(None);
+                        },
+                        on_deleted: move |id: String| {
+                            campaigns.write().retain(|c| c.id != id);
+                            selected_campaign.set(None);
+                            editing_campaign.set(None);
+                        },
+                        on_cancel: move |_| editing_campaign.set(None),
+                    }
                 } else {
+                    // Campaign filter
+                    if !campaigns_snapshot.is_empty() || is_dm {
+                        div {
+                            class: "flex flex-wrap items-center gap-2 mb-4",
+
+                            span { class: "text-gray-500 text-xs uppercase mr-1", "Campaign" }
+                            button {
+                                onclick: move |_| selected_campaign.set(None),
+                                class: if campaign.is_none() { CHIP_ACTIVE } else { CHIP },
+                                "All worlds"
+                            }
+                            for c in campaigns_snapshot.iter() {
+                                button {
+                                    key: "{c.id}",
+                                    onclick: {
+                                        let id = c.id.clone();
+                                        move |_| selected_campaign.set(Some(id.clone()))
+                                    },
+                                    class: if campaign.as_ref().is_some_and(|sel| sel.id == c.id) { CHIP_ACTIVE } else { CHIP },
+                                    title: c.description.clone().unwrap_or_default(),
+                                    "{c.name}"
+                                }
+                            }
+                            if is_dm {
+                                if let Some(c) = campaign.clone() {
+                                    button {
+                                        onclick: move |_| editing_campaign.set(Some(c.clone())),
+                                        class: "px-3 py-1 bg-transparent text-gray-400 border border-gray-700 rounded-full cursor-pointer text-xs",
+                                        "Edit"
+                                    }
+                                }
+                                button {
+                                    onclick: move |_| editing_campaign.set(Some(Campaign::default())),
+                                    class: "px-3 py-1 bg-transparent text-purple-400 border border-dashed border-purple-500/60 rounded-full cursor-pointer text-xs",
+                                    "+ New Campaign"
+                                }
+                            }
+                        }
+                    }
+
                     // World list
                     div {
                         class: "bg-dark-surface rounded-lg overflow-hidden",
@@ -209,10 +359,19 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
                             }
 
                             if is_dm {
-                                button {
-                                    onclick: move |_| show_create_form.set(true),
-                                    class: "px-4 py-2 bg-purple-500 text-white border-0 rounded cursor-pointer text-sm",
-                                    "+ Create New World"
+                                div {
+                                    class: "flex gap-2",
+                                    button {
+                                        onclick: create_tutorial,
+                                        title: "A small pre-written world with NPCs, challenges and events to practise on",
+                                        class: "px-4 py-2 bg-transparent text-purple-400 border border-purple-500/60 rounded cursor-pointer text-sm",
+                                        "Create Tutorial World"
+                                    }
+                                    button {
+                                        onclick: move |_| show_create_form.set(true),
+                                        class: "px-4 py-2 bg-purple-500 text-white border-0 rounded cursor-pointer text-sm",
+                                        "+ Create New World"
+                                    }
                                 }
                             }
                         }
@@ -224,9 +383,11 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
                             if filtered_worlds.is_empty() {
                                 div {
                                     class: "p-8 text-center text-gray-500",
-                                    if is_dm {
+                                    if campaign.is_some() {
+                                        p { "No worlds of this campaign are available." }
+                                    } else if is_dm {
                                         p { "No worlds yet." }
-                                        p { class: "text-sm", "Create your first world to get started!" }
+                                        p { class: "text-sm", "Create your first world to get started, or a tutorial world to learn the tools." }
                                     } else {
                                         p { "No worlds available." }
                                         p { class: "text-sm", "Ask your DM to create a world first." }
@@ -238,6 +399,7 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
                                 WorldCard {
                                     key: "{world.id}",
                                     world: world.clone(),
+                                    chapter: campaign.as_ref().and_then(|c| c.chapter_of(&world.id)),
                                     action_label: action_label,
                                     is_dm: is_dm,
                                     has_dm_session: if is_dm {
@@ -285,10 +447,16 @@ pub fn WorldSelectView(props: WorldSelectViewProps) -> Element {
     }
 }
 
+const CHIP: &str = "px-3 py-1 bg-dark-surface text-gray-300 border border-gray-700 rounded-full cursor-pointer text-xs";
+const CHIP_ACTIVE: &str = "px-3 py-1 bg-purple-500 text-white border border-purple-500 rounded-full cursor-pointer text-xs";
+
 /// World card in the list
 #[component]
 fn WorldCard(
     world: WorldSummary,
+    /// Place in the selected campaign's play order
+    #[props(default)]
+    chapter: Option<usize>,
     action_label: &'static str,
     is_dm: bool,
     has_dm_session: bool,
@@ -312,7 +480,12 @@ fn WorldCard(
 
             div {
                 class: "flex-1",
-                h3 { class: "text-white m-0 mb-1 text-base", "{world.name}" }
+                h3 { class: "text-white m-0 mb-1 text-base",
+                    if let Some(n) = chapter {
+                        span { class: "mr-2 px-2 py-0.5 bg-purple-500/20 text-purple-300 rounded text-xs align-middle", "Chapter {n}" }
+                    }
+                    "{world.name}"
+                }
                 if let Some(desc) = &world.description {
                     p { class: "text-gray-400 m-0 text-sm leading-snug", "{desc}" }
                 }
@@ -880,3 +1053,181 @@ fn RuleSystemConfigEditor(
         }
     }
 }
+
+/// Form for creating or editing a campaign (DM only)
+///
+/// Worlds join the campaign in the order they are ticked, which is the
+/// order they are played in.
+#[component]
+fn CampaignForm(
+    campaign: Campaign,
+    worlds: Vec<WorldSummary>,
+    on_saved: EventHandler<Campaign>,
+    on_deleted: EventHandler<String>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let campaign_service = use_campaign_service();
+    let is_new = campaign.id.is_empty();
+    let mut name = use_signal(|| campaign.name.clone());
+    let mut description = use_signal(|| campaign.description.clone().unwrap_or_default());
+    let mut world_ids = use_signal(|| campaign.world_ids.clone());
+    let mut is_saving = use_signal(|| false);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let handle_save = {
+        let svc = campaign_service.clone();
+        let campaign_id = campaign.id.clone();
+        move |_| {
+            let description = description.read().trim().to_string();
+            let request = SaveCampaignRequest {
+                name: name.read().trim().to_string(),
+                description: (!description.is_empty()).then_some(description),
+                world_ids: world_ids.read().clone(),
+            };
+            if let Err(msg) = request.validate() {
+                error.set(Some(msg));
+                return;
+            }
+            let svc = svc.clone();
+            let campaign_id = campaign_id.clone();
+            spawn(async move {
+                is_saving.set(true);
+                error.set(None);
+                let result = if campaign_id.is_empty() {
+                    svc.create_campaign(&request).await
+                } else {
+                    svc.update_campaign(&campaign_id, &request).await
+                };
+                match result {
+                    Ok(saved) => on_saved.call(saved),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+                is_saving.set(false);
+            });
+        }
+    };
+
+    let handle_delete = {
+        let svc = campaign_service.clone();
+        let campaign_id = campaign.id.clone();
+        move |_| {
+            let svc = svc.clone();
+            let campaign_id = campaign_id.clone();
+            spawn(async move {
+                is_saving.set(true);
+                match svc.delete_campaign(&campaign_id).await {
+                    Ok(()) => on_deleted.call(campaign_id),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+                is_saving.set(false);
+            });
+        }
+    };
+
+    let saving = *is_saving.read();
+    let selected = world_ids.read().clone();
+
+    rsx! {
+        div {
+            class: "bg-dark-surface rounded-lg p-6 max-h-[80vh] overflow-y-auto",
+
+            h2 { class: "text-white m-0 mb-4",
+                if is_new { "New Campaign" } else { "Edit Campaign" }
+            }
+
+            if let Some(err) = error.read().as_ref() {
+                div {
+                    class: "p-3 bg-red-500/10 rounded text-red-500 mb-4 text-sm",
+                    "{err}"
+                }
+            }
+
+            div { class: "mb-4",
+                label { class: "block text-gray-400 text-sm mb-1", "Name *" }
+                input {
+                    r#type: "text",
+                    value: "{name}",
+                    oninput: move |e| name.set(e.value()),
+                    placeholder: "The Shattered Crown",
+                    disabled: saving,
+                    class: "w-full p-3 bg-dark-bg border border-gray-700 rounded text-white box-border",
+                }
+            }
+
+            div { class: "mb-4",
+                label { class: "block text-gray-400 text-sm mb-1", "Description" }
+                textarea {
+                    value: "{description}",
+                    oninput: move |e| description.set(e.value()),
+                    disabled: saving,
+                    class: "w-full min-h-[60px] p-3 bg-dark-bg border border-gray-700 rounded text-white resize-y box-border",
+                }
+            }
+
+            div { class: "mb-6",
+                label { class: "block text-gray-400 text-sm mb-1", "Worlds, in play order" }
+                div {
+                    class: "flex flex-col gap-1 p-2 bg-dark-bg border border-gray-700 rounded max-h-[240px] overflow-y-auto",
+                    if worlds.is_empty() {
+                        p { class: "text-gray-500 text-sm m-0 p-2", "No worlds yet." }
+                    }
+                    for world in worlds.iter() {
+                        {
+                            let chapter = selected.iter().position(|id| id == &world.id).map(|i| i + 1);
+                            let world_id = world.id.clone();
+                            rsx! {
+                                label {
+                                    key: "{world.id}",
+                                    class: "flex items-center gap-2 p-2 rounded cursor-pointer hover:bg-dark-surface text-white text-sm",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: chapter.is_some(),
+                                        disabled: saving,
+                                        onchange: move |_| {
+                                            let mut ids = world_ids.write();
+                                            if let Some(pos) = ids.iter().position(|id| id == &world_id) {
+                                                ids.remove(pos);
+                                            } else {
+                                                ids.push(world_id.clone());
+                                            }
+                                        },
+                                    }
+                                    span { class: "flex-1", "{world.name}" }
+                                    if let Some(n) = chapter {
+                                        span { class: "text-purple-300 text-xs", "Chapter {n}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: "flex justify-between gap-3",
+                if !is_new {
+                    button {
+                        onclick: handle_delete,
+                        disabled: saving,
+                        title: "Delete the campaign; its worlds are kept",
+                        class: "px-4 py-2 bg-transparent text-red-400 border border-red-500/50 rounded cursor-pointer text-sm",
+                        "Delete"
+                    }
+                }
+                div { class: "flex gap-3 ml-auto",
+                    button {
+                        onclick: move |_| on_cancel.call(()),
+                        disabled: saving,
+                        class: "px-4 py-2 bg-transparent text-gray-400 border border-gray-700 rounded cursor-pointer",
+                        "Cancel"
+                    }
+                    button {
+                        onclick: handle_save,
+                        disabled: saving,
+                        class: "px-4 py-2 bg-purple-500 text-white border-0 rounded cursor-pointer",
+                        if saving { "Saving..." } else { "Save Campaign" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -7,7 +7,9 @@
 use dioxus::prelude::*;
 
 use crate::application::ports::outbound::{Platform, storage_keys};
-use crate::application::services::{ParticipantRolePort as ParticipantRole, SessionService, DEFAULT_ENGINE_URL};
+use crate::application::services::{
+    ParticipantRolePort as ParticipantRole, SessionService, DEFAULT_ENGINE_URL, DEMO_ENGINE_URL, DEMO_WORLD_ID,
+};
 use crate::presentation::state::{ConnectionStatus, DialogueState, GameState, GenerationState, SessionState};
 
 /// Ensure a WebSocket connection is established for the given world and role.
@@ -15,9 +17,13 @@ use crate::presentation::state::{ConnectionStatus, DialogueState, GameState, Gen
 /// This function checks the current connection status and only initiates
 /// a new connection if we're disconnected or failed. This prevents duplicate
 /// connections when navigating between views.
+///
+/// `auth_user_id` is the logged-in user's ID, if any; anonymous users fall back
+/// to the stable anonymous ID from storage.
 pub fn ensure_connection(
     world_id: &str,
     role: ParticipantRole,
+    auth_user_id: Option<String>,
     session_state: SessionState,
     game_state: GameState,
     dialogue_state: DialogueState,
@@ -36,17 +42,23 @@ pub fn ensure_connection(
         return;
     }
 
-    // Load server URL from storage or use default
-    let server_url = platform
-        .storage_load(storage_keys::SERVER_URL)
-        .unwrap_or_else(|| DEFAULT_ENGINE_URL.to_string());
-    platform.storage_save(storage_keys::SERVER_URL, &server_url);
+    let server_url = if world_id == DEMO_WORLD_ID {
+        // The demo runs locally and leaves the saved Engine alone
+        DEMO_ENGINE_URL.to_string()
+    } else {
+        // Load server URL from storage or use default
+        let server_url = platform
+            .storage_load(storage_keys::SERVER_URL)
+            .unwrap_or_else(|| DEFAULT_ENGINE_URL.to_string());
+        platform.storage_save(storage_keys::SERVER_URL, &server_url);
 
-    // Configure Engine HTTP base URL from the WebSocket URL
-    platform.configure_engine_url(&server_url);
+        // Configure Engine HTTP base URL from the WebSocket URL
+        platform.configure_engine_url(&server_url);
+        server_url
+    };
 
-    // Use the stable anonymous user ID from storage
-    let user_id = platform.get_user_id();
+    // Prefer the authenticated user ID, else the stable anonymous ID from storage
+    let user_id = auth_user_id.unwrap_or_else(|| platform.get_user_id());
 
     initiate_connection(
         server_url,
@@ -82,13 +94,16 @@ fn initiate_connection(
     session_state.start_connecting(&server_url);
     session_state.set_user(user_id.clone(), role);
 
+    // Use the platform's connection factory to create a game connection.
+    // Done before spawning so a demo session's REST responses are in place
+    // before the view's first requests.
+    let connection = platform.create_game_connection(&server_url);
+    session_state.set_connection_handle(connection.clone());
+
     // Spawn async task to handle connection
     spawn(async move {
         use futures_util::StreamExt;
 
-        // Use the platform's connection factory to create a game connection
-        let connection = platform.create_game_connection(&server_url);
-        session_state.set_connection_handle(connection.clone());
         let session_service = SessionService::new(connection.clone());
 
         match session_service.connect(user_id, role, world_id).await {
@@ -1,7 +1,7 @@
 //! DM (Dungeon Master) view route handlers
 
 use dioxus::prelude::*;
-use crate::application::ports::outbound::{Platform, storage_keys};
+use crate::application::ports::outbound::{Capability, Platform, storage_keys};
 use crate::application::services::ParticipantRolePort as ParticipantRole;
 use crate::presentation::state::{ConnectionStatus, DialogueState, GameState, SessionState};
 use crate::presentation::views::dm_view::DMMode;
@@ -12,10 +12,11 @@ use super::Route;
 /// DMViewRoute - renders Director tab directly (no redirect needed)
 #[component]
 pub fn DMViewRoute(world_id: String) -> Element {
+    let platform = use_context::<Platform>();
     rsx! {
         WorldSessionLayout {
             world_id: world_id.clone(),
-            role: ParticipantRole::DungeonMaster,
+            role: dm_session_role(&platform),
             page_title: "Director",
             show_status_bar: false,
 
@@ -35,19 +36,22 @@ pub fn DMViewRoute(world_id: String) -> Element {
 /// This avoids use_effect redirect race conditions
 #[component]
 pub fn DMViewTabRoute(world_id: String, tab: String) -> Element {
+    let platform = use_context::<Platform>();
     // Determine mode and default subtab based on tab parameter
     let (dm_mode, creator_subtab, settings_subtab, story_arc_subtab, title) = match tab.as_str() {
         "director" => (DMMode::Director, None, None, None, "Director"),
+        "challenges" => (DMMode::Director, None, None, None, "Director - Challenges"),
         "creator" => (DMMode::Creator, Some("characters".to_string()), None, None, "Creator - Characters"),
         "settings" => (DMMode::Settings, None, Some("workflows".to_string()), None, "Settings - Workflows"),
         "story-arc" => (DMMode::StoryArc, None, None, Some("timeline".to_string()), "Story Arc - Timeline"),
+        "notes" => (DMMode::Notes, None, None, None, "Notes"),
         _ => (DMMode::Director, None, None, None, "Director"),
     };
 
     rsx! {
         WorldSessionLayout {
             world_id: world_id.clone(),
-            role: ParticipantRole::DungeonMaster,
+            role: dm_session_role(&platform),
             page_title: title,
             show_status_bar: false,
 
@@ -57,6 +61,7 @@ pub fn DMViewTabRoute(world_id: String, tab: String) -> Element {
                 creator_subtab: creator_subtab,
                 settings_subtab: settings_subtab,
                 story_arc_subtab: story_arc_subtab,
+                challenges_open: tab == "challenges",
             }
         }
     }
@@ -65,6 +70,7 @@ pub fn DMViewTabRoute(world_id: String, tab: String) -> Element {
 /// DMCreatorSubTabRoute - Creator mode with specific sub-tab
 #[component]
 pub fn DMCreatorSubTabRoute(world_id: String, subtab: String) -> Element {
+    let platform = use_context::<Platform>();
     // Set page title based on subtab
     let title = match subtab.as_str() {
         "characters" => "Creator - Characters",
@@ -77,7 +83,7 @@ pub fn DMCreatorSubTabRoute(world_id: String, subtab: String) -> Element {
     rsx! {
         WorldSessionLayout {
             world_id: world_id.clone(),
-            role: ParticipantRole::DungeonMaster,
+            role: dm_session_role(&platform),
             page_title: title,
             show_status_bar: false,
 
@@ -92,20 +98,75 @@ pub fn DMCreatorSubTabRoute(world_id: String, subtab: String) -> Element {
     }
 }
 
+/// DMCreatorEntityRoute - Creator mode with one entity's form open
+#[component]
+pub fn DMCreatorEntityRoute(world_id: String, subtab: String, entity_id: String) -> Element {
+    let platform = use_context::<Platform>();
+    let title = match subtab.as_str() {
+        "characters" => "Creator - Edit Character",
+        "locations" => "Creator - Edit Location",
+        _ => "Creator",
+    };
+
+    rsx! {
+        WorldSessionLayout {
+            world_id: world_id.clone(),
+            role: dm_session_role(&platform),
+            page_title: title,
+            show_status_bar: false,
+
+            DMViewContent {
+                world_id: world_id,
+                dm_mode: DMMode::Creator,
+                creator_subtab: Some(subtab),
+                creator_entity_id: Some(entity_id),
+                settings_subtab: None,
+                story_arc_subtab: None,
+            }
+        }
+    }
+}
+
+/// DMChallengeRoute - Director with the Challenge Library editing one challenge
+#[component]
+pub fn DMChallengeRoute(world_id: String, challenge_id: String) -> Element {
+    let platform = use_context::<Platform>();
+    rsx! {
+        WorldSessionLayout {
+            world_id: world_id.clone(),
+            role: dm_session_role(&platform),
+            page_title: "Director - Edit Challenge",
+            show_status_bar: false,
+
+            DMViewContent {
+                world_id: world_id,
+                dm_mode: DMMode::Director,
+                creator_subtab: None,
+                settings_subtab: None,
+                story_arc_subtab: None,
+                challenges_open: true,
+                challenge_id: Some(challenge_id),
+            }
+        }
+    }
+}
+
 /// DMSettingsSubTabRoute - Settings with specific sub-tab
 #[component]
 pub fn DMSettingsSubTabRoute(world_id: String, subtab: String) -> Element {
+    let platform = use_context::<Platform>();
     // Set page title based on subtab
     let title = match subtab.as_str() {
         "workflows" => "Settings - Workflows",
         "skills" => "Settings - Skills",
+        "assets" => "Settings - Asset Storage",
         _ => "Settings",
     };
 
     rsx! {
         WorldSessionLayout {
             world_id: world_id.clone(),
-            role: ParticipantRole::DungeonMaster,
+            role: dm_session_role(&platform),
             page_title: title,
             show_status_bar: false,
 
@@ -123,18 +184,21 @@ pub fn DMSettingsSubTabRoute(world_id: String, subtab: String) -> Element {
 /// DMStoryArcSubTabRoute - Story Arc with specific sub-tab
 #[component]
 pub fn DMStoryArcSubTabRoute(world_id: String, subtab: String) -> Element {
+    let platform = use_context::<Platform>();
     // Set page title based on subtab
     let title = match subtab.as_str() {
         "timeline" => "Story Arc - Timeline",
         "events" => "Story Arc - Narrative Events",
         "chains" => "Story Arc - Event Chains",
+        "branches" => "Story Arc - Branches",
+        "campaign" => "Story Arc - Campaign",
         _ => "Story Arc",
     };
 
     rsx! {
         WorldSessionLayout {
             world_id: world_id.clone(),
-            role: ParticipantRole::DungeonMaster,
+            role: dm_session_role(&platform),
             page_title: title,
             show_status_bar: false,
 
@@ -149,6 +213,103 @@ pub fn DMStoryArcSubTabRoute(world_id: String, subtab: String) -> Element {
     }
 }
 
+/// DMTimelineEventRoute - Story Arc timeline with one event open
+#[component]
+pub fn DMTimelineEventRoute(world_id: String, event_id: String) -> Element {
+    let platform = use_context::<Platform>();
+    rsx! {
+        WorldSessionLayout {
+            world_id: world_id.clone(),
+            role: dm_session_role(&platform),
+            page_title: "Story Arc - Timeline",
+            show_status_bar: false,
+
+            DMViewContent {
+                world_id: world_id,
+                dm_mode: DMMode::StoryArc,
+                creator_subtab: None,
+                settings_subtab: None,
+                story_arc_subtab: Some("timeline".to_string()),
+                story_arc_event_id: Some(event_id),
+            }
+        }
+    }
+}
+
+/// DMNarrativeEventRoute - Narrative event library with one event open
+#[component]
+pub fn DMNarrativeEventRoute(world_id: String, event_id: String) -> Element {
+    let platform = use_context::<Platform>();
+    rsx! {
+        WorldSessionLayout {
+            world_id: world_id.clone(),
+            role: dm_session_role(&platform),
+            page_title: "Story Arc - Narrative Events",
+            show_status_bar: false,
+
+            DMViewContent {
+                world_id: world_id,
+                dm_mode: DMMode::StoryArc,
+                creator_subtab: None,
+                settings_subtab: None,
+                story_arc_subtab: Some("events".to_string()),
+                story_arc_event_id: Some(event_id),
+            }
+        }
+    }
+}
+
+/// DMNoteRoute - Notes wiki with one page open
+#[component]
+pub fn DMNoteRoute(world_id: String, note_id: String) -> Element {
+    let platform = use_context::<Platform>();
+    rsx! {
+        WorldSessionLayout {
+            world_id: world_id.clone(),
+            role: dm_session_role(&platform),
+            page_title: "Notes",
+            show_status_bar: false,
+
+            DMViewContent {
+                world_id: world_id,
+                dm_mode: DMMode::Notes,
+                creator_subtab: None,
+                settings_subtab: None,
+                story_arc_subtab: None,
+                note_id: Some(note_id),
+            }
+        }
+    }
+}
+
+/// DMPossessRoute - the DM acting as one character
+#[component]
+pub fn DMPossessRoute(world_id: String, character_id: String) -> Element {
+    let platform = use_context::<Platform>();
+    rsx! {
+        WorldSessionLayout {
+            world_id: world_id.clone(),
+            role: dm_session_role(&platform),
+            page_title: "Possession",
+            show_status_bar: false,
+
+            crate::presentation::views::possession_view::PossessionView {
+                world_id: world_id,
+                character_id: character_id,
+            }
+        }
+    }
+}
+
+/// Session role for the DM views: the co-DM role if that was chosen at role
+/// selection, otherwise the full DM role
+fn dm_session_role(platform: &Platform) -> ParticipantRole {
+    match platform.storage_load(storage_keys::ROLE).as_deref() {
+        Some("CoDungeonMaster") => ParticipantRole::CoDungeonMaster,
+        _ => ParticipantRole::DungeonMaster,
+    }
+}
+
 /// DMViewContent - inner content component for DM views
 /// Connection handling is done by WorldSessionLayout wrapper
 #[derive(Props, Clone, PartialEq)]
@@ -156,8 +317,23 @@ struct DMViewContentProps {
     world_id: String,
     dm_mode: DMMode,
     creator_subtab: Option<String>,
+    /// Entity whose form is open in Creator mode
+    #[props(default)]
+    creator_entity_id: Option<String>,
     settings_subtab: Option<String>,
     story_arc_subtab: Option<String>,
+    /// Timeline event or narrative event open in Story Arc
+    #[props(default)]
+    story_arc_event_id: Option<String>,
+    /// Whether the Challenge Library is open over the Director
+    #[props(default)]
+    challenges_open: bool,
+    /// Challenge open in the Challenge Library's editor
+    #[props(default)]
+    challenge_id: Option<String>,
+    /// Notes page open in the wiki
+    #[props(default)]
+    note_id: Option<String>,
 }
 
 #[component]
@@ -169,6 +345,7 @@ fn DMViewContent(props: DMViewContentProps) -> Element {
     let dialogue_state = use_context::<DialogueState>();
 
     let connection_status = *session_state.connection_status().read();
+    let can_edit_world = session_state.can(Capability::EditWorld);
 
     rsx! {
         div {
@@ -179,6 +356,7 @@ fn DMViewContent(props: DMViewContentProps) -> Element {
                 world_id: props.world_id.clone(),
                 dm_mode: props.dm_mode,
                 connection_status: connection_status,
+                can_edit_world: can_edit_world,
                 on_back: {
                     let platform = platform.clone();
                     let session_state = session_state.clone();
@@ -204,8 +382,13 @@ fn DMViewContent(props: DMViewContentProps) -> Element {
                     world_id: props.world_id.clone(),
                     active_mode: props.dm_mode,
                     creator_subtab: props.creator_subtab.clone(),
+                    creator_entity_id: props.creator_entity_id.clone(),
                     settings_subtab: props.settings_subtab.clone(),
                     story_arc_subtab: props.story_arc_subtab.clone(),
+                    story_arc_event_id: props.story_arc_event_id.clone(),
+                    challenges_open: props.challenges_open,
+                    challenge_id: props.challenge_id.clone(),
+                    note_id: props.note_id.clone(),
                 }
             }
         }
@@ -218,6 +401,8 @@ struct DMViewHeaderProps {
     world_id: String,
     dm_mode: DMMode,
     connection_status: ConnectionStatus,
+    /// Whether to show the world-editing tabs (false for co-DMs)
+    can_edit_world: bool,
     on_back: EventHandler<()>,
 }
 
@@ -250,23 +435,36 @@ fn DMViewHeader(props: DMViewHeaderProps) -> Element {
                         world_id: props.world_id.clone(),
                         active: props.dm_mode == DMMode::Director,
                     }
-                    DMHeaderTabLink {
-                        label: "Creator",
-                        tab: "creator",
-                        world_id: props.world_id.clone(),
-                        active: props.dm_mode == DMMode::Creator,
-                    }
-                    DMHeaderTabLink {
-                        label: "Story Arc",
-                        tab: "story-arc",
-                        world_id: props.world_id.clone(),
-                        active: props.dm_mode == DMMode::StoryArc,
-                    }
-                    DMHeaderTabLink {
-                        label: "Settings",
-                        tab: "settings",
-                        world_id: props.world_id.clone(),
-                        active: props.dm_mode == DMMode::Settings,
+                    if props.can_edit_world {
+                        DMHeaderTabLink {
+                            label: "Creator",
+                            tab: "creator",
+                            world_id: props.world_id.clone(),
+                            active: props.dm_mode == DMMode::Creator,
+                        }
+                        DMHeaderTabLink {
+                            label: "Story Arc",
+                            tab: "story-arc",
+                            world_id: props.world_id.clone(),
+                            active: props.dm_mode == DMMode::StoryArc,
+                        }
+                        DMHeaderTabLink {
+                            label: "Notes",
+                            tab: "notes",
+                            world_id: props.world_id.clone(),
+                            active: props.dm_mode == DMMode::Notes,
+                        }
+                        DMHeaderTabLink {
+                            label: "Settings",
+                            tab: "settings",
+                            world_id: props.world_id.clone(),
+                            active: props.dm_mode == DMMode::Settings,
+                        }
+                    } else {
+                        span {
+                            class: "py-1.5 px-3 text-xs text-amber-500",
+                            "Co-DM"
+                        }
                     }
                 }
             }
@@ -275,6 +473,8 @@ fn DMViewHeader(props: DMViewHeaderProps) -> Element {
             div {
                 class: "flex items-center gap-4",
 
+                crate::presentation::components::auth::UserMenu {}
+
                 // Back button
                 button {
                     onclick: move |e| {
@@ -318,6 +518,14 @@ fn DMHeaderTabLink(label: &'static str, tab: &'static str, world_id: String, act
         gs.active_count() + gs.active_suggestion_count()
     }).unwrap_or(0);
 
+    // Unread player whispers badge the Director tab
+    let session_state = use_context::<SessionState>();
+    let whisper_badge_count = if tab == "director" {
+        session_state.whispers.unread_total()
+    } else {
+        0
+    };
+
     // Determine the correct route based on tab - link directly to subtab routes
     // to avoid use_effect redirect race conditions
     let route = match tab {
@@ -353,6 +561,13 @@ fn DMHeaderTabLink(label: &'static str, tab: &'static str, world_id: String, act
                 if active { "font-medium" } else { "font-normal" }
             ),
             "{label}"
+            if whisper_badge_count > 0 {
+                span {
+                    class: "bg-purple-500 text-white rounded-xl py-0.5 px-1.5 text-[0.625rem] font-bold min-w-5 text-center",
+                    title: "Unread whispers",
+                    "{whisper_badge_count}"
+                }
+            }
             if tab == "creator" && queue_badge_count > 0 {
                 span {
                     class: "bg-amber-500 text-white rounded-xl py-0.5 px-1.5 text-[0.625rem] font-bold min-w-5 text-center",
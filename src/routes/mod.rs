@@ -7,7 +7,8 @@
 //! The application uses Dioxus Router which provides automatic browser history support:
 //! - URL updates automatically on navigation (browser address bar updates)
 //! - Back/forward buttons work correctly (router handles state restoration)
-//! - Deep links work: users can share or bookmark direct URLs to specific views
+//! - Deep links work: users can share or bookmark direct URLs to specific views, down to
+//!   a single entity's form (`/dm/creator/characters/:id`) or challenge (`/dm/challenges/:id`)
 //! - Missing state redirects: if a user navigates directly to a view without required context,
 //!   the application redirects to the appropriate setup step (MainMenu → RoleSelect → WorldSelect)
 //!
@@ -35,8 +36,12 @@ mod world_session_layout;
 
 pub use main_menu::MainMenuRoute;
 pub use world_select::{WorldSelectRoute, RoleSelectRoute};
-pub use dm_routes::{DMViewRoute, DMViewTabRoute, DMCreatorSubTabRoute, DMSettingsSubTabRoute, DMStoryArcSubTabRoute};
-pub use player_routes::{PCViewRoute, SpectatorViewRoute};
+pub use dm_routes::{
+    DMViewRoute, DMViewTabRoute, DMCreatorSubTabRoute, DMCreatorEntityRoute, DMChallengeRoute,
+    DMSettingsSubTabRoute, DMStoryArcSubTabRoute, DMTimelineEventRoute, DMNarrativeEventRoute, DMNoteRoute,
+    DMPossessRoute,
+};
+pub use player_routes::{PCViewRoute, SpectatorStreamRoute, SpectatorViewRoute};
 pub use pc_creation::PCCreationRoute;
 
 use dioxus::prelude::*;
@@ -65,6 +70,15 @@ pub enum Route {
     #[route("/worlds/:world_id/dm/creator/:subtab")]
     DMCreatorSubTabRoute { world_id: String, subtab: String },
 
+    // Creator entity deep link - opens the entity's form
+    #[route("/worlds/:world_id/dm/creator/:subtab/:entity_id")]
+    DMCreatorEntityRoute { world_id: String, subtab: String, entity_id: String },
+
+    // Challenge deep link - opens the Challenge Library's editor for one challenge
+    // (the library itself is `/worlds/:world_id/dm/challenges`)
+    #[route("/worlds/:world_id/dm/challenges/:challenge_id")]
+    DMChallengeRoute { world_id: String, challenge_id: String },
+
     // Settings sub-tabs
     #[route("/worlds/:world_id/dm/settings/:subtab")]
     DMSettingsSubTabRoute { world_id: String, subtab: String },
@@ -73,6 +87,22 @@ pub enum Route {
     #[route("/worlds/:world_id/dm/story-arc/:subtab")]
     DMStoryArcSubTabRoute { world_id: String, subtab: String },
 
+    // Timeline deep link - opens one story event
+    #[route("/worlds/:world_id/dm/story-arc/timeline/:event_id")]
+    DMTimelineEventRoute { world_id: String, event_id: String },
+
+    // Narrative event deep link - opens one event in the library
+    #[route("/worlds/:world_id/dm/story-arc/events/:event_id")]
+    DMNarrativeEventRoute { world_id: String, event_id: String },
+
+    // DM notes wiki deep link - opens one page
+    #[route("/worlds/:world_id/dm/notes/:note_id")]
+    DMNoteRoute { world_id: String, note_id: String },
+
+    // DM acting as an NPC or PC
+    #[route("/worlds/:world_id/dm/possess/:character_id")]
+    DMPossessRoute { world_id: String, character_id: String },
+
     #[route("/worlds/:world_id/play")]
     PCViewRoute { world_id: String },
 
@@ -82,6 +112,11 @@ pub enum Route {
     #[route("/worlds/:world_id/watch")]
     SpectatorViewRoute { world_id: String },
 
+    // Spectator stage without app chrome, for OBS browser sources
+    // (e.g. `?layout=dialogue&chroma=green`; both parameters are optional)
+    #[route("/worlds/:world_id/watch/stream?:layout&:chroma")]
+    SpectatorStreamRoute { world_id: String, layout: String, chroma: String },
+
     #[route("/:..route")]
     NotFoundRoute { route: Vec<String> },
 }
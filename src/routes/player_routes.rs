@@ -2,7 +2,8 @@
 
 use dioxus::prelude::*;
 use crate::application::services::ParticipantRolePort as ParticipantRole;
-use crate::presentation::state::SessionState;
+use crate::application::ports::outbound::Platform;
+use crate::presentation::state::{DisplayState, SessionState};
 use super::world_session_layout::WorldSessionLayout;
 use super::Route;
 
@@ -84,3 +85,38 @@ fn SpectatorViewContent() -> Element {
         crate::presentation::views::spectator_view::SpectatorView {}
     }
 }
+
+/// Spectator stream route - the spectator stage alone, for OBS browser sources
+#[component]
+pub fn SpectatorStreamRoute(world_id: String, layout: String, chroma: String) -> Element {
+    let settings = crate::application::services::StreamSettings::from_query(&layout, &chroma);
+
+    // See-through page and no app overlays while this route is up
+    {
+        let platform = use_context::<Platform>();
+        let mut stream_mode = use_context::<DisplayState>().stream_mode;
+        use_hook({
+            let platform = platform.clone();
+            move || {
+                platform.set_transparent_page(true);
+                stream_mode.set(true);
+            }
+        });
+        use_drop(move || {
+            platform.set_transparent_page(false);
+            stream_mode.set(false);
+        });
+    }
+
+    rsx! {
+        WorldSessionLayout {
+            world_id: world_id.clone(),
+            role: ParticipantRole::Spectator,
+            page_title: "Stream",
+            show_status_bar: false,
+            overlay: true,
+
+            crate::presentation::views::spectator_view::SpectatorView { stream: settings }
+        }
+    }
+}
@@ -2,6 +2,7 @@
 
 use dioxus::prelude::*;
 use crate::application::ports::outbound::{Platform, storage_keys};
+use crate::application::services::DEMO_WORLD_ID;
 use super::Route;
 
 /// Role selection route
@@ -19,6 +20,8 @@ pub fn RoleSelectRoute() -> Element {
         platform_title.set_page_title("Select Role");
     });
 
+    let platform_demo = platform.clone();
+
     rsx! {
         crate::presentation::views::role_select::RoleSelect {
             on_select_role: move |role: crate::UserRole| {
@@ -26,7 +29,15 @@ pub fn RoleSelectRoute() -> Element {
                 let role_str = format!("{:?}", role);
                 platform_storage.storage_save(storage_keys::ROLE, &role_str);
                 navigator.push(Route::WorldSelectRoute {});
-            }
+            },
+            on_try_demo: move |role: crate::UserRole| {
+                platform_demo.storage_save(storage_keys::ROLE, &format!("{:?}", role));
+                let world_id = DEMO_WORLD_ID.to_string();
+                match role {
+                    crate::UserRole::Player => navigator.push(Route::PCViewRoute { world_id }),
+                    _ => navigator.push(Route::DMViewRoute { world_id }),
+                };
+            },
         }
     }
 }
@@ -69,7 +80,8 @@ pub fn WorldSelectRoute() -> Element {
                     // Navigate to the appropriate view based on role
                     // Connection will be established by the destination view's ensure_*_connection
                     match role {
-                        crate::UserRole::DungeonMaster => {
+                        // Co-DMs share the DM views; the session role gates what they can do
+                        crate::UserRole::DungeonMaster | crate::UserRole::CoDungeonMaster => {
                             navigator.push(Route::DMViewRoute { world_id });
                         }
                         crate::UserRole::Player => {
@@ -93,6 +105,7 @@ fn load_role_from_storage(platform: &Platform) -> crate::UserRole {
     if let Some(role_str) = platform.storage_load(storage_keys::ROLE) {
         match role_str.as_str() {
             "DungeonMaster" => return crate::UserRole::DungeonMaster,
+            "CoDungeonMaster" => return crate::UserRole::CoDungeonMaster,
             "Player" => return crate::UserRole::Player,
             "Spectator" => return crate::UserRole::Spectator,
             _ => {}
@@ -9,8 +9,10 @@
 use dioxus::prelude::*;
 
 use crate::application::ports::outbound::{Platform, storage_keys};
-use crate::application::services::ParticipantRolePort as ParticipantRole;
-use crate::presentation::state::{ConnectionStatus, DialogueState, GameState, GenerationState, SessionState};
+use crate::application::services::{ParticipantRolePort as ParticipantRole, DEMO_WORLD_ID};
+use crate::presentation::components::auth::UserMenu;
+use crate::presentation::components::presence_strip::PresenceStrip;
+use crate::presentation::state::{AuthState, ConnectionStatus, DialogueState, GameState, GenerationState, SessionState, use_modal_focus};
 
 use super::connection::{ensure_connection, handle_disconnect};
 use super::Route;
@@ -28,6 +30,10 @@ pub struct WorldSessionLayoutProps {
     /// Set to false for views that have their own header with status indicator
     #[props(default = true)]
     pub show_status_bar: bool,
+    /// Render bare for streaming overlays: no presence tiles and no page
+    /// background, so the view decides what shows through (default: false)
+    #[props(default)]
+    pub overlay: bool,
     /// Child content to render
     pub children: Element,
 }
@@ -48,6 +54,7 @@ pub fn WorldSessionLayout(props: WorldSessionLayoutProps) -> Element {
     let game_state = use_context::<GameState>();
     let dialogue_state = use_context::<DialogueState>();
     let generation_state = use_context::<GenerationState>();
+    let auth_state = use_context::<AuthState>();
 
     // Set page title
     {
@@ -67,10 +74,12 @@ pub fn WorldSessionLayout(props: WorldSessionLayoutProps) -> Element {
         let game_state = game_state.clone();
         let dialogue_state = dialogue_state.clone();
         let generation_state = generation_state.clone();
+        let auth_state = auth_state.clone();
         use_effect(move || {
             ensure_connection(
                 &world_id,
                 role,
+                auth_state.user_id(),
                 session_state.clone(),
                 game_state.clone(),
                 dialogue_state.clone(),
@@ -81,10 +90,11 @@ pub fn WorldSessionLayout(props: WorldSessionLayoutProps) -> Element {
     }
 
     let connection_status = *session_state.connection_status().read();
+    let background_class = if props.overlay { "bg-transparent" } else { "bg-dark-bg" };
 
     rsx! {
         div {
-            class: "world-session-layout h-full flex flex-col bg-dark-bg",
+            class: "world-session-layout h-full flex flex-col {background_class}",
 
             // Connection status bar (optional - DM views use their own header)
             if props.show_status_bar {
@@ -98,12 +108,14 @@ pub fn WorldSessionLayout(props: WorldSessionLayoutProps) -> Element {
                         let game_state = game_state.clone();
                         let dialogue_state = dialogue_state.clone();
                         let generation_state = generation_state.clone();
+                        let auth_state = auth_state.clone();
                         move |_| {
                             // Force reconnection attempt by setting disconnected first
                             session_state.set_disconnected();
                             ensure_connection(
                                 &world_id,
                                 role,
+                                auth_state.user_id(),
                                 session_state.clone(),
                                 game_state.clone(),
                                 dialogue_state.clone(),
@@ -130,12 +142,26 @@ pub fn WorldSessionLayout(props: WorldSessionLayoutProps) -> Element {
                 }
             }
 
+            // Reminder that nothing here reaches an Engine
+            if props.world_id == DEMO_WORLD_ID {
+                div {
+                    class: "px-4 py-1 bg-amber-500/15 border-b border-amber-500/40 text-amber-300 text-xs text-center",
+                    role: "status",
+                    "Demo session · scripted NPCs, and nothing is saved"
+                }
+            }
+
             // Main content area
             main {
                 class: "flex-1 overflow-hidden relative",
                 {props.children}
             }
 
+            // Floating webcam/avatar tiles, once the session is joined
+            if !props.overlay && session_state.session_id().read().is_some() {
+                PresenceStrip {}
+            }
+
             // Error overlay (modal)
             if let Some(error) = session_state.error_message().read().as_ref() {
                 ErrorOverlay {
@@ -186,27 +212,34 @@ fn ConnectionStatusBar(props: ConnectionStatusBarProps) -> Element {
                 "← Back"
             }
 
-            // Right: Status indicator (clickable to retry when disconnected)
+            // Right: Logged-in identity and status indicator
             div {
-                class: "flex items-center gap-2",
-                class: if can_retry { "cursor-pointer" } else { "" },
-                onclick: move |_| {
-                    if can_retry {
-                        props.on_retry.call(());
-                    }
-                },
+                class: "flex items-center gap-4",
+
+                UserMenu {}
+
+                // Status indicator (clickable to retry when disconnected)
+                div {
+                    class: "flex items-center gap-2",
+                    class: if can_retry { "cursor-pointer" } else { "" },
+                    onclick: move |_| {
+                        if can_retry {
+                            props.on_retry.call(());
+                        }
+                    },
 
-                span {
-                    class: "w-2.5 h-2.5 rounded-full {indicator_class}",
-                }
-                span {
-                    class: "text-gray-400 text-sm",
-                    "{status_text}"
-                }
-                if can_retry {
                     span {
-                        class: "text-gray-500 text-xs ml-1",
-                        "(click to retry)"
+                        class: "w-2.5 h-2.5 rounded-full {indicator_class}",
+                    }
+                    span {
+                        class: "text-gray-400 text-sm",
+                        "{status_text}"
+                    }
+                    if can_retry {
+                        span {
+                            class: "text-gray-500 text-xs ml-1",
+                            "(click to retry)"
+                        }
                     }
                 }
             }
@@ -223,16 +256,29 @@ struct ErrorOverlayProps {
 
 #[component]
 fn ErrorOverlay(props: ErrorOverlayProps) -> Element {
+    use_modal_focus("connection-error-dialog");
+
     rsx! {
         div {
             class: "fixed inset-0 bg-black/75 flex items-center justify-center z-[1000]",
             onclick: move |_| props.on_dismiss.call(()),
 
             div {
+                id: "connection-error-dialog",
+                role: "alertdialog",
+                aria_modal: "true",
+                aria_labelledby: "connection-error-dialog-title",
                 class: "bg-dark-surface border border-red-500 rounded-lg p-6 max-w-md m-4",
                 onclick: move |e| e.stop_propagation(),
+                onkeydown: move |e: KeyboardEvent| {
+                    if e.key() == Key::Escape {
+                        e.stop_propagation();
+                        props.on_dismiss.call(());
+                    }
+                },
 
                 h3 {
+                    id: "connection-error-dialog-title",
                     class: "text-red-500 m-0 mb-2 text-lg",
                     "Connection Error"
                 }